@@ -1,3 +1,4 @@
+extern crate alloc;
 extern crate azul_core;
 
 #[cfg(target_arch = "wasm32")]
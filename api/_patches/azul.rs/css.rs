@@ -5,6 +5,16 @@
         StyleBackgroundRepeatVec,
         StyleTransformVec,
         StyleFontFamilyVec,
+        StyleTransitionPropertyVec,
+        StyleTransitionDurationVec,
+        StyleTransitionTimingFunctionVec,
+        StyleTransitionDelayVec,
+        StyleAnimationNameVec,
+        StyleAnimationDurationVec,
+        StyleAnimationIterationCountVec,
+        StyleAnimationDirectionVec,
+        StyleAnimationFillModeVec,
+        StyleAnimationDelayVec,
     };
 
     macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => ({
@@ -20,6 +30,7 @@
             CssPropertyType::Cursor => CssProperty::Cursor(StyleCursorValue::$content_type),
             CssPropertyType::Display => CssProperty::Display(LayoutDisplayValue::$content_type),
             CssPropertyType::Float => CssProperty::Float(LayoutFloatValue::$content_type),
+            CssPropertyType::Clear => CssProperty::Clear(LayoutClearValue::$content_type),
             CssPropertyType::BoxSizing => CssProperty::BoxSizing(LayoutBoxSizingValue::$content_type),
             CssPropertyType::Width => CssProperty::Width(LayoutWidthValue::$content_type),
             CssPropertyType::Height => CssProperty::Height(LayoutHeightValue::$content_type),
@@ -39,6 +50,12 @@
             CssPropertyType::JustifyContent => CssProperty::JustifyContent(LayoutJustifyContentValue::$content_type),
             CssPropertyType::AlignItems => CssProperty::AlignItems(LayoutAlignItemsValue::$content_type),
             CssPropertyType::AlignContent => CssProperty::AlignContent(LayoutAlignContentValue::$content_type),
+            CssPropertyType::GridTemplateColumns => CssProperty::GridTemplateColumns(LayoutGridTemplateColumnsValue::$content_type),
+            CssPropertyType::GridTemplateRows => CssProperty::GridTemplateRows(LayoutGridTemplateRowsValue::$content_type),
+            CssPropertyType::GridColumn => CssProperty::GridColumn(LayoutGridColumnValue::$content_type),
+            CssPropertyType::GridRow => CssProperty::GridRow(LayoutGridRowValue::$content_type),
+            CssPropertyType::ColumnGap => CssProperty::ColumnGap(LayoutColumnGapValue::$content_type),
+            CssPropertyType::RowGap => CssProperty::RowGap(LayoutRowGapValue::$content_type),
             CssPropertyType::BackgroundContent => CssProperty::BackgroundContent(StyleBackgroundContentVecValue::$content_type),
             CssPropertyType::BackgroundPosition => CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::$content_type),
             CssPropertyType::BackgroundSize => CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::$content_type),
@@ -79,6 +96,17 @@
             CssPropertyType::PerspectiveOrigin => CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::$content_type),
             CssPropertyType::TransformOrigin => CssProperty::TransformOrigin(StyleTransformOriginValue::$content_type),
             CssPropertyType::BackfaceVisibility => CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::$content_type),
+            CssPropertyType::TransitionProperty => CssProperty::TransitionProperty(StyleTransitionPropertyVecValue::$content_type),
+            CssPropertyType::TransitionDuration => CssProperty::TransitionDuration(StyleTransitionDurationVecValue::$content_type),
+            CssPropertyType::TransitionTimingFunction => CssProperty::TransitionTimingFunction(StyleTransitionTimingFunctionVecValue::$content_type),
+            CssPropertyType::TransitionDelay => CssProperty::TransitionDelay(StyleTransitionDelayVecValue::$content_type),
+            CssPropertyType::AnimationName => CssProperty::AnimationName(StyleAnimationNameVecValue::$content_type),
+            CssPropertyType::AnimationDuration => CssProperty::AnimationDuration(StyleAnimationDurationVecValue::$content_type),
+            CssPropertyType::AnimationTimingFunction => CssProperty::AnimationTimingFunction(StyleTransitionTimingFunctionVecValue::$content_type),
+            CssPropertyType::AnimationIterationCount => CssProperty::AnimationIterationCount(StyleAnimationIterationCountVecValue::$content_type),
+            CssPropertyType::AnimationDirection => CssProperty::AnimationDirection(StyleAnimationDirectionVecValue::$content_type),
+            CssPropertyType::AnimationFillMode => CssProperty::AnimationFillMode(StyleAnimationFillModeVecValue::$content_type),
+            CssPropertyType::AnimationDelay => CssProperty::AnimationDelay(StyleAnimationDelayVecValue::$content_type),
         }
     })}
 
@@ -98,6 +126,7 @@
                 CssProperty::Cursor(_) => CssPropertyType::Cursor,
                 CssProperty::Display(_) => CssPropertyType::Display,
                 CssProperty::Float(_) => CssPropertyType::Float,
+                CssProperty::Clear(_) => CssPropertyType::Clear,
                 CssProperty::BoxSizing(_) => CssPropertyType::BoxSizing,
                 CssProperty::Width(_) => CssPropertyType::Width,
                 CssProperty::Height(_) => CssPropertyType::Height,
@@ -117,6 +146,12 @@
                 CssProperty::JustifyContent(_) => CssPropertyType::JustifyContent,
                 CssProperty::AlignItems(_) => CssPropertyType::AlignItems,
                 CssProperty::AlignContent(_) => CssPropertyType::AlignContent,
+                CssProperty::GridTemplateColumns(_) => CssPropertyType::GridTemplateColumns,
+                CssProperty::GridTemplateRows(_) => CssPropertyType::GridTemplateRows,
+                CssProperty::GridColumn(_) => CssPropertyType::GridColumn,
+                CssProperty::GridRow(_) => CssPropertyType::GridRow,
+                CssProperty::ColumnGap(_) => CssPropertyType::ColumnGap,
+                CssProperty::RowGap(_) => CssPropertyType::RowGap,
                 CssProperty::BackgroundContent(_) => CssPropertyType::BackgroundContent,
                 CssProperty::BackgroundPosition(_) => CssPropertyType::BackgroundPosition,
                 CssProperty::BackgroundSize(_) => CssPropertyType::BackgroundSize,
@@ -157,6 +192,17 @@
                 CssProperty::PerspectiveOrigin(_) => CssPropertyType::PerspectiveOrigin,
                 CssProperty::TransformOrigin(_) => CssPropertyType::TransformOrigin,
                 CssProperty::BackfaceVisibility(_) => CssPropertyType::BackfaceVisibility,
+                CssProperty::TransitionProperty(_) => CssPropertyType::TransitionProperty,
+                CssProperty::TransitionDuration(_) => CssPropertyType::TransitionDuration,
+                CssProperty::TransitionTimingFunction(_) => CssPropertyType::TransitionTimingFunction,
+                CssProperty::TransitionDelay(_) => CssPropertyType::TransitionDelay,
+                CssProperty::AnimationName(_) => CssPropertyType::AnimationName,
+                CssProperty::AnimationDuration(_) => CssPropertyType::AnimationDuration,
+                CssProperty::AnimationTimingFunction(_) => CssPropertyType::AnimationTimingFunction,
+                CssProperty::AnimationIterationCount(_) => CssPropertyType::AnimationIterationCount,
+                CssProperty::AnimationDirection(_) => CssPropertyType::AnimationDirection,
+                CssProperty::AnimationFillMode(_) => CssPropertyType::AnimationFillMode,
+                CssProperty::AnimationDelay(_) => CssPropertyType::AnimationDelay,
             }
         }
 
@@ -178,6 +224,7 @@
         pub const fn cursor(input: StyleCursor) -> Self { CssProperty::Cursor(StyleCursorValue::Exact(input)) }
         pub const fn display(input: LayoutDisplay) -> Self { CssProperty::Display(LayoutDisplayValue::Exact(input)) }
         pub const fn float(input: LayoutFloat) -> Self { CssProperty::Float(LayoutFloatValue::Exact(input)) }
+        pub const fn clear(input: LayoutClear) -> Self { CssProperty::Clear(LayoutClearValue::Exact(input)) }
         pub const fn box_sizing(input: LayoutBoxSizing) -> Self { CssProperty::BoxSizing(LayoutBoxSizingValue::Exact(input)) }
         pub const fn width(input: LayoutWidth) -> Self { CssProperty::Width(LayoutWidthValue::Exact(input)) }
         pub const fn height(input: LayoutHeight) -> Self { CssProperty::Height(LayoutHeightValue::Exact(input)) }
@@ -197,6 +244,12 @@
         pub const fn justify_content(input: LayoutJustifyContent) -> Self { CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(input)) }
         pub const fn align_items(input: LayoutAlignItems) -> Self { CssProperty::AlignItems(LayoutAlignItemsValue::Exact(input)) }
         pub const fn align_content(input: LayoutAlignContent) -> Self { CssProperty::AlignContent(LayoutAlignContentValue::Exact(input)) }
+        pub const fn grid_template_columns(input: LayoutGridTemplateColumns) -> Self { CssProperty::GridTemplateColumns(LayoutGridTemplateColumnsValue::Exact(input)) }
+        pub const fn grid_template_rows(input: LayoutGridTemplateRows) -> Self { CssProperty::GridTemplateRows(LayoutGridTemplateRowsValue::Exact(input)) }
+        pub const fn grid_column(input: LayoutGridColumn) -> Self { CssProperty::GridColumn(LayoutGridColumnValue::Exact(input)) }
+        pub const fn grid_row(input: LayoutGridRow) -> Self { CssProperty::GridRow(LayoutGridRowValue::Exact(input)) }
+        pub const fn column_gap(input: LayoutColumnGap) -> Self { CssProperty::ColumnGap(LayoutColumnGapValue::Exact(input)) }
+        pub const fn row_gap(input: LayoutRowGap) -> Self { CssProperty::RowGap(LayoutRowGapValue::Exact(input)) }
         pub const fn background_content(input: StyleBackgroundContentVec) -> Self { CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(input)) }
         pub const fn background_position(input: StyleBackgroundPositionVec) -> Self { CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::Exact(input)) }
         pub const fn background_size(input: StyleBackgroundSizeVec) -> Self { CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::Exact(input)) }
@@ -236,6 +289,17 @@
         pub const fn transform_origin(input: StyleTransformOrigin) -> Self { CssProperty::TransformOrigin(StyleTransformOriginValue::Exact(input)) }
         pub const fn perspective_origin(input: StylePerspectiveOrigin) -> Self { CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::Exact(input)) }
         pub const fn backface_visiblity(input: StyleBackfaceVisibility) -> Self { CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::Exact(input)) }
+        pub const fn transition_property(input: StyleTransitionPropertyVec) -> Self { CssProperty::TransitionProperty(StyleTransitionPropertyVecValue::Exact(input)) }
+        pub const fn transition_duration(input: StyleTransitionDurationVec) -> Self { CssProperty::TransitionDuration(StyleTransitionDurationVecValue::Exact(input)) }
+        pub const fn transition_timing_function(input: StyleTransitionTimingFunctionVec) -> Self { CssProperty::TransitionTimingFunction(StyleTransitionTimingFunctionVecValue::Exact(input)) }
+        pub const fn transition_delay(input: StyleTransitionDelayVec) -> Self { CssProperty::TransitionDelay(StyleTransitionDelayVecValue::Exact(input)) }
+        pub const fn animation_name(input: StyleAnimationNameVec) -> Self { CssProperty::AnimationName(StyleAnimationNameVecValue::Exact(input)) }
+        pub const fn animation_duration(input: StyleAnimationDurationVec) -> Self { CssProperty::AnimationDuration(StyleAnimationDurationVecValue::Exact(input)) }
+        pub const fn animation_timing_function(input: StyleTransitionTimingFunctionVec) -> Self { CssProperty::AnimationTimingFunction(StyleTransitionTimingFunctionVecValue::Exact(input)) }
+        pub const fn animation_iteration_count(input: StyleAnimationIterationCountVec) -> Self { CssProperty::AnimationIterationCount(StyleAnimationIterationCountVecValue::Exact(input)) }
+        pub const fn animation_direction(input: StyleAnimationDirectionVec) -> Self { CssProperty::AnimationDirection(StyleAnimationDirectionVecValue::Exact(input)) }
+        pub const fn animation_fill_mode(input: StyleAnimationFillModeVec) -> Self { CssProperty::AnimationFillMode(StyleAnimationFillModeVecValue::Exact(input)) }
+        pub const fn animation_delay(input: StyleAnimationDelayVec) -> Self { CssProperty::AnimationDelay(StyleAnimationDelayVecValue::Exact(input)) }
 
     }
 
@@ -309,6 +309,67 @@
     impl_vec!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor, az_menu_item_vec_destructor, AzMenuItemVec_delete);
     impl_vec_clone!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor);
 
+    impl_vec!(AzCssFontFace,  AzCssFontFaceVec,  AzCssFontFaceVecDestructor, az_css_font_face_vec_destructor, AzCssFontFaceVec_delete);
+    impl_vec_clone!(AzCssFontFace,  AzCssFontFaceVec,  AzCssFontFaceVecDestructor);
+
+    impl_vec!(AzCssMediaQuery,  AzCssMediaQueryVec,  AzCssMediaQueryVecDestructor, az_css_media_query_vec_destructor, AzCssMediaQueryVec_delete);
+    impl_vec_clone!(AzCssMediaQuery,  AzCssMediaQueryVec,  AzCssMediaQueryVecDestructor);
+
+    impl_vec!(AzCssMediaCondition,  AzCssMediaConditionVec,  AzCssMediaConditionVecDestructor, az_css_media_condition_vec_destructor, AzCssMediaConditionVec_delete);
+    impl_vec_clone!(AzCssMediaCondition,  AzCssMediaConditionVec,  AzCssMediaConditionVecDestructor);
+
+    impl_vec!(AzGridTrackSizing,  AzGridTrackSizingVec,  AzGridTrackSizingVecDestructor, az_grid_track_sizing_vec_destructor, AzGridTrackSizingVec_delete);
+    impl_vec_clone!(AzGridTrackSizing,  AzGridTrackSizingVec,  AzGridTrackSizingVecDestructor);
+
+    impl_vec!(AzStyleTransitionProperty,  AzStyleTransitionPropertyVec,  AzStyleTransitionPropertyVecDestructor, az_style_transition_property_vec_destructor, AzStyleTransitionPropertyVec_delete);
+    impl_vec_clone!(AzStyleTransitionProperty,  AzStyleTransitionPropertyVec,  AzStyleTransitionPropertyVecDestructor);
+
+    impl_vec!(AzStyleTransitionDuration,  AzStyleTransitionDurationVec,  AzStyleTransitionDurationVecDestructor, az_style_transition_duration_vec_destructor, AzStyleTransitionDurationVec_delete);
+    impl_vec_clone!(AzStyleTransitionDuration,  AzStyleTransitionDurationVec,  AzStyleTransitionDurationVecDestructor);
+
+    impl_vec!(AzStyleTransitionTimingFunction,  AzStyleTransitionTimingFunctionVec,  AzStyleTransitionTimingFunctionVecDestructor, az_style_transition_timing_function_vec_destructor, AzStyleTransitionTimingFunctionVec_delete);
+    impl_vec_clone!(AzStyleTransitionTimingFunction,  AzStyleTransitionTimingFunctionVec,  AzStyleTransitionTimingFunctionVecDestructor);
+
+    impl_vec!(AzStyleTransitionDelay,  AzStyleTransitionDelayVec,  AzStyleTransitionDelayVecDestructor, az_style_transition_delay_vec_destructor, AzStyleTransitionDelayVec_delete);
+    impl_vec_clone!(AzStyleTransitionDelay,  AzStyleTransitionDelayVec,  AzStyleTransitionDelayVecDestructor);
+
+    impl_vec!(AzStyleAnimationName,  AzStyleAnimationNameVec,  AzStyleAnimationNameVecDestructor, az_style_animation_name_vec_destructor, AzStyleAnimationNameVec_delete);
+    impl_vec_clone!(AzStyleAnimationName,  AzStyleAnimationNameVec,  AzStyleAnimationNameVecDestructor);
+
+    impl_vec!(AzStyleAnimationDuration,  AzStyleAnimationDurationVec,  AzStyleAnimationDurationVecDestructor, az_style_animation_duration_vec_destructor, AzStyleAnimationDurationVec_delete);
+    impl_vec_clone!(AzStyleAnimationDuration,  AzStyleAnimationDurationVec,  AzStyleAnimationDurationVecDestructor);
+
+    impl_vec!(AzStyleAnimationIterationCount,  AzStyleAnimationIterationCountVec,  AzStyleAnimationIterationCountVecDestructor, az_style_animation_iteration_count_vec_destructor, AzStyleAnimationIterationCountVec_delete);
+    impl_vec_clone!(AzStyleAnimationIterationCount,  AzStyleAnimationIterationCountVec,  AzStyleAnimationIterationCountVecDestructor);
+
+    impl_vec!(AzStyleAnimationDirection,  AzStyleAnimationDirectionVec,  AzStyleAnimationDirectionVecDestructor, az_style_animation_direction_vec_destructor, AzStyleAnimationDirectionVec_delete);
+    impl_vec_clone!(AzStyleAnimationDirection,  AzStyleAnimationDirectionVec,  AzStyleAnimationDirectionVecDestructor);
+
+    impl_vec!(AzStyleAnimationFillMode,  AzStyleAnimationFillModeVec,  AzStyleAnimationFillModeVecDestructor, az_style_animation_fill_mode_vec_destructor, AzStyleAnimationFillModeVec_delete);
+    impl_vec_clone!(AzStyleAnimationFillMode,  AzStyleAnimationFillModeVec,  AzStyleAnimationFillModeVecDestructor);
+
+    impl_vec!(AzStyleAnimationDelay,  AzStyleAnimationDelayVec,  AzStyleAnimationDelayVecDestructor, az_style_animation_delay_vec_destructor, AzStyleAnimationDelayVec_delete);
+    impl_vec_clone!(AzStyleAnimationDelay,  AzStyleAnimationDelayVec,  AzStyleAnimationDelayVecDestructor);
+
+    impl_vec!(AzCssKeyframe,  AzCssKeyframeVec,  AzCssKeyframeVecDestructor, az_css_keyframe_vec_destructor, AzCssKeyframeVec_delete);
+    impl_vec_clone!(AzCssKeyframe,  AzCssKeyframeVec,  AzCssKeyframeVecDestructor);
+
+    impl_vec!(AzCssKeyframes,  AzCssKeyframesVec,  AzCssKeyframesVecDestructor, az_css_keyframes_vec_destructor, AzCssKeyframesVec_delete);
+    impl_vec_clone!(AzCssKeyframes,  AzCssKeyframesVec,  AzCssKeyframesVecDestructor);
+
+    impl_vec!(AzKeyboardShortcut,  AzKeyboardShortcutVec,  AzKeyboardShortcutVecDestructor, az_keyboard_shortcut_vec_destructor, AzKeyboardShortcutVec_delete);
+    impl_vec_clone!(AzKeyboardShortcut,  AzKeyboardShortcutVec,  AzKeyboardShortcutVecDestructor);
+
+    impl_vec!(AzAccessibilityNode,  AzAccessibilityNodeVec,  AzAccessibilityNodeVecDestructor, az_accessibility_node_vec_destructor, AzAccessibilityNodeVec_delete);
+    impl_vec_clone!(AzAccessibilityNode,  AzAccessibilityNodeVec,  AzAccessibilityNodeVecDestructor);
+
+    impl_vec!(AzTextEditSnapshot,  AzTextEditSnapshotVec,  AzTextEditSnapshotVecDestructor, az_text_edit_snapshot_vec_destructor, AzTextEditSnapshotVec_delete);
+    impl_vec_clone!(AzTextEditSnapshot,  AzTextEditSnapshotVec,  AzTextEditSnapshotVecDestructor);
+    impl_vec!(AzSpreadsheetSizeOverride,  AzSpreadsheetSizeOverrideVec,  AzSpreadsheetSizeOverrideVecDestructor, az_spreadsheet_size_override_vec_destructor, AzSpreadsheetSizeOverrideVec_delete);
+    impl_vec_clone!(AzSpreadsheetSizeOverride,  AzSpreadsheetSizeOverrideVec,  AzSpreadsheetSizeOverrideVecDestructor);
+    impl_vec!(AzSpreadsheetCellContent,  AzSpreadsheetCellContentVec,  AzSpreadsheetCellContentVecDestructor, az_spreadsheet_cell_content_vec_destructor, AzSpreadsheetCellContentVec_delete);
+    impl_vec_clone!(AzSpreadsheetCellContent,  AzSpreadsheetCellContentVec,  AzSpreadsheetCellContentVecDestructor);
+
     impl From<vec::Vec<string::String>> for crate::vec::StringVec {
         fn from(v: vec::Vec<string::String>) -> crate::vec::StringVec {
             let vec: Vec<AzString> = v.into_iter().map(Into::into).collect();
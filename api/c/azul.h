@@ -102,6 +102,30 @@ struct AzNumberInputState;
 typedef struct AzNumberInputState AzNumberInputState;
 typedef AzUpdate (*AzNumberInputOnValueChangeCallbackType)(AzRefAny* restrict A, AzNumberInputState* const B, AzCallbackInfo* restrict C);
 
+struct AzTextEditState;
+typedef struct AzTextEditState AzTextEditState;
+typedef AzUpdate (*AzTextEditOnTextChangeCallbackType)(AzRefAny* restrict A, AzTextEditState* const B, AzCallbackInfo* restrict C);
+
+typedef AzUpdate (*AzTextEditOnFocusLostCallbackType)(AzRefAny* restrict A, AzTextEditState* const B, AzCallbackInfo* restrict C);
+
+struct AzSliderState;
+typedef struct AzSliderState AzSliderState;
+typedef AzUpdate (*AzSliderOnValueChangeCallbackType)(AzRefAny* restrict A, AzSliderState* const B, AzCallbackInfo* restrict C);
+
+struct AzDropDownState;
+typedef struct AzDropDownState AzDropDownState;
+typedef AzUpdate (*AzDropDownOnChoiceChangeCallbackType)(AzRefAny* restrict A, AzDropDownState* const B, AzCallbackInfo* restrict C);
+
+struct AzSpreadsheetState;
+typedef struct AzSpreadsheetState AzSpreadsheetState;
+struct AzSpreadsheetCellSelection;
+typedef struct AzSpreadsheetCellSelection AzSpreadsheetCellSelection;
+typedef AzUpdate (*AzSpreadsheetOnCellsChangeCallbackType)(AzRefAny* restrict A, AzSpreadsheetState* const B, AzSpreadsheetCellSelection C, AzCallbackInfo* restrict D);
+
+typedef AzUpdate (*AzSpreadsheetOnSelectionChangeCallbackType)(AzRefAny* restrict A, AzSpreadsheetState* const B, AzCallbackInfo* restrict C);
+
+typedef AzUpdate (*AzSpreadsheetOnColumnResizeCallbackType)(AzRefAny* restrict A, AzSpreadsheetState* const B, size_t C, AzCallbackInfo* restrict D);
+
 typedef void (*AzParsedFontDestructorFnType)(void* restrict A);
 
 struct AzInstantPtr;
@@ -152,6 +176,10 @@ struct AzAccessibilityStateVec;
 typedef struct AzAccessibilityStateVec AzAccessibilityStateVec;
 typedef void (*AzAccessibilityStateVecDestructorType)(AzAccessibilityStateVec* restrict A);
 
+struct AzAccessibilityNodeVec;
+typedef struct AzAccessibilityNodeVec AzAccessibilityNodeVec;
+typedef void (*AzAccessibilityNodeVecDestructorType)(AzAccessibilityNodeVec* restrict A);
+
 struct AzMenuItemVec;
 typedef struct AzMenuItemVec AzMenuItemVec;
 typedef void (*AzMenuItemVecDestructorType)(AzMenuItemVec* restrict A);
@@ -260,6 +288,10 @@ struct AzVirtualKeyCodeVec;
 typedef struct AzVirtualKeyCodeVec AzVirtualKeyCodeVec;
 typedef void (*AzVirtualKeyCodeVecDestructorType)(AzVirtualKeyCodeVec* restrict A);
 
+struct AzKeyboardShortcutVec;
+typedef struct AzKeyboardShortcutVec AzKeyboardShortcutVec;
+typedef void (*AzKeyboardShortcutVecDestructorType)(AzKeyboardShortcutVec* restrict A);
+
 struct AzCascadeInfoVec;
 typedef struct AzCascadeInfoVec AzCascadeInfoVec;
 typedef void (*AzCascadeInfoVecDestructorType)(AzCascadeInfoVec* restrict A);
@@ -280,6 +312,70 @@ struct AzStylesheetVec;
 typedef struct AzStylesheetVec AzStylesheetVec;
 typedef void (*AzStylesheetVecDestructorType)(AzStylesheetVec* restrict A);
 
+struct AzCssFontFaceVec;
+typedef struct AzCssFontFaceVec AzCssFontFaceVec;
+typedef void (*AzCssFontFaceVecDestructorType)(AzCssFontFaceVec* restrict A);
+
+struct AzStyleTransitionPropertyVec;
+typedef struct AzStyleTransitionPropertyVec AzStyleTransitionPropertyVec;
+typedef void (*AzStyleTransitionPropertyVecDestructorType)(AzStyleTransitionPropertyVec* restrict A);
+
+struct AzStyleTransitionDurationVec;
+typedef struct AzStyleTransitionDurationVec AzStyleTransitionDurationVec;
+typedef void (*AzStyleTransitionDurationVecDestructorType)(AzStyleTransitionDurationVec* restrict A);
+
+struct AzStyleTransitionTimingFunctionVec;
+typedef struct AzStyleTransitionTimingFunctionVec AzStyleTransitionTimingFunctionVec;
+typedef void (*AzStyleTransitionTimingFunctionVecDestructorType)(AzStyleTransitionTimingFunctionVec* restrict A);
+
+struct AzStyleTransitionDelayVec;
+typedef struct AzStyleTransitionDelayVec AzStyleTransitionDelayVec;
+typedef void (*AzStyleTransitionDelayVecDestructorType)(AzStyleTransitionDelayVec* restrict A);
+
+struct AzStyleAnimationNameVec;
+typedef struct AzStyleAnimationNameVec AzStyleAnimationNameVec;
+typedef void (*AzStyleAnimationNameVecDestructorType)(AzStyleAnimationNameVec* restrict A);
+
+struct AzStyleAnimationDurationVec;
+typedef struct AzStyleAnimationDurationVec AzStyleAnimationDurationVec;
+typedef void (*AzStyleAnimationDurationVecDestructorType)(AzStyleAnimationDurationVec* restrict A);
+
+struct AzStyleAnimationIterationCountVec;
+typedef struct AzStyleAnimationIterationCountVec AzStyleAnimationIterationCountVec;
+typedef void (*AzStyleAnimationIterationCountVecDestructorType)(AzStyleAnimationIterationCountVec* restrict A);
+
+struct AzStyleAnimationDirectionVec;
+typedef struct AzStyleAnimationDirectionVec AzStyleAnimationDirectionVec;
+typedef void (*AzStyleAnimationDirectionVecDestructorType)(AzStyleAnimationDirectionVec* restrict A);
+
+struct AzStyleAnimationFillModeVec;
+typedef struct AzStyleAnimationFillModeVec AzStyleAnimationFillModeVec;
+typedef void (*AzStyleAnimationFillModeVecDestructorType)(AzStyleAnimationFillModeVec* restrict A);
+
+struct AzStyleAnimationDelayVec;
+typedef struct AzStyleAnimationDelayVec AzStyleAnimationDelayVec;
+typedef void (*AzStyleAnimationDelayVecDestructorType)(AzStyleAnimationDelayVec* restrict A);
+
+struct AzCssKeyframeVec;
+typedef struct AzCssKeyframeVec AzCssKeyframeVec;
+typedef void (*AzCssKeyframeVecDestructorType)(AzCssKeyframeVec* restrict A);
+
+struct AzCssKeyframesVec;
+typedef struct AzCssKeyframesVec AzCssKeyframesVec;
+typedef void (*AzCssKeyframesVecDestructorType)(AzCssKeyframesVec* restrict A);
+
+struct AzGridTrackSizingVec;
+typedef struct AzGridTrackSizingVec AzGridTrackSizingVec;
+typedef void (*AzGridTrackSizingVecDestructorType)(AzGridTrackSizingVec* restrict A);
+
+struct AzCssMediaQueryVec;
+typedef struct AzCssMediaQueryVec AzCssMediaQueryVec;
+typedef void (*AzCssMediaQueryVecDestructorType)(AzCssMediaQueryVec* restrict A);
+
+struct AzCssMediaConditionVec;
+typedef struct AzCssMediaConditionVec AzCssMediaConditionVec;
+typedef void (*AzCssMediaConditionVecDestructorType)(AzCssMediaConditionVec* restrict A);
+
 struct AzCssRuleBlockVec;
 typedef struct AzCssRuleBlockVec AzCssRuleBlockVec;
 typedef void (*AzCssRuleBlockVecDestructorType)(AzCssRuleBlockVec* restrict A);
@@ -352,6 +448,18 @@ struct AzNodeDataVec;
 typedef struct AzNodeDataVec AzNodeDataVec;
 typedef void (*AzNodeDataVecDestructorType)(AzNodeDataVec* restrict A);
 
+struct AzTextEditSnapshotVec;
+typedef struct AzTextEditSnapshotVec AzTextEditSnapshotVec;
+typedef void (*AzTextEditSnapshotVecDestructorType)(AzTextEditSnapshotVec* restrict A);
+
+struct AzSpreadsheetSizeOverrideVec;
+typedef struct AzSpreadsheetSizeOverrideVec AzSpreadsheetSizeOverrideVec;
+typedef void (*AzSpreadsheetSizeOverrideVecDestructorType)(AzSpreadsheetSizeOverrideVec* restrict A);
+
+struct AzSpreadsheetCellContentVec;
+typedef struct AzSpreadsheetCellContentVec AzSpreadsheetCellContentVec;
+typedef void (*AzSpreadsheetCellContentVecDestructorType)(AzSpreadsheetCellContentVec* restrict A);
+
 
 struct AzApp {
     void* ptr;
@@ -1132,6 +1240,18 @@ enum AzMenuItemState {
 };
 typedef enum AzMenuItemState AzMenuItemState;
 
+enum AzCssMediaOrientation {
+   AzCssMediaOrientation_Portrait,
+   AzCssMediaOrientation_Landscape,
+};
+typedef enum AzCssMediaOrientation AzCssMediaOrientation;
+
+enum AzCssMediaColorScheme {
+   AzCssMediaColorScheme_Light,
+   AzCssMediaColorScheme_Dark,
+};
+typedef enum AzCssMediaColorScheme AzCssMediaColorScheme;
+
 enum AzNodeTypeKey {
    AzNodeTypeKey_Body,
    AzNodeTypeKey_Div,
@@ -1142,12 +1262,30 @@ enum AzNodeTypeKey {
 };
 typedef enum AzNodeTypeKey AzNodeTypeKey;
 
+enum AzCssAttributeSelectorOp {
+   AzCssAttributeSelectorOp_Exists,
+   AzCssAttributeSelectorOp_Equals,
+   AzCssAttributeSelectorOp_Includes,
+   AzCssAttributeSelectorOp_DashMatch,
+   AzCssAttributeSelectorOp_Prefix,
+   AzCssAttributeSelectorOp_Suffix,
+   AzCssAttributeSelectorOp_Substring,
+};
+typedef enum AzCssAttributeSelectorOp AzCssAttributeSelectorOp;
+
 struct AzCssNthChildPattern {
     uint32_t repeat;
     uint32_t offset;
 };
 typedef struct AzCssNthChildPattern AzCssNthChildPattern;
 
+enum AzCssFontFaceStyle {
+   AzCssFontFaceStyle_Normal,
+   AzCssFontFaceStyle_Italic,
+   AzCssFontFaceStyle_Oblique,
+};
+typedef enum AzCssFontFaceStyle AzCssFontFaceStyle;
+
 enum AzCssPropertyType {
    AzCssPropertyType_TextColor,
    AzCssPropertyType_FontSize,
@@ -1160,6 +1298,7 @@ enum AzCssPropertyType {
    AzCssPropertyType_Cursor,
    AzCssPropertyType_Display,
    AzCssPropertyType_Float,
+   AzCssPropertyType_Clear,
    AzCssPropertyType_BoxSizing,
    AzCssPropertyType_Width,
    AzCssPropertyType_Height,
@@ -1179,6 +1318,12 @@ enum AzCssPropertyType {
    AzCssPropertyType_JustifyContent,
    AzCssPropertyType_AlignItems,
    AzCssPropertyType_AlignContent,
+   AzCssPropertyType_GridTemplateColumns,
+   AzCssPropertyType_GridTemplateRows,
+   AzCssPropertyType_GridColumn,
+   AzCssPropertyType_GridRow,
+   AzCssPropertyType_ColumnGap,
+   AzCssPropertyType_RowGap,
    AzCssPropertyType_BackgroundContent,
    AzCssPropertyType_BackgroundPosition,
    AzCssPropertyType_BackgroundSize,
@@ -1219,6 +1364,17 @@ enum AzCssPropertyType {
    AzCssPropertyType_TransformOrigin,
    AzCssPropertyType_PerspectiveOrigin,
    AzCssPropertyType_BackfaceVisibility,
+   AzCssPropertyType_TransitionProperty,
+   AzCssPropertyType_TransitionDuration,
+   AzCssPropertyType_TransitionTimingFunction,
+   AzCssPropertyType_TransitionDelay,
+   AzCssPropertyType_AnimationName,
+   AzCssPropertyType_AnimationDuration,
+   AzCssPropertyType_AnimationTimingFunction,
+   AzCssPropertyType_AnimationIterationCount,
+   AzCssPropertyType_AnimationDirection,
+   AzCssPropertyType_AnimationFillMode,
+   AzCssPropertyType_AnimationDelay,
 };
 typedef enum AzCssPropertyType AzCssPropertyType;
 
@@ -1259,6 +1415,26 @@ enum AzLayoutAlignContent {
 };
 typedef enum AzLayoutAlignContent AzLayoutAlignContent;
 
+enum AzGridLineTag {
+   AzGridLineTag_Auto,
+   AzGridLineTag_Line,
+   AzGridLineTag_Span,
+};
+typedef enum AzGridLineTag AzGridLineTag;
+
+struct AzGridLineVariant_Auto { AzGridLineTag tag; };
+typedef struct AzGridLineVariant_Auto AzGridLineVariant_Auto;
+struct AzGridLineVariant_Line { AzGridLineTag tag; int32_t payload; };
+typedef struct AzGridLineVariant_Line AzGridLineVariant_Line;
+struct AzGridLineVariant_Span { AzGridLineTag tag; uint32_t payload; };
+typedef struct AzGridLineVariant_Span AzGridLineVariant_Span;
+union AzGridLine {
+    AzGridLineVariant_Auto Auto;
+    AzGridLineVariant_Line Line;
+    AzGridLineVariant_Span Span;
+};
+typedef union AzGridLine AzGridLine;
+
 enum AzLayoutAlignItems {
    AzLayoutAlignItems_Stretch,
    AzLayoutAlignItems_Center,
@@ -1286,15 +1462,25 @@ enum AzLayoutDisplay {
    AzLayoutDisplay_Flex,
    AzLayoutDisplay_Block,
    AzLayoutDisplay_InlineBlock,
+   AzLayoutDisplay_Grid,
 };
 typedef enum AzLayoutDisplay AzLayoutDisplay;
 
 enum AzLayoutFloat {
    AzLayoutFloat_Left,
    AzLayoutFloat_Right,
+   AzLayoutFloat_None,
 };
 typedef enum AzLayoutFloat AzLayoutFloat;
 
+enum AzLayoutClear {
+   AzLayoutClear_None,
+   AzLayoutClear_Left,
+   AzLayoutClear_Right,
+   AzLayoutClear_Both,
+};
+typedef enum AzLayoutClear AzLayoutClear;
+
 enum AzLayoutJustifyContent {
    AzLayoutJustifyContent_Start,
    AzLayoutJustifyContent_End,
@@ -1430,6 +1616,58 @@ enum AzStyleBackfaceVisibility {
 };
 typedef enum AzStyleBackfaceVisibility AzStyleBackfaceVisibility;
 
+struct AzStyleTransitionDuration {
+    uint32_t ms;
+};
+typedef struct AzStyleTransitionDuration AzStyleTransitionDuration;
+
+struct AzStyleTransitionDelay {
+    uint32_t ms;
+};
+typedef struct AzStyleTransitionDelay AzStyleTransitionDelay;
+
+struct AzStyleAnimationDuration {
+    uint32_t ms;
+};
+typedef struct AzStyleAnimationDuration AzStyleAnimationDuration;
+
+struct AzStyleAnimationDelay {
+    uint32_t ms;
+};
+typedef struct AzStyleAnimationDelay AzStyleAnimationDelay;
+
+enum AzStyleAnimationIterationCountTag {
+   AzStyleAnimationIterationCountTag_Count,
+   AzStyleAnimationIterationCountTag_Infinite,
+};
+typedef enum AzStyleAnimationIterationCountTag AzStyleAnimationIterationCountTag;
+
+struct AzStyleAnimationIterationCountVariant_Count { AzStyleAnimationIterationCountTag tag; uint32_t payload; };
+typedef struct AzStyleAnimationIterationCountVariant_Count AzStyleAnimationIterationCountVariant_Count;
+struct AzStyleAnimationIterationCountVariant_Infinite { AzStyleAnimationIterationCountTag tag; };
+typedef struct AzStyleAnimationIterationCountVariant_Infinite AzStyleAnimationIterationCountVariant_Infinite;
+union AzStyleAnimationIterationCount {
+    AzStyleAnimationIterationCountVariant_Count Count;
+    AzStyleAnimationIterationCountVariant_Infinite Infinite;
+};
+typedef union AzStyleAnimationIterationCount AzStyleAnimationIterationCount;
+
+enum AzStyleAnimationDirection {
+   AzStyleAnimationDirection_Normal,
+   AzStyleAnimationDirection_Reverse,
+   AzStyleAnimationDirection_Alternate,
+   AzStyleAnimationDirection_AlternateReverse,
+};
+typedef enum AzStyleAnimationDirection AzStyleAnimationDirection;
+
+enum AzStyleAnimationFillMode {
+   AzStyleAnimationFillMode_None,
+   AzStyleAnimationFillMode_Forwards,
+   AzStyleAnimationFillMode_Backwards,
+   AzStyleAnimationFillMode_Both,
+};
+typedef enum AzStyleAnimationFillMode AzStyleAnimationFillMode;
+
 enum AzStyleTextAlign {
    AzStyleTextAlign_Left,
    AzStyleTextAlign_Center,
@@ -1492,6 +1730,72 @@ struct AzNumberInputOnValueChangeCallback {
 };
 typedef struct AzNumberInputOnValueChangeCallback AzNumberInputOnValueChangeCallback;
 
+enum AzTextEditKind {
+   AzTextEditKind_Typing,
+   AzTextEditKind_Deleting,
+   AzTextEditKind_Other,
+};
+typedef enum AzTextEditKind AzTextEditKind;
+
+struct AzTextEditOnTextChangeCallback {
+    AzTextEditOnTextChangeCallbackType cb;
+};
+typedef struct AzTextEditOnTextChangeCallback AzTextEditOnTextChangeCallback;
+
+struct AzTextEditOnFocusLostCallback {
+    AzTextEditOnFocusLostCallbackType cb;
+};
+typedef struct AzTextEditOnFocusLostCallback AzTextEditOnFocusLostCallback;
+
+enum AzSliderOrientation {
+   AzSliderOrientation_Horizontal,
+   AzSliderOrientation_Vertical,
+};
+typedef enum AzSliderOrientation AzSliderOrientation;
+
+struct AzSliderOnValueChangeCallback {
+    AzSliderOnValueChangeCallbackType cb;
+};
+typedef struct AzSliderOnValueChangeCallback AzSliderOnValueChangeCallback;
+
+struct AzDropDownOnChoiceChangeCallback {
+    AzDropDownOnChoiceChangeCallbackType cb;
+};
+typedef struct AzDropDownOnChoiceChangeCallback AzDropDownOnChoiceChangeCallback;
+
+struct AzSpreadsheetCellIndex {
+    size_t row;
+    size_t column;
+};
+typedef struct AzSpreadsheetCellIndex AzSpreadsheetCellIndex;
+
+struct AzSpreadsheetSizeOverride {
+    size_t index;
+    float size;
+};
+typedef struct AzSpreadsheetSizeOverride AzSpreadsheetSizeOverride;
+
+struct AzSpreadsheetColumnResize {
+    size_t column;
+    float width;
+};
+typedef struct AzSpreadsheetColumnResize AzSpreadsheetColumnResize;
+
+struct AzSpreadsheetOnCellsChangeCallback {
+    AzSpreadsheetOnCellsChangeCallbackType cb;
+};
+typedef struct AzSpreadsheetOnCellsChangeCallback AzSpreadsheetOnCellsChangeCallback;
+
+struct AzSpreadsheetOnSelectionChangeCallback {
+    AzSpreadsheetOnSelectionChangeCallbackType cb;
+};
+typedef struct AzSpreadsheetOnSelectionChangeCallback AzSpreadsheetOnSelectionChangeCallback;
+
+struct AzSpreadsheetOnColumnResizeCallback {
+    AzSpreadsheetOnColumnResizeCallbackType cb;
+};
+typedef struct AzSpreadsheetOnColumnResizeCallback AzSpreadsheetOnColumnResizeCallback;
+
 struct AzNode {
     size_t parent;
     size_t previous_sibling;
@@ -2024,6 +2328,26 @@ union AzAccessibilityStateVecDestructor {
 };
 typedef union AzAccessibilityStateVecDestructor AzAccessibilityStateVecDestructor;
 
+enum AzAccessibilityNodeVecDestructorTag {
+   AzAccessibilityNodeVecDestructorTag_DefaultRust,
+   AzAccessibilityNodeVecDestructorTag_NoDestructor,
+   AzAccessibilityNodeVecDestructorTag_External,
+};
+typedef enum AzAccessibilityNodeVecDestructorTag AzAccessibilityNodeVecDestructorTag;
+
+struct AzAccessibilityNodeVecDestructorVariant_DefaultRust { AzAccessibilityNodeVecDestructorTag tag; };
+typedef struct AzAccessibilityNodeVecDestructorVariant_DefaultRust AzAccessibilityNodeVecDestructorVariant_DefaultRust;
+struct AzAccessibilityNodeVecDestructorVariant_NoDestructor { AzAccessibilityNodeVecDestructorTag tag; };
+typedef struct AzAccessibilityNodeVecDestructorVariant_NoDestructor AzAccessibilityNodeVecDestructorVariant_NoDestructor;
+struct AzAccessibilityNodeVecDestructorVariant_External { AzAccessibilityNodeVecDestructorTag tag; AzAccessibilityNodeVecDestructorType payload; };
+typedef struct AzAccessibilityNodeVecDestructorVariant_External AzAccessibilityNodeVecDestructorVariant_External;
+union AzAccessibilityNodeVecDestructor {
+    AzAccessibilityNodeVecDestructorVariant_DefaultRust DefaultRust;
+    AzAccessibilityNodeVecDestructorVariant_NoDestructor NoDestructor;
+    AzAccessibilityNodeVecDestructorVariant_External External;
+};
+typedef union AzAccessibilityNodeVecDestructor AzAccessibilityNodeVecDestructor;
+
 enum AzMenuItemVecDestructorTag {
    AzMenuItemVecDestructorTag_DefaultRust,
    AzMenuItemVecDestructorTag_NoDestructor,
@@ -2564,6 +2888,26 @@ union AzVirtualKeyCodeVecDestructor {
 };
 typedef union AzVirtualKeyCodeVecDestructor AzVirtualKeyCodeVecDestructor;
 
+enum AzKeyboardShortcutVecDestructorTag {
+   AzKeyboardShortcutVecDestructorTag_DefaultRust,
+   AzKeyboardShortcutVecDestructorTag_NoDestructor,
+   AzKeyboardShortcutVecDestructorTag_External,
+};
+typedef enum AzKeyboardShortcutVecDestructorTag AzKeyboardShortcutVecDestructorTag;
+
+struct AzKeyboardShortcutVecDestructorVariant_DefaultRust { AzKeyboardShortcutVecDestructorTag tag; };
+typedef struct AzKeyboardShortcutVecDestructorVariant_DefaultRust AzKeyboardShortcutVecDestructorVariant_DefaultRust;
+struct AzKeyboardShortcutVecDestructorVariant_NoDestructor { AzKeyboardShortcutVecDestructorTag tag; };
+typedef struct AzKeyboardShortcutVecDestructorVariant_NoDestructor AzKeyboardShortcutVecDestructorVariant_NoDestructor;
+struct AzKeyboardShortcutVecDestructorVariant_External { AzKeyboardShortcutVecDestructorTag tag; AzKeyboardShortcutVecDestructorType payload; };
+typedef struct AzKeyboardShortcutVecDestructorVariant_External AzKeyboardShortcutVecDestructorVariant_External;
+union AzKeyboardShortcutVecDestructor {
+    AzKeyboardShortcutVecDestructorVariant_DefaultRust DefaultRust;
+    AzKeyboardShortcutVecDestructorVariant_NoDestructor NoDestructor;
+    AzKeyboardShortcutVecDestructorVariant_External External;
+};
+typedef union AzKeyboardShortcutVecDestructor AzKeyboardShortcutVecDestructor;
+
 enum AzCascadeInfoVecDestructorTag {
    AzCascadeInfoVecDestructorTag_DefaultRust,
    AzCascadeInfoVecDestructorTag_NoDestructor,
@@ -2664,6 +3008,326 @@ union AzStylesheetVecDestructor {
 };
 typedef union AzStylesheetVecDestructor AzStylesheetVecDestructor;
 
+enum AzCssFontFaceVecDestructorTag {
+   AzCssFontFaceVecDestructorTag_DefaultRust,
+   AzCssFontFaceVecDestructorTag_NoDestructor,
+   AzCssFontFaceVecDestructorTag_External,
+};
+typedef enum AzCssFontFaceVecDestructorTag AzCssFontFaceVecDestructorTag;
+
+struct AzCssFontFaceVecDestructorVariant_DefaultRust { AzCssFontFaceVecDestructorTag tag; };
+typedef struct AzCssFontFaceVecDestructorVariant_DefaultRust AzCssFontFaceVecDestructorVariant_DefaultRust;
+struct AzCssFontFaceVecDestructorVariant_NoDestructor { AzCssFontFaceVecDestructorTag tag; };
+typedef struct AzCssFontFaceVecDestructorVariant_NoDestructor AzCssFontFaceVecDestructorVariant_NoDestructor;
+struct AzCssFontFaceVecDestructorVariant_External { AzCssFontFaceVecDestructorTag tag; AzCssFontFaceVecDestructorType payload; };
+typedef struct AzCssFontFaceVecDestructorVariant_External AzCssFontFaceVecDestructorVariant_External;
+union AzCssFontFaceVecDestructor {
+    AzCssFontFaceVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssFontFaceVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssFontFaceVecDestructorVariant_External External;
+};
+typedef union AzCssFontFaceVecDestructor AzCssFontFaceVecDestructor;
+
+enum AzStyleTransitionPropertyVecDestructorTag {
+   AzStyleTransitionPropertyVecDestructorTag_DefaultRust,
+   AzStyleTransitionPropertyVecDestructorTag_NoDestructor,
+   AzStyleTransitionPropertyVecDestructorTag_External,
+};
+typedef enum AzStyleTransitionPropertyVecDestructorTag AzStyleTransitionPropertyVecDestructorTag;
+
+struct AzStyleTransitionPropertyVecDestructorVariant_DefaultRust { AzStyleTransitionPropertyVecDestructorTag tag; };
+typedef struct AzStyleTransitionPropertyVecDestructorVariant_DefaultRust AzStyleTransitionPropertyVecDestructorVariant_DefaultRust;
+struct AzStyleTransitionPropertyVecDestructorVariant_NoDestructor { AzStyleTransitionPropertyVecDestructorTag tag; };
+typedef struct AzStyleTransitionPropertyVecDestructorVariant_NoDestructor AzStyleTransitionPropertyVecDestructorVariant_NoDestructor;
+struct AzStyleTransitionPropertyVecDestructorVariant_External { AzStyleTransitionPropertyVecDestructorTag tag; AzStyleTransitionPropertyVecDestructorType payload; };
+typedef struct AzStyleTransitionPropertyVecDestructorVariant_External AzStyleTransitionPropertyVecDestructorVariant_External;
+union AzStyleTransitionPropertyVecDestructor {
+    AzStyleTransitionPropertyVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleTransitionPropertyVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleTransitionPropertyVecDestructorVariant_External External;
+};
+typedef union AzStyleTransitionPropertyVecDestructor AzStyleTransitionPropertyVecDestructor;
+
+enum AzStyleTransitionDurationVecDestructorTag {
+   AzStyleTransitionDurationVecDestructorTag_DefaultRust,
+   AzStyleTransitionDurationVecDestructorTag_NoDestructor,
+   AzStyleTransitionDurationVecDestructorTag_External,
+};
+typedef enum AzStyleTransitionDurationVecDestructorTag AzStyleTransitionDurationVecDestructorTag;
+
+struct AzStyleTransitionDurationVecDestructorVariant_DefaultRust { AzStyleTransitionDurationVecDestructorTag tag; };
+typedef struct AzStyleTransitionDurationVecDestructorVariant_DefaultRust AzStyleTransitionDurationVecDestructorVariant_DefaultRust;
+struct AzStyleTransitionDurationVecDestructorVariant_NoDestructor { AzStyleTransitionDurationVecDestructorTag tag; };
+typedef struct AzStyleTransitionDurationVecDestructorVariant_NoDestructor AzStyleTransitionDurationVecDestructorVariant_NoDestructor;
+struct AzStyleTransitionDurationVecDestructorVariant_External { AzStyleTransitionDurationVecDestructorTag tag; AzStyleTransitionDurationVecDestructorType payload; };
+typedef struct AzStyleTransitionDurationVecDestructorVariant_External AzStyleTransitionDurationVecDestructorVariant_External;
+union AzStyleTransitionDurationVecDestructor {
+    AzStyleTransitionDurationVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleTransitionDurationVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleTransitionDurationVecDestructorVariant_External External;
+};
+typedef union AzStyleTransitionDurationVecDestructor AzStyleTransitionDurationVecDestructor;
+
+enum AzStyleTransitionTimingFunctionVecDestructorTag {
+   AzStyleTransitionTimingFunctionVecDestructorTag_DefaultRust,
+   AzStyleTransitionTimingFunctionVecDestructorTag_NoDestructor,
+   AzStyleTransitionTimingFunctionVecDestructorTag_External,
+};
+typedef enum AzStyleTransitionTimingFunctionVecDestructorTag AzStyleTransitionTimingFunctionVecDestructorTag;
+
+struct AzStyleTransitionTimingFunctionVecDestructorVariant_DefaultRust { AzStyleTransitionTimingFunctionVecDestructorTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVecDestructorVariant_DefaultRust AzStyleTransitionTimingFunctionVecDestructorVariant_DefaultRust;
+struct AzStyleTransitionTimingFunctionVecDestructorVariant_NoDestructor { AzStyleTransitionTimingFunctionVecDestructorTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVecDestructorVariant_NoDestructor AzStyleTransitionTimingFunctionVecDestructorVariant_NoDestructor;
+struct AzStyleTransitionTimingFunctionVecDestructorVariant_External { AzStyleTransitionTimingFunctionVecDestructorTag tag; AzStyleTransitionTimingFunctionVecDestructorType payload; };
+typedef struct AzStyleTransitionTimingFunctionVecDestructorVariant_External AzStyleTransitionTimingFunctionVecDestructorVariant_External;
+union AzStyleTransitionTimingFunctionVecDestructor {
+    AzStyleTransitionTimingFunctionVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleTransitionTimingFunctionVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleTransitionTimingFunctionVecDestructorVariant_External External;
+};
+typedef union AzStyleTransitionTimingFunctionVecDestructor AzStyleTransitionTimingFunctionVecDestructor;
+
+enum AzStyleTransitionDelayVecDestructorTag {
+   AzStyleTransitionDelayVecDestructorTag_DefaultRust,
+   AzStyleTransitionDelayVecDestructorTag_NoDestructor,
+   AzStyleTransitionDelayVecDestructorTag_External,
+};
+typedef enum AzStyleTransitionDelayVecDestructorTag AzStyleTransitionDelayVecDestructorTag;
+
+struct AzStyleTransitionDelayVecDestructorVariant_DefaultRust { AzStyleTransitionDelayVecDestructorTag tag; };
+typedef struct AzStyleTransitionDelayVecDestructorVariant_DefaultRust AzStyleTransitionDelayVecDestructorVariant_DefaultRust;
+struct AzStyleTransitionDelayVecDestructorVariant_NoDestructor { AzStyleTransitionDelayVecDestructorTag tag; };
+typedef struct AzStyleTransitionDelayVecDestructorVariant_NoDestructor AzStyleTransitionDelayVecDestructorVariant_NoDestructor;
+struct AzStyleTransitionDelayVecDestructorVariant_External { AzStyleTransitionDelayVecDestructorTag tag; AzStyleTransitionDelayVecDestructorType payload; };
+typedef struct AzStyleTransitionDelayVecDestructorVariant_External AzStyleTransitionDelayVecDestructorVariant_External;
+union AzStyleTransitionDelayVecDestructor {
+    AzStyleTransitionDelayVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleTransitionDelayVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleTransitionDelayVecDestructorVariant_External External;
+};
+typedef union AzStyleTransitionDelayVecDestructor AzStyleTransitionDelayVecDestructor;
+
+enum AzStyleAnimationNameVecDestructorTag {
+   AzStyleAnimationNameVecDestructorTag_DefaultRust,
+   AzStyleAnimationNameVecDestructorTag_NoDestructor,
+   AzStyleAnimationNameVecDestructorTag_External,
+};
+typedef enum AzStyleAnimationNameVecDestructorTag AzStyleAnimationNameVecDestructorTag;
+
+struct AzStyleAnimationNameVecDestructorVariant_DefaultRust { AzStyleAnimationNameVecDestructorTag tag; };
+typedef struct AzStyleAnimationNameVecDestructorVariant_DefaultRust AzStyleAnimationNameVecDestructorVariant_DefaultRust;
+struct AzStyleAnimationNameVecDestructorVariant_NoDestructor { AzStyleAnimationNameVecDestructorTag tag; };
+typedef struct AzStyleAnimationNameVecDestructorVariant_NoDestructor AzStyleAnimationNameVecDestructorVariant_NoDestructor;
+struct AzStyleAnimationNameVecDestructorVariant_External { AzStyleAnimationNameVecDestructorTag tag; AzStyleAnimationNameVecDestructorType payload; };
+typedef struct AzStyleAnimationNameVecDestructorVariant_External AzStyleAnimationNameVecDestructorVariant_External;
+union AzStyleAnimationNameVecDestructor {
+    AzStyleAnimationNameVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleAnimationNameVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleAnimationNameVecDestructorVariant_External External;
+};
+typedef union AzStyleAnimationNameVecDestructor AzStyleAnimationNameVecDestructor;
+
+enum AzStyleAnimationDurationVecDestructorTag {
+   AzStyleAnimationDurationVecDestructorTag_DefaultRust,
+   AzStyleAnimationDurationVecDestructorTag_NoDestructor,
+   AzStyleAnimationDurationVecDestructorTag_External,
+};
+typedef enum AzStyleAnimationDurationVecDestructorTag AzStyleAnimationDurationVecDestructorTag;
+
+struct AzStyleAnimationDurationVecDestructorVariant_DefaultRust { AzStyleAnimationDurationVecDestructorTag tag; };
+typedef struct AzStyleAnimationDurationVecDestructorVariant_DefaultRust AzStyleAnimationDurationVecDestructorVariant_DefaultRust;
+struct AzStyleAnimationDurationVecDestructorVariant_NoDestructor { AzStyleAnimationDurationVecDestructorTag tag; };
+typedef struct AzStyleAnimationDurationVecDestructorVariant_NoDestructor AzStyleAnimationDurationVecDestructorVariant_NoDestructor;
+struct AzStyleAnimationDurationVecDestructorVariant_External { AzStyleAnimationDurationVecDestructorTag tag; AzStyleAnimationDurationVecDestructorType payload; };
+typedef struct AzStyleAnimationDurationVecDestructorVariant_External AzStyleAnimationDurationVecDestructorVariant_External;
+union AzStyleAnimationDurationVecDestructor {
+    AzStyleAnimationDurationVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleAnimationDurationVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleAnimationDurationVecDestructorVariant_External External;
+};
+typedef union AzStyleAnimationDurationVecDestructor AzStyleAnimationDurationVecDestructor;
+
+enum AzStyleAnimationIterationCountVecDestructorTag {
+   AzStyleAnimationIterationCountVecDestructorTag_DefaultRust,
+   AzStyleAnimationIterationCountVecDestructorTag_NoDestructor,
+   AzStyleAnimationIterationCountVecDestructorTag_External,
+};
+typedef enum AzStyleAnimationIterationCountVecDestructorTag AzStyleAnimationIterationCountVecDestructorTag;
+
+struct AzStyleAnimationIterationCountVecDestructorVariant_DefaultRust { AzStyleAnimationIterationCountVecDestructorTag tag; };
+typedef struct AzStyleAnimationIterationCountVecDestructorVariant_DefaultRust AzStyleAnimationIterationCountVecDestructorVariant_DefaultRust;
+struct AzStyleAnimationIterationCountVecDestructorVariant_NoDestructor { AzStyleAnimationIterationCountVecDestructorTag tag; };
+typedef struct AzStyleAnimationIterationCountVecDestructorVariant_NoDestructor AzStyleAnimationIterationCountVecDestructorVariant_NoDestructor;
+struct AzStyleAnimationIterationCountVecDestructorVariant_External { AzStyleAnimationIterationCountVecDestructorTag tag; AzStyleAnimationIterationCountVecDestructorType payload; };
+typedef struct AzStyleAnimationIterationCountVecDestructorVariant_External AzStyleAnimationIterationCountVecDestructorVariant_External;
+union AzStyleAnimationIterationCountVecDestructor {
+    AzStyleAnimationIterationCountVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleAnimationIterationCountVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleAnimationIterationCountVecDestructorVariant_External External;
+};
+typedef union AzStyleAnimationIterationCountVecDestructor AzStyleAnimationIterationCountVecDestructor;
+
+enum AzStyleAnimationDirectionVecDestructorTag {
+   AzStyleAnimationDirectionVecDestructorTag_DefaultRust,
+   AzStyleAnimationDirectionVecDestructorTag_NoDestructor,
+   AzStyleAnimationDirectionVecDestructorTag_External,
+};
+typedef enum AzStyleAnimationDirectionVecDestructorTag AzStyleAnimationDirectionVecDestructorTag;
+
+struct AzStyleAnimationDirectionVecDestructorVariant_DefaultRust { AzStyleAnimationDirectionVecDestructorTag tag; };
+typedef struct AzStyleAnimationDirectionVecDestructorVariant_DefaultRust AzStyleAnimationDirectionVecDestructorVariant_DefaultRust;
+struct AzStyleAnimationDirectionVecDestructorVariant_NoDestructor { AzStyleAnimationDirectionVecDestructorTag tag; };
+typedef struct AzStyleAnimationDirectionVecDestructorVariant_NoDestructor AzStyleAnimationDirectionVecDestructorVariant_NoDestructor;
+struct AzStyleAnimationDirectionVecDestructorVariant_External { AzStyleAnimationDirectionVecDestructorTag tag; AzStyleAnimationDirectionVecDestructorType payload; };
+typedef struct AzStyleAnimationDirectionVecDestructorVariant_External AzStyleAnimationDirectionVecDestructorVariant_External;
+union AzStyleAnimationDirectionVecDestructor {
+    AzStyleAnimationDirectionVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleAnimationDirectionVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleAnimationDirectionVecDestructorVariant_External External;
+};
+typedef union AzStyleAnimationDirectionVecDestructor AzStyleAnimationDirectionVecDestructor;
+
+enum AzStyleAnimationFillModeVecDestructorTag {
+   AzStyleAnimationFillModeVecDestructorTag_DefaultRust,
+   AzStyleAnimationFillModeVecDestructorTag_NoDestructor,
+   AzStyleAnimationFillModeVecDestructorTag_External,
+};
+typedef enum AzStyleAnimationFillModeVecDestructorTag AzStyleAnimationFillModeVecDestructorTag;
+
+struct AzStyleAnimationFillModeVecDestructorVariant_DefaultRust { AzStyleAnimationFillModeVecDestructorTag tag; };
+typedef struct AzStyleAnimationFillModeVecDestructorVariant_DefaultRust AzStyleAnimationFillModeVecDestructorVariant_DefaultRust;
+struct AzStyleAnimationFillModeVecDestructorVariant_NoDestructor { AzStyleAnimationFillModeVecDestructorTag tag; };
+typedef struct AzStyleAnimationFillModeVecDestructorVariant_NoDestructor AzStyleAnimationFillModeVecDestructorVariant_NoDestructor;
+struct AzStyleAnimationFillModeVecDestructorVariant_External { AzStyleAnimationFillModeVecDestructorTag tag; AzStyleAnimationFillModeVecDestructorType payload; };
+typedef struct AzStyleAnimationFillModeVecDestructorVariant_External AzStyleAnimationFillModeVecDestructorVariant_External;
+union AzStyleAnimationFillModeVecDestructor {
+    AzStyleAnimationFillModeVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleAnimationFillModeVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleAnimationFillModeVecDestructorVariant_External External;
+};
+typedef union AzStyleAnimationFillModeVecDestructor AzStyleAnimationFillModeVecDestructor;
+
+enum AzStyleAnimationDelayVecDestructorTag {
+   AzStyleAnimationDelayVecDestructorTag_DefaultRust,
+   AzStyleAnimationDelayVecDestructorTag_NoDestructor,
+   AzStyleAnimationDelayVecDestructorTag_External,
+};
+typedef enum AzStyleAnimationDelayVecDestructorTag AzStyleAnimationDelayVecDestructorTag;
+
+struct AzStyleAnimationDelayVecDestructorVariant_DefaultRust { AzStyleAnimationDelayVecDestructorTag tag; };
+typedef struct AzStyleAnimationDelayVecDestructorVariant_DefaultRust AzStyleAnimationDelayVecDestructorVariant_DefaultRust;
+struct AzStyleAnimationDelayVecDestructorVariant_NoDestructor { AzStyleAnimationDelayVecDestructorTag tag; };
+typedef struct AzStyleAnimationDelayVecDestructorVariant_NoDestructor AzStyleAnimationDelayVecDestructorVariant_NoDestructor;
+struct AzStyleAnimationDelayVecDestructorVariant_External { AzStyleAnimationDelayVecDestructorTag tag; AzStyleAnimationDelayVecDestructorType payload; };
+typedef struct AzStyleAnimationDelayVecDestructorVariant_External AzStyleAnimationDelayVecDestructorVariant_External;
+union AzStyleAnimationDelayVecDestructor {
+    AzStyleAnimationDelayVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleAnimationDelayVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleAnimationDelayVecDestructorVariant_External External;
+};
+typedef union AzStyleAnimationDelayVecDestructor AzStyleAnimationDelayVecDestructor;
+
+enum AzCssKeyframeVecDestructorTag {
+   AzCssKeyframeVecDestructorTag_DefaultRust,
+   AzCssKeyframeVecDestructorTag_NoDestructor,
+   AzCssKeyframeVecDestructorTag_External,
+};
+typedef enum AzCssKeyframeVecDestructorTag AzCssKeyframeVecDestructorTag;
+
+struct AzCssKeyframeVecDestructorVariant_DefaultRust { AzCssKeyframeVecDestructorTag tag; };
+typedef struct AzCssKeyframeVecDestructorVariant_DefaultRust AzCssKeyframeVecDestructorVariant_DefaultRust;
+struct AzCssKeyframeVecDestructorVariant_NoDestructor { AzCssKeyframeVecDestructorTag tag; };
+typedef struct AzCssKeyframeVecDestructorVariant_NoDestructor AzCssKeyframeVecDestructorVariant_NoDestructor;
+struct AzCssKeyframeVecDestructorVariant_External { AzCssKeyframeVecDestructorTag tag; AzCssKeyframeVecDestructorType payload; };
+typedef struct AzCssKeyframeVecDestructorVariant_External AzCssKeyframeVecDestructorVariant_External;
+union AzCssKeyframeVecDestructor {
+    AzCssKeyframeVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssKeyframeVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssKeyframeVecDestructorVariant_External External;
+};
+typedef union AzCssKeyframeVecDestructor AzCssKeyframeVecDestructor;
+
+enum AzCssKeyframesVecDestructorTag {
+   AzCssKeyframesVecDestructorTag_DefaultRust,
+   AzCssKeyframesVecDestructorTag_NoDestructor,
+   AzCssKeyframesVecDestructorTag_External,
+};
+typedef enum AzCssKeyframesVecDestructorTag AzCssKeyframesVecDestructorTag;
+
+struct AzCssKeyframesVecDestructorVariant_DefaultRust { AzCssKeyframesVecDestructorTag tag; };
+typedef struct AzCssKeyframesVecDestructorVariant_DefaultRust AzCssKeyframesVecDestructorVariant_DefaultRust;
+struct AzCssKeyframesVecDestructorVariant_NoDestructor { AzCssKeyframesVecDestructorTag tag; };
+typedef struct AzCssKeyframesVecDestructorVariant_NoDestructor AzCssKeyframesVecDestructorVariant_NoDestructor;
+struct AzCssKeyframesVecDestructorVariant_External { AzCssKeyframesVecDestructorTag tag; AzCssKeyframesVecDestructorType payload; };
+typedef struct AzCssKeyframesVecDestructorVariant_External AzCssKeyframesVecDestructorVariant_External;
+union AzCssKeyframesVecDestructor {
+    AzCssKeyframesVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssKeyframesVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssKeyframesVecDestructorVariant_External External;
+};
+typedef union AzCssKeyframesVecDestructor AzCssKeyframesVecDestructor;
+
+enum AzGridTrackSizingVecDestructorTag {
+   AzGridTrackSizingVecDestructorTag_DefaultRust,
+   AzGridTrackSizingVecDestructorTag_NoDestructor,
+   AzGridTrackSizingVecDestructorTag_External,
+};
+typedef enum AzGridTrackSizingVecDestructorTag AzGridTrackSizingVecDestructorTag;
+
+struct AzGridTrackSizingVecDestructorVariant_DefaultRust { AzGridTrackSizingVecDestructorTag tag; };
+typedef struct AzGridTrackSizingVecDestructorVariant_DefaultRust AzGridTrackSizingVecDestructorVariant_DefaultRust;
+struct AzGridTrackSizingVecDestructorVariant_NoDestructor { AzGridTrackSizingVecDestructorTag tag; };
+typedef struct AzGridTrackSizingVecDestructorVariant_NoDestructor AzGridTrackSizingVecDestructorVariant_NoDestructor;
+struct AzGridTrackSizingVecDestructorVariant_External { AzGridTrackSizingVecDestructorTag tag; AzGridTrackSizingVecDestructorType payload; };
+typedef struct AzGridTrackSizingVecDestructorVariant_External AzGridTrackSizingVecDestructorVariant_External;
+union AzGridTrackSizingVecDestructor {
+    AzGridTrackSizingVecDestructorVariant_DefaultRust DefaultRust;
+    AzGridTrackSizingVecDestructorVariant_NoDestructor NoDestructor;
+    AzGridTrackSizingVecDestructorVariant_External External;
+};
+typedef union AzGridTrackSizingVecDestructor AzGridTrackSizingVecDestructor;
+
+enum AzCssMediaQueryVecDestructorTag {
+   AzCssMediaQueryVecDestructorTag_DefaultRust,
+   AzCssMediaQueryVecDestructorTag_NoDestructor,
+   AzCssMediaQueryVecDestructorTag_External,
+};
+typedef enum AzCssMediaQueryVecDestructorTag AzCssMediaQueryVecDestructorTag;
+
+struct AzCssMediaQueryVecDestructorVariant_DefaultRust { AzCssMediaQueryVecDestructorTag tag; };
+typedef struct AzCssMediaQueryVecDestructorVariant_DefaultRust AzCssMediaQueryVecDestructorVariant_DefaultRust;
+struct AzCssMediaQueryVecDestructorVariant_NoDestructor { AzCssMediaQueryVecDestructorTag tag; };
+typedef struct AzCssMediaQueryVecDestructorVariant_NoDestructor AzCssMediaQueryVecDestructorVariant_NoDestructor;
+struct AzCssMediaQueryVecDestructorVariant_External { AzCssMediaQueryVecDestructorTag tag; AzCssMediaQueryVecDestructorType payload; };
+typedef struct AzCssMediaQueryVecDestructorVariant_External AzCssMediaQueryVecDestructorVariant_External;
+union AzCssMediaQueryVecDestructor {
+    AzCssMediaQueryVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssMediaQueryVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssMediaQueryVecDestructorVariant_External External;
+};
+typedef union AzCssMediaQueryVecDestructor AzCssMediaQueryVecDestructor;
+
+enum AzCssMediaConditionVecDestructorTag {
+   AzCssMediaConditionVecDestructorTag_DefaultRust,
+   AzCssMediaConditionVecDestructorTag_NoDestructor,
+   AzCssMediaConditionVecDestructorTag_External,
+};
+typedef enum AzCssMediaConditionVecDestructorTag AzCssMediaConditionVecDestructorTag;
+
+struct AzCssMediaConditionVecDestructorVariant_DefaultRust { AzCssMediaConditionVecDestructorTag tag; };
+typedef struct AzCssMediaConditionVecDestructorVariant_DefaultRust AzCssMediaConditionVecDestructorVariant_DefaultRust;
+struct AzCssMediaConditionVecDestructorVariant_NoDestructor { AzCssMediaConditionVecDestructorTag tag; };
+typedef struct AzCssMediaConditionVecDestructorVariant_NoDestructor AzCssMediaConditionVecDestructorVariant_NoDestructor;
+struct AzCssMediaConditionVecDestructorVariant_External { AzCssMediaConditionVecDestructorTag tag; AzCssMediaConditionVecDestructorType payload; };
+typedef struct AzCssMediaConditionVecDestructorVariant_External AzCssMediaConditionVecDestructorVariant_External;
+union AzCssMediaConditionVecDestructor {
+    AzCssMediaConditionVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssMediaConditionVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssMediaConditionVecDestructorVariant_External External;
+};
+typedef union AzCssMediaConditionVecDestructor AzCssMediaConditionVecDestructor;
+
 enum AzCssRuleBlockVecDestructorTag {
    AzCssRuleBlockVecDestructorTag_DefaultRust,
    AzCssRuleBlockVecDestructorTag_NoDestructor,
@@ -3024,6 +3688,66 @@ union AzNodeDataVecDestructor {
 };
 typedef union AzNodeDataVecDestructor AzNodeDataVecDestructor;
 
+enum AzTextEditSnapshotVecDestructorTag {
+   AzTextEditSnapshotVecDestructorTag_DefaultRust,
+   AzTextEditSnapshotVecDestructorTag_NoDestructor,
+   AzTextEditSnapshotVecDestructorTag_External,
+};
+typedef enum AzTextEditSnapshotVecDestructorTag AzTextEditSnapshotVecDestructorTag;
+
+struct AzTextEditSnapshotVecDestructorVariant_DefaultRust { AzTextEditSnapshotVecDestructorTag tag; };
+typedef struct AzTextEditSnapshotVecDestructorVariant_DefaultRust AzTextEditSnapshotVecDestructorVariant_DefaultRust;
+struct AzTextEditSnapshotVecDestructorVariant_NoDestructor { AzTextEditSnapshotVecDestructorTag tag; };
+typedef struct AzTextEditSnapshotVecDestructorVariant_NoDestructor AzTextEditSnapshotVecDestructorVariant_NoDestructor;
+struct AzTextEditSnapshotVecDestructorVariant_External { AzTextEditSnapshotVecDestructorTag tag; AzTextEditSnapshotVecDestructorType payload; };
+typedef struct AzTextEditSnapshotVecDestructorVariant_External AzTextEditSnapshotVecDestructorVariant_External;
+union AzTextEditSnapshotVecDestructor {
+    AzTextEditSnapshotVecDestructorVariant_DefaultRust DefaultRust;
+    AzTextEditSnapshotVecDestructorVariant_NoDestructor NoDestructor;
+    AzTextEditSnapshotVecDestructorVariant_External External;
+};
+typedef union AzTextEditSnapshotVecDestructor AzTextEditSnapshotVecDestructor;
+
+enum AzSpreadsheetSizeOverrideVecDestructorTag {
+   AzSpreadsheetSizeOverrideVecDestructorTag_DefaultRust,
+   AzSpreadsheetSizeOverrideVecDestructorTag_NoDestructor,
+   AzSpreadsheetSizeOverrideVecDestructorTag_External,
+};
+typedef enum AzSpreadsheetSizeOverrideVecDestructorTag AzSpreadsheetSizeOverrideVecDestructorTag;
+
+struct AzSpreadsheetSizeOverrideVecDestructorVariant_DefaultRust { AzSpreadsheetSizeOverrideVecDestructorTag tag; };
+typedef struct AzSpreadsheetSizeOverrideVecDestructorVariant_DefaultRust AzSpreadsheetSizeOverrideVecDestructorVariant_DefaultRust;
+struct AzSpreadsheetSizeOverrideVecDestructorVariant_NoDestructor { AzSpreadsheetSizeOverrideVecDestructorTag tag; };
+typedef struct AzSpreadsheetSizeOverrideVecDestructorVariant_NoDestructor AzSpreadsheetSizeOverrideVecDestructorVariant_NoDestructor;
+struct AzSpreadsheetSizeOverrideVecDestructorVariant_External { AzSpreadsheetSizeOverrideVecDestructorTag tag; AzSpreadsheetSizeOverrideVecDestructorType payload; };
+typedef struct AzSpreadsheetSizeOverrideVecDestructorVariant_External AzSpreadsheetSizeOverrideVecDestructorVariant_External;
+union AzSpreadsheetSizeOverrideVecDestructor {
+    AzSpreadsheetSizeOverrideVecDestructorVariant_DefaultRust DefaultRust;
+    AzSpreadsheetSizeOverrideVecDestructorVariant_NoDestructor NoDestructor;
+    AzSpreadsheetSizeOverrideVecDestructorVariant_External External;
+};
+typedef union AzSpreadsheetSizeOverrideVecDestructor AzSpreadsheetSizeOverrideVecDestructor;
+
+enum AzSpreadsheetCellContentVecDestructorTag {
+   AzSpreadsheetCellContentVecDestructorTag_DefaultRust,
+   AzSpreadsheetCellContentVecDestructorTag_NoDestructor,
+   AzSpreadsheetCellContentVecDestructorTag_External,
+};
+typedef enum AzSpreadsheetCellContentVecDestructorTag AzSpreadsheetCellContentVecDestructorTag;
+
+struct AzSpreadsheetCellContentVecDestructorVariant_DefaultRust { AzSpreadsheetCellContentVecDestructorTag tag; };
+typedef struct AzSpreadsheetCellContentVecDestructorVariant_DefaultRust AzSpreadsheetCellContentVecDestructorVariant_DefaultRust;
+struct AzSpreadsheetCellContentVecDestructorVariant_NoDestructor { AzSpreadsheetCellContentVecDestructorTag tag; };
+typedef struct AzSpreadsheetCellContentVecDestructorVariant_NoDestructor AzSpreadsheetCellContentVecDestructorVariant_NoDestructor;
+struct AzSpreadsheetCellContentVecDestructorVariant_External { AzSpreadsheetCellContentVecDestructorTag tag; AzSpreadsheetCellContentVecDestructorType payload; };
+typedef struct AzSpreadsheetCellContentVecDestructorVariant_External AzSpreadsheetCellContentVecDestructorVariant_External;
+union AzSpreadsheetCellContentVecDestructor {
+    AzSpreadsheetCellContentVecDestructorVariant_DefaultRust DefaultRust;
+    AzSpreadsheetCellContentVecDestructorVariant_NoDestructor NoDestructor;
+    AzSpreadsheetCellContentVecDestructorVariant_External External;
+};
+typedef union AzSpreadsheetCellContentVecDestructor AzSpreadsheetCellContentVecDestructor;
+
 enum AzOptionI16Tag {
    AzOptionI16Tag_None,
    AzOptionI16Tag_Some,
@@ -3381,6 +4105,7 @@ struct AzInlineGlyph {
     AzLogicalRect bounds;
     AzOptionChar unicode_codepoint;
     uint32_t glyph_index;
+    size_t fallback_font_index;
 };
 typedef struct AzInlineGlyph AzInlineGlyph;
 
@@ -3401,6 +4126,8 @@ struct AzInlineTextHit {
     size_t char_index_relative_to_line;
     size_t glyph_index_relative_to_word;
     size_t char_index_relative_to_word;
+    bool  is_rtl;
+    size_t cursor_char_index_relative_to_text;
 };
 typedef struct AzInlineTextHit AzInlineTextHit;
 
@@ -3518,6 +4245,78 @@ struct AzStyleBoxShadow {
 };
 typedef struct AzStyleBoxShadow AzStyleBoxShadow;
 
+enum AzGridTrackBreadthTag {
+   AzGridTrackBreadthTag_Fixed,
+   AzGridTrackBreadthTag_Fraction,
+   AzGridTrackBreadthTag_Auto,
+};
+typedef enum AzGridTrackBreadthTag AzGridTrackBreadthTag;
+
+struct AzGridTrackBreadthVariant_Fixed { AzGridTrackBreadthTag tag; AzPixelValue payload; };
+typedef struct AzGridTrackBreadthVariant_Fixed AzGridTrackBreadthVariant_Fixed;
+struct AzGridTrackBreadthVariant_Fraction { AzGridTrackBreadthTag tag; AzFloatValue payload; };
+typedef struct AzGridTrackBreadthVariant_Fraction AzGridTrackBreadthVariant_Fraction;
+struct AzGridTrackBreadthVariant_Auto { AzGridTrackBreadthTag tag; };
+typedef struct AzGridTrackBreadthVariant_Auto AzGridTrackBreadthVariant_Auto;
+union AzGridTrackBreadth {
+    AzGridTrackBreadthVariant_Fixed Fixed;
+    AzGridTrackBreadthVariant_Fraction Fraction;
+    AzGridTrackBreadthVariant_Auto Auto;
+};
+typedef union AzGridTrackBreadth AzGridTrackBreadth;
+
+struct AzGridMinMax {
+    AzGridTrackBreadth min;
+    AzGridTrackBreadth max;
+};
+typedef struct AzGridMinMax AzGridMinMax;
+
+enum AzGridTrackSizingTag {
+   AzGridTrackSizingTag_Fixed,
+   AzGridTrackSizingTag_Fraction,
+   AzGridTrackSizingTag_Auto,
+   AzGridTrackSizingTag_MinMax,
+};
+typedef enum AzGridTrackSizingTag AzGridTrackSizingTag;
+
+struct AzGridTrackSizingVariant_Fixed { AzGridTrackSizingTag tag; AzPixelValue payload; };
+typedef struct AzGridTrackSizingVariant_Fixed AzGridTrackSizingVariant_Fixed;
+struct AzGridTrackSizingVariant_Fraction { AzGridTrackSizingTag tag; AzFloatValue payload; };
+typedef struct AzGridTrackSizingVariant_Fraction AzGridTrackSizingVariant_Fraction;
+struct AzGridTrackSizingVariant_Auto { AzGridTrackSizingTag tag; };
+typedef struct AzGridTrackSizingVariant_Auto AzGridTrackSizingVariant_Auto;
+struct AzGridTrackSizingVariant_MinMax { AzGridTrackSizingTag tag; AzGridMinMax payload; };
+typedef struct AzGridTrackSizingVariant_MinMax AzGridTrackSizingVariant_MinMax;
+union AzGridTrackSizing {
+    AzGridTrackSizingVariant_Fixed Fixed;
+    AzGridTrackSizingVariant_Fraction Fraction;
+    AzGridTrackSizingVariant_Auto Auto;
+    AzGridTrackSizingVariant_MinMax MinMax;
+};
+typedef union AzGridTrackSizing AzGridTrackSizing;
+
+struct AzLayoutGridColumn {
+    AzGridLine start;
+    AzGridLine end;
+};
+typedef struct AzLayoutGridColumn AzLayoutGridColumn;
+
+struct AzLayoutGridRow {
+    AzGridLine start;
+    AzGridLine end;
+};
+typedef struct AzLayoutGridRow AzLayoutGridRow;
+
+struct AzLayoutColumnGap {
+    AzPixelValue inner;
+};
+typedef struct AzLayoutColumnGap AzLayoutColumnGap;
+
+struct AzLayoutRowGap {
+    AzPixelValue inner;
+};
+typedef struct AzLayoutRowGap AzLayoutRowGap;
+
 struct AzLayoutBottom {
     AzPixelValue inner;
 };
@@ -3854,6 +4653,30 @@ struct AzStylePerspectiveOrigin {
 };
 typedef struct AzStylePerspectiveOrigin AzStylePerspectiveOrigin;
 
+enum AzStyleTransitionPropertyTag {
+   AzStyleTransitionPropertyTag_All,
+   AzStyleTransitionPropertyTag_Property,
+};
+typedef enum AzStyleTransitionPropertyTag AzStyleTransitionPropertyTag;
+
+struct AzStyleTransitionPropertyVariant_All { AzStyleTransitionPropertyTag tag; };
+typedef struct AzStyleTransitionPropertyVariant_All AzStyleTransitionPropertyVariant_All;
+struct AzStyleTransitionPropertyVariant_Property { AzStyleTransitionPropertyTag tag; AzCssPropertyType payload; };
+typedef struct AzStyleTransitionPropertyVariant_Property AzStyleTransitionPropertyVariant_Property;
+union AzStyleTransitionProperty {
+    AzStyleTransitionPropertyVariant_All All;
+    AzStyleTransitionPropertyVariant_Property Property;
+};
+typedef union AzStyleTransitionProperty AzStyleTransitionProperty;
+
+struct AzStyleCubicBezier {
+    AzFloatValue x1;
+    AzFloatValue y1;
+    AzFloatValue x2;
+    AzFloatValue y2;
+};
+typedef struct AzStyleCubicBezier AzStyleCubicBezier;
+
 struct AzStyleTransformMatrix2D {
     AzPixelValue a;
     AzPixelValue b;
@@ -3990,6 +4813,118 @@ union AzLayoutAlignContentValue {
 };
 typedef union AzLayoutAlignContentValue AzLayoutAlignContentValue;
 
+enum AzLayoutGridColumnValueTag {
+   AzLayoutGridColumnValueTag_Auto,
+   AzLayoutGridColumnValueTag_None,
+   AzLayoutGridColumnValueTag_Inherit,
+   AzLayoutGridColumnValueTag_Initial,
+   AzLayoutGridColumnValueTag_Exact,
+};
+typedef enum AzLayoutGridColumnValueTag AzLayoutGridColumnValueTag;
+
+struct AzLayoutGridColumnValueVariant_Auto { AzLayoutGridColumnValueTag tag; };
+typedef struct AzLayoutGridColumnValueVariant_Auto AzLayoutGridColumnValueVariant_Auto;
+struct AzLayoutGridColumnValueVariant_None { AzLayoutGridColumnValueTag tag; };
+typedef struct AzLayoutGridColumnValueVariant_None AzLayoutGridColumnValueVariant_None;
+struct AzLayoutGridColumnValueVariant_Inherit { AzLayoutGridColumnValueTag tag; };
+typedef struct AzLayoutGridColumnValueVariant_Inherit AzLayoutGridColumnValueVariant_Inherit;
+struct AzLayoutGridColumnValueVariant_Initial { AzLayoutGridColumnValueTag tag; };
+typedef struct AzLayoutGridColumnValueVariant_Initial AzLayoutGridColumnValueVariant_Initial;
+struct AzLayoutGridColumnValueVariant_Exact { AzLayoutGridColumnValueTag tag; AzLayoutGridColumn payload; };
+typedef struct AzLayoutGridColumnValueVariant_Exact AzLayoutGridColumnValueVariant_Exact;
+union AzLayoutGridColumnValue {
+    AzLayoutGridColumnValueVariant_Auto Auto;
+    AzLayoutGridColumnValueVariant_None None;
+    AzLayoutGridColumnValueVariant_Inherit Inherit;
+    AzLayoutGridColumnValueVariant_Initial Initial;
+    AzLayoutGridColumnValueVariant_Exact Exact;
+};
+typedef union AzLayoutGridColumnValue AzLayoutGridColumnValue;
+
+enum AzLayoutGridRowValueTag {
+   AzLayoutGridRowValueTag_Auto,
+   AzLayoutGridRowValueTag_None,
+   AzLayoutGridRowValueTag_Inherit,
+   AzLayoutGridRowValueTag_Initial,
+   AzLayoutGridRowValueTag_Exact,
+};
+typedef enum AzLayoutGridRowValueTag AzLayoutGridRowValueTag;
+
+struct AzLayoutGridRowValueVariant_Auto { AzLayoutGridRowValueTag tag; };
+typedef struct AzLayoutGridRowValueVariant_Auto AzLayoutGridRowValueVariant_Auto;
+struct AzLayoutGridRowValueVariant_None { AzLayoutGridRowValueTag tag; };
+typedef struct AzLayoutGridRowValueVariant_None AzLayoutGridRowValueVariant_None;
+struct AzLayoutGridRowValueVariant_Inherit { AzLayoutGridRowValueTag tag; };
+typedef struct AzLayoutGridRowValueVariant_Inherit AzLayoutGridRowValueVariant_Inherit;
+struct AzLayoutGridRowValueVariant_Initial { AzLayoutGridRowValueTag tag; };
+typedef struct AzLayoutGridRowValueVariant_Initial AzLayoutGridRowValueVariant_Initial;
+struct AzLayoutGridRowValueVariant_Exact { AzLayoutGridRowValueTag tag; AzLayoutGridRow payload; };
+typedef struct AzLayoutGridRowValueVariant_Exact AzLayoutGridRowValueVariant_Exact;
+union AzLayoutGridRowValue {
+    AzLayoutGridRowValueVariant_Auto Auto;
+    AzLayoutGridRowValueVariant_None None;
+    AzLayoutGridRowValueVariant_Inherit Inherit;
+    AzLayoutGridRowValueVariant_Initial Initial;
+    AzLayoutGridRowValueVariant_Exact Exact;
+};
+typedef union AzLayoutGridRowValue AzLayoutGridRowValue;
+
+enum AzLayoutColumnGapValueTag {
+   AzLayoutColumnGapValueTag_Auto,
+   AzLayoutColumnGapValueTag_None,
+   AzLayoutColumnGapValueTag_Inherit,
+   AzLayoutColumnGapValueTag_Initial,
+   AzLayoutColumnGapValueTag_Exact,
+};
+typedef enum AzLayoutColumnGapValueTag AzLayoutColumnGapValueTag;
+
+struct AzLayoutColumnGapValueVariant_Auto { AzLayoutColumnGapValueTag tag; };
+typedef struct AzLayoutColumnGapValueVariant_Auto AzLayoutColumnGapValueVariant_Auto;
+struct AzLayoutColumnGapValueVariant_None { AzLayoutColumnGapValueTag tag; };
+typedef struct AzLayoutColumnGapValueVariant_None AzLayoutColumnGapValueVariant_None;
+struct AzLayoutColumnGapValueVariant_Inherit { AzLayoutColumnGapValueTag tag; };
+typedef struct AzLayoutColumnGapValueVariant_Inherit AzLayoutColumnGapValueVariant_Inherit;
+struct AzLayoutColumnGapValueVariant_Initial { AzLayoutColumnGapValueTag tag; };
+typedef struct AzLayoutColumnGapValueVariant_Initial AzLayoutColumnGapValueVariant_Initial;
+struct AzLayoutColumnGapValueVariant_Exact { AzLayoutColumnGapValueTag tag; AzLayoutColumnGap payload; };
+typedef struct AzLayoutColumnGapValueVariant_Exact AzLayoutColumnGapValueVariant_Exact;
+union AzLayoutColumnGapValue {
+    AzLayoutColumnGapValueVariant_Auto Auto;
+    AzLayoutColumnGapValueVariant_None None;
+    AzLayoutColumnGapValueVariant_Inherit Inherit;
+    AzLayoutColumnGapValueVariant_Initial Initial;
+    AzLayoutColumnGapValueVariant_Exact Exact;
+};
+typedef union AzLayoutColumnGapValue AzLayoutColumnGapValue;
+
+enum AzLayoutRowGapValueTag {
+   AzLayoutRowGapValueTag_Auto,
+   AzLayoutRowGapValueTag_None,
+   AzLayoutRowGapValueTag_Inherit,
+   AzLayoutRowGapValueTag_Initial,
+   AzLayoutRowGapValueTag_Exact,
+};
+typedef enum AzLayoutRowGapValueTag AzLayoutRowGapValueTag;
+
+struct AzLayoutRowGapValueVariant_Auto { AzLayoutRowGapValueTag tag; };
+typedef struct AzLayoutRowGapValueVariant_Auto AzLayoutRowGapValueVariant_Auto;
+struct AzLayoutRowGapValueVariant_None { AzLayoutRowGapValueTag tag; };
+typedef struct AzLayoutRowGapValueVariant_None AzLayoutRowGapValueVariant_None;
+struct AzLayoutRowGapValueVariant_Inherit { AzLayoutRowGapValueTag tag; };
+typedef struct AzLayoutRowGapValueVariant_Inherit AzLayoutRowGapValueVariant_Inherit;
+struct AzLayoutRowGapValueVariant_Initial { AzLayoutRowGapValueTag tag; };
+typedef struct AzLayoutRowGapValueVariant_Initial AzLayoutRowGapValueVariant_Initial;
+struct AzLayoutRowGapValueVariant_Exact { AzLayoutRowGapValueTag tag; AzLayoutRowGap payload; };
+typedef struct AzLayoutRowGapValueVariant_Exact AzLayoutRowGapValueVariant_Exact;
+union AzLayoutRowGapValue {
+    AzLayoutRowGapValueVariant_Auto Auto;
+    AzLayoutRowGapValueVariant_None None;
+    AzLayoutRowGapValueVariant_Inherit Inherit;
+    AzLayoutRowGapValueVariant_Initial Initial;
+    AzLayoutRowGapValueVariant_Exact Exact;
+};
+typedef union AzLayoutRowGapValue AzLayoutRowGapValue;
+
 enum AzLayoutAlignItemsValueTag {
    AzLayoutAlignItemsValueTag_Auto,
    AzLayoutAlignItemsValueTag_None,
@@ -4214,6 +5149,34 @@ union AzLayoutFloatValue {
 };
 typedef union AzLayoutFloatValue AzLayoutFloatValue;
 
+enum AzLayoutClearValueTag {
+   AzLayoutClearValueTag_Auto,
+   AzLayoutClearValueTag_None,
+   AzLayoutClearValueTag_Inherit,
+   AzLayoutClearValueTag_Initial,
+   AzLayoutClearValueTag_Exact,
+};
+typedef enum AzLayoutClearValueTag AzLayoutClearValueTag;
+
+struct AzLayoutClearValueVariant_Auto { AzLayoutClearValueTag tag; };
+typedef struct AzLayoutClearValueVariant_Auto AzLayoutClearValueVariant_Auto;
+struct AzLayoutClearValueVariant_None { AzLayoutClearValueTag tag; };
+typedef struct AzLayoutClearValueVariant_None AzLayoutClearValueVariant_None;
+struct AzLayoutClearValueVariant_Inherit { AzLayoutClearValueTag tag; };
+typedef struct AzLayoutClearValueVariant_Inherit AzLayoutClearValueVariant_Inherit;
+struct AzLayoutClearValueVariant_Initial { AzLayoutClearValueTag tag; };
+typedef struct AzLayoutClearValueVariant_Initial AzLayoutClearValueVariant_Initial;
+struct AzLayoutClearValueVariant_Exact { AzLayoutClearValueTag tag; AzLayoutClear payload; };
+typedef struct AzLayoutClearValueVariant_Exact AzLayoutClearValueVariant_Exact;
+union AzLayoutClearValue {
+    AzLayoutClearValueVariant_Auto Auto;
+    AzLayoutClearValueVariant_None None;
+    AzLayoutClearValueVariant_Inherit Inherit;
+    AzLayoutClearValueVariant_Initial Initial;
+    AzLayoutClearValueVariant_Exact Exact;
+};
+typedef union AzLayoutClearValue AzLayoutClearValue;
+
 enum AzLayoutHeightValueTag {
    AzLayoutHeightValueTag_Auto,
    AzLayoutHeightValueTag_None,
@@ -5655,6 +6618,107 @@ struct AzNumberInputOnValueChange {
 };
 typedef struct AzNumberInputOnValueChange AzNumberInputOnValueChange;
 
+struct AzTextEditView {
+    AzOptionF32 preferred_caret_x;
+    float scroll_y;
+};
+typedef struct AzTextEditView AzTextEditView;
+
+struct AzTextEditOnTextChange {
+    AzRefAny data;
+    AzTextEditOnTextChangeCallback callback;
+};
+typedef struct AzTextEditOnTextChange AzTextEditOnTextChange;
+
+struct AzTextEditOnFocusLost {
+    AzRefAny data;
+    AzTextEditOnFocusLostCallback callback;
+};
+typedef struct AzTextEditOnFocusLost AzTextEditOnFocusLost;
+
+struct AzSliderState {
+    float value;
+    float min;
+    float max;
+    float step;
+    AzSliderOrientation orientation;
+    size_t tick_count;
+    bool  show_tick_labels;
+};
+typedef struct AzSliderState AzSliderState;
+
+struct AzSliderOnValueChange {
+    AzRefAny data;
+    AzSliderOnValueChangeCallback callback;
+};
+typedef struct AzSliderOnValueChange AzSliderOnValueChange;
+
+struct AzDropDownOnChoiceChange {
+    AzRefAny data;
+    AzDropDownOnChoiceChangeCallback callback;
+};
+typedef struct AzDropDownOnChoiceChange AzDropDownOnChoiceChange;
+
+struct AzSpreadsheetStyle {
+    AzColorU accent_color;
+};
+typedef struct AzSpreadsheetStyle AzSpreadsheetStyle;
+
+struct AzSpreadsheetCellSelection {
+    AzSpreadsheetCellIndex from_top_left;
+    AzSpreadsheetCellIndex to_bottom_right;
+};
+typedef struct AzSpreadsheetCellSelection AzSpreadsheetCellSelection;
+
+struct AzSpreadsheetRenderWindow {
+    size_t row_start;
+    size_t row_end;
+    size_t column_start;
+    size_t column_end;
+    AzLogicalPosition origin;
+    AzLogicalPosition scroll_position;
+    AzLogicalSize viewport;
+};
+typedef struct AzSpreadsheetRenderWindow AzSpreadsheetRenderWindow;
+
+enum AzSpreadsheetDragTag {
+   AzSpreadsheetDragTag_None,
+   AzSpreadsheetDragTag_SelectCells,
+   AzSpreadsheetDragTag_ResizeColumn,
+};
+typedef enum AzSpreadsheetDragTag AzSpreadsheetDragTag;
+
+struct AzSpreadsheetDragVariant_None { AzSpreadsheetDragTag tag; };
+typedef struct AzSpreadsheetDragVariant_None AzSpreadsheetDragVariant_None;
+struct AzSpreadsheetDragVariant_SelectCells { AzSpreadsheetDragTag tag; };
+typedef struct AzSpreadsheetDragVariant_SelectCells AzSpreadsheetDragVariant_SelectCells;
+struct AzSpreadsheetDragVariant_ResizeColumn { AzSpreadsheetDragTag tag; AzSpreadsheetColumnResize payload; };
+typedef struct AzSpreadsheetDragVariant_ResizeColumn AzSpreadsheetDragVariant_ResizeColumn;
+union AzSpreadsheetDrag {
+    AzSpreadsheetDragVariant_None None;
+    AzSpreadsheetDragVariant_SelectCells SelectCells;
+    AzSpreadsheetDragVariant_ResizeColumn ResizeColumn;
+};
+typedef union AzSpreadsheetDrag AzSpreadsheetDrag;
+
+struct AzSpreadsheetOnCellsChange {
+    AzRefAny data;
+    AzSpreadsheetOnCellsChangeCallback callback;
+};
+typedef struct AzSpreadsheetOnCellsChange AzSpreadsheetOnCellsChange;
+
+struct AzSpreadsheetOnSelectionChange {
+    AzRefAny data;
+    AzSpreadsheetOnSelectionChangeCallback callback;
+};
+typedef struct AzSpreadsheetOnSelectionChange AzSpreadsheetOnSelectionChange;
+
+struct AzSpreadsheetOnColumnResize {
+    AzRefAny data;
+    AzSpreadsheetOnColumnResizeCallback callback;
+};
+typedef struct AzSpreadsheetOnColumnResize AzSpreadsheetOnColumnResize;
+
 struct AzParentWithNodeDepth {
     size_t depth;
     AzNodeId node_id;
@@ -5835,6 +6899,16 @@ struct AzAccessibilityStateVec {
 };
 typedef struct AzAccessibilityStateVec AzAccessibilityStateVec;
 
+struct AzAccessibilityNode;
+typedef struct AzAccessibilityNode AzAccessibilityNode;
+struct AzAccessibilityNodeVec {
+    AzAccessibilityNode* ptr;
+    size_t len;
+    size_t cap;
+    AzAccessibilityNodeVecDestructor destructor;
+};
+typedef struct AzAccessibilityNodeVec AzAccessibilityNodeVec;
+
 struct AzMenuItem;
 typedef struct AzMenuItem AzMenuItem;
 struct AzMenuItemVec {
@@ -5961,6 +7035,88 @@ struct AzScanCodeVec {
 };
 typedef struct AzScanCodeVec AzScanCodeVec;
 
+union AzCssPathSelector;
+typedef union AzCssPathSelector AzCssPathSelector;
+struct AzCssPathSelectorVec {
+    AzCssPathSelector* ptr;
+    size_t len;
+    size_t cap;
+    AzCssPathSelectorVecDestructor destructor;
+};
+typedef struct AzCssPathSelectorVec AzCssPathSelectorVec;
+
+struct AzStyleTransitionPropertyVec {
+    AzStyleTransitionProperty* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleTransitionPropertyVecDestructor destructor;
+};
+typedef struct AzStyleTransitionPropertyVec AzStyleTransitionPropertyVec;
+
+struct AzStyleTransitionDurationVec {
+    AzStyleTransitionDuration* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleTransitionDurationVecDestructor destructor;
+};
+typedef struct AzStyleTransitionDurationVec AzStyleTransitionDurationVec;
+
+struct AzStyleTransitionDelayVec {
+    AzStyleTransitionDelay* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleTransitionDelayVecDestructor destructor;
+};
+typedef struct AzStyleTransitionDelayVec AzStyleTransitionDelayVec;
+
+struct AzStyleAnimationDurationVec {
+    AzStyleAnimationDuration* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleAnimationDurationVecDestructor destructor;
+};
+typedef struct AzStyleAnimationDurationVec AzStyleAnimationDurationVec;
+
+struct AzStyleAnimationIterationCountVec {
+    AzStyleAnimationIterationCount* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleAnimationIterationCountVecDestructor destructor;
+};
+typedef struct AzStyleAnimationIterationCountVec AzStyleAnimationIterationCountVec;
+
+struct AzStyleAnimationDirectionVec {
+    AzStyleAnimationDirection* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleAnimationDirectionVecDestructor destructor;
+};
+typedef struct AzStyleAnimationDirectionVec AzStyleAnimationDirectionVec;
+
+struct AzStyleAnimationFillModeVec {
+    AzStyleAnimationFillMode* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleAnimationFillModeVecDestructor destructor;
+};
+typedef struct AzStyleAnimationFillModeVec AzStyleAnimationFillModeVec;
+
+struct AzStyleAnimationDelayVec {
+    AzStyleAnimationDelay* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleAnimationDelayVecDestructor destructor;
+};
+typedef struct AzStyleAnimationDelayVec AzStyleAnimationDelayVec;
+
+struct AzGridTrackSizingVec {
+    AzGridTrackSizing* ptr;
+    size_t len;
+    size_t cap;
+    AzGridTrackSizingVecDestructor destructor;
+};
+typedef struct AzGridTrackSizingVec AzGridTrackSizingVec;
+
 struct AzU16Vec {
     uint16_t* ptr;
     size_t len;
@@ -6041,6 +7197,14 @@ struct AzParentWithNodeDepthVec {
 };
 typedef struct AzParentWithNodeDepthVec AzParentWithNodeDepthVec;
 
+struct AzSpreadsheetSizeOverrideVec {
+    AzSpreadsheetSizeOverride* ptr;
+    size_t len;
+    size_t cap;
+    AzSpreadsheetSizeOverrideVecDestructor destructor;
+};
+typedef struct AzSpreadsheetSizeOverrideVec AzSpreadsheetSizeOverrideVec;
+
 enum AzOptionColorInputOnValueChangeTag {
    AzOptionColorInputOnValueChangeTag_None,
    AzOptionColorInputOnValueChangeTag_Some,
@@ -6169,6 +7333,150 @@ union AzOptionNumberInputOnValueChange {
 };
 typedef union AzOptionNumberInputOnValueChange AzOptionNumberInputOnValueChange;
 
+enum AzOptionTextEditOnTextChangeTag {
+   AzOptionTextEditOnTextChangeTag_None,
+   AzOptionTextEditOnTextChangeTag_Some,
+};
+typedef enum AzOptionTextEditOnTextChangeTag AzOptionTextEditOnTextChangeTag;
+
+struct AzOptionTextEditOnTextChangeVariant_None { AzOptionTextEditOnTextChangeTag tag; };
+typedef struct AzOptionTextEditOnTextChangeVariant_None AzOptionTextEditOnTextChangeVariant_None;
+struct AzOptionTextEditOnTextChangeVariant_Some { AzOptionTextEditOnTextChangeTag tag; AzTextEditOnTextChange payload; };
+typedef struct AzOptionTextEditOnTextChangeVariant_Some AzOptionTextEditOnTextChangeVariant_Some;
+union AzOptionTextEditOnTextChange {
+    AzOptionTextEditOnTextChangeVariant_None None;
+    AzOptionTextEditOnTextChangeVariant_Some Some;
+};
+typedef union AzOptionTextEditOnTextChange AzOptionTextEditOnTextChange;
+
+enum AzOptionTextEditOnFocusLostTag {
+   AzOptionTextEditOnFocusLostTag_None,
+   AzOptionTextEditOnFocusLostTag_Some,
+};
+typedef enum AzOptionTextEditOnFocusLostTag AzOptionTextEditOnFocusLostTag;
+
+struct AzOptionTextEditOnFocusLostVariant_None { AzOptionTextEditOnFocusLostTag tag; };
+typedef struct AzOptionTextEditOnFocusLostVariant_None AzOptionTextEditOnFocusLostVariant_None;
+struct AzOptionTextEditOnFocusLostVariant_Some { AzOptionTextEditOnFocusLostTag tag; AzTextEditOnFocusLost payload; };
+typedef struct AzOptionTextEditOnFocusLostVariant_Some AzOptionTextEditOnFocusLostVariant_Some;
+union AzOptionTextEditOnFocusLost {
+    AzOptionTextEditOnFocusLostVariant_None None;
+    AzOptionTextEditOnFocusLostVariant_Some Some;
+};
+typedef union AzOptionTextEditOnFocusLost AzOptionTextEditOnFocusLost;
+
+enum AzOptionTextEditKindTag {
+   AzOptionTextEditKindTag_None,
+   AzOptionTextEditKindTag_Some,
+};
+typedef enum AzOptionTextEditKindTag AzOptionTextEditKindTag;
+
+struct AzOptionTextEditKindVariant_None { AzOptionTextEditKindTag tag; };
+typedef struct AzOptionTextEditKindVariant_None AzOptionTextEditKindVariant_None;
+struct AzOptionTextEditKindVariant_Some { AzOptionTextEditKindTag tag; AzTextEditKind payload; };
+typedef struct AzOptionTextEditKindVariant_Some AzOptionTextEditKindVariant_Some;
+union AzOptionTextEditKind {
+    AzOptionTextEditKindVariant_None None;
+    AzOptionTextEditKindVariant_Some Some;
+};
+typedef union AzOptionTextEditKind AzOptionTextEditKind;
+
+enum AzOptionSliderOnValueChangeTag {
+   AzOptionSliderOnValueChangeTag_None,
+   AzOptionSliderOnValueChangeTag_Some,
+};
+typedef enum AzOptionSliderOnValueChangeTag AzOptionSliderOnValueChangeTag;
+
+struct AzOptionSliderOnValueChangeVariant_None { AzOptionSliderOnValueChangeTag tag; };
+typedef struct AzOptionSliderOnValueChangeVariant_None AzOptionSliderOnValueChangeVariant_None;
+struct AzOptionSliderOnValueChangeVariant_Some { AzOptionSliderOnValueChangeTag tag; AzSliderOnValueChange payload; };
+typedef struct AzOptionSliderOnValueChangeVariant_Some AzOptionSliderOnValueChangeVariant_Some;
+union AzOptionSliderOnValueChange {
+    AzOptionSliderOnValueChangeVariant_None None;
+    AzOptionSliderOnValueChangeVariant_Some Some;
+};
+typedef union AzOptionSliderOnValueChange AzOptionSliderOnValueChange;
+
+enum AzOptionDropDownOnChoiceChangeTag {
+   AzOptionDropDownOnChoiceChangeTag_None,
+   AzOptionDropDownOnChoiceChangeTag_Some,
+};
+typedef enum AzOptionDropDownOnChoiceChangeTag AzOptionDropDownOnChoiceChangeTag;
+
+struct AzOptionDropDownOnChoiceChangeVariant_None { AzOptionDropDownOnChoiceChangeTag tag; };
+typedef struct AzOptionDropDownOnChoiceChangeVariant_None AzOptionDropDownOnChoiceChangeVariant_None;
+struct AzOptionDropDownOnChoiceChangeVariant_Some { AzOptionDropDownOnChoiceChangeTag tag; AzDropDownOnChoiceChange payload; };
+typedef struct AzOptionDropDownOnChoiceChangeVariant_Some AzOptionDropDownOnChoiceChangeVariant_Some;
+union AzOptionDropDownOnChoiceChange {
+    AzOptionDropDownOnChoiceChangeVariant_None None;
+    AzOptionDropDownOnChoiceChangeVariant_Some Some;
+};
+typedef union AzOptionDropDownOnChoiceChange AzOptionDropDownOnChoiceChange;
+
+enum AzOptionSpreadsheetOnCellsChangeTag {
+   AzOptionSpreadsheetOnCellsChangeTag_None,
+   AzOptionSpreadsheetOnCellsChangeTag_Some,
+};
+typedef enum AzOptionSpreadsheetOnCellsChangeTag AzOptionSpreadsheetOnCellsChangeTag;
+
+struct AzOptionSpreadsheetOnCellsChangeVariant_None { AzOptionSpreadsheetOnCellsChangeTag tag; };
+typedef struct AzOptionSpreadsheetOnCellsChangeVariant_None AzOptionSpreadsheetOnCellsChangeVariant_None;
+struct AzOptionSpreadsheetOnCellsChangeVariant_Some { AzOptionSpreadsheetOnCellsChangeTag tag; AzSpreadsheetOnCellsChange payload; };
+typedef struct AzOptionSpreadsheetOnCellsChangeVariant_Some AzOptionSpreadsheetOnCellsChangeVariant_Some;
+union AzOptionSpreadsheetOnCellsChange {
+    AzOptionSpreadsheetOnCellsChangeVariant_None None;
+    AzOptionSpreadsheetOnCellsChangeVariant_Some Some;
+};
+typedef union AzOptionSpreadsheetOnCellsChange AzOptionSpreadsheetOnCellsChange;
+
+enum AzOptionSpreadsheetOnSelectionChangeTag {
+   AzOptionSpreadsheetOnSelectionChangeTag_None,
+   AzOptionSpreadsheetOnSelectionChangeTag_Some,
+};
+typedef enum AzOptionSpreadsheetOnSelectionChangeTag AzOptionSpreadsheetOnSelectionChangeTag;
+
+struct AzOptionSpreadsheetOnSelectionChangeVariant_None { AzOptionSpreadsheetOnSelectionChangeTag tag; };
+typedef struct AzOptionSpreadsheetOnSelectionChangeVariant_None AzOptionSpreadsheetOnSelectionChangeVariant_None;
+struct AzOptionSpreadsheetOnSelectionChangeVariant_Some { AzOptionSpreadsheetOnSelectionChangeTag tag; AzSpreadsheetOnSelectionChange payload; };
+typedef struct AzOptionSpreadsheetOnSelectionChangeVariant_Some AzOptionSpreadsheetOnSelectionChangeVariant_Some;
+union AzOptionSpreadsheetOnSelectionChange {
+    AzOptionSpreadsheetOnSelectionChangeVariant_None None;
+    AzOptionSpreadsheetOnSelectionChangeVariant_Some Some;
+};
+typedef union AzOptionSpreadsheetOnSelectionChange AzOptionSpreadsheetOnSelectionChange;
+
+enum AzOptionSpreadsheetOnColumnResizeTag {
+   AzOptionSpreadsheetOnColumnResizeTag_None,
+   AzOptionSpreadsheetOnColumnResizeTag_Some,
+};
+typedef enum AzOptionSpreadsheetOnColumnResizeTag AzOptionSpreadsheetOnColumnResizeTag;
+
+struct AzOptionSpreadsheetOnColumnResizeVariant_None { AzOptionSpreadsheetOnColumnResizeTag tag; };
+typedef struct AzOptionSpreadsheetOnColumnResizeVariant_None AzOptionSpreadsheetOnColumnResizeVariant_None;
+struct AzOptionSpreadsheetOnColumnResizeVariant_Some { AzOptionSpreadsheetOnColumnResizeTag tag; AzSpreadsheetOnColumnResize payload; };
+typedef struct AzOptionSpreadsheetOnColumnResizeVariant_Some AzOptionSpreadsheetOnColumnResizeVariant_Some;
+union AzOptionSpreadsheetOnColumnResize {
+    AzOptionSpreadsheetOnColumnResizeVariant_None None;
+    AzOptionSpreadsheetOnColumnResizeVariant_Some Some;
+};
+typedef union AzOptionSpreadsheetOnColumnResize AzOptionSpreadsheetOnColumnResize;
+
+enum AzOptionSpreadsheetCellSelectionTag {
+   AzOptionSpreadsheetCellSelectionTag_None,
+   AzOptionSpreadsheetCellSelectionTag_Some,
+};
+typedef enum AzOptionSpreadsheetCellSelectionTag AzOptionSpreadsheetCellSelectionTag;
+
+struct AzOptionSpreadsheetCellSelectionVariant_None { AzOptionSpreadsheetCellSelectionTag tag; };
+typedef struct AzOptionSpreadsheetCellSelectionVariant_None AzOptionSpreadsheetCellSelectionVariant_None;
+struct AzOptionSpreadsheetCellSelectionVariant_Some { AzOptionSpreadsheetCellSelectionTag tag; AzSpreadsheetCellSelection payload; };
+typedef struct AzOptionSpreadsheetCellSelectionVariant_Some AzOptionSpreadsheetCellSelectionVariant_Some;
+union AzOptionSpreadsheetCellSelection {
+    AzOptionSpreadsheetCellSelectionVariant_None None;
+    AzOptionSpreadsheetCellSelectionVariant_Some Some;
+};
+typedef union AzOptionSpreadsheetCellSelection AzOptionSpreadsheetCellSelection;
+
 enum AzOptionMenuItemIconTag {
    AzOptionMenuItemIconTag_None,
    AzOptionMenuItemIconTag_Some,
@@ -6848,6 +8156,7 @@ typedef struct AzMarshaledLayoutCallback AzMarshaledLayoutCallback;
 struct AzInlineTextContents {
     AzInlineGlyphVec glyphs;
     AzLogicalRect bounds;
+    bool  is_rtl;
 };
 typedef struct AzInlineTextContents AzInlineTextContents;
 
@@ -6953,6 +8262,57 @@ struct AzVirtualKeyCodeCombo {
 };
 typedef struct AzVirtualKeyCodeCombo AzVirtualKeyCodeCombo;
 
+struct AzKeyboardShortcut {
+    AzVirtualKeyCodeCombo keys;
+    AzMenuCallback callback;
+};
+typedef struct AzKeyboardShortcut AzKeyboardShortcut;
+
+enum AzCssMediaConditionTag {
+   AzCssMediaConditionTag_MinWidth,
+   AzCssMediaConditionTag_MaxWidth,
+   AzCssMediaConditionTag_MinHeight,
+   AzCssMediaConditionTag_MaxHeight,
+   AzCssMediaConditionTag_MinResolution,
+   AzCssMediaConditionTag_MaxResolution,
+   AzCssMediaConditionTag_Orientation,
+   AzCssMediaConditionTag_PrefersColorScheme,
+};
+typedef enum AzCssMediaConditionTag AzCssMediaConditionTag;
+
+struct AzCssMediaConditionVariant_MinWidth { AzCssMediaConditionTag tag; AzPixelValue payload; };
+typedef struct AzCssMediaConditionVariant_MinWidth AzCssMediaConditionVariant_MinWidth;
+struct AzCssMediaConditionVariant_MaxWidth { AzCssMediaConditionTag tag; AzPixelValue payload; };
+typedef struct AzCssMediaConditionVariant_MaxWidth AzCssMediaConditionVariant_MaxWidth;
+struct AzCssMediaConditionVariant_MinHeight { AzCssMediaConditionTag tag; AzPixelValue payload; };
+typedef struct AzCssMediaConditionVariant_MinHeight AzCssMediaConditionVariant_MinHeight;
+struct AzCssMediaConditionVariant_MaxHeight { AzCssMediaConditionTag tag; AzPixelValue payload; };
+typedef struct AzCssMediaConditionVariant_MaxHeight AzCssMediaConditionVariant_MaxHeight;
+struct AzCssMediaConditionVariant_MinResolution { AzCssMediaConditionTag tag; AzFloatValue payload; };
+typedef struct AzCssMediaConditionVariant_MinResolution AzCssMediaConditionVariant_MinResolution;
+struct AzCssMediaConditionVariant_MaxResolution { AzCssMediaConditionTag tag; AzFloatValue payload; };
+typedef struct AzCssMediaConditionVariant_MaxResolution AzCssMediaConditionVariant_MaxResolution;
+struct AzCssMediaConditionVariant_Orientation { AzCssMediaConditionTag tag; AzCssMediaOrientation payload; };
+typedef struct AzCssMediaConditionVariant_Orientation AzCssMediaConditionVariant_Orientation;
+struct AzCssMediaConditionVariant_PrefersColorScheme { AzCssMediaConditionTag tag; AzCssMediaColorScheme payload; };
+typedef struct AzCssMediaConditionVariant_PrefersColorScheme AzCssMediaConditionVariant_PrefersColorScheme;
+union AzCssMediaCondition {
+    AzCssMediaConditionVariant_MinWidth MinWidth;
+    AzCssMediaConditionVariant_MaxWidth MaxWidth;
+    AzCssMediaConditionVariant_MinHeight MinHeight;
+    AzCssMediaConditionVariant_MaxHeight MaxHeight;
+    AzCssMediaConditionVariant_MinResolution MinResolution;
+    AzCssMediaConditionVariant_MaxResolution MaxResolution;
+    AzCssMediaConditionVariant_Orientation Orientation;
+    AzCssMediaConditionVariant_PrefersColorScheme PrefersColorScheme;
+};
+typedef union AzCssMediaCondition AzCssMediaCondition;
+
+struct AzCssPath {
+    AzCssPathSelectorVec selectors;
+};
+typedef struct AzCssPath AzCssPath;
+
 enum AzCssPathPseudoSelectorTag {
    AzCssPathPseudoSelectorTag_First,
    AzCssPathPseudoSelectorTag_Last,
@@ -6960,6 +8320,12 @@ enum AzCssPathPseudoSelectorTag {
    AzCssPathPseudoSelectorTag_Hover,
    AzCssPathPseudoSelectorTag_Active,
    AzCssPathPseudoSelectorTag_Focus,
+   AzCssPathPseudoSelectorTag_FirstChild,
+   AzCssPathPseudoSelectorTag_LastChild,
+   AzCssPathPseudoSelectorTag_OnlyChild,
+   AzCssPathPseudoSelectorTag_NthLastChild,
+   AzCssPathPseudoSelectorTag_Empty,
+   AzCssPathPseudoSelectorTag_FocusWithin,
 };
 typedef enum AzCssPathPseudoSelectorTag AzCssPathPseudoSelectorTag;
 
@@ -6975,6 +8341,18 @@ struct AzCssPathPseudoSelectorVariant_Active { AzCssPathPseudoSelectorTag tag; }
 typedef struct AzCssPathPseudoSelectorVariant_Active AzCssPathPseudoSelectorVariant_Active;
 struct AzCssPathPseudoSelectorVariant_Focus { AzCssPathPseudoSelectorTag tag; };
 typedef struct AzCssPathPseudoSelectorVariant_Focus AzCssPathPseudoSelectorVariant_Focus;
+struct AzCssPathPseudoSelectorVariant_FirstChild { AzCssPathPseudoSelectorTag tag; };
+typedef struct AzCssPathPseudoSelectorVariant_FirstChild AzCssPathPseudoSelectorVariant_FirstChild;
+struct AzCssPathPseudoSelectorVariant_LastChild { AzCssPathPseudoSelectorTag tag; };
+typedef struct AzCssPathPseudoSelectorVariant_LastChild AzCssPathPseudoSelectorVariant_LastChild;
+struct AzCssPathPseudoSelectorVariant_OnlyChild { AzCssPathPseudoSelectorTag tag; };
+typedef struct AzCssPathPseudoSelectorVariant_OnlyChild AzCssPathPseudoSelectorVariant_OnlyChild;
+struct AzCssPathPseudoSelectorVariant_NthLastChild { AzCssPathPseudoSelectorTag tag; AzCssNthChildSelector payload; };
+typedef struct AzCssPathPseudoSelectorVariant_NthLastChild AzCssPathPseudoSelectorVariant_NthLastChild;
+struct AzCssPathPseudoSelectorVariant_Empty { AzCssPathPseudoSelectorTag tag; };
+typedef struct AzCssPathPseudoSelectorVariant_Empty AzCssPathPseudoSelectorVariant_Empty;
+struct AzCssPathPseudoSelectorVariant_FocusWithin { AzCssPathPseudoSelectorTag tag; };
+typedef struct AzCssPathPseudoSelectorVariant_FocusWithin AzCssPathPseudoSelectorVariant_FocusWithin;
 union AzCssPathPseudoSelector {
     AzCssPathPseudoSelectorVariant_First First;
     AzCssPathPseudoSelectorVariant_Last Last;
@@ -6982,6 +8360,12 @@ union AzCssPathPseudoSelector {
     AzCssPathPseudoSelectorVariant_Hover Hover;
     AzCssPathPseudoSelectorVariant_Active Active;
     AzCssPathPseudoSelectorVariant_Focus Focus;
+    AzCssPathPseudoSelectorVariant_FirstChild FirstChild;
+    AzCssPathPseudoSelectorVariant_LastChild LastChild;
+    AzCssPathPseudoSelectorVariant_OnlyChild OnlyChild;
+    AzCssPathPseudoSelectorVariant_NthLastChild NthLastChild;
+    AzCssPathPseudoSelectorVariant_Empty Empty;
+    AzCssPathPseudoSelectorVariant_FocusWithin FocusWithin;
 };
 typedef union AzCssPathPseudoSelector AzCssPathPseudoSelector;
 
@@ -7026,6 +8410,16 @@ struct AzInterpolateContext {
 };
 typedef struct AzInterpolateContext AzInterpolateContext;
 
+struct AzLayoutGridTemplateColumns {
+    AzGridTrackSizingVec tracks;
+};
+typedef struct AzLayoutGridTemplateColumns AzLayoutGridTemplateColumns;
+
+struct AzLayoutGridTemplateRows {
+    AzGridTrackSizingVec tracks;
+};
+typedef struct AzLayoutGridTemplateRows AzLayoutGridTemplateRows;
+
 struct AzLinearGradient {
     AzDirection direction;
     AzExtendMode extend_mode;
@@ -7050,6 +8444,38 @@ struct AzConicGradient {
 };
 typedef struct AzConicGradient AzConicGradient;
 
+enum AzStyleTransitionTimingFunctionTag {
+   AzStyleTransitionTimingFunctionTag_Ease,
+   AzStyleTransitionTimingFunctionTag_Linear,
+   AzStyleTransitionTimingFunctionTag_EaseIn,
+   AzStyleTransitionTimingFunctionTag_EaseOut,
+   AzStyleTransitionTimingFunctionTag_EaseInOut,
+   AzStyleTransitionTimingFunctionTag_CubicBezier,
+};
+typedef enum AzStyleTransitionTimingFunctionTag AzStyleTransitionTimingFunctionTag;
+
+struct AzStyleTransitionTimingFunctionVariant_Ease { AzStyleTransitionTimingFunctionTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVariant_Ease AzStyleTransitionTimingFunctionVariant_Ease;
+struct AzStyleTransitionTimingFunctionVariant_Linear { AzStyleTransitionTimingFunctionTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVariant_Linear AzStyleTransitionTimingFunctionVariant_Linear;
+struct AzStyleTransitionTimingFunctionVariant_EaseIn { AzStyleTransitionTimingFunctionTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVariant_EaseIn AzStyleTransitionTimingFunctionVariant_EaseIn;
+struct AzStyleTransitionTimingFunctionVariant_EaseOut { AzStyleTransitionTimingFunctionTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVariant_EaseOut AzStyleTransitionTimingFunctionVariant_EaseOut;
+struct AzStyleTransitionTimingFunctionVariant_EaseInOut { AzStyleTransitionTimingFunctionTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVariant_EaseInOut AzStyleTransitionTimingFunctionVariant_EaseInOut;
+struct AzStyleTransitionTimingFunctionVariant_CubicBezier { AzStyleTransitionTimingFunctionTag tag; AzStyleCubicBezier payload; };
+typedef struct AzStyleTransitionTimingFunctionVariant_CubicBezier AzStyleTransitionTimingFunctionVariant_CubicBezier;
+union AzStyleTransitionTimingFunction {
+    AzStyleTransitionTimingFunctionVariant_Ease Ease;
+    AzStyleTransitionTimingFunctionVariant_Linear Linear;
+    AzStyleTransitionTimingFunctionVariant_EaseIn EaseIn;
+    AzStyleTransitionTimingFunctionVariant_EaseOut EaseOut;
+    AzStyleTransitionTimingFunctionVariant_EaseInOut EaseInOut;
+    AzStyleTransitionTimingFunctionVariant_CubicBezier CubicBezier;
+};
+typedef union AzStyleTransitionTimingFunction AzStyleTransitionTimingFunction;
+
 enum AzStyleTransformTag {
    AzStyleTransformTag_Matrix,
    AzStyleTransformTag_Matrix3D,
@@ -7142,6 +8568,62 @@ union AzStyleTransform {
 };
 typedef union AzStyleTransform AzStyleTransform;
 
+enum AzLayoutGridTemplateColumnsValueTag {
+   AzLayoutGridTemplateColumnsValueTag_Auto,
+   AzLayoutGridTemplateColumnsValueTag_None,
+   AzLayoutGridTemplateColumnsValueTag_Inherit,
+   AzLayoutGridTemplateColumnsValueTag_Initial,
+   AzLayoutGridTemplateColumnsValueTag_Exact,
+};
+typedef enum AzLayoutGridTemplateColumnsValueTag AzLayoutGridTemplateColumnsValueTag;
+
+struct AzLayoutGridTemplateColumnsValueVariant_Auto { AzLayoutGridTemplateColumnsValueTag tag; };
+typedef struct AzLayoutGridTemplateColumnsValueVariant_Auto AzLayoutGridTemplateColumnsValueVariant_Auto;
+struct AzLayoutGridTemplateColumnsValueVariant_None { AzLayoutGridTemplateColumnsValueTag tag; };
+typedef struct AzLayoutGridTemplateColumnsValueVariant_None AzLayoutGridTemplateColumnsValueVariant_None;
+struct AzLayoutGridTemplateColumnsValueVariant_Inherit { AzLayoutGridTemplateColumnsValueTag tag; };
+typedef struct AzLayoutGridTemplateColumnsValueVariant_Inherit AzLayoutGridTemplateColumnsValueVariant_Inherit;
+struct AzLayoutGridTemplateColumnsValueVariant_Initial { AzLayoutGridTemplateColumnsValueTag tag; };
+typedef struct AzLayoutGridTemplateColumnsValueVariant_Initial AzLayoutGridTemplateColumnsValueVariant_Initial;
+struct AzLayoutGridTemplateColumnsValueVariant_Exact { AzLayoutGridTemplateColumnsValueTag tag; AzLayoutGridTemplateColumns payload; };
+typedef struct AzLayoutGridTemplateColumnsValueVariant_Exact AzLayoutGridTemplateColumnsValueVariant_Exact;
+union AzLayoutGridTemplateColumnsValue {
+    AzLayoutGridTemplateColumnsValueVariant_Auto Auto;
+    AzLayoutGridTemplateColumnsValueVariant_None None;
+    AzLayoutGridTemplateColumnsValueVariant_Inherit Inherit;
+    AzLayoutGridTemplateColumnsValueVariant_Initial Initial;
+    AzLayoutGridTemplateColumnsValueVariant_Exact Exact;
+};
+typedef union AzLayoutGridTemplateColumnsValue AzLayoutGridTemplateColumnsValue;
+
+enum AzLayoutGridTemplateRowsValueTag {
+   AzLayoutGridTemplateRowsValueTag_Auto,
+   AzLayoutGridTemplateRowsValueTag_None,
+   AzLayoutGridTemplateRowsValueTag_Inherit,
+   AzLayoutGridTemplateRowsValueTag_Initial,
+   AzLayoutGridTemplateRowsValueTag_Exact,
+};
+typedef enum AzLayoutGridTemplateRowsValueTag AzLayoutGridTemplateRowsValueTag;
+
+struct AzLayoutGridTemplateRowsValueVariant_Auto { AzLayoutGridTemplateRowsValueTag tag; };
+typedef struct AzLayoutGridTemplateRowsValueVariant_Auto AzLayoutGridTemplateRowsValueVariant_Auto;
+struct AzLayoutGridTemplateRowsValueVariant_None { AzLayoutGridTemplateRowsValueTag tag; };
+typedef struct AzLayoutGridTemplateRowsValueVariant_None AzLayoutGridTemplateRowsValueVariant_None;
+struct AzLayoutGridTemplateRowsValueVariant_Inherit { AzLayoutGridTemplateRowsValueTag tag; };
+typedef struct AzLayoutGridTemplateRowsValueVariant_Inherit AzLayoutGridTemplateRowsValueVariant_Inherit;
+struct AzLayoutGridTemplateRowsValueVariant_Initial { AzLayoutGridTemplateRowsValueTag tag; };
+typedef struct AzLayoutGridTemplateRowsValueVariant_Initial AzLayoutGridTemplateRowsValueVariant_Initial;
+struct AzLayoutGridTemplateRowsValueVariant_Exact { AzLayoutGridTemplateRowsValueTag tag; AzLayoutGridTemplateRows payload; };
+typedef struct AzLayoutGridTemplateRowsValueVariant_Exact AzLayoutGridTemplateRowsValueVariant_Exact;
+union AzLayoutGridTemplateRowsValue {
+    AzLayoutGridTemplateRowsValueVariant_Auto Auto;
+    AzLayoutGridTemplateRowsValueVariant_None None;
+    AzLayoutGridTemplateRowsValueVariant_Inherit Inherit;
+    AzLayoutGridTemplateRowsValueVariant_Initial Initial;
+    AzLayoutGridTemplateRowsValueVariant_Exact Exact;
+};
+typedef union AzLayoutGridTemplateRowsValue AzLayoutGridTemplateRowsValue;
+
 enum AzStyleBackgroundPositionVecValueTag {
    AzStyleBackgroundPositionVecValueTag_Auto,
    AzStyleBackgroundPositionVecValueTag_None,
@@ -7226,6 +8708,230 @@ union AzStyleBackgroundSizeVecValue {
 };
 typedef union AzStyleBackgroundSizeVecValue AzStyleBackgroundSizeVecValue;
 
+enum AzStyleTransitionPropertyVecValueTag {
+   AzStyleTransitionPropertyVecValueTag_Auto,
+   AzStyleTransitionPropertyVecValueTag_None,
+   AzStyleTransitionPropertyVecValueTag_Inherit,
+   AzStyleTransitionPropertyVecValueTag_Initial,
+   AzStyleTransitionPropertyVecValueTag_Exact,
+};
+typedef enum AzStyleTransitionPropertyVecValueTag AzStyleTransitionPropertyVecValueTag;
+
+struct AzStyleTransitionPropertyVecValueVariant_Auto { AzStyleTransitionPropertyVecValueTag tag; };
+typedef struct AzStyleTransitionPropertyVecValueVariant_Auto AzStyleTransitionPropertyVecValueVariant_Auto;
+struct AzStyleTransitionPropertyVecValueVariant_None { AzStyleTransitionPropertyVecValueTag tag; };
+typedef struct AzStyleTransitionPropertyVecValueVariant_None AzStyleTransitionPropertyVecValueVariant_None;
+struct AzStyleTransitionPropertyVecValueVariant_Inherit { AzStyleTransitionPropertyVecValueTag tag; };
+typedef struct AzStyleTransitionPropertyVecValueVariant_Inherit AzStyleTransitionPropertyVecValueVariant_Inherit;
+struct AzStyleTransitionPropertyVecValueVariant_Initial { AzStyleTransitionPropertyVecValueTag tag; };
+typedef struct AzStyleTransitionPropertyVecValueVariant_Initial AzStyleTransitionPropertyVecValueVariant_Initial;
+struct AzStyleTransitionPropertyVecValueVariant_Exact { AzStyleTransitionPropertyVecValueTag tag; AzStyleTransitionPropertyVec payload; };
+typedef struct AzStyleTransitionPropertyVecValueVariant_Exact AzStyleTransitionPropertyVecValueVariant_Exact;
+union AzStyleTransitionPropertyVecValue {
+    AzStyleTransitionPropertyVecValueVariant_Auto Auto;
+    AzStyleTransitionPropertyVecValueVariant_None None;
+    AzStyleTransitionPropertyVecValueVariant_Inherit Inherit;
+    AzStyleTransitionPropertyVecValueVariant_Initial Initial;
+    AzStyleTransitionPropertyVecValueVariant_Exact Exact;
+};
+typedef union AzStyleTransitionPropertyVecValue AzStyleTransitionPropertyVecValue;
+
+enum AzStyleTransitionDurationVecValueTag {
+   AzStyleTransitionDurationVecValueTag_Auto,
+   AzStyleTransitionDurationVecValueTag_None,
+   AzStyleTransitionDurationVecValueTag_Inherit,
+   AzStyleTransitionDurationVecValueTag_Initial,
+   AzStyleTransitionDurationVecValueTag_Exact,
+};
+typedef enum AzStyleTransitionDurationVecValueTag AzStyleTransitionDurationVecValueTag;
+
+struct AzStyleTransitionDurationVecValueVariant_Auto { AzStyleTransitionDurationVecValueTag tag; };
+typedef struct AzStyleTransitionDurationVecValueVariant_Auto AzStyleTransitionDurationVecValueVariant_Auto;
+struct AzStyleTransitionDurationVecValueVariant_None { AzStyleTransitionDurationVecValueTag tag; };
+typedef struct AzStyleTransitionDurationVecValueVariant_None AzStyleTransitionDurationVecValueVariant_None;
+struct AzStyleTransitionDurationVecValueVariant_Inherit { AzStyleTransitionDurationVecValueTag tag; };
+typedef struct AzStyleTransitionDurationVecValueVariant_Inherit AzStyleTransitionDurationVecValueVariant_Inherit;
+struct AzStyleTransitionDurationVecValueVariant_Initial { AzStyleTransitionDurationVecValueTag tag; };
+typedef struct AzStyleTransitionDurationVecValueVariant_Initial AzStyleTransitionDurationVecValueVariant_Initial;
+struct AzStyleTransitionDurationVecValueVariant_Exact { AzStyleTransitionDurationVecValueTag tag; AzStyleTransitionDurationVec payload; };
+typedef struct AzStyleTransitionDurationVecValueVariant_Exact AzStyleTransitionDurationVecValueVariant_Exact;
+union AzStyleTransitionDurationVecValue {
+    AzStyleTransitionDurationVecValueVariant_Auto Auto;
+    AzStyleTransitionDurationVecValueVariant_None None;
+    AzStyleTransitionDurationVecValueVariant_Inherit Inherit;
+    AzStyleTransitionDurationVecValueVariant_Initial Initial;
+    AzStyleTransitionDurationVecValueVariant_Exact Exact;
+};
+typedef union AzStyleTransitionDurationVecValue AzStyleTransitionDurationVecValue;
+
+enum AzStyleTransitionDelayVecValueTag {
+   AzStyleTransitionDelayVecValueTag_Auto,
+   AzStyleTransitionDelayVecValueTag_None,
+   AzStyleTransitionDelayVecValueTag_Inherit,
+   AzStyleTransitionDelayVecValueTag_Initial,
+   AzStyleTransitionDelayVecValueTag_Exact,
+};
+typedef enum AzStyleTransitionDelayVecValueTag AzStyleTransitionDelayVecValueTag;
+
+struct AzStyleTransitionDelayVecValueVariant_Auto { AzStyleTransitionDelayVecValueTag tag; };
+typedef struct AzStyleTransitionDelayVecValueVariant_Auto AzStyleTransitionDelayVecValueVariant_Auto;
+struct AzStyleTransitionDelayVecValueVariant_None { AzStyleTransitionDelayVecValueTag tag; };
+typedef struct AzStyleTransitionDelayVecValueVariant_None AzStyleTransitionDelayVecValueVariant_None;
+struct AzStyleTransitionDelayVecValueVariant_Inherit { AzStyleTransitionDelayVecValueTag tag; };
+typedef struct AzStyleTransitionDelayVecValueVariant_Inherit AzStyleTransitionDelayVecValueVariant_Inherit;
+struct AzStyleTransitionDelayVecValueVariant_Initial { AzStyleTransitionDelayVecValueTag tag; };
+typedef struct AzStyleTransitionDelayVecValueVariant_Initial AzStyleTransitionDelayVecValueVariant_Initial;
+struct AzStyleTransitionDelayVecValueVariant_Exact { AzStyleTransitionDelayVecValueTag tag; AzStyleTransitionDelayVec payload; };
+typedef struct AzStyleTransitionDelayVecValueVariant_Exact AzStyleTransitionDelayVecValueVariant_Exact;
+union AzStyleTransitionDelayVecValue {
+    AzStyleTransitionDelayVecValueVariant_Auto Auto;
+    AzStyleTransitionDelayVecValueVariant_None None;
+    AzStyleTransitionDelayVecValueVariant_Inherit Inherit;
+    AzStyleTransitionDelayVecValueVariant_Initial Initial;
+    AzStyleTransitionDelayVecValueVariant_Exact Exact;
+};
+typedef union AzStyleTransitionDelayVecValue AzStyleTransitionDelayVecValue;
+
+enum AzStyleAnimationDurationVecValueTag {
+   AzStyleAnimationDurationVecValueTag_Auto,
+   AzStyleAnimationDurationVecValueTag_None,
+   AzStyleAnimationDurationVecValueTag_Inherit,
+   AzStyleAnimationDurationVecValueTag_Initial,
+   AzStyleAnimationDurationVecValueTag_Exact,
+};
+typedef enum AzStyleAnimationDurationVecValueTag AzStyleAnimationDurationVecValueTag;
+
+struct AzStyleAnimationDurationVecValueVariant_Auto { AzStyleAnimationDurationVecValueTag tag; };
+typedef struct AzStyleAnimationDurationVecValueVariant_Auto AzStyleAnimationDurationVecValueVariant_Auto;
+struct AzStyleAnimationDurationVecValueVariant_None { AzStyleAnimationDurationVecValueTag tag; };
+typedef struct AzStyleAnimationDurationVecValueVariant_None AzStyleAnimationDurationVecValueVariant_None;
+struct AzStyleAnimationDurationVecValueVariant_Inherit { AzStyleAnimationDurationVecValueTag tag; };
+typedef struct AzStyleAnimationDurationVecValueVariant_Inherit AzStyleAnimationDurationVecValueVariant_Inherit;
+struct AzStyleAnimationDurationVecValueVariant_Initial { AzStyleAnimationDurationVecValueTag tag; };
+typedef struct AzStyleAnimationDurationVecValueVariant_Initial AzStyleAnimationDurationVecValueVariant_Initial;
+struct AzStyleAnimationDurationVecValueVariant_Exact { AzStyleAnimationDurationVecValueTag tag; AzStyleAnimationDurationVec payload; };
+typedef struct AzStyleAnimationDurationVecValueVariant_Exact AzStyleAnimationDurationVecValueVariant_Exact;
+union AzStyleAnimationDurationVecValue {
+    AzStyleAnimationDurationVecValueVariant_Auto Auto;
+    AzStyleAnimationDurationVecValueVariant_None None;
+    AzStyleAnimationDurationVecValueVariant_Inherit Inherit;
+    AzStyleAnimationDurationVecValueVariant_Initial Initial;
+    AzStyleAnimationDurationVecValueVariant_Exact Exact;
+};
+typedef union AzStyleAnimationDurationVecValue AzStyleAnimationDurationVecValue;
+
+enum AzStyleAnimationIterationCountVecValueTag {
+   AzStyleAnimationIterationCountVecValueTag_Auto,
+   AzStyleAnimationIterationCountVecValueTag_None,
+   AzStyleAnimationIterationCountVecValueTag_Inherit,
+   AzStyleAnimationIterationCountVecValueTag_Initial,
+   AzStyleAnimationIterationCountVecValueTag_Exact,
+};
+typedef enum AzStyleAnimationIterationCountVecValueTag AzStyleAnimationIterationCountVecValueTag;
+
+struct AzStyleAnimationIterationCountVecValueVariant_Auto { AzStyleAnimationIterationCountVecValueTag tag; };
+typedef struct AzStyleAnimationIterationCountVecValueVariant_Auto AzStyleAnimationIterationCountVecValueVariant_Auto;
+struct AzStyleAnimationIterationCountVecValueVariant_None { AzStyleAnimationIterationCountVecValueTag tag; };
+typedef struct AzStyleAnimationIterationCountVecValueVariant_None AzStyleAnimationIterationCountVecValueVariant_None;
+struct AzStyleAnimationIterationCountVecValueVariant_Inherit { AzStyleAnimationIterationCountVecValueTag tag; };
+typedef struct AzStyleAnimationIterationCountVecValueVariant_Inherit AzStyleAnimationIterationCountVecValueVariant_Inherit;
+struct AzStyleAnimationIterationCountVecValueVariant_Initial { AzStyleAnimationIterationCountVecValueTag tag; };
+typedef struct AzStyleAnimationIterationCountVecValueVariant_Initial AzStyleAnimationIterationCountVecValueVariant_Initial;
+struct AzStyleAnimationIterationCountVecValueVariant_Exact { AzStyleAnimationIterationCountVecValueTag tag; AzStyleAnimationIterationCountVec payload; };
+typedef struct AzStyleAnimationIterationCountVecValueVariant_Exact AzStyleAnimationIterationCountVecValueVariant_Exact;
+union AzStyleAnimationIterationCountVecValue {
+    AzStyleAnimationIterationCountVecValueVariant_Auto Auto;
+    AzStyleAnimationIterationCountVecValueVariant_None None;
+    AzStyleAnimationIterationCountVecValueVariant_Inherit Inherit;
+    AzStyleAnimationIterationCountVecValueVariant_Initial Initial;
+    AzStyleAnimationIterationCountVecValueVariant_Exact Exact;
+};
+typedef union AzStyleAnimationIterationCountVecValue AzStyleAnimationIterationCountVecValue;
+
+enum AzStyleAnimationDirectionVecValueTag {
+   AzStyleAnimationDirectionVecValueTag_Auto,
+   AzStyleAnimationDirectionVecValueTag_None,
+   AzStyleAnimationDirectionVecValueTag_Inherit,
+   AzStyleAnimationDirectionVecValueTag_Initial,
+   AzStyleAnimationDirectionVecValueTag_Exact,
+};
+typedef enum AzStyleAnimationDirectionVecValueTag AzStyleAnimationDirectionVecValueTag;
+
+struct AzStyleAnimationDirectionVecValueVariant_Auto { AzStyleAnimationDirectionVecValueTag tag; };
+typedef struct AzStyleAnimationDirectionVecValueVariant_Auto AzStyleAnimationDirectionVecValueVariant_Auto;
+struct AzStyleAnimationDirectionVecValueVariant_None { AzStyleAnimationDirectionVecValueTag tag; };
+typedef struct AzStyleAnimationDirectionVecValueVariant_None AzStyleAnimationDirectionVecValueVariant_None;
+struct AzStyleAnimationDirectionVecValueVariant_Inherit { AzStyleAnimationDirectionVecValueTag tag; };
+typedef struct AzStyleAnimationDirectionVecValueVariant_Inherit AzStyleAnimationDirectionVecValueVariant_Inherit;
+struct AzStyleAnimationDirectionVecValueVariant_Initial { AzStyleAnimationDirectionVecValueTag tag; };
+typedef struct AzStyleAnimationDirectionVecValueVariant_Initial AzStyleAnimationDirectionVecValueVariant_Initial;
+struct AzStyleAnimationDirectionVecValueVariant_Exact { AzStyleAnimationDirectionVecValueTag tag; AzStyleAnimationDirectionVec payload; };
+typedef struct AzStyleAnimationDirectionVecValueVariant_Exact AzStyleAnimationDirectionVecValueVariant_Exact;
+union AzStyleAnimationDirectionVecValue {
+    AzStyleAnimationDirectionVecValueVariant_Auto Auto;
+    AzStyleAnimationDirectionVecValueVariant_None None;
+    AzStyleAnimationDirectionVecValueVariant_Inherit Inherit;
+    AzStyleAnimationDirectionVecValueVariant_Initial Initial;
+    AzStyleAnimationDirectionVecValueVariant_Exact Exact;
+};
+typedef union AzStyleAnimationDirectionVecValue AzStyleAnimationDirectionVecValue;
+
+enum AzStyleAnimationFillModeVecValueTag {
+   AzStyleAnimationFillModeVecValueTag_Auto,
+   AzStyleAnimationFillModeVecValueTag_None,
+   AzStyleAnimationFillModeVecValueTag_Inherit,
+   AzStyleAnimationFillModeVecValueTag_Initial,
+   AzStyleAnimationFillModeVecValueTag_Exact,
+};
+typedef enum AzStyleAnimationFillModeVecValueTag AzStyleAnimationFillModeVecValueTag;
+
+struct AzStyleAnimationFillModeVecValueVariant_Auto { AzStyleAnimationFillModeVecValueTag tag; };
+typedef struct AzStyleAnimationFillModeVecValueVariant_Auto AzStyleAnimationFillModeVecValueVariant_Auto;
+struct AzStyleAnimationFillModeVecValueVariant_None { AzStyleAnimationFillModeVecValueTag tag; };
+typedef struct AzStyleAnimationFillModeVecValueVariant_None AzStyleAnimationFillModeVecValueVariant_None;
+struct AzStyleAnimationFillModeVecValueVariant_Inherit { AzStyleAnimationFillModeVecValueTag tag; };
+typedef struct AzStyleAnimationFillModeVecValueVariant_Inherit AzStyleAnimationFillModeVecValueVariant_Inherit;
+struct AzStyleAnimationFillModeVecValueVariant_Initial { AzStyleAnimationFillModeVecValueTag tag; };
+typedef struct AzStyleAnimationFillModeVecValueVariant_Initial AzStyleAnimationFillModeVecValueVariant_Initial;
+struct AzStyleAnimationFillModeVecValueVariant_Exact { AzStyleAnimationFillModeVecValueTag tag; AzStyleAnimationFillModeVec payload; };
+typedef struct AzStyleAnimationFillModeVecValueVariant_Exact AzStyleAnimationFillModeVecValueVariant_Exact;
+union AzStyleAnimationFillModeVecValue {
+    AzStyleAnimationFillModeVecValueVariant_Auto Auto;
+    AzStyleAnimationFillModeVecValueVariant_None None;
+    AzStyleAnimationFillModeVecValueVariant_Inherit Inherit;
+    AzStyleAnimationFillModeVecValueVariant_Initial Initial;
+    AzStyleAnimationFillModeVecValueVariant_Exact Exact;
+};
+typedef union AzStyleAnimationFillModeVecValue AzStyleAnimationFillModeVecValue;
+
+enum AzStyleAnimationDelayVecValueTag {
+   AzStyleAnimationDelayVecValueTag_Auto,
+   AzStyleAnimationDelayVecValueTag_None,
+   AzStyleAnimationDelayVecValueTag_Inherit,
+   AzStyleAnimationDelayVecValueTag_Initial,
+   AzStyleAnimationDelayVecValueTag_Exact,
+};
+typedef enum AzStyleAnimationDelayVecValueTag AzStyleAnimationDelayVecValueTag;
+
+struct AzStyleAnimationDelayVecValueVariant_Auto { AzStyleAnimationDelayVecValueTag tag; };
+typedef struct AzStyleAnimationDelayVecValueVariant_Auto AzStyleAnimationDelayVecValueVariant_Auto;
+struct AzStyleAnimationDelayVecValueVariant_None { AzStyleAnimationDelayVecValueTag tag; };
+typedef struct AzStyleAnimationDelayVecValueVariant_None AzStyleAnimationDelayVecValueVariant_None;
+struct AzStyleAnimationDelayVecValueVariant_Inherit { AzStyleAnimationDelayVecValueTag tag; };
+typedef struct AzStyleAnimationDelayVecValueVariant_Inherit AzStyleAnimationDelayVecValueVariant_Inherit;
+struct AzStyleAnimationDelayVecValueVariant_Initial { AzStyleAnimationDelayVecValueTag tag; };
+typedef struct AzStyleAnimationDelayVecValueVariant_Initial AzStyleAnimationDelayVecValueVariant_Initial;
+struct AzStyleAnimationDelayVecValueVariant_Exact { AzStyleAnimationDelayVecValueTag tag; AzStyleAnimationDelayVec payload; };
+typedef struct AzStyleAnimationDelayVecValueVariant_Exact AzStyleAnimationDelayVecValueVariant_Exact;
+union AzStyleAnimationDelayVecValue {
+    AzStyleAnimationDelayVecValueVariant_Auto Auto;
+    AzStyleAnimationDelayVecValueVariant_None None;
+    AzStyleAnimationDelayVecValueVariant_Inherit Inherit;
+    AzStyleAnimationDelayVecValueVariant_Initial Initial;
+    AzStyleAnimationDelayVecValueVariant_Exact Exact;
+};
+typedef union AzStyleAnimationDelayVecValue AzStyleAnimationDelayVecValue;
+
 struct AzCheckBoxStateWrapper {
     AzCheckBoxState inner;
     AzOptionCheckBoxOnToggle on_toggle;
@@ -7238,14 +8944,52 @@ struct AzNumberInputStateWrapper {
 };
 typedef struct AzNumberInputStateWrapper AzNumberInputStateWrapper;
 
-struct AzStyledNode {
-    AzStyledNodeState state;
-    AzOptionTagId tag_id;
+struct AzTextEditState {
+    AzU32Vec text;
+    AzOptionTextInputSelection selection;
+    size_t cursor_pos;
+    bool  show_line_numbers;
 };
-typedef struct AzStyledNode AzStyledNode;
+typedef struct AzTextEditState AzTextEditState;
 
-struct AzTagIdToNodeIdMapping {
-    AzTagId tag_id;
+struct AzTextEditSnapshot {
+    AzU32Vec text;
+    size_t cursor_pos;
+};
+typedef struct AzTextEditSnapshot AzTextEditSnapshot;
+
+struct AzSliderStateWrapper {
+    AzSliderState inner;
+    AzOptionSliderOnValueChange on_value_change;
+    bool  is_dragging;
+    float drag_value;
+};
+typedef struct AzSliderStateWrapper AzSliderStateWrapper;
+
+enum AzCssPropertySourceTag {
+   AzCssPropertySourceTag_Css,
+   AzCssPropertySourceTag_Inline,
+};
+typedef enum AzCssPropertySourceTag AzCssPropertySourceTag;
+
+struct AzCssPropertySourceVariant_Css { AzCssPropertySourceTag tag; AzCssPath payload; };
+typedef struct AzCssPropertySourceVariant_Css AzCssPropertySourceVariant_Css;
+struct AzCssPropertySourceVariant_Inline { AzCssPropertySourceTag tag; };
+typedef struct AzCssPropertySourceVariant_Inline AzCssPropertySourceVariant_Inline;
+union AzCssPropertySource {
+    AzCssPropertySourceVariant_Css Css;
+    AzCssPropertySourceVariant_Inline Inline;
+};
+typedef union AzCssPropertySource AzCssPropertySource;
+
+struct AzStyledNode {
+    AzStyledNodeState state;
+    AzOptionTagId tag_id;
+};
+typedef struct AzStyledNode AzStyledNode;
+
+struct AzTagIdToNodeIdMapping {
+    AzTagId tag_id;
     AzNodeId node_id;
     AzOptionTabIndex tab_index;
     AzNodeIdVec parents;
@@ -7414,6 +9158,30 @@ struct AzSvgPathElementVec {
 };
 typedef struct AzSvgPathElementVec AzSvgPathElementVec;
 
+struct AzKeyboardShortcutVec {
+    AzKeyboardShortcut* ptr;
+    size_t len;
+    size_t cap;
+    AzKeyboardShortcutVecDestructor destructor;
+};
+typedef struct AzKeyboardShortcutVec AzKeyboardShortcutVec;
+
+struct AzCssMediaConditionVec {
+    AzCssMediaCondition* ptr;
+    size_t len;
+    size_t cap;
+    AzCssMediaConditionVecDestructor destructor;
+};
+typedef struct AzCssMediaConditionVec AzCssMediaConditionVec;
+
+struct AzStyleTransitionTimingFunctionVec {
+    AzStyleTransitionTimingFunction* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleTransitionTimingFunctionVecDestructor destructor;
+};
+typedef struct AzStyleTransitionTimingFunctionVec AzStyleTransitionTimingFunctionVec;
+
 struct AzStringVec {
     AzString* ptr;
     size_t len;
@@ -7438,6 +9206,14 @@ struct AzTagIdToNodeIdMappingVec {
 };
 typedef struct AzTagIdToNodeIdMappingVec AzTagIdToNodeIdMappingVec;
 
+struct AzTextEditSnapshotVec {
+    AzTextEditSnapshot* ptr;
+    size_t len;
+    size_t cap;
+    AzTextEditSnapshotVecDestructor destructor;
+};
+typedef struct AzTextEditSnapshotVec AzTextEditSnapshotVec;
+
 enum AzOptionVirtualKeyCodeComboTag {
    AzOptionVirtualKeyCodeComboTag_None,
    AzOptionVirtualKeyCodeComboTag_Some,
@@ -7749,6 +9525,12 @@ union AzInlineWord {
 };
 typedef union AzInlineWord AzInlineWord;
 
+struct AzFocusTargetPath {
+    AzDomId dom;
+    AzCssPath css_path;
+};
+typedef struct AzFocusTargetPath AzFocusTargetPath;
+
 struct AzCallbackData {
     AzEventFilter event;
     AzCallback callback;
@@ -7798,6 +9580,24 @@ struct AzAccessibilityInfo {
 };
 typedef struct AzAccessibilityInfo AzAccessibilityInfo;
 
+struct AzAccessibilityNode {
+    AzDomNodeId node_id;
+    AzAccessibilityRole role;
+    AzOptionString name;
+    AzOptionString value;
+    AzAccessibilityStateVec states;
+    AzOptionVirtualKeyCodeCombo accelerator;
+    AzOptionString default_action;
+    AzLogicalRect bounds;
+    AzAccessibilityNodeVec children;
+};
+typedef struct AzAccessibilityNode AzAccessibilityNode;
+
+struct AzAccessibilityTree {
+    AzAccessibilityNode root;
+};
+typedef struct AzAccessibilityTree AzAccessibilityTree;
+
 enum AzIdOrClassTag {
    AzIdOrClassTag_Id,
    AzIdOrClassTag_Class,
@@ -7824,41 +9624,51 @@ struct AzStringMenuItem {
 };
 typedef struct AzStringMenuItem AzStringMenuItem;
 
-enum AzCssPathSelectorTag {
-   AzCssPathSelectorTag_Global,
-   AzCssPathSelectorTag_Type,
-   AzCssPathSelectorTag_Class,
-   AzCssPathSelectorTag_Id,
-   AzCssPathSelectorTag_PseudoSelector,
-   AzCssPathSelectorTag_DirectChildren,
-   AzCssPathSelectorTag_Children,
+enum AzShortcutConflictTag {
+   AzShortcutConflictTag_Shortcut,
+   AzShortcutConflictTag_MenuItem,
+   AzShortcutConflictTag_ContextMenuItem,
 };
-typedef enum AzCssPathSelectorTag AzCssPathSelectorTag;
+typedef enum AzShortcutConflictTag AzShortcutConflictTag;
 
-struct AzCssPathSelectorVariant_Global { AzCssPathSelectorTag tag; };
-typedef struct AzCssPathSelectorVariant_Global AzCssPathSelectorVariant_Global;
-struct AzCssPathSelectorVariant_Type { AzCssPathSelectorTag tag; AzNodeTypeKey payload; };
-typedef struct AzCssPathSelectorVariant_Type AzCssPathSelectorVariant_Type;
-struct AzCssPathSelectorVariant_Class { AzCssPathSelectorTag tag; AzString payload; };
-typedef struct AzCssPathSelectorVariant_Class AzCssPathSelectorVariant_Class;
-struct AzCssPathSelectorVariant_Id { AzCssPathSelectorTag tag; AzString payload; };
-typedef struct AzCssPathSelectorVariant_Id AzCssPathSelectorVariant_Id;
-struct AzCssPathSelectorVariant_PseudoSelector { AzCssPathSelectorTag tag; AzCssPathPseudoSelector payload; };
-typedef struct AzCssPathSelectorVariant_PseudoSelector AzCssPathSelectorVariant_PseudoSelector;
-struct AzCssPathSelectorVariant_DirectChildren { AzCssPathSelectorTag tag; };
-typedef struct AzCssPathSelectorVariant_DirectChildren AzCssPathSelectorVariant_DirectChildren;
-struct AzCssPathSelectorVariant_Children { AzCssPathSelectorTag tag; };
-typedef struct AzCssPathSelectorVariant_Children AzCssPathSelectorVariant_Children;
-union AzCssPathSelector {
-    AzCssPathSelectorVariant_Global Global;
-    AzCssPathSelectorVariant_Type Type;
-    AzCssPathSelectorVariant_Class Class;
-    AzCssPathSelectorVariant_Id Id;
-    AzCssPathSelectorVariant_PseudoSelector PseudoSelector;
-    AzCssPathSelectorVariant_DirectChildren DirectChildren;
-    AzCssPathSelectorVariant_Children Children;
+struct AzShortcutConflictVariant_Shortcut { AzShortcutConflictTag tag; AzVirtualKeyCodeCombo payload; };
+typedef struct AzShortcutConflictVariant_Shortcut AzShortcutConflictVariant_Shortcut;
+struct AzShortcutConflictVariant_MenuItem { AzShortcutConflictTag tag; AzString payload; };
+typedef struct AzShortcutConflictVariant_MenuItem AzShortcutConflictVariant_MenuItem;
+struct AzShortcutConflictVariant_ContextMenuItem { AzShortcutConflictTag tag; AzString payload; };
+typedef struct AzShortcutConflictVariant_ContextMenuItem AzShortcutConflictVariant_ContextMenuItem;
+union AzShortcutConflict {
+    AzShortcutConflictVariant_Shortcut Shortcut;
+    AzShortcutConflictVariant_MenuItem MenuItem;
+    AzShortcutConflictVariant_ContextMenuItem ContextMenuItem;
 };
-typedef union AzCssPathSelector AzCssPathSelector;
+typedef union AzShortcutConflict AzShortcutConflict;
+
+struct AzCssMediaQuery {
+    AzCssMediaConditionVec conditions;
+};
+typedef struct AzCssMediaQuery AzCssMediaQuery;
+
+struct AzCssCustomProperty {
+    AzString name;
+    AzString value;
+};
+typedef struct AzCssCustomProperty AzCssCustomProperty;
+
+struct AzCssAttributeSelector {
+    AzString name;
+    AzCssAttributeSelectorOp op;
+    AzString value;
+};
+typedef struct AzCssAttributeSelector AzCssAttributeSelector;
+
+struct AzCssFontFace {
+    AzString font_family;
+    AzString src;
+    uint16_t font_weight;
+    AzCssFontFaceStyle font_style;
+};
+typedef struct AzCssFontFace AzCssFontFace;
 
 enum AzStyleBackgroundContentTag {
    AzStyleBackgroundContentTag_LinearGradient,
@@ -7926,6 +9736,11 @@ union AzStyleFontFamily {
 };
 typedef union AzStyleFontFamily AzStyleFontFamily;
 
+struct AzStyleAnimationName {
+    AzString name;
+};
+typedef struct AzStyleAnimationName AzStyleAnimationName;
+
 enum AzScrollbarStyleValueTag {
    AzScrollbarStyleValueTag_Auto,
    AzScrollbarStyleValueTag_None,
@@ -7982,6 +9797,34 @@ union AzStyleTransformVecValue {
 };
 typedef union AzStyleTransformVecValue AzStyleTransformVecValue;
 
+enum AzStyleTransitionTimingFunctionVecValueTag {
+   AzStyleTransitionTimingFunctionVecValueTag_Auto,
+   AzStyleTransitionTimingFunctionVecValueTag_None,
+   AzStyleTransitionTimingFunctionVecValueTag_Inherit,
+   AzStyleTransitionTimingFunctionVecValueTag_Initial,
+   AzStyleTransitionTimingFunctionVecValueTag_Exact,
+};
+typedef enum AzStyleTransitionTimingFunctionVecValueTag AzStyleTransitionTimingFunctionVecValueTag;
+
+struct AzStyleTransitionTimingFunctionVecValueVariant_Auto { AzStyleTransitionTimingFunctionVecValueTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVecValueVariant_Auto AzStyleTransitionTimingFunctionVecValueVariant_Auto;
+struct AzStyleTransitionTimingFunctionVecValueVariant_None { AzStyleTransitionTimingFunctionVecValueTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVecValueVariant_None AzStyleTransitionTimingFunctionVecValueVariant_None;
+struct AzStyleTransitionTimingFunctionVecValueVariant_Inherit { AzStyleTransitionTimingFunctionVecValueTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVecValueVariant_Inherit AzStyleTransitionTimingFunctionVecValueVariant_Inherit;
+struct AzStyleTransitionTimingFunctionVecValueVariant_Initial { AzStyleTransitionTimingFunctionVecValueTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVecValueVariant_Initial AzStyleTransitionTimingFunctionVecValueVariant_Initial;
+struct AzStyleTransitionTimingFunctionVecValueVariant_Exact { AzStyleTransitionTimingFunctionVecValueTag tag; AzStyleTransitionTimingFunctionVec payload; };
+typedef struct AzStyleTransitionTimingFunctionVecValueVariant_Exact AzStyleTransitionTimingFunctionVecValueVariant_Exact;
+union AzStyleTransitionTimingFunctionVecValue {
+    AzStyleTransitionTimingFunctionVecValueVariant_Auto Auto;
+    AzStyleTransitionTimingFunctionVecValueVariant_None None;
+    AzStyleTransitionTimingFunctionVecValueVariant_Inherit Inherit;
+    AzStyleTransitionTimingFunctionVecValueVariant_Initial Initial;
+    AzStyleTransitionTimingFunctionVecValueVariant_Exact Exact;
+};
+typedef union AzStyleTransitionTimingFunctionVecValue AzStyleTransitionTimingFunctionVecValue;
+
 struct AzColorInputStateWrapper {
     AzColorInputState inner;
     AzString title;
@@ -7998,6 +9841,39 @@ struct AzTextInputState {
 };
 typedef struct AzTextInputState AzTextInputState;
 
+struct AzTextEditHistory {
+    AzTextEditSnapshotVec undo_stack;
+    AzTextEditSnapshotVec redo_stack;
+    AzOptionTextEditKind last_edit;
+};
+typedef struct AzTextEditHistory AzTextEditHistory;
+
+struct AzDropDownState {
+    AzStringVec choices;
+    AzU32Vec disabled_choices;
+    AzOptionUsize selected;
+    AzMenuPopupPosition popup_position;
+};
+typedef struct AzDropDownState AzDropDownState;
+
+struct AzSpreadsheetCellContent {
+    AzSpreadsheetCellIndex cell;
+    AzString text;
+};
+typedef struct AzSpreadsheetCellContent AzSpreadsheetCellContent;
+
+struct AzSpreadsheetCellClick {
+    AzSpreadsheetCellIndex cell;
+    AzInstant time;
+};
+typedef struct AzSpreadsheetCellClick AzSpreadsheetCellClick;
+
+struct AzSpreadsheetCellEdit {
+    AzSpreadsheetCellIndex cell;
+    AzString text;
+};
+typedef struct AzSpreadsheetCellEdit AzSpreadsheetCellEdit;
+
 struct AzVertexAttribute {
     AzString name;
     AzOptionUsize layout_location;
@@ -8236,13 +10112,29 @@ struct AzVertexAttributeVec {
 };
 typedef struct AzVertexAttributeVec AzVertexAttributeVec;
 
-struct AzCssPathSelectorVec {
-    AzCssPathSelector* ptr;
+struct AzCssMediaQueryVec {
+    AzCssMediaQuery* ptr;
     size_t len;
     size_t cap;
-    AzCssPathSelectorVecDestructor destructor;
+    AzCssMediaQueryVecDestructor destructor;
 };
-typedef struct AzCssPathSelectorVec AzCssPathSelectorVec;
+typedef struct AzCssMediaQueryVec AzCssMediaQueryVec;
+
+struct AzCssFontFaceVec {
+    AzCssFontFace* ptr;
+    size_t len;
+    size_t cap;
+    AzCssFontFaceVecDestructor destructor;
+};
+typedef struct AzCssFontFaceVec AzCssFontFaceVec;
+
+struct AzStyleAnimationNameVec {
+    AzStyleAnimationName* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleAnimationNameVecDestructor destructor;
+};
+typedef struct AzStyleAnimationNameVec AzStyleAnimationNameVec;
 
 struct AzCallbackDataVec {
     AzCallbackData* ptr;
@@ -8268,6 +10160,62 @@ struct AzStringPairVec {
 };
 typedef struct AzStringPairVec AzStringPairVec;
 
+struct AzSpreadsheetCellContentVec {
+    AzSpreadsheetCellContent* ptr;
+    size_t len;
+    size_t cap;
+    AzSpreadsheetCellContentVecDestructor destructor;
+};
+typedef struct AzSpreadsheetCellContentVec AzSpreadsheetCellContentVec;
+
+enum AzOptionSpreadsheetCellClickTag {
+   AzOptionSpreadsheetCellClickTag_None,
+   AzOptionSpreadsheetCellClickTag_Some,
+};
+typedef enum AzOptionSpreadsheetCellClickTag AzOptionSpreadsheetCellClickTag;
+
+struct AzOptionSpreadsheetCellClickVariant_None { AzOptionSpreadsheetCellClickTag tag; };
+typedef struct AzOptionSpreadsheetCellClickVariant_None AzOptionSpreadsheetCellClickVariant_None;
+struct AzOptionSpreadsheetCellClickVariant_Some { AzOptionSpreadsheetCellClickTag tag; AzSpreadsheetCellClick payload; };
+typedef struct AzOptionSpreadsheetCellClickVariant_Some AzOptionSpreadsheetCellClickVariant_Some;
+union AzOptionSpreadsheetCellClick {
+    AzOptionSpreadsheetCellClickVariant_None None;
+    AzOptionSpreadsheetCellClickVariant_Some Some;
+};
+typedef union AzOptionSpreadsheetCellClick AzOptionSpreadsheetCellClick;
+
+enum AzOptionSpreadsheetCellEditTag {
+   AzOptionSpreadsheetCellEditTag_None,
+   AzOptionSpreadsheetCellEditTag_Some,
+};
+typedef enum AzOptionSpreadsheetCellEditTag AzOptionSpreadsheetCellEditTag;
+
+struct AzOptionSpreadsheetCellEditVariant_None { AzOptionSpreadsheetCellEditTag tag; };
+typedef struct AzOptionSpreadsheetCellEditVariant_None AzOptionSpreadsheetCellEditVariant_None;
+struct AzOptionSpreadsheetCellEditVariant_Some { AzOptionSpreadsheetCellEditTag tag; AzSpreadsheetCellEdit payload; };
+typedef struct AzOptionSpreadsheetCellEditVariant_Some AzOptionSpreadsheetCellEditVariant_Some;
+union AzOptionSpreadsheetCellEdit {
+    AzOptionSpreadsheetCellEditVariant_None None;
+    AzOptionSpreadsheetCellEditVariant_Some Some;
+};
+typedef union AzOptionSpreadsheetCellEdit AzOptionSpreadsheetCellEdit;
+
+enum AzOptionAccessibilityNodeTag {
+   AzOptionAccessibilityNodeTag_None,
+   AzOptionAccessibilityNodeTag_Some,
+};
+typedef enum AzOptionAccessibilityNodeTag AzOptionAccessibilityNodeTag;
+
+struct AzOptionAccessibilityNodeVariant_None { AzOptionAccessibilityNodeTag tag; };
+typedef struct AzOptionAccessibilityNodeVariant_None AzOptionAccessibilityNodeVariant_None;
+struct AzOptionAccessibilityNodeVariant_Some { AzOptionAccessibilityNodeTag tag; AzAccessibilityNode payload; };
+typedef struct AzOptionAccessibilityNodeVariant_Some AzOptionAccessibilityNodeVariant_Some;
+union AzOptionAccessibilityNode {
+    AzOptionAccessibilityNodeVariant_None None;
+    AzOptionAccessibilityNodeVariant_Some Some;
+};
+typedef union AzOptionAccessibilityNode AzOptionAccessibilityNode;
+
 enum AzOptionFileTypeListTag {
    AzOptionFileTypeListTag_None,
    AzOptionFileTypeListTag_Some,
@@ -8430,6 +10378,42 @@ struct AzInlineLine {
 };
 typedef struct AzInlineLine AzInlineLine;
 
+enum AzFocusTargetTag {
+   AzFocusTargetTag_Id,
+   AzFocusTargetTag_Path,
+   AzFocusTargetTag_Previous,
+   AzFocusTargetTag_Next,
+   AzFocusTargetTag_First,
+   AzFocusTargetTag_Last,
+   AzFocusTargetTag_NoFocus,
+};
+typedef enum AzFocusTargetTag AzFocusTargetTag;
+
+struct AzFocusTargetVariant_Id { AzFocusTargetTag tag; AzDomNodeId payload; };
+typedef struct AzFocusTargetVariant_Id AzFocusTargetVariant_Id;
+struct AzFocusTargetVariant_Path { AzFocusTargetTag tag; AzFocusTargetPath payload; };
+typedef struct AzFocusTargetVariant_Path AzFocusTargetVariant_Path;
+struct AzFocusTargetVariant_Previous { AzFocusTargetTag tag; };
+typedef struct AzFocusTargetVariant_Previous AzFocusTargetVariant_Previous;
+struct AzFocusTargetVariant_Next { AzFocusTargetTag tag; };
+typedef struct AzFocusTargetVariant_Next AzFocusTargetVariant_Next;
+struct AzFocusTargetVariant_First { AzFocusTargetTag tag; };
+typedef struct AzFocusTargetVariant_First AzFocusTargetVariant_First;
+struct AzFocusTargetVariant_Last { AzFocusTargetTag tag; };
+typedef struct AzFocusTargetVariant_Last AzFocusTargetVariant_Last;
+struct AzFocusTargetVariant_NoFocus { AzFocusTargetTag tag; };
+typedef struct AzFocusTargetVariant_NoFocus AzFocusTargetVariant_NoFocus;
+union AzFocusTarget {
+    AzFocusTargetVariant_Id Id;
+    AzFocusTargetVariant_Path Path;
+    AzFocusTargetVariant_Previous Previous;
+    AzFocusTargetVariant_Next Next;
+    AzFocusTargetVariant_First First;
+    AzFocusTargetVariant_Last Last;
+    AzFocusTargetVariant_NoFocus NoFocus;
+};
+typedef union AzFocusTarget AzFocusTarget;
+
 enum AzMenuItemTag {
    AzMenuItemTag_Label,
    AzMenuItemTag_Separator,
@@ -8450,10 +10434,57 @@ union AzMenuItem {
 };
 typedef union AzMenuItem AzMenuItem;
 
-struct AzCssPath {
-    AzCssPathSelectorVec selectors;
+enum AzCssPathSelectorTag {
+   AzCssPathSelectorTag_Global,
+   AzCssPathSelectorTag_Type,
+   AzCssPathSelectorTag_Class,
+   AzCssPathSelectorTag_Id,
+   AzCssPathSelectorTag_PseudoSelector,
+   AzCssPathSelectorTag_Attribute,
+   AzCssPathSelectorTag_Not,
+   AzCssPathSelectorTag_DirectChildren,
+   AzCssPathSelectorTag_Children,
+   AzCssPathSelectorTag_AdjacentSibling,
+   AzCssPathSelectorTag_GeneralSibling,
 };
-typedef struct AzCssPath AzCssPath;
+typedef enum AzCssPathSelectorTag AzCssPathSelectorTag;
+
+struct AzCssPathSelectorVariant_Global { AzCssPathSelectorTag tag; };
+typedef struct AzCssPathSelectorVariant_Global AzCssPathSelectorVariant_Global;
+struct AzCssPathSelectorVariant_Type { AzCssPathSelectorTag tag; AzNodeTypeKey payload; };
+typedef struct AzCssPathSelectorVariant_Type AzCssPathSelectorVariant_Type;
+struct AzCssPathSelectorVariant_Class { AzCssPathSelectorTag tag; AzString payload; };
+typedef struct AzCssPathSelectorVariant_Class AzCssPathSelectorVariant_Class;
+struct AzCssPathSelectorVariant_Id { AzCssPathSelectorTag tag; AzString payload; };
+typedef struct AzCssPathSelectorVariant_Id AzCssPathSelectorVariant_Id;
+struct AzCssPathSelectorVariant_PseudoSelector { AzCssPathSelectorTag tag; AzCssPathPseudoSelector payload; };
+typedef struct AzCssPathSelectorVariant_PseudoSelector AzCssPathSelectorVariant_PseudoSelector;
+struct AzCssPathSelectorVariant_Attribute { AzCssPathSelectorTag tag; AzCssAttributeSelector payload; };
+typedef struct AzCssPathSelectorVariant_Attribute AzCssPathSelectorVariant_Attribute;
+struct AzCssPathSelectorVariant_Not { AzCssPathSelectorTag tag; AzCssPathSelectorVec payload; };
+typedef struct AzCssPathSelectorVariant_Not AzCssPathSelectorVariant_Not;
+struct AzCssPathSelectorVariant_DirectChildren { AzCssPathSelectorTag tag; };
+typedef struct AzCssPathSelectorVariant_DirectChildren AzCssPathSelectorVariant_DirectChildren;
+struct AzCssPathSelectorVariant_Children { AzCssPathSelectorTag tag; };
+typedef struct AzCssPathSelectorVariant_Children AzCssPathSelectorVariant_Children;
+struct AzCssPathSelectorVariant_AdjacentSibling { AzCssPathSelectorTag tag; };
+typedef struct AzCssPathSelectorVariant_AdjacentSibling AzCssPathSelectorVariant_AdjacentSibling;
+struct AzCssPathSelectorVariant_GeneralSibling { AzCssPathSelectorTag tag; };
+typedef struct AzCssPathSelectorVariant_GeneralSibling AzCssPathSelectorVariant_GeneralSibling;
+union AzCssPathSelector {
+    AzCssPathSelectorVariant_Global Global;
+    AzCssPathSelectorVariant_Type Type;
+    AzCssPathSelectorVariant_Class Class;
+    AzCssPathSelectorVariant_Id Id;
+    AzCssPathSelectorVariant_PseudoSelector PseudoSelector;
+    AzCssPathSelectorVariant_Attribute Attribute;
+    AzCssPathSelectorVariant_Not Not;
+    AzCssPathSelectorVariant_DirectChildren DirectChildren;
+    AzCssPathSelectorVariant_Children Children;
+    AzCssPathSelectorVariant_AdjacentSibling AdjacentSibling;
+    AzCssPathSelectorVariant_GeneralSibling GeneralSibling;
+};
+typedef union AzCssPathSelector AzCssPathSelector;
 
 enum AzStyleBackgroundContentVecValueTag {
    AzStyleBackgroundContentVecValueTag_Auto,
@@ -8511,6 +10542,34 @@ union AzStyleFontFamilyVecValue {
 };
 typedef union AzStyleFontFamilyVecValue AzStyleFontFamilyVecValue;
 
+enum AzStyleAnimationNameVecValueTag {
+   AzStyleAnimationNameVecValueTag_Auto,
+   AzStyleAnimationNameVecValueTag_None,
+   AzStyleAnimationNameVecValueTag_Inherit,
+   AzStyleAnimationNameVecValueTag_Initial,
+   AzStyleAnimationNameVecValueTag_Exact,
+};
+typedef enum AzStyleAnimationNameVecValueTag AzStyleAnimationNameVecValueTag;
+
+struct AzStyleAnimationNameVecValueVariant_Auto { AzStyleAnimationNameVecValueTag tag; };
+typedef struct AzStyleAnimationNameVecValueVariant_Auto AzStyleAnimationNameVecValueVariant_Auto;
+struct AzStyleAnimationNameVecValueVariant_None { AzStyleAnimationNameVecValueTag tag; };
+typedef struct AzStyleAnimationNameVecValueVariant_None AzStyleAnimationNameVecValueVariant_None;
+struct AzStyleAnimationNameVecValueVariant_Inherit { AzStyleAnimationNameVecValueTag tag; };
+typedef struct AzStyleAnimationNameVecValueVariant_Inherit AzStyleAnimationNameVecValueVariant_Inherit;
+struct AzStyleAnimationNameVecValueVariant_Initial { AzStyleAnimationNameVecValueTag tag; };
+typedef struct AzStyleAnimationNameVecValueVariant_Initial AzStyleAnimationNameVecValueVariant_Initial;
+struct AzStyleAnimationNameVecValueVariant_Exact { AzStyleAnimationNameVecValueTag tag; AzStyleAnimationNameVec payload; };
+typedef struct AzStyleAnimationNameVecValueVariant_Exact AzStyleAnimationNameVecValueVariant_Exact;
+union AzStyleAnimationNameVecValue {
+    AzStyleAnimationNameVecValueVariant_Auto Auto;
+    AzStyleAnimationNameVecValueVariant_None None;
+    AzStyleAnimationNameVecValueVariant_Inherit Inherit;
+    AzStyleAnimationNameVecValueVariant_Initial Initial;
+    AzStyleAnimationNameVecValueVariant_Exact Exact;
+};
+typedef union AzStyleAnimationNameVecValue AzStyleAnimationNameVecValue;
+
 enum AzCssPropertyTag {
    AzCssPropertyTag_TextColor,
    AzCssPropertyTag_FontSize,
@@ -8523,6 +10582,7 @@ enum AzCssPropertyTag {
    AzCssPropertyTag_Cursor,
    AzCssPropertyTag_Display,
    AzCssPropertyTag_Float,
+   AzCssPropertyTag_Clear,
    AzCssPropertyTag_BoxSizing,
    AzCssPropertyTag_Width,
    AzCssPropertyTag_Height,
@@ -8542,6 +10602,12 @@ enum AzCssPropertyTag {
    AzCssPropertyTag_JustifyContent,
    AzCssPropertyTag_AlignItems,
    AzCssPropertyTag_AlignContent,
+   AzCssPropertyTag_GridTemplateColumns,
+   AzCssPropertyTag_GridTemplateRows,
+   AzCssPropertyTag_GridColumn,
+   AzCssPropertyTag_GridRow,
+   AzCssPropertyTag_ColumnGap,
+   AzCssPropertyTag_RowGap,
    AzCssPropertyTag_BackgroundContent,
    AzCssPropertyTag_BackgroundPosition,
    AzCssPropertyTag_BackgroundSize,
@@ -8582,6 +10648,17 @@ enum AzCssPropertyTag {
    AzCssPropertyTag_TransformOrigin,
    AzCssPropertyTag_PerspectiveOrigin,
    AzCssPropertyTag_BackfaceVisibility,
+   AzCssPropertyTag_TransitionProperty,
+   AzCssPropertyTag_TransitionDuration,
+   AzCssPropertyTag_TransitionTimingFunction,
+   AzCssPropertyTag_TransitionDelay,
+   AzCssPropertyTag_AnimationName,
+   AzCssPropertyTag_AnimationDuration,
+   AzCssPropertyTag_AnimationTimingFunction,
+   AzCssPropertyTag_AnimationIterationCount,
+   AzCssPropertyTag_AnimationDirection,
+   AzCssPropertyTag_AnimationFillMode,
+   AzCssPropertyTag_AnimationDelay,
 };
 typedef enum AzCssPropertyTag AzCssPropertyTag;
 
@@ -8607,6 +10684,8 @@ struct AzCssPropertyVariant_Display { AzCssPropertyTag tag; AzLayoutDisplayValue
 typedef struct AzCssPropertyVariant_Display AzCssPropertyVariant_Display;
 struct AzCssPropertyVariant_Float { AzCssPropertyTag tag; AzLayoutFloatValue payload; };
 typedef struct AzCssPropertyVariant_Float AzCssPropertyVariant_Float;
+struct AzCssPropertyVariant_Clear { AzCssPropertyTag tag; AzLayoutClearValue payload; };
+typedef struct AzCssPropertyVariant_Clear AzCssPropertyVariant_Clear;
 struct AzCssPropertyVariant_BoxSizing { AzCssPropertyTag tag; AzLayoutBoxSizingValue payload; };
 typedef struct AzCssPropertyVariant_BoxSizing AzCssPropertyVariant_BoxSizing;
 struct AzCssPropertyVariant_Width { AzCssPropertyTag tag; AzLayoutWidthValue payload; };
@@ -8645,6 +10724,18 @@ struct AzCssPropertyVariant_AlignItems { AzCssPropertyTag tag; AzLayoutAlignItem
 typedef struct AzCssPropertyVariant_AlignItems AzCssPropertyVariant_AlignItems;
 struct AzCssPropertyVariant_AlignContent { AzCssPropertyTag tag; AzLayoutAlignContentValue payload; };
 typedef struct AzCssPropertyVariant_AlignContent AzCssPropertyVariant_AlignContent;
+struct AzCssPropertyVariant_GridTemplateColumns { AzCssPropertyTag tag; AzLayoutGridTemplateColumnsValue payload; };
+typedef struct AzCssPropertyVariant_GridTemplateColumns AzCssPropertyVariant_GridTemplateColumns;
+struct AzCssPropertyVariant_GridTemplateRows { AzCssPropertyTag tag; AzLayoutGridTemplateRowsValue payload; };
+typedef struct AzCssPropertyVariant_GridTemplateRows AzCssPropertyVariant_GridTemplateRows;
+struct AzCssPropertyVariant_GridColumn { AzCssPropertyTag tag; AzLayoutGridColumnValue payload; };
+typedef struct AzCssPropertyVariant_GridColumn AzCssPropertyVariant_GridColumn;
+struct AzCssPropertyVariant_GridRow { AzCssPropertyTag tag; AzLayoutGridRowValue payload; };
+typedef struct AzCssPropertyVariant_GridRow AzCssPropertyVariant_GridRow;
+struct AzCssPropertyVariant_ColumnGap { AzCssPropertyTag tag; AzLayoutColumnGapValue payload; };
+typedef struct AzCssPropertyVariant_ColumnGap AzCssPropertyVariant_ColumnGap;
+struct AzCssPropertyVariant_RowGap { AzCssPropertyTag tag; AzLayoutRowGapValue payload; };
+typedef struct AzCssPropertyVariant_RowGap AzCssPropertyVariant_RowGap;
 struct AzCssPropertyVariant_BackgroundContent { AzCssPropertyTag tag; AzStyleBackgroundContentVecValue payload; };
 typedef struct AzCssPropertyVariant_BackgroundContent AzCssPropertyVariant_BackgroundContent;
 struct AzCssPropertyVariant_BackgroundPosition { AzCssPropertyTag tag; AzStyleBackgroundPositionVecValue payload; };
@@ -8725,6 +10816,28 @@ struct AzCssPropertyVariant_PerspectiveOrigin { AzCssPropertyTag tag; AzStylePer
 typedef struct AzCssPropertyVariant_PerspectiveOrigin AzCssPropertyVariant_PerspectiveOrigin;
 struct AzCssPropertyVariant_BackfaceVisibility { AzCssPropertyTag tag; AzStyleBackfaceVisibilityValue payload; };
 typedef struct AzCssPropertyVariant_BackfaceVisibility AzCssPropertyVariant_BackfaceVisibility;
+struct AzCssPropertyVariant_TransitionProperty { AzCssPropertyTag tag; AzStyleTransitionPropertyVecValue payload; };
+typedef struct AzCssPropertyVariant_TransitionProperty AzCssPropertyVariant_TransitionProperty;
+struct AzCssPropertyVariant_TransitionDuration { AzCssPropertyTag tag; AzStyleTransitionDurationVecValue payload; };
+typedef struct AzCssPropertyVariant_TransitionDuration AzCssPropertyVariant_TransitionDuration;
+struct AzCssPropertyVariant_TransitionTimingFunction { AzCssPropertyTag tag; AzStyleTransitionTimingFunctionVecValue payload; };
+typedef struct AzCssPropertyVariant_TransitionTimingFunction AzCssPropertyVariant_TransitionTimingFunction;
+struct AzCssPropertyVariant_TransitionDelay { AzCssPropertyTag tag; AzStyleTransitionDelayVecValue payload; };
+typedef struct AzCssPropertyVariant_TransitionDelay AzCssPropertyVariant_TransitionDelay;
+struct AzCssPropertyVariant_AnimationName { AzCssPropertyTag tag; AzStyleAnimationNameVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationName AzCssPropertyVariant_AnimationName;
+struct AzCssPropertyVariant_AnimationDuration { AzCssPropertyTag tag; AzStyleAnimationDurationVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationDuration AzCssPropertyVariant_AnimationDuration;
+struct AzCssPropertyVariant_AnimationTimingFunction { AzCssPropertyTag tag; AzStyleTransitionTimingFunctionVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationTimingFunction AzCssPropertyVariant_AnimationTimingFunction;
+struct AzCssPropertyVariant_AnimationIterationCount { AzCssPropertyTag tag; AzStyleAnimationIterationCountVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationIterationCount AzCssPropertyVariant_AnimationIterationCount;
+struct AzCssPropertyVariant_AnimationDirection { AzCssPropertyTag tag; AzStyleAnimationDirectionVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationDirection AzCssPropertyVariant_AnimationDirection;
+struct AzCssPropertyVariant_AnimationFillMode { AzCssPropertyTag tag; AzStyleAnimationFillModeVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationFillMode AzCssPropertyVariant_AnimationFillMode;
+struct AzCssPropertyVariant_AnimationDelay { AzCssPropertyTag tag; AzStyleAnimationDelayVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationDelay AzCssPropertyVariant_AnimationDelay;
 union AzCssProperty {
     AzCssPropertyVariant_TextColor TextColor;
     AzCssPropertyVariant_FontSize FontSize;
@@ -8737,6 +10850,7 @@ union AzCssProperty {
     AzCssPropertyVariant_Cursor Cursor;
     AzCssPropertyVariant_Display Display;
     AzCssPropertyVariant_Float Float;
+    AzCssPropertyVariant_Clear Clear;
     AzCssPropertyVariant_BoxSizing BoxSizing;
     AzCssPropertyVariant_Width Width;
     AzCssPropertyVariant_Height Height;
@@ -8756,6 +10870,12 @@ union AzCssProperty {
     AzCssPropertyVariant_JustifyContent JustifyContent;
     AzCssPropertyVariant_AlignItems AlignItems;
     AzCssPropertyVariant_AlignContent AlignContent;
+    AzCssPropertyVariant_GridTemplateColumns GridTemplateColumns;
+    AzCssPropertyVariant_GridTemplateRows GridTemplateRows;
+    AzCssPropertyVariant_GridColumn GridColumn;
+    AzCssPropertyVariant_GridRow GridRow;
+    AzCssPropertyVariant_ColumnGap ColumnGap;
+    AzCssPropertyVariant_RowGap RowGap;
     AzCssPropertyVariant_BackgroundContent BackgroundContent;
     AzCssPropertyVariant_BackgroundPosition BackgroundPosition;
     AzCssPropertyVariant_BackgroundSize BackgroundSize;
@@ -8796,6 +10916,17 @@ union AzCssProperty {
     AzCssPropertyVariant_TransformOrigin TransformOrigin;
     AzCssPropertyVariant_PerspectiveOrigin PerspectiveOrigin;
     AzCssPropertyVariant_BackfaceVisibility BackfaceVisibility;
+    AzCssPropertyVariant_TransitionProperty TransitionProperty;
+    AzCssPropertyVariant_TransitionDuration TransitionDuration;
+    AzCssPropertyVariant_TransitionTimingFunction TransitionTimingFunction;
+    AzCssPropertyVariant_TransitionDelay TransitionDelay;
+    AzCssPropertyVariant_AnimationName AnimationName;
+    AzCssPropertyVariant_AnimationDuration AnimationDuration;
+    AzCssPropertyVariant_AnimationTimingFunction AnimationTimingFunction;
+    AzCssPropertyVariant_AnimationIterationCount AnimationIterationCount;
+    AzCssPropertyVariant_AnimationDirection AnimationDirection;
+    AzCssPropertyVariant_AnimationFillMode AnimationFillMode;
+    AzCssPropertyVariant_AnimationDelay AnimationDelay;
 };
 typedef union AzCssProperty AzCssProperty;
 
@@ -8806,24 +10937,50 @@ struct AzTextInputStateWrapper {
     AzOptionTextInputOnFocusLost on_focus_lost;
     bool  update_text_input_before_calling_focus_lost_fn;
     bool  update_text_input_before_calling_vk_down_fn;
+    bool  is_dragging;
 };
 typedef struct AzTextInputStateWrapper AzTextInputStateWrapper;
 
-enum AzCssPropertySourceTag {
-   AzCssPropertySourceTag_Css,
-   AzCssPropertySourceTag_Inline,
-};
-typedef enum AzCssPropertySourceTag AzCssPropertySourceTag;
-
-struct AzCssPropertySourceVariant_Css { AzCssPropertySourceTag tag; AzCssPath payload; };
-typedef struct AzCssPropertySourceVariant_Css AzCssPropertySourceVariant_Css;
-struct AzCssPropertySourceVariant_Inline { AzCssPropertySourceTag tag; };
-typedef struct AzCssPropertySourceVariant_Inline AzCssPropertySourceVariant_Inline;
-union AzCssPropertySource {
-    AzCssPropertySourceVariant_Css Css;
-    AzCssPropertySourceVariant_Inline Inline;
-};
-typedef union AzCssPropertySource AzCssPropertySource;
+struct AzTextEditStateWrapper {
+    AzTextEditState inner;
+    AzOptionTextEditOnTextChange on_text_change;
+    AzOptionTextEditOnFocusLost on_focus_lost;
+    AzTextEditHistory history;
+    AzTextEditView view;
+};
+typedef struct AzTextEditStateWrapper AzTextEditStateWrapper;
+
+struct AzDropDownStateWrapper {
+    AzDropDownState inner;
+    AzOptionDropDownOnChoiceChange on_choice_change;
+    bool  is_open;
+    AzOptionUsize highlighted;
+    AzString search;
+    AzOptionInstant last_search_input;
+};
+typedef struct AzDropDownStateWrapper AzDropDownStateWrapper;
+
+struct AzSpreadsheetState {
+    AzSpreadsheetStyle style;
+    size_t row_count;
+    size_t column_count;
+    float default_column_width;
+    float default_row_height;
+    AzOptionSpreadsheetCellSelection selection;
+    AzSpreadsheetSizeOverrideVec column_width_overrides;
+    AzSpreadsheetSizeOverrideVec row_height_overrides;
+    AzSpreadsheetCellContentVec cell_contents;
+};
+typedef struct AzSpreadsheetState AzSpreadsheetState;
+
+struct AzSpreadsheetView {
+    AzSpreadsheetRenderWindow rendered;
+    AzOptionDomNodeId iframe_node;
+    AzSpreadsheetDrag drag;
+    AzOptionSpreadsheetCellClick last_click;
+    AzOptionSpreadsheetCellEdit editing;
+};
+typedef struct AzSpreadsheetView AzSpreadsheetView;
 
 struct AzVertexLayout {
     AzVertexAttributeVec fields;
@@ -8931,6 +11088,7 @@ struct AzWindowState {
     AzColorU background_color;
     AzLayoutCallback layout_callback;
     AzOptionCallback close_callback;
+    AzKeyboardShortcutVec shortcuts;
 };
 typedef struct AzWindowState AzWindowState;
 
@@ -8963,6 +11121,8 @@ struct AzCallbackInfo {
     void* restrict images_changed_in_callbacks;
     void* restrict image_masks_changed_in_callbacks;
     void* restrict css_properties_changed_in_callbacks;
+    void* restrict css_variables_changed_in_callbacks;
+    void* restrict css_transitions_changed_in_callbacks;
     void* current_scroll_states;
     void* restrict nodes_scrolled_in_callback;
     AzDomNodeId hit_dom_node;
@@ -8982,12 +11142,6 @@ struct AzInlineText {
 };
 typedef struct AzInlineText AzInlineText;
 
-struct AzFocusTargetPath {
-    AzDomId dom;
-    AzCssPath css_path;
-};
-typedef struct AzFocusTargetPath AzFocusTargetPath;
-
 struct AzAnimation {
     AzCssProperty from;
     AzCssProperty to;
@@ -9040,6 +11194,21 @@ struct AzDynamicCssProperty {
 };
 typedef struct AzDynamicCssProperty AzDynamicCssProperty;
 
+struct AzCssKeyframe {
+    AzFloatValue offset;
+    AzCssPropertyVec properties;
+};
+typedef struct AzCssKeyframe AzCssKeyframe;
+
+struct AzSpreadsheetStateWrapper {
+    AzSpreadsheetState inner;
+    AzOptionSpreadsheetOnCellsChange on_cells_change;
+    AzOptionSpreadsheetOnSelectionChange on_selection_change;
+    AzOptionSpreadsheetOnColumnResize on_column_resize;
+    AzSpreadsheetView view;
+};
+typedef struct AzSpreadsheetStateWrapper AzSpreadsheetStateWrapper;
+
 enum AzSvgNodeTag {
    AzSvgNodeTag_MultiPolygonCollection,
    AzSvgNodeTag_MultiPolygon,
@@ -9082,6 +11251,14 @@ struct AzNodeDataInlineCssPropertyVec {
 };
 typedef struct AzNodeDataInlineCssPropertyVec AzNodeDataInlineCssPropertyVec;
 
+struct AzCssKeyframeVec {
+    AzCssKeyframe* ptr;
+    size_t len;
+    size_t cap;
+    AzCssKeyframeVecDestructor destructor;
+};
+typedef struct AzCssKeyframeVec AzCssKeyframeVec;
+
 enum AzOptionWindowStateTag {
    AzOptionWindowStateTag_None,
    AzOptionWindowStateTag_Some,
@@ -9172,42 +11349,6 @@ struct AzWindowCreateOptions {
 };
 typedef struct AzWindowCreateOptions AzWindowCreateOptions;
 
-enum AzFocusTargetTag {
-   AzFocusTargetTag_Id,
-   AzFocusTargetTag_Path,
-   AzFocusTargetTag_Previous,
-   AzFocusTargetTag_Next,
-   AzFocusTargetTag_First,
-   AzFocusTargetTag_Last,
-   AzFocusTargetTag_NoFocus,
-};
-typedef enum AzFocusTargetTag AzFocusTargetTag;
-
-struct AzFocusTargetVariant_Id { AzFocusTargetTag tag; AzDomNodeId payload; };
-typedef struct AzFocusTargetVariant_Id AzFocusTargetVariant_Id;
-struct AzFocusTargetVariant_Path { AzFocusTargetTag tag; AzFocusTargetPath payload; };
-typedef struct AzFocusTargetVariant_Path AzFocusTargetVariant_Path;
-struct AzFocusTargetVariant_Previous { AzFocusTargetTag tag; };
-typedef struct AzFocusTargetVariant_Previous AzFocusTargetVariant_Previous;
-struct AzFocusTargetVariant_Next { AzFocusTargetTag tag; };
-typedef struct AzFocusTargetVariant_Next AzFocusTargetVariant_Next;
-struct AzFocusTargetVariant_First { AzFocusTargetTag tag; };
-typedef struct AzFocusTargetVariant_First AzFocusTargetVariant_First;
-struct AzFocusTargetVariant_Last { AzFocusTargetTag tag; };
-typedef struct AzFocusTargetVariant_Last AzFocusTargetVariant_Last;
-struct AzFocusTargetVariant_NoFocus { AzFocusTargetTag tag; };
-typedef struct AzFocusTargetVariant_NoFocus AzFocusTargetVariant_NoFocus;
-union AzFocusTarget {
-    AzFocusTargetVariant_Id Id;
-    AzFocusTargetVariant_Path Path;
-    AzFocusTargetVariant_Previous Previous;
-    AzFocusTargetVariant_Next Next;
-    AzFocusTargetVariant_First First;
-    AzFocusTargetVariant_Last Last;
-    AzFocusTargetVariant_NoFocus NoFocus;
-};
-typedef union AzFocusTarget AzFocusTarget;
-
 struct AzNodeData {
     AzNodeType node_type;
     AzOptionRefAny dataset;
@@ -9221,6 +11362,7 @@ typedef struct AzNodeData AzNodeData;
 enum AzCssDeclarationTag {
    AzCssDeclarationTag_Static,
    AzCssDeclarationTag_Dynamic,
+   AzCssDeclarationTag_Custom,
 };
 typedef enum AzCssDeclarationTag AzCssDeclarationTag;
 
@@ -9228,12 +11370,21 @@ struct AzCssDeclarationVariant_Static { AzCssDeclarationTag tag; AzCssProperty p
 typedef struct AzCssDeclarationVariant_Static AzCssDeclarationVariant_Static;
 struct AzCssDeclarationVariant_Dynamic { AzCssDeclarationTag tag; AzDynamicCssProperty payload; };
 typedef struct AzCssDeclarationVariant_Dynamic AzCssDeclarationVariant_Dynamic;
+struct AzCssDeclarationVariant_Custom { AzCssDeclarationTag tag; AzCssCustomProperty payload; };
+typedef struct AzCssDeclarationVariant_Custom AzCssDeclarationVariant_Custom;
 union AzCssDeclaration {
     AzCssDeclarationVariant_Static Static;
     AzCssDeclarationVariant_Dynamic Dynamic;
+    AzCssDeclarationVariant_Custom Custom;
 };
 typedef union AzCssDeclaration AzCssDeclaration;
 
+struct AzCssKeyframes {
+    AzString name;
+    AzCssKeyframeVec keyframes;
+};
+typedef struct AzCssKeyframes AzCssKeyframes;
+
 struct AzButton {
     AzString label;
     AzOptionImageRef image;
@@ -9277,6 +11428,40 @@ struct AzNumberInput {
 };
 typedef struct AzNumberInput AzNumberInput;
 
+struct AzTextEdit {
+    AzTextEditStateWrapper state;
+    AzNodeDataInlineCssPropertyVec container_style;
+    AzNodeDataInlineCssPropertyVec line_numbers_style;
+    AzNodeDataInlineCssPropertyVec label_style;
+};
+typedef struct AzTextEdit AzTextEdit;
+
+struct AzSlider {
+    AzSliderStateWrapper state;
+    AzNodeDataInlineCssPropertyVec container_style;
+    AzNodeDataInlineCssPropertyVec track_style;
+    AzNodeDataInlineCssPropertyVec thumb_style;
+    AzNodeDataInlineCssPropertyVec tick_style;
+    AzNodeDataInlineCssPropertyVec label_style;
+};
+typedef struct AzSlider AzSlider;
+
+struct AzDropDown {
+    AzDropDownStateWrapper state;
+    AzNodeDataInlineCssPropertyVec container_style;
+    AzNodeDataInlineCssPropertyVec label_style;
+    AzNodeDataInlineCssPropertyVec popup_style;
+    AzNodeDataInlineCssPropertyVec entry_style;
+    AzNodeDataInlineCssPropertyVec disabled_entry_style;
+};
+typedef struct AzDropDown AzDropDown;
+
+struct AzSpreadsheet {
+    AzSpreadsheetStateWrapper state;
+    AzNodeDataInlineCssPropertyVec container_style;
+};
+typedef struct AzSpreadsheet AzSpreadsheet;
+
 struct AzCssDeclarationVec {
     AzCssDeclaration* ptr;
     size_t len;
@@ -9285,6 +11470,14 @@ struct AzCssDeclarationVec {
 };
 typedef struct AzCssDeclarationVec AzCssDeclarationVec;
 
+struct AzCssKeyframesVec {
+    AzCssKeyframes* ptr;
+    size_t len;
+    size_t cap;
+    AzCssKeyframesVecDestructor destructor;
+};
+typedef struct AzCssKeyframesVec AzCssKeyframesVec;
+
 struct AzNodeDataVec {
     AzNodeData* ptr;
     size_t len;
@@ -9379,6 +11572,7 @@ typedef struct AzDom AzDom;
 struct AzCssRuleBlock {
     AzCssPath path;
     AzCssDeclarationVec declarations;
+    AzCssMediaQueryVec media_queries;
 };
 typedef struct AzCssRuleBlock AzCssRuleBlock;
 
@@ -9529,6 +11723,8 @@ typedef union AzResultSvgSvgParseError AzResultSvgSvgParseError;
 
 struct AzCss {
     AzStylesheetVec stylesheets;
+    AzCssFontFaceVec font_faces;
+    AzCssKeyframesVec keyframes;
 };
 typedef struct AzCss AzCss;
 
@@ -9537,6 +11733,11 @@ typedef struct AzCss AzCss;
 #define AzTabIndex_Auto { .Auto = { .tag = AzTabIndexTag_Auto } }
 #define AzTabIndex_OverrideInParent(v) { .OverrideInParent = { .tag = AzTabIndexTag_OverrideInParent, .payload = v } }
 #define AzTabIndex_NoKeyboardFocus { .NoKeyboardFocus = { .tag = AzTabIndexTag_NoKeyboardFocus } }
+#define AzGridLine_Auto { .Auto = { .tag = AzGridLineTag_Auto } }
+#define AzGridLine_Line(v) { .Line = { .tag = AzGridLineTag_Line, .payload = v } }
+#define AzGridLine_Span(v) { .Span = { .tag = AzGridLineTag_Span, .payload = v } }
+#define AzStyleAnimationIterationCount_Count(v) { .Count = { .tag = AzStyleAnimationIterationCountTag_Count, .payload = v } }
+#define AzStyleAnimationIterationCount_Infinite { .Infinite = { .tag = AzStyleAnimationIterationCountTag_Infinite } }
 #define AzIndent_None { .None = { .tag = AzIndentTag_None } }
 #define AzIndent_Spaces(v) { .Spaces = { .tag = AzIndentTag_Spaces, .payload = v } }
 #define AzIndent_Tabs { .Tabs = { .tag = AzIndentTag_Tabs } }
@@ -9550,6 +11751,9 @@ typedef struct AzCss AzCss;
 #define AzAccessibilityStateVecDestructor_DefaultRust { .DefaultRust = { .tag = AzAccessibilityStateVecDestructorTag_DefaultRust } }
 #define AzAccessibilityStateVecDestructor_NoDestructor { .NoDestructor = { .tag = AzAccessibilityStateVecDestructorTag_NoDestructor } }
 #define AzAccessibilityStateVecDestructor_External(v) { .External = { .tag = AzAccessibilityStateVecDestructorTag_External, .payload = v } }
+#define AzAccessibilityNodeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzAccessibilityNodeVecDestructorTag_DefaultRust } }
+#define AzAccessibilityNodeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzAccessibilityNodeVecDestructorTag_NoDestructor } }
+#define AzAccessibilityNodeVecDestructor_External(v) { .External = { .tag = AzAccessibilityNodeVecDestructorTag_External, .payload = v } }
 #define AzMenuItemVecDestructor_DefaultRust { .DefaultRust = { .tag = AzMenuItemVecDestructorTag_DefaultRust } }
 #define AzMenuItemVecDestructor_NoDestructor { .NoDestructor = { .tag = AzMenuItemVecDestructorTag_NoDestructor } }
 #define AzMenuItemVecDestructor_External(v) { .External = { .tag = AzMenuItemVecDestructorTag_External, .payload = v } }
@@ -9631,6 +11835,9 @@ typedef struct AzCss AzCss;
 #define AzVirtualKeyCodeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzVirtualKeyCodeVecDestructorTag_DefaultRust } }
 #define AzVirtualKeyCodeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzVirtualKeyCodeVecDestructorTag_NoDestructor } }
 #define AzVirtualKeyCodeVecDestructor_External(v) { .External = { .tag = AzVirtualKeyCodeVecDestructorTag_External, .payload = v } }
+#define AzKeyboardShortcutVecDestructor_DefaultRust { .DefaultRust = { .tag = AzKeyboardShortcutVecDestructorTag_DefaultRust } }
+#define AzKeyboardShortcutVecDestructor_NoDestructor { .NoDestructor = { .tag = AzKeyboardShortcutVecDestructorTag_NoDestructor } }
+#define AzKeyboardShortcutVecDestructor_External(v) { .External = { .tag = AzKeyboardShortcutVecDestructorTag_External, .payload = v } }
 #define AzCascadeInfoVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCascadeInfoVecDestructorTag_DefaultRust } }
 #define AzCascadeInfoVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCascadeInfoVecDestructorTag_NoDestructor } }
 #define AzCascadeInfoVecDestructor_External(v) { .External = { .tag = AzCascadeInfoVecDestructorTag_External, .payload = v } }
@@ -9646,6 +11853,54 @@ typedef struct AzCss AzCss;
 #define AzStylesheetVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStylesheetVecDestructorTag_DefaultRust } }
 #define AzStylesheetVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStylesheetVecDestructorTag_NoDestructor } }
 #define AzStylesheetVecDestructor_External(v) { .External = { .tag = AzStylesheetVecDestructorTag_External, .payload = v } }
+#define AzCssFontFaceVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssFontFaceVecDestructorTag_DefaultRust } }
+#define AzCssFontFaceVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssFontFaceVecDestructorTag_NoDestructor } }
+#define AzCssFontFaceVecDestructor_External(v) { .External = { .tag = AzCssFontFaceVecDestructorTag_External, .payload = v } }
+#define AzStyleTransitionPropertyVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleTransitionPropertyVecDestructorTag_DefaultRust } }
+#define AzStyleTransitionPropertyVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleTransitionPropertyVecDestructorTag_NoDestructor } }
+#define AzStyleTransitionPropertyVecDestructor_External(v) { .External = { .tag = AzStyleTransitionPropertyVecDestructorTag_External, .payload = v } }
+#define AzStyleTransitionDurationVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleTransitionDurationVecDestructorTag_DefaultRust } }
+#define AzStyleTransitionDurationVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleTransitionDurationVecDestructorTag_NoDestructor } }
+#define AzStyleTransitionDurationVecDestructor_External(v) { .External = { .tag = AzStyleTransitionDurationVecDestructorTag_External, .payload = v } }
+#define AzStyleTransitionTimingFunctionVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleTransitionTimingFunctionVecDestructorTag_DefaultRust } }
+#define AzStyleTransitionTimingFunctionVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleTransitionTimingFunctionVecDestructorTag_NoDestructor } }
+#define AzStyleTransitionTimingFunctionVecDestructor_External(v) { .External = { .tag = AzStyleTransitionTimingFunctionVecDestructorTag_External, .payload = v } }
+#define AzStyleTransitionDelayVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleTransitionDelayVecDestructorTag_DefaultRust } }
+#define AzStyleTransitionDelayVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleTransitionDelayVecDestructorTag_NoDestructor } }
+#define AzStyleTransitionDelayVecDestructor_External(v) { .External = { .tag = AzStyleTransitionDelayVecDestructorTag_External, .payload = v } }
+#define AzStyleAnimationNameVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleAnimationNameVecDestructorTag_DefaultRust } }
+#define AzStyleAnimationNameVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleAnimationNameVecDestructorTag_NoDestructor } }
+#define AzStyleAnimationNameVecDestructor_External(v) { .External = { .tag = AzStyleAnimationNameVecDestructorTag_External, .payload = v } }
+#define AzStyleAnimationDurationVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleAnimationDurationVecDestructorTag_DefaultRust } }
+#define AzStyleAnimationDurationVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleAnimationDurationVecDestructorTag_NoDestructor } }
+#define AzStyleAnimationDurationVecDestructor_External(v) { .External = { .tag = AzStyleAnimationDurationVecDestructorTag_External, .payload = v } }
+#define AzStyleAnimationIterationCountVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleAnimationIterationCountVecDestructorTag_DefaultRust } }
+#define AzStyleAnimationIterationCountVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleAnimationIterationCountVecDestructorTag_NoDestructor } }
+#define AzStyleAnimationIterationCountVecDestructor_External(v) { .External = { .tag = AzStyleAnimationIterationCountVecDestructorTag_External, .payload = v } }
+#define AzStyleAnimationDirectionVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleAnimationDirectionVecDestructorTag_DefaultRust } }
+#define AzStyleAnimationDirectionVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleAnimationDirectionVecDestructorTag_NoDestructor } }
+#define AzStyleAnimationDirectionVecDestructor_External(v) { .External = { .tag = AzStyleAnimationDirectionVecDestructorTag_External, .payload = v } }
+#define AzStyleAnimationFillModeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleAnimationFillModeVecDestructorTag_DefaultRust } }
+#define AzStyleAnimationFillModeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleAnimationFillModeVecDestructorTag_NoDestructor } }
+#define AzStyleAnimationFillModeVecDestructor_External(v) { .External = { .tag = AzStyleAnimationFillModeVecDestructorTag_External, .payload = v } }
+#define AzStyleAnimationDelayVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleAnimationDelayVecDestructorTag_DefaultRust } }
+#define AzStyleAnimationDelayVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleAnimationDelayVecDestructorTag_NoDestructor } }
+#define AzStyleAnimationDelayVecDestructor_External(v) { .External = { .tag = AzStyleAnimationDelayVecDestructorTag_External, .payload = v } }
+#define AzCssKeyframeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssKeyframeVecDestructorTag_DefaultRust } }
+#define AzCssKeyframeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssKeyframeVecDestructorTag_NoDestructor } }
+#define AzCssKeyframeVecDestructor_External(v) { .External = { .tag = AzCssKeyframeVecDestructorTag_External, .payload = v } }
+#define AzCssKeyframesVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssKeyframesVecDestructorTag_DefaultRust } }
+#define AzCssKeyframesVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssKeyframesVecDestructorTag_NoDestructor } }
+#define AzCssKeyframesVecDestructor_External(v) { .External = { .tag = AzCssKeyframesVecDestructorTag_External, .payload = v } }
+#define AzGridTrackSizingVecDestructor_DefaultRust { .DefaultRust = { .tag = AzGridTrackSizingVecDestructorTag_DefaultRust } }
+#define AzGridTrackSizingVecDestructor_NoDestructor { .NoDestructor = { .tag = AzGridTrackSizingVecDestructorTag_NoDestructor } }
+#define AzGridTrackSizingVecDestructor_External(v) { .External = { .tag = AzGridTrackSizingVecDestructorTag_External, .payload = v } }
+#define AzCssMediaQueryVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssMediaQueryVecDestructorTag_DefaultRust } }
+#define AzCssMediaQueryVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssMediaQueryVecDestructorTag_NoDestructor } }
+#define AzCssMediaQueryVecDestructor_External(v) { .External = { .tag = AzCssMediaQueryVecDestructorTag_External, .payload = v } }
+#define AzCssMediaConditionVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssMediaConditionVecDestructorTag_DefaultRust } }
+#define AzCssMediaConditionVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssMediaConditionVecDestructorTag_NoDestructor } }
+#define AzCssMediaConditionVecDestructor_External(v) { .External = { .tag = AzCssMediaConditionVecDestructorTag_External, .payload = v } }
 #define AzCssRuleBlockVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssRuleBlockVecDestructorTag_DefaultRust } }
 #define AzCssRuleBlockVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssRuleBlockVecDestructorTag_NoDestructor } }
 #define AzCssRuleBlockVecDestructor_External(v) { .External = { .tag = AzCssRuleBlockVecDestructorTag_External, .payload = v } }
@@ -9700,6 +11955,15 @@ typedef struct AzCss AzCss;
 #define AzNodeDataVecDestructor_DefaultRust { .DefaultRust = { .tag = AzNodeDataVecDestructorTag_DefaultRust } }
 #define AzNodeDataVecDestructor_NoDestructor { .NoDestructor = { .tag = AzNodeDataVecDestructorTag_NoDestructor } }
 #define AzNodeDataVecDestructor_External(v) { .External = { .tag = AzNodeDataVecDestructorTag_External, .payload = v } }
+#define AzTextEditSnapshotVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTextEditSnapshotVecDestructorTag_DefaultRust } }
+#define AzTextEditSnapshotVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTextEditSnapshotVecDestructorTag_NoDestructor } }
+#define AzTextEditSnapshotVecDestructor_External(v) { .External = { .tag = AzTextEditSnapshotVecDestructorTag_External, .payload = v } }
+#define AzSpreadsheetSizeOverrideVecDestructor_DefaultRust { .DefaultRust = { .tag = AzSpreadsheetSizeOverrideVecDestructorTag_DefaultRust } }
+#define AzSpreadsheetSizeOverrideVecDestructor_NoDestructor { .NoDestructor = { .tag = AzSpreadsheetSizeOverrideVecDestructorTag_NoDestructor } }
+#define AzSpreadsheetSizeOverrideVecDestructor_External(v) { .External = { .tag = AzSpreadsheetSizeOverrideVecDestructorTag_External, .payload = v } }
+#define AzSpreadsheetCellContentVecDestructor_DefaultRust { .DefaultRust = { .tag = AzSpreadsheetCellContentVecDestructorTag_DefaultRust } }
+#define AzSpreadsheetCellContentVecDestructor_NoDestructor { .NoDestructor = { .tag = AzSpreadsheetCellContentVecDestructorTag_NoDestructor } }
+#define AzSpreadsheetCellContentVecDestructor_External(v) { .External = { .tag = AzSpreadsheetCellContentVecDestructorTag_External, .payload = v } }
 #define AzOptionI16_None { .None = { .tag = AzOptionI16Tag_None } }
 #define AzOptionI16_Some(v) { .Some = { .tag = AzOptionI16Tag_Some, .payload = v } }
 #define AzOptionU16_None { .None = { .tag = AzOptionU16Tag_None } }
@@ -9750,6 +12014,13 @@ typedef struct AzCss AzCss;
 #define AzCssNthChildSelector_Even { .Even = { .tag = AzCssNthChildSelectorTag_Even } }
 #define AzCssNthChildSelector_Odd { .Odd = { .tag = AzCssNthChildSelectorTag_Odd } }
 #define AzCssNthChildSelector_Pattern(v) { .Pattern = { .tag = AzCssNthChildSelectorTag_Pattern, .payload = v } }
+#define AzGridTrackBreadth_Fixed(v) { .Fixed = { .tag = AzGridTrackBreadthTag_Fixed, .payload = v } }
+#define AzGridTrackBreadth_Fraction(v) { .Fraction = { .tag = AzGridTrackBreadthTag_Fraction, .payload = v } }
+#define AzGridTrackBreadth_Auto { .Auto = { .tag = AzGridTrackBreadthTag_Auto } }
+#define AzGridTrackSizing_Fixed(v) { .Fixed = { .tag = AzGridTrackSizingTag_Fixed, .payload = v } }
+#define AzGridTrackSizing_Fraction(v) { .Fraction = { .tag = AzGridTrackSizingTag_Fraction, .payload = v } }
+#define AzGridTrackSizing_Auto { .Auto = { .tag = AzGridTrackSizingTag_Auto } }
+#define AzGridTrackSizing_MinMax(v) { .MinMax = { .tag = AzGridTrackSizingTag_MinMax, .payload = v } }
 #define AzDirection_Angle(v) { .Angle = { .tag = AzDirectionTag_Angle, .payload = v } }
 #define AzDirection_FromTo(v) { .FromTo = { .tag = AzDirectionTag_FromTo, .payload = v } }
 #define AzBackgroundPositionHorizontal_Left { .Left = { .tag = AzBackgroundPositionHorizontalTag_Left } }
@@ -9763,6 +12034,8 @@ typedef struct AzCss AzCss;
 #define AzStyleBackgroundSize_ExactSize(v) { .ExactSize = { .tag = AzStyleBackgroundSizeTag_ExactSize, .payload = v } }
 #define AzStyleBackgroundSize_Contain { .Contain = { .tag = AzStyleBackgroundSizeTag_Contain } }
 #define AzStyleBackgroundSize_Cover { .Cover = { .tag = AzStyleBackgroundSizeTag_Cover } }
+#define AzStyleTransitionProperty_All { .All = { .tag = AzStyleTransitionPropertyTag_All } }
+#define AzStyleTransitionProperty_Property(v) { .Property = { .tag = AzStyleTransitionPropertyTag_Property, .payload = v } }
 #define AzStyleBoxShadowValue_Auto { .Auto = { .tag = AzStyleBoxShadowValueTag_Auto } }
 #define AzStyleBoxShadowValue_None { .None = { .tag = AzStyleBoxShadowValueTag_None } }
 #define AzStyleBoxShadowValue_Inherit { .Inherit = { .tag = AzStyleBoxShadowValueTag_Inherit } }
@@ -9773,6 +12046,26 @@ typedef struct AzCss AzCss;
 #define AzLayoutAlignContentValue_Inherit { .Inherit = { .tag = AzLayoutAlignContentValueTag_Inherit } }
 #define AzLayoutAlignContentValue_Initial { .Initial = { .tag = AzLayoutAlignContentValueTag_Initial } }
 #define AzLayoutAlignContentValue_Exact(v) { .Exact = { .tag = AzLayoutAlignContentValueTag_Exact, .payload = v } }
+#define AzLayoutGridColumnValue_Auto { .Auto = { .tag = AzLayoutGridColumnValueTag_Auto } }
+#define AzLayoutGridColumnValue_None { .None = { .tag = AzLayoutGridColumnValueTag_None } }
+#define AzLayoutGridColumnValue_Inherit { .Inherit = { .tag = AzLayoutGridColumnValueTag_Inherit } }
+#define AzLayoutGridColumnValue_Initial { .Initial = { .tag = AzLayoutGridColumnValueTag_Initial } }
+#define AzLayoutGridColumnValue_Exact(v) { .Exact = { .tag = AzLayoutGridColumnValueTag_Exact, .payload = v } }
+#define AzLayoutGridRowValue_Auto { .Auto = { .tag = AzLayoutGridRowValueTag_Auto } }
+#define AzLayoutGridRowValue_None { .None = { .tag = AzLayoutGridRowValueTag_None } }
+#define AzLayoutGridRowValue_Inherit { .Inherit = { .tag = AzLayoutGridRowValueTag_Inherit } }
+#define AzLayoutGridRowValue_Initial { .Initial = { .tag = AzLayoutGridRowValueTag_Initial } }
+#define AzLayoutGridRowValue_Exact(v) { .Exact = { .tag = AzLayoutGridRowValueTag_Exact, .payload = v } }
+#define AzLayoutColumnGapValue_Auto { .Auto = { .tag = AzLayoutColumnGapValueTag_Auto } }
+#define AzLayoutColumnGapValue_None { .None = { .tag = AzLayoutColumnGapValueTag_None } }
+#define AzLayoutColumnGapValue_Inherit { .Inherit = { .tag = AzLayoutColumnGapValueTag_Inherit } }
+#define AzLayoutColumnGapValue_Initial { .Initial = { .tag = AzLayoutColumnGapValueTag_Initial } }
+#define AzLayoutColumnGapValue_Exact(v) { .Exact = { .tag = AzLayoutColumnGapValueTag_Exact, .payload = v } }
+#define AzLayoutRowGapValue_Auto { .Auto = { .tag = AzLayoutRowGapValueTag_Auto } }
+#define AzLayoutRowGapValue_None { .None = { .tag = AzLayoutRowGapValueTag_None } }
+#define AzLayoutRowGapValue_Inherit { .Inherit = { .tag = AzLayoutRowGapValueTag_Inherit } }
+#define AzLayoutRowGapValue_Initial { .Initial = { .tag = AzLayoutRowGapValueTag_Initial } }
+#define AzLayoutRowGapValue_Exact(v) { .Exact = { .tag = AzLayoutRowGapValueTag_Exact, .payload = v } }
 #define AzLayoutAlignItemsValue_Auto { .Auto = { .tag = AzLayoutAlignItemsValueTag_Auto } }
 #define AzLayoutAlignItemsValue_None { .None = { .tag = AzLayoutAlignItemsValueTag_None } }
 #define AzLayoutAlignItemsValue_Inherit { .Inherit = { .tag = AzLayoutAlignItemsValueTag_Inherit } }
@@ -9813,6 +12106,11 @@ typedef struct AzCss AzCss;
 #define AzLayoutFloatValue_Inherit { .Inherit = { .tag = AzLayoutFloatValueTag_Inherit } }
 #define AzLayoutFloatValue_Initial { .Initial = { .tag = AzLayoutFloatValueTag_Initial } }
 #define AzLayoutFloatValue_Exact(v) { .Exact = { .tag = AzLayoutFloatValueTag_Exact, .payload = v } }
+#define AzLayoutClearValue_Auto { .Auto = { .tag = AzLayoutClearValueTag_Auto } }
+#define AzLayoutClearValue_None { .None = { .tag = AzLayoutClearValueTag_None } }
+#define AzLayoutClearValue_Inherit { .Inherit = { .tag = AzLayoutClearValueTag_Inherit } }
+#define AzLayoutClearValue_Initial { .Initial = { .tag = AzLayoutClearValueTag_Initial } }
+#define AzLayoutClearValue_Exact(v) { .Exact = { .tag = AzLayoutClearValueTag_Exact, .payload = v } }
 #define AzLayoutHeightValue_Auto { .Auto = { .tag = AzLayoutHeightValueTag_Auto } }
 #define AzLayoutHeightValue_None { .None = { .tag = AzLayoutHeightValueTag_None } }
 #define AzLayoutHeightValue_Inherit { .Inherit = { .tag = AzLayoutHeightValueTag_Inherit } }
@@ -10060,6 +12358,9 @@ typedef struct AzCss AzCss;
 #define AzStyleBackfaceVisibilityValue_Exact(v) { .Exact = { .tag = AzStyleBackfaceVisibilityValueTag_Exact, .payload = v } }
 #define AzTextInputSelection_All { .All = { .tag = AzTextInputSelectionTag_All } }
 #define AzTextInputSelection_FromTo(v) { .FromTo = { .tag = AzTextInputSelectionTag_FromTo, .payload = v } }
+#define AzSpreadsheetDrag_None { .None = { .tag = AzSpreadsheetDragTag_None } }
+#define AzSpreadsheetDrag_SelectCells { .SelectCells = { .tag = AzSpreadsheetDragTag_SelectCells } }
+#define AzSpreadsheetDrag_ResizeColumn(v) { .ResizeColumn = { .tag = AzSpreadsheetDragTag_ResizeColumn, .payload = v } }
 #define AzDuration_System(v) { .System = { .tag = AzDurationTag_System, .payload = v } }
 #define AzDuration_Tick(v) { .Tick = { .tag = AzDurationTag_Tick, .payload = v } }
 #define AzThreadSendMsg_TerminateThread { .TerminateThread = { .tag = AzThreadSendMsgTag_TerminateThread } }
@@ -10081,6 +12382,24 @@ typedef struct AzCss AzCss;
 #define AzOptionTextInputSelection_Some(v) { .Some = { .tag = AzOptionTextInputSelectionTag_Some, .payload = v } }
 #define AzOptionNumberInputOnValueChange_None { .None = { .tag = AzOptionNumberInputOnValueChangeTag_None } }
 #define AzOptionNumberInputOnValueChange_Some(v) { .Some = { .tag = AzOptionNumberInputOnValueChangeTag_Some, .payload = v } }
+#define AzOptionTextEditOnTextChange_None { .None = { .tag = AzOptionTextEditOnTextChangeTag_None } }
+#define AzOptionTextEditOnTextChange_Some(v) { .Some = { .tag = AzOptionTextEditOnTextChangeTag_Some, .payload = v } }
+#define AzOptionTextEditOnFocusLost_None { .None = { .tag = AzOptionTextEditOnFocusLostTag_None } }
+#define AzOptionTextEditOnFocusLost_Some(v) { .Some = { .tag = AzOptionTextEditOnFocusLostTag_Some, .payload = v } }
+#define AzOptionTextEditKind_None { .None = { .tag = AzOptionTextEditKindTag_None } }
+#define AzOptionTextEditKind_Some(v) { .Some = { .tag = AzOptionTextEditKindTag_Some, .payload = v } }
+#define AzOptionSliderOnValueChange_None { .None = { .tag = AzOptionSliderOnValueChangeTag_None } }
+#define AzOptionSliderOnValueChange_Some(v) { .Some = { .tag = AzOptionSliderOnValueChangeTag_Some, .payload = v } }
+#define AzOptionDropDownOnChoiceChange_None { .None = { .tag = AzOptionDropDownOnChoiceChangeTag_None } }
+#define AzOptionDropDownOnChoiceChange_Some(v) { .Some = { .tag = AzOptionDropDownOnChoiceChangeTag_Some, .payload = v } }
+#define AzOptionSpreadsheetOnCellsChange_None { .None = { .tag = AzOptionSpreadsheetOnCellsChangeTag_None } }
+#define AzOptionSpreadsheetOnCellsChange_Some(v) { .Some = { .tag = AzOptionSpreadsheetOnCellsChangeTag_Some, .payload = v } }
+#define AzOptionSpreadsheetOnSelectionChange_None { .None = { .tag = AzOptionSpreadsheetOnSelectionChangeTag_None } }
+#define AzOptionSpreadsheetOnSelectionChange_Some(v) { .Some = { .tag = AzOptionSpreadsheetOnSelectionChangeTag_Some, .payload = v } }
+#define AzOptionSpreadsheetOnColumnResize_None { .None = { .tag = AzOptionSpreadsheetOnColumnResizeTag_None } }
+#define AzOptionSpreadsheetOnColumnResize_Some(v) { .Some = { .tag = AzOptionSpreadsheetOnColumnResizeTag_Some, .payload = v } }
+#define AzOptionSpreadsheetCellSelection_None { .None = { .tag = AzOptionSpreadsheetCellSelectionTag_None } }
+#define AzOptionSpreadsheetCellSelection_Some(v) { .Some = { .tag = AzOptionSpreadsheetCellSelectionTag_Some, .payload = v } }
 #define AzOptionMenuItemIcon_None { .None = { .tag = AzOptionMenuItemIconTag_None } }
 #define AzOptionMenuItemIcon_Some(v) { .Some = { .tag = AzOptionMenuItemIconTag_Some, .payload = v } }
 #define AzOptionMenuCallback_None { .None = { .tag = AzOptionMenuCallbackTag_None } }
@@ -10165,18 +12484,38 @@ typedef struct AzCss AzCss;
 #define AzEventFilter_Window(v) { .Window = { .tag = AzEventFilterTag_Window, .payload = v } }
 #define AzEventFilter_Component(v) { .Component = { .tag = AzEventFilterTag_Component, .payload = v } }
 #define AzEventFilter_Application(v) { .Application = { .tag = AzEventFilterTag_Application, .payload = v } }
+#define AzCssMediaCondition_MinWidth(v) { .MinWidth = { .tag = AzCssMediaConditionTag_MinWidth, .payload = v } }
+#define AzCssMediaCondition_MaxWidth(v) { .MaxWidth = { .tag = AzCssMediaConditionTag_MaxWidth, .payload = v } }
+#define AzCssMediaCondition_MinHeight(v) { .MinHeight = { .tag = AzCssMediaConditionTag_MinHeight, .payload = v } }
+#define AzCssMediaCondition_MaxHeight(v) { .MaxHeight = { .tag = AzCssMediaConditionTag_MaxHeight, .payload = v } }
+#define AzCssMediaCondition_MinResolution(v) { .MinResolution = { .tag = AzCssMediaConditionTag_MinResolution, .payload = v } }
+#define AzCssMediaCondition_MaxResolution(v) { .MaxResolution = { .tag = AzCssMediaConditionTag_MaxResolution, .payload = v } }
+#define AzCssMediaCondition_Orientation(v) { .Orientation = { .tag = AzCssMediaConditionTag_Orientation, .payload = v } }
+#define AzCssMediaCondition_PrefersColorScheme(v) { .PrefersColorScheme = { .tag = AzCssMediaConditionTag_PrefersColorScheme, .payload = v } }
 #define AzCssPathPseudoSelector_First { .First = { .tag = AzCssPathPseudoSelectorTag_First } }
 #define AzCssPathPseudoSelector_Last { .Last = { .tag = AzCssPathPseudoSelectorTag_Last } }
 #define AzCssPathPseudoSelector_NthChild(v) { .NthChild = { .tag = AzCssPathPseudoSelectorTag_NthChild, .payload = v } }
 #define AzCssPathPseudoSelector_Hover { .Hover = { .tag = AzCssPathPseudoSelectorTag_Hover } }
 #define AzCssPathPseudoSelector_Active { .Active = { .tag = AzCssPathPseudoSelectorTag_Active } }
 #define AzCssPathPseudoSelector_Focus { .Focus = { .tag = AzCssPathPseudoSelectorTag_Focus } }
+#define AzCssPathPseudoSelector_FirstChild { .FirstChild = { .tag = AzCssPathPseudoSelectorTag_FirstChild } }
+#define AzCssPathPseudoSelector_LastChild { .LastChild = { .tag = AzCssPathPseudoSelectorTag_LastChild } }
+#define AzCssPathPseudoSelector_OnlyChild { .OnlyChild = { .tag = AzCssPathPseudoSelectorTag_OnlyChild } }
+#define AzCssPathPseudoSelector_NthLastChild(v) { .NthLastChild = { .tag = AzCssPathPseudoSelectorTag_NthLastChild, .payload = v } }
+#define AzCssPathPseudoSelector_Empty { .Empty = { .tag = AzCssPathPseudoSelectorTag_Empty } }
+#define AzCssPathPseudoSelector_FocusWithin { .FocusWithin = { .tag = AzCssPathPseudoSelectorTag_FocusWithin } }
 #define AzAnimationInterpolationFunction_Ease { .Ease = { .tag = AzAnimationInterpolationFunctionTag_Ease } }
 #define AzAnimationInterpolationFunction_Linear { .Linear = { .tag = AzAnimationInterpolationFunctionTag_Linear } }
 #define AzAnimationInterpolationFunction_EaseIn { .EaseIn = { .tag = AzAnimationInterpolationFunctionTag_EaseIn } }
 #define AzAnimationInterpolationFunction_EaseOut { .EaseOut = { .tag = AzAnimationInterpolationFunctionTag_EaseOut } }
 #define AzAnimationInterpolationFunction_EaseInOut { .EaseInOut = { .tag = AzAnimationInterpolationFunctionTag_EaseInOut } }
 #define AzAnimationInterpolationFunction_CubicBezier(v) { .CubicBezier = { .tag = AzAnimationInterpolationFunctionTag_CubicBezier, .payload = v } }
+#define AzStyleTransitionTimingFunction_Ease { .Ease = { .tag = AzStyleTransitionTimingFunctionTag_Ease } }
+#define AzStyleTransitionTimingFunction_Linear { .Linear = { .tag = AzStyleTransitionTimingFunctionTag_Linear } }
+#define AzStyleTransitionTimingFunction_EaseIn { .EaseIn = { .tag = AzStyleTransitionTimingFunctionTag_EaseIn } }
+#define AzStyleTransitionTimingFunction_EaseOut { .EaseOut = { .tag = AzStyleTransitionTimingFunctionTag_EaseOut } }
+#define AzStyleTransitionTimingFunction_EaseInOut { .EaseInOut = { .tag = AzStyleTransitionTimingFunctionTag_EaseInOut } }
+#define AzStyleTransitionTimingFunction_CubicBezier(v) { .CubicBezier = { .tag = AzStyleTransitionTimingFunctionTag_CubicBezier, .payload = v } }
 #define AzStyleTransform_Matrix(v) { .Matrix = { .tag = AzStyleTransformTag_Matrix, .payload = v } }
 #define AzStyleTransform_Matrix3D(v) { .Matrix3D = { .tag = AzStyleTransformTag_Matrix3D, .payload = v } }
 #define AzStyleTransform_Translate(v) { .Translate = { .tag = AzStyleTransformTag_Translate, .payload = v } }
@@ -10198,6 +12537,16 @@ typedef struct AzCss AzCss;
 #define AzStyleTransform_SkewX(v) { .SkewX = { .tag = AzStyleTransformTag_SkewX, .payload = v } }
 #define AzStyleTransform_SkewY(v) { .SkewY = { .tag = AzStyleTransformTag_SkewY, .payload = v } }
 #define AzStyleTransform_Perspective(v) { .Perspective = { .tag = AzStyleTransformTag_Perspective, .payload = v } }
+#define AzLayoutGridTemplateColumnsValue_Auto { .Auto = { .tag = AzLayoutGridTemplateColumnsValueTag_Auto } }
+#define AzLayoutGridTemplateColumnsValue_None { .None = { .tag = AzLayoutGridTemplateColumnsValueTag_None } }
+#define AzLayoutGridTemplateColumnsValue_Inherit { .Inherit = { .tag = AzLayoutGridTemplateColumnsValueTag_Inherit } }
+#define AzLayoutGridTemplateColumnsValue_Initial { .Initial = { .tag = AzLayoutGridTemplateColumnsValueTag_Initial } }
+#define AzLayoutGridTemplateColumnsValue_Exact(v) { .Exact = { .tag = AzLayoutGridTemplateColumnsValueTag_Exact, .payload = v } }
+#define AzLayoutGridTemplateRowsValue_Auto { .Auto = { .tag = AzLayoutGridTemplateRowsValueTag_Auto } }
+#define AzLayoutGridTemplateRowsValue_None { .None = { .tag = AzLayoutGridTemplateRowsValueTag_None } }
+#define AzLayoutGridTemplateRowsValue_Inherit { .Inherit = { .tag = AzLayoutGridTemplateRowsValueTag_Inherit } }
+#define AzLayoutGridTemplateRowsValue_Initial { .Initial = { .tag = AzLayoutGridTemplateRowsValueTag_Initial } }
+#define AzLayoutGridTemplateRowsValue_Exact(v) { .Exact = { .tag = AzLayoutGridTemplateRowsValueTag_Exact, .payload = v } }
 #define AzStyleBackgroundPositionVecValue_Auto { .Auto = { .tag = AzStyleBackgroundPositionVecValueTag_Auto } }
 #define AzStyleBackgroundPositionVecValue_None { .None = { .tag = AzStyleBackgroundPositionVecValueTag_None } }
 #define AzStyleBackgroundPositionVecValue_Inherit { .Inherit = { .tag = AzStyleBackgroundPositionVecValueTag_Inherit } }
@@ -10213,6 +12562,48 @@ typedef struct AzCss AzCss;
 #define AzStyleBackgroundSizeVecValue_Inherit { .Inherit = { .tag = AzStyleBackgroundSizeVecValueTag_Inherit } }
 #define AzStyleBackgroundSizeVecValue_Initial { .Initial = { .tag = AzStyleBackgroundSizeVecValueTag_Initial } }
 #define AzStyleBackgroundSizeVecValue_Exact(v) { .Exact = { .tag = AzStyleBackgroundSizeVecValueTag_Exact, .payload = v } }
+#define AzStyleTransitionPropertyVecValue_Auto { .Auto = { .tag = AzStyleTransitionPropertyVecValueTag_Auto } }
+#define AzStyleTransitionPropertyVecValue_None { .None = { .tag = AzStyleTransitionPropertyVecValueTag_None } }
+#define AzStyleTransitionPropertyVecValue_Inherit { .Inherit = { .tag = AzStyleTransitionPropertyVecValueTag_Inherit } }
+#define AzStyleTransitionPropertyVecValue_Initial { .Initial = { .tag = AzStyleTransitionPropertyVecValueTag_Initial } }
+#define AzStyleTransitionPropertyVecValue_Exact(v) { .Exact = { .tag = AzStyleTransitionPropertyVecValueTag_Exact, .payload = v } }
+#define AzStyleTransitionDurationVecValue_Auto { .Auto = { .tag = AzStyleTransitionDurationVecValueTag_Auto } }
+#define AzStyleTransitionDurationVecValue_None { .None = { .tag = AzStyleTransitionDurationVecValueTag_None } }
+#define AzStyleTransitionDurationVecValue_Inherit { .Inherit = { .tag = AzStyleTransitionDurationVecValueTag_Inherit } }
+#define AzStyleTransitionDurationVecValue_Initial { .Initial = { .tag = AzStyleTransitionDurationVecValueTag_Initial } }
+#define AzStyleTransitionDurationVecValue_Exact(v) { .Exact = { .tag = AzStyleTransitionDurationVecValueTag_Exact, .payload = v } }
+#define AzStyleTransitionDelayVecValue_Auto { .Auto = { .tag = AzStyleTransitionDelayVecValueTag_Auto } }
+#define AzStyleTransitionDelayVecValue_None { .None = { .tag = AzStyleTransitionDelayVecValueTag_None } }
+#define AzStyleTransitionDelayVecValue_Inherit { .Inherit = { .tag = AzStyleTransitionDelayVecValueTag_Inherit } }
+#define AzStyleTransitionDelayVecValue_Initial { .Initial = { .tag = AzStyleTransitionDelayVecValueTag_Initial } }
+#define AzStyleTransitionDelayVecValue_Exact(v) { .Exact = { .tag = AzStyleTransitionDelayVecValueTag_Exact, .payload = v } }
+#define AzStyleAnimationDurationVecValue_Auto { .Auto = { .tag = AzStyleAnimationDurationVecValueTag_Auto } }
+#define AzStyleAnimationDurationVecValue_None { .None = { .tag = AzStyleAnimationDurationVecValueTag_None } }
+#define AzStyleAnimationDurationVecValue_Inherit { .Inherit = { .tag = AzStyleAnimationDurationVecValueTag_Inherit } }
+#define AzStyleAnimationDurationVecValue_Initial { .Initial = { .tag = AzStyleAnimationDurationVecValueTag_Initial } }
+#define AzStyleAnimationDurationVecValue_Exact(v) { .Exact = { .tag = AzStyleAnimationDurationVecValueTag_Exact, .payload = v } }
+#define AzStyleAnimationIterationCountVecValue_Auto { .Auto = { .tag = AzStyleAnimationIterationCountVecValueTag_Auto } }
+#define AzStyleAnimationIterationCountVecValue_None { .None = { .tag = AzStyleAnimationIterationCountVecValueTag_None } }
+#define AzStyleAnimationIterationCountVecValue_Inherit { .Inherit = { .tag = AzStyleAnimationIterationCountVecValueTag_Inherit } }
+#define AzStyleAnimationIterationCountVecValue_Initial { .Initial = { .tag = AzStyleAnimationIterationCountVecValueTag_Initial } }
+#define AzStyleAnimationIterationCountVecValue_Exact(v) { .Exact = { .tag = AzStyleAnimationIterationCountVecValueTag_Exact, .payload = v } }
+#define AzStyleAnimationDirectionVecValue_Auto { .Auto = { .tag = AzStyleAnimationDirectionVecValueTag_Auto } }
+#define AzStyleAnimationDirectionVecValue_None { .None = { .tag = AzStyleAnimationDirectionVecValueTag_None } }
+#define AzStyleAnimationDirectionVecValue_Inherit { .Inherit = { .tag = AzStyleAnimationDirectionVecValueTag_Inherit } }
+#define AzStyleAnimationDirectionVecValue_Initial { .Initial = { .tag = AzStyleAnimationDirectionVecValueTag_Initial } }
+#define AzStyleAnimationDirectionVecValue_Exact(v) { .Exact = { .tag = AzStyleAnimationDirectionVecValueTag_Exact, .payload = v } }
+#define AzStyleAnimationFillModeVecValue_Auto { .Auto = { .tag = AzStyleAnimationFillModeVecValueTag_Auto } }
+#define AzStyleAnimationFillModeVecValue_None { .None = { .tag = AzStyleAnimationFillModeVecValueTag_None } }
+#define AzStyleAnimationFillModeVecValue_Inherit { .Inherit = { .tag = AzStyleAnimationFillModeVecValueTag_Inherit } }
+#define AzStyleAnimationFillModeVecValue_Initial { .Initial = { .tag = AzStyleAnimationFillModeVecValueTag_Initial } }
+#define AzStyleAnimationFillModeVecValue_Exact(v) { .Exact = { .tag = AzStyleAnimationFillModeVecValueTag_Exact, .payload = v } }
+#define AzStyleAnimationDelayVecValue_Auto { .Auto = { .tag = AzStyleAnimationDelayVecValueTag_Auto } }
+#define AzStyleAnimationDelayVecValue_None { .None = { .tag = AzStyleAnimationDelayVecValueTag_None } }
+#define AzStyleAnimationDelayVecValue_Inherit { .Inherit = { .tag = AzStyleAnimationDelayVecValueTag_Inherit } }
+#define AzStyleAnimationDelayVecValue_Initial { .Initial = { .tag = AzStyleAnimationDelayVecValueTag_Initial } }
+#define AzStyleAnimationDelayVecValue_Exact(v) { .Exact = { .tag = AzStyleAnimationDelayVecValueTag_Exact, .payload = v } }
+#define AzCssPropertySource_Css(v) { .Css = { .tag = AzCssPropertySourceTag_Css, .payload = v } }
+#define AzCssPropertySource_Inline { .Inline = { .tag = AzCssPropertySourceTag_Inline } }
 #define AzRawImageData_U8(v) { .U8 = { .tag = AzRawImageDataTag_U8, .payload = v } }
 #define AzRawImageData_U16(v) { .U16 = { .tag = AzRawImageDataTag_U16, .payload = v } }
 #define AzRawImageData_F32(v) { .F32 = { .tag = AzRawImageDataTag_F32, .payload = v } }
@@ -10257,13 +12648,9 @@ typedef struct AzCss AzCss;
 #define AzNodeType_IFrame(v) { .IFrame = { .tag = AzNodeTypeTag_IFrame, .payload = v } }
 #define AzIdOrClass_Id(v) { .Id = { .tag = AzIdOrClassTag_Id, .payload = v } }
 #define AzIdOrClass_Class(v) { .Class = { .tag = AzIdOrClassTag_Class, .payload = v } }
-#define AzCssPathSelector_Global { .Global = { .tag = AzCssPathSelectorTag_Global } }
-#define AzCssPathSelector_Type(v) { .Type = { .tag = AzCssPathSelectorTag_Type, .payload = v } }
-#define AzCssPathSelector_Class(v) { .Class = { .tag = AzCssPathSelectorTag_Class, .payload = v } }
-#define AzCssPathSelector_Id(v) { .Id = { .tag = AzCssPathSelectorTag_Id, .payload = v } }
-#define AzCssPathSelector_PseudoSelector(v) { .PseudoSelector = { .tag = AzCssPathSelectorTag_PseudoSelector, .payload = v } }
-#define AzCssPathSelector_DirectChildren { .DirectChildren = { .tag = AzCssPathSelectorTag_DirectChildren } }
-#define AzCssPathSelector_Children { .Children = { .tag = AzCssPathSelectorTag_Children } }
+#define AzShortcutConflict_Shortcut(v) { .Shortcut = { .tag = AzShortcutConflictTag_Shortcut, .payload = v } }
+#define AzShortcutConflict_MenuItem(v) { .MenuItem = { .tag = AzShortcutConflictTag_MenuItem, .payload = v } }
+#define AzShortcutConflict_ContextMenuItem(v) { .ContextMenuItem = { .tag = AzShortcutConflictTag_ContextMenuItem, .payload = v } }
 #define AzStyleBackgroundContent_LinearGradient(v) { .LinearGradient = { .tag = AzStyleBackgroundContentTag_LinearGradient, .payload = v } }
 #define AzStyleBackgroundContent_RadialGradient(v) { .RadialGradient = { .tag = AzStyleBackgroundContentTag_RadialGradient, .payload = v } }
 #define AzStyleBackgroundContent_ConicGradient(v) { .ConicGradient = { .tag = AzStyleBackgroundContentTag_ConicGradient, .payload = v } }
@@ -10282,6 +12669,11 @@ typedef struct AzCss AzCss;
 #define AzStyleTransformVecValue_Inherit { .Inherit = { .tag = AzStyleTransformVecValueTag_Inherit } }
 #define AzStyleTransformVecValue_Initial { .Initial = { .tag = AzStyleTransformVecValueTag_Initial } }
 #define AzStyleTransformVecValue_Exact(v) { .Exact = { .tag = AzStyleTransformVecValueTag_Exact, .payload = v } }
+#define AzStyleTransitionTimingFunctionVecValue_Auto { .Auto = { .tag = AzStyleTransitionTimingFunctionVecValueTag_Auto } }
+#define AzStyleTransitionTimingFunctionVecValue_None { .None = { .tag = AzStyleTransitionTimingFunctionVecValueTag_None } }
+#define AzStyleTransitionTimingFunctionVecValue_Inherit { .Inherit = { .tag = AzStyleTransitionTimingFunctionVecValueTag_Inherit } }
+#define AzStyleTransitionTimingFunctionVecValue_Initial { .Initial = { .tag = AzStyleTransitionTimingFunctionVecValueTag_Initial } }
+#define AzStyleTransitionTimingFunctionVecValue_Exact(v) { .Exact = { .tag = AzStyleTransitionTimingFunctionVecValueTag_Exact, .payload = v } }
 #define AzSvgStyle_Fill(v) { .Fill = { .tag = AzSvgStyleTag_Fill, .payload = v } }
 #define AzSvgStyle_Stroke(v) { .Stroke = { .tag = AzSvgStyleTag_Stroke, .payload = v } }
 #define AzFmtValue_Bool(v) { .Bool = { .tag = AzFmtValueTag_Bool, .payload = v } }
@@ -10299,6 +12691,12 @@ typedef struct AzCss AzCss;
 #define AzFmtValue_Double(v) { .Double = { .tag = AzFmtValueTag_Double, .payload = v } }
 #define AzFmtValue_Str(v) { .Str = { .tag = AzFmtValueTag_Str, .payload = v } }
 #define AzFmtValue_StrVec(v) { .StrVec = { .tag = AzFmtValueTag_StrVec, .payload = v } }
+#define AzOptionSpreadsheetCellClick_None { .None = { .tag = AzOptionSpreadsheetCellClickTag_None } }
+#define AzOptionSpreadsheetCellClick_Some(v) { .Some = { .tag = AzOptionSpreadsheetCellClickTag_Some, .payload = v } }
+#define AzOptionSpreadsheetCellEdit_None { .None = { .tag = AzOptionSpreadsheetCellEditTag_None } }
+#define AzOptionSpreadsheetCellEdit_Some(v) { .Some = { .tag = AzOptionSpreadsheetCellEditTag_Some, .payload = v } }
+#define AzOptionAccessibilityNode_None { .None = { .tag = AzOptionAccessibilityNodeTag_None } }
+#define AzOptionAccessibilityNode_Some(v) { .Some = { .tag = AzOptionAccessibilityNodeTag_Some, .payload = v } }
 #define AzOptionFileTypeList_None { .None = { .tag = AzOptionFileTypeListTag_None } }
 #define AzOptionFileTypeList_Some(v) { .Some = { .tag = AzOptionFileTypeListTag_Some, .payload = v } }
 #define AzOptionFile_None { .None = { .tag = AzOptionFileTag_None } }
@@ -10322,9 +12720,27 @@ typedef struct AzCss AzCss;
 #define AzXmlStreamError_InvalidCommentData { .InvalidCommentData = { .tag = AzXmlStreamErrorTag_InvalidCommentData } }
 #define AzXmlStreamError_InvalidCommentEnd { .InvalidCommentEnd = { .tag = AzXmlStreamErrorTag_InvalidCommentEnd } }
 #define AzXmlStreamError_InvalidCharacterData { .InvalidCharacterData = { .tag = AzXmlStreamErrorTag_InvalidCharacterData } }
+#define AzFocusTarget_Id(v) { .Id = { .tag = AzFocusTargetTag_Id, .payload = v } }
+#define AzFocusTarget_Path(v) { .Path = { .tag = AzFocusTargetTag_Path, .payload = v } }
+#define AzFocusTarget_Previous { .Previous = { .tag = AzFocusTargetTag_Previous } }
+#define AzFocusTarget_Next { .Next = { .tag = AzFocusTargetTag_Next } }
+#define AzFocusTarget_First { .First = { .tag = AzFocusTargetTag_First } }
+#define AzFocusTarget_Last { .Last = { .tag = AzFocusTargetTag_Last } }
+#define AzFocusTarget_NoFocus { .NoFocus = { .tag = AzFocusTargetTag_NoFocus } }
 #define AzMenuItem_Label(v) { .Label = { .tag = AzMenuItemTag_Label, .payload = v } }
 #define AzMenuItem_Separator { .Separator = { .tag = AzMenuItemTag_Separator } }
 #define AzMenuItem_BreakLine { .BreakLine = { .tag = AzMenuItemTag_BreakLine } }
+#define AzCssPathSelector_Global { .Global = { .tag = AzCssPathSelectorTag_Global } }
+#define AzCssPathSelector_Type(v) { .Type = { .tag = AzCssPathSelectorTag_Type, .payload = v } }
+#define AzCssPathSelector_Class(v) { .Class = { .tag = AzCssPathSelectorTag_Class, .payload = v } }
+#define AzCssPathSelector_Id(v) { .Id = { .tag = AzCssPathSelectorTag_Id, .payload = v } }
+#define AzCssPathSelector_PseudoSelector(v) { .PseudoSelector = { .tag = AzCssPathSelectorTag_PseudoSelector, .payload = v } }
+#define AzCssPathSelector_Attribute(v) { .Attribute = { .tag = AzCssPathSelectorTag_Attribute, .payload = v } }
+#define AzCssPathSelector_Not(v) { .Not = { .tag = AzCssPathSelectorTag_Not, .payload = v } }
+#define AzCssPathSelector_DirectChildren { .DirectChildren = { .tag = AzCssPathSelectorTag_DirectChildren } }
+#define AzCssPathSelector_Children { .Children = { .tag = AzCssPathSelectorTag_Children } }
+#define AzCssPathSelector_AdjacentSibling { .AdjacentSibling = { .tag = AzCssPathSelectorTag_AdjacentSibling } }
+#define AzCssPathSelector_GeneralSibling { .GeneralSibling = { .tag = AzCssPathSelectorTag_GeneralSibling } }
 #define AzStyleBackgroundContentVecValue_Auto { .Auto = { .tag = AzStyleBackgroundContentVecValueTag_Auto } }
 #define AzStyleBackgroundContentVecValue_None { .None = { .tag = AzStyleBackgroundContentVecValueTag_None } }
 #define AzStyleBackgroundContentVecValue_Inherit { .Inherit = { .tag = AzStyleBackgroundContentVecValueTag_Inherit } }
@@ -10335,6 +12751,11 @@ typedef struct AzCss AzCss;
 #define AzStyleFontFamilyVecValue_Inherit { .Inherit = { .tag = AzStyleFontFamilyVecValueTag_Inherit } }
 #define AzStyleFontFamilyVecValue_Initial { .Initial = { .tag = AzStyleFontFamilyVecValueTag_Initial } }
 #define AzStyleFontFamilyVecValue_Exact(v) { .Exact = { .tag = AzStyleFontFamilyVecValueTag_Exact, .payload = v } }
+#define AzStyleAnimationNameVecValue_Auto { .Auto = { .tag = AzStyleAnimationNameVecValueTag_Auto } }
+#define AzStyleAnimationNameVecValue_None { .None = { .tag = AzStyleAnimationNameVecValueTag_None } }
+#define AzStyleAnimationNameVecValue_Inherit { .Inherit = { .tag = AzStyleAnimationNameVecValueTag_Inherit } }
+#define AzStyleAnimationNameVecValue_Initial { .Initial = { .tag = AzStyleAnimationNameVecValueTag_Initial } }
+#define AzStyleAnimationNameVecValue_Exact(v) { .Exact = { .tag = AzStyleAnimationNameVecValueTag_Exact, .payload = v } }
 #define AzCssProperty_TextColor(v) { .TextColor = { .tag = AzCssPropertyTag_TextColor, .payload = v } }
 #define AzCssProperty_FontSize(v) { .FontSize = { .tag = AzCssPropertyTag_FontSize, .payload = v } }
 #define AzCssProperty_FontFamily(v) { .FontFamily = { .tag = AzCssPropertyTag_FontFamily, .payload = v } }
@@ -10346,6 +12767,7 @@ typedef struct AzCss AzCss;
 #define AzCssProperty_Cursor(v) { .Cursor = { .tag = AzCssPropertyTag_Cursor, .payload = v } }
 #define AzCssProperty_Display(v) { .Display = { .tag = AzCssPropertyTag_Display, .payload = v } }
 #define AzCssProperty_Float(v) { .Float = { .tag = AzCssPropertyTag_Float, .payload = v } }
+#define AzCssProperty_Clear(v) { .Clear = { .tag = AzCssPropertyTag_Clear, .payload = v } }
 #define AzCssProperty_BoxSizing(v) { .BoxSizing = { .tag = AzCssPropertyTag_BoxSizing, .payload = v } }
 #define AzCssProperty_Width(v) { .Width = { .tag = AzCssPropertyTag_Width, .payload = v } }
 #define AzCssProperty_Height(v) { .Height = { .tag = AzCssPropertyTag_Height, .payload = v } }
@@ -10365,6 +12787,12 @@ typedef struct AzCss AzCss;
 #define AzCssProperty_JustifyContent(v) { .JustifyContent = { .tag = AzCssPropertyTag_JustifyContent, .payload = v } }
 #define AzCssProperty_AlignItems(v) { .AlignItems = { .tag = AzCssPropertyTag_AlignItems, .payload = v } }
 #define AzCssProperty_AlignContent(v) { .AlignContent = { .tag = AzCssPropertyTag_AlignContent, .payload = v } }
+#define AzCssProperty_GridTemplateColumns(v) { .GridTemplateColumns = { .tag = AzCssPropertyTag_GridTemplateColumns, .payload = v } }
+#define AzCssProperty_GridTemplateRows(v) { .GridTemplateRows = { .tag = AzCssPropertyTag_GridTemplateRows, .payload = v } }
+#define AzCssProperty_GridColumn(v) { .GridColumn = { .tag = AzCssPropertyTag_GridColumn, .payload = v } }
+#define AzCssProperty_GridRow(v) { .GridRow = { .tag = AzCssPropertyTag_GridRow, .payload = v } }
+#define AzCssProperty_ColumnGap(v) { .ColumnGap = { .tag = AzCssPropertyTag_ColumnGap, .payload = v } }
+#define AzCssProperty_RowGap(v) { .RowGap = { .tag = AzCssPropertyTag_RowGap, .payload = v } }
 #define AzCssProperty_BackgroundContent(v) { .BackgroundContent = { .tag = AzCssPropertyTag_BackgroundContent, .payload = v } }
 #define AzCssProperty_BackgroundPosition(v) { .BackgroundPosition = { .tag = AzCssPropertyTag_BackgroundPosition, .payload = v } }
 #define AzCssProperty_BackgroundSize(v) { .BackgroundSize = { .tag = AzCssPropertyTag_BackgroundSize, .payload = v } }
@@ -10405,8 +12833,17 @@ typedef struct AzCss AzCss;
 #define AzCssProperty_TransformOrigin(v) { .TransformOrigin = { .tag = AzCssPropertyTag_TransformOrigin, .payload = v } }
 #define AzCssProperty_PerspectiveOrigin(v) { .PerspectiveOrigin = { .tag = AzCssPropertyTag_PerspectiveOrigin, .payload = v } }
 #define AzCssProperty_BackfaceVisibility(v) { .BackfaceVisibility = { .tag = AzCssPropertyTag_BackfaceVisibility, .payload = v } }
-#define AzCssPropertySource_Css(v) { .Css = { .tag = AzCssPropertySourceTag_Css, .payload = v } }
-#define AzCssPropertySource_Inline { .Inline = { .tag = AzCssPropertySourceTag_Inline } }
+#define AzCssProperty_TransitionProperty(v) { .TransitionProperty = { .tag = AzCssPropertyTag_TransitionProperty, .payload = v } }
+#define AzCssProperty_TransitionDuration(v) { .TransitionDuration = { .tag = AzCssPropertyTag_TransitionDuration, .payload = v } }
+#define AzCssProperty_TransitionTimingFunction(v) { .TransitionTimingFunction = { .tag = AzCssPropertyTag_TransitionTimingFunction, .payload = v } }
+#define AzCssProperty_TransitionDelay(v) { .TransitionDelay = { .tag = AzCssPropertyTag_TransitionDelay, .payload = v } }
+#define AzCssProperty_AnimationName(v) { .AnimationName = { .tag = AzCssPropertyTag_AnimationName, .payload = v } }
+#define AzCssProperty_AnimationDuration(v) { .AnimationDuration = { .tag = AzCssPropertyTag_AnimationDuration, .payload = v } }
+#define AzCssProperty_AnimationTimingFunction(v) { .AnimationTimingFunction = { .tag = AzCssPropertyTag_AnimationTimingFunction, .payload = v } }
+#define AzCssProperty_AnimationIterationCount(v) { .AnimationIterationCount = { .tag = AzCssPropertyTag_AnimationIterationCount, .payload = v } }
+#define AzCssProperty_AnimationDirection(v) { .AnimationDirection = { .tag = AzCssPropertyTag_AnimationDirection, .payload = v } }
+#define AzCssProperty_AnimationFillMode(v) { .AnimationFillMode = { .tag = AzCssPropertyTag_AnimationFillMode, .payload = v } }
+#define AzCssProperty_AnimationDelay(v) { .AnimationDelay = { .tag = AzCssPropertyTag_AnimationDelay, .payload = v } }
 #define AzOptionCssProperty_None { .None = { .tag = AzOptionCssPropertyTag_None } }
 #define AzOptionCssProperty_Some(v) { .Some = { .tag = AzOptionCssPropertyTag_Some, .payload = v } }
 #define AzNodeDataInlineCssProperty_Normal(v) { .Normal = { .tag = AzNodeDataInlineCssPropertyTag_Normal, .payload = v } }
@@ -10432,15 +12869,9 @@ typedef struct AzCss AzCss;
 #define AzXmlParseError_InvalidCdata(v) { .InvalidCdata = { .tag = AzXmlParseErrorTag_InvalidCdata, .payload = v } }
 #define AzXmlParseError_InvalidCharData(v) { .InvalidCharData = { .tag = AzXmlParseErrorTag_InvalidCharData, .payload = v } }
 #define AzXmlParseError_UnknownToken(v) { .UnknownToken = { .tag = AzXmlParseErrorTag_UnknownToken, .payload = v } }
-#define AzFocusTarget_Id(v) { .Id = { .tag = AzFocusTargetTag_Id, .payload = v } }
-#define AzFocusTarget_Path(v) { .Path = { .tag = AzFocusTargetTag_Path, .payload = v } }
-#define AzFocusTarget_Previous { .Previous = { .tag = AzFocusTargetTag_Previous } }
-#define AzFocusTarget_Next { .Next = { .tag = AzFocusTargetTag_Next } }
-#define AzFocusTarget_First { .First = { .tag = AzFocusTargetTag_First } }
-#define AzFocusTarget_Last { .Last = { .tag = AzFocusTargetTag_Last } }
-#define AzFocusTarget_NoFocus { .NoFocus = { .tag = AzFocusTargetTag_NoFocus } }
 #define AzCssDeclaration_Static(v) { .Static = { .tag = AzCssDeclarationTag_Static, .payload = v } }
 #define AzCssDeclaration_Dynamic(v) { .Dynamic = { .tag = AzCssDeclarationTag_Dynamic, .payload = v } }
+#define AzCssDeclaration_Custom(v) { .Custom = { .tag = AzCssDeclarationTag_Custom, .payload = v } }
 #define AzXmlError_NoParserAvailable { .NoParserAvailable = { .tag = AzXmlErrorTag_NoParserAvailable } }
 #define AzXmlError_InvalidXmlPrefixUri(v) { .InvalidXmlPrefixUri = { .tag = AzXmlErrorTag_InvalidXmlPrefixUri, .payload = v } }
 #define AzXmlError_UnexpectedXmlUri(v) { .UnexpectedXmlUri = { .tag = AzXmlErrorTag_UnexpectedXmlUri, .payload = v } }
@@ -10477,6 +12908,10 @@ AzAccessibilityState AzAccessibilityStateVecArray[] = {};
 #define AzAccessibilityStateVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzAccessibilityState), .cap = sizeof(v) / sizeof(AzAccessibilityState), .destructor = { .NoDestructor = { .tag = AzAccessibilityStateVecDestructorTag_NoDestructor, }, }, }
 #define AzAccessibilityStateVec_empty { .ptr = &AzAccessibilityStateVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzAccessibilityStateVecDestructorTag_NoDestructor, }, }, }
 
+AzAccessibilityNode AzAccessibilityNodeVecArray[] = {};
+#define AzAccessibilityNodeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzAccessibilityNode), .cap = sizeof(v) / sizeof(AzAccessibilityNode), .destructor = { .NoDestructor = { .tag = AzAccessibilityNodeVecDestructorTag_NoDestructor, }, }, }
+#define AzAccessibilityNodeVec_empty { .ptr = &AzAccessibilityNodeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzAccessibilityNodeVecDestructorTag_NoDestructor, }, }, }
+
 AzMenuItem AzMenuItemVecArray[] = {};
 #define AzMenuItemVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzMenuItem), .cap = sizeof(v) / sizeof(AzMenuItem), .destructor = { .NoDestructor = { .tag = AzMenuItemVecDestructorTag_NoDestructor, }, }, }
 #define AzMenuItemVec_empty { .ptr = &AzMenuItemVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzMenuItemVecDestructorTag_NoDestructor, }, }, }
@@ -10589,6 +13024,10 @@ AzVirtualKeyCode AzVirtualKeyCodeVecArray[] = {};
 #define AzVirtualKeyCodeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzVirtualKeyCode), .cap = sizeof(v) / sizeof(AzVirtualKeyCode), .destructor = { .NoDestructor = { .tag = AzVirtualKeyCodeVecDestructorTag_NoDestructor, }, }, }
 #define AzVirtualKeyCodeVec_empty { .ptr = &AzVirtualKeyCodeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzVirtualKeyCodeVecDestructorTag_NoDestructor, }, }, }
 
+AzKeyboardShortcut AzKeyboardShortcutVecArray[] = {};
+#define AzKeyboardShortcutVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzKeyboardShortcut), .cap = sizeof(v) / sizeof(AzKeyboardShortcut), .destructor = { .NoDestructor = { .tag = AzKeyboardShortcutVecDestructorTag_NoDestructor, }, }, }
+#define AzKeyboardShortcutVec_empty { .ptr = &AzKeyboardShortcutVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzKeyboardShortcutVecDestructorTag_NoDestructor, }, }, }
+
 AzCascadeInfo AzCascadeInfoVecArray[] = {};
 #define AzCascadeInfoVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCascadeInfo), .cap = sizeof(v) / sizeof(AzCascadeInfo), .destructor = { .NoDestructor = { .tag = AzCascadeInfoVecDestructorTag_NoDestructor, }, }, }
 #define AzCascadeInfoVec_empty { .ptr = &AzCascadeInfoVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCascadeInfoVecDestructorTag_NoDestructor, }, }, }
@@ -10609,6 +13048,70 @@ AzStylesheet AzStylesheetVecArray[] = {};
 #define AzStylesheetVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStylesheet), .cap = sizeof(v) / sizeof(AzStylesheet), .destructor = { .NoDestructor = { .tag = AzStylesheetVecDestructorTag_NoDestructor, }, }, }
 #define AzStylesheetVec_empty { .ptr = &AzStylesheetVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStylesheetVecDestructorTag_NoDestructor, }, }, }
 
+AzCssMediaQuery AzCssMediaQueryVecArray[] = {};
+#define AzCssMediaQueryVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssMediaQuery), .cap = sizeof(v) / sizeof(AzCssMediaQuery), .destructor = { .NoDestructor = { .tag = AzCssMediaQueryVecDestructorTag_NoDestructor, }, }, }
+#define AzCssMediaQueryVec_empty { .ptr = &AzCssMediaQueryVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssMediaQueryVecDestructorTag_NoDestructor, }, }, }
+
+AzCssMediaCondition AzCssMediaConditionVecArray[] = {};
+#define AzCssMediaConditionVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssMediaCondition), .cap = sizeof(v) / sizeof(AzCssMediaCondition), .destructor = { .NoDestructor = { .tag = AzCssMediaConditionVecDestructorTag_NoDestructor, }, }, }
+#define AzCssMediaConditionVec_empty { .ptr = &AzCssMediaConditionVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssMediaConditionVecDestructorTag_NoDestructor, }, }, }
+
+AzCssFontFace AzCssFontFaceVecArray[] = {};
+#define AzCssFontFaceVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssFontFace), .cap = sizeof(v) / sizeof(AzCssFontFace), .destructor = { .NoDestructor = { .tag = AzCssFontFaceVecDestructorTag_NoDestructor, }, }, }
+#define AzCssFontFaceVec_empty { .ptr = &AzCssFontFaceVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssFontFaceVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleTransitionProperty AzStyleTransitionPropertyVecArray[] = {};
+#define AzStyleTransitionPropertyVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleTransitionProperty), .cap = sizeof(v) / sizeof(AzStyleTransitionProperty), .destructor = { .NoDestructor = { .tag = AzStyleTransitionPropertyVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleTransitionPropertyVec_empty { .ptr = &AzStyleTransitionPropertyVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleTransitionPropertyVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleTransitionDuration AzStyleTransitionDurationVecArray[] = {};
+#define AzStyleTransitionDurationVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleTransitionDuration), .cap = sizeof(v) / sizeof(AzStyleTransitionDuration), .destructor = { .NoDestructor = { .tag = AzStyleTransitionDurationVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleTransitionDurationVec_empty { .ptr = &AzStyleTransitionDurationVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleTransitionDurationVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleTransitionTimingFunction AzStyleTransitionTimingFunctionVecArray[] = {};
+#define AzStyleTransitionTimingFunctionVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleTransitionTimingFunction), .cap = sizeof(v) / sizeof(AzStyleTransitionTimingFunction), .destructor = { .NoDestructor = { .tag = AzStyleTransitionTimingFunctionVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleTransitionTimingFunctionVec_empty { .ptr = &AzStyleTransitionTimingFunctionVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleTransitionTimingFunctionVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleTransitionDelay AzStyleTransitionDelayVecArray[] = {};
+#define AzStyleTransitionDelayVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleTransitionDelay), .cap = sizeof(v) / sizeof(AzStyleTransitionDelay), .destructor = { .NoDestructor = { .tag = AzStyleTransitionDelayVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleTransitionDelayVec_empty { .ptr = &AzStyleTransitionDelayVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleTransitionDelayVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleAnimationName AzStyleAnimationNameVecArray[] = {};
+#define AzStyleAnimationNameVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleAnimationName), .cap = sizeof(v) / sizeof(AzStyleAnimationName), .destructor = { .NoDestructor = { .tag = AzStyleAnimationNameVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleAnimationNameVec_empty { .ptr = &AzStyleAnimationNameVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleAnimationNameVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleAnimationDuration AzStyleAnimationDurationVecArray[] = {};
+#define AzStyleAnimationDurationVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleAnimationDuration), .cap = sizeof(v) / sizeof(AzStyleAnimationDuration), .destructor = { .NoDestructor = { .tag = AzStyleAnimationDurationVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleAnimationDurationVec_empty { .ptr = &AzStyleAnimationDurationVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleAnimationDurationVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleAnimationIterationCount AzStyleAnimationIterationCountVecArray[] = {};
+#define AzStyleAnimationIterationCountVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleAnimationIterationCount), .cap = sizeof(v) / sizeof(AzStyleAnimationIterationCount), .destructor = { .NoDestructor = { .tag = AzStyleAnimationIterationCountVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleAnimationIterationCountVec_empty { .ptr = &AzStyleAnimationIterationCountVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleAnimationIterationCountVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleAnimationDirection AzStyleAnimationDirectionVecArray[] = {};
+#define AzStyleAnimationDirectionVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleAnimationDirection), .cap = sizeof(v) / sizeof(AzStyleAnimationDirection), .destructor = { .NoDestructor = { .tag = AzStyleAnimationDirectionVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleAnimationDirectionVec_empty { .ptr = &AzStyleAnimationDirectionVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleAnimationDirectionVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleAnimationFillMode AzStyleAnimationFillModeVecArray[] = {};
+#define AzStyleAnimationFillModeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleAnimationFillMode), .cap = sizeof(v) / sizeof(AzStyleAnimationFillMode), .destructor = { .NoDestructor = { .tag = AzStyleAnimationFillModeVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleAnimationFillModeVec_empty { .ptr = &AzStyleAnimationFillModeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleAnimationFillModeVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleAnimationDelay AzStyleAnimationDelayVecArray[] = {};
+#define AzStyleAnimationDelayVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleAnimationDelay), .cap = sizeof(v) / sizeof(AzStyleAnimationDelay), .destructor = { .NoDestructor = { .tag = AzStyleAnimationDelayVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleAnimationDelayVec_empty { .ptr = &AzStyleAnimationDelayVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleAnimationDelayVecDestructorTag_NoDestructor, }, }, }
+
+AzCssKeyframe AzCssKeyframeVecArray[] = {};
+#define AzCssKeyframeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssKeyframe), .cap = sizeof(v) / sizeof(AzCssKeyframe), .destructor = { .NoDestructor = { .tag = AzCssKeyframeVecDestructorTag_NoDestructor, }, }, }
+#define AzCssKeyframeVec_empty { .ptr = &AzCssKeyframeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssKeyframeVecDestructorTag_NoDestructor, }, }, }
+
+AzCssKeyframes AzCssKeyframesVecArray[] = {};
+#define AzCssKeyframesVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssKeyframes), .cap = sizeof(v) / sizeof(AzCssKeyframes), .destructor = { .NoDestructor = { .tag = AzCssKeyframesVecDestructorTag_NoDestructor, }, }, }
+#define AzCssKeyframesVec_empty { .ptr = &AzCssKeyframesVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssKeyframesVecDestructorTag_NoDestructor, }, }, }
+
+AzGridTrackSizing AzGridTrackSizingVecArray[] = {};
+#define AzGridTrackSizingVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzGridTrackSizing), .cap = sizeof(v) / sizeof(AzGridTrackSizing), .destructor = { .NoDestructor = { .tag = AzGridTrackSizingVecDestructorTag_NoDestructor, }, }, }
+#define AzGridTrackSizingVec_empty { .ptr = &AzGridTrackSizingVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzGridTrackSizingVecDestructorTag_NoDestructor, }, }, }
+
 AzCssRuleBlock AzCssRuleBlockVecArray[] = {};
 #define AzCssRuleBlockVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssRuleBlock), .cap = sizeof(v) / sizeof(AzCssRuleBlock), .destructor = { .NoDestructor = { .tag = AzCssRuleBlockVecDestructorTag_NoDestructor, }, }, }
 #define AzCssRuleBlockVec_empty { .ptr = &AzCssRuleBlockVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssRuleBlockVecDestructorTag_NoDestructor, }, }, }
@@ -10681,6 +13184,18 @@ AzNodeData AzNodeDataVecArray[] = {};
 #define AzNodeDataVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzNodeData), .cap = sizeof(v) / sizeof(AzNodeData), .destructor = { .NoDestructor = { .tag = AzNodeDataVecDestructorTag_NoDestructor, }, }, }
 #define AzNodeDataVec_empty { .ptr = &AzNodeDataVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzNodeDataVecDestructorTag_NoDestructor, }, }, }
 
+AzTextEditSnapshot AzTextEditSnapshotVecArray[] = {};
+#define AzTextEditSnapshotVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTextEditSnapshot), .cap = sizeof(v) / sizeof(AzTextEditSnapshot), .destructor = { .NoDestructor = { .tag = AzTextEditSnapshotVecDestructorTag_NoDestructor, }, }, }
+#define AzTextEditSnapshotVec_empty { .ptr = &AzTextEditSnapshotVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTextEditSnapshotVecDestructorTag_NoDestructor, }, }, }
+
+AzSpreadsheetSizeOverride AzSpreadsheetSizeOverrideVecArray[] = {};
+#define AzSpreadsheetSizeOverrideVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzSpreadsheetSizeOverride), .cap = sizeof(v) / sizeof(AzSpreadsheetSizeOverride), .destructor = { .NoDestructor = { .tag = AzSpreadsheetSizeOverrideVecDestructorTag_NoDestructor, }, }, }
+#define AzSpreadsheetSizeOverrideVec_empty { .ptr = &AzSpreadsheetSizeOverrideVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzSpreadsheetSizeOverrideVecDestructorTag_NoDestructor, }, }, }
+
+AzSpreadsheetCellContent AzSpreadsheetCellContentVecArray[] = {};
+#define AzSpreadsheetCellContentVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzSpreadsheetCellContent), .cap = sizeof(v) / sizeof(AzSpreadsheetCellContent), .destructor = { .NoDestructor = { .tag = AzSpreadsheetCellContentVecDestructorTag_NoDestructor, }, }, }
+#define AzSpreadsheetCellContentVec_empty { .ptr = &AzSpreadsheetCellContentVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzSpreadsheetCellContentVecDestructorTag_NoDestructor, }, }, }
+
 
 /* FUNCTIONS from azul.dll / libazul.so */
 extern DLLIMPORT AzApp AzApp_new(AzRefAny  data, AzAppConfig  config);
@@ -10725,6 +13240,7 @@ extern DLLIMPORT AzOptionCssProperty AzCallbackInfo_getComputedCssProperty(AzCal
 extern DLLIMPORT void AzCallbackInfo_setWindowState(AzCallbackInfo* restrict callbackinfo, AzWindowState  new_state);
 extern DLLIMPORT void AzCallbackInfo_setFocus(AzCallbackInfo* restrict callbackinfo, AzFocusTarget  target);
 extern DLLIMPORT void AzCallbackInfo_setCssProperty(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzCssProperty  new_property);
+extern DLLIMPORT void AzCallbackInfo_setCssVariable(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  name, AzString  value);
 extern DLLIMPORT void AzCallbackInfo_setScrollPosition(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzLogicalPosition  scroll_position);
 extern DLLIMPORT void AzCallbackInfo_setStringContents(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  string);
 extern DLLIMPORT void AzCallbackInfo_addImage(AzCallbackInfo* restrict callbackinfo, AzString  id, AzImageRef  image);
@@ -10808,6 +13324,8 @@ extern DLLIMPORT void AzDom_addFocusCssProperty(AzDom* restrict dom, AzCssProper
 extern DLLIMPORT AzDom AzDom_withFocusCssProperty(AzDom* restrict dom, AzCssProperty  prop);
 extern DLLIMPORT void AzDom_setClipMask(AzDom* restrict dom, AzImageMask  clip_mask);
 extern DLLIMPORT AzDom AzDom_withClipMask(AzDom* restrict dom, AzImageMask  clip_mask);
+extern DLLIMPORT void AzDom_setAttribute(AzDom* restrict dom, AzString  name, AzString  value);
+extern DLLIMPORT AzDom AzDom_withAttribute(AzDom* restrict dom, AzString  name, AzString  value);
 extern DLLIMPORT void AzDom_setTabIndex(AzDom* restrict dom, AzTabIndex  tab_index);
 extern DLLIMPORT AzDom AzDom_withTabIndex(AzDom* restrict dom, AzTabIndex  tab_index);
 extern DLLIMPORT void AzDom_setAccessibilityInfo(AzDom* restrict dom, AzAccessibilityInfo  accessibility_info);
@@ -10841,12 +13359,15 @@ extern DLLIMPORT AzNodeData AzNodeData_withCallbacks(AzNodeData* restrict nodeda
 extern DLLIMPORT void AzNodeData_setInlineCssProps(AzNodeData* restrict nodedata, AzNodeDataInlineCssPropertyVec  css_properties);
 extern DLLIMPORT AzNodeData AzNodeData_withInlineCssProps(AzNodeData* restrict nodedata, AzNodeDataInlineCssPropertyVec  css_properties);
 extern DLLIMPORT void AzNodeData_setClipMask(AzNodeData* restrict nodedata, AzImageMask  image_mask);
+extern DLLIMPORT void AzNodeData_setAttribute(AzNodeData* restrict nodedata, AzString  name, AzString  value);
 extern DLLIMPORT void AzNodeData_setTabIndex(AzNodeData* restrict nodedata, AzTabIndex  tab_index);
 extern DLLIMPORT void AzNodeData_setAccessibilityInfo(AzNodeData* restrict nodedata, AzAccessibilityInfo  accessibility_info);
 extern DLLIMPORT void AzNodeData_setMenuBar(AzNodeData* restrict nodedata, AzMenu  menu_bar);
 extern DLLIMPORT void AzNodeData_setContextMenu(AzNodeData* restrict nodedata, AzMenu  context_menu);
 extern DLLIMPORT uint64_t AzNodeData_hash(const AzNodeData* nodedata);
 extern DLLIMPORT AzEventFilter AzOn_intoEventFilter(const AzOn on);
+extern DLLIMPORT bool  AzAccessibilityNode_hasState(const AzAccessibilityNode* accessibilitynode, AzAccessibilityState  state);
+extern DLLIMPORT AzOptionAccessibilityNode AzAccessibilityTree_getFocusedNode(const AzAccessibilityTree* accessibilitytree);
 extern DLLIMPORT AzMenuItem AzMenuItem_new(AzString  label, AzOptionMenuCallback  callback);
 extern DLLIMPORT AzStringMenuItem AzStringMenuItem_new(AzString  label);
 extern DLLIMPORT void AzStringMenuItem_addChild(AzStringMenuItem* restrict stringmenuitem, AzMenuItem  child);
@@ -10889,6 +13410,92 @@ extern DLLIMPORT AzTextInput AzTextInput_withLabelStyle(AzTextInput* restrict te
 extern DLLIMPORT AzDom AzTextInput_dom(AzTextInput* restrict textinput);
 extern DLLIMPORT AzNumberInput AzNumberInput_new(float number);
 extern DLLIMPORT AzDom AzNumberInput_dom(AzNumberInput* restrict numberinput);
+extern DLLIMPORT AzTextEdit AzTextEdit_new(AzString  initial_text);
+extern DLLIMPORT void AzTextEdit_setOnTextChange(AzTextEdit* restrict textedit, AzRefAny  data, AzTextEditOnTextChangeCallbackType  callback);
+extern DLLIMPORT AzTextEdit AzTextEdit_withOnTextChange(AzTextEdit* restrict textedit, AzRefAny  data, AzTextEditOnTextChangeCallbackType  callback);
+extern DLLIMPORT void AzTextEdit_setOnFocusLost(AzTextEdit* restrict textedit, AzRefAny  data, AzTextEditOnFocusLostCallbackType  callback);
+extern DLLIMPORT AzTextEdit AzTextEdit_withOnFocusLost(AzTextEdit* restrict textedit, AzRefAny  data, AzTextEditOnFocusLostCallbackType  callback);
+extern DLLIMPORT void AzTextEdit_setShowLineNumbers(AzTextEdit* restrict textedit, bool  show_line_numbers);
+extern DLLIMPORT AzTextEdit AzTextEdit_withShowLineNumbers(AzTextEdit* restrict textedit, bool  show_line_numbers);
+extern DLLIMPORT void AzTextEdit_setContainerStyle(AzTextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  container_style);
+extern DLLIMPORT AzTextEdit AzTextEdit_withContainerStyle(AzTextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  container_style);
+extern DLLIMPORT void AzTextEdit_setLineNumbersStyle(AzTextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  line_numbers_style);
+extern DLLIMPORT AzTextEdit AzTextEdit_withLineNumbersStyle(AzTextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  line_numbers_style);
+extern DLLIMPORT void AzTextEdit_setLabelStyle(AzTextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  label_style);
+extern DLLIMPORT AzTextEdit AzTextEdit_withLabelStyle(AzTextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  label_style);
+extern DLLIMPORT AzDom AzTextEdit_dom(AzTextEdit* restrict textedit);
+extern DLLIMPORT AzSlider AzSlider_new(float value, float min, float max);
+extern DLLIMPORT void AzSlider_setStep(AzSlider* restrict slider, float step);
+extern DLLIMPORT AzSlider AzSlider_withStep(AzSlider* restrict slider, float step);
+extern DLLIMPORT void AzSlider_setOrientation(AzSlider* restrict slider, AzSliderOrientation  orientation);
+extern DLLIMPORT AzSlider AzSlider_withOrientation(AzSlider* restrict slider, AzSliderOrientation  orientation);
+extern DLLIMPORT void AzSlider_setTickCount(AzSlider* restrict slider, size_t tick_count);
+extern DLLIMPORT AzSlider AzSlider_withTickCount(AzSlider* restrict slider, size_t tick_count);
+extern DLLIMPORT void AzSlider_setShowTickLabels(AzSlider* restrict slider, bool  show_tick_labels);
+extern DLLIMPORT AzSlider AzSlider_withShowTickLabels(AzSlider* restrict slider, bool  show_tick_labels);
+extern DLLIMPORT void AzSlider_setOnValueChange(AzSlider* restrict slider, AzRefAny  data, AzSliderOnValueChangeCallbackType  callback);
+extern DLLIMPORT AzSlider AzSlider_withOnValueChange(AzSlider* restrict slider, AzRefAny  data, AzSliderOnValueChangeCallbackType  callback);
+extern DLLIMPORT void AzSlider_setContainerStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  container_style);
+extern DLLIMPORT AzSlider AzSlider_withContainerStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  container_style);
+extern DLLIMPORT void AzSlider_setTrackStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  track_style);
+extern DLLIMPORT AzSlider AzSlider_withTrackStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  track_style);
+extern DLLIMPORT void AzSlider_setThumbStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  thumb_style);
+extern DLLIMPORT AzSlider AzSlider_withThumbStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  thumb_style);
+extern DLLIMPORT void AzSlider_setTickStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  tick_style);
+extern DLLIMPORT AzSlider AzSlider_withTickStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  tick_style);
+extern DLLIMPORT void AzSlider_setLabelStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  label_style);
+extern DLLIMPORT AzSlider AzSlider_withLabelStyle(AzSlider* restrict slider, AzNodeDataInlineCssPropertyVec  label_style);
+extern DLLIMPORT AzDom AzSlider_dom(AzSlider* restrict slider);
+extern DLLIMPORT AzDropDown AzDropDown_new(AzStringVec  choices);
+extern DLLIMPORT void AzDropDown_setSelected(AzDropDown* restrict dropdown, size_t selected);
+extern DLLIMPORT AzDropDown AzDropDown_withSelected(AzDropDown* restrict dropdown, size_t selected);
+extern DLLIMPORT void AzDropDown_setDisabledChoices(AzDropDown* restrict dropdown, AzU32Vec  disabled_choices);
+extern DLLIMPORT AzDropDown AzDropDown_withDisabledChoices(AzDropDown* restrict dropdown, AzU32Vec  disabled_choices);
+extern DLLIMPORT void AzDropDown_setPopupPosition(AzDropDown* restrict dropdown, AzMenuPopupPosition  popup_position);
+extern DLLIMPORT AzDropDown AzDropDown_withPopupPosition(AzDropDown* restrict dropdown, AzMenuPopupPosition  popup_position);
+extern DLLIMPORT void AzDropDown_setOnChoiceChange(AzDropDown* restrict dropdown, AzRefAny  data, AzDropDownOnChoiceChangeCallbackType  callback);
+extern DLLIMPORT AzDropDown AzDropDown_withOnChoiceChange(AzDropDown* restrict dropdown, AzRefAny  data, AzDropDownOnChoiceChangeCallbackType  callback);
+extern DLLIMPORT void AzDropDown_setContainerStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  container_style);
+extern DLLIMPORT AzDropDown AzDropDown_withContainerStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  container_style);
+extern DLLIMPORT void AzDropDown_setLabelStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  label_style);
+extern DLLIMPORT AzDropDown AzDropDown_withLabelStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  label_style);
+extern DLLIMPORT void AzDropDown_setPopupStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  popup_style);
+extern DLLIMPORT AzDropDown AzDropDown_withPopupStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  popup_style);
+extern DLLIMPORT void AzDropDown_setEntryStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  entry_style);
+extern DLLIMPORT AzDropDown AzDropDown_withEntryStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  entry_style);
+extern DLLIMPORT void AzDropDown_setDisabledEntryStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  disabled_entry_style);
+extern DLLIMPORT AzDropDown AzDropDown_withDisabledEntryStyle(AzDropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  disabled_entry_style);
+extern DLLIMPORT AzDom AzDropDown_dom(AzDropDown* restrict dropdown);
+extern DLLIMPORT AzSpreadsheet AzSpreadsheet_new(AzSpreadsheetState  state);
+extern DLLIMPORT void AzSpreadsheet_setOnCellsChange(AzSpreadsheet* restrict spreadsheet, AzRefAny  data, AzSpreadsheetOnCellsChangeCallbackType  callback);
+extern DLLIMPORT AzSpreadsheet AzSpreadsheet_withOnCellsChange(AzSpreadsheet* restrict spreadsheet, AzRefAny  data, AzSpreadsheetOnCellsChangeCallbackType  callback);
+extern DLLIMPORT void AzSpreadsheet_setOnSelectionChange(AzSpreadsheet* restrict spreadsheet, AzRefAny  data, AzSpreadsheetOnSelectionChangeCallbackType  callback);
+extern DLLIMPORT AzSpreadsheet AzSpreadsheet_withOnSelectionChange(AzSpreadsheet* restrict spreadsheet, AzRefAny  data, AzSpreadsheetOnSelectionChangeCallbackType  callback);
+extern DLLIMPORT void AzSpreadsheet_setOnColumnResize(AzSpreadsheet* restrict spreadsheet, AzRefAny  data, AzSpreadsheetOnColumnResizeCallbackType  callback);
+extern DLLIMPORT AzSpreadsheet AzSpreadsheet_withOnColumnResize(AzSpreadsheet* restrict spreadsheet, AzRefAny  data, AzSpreadsheetOnColumnResizeCallbackType  callback);
+extern DLLIMPORT void AzSpreadsheet_setContainerStyle(AzSpreadsheet* restrict spreadsheet, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzSpreadsheet AzSpreadsheet_withContainerStyle(AzSpreadsheet* restrict spreadsheet, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzDom AzSpreadsheet_dom(AzSpreadsheet* restrict spreadsheet);
+extern DLLIMPORT AzSpreadsheetState AzSpreadsheetState_new();
+extern DLLIMPORT void AzSpreadsheetState_setCellContent(AzSpreadsheetState* restrict spreadsheetstate, AzSpreadsheetCellIndex  cell, AzString  value);
+extern DLLIMPORT AzOptionString AzSpreadsheetState_getCellContent(const AzSpreadsheetState* spreadsheetstate, AzSpreadsheetCellIndex  cell);
+extern DLLIMPORT void AzSpreadsheetState_setSelection(AzSpreadsheetState* restrict spreadsheetstate, AzOptionSpreadsheetCellSelection  selection);
+extern DLLIMPORT void AzSpreadsheetState_selectAll(AzSpreadsheetState* restrict spreadsheetstate);
+extern DLLIMPORT float AzSpreadsheetState_getColumnWidth(const AzSpreadsheetState* spreadsheetstate, size_t column);
+extern DLLIMPORT float AzSpreadsheetState_getRowHeight(const AzSpreadsheetState* spreadsheetstate, size_t row);
+extern DLLIMPORT void AzSpreadsheetState_setColumnWidth(AzSpreadsheetState* restrict spreadsheetstate, size_t column, float width);
+extern DLLIMPORT void AzSpreadsheetState_setRowHeight(AzSpreadsheetState* restrict spreadsheetstate, size_t row, float height);
+extern DLLIMPORT AzLogicalSize AzSpreadsheetState_getTableSize(const AzSpreadsheetState* spreadsheetstate);
+extern DLLIMPORT AzLogicalRect AzSpreadsheetState_getCellRect(const AzSpreadsheetState* spreadsheetstate, AzSpreadsheetCellIndex  cell);
+extern DLLIMPORT AzString AzSpreadsheetState_getSelectionTsv(const AzSpreadsheetState* spreadsheetstate, AzSpreadsheetCellSelection  selection);
+extern DLLIMPORT AzOptionSpreadsheetCellSelection AzSpreadsheetState_pasteTsv(AzSpreadsheetState* restrict spreadsheetstate, AzString  tsv);
+extern DLLIMPORT AzSpreadsheetCellSelection AzSpreadsheetCellSelection_from(size_t row, size_t column);
+extern DLLIMPORT AzSpreadsheetCellSelection AzSpreadsheetCellSelection_to(const AzSpreadsheetCellSelection spreadsheetcellselection, size_t row, size_t column);
+extern DLLIMPORT size_t AzSpreadsheetCellSelection_numberOfRowsSelected(const AzSpreadsheetCellSelection* spreadsheetcellselection);
+extern DLLIMPORT size_t AzSpreadsheetCellSelection_numberOfColumnsSelected(const AzSpreadsheetCellSelection* spreadsheetcellselection);
+extern DLLIMPORT AzSpreadsheetCellIndex AzSpreadsheetCellSelection_getTopLeft(const AzSpreadsheetCellSelection* spreadsheetcellselection);
+extern DLLIMPORT AzSpreadsheetCellIndex AzSpreadsheetCellSelection_getBottomRight(const AzSpreadsheetCellSelection* spreadsheetcellselection);
+extern DLLIMPORT bool  AzSpreadsheetCellSelection_contains(const AzSpreadsheetCellSelection* spreadsheetcellselection, AzSpreadsheetCellIndex  cell);
 extern DLLIMPORT void AzCssPropertyCache_delete(AzCssPropertyCache* restrict instance);
 extern DLLIMPORT AzCssPropertyCache AzCssPropertyCache_deepCopy(AzCssPropertyCache* const instance);
 extern DLLIMPORT AzStyledDom AzStyledDom_new(AzDom  dom, AzCss  css);
@@ -11231,6 +13838,7 @@ extern DLLIMPORT AzString AzString_copyFromBytes(uint8_t ptr, size_t start, size
 extern DLLIMPORT AzString AzString_trim(const AzString* string);
 extern DLLIMPORT AzRefstr AzString_asRefstr(const AzString* string);
 extern DLLIMPORT void AzAccessibilityStateVec_delete(AzAccessibilityStateVec* restrict instance);
+extern DLLIMPORT void AzAccessibilityNodeVec_delete(AzAccessibilityNodeVec* restrict instance);
 extern DLLIMPORT void AzMenuItemVec_delete(AzMenuItemVec* restrict instance);
 extern DLLIMPORT AzTessellatedSvgNodeVecRef AzTessellatedSvgNodeVec_asRefVec(const AzTessellatedSvgNodeVec* tessellatedsvgnodevec);
 extern DLLIMPORT void AzTessellatedSvgNodeVec_delete(AzTessellatedSvgNodeVec* restrict instance);
@@ -11260,11 +13868,28 @@ extern DLLIMPORT void AzSvgVertexVec_delete(AzSvgVertexVec* restrict instance);
 extern DLLIMPORT void AzU32Vec_delete(AzU32Vec* restrict instance);
 extern DLLIMPORT void AzXWindowTypeVec_delete(AzXWindowTypeVec* restrict instance);
 extern DLLIMPORT void AzVirtualKeyCodeVec_delete(AzVirtualKeyCodeVec* restrict instance);
+extern DLLIMPORT void AzKeyboardShortcutVec_delete(AzKeyboardShortcutVec* restrict instance);
 extern DLLIMPORT void AzCascadeInfoVec_delete(AzCascadeInfoVec* restrict instance);
 extern DLLIMPORT void AzScanCodeVec_delete(AzScanCodeVec* restrict instance);
 extern DLLIMPORT void AzCssDeclarationVec_delete(AzCssDeclarationVec* restrict instance);
 extern DLLIMPORT void AzCssPathSelectorVec_delete(AzCssPathSelectorVec* restrict instance);
 extern DLLIMPORT void AzStylesheetVec_delete(AzStylesheetVec* restrict instance);
+extern DLLIMPORT void AzCssMediaQueryVec_delete(AzCssMediaQueryVec* restrict instance);
+extern DLLIMPORT void AzCssMediaConditionVec_delete(AzCssMediaConditionVec* restrict instance);
+extern DLLIMPORT void AzCssFontFaceVec_delete(AzCssFontFaceVec* restrict instance);
+extern DLLIMPORT void AzStyleTransitionPropertyVec_delete(AzStyleTransitionPropertyVec* restrict instance);
+extern DLLIMPORT void AzStyleTransitionDurationVec_delete(AzStyleTransitionDurationVec* restrict instance);
+extern DLLIMPORT void AzStyleTransitionTimingFunctionVec_delete(AzStyleTransitionTimingFunctionVec* restrict instance);
+extern DLLIMPORT void AzStyleTransitionDelayVec_delete(AzStyleTransitionDelayVec* restrict instance);
+extern DLLIMPORT void AzStyleAnimationNameVec_delete(AzStyleAnimationNameVec* restrict instance);
+extern DLLIMPORT void AzStyleAnimationDurationVec_delete(AzStyleAnimationDurationVec* restrict instance);
+extern DLLIMPORT void AzStyleAnimationIterationCountVec_delete(AzStyleAnimationIterationCountVec* restrict instance);
+extern DLLIMPORT void AzStyleAnimationDirectionVec_delete(AzStyleAnimationDirectionVec* restrict instance);
+extern DLLIMPORT void AzStyleAnimationFillModeVec_delete(AzStyleAnimationFillModeVec* restrict instance);
+extern DLLIMPORT void AzStyleAnimationDelayVec_delete(AzStyleAnimationDelayVec* restrict instance);
+extern DLLIMPORT void AzCssKeyframeVec_delete(AzCssKeyframeVec* restrict instance);
+extern DLLIMPORT void AzCssKeyframesVec_delete(AzCssKeyframesVec* restrict instance);
+extern DLLIMPORT void AzGridTrackSizingVec_delete(AzGridTrackSizingVec* restrict instance);
 extern DLLIMPORT void AzCssRuleBlockVec_delete(AzCssRuleBlockVec* restrict instance);
 extern DLLIMPORT void AzU16Vec_delete(AzU16Vec* restrict instance);
 extern DLLIMPORT void AzF32Vec_delete(AzF32Vec* restrict instance);
@@ -11285,6 +13910,9 @@ extern DLLIMPORT void AzStyledNodeVec_delete(AzStyledNodeVec* restrict instance)
 extern DLLIMPORT void AzTagIdToNodeIdMappingVec_delete(AzTagIdToNodeIdMappingVec* restrict instance);
 extern DLLIMPORT void AzParentWithNodeDepthVec_delete(AzParentWithNodeDepthVec* restrict instance);
 extern DLLIMPORT void AzNodeDataVec_delete(AzNodeDataVec* restrict instance);
+extern DLLIMPORT void AzTextEditSnapshotVec_delete(AzTextEditSnapshotVec* restrict instance);
+extern DLLIMPORT void AzSpreadsheetSizeOverrideVec_delete(AzSpreadsheetSizeOverrideVec* restrict instance);
+extern DLLIMPORT void AzSpreadsheetCellContentVec_delete(AzSpreadsheetCellContentVec* restrict instance);
 
 /* CONSTANTS */
 
@@ -13341,6 +15969,48 @@ bool AzMenuItem_matchMut(AzMenuItem* restrict value, AzStringMenuItem* restrict
     return valid;
 }
 
+bool AzShortcutConflict_matchRef(const AzShortcutConflict* value, const AzVirtualKeyCodeCombo** restrict out) {
+    const AzShortcutConflictVariant_Shortcut* casted = (const AzShortcutConflictVariant_Shortcut*)value;
+    bool valid = casted->tag == AzShortcutConflictTag_Shortcut;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzShortcutConflict_matchMut(AzShortcutConflict* restrict value, AzVirtualKeyCodeCombo* restrict * restrict out) {
+    AzShortcutConflictVariant_Shortcut* restrict casted = (AzShortcutConflictVariant_Shortcut* restrict)value;
+    bool valid = casted->tag == AzShortcutConflictTag_Shortcut;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzShortcutConflict_matchRef(const AzShortcutConflict* value, const AzString** restrict out) {
+    const AzShortcutConflictVariant_MenuItem* casted = (const AzShortcutConflictVariant_MenuItem*)value;
+    bool valid = casted->tag == AzShortcutConflictTag_MenuItem;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzShortcutConflict_matchMut(AzShortcutConflict* restrict value, AzString* restrict * restrict out) {
+    AzShortcutConflictVariant_MenuItem* restrict casted = (AzShortcutConflictVariant_MenuItem* restrict)value;
+    bool valid = casted->tag == AzShortcutConflictTag_MenuItem;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzShortcutConflict_matchRef(const AzShortcutConflict* value, const AzString** restrict out) {
+    const AzShortcutConflictVariant_ContextMenuItem* casted = (const AzShortcutConflictVariant_ContextMenuItem*)value;
+    bool valid = casted->tag == AzShortcutConflictTag_ContextMenuItem;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzShortcutConflict_matchMut(AzShortcutConflict* restrict value, AzString* restrict * restrict out) {
+    AzShortcutConflictVariant_ContextMenuItem* restrict casted = (AzShortcutConflictVariant_ContextMenuItem* restrict)value;
+    bool valid = casted->tag == AzShortcutConflictTag_ContextMenuItem;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzMenuItemIcon_matchRef(const AzMenuItemIcon* value, const Azbool** restrict out) {
     const AzMenuItemIconVariant_Checkbox* casted = (const AzMenuItemIconVariant_Checkbox*)value;
     bool valid = casted->tag == AzMenuItemIconTag_Checkbox;
@@ -13369,6 +16039,118 @@ bool AzMenuItemIcon_matchMut(AzMenuItemIcon* restrict value, AzImageRef* restric
     return valid;
 }
 
+bool AzCssMediaCondition_matchRef(const AzCssMediaCondition* value, const AzPixelValue** restrict out) {
+    const AzCssMediaConditionVariant_MinWidth* casted = (const AzCssMediaConditionVariant_MinWidth*)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_MinWidth;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchMut(AzCssMediaCondition* restrict value, AzPixelValue* restrict * restrict out) {
+    AzCssMediaConditionVariant_MinWidth* restrict casted = (AzCssMediaConditionVariant_MinWidth* restrict)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_MinWidth;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchRef(const AzCssMediaCondition* value, const AzPixelValue** restrict out) {
+    const AzCssMediaConditionVariant_MaxWidth* casted = (const AzCssMediaConditionVariant_MaxWidth*)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_MaxWidth;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchMut(AzCssMediaCondition* restrict value, AzPixelValue* restrict * restrict out) {
+    AzCssMediaConditionVariant_MaxWidth* restrict casted = (AzCssMediaConditionVariant_MaxWidth* restrict)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_MaxWidth;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchRef(const AzCssMediaCondition* value, const AzPixelValue** restrict out) {
+    const AzCssMediaConditionVariant_MinHeight* casted = (const AzCssMediaConditionVariant_MinHeight*)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_MinHeight;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchMut(AzCssMediaCondition* restrict value, AzPixelValue* restrict * restrict out) {
+    AzCssMediaConditionVariant_MinHeight* restrict casted = (AzCssMediaConditionVariant_MinHeight* restrict)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_MinHeight;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchRef(const AzCssMediaCondition* value, const AzPixelValue** restrict out) {
+    const AzCssMediaConditionVariant_MaxHeight* casted = (const AzCssMediaConditionVariant_MaxHeight*)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_MaxHeight;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchMut(AzCssMediaCondition* restrict value, AzPixelValue* restrict * restrict out) {
+    AzCssMediaConditionVariant_MaxHeight* restrict casted = (AzCssMediaConditionVariant_MaxHeight* restrict)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_MaxHeight;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchRef(const AzCssMediaCondition* value, const AzFloatValue** restrict out) {
+    const AzCssMediaConditionVariant_MinResolution* casted = (const AzCssMediaConditionVariant_MinResolution*)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_MinResolution;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchMut(AzCssMediaCondition* restrict value, AzFloatValue* restrict * restrict out) {
+    AzCssMediaConditionVariant_MinResolution* restrict casted = (AzCssMediaConditionVariant_MinResolution* restrict)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_MinResolution;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchRef(const AzCssMediaCondition* value, const AzFloatValue** restrict out) {
+    const AzCssMediaConditionVariant_MaxResolution* casted = (const AzCssMediaConditionVariant_MaxResolution*)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_MaxResolution;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchMut(AzCssMediaCondition* restrict value, AzFloatValue* restrict * restrict out) {
+    AzCssMediaConditionVariant_MaxResolution* restrict casted = (AzCssMediaConditionVariant_MaxResolution* restrict)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_MaxResolution;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchRef(const AzCssMediaCondition* value, const AzCssMediaOrientation** restrict out) {
+    const AzCssMediaConditionVariant_Orientation* casted = (const AzCssMediaConditionVariant_Orientation*)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_Orientation;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchMut(AzCssMediaCondition* restrict value, AzCssMediaOrientation* restrict * restrict out) {
+    AzCssMediaConditionVariant_Orientation* restrict casted = (AzCssMediaConditionVariant_Orientation* restrict)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_Orientation;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchRef(const AzCssMediaCondition* value, const AzCssMediaColorScheme** restrict out) {
+    const AzCssMediaConditionVariant_PrefersColorScheme* casted = (const AzCssMediaConditionVariant_PrefersColorScheme*)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_PrefersColorScheme;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaCondition_matchMut(AzCssMediaCondition* restrict value, AzCssMediaColorScheme* restrict * restrict out) {
+    AzCssMediaConditionVariant_PrefersColorScheme* restrict casted = (AzCssMediaConditionVariant_PrefersColorScheme* restrict)value;
+    bool valid = casted->tag == AzCssMediaConditionTag_PrefersColorScheme;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssDeclaration_matchRef(const AzCssDeclaration* value, const AzCssProperty** restrict out) {
     const AzCssDeclarationVariant_Static* casted = (const AzCssDeclarationVariant_Static*)value;
     bool valid = casted->tag == AzCssDeclarationTag_Static;
@@ -13397,6 +16179,20 @@ bool AzCssDeclaration_matchMut(AzCssDeclaration* restrict value, AzDynamicCssPro
     return valid;
 }
 
+bool AzCssDeclaration_matchRef(const AzCssDeclaration* value, const AzCssCustomProperty** restrict out) {
+    const AzCssDeclarationVariant_Custom* casted = (const AzCssDeclarationVariant_Custom*)value;
+    bool valid = casted->tag == AzCssDeclarationTag_Custom;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssDeclaration_matchMut(AzCssDeclaration* restrict value, AzCssCustomProperty* restrict * restrict out) {
+    AzCssDeclarationVariant_Custom* restrict casted = (AzCssDeclarationVariant_Custom* restrict)value;
+    bool valid = casted->tag == AzCssDeclarationTag_Custom;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssPathSelector_matchRef(const AzCssPathSelector* value, const AzNodeTypeKey** restrict out) {
     const AzCssPathSelectorVariant_Type* casted = (const AzCssPathSelectorVariant_Type*)value;
     bool valid = casted->tag == AzCssPathSelectorTag_Type;
@@ -13453,6 +16249,34 @@ bool AzCssPathSelector_matchMut(AzCssPathSelector* restrict value, AzCssPathPseu
     return valid;
 }
 
+bool AzCssPathSelector_matchRef(const AzCssPathSelector* value, const AzCssAttributeSelector** restrict out) {
+    const AzCssPathSelectorVariant_Attribute* casted = (const AzCssPathSelectorVariant_Attribute*)value;
+    bool valid = casted->tag == AzCssPathSelectorTag_Attribute;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssPathSelector_matchMut(AzCssPathSelector* restrict value, AzCssAttributeSelector* restrict * restrict out) {
+    AzCssPathSelectorVariant_Attribute* restrict casted = (AzCssPathSelectorVariant_Attribute* restrict)value;
+    bool valid = casted->tag == AzCssPathSelectorTag_Attribute;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssPathSelector_matchRef(const AzCssPathSelector* value, const AzCssPathSelectorVec** restrict out) {
+    const AzCssPathSelectorVariant_Not* casted = (const AzCssPathSelectorVariant_Not*)value;
+    bool valid = casted->tag == AzCssPathSelectorTag_Not;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssPathSelector_matchMut(AzCssPathSelector* restrict value, AzCssPathSelectorVec* restrict * restrict out) {
+    AzCssPathSelectorVariant_Not* restrict casted = (AzCssPathSelectorVariant_Not* restrict)value;
+    bool valid = casted->tag == AzCssPathSelectorTag_Not;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssPathPseudoSelector_matchRef(const AzCssPathPseudoSelector* value, const AzCssNthChildSelector** restrict out) {
     const AzCssPathPseudoSelectorVariant_NthChild* casted = (const AzCssPathPseudoSelectorVariant_NthChild*)value;
     bool valid = casted->tag == AzCssPathPseudoSelectorTag_NthChild;
@@ -13467,6 +16291,20 @@ bool AzCssPathPseudoSelector_matchMut(AzCssPathPseudoSelector* restrict value, A
     return valid;
 }
 
+bool AzCssPathPseudoSelector_matchRef(const AzCssPathPseudoSelector* value, const AzCssNthChildSelector** restrict out) {
+    const AzCssPathPseudoSelectorVariant_NthLastChild* casted = (const AzCssPathPseudoSelectorVariant_NthLastChild*)value;
+    bool valid = casted->tag == AzCssPathPseudoSelectorTag_NthLastChild;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssPathPseudoSelector_matchMut(AzCssPathPseudoSelector* restrict value, AzCssNthChildSelector* restrict * restrict out) {
+    AzCssPathPseudoSelectorVariant_NthLastChild* restrict casted = (AzCssPathPseudoSelectorVariant_NthLastChild* restrict)value;
+    bool valid = casted->tag == AzCssPathPseudoSelectorTag_NthLastChild;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssNthChildSelector_matchRef(const AzCssNthChildSelector* value, const Azu32** restrict out) {
     const AzCssNthChildSelectorVariant_Number* casted = (const AzCssNthChildSelectorVariant_Number*)value;
     bool valid = casted->tag == AzCssNthChildSelectorTag_Number;
@@ -13509,63 +16347,161 @@ bool AzAnimationInterpolationFunction_matchMut(AzAnimationInterpolationFunction*
     return valid;
 }
 
-bool AzDirection_matchRef(const AzDirection* value, const AzAngleValue** restrict out) {
-    const AzDirectionVariant_Angle* casted = (const AzDirectionVariant_Angle*)value;
-    bool valid = casted->tag == AzDirectionTag_Angle;
+bool AzGridTrackBreadth_matchRef(const AzGridTrackBreadth* value, const AzPixelValue** restrict out) {
+    const AzGridTrackBreadthVariant_Fixed* casted = (const AzGridTrackBreadthVariant_Fixed*)value;
+    bool valid = casted->tag == AzGridTrackBreadthTag_Fixed;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzDirection_matchMut(AzDirection* restrict value, AzAngleValue* restrict * restrict out) {
-    AzDirectionVariant_Angle* restrict casted = (AzDirectionVariant_Angle* restrict)value;
-    bool valid = casted->tag == AzDirectionTag_Angle;
+bool AzGridTrackBreadth_matchMut(AzGridTrackBreadth* restrict value, AzPixelValue* restrict * restrict out) {
+    AzGridTrackBreadthVariant_Fixed* restrict casted = (AzGridTrackBreadthVariant_Fixed* restrict)value;
+    bool valid = casted->tag == AzGridTrackBreadthTag_Fixed;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzDirection_matchRef(const AzDirection* value, const AzDirectionCorners** restrict out) {
-    const AzDirectionVariant_FromTo* casted = (const AzDirectionVariant_FromTo*)value;
-    bool valid = casted->tag == AzDirectionTag_FromTo;
+bool AzGridTrackBreadth_matchRef(const AzGridTrackBreadth* value, const AzFloatValue** restrict out) {
+    const AzGridTrackBreadthVariant_Fraction* casted = (const AzGridTrackBreadthVariant_Fraction*)value;
+    bool valid = casted->tag == AzGridTrackBreadthTag_Fraction;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzDirection_matchMut(AzDirection* restrict value, AzDirectionCorners* restrict * restrict out) {
-    AzDirectionVariant_FromTo* restrict casted = (AzDirectionVariant_FromTo* restrict)value;
-    bool valid = casted->tag == AzDirectionTag_FromTo;
+bool AzGridTrackBreadth_matchMut(AzGridTrackBreadth* restrict value, AzFloatValue* restrict * restrict out) {
+    AzGridTrackBreadthVariant_Fraction* restrict casted = (AzGridTrackBreadthVariant_Fraction* restrict)value;
+    bool valid = casted->tag == AzGridTrackBreadthTag_Fraction;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundContent_matchRef(const AzStyleBackgroundContent* value, const AzLinearGradient** restrict out) {
-    const AzStyleBackgroundContentVariant_LinearGradient* casted = (const AzStyleBackgroundContentVariant_LinearGradient*)value;
-    bool valid = casted->tag == AzStyleBackgroundContentTag_LinearGradient;
+bool AzGridTrackSizing_matchRef(const AzGridTrackSizing* value, const AzPixelValue** restrict out) {
+    const AzGridTrackSizingVariant_Fixed* casted = (const AzGridTrackSizingVariant_Fixed*)value;
+    bool valid = casted->tag == AzGridTrackSizingTag_Fixed;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundContent_matchMut(AzStyleBackgroundContent* restrict value, AzLinearGradient* restrict * restrict out) {
-    AzStyleBackgroundContentVariant_LinearGradient* restrict casted = (AzStyleBackgroundContentVariant_LinearGradient* restrict)value;
-    bool valid = casted->tag == AzStyleBackgroundContentTag_LinearGradient;
+bool AzGridTrackSizing_matchMut(AzGridTrackSizing* restrict value, AzPixelValue* restrict * restrict out) {
+    AzGridTrackSizingVariant_Fixed* restrict casted = (AzGridTrackSizingVariant_Fixed* restrict)value;
+    bool valid = casted->tag == AzGridTrackSizingTag_Fixed;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundContent_matchRef(const AzStyleBackgroundContent* value, const AzRadialGradient** restrict out) {
-    const AzStyleBackgroundContentVariant_RadialGradient* casted = (const AzStyleBackgroundContentVariant_RadialGradient*)value;
-    bool valid = casted->tag == AzStyleBackgroundContentTag_RadialGradient;
+bool AzGridTrackSizing_matchRef(const AzGridTrackSizing* value, const AzFloatValue** restrict out) {
+    const AzGridTrackSizingVariant_Fraction* casted = (const AzGridTrackSizingVariant_Fraction*)value;
+    bool valid = casted->tag == AzGridTrackSizingTag_Fraction;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundContent_matchMut(AzStyleBackgroundContent* restrict value, AzRadialGradient* restrict * restrict out) {
-    AzStyleBackgroundContentVariant_RadialGradient* restrict casted = (AzStyleBackgroundContentVariant_RadialGradient* restrict)value;
-    bool valid = casted->tag == AzStyleBackgroundContentTag_RadialGradient;
+bool AzGridTrackSizing_matchMut(AzGridTrackSizing* restrict value, AzFloatValue* restrict * restrict out) {
+    AzGridTrackSizingVariant_Fraction* restrict casted = (AzGridTrackSizingVariant_Fraction* restrict)value;
+    bool valid = casted->tag == AzGridTrackSizingTag_Fraction;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundContent_matchRef(const AzStyleBackgroundContent* value, const AzConicGradient** restrict out) {
+bool AzGridTrackSizing_matchRef(const AzGridTrackSizing* value, const AzGridMinMax** restrict out) {
+    const AzGridTrackSizingVariant_MinMax* casted = (const AzGridTrackSizingVariant_MinMax*)value;
+    bool valid = casted->tag == AzGridTrackSizingTag_MinMax;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridTrackSizing_matchMut(AzGridTrackSizing* restrict value, AzGridMinMax* restrict * restrict out) {
+    AzGridTrackSizingVariant_MinMax* restrict casted = (AzGridTrackSizingVariant_MinMax* restrict)value;
+    bool valid = casted->tag == AzGridTrackSizingTag_MinMax;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridLine_matchRef(const AzGridLine* value, const Azi32** restrict out) {
+    const AzGridLineVariant_Line* casted = (const AzGridLineVariant_Line*)value;
+    bool valid = casted->tag == AzGridLineTag_Line;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridLine_matchMut(AzGridLine* restrict value, Azi32* restrict * restrict out) {
+    AzGridLineVariant_Line* restrict casted = (AzGridLineVariant_Line* restrict)value;
+    bool valid = casted->tag == AzGridLineTag_Line;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridLine_matchRef(const AzGridLine* value, const Azu32** restrict out) {
+    const AzGridLineVariant_Span* casted = (const AzGridLineVariant_Span*)value;
+    bool valid = casted->tag == AzGridLineTag_Span;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridLine_matchMut(AzGridLine* restrict value, Azu32* restrict * restrict out) {
+    AzGridLineVariant_Span* restrict casted = (AzGridLineVariant_Span* restrict)value;
+    bool valid = casted->tag == AzGridLineTag_Span;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzDirection_matchRef(const AzDirection* value, const AzAngleValue** restrict out) {
+    const AzDirectionVariant_Angle* casted = (const AzDirectionVariant_Angle*)value;
+    bool valid = casted->tag == AzDirectionTag_Angle;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzDirection_matchMut(AzDirection* restrict value, AzAngleValue* restrict * restrict out) {
+    AzDirectionVariant_Angle* restrict casted = (AzDirectionVariant_Angle* restrict)value;
+    bool valid = casted->tag == AzDirectionTag_Angle;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzDirection_matchRef(const AzDirection* value, const AzDirectionCorners** restrict out) {
+    const AzDirectionVariant_FromTo* casted = (const AzDirectionVariant_FromTo*)value;
+    bool valid = casted->tag == AzDirectionTag_FromTo;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzDirection_matchMut(AzDirection* restrict value, AzDirectionCorners* restrict * restrict out) {
+    AzDirectionVariant_FromTo* restrict casted = (AzDirectionVariant_FromTo* restrict)value;
+    bool valid = casted->tag == AzDirectionTag_FromTo;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleBackgroundContent_matchRef(const AzStyleBackgroundContent* value, const AzLinearGradient** restrict out) {
+    const AzStyleBackgroundContentVariant_LinearGradient* casted = (const AzStyleBackgroundContentVariant_LinearGradient*)value;
+    bool valid = casted->tag == AzStyleBackgroundContentTag_LinearGradient;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleBackgroundContent_matchMut(AzStyleBackgroundContent* restrict value, AzLinearGradient* restrict * restrict out) {
+    AzStyleBackgroundContentVariant_LinearGradient* restrict casted = (AzStyleBackgroundContentVariant_LinearGradient* restrict)value;
+    bool valid = casted->tag == AzStyleBackgroundContentTag_LinearGradient;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleBackgroundContent_matchRef(const AzStyleBackgroundContent* value, const AzRadialGradient** restrict out) {
+    const AzStyleBackgroundContentVariant_RadialGradient* casted = (const AzStyleBackgroundContentVariant_RadialGradient*)value;
+    bool valid = casted->tag == AzStyleBackgroundContentTag_RadialGradient;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleBackgroundContent_matchMut(AzStyleBackgroundContent* restrict value, AzRadialGradient* restrict * restrict out) {
+    AzStyleBackgroundContentVariant_RadialGradient* restrict casted = (AzStyleBackgroundContentVariant_RadialGradient* restrict)value;
+    bool valid = casted->tag == AzStyleBackgroundContentTag_RadialGradient;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleBackgroundContent_matchRef(const AzStyleBackgroundContent* value, const AzConicGradient** restrict out) {
     const AzStyleBackgroundContentVariant_ConicGradient* casted = (const AzStyleBackgroundContentVariant_ConicGradient*)value;
     bool valid = casted->tag == AzStyleBackgroundContentTag_ConicGradient;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -13691,6 +16627,48 @@ bool AzStyleFontFamily_matchMut(AzStyleFontFamily* restrict value, AzFontRef* re
     return valid;
 }
 
+bool AzStyleTransitionProperty_matchRef(const AzStyleTransitionProperty* value, const AzCssPropertyType** restrict out) {
+    const AzStyleTransitionPropertyVariant_Property* casted = (const AzStyleTransitionPropertyVariant_Property*)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyTag_Property;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionProperty_matchMut(AzStyleTransitionProperty* restrict value, AzCssPropertyType* restrict * restrict out) {
+    AzStyleTransitionPropertyVariant_Property* restrict casted = (AzStyleTransitionPropertyVariant_Property* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyTag_Property;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionTimingFunction_matchRef(const AzStyleTransitionTimingFunction* value, const AzStyleCubicBezier** restrict out) {
+    const AzStyleTransitionTimingFunctionVariant_CubicBezier* casted = (const AzStyleTransitionTimingFunctionVariant_CubicBezier*)value;
+    bool valid = casted->tag == AzStyleTransitionTimingFunctionTag_CubicBezier;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionTimingFunction_matchMut(AzStyleTransitionTimingFunction* restrict value, AzStyleCubicBezier* restrict * restrict out) {
+    AzStyleTransitionTimingFunctionVariant_CubicBezier* restrict casted = (AzStyleTransitionTimingFunctionVariant_CubicBezier* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionTimingFunctionTag_CubicBezier;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationIterationCount_matchRef(const AzStyleAnimationIterationCount* value, const Azu32** restrict out) {
+    const AzStyleAnimationIterationCountVariant_Count* casted = (const AzStyleAnimationIterationCountVariant_Count*)value;
+    bool valid = casted->tag == AzStyleAnimationIterationCountTag_Count;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationIterationCount_matchMut(AzStyleAnimationIterationCount* restrict value, Azu32* restrict * restrict out) {
+    AzStyleAnimationIterationCountVariant_Count* restrict casted = (AzStyleAnimationIterationCountVariant_Count* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationIterationCountTag_Count;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzStyleTransform_matchRef(const AzStyleTransform* value, const AzStyleTransformMatrix2D** restrict out) {
     const AzStyleTransformVariant_Matrix* casted = (const AzStyleTransformVariant_Matrix*)value;
     bool valid = casted->tag == AzStyleTransformTag_Matrix;
@@ -14013,6 +16991,90 @@ bool AzLayoutAlignContentValue_matchMut(AzLayoutAlignContentValue* restrict valu
     return valid;
 }
 
+bool AzLayoutGridTemplateColumnsValue_matchRef(const AzLayoutGridTemplateColumnsValue* value, const AzLayoutGridTemplateColumns** restrict out) {
+    const AzLayoutGridTemplateColumnsValueVariant_Exact* casted = (const AzLayoutGridTemplateColumnsValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutGridTemplateColumnsValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutGridTemplateColumnsValue_matchMut(AzLayoutGridTemplateColumnsValue* restrict value, AzLayoutGridTemplateColumns* restrict * restrict out) {
+    AzLayoutGridTemplateColumnsValueVariant_Exact* restrict casted = (AzLayoutGridTemplateColumnsValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutGridTemplateColumnsValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutGridTemplateRowsValue_matchRef(const AzLayoutGridTemplateRowsValue* value, const AzLayoutGridTemplateRows** restrict out) {
+    const AzLayoutGridTemplateRowsValueVariant_Exact* casted = (const AzLayoutGridTemplateRowsValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutGridTemplateRowsValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutGridTemplateRowsValue_matchMut(AzLayoutGridTemplateRowsValue* restrict value, AzLayoutGridTemplateRows* restrict * restrict out) {
+    AzLayoutGridTemplateRowsValueVariant_Exact* restrict casted = (AzLayoutGridTemplateRowsValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutGridTemplateRowsValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutGridColumnValue_matchRef(const AzLayoutGridColumnValue* value, const AzLayoutGridColumn** restrict out) {
+    const AzLayoutGridColumnValueVariant_Exact* casted = (const AzLayoutGridColumnValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutGridColumnValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutGridColumnValue_matchMut(AzLayoutGridColumnValue* restrict value, AzLayoutGridColumn* restrict * restrict out) {
+    AzLayoutGridColumnValueVariant_Exact* restrict casted = (AzLayoutGridColumnValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutGridColumnValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutGridRowValue_matchRef(const AzLayoutGridRowValue* value, const AzLayoutGridRow** restrict out) {
+    const AzLayoutGridRowValueVariant_Exact* casted = (const AzLayoutGridRowValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutGridRowValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutGridRowValue_matchMut(AzLayoutGridRowValue* restrict value, AzLayoutGridRow* restrict * restrict out) {
+    AzLayoutGridRowValueVariant_Exact* restrict casted = (AzLayoutGridRowValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutGridRowValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutColumnGapValue_matchRef(const AzLayoutColumnGapValue* value, const AzLayoutColumnGap** restrict out) {
+    const AzLayoutColumnGapValueVariant_Exact* casted = (const AzLayoutColumnGapValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutColumnGapValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutColumnGapValue_matchMut(AzLayoutColumnGapValue* restrict value, AzLayoutColumnGap* restrict * restrict out) {
+    AzLayoutColumnGapValueVariant_Exact* restrict casted = (AzLayoutColumnGapValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutColumnGapValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutRowGapValue_matchRef(const AzLayoutRowGapValue* value, const AzLayoutRowGap** restrict out) {
+    const AzLayoutRowGapValueVariant_Exact* casted = (const AzLayoutRowGapValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutRowGapValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutRowGapValue_matchMut(AzLayoutRowGapValue* restrict value, AzLayoutRowGap* restrict * restrict out) {
+    AzLayoutRowGapValueVariant_Exact* restrict casted = (AzLayoutRowGapValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutRowGapValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutAlignItemsValue_matchRef(const AzLayoutAlignItemsValue* value, const AzLayoutAlignItems** restrict out) {
     const AzLayoutAlignItemsValueVariant_Exact* casted = (const AzLayoutAlignItemsValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutAlignItemsValueTag_Exact;
@@ -14125,6 +17187,20 @@ bool AzLayoutFloatValue_matchMut(AzLayoutFloatValue* restrict value, AzLayoutFlo
     return valid;
 }
 
+bool AzLayoutClearValue_matchRef(const AzLayoutClearValue* value, const AzLayoutClear** restrict out) {
+    const AzLayoutClearValueVariant_Exact* casted = (const AzLayoutClearValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutClearValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutClearValue_matchMut(AzLayoutClearValue* restrict value, AzLayoutClear* restrict * restrict out) {
+    AzLayoutClearValueVariant_Exact* restrict casted = (AzLayoutClearValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutClearValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutHeightValue_matchRef(const AzLayoutHeightValue* value, const AzLayoutHeight** restrict out) {
     const AzLayoutHeightValueVariant_Exact* casted = (const AzLayoutHeightValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutHeightValueTag_Exact;
@@ -14909,6 +17985,146 @@ bool AzStyleBackfaceVisibilityValue_matchMut(AzStyleBackfaceVisibilityValue* res
     return valid;
 }
 
+bool AzStyleTransitionPropertyVecValue_matchRef(const AzStyleTransitionPropertyVecValue* value, const AzStyleTransitionPropertyVec** restrict out) {
+    const AzStyleTransitionPropertyVecValueVariant_Exact* casted = (const AzStyleTransitionPropertyVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionPropertyVecValue_matchMut(AzStyleTransitionPropertyVecValue* restrict value, AzStyleTransitionPropertyVec* restrict * restrict out) {
+    AzStyleTransitionPropertyVecValueVariant_Exact* restrict casted = (AzStyleTransitionPropertyVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionDurationVecValue_matchRef(const AzStyleTransitionDurationVecValue* value, const AzStyleTransitionDurationVec** restrict out) {
+    const AzStyleTransitionDurationVecValueVariant_Exact* casted = (const AzStyleTransitionDurationVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTransitionDurationVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionDurationVecValue_matchMut(AzStyleTransitionDurationVecValue* restrict value, AzStyleTransitionDurationVec* restrict * restrict out) {
+    AzStyleTransitionDurationVecValueVariant_Exact* restrict casted = (AzStyleTransitionDurationVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionDurationVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionTimingFunctionVecValue_matchRef(const AzStyleTransitionTimingFunctionVecValue* value, const AzStyleTransitionTimingFunctionVec** restrict out) {
+    const AzStyleTransitionTimingFunctionVecValueVariant_Exact* casted = (const AzStyleTransitionTimingFunctionVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTransitionTimingFunctionVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionTimingFunctionVecValue_matchMut(AzStyleTransitionTimingFunctionVecValue* restrict value, AzStyleTransitionTimingFunctionVec* restrict * restrict out) {
+    AzStyleTransitionTimingFunctionVecValueVariant_Exact* restrict casted = (AzStyleTransitionTimingFunctionVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionTimingFunctionVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionDelayVecValue_matchRef(const AzStyleTransitionDelayVecValue* value, const AzStyleTransitionDelayVec** restrict out) {
+    const AzStyleTransitionDelayVecValueVariant_Exact* casted = (const AzStyleTransitionDelayVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTransitionDelayVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionDelayVecValue_matchMut(AzStyleTransitionDelayVecValue* restrict value, AzStyleTransitionDelayVec* restrict * restrict out) {
+    AzStyleTransitionDelayVecValueVariant_Exact* restrict casted = (AzStyleTransitionDelayVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionDelayVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationNameVecValue_matchRef(const AzStyleAnimationNameVecValue* value, const AzStyleAnimationNameVec** restrict out) {
+    const AzStyleAnimationNameVecValueVariant_Exact* casted = (const AzStyleAnimationNameVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleAnimationNameVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationNameVecValue_matchMut(AzStyleAnimationNameVecValue* restrict value, AzStyleAnimationNameVec* restrict * restrict out) {
+    AzStyleAnimationNameVecValueVariant_Exact* restrict casted = (AzStyleAnimationNameVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationNameVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationDurationVecValue_matchRef(const AzStyleAnimationDurationVecValue* value, const AzStyleAnimationDurationVec** restrict out) {
+    const AzStyleAnimationDurationVecValueVariant_Exact* casted = (const AzStyleAnimationDurationVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleAnimationDurationVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationDurationVecValue_matchMut(AzStyleAnimationDurationVecValue* restrict value, AzStyleAnimationDurationVec* restrict * restrict out) {
+    AzStyleAnimationDurationVecValueVariant_Exact* restrict casted = (AzStyleAnimationDurationVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationDurationVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationIterationCountVecValue_matchRef(const AzStyleAnimationIterationCountVecValue* value, const AzStyleAnimationIterationCountVec** restrict out) {
+    const AzStyleAnimationIterationCountVecValueVariant_Exact* casted = (const AzStyleAnimationIterationCountVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleAnimationIterationCountVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationIterationCountVecValue_matchMut(AzStyleAnimationIterationCountVecValue* restrict value, AzStyleAnimationIterationCountVec* restrict * restrict out) {
+    AzStyleAnimationIterationCountVecValueVariant_Exact* restrict casted = (AzStyleAnimationIterationCountVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationIterationCountVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationDirectionVecValue_matchRef(const AzStyleAnimationDirectionVecValue* value, const AzStyleAnimationDirectionVec** restrict out) {
+    const AzStyleAnimationDirectionVecValueVariant_Exact* casted = (const AzStyleAnimationDirectionVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleAnimationDirectionVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationDirectionVecValue_matchMut(AzStyleAnimationDirectionVecValue* restrict value, AzStyleAnimationDirectionVec* restrict * restrict out) {
+    AzStyleAnimationDirectionVecValueVariant_Exact* restrict casted = (AzStyleAnimationDirectionVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationDirectionVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationFillModeVecValue_matchRef(const AzStyleAnimationFillModeVecValue* value, const AzStyleAnimationFillModeVec** restrict out) {
+    const AzStyleAnimationFillModeVecValueVariant_Exact* casted = (const AzStyleAnimationFillModeVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleAnimationFillModeVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationFillModeVecValue_matchMut(AzStyleAnimationFillModeVecValue* restrict value, AzStyleAnimationFillModeVec* restrict * restrict out) {
+    AzStyleAnimationFillModeVecValueVariant_Exact* restrict casted = (AzStyleAnimationFillModeVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationFillModeVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationDelayVecValue_matchRef(const AzStyleAnimationDelayVecValue* value, const AzStyleAnimationDelayVec** restrict out) {
+    const AzStyleAnimationDelayVecValueVariant_Exact* casted = (const AzStyleAnimationDelayVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleAnimationDelayVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationDelayVecValue_matchMut(AzStyleAnimationDelayVecValue* restrict value, AzStyleAnimationDelayVec* restrict * restrict out) {
+    AzStyleAnimationDelayVecValueVariant_Exact* restrict casted = (AzStyleAnimationDelayVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationDelayVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleTextColorValue** restrict out) {
     const AzCssPropertyVariant_TextColor* casted = (const AzCssPropertyVariant_TextColor*)value;
     bool valid = casted->tag == AzCssPropertyTag_TextColor;
@@ -15063,6 +18279,20 @@ bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzLayoutFloatValue* r
     return valid;
 }
 
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzLayoutClearValue** restrict out) {
+    const AzCssPropertyVariant_Clear* casted = (const AzCssPropertyVariant_Clear*)value;
+    bool valid = casted->tag == AzCssPropertyTag_Clear;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzLayoutClearValue* restrict * restrict out) {
+    AzCssPropertyVariant_Clear* restrict casted = (AzCssPropertyVariant_Clear* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_Clear;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssProperty_matchRef(const AzCssProperty* value, const AzLayoutBoxSizingValue** restrict out) {
     const AzCssPropertyVariant_BoxSizing* casted = (const AzCssPropertyVariant_BoxSizing*)value;
     bool valid = casted->tag == AzCssPropertyTag_BoxSizing;
@@ -15329,21 +18559,105 @@ bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzLayoutAlignContentV
     return valid;
 }
 
-bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleBackgroundContentVecValue** restrict out) {
-    const AzCssPropertyVariant_BackgroundContent* casted = (const AzCssPropertyVariant_BackgroundContent*)value;
-    bool valid = casted->tag == AzCssPropertyTag_BackgroundContent;
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzLayoutGridTemplateColumnsValue** restrict out) {
+    const AzCssPropertyVariant_GridTemplateColumns* casted = (const AzCssPropertyVariant_GridTemplateColumns*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridTemplateColumns;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzStyleBackgroundContentVecValue* restrict * restrict out) {
-    AzCssPropertyVariant_BackgroundContent* restrict casted = (AzCssPropertyVariant_BackgroundContent* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_BackgroundContent;
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzLayoutGridTemplateColumnsValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridTemplateColumns* restrict casted = (AzCssPropertyVariant_GridTemplateColumns* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridTemplateColumns;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleBackgroundPositionVecValue** restrict out) {
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzLayoutGridTemplateRowsValue** restrict out) {
+    const AzCssPropertyVariant_GridTemplateRows* casted = (const AzCssPropertyVariant_GridTemplateRows*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridTemplateRows;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzLayoutGridTemplateRowsValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridTemplateRows* restrict casted = (AzCssPropertyVariant_GridTemplateRows* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridTemplateRows;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzLayoutGridColumnValue** restrict out) {
+    const AzCssPropertyVariant_GridColumn* casted = (const AzCssPropertyVariant_GridColumn*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridColumn;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzLayoutGridColumnValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridColumn* restrict casted = (AzCssPropertyVariant_GridColumn* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridColumn;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzLayoutGridRowValue** restrict out) {
+    const AzCssPropertyVariant_GridRow* casted = (const AzCssPropertyVariant_GridRow*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridRow;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzLayoutGridRowValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridRow* restrict casted = (AzCssPropertyVariant_GridRow* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridRow;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzLayoutColumnGapValue** restrict out) {
+    const AzCssPropertyVariant_ColumnGap* casted = (const AzCssPropertyVariant_ColumnGap*)value;
+    bool valid = casted->tag == AzCssPropertyTag_ColumnGap;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzLayoutColumnGapValue* restrict * restrict out) {
+    AzCssPropertyVariant_ColumnGap* restrict casted = (AzCssPropertyVariant_ColumnGap* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_ColumnGap;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzLayoutRowGapValue** restrict out) {
+    const AzCssPropertyVariant_RowGap* casted = (const AzCssPropertyVariant_RowGap*)value;
+    bool valid = casted->tag == AzCssPropertyTag_RowGap;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzLayoutRowGapValue* restrict * restrict out) {
+    AzCssPropertyVariant_RowGap* restrict casted = (AzCssPropertyVariant_RowGap* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_RowGap;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleBackgroundContentVecValue** restrict out) {
+    const AzCssPropertyVariant_BackgroundContent* casted = (const AzCssPropertyVariant_BackgroundContent*)value;
+    bool valid = casted->tag == AzCssPropertyTag_BackgroundContent;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzStyleBackgroundContentVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_BackgroundContent* restrict casted = (AzCssPropertyVariant_BackgroundContent* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_BackgroundContent;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleBackgroundPositionVecValue** restrict out) {
     const AzCssPropertyVariant_BackgroundPosition* casted = (const AzCssPropertyVariant_BackgroundPosition*)value;
     bool valid = casted->tag == AzCssPropertyTag_BackgroundPosition;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -15889,6 +19203,160 @@ bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzStyleBackfaceVisibi
     return valid;
 }
 
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleTransitionPropertyVecValue** restrict out) {
+    const AzCssPropertyVariant_TransitionProperty* casted = (const AzCssPropertyVariant_TransitionProperty*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionProperty;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzStyleTransitionPropertyVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_TransitionProperty* restrict casted = (AzCssPropertyVariant_TransitionProperty* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionProperty;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleTransitionDurationVecValue** restrict out) {
+    const AzCssPropertyVariant_TransitionDuration* casted = (const AzCssPropertyVariant_TransitionDuration*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionDuration;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzStyleTransitionDurationVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_TransitionDuration* restrict casted = (AzCssPropertyVariant_TransitionDuration* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionDuration;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleTransitionTimingFunctionVecValue** restrict out) {
+    const AzCssPropertyVariant_TransitionTimingFunction* casted = (const AzCssPropertyVariant_TransitionTimingFunction*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionTimingFunction;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzStyleTransitionTimingFunctionVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_TransitionTimingFunction* restrict casted = (AzCssPropertyVariant_TransitionTimingFunction* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionTimingFunction;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleTransitionDelayVecValue** restrict out) {
+    const AzCssPropertyVariant_TransitionDelay* casted = (const AzCssPropertyVariant_TransitionDelay*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionDelay;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzStyleTransitionDelayVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_TransitionDelay* restrict casted = (AzCssPropertyVariant_TransitionDelay* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionDelay;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleAnimationNameVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationName* casted = (const AzCssPropertyVariant_AnimationName*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationName;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzStyleAnimationNameVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationName* restrict casted = (AzCssPropertyVariant_AnimationName* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationName;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleAnimationDurationVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationDuration* casted = (const AzCssPropertyVariant_AnimationDuration*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationDuration;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzStyleAnimationDurationVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationDuration* restrict casted = (AzCssPropertyVariant_AnimationDuration* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationDuration;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleTransitionTimingFunctionVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationTimingFunction* casted = (const AzCssPropertyVariant_AnimationTimingFunction*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationTimingFunction;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzStyleTransitionTimingFunctionVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationTimingFunction* restrict casted = (AzCssPropertyVariant_AnimationTimingFunction* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationTimingFunction;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleAnimationIterationCountVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationIterationCount* casted = (const AzCssPropertyVariant_AnimationIterationCount*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationIterationCount;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzStyleAnimationIterationCountVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationIterationCount* restrict casted = (AzCssPropertyVariant_AnimationIterationCount* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationIterationCount;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleAnimationDirectionVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationDirection* casted = (const AzCssPropertyVariant_AnimationDirection*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationDirection;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzStyleAnimationDirectionVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationDirection* restrict casted = (AzCssPropertyVariant_AnimationDirection* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationDirection;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleAnimationFillModeVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationFillMode* casted = (const AzCssPropertyVariant_AnimationFillMode*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationFillMode;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzStyleAnimationFillModeVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationFillMode* restrict casted = (AzCssPropertyVariant_AnimationFillMode* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationFillMode;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRef(const AzCssProperty* value, const AzStyleAnimationDelayVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationDelay* casted = (const AzCssPropertyVariant_AnimationDelay*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationDelay;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMut(AzCssProperty* restrict value, AzStyleAnimationDelayVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationDelay* restrict casted = (AzCssPropertyVariant_AnimationDelay* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationDelay;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzTextInputSelection_matchRef(const AzTextInputSelection* value, const AzTextInputSelectionRange** restrict out) {
     const AzTextInputSelectionVariant_FromTo* casted = (const AzTextInputSelectionVariant_FromTo*)value;
     bool valid = casted->tag == AzTextInputSelectionTag_FromTo;
@@ -15903,6 +19371,20 @@ bool AzTextInputSelection_matchMut(AzTextInputSelection* restrict value, AzTextI
     return valid;
 }
 
+bool AzSpreadsheetDrag_matchRef(const AzSpreadsheetDrag* value, const AzSpreadsheetColumnResize** restrict out) {
+    const AzSpreadsheetDragVariant_ResizeColumn* casted = (const AzSpreadsheetDragVariant_ResizeColumn*)value;
+    bool valid = casted->tag == AzSpreadsheetDragTag_ResizeColumn;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzSpreadsheetDrag_matchMut(AzSpreadsheetDrag* restrict value, AzSpreadsheetColumnResize* restrict * restrict out) {
+    AzSpreadsheetDragVariant_ResizeColumn* restrict casted = (AzSpreadsheetDragVariant_ResizeColumn* restrict)value;
+    bool valid = casted->tag == AzSpreadsheetDragTag_ResizeColumn;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssPropertySource_matchRef(const AzCssPropertySource* value, const AzCssPath** restrict out) {
     const AzCssPropertySourceVariant_Css* casted = (const AzCssPropertySourceVariant_Css*)value;
     bool valid = casted->tag == AzCssPropertySourceTag_Css;
@@ -16491,6 +19973,20 @@ bool AzAccessibilityStateVecDestructor_matchMut(AzAccessibilityStateVecDestructo
     return valid;
 }
 
+bool AzAccessibilityNodeVecDestructor_matchRef(const AzAccessibilityNodeVecDestructor* value, const AzAccessibilityNodeVecDestructorType** restrict out) {
+    const AzAccessibilityNodeVecDestructorVariant_External* casted = (const AzAccessibilityNodeVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzAccessibilityNodeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzAccessibilityNodeVecDestructor_matchMut(AzAccessibilityNodeVecDestructor* restrict value, AzAccessibilityNodeVecDestructorType* restrict * restrict out) {
+    AzAccessibilityNodeVecDestructorVariant_External* restrict casted = (AzAccessibilityNodeVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzAccessibilityNodeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzMenuItemVecDestructor_matchRef(const AzMenuItemVecDestructor* value, const AzMenuItemVecDestructorType** restrict out) {
     const AzMenuItemVecDestructorVariant_External* casted = (const AzMenuItemVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzMenuItemVecDestructorTag_External;
@@ -16869,6 +20365,20 @@ bool AzVirtualKeyCodeVecDestructor_matchMut(AzVirtualKeyCodeVecDestructor* restr
     return valid;
 }
 
+bool AzKeyboardShortcutVecDestructor_matchRef(const AzKeyboardShortcutVecDestructor* value, const AzKeyboardShortcutVecDestructorType** restrict out) {
+    const AzKeyboardShortcutVecDestructorVariant_External* casted = (const AzKeyboardShortcutVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzKeyboardShortcutVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzKeyboardShortcutVecDestructor_matchMut(AzKeyboardShortcutVecDestructor* restrict value, AzKeyboardShortcutVecDestructorType* restrict * restrict out) {
+    AzKeyboardShortcutVecDestructorVariant_External* restrict casted = (AzKeyboardShortcutVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzKeyboardShortcutVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCascadeInfoVecDestructor_matchRef(const AzCascadeInfoVecDestructor* value, const AzCascadeInfoVecDestructorType** restrict out) {
     const AzCascadeInfoVecDestructorVariant_External* casted = (const AzCascadeInfoVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzCascadeInfoVecDestructorTag_External;
@@ -16939,6 +20449,230 @@ bool AzStylesheetVecDestructor_matchMut(AzStylesheetVecDestructor* restrict valu
     return valid;
 }
 
+bool AzCssFontFaceVecDestructor_matchRef(const AzCssFontFaceVecDestructor* value, const AzCssFontFaceVecDestructorType** restrict out) {
+    const AzCssFontFaceVecDestructorVariant_External* casted = (const AzCssFontFaceVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssFontFaceVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssFontFaceVecDestructor_matchMut(AzCssFontFaceVecDestructor* restrict value, AzCssFontFaceVecDestructorType* restrict * restrict out) {
+    AzCssFontFaceVecDestructorVariant_External* restrict casted = (AzCssFontFaceVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssFontFaceVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionPropertyVecDestructor_matchRef(const AzStyleTransitionPropertyVecDestructor* value, const AzStyleTransitionPropertyVecDestructorType** restrict out) {
+    const AzStyleTransitionPropertyVecDestructorVariant_External* casted = (const AzStyleTransitionPropertyVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionPropertyVecDestructor_matchMut(AzStyleTransitionPropertyVecDestructor* restrict value, AzStyleTransitionPropertyVecDestructorType* restrict * restrict out) {
+    AzStyleTransitionPropertyVecDestructorVariant_External* restrict casted = (AzStyleTransitionPropertyVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionDurationVecDestructor_matchRef(const AzStyleTransitionDurationVecDestructor* value, const AzStyleTransitionDurationVecDestructorType** restrict out) {
+    const AzStyleTransitionDurationVecDestructorVariant_External* casted = (const AzStyleTransitionDurationVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleTransitionDurationVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionDurationVecDestructor_matchMut(AzStyleTransitionDurationVecDestructor* restrict value, AzStyleTransitionDurationVecDestructorType* restrict * restrict out) {
+    AzStyleTransitionDurationVecDestructorVariant_External* restrict casted = (AzStyleTransitionDurationVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionDurationVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionTimingFunctionVecDestructor_matchRef(const AzStyleTransitionTimingFunctionVecDestructor* value, const AzStyleTransitionTimingFunctionVecDestructorType** restrict out) {
+    const AzStyleTransitionTimingFunctionVecDestructorVariant_External* casted = (const AzStyleTransitionTimingFunctionVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleTransitionTimingFunctionVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionTimingFunctionVecDestructor_matchMut(AzStyleTransitionTimingFunctionVecDestructor* restrict value, AzStyleTransitionTimingFunctionVecDestructorType* restrict * restrict out) {
+    AzStyleTransitionTimingFunctionVecDestructorVariant_External* restrict casted = (AzStyleTransitionTimingFunctionVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionTimingFunctionVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionDelayVecDestructor_matchRef(const AzStyleTransitionDelayVecDestructor* value, const AzStyleTransitionDelayVecDestructorType** restrict out) {
+    const AzStyleTransitionDelayVecDestructorVariant_External* casted = (const AzStyleTransitionDelayVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleTransitionDelayVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionDelayVecDestructor_matchMut(AzStyleTransitionDelayVecDestructor* restrict value, AzStyleTransitionDelayVecDestructorType* restrict * restrict out) {
+    AzStyleTransitionDelayVecDestructorVariant_External* restrict casted = (AzStyleTransitionDelayVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionDelayVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationNameVecDestructor_matchRef(const AzStyleAnimationNameVecDestructor* value, const AzStyleAnimationNameVecDestructorType** restrict out) {
+    const AzStyleAnimationNameVecDestructorVariant_External* casted = (const AzStyleAnimationNameVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleAnimationNameVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationNameVecDestructor_matchMut(AzStyleAnimationNameVecDestructor* restrict value, AzStyleAnimationNameVecDestructorType* restrict * restrict out) {
+    AzStyleAnimationNameVecDestructorVariant_External* restrict casted = (AzStyleAnimationNameVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationNameVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationDurationVecDestructor_matchRef(const AzStyleAnimationDurationVecDestructor* value, const AzStyleAnimationDurationVecDestructorType** restrict out) {
+    const AzStyleAnimationDurationVecDestructorVariant_External* casted = (const AzStyleAnimationDurationVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleAnimationDurationVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationDurationVecDestructor_matchMut(AzStyleAnimationDurationVecDestructor* restrict value, AzStyleAnimationDurationVecDestructorType* restrict * restrict out) {
+    AzStyleAnimationDurationVecDestructorVariant_External* restrict casted = (AzStyleAnimationDurationVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationDurationVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationIterationCountVecDestructor_matchRef(const AzStyleAnimationIterationCountVecDestructor* value, const AzStyleAnimationIterationCountVecDestructorType** restrict out) {
+    const AzStyleAnimationIterationCountVecDestructorVariant_External* casted = (const AzStyleAnimationIterationCountVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleAnimationIterationCountVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationIterationCountVecDestructor_matchMut(AzStyleAnimationIterationCountVecDestructor* restrict value, AzStyleAnimationIterationCountVecDestructorType* restrict * restrict out) {
+    AzStyleAnimationIterationCountVecDestructorVariant_External* restrict casted = (AzStyleAnimationIterationCountVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationIterationCountVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationDirectionVecDestructor_matchRef(const AzStyleAnimationDirectionVecDestructor* value, const AzStyleAnimationDirectionVecDestructorType** restrict out) {
+    const AzStyleAnimationDirectionVecDestructorVariant_External* casted = (const AzStyleAnimationDirectionVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleAnimationDirectionVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationDirectionVecDestructor_matchMut(AzStyleAnimationDirectionVecDestructor* restrict value, AzStyleAnimationDirectionVecDestructorType* restrict * restrict out) {
+    AzStyleAnimationDirectionVecDestructorVariant_External* restrict casted = (AzStyleAnimationDirectionVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationDirectionVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationFillModeVecDestructor_matchRef(const AzStyleAnimationFillModeVecDestructor* value, const AzStyleAnimationFillModeVecDestructorType** restrict out) {
+    const AzStyleAnimationFillModeVecDestructorVariant_External* casted = (const AzStyleAnimationFillModeVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleAnimationFillModeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationFillModeVecDestructor_matchMut(AzStyleAnimationFillModeVecDestructor* restrict value, AzStyleAnimationFillModeVecDestructorType* restrict * restrict out) {
+    AzStyleAnimationFillModeVecDestructorVariant_External* restrict casted = (AzStyleAnimationFillModeVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationFillModeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationDelayVecDestructor_matchRef(const AzStyleAnimationDelayVecDestructor* value, const AzStyleAnimationDelayVecDestructorType** restrict out) {
+    const AzStyleAnimationDelayVecDestructorVariant_External* casted = (const AzStyleAnimationDelayVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleAnimationDelayVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationDelayVecDestructor_matchMut(AzStyleAnimationDelayVecDestructor* restrict value, AzStyleAnimationDelayVecDestructorType* restrict * restrict out) {
+    AzStyleAnimationDelayVecDestructorVariant_External* restrict casted = (AzStyleAnimationDelayVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationDelayVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssKeyframeVecDestructor_matchRef(const AzCssKeyframeVecDestructor* value, const AzCssKeyframeVecDestructorType** restrict out) {
+    const AzCssKeyframeVecDestructorVariant_External* casted = (const AzCssKeyframeVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssKeyframeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssKeyframeVecDestructor_matchMut(AzCssKeyframeVecDestructor* restrict value, AzCssKeyframeVecDestructorType* restrict * restrict out) {
+    AzCssKeyframeVecDestructorVariant_External* restrict casted = (AzCssKeyframeVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssKeyframeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssKeyframesVecDestructor_matchRef(const AzCssKeyframesVecDestructor* value, const AzCssKeyframesVecDestructorType** restrict out) {
+    const AzCssKeyframesVecDestructorVariant_External* casted = (const AzCssKeyframesVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssKeyframesVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssKeyframesVecDestructor_matchMut(AzCssKeyframesVecDestructor* restrict value, AzCssKeyframesVecDestructorType* restrict * restrict out) {
+    AzCssKeyframesVecDestructorVariant_External* restrict casted = (AzCssKeyframesVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssKeyframesVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridTrackSizingVecDestructor_matchRef(const AzGridTrackSizingVecDestructor* value, const AzGridTrackSizingVecDestructorType** restrict out) {
+    const AzGridTrackSizingVecDestructorVariant_External* casted = (const AzGridTrackSizingVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzGridTrackSizingVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridTrackSizingVecDestructor_matchMut(AzGridTrackSizingVecDestructor* restrict value, AzGridTrackSizingVecDestructorType* restrict * restrict out) {
+    AzGridTrackSizingVecDestructorVariant_External* restrict casted = (AzGridTrackSizingVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzGridTrackSizingVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaQueryVecDestructor_matchRef(const AzCssMediaQueryVecDestructor* value, const AzCssMediaQueryVecDestructorType** restrict out) {
+    const AzCssMediaQueryVecDestructorVariant_External* casted = (const AzCssMediaQueryVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssMediaQueryVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaQueryVecDestructor_matchMut(AzCssMediaQueryVecDestructor* restrict value, AzCssMediaQueryVecDestructorType* restrict * restrict out) {
+    AzCssMediaQueryVecDestructorVariant_External* restrict casted = (AzCssMediaQueryVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssMediaQueryVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaConditionVecDestructor_matchRef(const AzCssMediaConditionVecDestructor* value, const AzCssMediaConditionVecDestructorType** restrict out) {
+    const AzCssMediaConditionVecDestructorVariant_External* casted = (const AzCssMediaConditionVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssMediaConditionVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaConditionVecDestructor_matchMut(AzCssMediaConditionVecDestructor* restrict value, AzCssMediaConditionVecDestructorType* restrict * restrict out) {
+    AzCssMediaConditionVecDestructorVariant_External* restrict casted = (AzCssMediaConditionVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssMediaConditionVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssRuleBlockVecDestructor_matchRef(const AzCssRuleBlockVecDestructor* value, const AzCssRuleBlockVecDestructorType** restrict out) {
     const AzCssRuleBlockVecDestructorVariant_External* casted = (const AzCssRuleBlockVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzCssRuleBlockVecDestructorTag_External;
@@ -17191,6 +20925,48 @@ bool AzNodeDataVecDestructor_matchMut(AzNodeDataVecDestructor* restrict value, A
     return valid;
 }
 
+bool AzTextEditSnapshotVecDestructor_matchRef(const AzTextEditSnapshotVecDestructor* value, const AzTextEditSnapshotVecDestructorType** restrict out) {
+    const AzTextEditSnapshotVecDestructorVariant_External* casted = (const AzTextEditSnapshotVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTextEditSnapshotVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTextEditSnapshotVecDestructor_matchMut(AzTextEditSnapshotVecDestructor* restrict value, AzTextEditSnapshotVecDestructorType* restrict * restrict out) {
+    AzTextEditSnapshotVecDestructorVariant_External* restrict casted = (AzTextEditSnapshotVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTextEditSnapshotVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzSpreadsheetSizeOverrideVecDestructor_matchRef(const AzSpreadsheetSizeOverrideVecDestructor* value, const AzSpreadsheetSizeOverrideVecDestructorType** restrict out) {
+    const AzSpreadsheetSizeOverrideVecDestructorVariant_External* casted = (const AzSpreadsheetSizeOverrideVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzSpreadsheetSizeOverrideVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzSpreadsheetSizeOverrideVecDestructor_matchMut(AzSpreadsheetSizeOverrideVecDestructor* restrict value, AzSpreadsheetSizeOverrideVecDestructorType* restrict * restrict out) {
+    AzSpreadsheetSizeOverrideVecDestructorVariant_External* restrict casted = (AzSpreadsheetSizeOverrideVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzSpreadsheetSizeOverrideVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzSpreadsheetCellContentVecDestructor_matchRef(const AzSpreadsheetCellContentVecDestructor* value, const AzSpreadsheetCellContentVecDestructorType** restrict out) {
+    const AzSpreadsheetCellContentVecDestructorVariant_External* casted = (const AzSpreadsheetCellContentVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzSpreadsheetCellContentVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzSpreadsheetCellContentVecDestructor_matchMut(AzSpreadsheetCellContentVecDestructor* restrict value, AzSpreadsheetCellContentVecDestructorType* restrict * restrict out) {
+    AzSpreadsheetCellContentVecDestructorVariant_External* restrict casted = (AzSpreadsheetCellContentVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzSpreadsheetCellContentVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionColorInputOnValueChange_matchRef(const AzOptionColorInputOnValueChange* value, const AzColorInputOnValueChange** restrict out) {
     const AzOptionColorInputOnValueChangeVariant_Some* casted = (const AzOptionColorInputOnValueChangeVariant_Some*)value;
     bool valid = casted->tag == AzOptionColorInputOnValueChangeTag_Some;
@@ -17303,6 +21079,174 @@ bool AzOptionNumberInputOnValueChange_matchMut(AzOptionNumberInputOnValueChange*
     return valid;
 }
 
+bool AzOptionTextEditOnTextChange_matchRef(const AzOptionTextEditOnTextChange* value, const AzTextEditOnTextChange** restrict out) {
+    const AzOptionTextEditOnTextChangeVariant_Some* casted = (const AzOptionTextEditOnTextChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTextEditOnTextChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextEditOnTextChange_matchMut(AzOptionTextEditOnTextChange* restrict value, AzTextEditOnTextChange* restrict * restrict out) {
+    AzOptionTextEditOnTextChangeVariant_Some* restrict casted = (AzOptionTextEditOnTextChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTextEditOnTextChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextEditOnFocusLost_matchRef(const AzOptionTextEditOnFocusLost* value, const AzTextEditOnFocusLost** restrict out) {
+    const AzOptionTextEditOnFocusLostVariant_Some* casted = (const AzOptionTextEditOnFocusLostVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTextEditOnFocusLostTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextEditOnFocusLost_matchMut(AzOptionTextEditOnFocusLost* restrict value, AzTextEditOnFocusLost* restrict * restrict out) {
+    AzOptionTextEditOnFocusLostVariant_Some* restrict casted = (AzOptionTextEditOnFocusLostVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTextEditOnFocusLostTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextEditKind_matchRef(const AzOptionTextEditKind* value, const AzTextEditKind** restrict out) {
+    const AzOptionTextEditKindVariant_Some* casted = (const AzOptionTextEditKindVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTextEditKindTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextEditKind_matchMut(AzOptionTextEditKind* restrict value, AzTextEditKind* restrict * restrict out) {
+    AzOptionTextEditKindVariant_Some* restrict casted = (AzOptionTextEditKindVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTextEditKindTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSpreadsheetCellClick_matchRef(const AzOptionSpreadsheetCellClick* value, const AzSpreadsheetCellClick** restrict out) {
+    const AzOptionSpreadsheetCellClickVariant_Some* casted = (const AzOptionSpreadsheetCellClickVariant_Some*)value;
+    bool valid = casted->tag == AzOptionSpreadsheetCellClickTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSpreadsheetCellClick_matchMut(AzOptionSpreadsheetCellClick* restrict value, AzSpreadsheetCellClick* restrict * restrict out) {
+    AzOptionSpreadsheetCellClickVariant_Some* restrict casted = (AzOptionSpreadsheetCellClickVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionSpreadsheetCellClickTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSpreadsheetCellEdit_matchRef(const AzOptionSpreadsheetCellEdit* value, const AzSpreadsheetCellEdit** restrict out) {
+    const AzOptionSpreadsheetCellEditVariant_Some* casted = (const AzOptionSpreadsheetCellEditVariant_Some*)value;
+    bool valid = casted->tag == AzOptionSpreadsheetCellEditTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSpreadsheetCellEdit_matchMut(AzOptionSpreadsheetCellEdit* restrict value, AzSpreadsheetCellEdit* restrict * restrict out) {
+    AzOptionSpreadsheetCellEditVariant_Some* restrict casted = (AzOptionSpreadsheetCellEditVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionSpreadsheetCellEditTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSliderOnValueChange_matchRef(const AzOptionSliderOnValueChange* value, const AzSliderOnValueChange** restrict out) {
+    const AzOptionSliderOnValueChangeVariant_Some* casted = (const AzOptionSliderOnValueChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionSliderOnValueChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSliderOnValueChange_matchMut(AzOptionSliderOnValueChange* restrict value, AzSliderOnValueChange* restrict * restrict out) {
+    AzOptionSliderOnValueChangeVariant_Some* restrict casted = (AzOptionSliderOnValueChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionSliderOnValueChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDropDownOnChoiceChange_matchRef(const AzOptionDropDownOnChoiceChange* value, const AzDropDownOnChoiceChange** restrict out) {
+    const AzOptionDropDownOnChoiceChangeVariant_Some* casted = (const AzOptionDropDownOnChoiceChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionDropDownOnChoiceChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionDropDownOnChoiceChange_matchMut(AzOptionDropDownOnChoiceChange* restrict value, AzDropDownOnChoiceChange* restrict * restrict out) {
+    AzOptionDropDownOnChoiceChangeVariant_Some* restrict casted = (AzOptionDropDownOnChoiceChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionDropDownOnChoiceChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSpreadsheetOnCellsChange_matchRef(const AzOptionSpreadsheetOnCellsChange* value, const AzSpreadsheetOnCellsChange** restrict out) {
+    const AzOptionSpreadsheetOnCellsChangeVariant_Some* casted = (const AzOptionSpreadsheetOnCellsChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionSpreadsheetOnCellsChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSpreadsheetOnCellsChange_matchMut(AzOptionSpreadsheetOnCellsChange* restrict value, AzSpreadsheetOnCellsChange* restrict * restrict out) {
+    AzOptionSpreadsheetOnCellsChangeVariant_Some* restrict casted = (AzOptionSpreadsheetOnCellsChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionSpreadsheetOnCellsChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSpreadsheetOnSelectionChange_matchRef(const AzOptionSpreadsheetOnSelectionChange* value, const AzSpreadsheetOnSelectionChange** restrict out) {
+    const AzOptionSpreadsheetOnSelectionChangeVariant_Some* casted = (const AzOptionSpreadsheetOnSelectionChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionSpreadsheetOnSelectionChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSpreadsheetOnSelectionChange_matchMut(AzOptionSpreadsheetOnSelectionChange* restrict value, AzSpreadsheetOnSelectionChange* restrict * restrict out) {
+    AzOptionSpreadsheetOnSelectionChangeVariant_Some* restrict casted = (AzOptionSpreadsheetOnSelectionChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionSpreadsheetOnSelectionChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSpreadsheetOnColumnResize_matchRef(const AzOptionSpreadsheetOnColumnResize* value, const AzSpreadsheetOnColumnResize** restrict out) {
+    const AzOptionSpreadsheetOnColumnResizeVariant_Some* casted = (const AzOptionSpreadsheetOnColumnResizeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionSpreadsheetOnColumnResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSpreadsheetOnColumnResize_matchMut(AzOptionSpreadsheetOnColumnResize* restrict value, AzSpreadsheetOnColumnResize* restrict * restrict out) {
+    AzOptionSpreadsheetOnColumnResizeVariant_Some* restrict casted = (AzOptionSpreadsheetOnColumnResizeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionSpreadsheetOnColumnResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSpreadsheetCellSelection_matchRef(const AzOptionSpreadsheetCellSelection* value, const AzSpreadsheetCellSelection** restrict out) {
+    const AzOptionSpreadsheetCellSelectionVariant_Some* casted = (const AzOptionSpreadsheetCellSelectionVariant_Some*)value;
+    bool valid = casted->tag == AzOptionSpreadsheetCellSelectionTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSpreadsheetCellSelection_matchMut(AzOptionSpreadsheetCellSelection* restrict value, AzSpreadsheetCellSelection* restrict * restrict out) {
+    AzOptionSpreadsheetCellSelectionVariant_Some* restrict casted = (AzOptionSpreadsheetCellSelectionVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionSpreadsheetCellSelectionTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionAccessibilityNode_matchRef(const AzOptionAccessibilityNode* value, const AzAccessibilityNode** restrict out) {
+    const AzOptionAccessibilityNodeVariant_Some* casted = (const AzOptionAccessibilityNodeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionAccessibilityNodeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionAccessibilityNode_matchMut(AzOptionAccessibilityNode* restrict value, AzAccessibilityNode* restrict * restrict out) {
+    AzOptionAccessibilityNodeVariant_Some* restrict casted = (AzOptionAccessibilityNodeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionAccessibilityNodeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionMenuItemIcon_matchRef(const AzOptionMenuItemIcon* value, const AzMenuItemIcon** restrict out) {
     const AzOptionMenuItemIconVariant_Some* casted = (const AzOptionMenuItemIconVariant_Some*)value;
     bool valid = casted->tag == AzOptionMenuItemIconTag_Some;
@@ -54,6 +54,25 @@ namespace dll {
     struct NumberInputState;
     using NumberInputOnValueChangeCallbackType = Update(*)(RefAny* restrict, NumberInputState* const, CallbackInfo* restrict);
     
+    struct TextEditState;
+    using TextEditOnTextChangeCallbackType = Update(*)(RefAny* restrict, TextEditState* const, CallbackInfo* restrict);
+    
+    using TextEditOnFocusLostCallbackType = Update(*)(RefAny* restrict, TextEditState* const, CallbackInfo* restrict);
+    
+    struct SliderState;
+    using SliderOnValueChangeCallbackType = Update(*)(RefAny* restrict, SliderState* const, CallbackInfo* restrict);
+    
+    struct DropDownState;
+    using DropDownOnChoiceChangeCallbackType = Update(*)(RefAny* restrict, DropDownState* const, CallbackInfo* restrict);
+    
+    struct SpreadsheetState;
+    struct SpreadsheetCellSelection;
+    using SpreadsheetOnCellsChangeCallbackType = Update(*)(RefAny* restrict, SpreadsheetState* const, SpreadsheetCellSelection, CallbackInfo* restrict);
+    
+    using SpreadsheetOnSelectionChangeCallbackType = Update(*)(RefAny* restrict, SpreadsheetState* const, CallbackInfo* restrict);
+    
+    using SpreadsheetOnColumnResizeCallbackType = Update(*)(RefAny* restrict, SpreadsheetState* const, size_t, CallbackInfo* restrict);
+    
     using ParsedFontDestructorFnType = void(*)(void* restrict);
     
     struct InstantPtr;
@@ -94,6 +113,9 @@ namespace dll {
     struct AccessibilityStateVec;
     using AccessibilityStateVecDestructorType = void(*)(AccessibilityStateVec* restrict);
     
+    struct AccessibilityNodeVec;
+    using AccessibilityNodeVecDestructorType = void(*)(AccessibilityNodeVec* restrict);
+    
     struct MenuItemVec;
     using MenuItemVecDestructorType = void(*)(MenuItemVec* restrict);
     
@@ -175,6 +197,9 @@ namespace dll {
     struct VirtualKeyCodeVec;
     using VirtualKeyCodeVecDestructorType = void(*)(VirtualKeyCodeVec* restrict);
     
+    struct KeyboardShortcutVec;
+    using KeyboardShortcutVecDestructorType = void(*)(KeyboardShortcutVec* restrict);
+    
     struct CascadeInfoVec;
     using CascadeInfoVecDestructorType = void(*)(CascadeInfoVec* restrict);
     
@@ -190,6 +215,54 @@ namespace dll {
     struct StylesheetVec;
     using StylesheetVecDestructorType = void(*)(StylesheetVec* restrict);
     
+    struct CssFontFaceVec;
+    using CssFontFaceVecDestructorType = void(*)(CssFontFaceVec* restrict);
+    
+    struct StyleTransitionPropertyVec;
+    using StyleTransitionPropertyVecDestructorType = void(*)(StyleTransitionPropertyVec* restrict);
+    
+    struct StyleTransitionDurationVec;
+    using StyleTransitionDurationVecDestructorType = void(*)(StyleTransitionDurationVec* restrict);
+    
+    struct StyleTransitionTimingFunctionVec;
+    using StyleTransitionTimingFunctionVecDestructorType = void(*)(StyleTransitionTimingFunctionVec* restrict);
+    
+    struct StyleTransitionDelayVec;
+    using StyleTransitionDelayVecDestructorType = void(*)(StyleTransitionDelayVec* restrict);
+    
+    struct StyleAnimationNameVec;
+    using StyleAnimationNameVecDestructorType = void(*)(StyleAnimationNameVec* restrict);
+    
+    struct StyleAnimationDurationVec;
+    using StyleAnimationDurationVecDestructorType = void(*)(StyleAnimationDurationVec* restrict);
+    
+    struct StyleAnimationIterationCountVec;
+    using StyleAnimationIterationCountVecDestructorType = void(*)(StyleAnimationIterationCountVec* restrict);
+    
+    struct StyleAnimationDirectionVec;
+    using StyleAnimationDirectionVecDestructorType = void(*)(StyleAnimationDirectionVec* restrict);
+    
+    struct StyleAnimationFillModeVec;
+    using StyleAnimationFillModeVecDestructorType = void(*)(StyleAnimationFillModeVec* restrict);
+    
+    struct StyleAnimationDelayVec;
+    using StyleAnimationDelayVecDestructorType = void(*)(StyleAnimationDelayVec* restrict);
+    
+    struct CssKeyframeVec;
+    using CssKeyframeVecDestructorType = void(*)(CssKeyframeVec* restrict);
+    
+    struct CssKeyframesVec;
+    using CssKeyframesVecDestructorType = void(*)(CssKeyframesVec* restrict);
+    
+    struct GridTrackSizingVec;
+    using GridTrackSizingVecDestructorType = void(*)(GridTrackSizingVec* restrict);
+    
+    struct CssMediaQueryVec;
+    using CssMediaQueryVecDestructorType = void(*)(CssMediaQueryVec* restrict);
+    
+    struct CssMediaConditionVec;
+    using CssMediaConditionVecDestructorType = void(*)(CssMediaConditionVec* restrict);
+    
     struct CssRuleBlockVec;
     using CssRuleBlockVecDestructorType = void(*)(CssRuleBlockVec* restrict);
     
@@ -244,6 +317,15 @@ namespace dll {
     struct NodeDataVec;
     using NodeDataVecDestructorType = void(*)(NodeDataVec* restrict);
     
+    struct TextEditSnapshotVec;
+    using TextEditSnapshotVecDestructorType = void(*)(TextEditSnapshotVec* restrict);
+    
+    struct SpreadsheetSizeOverrideVec;
+    using SpreadsheetSizeOverrideVecDestructorType = void(*)(SpreadsheetSizeOverrideVec* restrict);
+    
+    struct SpreadsheetCellContentVec;
+    using SpreadsheetCellContentVecDestructorType = void(*)(SpreadsheetCellContentVec* restrict);
+    
     
     struct App {
         void* ptr;
@@ -1030,6 +1112,16 @@ namespace dll {
        Disabled,
     };
     
+    enum class CssMediaOrientation {
+       Portrait,
+       Landscape,
+    };
+    
+    enum class CssMediaColorScheme {
+       Light,
+       Dark,
+    };
+    
     enum class NodeTypeKey {
        Body,
        Div,
@@ -1039,6 +1131,16 @@ namespace dll {
        IFrame,
     };
     
+    enum class CssAttributeSelectorOp {
+       Exists,
+       Equals,
+       Includes,
+       DashMatch,
+       Prefix,
+       Suffix,
+       Substring,
+    };
+    
     struct CssNthChildPattern {
         uint32_t repeat;
         uint32_t offset;
@@ -1046,6 +1148,12 @@ namespace dll {
         CssNthChildPattern() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class CssFontFaceStyle {
+       Normal,
+       Italic,
+       Oblique,
+    };
+    
     enum class CssPropertyType {
        TextColor,
        FontSize,
@@ -1058,6 +1166,7 @@ namespace dll {
        Cursor,
        Display,
        Float,
+       Clear,
        BoxSizing,
        Width,
        Height,
@@ -1077,6 +1186,12 @@ namespace dll {
        JustifyContent,
        AlignItems,
        AlignContent,
+       GridTemplateColumns,
+       GridTemplateRows,
+       GridColumn,
+       GridRow,
+       ColumnGap,
+       RowGap,
        BackgroundContent,
        BackgroundPosition,
        BackgroundSize,
@@ -1117,6 +1232,17 @@ namespace dll {
        TransformOrigin,
        PerspectiveOrigin,
        BackfaceVisibility,
+       TransitionProperty,
+       TransitionDuration,
+       TransitionTimingFunction,
+       TransitionDelay,
+       AnimationName,
+       AnimationDuration,
+       AnimationTimingFunction,
+       AnimationIterationCount,
+       AnimationDirection,
+       AnimationFillMode,
+       AnimationDelay,
     };
     
     struct ColorU {
@@ -1155,6 +1281,22 @@ namespace dll {
        SpaceAround,
     };
     
+    enum class GridLineTag {
+       Auto,
+       Line,
+       Span,
+    };
+    
+    struct GridLineVariant_Auto { GridLineTag tag; };
+    struct GridLineVariant_Line { GridLineTag tag; int32_t payload; };
+    struct GridLineVariant_Span { GridLineTag tag; uint32_t payload; };
+    union GridLine {
+        GridLineVariant_Auto Auto;
+        GridLineVariant_Line Line;
+        GridLineVariant_Span Span;
+    };
+    
+    
     enum class LayoutAlignItems {
        Stretch,
        Center,
@@ -1179,11 +1321,20 @@ namespace dll {
        Flex,
        Block,
        InlineBlock,
+       Grid,
     };
     
     enum class LayoutFloat {
        Left,
        Right,
+       None,
+    };
+    
+    enum class LayoutClear {
+       None,
+       Left,
+       Right,
+       Both,
     };
     
     enum class LayoutJustifyContent {
@@ -1308,6 +1459,57 @@ namespace dll {
        Visible,
     };
     
+    struct StyleTransitionDuration {
+        uint32_t ms;
+        StyleTransitionDuration& operator=(const StyleTransitionDuration&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTransitionDuration() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleTransitionDelay {
+        uint32_t ms;
+        StyleTransitionDelay& operator=(const StyleTransitionDelay&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTransitionDelay() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleAnimationDuration {
+        uint32_t ms;
+        StyleAnimationDuration& operator=(const StyleAnimationDuration&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleAnimationDuration() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleAnimationDelay {
+        uint32_t ms;
+        StyleAnimationDelay& operator=(const StyleAnimationDelay&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleAnimationDelay() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class StyleAnimationIterationCountTag {
+       Count,
+       Infinite,
+    };
+    
+    struct StyleAnimationIterationCountVariant_Count { StyleAnimationIterationCountTag tag; uint32_t payload; };
+    struct StyleAnimationIterationCountVariant_Infinite { StyleAnimationIterationCountTag tag; };
+    union StyleAnimationIterationCount {
+        StyleAnimationIterationCountVariant_Count Count;
+        StyleAnimationIterationCountVariant_Infinite Infinite;
+    };
+    
+    
+    enum class StyleAnimationDirection {
+       Normal,
+       Reverse,
+       Alternate,
+       AlternateReverse,
+    };
+    
+    enum class StyleAnimationFillMode {
+       None,
+       Forwards,
+       Backwards,
+       Both,
+    };
+    
     enum class StyleTextAlign {
        Left,
        Center,
@@ -1386,6 +1588,87 @@ namespace dll {
         NumberInputOnValueChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class TextEditKind {
+       Typing,
+       Deleting,
+       Other,
+    };
+    
+    struct TextEditOnTextChangeCallback {
+        TextEditOnTextChangeCallbackType cb;
+        TextEditOnTextChangeCallback& operator=(const TextEditOnTextChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditOnTextChangeCallback(const TextEditOnTextChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditOnTextChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextEditOnFocusLostCallback {
+        TextEditOnFocusLostCallbackType cb;
+        TextEditOnFocusLostCallback& operator=(const TextEditOnFocusLostCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditOnFocusLostCallback(const TextEditOnFocusLostCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditOnFocusLostCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class SliderOrientation {
+       Horizontal,
+       Vertical,
+    };
+    
+    struct SliderOnValueChangeCallback {
+        SliderOnValueChangeCallbackType cb;
+        SliderOnValueChangeCallback& operator=(const SliderOnValueChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SliderOnValueChangeCallback(const SliderOnValueChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        SliderOnValueChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DropDownOnChoiceChangeCallback {
+        DropDownOnChoiceChangeCallbackType cb;
+        DropDownOnChoiceChangeCallback& operator=(const DropDownOnChoiceChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DropDownOnChoiceChangeCallback(const DropDownOnChoiceChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        DropDownOnChoiceChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetCellIndex {
+        size_t row;
+        size_t column;
+        SpreadsheetCellIndex& operator=(const SpreadsheetCellIndex&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetCellIndex() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetSizeOverride {
+        size_t index;
+        float size;
+        SpreadsheetSizeOverride& operator=(const SpreadsheetSizeOverride&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetSizeOverride() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetColumnResize {
+        size_t column;
+        float width;
+        SpreadsheetColumnResize& operator=(const SpreadsheetColumnResize&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetColumnResize() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetOnCellsChangeCallback {
+        SpreadsheetOnCellsChangeCallbackType cb;
+        SpreadsheetOnCellsChangeCallback& operator=(const SpreadsheetOnCellsChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetOnCellsChangeCallback(const SpreadsheetOnCellsChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetOnCellsChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetOnSelectionChangeCallback {
+        SpreadsheetOnSelectionChangeCallbackType cb;
+        SpreadsheetOnSelectionChangeCallback& operator=(const SpreadsheetOnSelectionChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetOnSelectionChangeCallback(const SpreadsheetOnSelectionChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetOnSelectionChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetOnColumnResizeCallback {
+        SpreadsheetOnColumnResizeCallbackType cb;
+        SpreadsheetOnColumnResizeCallback& operator=(const SpreadsheetOnColumnResizeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetOnColumnResizeCallback(const SpreadsheetOnColumnResizeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetOnColumnResizeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct Node {
         size_t parent;
         size_t previous_sibling;
@@ -1975,6 +2258,22 @@ namespace dll {
     };
     
     
+    enum class AccessibilityNodeVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct AccessibilityNodeVecDestructorVariant_DefaultRust { AccessibilityNodeVecDestructorTag tag; };
+    struct AccessibilityNodeVecDestructorVariant_NoDestructor { AccessibilityNodeVecDestructorTag tag; };
+    struct AccessibilityNodeVecDestructorVariant_External { AccessibilityNodeVecDestructorTag tag; AccessibilityNodeVecDestructorType payload; };
+    union AccessibilityNodeVecDestructor {
+        AccessibilityNodeVecDestructorVariant_DefaultRust DefaultRust;
+        AccessibilityNodeVecDestructorVariant_NoDestructor NoDestructor;
+        AccessibilityNodeVecDestructorVariant_External External;
+    };
+    
+    
     enum class MenuItemVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2407,6 +2706,22 @@ namespace dll {
     };
     
     
+    enum class KeyboardShortcutVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct KeyboardShortcutVecDestructorVariant_DefaultRust { KeyboardShortcutVecDestructorTag tag; };
+    struct KeyboardShortcutVecDestructorVariant_NoDestructor { KeyboardShortcutVecDestructorTag tag; };
+    struct KeyboardShortcutVecDestructorVariant_External { KeyboardShortcutVecDestructorTag tag; KeyboardShortcutVecDestructorType payload; };
+    union KeyboardShortcutVecDestructor {
+        KeyboardShortcutVecDestructorVariant_DefaultRust DefaultRust;
+        KeyboardShortcutVecDestructorVariant_NoDestructor NoDestructor;
+        KeyboardShortcutVecDestructorVariant_External External;
+    };
+    
+    
     enum class CascadeInfoVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2487,6 +2802,262 @@ namespace dll {
     };
     
     
+    enum class CssFontFaceVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssFontFaceVecDestructorVariant_DefaultRust { CssFontFaceVecDestructorTag tag; };
+    struct CssFontFaceVecDestructorVariant_NoDestructor { CssFontFaceVecDestructorTag tag; };
+    struct CssFontFaceVecDestructorVariant_External { CssFontFaceVecDestructorTag tag; CssFontFaceVecDestructorType payload; };
+    union CssFontFaceVecDestructor {
+        CssFontFaceVecDestructorVariant_DefaultRust DefaultRust;
+        CssFontFaceVecDestructorVariant_NoDestructor NoDestructor;
+        CssFontFaceVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleTransitionPropertyVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleTransitionPropertyVecDestructorVariant_DefaultRust { StyleTransitionPropertyVecDestructorTag tag; };
+    struct StyleTransitionPropertyVecDestructorVariant_NoDestructor { StyleTransitionPropertyVecDestructorTag tag; };
+    struct StyleTransitionPropertyVecDestructorVariant_External { StyleTransitionPropertyVecDestructorTag tag; StyleTransitionPropertyVecDestructorType payload; };
+    union StyleTransitionPropertyVecDestructor {
+        StyleTransitionPropertyVecDestructorVariant_DefaultRust DefaultRust;
+        StyleTransitionPropertyVecDestructorVariant_NoDestructor NoDestructor;
+        StyleTransitionPropertyVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleTransitionDurationVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleTransitionDurationVecDestructorVariant_DefaultRust { StyleTransitionDurationVecDestructorTag tag; };
+    struct StyleTransitionDurationVecDestructorVariant_NoDestructor { StyleTransitionDurationVecDestructorTag tag; };
+    struct StyleTransitionDurationVecDestructorVariant_External { StyleTransitionDurationVecDestructorTag tag; StyleTransitionDurationVecDestructorType payload; };
+    union StyleTransitionDurationVecDestructor {
+        StyleTransitionDurationVecDestructorVariant_DefaultRust DefaultRust;
+        StyleTransitionDurationVecDestructorVariant_NoDestructor NoDestructor;
+        StyleTransitionDurationVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleTransitionTimingFunctionVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleTransitionTimingFunctionVecDestructorVariant_DefaultRust { StyleTransitionTimingFunctionVecDestructorTag tag; };
+    struct StyleTransitionTimingFunctionVecDestructorVariant_NoDestructor { StyleTransitionTimingFunctionVecDestructorTag tag; };
+    struct StyleTransitionTimingFunctionVecDestructorVariant_External { StyleTransitionTimingFunctionVecDestructorTag tag; StyleTransitionTimingFunctionVecDestructorType payload; };
+    union StyleTransitionTimingFunctionVecDestructor {
+        StyleTransitionTimingFunctionVecDestructorVariant_DefaultRust DefaultRust;
+        StyleTransitionTimingFunctionVecDestructorVariant_NoDestructor NoDestructor;
+        StyleTransitionTimingFunctionVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleTransitionDelayVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleTransitionDelayVecDestructorVariant_DefaultRust { StyleTransitionDelayVecDestructorTag tag; };
+    struct StyleTransitionDelayVecDestructorVariant_NoDestructor { StyleTransitionDelayVecDestructorTag tag; };
+    struct StyleTransitionDelayVecDestructorVariant_External { StyleTransitionDelayVecDestructorTag tag; StyleTransitionDelayVecDestructorType payload; };
+    union StyleTransitionDelayVecDestructor {
+        StyleTransitionDelayVecDestructorVariant_DefaultRust DefaultRust;
+        StyleTransitionDelayVecDestructorVariant_NoDestructor NoDestructor;
+        StyleTransitionDelayVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleAnimationNameVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleAnimationNameVecDestructorVariant_DefaultRust { StyleAnimationNameVecDestructorTag tag; };
+    struct StyleAnimationNameVecDestructorVariant_NoDestructor { StyleAnimationNameVecDestructorTag tag; };
+    struct StyleAnimationNameVecDestructorVariant_External { StyleAnimationNameVecDestructorTag tag; StyleAnimationNameVecDestructorType payload; };
+    union StyleAnimationNameVecDestructor {
+        StyleAnimationNameVecDestructorVariant_DefaultRust DefaultRust;
+        StyleAnimationNameVecDestructorVariant_NoDestructor NoDestructor;
+        StyleAnimationNameVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleAnimationDurationVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleAnimationDurationVecDestructorVariant_DefaultRust { StyleAnimationDurationVecDestructorTag tag; };
+    struct StyleAnimationDurationVecDestructorVariant_NoDestructor { StyleAnimationDurationVecDestructorTag tag; };
+    struct StyleAnimationDurationVecDestructorVariant_External { StyleAnimationDurationVecDestructorTag tag; StyleAnimationDurationVecDestructorType payload; };
+    union StyleAnimationDurationVecDestructor {
+        StyleAnimationDurationVecDestructorVariant_DefaultRust DefaultRust;
+        StyleAnimationDurationVecDestructorVariant_NoDestructor NoDestructor;
+        StyleAnimationDurationVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleAnimationIterationCountVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleAnimationIterationCountVecDestructorVariant_DefaultRust { StyleAnimationIterationCountVecDestructorTag tag; };
+    struct StyleAnimationIterationCountVecDestructorVariant_NoDestructor { StyleAnimationIterationCountVecDestructorTag tag; };
+    struct StyleAnimationIterationCountVecDestructorVariant_External { StyleAnimationIterationCountVecDestructorTag tag; StyleAnimationIterationCountVecDestructorType payload; };
+    union StyleAnimationIterationCountVecDestructor {
+        StyleAnimationIterationCountVecDestructorVariant_DefaultRust DefaultRust;
+        StyleAnimationIterationCountVecDestructorVariant_NoDestructor NoDestructor;
+        StyleAnimationIterationCountVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleAnimationDirectionVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleAnimationDirectionVecDestructorVariant_DefaultRust { StyleAnimationDirectionVecDestructorTag tag; };
+    struct StyleAnimationDirectionVecDestructorVariant_NoDestructor { StyleAnimationDirectionVecDestructorTag tag; };
+    struct StyleAnimationDirectionVecDestructorVariant_External { StyleAnimationDirectionVecDestructorTag tag; StyleAnimationDirectionVecDestructorType payload; };
+    union StyleAnimationDirectionVecDestructor {
+        StyleAnimationDirectionVecDestructorVariant_DefaultRust DefaultRust;
+        StyleAnimationDirectionVecDestructorVariant_NoDestructor NoDestructor;
+        StyleAnimationDirectionVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleAnimationFillModeVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleAnimationFillModeVecDestructorVariant_DefaultRust { StyleAnimationFillModeVecDestructorTag tag; };
+    struct StyleAnimationFillModeVecDestructorVariant_NoDestructor { StyleAnimationFillModeVecDestructorTag tag; };
+    struct StyleAnimationFillModeVecDestructorVariant_External { StyleAnimationFillModeVecDestructorTag tag; StyleAnimationFillModeVecDestructorType payload; };
+    union StyleAnimationFillModeVecDestructor {
+        StyleAnimationFillModeVecDestructorVariant_DefaultRust DefaultRust;
+        StyleAnimationFillModeVecDestructorVariant_NoDestructor NoDestructor;
+        StyleAnimationFillModeVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleAnimationDelayVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleAnimationDelayVecDestructorVariant_DefaultRust { StyleAnimationDelayVecDestructorTag tag; };
+    struct StyleAnimationDelayVecDestructorVariant_NoDestructor { StyleAnimationDelayVecDestructorTag tag; };
+    struct StyleAnimationDelayVecDestructorVariant_External { StyleAnimationDelayVecDestructorTag tag; StyleAnimationDelayVecDestructorType payload; };
+    union StyleAnimationDelayVecDestructor {
+        StyleAnimationDelayVecDestructorVariant_DefaultRust DefaultRust;
+        StyleAnimationDelayVecDestructorVariant_NoDestructor NoDestructor;
+        StyleAnimationDelayVecDestructorVariant_External External;
+    };
+    
+    
+    enum class CssKeyframeVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssKeyframeVecDestructorVariant_DefaultRust { CssKeyframeVecDestructorTag tag; };
+    struct CssKeyframeVecDestructorVariant_NoDestructor { CssKeyframeVecDestructorTag tag; };
+    struct CssKeyframeVecDestructorVariant_External { CssKeyframeVecDestructorTag tag; CssKeyframeVecDestructorType payload; };
+    union CssKeyframeVecDestructor {
+        CssKeyframeVecDestructorVariant_DefaultRust DefaultRust;
+        CssKeyframeVecDestructorVariant_NoDestructor NoDestructor;
+        CssKeyframeVecDestructorVariant_External External;
+    };
+    
+    
+    enum class CssKeyframesVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssKeyframesVecDestructorVariant_DefaultRust { CssKeyframesVecDestructorTag tag; };
+    struct CssKeyframesVecDestructorVariant_NoDestructor { CssKeyframesVecDestructorTag tag; };
+    struct CssKeyframesVecDestructorVariant_External { CssKeyframesVecDestructorTag tag; CssKeyframesVecDestructorType payload; };
+    union CssKeyframesVecDestructor {
+        CssKeyframesVecDestructorVariant_DefaultRust DefaultRust;
+        CssKeyframesVecDestructorVariant_NoDestructor NoDestructor;
+        CssKeyframesVecDestructorVariant_External External;
+    };
+    
+    
+    enum class GridTrackSizingVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct GridTrackSizingVecDestructorVariant_DefaultRust { GridTrackSizingVecDestructorTag tag; };
+    struct GridTrackSizingVecDestructorVariant_NoDestructor { GridTrackSizingVecDestructorTag tag; };
+    struct GridTrackSizingVecDestructorVariant_External { GridTrackSizingVecDestructorTag tag; GridTrackSizingVecDestructorType payload; };
+    union GridTrackSizingVecDestructor {
+        GridTrackSizingVecDestructorVariant_DefaultRust DefaultRust;
+        GridTrackSizingVecDestructorVariant_NoDestructor NoDestructor;
+        GridTrackSizingVecDestructorVariant_External External;
+    };
+    
+    
+    enum class CssMediaQueryVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssMediaQueryVecDestructorVariant_DefaultRust { CssMediaQueryVecDestructorTag tag; };
+    struct CssMediaQueryVecDestructorVariant_NoDestructor { CssMediaQueryVecDestructorTag tag; };
+    struct CssMediaQueryVecDestructorVariant_External { CssMediaQueryVecDestructorTag tag; CssMediaQueryVecDestructorType payload; };
+    union CssMediaQueryVecDestructor {
+        CssMediaQueryVecDestructorVariant_DefaultRust DefaultRust;
+        CssMediaQueryVecDestructorVariant_NoDestructor NoDestructor;
+        CssMediaQueryVecDestructorVariant_External External;
+    };
+    
+    
+    enum class CssMediaConditionVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssMediaConditionVecDestructorVariant_DefaultRust { CssMediaConditionVecDestructorTag tag; };
+    struct CssMediaConditionVecDestructorVariant_NoDestructor { CssMediaConditionVecDestructorTag tag; };
+    struct CssMediaConditionVecDestructorVariant_External { CssMediaConditionVecDestructorTag tag; CssMediaConditionVecDestructorType payload; };
+    union CssMediaConditionVecDestructor {
+        CssMediaConditionVecDestructorVariant_DefaultRust DefaultRust;
+        CssMediaConditionVecDestructorVariant_NoDestructor NoDestructor;
+        CssMediaConditionVecDestructorVariant_External External;
+    };
+    
+    
     enum class CssRuleBlockVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2775,24 +3346,72 @@ namespace dll {
     };
     
     
-    enum class OptionI16Tag {
-       None,
-       Some,
+    enum class TextEditSnapshotVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
     };
     
-    struct OptionI16Variant_None { OptionI16Tag tag; };
-    struct OptionI16Variant_Some { OptionI16Tag tag; int16_t payload; };
-    union OptionI16 {
-        OptionI16Variant_None None;
-        OptionI16Variant_Some Some;
+    struct TextEditSnapshotVecDestructorVariant_DefaultRust { TextEditSnapshotVecDestructorTag tag; };
+    struct TextEditSnapshotVecDestructorVariant_NoDestructor { TextEditSnapshotVecDestructorTag tag; };
+    struct TextEditSnapshotVecDestructorVariant_External { TextEditSnapshotVecDestructorTag tag; TextEditSnapshotVecDestructorType payload; };
+    union TextEditSnapshotVecDestructor {
+        TextEditSnapshotVecDestructorVariant_DefaultRust DefaultRust;
+        TextEditSnapshotVecDestructorVariant_NoDestructor NoDestructor;
+        TextEditSnapshotVecDestructorVariant_External External;
     };
     
     
-    enum class OptionU16Tag {
-       None,
-       Some,
-    };
-    
+    enum class SpreadsheetSizeOverrideVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct SpreadsheetSizeOverrideVecDestructorVariant_DefaultRust { SpreadsheetSizeOverrideVecDestructorTag tag; };
+    struct SpreadsheetSizeOverrideVecDestructorVariant_NoDestructor { SpreadsheetSizeOverrideVecDestructorTag tag; };
+    struct SpreadsheetSizeOverrideVecDestructorVariant_External { SpreadsheetSizeOverrideVecDestructorTag tag; SpreadsheetSizeOverrideVecDestructorType payload; };
+    union SpreadsheetSizeOverrideVecDestructor {
+        SpreadsheetSizeOverrideVecDestructorVariant_DefaultRust DefaultRust;
+        SpreadsheetSizeOverrideVecDestructorVariant_NoDestructor NoDestructor;
+        SpreadsheetSizeOverrideVecDestructorVariant_External External;
+    };
+    
+    
+    enum class SpreadsheetCellContentVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct SpreadsheetCellContentVecDestructorVariant_DefaultRust { SpreadsheetCellContentVecDestructorTag tag; };
+    struct SpreadsheetCellContentVecDestructorVariant_NoDestructor { SpreadsheetCellContentVecDestructorTag tag; };
+    struct SpreadsheetCellContentVecDestructorVariant_External { SpreadsheetCellContentVecDestructorTag tag; SpreadsheetCellContentVecDestructorType payload; };
+    union SpreadsheetCellContentVecDestructor {
+        SpreadsheetCellContentVecDestructorVariant_DefaultRust DefaultRust;
+        SpreadsheetCellContentVecDestructorVariant_NoDestructor NoDestructor;
+        SpreadsheetCellContentVecDestructorVariant_External External;
+    };
+    
+    
+    enum class OptionI16Tag {
+       None,
+       Some,
+    };
+    
+    struct OptionI16Variant_None { OptionI16Tag tag; };
+    struct OptionI16Variant_Some { OptionI16Tag tag; int16_t payload; };
+    union OptionI16 {
+        OptionI16Variant_None None;
+        OptionI16Variant_Some Some;
+    };
+    
+    
+    enum class OptionU16Tag {
+       None,
+       Some,
+    };
+    
     struct OptionU16Variant_None { OptionU16Tag tag; };
     struct OptionU16Variant_Some { OptionU16Tag tag; uint16_t payload; };
     union OptionU16 {
@@ -3086,6 +3705,7 @@ namespace dll {
         LogicalRect bounds;
         OptionChar unicode_codepoint;
         uint32_t glyph_index;
+        size_t fallback_font_index;
         InlineGlyph& operator=(const InlineGlyph&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         InlineGlyph(const InlineGlyph&) = delete; /* disable copy constructor, use explicit .clone() */
         InlineGlyph() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -3108,6 +3728,8 @@ namespace dll {
         size_t char_index_relative_to_line;
         size_t glyph_index_relative_to_word;
         size_t char_index_relative_to_word;
+        bool  is_rtl;
+        size_t cursor_char_index_relative_to_text;
         InlineTextHit& operator=(const InlineTextHit&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         InlineTextHit(const InlineTextHit&) = delete; /* disable copy constructor, use explicit .clone() */
         InlineTextHit() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -3229,6 +3851,74 @@ namespace dll {
         StyleBoxShadow() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class GridTrackBreadthTag {
+       Fixed,
+       Fraction,
+       Auto,
+    };
+    
+    struct GridTrackBreadthVariant_Fixed { GridTrackBreadthTag tag; PixelValue payload; };
+    struct GridTrackBreadthVariant_Fraction { GridTrackBreadthTag tag; FloatValue payload; };
+    struct GridTrackBreadthVariant_Auto { GridTrackBreadthTag tag; };
+    union GridTrackBreadth {
+        GridTrackBreadthVariant_Fixed Fixed;
+        GridTrackBreadthVariant_Fraction Fraction;
+        GridTrackBreadthVariant_Auto Auto;
+    };
+    
+    
+    struct GridMinMax {
+        GridTrackBreadth min;
+        GridTrackBreadth max;
+        GridMinMax& operator=(const GridMinMax&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GridMinMax() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class GridTrackSizingTag {
+       Fixed,
+       Fraction,
+       Auto,
+       MinMax,
+    };
+    
+    struct GridTrackSizingVariant_Fixed { GridTrackSizingTag tag; PixelValue payload; };
+    struct GridTrackSizingVariant_Fraction { GridTrackSizingTag tag; FloatValue payload; };
+    struct GridTrackSizingVariant_Auto { GridTrackSizingTag tag; };
+    struct GridTrackSizingVariant_MinMax { GridTrackSizingTag tag; GridMinMax payload; };
+    union GridTrackSizing {
+        GridTrackSizingVariant_Fixed Fixed;
+        GridTrackSizingVariant_Fraction Fraction;
+        GridTrackSizingVariant_Auto Auto;
+        GridTrackSizingVariant_MinMax MinMax;
+    };
+    
+    
+    struct LayoutGridColumn {
+        GridLine start;
+        GridLine end;
+        LayoutGridColumn& operator=(const LayoutGridColumn&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        LayoutGridColumn() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct LayoutGridRow {
+        GridLine start;
+        GridLine end;
+        LayoutGridRow& operator=(const LayoutGridRow&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        LayoutGridRow() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct LayoutColumnGap {
+        PixelValue inner;
+        LayoutColumnGap& operator=(const LayoutColumnGap&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        LayoutColumnGap() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct LayoutRowGap {
+        PixelValue inner;
+        LayoutRowGap& operator=(const LayoutRowGap&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        LayoutRowGap() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct LayoutBottom {
         PixelValue inner;
         LayoutBottom& operator=(const LayoutBottom&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -3597,6 +4287,28 @@ namespace dll {
         StylePerspectiveOrigin() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class StyleTransitionPropertyTag {
+       All,
+       Property,
+    };
+    
+    struct StyleTransitionPropertyVariant_All { StyleTransitionPropertyTag tag; };
+    struct StyleTransitionPropertyVariant_Property { StyleTransitionPropertyTag tag; CssPropertyType payload; };
+    union StyleTransitionProperty {
+        StyleTransitionPropertyVariant_All All;
+        StyleTransitionPropertyVariant_Property Property;
+    };
+    
+    
+    struct StyleCubicBezier {
+        FloatValue x1;
+        FloatValue y1;
+        FloatValue x2;
+        FloatValue y2;
+        StyleCubicBezier& operator=(const StyleCubicBezier&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleCubicBezier() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StyleTransformMatrix2D {
         PixelValue a;
         PixelValue b;
@@ -3731,6 +4443,94 @@ namespace dll {
     };
     
     
+    enum class LayoutGridColumnValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutGridColumnValueVariant_Auto { LayoutGridColumnValueTag tag; };
+    struct LayoutGridColumnValueVariant_None { LayoutGridColumnValueTag tag; };
+    struct LayoutGridColumnValueVariant_Inherit { LayoutGridColumnValueTag tag; };
+    struct LayoutGridColumnValueVariant_Initial { LayoutGridColumnValueTag tag; };
+    struct LayoutGridColumnValueVariant_Exact { LayoutGridColumnValueTag tag; LayoutGridColumn payload; };
+    union LayoutGridColumnValue {
+        LayoutGridColumnValueVariant_Auto Auto;
+        LayoutGridColumnValueVariant_None None;
+        LayoutGridColumnValueVariant_Inherit Inherit;
+        LayoutGridColumnValueVariant_Initial Initial;
+        LayoutGridColumnValueVariant_Exact Exact;
+    };
+    
+    
+    enum class LayoutGridRowValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutGridRowValueVariant_Auto { LayoutGridRowValueTag tag; };
+    struct LayoutGridRowValueVariant_None { LayoutGridRowValueTag tag; };
+    struct LayoutGridRowValueVariant_Inherit { LayoutGridRowValueTag tag; };
+    struct LayoutGridRowValueVariant_Initial { LayoutGridRowValueTag tag; };
+    struct LayoutGridRowValueVariant_Exact { LayoutGridRowValueTag tag; LayoutGridRow payload; };
+    union LayoutGridRowValue {
+        LayoutGridRowValueVariant_Auto Auto;
+        LayoutGridRowValueVariant_None None;
+        LayoutGridRowValueVariant_Inherit Inherit;
+        LayoutGridRowValueVariant_Initial Initial;
+        LayoutGridRowValueVariant_Exact Exact;
+    };
+    
+    
+    enum class LayoutColumnGapValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutColumnGapValueVariant_Auto { LayoutColumnGapValueTag tag; };
+    struct LayoutColumnGapValueVariant_None { LayoutColumnGapValueTag tag; };
+    struct LayoutColumnGapValueVariant_Inherit { LayoutColumnGapValueTag tag; };
+    struct LayoutColumnGapValueVariant_Initial { LayoutColumnGapValueTag tag; };
+    struct LayoutColumnGapValueVariant_Exact { LayoutColumnGapValueTag tag; LayoutColumnGap payload; };
+    union LayoutColumnGapValue {
+        LayoutColumnGapValueVariant_Auto Auto;
+        LayoutColumnGapValueVariant_None None;
+        LayoutColumnGapValueVariant_Inherit Inherit;
+        LayoutColumnGapValueVariant_Initial Initial;
+        LayoutColumnGapValueVariant_Exact Exact;
+    };
+    
+    
+    enum class LayoutRowGapValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutRowGapValueVariant_Auto { LayoutRowGapValueTag tag; };
+    struct LayoutRowGapValueVariant_None { LayoutRowGapValueTag tag; };
+    struct LayoutRowGapValueVariant_Inherit { LayoutRowGapValueTag tag; };
+    struct LayoutRowGapValueVariant_Initial { LayoutRowGapValueTag tag; };
+    struct LayoutRowGapValueVariant_Exact { LayoutRowGapValueTag tag; LayoutRowGap payload; };
+    union LayoutRowGapValue {
+        LayoutRowGapValueVariant_Auto Auto;
+        LayoutRowGapValueVariant_None None;
+        LayoutRowGapValueVariant_Inherit Inherit;
+        LayoutRowGapValueVariant_Initial Initial;
+        LayoutRowGapValueVariant_Exact Exact;
+    };
+    
+    
     enum class LayoutAlignItemsValueTag {
        Auto,
        None,
@@ -3907,6 +4707,28 @@ namespace dll {
     };
     
     
+    enum class LayoutClearValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutClearValueVariant_Auto { LayoutClearValueTag tag; };
+    struct LayoutClearValueVariant_None { LayoutClearValueTag tag; };
+    struct LayoutClearValueVariant_Inherit { LayoutClearValueTag tag; };
+    struct LayoutClearValueVariant_Initial { LayoutClearValueTag tag; };
+    struct LayoutClearValueVariant_Exact { LayoutClearValueTag tag; LayoutClear payload; };
+    union LayoutClearValue {
+        LayoutClearValueVariant_Auto Auto;
+        LayoutClearValueVariant_None None;
+        LayoutClearValueVariant_Inherit Inherit;
+        LayoutClearValueVariant_Initial Initial;
+        LayoutClearValueVariant_Exact Exact;
+    };
+    
+    
     enum class LayoutHeightValueTag {
        Auto,
        None,
@@ -5069,6 +5891,124 @@ namespace dll {
         NumberInputOnValueChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextEditView {
+        OptionF32 preferred_caret_x;
+        float scroll_y;
+        TextEditView& operator=(const TextEditView&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditView() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextEditOnTextChange {
+        RefAny data;
+        TextEditOnTextChangeCallback callback;
+        TextEditOnTextChange& operator=(const TextEditOnTextChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditOnTextChange(const TextEditOnTextChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditOnTextChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextEditOnFocusLost {
+        RefAny data;
+        TextEditOnFocusLostCallback callback;
+        TextEditOnFocusLost& operator=(const TextEditOnFocusLost&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditOnFocusLost(const TextEditOnFocusLost&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditOnFocusLost() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SliderState {
+        float value;
+        float min;
+        float max;
+        float step;
+        SliderOrientation orientation;
+        size_t tick_count;
+        bool  show_tick_labels;
+        SliderState& operator=(const SliderState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SliderState(const SliderState&) = delete; /* disable copy constructor, use explicit .clone() */
+        SliderState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SliderOnValueChange {
+        RefAny data;
+        SliderOnValueChangeCallback callback;
+        SliderOnValueChange& operator=(const SliderOnValueChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SliderOnValueChange(const SliderOnValueChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        SliderOnValueChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DropDownOnChoiceChange {
+        RefAny data;
+        DropDownOnChoiceChangeCallback callback;
+        DropDownOnChoiceChange& operator=(const DropDownOnChoiceChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DropDownOnChoiceChange(const DropDownOnChoiceChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        DropDownOnChoiceChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetStyle {
+        ColorU accent_color;
+        SpreadsheetStyle& operator=(const SpreadsheetStyle&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetStyle() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetCellSelection {
+        SpreadsheetCellIndex from_top_left;
+        SpreadsheetCellIndex to_bottom_right;
+        SpreadsheetCellSelection& operator=(const SpreadsheetCellSelection&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetCellSelection() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetRenderWindow {
+        size_t row_start;
+        size_t row_end;
+        size_t column_start;
+        size_t column_end;
+        LogicalPosition origin;
+        LogicalPosition scroll_position;
+        LogicalSize viewport;
+        SpreadsheetRenderWindow& operator=(const SpreadsheetRenderWindow&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetRenderWindow(const SpreadsheetRenderWindow&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetRenderWindow() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class SpreadsheetDragTag {
+       None,
+       SelectCells,
+       ResizeColumn,
+    };
+    
+    struct SpreadsheetDragVariant_None { SpreadsheetDragTag tag; };
+    struct SpreadsheetDragVariant_SelectCells { SpreadsheetDragTag tag; };
+    struct SpreadsheetDragVariant_ResizeColumn { SpreadsheetDragTag tag; SpreadsheetColumnResize payload; };
+    union SpreadsheetDrag {
+        SpreadsheetDragVariant_None None;
+        SpreadsheetDragVariant_SelectCells SelectCells;
+        SpreadsheetDragVariant_ResizeColumn ResizeColumn;
+    };
+    
+    
+    struct SpreadsheetOnCellsChange {
+        RefAny data;
+        SpreadsheetOnCellsChangeCallback callback;
+        SpreadsheetOnCellsChange& operator=(const SpreadsheetOnCellsChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetOnCellsChange(const SpreadsheetOnCellsChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetOnCellsChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetOnSelectionChange {
+        RefAny data;
+        SpreadsheetOnSelectionChangeCallback callback;
+        SpreadsheetOnSelectionChange& operator=(const SpreadsheetOnSelectionChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetOnSelectionChange(const SpreadsheetOnSelectionChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetOnSelectionChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetOnColumnResize {
+        RefAny data;
+        SpreadsheetOnColumnResizeCallback callback;
+        SpreadsheetOnColumnResize& operator=(const SpreadsheetOnColumnResize&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetOnColumnResize(const SpreadsheetOnColumnResize&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetOnColumnResize() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct ParentWithNodeDepth {
         size_t depth;
         NodeId node_id;
@@ -5263,6 +6203,17 @@ namespace dll {
         AccessibilityStateVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct AzAccessibilityNode;
+    struct AccessibilityNodeVec {
+        AccessibilityNode* ptr;
+        size_t len;
+        size_t cap;
+        AccessibilityNodeVecDestructor destructor;
+        AccessibilityNodeVec& operator=(const AccessibilityNodeVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        AccessibilityNodeVec(const AccessibilityNodeVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        AccessibilityNodeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct AzMenuItem;
     struct MenuItemVec {
         MenuItem* ptr;
@@ -5416,6 +6367,107 @@ namespace dll {
         ScanCodeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    union AzCssPathSelector;
+    struct CssPathSelectorVec {
+        CssPathSelector* ptr;
+        size_t len;
+        size_t cap;
+        CssPathSelectorVecDestructor destructor;
+        CssPathSelectorVec& operator=(const CssPathSelectorVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssPathSelectorVec(const CssPathSelectorVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssPathSelectorVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleTransitionPropertyVec {
+        StyleTransitionProperty* ptr;
+        size_t len;
+        size_t cap;
+        StyleTransitionPropertyVecDestructor destructor;
+        StyleTransitionPropertyVec& operator=(const StyleTransitionPropertyVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTransitionPropertyVec(const StyleTransitionPropertyVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleTransitionPropertyVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleTransitionDurationVec {
+        StyleTransitionDuration* ptr;
+        size_t len;
+        size_t cap;
+        StyleTransitionDurationVecDestructor destructor;
+        StyleTransitionDurationVec& operator=(const StyleTransitionDurationVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTransitionDurationVec(const StyleTransitionDurationVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleTransitionDurationVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleTransitionDelayVec {
+        StyleTransitionDelay* ptr;
+        size_t len;
+        size_t cap;
+        StyleTransitionDelayVecDestructor destructor;
+        StyleTransitionDelayVec& operator=(const StyleTransitionDelayVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTransitionDelayVec(const StyleTransitionDelayVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleTransitionDelayVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleAnimationDurationVec {
+        StyleAnimationDuration* ptr;
+        size_t len;
+        size_t cap;
+        StyleAnimationDurationVecDestructor destructor;
+        StyleAnimationDurationVec& operator=(const StyleAnimationDurationVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleAnimationDurationVec(const StyleAnimationDurationVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleAnimationDurationVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleAnimationIterationCountVec {
+        StyleAnimationIterationCount* ptr;
+        size_t len;
+        size_t cap;
+        StyleAnimationIterationCountVecDestructor destructor;
+        StyleAnimationIterationCountVec& operator=(const StyleAnimationIterationCountVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleAnimationIterationCountVec(const StyleAnimationIterationCountVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleAnimationIterationCountVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleAnimationDirectionVec {
+        StyleAnimationDirection* ptr;
+        size_t len;
+        size_t cap;
+        StyleAnimationDirectionVecDestructor destructor;
+        StyleAnimationDirectionVec& operator=(const StyleAnimationDirectionVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleAnimationDirectionVec(const StyleAnimationDirectionVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleAnimationDirectionVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleAnimationFillModeVec {
+        StyleAnimationFillMode* ptr;
+        size_t len;
+        size_t cap;
+        StyleAnimationFillModeVecDestructor destructor;
+        StyleAnimationFillModeVec& operator=(const StyleAnimationFillModeVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleAnimationFillModeVec(const StyleAnimationFillModeVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleAnimationFillModeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleAnimationDelayVec {
+        StyleAnimationDelay* ptr;
+        size_t len;
+        size_t cap;
+        StyleAnimationDelayVecDestructor destructor;
+        StyleAnimationDelayVec& operator=(const StyleAnimationDelayVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleAnimationDelayVec(const StyleAnimationDelayVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleAnimationDelayVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct GridTrackSizingVec {
+        GridTrackSizing* ptr;
+        size_t len;
+        size_t cap;
+        GridTrackSizingVecDestructor destructor;
+        GridTrackSizingVec& operator=(const GridTrackSizingVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GridTrackSizingVec(const GridTrackSizingVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        GridTrackSizingVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct U16Vec {
         uint16_t* ptr;
         size_t len;
@@ -5516,6 +6568,16 @@ namespace dll {
         ParentWithNodeDepthVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct SpreadsheetSizeOverrideVec {
+        SpreadsheetSizeOverride* ptr;
+        size_t len;
+        size_t cap;
+        SpreadsheetSizeOverrideVecDestructor destructor;
+        SpreadsheetSizeOverrideVec& operator=(const SpreadsheetSizeOverrideVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetSizeOverrideVec(const SpreadsheetSizeOverrideVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetSizeOverrideVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class OptionColorInputOnValueChangeTag {
        None,
        Some,
@@ -5620,103 +6682,220 @@ namespace dll {
     };
     
     
-    enum class OptionMenuItemIconTag {
+    enum class OptionTextEditOnTextChangeTag {
        None,
        Some,
     };
     
-    struct OptionMenuItemIconVariant_None { OptionMenuItemIconTag tag; };
-    struct OptionMenuItemIconVariant_Some { OptionMenuItemIconTag tag; MenuItemIcon payload; };
-    union OptionMenuItemIcon {
-        OptionMenuItemIconVariant_None None;
-        OptionMenuItemIconVariant_Some Some;
+    struct OptionTextEditOnTextChangeVariant_None { OptionTextEditOnTextChangeTag tag; };
+    struct OptionTextEditOnTextChangeVariant_Some { OptionTextEditOnTextChangeTag tag; TextEditOnTextChange payload; };
+    union OptionTextEditOnTextChange {
+        OptionTextEditOnTextChangeVariant_None None;
+        OptionTextEditOnTextChangeVariant_Some Some;
     };
     
     
-    enum class OptionMenuCallbackTag {
+    enum class OptionTextEditOnFocusLostTag {
        None,
        Some,
     };
     
-    struct OptionMenuCallbackVariant_None { OptionMenuCallbackTag tag; };
-    struct OptionMenuCallbackVariant_Some { OptionMenuCallbackTag tag; MenuCallback payload; };
-    union OptionMenuCallback {
-        OptionMenuCallbackVariant_None None;
-        OptionMenuCallbackVariant_Some Some;
+    struct OptionTextEditOnFocusLostVariant_None { OptionTextEditOnFocusLostTag tag; };
+    struct OptionTextEditOnFocusLostVariant_Some { OptionTextEditOnFocusLostTag tag; TextEditOnFocusLost payload; };
+    union OptionTextEditOnFocusLost {
+        OptionTextEditOnFocusLostVariant_None None;
+        OptionTextEditOnFocusLostVariant_Some Some;
     };
     
     
-    enum class OptionPositionInfoTag {
+    enum class OptionTextEditKindTag {
        None,
        Some,
     };
     
-    struct OptionPositionInfoVariant_None { OptionPositionInfoTag tag; };
-    struct OptionPositionInfoVariant_Some { OptionPositionInfoTag tag; PositionInfo payload; };
-    union OptionPositionInfo {
-        OptionPositionInfoVariant_None None;
-        OptionPositionInfoVariant_Some Some;
+    struct OptionTextEditKindVariant_None { OptionTextEditKindTag tag; };
+    struct OptionTextEditKindVariant_Some { OptionTextEditKindTag tag; TextEditKind payload; };
+    union OptionTextEditKind {
+        OptionTextEditKindVariant_None None;
+        OptionTextEditKindVariant_Some Some;
     };
     
     
-    enum class OptionTimerIdTag {
+    enum class OptionSliderOnValueChangeTag {
        None,
        Some,
     };
     
-    struct OptionTimerIdVariant_None { OptionTimerIdTag tag; };
-    struct OptionTimerIdVariant_Some { OptionTimerIdTag tag; TimerId payload; };
-    union OptionTimerId {
-        OptionTimerIdVariant_None None;
-        OptionTimerIdVariant_Some Some;
+    struct OptionSliderOnValueChangeVariant_None { OptionSliderOnValueChangeTag tag; };
+    struct OptionSliderOnValueChangeVariant_Some { OptionSliderOnValueChangeTag tag; SliderOnValueChange payload; };
+    union OptionSliderOnValueChange {
+        OptionSliderOnValueChangeVariant_None None;
+        OptionSliderOnValueChangeVariant_Some Some;
     };
     
     
-    enum class OptionThreadIdTag {
+    enum class OptionDropDownOnChoiceChangeTag {
        None,
        Some,
     };
     
-    struct OptionThreadIdVariant_None { OptionThreadIdTag tag; };
-    struct OptionThreadIdVariant_Some { OptionThreadIdTag tag; ThreadId payload; };
-    union OptionThreadId {
-        OptionThreadIdVariant_None None;
-        OptionThreadIdVariant_Some Some;
+    struct OptionDropDownOnChoiceChangeVariant_None { OptionDropDownOnChoiceChangeTag tag; };
+    struct OptionDropDownOnChoiceChangeVariant_Some { OptionDropDownOnChoiceChangeTag tag; DropDownOnChoiceChange payload; };
+    union OptionDropDownOnChoiceChange {
+        OptionDropDownOnChoiceChangeVariant_None None;
+        OptionDropDownOnChoiceChangeVariant_Some Some;
     };
     
     
-    enum class OptionImageRefTag {
+    enum class OptionSpreadsheetOnCellsChangeTag {
        None,
        Some,
     };
     
-    struct OptionImageRefVariant_None { OptionImageRefTag tag; };
-    struct OptionImageRefVariant_Some { OptionImageRefTag tag; ImageRef payload; };
-    union OptionImageRef {
-        OptionImageRefVariant_None None;
-        OptionImageRefVariant_Some Some;
+    struct OptionSpreadsheetOnCellsChangeVariant_None { OptionSpreadsheetOnCellsChangeTag tag; };
+    struct OptionSpreadsheetOnCellsChangeVariant_Some { OptionSpreadsheetOnCellsChangeTag tag; SpreadsheetOnCellsChange payload; };
+    union OptionSpreadsheetOnCellsChange {
+        OptionSpreadsheetOnCellsChangeVariant_None None;
+        OptionSpreadsheetOnCellsChangeVariant_Some Some;
     };
     
     
-    enum class OptionFontRefTag {
+    enum class OptionSpreadsheetOnSelectionChangeTag {
        None,
        Some,
     };
     
-    struct OptionFontRefVariant_None { OptionFontRefTag tag; };
-    struct OptionFontRefVariant_Some { OptionFontRefTag tag; FontRef payload; };
-    union OptionFontRef {
-        OptionFontRefVariant_None None;
-        OptionFontRefVariant_Some Some;
+    struct OptionSpreadsheetOnSelectionChangeVariant_None { OptionSpreadsheetOnSelectionChangeTag tag; };
+    struct OptionSpreadsheetOnSelectionChangeVariant_Some { OptionSpreadsheetOnSelectionChangeTag tag; SpreadsheetOnSelectionChange payload; };
+    union OptionSpreadsheetOnSelectionChange {
+        OptionSpreadsheetOnSelectionChangeVariant_None None;
+        OptionSpreadsheetOnSelectionChangeVariant_Some Some;
     };
     
     
-    enum class OptionSystemClipboardTag {
+    enum class OptionSpreadsheetOnColumnResizeTag {
        None,
        Some,
     };
     
-    struct OptionSystemClipboardVariant_None { OptionSystemClipboardTag tag; };
+    struct OptionSpreadsheetOnColumnResizeVariant_None { OptionSpreadsheetOnColumnResizeTag tag; };
+    struct OptionSpreadsheetOnColumnResizeVariant_Some { OptionSpreadsheetOnColumnResizeTag tag; SpreadsheetOnColumnResize payload; };
+    union OptionSpreadsheetOnColumnResize {
+        OptionSpreadsheetOnColumnResizeVariant_None None;
+        OptionSpreadsheetOnColumnResizeVariant_Some Some;
+    };
+    
+    
+    enum class OptionSpreadsheetCellSelectionTag {
+       None,
+       Some,
+    };
+    
+    struct OptionSpreadsheetCellSelectionVariant_None { OptionSpreadsheetCellSelectionTag tag; };
+    struct OptionSpreadsheetCellSelectionVariant_Some { OptionSpreadsheetCellSelectionTag tag; SpreadsheetCellSelection payload; };
+    union OptionSpreadsheetCellSelection {
+        OptionSpreadsheetCellSelectionVariant_None None;
+        OptionSpreadsheetCellSelectionVariant_Some Some;
+    };
+    
+    
+    enum class OptionMenuItemIconTag {
+       None,
+       Some,
+    };
+    
+    struct OptionMenuItemIconVariant_None { OptionMenuItemIconTag tag; };
+    struct OptionMenuItemIconVariant_Some { OptionMenuItemIconTag tag; MenuItemIcon payload; };
+    union OptionMenuItemIcon {
+        OptionMenuItemIconVariant_None None;
+        OptionMenuItemIconVariant_Some Some;
+    };
+    
+    
+    enum class OptionMenuCallbackTag {
+       None,
+       Some,
+    };
+    
+    struct OptionMenuCallbackVariant_None { OptionMenuCallbackTag tag; };
+    struct OptionMenuCallbackVariant_Some { OptionMenuCallbackTag tag; MenuCallback payload; };
+    union OptionMenuCallback {
+        OptionMenuCallbackVariant_None None;
+        OptionMenuCallbackVariant_Some Some;
+    };
+    
+    
+    enum class OptionPositionInfoTag {
+       None,
+       Some,
+    };
+    
+    struct OptionPositionInfoVariant_None { OptionPositionInfoTag tag; };
+    struct OptionPositionInfoVariant_Some { OptionPositionInfoTag tag; PositionInfo payload; };
+    union OptionPositionInfo {
+        OptionPositionInfoVariant_None None;
+        OptionPositionInfoVariant_Some Some;
+    };
+    
+    
+    enum class OptionTimerIdTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTimerIdVariant_None { OptionTimerIdTag tag; };
+    struct OptionTimerIdVariant_Some { OptionTimerIdTag tag; TimerId payload; };
+    union OptionTimerId {
+        OptionTimerIdVariant_None None;
+        OptionTimerIdVariant_Some Some;
+    };
+    
+    
+    enum class OptionThreadIdTag {
+       None,
+       Some,
+    };
+    
+    struct OptionThreadIdVariant_None { OptionThreadIdTag tag; };
+    struct OptionThreadIdVariant_Some { OptionThreadIdTag tag; ThreadId payload; };
+    union OptionThreadId {
+        OptionThreadIdVariant_None None;
+        OptionThreadIdVariant_Some Some;
+    };
+    
+    
+    enum class OptionImageRefTag {
+       None,
+       Some,
+    };
+    
+    struct OptionImageRefVariant_None { OptionImageRefTag tag; };
+    struct OptionImageRefVariant_Some { OptionImageRefTag tag; ImageRef payload; };
+    union OptionImageRef {
+        OptionImageRefVariant_None None;
+        OptionImageRefVariant_Some Some;
+    };
+    
+    
+    enum class OptionFontRefTag {
+       None,
+       Some,
+    };
+    
+    struct OptionFontRefVariant_None { OptionFontRefTag tag; };
+    struct OptionFontRefVariant_Some { OptionFontRefTag tag; FontRef payload; };
+    union OptionFontRef {
+        OptionFontRefVariant_None None;
+        OptionFontRefVariant_Some Some;
+    };
+    
+    
+    enum class OptionSystemClipboardTag {
+       None,
+       Some,
+    };
+    
+    struct OptionSystemClipboardVariant_None { OptionSystemClipboardTag tag; };
     struct OptionSystemClipboardVariant_Some { OptionSystemClipboardTag tag; SystemClipboard payload; };
     union OptionSystemClipboard {
         OptionSystemClipboardVariant_None None;
@@ -6212,6 +7391,7 @@ namespace dll {
     struct InlineTextContents {
         InlineGlyphVec glyphs;
         LogicalRect bounds;
+        bool  is_rtl;
         InlineTextContents& operator=(const InlineTextContents&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         InlineTextContents(const InlineTextContents&) = delete; /* disable copy constructor, use explicit .clone() */
         InlineTextContents() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -6313,6 +7493,52 @@ namespace dll {
         VirtualKeyCodeCombo() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct KeyboardShortcut {
+        VirtualKeyCodeCombo keys;
+        MenuCallback callback;
+        KeyboardShortcut& operator=(const KeyboardShortcut&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        KeyboardShortcut(const KeyboardShortcut&) = delete; /* disable copy constructor, use explicit .clone() */
+        KeyboardShortcut() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class CssMediaConditionTag {
+       MinWidth,
+       MaxWidth,
+       MinHeight,
+       MaxHeight,
+       MinResolution,
+       MaxResolution,
+       Orientation,
+       PrefersColorScheme,
+    };
+    
+    struct CssMediaConditionVariant_MinWidth { CssMediaConditionTag tag; PixelValue payload; };
+    struct CssMediaConditionVariant_MaxWidth { CssMediaConditionTag tag; PixelValue payload; };
+    struct CssMediaConditionVariant_MinHeight { CssMediaConditionTag tag; PixelValue payload; };
+    struct CssMediaConditionVariant_MaxHeight { CssMediaConditionTag tag; PixelValue payload; };
+    struct CssMediaConditionVariant_MinResolution { CssMediaConditionTag tag; FloatValue payload; };
+    struct CssMediaConditionVariant_MaxResolution { CssMediaConditionTag tag; FloatValue payload; };
+    struct CssMediaConditionVariant_Orientation { CssMediaConditionTag tag; CssMediaOrientation payload; };
+    struct CssMediaConditionVariant_PrefersColorScheme { CssMediaConditionTag tag; CssMediaColorScheme payload; };
+    union CssMediaCondition {
+        CssMediaConditionVariant_MinWidth MinWidth;
+        CssMediaConditionVariant_MaxWidth MaxWidth;
+        CssMediaConditionVariant_MinHeight MinHeight;
+        CssMediaConditionVariant_MaxHeight MaxHeight;
+        CssMediaConditionVariant_MinResolution MinResolution;
+        CssMediaConditionVariant_MaxResolution MaxResolution;
+        CssMediaConditionVariant_Orientation Orientation;
+        CssMediaConditionVariant_PrefersColorScheme PrefersColorScheme;
+    };
+    
+    
+    struct CssPath {
+        CssPathSelectorVec selectors;
+        CssPath& operator=(const CssPath&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssPath(const CssPath&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssPath() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class CssPathPseudoSelectorTag {
        First,
        Last,
@@ -6320,6 +7546,12 @@ namespace dll {
        Hover,
        Active,
        Focus,
+       FirstChild,
+       LastChild,
+       OnlyChild,
+       NthLastChild,
+       Empty,
+       FocusWithin,
     };
     
     struct CssPathPseudoSelectorVariant_First { CssPathPseudoSelectorTag tag; };
@@ -6328,6 +7560,12 @@ namespace dll {
     struct CssPathPseudoSelectorVariant_Hover { CssPathPseudoSelectorTag tag; };
     struct CssPathPseudoSelectorVariant_Active { CssPathPseudoSelectorTag tag; };
     struct CssPathPseudoSelectorVariant_Focus { CssPathPseudoSelectorTag tag; };
+    struct CssPathPseudoSelectorVariant_FirstChild { CssPathPseudoSelectorTag tag; };
+    struct CssPathPseudoSelectorVariant_LastChild { CssPathPseudoSelectorTag tag; };
+    struct CssPathPseudoSelectorVariant_OnlyChild { CssPathPseudoSelectorTag tag; };
+    struct CssPathPseudoSelectorVariant_NthLastChild { CssPathPseudoSelectorTag tag; CssNthChildSelector payload; };
+    struct CssPathPseudoSelectorVariant_Empty { CssPathPseudoSelectorTag tag; };
+    struct CssPathPseudoSelectorVariant_FocusWithin { CssPathPseudoSelectorTag tag; };
     union CssPathPseudoSelector {
         CssPathPseudoSelectorVariant_First First;
         CssPathPseudoSelectorVariant_Last Last;
@@ -6335,6 +7573,12 @@ namespace dll {
         CssPathPseudoSelectorVariant_Hover Hover;
         CssPathPseudoSelectorVariant_Active Active;
         CssPathPseudoSelectorVariant_Focus Focus;
+        CssPathPseudoSelectorVariant_FirstChild FirstChild;
+        CssPathPseudoSelectorVariant_LastChild LastChild;
+        CssPathPseudoSelectorVariant_OnlyChild OnlyChild;
+        CssPathPseudoSelectorVariant_NthLastChild NthLastChild;
+        CssPathPseudoSelectorVariant_Empty Empty;
+        CssPathPseudoSelectorVariant_FocusWithin FocusWithin;
     };
     
     
@@ -6373,6 +7617,20 @@ namespace dll {
         InterpolateContext() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct LayoutGridTemplateColumns {
+        GridTrackSizingVec tracks;
+        LayoutGridTemplateColumns& operator=(const LayoutGridTemplateColumns&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        LayoutGridTemplateColumns(const LayoutGridTemplateColumns&) = delete; /* disable copy constructor, use explicit .clone() */
+        LayoutGridTemplateColumns() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct LayoutGridTemplateRows {
+        GridTrackSizingVec tracks;
+        LayoutGridTemplateRows& operator=(const LayoutGridTemplateRows&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        LayoutGridTemplateRows(const LayoutGridTemplateRows&) = delete; /* disable copy constructor, use explicit .clone() */
+        LayoutGridTemplateRows() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct LinearGradient {
         Direction direction;
         ExtendMode extend_mode;
@@ -6403,6 +7661,31 @@ namespace dll {
         ConicGradient() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class StyleTransitionTimingFunctionTag {
+       Ease,
+       Linear,
+       EaseIn,
+       EaseOut,
+       EaseInOut,
+       CubicBezier,
+    };
+    
+    struct StyleTransitionTimingFunctionVariant_Ease { StyleTransitionTimingFunctionTag tag; };
+    struct StyleTransitionTimingFunctionVariant_Linear { StyleTransitionTimingFunctionTag tag; };
+    struct StyleTransitionTimingFunctionVariant_EaseIn { StyleTransitionTimingFunctionTag tag; };
+    struct StyleTransitionTimingFunctionVariant_EaseOut { StyleTransitionTimingFunctionTag tag; };
+    struct StyleTransitionTimingFunctionVariant_EaseInOut { StyleTransitionTimingFunctionTag tag; };
+    struct StyleTransitionTimingFunctionVariant_CubicBezier { StyleTransitionTimingFunctionTag tag; StyleCubicBezier payload; };
+    union StyleTransitionTimingFunction {
+        StyleTransitionTimingFunctionVariant_Ease Ease;
+        StyleTransitionTimingFunctionVariant_Linear Linear;
+        StyleTransitionTimingFunctionVariant_EaseIn EaseIn;
+        StyleTransitionTimingFunctionVariant_EaseOut EaseOut;
+        StyleTransitionTimingFunctionVariant_EaseInOut EaseInOut;
+        StyleTransitionTimingFunctionVariant_CubicBezier CubicBezier;
+    };
+    
+    
     enum class StyleTransformTag {
        Matrix,
        Matrix3D,
@@ -6473,6 +7756,50 @@ namespace dll {
     };
     
     
+    enum class LayoutGridTemplateColumnsValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutGridTemplateColumnsValueVariant_Auto { LayoutGridTemplateColumnsValueTag tag; };
+    struct LayoutGridTemplateColumnsValueVariant_None { LayoutGridTemplateColumnsValueTag tag; };
+    struct LayoutGridTemplateColumnsValueVariant_Inherit { LayoutGridTemplateColumnsValueTag tag; };
+    struct LayoutGridTemplateColumnsValueVariant_Initial { LayoutGridTemplateColumnsValueTag tag; };
+    struct LayoutGridTemplateColumnsValueVariant_Exact { LayoutGridTemplateColumnsValueTag tag; LayoutGridTemplateColumns payload; };
+    union LayoutGridTemplateColumnsValue {
+        LayoutGridTemplateColumnsValueVariant_Auto Auto;
+        LayoutGridTemplateColumnsValueVariant_None None;
+        LayoutGridTemplateColumnsValueVariant_Inherit Inherit;
+        LayoutGridTemplateColumnsValueVariant_Initial Initial;
+        LayoutGridTemplateColumnsValueVariant_Exact Exact;
+    };
+    
+    
+    enum class LayoutGridTemplateRowsValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutGridTemplateRowsValueVariant_Auto { LayoutGridTemplateRowsValueTag tag; };
+    struct LayoutGridTemplateRowsValueVariant_None { LayoutGridTemplateRowsValueTag tag; };
+    struct LayoutGridTemplateRowsValueVariant_Inherit { LayoutGridTemplateRowsValueTag tag; };
+    struct LayoutGridTemplateRowsValueVariant_Initial { LayoutGridTemplateRowsValueTag tag; };
+    struct LayoutGridTemplateRowsValueVariant_Exact { LayoutGridTemplateRowsValueTag tag; LayoutGridTemplateRows payload; };
+    union LayoutGridTemplateRowsValue {
+        LayoutGridTemplateRowsValueVariant_Auto Auto;
+        LayoutGridTemplateRowsValueVariant_None None;
+        LayoutGridTemplateRowsValueVariant_Inherit Inherit;
+        LayoutGridTemplateRowsValueVariant_Initial Initial;
+        LayoutGridTemplateRowsValueVariant_Exact Exact;
+    };
+    
+    
     enum class StyleBackgroundPositionVecValueTag {
        Auto,
        None,
@@ -6539,6 +7866,182 @@ namespace dll {
     };
     
     
+    enum class StyleTransitionPropertyVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleTransitionPropertyVecValueVariant_Auto { StyleTransitionPropertyVecValueTag tag; };
+    struct StyleTransitionPropertyVecValueVariant_None { StyleTransitionPropertyVecValueTag tag; };
+    struct StyleTransitionPropertyVecValueVariant_Inherit { StyleTransitionPropertyVecValueTag tag; };
+    struct StyleTransitionPropertyVecValueVariant_Initial { StyleTransitionPropertyVecValueTag tag; };
+    struct StyleTransitionPropertyVecValueVariant_Exact { StyleTransitionPropertyVecValueTag tag; StyleTransitionPropertyVec payload; };
+    union StyleTransitionPropertyVecValue {
+        StyleTransitionPropertyVecValueVariant_Auto Auto;
+        StyleTransitionPropertyVecValueVariant_None None;
+        StyleTransitionPropertyVecValueVariant_Inherit Inherit;
+        StyleTransitionPropertyVecValueVariant_Initial Initial;
+        StyleTransitionPropertyVecValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleTransitionDurationVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleTransitionDurationVecValueVariant_Auto { StyleTransitionDurationVecValueTag tag; };
+    struct StyleTransitionDurationVecValueVariant_None { StyleTransitionDurationVecValueTag tag; };
+    struct StyleTransitionDurationVecValueVariant_Inherit { StyleTransitionDurationVecValueTag tag; };
+    struct StyleTransitionDurationVecValueVariant_Initial { StyleTransitionDurationVecValueTag tag; };
+    struct StyleTransitionDurationVecValueVariant_Exact { StyleTransitionDurationVecValueTag tag; StyleTransitionDurationVec payload; };
+    union StyleTransitionDurationVecValue {
+        StyleTransitionDurationVecValueVariant_Auto Auto;
+        StyleTransitionDurationVecValueVariant_None None;
+        StyleTransitionDurationVecValueVariant_Inherit Inherit;
+        StyleTransitionDurationVecValueVariant_Initial Initial;
+        StyleTransitionDurationVecValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleTransitionDelayVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleTransitionDelayVecValueVariant_Auto { StyleTransitionDelayVecValueTag tag; };
+    struct StyleTransitionDelayVecValueVariant_None { StyleTransitionDelayVecValueTag tag; };
+    struct StyleTransitionDelayVecValueVariant_Inherit { StyleTransitionDelayVecValueTag tag; };
+    struct StyleTransitionDelayVecValueVariant_Initial { StyleTransitionDelayVecValueTag tag; };
+    struct StyleTransitionDelayVecValueVariant_Exact { StyleTransitionDelayVecValueTag tag; StyleTransitionDelayVec payload; };
+    union StyleTransitionDelayVecValue {
+        StyleTransitionDelayVecValueVariant_Auto Auto;
+        StyleTransitionDelayVecValueVariant_None None;
+        StyleTransitionDelayVecValueVariant_Inherit Inherit;
+        StyleTransitionDelayVecValueVariant_Initial Initial;
+        StyleTransitionDelayVecValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleAnimationDurationVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleAnimationDurationVecValueVariant_Auto { StyleAnimationDurationVecValueTag tag; };
+    struct StyleAnimationDurationVecValueVariant_None { StyleAnimationDurationVecValueTag tag; };
+    struct StyleAnimationDurationVecValueVariant_Inherit { StyleAnimationDurationVecValueTag tag; };
+    struct StyleAnimationDurationVecValueVariant_Initial { StyleAnimationDurationVecValueTag tag; };
+    struct StyleAnimationDurationVecValueVariant_Exact { StyleAnimationDurationVecValueTag tag; StyleAnimationDurationVec payload; };
+    union StyleAnimationDurationVecValue {
+        StyleAnimationDurationVecValueVariant_Auto Auto;
+        StyleAnimationDurationVecValueVariant_None None;
+        StyleAnimationDurationVecValueVariant_Inherit Inherit;
+        StyleAnimationDurationVecValueVariant_Initial Initial;
+        StyleAnimationDurationVecValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleAnimationIterationCountVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleAnimationIterationCountVecValueVariant_Auto { StyleAnimationIterationCountVecValueTag tag; };
+    struct StyleAnimationIterationCountVecValueVariant_None { StyleAnimationIterationCountVecValueTag tag; };
+    struct StyleAnimationIterationCountVecValueVariant_Inherit { StyleAnimationIterationCountVecValueTag tag; };
+    struct StyleAnimationIterationCountVecValueVariant_Initial { StyleAnimationIterationCountVecValueTag tag; };
+    struct StyleAnimationIterationCountVecValueVariant_Exact { StyleAnimationIterationCountVecValueTag tag; StyleAnimationIterationCountVec payload; };
+    union StyleAnimationIterationCountVecValue {
+        StyleAnimationIterationCountVecValueVariant_Auto Auto;
+        StyleAnimationIterationCountVecValueVariant_None None;
+        StyleAnimationIterationCountVecValueVariant_Inherit Inherit;
+        StyleAnimationIterationCountVecValueVariant_Initial Initial;
+        StyleAnimationIterationCountVecValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleAnimationDirectionVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleAnimationDirectionVecValueVariant_Auto { StyleAnimationDirectionVecValueTag tag; };
+    struct StyleAnimationDirectionVecValueVariant_None { StyleAnimationDirectionVecValueTag tag; };
+    struct StyleAnimationDirectionVecValueVariant_Inherit { StyleAnimationDirectionVecValueTag tag; };
+    struct StyleAnimationDirectionVecValueVariant_Initial { StyleAnimationDirectionVecValueTag tag; };
+    struct StyleAnimationDirectionVecValueVariant_Exact { StyleAnimationDirectionVecValueTag tag; StyleAnimationDirectionVec payload; };
+    union StyleAnimationDirectionVecValue {
+        StyleAnimationDirectionVecValueVariant_Auto Auto;
+        StyleAnimationDirectionVecValueVariant_None None;
+        StyleAnimationDirectionVecValueVariant_Inherit Inherit;
+        StyleAnimationDirectionVecValueVariant_Initial Initial;
+        StyleAnimationDirectionVecValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleAnimationFillModeVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleAnimationFillModeVecValueVariant_Auto { StyleAnimationFillModeVecValueTag tag; };
+    struct StyleAnimationFillModeVecValueVariant_None { StyleAnimationFillModeVecValueTag tag; };
+    struct StyleAnimationFillModeVecValueVariant_Inherit { StyleAnimationFillModeVecValueTag tag; };
+    struct StyleAnimationFillModeVecValueVariant_Initial { StyleAnimationFillModeVecValueTag tag; };
+    struct StyleAnimationFillModeVecValueVariant_Exact { StyleAnimationFillModeVecValueTag tag; StyleAnimationFillModeVec payload; };
+    union StyleAnimationFillModeVecValue {
+        StyleAnimationFillModeVecValueVariant_Auto Auto;
+        StyleAnimationFillModeVecValueVariant_None None;
+        StyleAnimationFillModeVecValueVariant_Inherit Inherit;
+        StyleAnimationFillModeVecValueVariant_Initial Initial;
+        StyleAnimationFillModeVecValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleAnimationDelayVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleAnimationDelayVecValueVariant_Auto { StyleAnimationDelayVecValueTag tag; };
+    struct StyleAnimationDelayVecValueVariant_None { StyleAnimationDelayVecValueTag tag; };
+    struct StyleAnimationDelayVecValueVariant_Inherit { StyleAnimationDelayVecValueTag tag; };
+    struct StyleAnimationDelayVecValueVariant_Initial { StyleAnimationDelayVecValueTag tag; };
+    struct StyleAnimationDelayVecValueVariant_Exact { StyleAnimationDelayVecValueTag tag; StyleAnimationDelayVec payload; };
+    union StyleAnimationDelayVecValue {
+        StyleAnimationDelayVecValueVariant_Auto Auto;
+        StyleAnimationDelayVecValueVariant_None None;
+        StyleAnimationDelayVecValueVariant_Inherit Inherit;
+        StyleAnimationDelayVecValueVariant_Initial Initial;
+        StyleAnimationDelayVecValueVariant_Exact Exact;
+    };
+    
+    
     struct CheckBoxStateWrapper {
         CheckBoxState inner;
         OptionCheckBoxOnToggle on_toggle;
@@ -6555,6 +8058,47 @@ namespace dll {
         NumberInputStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextEditState {
+        U32Vec text;
+        OptionTextInputSelection selection;
+        size_t cursor_pos;
+        bool  show_line_numbers;
+        TextEditState& operator=(const TextEditState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditState(const TextEditState&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TextEditSnapshot {
+        U32Vec text;
+        size_t cursor_pos;
+        TextEditSnapshot& operator=(const TextEditSnapshot&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditSnapshot(const TextEditSnapshot&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditSnapshot() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SliderStateWrapper {
+        SliderState inner;
+        OptionSliderOnValueChange on_value_change;
+        bool  is_dragging;
+        float drag_value;
+        SliderStateWrapper& operator=(const SliderStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SliderStateWrapper(const SliderStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
+        SliderStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class CssPropertySourceTag {
+       Css,
+       Inline,
+    };
+    
+    struct CssPropertySourceVariant_Css { CssPropertySourceTag tag; CssPath payload; };
+    struct CssPropertySourceVariant_Inline { CssPropertySourceTag tag; };
+    union CssPropertySource {
+        CssPropertySourceVariant_Css Css;
+        CssPropertySourceVariant_Inline Inline;
+    };
+    
+    
     struct StyledNode {
         StyledNodeState state;
         OptionTagId tag_id;
@@ -6744,6 +8288,36 @@ namespace dll {
         SvgPathElementVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct KeyboardShortcutVec {
+        KeyboardShortcut* ptr;
+        size_t len;
+        size_t cap;
+        KeyboardShortcutVecDestructor destructor;
+        KeyboardShortcutVec& operator=(const KeyboardShortcutVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        KeyboardShortcutVec(const KeyboardShortcutVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        KeyboardShortcutVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct CssMediaConditionVec {
+        CssMediaCondition* ptr;
+        size_t len;
+        size_t cap;
+        CssMediaConditionVecDestructor destructor;
+        CssMediaConditionVec& operator=(const CssMediaConditionVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssMediaConditionVec(const CssMediaConditionVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssMediaConditionVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleTransitionTimingFunctionVec {
+        StyleTransitionTimingFunction* ptr;
+        size_t len;
+        size_t cap;
+        StyleTransitionTimingFunctionVecDestructor destructor;
+        StyleTransitionTimingFunctionVec& operator=(const StyleTransitionTimingFunctionVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTransitionTimingFunctionVec(const StyleTransitionTimingFunctionVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleTransitionTimingFunctionVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StringVec {
         String* ptr;
         size_t len;
@@ -6774,6 +8348,16 @@ namespace dll {
         TagIdToNodeIdMappingVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextEditSnapshotVec {
+        TextEditSnapshot* ptr;
+        size_t len;
+        size_t cap;
+        TextEditSnapshotVecDestructor destructor;
+        TextEditSnapshotVec& operator=(const TextEditSnapshotVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditSnapshotVec(const TextEditSnapshotVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditSnapshotVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class OptionVirtualKeyCodeComboTag {
        None,
        Some,
@@ -7067,6 +8651,14 @@ namespace dll {
     };
     
     
+    struct FocusTargetPath {
+        DomId dom;
+        CssPath css_path;
+        FocusTargetPath& operator=(const FocusTargetPath&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        FocusTargetPath(const FocusTargetPath&) = delete; /* disable copy constructor, use explicit .clone() */
+        FocusTargetPath() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct CallbackData {
         EventFilter event;
         Callback callback;
@@ -7104,13 +8696,35 @@ namespace dll {
     struct AccessibilityInfo {
         OptionString name;
         OptionString value;
-        AccessibilityRole role;
+        AccessibilityRole role;
+        AccessibilityStateVec states;
+        OptionVirtualKeyCodeCombo accelerator;
+        OptionString default_action;
+        AccessibilityInfo& operator=(const AccessibilityInfo&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        AccessibilityInfo(const AccessibilityInfo&) = delete; /* disable copy constructor, use explicit .clone() */
+        AccessibilityInfo() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct AccessibilityNode {
+        DomNodeId node_id;
+        AccessibilityRole role;
+        OptionString name;
+        OptionString value;
         AccessibilityStateVec states;
         OptionVirtualKeyCodeCombo accelerator;
         OptionString default_action;
-        AccessibilityInfo& operator=(const AccessibilityInfo&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        AccessibilityInfo(const AccessibilityInfo&) = delete; /* disable copy constructor, use explicit .clone() */
-        AccessibilityInfo() = delete; /* disable default constructor, use C++20 designated initializer instead */
+        LogicalRect bounds;
+        AccessibilityNodeVec children;
+        AccessibilityNode& operator=(const AccessibilityNode&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        AccessibilityNode(const AccessibilityNode&) = delete; /* disable copy constructor, use explicit .clone() */
+        AccessibilityNode() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct AccessibilityTree {
+        AccessibilityNode root;
+        AccessibilityTree& operator=(const AccessibilityTree&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        AccessibilityTree(const AccessibilityTree&) = delete; /* disable copy constructor, use explicit .clone() */
+        AccessibilityTree() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
     enum class IdOrClassTag {
@@ -7138,34 +8752,56 @@ namespace dll {
         StringMenuItem() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    enum class CssPathSelectorTag {
-       Global,
-       Type,
-       Class,
-       Id,
-       PseudoSelector,
-       DirectChildren,
-       Children,
+    enum class ShortcutConflictTag {
+       Shortcut,
+       MenuItem,
+       ContextMenuItem,
     };
     
-    struct CssPathSelectorVariant_Global { CssPathSelectorTag tag; };
-    struct CssPathSelectorVariant_Type { CssPathSelectorTag tag; NodeTypeKey payload; };
-    struct CssPathSelectorVariant_Class { CssPathSelectorTag tag; String payload; };
-    struct CssPathSelectorVariant_Id { CssPathSelectorTag tag; String payload; };
-    struct CssPathSelectorVariant_PseudoSelector { CssPathSelectorTag tag; CssPathPseudoSelector payload; };
-    struct CssPathSelectorVariant_DirectChildren { CssPathSelectorTag tag; };
-    struct CssPathSelectorVariant_Children { CssPathSelectorTag tag; };
-    union CssPathSelector {
-        CssPathSelectorVariant_Global Global;
-        CssPathSelectorVariant_Type Type;
-        CssPathSelectorVariant_Class Class;
-        CssPathSelectorVariant_Id Id;
-        CssPathSelectorVariant_PseudoSelector PseudoSelector;
-        CssPathSelectorVariant_DirectChildren DirectChildren;
-        CssPathSelectorVariant_Children Children;
+    struct ShortcutConflictVariant_Shortcut { ShortcutConflictTag tag; VirtualKeyCodeCombo payload; };
+    struct ShortcutConflictVariant_MenuItem { ShortcutConflictTag tag; String payload; };
+    struct ShortcutConflictVariant_ContextMenuItem { ShortcutConflictTag tag; String payload; };
+    union ShortcutConflict {
+        ShortcutConflictVariant_Shortcut Shortcut;
+        ShortcutConflictVariant_MenuItem MenuItem;
+        ShortcutConflictVariant_ContextMenuItem ContextMenuItem;
     };
     
     
+    struct CssMediaQuery {
+        CssMediaConditionVec conditions;
+        CssMediaQuery& operator=(const CssMediaQuery&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssMediaQuery(const CssMediaQuery&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssMediaQuery() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct CssCustomProperty {
+        String name;
+        String value;
+        CssCustomProperty& operator=(const CssCustomProperty&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssCustomProperty(const CssCustomProperty&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssCustomProperty() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct CssAttributeSelector {
+        String name;
+        CssAttributeSelectorOp op;
+        String value;
+        CssAttributeSelector& operator=(const CssAttributeSelector&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssAttributeSelector(const CssAttributeSelector&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssAttributeSelector() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct CssFontFace {
+        String font_family;
+        String src;
+        uint16_t font_weight;
+        CssFontFaceStyle font_style;
+        CssFontFace& operator=(const CssFontFace&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssFontFace(const CssFontFace&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssFontFace() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleBackgroundContentTag {
        LinearGradient,
        RadialGradient,
@@ -7226,6 +8862,13 @@ namespace dll {
     };
     
     
+    struct StyleAnimationName {
+        String name;
+        StyleAnimationName& operator=(const StyleAnimationName&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleAnimationName(const StyleAnimationName&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleAnimationName() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class ScrollbarStyleValueTag {
        Auto,
        None,
@@ -7270,6 +8913,28 @@ namespace dll {
     };
     
     
+    enum class StyleTransitionTimingFunctionVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleTransitionTimingFunctionVecValueVariant_Auto { StyleTransitionTimingFunctionVecValueTag tag; };
+    struct StyleTransitionTimingFunctionVecValueVariant_None { StyleTransitionTimingFunctionVecValueTag tag; };
+    struct StyleTransitionTimingFunctionVecValueVariant_Inherit { StyleTransitionTimingFunctionVecValueTag tag; };
+    struct StyleTransitionTimingFunctionVecValueVariant_Initial { StyleTransitionTimingFunctionVecValueTag tag; };
+    struct StyleTransitionTimingFunctionVecValueVariant_Exact { StyleTransitionTimingFunctionVecValueTag tag; StyleTransitionTimingFunctionVec payload; };
+    union StyleTransitionTimingFunctionVecValue {
+        StyleTransitionTimingFunctionVecValueVariant_Auto Auto;
+        StyleTransitionTimingFunctionVecValueVariant_None None;
+        StyleTransitionTimingFunctionVecValueVariant_Inherit Inherit;
+        StyleTransitionTimingFunctionVecValueVariant_Initial Initial;
+        StyleTransitionTimingFunctionVecValueVariant_Exact Exact;
+    };
+    
+    
     struct ColorInputStateWrapper {
         ColorInputState inner;
         String title;
@@ -7290,6 +8955,49 @@ namespace dll {
         TextInputState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextEditHistory {
+        TextEditSnapshotVec undo_stack;
+        TextEditSnapshotVec redo_stack;
+        OptionTextEditKind last_edit;
+        TextEditHistory& operator=(const TextEditHistory&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditHistory(const TextEditHistory&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditHistory() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DropDownState {
+        StringVec choices;
+        U32Vec disabled_choices;
+        OptionUsize selected;
+        MenuPopupPosition popup_position;
+        DropDownState& operator=(const DropDownState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DropDownState(const DropDownState&) = delete; /* disable copy constructor, use explicit .clone() */
+        DropDownState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetCellContent {
+        SpreadsheetCellIndex cell;
+        String text;
+        SpreadsheetCellContent& operator=(const SpreadsheetCellContent&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetCellContent(const SpreadsheetCellContent&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetCellContent() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetCellClick {
+        SpreadsheetCellIndex cell;
+        Instant time;
+        SpreadsheetCellClick& operator=(const SpreadsheetCellClick&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetCellClick(const SpreadsheetCellClick&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetCellClick() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetCellEdit {
+        SpreadsheetCellIndex cell;
+        String text;
+        SpreadsheetCellEdit& operator=(const SpreadsheetCellEdit&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetCellEdit(const SpreadsheetCellEdit&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetCellEdit() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct VertexAttribute {
         String name;
         OptionUsize layout_location;
@@ -7547,14 +9255,34 @@ namespace dll {
         VertexAttributeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct CssPathSelectorVec {
-        CssPathSelector* ptr;
+    struct CssMediaQueryVec {
+        CssMediaQuery* ptr;
         size_t len;
         size_t cap;
-        CssPathSelectorVecDestructor destructor;
-        CssPathSelectorVec& operator=(const CssPathSelectorVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        CssPathSelectorVec(const CssPathSelectorVec&) = delete; /* disable copy constructor, use explicit .clone() */
-        CssPathSelectorVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+        CssMediaQueryVecDestructor destructor;
+        CssMediaQueryVec& operator=(const CssMediaQueryVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssMediaQueryVec(const CssMediaQueryVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssMediaQueryVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct CssFontFaceVec {
+        CssFontFace* ptr;
+        size_t len;
+        size_t cap;
+        CssFontFaceVecDestructor destructor;
+        CssFontFaceVec& operator=(const CssFontFaceVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssFontFaceVec(const CssFontFaceVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssFontFaceVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleAnimationNameVec {
+        StyleAnimationName* ptr;
+        size_t len;
+        size_t cap;
+        StyleAnimationNameVecDestructor destructor;
+        StyleAnimationNameVec& operator=(const StyleAnimationNameVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleAnimationNameVec(const StyleAnimationNameVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleAnimationNameVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
     struct CallbackDataVec {
@@ -7587,6 +9315,55 @@ namespace dll {
         StringPairVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct SpreadsheetCellContentVec {
+        SpreadsheetCellContent* ptr;
+        size_t len;
+        size_t cap;
+        SpreadsheetCellContentVecDestructor destructor;
+        SpreadsheetCellContentVec& operator=(const SpreadsheetCellContentVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetCellContentVec(const SpreadsheetCellContentVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetCellContentVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class OptionSpreadsheetCellClickTag {
+       None,
+       Some,
+    };
+    
+    struct OptionSpreadsheetCellClickVariant_None { OptionSpreadsheetCellClickTag tag; };
+    struct OptionSpreadsheetCellClickVariant_Some { OptionSpreadsheetCellClickTag tag; SpreadsheetCellClick payload; };
+    union OptionSpreadsheetCellClick {
+        OptionSpreadsheetCellClickVariant_None None;
+        OptionSpreadsheetCellClickVariant_Some Some;
+    };
+    
+    
+    enum class OptionSpreadsheetCellEditTag {
+       None,
+       Some,
+    };
+    
+    struct OptionSpreadsheetCellEditVariant_None { OptionSpreadsheetCellEditTag tag; };
+    struct OptionSpreadsheetCellEditVariant_Some { OptionSpreadsheetCellEditTag tag; SpreadsheetCellEdit payload; };
+    union OptionSpreadsheetCellEdit {
+        OptionSpreadsheetCellEditVariant_None None;
+        OptionSpreadsheetCellEditVariant_Some Some;
+    };
+    
+    
+    enum class OptionAccessibilityNodeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionAccessibilityNodeVariant_None { OptionAccessibilityNodeTag tag; };
+    struct OptionAccessibilityNodeVariant_Some { OptionAccessibilityNodeTag tag; AccessibilityNode payload; };
+    union OptionAccessibilityNode {
+        OptionAccessibilityNodeVariant_None None;
+        OptionAccessibilityNodeVariant_Some Some;
+    };
+    
+    
     enum class OptionFileTypeListTag {
        None,
        Some,
@@ -7724,6 +9501,34 @@ namespace dll {
         InlineLine() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class FocusTargetTag {
+       Id,
+       Path,
+       Previous,
+       Next,
+       First,
+       Last,
+       NoFocus,
+    };
+    
+    struct FocusTargetVariant_Id { FocusTargetTag tag; DomNodeId payload; };
+    struct FocusTargetVariant_Path { FocusTargetTag tag; FocusTargetPath payload; };
+    struct FocusTargetVariant_Previous { FocusTargetTag tag; };
+    struct FocusTargetVariant_Next { FocusTargetTag tag; };
+    struct FocusTargetVariant_First { FocusTargetTag tag; };
+    struct FocusTargetVariant_Last { FocusTargetTag tag; };
+    struct FocusTargetVariant_NoFocus { FocusTargetTag tag; };
+    union FocusTarget {
+        FocusTargetVariant_Id Id;
+        FocusTargetVariant_Path Path;
+        FocusTargetVariant_Previous Previous;
+        FocusTargetVariant_Next Next;
+        FocusTargetVariant_First First;
+        FocusTargetVariant_Last Last;
+        FocusTargetVariant_NoFocus NoFocus;
+    };
+    
+    
     enum class MenuItemTag {
        Label,
        Separator,
@@ -7740,13 +9545,46 @@ namespace dll {
     };
     
     
-    struct CssPath {
-        CssPathSelectorVec selectors;
-        CssPath& operator=(const CssPath&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        CssPath(const CssPath&) = delete; /* disable copy constructor, use explicit .clone() */
-        CssPath() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    enum class CssPathSelectorTag {
+       Global,
+       Type,
+       Class,
+       Id,
+       PseudoSelector,
+       Attribute,
+       Not,
+       DirectChildren,
+       Children,
+       AdjacentSibling,
+       GeneralSibling,
+    };
+    
+    struct CssPathSelectorVariant_Global { CssPathSelectorTag tag; };
+    struct CssPathSelectorVariant_Type { CssPathSelectorTag tag; NodeTypeKey payload; };
+    struct CssPathSelectorVariant_Class { CssPathSelectorTag tag; String payload; };
+    struct CssPathSelectorVariant_Id { CssPathSelectorTag tag; String payload; };
+    struct CssPathSelectorVariant_PseudoSelector { CssPathSelectorTag tag; CssPathPseudoSelector payload; };
+    struct CssPathSelectorVariant_Attribute { CssPathSelectorTag tag; CssAttributeSelector payload; };
+    struct CssPathSelectorVariant_Not { CssPathSelectorTag tag; CssPathSelectorVec payload; };
+    struct CssPathSelectorVariant_DirectChildren { CssPathSelectorTag tag; };
+    struct CssPathSelectorVariant_Children { CssPathSelectorTag tag; };
+    struct CssPathSelectorVariant_AdjacentSibling { CssPathSelectorTag tag; };
+    struct CssPathSelectorVariant_GeneralSibling { CssPathSelectorTag tag; };
+    union CssPathSelector {
+        CssPathSelectorVariant_Global Global;
+        CssPathSelectorVariant_Type Type;
+        CssPathSelectorVariant_Class Class;
+        CssPathSelectorVariant_Id Id;
+        CssPathSelectorVariant_PseudoSelector PseudoSelector;
+        CssPathSelectorVariant_Attribute Attribute;
+        CssPathSelectorVariant_Not Not;
+        CssPathSelectorVariant_DirectChildren DirectChildren;
+        CssPathSelectorVariant_Children Children;
+        CssPathSelectorVariant_AdjacentSibling AdjacentSibling;
+        CssPathSelectorVariant_GeneralSibling GeneralSibling;
     };
     
+    
     enum class StyleBackgroundContentVecValueTag {
        Auto,
        None,
@@ -7791,6 +9629,28 @@ namespace dll {
     };
     
     
+    enum class StyleAnimationNameVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleAnimationNameVecValueVariant_Auto { StyleAnimationNameVecValueTag tag; };
+    struct StyleAnimationNameVecValueVariant_None { StyleAnimationNameVecValueTag tag; };
+    struct StyleAnimationNameVecValueVariant_Inherit { StyleAnimationNameVecValueTag tag; };
+    struct StyleAnimationNameVecValueVariant_Initial { StyleAnimationNameVecValueTag tag; };
+    struct StyleAnimationNameVecValueVariant_Exact { StyleAnimationNameVecValueTag tag; StyleAnimationNameVec payload; };
+    union StyleAnimationNameVecValue {
+        StyleAnimationNameVecValueVariant_Auto Auto;
+        StyleAnimationNameVecValueVariant_None None;
+        StyleAnimationNameVecValueVariant_Inherit Inherit;
+        StyleAnimationNameVecValueVariant_Initial Initial;
+        StyleAnimationNameVecValueVariant_Exact Exact;
+    };
+    
+    
     enum class CssPropertyTag {
        TextColor,
        FontSize,
@@ -7803,6 +9663,7 @@ namespace dll {
        Cursor,
        Display,
        Float,
+       Clear,
        BoxSizing,
        Width,
        Height,
@@ -7822,6 +9683,12 @@ namespace dll {
        JustifyContent,
        AlignItems,
        AlignContent,
+       GridTemplateColumns,
+       GridTemplateRows,
+       GridColumn,
+       GridRow,
+       ColumnGap,
+       RowGap,
        BackgroundContent,
        BackgroundPosition,
        BackgroundSize,
@@ -7862,6 +9729,17 @@ namespace dll {
        TransformOrigin,
        PerspectiveOrigin,
        BackfaceVisibility,
+       TransitionProperty,
+       TransitionDuration,
+       TransitionTimingFunction,
+       TransitionDelay,
+       AnimationName,
+       AnimationDuration,
+       AnimationTimingFunction,
+       AnimationIterationCount,
+       AnimationDirection,
+       AnimationFillMode,
+       AnimationDelay,
     };
     
     struct CssPropertyVariant_TextColor { CssPropertyTag tag; StyleTextColorValue payload; };
@@ -7875,6 +9753,7 @@ namespace dll {
     struct CssPropertyVariant_Cursor { CssPropertyTag tag; StyleCursorValue payload; };
     struct CssPropertyVariant_Display { CssPropertyTag tag; LayoutDisplayValue payload; };
     struct CssPropertyVariant_Float { CssPropertyTag tag; LayoutFloatValue payload; };
+    struct CssPropertyVariant_Clear { CssPropertyTag tag; LayoutClearValue payload; };
     struct CssPropertyVariant_BoxSizing { CssPropertyTag tag; LayoutBoxSizingValue payload; };
     struct CssPropertyVariant_Width { CssPropertyTag tag; LayoutWidthValue payload; };
     struct CssPropertyVariant_Height { CssPropertyTag tag; LayoutHeightValue payload; };
@@ -7894,6 +9773,12 @@ namespace dll {
     struct CssPropertyVariant_JustifyContent { CssPropertyTag tag; LayoutJustifyContentValue payload; };
     struct CssPropertyVariant_AlignItems { CssPropertyTag tag; LayoutAlignItemsValue payload; };
     struct CssPropertyVariant_AlignContent { CssPropertyTag tag; LayoutAlignContentValue payload; };
+    struct CssPropertyVariant_GridTemplateColumns { CssPropertyTag tag; LayoutGridTemplateColumnsValue payload; };
+    struct CssPropertyVariant_GridTemplateRows { CssPropertyTag tag; LayoutGridTemplateRowsValue payload; };
+    struct CssPropertyVariant_GridColumn { CssPropertyTag tag; LayoutGridColumnValue payload; };
+    struct CssPropertyVariant_GridRow { CssPropertyTag tag; LayoutGridRowValue payload; };
+    struct CssPropertyVariant_ColumnGap { CssPropertyTag tag; LayoutColumnGapValue payload; };
+    struct CssPropertyVariant_RowGap { CssPropertyTag tag; LayoutRowGapValue payload; };
     struct CssPropertyVariant_BackgroundContent { CssPropertyTag tag; StyleBackgroundContentVecValue payload; };
     struct CssPropertyVariant_BackgroundPosition { CssPropertyTag tag; StyleBackgroundPositionVecValue payload; };
     struct CssPropertyVariant_BackgroundSize { CssPropertyTag tag; StyleBackgroundSizeVecValue payload; };
@@ -7934,6 +9819,17 @@ namespace dll {
     struct CssPropertyVariant_TransformOrigin { CssPropertyTag tag; StyleTransformOriginValue payload; };
     struct CssPropertyVariant_PerspectiveOrigin { CssPropertyTag tag; StylePerspectiveOriginValue payload; };
     struct CssPropertyVariant_BackfaceVisibility { CssPropertyTag tag; StyleBackfaceVisibilityValue payload; };
+    struct CssPropertyVariant_TransitionProperty { CssPropertyTag tag; StyleTransitionPropertyVecValue payload; };
+    struct CssPropertyVariant_TransitionDuration { CssPropertyTag tag; StyleTransitionDurationVecValue payload; };
+    struct CssPropertyVariant_TransitionTimingFunction { CssPropertyTag tag; StyleTransitionTimingFunctionVecValue payload; };
+    struct CssPropertyVariant_TransitionDelay { CssPropertyTag tag; StyleTransitionDelayVecValue payload; };
+    struct CssPropertyVariant_AnimationName { CssPropertyTag tag; StyleAnimationNameVecValue payload; };
+    struct CssPropertyVariant_AnimationDuration { CssPropertyTag tag; StyleAnimationDurationVecValue payload; };
+    struct CssPropertyVariant_AnimationTimingFunction { CssPropertyTag tag; StyleTransitionTimingFunctionVecValue payload; };
+    struct CssPropertyVariant_AnimationIterationCount { CssPropertyTag tag; StyleAnimationIterationCountVecValue payload; };
+    struct CssPropertyVariant_AnimationDirection { CssPropertyTag tag; StyleAnimationDirectionVecValue payload; };
+    struct CssPropertyVariant_AnimationFillMode { CssPropertyTag tag; StyleAnimationFillModeVecValue payload; };
+    struct CssPropertyVariant_AnimationDelay { CssPropertyTag tag; StyleAnimationDelayVecValue payload; };
     union CssProperty {
         CssPropertyVariant_TextColor TextColor;
         CssPropertyVariant_FontSize FontSize;
@@ -7946,6 +9842,7 @@ namespace dll {
         CssPropertyVariant_Cursor Cursor;
         CssPropertyVariant_Display Display;
         CssPropertyVariant_Float Float;
+        CssPropertyVariant_Clear Clear;
         CssPropertyVariant_BoxSizing BoxSizing;
         CssPropertyVariant_Width Width;
         CssPropertyVariant_Height Height;
@@ -7965,6 +9862,12 @@ namespace dll {
         CssPropertyVariant_JustifyContent JustifyContent;
         CssPropertyVariant_AlignItems AlignItems;
         CssPropertyVariant_AlignContent AlignContent;
+        CssPropertyVariant_GridTemplateColumns GridTemplateColumns;
+        CssPropertyVariant_GridTemplateRows GridTemplateRows;
+        CssPropertyVariant_GridColumn GridColumn;
+        CssPropertyVariant_GridRow GridRow;
+        CssPropertyVariant_ColumnGap ColumnGap;
+        CssPropertyVariant_RowGap RowGap;
         CssPropertyVariant_BackgroundContent BackgroundContent;
         CssPropertyVariant_BackgroundPosition BackgroundPosition;
         CssPropertyVariant_BackgroundSize BackgroundSize;
@@ -8005,6 +9908,17 @@ namespace dll {
         CssPropertyVariant_TransformOrigin TransformOrigin;
         CssPropertyVariant_PerspectiveOrigin PerspectiveOrigin;
         CssPropertyVariant_BackfaceVisibility BackfaceVisibility;
+        CssPropertyVariant_TransitionProperty TransitionProperty;
+        CssPropertyVariant_TransitionDuration TransitionDuration;
+        CssPropertyVariant_TransitionTimingFunction TransitionTimingFunction;
+        CssPropertyVariant_TransitionDelay TransitionDelay;
+        CssPropertyVariant_AnimationName AnimationName;
+        CssPropertyVariant_AnimationDuration AnimationDuration;
+        CssPropertyVariant_AnimationTimingFunction AnimationTimingFunction;
+        CssPropertyVariant_AnimationIterationCount AnimationIterationCount;
+        CssPropertyVariant_AnimationDirection AnimationDirection;
+        CssPropertyVariant_AnimationFillMode AnimationFillMode;
+        CssPropertyVariant_AnimationDelay AnimationDelay;
     };
     
     
@@ -8015,24 +9929,61 @@ namespace dll {
         OptionTextInputOnFocusLost on_focus_lost;
         bool  update_text_input_before_calling_focus_lost_fn;
         bool  update_text_input_before_calling_vk_down_fn;
+        bool  is_dragging;
         TextInputStateWrapper& operator=(const TextInputStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         TextInputStateWrapper(const TextInputStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
         TextInputStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    enum class CssPropertySourceTag {
-       Css,
-       Inline,
-    };
-    
-    struct CssPropertySourceVariant_Css { CssPropertySourceTag tag; CssPath payload; };
-    struct CssPropertySourceVariant_Inline { CssPropertySourceTag tag; };
-    union CssPropertySource {
-        CssPropertySourceVariant_Css Css;
-        CssPropertySourceVariant_Inline Inline;
+    struct TextEditStateWrapper {
+        TextEditState inner;
+        OptionTextEditOnTextChange on_text_change;
+        OptionTextEditOnFocusLost on_focus_lost;
+        TextEditHistory history;
+        TextEditView view;
+        TextEditStateWrapper& operator=(const TextEditStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEditStateWrapper(const TextEditStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEditStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DropDownStateWrapper {
+        DropDownState inner;
+        OptionDropDownOnChoiceChange on_choice_change;
+        bool  is_open;
+        OptionUsize highlighted;
+        String search;
+        OptionInstant last_search_input;
+        DropDownStateWrapper& operator=(const DropDownStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DropDownStateWrapper(const DropDownStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
+        DropDownStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetState {
+        SpreadsheetStyle style;
+        size_t row_count;
+        size_t column_count;
+        float default_column_width;
+        float default_row_height;
+        OptionSpreadsheetCellSelection selection;
+        SpreadsheetSizeOverrideVec column_width_overrides;
+        SpreadsheetSizeOverrideVec row_height_overrides;
+        SpreadsheetCellContentVec cell_contents;
+        SpreadsheetState& operator=(const SpreadsheetState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetState(const SpreadsheetState&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetView {
+        SpreadsheetRenderWindow rendered;
+        OptionDomNodeId iframe_node;
+        SpreadsheetDrag drag;
+        OptionSpreadsheetCellClick last_click;
+        OptionSpreadsheetCellEdit editing;
+        SpreadsheetView& operator=(const SpreadsheetView&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetView(const SpreadsheetView&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetView() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    
     struct VertexLayout {
         VertexAttributeVec fields;
         VertexLayout& operator=(const VertexLayout&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -8156,6 +10107,7 @@ namespace dll {
         ColorU background_color;
         LayoutCallback layout_callback;
         OptionCallback close_callback;
+        KeyboardShortcutVec shortcuts;
         WindowState& operator=(const WindowState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         WindowState(const WindowState&) = delete; /* disable copy constructor, use explicit .clone() */
         WindowState() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -8190,6 +10142,8 @@ namespace dll {
         void* restrict images_changed_in_callbacks;
         void* restrict image_masks_changed_in_callbacks;
         void* restrict css_properties_changed_in_callbacks;
+        void* restrict css_variables_changed_in_callbacks;
+        void* restrict css_transitions_changed_in_callbacks;
         void* current_scroll_states;
         void* restrict nodes_scrolled_in_callback;
         DomNodeId hit_dom_node;
@@ -8213,14 +10167,6 @@ namespace dll {
         InlineText() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct FocusTargetPath {
-        DomId dom;
-        CssPath css_path;
-        FocusTargetPath& operator=(const FocusTargetPath&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        FocusTargetPath(const FocusTargetPath&) = delete; /* disable copy constructor, use explicit .clone() */
-        FocusTargetPath() = delete; /* disable default constructor, use C++20 designated initializer instead */
-    };
-    
     struct Animation {
         CssProperty from;
         CssProperty to;
@@ -8274,6 +10220,25 @@ namespace dll {
         DynamicCssProperty() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CssKeyframe {
+        FloatValue offset;
+        CssPropertyVec properties;
+        CssKeyframe& operator=(const CssKeyframe&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssKeyframe(const CssKeyframe&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssKeyframe() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SpreadsheetStateWrapper {
+        SpreadsheetState inner;
+        OptionSpreadsheetOnCellsChange on_cells_change;
+        OptionSpreadsheetOnSelectionChange on_selection_change;
+        OptionSpreadsheetOnColumnResize on_column_resize;
+        SpreadsheetView view;
+        SpreadsheetStateWrapper& operator=(const SpreadsheetStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SpreadsheetStateWrapper(const SpreadsheetStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
+        SpreadsheetStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class SvgNodeTag {
        MultiPolygonCollection,
        MultiPolygon,
@@ -8314,6 +10279,16 @@ namespace dll {
         NodeDataInlineCssPropertyVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CssKeyframeVec {
+        CssKeyframe* ptr;
+        size_t len;
+        size_t cap;
+        CssKeyframeVecDestructor destructor;
+        CssKeyframeVec& operator=(const CssKeyframeVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssKeyframeVec(const CssKeyframeVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssKeyframeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class OptionWindowStateTag {
        None,
        Some,
@@ -8389,34 +10364,6 @@ namespace dll {
         WindowCreateOptions() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    enum class FocusTargetTag {
-       Id,
-       Path,
-       Previous,
-       Next,
-       First,
-       Last,
-       NoFocus,
-    };
-    
-    struct FocusTargetVariant_Id { FocusTargetTag tag; DomNodeId payload; };
-    struct FocusTargetVariant_Path { FocusTargetTag tag; FocusTargetPath payload; };
-    struct FocusTargetVariant_Previous { FocusTargetTag tag; };
-    struct FocusTargetVariant_Next { FocusTargetTag tag; };
-    struct FocusTargetVariant_First { FocusTargetTag tag; };
-    struct FocusTargetVariant_Last { FocusTargetTag tag; };
-    struct FocusTargetVariant_NoFocus { FocusTargetTag tag; };
-    union FocusTarget {
-        FocusTargetVariant_Id Id;
-        FocusTargetVariant_Path Path;
-        FocusTargetVariant_Previous Previous;
-        FocusTargetVariant_Next Next;
-        FocusTargetVariant_First First;
-        FocusTargetVariant_Last Last;
-        FocusTargetVariant_NoFocus NoFocus;
-    };
-    
-    
     struct NodeData {
         NodeType node_type;
         OptionRefAny dataset;
@@ -8432,16 +10379,27 @@ namespace dll {
     enum class CssDeclarationTag {
        Static,
        Dynamic,
+       Custom,
     };
     
     struct CssDeclarationVariant_Static { CssDeclarationTag tag; CssProperty payload; };
     struct CssDeclarationVariant_Dynamic { CssDeclarationTag tag; DynamicCssProperty payload; };
+    struct CssDeclarationVariant_Custom { CssDeclarationTag tag; CssCustomProperty payload; };
     union CssDeclaration {
         CssDeclarationVariant_Static Static;
         CssDeclarationVariant_Dynamic Dynamic;
+        CssDeclarationVariant_Custom Custom;
     };
     
     
+    struct CssKeyframes {
+        String name;
+        CssKeyframeVec keyframes;
+        CssKeyframes& operator=(const CssKeyframes&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssKeyframes(const CssKeyframes&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssKeyframes() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct Button {
         String label;
         OptionImageRef image;
@@ -8497,6 +10455,48 @@ namespace dll {
         NumberInput() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextEdit {
+        TextEditStateWrapper state;
+        NodeDataInlineCssPropertyVec container_style;
+        NodeDataInlineCssPropertyVec line_numbers_style;
+        NodeDataInlineCssPropertyVec label_style;
+        TextEdit& operator=(const TextEdit&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextEdit(const TextEdit&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextEdit() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct Slider {
+        SliderStateWrapper state;
+        NodeDataInlineCssPropertyVec container_style;
+        NodeDataInlineCssPropertyVec track_style;
+        NodeDataInlineCssPropertyVec thumb_style;
+        NodeDataInlineCssPropertyVec tick_style;
+        NodeDataInlineCssPropertyVec label_style;
+        Slider& operator=(const Slider&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Slider(const Slider&) = delete; /* disable copy constructor, use explicit .clone() */
+        Slider() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct DropDown {
+        DropDownStateWrapper state;
+        NodeDataInlineCssPropertyVec container_style;
+        NodeDataInlineCssPropertyVec label_style;
+        NodeDataInlineCssPropertyVec popup_style;
+        NodeDataInlineCssPropertyVec entry_style;
+        NodeDataInlineCssPropertyVec disabled_entry_style;
+        DropDown& operator=(const DropDown&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        DropDown(const DropDown&) = delete; /* disable copy constructor, use explicit .clone() */
+        DropDown() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct Spreadsheet {
+        SpreadsheetStateWrapper state;
+        NodeDataInlineCssPropertyVec container_style;
+        Spreadsheet& operator=(const Spreadsheet&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Spreadsheet(const Spreadsheet&) = delete; /* disable copy constructor, use explicit .clone() */
+        Spreadsheet() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct CssDeclarationVec {
         CssDeclaration* ptr;
         size_t len;
@@ -8507,6 +10507,16 @@ namespace dll {
         CssDeclarationVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CssKeyframesVec {
+        CssKeyframes* ptr;
+        size_t len;
+        size_t cap;
+        CssKeyframesVecDestructor destructor;
+        CssKeyframesVec& operator=(const CssKeyframesVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssKeyframesVec(const CssKeyframesVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssKeyframesVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NodeDataVec {
         NodeData* ptr;
         size_t len;
@@ -8587,6 +10597,7 @@ namespace dll {
     struct CssRuleBlock {
         CssPath path;
         CssDeclarationVec declarations;
+        CssMediaQueryVec media_queries;
         CssRuleBlock& operator=(const CssRuleBlock&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         CssRuleBlock(const CssRuleBlock&) = delete; /* disable copy constructor, use explicit .clone() */
         CssRuleBlock() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -8729,6 +10740,8 @@ namespace dll {
     
     struct Css {
         StylesheetVec stylesheets;
+        CssFontFaceVec font_faces;
+        CssKeyframesVec keyframes;
         Css& operator=(const Css&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         Css(const Css&) = delete; /* disable copy constructor, use explicit .clone() */
         Css() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -8779,6 +10792,7 @@ namespace dll {
         void CallbackInfo_setWindowState(CallbackInfo* restrict callbackinfo, AzWindowState  new_state);
         void CallbackInfo_setFocus(CallbackInfo* restrict callbackinfo, AzFocusTarget  target);
         void CallbackInfo_setCssProperty(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzCssProperty  new_property);
+        void CallbackInfo_setCssVariable(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  name, AzString  value);
         void CallbackInfo_setScrollPosition(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzLogicalPosition  scroll_position);
         void CallbackInfo_setStringContents(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  string);
         void CallbackInfo_addImage(CallbackInfo* restrict callbackinfo, AzString  id, AzImageRef  image);
@@ -8862,6 +10876,8 @@ namespace dll {
         Dom Dom_withFocusCssProperty(Dom* restrict dom, AzCssProperty  prop);
         void Dom_setClipMask(Dom* restrict dom, AzImageMask  clip_mask);
         Dom Dom_withClipMask(Dom* restrict dom, AzImageMask  clip_mask);
+        void Dom_setAttribute(Dom* restrict dom, AzString  name, AzString  value);
+        Dom Dom_withAttribute(Dom* restrict dom, AzString  name, AzString  value);
         void Dom_setTabIndex(Dom* restrict dom, AzTabIndex  tab_index);
         Dom Dom_withTabIndex(Dom* restrict dom, AzTabIndex  tab_index);
         void Dom_setAccessibilityInfo(Dom* restrict dom, AzAccessibilityInfo  accessibility_info);
@@ -8895,12 +10911,15 @@ namespace dll {
         void NodeData_setInlineCssProps(NodeData* restrict nodedata, AzNodeDataInlineCssPropertyVec  css_properties);
         NodeData NodeData_withInlineCssProps(NodeData* restrict nodedata, AzNodeDataInlineCssPropertyVec  css_properties);
         void NodeData_setClipMask(NodeData* restrict nodedata, AzImageMask  image_mask);
+        void NodeData_setAttribute(NodeData* restrict nodedata, AzString  name, AzString  value);
         void NodeData_setTabIndex(NodeData* restrict nodedata, AzTabIndex  tab_index);
         void NodeData_setAccessibilityInfo(NodeData* restrict nodedata, AzAccessibilityInfo  accessibility_info);
         void NodeData_setMenuBar(NodeData* restrict nodedata, AzMenu  menu_bar);
         void NodeData_setContextMenu(NodeData* restrict nodedata, AzMenu  context_menu);
         uint64_t NodeData_hash(const NodeData* nodedata);
         EventFilter On_intoEventFilter(const On on);
+        bool  AccessibilityNode_hasState(const AccessibilityNode* accessibilitynode, AzAccessibilityState  state);
+        OptionAccessibilityNode AccessibilityTree_getFocusedNode(const AccessibilityTree* accessibilitytree);
         MenuItem MenuItem_new(AzString  label, AzOptionMenuCallback  callback);
         StringMenuItem StringMenuItem_new(AzString  label);
         void StringMenuItem_addChild(StringMenuItem* restrict stringmenuitem, AzMenuItem  child);
@@ -8943,6 +10962,92 @@ namespace dll {
         Dom TextInput_dom(TextInput* restrict textinput);
         NumberInput NumberInput_new(float number);
         Dom NumberInput_dom(NumberInput* restrict numberinput);
+        TextEdit TextEdit_new(AzString  initial_text);
+        void TextEdit_setOnTextChange(TextEdit* restrict textedit, AzRefAny  data, AzTextEditOnTextChangeCallbackType  callback);
+        TextEdit TextEdit_withOnTextChange(TextEdit* restrict textedit, AzRefAny  data, AzTextEditOnTextChangeCallbackType  callback);
+        void TextEdit_setOnFocusLost(TextEdit* restrict textedit, AzRefAny  data, AzTextEditOnFocusLostCallbackType  callback);
+        TextEdit TextEdit_withOnFocusLost(TextEdit* restrict textedit, AzRefAny  data, AzTextEditOnFocusLostCallbackType  callback);
+        void TextEdit_setShowLineNumbers(TextEdit* restrict textedit, bool  show_line_numbers);
+        TextEdit TextEdit_withShowLineNumbers(TextEdit* restrict textedit, bool  show_line_numbers);
+        void TextEdit_setContainerStyle(TextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  container_style);
+        TextEdit TextEdit_withContainerStyle(TextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  container_style);
+        void TextEdit_setLineNumbersStyle(TextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  line_numbers_style);
+        TextEdit TextEdit_withLineNumbersStyle(TextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  line_numbers_style);
+        void TextEdit_setLabelStyle(TextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  label_style);
+        TextEdit TextEdit_withLabelStyle(TextEdit* restrict textedit, AzNodeDataInlineCssPropertyVec  label_style);
+        Dom TextEdit_dom(TextEdit* restrict textedit);
+        Slider Slider_new(float value, float min, float max);
+        void Slider_setStep(Slider* restrict slider, float step);
+        Slider Slider_withStep(Slider* restrict slider, float step);
+        void Slider_setOrientation(Slider* restrict slider, AzSliderOrientation  orientation);
+        Slider Slider_withOrientation(Slider* restrict slider, AzSliderOrientation  orientation);
+        void Slider_setTickCount(Slider* restrict slider, size_t tick_count);
+        Slider Slider_withTickCount(Slider* restrict slider, size_t tick_count);
+        void Slider_setShowTickLabels(Slider* restrict slider, bool  show_tick_labels);
+        Slider Slider_withShowTickLabels(Slider* restrict slider, bool  show_tick_labels);
+        void Slider_setOnValueChange(Slider* restrict slider, AzRefAny  data, AzSliderOnValueChangeCallbackType  callback);
+        Slider Slider_withOnValueChange(Slider* restrict slider, AzRefAny  data, AzSliderOnValueChangeCallbackType  callback);
+        void Slider_setContainerStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  container_style);
+        Slider Slider_withContainerStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  container_style);
+        void Slider_setTrackStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  track_style);
+        Slider Slider_withTrackStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  track_style);
+        void Slider_setThumbStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  thumb_style);
+        Slider Slider_withThumbStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  thumb_style);
+        void Slider_setTickStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  tick_style);
+        Slider Slider_withTickStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  tick_style);
+        void Slider_setLabelStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  label_style);
+        Slider Slider_withLabelStyle(Slider* restrict slider, AzNodeDataInlineCssPropertyVec  label_style);
+        Dom Slider_dom(Slider* restrict slider);
+        DropDown DropDown_new(AzStringVec  choices);
+        void DropDown_setSelected(DropDown* restrict dropdown, size_t selected);
+        DropDown DropDown_withSelected(DropDown* restrict dropdown, size_t selected);
+        void DropDown_setDisabledChoices(DropDown* restrict dropdown, AzU32Vec  disabled_choices);
+        DropDown DropDown_withDisabledChoices(DropDown* restrict dropdown, AzU32Vec  disabled_choices);
+        void DropDown_setPopupPosition(DropDown* restrict dropdown, AzMenuPopupPosition  popup_position);
+        DropDown DropDown_withPopupPosition(DropDown* restrict dropdown, AzMenuPopupPosition  popup_position);
+        void DropDown_setOnChoiceChange(DropDown* restrict dropdown, AzRefAny  data, AzDropDownOnChoiceChangeCallbackType  callback);
+        DropDown DropDown_withOnChoiceChange(DropDown* restrict dropdown, AzRefAny  data, AzDropDownOnChoiceChangeCallbackType  callback);
+        void DropDown_setContainerStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  container_style);
+        DropDown DropDown_withContainerStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  container_style);
+        void DropDown_setLabelStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  label_style);
+        DropDown DropDown_withLabelStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  label_style);
+        void DropDown_setPopupStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  popup_style);
+        DropDown DropDown_withPopupStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  popup_style);
+        void DropDown_setEntryStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  entry_style);
+        DropDown DropDown_withEntryStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  entry_style);
+        void DropDown_setDisabledEntryStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  disabled_entry_style);
+        DropDown DropDown_withDisabledEntryStyle(DropDown* restrict dropdown, AzNodeDataInlineCssPropertyVec  disabled_entry_style);
+        Dom DropDown_dom(DropDown* restrict dropdown);
+        Spreadsheet Spreadsheet_new(AzSpreadsheetState  state);
+        void Spreadsheet_setOnCellsChange(Spreadsheet* restrict spreadsheet, AzRefAny  data, AzSpreadsheetOnCellsChangeCallbackType  callback);
+        Spreadsheet Spreadsheet_withOnCellsChange(Spreadsheet* restrict spreadsheet, AzRefAny  data, AzSpreadsheetOnCellsChangeCallbackType  callback);
+        void Spreadsheet_setOnSelectionChange(Spreadsheet* restrict spreadsheet, AzRefAny  data, AzSpreadsheetOnSelectionChangeCallbackType  callback);
+        Spreadsheet Spreadsheet_withOnSelectionChange(Spreadsheet* restrict spreadsheet, AzRefAny  data, AzSpreadsheetOnSelectionChangeCallbackType  callback);
+        void Spreadsheet_setOnColumnResize(Spreadsheet* restrict spreadsheet, AzRefAny  data, AzSpreadsheetOnColumnResizeCallbackType  callback);
+        Spreadsheet Spreadsheet_withOnColumnResize(Spreadsheet* restrict spreadsheet, AzRefAny  data, AzSpreadsheetOnColumnResizeCallbackType  callback);
+        void Spreadsheet_setContainerStyle(Spreadsheet* restrict spreadsheet, AzNodeDataInlineCssPropertyVec  style);
+        Spreadsheet Spreadsheet_withContainerStyle(Spreadsheet* restrict spreadsheet, AzNodeDataInlineCssPropertyVec  style);
+        Dom Spreadsheet_dom(Spreadsheet* restrict spreadsheet);
+        SpreadsheetState SpreadsheetState_new();
+        void SpreadsheetState_setCellContent(SpreadsheetState* restrict spreadsheetstate, AzSpreadsheetCellIndex  cell, AzString  value);
+        OptionString SpreadsheetState_getCellContent(const SpreadsheetState* spreadsheetstate, AzSpreadsheetCellIndex  cell);
+        void SpreadsheetState_setSelection(SpreadsheetState* restrict spreadsheetstate, AzOptionSpreadsheetCellSelection  selection);
+        void SpreadsheetState_selectAll(SpreadsheetState* restrict spreadsheetstate);
+        float SpreadsheetState_getColumnWidth(const SpreadsheetState* spreadsheetstate, size_t column);
+        float SpreadsheetState_getRowHeight(const SpreadsheetState* spreadsheetstate, size_t row);
+        void SpreadsheetState_setColumnWidth(SpreadsheetState* restrict spreadsheetstate, size_t column, float width);
+        void SpreadsheetState_setRowHeight(SpreadsheetState* restrict spreadsheetstate, size_t row, float height);
+        LogicalSize SpreadsheetState_getTableSize(const SpreadsheetState* spreadsheetstate);
+        LogicalRect SpreadsheetState_getCellRect(const SpreadsheetState* spreadsheetstate, AzSpreadsheetCellIndex  cell);
+        String SpreadsheetState_getSelectionTsv(const SpreadsheetState* spreadsheetstate, AzSpreadsheetCellSelection  selection);
+        OptionSpreadsheetCellSelection SpreadsheetState_pasteTsv(SpreadsheetState* restrict spreadsheetstate, AzString  tsv);
+        SpreadsheetCellSelection SpreadsheetCellSelection_from(size_t row, size_t column);
+        SpreadsheetCellSelection SpreadsheetCellSelection_to(const SpreadsheetCellSelection spreadsheetcellselection, size_t row, size_t column);
+        size_t SpreadsheetCellSelection_numberOfRowsSelected(const SpreadsheetCellSelection* spreadsheetcellselection);
+        size_t SpreadsheetCellSelection_numberOfColumnsSelected(const SpreadsheetCellSelection* spreadsheetcellselection);
+        SpreadsheetCellIndex SpreadsheetCellSelection_getTopLeft(const SpreadsheetCellSelection* spreadsheetcellselection);
+        SpreadsheetCellIndex SpreadsheetCellSelection_getBottomRight(const SpreadsheetCellSelection* spreadsheetcellselection);
+        bool  SpreadsheetCellSelection_contains(const SpreadsheetCellSelection* spreadsheetcellselection, AzSpreadsheetCellIndex  cell);
         void CssPropertyCache_delete(CssPropertyCache* restrict instance);
         CssPropertyCache CssPropertyCache_deepCopy(CssPropertyCache* const instance);
         StyledDom StyledDom_new(AzDom  dom, AzCss  css);
@@ -9285,6 +11390,7 @@ namespace dll {
         String String_trim(const String* string);
         Refstr String_asRefstr(const String* string);
         void AccessibilityStateVec_delete(AccessibilityStateVec* restrict instance);
+        void AccessibilityNodeVec_delete(AccessibilityNodeVec* restrict instance);
         void MenuItemVec_delete(MenuItemVec* restrict instance);
         TessellatedSvgNodeVecRef TessellatedSvgNodeVec_asRefVec(const TessellatedSvgNodeVec* tessellatedsvgnodevec);
         void TessellatedSvgNodeVec_delete(TessellatedSvgNodeVec* restrict instance);
@@ -9314,11 +11420,28 @@ namespace dll {
         void U32Vec_delete(U32Vec* restrict instance);
         void XWindowTypeVec_delete(XWindowTypeVec* restrict instance);
         void VirtualKeyCodeVec_delete(VirtualKeyCodeVec* restrict instance);
+        void KeyboardShortcutVec_delete(KeyboardShortcutVec* restrict instance);
         void CascadeInfoVec_delete(CascadeInfoVec* restrict instance);
         void ScanCodeVec_delete(ScanCodeVec* restrict instance);
         void CssDeclarationVec_delete(CssDeclarationVec* restrict instance);
         void CssPathSelectorVec_delete(CssPathSelectorVec* restrict instance);
         void StylesheetVec_delete(StylesheetVec* restrict instance);
+        void CssMediaQueryVec_delete(CssMediaQueryVec* restrict instance);
+        void CssMediaConditionVec_delete(CssMediaConditionVec* restrict instance);
+        void CssFontFaceVec_delete(CssFontFaceVec* restrict instance);
+        void StyleTransitionPropertyVec_delete(StyleTransitionPropertyVec* restrict instance);
+        void StyleTransitionDurationVec_delete(StyleTransitionDurationVec* restrict instance);
+        void StyleTransitionTimingFunctionVec_delete(StyleTransitionTimingFunctionVec* restrict instance);
+        void StyleTransitionDelayVec_delete(StyleTransitionDelayVec* restrict instance);
+        void StyleAnimationNameVec_delete(StyleAnimationNameVec* restrict instance);
+        void StyleAnimationDurationVec_delete(StyleAnimationDurationVec* restrict instance);
+        void StyleAnimationIterationCountVec_delete(StyleAnimationIterationCountVec* restrict instance);
+        void StyleAnimationDirectionVec_delete(StyleAnimationDirectionVec* restrict instance);
+        void StyleAnimationFillModeVec_delete(StyleAnimationFillModeVec* restrict instance);
+        void StyleAnimationDelayVec_delete(StyleAnimationDelayVec* restrict instance);
+        void CssKeyframeVec_delete(CssKeyframeVec* restrict instance);
+        void CssKeyframesVec_delete(CssKeyframesVec* restrict instance);
+        void GridTrackSizingVec_delete(GridTrackSizingVec* restrict instance);
         void CssRuleBlockVec_delete(CssRuleBlockVec* restrict instance);
         void U16Vec_delete(U16Vec* restrict instance);
         void F32Vec_delete(F32Vec* restrict instance);
@@ -9339,6 +11462,9 @@ namespace dll {
         void TagIdToNodeIdMappingVec_delete(TagIdToNodeIdMappingVec* restrict instance);
         void ParentWithNodeDepthVec_delete(ParentWithNodeDepthVec* restrict instance);
         void NodeDataVec_delete(NodeDataVec* restrict instance);
+        void TextEditSnapshotVec_delete(TextEditSnapshotVec* restrict instance);
+        void SpreadsheetSizeOverrideVec_delete(SpreadsheetSizeOverrideVec* restrict instance);
+        void SpreadsheetCellContentVec_delete(SpreadsheetCellContentVec* restrict instance);
 
     } /* extern "C" */
 
@@ -588,6 +588,13 @@ mod dll {
     impl ::core::fmt::Debug for AzTextInputOnVirtualKeyDownCallback { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
     impl ::core::fmt::Debug for AzTextInputOnFocusLostCallback      { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
     impl ::core::fmt::Debug for AzNumberInputOnValueChangeCallback  { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzSpreadsheetOnCellsChangeCallback  { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzSpreadsheetOnSelectionChangeCallback{ fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzSpreadsheetOnColumnResizeCallback { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzDropDownOnChoiceChangeCallback    { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzSliderOnValueChangeCallback       { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzTextEditOnTextChangeCallback      { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzTextEditOnFocusLostCallback       { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
 
 
     impl PartialEq for AzCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
@@ -616,6 +623,13 @@ mod dll {
     impl PartialEq for AzTextInputOnVirtualKeyDownCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
     impl PartialEq for AzTextInputOnFocusLostCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
     impl PartialEq for AzNumberInputOnValueChangeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    impl PartialEq for AzSpreadsheetOnCellsChangeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    impl PartialEq for AzSpreadsheetOnSelectionChangeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    impl PartialEq for AzSpreadsheetOnColumnResizeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    impl PartialEq for AzDropDownOnChoiceChangeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    impl PartialEq for AzSliderOnValueChangeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    impl PartialEq for AzTextEditOnTextChangeCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
+    impl PartialEq for AzTextEditOnFocusLostCallback { fn eq(&self, rhs: &Self) -> bool { (self.cb as usize).eq(&(rhs.cb as usize)) } }
 
     impl PartialOrd for AzCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
     impl PartialOrd for AzLayoutCallbackInner { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) } }
@@ -642,7 +656,14 @@ mod dll {
     impl PartialOrd for AzTextInputOnTextInputCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) }}
     impl PartialOrd for AzTextInputOnVirtualKeyDownCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) }}
     impl PartialOrd for AzTextInputOnFocusLostCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) }}
-    impl PartialOrd for AzNumberInputOnValueChangeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) }}    #[cfg(not(feature = "link_static"))]
+    impl PartialOrd for AzNumberInputOnValueChangeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) }}
+    impl PartialOrd for AzSpreadsheetOnCellsChangeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) }}
+    impl PartialOrd for AzSpreadsheetOnSelectionChangeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) }}
+    impl PartialOrd for AzSpreadsheetOnColumnResizeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) }}
+    impl PartialOrd for AzDropDownOnChoiceChangeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) }}
+    impl PartialOrd for AzSliderOnValueChangeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) }}
+    impl PartialOrd for AzTextEditOnTextChangeCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) }}
+    impl PartialOrd for AzTextEditOnFocusLostCallback { fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> { (self.cb as usize).partial_cmp(&(rhs.cb as usize)) }}    #[cfg(not(feature = "link_static"))]
     mod dynamic_link {
     use core::ffi::c_void;
 
@@ -1694,6 +1715,28 @@ mod dll {
         Disabled,
     }
 
+    /// Re-export of rust-allocated (stack based) `CssMediaOrientation` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzCssMediaOrientation {
+        Portrait,
+        Landscape,
+    }
+
+    /// Re-export of rust-allocated (stack based) `CssMediaColorScheme` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzCssMediaColorScheme {
+        Light,
+        Dark,
+    }
+
     /// Re-export of rust-allocated (stack based) `NodeTypeKey` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -1709,6 +1752,22 @@ mod dll {
         IFrame,
     }
 
+    /// Re-export of rust-allocated (stack based) `CssAttributeSelectorOp` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzCssAttributeSelectorOp {
+        Exists,
+        Equals,
+        Includes,
+        DashMatch,
+        Prefix,
+        Suffix,
+        Substring,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssNthChildPattern` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -1720,6 +1779,18 @@ mod dll {
         pub offset: u32,
     }
 
+    /// Re-export of rust-allocated (stack based) `CssFontFaceStyle` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzCssFontFaceStyle {
+        Normal,
+        Italic,
+        Oblique,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -1738,6 +1809,7 @@ mod dll {
         Cursor,
         Display,
         Float,
+        Clear,
         BoxSizing,
         Width,
         Height,
@@ -1757,6 +1829,12 @@ mod dll {
         JustifyContent,
         AlignItems,
         AlignContent,
+        GridTemplateColumns,
+        GridTemplateRows,
+        GridColumn,
+        GridRow,
+        ColumnGap,
+        RowGap,
         BackgroundContent,
         BackgroundPosition,
         BackgroundSize,
@@ -1797,6 +1875,17 @@ mod dll {
         TransformOrigin,
         PerspectiveOrigin,
         BackfaceVisibility,
+        TransitionProperty,
+        TransitionDuration,
+        TransitionTimingFunction,
+        TransitionDelay,
+        AnimationName,
+        AnimationDuration,
+        AnimationTimingFunction,
+        AnimationIterationCount,
+        AnimationDirection,
+        AnimationFillMode,
+        AnimationDelay,
     }
 
     /// Re-export of rust-allocated (stack based) `ColorU` struct
@@ -1861,6 +1950,18 @@ mod dll {
         SpaceAround,
     }
 
+    /// Start or end line of a grid item (`auto`, `2` or `span 2`)
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzGridLine {
+        Auto,
+        Line(i32),
+        Span(u32),
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutAlignItems` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -1909,6 +2010,7 @@ mod dll {
         Flex,
         Block,
         InlineBlock,
+        Grid,
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutFloat` struct
@@ -1920,6 +2022,20 @@ mod dll {
     pub enum AzLayoutFloat {
         Left,
         Right,
+        None,
+    }
+
+    /// Represents a `clear` attribute: on which sides the node may not be placed next to floating nodes that come before it
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzLayoutClear {
+        None,
+        Left,
+        Right,
+        Both,
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutJustifyContent` struct
@@ -2121,6 +2237,83 @@ mod dll {
         Visible,
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleTransitionDuration` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzStyleTransitionDuration {
+        pub ms: u32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransitionDelay` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzStyleTransitionDelay {
+        pub ms: u32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDuration` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzStyleAnimationDuration {
+        pub ms: u32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDelay` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzStyleAnimationDelay {
+        pub ms: u32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationIterationCount` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleAnimationIterationCount {
+        Count(u32),
+        Infinite,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDirection` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleAnimationDirection {
+        Normal,
+        Reverse,
+        Alternate,
+        AlternateReverse,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationFillMode` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleAnimationFillMode {
+        None,
+        Forwards,
+        Backwards,
+        Both,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTextAlign` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -2234,6 +2427,132 @@ mod dll {
     /// `AzNumberInputOnValueChangeCallbackType` struct
     pub type AzNumberInputOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzNumberInputState, &mut AzCallbackInfo) -> AzUpdate;
 
+    /// Re-export of rust-allocated (stack based) `TextEditKind` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzTextEditKind {
+        Typing,
+        Deleting,
+        Other,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditOnTextChangeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTextEditOnTextChangeCallback {
+        pub cb: AzTextEditOnTextChangeCallbackType,
+    }
+
+    /// `AzTextEditOnTextChangeCallbackType` struct
+    pub type AzTextEditOnTextChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTextEditState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TextEditOnFocusLostCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTextEditOnFocusLostCallback {
+        pub cb: AzTextEditOnFocusLostCallbackType,
+    }
+
+    /// `AzTextEditOnFocusLostCallbackType` struct
+    pub type AzTextEditOnFocusLostCallbackType = extern "C" fn(&mut AzRefAny, &AzTextEditState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `SliderOrientation` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzSliderOrientation {
+        Horizontal,
+        Vertical,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderOnValueChangeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzSliderOnValueChangeCallback {
+        pub cb: AzSliderOnValueChangeCallbackType,
+    }
+
+    /// `AzSliderOnValueChangeCallbackType` struct
+    pub type AzSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSliderState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChangeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzDropDownOnChoiceChangeCallback {
+        pub cb: AzDropDownOnChoiceChangeCallbackType,
+    }
+
+    /// `AzDropDownOnChoiceChangeCallbackType` struct
+    pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzDropDownState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Represents the index of a single cell (row + column)
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzSpreadsheetCellIndex {
+        pub row: usize,
+        pub column: usize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetSizeOverride` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzSpreadsheetSizeOverride {
+        pub index: usize,
+        pub size: f32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetColumnResize` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzSpreadsheetColumnResize {
+        pub column: usize,
+        pub width: f32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetOnCellsChangeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzSpreadsheetOnCellsChangeCallback {
+        pub cb: AzSpreadsheetOnCellsChangeCallbackType,
+    }
+
+    /// `AzSpreadsheetOnCellsChangeCallbackType` struct
+    pub type AzSpreadsheetOnCellsChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSpreadsheetState, AzSpreadsheetCellSelection, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetOnSelectionChangeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzSpreadsheetOnSelectionChangeCallback {
+        pub cb: AzSpreadsheetOnSelectionChangeCallbackType,
+    }
+
+    /// `AzSpreadsheetOnSelectionChangeCallbackType` struct
+    pub type AzSpreadsheetOnSelectionChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSpreadsheetState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetOnColumnResizeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzSpreadsheetOnColumnResizeCallback {
+        pub cb: AzSpreadsheetOnColumnResizeCallbackType,
+    }
+
+    /// `AzSpreadsheetOnColumnResizeCallbackType` struct
+    pub type AzSpreadsheetOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &AzSpreadsheetState, usize, &mut AzCallbackInfo) -> AzUpdate;
+
     /// Re-export of rust-allocated (stack based) `Node` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -3054,6 +3373,19 @@ mod dll {
     /// `AzAccessibilityStateVecDestructorType` struct
     pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
 
+    /// Re-export of rust-allocated (stack based) `AccessibilityNodeVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzAccessibilityNodeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzAccessibilityNodeVecDestructorType),
+    }
+
+    /// `AzAccessibilityNodeVecDestructorType` struct
+    pub type AzAccessibilityNodeVecDestructorType = extern "C" fn(&mut AzAccessibilityNodeVec);
+
     /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
@@ -3405,6 +3737,19 @@ mod dll {
     /// `AzVirtualKeyCodeVecDestructorType` struct
     pub type AzVirtualKeyCodeVecDestructorType = extern "C" fn(&mut AzVirtualKeyCodeVec);
 
+    /// Re-export of rust-allocated (stack based) `KeyboardShortcutVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzKeyboardShortcutVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzKeyboardShortcutVecDestructorType),
+    }
+
+    /// `AzKeyboardShortcutVecDestructorType` struct
+    pub type AzKeyboardShortcutVecDestructorType = extern "C" fn(&mut AzKeyboardShortcutVec);
+
     /// Re-export of rust-allocated (stack based) `CascadeInfoVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
@@ -3470,183 +3815,391 @@ mod dll {
     /// `AzStylesheetVecDestructorType` struct
     pub type AzStylesheetVecDestructorType = extern "C" fn(&mut AzStylesheetVec);
 
-    /// Re-export of rust-allocated (stack based) `CssRuleBlockVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `CssFontFaceVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzCssRuleBlockVecDestructor {
+    pub enum AzCssFontFaceVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzCssRuleBlockVecDestructorType),
+        External(AzCssFontFaceVecDestructorType),
     }
 
-    /// `AzCssRuleBlockVecDestructorType` struct
-    pub type AzCssRuleBlockVecDestructorType = extern "C" fn(&mut AzCssRuleBlockVec);
+    /// `AzCssFontFaceVecDestructorType` struct
+    pub type AzCssFontFaceVecDestructorType = extern "C" fn(&mut AzCssFontFaceVec);
 
-    /// Re-export of rust-allocated (stack based) `F32VecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzF32VecDestructor {
+    pub enum AzStyleTransitionPropertyVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzF32VecDestructorType),
+        External(AzStyleTransitionPropertyVecDestructorType),
     }
 
-    /// `AzF32VecDestructorType` struct
-    pub type AzF32VecDestructorType = extern "C" fn(&mut AzF32Vec);
+    /// `AzStyleTransitionPropertyVecDestructorType` struct
+    pub type AzStyleTransitionPropertyVecDestructorType = extern "C" fn(&mut AzStyleTransitionPropertyVec);
 
-    /// Re-export of rust-allocated (stack based) `U16VecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleTransitionDurationVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzU16VecDestructor {
+    pub enum AzStyleTransitionDurationVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzU16VecDestructorType),
+        External(AzStyleTransitionDurationVecDestructorType),
     }
 
-    /// `AzU16VecDestructorType` struct
-    pub type AzU16VecDestructorType = extern "C" fn(&mut AzU16Vec);
+    /// `AzStyleTransitionDurationVecDestructorType` struct
+    pub type AzStyleTransitionDurationVecDestructorType = extern "C" fn(&mut AzStyleTransitionDurationVec);
 
-    /// Re-export of rust-allocated (stack based) `U8VecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunctionVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzU8VecDestructor {
+    pub enum AzStyleTransitionTimingFunctionVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzU8VecDestructorType),
+        External(AzStyleTransitionTimingFunctionVecDestructorType),
     }
 
-    /// `AzU8VecDestructorType` struct
-    pub type AzU8VecDestructorType = extern "C" fn(&mut AzU8Vec);
+    /// `AzStyleTransitionTimingFunctionVecDestructorType` struct
+    pub type AzStyleTransitionTimingFunctionVecDestructorType = extern "C" fn(&mut AzStyleTransitionTimingFunctionVec);
 
-    /// Re-export of rust-allocated (stack based) `CallbackDataVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleTransitionDelayVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzCallbackDataVecDestructor {
+    pub enum AzStyleTransitionDelayVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzCallbackDataVecDestructorType),
+        External(AzStyleTransitionDelayVecDestructorType),
     }
 
-    /// `AzCallbackDataVecDestructorType` struct
-    pub type AzCallbackDataVecDestructorType = extern "C" fn(&mut AzCallbackDataVec);
+    /// `AzStyleTransitionDelayVecDestructorType` struct
+    pub type AzStyleTransitionDelayVecDestructorType = extern "C" fn(&mut AzStyleTransitionDelayVec);
 
-    /// Re-export of rust-allocated (stack based) `DebugMessageVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleAnimationNameVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzDebugMessageVecDestructor {
+    pub enum AzStyleAnimationNameVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzDebugMessageVecDestructorType),
+        External(AzStyleAnimationNameVecDestructorType),
     }
 
-    /// `AzDebugMessageVecDestructorType` struct
-    pub type AzDebugMessageVecDestructorType = extern "C" fn(&mut AzDebugMessageVec);
+    /// `AzStyleAnimationNameVecDestructorType` struct
+    pub type AzStyleAnimationNameVecDestructorType = extern "C" fn(&mut AzStyleAnimationNameVec);
 
-    /// Re-export of rust-allocated (stack based) `GLuintVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDurationVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzGLuintVecDestructor {
+    pub enum AzStyleAnimationDurationVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzGLuintVecDestructorType),
+        External(AzStyleAnimationDurationVecDestructorType),
     }
 
-    /// `AzGLuintVecDestructorType` struct
-    pub type AzGLuintVecDestructorType = extern "C" fn(&mut AzGLuintVec);
+    /// `AzStyleAnimationDurationVecDestructorType` struct
+    pub type AzStyleAnimationDurationVecDestructorType = extern "C" fn(&mut AzStyleAnimationDurationVec);
 
-    /// Re-export of rust-allocated (stack based) `GLintVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzGLintVecDestructor {
+    pub enum AzStyleAnimationIterationCountVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzGLintVecDestructorType),
+        External(AzStyleAnimationIterationCountVecDestructorType),
     }
 
-    /// `AzGLintVecDestructorType` struct
-    pub type AzGLintVecDestructorType = extern "C" fn(&mut AzGLintVec);
+    /// `AzStyleAnimationIterationCountVecDestructorType` struct
+    pub type AzStyleAnimationIterationCountVecDestructorType = extern "C" fn(&mut AzStyleAnimationIterationCountVec);
 
-    /// Re-export of rust-allocated (stack based) `StringVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzStringVecDestructor {
+    pub enum AzStyleAnimationDirectionVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzStringVecDestructorType),
+        External(AzStyleAnimationDirectionVecDestructorType),
     }
 
-    /// `AzStringVecDestructorType` struct
-    pub type AzStringVecDestructorType = extern "C" fn(&mut AzStringVec);
+    /// `AzStyleAnimationDirectionVecDestructorType` struct
+    pub type AzStyleAnimationDirectionVecDestructorType = extern "C" fn(&mut AzStyleAnimationDirectionVec);
 
-    /// Re-export of rust-allocated (stack based) `StringPairVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzStringPairVecDestructor {
+    pub enum AzStyleAnimationFillModeVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzStringPairVecDestructorType),
+        External(AzStyleAnimationFillModeVecDestructorType),
     }
 
-    /// `AzStringPairVecDestructorType` struct
-    pub type AzStringPairVecDestructorType = extern "C" fn(&mut AzStringPairVec);
+    /// `AzStyleAnimationFillModeVecDestructorType` struct
+    pub type AzStyleAnimationFillModeVecDestructorType = extern "C" fn(&mut AzStyleAnimationFillModeVec);
 
-    /// Re-export of rust-allocated (stack based) `NormalizedLinearColorStopVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDelayVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzNormalizedLinearColorStopVecDestructor {
+    pub enum AzStyleAnimationDelayVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzNormalizedLinearColorStopVecDestructorType),
+        External(AzStyleAnimationDelayVecDestructorType),
     }
 
-    /// `AzNormalizedLinearColorStopVecDestructorType` struct
-    pub type AzNormalizedLinearColorStopVecDestructorType = extern "C" fn(&mut AzNormalizedLinearColorStopVec);
+    /// `AzStyleAnimationDelayVecDestructorType` struct
+    pub type AzStyleAnimationDelayVecDestructorType = extern "C" fn(&mut AzStyleAnimationDelayVec);
 
-    /// Re-export of rust-allocated (stack based) `NormalizedRadialColorStopVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `CssKeyframeVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzNormalizedRadialColorStopVecDestructor {
+    pub enum AzCssKeyframeVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzNormalizedRadialColorStopVecDestructorType),
+        External(AzCssKeyframeVecDestructorType),
     }
 
-    /// `AzNormalizedRadialColorStopVecDestructorType` struct
-    pub type AzNormalizedRadialColorStopVecDestructorType = extern "C" fn(&mut AzNormalizedRadialColorStopVec);
+    /// `AzCssKeyframeVecDestructorType` struct
+    pub type AzCssKeyframeVecDestructorType = extern "C" fn(&mut AzCssKeyframeVec);
 
-    /// Re-export of rust-allocated (stack based) `NodeIdVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `CssKeyframesVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzNodeIdVecDestructor {
+    pub enum AzCssKeyframesVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzNodeIdVecDestructorType),
+        External(AzCssKeyframesVecDestructorType),
     }
 
-    /// `AzNodeIdVecDestructorType` struct
-    pub type AzNodeIdVecDestructorType = extern "C" fn(&mut AzNodeIdVec);
+    /// `AzCssKeyframesVecDestructorType` struct
+    pub type AzCssKeyframesVecDestructorType = extern "C" fn(&mut AzCssKeyframesVec);
 
-    /// Re-export of rust-allocated (stack based) `NodeVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `GridTrackSizingVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzNodeVecDestructor {
+    pub enum AzGridTrackSizingVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzNodeVecDestructorType),
+        External(AzGridTrackSizingVecDestructorType),
+    }
+
+    /// `AzGridTrackSizingVecDestructorType` struct
+    pub type AzGridTrackSizingVecDestructorType = extern "C" fn(&mut AzGridTrackSizingVec);
+
+    /// Re-export of rust-allocated (stack based) `CssMediaQueryVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzCssMediaQueryVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssMediaQueryVecDestructorType),
+    }
+
+    /// `AzCssMediaQueryVecDestructorType` struct
+    pub type AzCssMediaQueryVecDestructorType = extern "C" fn(&mut AzCssMediaQueryVec);
+
+    /// Re-export of rust-allocated (stack based) `CssMediaConditionVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzCssMediaConditionVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssMediaConditionVecDestructorType),
+    }
+
+    /// `AzCssMediaConditionVecDestructorType` struct
+    pub type AzCssMediaConditionVecDestructorType = extern "C" fn(&mut AzCssMediaConditionVec);
+
+    /// Re-export of rust-allocated (stack based) `CssRuleBlockVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzCssRuleBlockVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssRuleBlockVecDestructorType),
+    }
+
+    /// `AzCssRuleBlockVecDestructorType` struct
+    pub type AzCssRuleBlockVecDestructorType = extern "C" fn(&mut AzCssRuleBlockVec);
+
+    /// Re-export of rust-allocated (stack based) `F32VecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzF32VecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzF32VecDestructorType),
+    }
+
+    /// `AzF32VecDestructorType` struct
+    pub type AzF32VecDestructorType = extern "C" fn(&mut AzF32Vec);
+
+    /// Re-export of rust-allocated (stack based) `U16VecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzU16VecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzU16VecDestructorType),
+    }
+
+    /// `AzU16VecDestructorType` struct
+    pub type AzU16VecDestructorType = extern "C" fn(&mut AzU16Vec);
+
+    /// Re-export of rust-allocated (stack based) `U8VecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzU8VecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzU8VecDestructorType),
+    }
+
+    /// `AzU8VecDestructorType` struct
+    pub type AzU8VecDestructorType = extern "C" fn(&mut AzU8Vec);
+
+    /// Re-export of rust-allocated (stack based) `CallbackDataVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzCallbackDataVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCallbackDataVecDestructorType),
+    }
+
+    /// `AzCallbackDataVecDestructorType` struct
+    pub type AzCallbackDataVecDestructorType = extern "C" fn(&mut AzCallbackDataVec);
+
+    /// Re-export of rust-allocated (stack based) `DebugMessageVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzDebugMessageVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzDebugMessageVecDestructorType),
+    }
+
+    /// `AzDebugMessageVecDestructorType` struct
+    pub type AzDebugMessageVecDestructorType = extern "C" fn(&mut AzDebugMessageVec);
+
+    /// Re-export of rust-allocated (stack based) `GLuintVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzGLuintVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzGLuintVecDestructorType),
+    }
+
+    /// `AzGLuintVecDestructorType` struct
+    pub type AzGLuintVecDestructorType = extern "C" fn(&mut AzGLuintVec);
+
+    /// Re-export of rust-allocated (stack based) `GLintVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzGLintVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzGLintVecDestructorType),
+    }
+
+    /// `AzGLintVecDestructorType` struct
+    pub type AzGLintVecDestructorType = extern "C" fn(&mut AzGLintVec);
+
+    /// Re-export of rust-allocated (stack based) `StringVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzStringVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStringVecDestructorType),
+    }
+
+    /// `AzStringVecDestructorType` struct
+    pub type AzStringVecDestructorType = extern "C" fn(&mut AzStringVec);
+
+    /// Re-export of rust-allocated (stack based) `StringPairVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzStringPairVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStringPairVecDestructorType),
+    }
+
+    /// `AzStringPairVecDestructorType` struct
+    pub type AzStringPairVecDestructorType = extern "C" fn(&mut AzStringPairVec);
+
+    /// Re-export of rust-allocated (stack based) `NormalizedLinearColorStopVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzNormalizedLinearColorStopVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzNormalizedLinearColorStopVecDestructorType),
+    }
+
+    /// `AzNormalizedLinearColorStopVecDestructorType` struct
+    pub type AzNormalizedLinearColorStopVecDestructorType = extern "C" fn(&mut AzNormalizedLinearColorStopVec);
+
+    /// Re-export of rust-allocated (stack based) `NormalizedRadialColorStopVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzNormalizedRadialColorStopVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzNormalizedRadialColorStopVecDestructorType),
+    }
+
+    /// `AzNormalizedRadialColorStopVecDestructorType` struct
+    pub type AzNormalizedRadialColorStopVecDestructorType = extern "C" fn(&mut AzNormalizedRadialColorStopVec);
+
+    /// Re-export of rust-allocated (stack based) `NodeIdVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzNodeIdVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzNodeIdVecDestructorType),
+    }
+
+    /// `AzNodeIdVecDestructorType` struct
+    pub type AzNodeIdVecDestructorType = extern "C" fn(&mut AzNodeIdVec);
+
+    /// Re-export of rust-allocated (stack based) `NodeVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzNodeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzNodeVecDestructorType),
     }
 
     /// `AzNodeVecDestructorType` struct
@@ -3704,6 +4257,45 @@ mod dll {
     /// `AzNodeDataVecDestructorType` struct
     pub type AzNodeDataVecDestructorType = extern "C" fn(&mut AzNodeDataVec);
 
+    /// Re-export of rust-allocated (stack based) `TextEditSnapshotVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzTextEditSnapshotVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTextEditSnapshotVecDestructorType),
+    }
+
+    /// `AzTextEditSnapshotVecDestructorType` struct
+    pub type AzTextEditSnapshotVecDestructorType = extern "C" fn(&mut AzTextEditSnapshotVec);
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetSizeOverrideVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzSpreadsheetSizeOverrideVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzSpreadsheetSizeOverrideVecDestructorType),
+    }
+
+    /// `AzSpreadsheetSizeOverrideVecDestructorType` struct
+    pub type AzSpreadsheetSizeOverrideVecDestructorType = extern "C" fn(&mut AzSpreadsheetSizeOverrideVec);
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetCellContentVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzSpreadsheetCellContentVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzSpreadsheetCellContentVecDestructorType),
+    }
+
+    /// `AzSpreadsheetCellContentVecDestructorType` struct
+    pub type AzSpreadsheetCellContentVecDestructorType = extern "C" fn(&mut AzSpreadsheetCellContentVec);
+
     /// Re-export of rust-allocated (stack based) `OptionI16` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -3994,6 +4586,7 @@ mod dll {
         pub bounds: AzLogicalRect,
         pub unicode_codepoint: AzOptionChar,
         pub glyph_index: u32,
+        pub fallback_font_index: usize,
     }
 
     /// Re-export of rust-allocated (stack based) `InlineTextHit` struct
@@ -4018,6 +4611,8 @@ mod dll {
         pub char_index_relative_to_line: usize,
         pub glyph_index_relative_to_word: usize,
         pub char_index_relative_to_word: usize,
+        pub is_rtl: bool,
+        pub cursor_char_index_relative_to_text: usize,
     }
 
     /// Re-export of rust-allocated (stack based) `IFrameCallbackInfo` struct
@@ -4146,6 +4741,84 @@ mod dll {
         pub clip_mode: AzBoxShadowClipMode,
     }
 
+    /// Re-export of rust-allocated (stack based) `GridTrackBreadth` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzGridTrackBreadth {
+        Fixed(AzPixelValue),
+        Fraction(AzFloatValue),
+        Auto,
+    }
+
+    /// `minmax(min, max)` track size
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzGridMinMax {
+        pub min: AzGridTrackBreadth,
+        pub max: AzGridTrackBreadth,
+    }
+
+    /// Size of a single column / row track of a `grid-template-*` declaration
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzGridTrackSizing {
+        Fixed(AzPixelValue),
+        Fraction(AzFloatValue),
+        Auto,
+        MinMax(AzGridMinMax),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridColumn` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzLayoutGridColumn {
+        pub start: AzGridLine,
+        pub end: AzGridLine,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridRow` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzLayoutGridRow {
+        pub start: AzGridLine,
+        pub end: AzGridLine,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutColumnGap` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzLayoutColumnGap {
+        pub inner: AzPixelValue,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutRowGap` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzLayoutRowGap {
+        pub inner: AzPixelValue,
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutBottom` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -4692,6 +5365,30 @@ mod dll {
         pub y: AzPixelValue,
     }
 
+    /// Property that is animated by a `transition` (`all` or a single property)
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleTransitionProperty {
+        All,
+        Property(AzCssPropertyType),
+    }
+
+    /// Control points of a `cubic-bezier(x1, y1, x2, y2)` timing function
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzStyleCubicBezier {
+        pub x1: AzFloatValue,
+        pub y1: AzFloatValue,
+        pub x2: AzFloatValue,
+        pub y2: AzFloatValue,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTransformMatrix2D` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -4850,6 +5547,62 @@ mod dll {
         Exact(AzLayoutAlignContent),
     }
 
+    /// Re-export of rust-allocated (stack based) `LayoutGridColumnValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzLayoutGridColumnValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutGridColumn),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridRowValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzLayoutGridRowValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutGridRow),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutColumnGapValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzLayoutColumnGapValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutColumnGap),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutRowGapValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzLayoutRowGapValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutRowGap),
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutAlignItemsValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -4962,6 +5715,20 @@ mod dll {
         Exact(AzLayoutFloat),
     }
 
+    /// Re-export of rust-allocated (stack based) `LayoutClearValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzLayoutClearValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutClear),
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutHeightValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -5747,6 +6514,150 @@ mod dll {
         pub callback: AzNumberInputOnValueChangeCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `TextEditView` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzTextEditView {
+        pub preferred_caret_x: AzOptionF32,
+        pub scroll_y: f32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditOnTextChange` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextEditOnTextChange {
+        pub data: AzRefAny,
+        pub callback: AzTextEditOnTextChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditOnFocusLost` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextEditOnFocusLost {
+        pub data: AzRefAny,
+        pub callback: AzTextEditOnFocusLostCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSliderState {
+        pub value: f32,
+        pub min: f32,
+        pub max: f32,
+        pub step: f32,
+        pub orientation: AzSliderOrientation,
+        pub tick_count: usize,
+        pub show_tick_labels: bool,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderOnValueChange` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSliderOnValueChange {
+        pub data: AzRefAny,
+        pub callback: AzSliderOnValueChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChange` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzDropDownOnChoiceChange {
+        pub data: AzRefAny,
+        pub callback: AzDropDownOnChoiceChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetStyle` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzSpreadsheetStyle {
+        pub accent_color: AzColorU,
+    }
+
+    /// Represents a rectangular selection of table cells
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzSpreadsheetCellSelection {
+        pub from_top_left: AzSpreadsheetCellIndex,
+        pub to_bottom_right: AzSpreadsheetCellIndex,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetRenderWindow` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSpreadsheetRenderWindow {
+        pub row_start: usize,
+        pub row_end: usize,
+        pub column_start: usize,
+        pub column_end: usize,
+        pub origin: AzLogicalPosition,
+        pub scroll_position: AzLogicalPosition,
+        pub viewport: AzLogicalSize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetDrag` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzSpreadsheetDrag {
+        None,
+        SelectCells,
+        ResizeColumn(AzSpreadsheetColumnResize),
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetOnCellsChange` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSpreadsheetOnCellsChange {
+        pub data: AzRefAny,
+        pub callback: AzSpreadsheetOnCellsChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetOnSelectionChange` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSpreadsheetOnSelectionChange {
+        pub data: AzRefAny,
+        pub callback: AzSpreadsheetOnSelectionChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetOnColumnResize` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSpreadsheetOnColumnResize {
+        pub data: AzRefAny,
+        pub callback: AzSpreadsheetOnColumnResizeCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `ParentWithNodeDepth` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -5965,6 +6876,15 @@ mod dll {
         pub destructor: AzAccessibilityStateVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<AccessibilityNode>`
+    #[repr(C)]
+    pub struct AzAccessibilityNodeVec {
+        pub(crate) ptr: *const AzAccessibilityNode,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzAccessibilityNodeVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<MenuItem>`
     #[repr(C)]
     pub struct AzMenuItemVec {
@@ -6079,25 +6999,115 @@ mod dll {
         pub(crate) ptr: *const AzVirtualKeyCode,
         pub len: usize,
         pub cap: usize,
-        pub destructor: AzVirtualKeyCodeVecDestructor,
+        pub destructor: AzVirtualKeyCodeVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `CascadeInfo`
+    #[repr(C)]
+    pub struct AzCascadeInfoVec {
+        pub(crate) ptr: *const AzCascadeInfo,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCascadeInfoVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `ScanCode`
+    #[repr(C)]
+    pub struct AzScanCodeVec {
+        pub(crate) ptr: *const u32,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzScanCodeVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `CssPathSelector`
+    #[repr(C)]
+    pub struct AzCssPathSelectorVec {
+        pub(crate) ptr: *const AzCssPathSelector,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssPathSelectorVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionProperty>`
+    #[repr(C)]
+    pub struct AzStyleTransitionPropertyVec {
+        pub(crate) ptr: *const AzStyleTransitionProperty,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleTransitionPropertyVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionDuration>`
+    #[repr(C)]
+    pub struct AzStyleTransitionDurationVec {
+        pub(crate) ptr: *const AzStyleTransitionDuration,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleTransitionDurationVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionDelay>`
+    #[repr(C)]
+    pub struct AzStyleTransitionDelayVec {
+        pub(crate) ptr: *const AzStyleTransitionDelay,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleTransitionDelayVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationDuration>`
+    #[repr(C)]
+    pub struct AzStyleAnimationDurationVec {
+        pub(crate) ptr: *const AzStyleAnimationDuration,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationDurationVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationIterationCount>`
+    #[repr(C)]
+    pub struct AzStyleAnimationIterationCountVec {
+        pub(crate) ptr: *const AzStyleAnimationIterationCount,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationIterationCountVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationDirection>`
+    #[repr(C)]
+    pub struct AzStyleAnimationDirectionVec {
+        pub(crate) ptr: *const AzStyleAnimationDirection,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationDirectionVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationFillMode>`
+    #[repr(C)]
+    pub struct AzStyleAnimationFillModeVec {
+        pub(crate) ptr: *const AzStyleAnimationFillMode,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationFillModeVecDestructor,
     }
 
-    /// Wrapper over a Rust-allocated `CascadeInfo`
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationDelay>`
     #[repr(C)]
-    pub struct AzCascadeInfoVec {
-        pub(crate) ptr: *const AzCascadeInfo,
+    pub struct AzStyleAnimationDelayVec {
+        pub(crate) ptr: *const AzStyleAnimationDelay,
         pub len: usize,
         pub cap: usize,
-        pub destructor: AzCascadeInfoVecDestructor,
+        pub destructor: AzStyleAnimationDelayVecDestructor,
     }
 
-    /// Wrapper over a Rust-allocated `ScanCode`
+    /// Wrapper over a Rust-allocated `Vec<GridTrackSizing>`
     #[repr(C)]
-    pub struct AzScanCodeVec {
-        pub(crate) ptr: *const u32,
+    pub struct AzGridTrackSizingVec {
+        pub(crate) ptr: *const AzGridTrackSizing,
         pub len: usize,
         pub cap: usize,
-        pub destructor: AzScanCodeVecDestructor,
+        pub destructor: AzGridTrackSizingVecDestructor,
     }
 
     /// Wrapper over a Rust-allocated `Vec<u16>`
@@ -6190,6 +7200,15 @@ mod dll {
         pub destructor: AzParentWithNodeDepthVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<SpreadsheetSizeOverride>`
+    #[repr(C)]
+    pub struct AzSpreadsheetSizeOverrideVec {
+        pub(crate) ptr: *const AzSpreadsheetSizeOverride,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzSpreadsheetSizeOverrideVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionColorInputOnValueChange` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -6270,6 +7289,98 @@ mod dll {
         Some(AzNumberInputOnValueChange),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTextEditOnTextChange` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTextEditOnTextChange {
+        None,
+        Some(AzTextEditOnTextChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTextEditOnFocusLost` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTextEditOnFocusLost {
+        None,
+        Some(AzTextEditOnFocusLost),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTextEditKind` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzOptionTextEditKind {
+        None,
+        Some(AzTextEditKind),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSliderOnValueChange` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionSliderOnValueChange {
+        None,
+        Some(AzSliderOnValueChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDropDownOnChoiceChange` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionDropDownOnChoiceChange {
+        None,
+        Some(AzDropDownOnChoiceChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSpreadsheetOnCellsChange` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionSpreadsheetOnCellsChange {
+        None,
+        Some(AzSpreadsheetOnCellsChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSpreadsheetOnSelectionChange` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionSpreadsheetOnSelectionChange {
+        None,
+        Some(AzSpreadsheetOnSelectionChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSpreadsheetOnColumnResize` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionSpreadsheetOnColumnResize {
+        None,
+        Some(AzSpreadsheetOnColumnResize),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSpreadsheetCellSelection` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzOptionSpreadsheetCellSelection {
+        None,
+        Some(AzSpreadsheetCellSelection),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionMenuItemIcon` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -6815,6 +7926,7 @@ mod dll {
     pub struct AzInlineTextContents {
         pub glyphs: AzInlineGlyphVec,
         pub bounds: AzLogicalRect,
+        pub is_rtl: bool,
     }
 
     /// Easing function of the animation (ease-in, ease-out, ease-in-out, custom)
@@ -6900,6 +8012,42 @@ mod dll {
         pub keys: AzVirtualKeyCodeVec,
     }
 
+    /// Window-wide keyboard shortcut that is not attached to a menu item
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzKeyboardShortcut {
+        pub keys: AzVirtualKeyCodeCombo,
+        pub callback: AzMenuCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `CssMediaCondition` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzCssMediaCondition {
+        MinWidth(AzPixelValue),
+        MaxWidth(AzPixelValue),
+        MinHeight(AzPixelValue),
+        MaxHeight(AzPixelValue),
+        MinResolution(AzFloatValue),
+        MaxResolution(AzFloatValue),
+        Orientation(AzCssMediaOrientation),
+        PrefersColorScheme(AzCssMediaColorScheme),
+    }
+
+    /// Re-export of rust-allocated (stack based) `CssPath` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzCssPath {
+        pub selectors: AzCssPathSelectorVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssPathPseudoSelector` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -6913,6 +8061,12 @@ mod dll {
         Hover,
         Active,
         Focus,
+        FirstChild,
+        LastChild,
+        OnlyChild,
+        NthLastChild(AzCssNthChildSelector),
+        Empty,
+        FocusWithin,
     }
 
     /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
@@ -6944,6 +8098,24 @@ mod dll {
         pub current_rect_height: f32,
     }
 
+    /// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumns` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzLayoutGridTemplateColumns {
+        pub tracks: AzGridTrackSizingVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridTemplateRows` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzLayoutGridTemplateRows {
+        pub tracks: AzGridTrackSizingVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `LinearGradient` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -6980,6 +8152,21 @@ mod dll {
         pub stops: AzNormalizedRadialColorStopVec,
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunction` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleTransitionTimingFunction {
+        Ease,
+        Linear,
+        EaseIn,
+        EaseOut,
+        EaseInOut,
+        CubicBezier(AzStyleCubicBezier),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTransform` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -7010,6 +8197,32 @@ mod dll {
         Perspective(AzPixelValue),
     }
 
+    /// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumnsValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzLayoutGridTemplateColumnsValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutGridTemplateColumns),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridTemplateRowsValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzLayoutGridTemplateRowsValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutGridTemplateRows),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBackgroundPositionVecValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -7049,24 +8262,172 @@ mod dll {
         Exact(AzStyleBackgroundSizeVec),
     }
 
-    /// Re-export of rust-allocated (stack based) `CheckBoxStateWrapper` struct
+    /// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleTransitionPropertyVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTransitionPropertyVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransitionDurationVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleTransitionDurationVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTransitionDurationVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransitionDelayVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleTransitionDelayVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTransitionDelayVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDurationVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleAnimationDurationVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationDurationVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleAnimationIterationCountVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationIterationCountVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleAnimationDirectionVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationDirectionVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleAnimationFillModeVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationFillModeVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDelayVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleAnimationDelayVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationDelayVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `CheckBoxStateWrapper` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzCheckBoxStateWrapper {
+        pub inner: AzCheckBoxState,
+        pub on_toggle: AzOptionCheckBoxOnToggle,
+    }
+
+    /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzNumberInputStateWrapper {
+        pub inner: AzNumberInputState,
+        pub on_value_change: AzOptionNumberInputOnValueChange,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextEditState {
+        pub text: AzU32Vec,
+        pub selection: AzOptionTextInputSelection,
+        pub cursor_pos: usize,
+        pub show_line_numbers: bool,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditSnapshot` struct
     #[repr(C)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub struct AzCheckBoxStateWrapper {
-        pub inner: AzCheckBoxState,
-        pub on_toggle: AzOptionCheckBoxOnToggle,
+    pub struct AzTextEditSnapshot {
+        pub text: AzU32Vec,
+        pub cursor_pos: usize,
     }
 
-    /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
+    /// Re-export of rust-allocated (stack based) `SliderStateWrapper` struct
     #[repr(C)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub struct AzNumberInputStateWrapper {
-        pub inner: AzNumberInputState,
-        pub on_value_change: AzOptionNumberInputOnValueChange,
+    pub struct AzSliderStateWrapper {
+        pub inner: AzSliderState,
+        pub on_value_change: AzOptionSliderOnValueChange,
+        pub is_dragging: bool,
+        pub drag_value: f32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `CssPropertySource` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzCssPropertySource {
+        Css(AzCssPath),
+        Inline,
     }
 
     /// Re-export of rust-allocated (stack based) `StyledNode` struct
@@ -7263,6 +8624,33 @@ mod dll {
         pub destructor: AzSvgPathElementVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<KeyboardShortcut>`
+    #[repr(C)]
+    pub struct AzKeyboardShortcutVec {
+        pub(crate) ptr: *const AzKeyboardShortcut,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzKeyboardShortcutVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `CssMediaCondition`
+    #[repr(C)]
+    pub struct AzCssMediaConditionVec {
+        pub(crate) ptr: *const AzCssMediaCondition,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssMediaConditionVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionTimingFunction>`
+    #[repr(C)]
+    pub struct AzStyleTransitionTimingFunctionVec {
+        pub(crate) ptr: *const AzStyleTransitionTimingFunction,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleTransitionTimingFunctionVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `StringVec`
     #[repr(C)]
     pub struct AzStringVec {
@@ -7290,6 +8678,15 @@ mod dll {
         pub destructor: AzTagIdToNodeIdMappingVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<TextEditSnapshot>`
+    #[repr(C)]
+    pub struct AzTextEditSnapshotVec {
+        pub(crate) ptr: *const AzTextEditSnapshot,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTextEditSnapshotVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionVirtualKeyCodeCombo` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -7563,6 +8960,16 @@ mod dll {
         Word(AzInlineTextContents),
     }
 
+    /// CSS path to set the keyboard input focus
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzFocusTargetPath {
+        pub dom: AzDomId,
+        pub css_path: AzCssPath,
+    }
+
     /// Re-export of rust-allocated (stack based) `CallbackData` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -7602,6 +9009,32 @@ mod dll {
         pub default_action: AzOptionString,
     }
 
+    /// Single node in the accessibility tree of a window, see `AccessibilityTree`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzAccessibilityNode {
+        pub node_id: AzDomNodeId,
+        pub role: AzAccessibilityRole,
+        pub name: AzOptionString,
+        pub value: AzOptionString,
+        pub states: AzAccessibilityStateVec,
+        pub accelerator: AzOptionVirtualKeyCodeCombo,
+        pub default_action: AzOptionString,
+        pub bounds: AzLogicalRect,
+        pub children: AzAccessibilityNodeVec,
+    }
+
+    /// Platform-neutral snapshot of the accessibility tree of all DOMs in a window, can be fed into an assistive-technology bridge (AT-SPI, UIA, NSAccessibility)
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzAccessibilityTree {
+        pub root: AzAccessibilityNode,
+    }
+
     /// Re-export of rust-allocated (stack based) `IdOrClass` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -7626,19 +9059,57 @@ mod dll {
         pub children: AzMenuItemVec,
     }
 
-    /// Re-export of rust-allocated (stack based) `CssPathSelector` struct
+    /// Returned when a keyboard shortcut can't be registered because its key combination is already in use
     #[repr(C, u8)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub enum AzCssPathSelector {
-        Global,
-        Type(AzNodeTypeKey),
-        Class(AzString),
-        Id(AzString),
-        PseudoSelector(AzCssPathPseudoSelector),
-        DirectChildren,
-        Children,
+    pub enum AzShortcutConflict {
+        Shortcut(AzVirtualKeyCodeCombo),
+        MenuItem(AzString),
+        ContextMenuItem(AzString),
+    }
+
+    /// One query of an `@media` rule, matches if all conditions match
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzCssMediaQuery {
+        pub conditions: AzCssMediaConditionVec,
+    }
+
+    /// Custom property (CSS variable) declaration, i.e. `--accent: #3366ff`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzCssCustomProperty {
+        pub name: AzString,
+        pub value: AzString,
+    }
+
+    /// Attribute selector such as `[data-state=open]`, matched against the attributes of a node
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzCssAttributeSelector {
+        pub name: AzString,
+        pub op: AzCssAttributeSelectorOp,
+        pub value: AzString,
+    }
+
+    /// Font file declared via an `@font-face` rule
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzCssFontFace {
+        pub font_family: AzString,
+        pub src: AzString,
+        pub font_weight: u16,
+        pub font_style: AzCssFontFaceStyle,
     }
 
     /// Re-export of rust-allocated (stack based) `StyleBackgroundContent` struct
@@ -7691,6 +9162,15 @@ mod dll {
         Ref(AzFontRef),
     }
 
+    /// Name of the `@keyframes` rule that an `animation-name` refers to
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzStyleAnimationName {
+        pub name: AzString,
+    }
+
     /// Re-export of rust-allocated (stack based) `ScrollbarStyleValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -7717,6 +9197,19 @@ mod dll {
         Exact(AzStyleTransformVec),
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunctionVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleTransitionTimingFunctionVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTransitionTimingFunctionVec),
+    }
+
     /// Re-export of rust-allocated (stack based) `ColorInputStateWrapper` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -7741,6 +9234,59 @@ mod dll {
         pub cursor_pos: usize,
     }
 
+    /// Re-export of rust-allocated (stack based) `TextEditHistory` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextEditHistory {
+        pub undo_stack: AzTextEditSnapshotVec,
+        pub redo_stack: AzTextEditSnapshotVec,
+        pub last_edit: AzOptionTextEditKind,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DropDownState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzDropDownState {
+        pub choices: AzStringVec,
+        pub disabled_choices: AzU32Vec,
+        pub selected: AzOptionUsize,
+        pub popup_position: AzMenuPopupPosition,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetCellContent` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSpreadsheetCellContent {
+        pub cell: AzSpreadsheetCellIndex,
+        pub text: AzString,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetCellClick` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSpreadsheetCellClick {
+        pub cell: AzSpreadsheetCellIndex,
+        pub time: AzInstant,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetCellEdit` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSpreadsheetCellEdit {
+        pub cell: AzSpreadsheetCellIndex,
+        pub text: AzString,
+    }
+
     /// Re-export of rust-allocated (stack based) `VertexAttribute` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -7980,13 +9526,31 @@ mod dll {
         pub destructor: AzVertexAttributeVecDestructor,
     }
 
-    /// Wrapper over a Rust-allocated `CssPathSelector`
+    /// Wrapper over a Rust-allocated `CssMediaQuery`
     #[repr(C)]
-    pub struct AzCssPathSelectorVec {
-        pub(crate) ptr: *const AzCssPathSelector,
+    pub struct AzCssMediaQueryVec {
+        pub(crate) ptr: *const AzCssMediaQuery,
         pub len: usize,
         pub cap: usize,
-        pub destructor: AzCssPathSelectorVecDestructor,
+        pub destructor: AzCssMediaQueryVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `CssFontFace`
+    #[repr(C)]
+    pub struct AzCssFontFaceVec {
+        pub(crate) ptr: *const AzCssFontFace,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssFontFaceVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationName>`
+    #[repr(C)]
+    pub struct AzStyleAnimationNameVec {
+        pub(crate) ptr: *const AzStyleAnimationName,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationNameVecDestructor,
     }
 
     /// Wrapper over a Rust-allocated `CallbackData`
@@ -8016,6 +9580,45 @@ mod dll {
         pub destructor: AzStringPairVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<SpreadsheetCellContent>`
+    #[repr(C)]
+    pub struct AzSpreadsheetCellContentVec {
+        pub(crate) ptr: *const AzSpreadsheetCellContent,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzSpreadsheetCellContentVecDestructor,
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSpreadsheetCellClick` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionSpreadsheetCellClick {
+        None,
+        Some(AzSpreadsheetCellClick),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSpreadsheetCellEdit` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionSpreadsheetCellEdit {
+        None,
+        Some(AzSpreadsheetCellEdit),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionAccessibilityNode` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionAccessibilityNode {
+        None,
+        Some(AzAccessibilityNode),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionFileTypeList` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -8117,6 +9720,21 @@ mod dll {
         pub bounds: AzLogicalRect,
     }
 
+    /// Defines the keyboard input focus target
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzFocusTarget {
+        Id(AzDomNodeId),
+        Path(AzFocusTargetPath),
+        Previous,
+        Next,
+        First,
+        Last,
+        NoFocus,
+    }
+
     /// Item entry in a menu or menu bar
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -8128,13 +9746,23 @@ mod dll {
         BreakLine,
     }
 
-    /// Re-export of rust-allocated (stack based) `CssPath` struct
-    #[repr(C)]
+    /// Re-export of rust-allocated (stack based) `CssPathSelector` struct
+    #[repr(C, u8)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub struct AzCssPath {
-        pub selectors: AzCssPathSelectorVec,
+    pub enum AzCssPathSelector {
+        Global,
+        Type(AzNodeTypeKey),
+        Class(AzString),
+        Id(AzString),
+        PseudoSelector(AzCssPathPseudoSelector),
+        Attribute(AzCssAttributeSelector),
+        Not(AzCssPathSelectorVec),
+        DirectChildren,
+        Children,
+        AdjacentSibling,
+        GeneralSibling,
     }
 
     /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
@@ -8163,6 +9791,19 @@ mod dll {
         Exact(AzStyleFontFamilyVec),
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleAnimationNameVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleAnimationNameVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationNameVec),
+    }
+
     /// Parsed CSS key-value pair
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -8180,6 +9821,7 @@ mod dll {
         Cursor(AzStyleCursorValue),
         Display(AzLayoutDisplayValue),
         Float(AzLayoutFloatValue),
+        Clear(AzLayoutClearValue),
         BoxSizing(AzLayoutBoxSizingValue),
         Width(AzLayoutWidthValue),
         Height(AzLayoutHeightValue),
@@ -8199,6 +9841,12 @@ mod dll {
         JustifyContent(AzLayoutJustifyContentValue),
         AlignItems(AzLayoutAlignItemsValue),
         AlignContent(AzLayoutAlignContentValue),
+        GridTemplateColumns(AzLayoutGridTemplateColumnsValue),
+        GridTemplateRows(AzLayoutGridTemplateRowsValue),
+        GridColumn(AzLayoutGridColumnValue),
+        GridRow(AzLayoutGridRowValue),
+        ColumnGap(AzLayoutColumnGapValue),
+        RowGap(AzLayoutRowGapValue),
         BackgroundContent(AzStyleBackgroundContentVecValue),
         BackgroundPosition(AzStyleBackgroundPositionVecValue),
         BackgroundSize(AzStyleBackgroundSizeVecValue),
@@ -8239,30 +9887,89 @@ mod dll {
         TransformOrigin(AzStyleTransformOriginValue),
         PerspectiveOrigin(AzStylePerspectiveOriginValue),
         BackfaceVisibility(AzStyleBackfaceVisibilityValue),
+        TransitionProperty(AzStyleTransitionPropertyVecValue),
+        TransitionDuration(AzStyleTransitionDurationVecValue),
+        TransitionTimingFunction(AzStyleTransitionTimingFunctionVecValue),
+        TransitionDelay(AzStyleTransitionDelayVecValue),
+        AnimationName(AzStyleAnimationNameVecValue),
+        AnimationDuration(AzStyleAnimationDurationVecValue),
+        AnimationTimingFunction(AzStyleTransitionTimingFunctionVecValue),
+        AnimationIterationCount(AzStyleAnimationIterationCountVecValue),
+        AnimationDirection(AzStyleAnimationDirectionVecValue),
+        AnimationFillMode(AzStyleAnimationFillModeVecValue),
+        AnimationDelay(AzStyleAnimationDelayVecValue),
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextInputStateWrapper` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextInputStateWrapper {
+        pub inner: AzTextInputState,
+        pub on_text_input: AzOptionTextInputOnTextInput,
+        pub on_virtual_key_down: AzOptionTextInputOnVirtualKeyDown,
+        pub on_focus_lost: AzOptionTextInputOnFocusLost,
+        pub update_text_input_before_calling_focus_lost_fn: bool,
+        pub update_text_input_before_calling_vk_down_fn: bool,
+        pub is_dragging: bool,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditStateWrapper` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextEditStateWrapper {
+        pub inner: AzTextEditState,
+        pub on_text_change: AzOptionTextEditOnTextChange,
+        pub on_focus_lost: AzOptionTextEditOnFocusLost,
+        pub history: AzTextEditHistory,
+        pub view: AzTextEditView,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DropDownStateWrapper` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzDropDownStateWrapper {
+        pub inner: AzDropDownState,
+        pub on_choice_change: AzOptionDropDownOnChoiceChange,
+        pub is_open: bool,
+        pub highlighted: AzOptionUsize,
+        pub search: AzString,
+        pub last_search_input: AzOptionInstant,
     }
 
-    /// Re-export of rust-allocated (stack based) `TextInputStateWrapper` struct
+    /// Re-export of rust-allocated (stack based) `SpreadsheetState` struct
     #[repr(C)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub struct AzTextInputStateWrapper {
-        pub inner: AzTextInputState,
-        pub on_text_input: AzOptionTextInputOnTextInput,
-        pub on_virtual_key_down: AzOptionTextInputOnVirtualKeyDown,
-        pub on_focus_lost: AzOptionTextInputOnFocusLost,
-        pub update_text_input_before_calling_focus_lost_fn: bool,
-        pub update_text_input_before_calling_vk_down_fn: bool,
+    pub struct AzSpreadsheetState {
+        pub style: AzSpreadsheetStyle,
+        pub row_count: usize,
+        pub column_count: usize,
+        pub default_column_width: f32,
+        pub default_row_height: f32,
+        pub selection: AzOptionSpreadsheetCellSelection,
+        pub column_width_overrides: AzSpreadsheetSizeOverrideVec,
+        pub row_height_overrides: AzSpreadsheetSizeOverrideVec,
+        pub cell_contents: AzSpreadsheetCellContentVec,
     }
 
-    /// Re-export of rust-allocated (stack based) `CssPropertySource` struct
-    #[repr(C, u8)]
+    /// Re-export of rust-allocated (stack based) `SpreadsheetView` struct
+    #[repr(C)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub enum AzCssPropertySource {
-        Css(AzCssPath),
-        Inline,
+    pub struct AzSpreadsheetView {
+        pub rendered: AzSpreadsheetRenderWindow,
+        pub iframe_node: AzOptionDomNodeId,
+        pub drag: AzSpreadsheetDrag,
+        pub last_click: AzOptionSpreadsheetCellClick,
+        pub editing: AzOptionSpreadsheetCellEdit,
     }
 
     /// Re-export of rust-allocated (stack based) `VertexLayout` struct
@@ -8401,6 +10108,7 @@ mod dll {
         pub background_color: AzColorU,
         pub layout_callback: AzLayoutCallback,
         pub close_callback: AzOptionCallback,
+        pub shortcuts: AzKeyboardShortcutVec,
     }
 
     /// Re-export of rust-allocated (stack based) `CallbackInfo` struct
@@ -8437,6 +10145,8 @@ mod dll {
         pub images_changed_in_callbacks: *mut c_void,
         pub image_masks_changed_in_callbacks: *mut c_void,
         pub css_properties_changed_in_callbacks: *mut c_void,
+        pub css_variables_changed_in_callbacks: *mut c_void,
+        pub css_transitions_changed_in_callbacks: *mut c_void,
         pub current_scroll_states: *const c_void,
         pub nodes_scrolled_in_callback: *mut c_void,
         pub hit_dom_node: AzDomNodeId,
@@ -8459,16 +10169,6 @@ mod dll {
         pub baseline_descender_px: f32,
     }
 
-    /// CSS path to set the keyboard input focus
-    #[repr(C)]
-    #[derive(Debug)]
-    #[derive(Clone)]
-    #[derive(PartialEq, PartialOrd)]
-    pub struct AzFocusTargetPath {
-        pub dom: AzDomId,
-        pub css_path: AzCssPath,
-    }
-
     /// Animation struct to start a new animation
     #[repr(C)]
     #[derive(Debug)]
@@ -8521,6 +10221,29 @@ mod dll {
         pub default_value: AzCssProperty,
     }
 
+    /// Single step (i.e. `50% { opacity: 0.5; }`) of a `@keyframes` rule
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzCssKeyframe {
+        pub offset: AzFloatValue,
+        pub properties: AzCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetStateWrapper` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSpreadsheetStateWrapper {
+        pub inner: AzSpreadsheetState,
+        pub on_cells_change: AzOptionSpreadsheetOnCellsChange,
+        pub on_selection_change: AzOptionSpreadsheetOnSelectionChange,
+        pub on_column_resize: AzOptionSpreadsheetOnColumnResize,
+        pub view: AzSpreadsheetView,
+    }
+
     /// Re-export of rust-allocated (stack based) `SvgNode` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -8553,6 +10276,15 @@ mod dll {
         pub destructor: AzNodeDataInlineCssPropertyVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssKeyframe>`
+    #[repr(C)]
+    pub struct AzCssKeyframeVec {
+        pub(crate) ptr: *const AzCssKeyframe,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssKeyframeVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionWindowState` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -8605,21 +10337,6 @@ mod dll {
         pub hot_reload: bool,
     }
 
-    /// Defines the keyboard input focus target
-    #[repr(C, u8)]
-    #[derive(Debug)]
-    #[derive(Clone)]
-    #[derive(PartialEq, PartialOrd)]
-    pub enum AzFocusTarget {
-        Id(AzDomNodeId),
-        Path(AzFocusTargetPath),
-        Previous,
-        Next,
-        First,
-        Last,
-        NoFocus,
-    }
-
     /// Represents one single DOM node (node type, classes, ids and callbacks are stored here)
     #[repr(C)]
     #[derive(Debug)]
@@ -8642,6 +10359,17 @@ mod dll {
     pub enum AzCssDeclaration {
         Static(AzCssProperty),
         Dynamic(AzDynamicCssProperty),
+        Custom(AzCssCustomProperty),
+    }
+
+    /// Animation declared via a `@keyframes` rule
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzCssKeyframes {
+        pub name: AzString,
+        pub keyframes: AzCssKeyframeVec,
     }
 
     /// Re-export of rust-allocated (stack based) `Button` struct
@@ -8711,6 +10439,56 @@ mod dll {
         pub state: AzNumberInputStateWrapper,
     }
 
+    /// Re-export of rust-allocated (stack based) `TextEdit` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextEdit {
+        pub state: AzTextEditStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub line_numbers_style: AzNodeDataInlineCssPropertyVec,
+        pub label_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `Slider` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSlider {
+        pub state: AzSliderStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub track_style: AzNodeDataInlineCssPropertyVec,
+        pub thumb_style: AzNodeDataInlineCssPropertyVec,
+        pub tick_style: AzNodeDataInlineCssPropertyVec,
+        pub label_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DropDown` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzDropDown {
+        pub state: AzDropDownStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub label_style: AzNodeDataInlineCssPropertyVec,
+        pub popup_style: AzNodeDataInlineCssPropertyVec,
+        pub entry_style: AzNodeDataInlineCssPropertyVec,
+        pub disabled_entry_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `Spreadsheet` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSpreadsheet {
+        pub state: AzSpreadsheetStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+    }
+
     /// Wrapper over a Rust-allocated `CssDeclaration`
     #[repr(C)]
     pub struct AzCssDeclarationVec {
@@ -8720,6 +10498,15 @@ mod dll {
         pub destructor: AzCssDeclarationVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssKeyframes>`
+    #[repr(C)]
+    pub struct AzCssKeyframesVec {
+        pub(crate) ptr: *const AzCssKeyframes,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssKeyframesVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `NodeDataVec`
     #[repr(C)]
     pub struct AzNodeDataVec {
@@ -8773,6 +10560,7 @@ mod dll {
     pub struct AzCssRuleBlock {
         pub path: AzCssPath,
         pub declarations: AzCssDeclarationVec,
+        pub media_queries: AzCssMediaQueryVec,
     }
 
     /// Re-export of rust-allocated (stack based) `StyledDom` struct
@@ -8896,6 +10684,8 @@ mod dll {
     #[derive(PartialEq, PartialOrd)]
     pub struct AzCss {
         pub stylesheets: AzStylesheetVec,
+        pub font_faces: AzCssFontFaceVec,
+        pub keyframes: AzCssKeyframesVec,
     }
 
     #[cfg_attr(target_os = "windows", link(name="azul.dll"))] // https://github.com/rust-lang/cargo/issues/9082
@@ -8943,6 +10733,7 @@ mod dll {
         pub(crate) fn AzCallbackInfo_setWindowState(_:  &mut AzCallbackInfo, _:  AzWindowState);
         pub(crate) fn AzCallbackInfo_setFocus(_:  &mut AzCallbackInfo, _:  AzFocusTarget);
         pub(crate) fn AzCallbackInfo_setCssProperty(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzCssProperty);
+        pub(crate) fn AzCallbackInfo_setCssVariable(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzString, _:  AzString);
         pub(crate) fn AzCallbackInfo_setScrollPosition(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzLogicalPosition);
         pub(crate) fn AzCallbackInfo_setStringContents(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzString);
         pub(crate) fn AzCallbackInfo_addImage(_:  &mut AzCallbackInfo, _:  AzString, _:  AzImageRef);
@@ -9026,6 +10817,8 @@ mod dll {
         pub(crate) fn AzDom_withFocusCssProperty(_:  &mut AzDom, _:  AzCssProperty) -> AzDom;
         pub(crate) fn AzDom_setClipMask(_:  &mut AzDom, _:  AzImageMask);
         pub(crate) fn AzDom_withClipMask(_:  &mut AzDom, _:  AzImageMask) -> AzDom;
+        pub(crate) fn AzDom_setAttribute(_:  &mut AzDom, _:  AzString, _:  AzString);
+        pub(crate) fn AzDom_withAttribute(_:  &mut AzDom, _:  AzString, _:  AzString) -> AzDom;
         pub(crate) fn AzDom_setTabIndex(_:  &mut AzDom, _:  AzTabIndex);
         pub(crate) fn AzDom_withTabIndex(_:  &mut AzDom, _:  AzTabIndex) -> AzDom;
         pub(crate) fn AzDom_setAccessibilityInfo(_:  &mut AzDom, _:  AzAccessibilityInfo);
@@ -9059,12 +10852,15 @@ mod dll {
         pub(crate) fn AzNodeData_setInlineCssProps(_:  &mut AzNodeData, _:  AzNodeDataInlineCssPropertyVec);
         pub(crate) fn AzNodeData_withInlineCssProps(_:  &mut AzNodeData, _:  AzNodeDataInlineCssPropertyVec) -> AzNodeData;
         pub(crate) fn AzNodeData_setClipMask(_:  &mut AzNodeData, _:  AzImageMask);
+        pub(crate) fn AzNodeData_setAttribute(_:  &mut AzNodeData, _:  AzString, _:  AzString);
         pub(crate) fn AzNodeData_setTabIndex(_:  &mut AzNodeData, _:  AzTabIndex);
         pub(crate) fn AzNodeData_setAccessibilityInfo(_:  &mut AzNodeData, _:  AzAccessibilityInfo);
         pub(crate) fn AzNodeData_setMenuBar(_:  &mut AzNodeData, _:  AzMenu);
         pub(crate) fn AzNodeData_setContextMenu(_:  &mut AzNodeData, _:  AzMenu);
         pub(crate) fn AzNodeData_hash(_:  &AzNodeData) -> u64;
         pub(crate) fn AzOn_intoEventFilter(_:  AzOn) -> AzEventFilter;
+        pub(crate) fn AzAccessibilityNode_hasState(_:  &AzAccessibilityNode, _:  AzAccessibilityState) -> bool;
+        pub(crate) fn AzAccessibilityTree_getFocusedNode(_:  &AzAccessibilityTree) -> AzOptionAccessibilityNode;
         pub(crate) fn AzMenuItem_new(_:  AzString, _:  AzOptionMenuCallback) -> AzMenuItem;
         pub(crate) fn AzStringMenuItem_new(_:  AzString) -> AzStringMenuItem;
         pub(crate) fn AzStringMenuItem_addChild(_:  &mut AzStringMenuItem, _:  AzMenuItem);
@@ -9107,6 +10903,92 @@ mod dll {
         pub(crate) fn AzTextInput_dom(_:  &mut AzTextInput) -> AzDom;
         pub(crate) fn AzNumberInput_new(_:  f32) -> AzNumberInput;
         pub(crate) fn AzNumberInput_dom(_:  &mut AzNumberInput) -> AzDom;
+        pub(crate) fn AzTextEdit_new(_:  AzString) -> AzTextEdit;
+        pub(crate) fn AzTextEdit_setOnTextChange(_:  &mut AzTextEdit, _:  AzRefAny, _:  AzTextEditOnTextChangeCallbackType);
+        pub(crate) fn AzTextEdit_withOnTextChange(_:  &mut AzTextEdit, _:  AzRefAny, _:  AzTextEditOnTextChangeCallbackType) -> AzTextEdit;
+        pub(crate) fn AzTextEdit_setOnFocusLost(_:  &mut AzTextEdit, _:  AzRefAny, _:  AzTextEditOnFocusLostCallbackType);
+        pub(crate) fn AzTextEdit_withOnFocusLost(_:  &mut AzTextEdit, _:  AzRefAny, _:  AzTextEditOnFocusLostCallbackType) -> AzTextEdit;
+        pub(crate) fn AzTextEdit_setShowLineNumbers(_:  &mut AzTextEdit, _:  bool);
+        pub(crate) fn AzTextEdit_withShowLineNumbers(_:  &mut AzTextEdit, _:  bool) -> AzTextEdit;
+        pub(crate) fn AzTextEdit_setContainerStyle(_:  &mut AzTextEdit, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTextEdit_withContainerStyle(_:  &mut AzTextEdit, _:  AzNodeDataInlineCssPropertyVec) -> AzTextEdit;
+        pub(crate) fn AzTextEdit_setLineNumbersStyle(_:  &mut AzTextEdit, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTextEdit_withLineNumbersStyle(_:  &mut AzTextEdit, _:  AzNodeDataInlineCssPropertyVec) -> AzTextEdit;
+        pub(crate) fn AzTextEdit_setLabelStyle(_:  &mut AzTextEdit, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzTextEdit_withLabelStyle(_:  &mut AzTextEdit, _:  AzNodeDataInlineCssPropertyVec) -> AzTextEdit;
+        pub(crate) fn AzTextEdit_dom(_:  &mut AzTextEdit) -> AzDom;
+        pub(crate) fn AzSlider_new(_:  f32, _:  f32, _:  f32) -> AzSlider;
+        pub(crate) fn AzSlider_setStep(_:  &mut AzSlider, _:  f32);
+        pub(crate) fn AzSlider_withStep(_:  &mut AzSlider, _:  f32) -> AzSlider;
+        pub(crate) fn AzSlider_setOrientation(_:  &mut AzSlider, _:  AzSliderOrientation);
+        pub(crate) fn AzSlider_withOrientation(_:  &mut AzSlider, _:  AzSliderOrientation) -> AzSlider;
+        pub(crate) fn AzSlider_setTickCount(_:  &mut AzSlider, _:  usize);
+        pub(crate) fn AzSlider_withTickCount(_:  &mut AzSlider, _:  usize) -> AzSlider;
+        pub(crate) fn AzSlider_setShowTickLabels(_:  &mut AzSlider, _:  bool);
+        pub(crate) fn AzSlider_withShowTickLabels(_:  &mut AzSlider, _:  bool) -> AzSlider;
+        pub(crate) fn AzSlider_setOnValueChange(_:  &mut AzSlider, _:  AzRefAny, _:  AzSliderOnValueChangeCallbackType);
+        pub(crate) fn AzSlider_withOnValueChange(_:  &mut AzSlider, _:  AzRefAny, _:  AzSliderOnValueChangeCallbackType) -> AzSlider;
+        pub(crate) fn AzSlider_setContainerStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzSlider_withContainerStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec) -> AzSlider;
+        pub(crate) fn AzSlider_setTrackStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzSlider_withTrackStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec) -> AzSlider;
+        pub(crate) fn AzSlider_setThumbStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzSlider_withThumbStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec) -> AzSlider;
+        pub(crate) fn AzSlider_setTickStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzSlider_withTickStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec) -> AzSlider;
+        pub(crate) fn AzSlider_setLabelStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzSlider_withLabelStyle(_:  &mut AzSlider, _:  AzNodeDataInlineCssPropertyVec) -> AzSlider;
+        pub(crate) fn AzSlider_dom(_:  &mut AzSlider) -> AzDom;
+        pub(crate) fn AzDropDown_new(_:  AzStringVec) -> AzDropDown;
+        pub(crate) fn AzDropDown_setSelected(_:  &mut AzDropDown, _:  usize);
+        pub(crate) fn AzDropDown_withSelected(_:  &mut AzDropDown, _:  usize) -> AzDropDown;
+        pub(crate) fn AzDropDown_setDisabledChoices(_:  &mut AzDropDown, _:  AzU32Vec);
+        pub(crate) fn AzDropDown_withDisabledChoices(_:  &mut AzDropDown, _:  AzU32Vec) -> AzDropDown;
+        pub(crate) fn AzDropDown_setPopupPosition(_:  &mut AzDropDown, _:  AzMenuPopupPosition);
+        pub(crate) fn AzDropDown_withPopupPosition(_:  &mut AzDropDown, _:  AzMenuPopupPosition) -> AzDropDown;
+        pub(crate) fn AzDropDown_setOnChoiceChange(_:  &mut AzDropDown, _:  AzRefAny, _:  AzDropDownOnChoiceChangeCallbackType);
+        pub(crate) fn AzDropDown_withOnChoiceChange(_:  &mut AzDropDown, _:  AzRefAny, _:  AzDropDownOnChoiceChangeCallbackType) -> AzDropDown;
+        pub(crate) fn AzDropDown_setContainerStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzDropDown_withContainerStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec) -> AzDropDown;
+        pub(crate) fn AzDropDown_setLabelStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzDropDown_withLabelStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec) -> AzDropDown;
+        pub(crate) fn AzDropDown_setPopupStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzDropDown_withPopupStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec) -> AzDropDown;
+        pub(crate) fn AzDropDown_setEntryStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzDropDown_withEntryStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec) -> AzDropDown;
+        pub(crate) fn AzDropDown_setDisabledEntryStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzDropDown_withDisabledEntryStyle(_:  &mut AzDropDown, _:  AzNodeDataInlineCssPropertyVec) -> AzDropDown;
+        pub(crate) fn AzDropDown_dom(_:  &mut AzDropDown) -> AzDom;
+        pub(crate) fn AzSpreadsheet_new(_:  AzSpreadsheetState) -> AzSpreadsheet;
+        pub(crate) fn AzSpreadsheet_setOnCellsChange(_:  &mut AzSpreadsheet, _:  AzRefAny, _:  AzSpreadsheetOnCellsChangeCallbackType);
+        pub(crate) fn AzSpreadsheet_withOnCellsChange(_:  &mut AzSpreadsheet, _:  AzRefAny, _:  AzSpreadsheetOnCellsChangeCallbackType) -> AzSpreadsheet;
+        pub(crate) fn AzSpreadsheet_setOnSelectionChange(_:  &mut AzSpreadsheet, _:  AzRefAny, _:  AzSpreadsheetOnSelectionChangeCallbackType);
+        pub(crate) fn AzSpreadsheet_withOnSelectionChange(_:  &mut AzSpreadsheet, _:  AzRefAny, _:  AzSpreadsheetOnSelectionChangeCallbackType) -> AzSpreadsheet;
+        pub(crate) fn AzSpreadsheet_setOnColumnResize(_:  &mut AzSpreadsheet, _:  AzRefAny, _:  AzSpreadsheetOnColumnResizeCallbackType);
+        pub(crate) fn AzSpreadsheet_withOnColumnResize(_:  &mut AzSpreadsheet, _:  AzRefAny, _:  AzSpreadsheetOnColumnResizeCallbackType) -> AzSpreadsheet;
+        pub(crate) fn AzSpreadsheet_setContainerStyle(_:  &mut AzSpreadsheet, _:  AzNodeDataInlineCssPropertyVec);
+        pub(crate) fn AzSpreadsheet_withContainerStyle(_:  &mut AzSpreadsheet, _:  AzNodeDataInlineCssPropertyVec) -> AzSpreadsheet;
+        pub(crate) fn AzSpreadsheet_dom(_:  &mut AzSpreadsheet) -> AzDom;
+        pub(crate) fn AzSpreadsheetState_new() -> AzSpreadsheetState;
+        pub(crate) fn AzSpreadsheetState_setCellContent(_:  &mut AzSpreadsheetState, _:  AzSpreadsheetCellIndex, _:  AzString);
+        pub(crate) fn AzSpreadsheetState_getCellContent(_:  &AzSpreadsheetState, _:  AzSpreadsheetCellIndex) -> AzOptionString;
+        pub(crate) fn AzSpreadsheetState_setSelection(_:  &mut AzSpreadsheetState, _:  AzOptionSpreadsheetCellSelection);
+        pub(crate) fn AzSpreadsheetState_selectAll(_:  &mut AzSpreadsheetState);
+        pub(crate) fn AzSpreadsheetState_getColumnWidth(_:  &AzSpreadsheetState, _:  usize) -> f32;
+        pub(crate) fn AzSpreadsheetState_getRowHeight(_:  &AzSpreadsheetState, _:  usize) -> f32;
+        pub(crate) fn AzSpreadsheetState_setColumnWidth(_:  &mut AzSpreadsheetState, _:  usize, _:  f32);
+        pub(crate) fn AzSpreadsheetState_setRowHeight(_:  &mut AzSpreadsheetState, _:  usize, _:  f32);
+        pub(crate) fn AzSpreadsheetState_getTableSize(_:  &AzSpreadsheetState) -> AzLogicalSize;
+        pub(crate) fn AzSpreadsheetState_getCellRect(_:  &AzSpreadsheetState, _:  AzSpreadsheetCellIndex) -> AzLogicalRect;
+        pub(crate) fn AzSpreadsheetState_getSelectionTsv(_:  &AzSpreadsheetState, _:  AzSpreadsheetCellSelection) -> AzString;
+        pub(crate) fn AzSpreadsheetState_pasteTsv(_:  &mut AzSpreadsheetState, _:  AzString) -> AzOptionSpreadsheetCellSelection;
+        pub(crate) fn AzSpreadsheetCellSelection_from(_:  usize, _:  usize) -> AzSpreadsheetCellSelection;
+        pub(crate) fn AzSpreadsheetCellSelection_to(_:  AzSpreadsheetCellSelection, _:  usize, _:  usize) -> AzSpreadsheetCellSelection;
+        pub(crate) fn AzSpreadsheetCellSelection_numberOfRowsSelected(_:  &AzSpreadsheetCellSelection) -> usize;
+        pub(crate) fn AzSpreadsheetCellSelection_numberOfColumnsSelected(_:  &AzSpreadsheetCellSelection) -> usize;
+        pub(crate) fn AzSpreadsheetCellSelection_getTopLeft(_:  &AzSpreadsheetCellSelection) -> AzSpreadsheetCellIndex;
+        pub(crate) fn AzSpreadsheetCellSelection_getBottomRight(_:  &AzSpreadsheetCellSelection) -> AzSpreadsheetCellIndex;
+        pub(crate) fn AzSpreadsheetCellSelection_contains(_:  &AzSpreadsheetCellSelection, _:  AzSpreadsheetCellIndex) -> bool;
         pub(crate) fn AzCssPropertyCache_delete(_:  &mut AzCssPropertyCache);
         pub(crate) fn AzCssPropertyCache_deepCopy(_:  &AzCssPropertyCache) -> AzCssPropertyCache;
         pub(crate) fn AzStyledDom_new(_:  AzDom, _:  AzCss) -> AzStyledDom;
@@ -9449,6 +11331,7 @@ mod dll {
         pub(crate) fn AzString_trim(_:  &AzString) -> AzString;
         pub(crate) fn AzString_asRefstr(_:  &AzString) -> AzRefstr;
         pub(crate) fn AzAccessibilityStateVec_delete(_:  &mut AzAccessibilityStateVec);
+        pub(crate) fn AzAccessibilityNodeVec_delete(_:  &mut AzAccessibilityNodeVec);
         pub(crate) fn AzMenuItemVec_delete(_:  &mut AzMenuItemVec);
         pub(crate) fn AzTessellatedSvgNodeVec_asRefVec(_:  &AzTessellatedSvgNodeVec) -> AzTessellatedSvgNodeVecRef;
         pub(crate) fn AzTessellatedSvgNodeVec_delete(_:  &mut AzTessellatedSvgNodeVec);
@@ -9478,11 +11361,28 @@ mod dll {
         pub(crate) fn AzU32Vec_delete(_:  &mut AzU32Vec);
         pub(crate) fn AzXWindowTypeVec_delete(_:  &mut AzXWindowTypeVec);
         pub(crate) fn AzVirtualKeyCodeVec_delete(_:  &mut AzVirtualKeyCodeVec);
+        pub(crate) fn AzKeyboardShortcutVec_delete(_:  &mut AzKeyboardShortcutVec);
         pub(crate) fn AzCascadeInfoVec_delete(_:  &mut AzCascadeInfoVec);
         pub(crate) fn AzScanCodeVec_delete(_:  &mut AzScanCodeVec);
         pub(crate) fn AzCssDeclarationVec_delete(_:  &mut AzCssDeclarationVec);
         pub(crate) fn AzCssPathSelectorVec_delete(_:  &mut AzCssPathSelectorVec);
         pub(crate) fn AzStylesheetVec_delete(_:  &mut AzStylesheetVec);
+        pub(crate) fn AzCssMediaQueryVec_delete(_:  &mut AzCssMediaQueryVec);
+        pub(crate) fn AzCssMediaConditionVec_delete(_:  &mut AzCssMediaConditionVec);
+        pub(crate) fn AzCssFontFaceVec_delete(_:  &mut AzCssFontFaceVec);
+        pub(crate) fn AzStyleTransitionPropertyVec_delete(_:  &mut AzStyleTransitionPropertyVec);
+        pub(crate) fn AzStyleTransitionDurationVec_delete(_:  &mut AzStyleTransitionDurationVec);
+        pub(crate) fn AzStyleTransitionTimingFunctionVec_delete(_:  &mut AzStyleTransitionTimingFunctionVec);
+        pub(crate) fn AzStyleTransitionDelayVec_delete(_:  &mut AzStyleTransitionDelayVec);
+        pub(crate) fn AzStyleAnimationNameVec_delete(_:  &mut AzStyleAnimationNameVec);
+        pub(crate) fn AzStyleAnimationDurationVec_delete(_:  &mut AzStyleAnimationDurationVec);
+        pub(crate) fn AzStyleAnimationIterationCountVec_delete(_:  &mut AzStyleAnimationIterationCountVec);
+        pub(crate) fn AzStyleAnimationDirectionVec_delete(_:  &mut AzStyleAnimationDirectionVec);
+        pub(crate) fn AzStyleAnimationFillModeVec_delete(_:  &mut AzStyleAnimationFillModeVec);
+        pub(crate) fn AzStyleAnimationDelayVec_delete(_:  &mut AzStyleAnimationDelayVec);
+        pub(crate) fn AzCssKeyframeVec_delete(_:  &mut AzCssKeyframeVec);
+        pub(crate) fn AzCssKeyframesVec_delete(_:  &mut AzCssKeyframesVec);
+        pub(crate) fn AzGridTrackSizingVec_delete(_:  &mut AzGridTrackSizingVec);
         pub(crate) fn AzCssRuleBlockVec_delete(_:  &mut AzCssRuleBlockVec);
         pub(crate) fn AzU16Vec_delete(_:  &mut AzU16Vec);
         pub(crate) fn AzF32Vec_delete(_:  &mut AzF32Vec);
@@ -9503,6 +11403,9 @@ mod dll {
         pub(crate) fn AzTagIdToNodeIdMappingVec_delete(_:  &mut AzTagIdToNodeIdMappingVec);
         pub(crate) fn AzParentWithNodeDepthVec_delete(_:  &mut AzParentWithNodeDepthVec);
         pub(crate) fn AzNodeDataVec_delete(_:  &mut AzNodeDataVec);
+        pub(crate) fn AzTextEditSnapshotVec_delete(_:  &mut AzTextEditSnapshotVec);
+        pub(crate) fn AzSpreadsheetSizeOverrideVec_delete(_:  &mut AzSpreadsheetSizeOverrideVec);
+        pub(crate) fn AzSpreadsheetCellContentVec_delete(_:  &mut AzSpreadsheetCellContentVec);
     }
 
     }
@@ -10061,6 +11964,8 @@ pub mod callbacks {
         pub fn set_focus(&mut self, target: FocusTarget)  { unsafe { crate::dll::AzCallbackInfo_setFocus(self, target) } }
         /// Sets a `CssProperty` on a given node to its new value. If this property change affects the layout, this will automatically trigger a relayout and redraw of the screen.
         pub fn set_css_property(&mut self, node_id: DomNodeId, new_property: CssProperty)  { unsafe { crate::dll::AzCallbackInfo_setCssProperty(self, node_id, new_property) } }
+        /// Sets the value of the CSS variable `--name` on the given node and all of its children (which inherit the variable), i.e. setting a variable on the root node switches the theme of the entire window. All `var(--name)` references are re-resolved before the next frame. An empty value removes the variable again.
+        pub fn set_css_variable(&mut self, node_id: DomNodeId, name: String, value: String)  { unsafe { crate::dll::AzCallbackInfo_setCssVariable(self, node_id, name, value) } }
         /// Sets the scroll position of the node
         pub fn set_scroll_position(&mut self, node_id: DomNodeId, scroll_position: LogicalPosition)  { unsafe { crate::dll::AzCallbackInfo_setScrollPosition(self, node_id, scroll_position) } }
         /// If the node is a `Text` node, overwrites the `Text` content with the new string, without requiring the entire UI to be rebuilt.
@@ -10444,6 +12349,10 @@ pub mod dom {
         pub fn set_clip_mask(&mut self, clip_mask: ImageMask)  { unsafe { crate::dll::AzDom_setClipMask(self, clip_mask) } }
         /// Same as set_clip_mask, but as a builder method
         pub fn with_clip_mask(&mut self, clip_mask: ImageMask)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withClipMask(self, clip_mask) } }
+        /// Sets a string attribute (i.e. `data-state: open`) on the DOM root node, matched by CSS attribute selectors (`[data-state=open]`)
+        pub fn set_attribute(&mut self, name: String, value: String)  { unsafe { crate::dll::AzDom_setAttribute(self, name, value) } }
+        /// Same as set_attribute, but as a builder method
+        pub fn with_attribute(&mut self, name: String, value: String)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withAttribute(self, name, value) } }
         /// Sets the tab index for the DOM root node.
         pub fn set_tab_index(&mut self, tab_index: TabIndex)  { unsafe { crate::dll::AzDom_setTabIndex(self, tab_index) } }
         /// Same as set_tab_index, but as a builder method
@@ -10522,6 +12431,8 @@ pub mod dom {
         pub fn with_inline_css_props(&mut self, css_properties: NodeDataInlineCssPropertyVec)  -> crate::dom::NodeData { unsafe { crate::dll::AzNodeData_withInlineCssProps(self, css_properties) } }
         /// Sets the `extra.clip_mask` field for this node
         pub fn set_clip_mask(&mut self, image_mask: ImageMask)  { unsafe { crate::dll::AzNodeData_setClipMask(self, image_mask) } }
+        /// Sets a string attribute (i.e. `data-state: open`) on this node, matched by CSS attribute selectors (`[data-state=open]`)
+        pub fn set_attribute(&mut self, name: String, value: String)  { unsafe { crate::dll::AzNodeData_setAttribute(self, name, value) } }
         /// Sets the tab index for this node
         pub fn set_tab_index(&mut self, tab_index: TabIndex)  { unsafe { crate::dll::AzNodeData_setTabIndex(self, tab_index) } }
         /// Sets accessibility attributes for this node
@@ -10575,6 +12486,22 @@ pub mod dom {
     /// MSAA accessibility state. For information on what each state does, see the <a href="https://docs.microsoft.com/en-us/windows/win32/winauto/object-state-constants">MSDN State Constants page</a>.
     
 #[doc(inline)] pub use crate::dll::AzAccessibilityState as AccessibilityState;
+    /// Single node in the accessibility tree of a window, see `AccessibilityTree`
+    
+#[doc(inline)] pub use crate::dll::AzAccessibilityNode as AccessibilityNode;
+    impl AccessibilityNode {
+        /// Returns whether the node has the given accessibility state
+        pub fn has_state(&self, state: AccessibilityState)  -> bool { unsafe { crate::dll::AzAccessibilityNode_hasState(self, state) } }
+    }
+
+    /// Platform-neutral snapshot of the accessibility tree of all DOMs in a window, can be fed into an assistive-technology bridge (AT-SPI, UIA, NSAccessibility)
+    
+#[doc(inline)] pub use crate::dll::AzAccessibilityTree as AccessibilityTree;
+    impl AccessibilityTree {
+        /// Returns a copy of the node that currently has the keyboard focus, if any
+        pub fn get_focused_node(&self)  -> crate::option::OptionAccessibilityNode { unsafe { crate::dll::AzAccessibilityTree_getFocusedNode(self) } }
+    }
+
     /// `TabIndex` struct
     
 #[doc(inline)] pub use crate::dll::AzTabIndex as TabIndex;
@@ -10624,6 +12551,12 @@ pub mod menu {
     /// Similar to `dom.CallbackData`, stores some data + a callback to call when the menu is activated
     
 #[doc(inline)] pub use crate::dll::AzMenuCallback as MenuCallback;
+    /// Window-wide keyboard shortcut that is not attached to a menu item
+    
+#[doc(inline)] pub use crate::dll::AzKeyboardShortcut as KeyboardShortcut;
+    /// Returned when a keyboard shortcut can't be registered because its key combination is already in use
+    
+#[doc(inline)] pub use crate::dll::AzShortcutConflict as ShortcutConflict;
     /// Icon of a menu entry
     
 #[doc(inline)] pub use crate::dll::AzMenuItemIcon as MenuItemIcon;
@@ -10644,6 +12577,16 @@ pub mod css {
         StyleBackgroundRepeatVec,
         StyleTransformVec,
         StyleFontFamilyVec,
+        StyleTransitionPropertyVec,
+        StyleTransitionDurationVec,
+        StyleTransitionTimingFunctionVec,
+        StyleTransitionDelayVec,
+        StyleAnimationNameVec,
+        StyleAnimationDurationVec,
+        StyleAnimationIterationCountVec,
+        StyleAnimationDirectionVec,
+        StyleAnimationFillModeVec,
+        StyleAnimationDelayVec,
     };
 
     macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => ({
@@ -10659,6 +12602,7 @@ pub mod css {
             CssPropertyType::Cursor => CssProperty::Cursor(StyleCursorValue::$content_type),
             CssPropertyType::Display => CssProperty::Display(LayoutDisplayValue::$content_type),
             CssPropertyType::Float => CssProperty::Float(LayoutFloatValue::$content_type),
+            CssPropertyType::Clear => CssProperty::Clear(LayoutClearValue::$content_type),
             CssPropertyType::BoxSizing => CssProperty::BoxSizing(LayoutBoxSizingValue::$content_type),
             CssPropertyType::Width => CssProperty::Width(LayoutWidthValue::$content_type),
             CssPropertyType::Height => CssProperty::Height(LayoutHeightValue::$content_type),
@@ -10678,6 +12622,12 @@ pub mod css {
             CssPropertyType::JustifyContent => CssProperty::JustifyContent(LayoutJustifyContentValue::$content_type),
             CssPropertyType::AlignItems => CssProperty::AlignItems(LayoutAlignItemsValue::$content_type),
             CssPropertyType::AlignContent => CssProperty::AlignContent(LayoutAlignContentValue::$content_type),
+            CssPropertyType::GridTemplateColumns => CssProperty::GridTemplateColumns(LayoutGridTemplateColumnsValue::$content_type),
+            CssPropertyType::GridTemplateRows => CssProperty::GridTemplateRows(LayoutGridTemplateRowsValue::$content_type),
+            CssPropertyType::GridColumn => CssProperty::GridColumn(LayoutGridColumnValue::$content_type),
+            CssPropertyType::GridRow => CssProperty::GridRow(LayoutGridRowValue::$content_type),
+            CssPropertyType::ColumnGap => CssProperty::ColumnGap(LayoutColumnGapValue::$content_type),
+            CssPropertyType::RowGap => CssProperty::RowGap(LayoutRowGapValue::$content_type),
             CssPropertyType::BackgroundContent => CssProperty::BackgroundContent(StyleBackgroundContentVecValue::$content_type),
             CssPropertyType::BackgroundPosition => CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::$content_type),
             CssPropertyType::BackgroundSize => CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::$content_type),
@@ -10718,6 +12668,17 @@ pub mod css {
             CssPropertyType::PerspectiveOrigin => CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::$content_type),
             CssPropertyType::TransformOrigin => CssProperty::TransformOrigin(StyleTransformOriginValue::$content_type),
             CssPropertyType::BackfaceVisibility => CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::$content_type),
+            CssPropertyType::TransitionProperty => CssProperty::TransitionProperty(StyleTransitionPropertyVecValue::$content_type),
+            CssPropertyType::TransitionDuration => CssProperty::TransitionDuration(StyleTransitionDurationVecValue::$content_type),
+            CssPropertyType::TransitionTimingFunction => CssProperty::TransitionTimingFunction(StyleTransitionTimingFunctionVecValue::$content_type),
+            CssPropertyType::TransitionDelay => CssProperty::TransitionDelay(StyleTransitionDelayVecValue::$content_type),
+            CssPropertyType::AnimationName => CssProperty::AnimationName(StyleAnimationNameVecValue::$content_type),
+            CssPropertyType::AnimationDuration => CssProperty::AnimationDuration(StyleAnimationDurationVecValue::$content_type),
+            CssPropertyType::AnimationTimingFunction => CssProperty::AnimationTimingFunction(StyleTransitionTimingFunctionVecValue::$content_type),
+            CssPropertyType::AnimationIterationCount => CssProperty::AnimationIterationCount(StyleAnimationIterationCountVecValue::$content_type),
+            CssPropertyType::AnimationDirection => CssProperty::AnimationDirection(StyleAnimationDirectionVecValue::$content_type),
+            CssPropertyType::AnimationFillMode => CssProperty::AnimationFillMode(StyleAnimationFillModeVecValue::$content_type),
+            CssPropertyType::AnimationDelay => CssProperty::AnimationDelay(StyleAnimationDelayVecValue::$content_type),
         }
     })}
 
@@ -10737,6 +12698,7 @@ pub mod css {
                 CssProperty::Cursor(_) => CssPropertyType::Cursor,
                 CssProperty::Display(_) => CssPropertyType::Display,
                 CssProperty::Float(_) => CssPropertyType::Float,
+                CssProperty::Clear(_) => CssPropertyType::Clear,
                 CssProperty::BoxSizing(_) => CssPropertyType::BoxSizing,
                 CssProperty::Width(_) => CssPropertyType::Width,
                 CssProperty::Height(_) => CssPropertyType::Height,
@@ -10756,6 +12718,12 @@ pub mod css {
                 CssProperty::JustifyContent(_) => CssPropertyType::JustifyContent,
                 CssProperty::AlignItems(_) => CssPropertyType::AlignItems,
                 CssProperty::AlignContent(_) => CssPropertyType::AlignContent,
+                CssProperty::GridTemplateColumns(_) => CssPropertyType::GridTemplateColumns,
+                CssProperty::GridTemplateRows(_) => CssPropertyType::GridTemplateRows,
+                CssProperty::GridColumn(_) => CssPropertyType::GridColumn,
+                CssProperty::GridRow(_) => CssPropertyType::GridRow,
+                CssProperty::ColumnGap(_) => CssPropertyType::ColumnGap,
+                CssProperty::RowGap(_) => CssPropertyType::RowGap,
                 CssProperty::BackgroundContent(_) => CssPropertyType::BackgroundContent,
                 CssProperty::BackgroundPosition(_) => CssPropertyType::BackgroundPosition,
                 CssProperty::BackgroundSize(_) => CssPropertyType::BackgroundSize,
@@ -10796,6 +12764,17 @@ pub mod css {
                 CssProperty::PerspectiveOrigin(_) => CssPropertyType::PerspectiveOrigin,
                 CssProperty::TransformOrigin(_) => CssPropertyType::TransformOrigin,
                 CssProperty::BackfaceVisibility(_) => CssPropertyType::BackfaceVisibility,
+                CssProperty::TransitionProperty(_) => CssPropertyType::TransitionProperty,
+                CssProperty::TransitionDuration(_) => CssPropertyType::TransitionDuration,
+                CssProperty::TransitionTimingFunction(_) => CssPropertyType::TransitionTimingFunction,
+                CssProperty::TransitionDelay(_) => CssPropertyType::TransitionDelay,
+                CssProperty::AnimationName(_) => CssPropertyType::AnimationName,
+                CssProperty::AnimationDuration(_) => CssPropertyType::AnimationDuration,
+                CssProperty::AnimationTimingFunction(_) => CssPropertyType::AnimationTimingFunction,
+                CssProperty::AnimationIterationCount(_) => CssPropertyType::AnimationIterationCount,
+                CssProperty::AnimationDirection(_) => CssPropertyType::AnimationDirection,
+                CssProperty::AnimationFillMode(_) => CssPropertyType::AnimationFillMode,
+                CssProperty::AnimationDelay(_) => CssPropertyType::AnimationDelay,
             }
         }
 
@@ -10817,6 +12796,7 @@ pub mod css {
         pub const fn cursor(input: StyleCursor) -> Self { CssProperty::Cursor(StyleCursorValue::Exact(input)) }
         pub const fn display(input: LayoutDisplay) -> Self { CssProperty::Display(LayoutDisplayValue::Exact(input)) }
         pub const fn float(input: LayoutFloat) -> Self { CssProperty::Float(LayoutFloatValue::Exact(input)) }
+        pub const fn clear(input: LayoutClear) -> Self { CssProperty::Clear(LayoutClearValue::Exact(input)) }
         pub const fn box_sizing(input: LayoutBoxSizing) -> Self { CssProperty::BoxSizing(LayoutBoxSizingValue::Exact(input)) }
         pub const fn width(input: LayoutWidth) -> Self { CssProperty::Width(LayoutWidthValue::Exact(input)) }
         pub const fn height(input: LayoutHeight) -> Self { CssProperty::Height(LayoutHeightValue::Exact(input)) }
@@ -10836,6 +12816,12 @@ pub mod css {
         pub const fn justify_content(input: LayoutJustifyContent) -> Self { CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(input)) }
         pub const fn align_items(input: LayoutAlignItems) -> Self { CssProperty::AlignItems(LayoutAlignItemsValue::Exact(input)) }
         pub const fn align_content(input: LayoutAlignContent) -> Self { CssProperty::AlignContent(LayoutAlignContentValue::Exact(input)) }
+        pub const fn grid_template_columns(input: LayoutGridTemplateColumns) -> Self { CssProperty::GridTemplateColumns(LayoutGridTemplateColumnsValue::Exact(input)) }
+        pub const fn grid_template_rows(input: LayoutGridTemplateRows) -> Self { CssProperty::GridTemplateRows(LayoutGridTemplateRowsValue::Exact(input)) }
+        pub const fn grid_column(input: LayoutGridColumn) -> Self { CssProperty::GridColumn(LayoutGridColumnValue::Exact(input)) }
+        pub const fn grid_row(input: LayoutGridRow) -> Self { CssProperty::GridRow(LayoutGridRowValue::Exact(input)) }
+        pub const fn column_gap(input: LayoutColumnGap) -> Self { CssProperty::ColumnGap(LayoutColumnGapValue::Exact(input)) }
+        pub const fn row_gap(input: LayoutRowGap) -> Self { CssProperty::RowGap(LayoutRowGapValue::Exact(input)) }
         pub const fn background_content(input: StyleBackgroundContentVec) -> Self { CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(input)) }
         pub const fn background_position(input: StyleBackgroundPositionVec) -> Self { CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::Exact(input)) }
         pub const fn background_size(input: StyleBackgroundSizeVec) -> Self { CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::Exact(input)) }
@@ -10875,6 +12861,17 @@ pub mod css {
         pub const fn transform_origin(input: StyleTransformOrigin) -> Self { CssProperty::TransformOrigin(StyleTransformOriginValue::Exact(input)) }
         pub const fn perspective_origin(input: StylePerspectiveOrigin) -> Self { CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::Exact(input)) }
         pub const fn backface_visiblity(input: StyleBackfaceVisibility) -> Self { CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::Exact(input)) }
+        pub const fn transition_property(input: StyleTransitionPropertyVec) -> Self { CssProperty::TransitionProperty(StyleTransitionPropertyVecValue::Exact(input)) }
+        pub const fn transition_duration(input: StyleTransitionDurationVec) -> Self { CssProperty::TransitionDuration(StyleTransitionDurationVecValue::Exact(input)) }
+        pub const fn transition_timing_function(input: StyleTransitionTimingFunctionVec) -> Self { CssProperty::TransitionTimingFunction(StyleTransitionTimingFunctionVecValue::Exact(input)) }
+        pub const fn transition_delay(input: StyleTransitionDelayVec) -> Self { CssProperty::TransitionDelay(StyleTransitionDelayVecValue::Exact(input)) }
+        pub const fn animation_name(input: StyleAnimationNameVec) -> Self { CssProperty::AnimationName(StyleAnimationNameVecValue::Exact(input)) }
+        pub const fn animation_duration(input: StyleAnimationDurationVec) -> Self { CssProperty::AnimationDuration(StyleAnimationDurationVecValue::Exact(input)) }
+        pub const fn animation_timing_function(input: StyleTransitionTimingFunctionVec) -> Self { CssProperty::AnimationTimingFunction(StyleTransitionTimingFunctionVecValue::Exact(input)) }
+        pub const fn animation_iteration_count(input: StyleAnimationIterationCountVec) -> Self { CssProperty::AnimationIterationCount(StyleAnimationIterationCountVecValue::Exact(input)) }
+        pub const fn animation_direction(input: StyleAnimationDirectionVec) -> Self { CssProperty::AnimationDirection(StyleAnimationDirectionVecValue::Exact(input)) }
+        pub const fn animation_fill_mode(input: StyleAnimationFillModeVec) -> Self { CssProperty::AnimationFillMode(StyleAnimationFillModeVecValue::Exact(input)) }
+        pub const fn animation_delay(input: StyleAnimationDelayVec) -> Self { CssProperty::AnimationDelay(StyleAnimationDelayVecValue::Exact(input)) }
 
     }
 
@@ -11279,12 +13276,27 @@ pub mod css {
     /// `CssRuleBlock` struct
     
 #[doc(inline)] pub use crate::dll::AzCssRuleBlock as CssRuleBlock;
+    /// One query of an `@media` rule, matches if all conditions match
+    
+#[doc(inline)] pub use crate::dll::AzCssMediaQuery as CssMediaQuery;
+    /// `CssMediaCondition` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssMediaCondition as CssMediaCondition;
+    /// `CssMediaOrientation` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssMediaOrientation as CssMediaOrientation;
+    /// `CssMediaColorScheme` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssMediaColorScheme as CssMediaColorScheme;
     /// `CssDeclaration` struct
     
 #[doc(inline)] pub use crate::dll::AzCssDeclaration as CssDeclaration;
     /// `DynamicCssProperty` struct
     
 #[doc(inline)] pub use crate::dll::AzDynamicCssProperty as DynamicCssProperty;
+    /// Custom property (CSS variable) declaration, i.e. `--accent: #3366ff`
+    
+#[doc(inline)] pub use crate::dll::AzCssCustomProperty as CssCustomProperty;
     /// `CssPath` struct
     
 #[doc(inline)] pub use crate::dll::AzCssPath as CssPath;
@@ -11297,6 +13309,12 @@ pub mod css {
     /// `CssPathPseudoSelector` struct
     
 #[doc(inline)] pub use crate::dll::AzCssPathPseudoSelector as CssPathPseudoSelector;
+    /// Attribute selector such as `[data-state=open]`, matched against the attributes of a node
+    
+#[doc(inline)] pub use crate::dll::AzCssAttributeSelector as CssAttributeSelector;
+    /// `CssAttributeSelectorOp` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssAttributeSelectorOp as CssAttributeSelectorOp;
     /// `CssNthChildSelector` struct
     
 #[doc(inline)] pub use crate::dll::AzCssNthChildSelector as CssNthChildSelector;
@@ -11316,6 +13334,18 @@ pub mod css {
         pub fn from_string(s: String) -> Self { unsafe { crate::dll::AzCss_fromString(s) } }
     }
 
+    /// Font file declared via an `@font-face` rule
+    
+#[doc(inline)] pub use crate::dll::AzCssFontFace as CssFontFace;
+    /// `CssFontFaceStyle` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssFontFaceStyle as CssFontFaceStyle;
+    /// Animation declared via a `@keyframes` rule
+    
+#[doc(inline)] pub use crate::dll::AzCssKeyframes as CssKeyframes;
+    /// Single step (i.e. `50% { opacity: 0.5; }`) of a `@keyframes` rule
+    
+#[doc(inline)] pub use crate::dll::AzCssKeyframe as CssKeyframe;
     /// `CssPropertyType` struct
     
 #[doc(inline)] pub use crate::dll::AzCssPropertyType as CssPropertyType;
@@ -11356,6 +13386,36 @@ pub mod css {
     /// `LayoutAlignContent` struct
     
 #[doc(inline)] pub use crate::dll::AzLayoutAlignContent as LayoutAlignContent;
+    /// `GridTrackBreadth` struct
+    
+#[doc(inline)] pub use crate::dll::AzGridTrackBreadth as GridTrackBreadth;
+    /// `minmax(min, max)` track size
+    
+#[doc(inline)] pub use crate::dll::AzGridMinMax as GridMinMax;
+    /// Size of a single column / row track of a `grid-template-*` declaration
+    
+#[doc(inline)] pub use crate::dll::AzGridTrackSizing as GridTrackSizing;
+    /// `LayoutGridTemplateColumns` struct
+    
+#[doc(inline)] pub use crate::dll::AzLayoutGridTemplateColumns as LayoutGridTemplateColumns;
+    /// `LayoutGridTemplateRows` struct
+    
+#[doc(inline)] pub use crate::dll::AzLayoutGridTemplateRows as LayoutGridTemplateRows;
+    /// Start or end line of a grid item (`auto`, `2` or `span 2`)
+    
+#[doc(inline)] pub use crate::dll::AzGridLine as GridLine;
+    /// `LayoutGridColumn` struct
+    
+#[doc(inline)] pub use crate::dll::AzLayoutGridColumn as LayoutGridColumn;
+    /// `LayoutGridRow` struct
+    
+#[doc(inline)] pub use crate::dll::AzLayoutGridRow as LayoutGridRow;
+    /// `LayoutColumnGap` struct
+    
+#[doc(inline)] pub use crate::dll::AzLayoutColumnGap as LayoutColumnGap;
+    /// `LayoutRowGap` struct
+    
+#[doc(inline)] pub use crate::dll::AzLayoutRowGap as LayoutRowGap;
     /// `LayoutAlignItems` struct
     
 #[doc(inline)] pub use crate::dll::AzLayoutAlignItems as LayoutAlignItems;
@@ -11380,6 +13440,9 @@ pub mod css {
     /// `LayoutFloat` struct
     
 #[doc(inline)] pub use crate::dll::AzLayoutFloat as LayoutFloat;
+    /// Represents a `clear` attribute: on which sides the node may not be placed next to floating nodes that come before it
+    
+#[doc(inline)] pub use crate::dll::AzLayoutClear as LayoutClear;
     /// `LayoutHeight` struct
     
 #[doc(inline)] pub use crate::dll::AzLayoutHeight as LayoutHeight;
@@ -11590,6 +13653,39 @@ pub mod css {
     /// `StyleBackfaceVisibility` struct
     
 #[doc(inline)] pub use crate::dll::AzStyleBackfaceVisibility as StyleBackfaceVisibility;
+    /// Property that is animated by a `transition` (`all` or a single property)
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionProperty as StyleTransitionProperty;
+    /// `StyleTransitionDuration` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionDuration as StyleTransitionDuration;
+    /// `StyleTransitionDelay` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionDelay as StyleTransitionDelay;
+    /// `StyleTransitionTimingFunction` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionTimingFunction as StyleTransitionTimingFunction;
+    /// Control points of a `cubic-bezier(x1, y1, x2, y2)` timing function
+    
+#[doc(inline)] pub use crate::dll::AzStyleCubicBezier as StyleCubicBezier;
+    /// Name of the `@keyframes` rule that an `animation-name` refers to
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationName as StyleAnimationName;
+    /// `StyleAnimationDuration` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDuration as StyleAnimationDuration;
+    /// `StyleAnimationDelay` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDelay as StyleAnimationDelay;
+    /// `StyleAnimationIterationCount` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationIterationCount as StyleAnimationIterationCount;
+    /// `StyleAnimationDirection` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDirection as StyleAnimationDirection;
+    /// `StyleAnimationFillMode` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationFillMode as StyleAnimationFillMode;
     /// `StyleTransform` struct
     
 #[doc(inline)] pub use crate::dll::AzStyleTransform as StyleTransform;
@@ -11632,6 +13728,24 @@ pub mod css {
     /// `LayoutAlignContentValue` struct
     
 #[doc(inline)] pub use crate::dll::AzLayoutAlignContentValue as LayoutAlignContentValue;
+    /// `LayoutGridTemplateColumnsValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzLayoutGridTemplateColumnsValue as LayoutGridTemplateColumnsValue;
+    /// `LayoutGridTemplateRowsValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzLayoutGridTemplateRowsValue as LayoutGridTemplateRowsValue;
+    /// `LayoutGridColumnValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzLayoutGridColumnValue as LayoutGridColumnValue;
+    /// `LayoutGridRowValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzLayoutGridRowValue as LayoutGridRowValue;
+    /// `LayoutColumnGapValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzLayoutColumnGapValue as LayoutColumnGapValue;
+    /// `LayoutRowGapValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzLayoutRowGapValue as LayoutRowGapValue;
     /// `LayoutAlignItemsValue` struct
     
 #[doc(inline)] pub use crate::dll::AzLayoutAlignItemsValue as LayoutAlignItemsValue;
@@ -11656,6 +13770,9 @@ pub mod css {
     /// `LayoutFloatValue` struct
     
 #[doc(inline)] pub use crate::dll::AzLayoutFloatValue as LayoutFloatValue;
+    /// `LayoutClearValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzLayoutClearValue as LayoutClearValue;
     /// `LayoutHeightValue` struct
     
 #[doc(inline)] pub use crate::dll::AzLayoutHeightValue as LayoutHeightValue;
@@ -11824,6 +13941,36 @@ pub mod css {
     /// `StyleBackfaceVisibilityValue` struct
     
 #[doc(inline)] pub use crate::dll::AzStyleBackfaceVisibilityValue as StyleBackfaceVisibilityValue;
+    /// `StyleTransitionPropertyVecValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionPropertyVecValue as StyleTransitionPropertyVecValue;
+    /// `StyleTransitionDurationVecValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionDurationVecValue as StyleTransitionDurationVecValue;
+    /// `StyleTransitionTimingFunctionVecValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionTimingFunctionVecValue as StyleTransitionTimingFunctionVecValue;
+    /// `StyleTransitionDelayVecValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionDelayVecValue as StyleTransitionDelayVecValue;
+    /// `StyleAnimationNameVecValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationNameVecValue as StyleAnimationNameVecValue;
+    /// `StyleAnimationDurationVecValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDurationVecValue as StyleAnimationDurationVecValue;
+    /// `StyleAnimationIterationCountVecValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationIterationCountVecValue as StyleAnimationIterationCountVecValue;
+    /// `StyleAnimationDirectionVecValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDirectionVecValue as StyleAnimationDirectionVecValue;
+    /// `StyleAnimationFillModeVecValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationFillModeVecValue as StyleAnimationFillModeVecValue;
+    /// `StyleAnimationDelayVecValue` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDelayVecValue as StyleAnimationDelayVecValue;
     /// Parsed CSS key-value pair
     
 #[doc(inline)] pub use crate::dll::AzCssProperty as CssProperty;
@@ -11848,7 +13995,9 @@ pub mod widgets {
     use crate::str::String;
     use crate::callbacks::{CallbackType, RefAny};
     use crate::css::ColorU;
-    use crate::vec::NodeDataInlineCssPropertyVec;
+    use crate::vec::{NodeDataInlineCssPropertyVec, StringVec, U32Vec};
+    use crate::menu::MenuPopupPosition;
+    use crate::option::OptionSpreadsheetCellSelection;
     /// `Button` struct
     
 #[doc(inline)] pub use crate::dll::AzButton as Button;
@@ -12038,6 +14187,343 @@ pub mod widgets {
     /// `NumberInputOnValueChangeCallbackType` struct
     
 #[doc(inline)] pub use crate::dll::AzNumberInputOnValueChangeCallbackType as NumberInputOnValueChangeCallbackType;
+    /// `TextEdit` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEdit as TextEdit;
+    impl TextEdit {
+        /// Creates a new `TextEdit` instance.
+        pub fn new(initial_text: String) -> Self { unsafe { crate::dll::AzTextEdit_new(initial_text) } }
+        /// Calls the `TextEdit::set_on_text_change` function.
+        pub fn set_on_text_change(&mut self, data: RefAny, callback: TextEditOnTextChangeCallbackType)  { unsafe { crate::dll::AzTextEdit_setOnTextChange(self, data, callback) } }
+        /// Calls the `TextEdit::with_on_text_change` function.
+        pub fn with_on_text_change(&mut self, data: RefAny, callback: TextEditOnTextChangeCallbackType)  -> crate::widgets::TextEdit { unsafe { crate::dll::AzTextEdit_withOnTextChange(self, data, callback) } }
+        /// Calls the `TextEdit::set_on_focus_lost` function.
+        pub fn set_on_focus_lost(&mut self, data: RefAny, callback: TextEditOnFocusLostCallbackType)  { unsafe { crate::dll::AzTextEdit_setOnFocusLost(self, data, callback) } }
+        /// Calls the `TextEdit::with_on_focus_lost` function.
+        pub fn with_on_focus_lost(&mut self, data: RefAny, callback: TextEditOnFocusLostCallbackType)  -> crate::widgets::TextEdit { unsafe { crate::dll::AzTextEdit_withOnFocusLost(self, data, callback) } }
+        /// Calls the `TextEdit::set_show_line_numbers` function.
+        pub fn set_show_line_numbers(&mut self, show_line_numbers: bool)  { unsafe { crate::dll::AzTextEdit_setShowLineNumbers(self, show_line_numbers) } }
+        /// Calls the `TextEdit::with_show_line_numbers` function.
+        pub fn with_show_line_numbers(&mut self, show_line_numbers: bool)  -> crate::widgets::TextEdit { unsafe { crate::dll::AzTextEdit_withShowLineNumbers(self, show_line_numbers) } }
+        /// Calls the `TextEdit::set_container_style` function.
+        pub fn set_container_style(&mut self, container_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTextEdit_setContainerStyle(self, container_style) } }
+        /// Calls the `TextEdit::with_container_style` function.
+        pub fn with_container_style(&mut self, container_style: NodeDataInlineCssPropertyVec)  -> crate::widgets::TextEdit { unsafe { crate::dll::AzTextEdit_withContainerStyle(self, container_style) } }
+        /// Calls the `TextEdit::set_line_numbers_style` function.
+        pub fn set_line_numbers_style(&mut self, line_numbers_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTextEdit_setLineNumbersStyle(self, line_numbers_style) } }
+        /// Calls the `TextEdit::with_line_numbers_style` function.
+        pub fn with_line_numbers_style(&mut self, line_numbers_style: NodeDataInlineCssPropertyVec)  -> crate::widgets::TextEdit { unsafe { crate::dll::AzTextEdit_withLineNumbersStyle(self, line_numbers_style) } }
+        /// Calls the `TextEdit::set_label_style` function.
+        pub fn set_label_style(&mut self, label_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzTextEdit_setLabelStyle(self, label_style) } }
+        /// Calls the `TextEdit::with_label_style` function.
+        pub fn with_label_style(&mut self, label_style: NodeDataInlineCssPropertyVec)  -> crate::widgets::TextEdit { unsafe { crate::dll::AzTextEdit_withLabelStyle(self, label_style) } }
+        /// Calls the `TextEdit::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzTextEdit_dom(self) } }
+    }
+
+    /// `TextEditStateWrapper` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditStateWrapper as TextEditStateWrapper;
+    /// `TextEditState` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditState as TextEditState;
+    /// `TextEditHistory` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditHistory as TextEditHistory;
+    /// `TextEditSnapshot` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditSnapshot as TextEditSnapshot;
+    /// `TextEditKind` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditKind as TextEditKind;
+    /// `TextEditView` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditView as TextEditView;
+    /// `TextEditOnTextChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditOnTextChange as TextEditOnTextChange;
+    /// `TextEditOnTextChangeCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditOnTextChangeCallback as TextEditOnTextChangeCallback;
+    /// `TextEditOnTextChangeCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditOnTextChangeCallbackType as TextEditOnTextChangeCallbackType;
+    /// `TextEditOnFocusLost` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditOnFocusLost as TextEditOnFocusLost;
+    /// `TextEditOnFocusLostCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditOnFocusLostCallback as TextEditOnFocusLostCallback;
+    /// `TextEditOnFocusLostCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditOnFocusLostCallbackType as TextEditOnFocusLostCallbackType;
+    /// `Slider` struct
+    
+#[doc(inline)] pub use crate::dll::AzSlider as Slider;
+    impl Slider {
+        /// Creates a new `Slider` instance.
+        pub fn new(value: f32, min: f32, max: f32) -> Self { unsafe { crate::dll::AzSlider_new(value, min, max) } }
+        /// Calls the `Slider::set_step` function.
+        pub fn set_step(&mut self, step: f32)  { unsafe { crate::dll::AzSlider_setStep(self, step) } }
+        /// Calls the `Slider::with_step` function.
+        pub fn with_step(&mut self, step: f32)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withStep(self, step) } }
+        /// Calls the `Slider::set_orientation` function.
+        pub fn set_orientation(&mut self, orientation: SliderOrientation)  { unsafe { crate::dll::AzSlider_setOrientation(self, orientation) } }
+        /// Calls the `Slider::with_orientation` function.
+        pub fn with_orientation(&mut self, orientation: SliderOrientation)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withOrientation(self, orientation) } }
+        /// Calls the `Slider::set_tick_count` function.
+        pub fn set_tick_count(&mut self, tick_count: usize)  { unsafe { crate::dll::AzSlider_setTickCount(self, tick_count) } }
+        /// Calls the `Slider::with_tick_count` function.
+        pub fn with_tick_count(&mut self, tick_count: usize)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withTickCount(self, tick_count) } }
+        /// Calls the `Slider::set_show_tick_labels` function.
+        pub fn set_show_tick_labels(&mut self, show_tick_labels: bool)  { unsafe { crate::dll::AzSlider_setShowTickLabels(self, show_tick_labels) } }
+        /// Calls the `Slider::with_show_tick_labels` function.
+        pub fn with_show_tick_labels(&mut self, show_tick_labels: bool)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withShowTickLabels(self, show_tick_labels) } }
+        /// Calls the `Slider::set_on_value_change` function.
+        pub fn set_on_value_change(&mut self, data: RefAny, callback: SliderOnValueChangeCallbackType)  { unsafe { crate::dll::AzSlider_setOnValueChange(self, data, callback) } }
+        /// Calls the `Slider::with_on_value_change` function.
+        pub fn with_on_value_change(&mut self, data: RefAny, callback: SliderOnValueChangeCallbackType)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withOnValueChange(self, data, callback) } }
+        /// Calls the `Slider::set_container_style` function.
+        pub fn set_container_style(&mut self, container_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzSlider_setContainerStyle(self, container_style) } }
+        /// Calls the `Slider::with_container_style` function.
+        pub fn with_container_style(&mut self, container_style: NodeDataInlineCssPropertyVec)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withContainerStyle(self, container_style) } }
+        /// Calls the `Slider::set_track_style` function.
+        pub fn set_track_style(&mut self, track_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzSlider_setTrackStyle(self, track_style) } }
+        /// Calls the `Slider::with_track_style` function.
+        pub fn with_track_style(&mut self, track_style: NodeDataInlineCssPropertyVec)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withTrackStyle(self, track_style) } }
+        /// Calls the `Slider::set_thumb_style` function.
+        pub fn set_thumb_style(&mut self, thumb_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzSlider_setThumbStyle(self, thumb_style) } }
+        /// Calls the `Slider::with_thumb_style` function.
+        pub fn with_thumb_style(&mut self, thumb_style: NodeDataInlineCssPropertyVec)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withThumbStyle(self, thumb_style) } }
+        /// Calls the `Slider::set_tick_style` function.
+        pub fn set_tick_style(&mut self, tick_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzSlider_setTickStyle(self, tick_style) } }
+        /// Calls the `Slider::with_tick_style` function.
+        pub fn with_tick_style(&mut self, tick_style: NodeDataInlineCssPropertyVec)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withTickStyle(self, tick_style) } }
+        /// Calls the `Slider::set_label_style` function.
+        pub fn set_label_style(&mut self, label_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzSlider_setLabelStyle(self, label_style) } }
+        /// Calls the `Slider::with_label_style` function.
+        pub fn with_label_style(&mut self, label_style: NodeDataInlineCssPropertyVec)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withLabelStyle(self, label_style) } }
+        /// Calls the `Slider::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzSlider_dom(self) } }
+    }
+
+    /// `SliderStateWrapper` struct
+    
+#[doc(inline)] pub use crate::dll::AzSliderStateWrapper as SliderStateWrapper;
+    /// `SliderState` struct
+    
+#[doc(inline)] pub use crate::dll::AzSliderState as SliderState;
+    /// `SliderOrientation` struct
+    
+#[doc(inline)] pub use crate::dll::AzSliderOrientation as SliderOrientation;
+    /// `SliderOnValueChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzSliderOnValueChange as SliderOnValueChange;
+    /// `SliderOnValueChangeCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzSliderOnValueChangeCallback as SliderOnValueChangeCallback;
+    /// `SliderOnValueChangeCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzSliderOnValueChangeCallbackType as SliderOnValueChangeCallbackType;
+    /// `DropDown` struct
+    
+#[doc(inline)] pub use crate::dll::AzDropDown as DropDown;
+    impl DropDown {
+        /// Creates a new `DropDown` instance.
+        pub fn new(choices: StringVec) -> Self { unsafe { crate::dll::AzDropDown_new(choices) } }
+        /// Calls the `DropDown::set_selected` function.
+        pub fn set_selected(&mut self, selected: usize)  { unsafe { crate::dll::AzDropDown_setSelected(self, selected) } }
+        /// Calls the `DropDown::with_selected` function.
+        pub fn with_selected(&mut self, selected: usize)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withSelected(self, selected) } }
+        /// Calls the `DropDown::set_disabled_choices` function.
+        pub fn set_disabled_choices(&mut self, disabled_choices: U32Vec)  { unsafe { crate::dll::AzDropDown_setDisabledChoices(self, disabled_choices) } }
+        /// Calls the `DropDown::with_disabled_choices` function.
+        pub fn with_disabled_choices(&mut self, disabled_choices: U32Vec)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withDisabledChoices(self, disabled_choices) } }
+        /// Calls the `DropDown::set_popup_position` function.
+        pub fn set_popup_position(&mut self, popup_position: MenuPopupPosition)  { unsafe { crate::dll::AzDropDown_setPopupPosition(self, popup_position) } }
+        /// Calls the `DropDown::with_popup_position` function.
+        pub fn with_popup_position(&mut self, popup_position: MenuPopupPosition)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withPopupPosition(self, popup_position) } }
+        /// Calls the `DropDown::set_on_choice_change` function.
+        pub fn set_on_choice_change(&mut self, data: RefAny, callback: DropDownOnChoiceChangeCallbackType)  { unsafe { crate::dll::AzDropDown_setOnChoiceChange(self, data, callback) } }
+        /// Calls the `DropDown::with_on_choice_change` function.
+        pub fn with_on_choice_change(&mut self, data: RefAny, callback: DropDownOnChoiceChangeCallbackType)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withOnChoiceChange(self, data, callback) } }
+        /// Calls the `DropDown::set_container_style` function.
+        pub fn set_container_style(&mut self, container_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzDropDown_setContainerStyle(self, container_style) } }
+        /// Calls the `DropDown::with_container_style` function.
+        pub fn with_container_style(&mut self, container_style: NodeDataInlineCssPropertyVec)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withContainerStyle(self, container_style) } }
+        /// Calls the `DropDown::set_label_style` function.
+        pub fn set_label_style(&mut self, label_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzDropDown_setLabelStyle(self, label_style) } }
+        /// Calls the `DropDown::with_label_style` function.
+        pub fn with_label_style(&mut self, label_style: NodeDataInlineCssPropertyVec)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withLabelStyle(self, label_style) } }
+        /// Calls the `DropDown::set_popup_style` function.
+        pub fn set_popup_style(&mut self, popup_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzDropDown_setPopupStyle(self, popup_style) } }
+        /// Calls the `DropDown::with_popup_style` function.
+        pub fn with_popup_style(&mut self, popup_style: NodeDataInlineCssPropertyVec)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withPopupStyle(self, popup_style) } }
+        /// Calls the `DropDown::set_entry_style` function.
+        pub fn set_entry_style(&mut self, entry_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzDropDown_setEntryStyle(self, entry_style) } }
+        /// Calls the `DropDown::with_entry_style` function.
+        pub fn with_entry_style(&mut self, entry_style: NodeDataInlineCssPropertyVec)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withEntryStyle(self, entry_style) } }
+        /// Calls the `DropDown::set_disabled_entry_style` function.
+        pub fn set_disabled_entry_style(&mut self, disabled_entry_style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzDropDown_setDisabledEntryStyle(self, disabled_entry_style) } }
+        /// Calls the `DropDown::with_disabled_entry_style` function.
+        pub fn with_disabled_entry_style(&mut self, disabled_entry_style: NodeDataInlineCssPropertyVec)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withDisabledEntryStyle(self, disabled_entry_style) } }
+        /// Calls the `DropDown::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzDropDown_dom(self) } }
+    }
+
+    /// `DropDownStateWrapper` struct
+    
+#[doc(inline)] pub use crate::dll::AzDropDownStateWrapper as DropDownStateWrapper;
+    /// `DropDownState` struct
+    
+#[doc(inline)] pub use crate::dll::AzDropDownState as DropDownState;
+    /// `DropDownOnChoiceChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzDropDownOnChoiceChange as DropDownOnChoiceChange;
+    /// `DropDownOnChoiceChangeCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzDropDownOnChoiceChangeCallback as DropDownOnChoiceChangeCallback;
+    /// `DropDownOnChoiceChangeCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzDropDownOnChoiceChangeCallbackType as DropDownOnChoiceChangeCallbackType;
+    /// `Spreadsheet` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheet as Spreadsheet;
+    impl Spreadsheet {
+        /// Creates a new `Spreadsheet` instance.
+        pub fn new(state: SpreadsheetState) -> Self { unsafe { crate::dll::AzSpreadsheet_new(state) } }
+        /// Calls the `Spreadsheet::set_on_cells_change` function.
+        pub fn set_on_cells_change(&mut self, data: RefAny, callback: SpreadsheetOnCellsChangeCallbackType)  { unsafe { crate::dll::AzSpreadsheet_setOnCellsChange(self, data, callback) } }
+        /// Calls the `Spreadsheet::with_on_cells_change` function.
+        pub fn with_on_cells_change(&mut self, data: RefAny, callback: SpreadsheetOnCellsChangeCallbackType)  -> crate::widgets::Spreadsheet { unsafe { crate::dll::AzSpreadsheet_withOnCellsChange(self, data, callback) } }
+        /// Calls the `Spreadsheet::set_on_selection_change` function.
+        pub fn set_on_selection_change(&mut self, data: RefAny, callback: SpreadsheetOnSelectionChangeCallbackType)  { unsafe { crate::dll::AzSpreadsheet_setOnSelectionChange(self, data, callback) } }
+        /// Calls the `Spreadsheet::with_on_selection_change` function.
+        pub fn with_on_selection_change(&mut self, data: RefAny, callback: SpreadsheetOnSelectionChangeCallbackType)  -> crate::widgets::Spreadsheet { unsafe { crate::dll::AzSpreadsheet_withOnSelectionChange(self, data, callback) } }
+        /// Calls the `Spreadsheet::set_on_column_resize` function.
+        pub fn set_on_column_resize(&mut self, data: RefAny, callback: SpreadsheetOnColumnResizeCallbackType)  { unsafe { crate::dll::AzSpreadsheet_setOnColumnResize(self, data, callback) } }
+        /// Calls the `Spreadsheet::with_on_column_resize` function.
+        pub fn with_on_column_resize(&mut self, data: RefAny, callback: SpreadsheetOnColumnResizeCallbackType)  -> crate::widgets::Spreadsheet { unsafe { crate::dll::AzSpreadsheet_withOnColumnResize(self, data, callback) } }
+        /// Calls the `Spreadsheet::set_container_style` function.
+        pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec)  { unsafe { crate::dll::AzSpreadsheet_setContainerStyle(self, style) } }
+        /// Calls the `Spreadsheet::with_container_style` function.
+        pub fn with_container_style(&mut self, style: NodeDataInlineCssPropertyVec)  -> crate::widgets::Spreadsheet { unsafe { crate::dll::AzSpreadsheet_withContainerStyle(self, style) } }
+        /// Calls the `Spreadsheet::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzSpreadsheet_dom(self) } }
+    }
+
+    /// `SpreadsheetStateWrapper` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetStateWrapper as SpreadsheetStateWrapper;
+    /// `SpreadsheetState` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetState as SpreadsheetState;
+    impl SpreadsheetState {
+        /// Creates a new `SpreadsheetState` instance.
+        pub fn new() -> Self { unsafe { crate::dll::AzSpreadsheetState_new() } }
+        /// Sets the contents of the cell, an empty string clears the cell
+        pub fn set_cell_content(&mut self, cell: SpreadsheetCellIndex, value: String)  { unsafe { crate::dll::AzSpreadsheetState_setCellContent(self, cell, value) } }
+        /// Calls the `SpreadsheetState::get_cell_content` function.
+        pub fn get_cell_content(&self, cell: SpreadsheetCellIndex)  -> crate::option::OptionString { unsafe { crate::dll::AzSpreadsheetState_getCellContent(self, cell) } }
+        /// Calls the `SpreadsheetState::set_selection` function.
+        pub fn set_selection(&mut self, selection: OptionSpreadsheetCellSelection)  { unsafe { crate::dll::AzSpreadsheetState_setSelection(self, selection) } }
+        /// Selects all cells of the table
+        pub fn select_all(&mut self)  { unsafe { crate::dll::AzSpreadsheetState_selectAll(self) } }
+        /// Calls the `SpreadsheetState::get_column_width` function.
+        pub fn get_column_width(&self, column: usize)  -> f32 { unsafe { crate::dll::AzSpreadsheetState_getColumnWidth(self, column) } }
+        /// Calls the `SpreadsheetState::get_row_height` function.
+        pub fn get_row_height(&self, row: usize)  -> f32 { unsafe { crate::dll::AzSpreadsheetState_getRowHeight(self, row) } }
+        /// Overrides the `default_column_width` for the column
+        pub fn set_column_width(&mut self, column: usize, width: f32)  { unsafe { crate::dll::AzSpreadsheetState_setColumnWidth(self, column, width) } }
+        /// Overrides the `default_row_height` for the row
+        pub fn set_row_height(&mut self, row: usize, height: f32)  { unsafe { crate::dll::AzSpreadsheetState_setRowHeight(self, row, height) } }
+        /// Returns the size of all cells (without the row numbers and column names)
+        pub fn get_table_size(&self)  -> crate::window::LogicalSize { unsafe { crate::dll::AzSpreadsheetState_getTableSize(self) } }
+        /// Returns the rect of the cell, relative to the first cell of the table
+        pub fn get_cell_rect(&self, cell: SpreadsheetCellIndex)  -> crate::window::LogicalRect { unsafe { crate::dll::AzSpreadsheetState_getCellRect(self, cell) } }
+        /// Returns the contents of the selected cells as tab-separated values
+        pub fn get_selection_tsv(&self, selection: SpreadsheetCellSelection)  -> crate::str::String { unsafe { crate::dll::AzSpreadsheetState_getSelectionTsv(self, selection) } }
+        /// Pastes the tab-separated values, starting at the top left cell of the selection. Selects and returns the range of pasted cells.
+        pub fn paste_tsv(&mut self, tsv: String)  -> crate::option::OptionSpreadsheetCellSelection { unsafe { crate::dll::AzSpreadsheetState_pasteTsv(self, tsv) } }
+    }
+
+    /// `SpreadsheetStyle` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetStyle as SpreadsheetStyle;
+    /// Represents the index of a single cell (row + column)
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetCellIndex as SpreadsheetCellIndex;
+    /// Represents a rectangular selection of table cells
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetCellSelection as SpreadsheetCellSelection;
+    impl SpreadsheetCellSelection {
+        /// Creates a new `SpreadsheetCellSelection` instance.
+        pub fn from(row: usize, column: usize) -> Self { unsafe { crate::dll::AzSpreadsheetCellSelection_from(row, column) } }
+        /// Calls the `SpreadsheetCellSelection::to` function.
+        pub fn to(self, row: usize, column: usize)  -> crate::widgets::SpreadsheetCellSelection { unsafe { crate::dll::AzSpreadsheetCellSelection_to(self, row, column) } }
+        /// Calls the `SpreadsheetCellSelection::number_of_rows_selected` function.
+        pub fn number_of_rows_selected(&self)  -> usize { unsafe { crate::dll::AzSpreadsheetCellSelection_numberOfRowsSelected(self) } }
+        /// Calls the `SpreadsheetCellSelection::number_of_columns_selected` function.
+        pub fn number_of_columns_selected(&self)  -> usize { unsafe { crate::dll::AzSpreadsheetCellSelection_numberOfColumnsSelected(self) } }
+        /// Returns the top left cell of the selection
+        pub fn get_top_left(&self)  -> crate::widgets::SpreadsheetCellIndex { unsafe { crate::dll::AzSpreadsheetCellSelection_getTopLeft(self) } }
+        /// Returns the bottom right cell of the selection
+        pub fn get_bottom_right(&self)  -> crate::widgets::SpreadsheetCellIndex { unsafe { crate::dll::AzSpreadsheetCellSelection_getBottomRight(self) } }
+        /// Calls the `SpreadsheetCellSelection::contains` function.
+        pub fn contains(&self, cell: SpreadsheetCellIndex)  -> bool { unsafe { crate::dll::AzSpreadsheetCellSelection_contains(self, cell) } }
+    }
+
+    /// `SpreadsheetSizeOverride` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetSizeOverride as SpreadsheetSizeOverride;
+    /// `SpreadsheetCellContent` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetCellContent as SpreadsheetCellContent;
+    /// `SpreadsheetView` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetView as SpreadsheetView;
+    /// `SpreadsheetRenderWindow` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetRenderWindow as SpreadsheetRenderWindow;
+    /// `SpreadsheetDrag` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetDrag as SpreadsheetDrag;
+    /// `SpreadsheetColumnResize` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetColumnResize as SpreadsheetColumnResize;
+    /// `SpreadsheetCellClick` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetCellClick as SpreadsheetCellClick;
+    /// `SpreadsheetCellEdit` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetCellEdit as SpreadsheetCellEdit;
+    /// `SpreadsheetOnCellsChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetOnCellsChange as SpreadsheetOnCellsChange;
+    /// `SpreadsheetOnCellsChangeCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetOnCellsChangeCallback as SpreadsheetOnCellsChangeCallback;
+    /// `SpreadsheetOnCellsChangeCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetOnCellsChangeCallbackType as SpreadsheetOnCellsChangeCallbackType;
+    /// `SpreadsheetOnSelectionChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetOnSelectionChange as SpreadsheetOnSelectionChange;
+    /// `SpreadsheetOnSelectionChangeCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetOnSelectionChangeCallback as SpreadsheetOnSelectionChangeCallback;
+    /// `SpreadsheetOnSelectionChangeCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetOnSelectionChangeCallbackType as SpreadsheetOnSelectionChangeCallbackType;
+    /// `SpreadsheetOnColumnResize` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetOnColumnResize as SpreadsheetOnColumnResize;
+    /// `SpreadsheetOnColumnResizeCallback` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetOnColumnResizeCallback as SpreadsheetOnColumnResizeCallback;
+    /// `SpreadsheetOnColumnResizeCallbackType` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetOnColumnResizeCallbackType as SpreadsheetOnColumnResizeCallbackType;
 }
 
 pub mod style {
@@ -15333,6 +17819,67 @@ pub mod vec {
     impl_vec!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor, az_menu_item_vec_destructor, AzMenuItemVec_delete);
     impl_vec_clone!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor);
 
+    impl_vec!(AzCssFontFace,  AzCssFontFaceVec,  AzCssFontFaceVecDestructor, az_css_font_face_vec_destructor, AzCssFontFaceVec_delete);
+    impl_vec_clone!(AzCssFontFace,  AzCssFontFaceVec,  AzCssFontFaceVecDestructor);
+
+    impl_vec!(AzCssMediaQuery,  AzCssMediaQueryVec,  AzCssMediaQueryVecDestructor, az_css_media_query_vec_destructor, AzCssMediaQueryVec_delete);
+    impl_vec_clone!(AzCssMediaQuery,  AzCssMediaQueryVec,  AzCssMediaQueryVecDestructor);
+
+    impl_vec!(AzCssMediaCondition,  AzCssMediaConditionVec,  AzCssMediaConditionVecDestructor, az_css_media_condition_vec_destructor, AzCssMediaConditionVec_delete);
+    impl_vec_clone!(AzCssMediaCondition,  AzCssMediaConditionVec,  AzCssMediaConditionVecDestructor);
+
+    impl_vec!(AzGridTrackSizing,  AzGridTrackSizingVec,  AzGridTrackSizingVecDestructor, az_grid_track_sizing_vec_destructor, AzGridTrackSizingVec_delete);
+    impl_vec_clone!(AzGridTrackSizing,  AzGridTrackSizingVec,  AzGridTrackSizingVecDestructor);
+
+    impl_vec!(AzStyleTransitionProperty,  AzStyleTransitionPropertyVec,  AzStyleTransitionPropertyVecDestructor, az_style_transition_property_vec_destructor, AzStyleTransitionPropertyVec_delete);
+    impl_vec_clone!(AzStyleTransitionProperty,  AzStyleTransitionPropertyVec,  AzStyleTransitionPropertyVecDestructor);
+
+    impl_vec!(AzStyleTransitionDuration,  AzStyleTransitionDurationVec,  AzStyleTransitionDurationVecDestructor, az_style_transition_duration_vec_destructor, AzStyleTransitionDurationVec_delete);
+    impl_vec_clone!(AzStyleTransitionDuration,  AzStyleTransitionDurationVec,  AzStyleTransitionDurationVecDestructor);
+
+    impl_vec!(AzStyleTransitionTimingFunction,  AzStyleTransitionTimingFunctionVec,  AzStyleTransitionTimingFunctionVecDestructor, az_style_transition_timing_function_vec_destructor, AzStyleTransitionTimingFunctionVec_delete);
+    impl_vec_clone!(AzStyleTransitionTimingFunction,  AzStyleTransitionTimingFunctionVec,  AzStyleTransitionTimingFunctionVecDestructor);
+
+    impl_vec!(AzStyleTransitionDelay,  AzStyleTransitionDelayVec,  AzStyleTransitionDelayVecDestructor, az_style_transition_delay_vec_destructor, AzStyleTransitionDelayVec_delete);
+    impl_vec_clone!(AzStyleTransitionDelay,  AzStyleTransitionDelayVec,  AzStyleTransitionDelayVecDestructor);
+
+    impl_vec!(AzStyleAnimationName,  AzStyleAnimationNameVec,  AzStyleAnimationNameVecDestructor, az_style_animation_name_vec_destructor, AzStyleAnimationNameVec_delete);
+    impl_vec_clone!(AzStyleAnimationName,  AzStyleAnimationNameVec,  AzStyleAnimationNameVecDestructor);
+
+    impl_vec!(AzStyleAnimationDuration,  AzStyleAnimationDurationVec,  AzStyleAnimationDurationVecDestructor, az_style_animation_duration_vec_destructor, AzStyleAnimationDurationVec_delete);
+    impl_vec_clone!(AzStyleAnimationDuration,  AzStyleAnimationDurationVec,  AzStyleAnimationDurationVecDestructor);
+
+    impl_vec!(AzStyleAnimationIterationCount,  AzStyleAnimationIterationCountVec,  AzStyleAnimationIterationCountVecDestructor, az_style_animation_iteration_count_vec_destructor, AzStyleAnimationIterationCountVec_delete);
+    impl_vec_clone!(AzStyleAnimationIterationCount,  AzStyleAnimationIterationCountVec,  AzStyleAnimationIterationCountVecDestructor);
+
+    impl_vec!(AzStyleAnimationDirection,  AzStyleAnimationDirectionVec,  AzStyleAnimationDirectionVecDestructor, az_style_animation_direction_vec_destructor, AzStyleAnimationDirectionVec_delete);
+    impl_vec_clone!(AzStyleAnimationDirection,  AzStyleAnimationDirectionVec,  AzStyleAnimationDirectionVecDestructor);
+
+    impl_vec!(AzStyleAnimationFillMode,  AzStyleAnimationFillModeVec,  AzStyleAnimationFillModeVecDestructor, az_style_animation_fill_mode_vec_destructor, AzStyleAnimationFillModeVec_delete);
+    impl_vec_clone!(AzStyleAnimationFillMode,  AzStyleAnimationFillModeVec,  AzStyleAnimationFillModeVecDestructor);
+
+    impl_vec!(AzStyleAnimationDelay,  AzStyleAnimationDelayVec,  AzStyleAnimationDelayVecDestructor, az_style_animation_delay_vec_destructor, AzStyleAnimationDelayVec_delete);
+    impl_vec_clone!(AzStyleAnimationDelay,  AzStyleAnimationDelayVec,  AzStyleAnimationDelayVecDestructor);
+
+    impl_vec!(AzCssKeyframe,  AzCssKeyframeVec,  AzCssKeyframeVecDestructor, az_css_keyframe_vec_destructor, AzCssKeyframeVec_delete);
+    impl_vec_clone!(AzCssKeyframe,  AzCssKeyframeVec,  AzCssKeyframeVecDestructor);
+
+    impl_vec!(AzCssKeyframes,  AzCssKeyframesVec,  AzCssKeyframesVecDestructor, az_css_keyframes_vec_destructor, AzCssKeyframesVec_delete);
+    impl_vec_clone!(AzCssKeyframes,  AzCssKeyframesVec,  AzCssKeyframesVecDestructor);
+
+    impl_vec!(AzKeyboardShortcut,  AzKeyboardShortcutVec,  AzKeyboardShortcutVecDestructor, az_keyboard_shortcut_vec_destructor, AzKeyboardShortcutVec_delete);
+    impl_vec_clone!(AzKeyboardShortcut,  AzKeyboardShortcutVec,  AzKeyboardShortcutVecDestructor);
+
+    impl_vec!(AzAccessibilityNode,  AzAccessibilityNodeVec,  AzAccessibilityNodeVecDestructor, az_accessibility_node_vec_destructor, AzAccessibilityNodeVec_delete);
+    impl_vec_clone!(AzAccessibilityNode,  AzAccessibilityNodeVec,  AzAccessibilityNodeVecDestructor);
+
+    impl_vec!(AzTextEditSnapshot,  AzTextEditSnapshotVec,  AzTextEditSnapshotVecDestructor, az_text_edit_snapshot_vec_destructor, AzTextEditSnapshotVec_delete);
+    impl_vec_clone!(AzTextEditSnapshot,  AzTextEditSnapshotVec,  AzTextEditSnapshotVecDestructor);
+    impl_vec!(AzSpreadsheetSizeOverride,  AzSpreadsheetSizeOverrideVec,  AzSpreadsheetSizeOverrideVecDestructor, az_spreadsheet_size_override_vec_destructor, AzSpreadsheetSizeOverrideVec_delete);
+    impl_vec_clone!(AzSpreadsheetSizeOverride,  AzSpreadsheetSizeOverrideVec,  AzSpreadsheetSizeOverrideVecDestructor);
+    impl_vec!(AzSpreadsheetCellContent,  AzSpreadsheetCellContentVec,  AzSpreadsheetCellContentVecDestructor, az_spreadsheet_cell_content_vec_destructor, AzSpreadsheetCellContentVec_delete);
+    impl_vec_clone!(AzSpreadsheetCellContent,  AzSpreadsheetCellContentVec,  AzSpreadsheetCellContentVecDestructor);
+
     impl From<vec::Vec<string::String>> for crate::vec::StringVec {
         fn from(v: vec::Vec<string::String>) -> crate::vec::StringVec {
             let vec: Vec<AzString> = v.into_iter().map(Into::into).collect();
@@ -15342,6 +17889,9 @@ pub mod vec {
     }    /// Wrapper over a Rust-allocated `Vec<AccessibilityState>`
     
 #[doc(inline)] pub use crate::dll::AzAccessibilityStateVec as AccessibilityStateVec;
+    /// Wrapper over a Rust-allocated `Vec<AccessibilityNode>`
+    
+#[doc(inline)] pub use crate::dll::AzAccessibilityNodeVec as AccessibilityNodeVec;
     /// Wrapper over a Rust-allocated `Vec<MenuItem>`
     
 #[doc(inline)] pub use crate::dll::AzMenuItemVec as MenuItemVec;
@@ -15431,6 +17981,9 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `VirtualKeyCode`
     
 #[doc(inline)] pub use crate::dll::AzVirtualKeyCodeVec as VirtualKeyCodeVec;
+    /// Wrapper over a Rust-allocated `Vec<KeyboardShortcut>`
+    
+#[doc(inline)] pub use crate::dll::AzKeyboardShortcutVec as KeyboardShortcutVec;
     /// Wrapper over a Rust-allocated `CascadeInfo`
     
 #[doc(inline)] pub use crate::dll::AzCascadeInfoVec as CascadeInfoVec;
@@ -15446,6 +17999,54 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `Stylesheet`
     
 #[doc(inline)] pub use crate::dll::AzStylesheetVec as StylesheetVec;
+    /// Wrapper over a Rust-allocated `CssMediaQuery`
+    
+#[doc(inline)] pub use crate::dll::AzCssMediaQueryVec as CssMediaQueryVec;
+    /// Wrapper over a Rust-allocated `CssMediaCondition`
+    
+#[doc(inline)] pub use crate::dll::AzCssMediaConditionVec as CssMediaConditionVec;
+    /// Wrapper over a Rust-allocated `CssFontFace`
+    
+#[doc(inline)] pub use crate::dll::AzCssFontFaceVec as CssFontFaceVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionProperty>`
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionPropertyVec as StyleTransitionPropertyVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionDuration>`
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionDurationVec as StyleTransitionDurationVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionTimingFunction>`
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionTimingFunctionVec as StyleTransitionTimingFunctionVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionDelay>`
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionDelayVec as StyleTransitionDelayVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationName>`
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationNameVec as StyleAnimationNameVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationDuration>`
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDurationVec as StyleAnimationDurationVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationIterationCount>`
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationIterationCountVec as StyleAnimationIterationCountVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationDirection>`
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDirectionVec as StyleAnimationDirectionVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationFillMode>`
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationFillModeVec as StyleAnimationFillModeVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationDelay>`
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDelayVec as StyleAnimationDelayVec;
+    /// Wrapper over a Rust-allocated `Vec<CssKeyframe>`
+    
+#[doc(inline)] pub use crate::dll::AzCssKeyframeVec as CssKeyframeVec;
+    /// Wrapper over a Rust-allocated `Vec<CssKeyframes>`
+    
+#[doc(inline)] pub use crate::dll::AzCssKeyframesVec as CssKeyframesVec;
+    /// Wrapper over a Rust-allocated `Vec<GridTrackSizing>`
+    
+#[doc(inline)] pub use crate::dll::AzGridTrackSizingVec as GridTrackSizingVec;
     /// Wrapper over a Rust-allocated `CssRuleBlock`
     
 #[doc(inline)] pub use crate::dll::AzCssRuleBlockVec as CssRuleBlockVec;
@@ -15507,6 +18108,15 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `NodeDataVec`
     
 #[doc(inline)] pub use crate::dll::AzNodeDataVec as NodeDataVec;
+    /// Wrapper over a Rust-allocated `Vec<TextEditSnapshot>`
+    
+#[doc(inline)] pub use crate::dll::AzTextEditSnapshotVec as TextEditSnapshotVec;
+    /// Wrapper over a Rust-allocated `Vec<SpreadsheetSizeOverride>`
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetSizeOverrideVec as SpreadsheetSizeOverrideVec;
+    /// Wrapper over a Rust-allocated `Vec<SpreadsheetCellContent>`
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetCellContentVec as SpreadsheetCellContentVec;
     /// `StyleFontFamilyVecDestructor` struct
     
 #[doc(inline)] pub use crate::dll::AzStyleFontFamilyVecDestructor as StyleFontFamilyVecDestructor;
@@ -15519,6 +18129,12 @@ pub mod vec {
     /// `AccessibilityStateVecDestructorType` struct
     
 #[doc(inline)] pub use crate::dll::AzAccessibilityStateVecDestructorType as AccessibilityStateVecDestructorType;
+    /// `AccessibilityNodeVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzAccessibilityNodeVecDestructor as AccessibilityNodeVecDestructor;
+    /// `AccessibilityNodeVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzAccessibilityNodeVecDestructorType as AccessibilityNodeVecDestructorType;
     /// `MenuItemVecDestructor` struct
     
 #[doc(inline)] pub use crate::dll::AzMenuItemVecDestructor as MenuItemVecDestructor;
@@ -15681,6 +18297,12 @@ pub mod vec {
     /// `VirtualKeyCodeVecDestructorType` struct
     
 #[doc(inline)] pub use crate::dll::AzVirtualKeyCodeVecDestructorType as VirtualKeyCodeVecDestructorType;
+    /// `KeyboardShortcutVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzKeyboardShortcutVecDestructor as KeyboardShortcutVecDestructor;
+    /// `KeyboardShortcutVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzKeyboardShortcutVecDestructorType as KeyboardShortcutVecDestructorType;
     /// `CascadeInfoVecDestructor` struct
     
 #[doc(inline)] pub use crate::dll::AzCascadeInfoVecDestructor as CascadeInfoVecDestructor;
@@ -15711,6 +18333,102 @@ pub mod vec {
     /// `StylesheetVecDestructorType` struct
     
 #[doc(inline)] pub use crate::dll::AzStylesheetVecDestructorType as StylesheetVecDestructorType;
+    /// `CssFontFaceVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssFontFaceVecDestructor as CssFontFaceVecDestructor;
+    /// `CssFontFaceVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssFontFaceVecDestructorType as CssFontFaceVecDestructorType;
+    /// `StyleTransitionPropertyVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionPropertyVecDestructor as StyleTransitionPropertyVecDestructor;
+    /// `StyleTransitionPropertyVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionPropertyVecDestructorType as StyleTransitionPropertyVecDestructorType;
+    /// `StyleTransitionDurationVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionDurationVecDestructor as StyleTransitionDurationVecDestructor;
+    /// `StyleTransitionDurationVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionDurationVecDestructorType as StyleTransitionDurationVecDestructorType;
+    /// `StyleTransitionTimingFunctionVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionTimingFunctionVecDestructor as StyleTransitionTimingFunctionVecDestructor;
+    /// `StyleTransitionTimingFunctionVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionTimingFunctionVecDestructorType as StyleTransitionTimingFunctionVecDestructorType;
+    /// `StyleTransitionDelayVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionDelayVecDestructor as StyleTransitionDelayVecDestructor;
+    /// `StyleTransitionDelayVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleTransitionDelayVecDestructorType as StyleTransitionDelayVecDestructorType;
+    /// `StyleAnimationNameVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationNameVecDestructor as StyleAnimationNameVecDestructor;
+    /// `StyleAnimationNameVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationNameVecDestructorType as StyleAnimationNameVecDestructorType;
+    /// `StyleAnimationDurationVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDurationVecDestructor as StyleAnimationDurationVecDestructor;
+    /// `StyleAnimationDurationVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDurationVecDestructorType as StyleAnimationDurationVecDestructorType;
+    /// `StyleAnimationIterationCountVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationIterationCountVecDestructor as StyleAnimationIterationCountVecDestructor;
+    /// `StyleAnimationIterationCountVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationIterationCountVecDestructorType as StyleAnimationIterationCountVecDestructorType;
+    /// `StyleAnimationDirectionVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDirectionVecDestructor as StyleAnimationDirectionVecDestructor;
+    /// `StyleAnimationDirectionVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDirectionVecDestructorType as StyleAnimationDirectionVecDestructorType;
+    /// `StyleAnimationFillModeVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationFillModeVecDestructor as StyleAnimationFillModeVecDestructor;
+    /// `StyleAnimationFillModeVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationFillModeVecDestructorType as StyleAnimationFillModeVecDestructorType;
+    /// `StyleAnimationDelayVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDelayVecDestructor as StyleAnimationDelayVecDestructor;
+    /// `StyleAnimationDelayVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzStyleAnimationDelayVecDestructorType as StyleAnimationDelayVecDestructorType;
+    /// `CssKeyframeVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssKeyframeVecDestructor as CssKeyframeVecDestructor;
+    /// `CssKeyframeVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssKeyframeVecDestructorType as CssKeyframeVecDestructorType;
+    /// `CssKeyframesVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssKeyframesVecDestructor as CssKeyframesVecDestructor;
+    /// `CssKeyframesVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssKeyframesVecDestructorType as CssKeyframesVecDestructorType;
+    /// `GridTrackSizingVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzGridTrackSizingVecDestructor as GridTrackSizingVecDestructor;
+    /// `GridTrackSizingVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzGridTrackSizingVecDestructorType as GridTrackSizingVecDestructorType;
+    /// `CssMediaQueryVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssMediaQueryVecDestructor as CssMediaQueryVecDestructor;
+    /// `CssMediaQueryVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssMediaQueryVecDestructorType as CssMediaQueryVecDestructorType;
+    /// `CssMediaConditionVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssMediaConditionVecDestructor as CssMediaConditionVecDestructor;
+    /// `CssMediaConditionVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzCssMediaConditionVecDestructorType as CssMediaConditionVecDestructorType;
     /// `CssRuleBlockVecDestructor` struct
     
 #[doc(inline)] pub use crate::dll::AzCssRuleBlockVecDestructor as CssRuleBlockVecDestructor;
@@ -15819,6 +18537,24 @@ pub mod vec {
     /// `NodeDataVecDestructorType` struct
     
 #[doc(inline)] pub use crate::dll::AzNodeDataVecDestructorType as NodeDataVecDestructorType;
+    /// `TextEditSnapshotVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditSnapshotVecDestructor as TextEditSnapshotVecDestructor;
+    /// `TextEditSnapshotVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzTextEditSnapshotVecDestructorType as TextEditSnapshotVecDestructorType;
+    /// `SpreadsheetSizeOverrideVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetSizeOverrideVecDestructor as SpreadsheetSizeOverrideVecDestructor;
+    /// `SpreadsheetSizeOverrideVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetSizeOverrideVecDestructorType as SpreadsheetSizeOverrideVecDestructorType;
+    /// `SpreadsheetCellContentVecDestructor` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetCellContentVecDestructor as SpreadsheetCellContentVecDestructor;
+    /// `SpreadsheetCellContentVecDestructorType` struct
+    
+#[doc(inline)] pub use crate::dll::AzSpreadsheetCellContentVecDestructorType as SpreadsheetCellContentVecDestructorType;
 }
 
 pub mod option {
@@ -16015,6 +18751,42 @@ pub mod option {
     /// `OptionNumberInputOnValueChange` struct
     
 #[doc(inline)] pub use crate::dll::AzOptionNumberInputOnValueChange as OptionNumberInputOnValueChange;
+    /// `OptionTextEditOnTextChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionTextEditOnTextChange as OptionTextEditOnTextChange;
+    /// `OptionTextEditOnFocusLost` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionTextEditOnFocusLost as OptionTextEditOnFocusLost;
+    /// `OptionTextEditKind` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionTextEditKind as OptionTextEditKind;
+    /// `OptionSpreadsheetCellClick` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionSpreadsheetCellClick as OptionSpreadsheetCellClick;
+    /// `OptionSpreadsheetCellEdit` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionSpreadsheetCellEdit as OptionSpreadsheetCellEdit;
+    /// `OptionSliderOnValueChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionSliderOnValueChange as OptionSliderOnValueChange;
+    /// `OptionDropDownOnChoiceChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionDropDownOnChoiceChange as OptionDropDownOnChoiceChange;
+    /// `OptionSpreadsheetOnCellsChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionSpreadsheetOnCellsChange as OptionSpreadsheetOnCellsChange;
+    /// `OptionSpreadsheetOnSelectionChange` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionSpreadsheetOnSelectionChange as OptionSpreadsheetOnSelectionChange;
+    /// `OptionSpreadsheetOnColumnResize` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionSpreadsheetOnColumnResize as OptionSpreadsheetOnColumnResize;
+    /// `OptionSpreadsheetCellSelection` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionSpreadsheetCellSelection as OptionSpreadsheetCellSelection;
+    /// `OptionAccessibilityNode` struct
+    
+#[doc(inline)] pub use crate::dll::AzOptionAccessibilityNode as OptionAccessibilityNode;
     /// `OptionMenuItemIcon` struct
     
 #[doc(inline)] pub use crate::dll::AzOptionMenuItemIcon as OptionMenuItemIcon;
@@ -0,0 +1,486 @@
+//! Platform-neutral snapshot of the accessibility tree of a window
+//!
+//! The tree is built from the laid-out DOMs: every node that has an
+//! `AccessibilityInfo`, is a (non-empty) text node, an image, one of the
+//! built-in widgets or is focusable shows up as an `AccessibilityNode`.
+//! Nodes without any semantic meaning (plain layout divs) are skipped and
+//! their children are attached to the closest accessible ancestor instead.
+//!
+//! The snapshot doesn't depend on any OS API, so it can be fed into an
+//! assistive-technology bridge (AT-SPI, UIA, NSAccessibility) or printed
+//! via `Display` and compared in tests.
+
+use core::fmt;
+use alloc::vec::Vec;
+use alloc::string::String;
+use alloc::collections::btree_map::BTreeMap;
+use azul_css::{AzString, OptionAzString, LayoutDisplay};
+use crate::{
+    callbacks::DomNodeId,
+    dom::{
+        NodeData, NodeType, AccessibilityRole, AccessibilityState,
+        AccessibilityStateVec,
+    },
+    id_tree::{NodeId, NodeDataContainer},
+    styled_dom::{StyledDom, DomId, AzNodeId},
+    ui_solver::{LayoutResult, PositionedRectangle},
+    window::{LogicalRect, OptionVirtualKeyCodeCombo},
+};
+
+/// Class of the container node of the built-in `Button` widget
+const BUTTON_CONTAINER_CLASS: &str = "__azul-native-button-container";
+/// Class of the container node of the built-in `CheckBox` widget
+const CHECKBOX_CONTAINER_CLASS: &str = "__azul-native-checkbox-container";
+/// Class of the node of the `CheckBox` widget that is only visible if checked
+const CHECKBOX_CONTENT_CLASS: &str = "__azul-native-checkbox-content";
+/// Class of the container node of the built-in `TextInput` widget
+const TEXT_INPUT_CONTAINER_CLASS: &str = "__azul-native-text-input-container";
+
+/// Single node in the accessibility tree
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct AccessibilityNode {
+    /// DOM node this accessibility node was created from
+    pub node_id: DomNodeId,
+    /// Explicit role of the node or the role inferred from the node type / widget
+    pub role: AccessibilityRole,
+    /// Name of the node, either explicitly set or the text content of the node
+    pub name: OptionAzString,
+    /// Value of the node, for example the text in a text input
+    pub value: OptionAzString,
+    /// Sorted, deduplicated list of states (focused, focusable, checked, ...)
+    pub states: AccessibilityStateVec,
+    /// Optional keyboard accelerator
+    pub accelerator: OptionVirtualKeyCodeCombo,
+    /// Optional "default action" description
+    pub default_action: OptionAzString,
+    /// Bounds of the node, relative to the window
+    pub bounds: LogicalRect,
+    /// Accessible child nodes, in document order
+    pub children: AccessibilityNodeVec,
+}
+
+impl_vec!(AccessibilityNode, AccessibilityNodeVec, AccessibilityNodeVecDestructor);
+impl_vec_clone!(AccessibilityNode, AccessibilityNodeVec, AccessibilityNodeVecDestructor);
+impl_vec_debug!(AccessibilityNode, AccessibilityNodeVec);
+impl_vec_partialeq!(AccessibilityNode, AccessibilityNodeVec);
+
+impl_option!(AccessibilityNode, OptionAccessibilityNode, copy = false, [Debug, Clone, PartialEq]);
+
+impl AccessibilityNode {
+
+    #[inline]
+    pub fn has_state(&self, state: AccessibilityState) -> bool {
+        self.states.as_slice().contains(&state)
+    }
+
+    /// Returns the node (this node or any descendant) created from the given DOM node
+    pub fn find(&self, node_id: DomNodeId) -> Option<&AccessibilityNode> {
+        if self.node_id == node_id {
+            return Some(self);
+        }
+        self.children.iter().find_map(|c| c.find(node_id))
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter, depth: usize) -> fmt::Result {
+
+        write!(f, "{}{:?}", "  ".repeat(depth), self.role)?;
+
+        if let Some(name) = self.name.as_ref() {
+            write!(f, " {:?}", name.as_str())?;
+        }
+
+        if let Some(value) = self.value.as_ref() {
+            write!(f, " = {:?}", value.as_str())?;
+        }
+
+        if !self.states.is_empty() {
+            let states = self.states.iter().map(|s| format!("{:?}", s)).collect::<Vec<_>>();
+            write!(f, " [{}]", states.join(", "))?;
+        }
+
+        writeln!(f, " {}", self.bounds)?;
+
+        for child in self.children.iter() {
+            child.fmt_indented(f, depth + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Snapshot of the accessibility tree of all DOMs in a window
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct AccessibilityTree {
+    /// Root node, always created from the root node of the root DOM
+    pub root: AccessibilityNode,
+}
+
+impl fmt::Display for AccessibilityTree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.root.fmt_indented(f, 0)
+    }
+}
+
+impl AccessibilityTree {
+
+    /// Builds the accessibility tree for the (already laid out) DOMs of a window,
+    /// descending into the child DOMs of iframes.
+    ///
+    /// Returns `None` if there is no root DOM or the root DOM is empty.
+    pub fn new(layout_results: &[LayoutResult], focused_node: Option<DomNodeId>) -> Option<Self> {
+        let builder = TreeBuilder {
+            doms: layout_results.iter().map(|lr| DomSource {
+                styled_dom: &lr.styled_dom,
+                rects: &lr.rects,
+                iframe_mapping: Some(&lr.iframe_mapping),
+            }).collect(),
+            focused_node,
+        };
+        builder.build()
+    }
+
+    /// Builds the accessibility tree for a single `StyledDom`, given the
+    /// positioned rectangles of its nodes (ignores iframes)
+    pub fn from_styled_dom(
+        styled_dom: &StyledDom,
+        rects: &NodeDataContainer<PositionedRectangle>,
+        focused_node: Option<NodeId>
+    ) -> Option<Self> {
+        let builder = TreeBuilder {
+            doms: vec![DomSource { styled_dom, rects, iframe_mapping: None }],
+            focused_node: focused_node.map(|n| DomNodeId {
+                dom: DomId::ROOT_ID,
+                node: AzNodeId::from_crate_internal(Some(n)),
+            }),
+        };
+        builder.build()
+    }
+
+    /// Returns the accessibility node created from the given DOM node, if any
+    pub fn find(&self, node_id: DomNodeId) -> Option<&AccessibilityNode> {
+        self.root.find(node_id)
+    }
+
+    /// Returns a copy of the node that currently has the keyboard focus, if any
+    #[inline]
+    pub fn get_focused_node_cloned(&self) -> OptionAccessibilityNode {
+        self.get_focused_node().cloned().into()
+    }
+
+    /// Returns the node that currently has the keyboard focus, if any
+    pub fn get_focused_node(&self) -> Option<&AccessibilityNode> {
+        fn find_focused(node: &AccessibilityNode) -> Option<&AccessibilityNode> {
+            if node.has_state(AccessibilityState::Focused) {
+                return Some(node);
+            }
+            node.children.iter().find_map(find_focused)
+        }
+        find_focused(&self.root)
+    }
+}
+
+struct DomSource<'a> {
+    styled_dom: &'a StyledDom,
+    rects: &'a NodeDataContainer<PositionedRectangle>,
+    iframe_mapping: Option<&'a BTreeMap<NodeId, DomId>>,
+}
+
+struct TreeBuilder<'a> {
+    doms: Vec<DomSource<'a>>,
+    focused_node: Option<DomNodeId>,
+}
+
+impl<'a> TreeBuilder<'a> {
+
+    fn build(&self) -> Option<AccessibilityTree> {
+
+        let root_dom = self.doms.get(DomId::ROOT_ID.inner)?;
+        if root_dom.styled_dom.node_data.is_empty() {
+            return None;
+        }
+
+        let role = self.get_role(DomId::ROOT_ID, NodeId::ZERO)
+            .unwrap_or(AccessibilityRole::Client);
+
+        let mut root = self.create_node(DomId::ROOT_ID, NodeId::ZERO, role);
+        if !has_presentational_children(role) {
+            let mut children = Vec::new();
+            self.collect_children(DomId::ROOT_ID, NodeId::ZERO, &mut children);
+            root.children = children.into();
+        }
+
+        Some(AccessibilityTree { root })
+    }
+
+    fn get_node_data(&self, dom_id: DomId, node_id: NodeId) -> &NodeData {
+        &self.doms[dom_id.inner].styled_dom.node_data.as_ref()[node_id.index()]
+    }
+
+    fn collect_children(&self, dom_id: DomId, parent: NodeId, out: &mut Vec<AccessibilityNode>) {
+
+        let dom = &self.doms[dom_id.inner];
+
+        // iframes have no children of their own, continue with the root of the child DOM
+        if let Some(child_dom_id) = dom.iframe_mapping.and_then(|m| m.get(&parent)) {
+            let child_dom_is_laid_out = self.doms
+                .get(child_dom_id.inner)
+                .map(|d| !d.styled_dom.node_data.is_empty())
+                .unwrap_or(false);
+            if child_dom_is_laid_out {
+                self.collect_node(*child_dom_id, NodeId::ZERO, out);
+            }
+            return;
+        }
+
+        let node_hierarchy = dom.styled_dom.node_hierarchy.as_container();
+        for child_id in parent.az_children(&node_hierarchy) {
+            self.collect_node(dom_id, child_id, out);
+        }
+    }
+
+    fn collect_node(&self, dom_id: DomId, node_id: NodeId, out: &mut Vec<AccessibilityNode>) {
+
+        if self.is_hidden(dom_id, node_id) {
+            return;
+        }
+
+        match self.get_role(dom_id, node_id) {
+            Some(role) => {
+                let mut node = self.create_node(dom_id, node_id, role);
+                if !has_presentational_children(role) {
+                    let mut children = Vec::new();
+                    self.collect_children(dom_id, node_id, &mut children);
+                    node.children = children.into();
+                }
+                out.push(node);
+            },
+            // node has no semantic meaning, attach the children to the parent
+            None => self.collect_children(dom_id, node_id, out),
+        }
+    }
+
+    fn is_hidden(&self, dom_id: DomId, node_id: NodeId) -> bool {
+        let styled_dom = self.doms[dom_id.inner].styled_dom;
+        let node_data = self.get_node_data(dom_id, node_id);
+        let styled_node_state = &styled_dom.styled_nodes.as_ref()[node_id.index()].state;
+        styled_dom.get_css_property_cache()
+            .get_display(node_data, &node_id, styled_node_state)
+            .and_then(|d| d.get_property().copied())
+            == Some(LayoutDisplay::None)
+    }
+
+    /// Returns the explicit role of the node or infers the role from the
+    /// widget class / node type. `None` means that the node is skipped.
+    fn get_role(&self, dom_id: DomId, node_id: NodeId) -> Option<AccessibilityRole> {
+
+        let node_data = self.get_node_data(dom_id, node_id);
+
+        if let Some(info) = node_data.get_accessibility_info() {
+            return match info.role {
+                AccessibilityRole::Nothing => None,
+                r => Some(r),
+            };
+        }
+
+        if has_class(node_data, BUTTON_CONTAINER_CLASS) {
+            return Some(AccessibilityRole::PushButton);
+        }
+
+        if has_class(node_data, CHECKBOX_CONTAINER_CLASS) {
+            return Some(AccessibilityRole::CheckButton);
+        }
+
+        if has_class(node_data, TEXT_INPUT_CONTAINER_CLASS) {
+            return Some(AccessibilityRole::Text);
+        }
+
+        match node_data.get_node_type() {
+            NodeType::Text(t) if !t.as_str().trim().is_empty() => Some(AccessibilityRole::StaticText),
+            NodeType::Image(_) => Some(AccessibilityRole::Graphic),
+            _ if node_data.is_focusable() => Some(AccessibilityRole::Grouping),
+            _ => None,
+        }
+    }
+
+    fn create_node(&self, dom_id: DomId, node_id: NodeId, role: AccessibilityRole) -> AccessibilityNode {
+
+        let node_data = self.get_node_data(dom_id, node_id);
+        let info = node_data.get_accessibility_info();
+        let dom_node_id = DomNodeId {
+            dom: dom_id,
+            node: AzNodeId::from_crate_internal(Some(node_id)),
+        };
+
+        let text_content = || {
+            let mut text = String::new();
+            self.get_text_content(dom_id, node_id, &mut text);
+            let text = text.trim();
+            if text.is_empty() { None } else { Some(AzString::from(String::from(text))) }
+        };
+
+        // editable text: the text is the value, the name has to be set explicitly
+        // (same for sliders, the text content are only the tick labels)
+        let name = match info.and_then(|i| i.name.as_ref()) {
+            Some(s) => Some(s.clone()),
+            None if role == AccessibilityRole::Text || role == AccessibilityRole::Slider => None,
+            None if has_presentational_children(role) => text_content(),
+            None => None,
+        };
+
+        let value = match info.and_then(|i| i.value.as_ref()) {
+            Some(s) => Some(s.clone()),
+            None if role == AccessibilityRole::Text => text_content(),
+            None => None,
+        };
+
+        let mut states = info.map(|i| i.states.clone().into_library_owned_vec()).unwrap_or_default();
+        if node_data.is_focusable() {
+            states.push(AccessibilityState::Focusable);
+        }
+        if self.focused_node == Some(dom_node_id) {
+            states.push(AccessibilityState::Focused);
+        }
+        if has_class(node_data, CHECKBOX_CONTAINER_CLASS) && self.is_checkbox_checked(dom_id, node_id) {
+            states.push(AccessibilityState::Checked);
+        }
+        states.sort();
+        states.dedup();
+
+        let bounds = self.doms[dom_id.inner].rects.as_ref()
+            .get(node_id)
+            .map(|r| LogicalRect::new(r.position.get_static_offset(), r.size))
+            .unwrap_or_else(LogicalRect::zero);
+
+        AccessibilityNode {
+            node_id: dom_node_id,
+            role,
+            name: name.into(),
+            value: value.into(),
+            states: states.into(),
+            accelerator: info.map(|i| i.accelerator.clone()).unwrap_or(OptionVirtualKeyCodeCombo::None),
+            default_action: info.map(|i| i.default_action.clone()).unwrap_or(OptionAzString::None),
+            bounds,
+            children: Vec::new().into(),
+        }
+    }
+
+    /// Concatenates the text of all visible text nodes in the subtree, separated by spaces
+    fn get_text_content(&self, dom_id: DomId, node_id: NodeId, text: &mut String) {
+
+        if self.is_hidden(dom_id, node_id) {
+            return;
+        }
+
+        if let NodeType::Text(t) = self.get_node_data(dom_id, node_id).get_node_type() {
+            let t = t.as_str().trim();
+            if !t.is_empty() {
+                if !text.is_empty() {
+                    text.push(' ');
+                }
+                text.push_str(t);
+            }
+        }
+
+        let node_hierarchy = self.doms[dom_id.inner].styled_dom.node_hierarchy.as_container();
+        for child_id in node_id.az_children(&node_hierarchy) {
+            self.get_text_content(dom_id, child_id, text);
+        }
+    }
+
+    /// The `CheckBox` widget toggles the opacity of its content node
+    /// instead of re-rendering the DOM, so the opacity is the checked state
+    fn is_checkbox_checked(&self, dom_id: DomId, node_id: NodeId) -> bool {
+
+        let styled_dom = self.doms[dom_id.inner].styled_dom;
+        let node_hierarchy = styled_dom.node_hierarchy.as_container();
+        let styled_nodes = styled_dom.styled_nodes.as_container();
+        let css_property_cache = styled_dom.get_css_property_cache();
+
+        node_id.az_children(&node_hierarchy).any(|child_id| {
+            let child_data = self.get_node_data(dom_id, child_id);
+            has_class(child_data, CHECKBOX_CONTENT_CLASS) &&
+            css_property_cache
+                .get_opacity(child_data, &child_id, &styled_nodes[child_id].state)
+                .and_then(|o| o.get_property())
+                .map(|o| o.inner.normalized() > 0.0)
+                .unwrap_or(false)
+        })
+    }
+}
+
+fn has_class(node_data: &NodeData, class: &str) -> bool {
+    node_data.get_ids_and_classes().iter().any(|i| i.as_class() == Some(class))
+}
+
+/// Roles whose children are not exposed separately, their text
+/// content is used as the name (or value) of the node instead
+fn has_presentational_children(role: AccessibilityRole) -> bool {
+    use self::AccessibilityRole::*;
+    match role {
+        PushButton | CheckButton | RadioButton | MenuItem | Link |
+        StaticText | Text | Graphic | Slider => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn test_accessibility_tree() {
+
+    use azul_css::{Css, CssProperty, StyleOpacity};
+    use crate::dom::{Dom, IdOrClass, NodeDataInlineCssProperty, TabIndex};
+
+    fn class(s: &'static str) -> crate::dom::IdOrClassVec {
+        vec![IdOrClass::Class(s.into())].into()
+    }
+
+    let mut dom = Dom::body()
+    .with_children(vec![
+        Dom::text("Settings"),
+        Dom::div()
+        .with_children(vec![
+            Dom::div()
+            .with_ids_and_classes(class(BUTTON_CONTAINER_CLASS))
+            .with_tab_index(TabIndex::Auto)
+            .with_children(vec![Dom::text("OK")].into()),
+            Dom::div()
+            .with_ids_and_classes(class(CHECKBOX_CONTAINER_CLASS))
+            .with_tab_index(TabIndex::Auto)
+            .with_children(vec![
+                Dom::div()
+                .with_ids_and_classes(class(CHECKBOX_CONTENT_CLASS))
+                .with_inline_css_props(vec![NodeDataInlineCssProperty::Normal(
+                    CssProperty::const_opacity(StyleOpacity::const_new(100))
+                )].into())
+            ].into()),
+        ].into()),
+        Dom::div()
+        .with_ids_and_classes(class(TEXT_INPUT_CONTAINER_CLASS))
+        .with_tab_index(TabIndex::Auto)
+        .with_children(vec![Dom::div(), Dom::text("hello")].into()),
+        Dom::div()
+        .with_inline_css_props(vec![NodeDataInlineCssProperty::Normal(
+            CssProperty::Display(LayoutDisplay::None.into())
+        )].into())
+        .with_children(vec![Dom::text("hidden")].into()),
+    ].into());
+
+    let styled_dom = StyledDom::new(&mut dom, &mut Css::empty());
+    let rects = NodeDataContainer::new(vec![PositionedRectangle::default(); styled_dom.node_data.len()]);
+
+    // body (0), text (1), div (2), button (3), "OK" (4), checkbox (5), ...
+    let tree = AccessibilityTree::from_styled_dom(&styled_dom, &rects, Some(NodeId::new(3))).unwrap();
+
+    let expected = [
+        "Client 0x0 @ (0, 0)",
+        "  StaticText \"Settings\" 0x0 @ (0, 0)",
+        "  PushButton \"OK\" [Focused, Focusable] 0x0 @ (0, 0)",
+        "  CheckButton [Checked, Focusable] 0x0 @ (0, 0)",
+        "  Text = \"hello\" [Focusable] 0x0 @ (0, 0)",
+        "",
+    ].join("\n");
+
+    assert_eq!(tree.to_string(), expected);
+    assert_eq!(tree.get_focused_node().map(|n| n.role), Some(AccessibilityRole::PushButton));
+}
@@ -43,8 +43,9 @@ pub struct AppConfig {
     /// If this is set to `true` (the default), a backtrace + error information
     /// gets logged to stdout and the logging file (only if logging is enabled).
     pub enable_logging_on_panic: bool,
-    /// (STUB) Whether keyboard navigation should be enabled (default: true).
-    /// Currently not implemented.
+    /// Whether the focus can be moved between focusable nodes with `Tab` /
+    /// `Shift + Tab` (default: true). The order is determined by the DOM order
+    /// and the `TabIndex` of the nodes, see `StyledDom::get_tab_order()`.
     pub enable_tab_navigation: bool,
     /// External callbacks to create a thread or get the curent time
     pub system_callbacks: ExternalSystemCallbacks,
@@ -529,6 +530,9 @@ pub struct RendererResources {
     /// (in this case "Arial" on Windows and "Helvetica" on Mac,
     /// because the fonts are loaded in fallback-order)
     pub font_families_map: FastHashMap<StyleFontFamiliesHash, StyleFontFamilyHash>,
+    /// Map from the calculated families vec to all fonts that are used for
+    /// rendering glyphs which are missing in the first font
+    pub font_fallback_chains: FastHashMap<StyleFontFamiliesHash, FontFallbackChain>,
     /// Same as AzString -> ImageId, but for fonts, i.e. "Roboto" -> FontId(9)
     pub font_id_map: FastHashMap<StyleFontFamilyHash, FontKey>,
     /// All font keys currently active in the RenderApi
@@ -544,6 +548,7 @@ impl Default for RendererResources {
             currently_registered_images: FastHashMap::default(),
             last_frame_registered_images: FastHashMap::default(),
             font_families_map: FastHashMap::default(),
+            font_fallback_chains: FastHashMap::default(),
             font_id_map: FastHashMap::default(),
             currently_registered_fonts: FastHashMap::default(),
             last_frame_registered_fonts: FastHashMap::default(),
@@ -553,6 +558,38 @@ impl Default for RendererResources {
 
 impl RendererResources {
 
+    /// Returns the fonts (in fallback order) that should be used to render
+    /// the text of a node with the given `font-family`, the first font is the
+    /// same as the one in the `font_families_map`
+    pub fn get_font_fallback_chain(&self, css_font_families_hash: &StyleFontFamiliesHash) -> Vec<(StyleFontFamilyHash, FontKey)> {
+        match self.font_fallback_chains.get(css_font_families_hash) {
+            Some(fallback_chain) => {
+                fallback_chain.fonts.iter()
+                .filter_map(|font_family_hash| Some((*font_family_hash, *self.font_id_map.get(font_family_hash)?)))
+                .collect()
+            },
+            None => {
+                self.font_families_map.get(css_font_families_hash)
+                .and_then(|font_family_hash| Some((*font_family_hash, *self.font_id_map.get(font_family_hash)?)))
+                .into_iter()
+                .collect()
+            }
+        }
+    }
+
+    /// Returns the font instance key of the font at `fallback_font_index`
+    /// in the fallback chain of the `font-family` for the given font size
+    pub fn get_fallback_font_instance_key(
+        &self,
+        css_font_families_hash: &StyleFontFamiliesHash,
+        fallback_font_index: usize,
+        font_size: Au,
+    ) -> Option<FontInstanceKey> {
+        let (_, font_key) = self.get_font_fallback_chain(css_font_families_hash).get(fallback_font_index).copied()?;
+        let (_, font_instances) = self.currently_registered_fonts.get(&font_key)?;
+        font_instances.get(&font_size).copied()
+    }
+
     /// Updates the internal cache, adds `ResourceUpdate::Remove()` to the `all_resource_updates`
     #[cfg(feature = "multithreading")]
     pub fn do_gc(&mut self, all_resource_updates: &mut Vec<ResourceUpdate>) {
@@ -613,6 +650,14 @@ impl RendererResources {
             self.font_families_map.remove(&f); // font family does not exist anymore
         }
 
+        let font_id_map = &self.font_id_map;
+        for fallback_chain in self.font_fallback_chains.values_mut() {
+            fallback_chain.fonts.retain(|font_family| font_id_map.contains_key(font_family));
+        }
+
+        let font_families_map = &self.font_families_map;
+        self.font_fallback_chains.retain(|font_families, _| font_families_map.contains_key(font_families));
+
         // Reset the GC for the next cycle
         //
         // NOTE: This system will retain fonts / images for one frame
@@ -664,6 +709,18 @@ pub struct ImageMask {
 
 impl_option!(ImageMask, OptionImageMask, copy = false, [Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash]);
 
+/// Fonts that are used to render the glyphs of a `font-family`, in the
+/// order in which they are tried for each character
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FontFallbackChain {
+    /// All fonts of the `font-family` that could be loaded, followed by
+    /// the system fallback fonts that were loaded for missing glyphs
+    pub fonts: Vec<StyleFontFamilyHash>,
+    /// Characters that the system fonts were already searched for, so
+    /// that characters which no font can render aren't searched on every frame
+    pub searched_chars: FastBTreeSet<char>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ImmediateFontId {
     Resolved((StyleFontFamilyHash, FontKey)),
@@ -1092,6 +1149,12 @@ pub struct Words {
     pub internal_str: AzString,
     /// `internal_chars` is used in order to enable copy-paste (since taking a sub-string isn't possible using UTF-8)
     pub internal_chars: U32Vec,
+    /// Resolved bidi embedding level (UAX #9) of each character in `internal_chars`.
+    /// Empty if the text only contains left-to-right characters (fast path)
+    pub bidi_levels: U8Vec,
+    /// Embedding level of the paragraph that each character in `internal_chars` belongs to,
+    /// empty if `bidi_levels` is empty
+    pub bidi_paragraph_levels: U8Vec,
 }
 
 impl Words {
@@ -1107,6 +1170,36 @@ impl Words {
     pub fn get_char(&self, idx: usize) -> Option<char> {
         self.internal_chars.as_ref().get(idx).and_then(|c| core::char::from_u32(*c))
     }
+
+    /// Returns whether the text contains any right-to-left characters
+    #[inline]
+    pub fn has_rtl(&self) -> bool {
+        !self.bidi_levels.is_empty()
+    }
+
+    /// Returns the bidi embedding level of the character at `idx` (0 = left-to-right)
+    #[inline]
+    pub fn get_bidi_level(&self, idx: usize) -> u8 {
+        self.bidi_levels.as_ref().get(idx).copied().unwrap_or(0)
+    }
+
+    /// Returns the embedding level of the paragraph that the character at `idx` belongs to
+    #[inline]
+    pub fn get_paragraph_bidi_level(&self, idx: usize) -> u8 {
+        self.bidi_paragraph_levels.as_ref().get(idx).copied().unwrap_or(0)
+    }
+
+    /// Returns the bidi embedding level of a word (= level of its first character)
+    #[inline]
+    pub fn get_word_bidi_level(&self, word: &Word) -> u8 {
+        self.get_bidi_level(word.start)
+    }
+
+    /// Returns whether the word has to be laid out right-to-left
+    #[inline]
+    pub fn is_rtl_word(&self, word: &Word) -> bool {
+        self.get_word_bidi_level(word) % 2 == 1
+    }
 }
 
 /// Section of a certain type
@@ -1139,6 +1232,26 @@ pub enum WordType {
     Return,
     /// Space character
     Space,
+    /// Non-breaking space (`U+00A0`, `U+2007` or `U+202F`): advances
+    /// like a space, but the line may not be broken at this position
+    NonBreakingSpace,
+    /// Zero-width space (`U+200B`): invisible, but the line may be broken here
+    ZeroWidthSpace,
+    /// Zero-length item (`start == end`) between two words that are not separated by
+    /// whitespace, but where the line may be broken (UAX #14), i.e. between CJK characters
+    BreakOpportunity,
+}
+
+impl WordType {
+    /// Returns whether the line may be broken directly after an item of this type
+    #[inline]
+    pub fn is_break_opportunity(&self) -> bool {
+        use self::WordType::*;
+        match self {
+            Space | Tab | Return | ZeroWidthSpace | BreakOpportunity => true,
+            Word | NonBreakingSpace => false,
+        }
+    }
 }
 
 /// A paragraph of words that are shaped and scaled (* but not yet layouted / positioned*!)
@@ -1339,6 +1452,9 @@ pub struct GlyphInfo {
     pub size: Advance,
     pub placement: Placement,
     pub attachment: Attachment,
+    /// Index of the font in the font fallback chain that was used
+    /// to shape this glyph (0 = first font of the `font-family`)
+    pub fallback_font_index: usize,
 }
 
 #[cfg(feature = "multithreading")]
@@ -1389,6 +1505,8 @@ pub fn get_inline_text(words: &Words, shaped_words: &ShapedWords, word_positions
 
                     // most words are less than 16 chars, avg length of an english word is 4.7 chars
                     let mut all_glyphs_in_this_word = Vec::<InlineGlyph>::with_capacity(16);
+                    // horizontal advance of each glyph (marks: advance of their base glyph)
+                    let mut glyph_advances = Vec::<f32>::with_capacity(16);
                     let mut x_pos_in_word_px = 0.0;
 
                     // all words only store the unscaled horizontal advance + horizontal kerning
@@ -1399,39 +1517,51 @@ pub fn get_inline_text(words: &Words, shaped_words: &ShapedWords, word_positions
 
                         // if the character is a mark, the mark displacement has to be added ON TOP OF the existing displacement
                         // the origin should be relative to the word, not the final text
-                        let (letter_spacing_for_glyph, origin) = match glyph_info.attachment {
+                        let glyph_advance_x = glyph_info.size.get_x_advance_scaled(units_per_em, font_size_px);
+                        let kerning_x = glyph_info.size.get_kerning_scaled(units_per_em, font_size_px);
+
+                        let (letter_spacing_for_glyph, origin, mirror_advance) = match glyph_info.attachment {
                             Attachment::None => {
-                                (letter_spacing_px, LogicalPosition::new(x_pos_in_word_px + displacement.x, displacement.y))
+                                (letter_spacing_px, LogicalPosition::new(x_pos_in_word_px + displacement.x, displacement.y), glyph_advance_x)
                             },
                             Attachment::MarkAnchor(MarkAnchorPlacement { base_glyph_index, .. }) => {
                                 let anchor = &all_glyphs_in_this_word[base_glyph_index];
-                                (0.0, anchor.bounds.origin + displacement) // TODO: wrong
+                                (0.0, anchor.bounds.origin + displacement, glyph_advances[base_glyph_index]) // TODO: wrong
                             },
                             Attachment::MarkOverprint(index) => {
                                 let anchor = &all_glyphs_in_this_word[index];
-                                (0.0, anchor.bounds.origin + displacement)
+                                (0.0, anchor.bounds.origin + displacement, glyph_advances[index])
                             },
                             Attachment::CursiveAnchor(CursiveAnchorPlacement { exit_glyph_index, .. }) => {
                                 let anchor = &all_glyphs_in_this_word[exit_glyph_index];
-                                (0.0, anchor.bounds.origin + displacement) // TODO: wrong
+                                (0.0, anchor.bounds.origin + displacement, glyph_advances[exit_glyph_index]) // TODO: wrong
                             },
                         };
 
                         let glyph_scale_x = glyph_info.size.get_x_size_scaled(units_per_em, font_size_px);
                         let glyph_scale_y = glyph_info.size.get_y_size_scaled(units_per_em, font_size_px);
 
-                        let glyph_advance_x = glyph_info.size.get_x_advance_scaled(units_per_em, font_size_px);
-                        let kerning_x = glyph_info.size.get_kerning_scaled(units_per_em, font_size_px);
-
                         let inline_char = InlineGlyph {
                             bounds: LogicalRect::new(origin, LogicalSize::new(glyph_scale_x, glyph_scale_y)),
                             unicode_codepoint: glyph_info.glyph.unicode_codepoint,
                             glyph_index: glyph_info.glyph.glyph_index as u32,
+                            fallback_font_index: glyph_info.fallback_font_index,
                         };
 
                         x_pos_in_word_px += glyph_advance_x + kerning_x + letter_spacing_for_glyph;
 
                         all_glyphs_in_this_word.push(inline_char);
+                        glyph_advances.push(mirror_advance);
+                    }
+
+                    // The glyphs are stored in logical order (so that hit-testing returns
+                    // logical character indices), but right-to-left words have to be
+                    // displayed mirrored, starting at the right edge of the word
+                    let is_rtl = words.is_rtl_word(word);
+                    if is_rtl {
+                        for (glyph, advance) in all_glyphs_in_this_word.iter_mut().zip(glyph_advances.iter()) {
+                            glyph.bounds.origin.x = x_pos_in_word_px - glyph.bounds.origin.x - advance;
+                        }
                     }
 
                     let inline_word = InlineWord::Word(InlineTextContents {
@@ -1440,6 +1570,7 @@ pub fn get_inline_text(words: &Words, shaped_words: &ShapedWords, word_positions
                             word_position.position,
                             word_position.size
                         ),
+                        is_rtl,
                     });
 
                     Some(inline_word)
@@ -1447,6 +1578,10 @@ pub fn get_inline_text(words: &Words, shaped_words: &ShapedWords, word_positions
                 WordType::Tab => Some(InlineWord::Tab),
                 WordType::Return => Some(InlineWord::Return),
                 WordType::Space => Some(InlineWord::Space),
+                WordType::NonBreakingSpace => Some(InlineWord::Space),
+                WordType::ZeroWidthSpace => Some(InlineWord::Space),
+                // not a character, only a hint for the line breaking
+                WordType::BreakOpportunity => None,
             }
         }).collect::<Vec<InlineWord>>();
 
@@ -1629,6 +1764,43 @@ pub fn add_fonts_and_images(
     );
 }
 
+/// Scans the text in the `StyledDom` for characters that no font of the
+/// `font-family` can render and loads system fonts for these characters
+/// (per-glyph font fallback). Has to be called after `add_fonts_and_images`.
+#[cfg(feature = "multithreading")]
+pub fn add_fallback_fonts(
+    renderer_resources: &mut RendererResources,
+    fc_cache: &FcFontCache,
+    render_api_namespace: IdNamespace,
+    all_resource_updates: &mut Vec<ResourceUpdate>,
+    styled_dom: &StyledDom,
+    load_font_fn: LoadFontFn,
+    parse_font_fn: ParseFontFn,
+    font_has_glyph_fn: FontHasGlyphFn,
+) {
+    let missing_glyphs = styled_dom.scan_for_missing_glyphs(&renderer_resources, font_has_glyph_fn);
+    if missing_glyphs.is_empty() {
+        return;
+    }
+
+    let add_font_resource_updates = build_add_fallback_font_resource_updates(
+        renderer_resources,
+        fc_cache,
+        render_api_namespace,
+        &missing_glyphs,
+        load_font_fn,
+        parse_font_fn,
+        font_has_glyph_fn,
+    );
+
+    add_resources(
+        renderer_resources,
+        all_resource_updates,
+        add_font_resource_updates,
+        Vec::new(),
+    );
+}
+
 pub fn font_size_to_au(font_size: StyleFontSize) -> Au {
     use crate::ui_solver::DEFAULT_FONT_SIZE_PX;
     Au::from_px(font_size.inner.to_pixels(DEFAULT_FONT_SIZE_PX as f32))
@@ -2013,61 +2185,19 @@ pub fn build_add_font_resource_updates(
 
     'outer: for (im_font_id, font_sizes) in fonts_in_dom {
 
-        macro_rules! insert_font_instances {($font_family_hash:expr, $font_key:expr, $font_size:expr) => ({
-
-            let font_instance_key_exists = renderer_resources.currently_registered_fonts
-                .get(&$font_key)
-                .and_then(|(_, font_instances)| font_instances.get(&$font_size))
-                .is_some() || font_instances_added_this_frame.contains(&($font_key, $font_size));
-
-            if !font_instance_key_exists {
-
-                let font_instance_key = FontInstanceKey::unique(id_namespace);
-
-                // For some reason the gamma is way to low on Windows
-                #[cfg(target_os = "windows")]
-                let platform_options = FontInstancePlatformOptions {
-                    gamma: 300,
-                    contrast: 100,
-                    cleartype_level: 100,
-                };
-
-                #[cfg(target_os = "linux")]
-                let platform_options = FontInstancePlatformOptions {
-                    lcd_filter: FontLCDFilter::Default,
-                    hinting: FontHinting::Normal,
-                };
-
-                #[cfg(target_os = "macos")]
-                let platform_options = FontInstancePlatformOptions::default();
-
-                #[cfg(target_arch = "wasm32")]
-                let platform_options = FontInstancePlatformOptions::default();
-
-                let options = FontInstanceOptions {
-                    render_mode: FontRenderMode::Subpixel,
-                    flags: 0 | FONT_INSTANCE_FLAG_NO_AUTOHINT,
-                    .. Default::default()
-                };
-
-                font_instances_added_this_frame.insert(($font_key, $font_size));
-                resource_updates.push(($font_family_hash, AddFontMsg::Instance(AddFontInstance {
-                    key: font_instance_key,
-                    font_key: $font_key,
-                    glyph_size: $font_size,
-                    options: Some(options),
-                    platform_options: Some(platform_options),
-                    variations: alloc::vec::Vec::new(),
-                }, $font_size)));
-            }
-        })}
-
         match im_font_id {
             ImmediateFontId::Resolved((font_family_hash, font_id)) => {
                 // nothing to do, font is already added,
                 // just insert the missing font instances
                 for font_size in font_sizes.iter() {
-                    insert_font_instances!(*font_family_hash, *font_id, *font_size);
+                    resource_updates.extend(build_add_font_instance_msg(
+                        renderer_resources,
+                        &mut font_instances_added_this_frame,
+                        id_namespace,
+                        *font_family_hash,
+                        *font_id,
+                        *font_size,
+                    ));
                 }
             },
             ImmediateFontId::Unresolved(style_font_families) => {
@@ -2080,71 +2210,337 @@ pub fn build_add_font_resource_updates(
                 // - vec!["sans", "Times New Roman"]
                 // ... will resolve to the same font instead of creating two fonts
 
-                // If there is no font key, that means there's also no font instances
-                let mut font_family_hash = None;
                 let font_families_hash = StyleFontFamiliesHash::new(style_font_families.as_ref());
 
-                // Find the first font that can be loaded and parsed
-                'inner: for family in style_font_families.as_ref().iter() {
+                // Load all fonts that can be loaded and parsed: the first font is
+                // used for layout, the other ones for glyphs missing in the first font
+                let mut fallback_chain = Vec::new();
+                let mut fallback_font_keys = Vec::new();
 
-                    let current_family_hash = StyleFontFamilyHash::new(&family);
+                for family in style_font_families.as_ref().iter() {
 
-                    if let Some(font_id) = renderer_resources.font_id_map.get(&current_family_hash) {
-                        // font key already exists
-                        for font_size in font_sizes {
-                            insert_font_instances!(current_family_hash, *font_id, *font_size);
-                        }
-                        continue 'outer;
+                    let current_family_hash = StyleFontFamilyHash::new(&family);
+                    if fallback_chain.contains(&current_family_hash) {
+                        continue;
                     }
 
-                    let font_ref = match family {
-                        StyleFontFamily::Ref(r) => r.clone(), // Clone the FontRef
-                        other => {
-
-                            // Load and parse the font
-                            let font_data = match (font_source_load_fn)(&other, fc_cache) {
-                                Some(s) => s,
-                                None => continue 'inner,
-                            };
+                    let font_key = match resolve_font_family(
+                        renderer_resources,
+                        &mut resource_updates,
+                        fc_cache,
+                        id_namespace,
+                        family,
+                        font_source_load_fn,
+                        parse_font_fn,
+                    ) {
+                        Some((font_key, _)) => font_key,
+                        None => continue,
+                    };
 
-                            let font_ref = match (parse_font_fn)(font_data) {
-                                Some(s) => s,
-                                None => continue 'inner,
-                            };
+                    // two font names resolved to the same font
+                    if fallback_font_keys.contains(&font_key) {
+                        continue;
+                    }
 
-                            font_ref
-                        }
-                    };
+                    // Insert font sizes for the font key
+                    for font_size in font_sizes {
+                        resource_updates.extend(build_add_font_instance_msg(
+                            renderer_resources,
+                            &mut font_instances_added_this_frame,
+                            id_namespace,
+                            current_family_hash,
+                            font_key,
+                            *font_size,
+                        ));
+                    }
 
-                    // font loaded properly
-                    font_family_hash = Some((current_family_hash, font_ref));
-                    break 'inner;
+                    fallback_chain.push(current_family_hash);
+                    fallback_font_keys.push(font_key);
                 }
 
-                let (font_family_hash, font_ref) = match font_family_hash {
+                let font_family_hash = match fallback_chain.first() {
                     None => continue 'outer, // No font could be loaded, try again next frame
-                    Some(s) => s,
+                    Some(s) => *s,
                 };
 
-                // Generate a new font key, store the mapping between hash and font key
-                let font_key = FontKey::unique(id_namespace);
-                let add_font_msg = AddFontMsg::Font(font_key, font_family_hash, font_ref);
-
-                renderer_resources.font_id_map.insert(font_family_hash, font_key);
                 renderer_resources.font_families_map.insert(font_families_hash, font_family_hash);
-                resource_updates.push((font_family_hash, add_font_msg));
+                renderer_resources.font_fallback_chains.insert(font_families_hash, FontFallbackChain {
+                    fonts: fallback_chain,
+                    searched_chars: FastBTreeSet::new(),
+                });
+            }
+        }
+    }
 
-                // Insert font sizes for the newly generated font key
-                for font_size in font_sizes {
-                    insert_font_instances!(font_family_hash, font_key, *font_size);
-                }
+    resource_updates
+}
+
+/// Names of the system fonts that are searched (in this order) for glyphs
+/// which are not contained in any font of the `font-family`
+pub const SYSTEM_FALLBACK_FONTS: &[&str] = &[
+    "sans-serif",
+    "serif",
+    // emoji
+    "Noto Color Emoji",
+    "Apple Color Emoji",
+    "Segoe UI Emoji",
+    "Segoe UI Symbol",
+    // CJK
+    "Noto Sans CJK SC",
+    "PingFang SC",
+    "Hiragino Sans",
+    "Microsoft YaHei",
+    "Malgun Gothic",
+    // math and symbols
+    "Noto Sans Math",
+    "Noto Sans Symbols",
+    "Noto Sans Symbols2",
+    "Cambria Math",
+    "STIXGeneral",
+    "DejaVu Sans",
+];
+
+// function to determine whether the (parsed) font contains a glyph for the given character
+pub type FontHasGlyphFn = fn(&FontRef, char) -> bool;
+
+/// Given the characters that no font in the fallback chain of a `font-family`
+/// can render, searches the `SYSTEM_FALLBACK_FONTS` for these characters and returns
+/// `AddFont` and `AddFontInstance`s for the fonts that are appended to the fallback chain.
+pub fn build_add_fallback_font_resource_updates(
+    renderer_resources: &mut RendererResources,
+    fc_cache: &FcFontCache,
+    id_namespace: IdNamespace,
+    missing_glyphs: &FastHashMap<StyleFontFamiliesHash, (FastBTreeSet<char>, FastBTreeSet<Au>)>,
+    font_source_load_fn: LoadFontFn,
+    parse_font_fn: ParseFontFn,
+    font_has_glyph_fn: FontHasGlyphFn,
+) -> Vec<(StyleFontFamilyHash, AddFontMsg)> {
+
+    let mut resource_updates = alloc::vec::Vec::new();
+    let mut font_instances_added_this_frame = FastBTreeSet::new();
+
+    for (font_families_hash, (missing_chars, font_sizes)) in missing_glyphs.iter() {
+
+        let mut fallback_chain = match renderer_resources.font_fallback_chains.get(font_families_hash) {
+            Some(s) => s.clone(),
+            None => continue, // font-family not resolved yet
+        };
+
+        let mut chars_to_search = missing_chars
+            .iter()
+            .filter(|c| !fallback_chain.searched_chars.contains(*c))
+            .copied()
+            .collect::<FastBTreeSet<char>>();
+
+        if chars_to_search.is_empty() {
+            continue;
+        }
+
+        fallback_chain.searched_chars.extend(chars_to_search.iter().copied());
+
+        let mut fallback_font_keys = fallback_chain.fonts
+            .iter()
+            .filter_map(|font_family_hash| renderer_resources.font_id_map.get(font_family_hash).copied())
+            .collect::<Vec<FontKey>>();
+
+        for system_font_name in SYSTEM_FALLBACK_FONTS.iter() {
+
+            if chars_to_search.is_empty() {
+                break;
             }
+
+            let family = StyleFontFamily::System((*system_font_name).into());
+            let current_family_hash = StyleFontFamilyHash::new(&family);
+            if fallback_chain.fonts.contains(&current_family_hash) {
+                continue;
+            }
+
+            let (font_key, font_ref) = match resolve_font_family(
+                renderer_resources,
+                &mut resource_updates,
+                fc_cache,
+                id_namespace,
+                &family,
+                font_source_load_fn,
+                parse_font_fn,
+            ) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            // system font name resolved to a font that is already in the fallback chain
+            if fallback_font_keys.contains(&font_key) {
+                continue;
+            }
+
+            // Only use the font if it can render at least one missing character
+            let chars_covered = chars_to_search
+                .iter()
+                .filter(|c| (font_has_glyph_fn)(&font_ref, **c))
+                .copied()
+                .collect::<Vec<char>>();
+
+            if chars_covered.is_empty() {
+                continue;
+            }
+
+            for c in chars_covered.iter() {
+                chars_to_search.remove(c);
+            }
+
+            for font_size in font_sizes.iter() {
+                resource_updates.extend(build_add_font_instance_msg(
+                    renderer_resources,
+                    &mut font_instances_added_this_frame,
+                    id_namespace,
+                    current_family_hash,
+                    font_key,
+                    *font_size,
+                ));
+            }
+
+            fallback_chain.fonts.push(current_family_hash);
+            fallback_font_keys.push(font_key);
         }
+
+        renderer_resources.font_fallback_chains.insert(*font_families_hash, fallback_chain);
     }
 
     resource_updates
 }
 
+/// Returns the font key (and the parsed font) for the given font family,
+/// loads and parses the font if it isn't registered yet.
+///
+/// Fonts that are loaded from the same source (i.e. different system font
+/// names that resolve to the same font file) share the same font key.
+fn resolve_font_family(
+    renderer_resources: &mut RendererResources,
+    resource_updates: &mut Vec<(StyleFontFamilyHash, AddFontMsg)>,
+    fc_cache: &FcFontCache,
+    id_namespace: IdNamespace,
+    family: &StyleFontFamily,
+    font_source_load_fn: LoadFontFn,
+    parse_font_fn: ParseFontFn,
+) -> Option<(FontKey, FontRef)> {
+
+    let font_family_hash = StyleFontFamilyHash::new(family);
+
+    // fonts added this frame are not yet in the currently_registered_fonts
+    let find_registered_font = |renderer_resources: &RendererResources, resource_updates: &[(StyleFontFamilyHash, AddFontMsg)], font_key: &FontKey| {
+        renderer_resources.currently_registered_fonts
+        .get(font_key)
+        .map(|(font_ref, _)| font_ref.clone())
+        .or_else(|| resource_updates.iter().find_map(|(_, msg)| match msg {
+            AddFontMsg::Font(fk, _, font_ref) if fk == font_key => Some(font_ref.clone()),
+            _ => None,
+        }))
+    };
+
+    if let Some(font_key) = renderer_resources.font_id_map.get(&font_family_hash) {
+        // font key already exists
+        let font_ref = find_registered_font(renderer_resources, &resource_updates[..], font_key)?;
+        return Some((*font_key, font_ref));
+    }
+
+    let font_ref = match family {
+        StyleFontFamily::Ref(r) => r.clone(), // Clone the FontRef
+        other => {
+
+            // Load and parse the font
+            let font_data = (font_source_load_fn)(&other, fc_cache)?;
+
+            // Check if a font with the same source is already loaded
+            let existing_font_key = renderer_resources.font_id_map.values()
+            .find(|font_key| {
+                find_registered_font(renderer_resources, &resource_updates[..], font_key)
+                .map(|font_ref| {
+                    let font_ref_data = font_ref.get_data();
+                    font_ref_data.font_index == font_data.index &&
+                    font_ref_data.bytes.as_ref() == font_data.data.as_ref()
+                })
+                .unwrap_or(false)
+            })
+            .copied();
+
+            if let Some(font_key) = existing_font_key {
+                let font_ref = find_registered_font(renderer_resources, &resource_updates[..], &font_key)?;
+                renderer_resources.font_id_map.insert(font_family_hash, font_key);
+                return Some((font_key, font_ref));
+            }
+
+            (parse_font_fn)(font_data)?
+        }
+    };
+
+    // Generate a new font key, store the mapping between hash and font key
+    let font_key = FontKey::unique(id_namespace);
+    renderer_resources.font_id_map.insert(font_family_hash, font_key);
+    resource_updates.push((font_family_hash, AddFontMsg::Font(font_key, font_family_hash, font_ref.clone())));
+
+    Some((font_key, font_ref))
+}
+
+/// Returns an `AddFontInstance` message if the font instance for the
+/// given font size does not exist yet and wasn't added during this frame
+fn build_add_font_instance_msg(
+    renderer_resources: &RendererResources,
+    font_instances_added_this_frame: &mut FastBTreeSet<(FontKey, Au)>,
+    id_namespace: IdNamespace,
+    font_family_hash: StyleFontFamilyHash,
+    font_key: FontKey,
+    font_size: Au,
+) -> Option<(StyleFontFamilyHash, AddFontMsg)> {
+
+    let font_instance_key_exists = renderer_resources.currently_registered_fonts
+        .get(&font_key)
+        .and_then(|(_, font_instances)| font_instances.get(&font_size))
+        .is_some() || font_instances_added_this_frame.contains(&(font_key, font_size));
+
+    if font_instance_key_exists {
+        return None;
+    }
+
+    let font_instance_key = FontInstanceKey::unique(id_namespace);
+
+    // For some reason the gamma is way to low on Windows
+    #[cfg(target_os = "windows")]
+    let platform_options = FontInstancePlatformOptions {
+        gamma: 300,
+        contrast: 100,
+        cleartype_level: 100,
+    };
+
+    #[cfg(target_os = "linux")]
+    let platform_options = FontInstancePlatformOptions {
+        lcd_filter: FontLCDFilter::Default,
+        hinting: FontHinting::Normal,
+    };
+
+    #[cfg(target_os = "macos")]
+    let platform_options = FontInstancePlatformOptions::default();
+
+    #[cfg(target_arch = "wasm32")]
+    let platform_options = FontInstancePlatformOptions::default();
+
+    let options = FontInstanceOptions {
+        render_mode: FontRenderMode::Subpixel,
+        flags: 0 | FONT_INSTANCE_FLAG_NO_AUTOHINT,
+        .. Default::default()
+    };
+
+    font_instances_added_this_frame.insert((font_key, font_size));
+
+    Some((font_family_hash, AddFontMsg::Instance(AddFontInstance {
+        key: font_instance_key,
+        font_key,
+        glyph_size: font_size,
+        options: Some(options),
+        platform_options: Some(platform_options),
+        variations: alloc::vec::Vec::new(),
+    }, font_size)))
+}
+
 /// Given the images of the current frame, returns `AddImage`s of
 /// which image keys are currently not in the `current_registered_images` and
 /// need to be added.
@@ -14,7 +14,8 @@ use std::hash::Hash;
 use azul_css::{
     CssProperty, LayoutSize, CssPath, InterpolateResolver,
     AzString, LayoutRect, AnimationInterpolationFunction,
-    CssPropertyType, FontRef,
+    CssPropertyType, FontRef, CssCustomProperty, StyleAnimationIterationCount,
+    StyleAnimationDirection, StyleAnimationFillMode,
 };
 use rust_fontconfig::FcFontCache;
 use crate::{
@@ -24,6 +25,7 @@ use crate::{
         WordPositions, FontInstanceKey, LayoutedGlyphs, ImageMask
     },
     window::{AzStringPair, OptionLogicalPosition},
+    display_list::GlyphInstance,
     styled_dom::{StyledDom, CssPropertyCache, StyledNode},
     ui_solver::{
         OverflowingScrollNode, PositionedRectangle,
@@ -35,6 +37,7 @@ use crate::{
         WindowSize, WindowState, FullWindowState, LogicalPosition, OptionChar,
         LogicalSize, PhysicalSize, UpdateFocusWarning, WindowCreateOptions,
         RawWindowHandle, KeyboardState, MouseState, LogicalRect, WindowTheme,
+        KeyboardShortcut, ShortcutConflict, VirtualKeyCodeCombo,
     },
     task::{
         ThreadSendMsg, Duration as AzDuration, Instant as AzInstant,
@@ -223,7 +226,7 @@ impl RefAny {
                 // copy the struct from the heap to the stack and
                 // call mem::drop on U to run the destructor
                 let mut stack_mem = mem::MaybeUninit::<U>::uninit();
-                ptr::copy_nonoverlapping((ptr as *mut c_void) as *const U, stack_mem.as_mut_ptr(), mem::size_of::<U>());
+                ptr::copy_nonoverlapping((ptr as *mut c_void) as *const U, stack_mem.as_mut_ptr(), 1);
                 let stack_mem = stack_mem.assume_init();
                 mem::drop(stack_mem);
             }
@@ -675,6 +678,14 @@ pub struct InlineTextHit {
     // relative to text content (word)
     pub glyph_index_relative_to_word: usize,
     pub char_index_relative_to_word: usize,
+
+    // bidirectional text
+    /// Whether the hit glyph is part of a right-to-left run
+    pub is_rtl: bool,
+    /// Logical index (relative to text) at which a text cursor has to be placed
+    /// for this hit: before the hit character if the leading half of the glyph was hit,
+    /// after the character otherwise. For right-to-left text, the leading half is the right half.
+    pub cursor_char_index_relative_to_text: usize,
 }
 
 impl_vec!(InlineTextHit, InlineTextHitVec, InlineTextHitVecDestructor);
@@ -715,51 +726,61 @@ impl InlineText {
     /// relative to the text_origin), but the word position is relative to the BOTTOM left
     /// corner (of the line bounds)
     pub fn get_layouted_glyphs(&self) -> LayoutedGlyphs {
+        LayoutedGlyphs {
+            glyphs: self.iter_layouted_glyphs().map(|(_, glyph)| glyph).collect(),
+        }
+    }
 
-        use crate::display_list::GlyphInstance;
+    /// Same as `get_layouted_glyphs`, but groups the glyphs by the index of the font
+    /// in the font fallback chain, since every font needs its own text display item
+    pub fn get_layouted_glyphs_by_font(&self) -> BTreeMap<usize, LayoutedGlyphs> {
+        let mut map = BTreeMap::<usize, LayoutedGlyphs>::new();
+        for (fallback_font_index, glyph) in self.iter_layouted_glyphs() {
+            map.entry(fallback_font_index)
+            .or_insert_with(|| LayoutedGlyphs { glyphs: Vec::new() })
+            .glyphs.push(glyph);
+        }
+        map
+    }
 
-        let default: InlineGlyphVec = Vec::new().into();
-        let default_ref = &default;
+    fn iter_layouted_glyphs<'a>(&'a self) -> impl Iterator<Item = (usize, GlyphInstance)> + 'a {
 
         // descender_px is NEGATIVE
         let baseline_descender_px = LogicalPosition::new(0.0, self.baseline_descender_px);
 
-        LayoutedGlyphs {
-            glyphs: self.lines
+        self.lines
+        .iter()
+        .flat_map(move |line| {
+
+            // bottom left corner of line rect
+            let line_origin = line.bounds.origin;
+
+            line.words
             .iter()
-            .flat_map(move |line| {
-
-                // bottom left corner of line rect
-                let line_origin = line.bounds.origin;
-
-                line.words
-                .iter()
-                .flat_map(move |word| {
-
-                    let (glyphs, mut word_origin) = match word {
-                        InlineWord::Tab | InlineWord::Return | InlineWord::Space => (default_ref, LogicalPosition::zero()),
-                        InlineWord::Word(text_contents) => (&text_contents.glyphs, text_contents.bounds.origin),
-                    };
-
-                    word_origin.y = 0.0;
-
-                    glyphs.iter()
-                    .map(move |glyph| {
-                        GlyphInstance {
-                            index: glyph.glyph_index,
-                            point: {
-                                line_origin +
-                                baseline_descender_px +
-                                word_origin +
-                                glyph.bounds.origin
-                            },
-                            size: glyph.bounds.size,
-                        }
+            .flat_map(move |word| {
+
+                let (glyphs, mut word_origin) = match word {
+                    InlineWord::Tab | InlineWord::Return | InlineWord::Space => (&[][..], LogicalPosition::zero()),
+                    InlineWord::Word(text_contents) => (text_contents.glyphs.as_ref(), text_contents.bounds.origin),
+                };
+
+                word_origin.y = 0.0;
+
+                glyphs.iter()
+                .map(move |glyph| {
+                    (glyph.fallback_font_index, GlyphInstance {
+                        index: glyph.glyph_index,
+                        point: {
+                            line_origin +
+                            baseline_descender_px +
+                            word_origin +
+                            glyph.bounds.origin
+                        },
+                        size: glyph.bounds.size,
                     })
                 })
-
-            }).collect::<Vec<GlyphInstance>>()
-        }
+            })
+        })
     }
 
     /// Hit tests all glyphs, returns the hit glyphs - note that the result may
@@ -797,83 +818,107 @@ impl InlineText {
             let mut line_bounds = line.bounds.clone();
             line_bounds.origin.y -= line.bounds.size.height;
 
-            line_bounds.hit_test(&hit_relative_to_inline_text)
-            .map(|hit_relative_to_line| {
-
-                line.words
-                .iter() // TODO: par_iter
-                .flat_map(|word| {
-
-                    let char_at_text_content_start = global_char_hit;
-                    let glyph_at_text_content_start = global_glyph_hit;
-
-                    let word_result = word
-                    .get_text_content()
-                    .and_then(|text_content| {
-
-                        let mut text_content_bounds = text_content.bounds.clone();
-                        text_content_bounds.origin.y = 0.0;
+            let hit_relative_to_line = match line_bounds.hit_test(&hit_relative_to_inline_text) {
+                Some(s) => s,
+                None => {
+                    // line was not hit, but the indices of the following
+                    // lines still have to be relative to the start of the text
+                    for word in line.words.iter() {
+                        if let Some(text_content) = word.get_text_content() {
+                            global_glyph_hit += text_content.glyphs.len();
+                            global_char_hit += text_content.glyphs.iter().filter(|g| g.has_codepoint()).count();
+                            global_text_content_hit += 1;
+                        }
+                        global_word_hit += 1;
+                    }
+                    return Vec::new().into_iter();
+                }
+            };
 
-                        text_content_bounds
-                        .hit_test(&hit_relative_to_line)
-                        .map(|hit_relative_to_text_content| {
+            line.words
+            .iter() // TODO: par_iter
+            .flat_map(|word| {
 
-                            text_content.glyphs
-                            .iter() // TODO: par_iter
-                            .flat_map(|glyph| {
+                let char_at_text_content_start = global_char_hit;
+                let glyph_at_text_content_start = global_glyph_hit;
 
-                                let result = glyph.bounds
-                                .hit_test(&hit_relative_to_text_content)
-                                .map(|hit_relative_to_glyph| {
-                                    InlineTextHit {
-                                        unicode_codepoint: glyph.unicode_codepoint,
+                let word_result = word
+                .get_text_content()
+                .map(|text_content| {
 
-                                        hit_relative_to_inline_text,
-                                        hit_relative_to_line,
-                                        hit_relative_to_text_content,
-                                        hit_relative_to_glyph,
+                    let mut text_content_bounds = text_content.bounds.clone();
+                    text_content_bounds.origin.y = 0.0;
 
-                                        line_index_relative_to_text: line_index,
-                                        word_index_relative_to_text: global_word_hit,
-                                        text_content_index_relative_to_text: global_text_content_hit,
-                                        glyph_index_relative_to_text: global_glyph_hit,
-                                        char_index_relative_to_text: global_char_hit,
+                    let hit_relative_to_text_content = text_content_bounds.hit_test(&hit_relative_to_line);
 
-                                        word_index_relative_to_line: global_word_hit - word_at_line_start,
-                                        text_content_index_relative_to_line: global_text_content_hit - text_content_at_line_start,
-                                        glyph_index_relative_to_line: global_glyph_hit - glyph_at_line_start,
-                                        char_index_relative_to_line: global_char_hit - char_at_line_start,
+                    text_content.glyphs
+                    .iter() // TODO: par_iter
+                    .flat_map(|glyph| {
 
-                                        glyph_index_relative_to_word: global_glyph_hit - glyph_at_text_content_start,
-                                        char_index_relative_to_word: global_char_hit - char_at_text_content_start,
-                                    }
-                                });
+                        let result = hit_relative_to_text_content
+                        .and_then(|hit_relative_to_text_content| {
+                            glyph.bounds
+                            .hit_test(&hit_relative_to_text_content)
+                            .map(|hit_relative_to_glyph| (hit_relative_to_text_content, hit_relative_to_glyph))
+                        })
+                        .map(|(hit_relative_to_text_content, hit_relative_to_glyph)| {
+
+                            // LTR: left half = leading half, RTL: right half = leading half
+                            let hit_left_half = hit_relative_to_glyph.x < glyph.bounds.size.width / 2.0;
+                            let cursor_before_char = hit_left_half != text_content.is_rtl;
+
+                            InlineTextHit {
+                                unicode_codepoint: glyph.unicode_codepoint,
+
+                                hit_relative_to_inline_text,
+                                hit_relative_to_line,
+                                hit_relative_to_text_content,
+                                hit_relative_to_glyph,
+
+                                line_index_relative_to_text: line_index,
+                                word_index_relative_to_text: global_word_hit,
+                                text_content_index_relative_to_text: global_text_content_hit,
+                                glyph_index_relative_to_text: global_glyph_hit,
+                                char_index_relative_to_text: global_char_hit,
+
+                                word_index_relative_to_line: global_word_hit - word_at_line_start,
+                                text_content_index_relative_to_line: global_text_content_hit - text_content_at_line_start,
+                                glyph_index_relative_to_line: global_glyph_hit - glyph_at_line_start,
+                                char_index_relative_to_line: global_char_hit - char_at_line_start,
+
+                                glyph_index_relative_to_word: global_glyph_hit - glyph_at_text_content_start,
+                                char_index_relative_to_word: global_char_hit - char_at_text_content_start,
+
+                                is_rtl: text_content.is_rtl,
+                                cursor_char_index_relative_to_text: if cursor_before_char || !glyph.has_codepoint() {
+                                    global_char_hit
+                                } else {
+                                    global_char_hit + 1
+                                },
+                            }
+                        });
 
-                                if glyph.has_codepoint() {
-                                    global_char_hit += 1;
-                                }
+                        if glyph.has_codepoint() {
+                            global_char_hit += 1;
+                        }
 
-                                global_glyph_hit += 1;
+                        global_glyph_hit += 1;
 
-                                result
-                            })
-                            .collect::<Vec<_>>()
-                        })
-                    }).unwrap_or_default();
+                        result
+                    })
+                    .collect::<Vec<_>>()
+                }).unwrap_or_default();
 
-                    if word.has_text_content() {
-                        global_text_content_hit += 1;
-                    }
+                if word.has_text_content() {
+                    global_text_content_hit += 1;
+                }
 
-                    global_word_hit += 1;
+                global_word_hit += 1;
 
-                    word_result.into_iter()
-                })
-                .collect::<Vec<_>>()
+                word_result.into_iter()
             })
-            .unwrap_or_default()
+            .collect::<Vec<_>>()
             .into_iter()
-
         })
         .collect::<Vec<_>>()
     }
@@ -924,6 +969,9 @@ impl_vec_partialord!(InlineWord, InlineWordVec);
 pub struct InlineTextContents {
     pub glyphs: InlineGlyphVec,
     pub bounds: LogicalRect,
+    /// Whether the word is laid out right-to-left - note that the glyphs
+    /// are always stored in logical order, only their position is mirrored
+    pub is_rtl: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -932,6 +980,7 @@ pub struct InlineGlyph {
     pub bounds: LogicalRect,
     pub unicode_codepoint: OptionChar,
     pub glyph_index: u32,
+    pub fallback_font_index: usize,
 }
 
 impl InlineGlyph {
@@ -1007,6 +1056,10 @@ pub struct CallbackInfo {
     image_masks_changed_in_callbacks: *mut BTreeMap<DomId, BTreeMap<NodeId, ImageMask>>,
     /// Mutable reference to a list of CSS property changes, so that the callbacks can change CSS properties
     css_properties_changed_in_callbacks: *mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>,
+    /// Mutable reference to a list of CSS variables (`--accent`) that were changed in the callback
+    css_variables_changed_in_callbacks: *mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssCustomProperty>>>,
+    /// Mutable reference to the interpolated values of running CSS transitions (only written by transition timers)
+    css_transitions_changed_in_callbacks: *mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>,
     /// Immutable (!) reference to where the nodes are currently scrolled (current position)
     current_scroll_states: *const BTreeMap<DomId, BTreeMap<AzNodeId, ScrollPosition>>,
     /// Mutable map where a user can set where he wants the nodes to be scrolled to (for the next frame)
@@ -1060,6 +1113,8 @@ impl CallbackInfo {
        images_changed_in_callbacks: &'a mut BTreeMap<DomId, BTreeMap<NodeId, (ImageRef, UpdateImageType)>>,
        image_masks_changed_in_callbacks: &'a mut BTreeMap<DomId, BTreeMap<NodeId, ImageMask>>,
        css_properties_changed_in_callbacks: &'a mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>,
+       css_variables_changed_in_callbacks: &'a mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssCustomProperty>>>,
+       css_transitions_changed_in_callbacks: &'a mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>,
        current_scroll_states: &'a BTreeMap<DomId, BTreeMap<AzNodeId, ScrollPosition>>,
        nodes_scrolled_in_callback: &'a mut BTreeMap<DomId, BTreeMap<AzNodeId, LogicalPosition>>,
        hit_dom_node: DomNodeId,
@@ -1095,6 +1150,8 @@ impl CallbackInfo {
             images_changed_in_callbacks: images_changed_in_callbacks as *mut BTreeMap<DomId, BTreeMap<NodeId, (ImageRef, UpdateImageType)>>,
             image_masks_changed_in_callbacks: image_masks_changed_in_callbacks as *mut BTreeMap<DomId, BTreeMap<NodeId, ImageMask>>,
             css_properties_changed_in_callbacks: css_properties_changed_in_callbacks as *mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>,
+            css_variables_changed_in_callbacks: css_variables_changed_in_callbacks as *mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssCustomProperty>>>,
+            css_transitions_changed_in_callbacks: css_transitions_changed_in_callbacks as *mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>,
             current_scroll_states: current_scroll_states as *const BTreeMap<DomId, BTreeMap<AzNodeId, ScrollPosition>>,
             nodes_scrolled_in_callback: nodes_scrolled_in_callback as *mut BTreeMap<DomId, BTreeMap<AzNodeId, LogicalPosition>>,
             hit_dom_node: hit_dom_node,
@@ -1128,6 +1185,8 @@ impl CallbackInfo {
     fn internal_get_focus_target<'a>(&'a mut self) -> &'a mut Option<FocusTarget> { unsafe { &mut *self.focus_target } }
     fn internal_get_current_scroll_states<'a>(&'a self) -> &'a BTreeMap<DomId, BTreeMap<AzNodeId, ScrollPosition>> { unsafe { &*self.current_scroll_states } }
     fn internal_get_css_properties_changed_in_callbacks<'a>(&'a mut self) -> &'a mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>> { unsafe { &mut *self.css_properties_changed_in_callbacks } }
+    fn internal_get_css_variables_changed_in_callbacks<'a>(&'a mut self) -> &'a mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssCustomProperty>>> { unsafe { &mut *self.css_variables_changed_in_callbacks } }
+    fn internal_get_css_transitions_changed_in_callbacks<'a>(&'a mut self) -> &'a mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>> { unsafe { &mut *self.css_transitions_changed_in_callbacks } }
     fn internal_get_nodes_scrolled_in_callback<'a>(&'a mut self) -> &'a mut BTreeMap<DomId, BTreeMap<AzNodeId, LogicalPosition>> { unsafe { &mut *self.nodes_scrolled_in_callback } }
     fn internal_get_hit_dom_node<'a>(&'a self) -> DomNodeId { self.hit_dom_node }
     fn internal_get_cursor_relative_to_item<'a>(&'a self) -> OptionLogicalPosition { self.cursor_relative_to_item }
@@ -1241,6 +1300,18 @@ impl CallbackInfo {
         *self.internal_get_modifiable_window_state() = new_state;
     }
 
+    /// Registers a window-wide keyboard shortcut, fails if the key combination
+    /// is already used by another shortcut, see `WindowState::add_shortcut()`
+    pub fn add_shortcut(&mut self, shortcut: KeyboardShortcut) -> Result<(), ShortcutConflict> {
+        self.internal_get_modifiable_window_state().add_shortcut(shortcut)
+    }
+
+    /// Removes the window shortcut with the given key combination,
+    /// returns whether a shortcut was removed
+    pub fn remove_shortcut(&mut self, keys: &VirtualKeyCodeCombo) -> bool {
+        self.internal_get_modifiable_window_state().remove_shortcut(keys)
+    }
+
     pub fn set_css_property(&mut self, node_id: DomNodeId, prop: CssProperty) {
         if let Some(nid) = node_id.node.into_crate_internal() {
             self.internal_get_css_properties_changed_in_callbacks()
@@ -1251,6 +1322,35 @@ impl CallbackInfo {
         }
     }
 
+    /// Sets the value of the CSS variable `--name` on the given node. Since variables
+    /// are inherited, setting a variable on the root node changes it for the entire DOM
+    /// (i.e. for switching the theme of the application). All `var(--name)` references
+    /// get re-resolved before the next frame, which triggers a relayout if necessary.
+    ///
+    /// The `name` is the name of the variable without the leading dashes. An empty
+    /// `value` removes the variable again, so that the value from the stylesheet applies.
+    pub fn set_css_variable(&mut self, node_id: DomNodeId, name: AzString, value: AzString) {
+        if let Some(nid) = node_id.node.into_crate_internal() {
+            self.internal_get_css_variables_changed_in_callbacks()
+            .entry(node_id.dom)
+            .or_insert_with(|| BTreeMap::new())
+            .entry(nid)
+            .or_insert_with(|| Vec::new()).push(CssCustomProperty::new(name, value));
+        }
+    }
+
+    /// Sets the next frame of a running CSS transition or animation
+    /// (see `drive_transition_func` and `drive_css_animation_func`)
+    fn set_css_transition_frame(&mut self, node_id: DomNodeId, prop: CssProperty) {
+        if let Some(nid) = node_id.node.into_crate_internal() {
+            self.internal_get_css_transitions_changed_in_callbacks()
+            .entry(node_id.dom)
+            .or_insert_with(|| BTreeMap::new())
+            .entry(nid)
+            .or_insert_with(|| Vec::new()).push(prop);
+        }
+    }
+
     pub fn set_focus(&mut self, target: FocusTarget) {
         *self.internal_get_focus_target() = Some(target);
     }
@@ -1404,6 +1504,7 @@ impl CallbackInfo {
             to: animation.to,
             start: now.clone(),
             repeat: animation.repeat,
+            repeat_times: animation.repeat_times,
             interpolate: animation.easing,
             duration: animation.duration,
             relayout_on_finish: animation.relayout_on_finish,
@@ -1538,6 +1639,8 @@ impl Clone for CallbackInfo {
             images_changed_in_callbacks: self.images_changed_in_callbacks,
             image_masks_changed_in_callbacks: self.image_masks_changed_in_callbacks,
             css_properties_changed_in_callbacks: self.css_properties_changed_in_callbacks,
+            css_variables_changed_in_callbacks: self.css_variables_changed_in_callbacks,
+            css_transitions_changed_in_callbacks: self.css_transitions_changed_in_callbacks,
             current_scroll_states: self.current_scroll_states,
             nodes_scrolled_in_callback: self.nodes_scrolled_in_callback,
             hit_dom_node: self.hit_dom_node,
@@ -1563,6 +1666,7 @@ pub struct AnimationData {
     pub start: AzInstant,
     pub duration: AzDuration,
     pub repeat: AnimationRepeat,
+    pub repeat_times: AnimationRepeatCount,
     pub interpolate: AnimationInterpolationFunction,
     pub relayout_on_finish: bool,
     pub parent_rect_width: f32,
@@ -1579,6 +1683,7 @@ pub struct Animation {
     pub to: CssProperty,
     pub duration: AzDuration,
     pub repeat: AnimationRepeat,
+    /// How often a `Loop` / `PingPong` animation runs in total (ignored for `NoRepeat`)
     pub repeat_times: AnimationRepeatCount,
     pub easing: AnimationInterpolationFunction,
     pub relayout_on_finish: bool,
@@ -1599,6 +1704,268 @@ pub enum AnimationRepeatCount {
     Infinite,
 }
 
+/// Transition of a CSS property that was started automatically because the
+/// property is listed in the `transition-property` of the node
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssTransition {
+    pub node: DomNodeId,
+    pub from: CssProperty,
+    pub to: CssProperty,
+    pub duration: AzDuration,
+    pub delay: AzDuration,
+    pub easing: AnimationInterpolationFunction,
+    pub parent_rect_width: f32,
+    pub parent_rect_height: f32,
+    pub current_rect_width: f32,
+    pub current_rect_height: f32,
+}
+
+impl CssTransition {
+    /// Creates the timer that drives the transition
+    pub fn into_timer(self, get_system_time_fn: GetSystemTimeCallback) -> Timer {
+
+        use crate::task::SystemTimeDiff;
+
+        let now = (get_system_time_fn.cb)();
+
+        // the value stays at `from` until the delay has elapsed
+        let animation_data = AnimationData {
+            from: self.from,
+            to: self.to,
+            start: now.add_optional_duration(Some(&self.delay)),
+            repeat: AnimationRepeat::NoRepeat,
+            repeat_times: AnimationRepeatCount::Times(1),
+            interpolate: self.easing,
+            duration: self.duration,
+            relayout_on_finish: false,
+            parent_rect_width: self.parent_rect_width,
+            parent_rect_height: self.parent_rect_height,
+            current_rect_width: self.current_rect_width,
+            current_rect_height: self.current_rect_height,
+            get_system_time_fn,
+        };
+
+        Timer {
+            data: RefAny::new(animation_data),
+            node_id: Some(self.node).into(),
+            created: now,
+            run_count: 0,
+            last_run: None.into(),
+            delay: None.into(),
+            interval: Some(AzDuration::System(SystemTimeDiff::from_millis(16))).into(),
+            timeout: None.into(),
+            callback: TimerCallback { cb: drive_transition_func },
+        }
+    }
+}
+
+// callback that drives a CSS transition: same as drive_animation_func, but the
+// interpolated value is not stored as a user-overridden property, so that a
+// :hover / :active / :focus change of the same property is still detected
+extern "C" fn drive_transition_func(_: &mut RefAny, anim_data: &mut RefAny, mut info: TimerCallbackInfo) -> TimerCallbackReturn {
+
+    let terminate = TimerCallbackReturn {
+        should_update: Update::DoNothing,
+        should_terminate: TerminateTimer::Terminate,
+    };
+
+    let anim_data = match anim_data.downcast_ref::<AnimationData>() {
+        Some(s) => s,
+        None => return terminate,
+    };
+
+    let node_id = match info.node_id.into_option() {
+        Some(s) => s,
+        None => return terminate,
+    };
+
+    let anim_end = anim_data.start.add_optional_duration(Some(&anim_data.duration));
+    let now = (anim_data.get_system_time_fn.cb)();
+
+    if now > anim_end || info.is_about_to_finish {
+        // remove the transition value, the property falls back to its regular value
+        info.callback_info.set_css_transition_frame(node_id, CssProperty::initial(anim_data.to.get_type()));
+        return terminate;
+    }
+
+    let resolver = InterpolateResolver {
+        parent_rect_width: anim_data.parent_rect_width,
+        parent_rect_height: anim_data.parent_rect_height,
+        current_rect_width: anim_data.current_rect_width,
+        current_rect_height: anim_data.current_rect_height,
+        interpolate_func: anim_data.interpolate,
+    };
+
+    let t = now.linear_interpolate(anim_data.start.clone(), anim_end);
+    info.callback_info.set_css_transition_frame(node_id, anim_data.from.interpolate(&anim_data.to, t, &resolver));
+
+    TimerCallbackReturn {
+        should_terminate: TerminateTimer::Continue,
+        should_update: Update::DoNothing,
+    }
+}
+
+/// Animation of one or more CSS properties along the keyframes of a `@keyframes` rule,
+/// started automatically because the node has an `animation-name`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssAnimation {
+    /// Keyframes (offset from 0.0 to 1.0, value) of every animated property, sorted
+    /// by offset - the first keyframe is always at 0.0, the last one at 1.0
+    pub properties: Vec<Vec<(f32, CssProperty)>>,
+    /// Duration of one iteration
+    pub duration: AzDuration,
+    pub delay: AzDuration,
+    pub iteration_count: StyleAnimationIterationCount,
+    pub direction: StyleAnimationDirection,
+    pub fill_mode: StyleAnimationFillMode,
+    /// Easing between two keyframes (not over the whole iteration)
+    pub easing: AnimationInterpolationFunction,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct CssAnimationData {
+    animation: CssAnimation,
+    /// Start of the first iteration (after the delay)
+    start: AzInstant,
+    get_system_time_fn: GetSystemTimeCallback,
+}
+
+impl CssAnimation {
+
+    /// Returns the types of the animated properties
+    pub fn get_property_types(&self) -> Vec<CssPropertyType> {
+        self.properties.iter().filter_map(|keyframes| Some(keyframes.first()?.1.get_type())).collect()
+    }
+
+    /// Returns the values of the animated properties at the given position on the keyframes
+    pub fn get_properties(&self, offset: f32, resolver: &InterpolateResolver) -> Vec<CssProperty> {
+        self.properties.iter().filter_map(|keyframes| {
+            let end = keyframes.iter().position(|(o, _)| *o >= offset)?;
+            let (end_offset, end_prop) = &keyframes[end];
+            let (start_offset, start_prop) = match end.checked_sub(1) {
+                Some(start) => &keyframes[start],
+                None => return Some(end_prop.clone()),
+            };
+            let t = if end_offset > start_offset { (offset - start_offset) / (end_offset - start_offset) } else { 1.0 };
+            Some(start_prop.interpolate(end_prop, t, resolver))
+        }).collect()
+    }
+
+    /// Creates the timer that drives the animation on the given node
+    pub fn into_timer(self, node: DomNodeId, get_system_time_fn: GetSystemTimeCallback) -> Timer {
+
+        use crate::task::SystemTimeDiff;
+
+        let now = (get_system_time_fn.cb)();
+
+        let animation_data = CssAnimationData {
+            start: now.add_optional_duration(Some(&self.delay)),
+            animation: self,
+            get_system_time_fn,
+        };
+
+        Timer {
+            data: RefAny::new(animation_data),
+            node_id: Some(node).into(),
+            created: now,
+            run_count: 0,
+            last_run: None.into(),
+            delay: None.into(),
+            interval: Some(AzDuration::System(SystemTimeDiff::from_millis(16))).into(),
+            timeout: None.into(),
+            callback: TimerCallback { cb: drive_css_animation_func },
+        }
+    }
+}
+
+// callback that drives a CSS animation: writes the interpolated values of all animated
+// properties into the same layer as the CSS transitions, see `set_css_transition_frame`
+extern "C" fn drive_css_animation_func(_: &mut RefAny, anim_data: &mut RefAny, mut info: TimerCallbackInfo) -> TimerCallbackReturn {
+
+    let terminate = TimerCallbackReturn {
+        should_update: Update::DoNothing,
+        should_terminate: TerminateTimer::Terminate,
+    };
+
+    let anim_data = match anim_data.downcast_ref::<CssAnimationData>() {
+        Some(s) => s,
+        None => return terminate,
+    };
+
+    let node_id = match info.node_id.into_option() {
+        Some(s) => s,
+        None => return terminate,
+    };
+
+    let animation = &anim_data.animation;
+    let now = (anim_data.get_system_time_fn.cb)();
+
+    // percentages are resolved against the current size, since the node may have been resized
+    let current_size = info.callback_info.get_node_size(node_id).unwrap_or_default();
+    let parent_size = info.callback_info.get_parent(node_id)
+        .and_then(|parent| info.callback_info.get_node_size(parent))
+        .unwrap_or_default();
+
+    let resolver = InterpolateResolver {
+        parent_rect_width: parent_size.width,
+        parent_rect_height: parent_size.height,
+        current_rect_width: current_size.width,
+        current_rect_height: current_size.height,
+        interpolate_func: animation.easing,
+    };
+
+    if now < anim_data.start {
+        // still in the animation-delay
+        if animation.fill_mode.fills_backwards() {
+            let offset = animation.direction.get_keyframe_offset(0, 0.0);
+            for prop in animation.get_properties(offset, &resolver) {
+                info.callback_info.set_css_transition_frame(node_id, prop);
+            }
+        }
+        return TimerCallbackReturn {
+            should_terminate: TerminateTimer::Continue,
+            should_update: Update::DoNothing,
+        };
+    }
+
+    let iterations = now.duration_since(&anim_data.start).div(&animation.duration);
+
+    let is_finished = match animation.iteration_count {
+        StyleAnimationIterationCount::Count(n) => iterations >= n as f32,
+        StyleAnimationIterationCount::Infinite => false,
+    };
+
+    if is_finished || info.is_about_to_finish {
+        if animation.fill_mode.fills_forwards() {
+            // keep the values of the end of the last iteration
+            let offset = match animation.iteration_count {
+                StyleAnimationIterationCount::Count(0) => animation.direction.get_keyframe_offset(0, 0.0),
+                StyleAnimationIterationCount::Count(n) => animation.direction.get_keyframe_offset(n - 1, 1.0),
+                StyleAnimationIterationCount::Infinite => animation.direction.get_keyframe_offset(iterations as u32, iterations.fract()),
+            };
+            for prop in animation.get_properties(offset, &resolver) {
+                info.callback_info.set_css_transition_frame(node_id, prop);
+            }
+        } else {
+            // remove the animated values, the properties fall back to their regular value
+            for prop_type in animation.get_property_types() {
+                info.callback_info.set_css_transition_frame(node_id, CssProperty::initial(prop_type));
+            }
+        }
+        return terminate;
+    }
+
+    let offset = animation.direction.get_keyframe_offset(iterations as u32, iterations.fract());
+    for prop in animation.get_properties(offset, &resolver) {
+        info.callback_info.set_css_transition_frame(node_id, prop);
+    }
+
+    TimerCallbackReturn {
+        should_terminate: TerminateTimer::Continue,
+        should_update: Update::DoNothing,
+    }
+}
+
 // callback that drives an animation
 extern "C" fn drive_animation_func(_: &mut RefAny, anim_data: &mut RefAny, mut info: TimerCallbackInfo) -> TimerCallbackReturn {
 
@@ -1643,18 +2010,28 @@ extern "C" fn drive_animation_func(_: &mut RefAny, anim_data: &mut RefAny, mut i
 
     // if the timer has finished one iteration, what next?
     if now > anim_next_end {
+
+        let has_iterations_left = match anim_data.repeat_times {
+            AnimationRepeatCount::Times(n) => n > 1,
+            AnimationRepeatCount::Infinite => true,
+        };
+
+        if let AnimationRepeatCount::Times(n) = &mut anim_data.repeat_times {
+            *n = n.saturating_sub(1);
+        }
+
         match anim_data.repeat {
-            AnimationRepeat::Loop => {
+            AnimationRepeat::Loop if has_iterations_left => {
                 // reset timer
                 anim_data.start = now;
             },
-            AnimationRepeat::PingPong => {
+            AnimationRepeat::PingPong if has_iterations_left => {
                 use core::mem;
                 // swap start and end and reset timer
                 mem::swap(&mut anim_data.from, &mut anim_data.to);
                 anim_data.start = now;
             },
-            AnimationRepeat::NoRepeat => {
+            _ => {
                 // remove / cancel timer
                 return TimerCallbackReturn {
                     should_terminate: TerminateTimer::Terminate,
@@ -2174,72 +2551,20 @@ impl FocusTarget {
 
         if layout_results.is_empty() { return Ok(None); }
 
-        macro_rules! search_for_focusable_node_id {($layout_results:expr, $start_dom_id:expr, $start_node_id:expr, $get_next_node_fn:ident) => {{
-
-            let mut start_dom_id = $start_dom_id;
-            let mut start_node_id = $start_node_id;
-
-            let min_dom_id = DomId::ROOT_ID;
-            let max_dom_id = DomId { inner: layout_results.len() - 1 };
-
-            // iterate through all DOMs
-            loop { // 'outer_dom_iter
-
-                let layout_result = $layout_results.get(start_dom_id.inner).ok_or(UpdateFocusWarning::FocusInvalidDomId(start_dom_id.clone()))?;
-
-                let node_id_valid = layout_result.styled_dom.node_data.as_container().get(start_node_id).is_some();
-
-                if !node_id_valid {
-                    return Err(UpdateFocusWarning::FocusInvalidNodeId(AzNodeId::from_crate_internal(Some(start_node_id.clone()))));
-                }
-
-                if layout_result.styled_dom.node_data.is_empty() {
-                    return Err(UpdateFocusWarning::FocusInvalidDomId(start_dom_id.clone())); // ???
-                }
-
-                let max_node_id = NodeId::new(layout_result.styled_dom.node_data.len() - 1);
-                let min_node_id = NodeId::ZERO;
-
-                // iterate through nodes in DOM
-                loop {
-
-                    let current_node_id = NodeId::new(start_node_id.index().$get_next_node_fn(1))
-                        .max(min_node_id)
-                        .min(max_node_id);
-
-                    if layout_result.styled_dom.node_data.as_container()[current_node_id].is_focusable() {
-                        return Ok(Some(DomNodeId {
-                            dom: start_dom_id,
-                            node: AzNodeId::from_crate_internal(Some(current_node_id)),
-                        }));
-                    }
+        // all nodes of all DOMs that can be focused via the keyboard, in tab order
+        let get_tab_order = || -> Vec<DomNodeId> {
+            layout_results.iter().enumerate().flat_map(|(dom_id, layout_result)| {
+                layout_result.styled_dom.get_tab_order().into_iter().map(move |node_id| DomNodeId {
+                    dom: DomId { inner: dom_id },
+                    node: AzNodeId::from_crate_internal(Some(node_id)),
+                })
+            }).collect()
+        };
 
-                    if current_node_id == min_node_id && current_node_id < start_node_id {
-                        // going in decreasing (previous) direction
-                        if start_dom_id == min_dom_id {
-                            // root node / root dom encountered
-                            return Ok(None);
-                        } else {
-                            start_dom_id.inner -= 1;
-                            start_node_id = NodeId::new($layout_results[start_dom_id.inner].styled_dom.node_data.len() - 1);
-                            break; // continue 'outer_dom_iter
-                        }
-                    } else if current_node_id == max_node_id && current_node_id > start_node_id {
-                        // going in increasing (next) direction
-                        if start_dom_id == max_dom_id {
-                            // last dom / last node encountered
-                            return Ok(None);
-                        } else {
-                            start_dom_id.inner += 1;
-                            start_node_id = NodeId::ZERO;
-                            break; // continue 'outer_dom_iter
-                        }
-                    } else {
-                        start_node_id = current_node_id;
-                    }
-                }
-            }
-        }};}
+        let current_tab_index = |tab_order: &[DomNodeId]| -> Option<usize> {
+            let current_focus = current_focus?;
+            tab_order.iter().position(|node| *node == current_focus)
+        };
 
         match self {
             Path(FocusTargetPath { dom, css_path }) => {
@@ -2270,56 +2595,42 @@ impl FocusTarget {
                 }
             },
             Previous => {
-
-                let last_layout_dom_id = DomId { inner: layout_results.len() - 1 };
-
                 // select the previous focusable element or `None`
                 // if this was the first focusable element in the DOM
-                let (current_focus_dom, current_focus_node_id) = match current_focus {
-                    Some(s) => match s.node.into_crate_internal() {
-                        Some(n) => (s.dom, n),
-                        None => {
-                            if let Some(layout_result) = layout_results.get(s.dom.inner) {
-                                (s.dom, NodeId::new(layout_result.styled_dom.node_data.len() - 1))
-                            } else {
-                                (last_layout_dom_id, NodeId::new(layout_results[last_layout_dom_id.inner].styled_dom.node_data.len() - 1))
-                            }
-                        }
-                    },
-                    None => (last_layout_dom_id, NodeId::new(layout_results[last_layout_dom_id.inner].styled_dom.node_data.len() - 1)),
-                };
-
-                search_for_focusable_node_id!(layout_results, current_focus_dom, current_focus_node_id, saturating_sub);
+                let tab_order = get_tab_order();
+                match current_tab_index(&tab_order) {
+                    Some(i) => Ok(i.checked_sub(1).map(|i| tab_order[i])),
+                    None => Ok(tab_order.last().copied()),
+                }
             },
             Next => {
-                // select the previous focusable element or `None`
-                // if this was the first focusable element in the DOM, select the first focusable element
-                let (current_focus_dom, current_focus_node_id) = match current_focus {
-                    Some(s) => match s.node.into_crate_internal() {
-                        Some(n) => (s.dom, n),
-                        None => {
-                            if layout_results.get(s.dom.inner).is_some() {
-                                (s.dom, NodeId::ZERO)
-                            } else {
-                                (DomId::ROOT_ID, NodeId::ZERO)
-                            }
-                        }
-                    },
-                    None => (DomId::ROOT_ID, NodeId::ZERO),
-                };
-
-                search_for_focusable_node_id!(layout_results, current_focus_dom, current_focus_node_id, saturating_add);
-            },
-            First => {
-                let (current_focus_dom, current_focus_node_id) = (DomId::ROOT_ID, NodeId::ZERO);
-                search_for_focusable_node_id!(layout_results, current_focus_dom, current_focus_node_id, saturating_add);
-            },
-            Last => {
-                let last_layout_dom_id = DomId { inner: layout_results.len() - 1 };
-                let (current_focus_dom, current_focus_node_id) = (last_layout_dom_id, NodeId::new(layout_results[last_layout_dom_id.inner].styled_dom.node_data.len() - 1));
-                search_for_focusable_node_id!(layout_results, current_focus_dom, current_focus_node_id, saturating_add);
+                // select the next focusable element or `None`
+                // if this was the last focusable element in the DOM
+                let tab_order = get_tab_order();
+                match current_tab_index(&tab_order) {
+                    Some(i) => Ok(tab_order.get(i + 1).copied()),
+                    None => Ok(tab_order.first().copied()),
+                }
             },
+            First => Ok(get_tab_order().first().copied()),
+            Last => Ok(get_tab_order().last().copied()),
             NoFocus => Ok(None),
         }
     }
 }
+
+#[test]
+fn test_refany_drop() {
+    use alloc::rc::Rc;
+    use alloc::string::String;
+
+    // the destructor runs exactly once, when the last copy is dropped
+    let counter = Rc::new(());
+    let data = RefAny::new((counter.clone(), String::from("data")));
+    let copy = data.clone();
+    assert_eq!(Rc::strong_count(&counter), 2);
+    drop(data);
+    assert_eq!(Rc::strong_count(&counter), 2);
+    drop(copy);
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
@@ -4,9 +4,12 @@ use core::{
 use alloc::vec::Vec;
 use alloc::string::String;
 use alloc::collections::btree_set::BTreeSet;
+use alloc::collections::btree_map::BTreeMap;
+use alloc::collections::vec_deque::VecDeque;
 use crate::{
-    id_tree::{NodeId, NodeDataContainer},
-    dom::{CompactDom, NodeData},
+    id_tree::{NodeId, NodeDataContainerRef},
+    dom::NodeData,
+    styled_dom::{StyledDom, AzNode},
 };
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -27,6 +30,11 @@ impl DomRange {
             end: node_id,
         }
     }
+
+    /// Returns all node IDs in this range (including the `end` node)
+    pub fn iter(&self) -> impl Iterator<Item = NodeId> {
+        (self.start.index()..=self.end.index()).map(NodeId::new)
+    }
 }
 
 impl fmt::Debug for DomRange {
@@ -63,58 +71,87 @@ impl fmt::Display for DomChange {
 pub struct DomDiff {
     /// What the actual changes nodes (not trees / subtrees) were in this diff, in order of appearance
     pub changed_nodes: Vec<DomChange>,
+    /// Old node ID -> new node ID of all nodes that are present in both DOMs
+    pub matched_nodes: BTreeMap<NodeId, NodeId>,
 }
 
 impl DomDiff {
-    pub fn new(old: &CompactDom, new: &CompactDom) -> Self {
-
-        // TODO: Check if old root = new root, if not, change entire tree
-
-        let mut changes = BTreeSet::new();
-        let mut visited_nodes = NodeDataContainer::new(vec![false; new.len()]);
 
-        visited_nodes[NodeId::ZERO] = true;
+    /// Diffs the two DOMs by matching the children of each node: two children are
+    /// the same node if their node type and their IDs / classes are equal. The common
+    /// prefix / suffix of the children is matched in order, the remaining children are
+    /// matched by their key. A node that has no match is removed from the old DOM / added
+    /// to the new DOM together with its entire subtree, so inserting or removing a child
+    /// does not re-mount the following siblings
+    pub fn new(old: &StyledDom, new: &StyledDom) -> Self {
 
-        let has_root_changed = node_has_changed(
-            &old.arena.node_data[NodeId::ZERO],
-            &new.arena.node_data[NodeId::ZERO]
-        );
+        let old_nodes = DiffNodes {
+            node_data: old.node_data.as_container(),
+            node_hierarchy: old.node_hierarchy.as_container(),
+        };
 
-        if has_root_changed == NODE_CHANGED_NOTHING {
+        let new_nodes = DiffNodes {
+            node_data: new.node_data.as_container(),
+            node_hierarchy: new.node_hierarchy.as_container(),
+        };
 
-            diff_tree_inner(NodeId::ZERO, old, new, &mut changes, &mut visited_nodes);
-            add_visited_nodes(visited_nodes, &mut changes);
+        let mut changes = BTreeSet::new();
+        let mut matched_nodes = BTreeMap::new();
+
+        match (old.root.into_crate_internal(), new.root.into_crate_internal()) {
+            (Some(old_root), Some(new_root)) if node_has_changed(
+                &old_nodes.node_data[old_root],
+                &new_nodes.node_data[new_root]
+            ) == NODE_CHANGED_NOTHING => {
+                diff_tree_inner(old_root, new_root, &old_nodes, &new_nodes, &mut changes, &mut matched_nodes);
+            },
+            _ => {
+                // Root changed = everything changed
+                if old_nodes.node_data.len() != 0 {
+                    changes.insert(DomChange::Removed(DomRange {
+                        start: NodeId::ZERO,
+                        end: NodeId::new(old_nodes.node_data.len() - 1)
+                    }));
+                }
 
-            Self {
-                changed_nodes: optimize_changeset(changes)
+                if new_nodes.node_data.len() != 0 {
+                    changes.insert(DomChange::Added(DomRange {
+                        start: NodeId::ZERO,
+                        end: NodeId::new(new_nodes.node_data.len() - 1)
+                    }));
+                }
             }
+        }
 
-        } else {
-
-            // Root changed = everything changed
-            changes.insert(DomChange::Removed(DomRange {
-                start: NodeId::ZERO,
-                end: NodeId::new(old.len() - 1)
-            }));
+        Self {
+            changed_nodes: optimize_changeset(changes),
+            matched_nodes,
+        }
+    }
 
-            changes.insert(DomChange::Added(DomRange {
-                start: NodeId::ZERO,
-                end: NodeId::new(new.len() - 1)
-            }));
+    /// Returns all node IDs (of the new DOM) that were added
+    pub fn added_nodes<'a>(&'a self) -> impl Iterator<Item = NodeId> + 'a {
+        self.changed_nodes.iter().filter_map(|c| match c {
+            DomChange::Added(r) => Some(r.iter()),
+            DomChange::Removed(_) => None,
+        }).flatten()
+    }
 
-            Self {
-                changed_nodes: optimize_changeset(changes)
-            }
-        }
+    /// Returns all node IDs (of the old DOM) that were removed
+    pub fn removed_nodes<'a>(&'a self) -> impl Iterator<Item = NodeId> + 'a {
+        self.changed_nodes.iter().filter_map(|c| match c {
+            DomChange::Added(_) => None,
+            DomChange::Removed(r) => Some(r.iter()),
+        }).flatten()
     }
 
     /// Formats the diff into a git-like `+ Node1 / - Node3` form
-    pub fn format_nicely(&self, old: &CompactDom, new: &CompactDom) -> String {
+    pub fn format_nicely(&self, old: &StyledDom, new: &StyledDom) -> String {
         use self::DomChange::*;
         self.changed_nodes.iter().map(|change| {
             match change {
-                Added(c) => format!("+\t{}", new.arena.node_data[c.start]),
-                Removed(c) => format!("-\t{}", old.arena.node_data[c.start]),
+                Added(c) => format!("+\t{}", new.node_data.as_container()[c.start]),
+                Removed(c) => format!("-\t{}", old.node_data.as_container()[c.start]),
             }
         }).collect::<Vec<String>>().join("\r\n")
     }
@@ -146,117 +183,238 @@ impl DomRange {
     }
 }
 
-const NODE_CHANGED_NOTHING: u8  = 0x01;
-const NODE_CHANGED_TYPE: u8     = 0x02;
-const NODE_CHANGED_CLASSES: u8  = 0x04;
-const NODE_CHANGED_IDS: u8      = 0x08;
+struct DiffNodes<'a> {
+    node_data: NodeDataContainerRef<'a, NodeData>,
+    node_hierarchy: NodeDataContainerRef<'a, AzNode>,
+}
+
+impl<'a> DiffNodes<'a> {
+
+    /// Returns the range of the node + all its children (the nodes are stored depth-first)
+    fn subtree_range(&self, node_id: NodeId) -> DomRange {
+        let mut current = node_id;
+        loop {
+            if let Some(next) = self.node_hierarchy[current].next_sibling_id() {
+                return DomRange::new(node_id, NodeId::new(next.index() - 1));
+            }
+            match self.node_hierarchy[current].parent_id() {
+                Some(parent) => { current = parent; },
+                None => return DomRange::new(node_id, NodeId::new(self.node_hierarchy.len() - 1)),
+            }
+        }
+    }
+}
+
+const NODE_CHANGED_NOTHING: u8  = 0x00;
+const NODE_CHANGED_TYPE: u8     = 0x01;
+const NODE_CHANGED_IDS_AND_CLASSES: u8 = 0x02;
 
 // In order to test two DOM nodes for "equality", you'd need to
 // test if the node type, the classes and the ids are the same.
 // The rest of the attributes can be ignored, since they are not
 // used by the CSS engine.
 //
-// NOTE: The callbacks / etc. need to be changed!
+// NOTE: The text content of a node is not compared, changing the
+// text does not remove the node from the DOM
 #[inline]
 fn node_has_changed(old: &NodeData, new: &NodeData) -> u8 {
     let mut result = NODE_CHANGED_NOTHING;
 
-    if old.get_node_type() != new.get_node_type() {
-        result &= NODE_CHANGED_TYPE;
+    if old.get_node_type().get_path() != new.get_node_type().get_path() {
+        result |= NODE_CHANGED_TYPE;
     }
 
-    if old.get_classes() != new.get_classes() {
-        result &= NODE_CHANGED_CLASSES;
-    }
-
-    if old.get_ids() != new.get_ids() {
-        result &= NODE_CHANGED_IDS;
+    if old.get_ids_and_classes() != new.get_ids_and_classes() {
+        result |= NODE_CHANGED_IDS_AND_CLASSES;
     }
 
     result
 }
 
 fn diff_tree_inner(
-    old_root_id: NodeId,
-    old: &CompactDom,
-    new: &CompactDom,
+    old_parent_id: NodeId,
+    new_parent_id: NodeId,
+    old: &DiffNodes,
+    new: &DiffNodes,
     changes: &mut BTreeSet<DomChange>,
-    visited_nodes: &mut NodeDataContainer<bool>,
+    matched_nodes: &mut BTreeMap<NodeId, NodeId>,
 ) {
-    let mut node_shift = 0_isize;
+    matched_nodes.insert(old_parent_id, new_parent_id);
+
+    let old_children = old_parent_id.az_children_collect(&old.node_hierarchy);
+    let new_children = new_parent_id.az_children_collect(&new.node_hierarchy);
+
+    let is_same_node = |old_node_id: NodeId, new_node_id: NodeId| {
+        node_has_changed(&old.node_data[old_node_id], &new.node_data[new_node_id]) == NODE_CHANGED_NOTHING
+    };
+
+    // the common prefix and suffix of the children are matched linearly, so that
+    // appending / removing a child at the start or the end does not need a lookup
+    let mut prefix = 0;
+    while prefix < old_children.len() &&
+          prefix < new_children.len() &&
+          is_same_node(old_children[prefix], new_children[prefix]) {
+        prefix += 1;
+    }
 
-    for old_node_id in old_root_id.children(&old.arena.node_hierarchy) {
+    let mut suffix = 0;
+    while suffix < old_children.len() - prefix &&
+          suffix < new_children.len() - prefix &&
+          is_same_node(old_children[old_children.len() - 1 - suffix], new_children[new_children.len() - 1 - suffix]) {
+        suffix += 1;
+    }
 
-        // Node ID that corresponds to the same node in the new node tree
-        let new_node_id = NodeId::new((old_node_id.index() as isize + node_shift) as usize);
+    let old_middle = &old_children[prefix..(old_children.len() - suffix)];
+    let new_middle = &new_children[prefix..(new_children.len() - suffix)];
+
+    // the remaining children are matched by their key (node type + IDs / classes):
+    // every old child is matched with the first unused new child that has the same key
+    let mut new_children_by_key = BTreeMap::new();
+    for new_node_id in new_middle.iter() {
+        let node_data = &new.node_data[*new_node_id];
+        new_children_by_key
+            .entry((node_data.get_node_type().get_path(), node_data.get_ids_and_classes()))
+            .or_insert_with(|| VecDeque::new())
+            .push_back(*new_node_id);
+    }
 
-        let old_node_last_child = NodeId::new(match old.arena.node_hierarchy[old_node_id].next_sibling {
-            Some(s) => s.index(),
-            None => old.arena.node_hierarchy.len() - 1,
-        });
+    let mut matched_children = Vec::with_capacity(prefix + old_middle.len() + suffix);
+    matched_children.extend(old_children[..prefix].iter().copied().zip(new_children[..prefix].iter().copied()));
+    matched_children.extend(old_children[(old_children.len() - suffix)..].iter().copied().zip(new_children[(new_children.len() - suffix)..].iter().copied()));
+
+    for old_node_id in old_middle.iter() {
+        let node_data = &old.node_data[*old_node_id];
+        let key = (node_data.get_node_type().get_path(), node_data.get_ids_and_classes());
+        match new_children_by_key.get_mut(&key).and_then(|n| n.pop_front()) {
+            Some(new_node_id) => matched_children.push((*old_node_id, new_node_id)),
+            // remove entire old subtree, including the node itself
+            None => { changes.insert(DomChange::Removed(old.subtree_range(*old_node_id))); },
+        }
+    }
 
-        match new.arena.node_data.get(new_node_id) {
-            None => {
-                // Couldn't find the new node in the new tree, old tree has more children than new
-                changes.insert(DomChange::Removed(DomRange {
-                    start: old_node_id,
-                    end: old_node_last_child,
-                }));
-            },
-            Some(new_node_data) => {
+    // add entire new subtrees of the children that were not matched
+    for new_node_id in new_children_by_key.values().flat_map(|n| n.iter()) {
+        changes.insert(DomChange::Added(new.subtree_range(*new_node_id)));
+    }
 
-                visited_nodes[new_node_id] = true;
+    for (old_node_id, new_node_id) in matched_children {
+        diff_tree_inner(old_node_id, new_node_id, old, new, changes, matched_nodes);
+    }
+}
 
-                let old_node_data = &old.arena.node_data[old_node_id];
-                let compare_nodes = node_has_changed(old_node_data, new_node_data);
+fn optimize_changeset(changes: BTreeSet<DomChange>) -> Vec<DomChange> {
+    // TODO: optimize changeset into larger chunks!
+    changes.into_iter().collect()
+}
 
-                if compare_nodes == NODE_CHANGED_NOTHING {
-                    diff_tree_inner(old_node_id, old, new, changes, visited_nodes);
-                } else {
+#[test]
+fn test_dom_diff_replaced_subtree() {
 
-                    let new_node_subtree_len = new.arena.node_hierarchy.subtree_len(new_node_id);
+    use crate::dom::{Dom, IdOrClass};
+    use azul_css::Css;
 
-                    let next_node_id = match new.arena.node_hierarchy[new_node_id].next_sibling {
-                        Some(s) => s.index(),
-                        None => new.arena.node_hierarchy.len() - 1,
-                    };
+    let class = |s: &'static str| -> crate::dom::IdOrClassVec { vec![IdOrClass::Class(s.into())].into() };
 
-                    // remove entire old subtree, including the node itself
-                    changes.insert(DomChange::Removed(DomRange {
-                        start: old_node_id,
-                        end: old_node_last_child,
-                    }));
+    let old = Dom::body().with_children(vec![
+        Dom::div(),
+        Dom::text("hello"),
+        Dom::div().with_ids_and_classes(class("a")).with_children(vec![Dom::div()].into()),
+    ].into()).style(&mut Css::empty());
 
-                    // add entire new subtree, including the node itself
-                    changes.insert(DomChange::Added(DomRange {
-                        start: new_node_id,
-                        end: NodeId::new(next_node_id),
-                    }));
+    let new = Dom::body().with_children(vec![
+        Dom::div(),
+        Dom::div().with_ids_and_classes(class("b")).with_children(vec![Dom::div()].into()),
+    ].into()).style(&mut Css::empty());
 
-                    node_shift += new_node_subtree_len as isize;
+    let diff = DomDiff::new(&old, &new);
 
-                    for n in new_node_id.index()..next_node_id {
-                        visited_nodes[NodeId::new(n)] = true;
-                    }
-                }
-            }
-        }
-    }
+    // text node removed, div with a different class replaced including its child
+    assert_eq!(diff.removed_nodes().collect::<Vec<_>>(), vec![NodeId::new(2), NodeId::new(3), NodeId::new(4)]);
+    assert_eq!(diff.added_nodes().collect::<Vec<_>>(), vec![NodeId::new(2), NodeId::new(3)]);
+    assert_eq!(diff.matched_nodes.into_iter().collect::<Vec<_>>(), vec![
+        (NodeId::new(0), NodeId::new(0)),
+        (NodeId::new(1), NodeId::new(1)),
+    ]);
+
+    // same DOM = no changes
+    assert!(DomDiff::new(&old, &old).changed_nodes.is_empty());
 }
 
-fn add_visited_nodes(
-    visited_nodes: NodeDataContainer<bool>,
-    changes: &mut BTreeSet<DomChange>,
-) {
-    changes.extend(
-        visited_nodes
-        .linear_iter()
-        .filter_map(|node_id| if visited_nodes[node_id] { None } else { Some(node_id) })
-        .map(|node_id| DomChange::Added(DomRange::single_node(node_id)))
-    );
+#[test]
+fn test_dom_diff_inserted_and_removed_siblings() {
+
+    use crate::dom::{Dom, IdOrClass};
+    use azul_css::Css;
+
+    let class = |s: &'static str| -> crate::dom::IdOrClassVec { vec![IdOrClass::Class(s.into())].into() };
+
+    // body = 0, .a = 1, .b = 2 (+ child = 3), .c = 4
+    let old = Dom::body().with_children(vec![
+        Dom::div().with_ids_and_classes(class("a")),
+        Dom::div().with_ids_and_classes(class("b")).with_children(vec![Dom::text("b")].into()),
+        Dom::div().with_ids_and_classes(class("c")),
+    ].into()).style(&mut Css::empty());
+
+    // body = 0, .x = 1, .b = 2 (+ child = 3), .c = 4, .d = 5
+    let new = Dom::body().with_children(vec![
+        Dom::div().with_ids_and_classes(class("x")),
+        Dom::div().with_ids_and_classes(class("b")).with_children(vec![Dom::text("changed text")].into()),
+        Dom::div().with_ids_and_classes(class("c")),
+        Dom::div().with_ids_and_classes(class("d")),
+    ].into()).style(&mut Css::empty());
+
+    let diff = DomDiff::new(&old, &new);
+
+    // the siblings after the replaced node are not re-mounted
+    assert_eq!(diff.removed_nodes().collect::<Vec<_>>(), vec![NodeId::new(1)]);
+    assert_eq!(diff.added_nodes().collect::<Vec<_>>(), vec![NodeId::new(1), NodeId::new(5)]);
+
+    // inserting a node at the front shifts the node IDs of the following siblings
+    let diff = DomDiff::new(&new, &Dom::body().with_children(vec![
+        Dom::div().with_ids_and_classes(class("first")),
+        Dom::div().with_ids_and_classes(class("b")).with_children(vec![Dom::text("b")].into()),
+        Dom::div().with_ids_and_classes(class("d")),
+    ].into()).style(&mut Css::empty()));
+
+    assert_eq!(diff.removed_nodes().collect::<Vec<_>>(), vec![NodeId::new(1), NodeId::new(4)]);
+    assert_eq!(diff.added_nodes().collect::<Vec<_>>(), vec![NodeId::new(1)]);
+    assert_eq!(diff.matched_nodes.into_iter().collect::<Vec<_>>(), vec![
+        (NodeId::new(0), NodeId::new(0)),
+        (NodeId::new(2), NodeId::new(2)),
+        (NodeId::new(3), NodeId::new(3)),
+        (NodeId::new(5), NodeId::new(4)),
+    ]);
 }
 
-fn optimize_changeset(changes: BTreeSet<DomChange>) -> Vec<DomChange> {
-    // TODO: optimize changeset into larger chunks!
-    changes.into_iter().collect()
+#[test]
+fn test_dom_diff_reordered_siblings() {
+
+    use crate::dom::{Dom, IdOrClass};
+    use azul_css::Css;
+
+    let class = |s: &'static str| -> crate::dom::IdOrClassVec { vec![IdOrClass::Class(s.into())].into() };
+
+    // body = 0, .a = 1, .b = 2, .c = 3
+    let old = Dom::body().with_children(vec![
+        Dom::div().with_ids_and_classes(class("a")),
+        Dom::div().with_ids_and_classes(class("b")),
+        Dom::div().with_ids_and_classes(class("c")),
+    ].into()).style(&mut Css::empty());
+
+    // body = 0, .b = 1, .a = 2, .c = 3
+    let new = Dom::body().with_children(vec![
+        Dom::div().with_ids_and_classes(class("b")),
+        Dom::div().with_ids_and_classes(class("a")),
+        Dom::div().with_ids_and_classes(class("c")),
+    ].into()).style(&mut Css::empty());
+
+    // moved nodes are matched by their key instead of being re-mounted
+    let diff = DomDiff::new(&old, &new);
+    assert!(diff.changed_nodes.is_empty());
+    assert_eq!(diff.matched_nodes.into_iter().collect::<Vec<_>>(), vec![
+        (NodeId::new(0), NodeId::new(0)),
+        (NodeId::new(1), NodeId::new(2)),
+        (NodeId::new(2), NodeId::new(1)),
+        (NodeId::new(3), NodeId::new(3)),
+    ]);
 }
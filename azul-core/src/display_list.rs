@@ -566,6 +566,8 @@ unsafe impl Send for GlTextureCache { } // necessary so the display list can be
 
 // todo: very unclean
 pub type LayoutFn = fn(StyledDom, &ImageCache, &FcFontCache, &mut RendererResources, &mut Vec<ResourceUpdate>, IdNamespace, &DocumentId, Epoch, &RenderCallbacks, &FullWindowState) -> Vec<LayoutResult>;
+// (parent DomId, IFrame DomId, IFrame DOM, IFrame bounds, ...) - lays out the DOM of an IFrame that was re-invoked
+pub type LayoutIFrameFn = fn(DomId, DomId, StyledDom, LogicalRect, &ImageCache, &FcFontCache, &mut RendererResources, &mut Vec<ResourceUpdate>, IdNamespace, &DocumentId, Epoch, &RenderCallbacks, &FullWindowState) -> LayoutResult;
 pub type GlStoreImageFn = fn(DocumentId, Epoch, Texture) -> ExternalImageId;
 
 #[derive(Debug, Default)]
@@ -577,6 +579,7 @@ pub struct SolvedLayout {
 pub struct RenderCallbacks {
     pub insert_into_active_gl_textures_fn: GlStoreImageFn,
     pub layout_fn: LayoutFn,
+    pub layout_iframe_fn: LayoutIFrameFn,
     pub load_font_fn: LoadFontFn,
     pub parse_font_fn: ParseFontFn,
 }
@@ -1010,26 +1013,51 @@ pub fn displaylist_handle_rect<'a>(
                 positioned_rect.resolved_text_layout_options.as_ref(),
             ) {
 
+                use crate::app_resources::font_size_to_au;
+                use crate::styled_dom::StyleFontFamiliesHash;
+
                 let inline_text = get_inline_text(&words, &shaped_words, &word_positions.0, &inline_text_layout);
-                let layouted_glyphs = inline_text.get_layouted_glyphs();
 
-                if !layouted_glyphs.glyphs.is_empty() {
+                // glyphs from fallback fonts need their own text item with the font instance of the fallback font
+                let layouted_glyphs_by_font = inline_text.get_layouted_glyphs_by_font();
+
+                if !layouted_glyphs_by_font.is_empty() {
 
-                    let font_instance_key = word_positions.1;
-                    let text_color = layout_result.styled_dom.get_css_property_cache()
+                    let css_property_cache = layout_result.styled_dom.get_css_property_cache();
+                    let text_color = css_property_cache
                     .get_text_color_or_default(&html_node, &rect_idx, &styled_node.state);
-                    let overflow_horizontal_visible = layout_result.styled_dom.get_css_property_cache()
+                    let overflow_horizontal_visible = css_property_cache
                     .is_horizontal_overflow_visible(&html_node, &rect_idx, &styled_node.state);
-                    let overflow_vertical_visible = layout_result.styled_dom.get_css_property_cache()
+                    let overflow_vertical_visible = css_property_cache
                     .is_vertical_overflow_visible(&html_node, &rect_idx, &styled_node.state);
-
-                    frame.content.push(LayoutRectContent::Text {
-                       glyphs: layouted_glyphs.glyphs,
-                       font_instance_key,
-                       color: text_color.inner,
-                       glyph_options: None,
-                       overflow: (overflow_horizontal_visible, overflow_vertical_visible),
-                    });
+                    let css_font_families = css_property_cache
+                    .get_font_id_or_default(&html_node, &rect_idx, &styled_node.state);
+                    let css_font_families_hash = StyleFontFamiliesHash::new(css_font_families.as_ref());
+                    let font_size_au = font_size_to_au(css_property_cache
+                    .get_font_size_or_default(&html_node, &rect_idx, &styled_node.state));
+
+                    for (fallback_font_index, layouted_glyphs) in layouted_glyphs_by_font {
+
+                        let font_instance_key = match fallback_font_index {
+                            0 => word_positions.1,
+                            _ => match renderer_resources.get_fallback_font_instance_key(
+                                &css_font_families_hash,
+                                fallback_font_index,
+                                font_size_au,
+                            ) {
+                                Some(s) => s,
+                                None => continue,
+                            },
+                        };
+
+                        frame.content.push(LayoutRectContent::Text {
+                           glyphs: layouted_glyphs.glyphs,
+                           font_instance_key,
+                           color: text_color.inner,
+                           glyph_options: None,
+                           overflow: (overflow_horizontal_visible, overflow_vertical_visible),
+                        });
+                    }
                 }
             }
         },
@@ -1091,13 +1119,25 @@ pub fn displaylist_handle_rect<'a>(
                     referenced_content.renderer_resources,
                     referenced_content.image_cache,
                 );
-                let iframe_clip_size = positioned_rect.size;
-                frame.children.push(DisplayListMsg::IFrame(
+
+                // the rendered DOM of the IFrame is positioned at the virtual scroll
+                // offset inside of the (virtual) scroll frame of the IFrame node
+                let (iframe_origin, iframe_clip_size) = match layout_result.scrollable_nodes.overflowing_nodes.get(&AzNodeId::from_crate_internal(Some(rect_idx))) {
+                    Some(scroll_node) => (scroll_node.get_virtual_scroll_offset(), scroll_node.child_rect.size),
+                    None => (LogicalPosition::zero(), positioned_rect.size),
+                };
+
+                let mut iframe_frame = DisplayListFrame::root(
+                    LayoutSize::zero(),
+                    LayoutPoint::new(libm::roundf(iframe_origin.x) as isize, libm::roundf(iframe_origin.y) as isize),
+                );
+                iframe_frame.children.push(DisplayListMsg::IFrame(
                     iframe_pipeline_id,
                     iframe_clip_size,
                     referenced_content.epoch,
                     Box::new(cached_display_list))
                 );
+                frame.children.push(DisplayListMsg::Frame(iframe_frame));
             }
         },
     };
@@ -1128,7 +1168,7 @@ pub fn displaylist_handle_rect<'a>(
     match layout_result.scrollable_nodes.overflowing_nodes.get(&AzNodeId::from_crate_internal(Some(rect_idx))) {
         Some(scroll_node) => Some(DisplayListMsg::ScrollFrame(DisplayListScrollFrame {
             parent_rect: scroll_node.parent_rect,
-            content_rect: scroll_node.child_rect,
+            content_rect: scroll_node.virtual_child_rect,
             scroll_id: scroll_node.parent_external_scroll_id,
             scroll_tag: scroll_node.scroll_tag_id,
             frame,
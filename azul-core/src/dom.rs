@@ -667,6 +667,8 @@ pub struct NodeDataExt {
     pub(crate) menu_bar: Option<Box<Menu>>,
     /// Context menu that should be opened when the item is left-clicked
     pub(crate) context_menu: Option<Box<Menu>>,
+    /// String attributes such as `data-state: open`, matched by CSS attribute selectors (`[data-state=open]`)
+    pub(crate) attributes: BTreeMap<AzString, AzString>,
 
     // ... insert further API extensions here...
 }
@@ -1097,6 +1099,10 @@ impl NodeData {
     pub fn get_menu_bar(&self) -> Option<&Box<Menu>> { self.extra.as_ref().and_then(|e| e.menu_bar.as_ref()) }
     #[inline]
     pub fn get_context_menu(&self) -> Option<&Box<Menu>> { self.extra.as_ref().and_then(|e| e.context_menu.as_ref()) }
+    #[inline]
+    pub fn get_attribute(&self, name: &str) -> Option<&str> {
+        self.extra.as_ref().and_then(|e| e.attributes.iter().find(|(k, _)| k.as_str() == name)).map(|(_, v)| v.as_str())
+    }
 
     #[inline(always)]
     pub fn set_node_type(&mut self, node_type: NodeType) { self.node_type = node_type; }
@@ -1134,6 +1140,11 @@ impl NodeData {
         .context_menu = Some(Box::new(context_menu));
     }
     #[inline]
+    pub fn set_attribute(&mut self, name: AzString, value: AzString) {
+        self.extra.get_or_insert_with(|| Box::new(NodeDataExt::default()))
+        .attributes.insert(name, value);
+    }
+    #[inline]
     pub fn add_callback(&mut self, event: EventFilter, data: RefAny, callback: CallbackType) {
         let mut v: CallbackDataVec = Vec::new().into();
         mem::swap(&mut v, &mut self.callbacks);
@@ -1203,6 +1214,10 @@ impl NodeData {
     #[inline(always)]
     pub fn with_tab_index(mut self, tab_index: TabIndex) -> Self { self.set_tab_index(tab_index); self }
     #[inline(always)]
+    pub fn with_accessibility_info(mut self, accessibility_info: AccessibilityInfo) -> Self { self.set_accessibility_info(accessibility_info); self }
+    #[inline(always)]
+    pub fn with_attribute(mut self, name: AzString, value: AzString) -> Self { self.set_attribute(name, value); self }
+    #[inline(always)]
     pub fn with_dataset(mut self, data: OptionRefAny) -> Self { self.dataset = data; self }
     #[inline(always)]
     pub fn with_ids_and_classes(mut self, ids_and_classes: IdOrClassVec) -> Self { self.ids_and_classes = ids_and_classes; self }
@@ -1366,6 +1381,10 @@ impl Dom {
     #[inline(always)]
     pub fn with_tab_index(mut self, tab_index: TabIndex) -> Self { self.root.set_tab_index(tab_index); self }
     #[inline(always)]
+    pub fn with_accessibility_info(mut self, accessibility_info: AccessibilityInfo) -> Self { self.root.set_accessibility_info(accessibility_info); self }
+    #[inline(always)]
+    pub fn with_attribute(mut self, name: AzString, value: AzString) -> Self { self.root.set_attribute(name, value); self }
+    #[inline(always)]
     pub fn with_dataset(mut self, data: OptionRefAny) -> Self { self.root.dataset = data; self }
     #[inline(always)]
     pub fn with_ids_and_classes(mut self, ids_and_classes: IdOrClassVec) -> Self { self.root.ids_and_classes = ids_and_classes; self }
@@ -1497,21 +1516,16 @@ fn convert_dom_into_compact_dom(mut dom: Dom) -> CompactDom {
 #[test]
 fn test_compact_dom_conversion() {
 
-    let dom: Dom = Dom::body()
-        .with_child(Dom::div().with_class("class1"))
-        .with_child(Dom::div().with_class("class1")
-            .with_child(Dom::div().with_id("child_2"))
-        )
-        .with_child(Dom::div().with_class("class1"));
-
-    let c0: Vec<AzString> = vec!["class1".to_string().into()];
-    let c0: StringVec = c0.into();
-    let c1: Vec<AzString> = vec!["class1".to_string().into()];
-    let c1: StringVec = c1.into();
-    let c2: Vec<AzString> = vec!["child_2".to_string().into()];
-    let c2: StringVec = c2.into();
-    let c3: Vec<AzString> = vec!["class1".to_string().into()];
-    let c3: StringVec = c3.into();
+    let class1 = || -> IdOrClassVec { vec![IdOrClass::Class("class1".into())].into() };
+    let child_2 = || -> IdOrClassVec { vec![IdOrClass::Id("child_2".into())].into() };
+
+    let dom: Dom = Dom::body().with_children(vec![
+        Dom::div().with_ids_and_classes(class1()),
+        Dom::div().with_ids_and_classes(class1()).with_children(vec![
+            Dom::div().with_ids_and_classes(child_2()),
+        ].into()),
+        Dom::div().with_ids_and_classes(class1()),
+    ].into());
 
     let expected_dom: CompactDom = CompactDom {
         root: NodeId::ZERO,
@@ -1520,44 +1534,39 @@ fn test_compact_dom_conversion() {
                 parent: None,
                 previous_sibling: None,
                 next_sibling: None,
-                first_child: Some(NodeId::new(1)),
                 last_child: Some(NodeId::new(4)),
             },
             Node /* 1 */ {
                 parent: Some(NodeId::new(0)),
                 previous_sibling: None,
                 next_sibling: Some(NodeId::new(2)),
-                first_child: None,
                 last_child: None,
             },
             Node /* 2 */ {
                 parent: Some(NodeId::new(0)),
                 previous_sibling: Some(NodeId::new(1)),
                 next_sibling: Some(NodeId::new(4)),
-                first_child: Some(NodeId::new(3)),
                 last_child: Some(NodeId::new(3)),
             },
             Node /* 3 */ {
                 parent: Some(NodeId::new(2)),
                 previous_sibling: None,
                 next_sibling: None,
-                first_child: None,
                 last_child: None,
             },
             Node /* 4 */ {
                 parent: Some(NodeId::new(0)),
                 previous_sibling: Some(NodeId::new(2)),
                 next_sibling: None,
-                first_child: None,
                 last_child: None,
             },
         ]},
         node_data: NodeDataContainer { internal: vec![
             /* 0 */    NodeData::body(),
-            /* 1 */    NodeData::div().with_classes(c0),
-            /* 2 */    NodeData::div().with_classes(c1),
-            /* 3 */    NodeData::div().with_ids(c2),
-            /* 4 */    NodeData::div().with_classes(c3),
+            /* 1 */    NodeData::div().with_ids_and_classes(class1()),
+            /* 2 */    NodeData::div().with_ids_and_classes(class1()),
+            /* 3 */    NodeData::div().with_ids_and_classes(child_2()),
+            /* 4 */    NodeData::div().with_ids_and_classes(class1()),
         ]},
     };
 
@@ -1570,74 +1579,67 @@ fn test_compact_dom_conversion() {
 #[test]
 fn test_dom_sibling_1() {
 
+    let id = |s: &'static str| -> IdOrClassVec { vec![IdOrClass::Id(s.into())].into() };
+
     let dom: Dom =
-        Dom::div()
-            .with_child(
-                Dom::div()
-                .with_id("sibling-1")
-                .with_child(Dom::div()
-                    .with_id("sibling-1-child-1")))
-            .with_child(Dom::div()
-                .with_id("sibling-2")
-                .with_child(Dom::div()
-                    .with_id("sibling-2-child-1")));
+        Dom::div().with_children(vec![
+            Dom::div()
+            .with_ids_and_classes(id("sibling-1"))
+            .with_children(vec![Dom::div()
+                .with_ids_and_classes(id("sibling-1-child-1"))].into()),
+            Dom::div()
+            .with_ids_and_classes(id("sibling-2"))
+            .with_children(vec![Dom::div()
+                .with_ids_and_classes(id("sibling-2-child-1"))].into()),
+        ].into());
 
     let dom = convert_dom_into_compact_dom(dom);
 
-    let arena = &dom.arena;
+    let node_hierarchy = dom.node_hierarchy.as_ref();
+    let node_data = dom.node_data.as_ref();
+
+    // first_child is not stored in the node, it is always the next node
+    let first_child = |node_id: NodeId| node_hierarchy[node_id].last_child.map(|_| node_id + 1);
 
     assert_eq!(NodeId::new(0), dom.root);
 
-    let v: Vec<AzString> = vec!["sibling-1".to_string().into()];
-    let v: StringVec = v.into();
-    assert_eq!(v,
-        arena.node_data[
-            arena.node_hierarchy[dom.root]
-            .first_child.expect("root has no first child")
-        ].ids);
-
-    let v: Vec<AzString> = vec!["sibling-2".to_string().into()];
-    let v: StringVec = v.into();
-    assert_eq!(v,
-        arena.node_data[
-            arena.node_hierarchy[
-                arena.node_hierarchy[dom.root]
-                .first_child.expect("root has no first child")
+    assert_eq!(id("sibling-1"),
+        node_data[
+            first_child(dom.root).expect("root has no first child")
+        ].ids_and_classes);
+
+    assert_eq!(id("sibling-2"),
+        node_data[
+            node_hierarchy[
+                first_child(dom.root).expect("root has no first child")
             ].next_sibling.expect("root has no second sibling")
-        ].ids);
-
-    let v: Vec<AzString> = vec!["sibling-1-child-1".to_string().into()];
-    let v: StringVec = v.into();
-    assert_eq!(v,
-        arena.node_data[
-            arena.node_hierarchy[
-                arena.node_hierarchy[dom.root]
-                .first_child.expect("root has no first child")
-            ].first_child.expect("first child has no first child")
-        ].ids);
-
-    let v: Vec<AzString> = vec!["sibling-2-child-1".to_string().into()];
-    let v: StringVec = v.into();
-    assert_eq!(v,
-        arena.node_data[
-            arena.node_hierarchy[
-                arena.node_hierarchy[
-                    arena.node_hierarchy[dom.root]
-                    .first_child.expect("root has no first child")
+        ].ids_and_classes);
+
+    assert_eq!(id("sibling-1-child-1"),
+        node_data[
+            first_child(
+                first_child(dom.root).expect("root has no first child")
+            ).expect("first child has no first child")
+        ].ids_and_classes);
+
+    assert_eq!(id("sibling-2-child-1"),
+        node_data[
+            first_child(
+                node_hierarchy[
+                    first_child(dom.root).expect("root has no first child")
                 ].next_sibling.expect("first child has no second sibling")
-            ].first_child.expect("second sibling has no first child")
-        ].ids);
+            ).expect("second sibling has no first child")
+        ].ids_and_classes);
 }
 
 #[test]
 fn test_dom_from_iter_1() {
 
-    use crate::id_tree::Node;
-
-    let dom: Dom = (0..5).map(|e| NodeData::new(NodeType::Label(format!("{}", e + 1).into()))).collect();
+    let dom: Dom = Dom::div().with_children((0..5).map(|e| Dom::text(format!("{}", e + 1))).collect::<Vec<_>>().into());
     let dom = convert_dom_into_compact_dom(dom);
 
-    let arena = &dom.arena;
+    let node_hierarchy = dom.node_hierarchy.as_ref();
+    let node_data = dom.node_data.as_ref();
 
     // We need to have 6 nodes:
     //
@@ -1648,38 +1650,65 @@ fn test_dom_from_iter_1() {
     //   |-> 4              NodeId(4)
     //   '-> 5              NodeId(5)
 
-    assert_eq!(arena.len(), 6);
+    assert_eq!(dom.len(), 6);
 
     // Check root node
-    assert_eq!(arena.node_hierarchy.get(NodeId::new(0)), Some(&Node {
+    assert_eq!(node_hierarchy.get(NodeId::new(0)), Some(&Node {
         parent: None,
         previous_sibling: None,
         next_sibling: None,
-        first_child: Some(NodeId::new(1)),
         last_child: Some(NodeId::new(5)),
     }));
-    assert_eq!(arena.node_data.get(NodeId::new(0)), Some(&NodeData::new(NodeType::Div)));
+    assert_eq!(node_data.get(NodeId::new(0)), Some(&NodeData::new(NodeType::Div)));
 
-    assert_eq!(arena.node_hierarchy.get(NodeId::new(arena.node_hierarchy.len() - 1)), Some(&Node {
+    assert_eq!(node_hierarchy.get(NodeId::new(node_hierarchy.len() - 1)), Some(&Node {
         parent: Some(NodeId::new(0)),
         previous_sibling: Some(NodeId::new(4)),
         next_sibling: None,
-        first_child: None,
         last_child: None,
     }));
 
-    assert_eq!(arena.node_data.get(NodeId::new(arena.node_data.len() - 1)), Some(&NodeData {
-        node_type: NodeType::Label("5".to_string().into()),
-        .. Default::default()
-    }));
+    assert_eq!(node_data.get(NodeId::new(node_data.len() - 1)), Some(&NodeData::new(NodeType::Text("5".to_string().into()))));
 }
 
 /// Test that there shouldn't be a DOM that has 0 nodes
 #[test]
 fn test_zero_size_dom() {
 
-    let null_dom: Dom = (0..0).map(|_| NodeData::default()).collect();
+    let null_dom: Dom = Dom::div().with_children(Vec::new().into());
     let null_dom = convert_dom_into_compact_dom(null_dom);
 
-    assert!(null_dom.arena.len() == 1);
+    assert!(null_dom.len() == 1);
+}
+
+#[test]
+fn test_tab_order() {
+
+    // body                             NodeId(0)
+    //  |- div (Auto)                   NodeId(1)
+    //  |   |- div (OverrideInParent 5) NodeId(2)
+    //  |   |- div (OverrideInParent 2) NodeId(3)
+    //  |   |- div (NoKeyboardFocus)    NodeId(4)
+    //  |       |- div (Auto)           NodeId(5)
+    //  |- div                          NodeId(6)
+    //  |- div (Auto)                   NodeId(7)
+    let mut dom = Dom::body()
+    .with_children(vec![
+        Dom::div().with_tab_index(TabIndex::Auto)
+        .with_children(vec![
+            Dom::div().with_tab_index(TabIndex::OverrideInParent(5)),
+            Dom::div().with_tab_index(TabIndex::OverrideInParent(2)),
+            Dom::div().with_tab_index(TabIndex::NoKeyboardFocus)
+            .with_children(vec![Dom::div().with_tab_index(TabIndex::Auto)].into()),
+        ].into()),
+        Dom::div(),
+        Dom::div().with_tab_index(TabIndex::Auto),
+    ].into());
+
+    let styled_dom = StyledDom::new(&mut dom, &mut Css::empty());
+
+    assert_eq!(
+        styled_dom.get_tab_order(),
+        vec![NodeId::new(1), NodeId::new(3), NodeId::new(2), NodeId::new(5), NodeId::new(7)]
+    );
 }
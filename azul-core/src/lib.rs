@@ -29,8 +29,8 @@ pub mod app_resources;
 pub mod display_list;
 /// `Dom` construction, `NodeData` and `NodeType` management functions
 pub mod dom;
-// Algorithms to create git-like diffs between two doms in linear time
-// pub mod diff;
+/// Algorithms to create git-like diffs between two doms in linear time
+pub mod diff;
 /// Contains OpenGL helper functions (to compile / link shaders), `VirtualGlDriver` for unit testing
 pub mod gl;
 /// Internal, arena-based storage for Dom nodes
@@ -53,6 +53,8 @@ pub mod ui_solver;
 pub mod window;
 /// Window state handling / synchronization
 pub mod window_state;
+/// Platform-neutral accessibility tree, built from the laid-out DOMs
+pub mod accessibility;
 
 // Typedef for possible faster implementation of hashing
 pub type FastHashMap<T, U> = alloc::collections::BTreeMap<T, U>;
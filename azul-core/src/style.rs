@@ -2,11 +2,11 @@
 
 use azul_css::{
     CssContentGroup, CssPath, CssPathSelector,
-    CssPathPseudoSelector, CssNthChildSelector::*,
+    CssPathPseudoSelector, CssNthChildSelector, CssNthChildSelector::*,
 };
 use alloc::vec::Vec;
 use crate::{
-    dom::NodeData,
+    dom::{NodeData, NodeType},
     styled_dom::AzNode,
     id_tree::{NodeId, NodeHierarchyRef, NodeDataContainer, NodeDataContainerRef},
 };
@@ -42,58 +42,102 @@ pub(crate) fn matches_html_element(
     expected_path_ending: Option<CssPathPseudoSelector>,
 ) -> bool {
 
-    use self::CssGroupSplitReason::*;
-
     if css_path.selectors.is_empty() {
         return false;
     }
 
-    let mut current_node = Some(node_id);
-    let mut direct_parent_has_to_match = false;
-    let mut last_selector_matched = true;
-
-    let mut iterator = CssGroupIterator::new(css_path.selectors.as_ref());
-    while let Some((content_group, reason)) = iterator.next() {
-
-        let is_last_content_group = iterator.is_last_content_group();
-        let cur_node_id = match current_node {
-            Some(c) => c,
-            None => {
-                // The node has no parent, but the CSS path
-                // still has an extra limitation - only valid if the
-                // next content group is a "*" element
-                return *content_group == [&CssPathSelector::Global];
-            },
-        };
+    let content_groups = CssGroupIterator::new(css_path.selectors.as_ref()).collect::<Vec<_>>();
+
+    content_groups_match(
+        &content_groups,
+        node_id,
+        node_hierarchy,
+        node_data,
+        html_node_tree,
+        expected_path_ending,
+        /* is_last_content_group = */ true,
+    )
+}
 
-        let current_selector_matches = selector_group_matches(
-            &content_group,
-            &html_node_tree[cur_node_id],
-            &node_data[cur_node_id],
-            expected_path_ending,
-            is_last_content_group
-        );
-
-        if direct_parent_has_to_match && !current_selector_matches {
-            // If the element was a ">" element and the current,
-            // direct parent does not match, return false
-            return false; // not executed (maybe this is the bug)
-        }
+/// Matches the content groups (ordered from the last to the first group of the CSS path)
+/// against the node, then recursively matches the remaining groups against
+/// the parents / previous siblings of the node, depending on the combinator
+fn content_groups_match(
+    content_groups: &[(CssContentGroup, CssGroupSplitReason)],
+    node_id: NodeId,
+    node_hierarchy: &NodeDataContainerRef<AzNode>,
+    node_data: &NodeDataContainerRef<NodeData>,
+    html_node_tree: &NodeDataContainerRef<CascadeInfo>,
+    expected_path_ending: Option<CssPathPseudoSelector>,
+    is_last_content_group: bool,
+) -> bool {
 
-        // If the current selector matches, but the previous one didn't,
-        // that means that the CSS path chain is broken and therefore doesn't match the element
-        if current_selector_matches && !last_selector_matched {
-            return false;
-        }
+    use self::CssGroupSplitReason::*;
+
+    let ((content_group, reason), next_groups) = match content_groups.split_first() {
+        Some(s) => s,
+        None => return true,
+    };
+
+    if !selector_group_matches(
+        &content_group,
+        &html_node_tree[node_id],
+        &node_data[node_id],
+        node_id,
+        node_hierarchy,
+        expected_path_ending,
+        is_last_content_group,
+    ) {
+        return false;
+    }
 
-        // Important: Set if the current selector has matched the element
-        last_selector_matched = current_selector_matches;
-        // Select if the next content group has to exactly match or if it can potentially be skipped
-        direct_parent_has_to_match = reason == DirectChildren;
-        current_node = node_hierarchy[cur_node_id].parent_id();
+    if next_groups.is_empty() {
+        return true;
     }
 
-    last_selector_matched
+    let groups_match = |n: NodeId| content_groups_match(next_groups, n, node_hierarchy, node_data, html_node_tree, expected_path_ending, false);
+
+    match reason {
+        Children | DirectChildren => {
+            let parent_id = match node_hierarchy[node_id].parent_id() {
+                Some(s) => s,
+                None => {
+                    // The node has no parent, but the CSS path
+                    // still has an extra limitation - only valid if the
+                    // next content group is a "*" element
+                    return next_groups.len() == 1 && next_groups[0].0 == [&CssPathSelector::Global];
+                },
+            };
+            if *reason == DirectChildren {
+                groups_match(parent_id)
+            } else {
+                let mut current = Some(parent_id);
+                while let Some(ancestor_id) = current {
+                    if groups_match(ancestor_id) {
+                        return true;
+                    }
+                    current = node_hierarchy[ancestor_id].parent_id();
+                }
+                false
+            }
+        },
+        AdjacentSibling => {
+            match node_hierarchy[node_id].previous_sibling_id() {
+                Some(sibling_id) => groups_match(sibling_id),
+                None => false,
+            }
+        },
+        GeneralSibling => {
+            let mut current = node_hierarchy[node_id].previous_sibling_id();
+            while let Some(sibling_id) = current {
+                if groups_match(sibling_id) {
+                    return true;
+                }
+                current = node_hierarchy[sibling_id].previous_sibling_id();
+            }
+            false
+        },
+    }
 }
 
 /// A CSS group is a group of css selectors in a path that specify the rule that a
@@ -108,6 +152,9 @@ pub(crate) fn matches_html_element(
 /// The CssGroupIterator splits the CSS path into semantic blocks, i.e.:
 ///
 /// "body > .foo.main > #baz" will be split into ["body", ".foo.main" and "#baz"]
+///
+/// The groups are returned from the last to the first group, together with
+/// the combinator that separates the group from the next returned group.
 pub(crate) struct CssGroupIterator<'a> {
     pub css_path: &'a [CssPathSelector],
     pub current_idx: usize,
@@ -120,6 +167,10 @@ pub(crate) enum CssGroupSplitReason {
     Children,
     /// ".foo > .main" - match only direct children
     DirectChildren,
+    /// ".foo + .main" - match only the directly preceding sibling
+    AdjacentSibling,
+    /// ".foo ~ .main" - match any preceding sibling
+    GeneralSibling,
 }
 
 impl<'a> CssGroupIterator<'a> {
@@ -131,9 +182,6 @@ impl<'a> CssGroupIterator<'a> {
             last_reason: CssGroupSplitReason::Children,
         }
     }
-    pub fn is_last_content_group(&self) -> bool {
-        self.current_idx.saturating_add(1) == self.css_path.len().saturating_sub(1)
-    }
 }
 
 impl<'a> Iterator for CssGroupIterator<'a> {
@@ -160,6 +208,14 @@ impl<'a> Iterator for CssGroupIterator<'a> {
                     self.last_reason = CssGroupSplitReason::DirectChildren;
                     break;
                 },
+                AdjacentSibling => {
+                    self.last_reason = CssGroupSplitReason::AdjacentSibling;
+                    break;
+                },
+                GeneralSibling => {
+                    self.last_reason = CssGroupSplitReason::GeneralSibling;
+                    break;
+                },
                 other => current_path.push(other),
             }
             new_idx -= 1;
@@ -179,7 +235,7 @@ impl<'a> Iterator for CssGroupIterator<'a> {
                 Some((current_path, self.last_reason))
             }
         } else {
-            // skip the "Children | DirectChildren" element itself
+            // skip the "Children | DirectChildren | AdjacentSibling | GeneralSibling" element itself
             self.current_idx = new_idx - 1;
             Some((current_path, self.last_reason))
         }
@@ -222,25 +278,18 @@ pub(crate) fn construct_html_cascade_tree(
     NodeDataContainer { internal: nodes }
 }
 
-/// TODO: This is wrong, but it's fast
+/// Returns whether the last content group of the path contains the `target`
+/// pseudo-class (`:hover`, `:active`, `:focus` or `:focus-within`) - or no such
+/// pseudo-class at all if `target` is `None`
 #[inline]
 pub fn rule_ends_with(path: &CssPath, target: Option<CssPathPseudoSelector>) -> bool {
-    match target {
-        None => match path.selectors.as_ref().last() {
-            None => false,
-            Some(q) => match q {
-                CssPathSelector::PseudoSelector(_) => false,
-                _ => true
-            }
-        },
-        Some(s) => match path.selectors.as_ref().last() {
-            None => false,
-            Some(q) => match q {
-                CssPathSelector::PseudoSelector(q) => *q == s,
-                _ => false
-            }
-        }
-    }
+    let dynamic_pseudo_selector = path.selectors.as_ref().iter().rev()
+        .take_while(|selector| !selector.is_combinator())
+        .find_map(|selector| match selector {
+            CssPathSelector::PseudoSelector(p) if p.is_dynamic() => Some(*p),
+            _ => None,
+        });
+    !path.selectors.is_empty() && dynamic_pseudo_selector == target
 }
 
 /// Matches a single group of items, panics on Children or DirectChildren selectors
@@ -251,6 +300,8 @@ pub(crate) fn selector_group_matches(
     selectors: &[&CssPathSelector],
     html_node: &CascadeInfo,
     node_data: &NodeData,
+    node_id: NodeId,
+    node_hierarchy: &NodeDataContainerRef<AzNode>,
     expected_path_ending: Option<CssPathPseudoSelector>,
     is_last_content_group: bool,
 ) -> bool {
@@ -286,18 +337,34 @@ pub(crate) fn selector_group_matches(
                         if !html_node.is_last_child { return false; }
                     },
                     CssPathPseudoSelector::NthChild(x) => {
-                        use azul_css::CssNthChildPattern;
                         let index_in_parent = html_node.index_in_parent + 1; // nth-child starts at 1!
-                        match *x {
-                            Number(value) => if index_in_parent != value { return false; },
-                            Even => if index_in_parent % 2 == 0 { return false; },
-                            Odd => if index_in_parent % 2 == 1 { return false; },
-                            Pattern(CssNthChildPattern { repeat, offset }) => {
-                                if index_in_parent >= offset &&
-                                   ((index_in_parent - offset) % repeat != 0) {
-                                    return false;
-                                }
-                            },
+                        if !nth_child_matches(x, index_in_parent) { return false; }
+                    },
+                    CssPathPseudoSelector::NthLastChild(x) => {
+                        let mut index_from_end = 1;
+                        let mut next_sibling = node_hierarchy[node_id].next_sibling_id();
+                        while let Some(sibling_id) = next_sibling {
+                            index_from_end += 1;
+                            next_sibling = node_hierarchy[sibling_id].next_sibling_id();
+                        }
+                        if !nth_child_matches(x, index_from_end) { return false; }
+                    },
+                    CssPathPseudoSelector::FirstChild => {
+                        if node_hierarchy[node_id].previous_sibling_id().is_some() { return false; }
+                    },
+                    CssPathPseudoSelector::LastChild => {
+                        if node_hierarchy[node_id].next_sibling_id().is_some() { return false; }
+                    },
+                    CssPathPseudoSelector::OnlyChild => {
+                        if node_hierarchy[node_id].previous_sibling_id().is_some() ||
+                           node_hierarchy[node_id].next_sibling_id().is_some() {
+                            return false;
+                        }
+                    },
+                    CssPathPseudoSelector::Empty => {
+                        if node_hierarchy[node_id].last_child_id().is_some() { return false; }
+                        if let NodeType::Text(t) = node_data.get_node_type() {
+                            if !t.as_str().is_empty() { return false; }
                         }
                     },
 
@@ -315,10 +382,33 @@ pub(crate) fn selector_group_matches(
                     CssPathPseudoSelector::Focus => {
                         if !is_last_content_group { return false; }
                         if expected_path_ending != Some(CssPathPseudoSelector::Focus) { return false; }
-                    }
+                    },
+                    CssPathPseudoSelector::FocusWithin => {
+                        if !is_last_content_group { return false; }
+                        if expected_path_ending != Some(CssPathPseudoSelector::FocusWithin) { return false; }
+                    },
                 }
-            }
-            DirectChildren | Children => {
+            },
+            Attribute(a) => {
+                if !a.matches(node_data.get_attribute(a.name.as_str())) {
+                    return false;
+                }
+            },
+            Not(n) => {
+                let not_selectors = n.iter().collect::<Vec<_>>();
+                if selector_group_matches(
+                    &not_selectors,
+                    html_node,
+                    node_data,
+                    node_id,
+                    node_hierarchy,
+                    expected_path_ending,
+                    is_last_content_group,
+                ) {
+                    return false;
+                }
+            },
+            DirectChildren | Children | AdjacentSibling | GeneralSibling => {
                 // panic!("Unreachable: DirectChildren or Children in CSS path!");
                 return false;
             },
@@ -328,6 +418,23 @@ pub(crate) fn selector_group_matches(
     true
 }
 
+/// Returns whether the 1-based index of the node matches the `:nth-child` / `:nth-last-child` selector
+fn nth_child_matches(selector: &CssNthChildSelector, index: u32) -> bool {
+    use azul_css::CssNthChildPattern;
+    match *selector {
+        Number(value) => index == value,
+        Even => index % 2 == 0,
+        Odd => index % 2 == 1,
+        Pattern(CssNthChildPattern { repeat, offset }) => {
+            if repeat == 0 {
+                index == offset
+            } else {
+                index >= offset && (index - offset) % repeat == 0
+            }
+        },
+    }
+}
+
 #[test]
 fn test_case_issue_93() {
 
@@ -335,22 +442,28 @@ fn test_case_issue_93() {
     use azul_css::*;
     use crate::dom::*;
 
-    fn render_tab() -> Dom {
-        Dom::div().with_class("tabwidget-tab")
-            .with_child(Dom::label("").with_class("tabwidget-tab-label"))
-            .with_child(Dom::label("").with_class("tabwidget-tab-close"))
-    }
-
-    let dom = Dom::div().with_id("editor-rooms")
-    .with_child(
-        Dom::div().with_class("tabwidget-bar")
-        .with_child(render_tab().with_class("active"))
-        .with_child(render_tab())
-        .with_child(render_tab())
-        .with_child(render_tab())
-    );
-
-    let dom = convert_dom_into_compact_dom(dom);
+    let class = |s: &'static str| -> IdOrClassVec { vec![IdOrClass::Class(s.into())].into() };
+
+    let render_tab = |classes: IdOrClassVec| -> Dom {
+        Dom::div().with_ids_and_classes(classes)
+        .with_children(vec![
+            Dom::text("").with_ids_and_classes(class("tabwidget-tab-label")),
+            Dom::text("").with_ids_and_classes(class("tabwidget-tab-close")),
+        ].into())
+    };
+
+    let mut dom = Dom::div().with_ids_and_classes(vec![IdOrClass::Id("editor-rooms".into())].into())
+    .with_children(vec![
+        Dom::div().with_ids_and_classes(class("tabwidget-bar"))
+        .with_children(vec![
+            render_tab(vec![IdOrClass::Class("tabwidget-tab".into()), IdOrClass::Class("active".into())].into()),
+            render_tab(class("tabwidget-tab")),
+            render_tab(class("tabwidget-tab")),
+            render_tab(class("tabwidget-tab")),
+        ].into())
+    ].into());
+
+    let styled_dom = dom.style(&mut Css::empty());
 
     let tab_active_close = CssPath { selectors: vec![
         Class("tabwidget-tab".to_string().into()),
@@ -359,16 +472,9 @@ fn test_case_issue_93() {
         Class("tabwidget-tab-close".to_string().into())
     ].into() };
 
-    let node_hierarchy = &dom.arena.node_hierarchy;
-    let node_data = &dom.arena.node_data;
-    let nodes_sorted: Vec<_> = node_hierarchy.get_parents_sorted_by_depth();
-    let html_node_tree = construct_html_cascade_tree(
-        &node_hierarchy,
-        &nodes_sorted,
-        None,
-        &BTreeMap::new(),
-        false,
-    );
+    let node_hierarchy = styled_dom.node_hierarchy.as_container();
+    let node_data = styled_dom.node_data.as_container();
+    let html_node_tree = styled_dom.cascade_info.as_container();
 
     //  rules: [
     //    ".tabwidget-tab-label"                        : ColorU::BLACK,
@@ -395,13 +501,13 @@ fn test_case_issue_93() {
     // ".tabwidget-tab.active .tabwidget-tab-label"
     // should not match
     // ".tabwidget-tab.active .tabwidget-tab-close"
-    assert_eq!(matches_html_element(&tab_active_close, NodeId::new(3), &node_hierarchy, &node_data, &html_node_tree), false);
+    assert_eq!(matches_html_element(&tab_active_close, NodeId::new(3), &node_hierarchy, &node_data, &html_node_tree, None), false);
 
     // Test 2:
     // ".tabwidget-tab.active .tabwidget-tab-close"
     // should match
     // ".tabwidget-tab.active .tabwidget-tab-close"
-    assert_eq!(matches_html_element(&tab_active_close, NodeId::new(4), &node_hierarchy, &node_data, &html_node_tree), true);
+    assert_eq!(matches_html_element(&tab_active_close, NodeId::new(4), &node_hierarchy, &node_data, &html_node_tree, None), true);
 }
 
 #[test]
@@ -451,4 +557,38 @@ fn test_css_group_iterator() {
     ], CssGroupSplitReason::Children)));
 
     assert_eq!(it.next(), None);
-}
\ No newline at end of file
+}
+#[test]
+fn test_css_group_iterator_siblings() {
+    use self::CssPathSelector::*;
+    use azul_css::*;
+
+    // ".a + .b ~ div:hover"
+    let selectors = vec![
+        Class("a".to_string().into()),
+        AdjacentSibling,
+        Class("b".to_string().into()),
+        GeneralSibling,
+        Type(NodeTypeTag::Div),
+        PseudoSelector(CssPathPseudoSelector::Hover),
+    ];
+
+    let mut it = CssGroupIterator::new(&selectors);
+
+    assert_eq!(it.next(), Some((vec![
+       &Type(NodeTypeTag::Div),
+       &PseudoSelector(CssPathPseudoSelector::Hover),
+    ], CssGroupSplitReason::GeneralSibling)));
+
+    assert_eq!(it.next(), Some((vec![
+       &Class("b".to_string().into()),
+    ], CssGroupSplitReason::AdjacentSibling)));
+
+    let path = CssPath { selectors: selectors.clone().into() };
+    assert!(rule_ends_with(&path, Some(CssPathPseudoSelector::Hover)));
+    assert!(!rule_ends_with(&path, None));
+
+    // structural pseudo-classes are not applied on a state change
+    let path = CssPath { selectors: vec![Class("a".to_string().into()), PseudoSelector(CssPathPseudoSelector::FirstChild)].into() };
+    assert!(rule_ends_with(&path, None));
+}
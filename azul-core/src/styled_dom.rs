@@ -1,10 +1,12 @@
 use core::fmt;
+use core::ops::Range;
 use alloc::vec::Vec;
 use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::collections::btree_map::BTreeMap;
 use azul_css::{
-    Css, CssPath, CssProperty, CssPropertyType, AzString,
+    Css, CssPath, CssProperty, CssPropertyType, CssMediaContext, AzString,
+    CssCustomProperty, DynamicCssProperty,
 
     StyleBackgroundContentVecValue, StyleBackgroundPositionVecValue,
     StyleBackgroundSizeVecValue, StyleBackgroundRepeatVecValue,
@@ -19,9 +21,14 @@ use azul_css::{
     StyleBorderBottomLeftRadiusValue, StyleBorderBottomRightRadiusValue,
     StyleOpacityValue, StyleTransformVecValue, StyleTransformOriginValue,
     StylePerspectiveOriginValue, StyleBackfaceVisibilityValue, StyleTextColor,
-    StyleFontSize, StyleTextColorValue,
-
-    LayoutDisplayValue, LayoutFloatValue, LayoutBoxSizingValue,
+    StyleFontSize, StyleTextColorValue, StyleTransitionPropertyVecValue,
+    StyleTransitionDurationVecValue, StyleTransitionTimingFunctionVecValue,
+    StyleTransitionDelayVecValue, StyleTransitionDuration, StyleTransitionTimingFunction,
+    StyleTransitionDelay, StyleAnimationNameVecValue, StyleAnimationDurationVecValue,
+    StyleAnimationIterationCountVecValue, StyleAnimationDirectionVecValue,
+    StyleAnimationFillModeVecValue, StyleAnimationDelayVecValue, CssKeyframes,
+
+    LayoutDisplayValue, LayoutFloatValue, LayoutClearValue, LayoutBoxSizingValue,
     LayoutWidthValue,  LayoutHeightValue, LayoutMinWidthValue,
     LayoutMinHeightValue, LayoutMaxWidthValue,  LayoutMaxHeightValue,
     LayoutPositionValue, LayoutTopValue, LayoutBottomValue, LayoutRightValue,
@@ -33,23 +40,26 @@ use azul_css::{
     LayoutOverflowValue, LayoutFlexDirectionValue, LayoutFlexWrapValue,
     LayoutFlexGrowValue, LayoutFlexShrinkValue, LayoutJustifyContentValue,
     LayoutAlignItemsValue, LayoutAlignContentValue,
+    LayoutGridTemplateColumnsValue, LayoutGridTemplateRowsValue,
+    LayoutGridColumnValue, LayoutGridRowValue, LayoutColumnGapValue, LayoutRowGapValue,
 };
 use crate::{
     FastBTreeSet, FastHashMap,
-    id_tree::{NodeDataContainer, NodeDataContainerRef, Node, NodeId, NodeDataContainerRefMut},
+    id_tree::{NodeDataContainerRef, Node, NodeId, NodeDataContainerRefMut},
     dom::{
         Dom, TabIndex, NodeData, NodeDataVec,
         CompactDom, TagId, OptionTabIndex,
         NodeDataInlineCssProperty
     },
-    callbacks::{RefAny, Update, CallbackInfo},
+    callbacks::{RefAny, Update, CallbackInfo, CssAnimation},
+    task::{TimerId, Duration as AzDuration, SystemTimeDiff},
     style::{
         CascadeInfo, CascadeInfoVec, construct_html_cascade_tree,
         matches_html_element, rule_ends_with,
     },
     app_resources::{
         ImageRef, RendererResources,
-        ImageCache, Au, ImmediateFontId
+        ImageCache, Au, ImmediateFontId, FontHasGlyphFn
     },
 };
 
@@ -179,6 +189,17 @@ pub struct CssPropertyCache {
     // properties that were overridden in callbacks (not specific to any node state)
     pub user_overridden_properties: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
 
+    // current (interpolated) values of the running CSS transitions, take precedence over
+    // all other properties, but are ignored when detecting which properties changed
+    pub transition_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+    // timers driving the running CSS transitions (so that they can be interrupted)
+    pub running_transitions: BTreeMap<NodeId, BTreeMap<CssPropertyType, TimerId>>,
+    // `@keyframes` rules of the stylesheet, by animation name
+    pub keyframes: BTreeMap<AzString, CssKeyframes>,
+    // CSS animations that were started on the node (the timer may have finished already),
+    // the animated values are stored in the `transition_props`
+    pub running_animations: BTreeMap<NodeId, BTreeMap<TimerId, CssAnimation>>,
+
     // non-default CSS properties that were cascaded from the parent
     pub cascaded_normal_props:    BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub cascaded_hover_props:     BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
@@ -190,6 +211,33 @@ pub struct CssPropertyCache {
     pub css_hover_props:         BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub css_active_props:        BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub css_focus_props:         BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+    pub css_focus_within_props:  BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+
+    // nodes that contain the focused node (or are focused themselves), for `:focus-within`
+    pub focus_within_nodes: FastBTreeSet<NodeId>,
+
+    // custom properties (`--accent: red`) declared by the CSS rules matching the node,
+    // inherited by looking up the parent chain when a `var()` gets resolved
+    pub css_custom_props:        BTreeMap<NodeId, BTreeMap<AzString, AzString>>,
+    // custom properties that were overridden in callbacks (take precedence over the CSS)
+    pub user_overridden_custom_props: BTreeMap<NodeId, BTreeMap<AzString, AzString>>,
+
+    // window size / theme that the `@media` rules were evaluated against
+    pub media_context: CssMediaContext,
+    // copies of the stylesheets, only stored if they contain `@media` rules or CSS variables
+    // (necessary to restyle the DOM when the window crosses a breakpoint or a variable changes)
+    pub restyle_css: Vec<RestyleCss>,
+}
+
+/// Stylesheet that a range of nodes was styled with, the nodes of a `StyledDom`
+/// that was added via `append_child` keep the stylesheet that they were styled with
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestyleCss {
+    /// First node that the stylesheet was applied to
+    pub first_node: NodeId,
+    /// Number of nodes (starting at `first_node`) that the stylesheet was applied to
+    pub node_count: usize,
+    pub css: Css,
 }
 
 impl CssPropertyCache {
@@ -205,93 +253,205 @@ impl CssPropertyCache {
         html_tree: &NodeDataContainerRef<CascadeInfo>
     ) -> Vec<TagIdToNodeIdMapping> {
 
+        let css_is_empty = css.is_empty();
+
+        if !css_is_empty {
+            css.sort_by_specificity();
+            // font-family: "Brand Sans" => font file declared in the @font-face
+            css.resolve_font_faces();
+            self.apply_css_rules(css, 0..node_data.len(), node_data, node_hierarchy, html_tree);
+        }
+
+        // if a name is declared multiple times, the last declaration wins
+        self.keyframes = css.keyframes.iter().map(|k| (k.name.clone(), k.clone())).collect();
+
+        self.restyle_css = if !css_is_empty && (css.has_media_queries() || css.has_css_variables()) {
+            vec![RestyleCss { first_node: NodeId::ZERO, node_count: node_data.len(), css: css.clone() }]
+        } else {
+            Vec::new()
+        };
+
+        self.inherit_props(node_data, node_hierarchy, non_leaf_nodes);
+        self.get_tag_ids(node_data, node_hierarchy)
+    }
+
+    /// Restyles the nodes with the stored copies of the stylesheets that contain `@media` rules
+    /// or CSS variables, each stylesheet only applies to the nodes that it was originally applied to
+    #[must_use]
+    pub fn restyle_with_stored_css(
+        &mut self,
+        node_data: &NodeDataContainerRef<NodeData>,
+        node_hierarchy: &AzNodeVec,
+        non_leaf_nodes: &ParentWithNodeDepthVec,
+        html_tree: &NodeDataContainerRef<CascadeInfo>
+    ) -> Vec<TagIdToNodeIdMapping> {
+
+        // values inherited from the old rules would otherwise be kept
+        self.cascaded_normal_props.clear();
+        self.cascaded_hover_props.clear();
+        self.cascaded_active_props.clear();
+        self.cascaded_focus_props.clear();
+
+        let restyle_css = core::mem::replace(&mut self.restyle_css, Vec::new());
+        for r in restyle_css.iter() {
+            let first_node = r.first_node.index();
+            self.apply_css_rules(&r.css, first_node..(first_node + r.node_count), node_data, node_hierarchy, html_tree);
+        }
+        self.restyle_css = restyle_css;
+
+        self.inherit_props(node_data, node_hierarchy, non_leaf_nodes);
+        self.get_tag_ids(node_data, node_hierarchy)
+    }
+
+    /// Replaces the properties of the `nodes` with the properties of the matching CSS rules,
+    /// the CSS has to be sorted by specificity already
+    fn apply_css_rules(
+        &mut self,
+        css: &Css,
+        nodes: Range<usize>,
+        node_data: &NodeDataContainerRef<NodeData>,
+        node_hierarchy: &AzNodeVec,
+        html_tree: &NodeDataContainerRef<CascadeInfo>
+    ) {
+
         use azul_css::CssDeclaration;
-        use azul_css::LayoutDisplay;
         use azul_css::CssPathPseudoSelector::*;
         use rayon::prelude::*;
 
-        let css_is_empty = css.is_empty();
+        let media_context = self.media_context;
+
+        macro_rules! matching_rules {($expected_pseudo_selector:expr, $node_id:expr) => {{
+            css
+            .rules() // can not be parallelized due to specificity order matching
+            .filter(|rule_block| rule_block.matches_media(&media_context))
+            .filter(|rule_block| rule_ends_with(&rule_block.path, $expected_pseudo_selector))
+            .filter(|rule_block| matches_html_element(
+                &rule_block.path,
+                $node_id,
+                &node_hierarchy.as_container(),
+                &node_data,
+                &html_tree,
+                $expected_pseudo_selector
+            ))
+        }};}
 
-        if !css_is_empty {
+        // custom properties are only collected from the rules without a pseudo selector,
+        // they have to be known before the `var()` references can be resolved
+        let css_custom_rules: Vec<(NodeId, BTreeMap<AzString, AzString>)> =
+        nodes.clone().into_par_iter().filter_map(|node_id| {
+            let node_id = NodeId::new(node_id);
+            let r = matching_rules!(None, node_id)
+            .flat_map(|matched_rule| matched_rule.declarations.iter())
+            .filter_map(|declaration| match declaration {
+                CssDeclaration::Custom(c) => Some((c.name.clone(), c.value.clone())),
+                _ => None,
+            })
+            .collect::<BTreeMap<AzString, AzString>>();
+            if r.is_empty() { None } else { Some((node_id, r)) }
+        }).collect();
+
+        self.css_custom_props.retain(|node_id, _| !nodes.contains(&node_id.index()));
+        self.css_custom_props.extend(css_custom_rules.into_iter());
+
+        let css_custom_props = &self.css_custom_props;
+        let user_overridden_custom_props = &self.user_overridden_custom_props;
+
+        macro_rules! filter_rules {($expected_pseudo_selector:expr, $node_id:expr) => {{
+            matching_rules!($expected_pseudo_selector, $node_id)
+            // rule matched, now copy all the styles of this rule
+            .flat_map(|matched_rule| {
+                matched_rule.declarations
+                .iter()
+                .filter_map(move |declaration| {
+                    match declaration {
+                        CssDeclaration::Static(s) => Some(s.clone()),
+                        CssDeclaration::Dynamic(d) => Some(resolve_css_variable(d, &|name: &str| {
+                            get_custom_property(
+                                css_custom_props,
+                                user_overridden_custom_props,
+                                &node_hierarchy.as_container(),
+                                $node_id,
+                                name
+                            ).map(|v| String::from(v.as_str()))
+                        })),
+                        CssDeclaration::Custom(_) => None,
+                    }
+                })
+            })
+            .collect::<Vec<CssProperty>>()
+        }};}
 
-            css.sort_by_specificity();
+        // NOTE: This is wrong, but fast
+        //
+        // Get all nodes that end with `:hover`, `:focus` or `:active`
+        // and copy the respective styles to the `hover_css_constraints`, etc. respectively
+        //
+        // NOTE: This won't work correctly for paths with `.blah:hover > #thing`
+        // but that can be fixed later
+
+        // go through each HTML node (in parallel) and see which CSS rules match
+        let css_normal_rules: Vec<(NodeId, Vec<CssProperty>)> =
+        nodes.clone().into_par_iter().filter_map(|node_id| {
+            let node_id = NodeId::new(node_id);
+            let r = filter_rules!(None, node_id);
+            if r.is_empty() { None } else { Some((node_id, r)) }
+        }).collect();
 
-            macro_rules! filter_rules {($expected_pseudo_selector:expr, $node_id:expr) => {{
-                css
-                .rules() // can not be parallelized due to specificity order matching
-                .filter(|rule_block| rule_ends_with(&rule_block.path, $expected_pseudo_selector))
-                .filter(|rule_block| matches_html_element(
-                    &rule_block.path,
-                    $node_id,
-                    &node_hierarchy.as_container(),
-                    &node_data,
-                    &html_tree,
-                    $expected_pseudo_selector
-                ))
-                // rule matched, now copy all the styles of this rule
-                .flat_map(|matched_rule| {
-                    matched_rule.declarations
-                    .iter()
-                    .filter_map(move |declaration| {
-                        match declaration {
-                            CssDeclaration::Static(s) => Some(s),
-                            CssDeclaration::Dynamic(_d) => None, // TODO: No variable support yet!
-                        }
-                    })
-                })
-                .map(|prop| prop.clone())
-                .collect::<Vec<CssProperty>>()
-            }};}
-
-            // NOTE: This is wrong, but fast
-            //
-            // Get all nodes that end with `:hover`, `:focus` or `:active`
-            // and copy the respective styles to the `hover_css_constraints`, etc. respectively
-            //
-            // NOTE: This won't work correctly for paths with `.blah:hover > #thing`
-            // but that can be fixed later
-
-            // go through each HTML node (in parallel) and see which CSS rules match
-            let css_normal_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)> =
-            node_data.transform_nodeid_multithreaded_optional(|node_id| {
-                let r = filter_rules!(None, node_id);
-                if r.is_empty() { None } else { Some((node_id, r)) }
-            });
+        let css_hover_rules: Vec<(NodeId, Vec<CssProperty>)>  =
+        nodes.clone().into_par_iter().filter_map(|node_id| {
+            let node_id = NodeId::new(node_id);
+            let r = filter_rules!(Some(Hover), node_id);
+            if r.is_empty() { None } else { Some((node_id, r)) }
+        }).collect();
 
-            let css_hover_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)>  =
-            node_data.transform_nodeid_multithreaded_optional(|node_id| {
-                let r = filter_rules!(Some(Hover), node_id);
-                if r.is_empty() { None } else { Some((node_id, r)) }
-            });
+        let css_active_rules: Vec<(NodeId, Vec<CssProperty>)>  =
+        nodes.clone().into_par_iter().filter_map(|node_id| {
+            let node_id = NodeId::new(node_id);
+            let r = filter_rules!(Some(Active), node_id);
+            if r.is_empty() { None } else { Some((node_id, r)) }
+        }).collect();
 
-            let css_active_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)>  =
-            node_data.transform_nodeid_multithreaded_optional(|node_id| {
-                let r = filter_rules!(Some(Active), node_id);
-                if r.is_empty() { None } else { Some((node_id, r)) }
-            });
+        let css_focus_rules: Vec<(NodeId, Vec<CssProperty>)>  =
+        nodes.clone().into_par_iter().filter_map(|node_id| {
+            let node_id = NodeId::new(node_id);
+            let r = filter_rules!(Some(Focus), node_id);
+            if r.is_empty() { None } else { Some((node_id, r)) }
+        }).collect();
 
-            let css_focus_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)>  =
-            node_data.transform_nodeid_multithreaded_optional(|node_id| {
-                let r = filter_rules!(Some(Focus), node_id);
-                if r.is_empty() { None } else { Some((node_id, r)) }
-            });
+        let css_focus_within_rules: Vec<(NodeId, Vec<CssProperty>)>  =
+        nodes.clone().into_par_iter().filter_map(|node_id| {
+            let node_id = NodeId::new(node_id);
+            let r = filter_rules!(Some(FocusWithin), node_id);
+            if r.is_empty() { None } else { Some((node_id, r)) }
+        }).collect();
 
-            self.css_normal_props = css_normal_rules.internal.into_iter()
-            .map(|(n, map)| (n, map.into_iter().map(|prop| (prop.get_type(), prop)).collect()))
-            .collect();
+        self.css_normal_props.retain(|node_id, _| !nodes.contains(&node_id.index()));
+        self.css_normal_props.extend(css_normal_rules.into_iter()
+        .map(|(n, map)| (n, map.into_iter().map(|prop| (prop.get_type(), prop)).collect())));
 
-            self.css_hover_props = css_hover_rules.internal.into_iter()
-            .map(|(n, map)| (n, map.into_iter().map(|prop| (prop.get_type(), prop)).collect()))
-            .collect();
+        self.css_hover_props.retain(|node_id, _| !nodes.contains(&node_id.index()));
+        self.css_hover_props.extend(css_hover_rules.into_iter()
+        .map(|(n, map)| (n, map.into_iter().map(|prop| (prop.get_type(), prop)).collect())));
 
-            self.css_active_props = css_active_rules.internal.into_iter()
-            .map(|(n, map)| (n, map.into_iter().map(|prop| (prop.get_type(), prop)).collect()))
-            .collect();
+        self.css_active_props.retain(|node_id, _| !nodes.contains(&node_id.index()));
+        self.css_active_props.extend(css_active_rules.into_iter()
+        .map(|(n, map)| (n, map.into_iter().map(|prop| (prop.get_type(), prop)).collect())));
 
-            self.css_focus_props = css_focus_rules.internal.into_iter()
-            .map(|(n, map)| (n, map.into_iter().map(|prop| (prop.get_type(), prop)).collect()))
-            .collect();
-        }
+        self.css_focus_props.retain(|node_id, _| !nodes.contains(&node_id.index()));
+        self.css_focus_props.extend(css_focus_rules.into_iter()
+        .map(|(n, map)| (n, map.into_iter().map(|prop| (prop.get_type(), prop)).collect())));
+
+        self.css_focus_within_props.retain(|node_id, _| !nodes.contains(&node_id.index()));
+        self.css_focus_within_props.extend(css_focus_within_rules.into_iter()
+        .map(|(n, map)| (n, map.into_iter().map(|prop| (prop.get_type(), prop)).collect())));
+    }
+
+    fn inherit_props(
+        &mut self,
+        node_data: &NodeDataContainerRef<NodeData>,
+        node_hierarchy: &AzNodeVec,
+        non_leaf_nodes: &ParentWithNodeDepthVec,
+    ) {
 
         // Inheritance: Inherit all values of the parent to the children, but
         // only if the property is inheritable and isn't yet set
@@ -371,12 +531,10 @@ impl CssPropertyCache {
             inherit_inline_css_props!(Focus, self.cascaded_focus_props);
 
             // Inherit the CSS properties from the CSS file
-            if !css_is_empty {
-                inherit_props!(self.css_normal_props, self.cascaded_normal_props);
-                inherit_props!(self.css_hover_props, self.cascaded_hover_props);
-                inherit_props!(self.css_active_props, self.cascaded_active_props);
-                inherit_props!(self.css_focus_props, self.cascaded_focus_props);
-            }
+            inherit_props!(self.css_normal_props, self.cascaded_normal_props);
+            inherit_props!(self.css_hover_props, self.cascaded_hover_props);
+            inherit_props!(self.css_active_props, self.cascaded_active_props);
+            inherit_props!(self.css_focus_props, self.cascaded_focus_props);
 
             // Inherit properties that were inherited in a previous iteration of the loop
             inherit_props!(self.cascaded_normal_props, self.cascaded_normal_props);
@@ -384,6 +542,16 @@ impl CssPropertyCache {
             inherit_props!(self.cascaded_active_props, self.cascaded_active_props);
             inherit_props!(self.cascaded_focus_props, self.cascaded_focus_props);
         }
+    }
+
+    fn get_tag_ids(
+        &self,
+        node_data: &NodeDataContainerRef<NodeData>,
+        node_hierarchy: &AzNodeVec,
+    ) -> Vec<TagIdToNodeIdMapping> {
+
+        use azul_css::LayoutDisplay;
+        use rayon::prelude::*;
 
         // When restyling, the tag / node ID mappings may change, regenerate them
         // See if the node should have a hit-testing tag ID
@@ -440,6 +608,13 @@ impl CssPropertyCache {
                     break;
                 }
 
+                // IFrames have to be hit-tested in order to hit-test the
+                // nodes of the IFrame DOM and in order to be scrollable
+                if let crate::dom::NodeType::IFrame(_) = node_data.get_node_type() {
+                    node_should_have_tag = true;
+                    break;
+                }
+
                 // check for context menu
                 if node_data.get_context_menu().is_some() {
                     node_should_have_tag = true;
@@ -562,8 +737,20 @@ impl CssPropertyCache {
         if let Some(p) = self.get_transform_origin(&node_data, node_id, node_state) { s.push_str(&format!("transform-origin: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_perspective_origin(&node_data, node_id, node_state) { s.push_str(&format!("perspective-origin: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_backface_visibility(&node_data, node_id, node_state) { s.push_str(&format!("backface-visibility: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_transition_property(&node_data, node_id, node_state) { s.push_str(&format!("transition-property: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_transition_duration(&node_data, node_id, node_state) { s.push_str(&format!("transition-duration: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_transition_timing_function(&node_data, node_id, node_state) { s.push_str(&format!("transition-timing-function: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_transition_delay(&node_data, node_id, node_state) { s.push_str(&format!("transition-delay: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_name(&node_data, node_id, node_state) { s.push_str(&format!("animation-name: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_duration(&node_data, node_id, node_state) { s.push_str(&format!("animation-duration: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_timing_function(&node_data, node_id, node_state) { s.push_str(&format!("animation-timing-function: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_iteration_count(&node_data, node_id, node_state) { s.push_str(&format!("animation-iteration-count: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_direction(&node_data, node_id, node_state) { s.push_str(&format!("animation-direction: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_fill_mode(&node_data, node_id, node_state) { s.push_str(&format!("animation-fill-mode: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_delay(&node_data, node_id, node_state) { s.push_str(&format!("animation-delay: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_display(&node_data, node_id, node_state) { s.push_str(&format!("display: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_float(&node_data, node_id, node_state) { s.push_str(&format!("float: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_clear(&node_data, node_id, node_state) { s.push_str(&format!("clear: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_box_sizing(&node_data, node_id, node_state) { s.push_str(&format!("box-sizing: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_width(&node_data, node_id, node_state) { s.push_str(&format!("width: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_height(&node_data, node_id, node_state) { s.push_str(&format!("height: {};", p.get_css_value_fmt())); }
@@ -597,6 +784,12 @@ impl CssPropertyCache {
         if let Some(p) = self.get_justify_content(&node_data, node_id, node_state) { s.push_str(&format!("justify-content: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_align_items(&node_data, node_id, node_state) { s.push_str(&format!("align-items: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_align_content(&node_data, node_id, node_state) { s.push_str(&format!("align-content: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_grid_template_columns(&node_data, node_id, node_state) { s.push_str(&format!("grid-template-columns: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_grid_template_rows(&node_data, node_id, node_state) { s.push_str(&format!("grid-template-rows: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_grid_column(&node_data, node_id, node_state) { s.push_str(&format!("grid-column: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_grid_row(&node_data, node_id, node_state) { s.push_str(&format!("grid-row: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_column_gap(&node_data, node_id, node_state) { s.push_str(&format!("column-gap: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_row_gap(&node_data, node_id, node_state) { s.push_str(&format!("row-gap: {};", p.get_css_value_fmt())); }
         s
     }
 }
@@ -654,6 +847,11 @@ impl CssPropertyCache {
             node_count,
             user_overridden_properties: BTreeMap::new(),
 
+            transition_props: BTreeMap::new(),
+            running_transitions: BTreeMap::new(),
+            keyframes: BTreeMap::new(),
+            running_animations: BTreeMap::new(),
+
             cascaded_normal_props: BTreeMap::new(),
             cascaded_hover_props: BTreeMap::new(),
             cascaded_active_props: BTreeMap::new(),
@@ -663,9 +861,24 @@ impl CssPropertyCache {
             css_hover_props: BTreeMap::new(),
             css_active_props: BTreeMap::new(),
             css_focus_props: BTreeMap::new(),
+            css_focus_within_props: BTreeMap::new(),
+
+            focus_within_nodes: FastBTreeSet::new(),
+
+            css_custom_props: BTreeMap::new(),
+            user_overridden_custom_props: BTreeMap::new(),
+
+            media_context: CssMediaContext::default(),
+            restyle_css: Vec::new(),
         }
     }
 
+    /// Returns whether the `@media` rules of the stylesheet would apply differently
+    /// in the `new_context` than in the context that the DOM was styled with
+    pub fn media_queries_changed(&self, new_context: &CssMediaContext) -> bool {
+        self.restyle_css.iter().any(|r| r.css.media_queries_changed(&self.media_context, new_context))
+    }
+
     pub fn append(&mut self, other: Self) {
 
         macro_rules! append_css_property_vec {($field_name:ident) => {{
@@ -675,6 +888,9 @@ impl CssPropertyCache {
         }};}
 
         append_css_property_vec!(user_overridden_properties);
+        append_css_property_vec!(transition_props);
+        append_css_property_vec!(running_transitions);
+        append_css_property_vec!(running_animations);
         append_css_property_vec!(cascaded_normal_props);
         append_css_property_vec!(cascaded_hover_props);
         append_css_property_vec!(cascaded_active_props);
@@ -683,6 +899,23 @@ impl CssPropertyCache {
         append_css_property_vec!(css_hover_props);
         append_css_property_vec!(css_active_props);
         append_css_property_vec!(css_focus_props);
+        append_css_property_vec!(css_focus_within_props);
+        append_css_property_vec!(css_custom_props);
+        append_css_property_vec!(user_overridden_custom_props);
+
+        let node_count = self.node_count;
+        self.focus_within_nodes.extend(other.focus_within_nodes.into_iter().map(|node_id| node_id + node_count));
+
+        // on a name collision, the `@keyframes` of `self` win
+        for (name, keyframes) in other.keyframes.into_iter() {
+            self.keyframes.entry(name).or_insert(keyframes);
+        }
+
+        // the stylesheets of the appended cache only apply to the appended nodes
+        self.restyle_css.extend(other.restyle_css.into_iter().map(|r| RestyleCss {
+            first_node: r.first_node + node_count,
+            .. r
+        }));
 
         self.node_count += other.node_count;
     }
@@ -739,6 +972,26 @@ impl CssPropertyCache {
         node_state: &StyledNodeState,
         css_property_type: &CssPropertyType
     ) -> Option<&CssProperty> {
+
+        // A running transition overrides everything else
+        if let Some(p) = self.transition_props
+            .get(node_id)
+            .and_then(|n| n.get(css_property_type)) {
+            return Some(p);
+        }
+
+        self.get_property_without_transitions(node_data, node_id, node_state, css_property_type)
+    }
+
+    /// Same as `get_property`, but ignores the interpolated values of running CSS transitions,
+    /// i.e. returns the value that the transition is going to end on
+    pub fn get_property_without_transitions<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+        css_property_type: &CssPropertyType
+    ) -> Option<&CssProperty> {
         // NOTE: This function is slow, but it is going to be called on every
         // node in parallel, so it should be rather fast in the end
 
@@ -780,6 +1033,13 @@ impl CssPropertyCache {
             }
         }
 
+        if self.focus_within_nodes.contains(node_id) {
+            if let Some(p) = self.css_focus_within_props.get(node_id)
+            .and_then(|map| map.get(css_property_type)) {
+                return Some(p);
+            }
+        }
+
         if node_state.active {
             if let Some(p) = self.css_active_props.get(node_id)
             .and_then(|map| map.get(css_property_type)) {
@@ -955,12 +1215,48 @@ impl CssPropertyCache {
     pub fn get_backface_visibility<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleBackfaceVisibilityValue> {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::BackfaceVisibility).and_then(|p| p.as_backface_visibility())
     }
+    pub fn get_transition_property<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleTransitionPropertyVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::TransitionProperty).and_then(|p| p.as_transition_property())
+    }
+    pub fn get_transition_duration<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleTransitionDurationVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::TransitionDuration).and_then(|p| p.as_transition_duration())
+    }
+    pub fn get_transition_timing_function<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleTransitionTimingFunctionVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::TransitionTimingFunction).and_then(|p| p.as_transition_timing_function())
+    }
+    pub fn get_transition_delay<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleTransitionDelayVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::TransitionDelay).and_then(|p| p.as_transition_delay())
+    }
+    pub fn get_animation_name<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleAnimationNameVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationName).and_then(|p| p.as_animation_name())
+    }
+    pub fn get_animation_duration<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleAnimationDurationVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationDuration).and_then(|p| p.as_animation_duration())
+    }
+    pub fn get_animation_timing_function<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleTransitionTimingFunctionVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationTimingFunction).and_then(|p| p.as_animation_timing_function())
+    }
+    pub fn get_animation_iteration_count<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleAnimationIterationCountVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationIterationCount).and_then(|p| p.as_animation_iteration_count())
+    }
+    pub fn get_animation_direction<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleAnimationDirectionVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationDirection).and_then(|p| p.as_animation_direction())
+    }
+    pub fn get_animation_fill_mode<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleAnimationFillModeVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationFillMode).and_then(|p| p.as_animation_fill_mode())
+    }
+    pub fn get_animation_delay<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleAnimationDelayVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationDelay).and_then(|p| p.as_animation_delay())
+    }
     pub fn get_display<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutDisplayValue> {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::Display).and_then(|p| p.as_display())
     }
     pub fn get_float<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutFloatValue> {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::Float).and_then(|p| p.as_float())
     }
+    pub fn get_clear<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutClearValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::Clear).and_then(|p| p.as_clear())
+    }
     pub fn get_box_sizing<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutBoxSizingValue> {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::BoxSizing).and_then(|p| p.as_box_sizing())
     }
@@ -1060,6 +1356,24 @@ impl CssPropertyCache {
     pub fn get_align_content<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutAlignContentValue> {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::AlignContent).and_then(|p| p.as_align_content())
     }
+    pub fn get_grid_template_columns<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutGridTemplateColumnsValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::GridTemplateColumns).and_then(|p| p.as_grid_template_columns())
+    }
+    pub fn get_grid_template_rows<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutGridTemplateRowsValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::GridTemplateRows).and_then(|p| p.as_grid_template_rows())
+    }
+    pub fn get_grid_column<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutGridColumnValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::GridColumn).and_then(|p| p.as_grid_column())
+    }
+    pub fn get_grid_row<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutGridRowValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::GridRow).and_then(|p| p.as_grid_row())
+    }
+    pub fn get_column_gap<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutColumnGapValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::ColumnGap).and_then(|p| p.as_column_gap())
+    }
+    pub fn get_row_gap<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutRowGapValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::RowGap).and_then(|p| p.as_row_gap())
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
@@ -1499,9 +1813,142 @@ impl StyledDom {
 
     }
 
-    pub fn restyle(&mut self, css: &mut Css) {
+    /// Returns whether the `@media` rules of the DOM would apply differently in the `new_context`
+    pub fn media_queries_changed(&self, new_context: &CssMediaContext) -> bool {
+        self.css_property_cache.ptr.media_queries_changed(new_context)
+    }
 
-        use rayon::prelude::*;
+    /// Sets the window size / theme that the `@media` rules are evaluated against and
+    /// restyles the DOM in case the window crossed a breakpoint of the stylesheet
+    pub fn set_media_context(&mut self, new_context: CssMediaContext) {
+
+        let cache = self.css_property_cache.downcast_mut();
+        let media_queries_changed = cache.media_queries_changed(&new_context);
+        cache.media_context = new_context;
+
+        if !media_queries_changed {
+            return;
+        }
+
+        self.restyle_with_stored_css();
+    }
+
+    /// Overrides the value of the custom properties (`--accent`) on the given node
+    /// (and therefore on all of its children, which inherit the variable)
+    /// and re-resolves all `var()` references of the stylesheet.
+    ///
+    /// An empty value removes the override, so that the value of the stylesheet applies again.
+    /// Returns the properties whose value changed due to the new variables.
+    pub fn restyle_css_variables(&mut self, node_id: &NodeId, new_variables: &[CssCustomProperty])
+    -> BTreeMap<NodeId, Vec<ChangedCssProperty>> {
+
+        let mut map = BTreeMap::default();
+
+        if new_variables.is_empty() {
+            return map;
+        }
+
+        {
+            let css_property_cache_mut = self.css_property_cache.downcast_mut();
+            let overrides = css_property_cache_mut.user_overridden_custom_props
+                .entry(*node_id)
+                .or_insert_with(|| BTreeMap::new());
+
+            for var in new_variables.iter() {
+                if var.value.as_str().trim().is_empty() {
+                    overrides.remove(&var.name);
+                } else {
+                    overrides.insert(var.name.clone(), var.value.clone());
+                }
+            }
+
+            if overrides.is_empty() {
+                css_property_cache_mut.user_overridden_custom_props.remove(node_id);
+            }
+        }
+
+        // stylesheet doesn't reference any variables: nothing to resolve
+        if self.get_css_property_cache().restyle_css.is_empty() {
+            return map;
+        }
+
+        let old_css_property_cache = self.get_css_property_cache().clone();
+
+        self.restyle_with_stored_css();
+
+        // only the node itself and its children can reference the changed variables
+        let node_hierarchy = self.node_hierarchy.as_container();
+        let node_data = self.node_data.as_container();
+        let styled_nodes = self.styled_nodes.as_container();
+        let new_css_property_cache = self.get_css_property_cache();
+        let subtree_end = (node_id.index() + 1 + node_hierarchy.subtree_len(*node_id)).min(node_data.len());
+
+        for nid in node_id.index()..subtree_end {
+
+            let nid = NodeId::new(nid);
+            let node_state = &styled_nodes[nid].state;
+            let mut property_types = FastBTreeSet::new();
+
+            for cache in [&old_css_property_cache, new_css_property_cache].iter() {
+                for props in [
+                    &cache.css_normal_props, &cache.css_hover_props,
+                    &cache.css_active_props, &cache.css_focus_props,
+                    &cache.css_focus_within_props,
+                    &cache.cascaded_normal_props, &cache.cascaded_hover_props,
+                    &cache.cascaded_active_props, &cache.cascaded_focus_props,
+                ].iter() {
+                    if let Some(p) = props.get(&nid) {
+                        property_types.extend(p.keys().copied());
+                    }
+                }
+            }
+
+            let changes = property_types.into_iter().filter_map(|prop_type| {
+
+                let old_prop = old_css_property_cache.get_property_without_transitions(&node_data[nid], &nid, node_state, &prop_type);
+                let new_prop = new_css_property_cache.get_property_without_transitions(&node_data[nid], &nid, node_state, &prop_type);
+
+                if old_prop == new_prop {
+                    return None;
+                }
+
+                Some(ChangedCssProperty {
+                    previous_state: node_state.clone(),
+                    previous_prop: old_prop.cloned().unwrap_or_else(|| CssProperty::auto(prop_type)),
+                    // changing a variable does not change the state
+                    current_state: node_state.clone(),
+                    current_prop: new_prop.cloned().unwrap_or_else(|| CssProperty::auto(prop_type)),
+                })
+            }).collect::<Vec<_>>();
+
+            if !changes.is_empty() {
+                map.insert(nid, changes);
+            }
+        }
+
+        map
+    }
+
+    /// Restyles the DOM with the copies of the stylesheets that the `CssPropertyCache` keeps
+    /// if the stylesheets contain `@media` rules or CSS variables
+    fn restyle_with_stored_css(&mut self) {
+
+        if self.get_css_property_cache().restyle_css.is_empty() {
+            return;
+        }
+
+        let new_tag_ids = self.css_property_cache.downcast_mut()
+        .restyle_with_stored_css(
+            &self.node_data.as_container(),
+            &self.node_hierarchy,
+            &self.non_leaf_nodes,
+            &self.cascade_info.as_container()
+        );
+
+        self.set_tag_ids(new_tag_ids);
+    }
+
+    pub fn restyle(&mut self, css: &mut Css) {
 
         let new_tag_ids = self.css_property_cache.downcast_mut()
         .restyle(
@@ -1512,6 +1959,13 @@ impl StyledDom {
             &self.cascade_info.as_container()
         );
 
+        self.set_tag_ids(new_tag_ids);
+    }
+
+    fn set_tag_ids(&mut self, new_tag_ids: Vec<TagIdToNodeIdMapping>) {
+
+        use rayon::prelude::*;
+
         // Restyling may change the tag IDs
         let mut styled_nodes_mut = self.styled_nodes.as_container_mut();
 
@@ -1633,20 +2087,19 @@ impl StyledDom {
 
                     let style_font_families_hash = StyleFontFamiliesHash::new(css_font_ids.as_ref());
 
-                    let existing_font_key = resources.font_families_map
-                    .get(&style_font_families_hash)
-                    .and_then(|font_family_hash| {
-                        resources.font_id_map
-                        .get(&font_family_hash)
-                        .map(|font_key| (font_family_hash, font_key))
-                    });
+                    // all fonts of the fallback chain need an instance for the font size
+                    let existing_font_keys = resources.get_font_fallback_chain(&style_font_families_hash);
 
-                    let font_id = match existing_font_key {
-                        Some((hash, key)) => ImmediateFontId::Resolved((*hash, *key)),
-                        None => ImmediateFontId::Unresolved(css_font_ids),
+                    let font_ids = if existing_font_keys.is_empty() {
+                        vec![ImmediateFontId::Unresolved(css_font_ids)]
+                    } else {
+                        existing_font_keys
+                        .into_iter()
+                        .map(|(hash, key)| ImmediateFontId::Resolved((hash, key)))
+                        .collect()
                     };
 
-                    Some((font_id, font_size_to_au(font_size)))
+                    Some((font_ids, font_size_to_au(font_size)))
                 },
                 _ => None
             }
@@ -1655,8 +2108,84 @@ impl StyledDom {
 
         let mut map = FastHashMap::default();
 
-        for (font_id, au) in keys.into_iter() {
-            map.entry(font_id).or_insert_with(|| FastBTreeSet::default()).insert(au);
+        for (font_ids, au) in keys.into_iter() {
+            for font_id in font_ids {
+                map.entry(font_id).or_insert_with(|| FastBTreeSet::default()).insert(au);
+            }
+        }
+
+        map
+    }
+
+    /// Scans all text nodes for characters that none of the fonts in the
+    /// font fallback chain of the nodes `font-family` can render
+    #[cfg(feature = "multithreading")]
+    pub(crate) fn scan_for_missing_glyphs(
+        &self,
+        resources: &RendererResources,
+        font_has_glyph_fn: FontHasGlyphFn,
+    ) -> FastHashMap<StyleFontFamiliesHash, (FastBTreeSet<char>, FastBTreeSet<Au>)> {
+
+        use crate::dom::NodeType::*;
+        use crate::app_resources::font_size_to_au;
+        use rayon::prelude::*;
+
+        // zero-width and formatting characters are usually not in any font
+        fn is_invisible_char(c: char) -> bool {
+            c.is_whitespace() ||
+            c.is_control() ||
+            ('\u{200B}'..='\u{200F}').contains(&c) ||
+            ('\u{202A}'..='\u{202E}').contains(&c) ||
+            ('\u{2060}'..='\u{206F}').contains(&c) ||
+            ('\u{FE00}'..='\u{FE0F}').contains(&c) ||
+            c == '\u{FEFF}'
+        }
+
+        let missing = self.node_data
+        .as_ref()
+        .par_iter()
+        .enumerate()
+        .filter_map(|(node_id, node_data)| {
+            let node_id = NodeId::new(node_id);
+            let text = match node_data.get_node_type() {
+                Text(t) => t,
+                _ => return None,
+            };
+
+            let styled_node_state = &self.styled_nodes.as_container()[node_id].state;
+            let css_font_ids = self.get_css_property_cache()
+            .get_font_id_or_default(&node_data, &node_id, styled_node_state);
+            let style_font_families_hash = StyleFontFamiliesHash::new(css_font_ids.as_ref());
+
+            let fallback_chain = resources.font_fallback_chains.get(&style_font_families_hash)?;
+            let fonts = resources.get_font_fallback_chain(&style_font_families_hash)
+            .into_iter()
+            .filter_map(|(_, font_key)| resources.currently_registered_fonts.get(&font_key).map(|f| &f.0))
+            .collect::<Vec<_>>();
+
+            let missing_chars = text.as_str().chars()
+            .filter(|c| !is_invisible_char(*c))
+            .filter(|c| !fallback_chain.searched_chars.contains(c))
+            .filter(|c| !fonts.iter().any(|font_ref| (font_has_glyph_fn)(font_ref, *c)))
+            .collect::<FastBTreeSet<char>>();
+
+            if missing_chars.is_empty() {
+                return None;
+            }
+
+            let font_size = self.get_css_property_cache()
+            .get_font_size_or_default(&node_data, &node_id, styled_node_state);
+
+            Some((style_font_families_hash, missing_chars, font_size_to_au(font_size)))
+        })
+        .collect::<Vec<_>>();
+
+        let mut map = FastHashMap::<StyleFontFamiliesHash, (FastBTreeSet<char>, FastBTreeSet<Au>)>::default();
+
+        for (font_families_hash, missing_chars, au) in missing.into_iter() {
+            let entry = map.entry(font_families_hash).or_insert_with(|| (FastBTreeSet::default(), FastBTreeSet::default()));
+            entry.0.extend(missing_chars.into_iter());
+            entry.1.insert(au);
         }
 
         map
@@ -1789,8 +2318,8 @@ impl StyledDom {
             .into_iter()
             .filter_map(|prop| {
                 // calculate both the old and the new state
-                let old = css_property_cache.get_property(node_data, node_id, old_node_state, prop);
-                let new = css_property_cache.get_property(node_data, node_id, new_node_state, prop);
+                let old = css_property_cache.get_property_without_transitions(node_data, node_id, old_node_state, prop);
+                let new = css_property_cache.get_property_without_transitions(node_data, node_id, new_node_state, prop);
                 if old == new {
                     None
                 } else {
@@ -1871,8 +2400,8 @@ impl StyledDom {
             .into_iter()
             .filter_map(|prop| {
                 // calculate both the old and the new state
-                let old = css_property_cache.get_property(node_data, node_id, old_node_state, prop);
-                let new = css_property_cache.get_property(node_data, node_id, new_node_state, prop);
+                let old = css_property_cache.get_property_without_transitions(node_data, node_id, old_node_state, prop);
+                let new = css_property_cache.get_property_without_transitions(node_data, node_id, new_node_state, prop);
                 if old == new {
                     None
                 } else {
@@ -1953,8 +2482,8 @@ impl StyledDom {
             .into_iter()
             .filter_map(|prop| {
                 // calculate both the old and the new state
-                let old = css_property_cache.get_property(node_data, node_id, old_node_state, prop);
-                let new = css_property_cache.get_property(node_data, node_id, new_node_state, prop);
+                let old = css_property_cache.get_property_without_transitions(node_data, node_id, old_node_state, prop);
+                let new = css_property_cache.get_property_without_transitions(node_data, node_id, new_node_state, prop);
                 if old == new {
                     None
                 } else {
@@ -1978,6 +2507,98 @@ impl StyledDom {
         v.into_iter().collect()
     }
 
+    /// Sets whether the nodes contain the focused node (`:focus-within`)
+    /// and returns the properties that changed because of that
+    #[must_use]
+    pub fn restyle_nodes_focus_within(&mut self, nodes: &[NodeId], new_focus_within_state: bool)
+    -> BTreeMap<NodeId, Vec<ChangedCssProperty>> {
+
+        let node_data = self.node_data.as_container();
+        let styled_nodes = self.styled_nodes.as_container();
+        let css_property_cache = self.css_property_cache.downcast_mut();
+
+        let nodes = nodes.iter()
+        .filter(|node_id| css_property_cache.focus_within_nodes.contains(node_id) != new_focus_within_state)
+        .copied()
+        .collect::<Vec<_>>();
+
+        let old_props = nodes.iter().filter_map(|node_id| {
+            let props = css_property_cache.css_focus_within_props.get(node_id)?;
+            let node_state = &styled_nodes[*node_id].state;
+            Some((*node_id, props.keys().map(|prop_type| {
+                let old = css_property_cache.get_property_without_transitions(&node_data[*node_id], node_id, node_state, prop_type).cloned();
+                (*prop_type, old)
+            }).collect::<Vec<_>>()))
+        }).collect::<Vec<_>>();
+
+        for node_id in nodes.iter() {
+            if new_focus_within_state {
+                css_property_cache.focus_within_nodes.insert(*node_id);
+            } else {
+                css_property_cache.focus_within_nodes.remove(node_id);
+            }
+        }
+
+        old_props.into_iter().filter_map(|(node_id, old_props)| {
+            let node_state = &styled_nodes[node_id].state;
+            let changes = old_props.into_iter().filter_map(|(prop_type, old)| {
+                let new = css_property_cache.get_property_without_transitions(&node_data[node_id], &node_id, node_state, &prop_type).cloned();
+                if old == new {
+                    None
+                } else {
+                    Some(ChangedCssProperty {
+                        previous_state: node_state.clone(),
+                        previous_prop: old.unwrap_or_else(|| CssProperty::auto(prop_type)),
+                        current_state: node_state.clone(),
+                        current_prop: new.unwrap_or_else(|| CssProperty::auto(prop_type)),
+                    })
+                }
+            }).collect::<Vec<_>>();
+            if changes.is_empty() { None } else { Some((node_id, changes)) }
+        }).collect()
+    }
+
+    /// Returns the nodes that can be focused with the keyboard, in the order
+    /// in which they should be visited when pressing `Tab`
+    ///
+    /// The DOM is traversed in document order (parents before their children),
+    /// but siblings with a `TabIndex::OverrideInParent` are visited before their
+    /// `TabIndex::Auto` siblings, sorted by their index. Nodes with
+    /// `TabIndex::NoKeyboardFocus` are skipped, but their children are not.
+    pub fn get_tab_order(&self) -> Vec<NodeId> {
+
+        use crate::dom::TabIndex;
+
+        let mut tab_order = Vec::new();
+        if self.node_data.is_empty() {
+            return tab_order;
+        }
+
+        let node_hierarchy = self.node_hierarchy.as_container();
+        let node_data = self.node_data.as_container();
+
+        let mut stack = vec![NodeId::ZERO];
+
+        while let Some(node_id) = stack.pop() {
+
+            let nd = &node_data[node_id];
+            if nd.is_focusable() && nd.get_tab_index() != Some(&TabIndex::NoKeyboardFocus) {
+                tab_order.push(node_id);
+            }
+
+            let mut children = node_id.az_children(&node_hierarchy).collect::<Vec<_>>();
+            // stable sort: Auto / NoKeyboardFocus siblings keep their document order
+            children.sort_by_key(|child_id| match node_data[*child_id].get_tab_index() {
+                Some(TabIndex::OverrideInParent(i)) => (0, *i),
+                _ => (1, 0),
+            });
+
+            stack.extend(children.into_iter().rev());
+        }
+
+        tab_order
+    }
+
     // Inserts a property into the self.user_overridden_properties
     #[cfg(feature = "multithreading")]
     #[must_use]
@@ -2006,7 +2627,7 @@ impl StyledDom {
             .par_iter()
             .filter_map(|new_prop| {
 
-                let old_prop = css_property_cache.get_property(
+                let old_prop = css_property_cache.get_property_without_transitions(
                     node_data,
                     node_id,
                     old_node_state,
@@ -2060,6 +2681,251 @@ impl StyledDom {
         map
     }
 
+    /// Returns the `transition` (duration, easing, delay) that applies when the given
+    /// property of the node changes, or `None` if the property isn't transitioned.
+    ///
+    /// If the property is listed multiple times in `transition-property`, the last entry wins.
+    /// Shorter `transition-duration` / `-timing-function` / `-delay` lists are repeated.
+    pub fn get_css_transition(&self, node_id: &NodeId, property: CssPropertyType)
+    -> Option<(StyleTransitionDuration, StyleTransitionTimingFunction, StyleTransitionDelay)> {
+
+        let node_data = &self.node_data.as_container()[*node_id];
+        let node_state = &self.styled_nodes.as_container()[*node_id].state;
+        let css_property_cache = self.get_css_property_cache();
+
+        let properties = css_property_cache.get_transition_property(node_data, node_id, node_state)?.get_property()?;
+        let durations = css_property_cache.get_transition_duration(node_data, node_id, node_state)?.get_property()?;
+        let index = properties.as_ref().iter().rposition(|p| p.matches(&property))?;
+
+        let duration = *durations.as_ref().get(index % durations.len().max(1))?;
+        let timing_function = css_property_cache.get_transition_timing_function(node_data, node_id, node_state)
+            .and_then(|t| t.get_property())
+            .and_then(|t| t.as_ref().get(index % t.len().max(1)).copied())
+            .unwrap_or_default();
+        let delay = css_property_cache.get_transition_delay(node_data, node_id, node_state)
+            .and_then(|d| d.get_property())
+            .and_then(|d| d.as_ref().get(index % d.len().max(1)).copied())
+            .unwrap_or_default();
+
+        if duration.ms == 0 {
+            None
+        } else {
+            Some((duration, timing_function, delay))
+        }
+    }
+
+    /// Registers a transition of the `from` property, driven by the timer `timer_id`.
+    /// Until the first frame of the transition, the node keeps displaying the `from` value.
+    ///
+    /// Returns the timer of the transition that was interrupted by the new transition (if any)
+    pub fn start_css_transition(&mut self, node_id: &NodeId, from: CssProperty, timer_id: TimerId) -> Option<TimerId> {
+        let css_property_cache = self.css_property_cache.downcast_mut();
+        let prop_type = from.get_type();
+        css_property_cache.transition_props
+            .entry(*node_id)
+            .or_insert_with(|| BTreeMap::new())
+            .insert(prop_type, from);
+        css_property_cache.running_transitions
+            .entry(*node_id)
+            .or_insert_with(|| BTreeMap::new())
+            .insert(prop_type, timer_id)
+    }
+
+    /// Updates the interpolated values of the running CSS transitions. `CssProperty::Initial`
+    /// marks the end of the transition, after which the regular value of the property applies again.
+    ///
+    /// Returns the properties whose displayed value changed.
+    pub fn restyle_css_transitions(&mut self, node_id: &NodeId, frames: &[CssProperty])
+    -> BTreeMap<NodeId, Vec<ChangedCssProperty>> {
+
+        let mut map = BTreeMap::default();
+
+        if frames.is_empty() {
+            return map;
+        }
+
+        let node_state = self.styled_nodes.as_container()[*node_id].state.clone();
+        let mut changes = Vec::new();
+
+        for frame in frames.iter() {
+
+            let prop_type = frame.get_type();
+
+            let old_prop = {
+                let node_data = &self.node_data.as_container()[*node_id];
+                self.get_css_property_cache().get_property(node_data, node_id, &node_state, &prop_type).cloned()
+            };
+
+            {
+                let css_property_cache = self.css_property_cache.downcast_mut();
+                if frame.is_initial() {
+                    if let Some(m) = css_property_cache.transition_props.get_mut(node_id) {
+                        m.remove(&prop_type);
+                        if m.is_empty() { css_property_cache.transition_props.remove(node_id); }
+                    }
+                    if let Some(m) = css_property_cache.running_transitions.get_mut(node_id) {
+                        m.remove(&prop_type);
+                        if m.is_empty() { css_property_cache.running_transitions.remove(node_id); }
+                    }
+                } else {
+                    css_property_cache.transition_props
+                        .entry(*node_id)
+                        .or_insert_with(|| BTreeMap::new())
+                        .insert(prop_type, frame.clone());
+                }
+            }
+
+            let new_prop = {
+                let node_data = &self.node_data.as_container()[*node_id];
+                self.get_css_property_cache().get_property(node_data, node_id, &node_state, &prop_type).cloned()
+            };
+
+            if old_prop != new_prop {
+                changes.push(ChangedCssProperty {
+                    previous_state: node_state.clone(),
+                    previous_prop: old_prop.unwrap_or_else(|| CssProperty::auto(prop_type)),
+                    // transitions do not change the state
+                    current_state: node_state.clone(),
+                    current_prop: new_prop.unwrap_or_else(|| CssProperty::auto(prop_type)),
+                });
+            }
+        }
+
+        if !changes.is_empty() {
+            map.insert(*node_id, changes);
+        }
+
+        map
+    }
+
+    /// Returns the CSS animations that the `animation-name` of the node refers to,
+    /// names without a matching `@keyframes` rule and animations with a
+    /// duration of 0 are skipped. Shorter `animation-*` lists are repeated.
+    ///
+    /// Properties that are missing in the `0%` / `100%` keyframes start / end
+    /// on the regular value of the property.
+    pub fn get_css_animations(&self, node_id: &NodeId) -> Vec<CssAnimation> {
+
+        let node_data = &self.node_data.as_container()[*node_id];
+        let node_state = &self.styled_nodes.as_container()[*node_id].state;
+        let css_property_cache = self.get_css_property_cache();
+
+        if css_property_cache.keyframes.is_empty() {
+            return Vec::new();
+        }
+
+        let names = match css_property_cache.get_animation_name(node_data, node_id, node_state).and_then(|n| n.get_property()) {
+            Some(s) => s,
+            None => return Vec::new(),
+        };
+
+        macro_rules! get_nth {($getter:ident, $index:expr) => {
+            css_property_cache.$getter(node_data, node_id, node_state)
+            .and_then(|p| p.get_property())
+            .and_then(|p| p.as_ref().get($index % p.len().max(1)).copied())
+            .unwrap_or_default()
+        };}
+
+        names.iter().enumerate().filter_map(|(index, name)| {
+
+            let keyframes = css_property_cache.keyframes.get(&name.name)?;
+            let duration = get_nth!(get_animation_duration, index);
+            if duration.ms == 0 {
+                return None;
+            }
+
+            let mut tracks = BTreeMap::<CssPropertyType, Vec<(f32, CssProperty)>>::new();
+            for keyframe in keyframes.keyframes.iter() {
+                for prop in keyframe.properties.iter() {
+                    let prop_type = prop.get_type();
+                    if is_animation_or_transition_property(&prop_type) {
+                        continue; // ignored in keyframes
+                    }
+                    tracks.entry(prop_type).or_insert_with(|| Vec::new()).push((keyframe.offset.get(), prop.clone()));
+                }
+            }
+
+            for (prop_type, frames) in tracks.iter_mut() {
+                let regular_value = css_property_cache
+                    .get_property_without_transitions(node_data, node_id, node_state, prop_type)
+                    .cloned()
+                    .unwrap_or_else(|| CssProperty::auto(*prop_type));
+                if frames.first().map(|(offset, _)| *offset > 0.0).unwrap_or(true) {
+                    frames.insert(0, (0.0, regular_value.clone()));
+                }
+                if frames.last().map(|(offset, _)| *offset < 1.0).unwrap_or(true) {
+                    frames.push((1.0, regular_value));
+                }
+            }
+
+            let timing_function: StyleTransitionTimingFunction = get_nth!(get_animation_timing_function, index);
+            let delay: azul_css::StyleAnimationDelay = get_nth!(get_animation_delay, index);
+
+            Some(CssAnimation {
+                properties: tracks.into_iter().map(|(_, frames)| frames).collect(),
+                duration: AzDuration::System(SystemTimeDiff::from_millis(duration.ms as u64)),
+                delay: AzDuration::System(SystemTimeDiff::from_millis(delay.ms as u64)),
+                iteration_count: get_nth!(get_animation_iteration_count, index),
+                direction: get_nth!(get_animation_direction, index),
+                fill_mode: get_nth!(get_animation_fill_mode, index),
+                easing: timing_function.to_interpolation_function(),
+            })
+        }).collect()
+    }
+
+    /// Registers a CSS animation of the node, driven by the timer `timer_id`
+    pub fn register_css_animation(&mut self, node_id: &NodeId, timer_id: TimerId, animation: CssAnimation) {
+        self.css_property_cache.downcast_mut().running_animations
+            .entry(*node_id)
+            .or_insert_with(|| BTreeMap::new())
+            .insert(timer_id, animation);
+    }
+
+    /// Unregisters all CSS animations of the node and returns their timers - the
+    /// animated values stay until they are reset via `restyle_css_transitions`
+    pub fn stop_css_animations(&mut self, node_id: &NodeId) -> BTreeMap<TimerId, CssAnimation> {
+        self.css_property_cache.downcast_mut().running_animations.remove(node_id).unwrap_or_default()
+    }
+
+    /// Takes over the CSS animations of the `previous` DOM (i.e. before the DOM was regenerated)
+    /// that are still applied to the same node, including their current animated values,
+    /// so that they keep running instead of restarting
+    pub fn continue_css_animations(&mut self, previous: &StyledDom) {
+
+        let previous_cache = previous.get_css_property_cache();
+        let node_count = self.node_data.len();
+
+        for (node_id, animations) in previous_cache.running_animations.iter() {
+
+            if node_id.index() >= node_count {
+                continue;
+            }
+
+            let current_animations = self.get_css_animations(node_id);
+
+            for (timer_id, animation) in animations.iter() {
+
+                if !current_animations.contains(animation) {
+                    continue;
+                }
+
+                let frames = animation.get_property_types().into_iter().filter_map(|prop_type| {
+                    previous_cache.transition_props.get(node_id)?.get(&prop_type).cloned()
+                }).collect::<Vec<_>>();
+
+                let css_property_cache = self.css_property_cache.downcast_mut();
+                for frame in frames {
+                    css_property_cache.transition_props
+                        .entry(*node_id)
+                        .or_insert_with(|| BTreeMap::new())
+                        .insert(frame.get_type(), frame);
+                }
+
+                self.register_css_animation(node_id, *timer_id, animation.clone());
+            }
+        }
+    }
+
     /// Scans the `StyledDom` for iframe callbacks
     #[cfg(feature = "multithreading")]
     pub fn scan_for_iframe_callbacks(&self) -> Vec<NodeId> {
@@ -2307,6 +3173,73 @@ extern "C" fn default_on_scroll(data: &mut RefAny, info: CallbackInfo) -> Update
     Update::DoNothing
 }
 
+fn is_animation_or_transition_property(prop_type: &CssPropertyType) -> bool {
+    use azul_css::CssPropertyType::*;
+    match prop_type {
+        TransitionProperty | TransitionDuration | TransitionTimingFunction | TransitionDelay |
+        AnimationName | AnimationDuration | AnimationTimingFunction | AnimationIterationCount |
+        AnimationDirection | AnimationFillMode | AnimationDelay => true,
+        _ => false,
+    }
+}
+
+/// Looks up the value of the custom property `--name` on the node, or, since custom
+/// properties are inherited, on the closest parent that declares it
+fn get_custom_property<'a>(
+    css_custom_props: &'a BTreeMap<NodeId, BTreeMap<AzString, AzString>>,
+    user_overridden_custom_props: &'a BTreeMap<NodeId, BTreeMap<AzString, AzString>>,
+    node_hierarchy: &NodeDataContainerRef<AzNode>,
+    node_id: NodeId,
+    name: &str,
+) -> Option<&'a AzString> {
+
+    let mut current_node = Some(node_id);
+
+    while let Some(node_id) = current_node {
+
+        // overrides from callbacks take precedence over the stylesheet
+        let value = user_overridden_custom_props.get(&node_id)
+            .and_then(|map| map.iter().find(|(k, _)| k.as_str() == name))
+            .or_else(|| css_custom_props.get(&node_id)
+                .and_then(|map| map.iter().find(|(k, _)| k.as_str() == name)));
+
+        if let Some((_, v)) = value {
+            return Some(v);
+        }
+
+        current_node = node_hierarchy[node_id].parent_id();
+    }
+
+    None
+}
+
+/// Resolves a `var(--name, default)` reference to a property: substitutes the variable
+/// (and all variables referenced in its value) and parses the result as the type of the
+/// property. Falls back to the default value if the variable is not declared or if the
+/// value is not a valid value for the property.
+///
+/// NOTE: Without the `css_parser` feature, the value of variables can't be parsed,
+/// so `var()` references always resolve to the default value
+fn resolve_css_variable<F: Fn(&str) -> Option<String>>(property: &DynamicCssProperty, lookup: &F) -> CssProperty {
+
+    #[cfg(feature = "css_parser")] {
+        let substituted = lookup(property.dynamic_id.as_str())
+            .and_then(|value| CssCustomProperty::substitute_var_references(&value, lookup));
+
+        if let Some(value) = substituted {
+            if let Ok(parsed) = azul_css_parser::parse_css_property(property.default_value.get_type(), &value) {
+                return parsed;
+            }
+        }
+    }
+
+    #[cfg(not(feature = "css_parser"))] {
+        let _ = lookup;
+    }
+
+    property.default_value.clone()
+}
+
 fn fill_content_group_children(group: &mut ContentGroup, children_sorted: &BTreeMap<AzNodeId, Vec<AzNodeId>>) {
     use rayon::prelude::*;
 
@@ -2372,3 +3305,92 @@ fn recursive_get_last_child(node_id: NodeId, node_hierarchy: &[AzNode], target:
         }
     }
 }
+
+#[cfg(all(test, feature = "css_parser"))]
+mod tests {
+
+    use super::*;
+
+    fn styled_dom_with_appended_child(root_css: &str, child_css: &str) -> StyledDom {
+
+        use crate::dom::{Dom, IdOrClass};
+
+        let mut root = Dom::body().with_children(vec![
+            Dom::div().with_ids_and_classes(vec![IdOrClass::Class("a".into())].into())
+        ].into());
+        let mut child = Dom::div().with_ids_and_classes(vec![IdOrClass::Class("b".into())].into());
+
+        let mut styled_dom = StyledDom::new(&mut root, &mut azul_css_parser::new_from_str(root_css).unwrap());
+        styled_dom.append_child(StyledDom::new(&mut child, &mut azul_css_parser::new_from_str(child_css).unwrap()));
+        styled_dom
+    }
+
+    #[test]
+    fn test_media_queries_of_appended_child() {
+
+        use azul_css::{LayoutWidth, LayoutHeight, LayoutWidthValue, LayoutHeightValue};
+
+        // body = 0, .a = 1, appended .b = 2
+        let mut styled_dom = styled_dom_with_appended_child(
+            ".a { width: 10px; } @media (min-width: 600px) { .a { width: 20px; } }",
+            ".b { height: 10px; } @media (min-width: 600px) { .b { height: 30px; } }",
+        );
+
+        let width = |styled_dom: &StyledDom| styled_dom.get_css_property_cache()
+            .get_width(&styled_dom.node_data.as_container()[NodeId::new(1)], &NodeId::new(1), &StyledNodeState::new()).cloned();
+        let height = |styled_dom: &StyledDom| styled_dom.get_css_property_cache()
+            .get_height(&styled_dom.node_data.as_container()[NodeId::new(2)], &NodeId::new(2), &StyledNodeState::new()).cloned();
+
+        assert_eq!(width(&styled_dom), Some(LayoutWidthValue::Exact(LayoutWidth::px(10.0))));
+        assert_eq!(height(&styled_dom), Some(LayoutHeightValue::Exact(LayoutHeight::px(10.0))));
+
+        // both stylesheets are re-evaluated when crossing the breakpoint
+        let wide = CssMediaContext { width: 800.0, height: 600.0, .. CssMediaContext::default() };
+        assert!(styled_dom.media_queries_changed(&wide));
+        styled_dom.set_media_context(wide);
+
+        assert_eq!(width(&styled_dom), Some(LayoutWidthValue::Exact(LayoutWidth::px(20.0))));
+        assert_eq!(height(&styled_dom), Some(LayoutHeightValue::Exact(LayoutHeight::px(30.0))));
+
+        styled_dom.set_media_context(CssMediaContext::default());
+
+        assert_eq!(width(&styled_dom), Some(LayoutWidthValue::Exact(LayoutWidth::px(10.0))));
+        assert_eq!(height(&styled_dom), Some(LayoutHeightValue::Exact(LayoutHeight::px(10.0))));
+    }
+
+    #[test]
+    fn test_css_variables_of_appended_child() {
+
+        use azul_css::{LayoutWidth, LayoutHeight, LayoutWidthValue, LayoutHeightValue};
+
+        // body = 0, .a = 1, appended .b = 2
+        let mut styled_dom = styled_dom_with_appended_child(
+            ".a { width: 10px; }",
+            ".b { height: var(--size, 10px); }",
+        );
+
+        let width = |styled_dom: &StyledDom| styled_dom.get_css_property_cache()
+            .get_width(&styled_dom.node_data.as_container()[NodeId::new(1)], &NodeId::new(1), &StyledNodeState::new()).cloned();
+        let height = |styled_dom: &StyledDom| styled_dom.get_css_property_cache()
+            .get_height(&styled_dom.node_data.as_container()[NodeId::new(2)], &NodeId::new(2), &StyledNodeState::new()).cloned();
+
+        assert_eq!(height(&styled_dom), Some(LayoutHeightValue::Exact(LayoutHeight::px(10.0))));
+
+        // the variable is inherited from the root of the DOM into the appended node
+        let changes = styled_dom.restyle_css_variables(&NodeId::ZERO, &[
+            CssCustomProperty { name: "size".into(), value: "40px".into() }
+        ]);
+
+        assert_eq!(changes.keys().copied().collect::<Vec<_>>(), vec![NodeId::new(2)]);
+        assert_eq!(height(&styled_dom), Some(LayoutHeightValue::Exact(LayoutHeight::px(40.0))));
+        // the properties of the root stylesheet are not wiped by the restyle
+        assert_eq!(width(&styled_dom), Some(LayoutWidthValue::Exact(LayoutWidth::px(10.0))));
+
+        // an empty value removes the override again
+        styled_dom.restyle_css_variables(&NodeId::ZERO, &[
+            CssCustomProperty { name: "size".into(), value: "".into() }
+        ]);
+
+        assert_eq!(height(&styled_dom), Some(LayoutHeightValue::Exact(LayoutHeight::px(10.0))));
+    }
+}
@@ -114,7 +114,7 @@ impl Instant {
         let duration_total = end.duration_since(&start);
         let duration_current = self.duration_since(&start);
 
-        duration_current.div(&duration_total).min(0.0).max(1.0)
+        duration_current.div(&duration_total).max(0.0).min(1.0)
     }
 
     /// Adds a duration to the instant, does nothing in undefined cases
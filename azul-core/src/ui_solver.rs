@@ -15,6 +15,7 @@ use azul_css::{
     LayoutLeft, LayoutRight, LayoutTop, LayoutBottom, LayoutFlexDirection, LayoutJustifyContent,
     LayoutBoxSizing, LayoutBorderRightWidth, LayoutBorderLeftWidth, LayoutBorderTopWidth,
     LayoutBorderBottomWidth, StyleTransform, StyleTransformOrigin, StyleBoxShadow,
+    GridTrackSizing,
 };
 use crate::{
     display_list::{CachedDisplayList, GlTextureCache, RenderCallbacks},
@@ -22,7 +23,7 @@ use crate::{
     app_resources::{
         Words, ShapedWords, TransformKey, OpacityKey,
         FontInstanceKey, WordPositions, Epoch,
-        RendererResources, ImageCache,
+        RendererResources, ImageCache, IdNamespace, ResourceUpdate,
     },
     id_tree::{NodeId, NodeDataContainer, NodeDataContainerRef},
     dom::{DomNodeHash, ScrollTagId, TagId},
@@ -211,6 +212,60 @@ impl Default for OverflowingScrollNode {
     }
 }
 
+// IFrame nodes only render a part of their content, see /examples/assets/images/scrollbounds.png:
+// the `virtual_child_rect` is the entire (virtual) content that can be scrolled, the `child_rect`
+// is the part of the content that is currently rendered by the IFrame DOM. Scroll positions are
+// always relative to the virtual content. For regular nodes, both rects are the same.
+impl OverflowingScrollNode {
+
+    /// Updates the scroll frame of an IFrame node with the sizes returned by the IFrame callback
+    pub fn set_iframe_scroll_sizes(
+        &mut self,
+        scroll_size: LogicalSize,
+        virtual_scroll_size: LogicalSize,
+        virtual_scroll_offset: LogicalPosition,
+    ) {
+        // IFrames that don't virtualize their content return a zero virtual size
+        let virtual_scroll_size = LogicalSize::new(
+            virtual_scroll_size.width.max(scroll_size.width + virtual_scroll_offset.x),
+            virtual_scroll_size.height.max(scroll_size.height + virtual_scroll_offset.y),
+        );
+        self.child_rect = LogicalRect::new(self.parent_rect.origin + virtual_scroll_offset, scroll_size);
+        self.virtual_child_rect = LogicalRect::new(self.parent_rect.origin, virtual_scroll_size);
+    }
+
+    /// Returns the position of the rendered content, relative to the virtual content
+    pub fn get_virtual_scroll_offset(&self) -> LogicalPosition {
+        self.child_rect.origin - self.virtual_child_rect.origin
+    }
+
+    /// Maps a scroll position (relative to the virtual content) to
+    /// a scroll position relative to the rendered content
+    pub fn get_scroll_offset(&self, scroll_position: LogicalPosition) -> LogicalPosition {
+        scroll_position - self.get_virtual_scroll_offset()
+    }
+
+    /// Returns whether the visible area at the given scroll position is not entirely
+    /// covered by the rendered content, i.e. whether the IFrame has to be re-invoked
+    pub fn iframe_needs_rerender(&self, scroll_position: LogicalPosition) -> bool {
+
+        // the visible area can't be larger than the virtual content
+        let virtual_size = self.virtual_child_rect.size;
+        let visible_min_x = scroll_position.x.min(virtual_size.width).max(0.0);
+        let visible_min_y = scroll_position.y.min(virtual_size.height).max(0.0);
+        let visible_max_x = (scroll_position.x + self.parent_rect.size.width).min(virtual_size.width);
+        let visible_max_y = (scroll_position.y + self.parent_rect.size.height).min(virtual_size.height);
+
+        let rendered_origin = self.get_virtual_scroll_offset();
+        let rendered_size = self.child_rect.size;
+
+        visible_min_x < rendered_origin.x ||
+        visible_min_y < rendered_origin.y ||
+        visible_max_x > rendered_origin.x + rendered_size.width ||
+        visible_max_y > rendered_origin.y + rendered_size.height
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum WhConstraint {
     /// between min, max
@@ -531,6 +586,122 @@ pub struct HorizontalSolvedPosition(pub f32);
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct VerticalSolvedPosition(pub f32);
 
+/// Axis of a CSS grid: columns run along the x axis, rows along the y axis
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GridAxis {
+    Column,
+    Row,
+}
+
+/// Area that a grid item occupies, in resolved (0-based) track indices,
+/// `end` is exclusive
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GridArea {
+    pub column_start: usize,
+    pub column_end: usize,
+    pub row_start: usize,
+    pub row_end: usize,
+}
+
+impl GridArea {
+    /// Returns the (start, end) track indices along the given axis
+    #[inline]
+    pub fn get_range(&self, axis: GridAxis) -> (usize, usize) {
+        match axis {
+            GridAxis::Column => (self.column_start, self.column_end),
+            GridAxis::Row => (self.row_start, self.row_end),
+        }
+    }
+
+    /// Number of tracks this area spans along the given axis
+    #[inline]
+    pub fn span(&self, axis: GridAxis) -> usize {
+        let (start, end) = self.get_range(axis);
+        end.saturating_sub(start)
+    }
+}
+
+/// Tracks of one grid axis: the sizing functions (explicit tracks + implicit
+/// `auto` tracks) and - after the layout has been solved - their sizes in pixels
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GridTracks {
+    /// Sizing function of each track, implicit tracks are `GridTrackSizing::Auto`
+    pub templates: Vec<GridTrackSizing>,
+    /// `column-gap` / `row-gap` of the grid container
+    pub gap: PixelValue,
+    /// `gap` resolved against the inner size of the grid container
+    pub gap_px: f32,
+    /// Solved track sizes in pixels, empty if the axis has not been solved yet
+    pub sizes: Vec<f32>,
+}
+
+impl GridTracks {
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.templates.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.templates.is_empty()
+    }
+
+    /// Returns the offset of the start of the track at `index`,
+    /// relative to the content box of the grid container
+    pub fn get_offset(&self, index: usize) -> f32 {
+        let index = index.min(self.sizes.len());
+        self.sizes[..index].iter().sum::<f32>() + self.gap_px * index as f32
+    }
+
+    /// Returns the size of the tracks between `start` and `end`
+    /// (exclusive), including the gaps between them
+    pub fn get_span_size(&self, start: usize, end: usize) -> f32 {
+        let end = end.min(self.sizes.len());
+        if start >= end {
+            return 0.0;
+        }
+        self.sizes[start..end].iter().sum::<f32>() + self.gap_px * (end - start - 1) as f32
+    }
+}
+
+/// Resolved grid of a `display: grid` container
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GridLayout {
+    pub columns: GridTracks,
+    pub rows: GridTracks,
+    /// Area of each in-flow child of the grid container
+    pub items: BTreeMap<NodeId, GridArea>,
+}
+
+impl GridLayout {
+
+    #[inline]
+    pub fn get_tracks(&self, axis: GridAxis) -> &GridTracks {
+        match axis {
+            GridAxis::Column => &self.columns,
+            GridAxis::Row => &self.rows,
+        }
+    }
+
+    #[inline]
+    pub fn get_tracks_mut(&mut self, axis: GridAxis) -> &mut GridTracks {
+        match axis {
+            GridAxis::Column => &mut self.columns,
+            GridAxis::Row => &mut self.rows,
+        }
+    }
+
+    /// Returns the (offset, size) of the area of `child` along the given axis,
+    /// relative to the content box of the grid container
+    pub fn get_item_bounds(&self, child: &NodeId, axis: GridAxis) -> Option<(f32, f32)> {
+        let area = self.items.get(child)?;
+        let (start, end) = area.get_range(axis);
+        let tracks = self.get_tracks(axis);
+        Some((tracks.get_offset(start), tracks.get_span_size(start, end)))
+    }
+}
+
 #[derive(Debug)]
 pub struct LayoutResult {
     pub dom_id: DomId,
@@ -549,6 +720,7 @@ pub struct LayoutResult {
     pub layout_positions: NodeDataContainer<LayoutPosition>,
     pub layout_flex_directions: NodeDataContainer<LayoutFlexDirection>,
     pub layout_justify_contents: NodeDataContainer<LayoutJustifyContent>,
+    pub layout_grids: BTreeMap<NodeId, GridLayout>,
     pub rects: NodeDataContainer<PositionedRectangle>,  // TODO: warning: large struct
     pub words_cache: BTreeMap<NodeId, Words>,
     pub shaped_words_cache: BTreeMap<NodeId, ShapedWords>,
@@ -663,6 +835,7 @@ impl LayoutResult {
         fc_cache: &FcFontCache,
         window_size: &WindowSize,
         window_theme: WindowTheme,
+        scroll_states: &ScrollStates,
     ) -> QuickResizeResult {
 
         let dom_bounds = LogicalRect::new(LogicalPosition::zero(), window_size.dimensions);
@@ -711,42 +884,24 @@ impl LayoutResult {
                         continue; // old iframe size still covers the new extent
                     }
 
-                    let iframe_return: IFrameCallbackReturn = {
-
-                        let layout_result = &mut layout_results[dom_id.inner];
-                        let mut node_data_mut = layout_result.styled_dom.node_data.as_container_mut();
-                        let mut node = &mut node_data_mut[node_id];
-                        let iframe_node = match node.get_iframe_node() {
-                            Some(iframe_node) => iframe_node,
-                            None => continue, // not an iframe
-                        };
-
-                        // invoke the iframe with the new size and replace the dom with the DOM ID
-                        let hidpi_bounds = HidpiAdjustedBounds::from_bounds(layout_size.size, window_size.hidpi_factor);
-                        let scroll_node = layout_result.scrollable_nodes.overflowing_nodes
-                            .get(&AzNodeId::from_crate_internal(Some(node_id)))
-                            .cloned()
-                            .unwrap_or_default();
-
-                        let iframe_callback_info = IFrameCallbackInfo::new(
-                            fc_cache,
-                            image_cache,
-                            window_theme,
-                            hidpi_bounds,
-
-                            // see /examples/assets/images/scrollbounds.png for documentation!
-                            /* scroll_size  */ scroll_node.child_rect.size,
-                            /* scroll_offset */ scroll_node.child_rect.origin - scroll_node.parent_rect.origin,
-                            /* virtual_scroll_size  */scroll_node.virtual_child_rect.size,
-                            /* virtual_scroll_offset */ scroll_node.virtual_child_rect.origin - scroll_node.parent_rect.origin,
-                        );
-                        (iframe_node.callback.cb)(&mut iframe_node.data, iframe_callback_info)
+                    // invoke the iframe with the new size and replace the dom with the DOM ID
+                    let hidpi_bounds = HidpiAdjustedBounds::from_bounds(layout_size.size, window_size.hidpi_factor);
+                    let iframe_dom = match layout_results[dom_id.inner].invoke_iframe_callback(
+                        node_id,
+                        hidpi_bounds,
+                        scroll_states,
+                        fc_cache,
+                        image_cache,
+                        window_theme,
+                    ) {
+                        Some(s) => s,
+                        None => continue, // not an iframe
                     };
 
                     // TODO: what to do if the new iframe has less or more sub-iframes
                     // than the current one? edge-case, solve later.
 
-                    layout_results[iframe_dom_id.inner].styled_dom = iframe_return.dom;
+                    layout_results[iframe_dom_id.inner].styled_dom = iframe_dom;
 
                     let new_iframe_rect = LogicalRect {
                         // TODO: correct? or layout_results[dom_id.0].positioned_rects[node_id]?
@@ -754,21 +909,6 @@ impl LayoutResult {
                         size: layout_results[dom_id.inner].rects.as_ref()[node_id].size,
                     };
 
-                    // Store the new scroll position
-                    // (trust the iframe to return these values correctly)
-                    let osn = layout_results[dom_id.inner].scrollable_nodes.overflowing_nodes
-                        .entry(AzNodeId::from_crate_internal(Some(node_id)))
-                        .or_insert_with(|| OverflowingScrollNode::default());
-
-                    osn.child_rect = LogicalRect {
-                        origin: iframe_return.scroll_offset,
-                        size: iframe_return.scroll_size,
-                    };
-                    osn.virtual_child_rect = LogicalRect {
-                        origin: iframe_return.virtual_scroll_offset,
-                        size: iframe_return.virtual_scroll_size,
-                    };
-
                     new_dom_ids_to_resize.push((iframe_dom_id, new_iframe_rect));
                 }
             }
@@ -814,19 +954,162 @@ impl LayoutResult {
         }
     }
 
-    // Calls the IFrame callbacks again if they are currently
-    // scrolled out of bounds
+    /// Invokes the IFrame callback of the node with the current scroll position of the IFrame,
+    /// updates the scroll frame of the IFrame with the returned scroll sizes and returns the
+    /// new IFrame DOM. Returns None if the node is not an IFrame.
+    pub fn invoke_iframe_callback(
+        &mut self,
+        node_id: NodeId,
+        bounds: HidpiAdjustedBounds,
+        scroll_states: &ScrollStates,
+        fc_cache: &FcFontCache,
+        image_cache: &ImageCache,
+        window_theme: WindowTheme,
+    ) -> Option<StyledDom> {
+
+        let az_node_id = AzNodeId::from_crate_internal(Some(node_id));
+        let scroll_node = self.scrollable_nodes.overflowing_nodes
+            .get(&az_node_id)
+            .cloned()
+            .unwrap_or_default();
+
+        // scroll positions are relative to the virtual content of the IFrame
+        let scroll_position = scroll_states
+            .get_scroll_position(&scroll_node.parent_external_scroll_id)
+            .unwrap_or_default();
+
+        let iframe_callback_info = IFrameCallbackInfo::new(
+            fc_cache,
+            image_cache,
+            window_theme,
+            bounds,
+
+            // see /examples/assets/images/scrollbounds.png for documentation!
+            /* scroll_size  */ scroll_node.child_rect.size,
+            /* scroll_offset */ scroll_node.get_scroll_offset(scroll_position),
+            /* virtual_scroll_size  */ scroll_node.virtual_child_rect.size,
+            /* virtual_scroll_offset */ scroll_node.get_virtual_scroll_offset(),
+        );
+
+        let iframe_return: IFrameCallbackReturn = {
+            let mut node_data_mut = self.styled_dom.node_data.as_container_mut();
+            let iframe_node = node_data_mut[node_id].get_iframe_node()?;
+            (iframe_node.callback.cb)(&mut iframe_node.data, iframe_callback_info)
+        };
+
+        // Store the new scroll sizes
+        // (trust the iframe to return these values correctly)
+        if let Some(osn) = self.scrollable_nodes.overflowing_nodes.get_mut(&az_node_id) {
+            osn.set_iframe_scroll_sizes(
+                iframe_return.scroll_size,
+                iframe_return.virtual_scroll_size,
+                iframe_return.virtual_scroll_offset,
+            );
+        }
+
+        Some(iframe_return.dom)
+    }
+
+    /// Calls the IFrame callbacks again if they are currently scrolled out of the
+    /// bounds of their rendered DOM and lays out the new IFrame DOMs. The nested
+    /// IFrames of a re-invoked IFrame are re-invoked, too.
+    /// Returns the DomIds of the IFrames that were re-invoked.
     pub fn scroll_iframes(
         document_id: &DocumentId,
-        dom_id: DomId,
         epoch: Epoch,
-        layout_results: &[LayoutResult],
+        id_namespace: IdNamespace,
+        layout_results: &mut [LayoutResult],
+        scroll_states: &ScrollStates,
         full_window_state: &FullWindowState,
-        gl_texture_cache: &GlTextureCache,
-        renderer_resources: &RendererResources,
+        renderer_resources: &mut RendererResources,
+        all_resource_updates: &mut Vec<ResourceUpdate>,
         image_cache: &ImageCache,
-    ) {
-        // TODO
+        fc_cache: &FcFontCache,
+        callbacks: &RenderCallbacks,
+    ) -> Vec<DomId> {
+
+        let mut invoked_iframes = Vec::new();
+
+        // the DomId of an IFrame is always larger than the DomId of its parent
+        for dom_id in 0..layout_results.len() {
+
+            let iframes = layout_results[dom_id].iframe_mapping
+                .iter()
+                .map(|(node_id, iframe_dom_id)| (*node_id, *iframe_dom_id))
+                .collect::<Vec<_>>();
+
+            // the DOMs of nested IFrames were rendered by the IFrame callbacks of
+            // the previous parent DOM, so they have to be re-invoked unconditionally
+            let parent_was_invoked = invoked_iframes.contains(&DomId { inner: dom_id });
+
+            for (node_id, iframe_dom_id) in iframes {
+
+                if !parent_was_invoked {
+
+                    let scroll_node = match layout_results[dom_id].scrollable_nodes.overflowing_nodes.get(&AzNodeId::from_crate_internal(Some(node_id))) {
+                        Some(s) => *s,
+                        None => continue,
+                    };
+
+                    let scroll_position = scroll_states
+                        .get_scroll_position(&scroll_node.parent_external_scroll_id)
+                        .unwrap_or_default();
+
+                    if !scroll_node.iframe_needs_rerender(scroll_position) {
+                        continue; // rendered DOM still covers the visible area
+                    }
+                }
+
+                let iframe_size = layout_results[dom_id].rects.as_ref()[node_id].size;
+                let hidpi_bounds = HidpiAdjustedBounds::from_bounds(
+                    LayoutSize::new(libm::roundf(iframe_size.width) as isize, libm::roundf(iframe_size.height) as isize),
+                    full_window_state.size.hidpi_factor,
+                );
+
+                let iframe_dom = match layout_results[dom_id].invoke_iframe_callback(
+                    node_id,
+                    hidpi_bounds,
+                    scroll_states,
+                    fc_cache,
+                    image_cache,
+                    full_window_state.theme,
+                ) {
+                    Some(s) => s,
+                    None => continue, // not an iframe
+                };
+
+                let mut iframe_layout_result = (callbacks.layout_iframe_fn)(
+                    DomId { inner: dom_id },
+                    iframe_dom_id,
+                    iframe_dom,
+                    LogicalRect::new(LogicalPosition::zero(), hidpi_bounds.get_logical_size()),
+                    image_cache,
+                    fc_cache,
+                    renderer_resources,
+                    all_resource_updates,
+                    id_namespace,
+                    document_id,
+                    epoch,
+                    callbacks,
+                    full_window_state,
+                );
+
+                // Nested IFrames keep their DomId as long as the node is still an IFrame
+                // (and are re-invoked later in this loop, since their DomId is larger).
+                // Nodes that only became IFrames in the new DOM are not rendered
+                // until the next full layout (see `WindowInternal::scroll_iframes`).
+                let iframe_nodes = iframe_layout_result.styled_dom.scan_for_iframe_callbacks();
+                iframe_layout_result.iframe_mapping = core::mem::replace(&mut layout_results[iframe_dom_id.inner].iframe_mapping, BTreeMap::new())
+                    .into_iter()
+                    .filter(|(node_id, _)| iframe_nodes.contains(node_id))
+                    .collect();
+
+                layout_results[iframe_dom_id.inner] = iframe_layout_result;
+                invoked_iframes.push(iframe_dom_id);
+            }
+        }
+
+        invoked_iframes
     }
 }
 
@@ -1067,7 +1350,8 @@ pub struct TextLayoutOptions {
     /// This is more important for inline text layout where items can punch "holes"
     /// into the text flow, for example an image that floats to the right.
     ///
-    /// TODO: Currently unused!
+    /// The holes are relative to the top left corner of the text, words are moved
+    /// to the right of / below the holes.
     pub holes: Vec<LayoutRect>,
 }
 
@@ -1094,7 +1378,8 @@ pub struct ResolvedTextLayoutOptions {
     /// This is more important for inline text layout where items can punch "holes"
     /// into the text flow, for example an image that floats to the right.
     ///
-    /// TODO: Currently unused!
+    /// The holes are relative to the top left corner of the text, words are moved
+    /// to the right of / below the holes.
     pub holes: LayoutRectVec,
 }
 
@@ -1414,6 +1699,16 @@ impl PositionInfo {
             PositionInfo::Relative(p) => (p.x_offset, p.y_offset)
         }
     }
+    /// Returns the absolute position of the node, relative to the DOM root
+    #[inline]
+    pub fn get_static_offset(&self) -> LogicalPosition {
+        match self {
+            PositionInfo::Static(p) |
+            PositionInfo::Fixed(p) |
+            PositionInfo::Absolute(p) |
+            PositionInfo::Relative(p) => LogicalPosition::new(p.static_x_offset, p.static_y_offset)
+        }
+    }
 }
 
 #[derive(Default, Debug, Copy, Clone, PartialEq, PartialOrd)]
@@ -12,14 +12,14 @@ use alloc::collections::btree_set::BTreeSet;
 use azul_css::{
     CssProperty, LayoutSize, U8Vec, ColorU, OptionF32,
     AzString, OptionAzString, LayoutPoint, LayoutRect,
-    CssPath, OptionI32,
+    CssPath, OptionI32, CssMediaContext, CssMediaColorScheme, CssCustomProperty,
 };
 use crate::{
     FastHashMap, FastBTreeSet,
     callbacks::{Callback, UpdateImageType},
-    window_state::RelayoutFn,
+    window_state::{RelayoutFn, ComponentEvents},
     app_resources::{ImageRef, ImageCache, RendererResources, IdNamespace, ResourceUpdate, Epoch, ImageMask},
-    styled_dom::{DomId, AzNodeId},
+    styled_dom::{DomId, AzNodeId, StyledDom},
     id_tree::NodeId,
     callbacks::{OptionCallback, PipelineId, RefAny, DocumentId, DomNodeId, ScrollPosition, Update},
     ui_solver::{
@@ -27,6 +27,7 @@ use crate::{
         HitTest, LayoutResult, ExternalScrollId
     },
     display_list::{GlTextureCache, RenderCallbacks},
+    accessibility::AccessibilityTree,
     callbacks::{LayoutCallback, LayoutCallbackType},
     task::{TimerId, ExternalSystemCallbacks, ThreadId, Timer, Thread, Instant},
 };
@@ -482,10 +483,13 @@ impl ScrollStates {
 
     /// Set the scroll amount - does not update the `entry.used_this_frame`,
     /// since that is only relevant when we are actually querying the renderer.
+    ///
+    /// Scroll positions are relative to the virtual content of the node
+    /// (see `OverflowingScrollNode::virtual_child_rect`).
     pub fn set_scroll_position(&mut self, node: &OverflowingScrollNode, scroll_position: LogicalPosition) {
         self.0.entry(node.parent_external_scroll_id)
         .or_insert_with(|| ScrollState::default())
-        .set(scroll_position.x, scroll_position.y, &node.child_rect);
+        .set(scroll_position.x, scroll_position.y, &node.virtual_child_rect);
     }
 
     /// Updating (add to) the existing scroll amount does not update the `entry.used_this_frame`,
@@ -493,7 +497,7 @@ impl ScrollStates {
     pub fn scroll_node(&mut self, node: &OverflowingScrollNode, scroll_by_x: f32, scroll_by_y: f32) {
         self.0.entry(node.parent_external_scroll_id)
         .or_insert_with(|| ScrollState::default())
-        .add(scroll_by_x, scroll_by_y, &node.child_rect);
+        .add(scroll_by_x, scroll_by_y, &node.virtual_child_rect);
     }
 }
 
@@ -531,6 +535,21 @@ impl Default for ScrollState {
     }
 }
 
+// Returns the scroll offset (on one axis) that makes the item visible inside of the
+// scroll container, scrolling as little as possible. If the item is larger than the
+// container, its start is aligned with the start of the container.
+fn scroll_offset_into_view(scroll_offset: f32, item_start: f32, item_size: f32, container_start: f32, container_size: f32) -> f32 {
+    let item_start = item_start - container_start;
+    let item_end = item_start + item_size;
+    if item_start < scroll_offset {
+        item_start
+    } else if item_end > scroll_offset + container_size {
+        (item_end - container_size).min(item_start)
+    } else {
+        scroll_offset
+    }
+}
+
 /// Overwrites all fields of the `FullWindowState` with the fields of the `WindowState`,
 /// but leaves the extra fields such as `.hover_nodes` untouched
 pub fn update_full_window_state(
@@ -575,6 +594,19 @@ pub struct WindowInternal {
     pub timers: BTreeMap<TimerId, Timer>,
     /// List of threads running in the background
     pub threads: BTreeMap<ThreadId, Thread>,
+    /// AfterMount / BeforeUnmount / NodeResized events that have not been dispatched yet
+    pub component_events: ComponentEvents,
+    /// Layout results of the previous DOMs (in the order they were replaced), kept
+    /// alive until the `BeforeUnmount` callbacks of the removed nodes have been called
+    pub unmounted_layout_results: Vec<UnmountedLayoutResults>,
+}
+
+/// Layout results of a DOM that was replaced by `regenerate_styled_dom()`
+#[derive(Debug)]
+pub struct UnmountedLayoutResults {
+    pub layout_results: Vec<LayoutResult>,
+    /// Nodes of the `layout_results` that are not present in the next DOM anymore
+    pub before_unmount: BTreeMap<DomId, BTreeSet<NodeId>>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -590,6 +622,177 @@ impl FullHitTest {
             focused_node: focused_node.and_then(|f| Some((f.dom, f.node.into_crate_internal()?))),
         }
     }
+
+    /// CPU-side hit-test: walks the hit-testable (tagged) nodes of every
+    /// `LayoutResult`, recursing into IFrames, without needing a renderer.
+    ///
+    /// Used by the headless shell - the results should be identical to the
+    /// webrender hit-tester, except that CSS transforms are not yet taken into account
+    pub fn new(
+        layout_results: &[LayoutResult],
+        cursor_position: &CursorPosition,
+        scroll_states: &ScrollStates,
+    ) -> Self {
+
+        use crate::callbacks::{HitTestItem, ScrollHitTestItem};
+
+        let cursor_location = match cursor_position {
+            CursorPosition::OutOfWindow | CursorPosition::Uninitialized => return FullHitTest::empty(None),
+            CursorPosition::InWindow(pos) => LogicalPosition::new(pos.x, pos.y),
+        };
+
+        let mut ret = FullHitTest::empty(None);
+        let mut dom_ids = vec![(DomId::ROOT_ID, cursor_location)];
+
+        loop {
+
+            let mut new_dom_ids = Vec::new();
+
+            for (dom_id, cursor_relative_to_dom) in dom_ids.iter() {
+
+                let layout_result = match layout_results.get(dom_id.inner) {
+                    Some(s) => s,
+                    None => break,
+                };
+
+                let rects = layout_result.rects.as_ref();
+                let node_data = layout_result.styled_dom.node_data.as_container();
+                let overflowing_nodes = &layout_result.scrollable_nodes.overflowing_nodes;
+                let clip_nodes = &layout_result.scrollable_nodes.clip_nodes;
+
+                // scroll offset of a node: sum of all scroll positions of its scroll parents
+                let get_scroll_offset = |node_id: NodeId| -> LogicalPosition {
+                    let scroll_node = match overflowing_nodes.get(&AzNodeId::from_crate_internal(Some(node_id))) {
+                        Some(s) => s,
+                        None => return LogicalPosition::zero(),
+                    };
+                    scroll_states.get_scroll_position(&scroll_node.parent_external_scroll_id).unwrap_or_default()
+                };
+
+                for mapping in layout_result.styled_dom.tag_ids_to_node_ids.iter() {
+
+                    let node_id = match mapping.node_id.into_crate_internal() {
+                        Some(s) => s,
+                        None => continue,
+                    };
+
+                    // adjust the cursor for all scrolled parents and reject
+                    // the hit if the cursor is outside of a clipping parent
+                    let mut cursor = *cursor_relative_to_dom;
+                    let mut is_clipped = false;
+
+                    for parent_id in mapping.parent_node_ids.iter() {
+                        let parent_id = match parent_id.into_crate_internal() {
+                            Some(s) => s,
+                            None => continue,
+                        };
+
+                        let parent_rect = &rects[parent_id];
+                        let parent_origin = parent_rect.position.get_static_offset();
+
+                        if let Some(clip_size) = clip_nodes.get(&parent_id) {
+                            let clip_rect = LogicalRect::new(parent_origin, *clip_size);
+                            if clip_rect.hit_test(&cursor).is_none() {
+                                is_clipped = true;
+                                break;
+                            }
+                        }
+
+                        if let Some(scroll_node) = overflowing_nodes.get(&AzNodeId::from_crate_internal(Some(parent_id))) {
+                            if scroll_node.parent_rect.hit_test(&cursor).is_none() {
+                                is_clipped = true;
+                                break;
+                            }
+                            let scroll_offset = get_scroll_offset(parent_id);
+                            cursor.x += scroll_offset.x;
+                            cursor.y += scroll_offset.y;
+                        }
+                    }
+
+                    if is_clipped {
+                        continue;
+                    }
+
+                    let positioned_rect = &rects[node_id];
+                    let node_rect = LogicalRect::new(positioned_rect.position.get_static_offset(), positioned_rect.size);
+
+                    let relative_to_item = match node_rect.hit_test(&cursor) {
+                        Some(s) => s,
+                        None => continue,
+                    };
+
+                    let item = HitTestItem {
+                        point_in_viewport: *cursor_relative_to_dom,
+                        point_relative_to_item: relative_to_item,
+                        is_iframe_hit: layout_result.iframe_mapping.get(&node_id).map(|iframe_dom_id| {
+                            // IFrames scroll their own content: translate the cursor
+                            // into the coordinate space of the rendered IFrame DOM
+                            let cursor_relative_to_iframe = match overflowing_nodes.get(&AzNodeId::from_crate_internal(Some(node_id))) {
+                                Some(scroll_node) => {
+                                    let scroll_offset = scroll_node.get_scroll_offset(get_scroll_offset(node_id));
+                                    LogicalPosition::new(relative_to_item.x + scroll_offset.x, relative_to_item.y + scroll_offset.y)
+                                },
+                                None => relative_to_item,
+                            };
+                            (*iframe_dom_id, cursor_relative_to_iframe)
+                        }),
+                        is_focusable: node_data.get(node_id).map(|nd| nd.get_tab_index().is_some()).unwrap_or(false),
+                    };
+
+                    if let Some(i) = item.is_iframe_hit.as_ref() {
+                        new_dom_ids.push(*i);
+                    }
+
+                    if item.is_focusable {
+                        ret.focused_node = Some((*dom_id, node_id));
+                    }
+
+                    ret.hovered_nodes
+                    .entry(*dom_id)
+                    .or_insert_with(|| HitTest::empty())
+                    .regular_hit_test_nodes
+                    .insert(node_id, item);
+                }
+
+                // scroll nodes don't necessarily have a hit-testing tag,
+                // so they have to be tested separately
+                for (az_node_id, scroll_node) in overflowing_nodes.iter() {
+
+                    let node_id = match az_node_id.into_crate_internal() {
+                        Some(s) => s,
+                        None => continue,
+                    };
+
+                    let relative_to_item = match scroll_node.parent_rect.hit_test(cursor_relative_to_dom) {
+                        Some(s) => s,
+                        None => continue,
+                    };
+
+                    let hit_test = ret.hovered_nodes
+                    .entry(*dom_id)
+                    .or_insert_with(|| HitTest::empty());
+
+                    // IFrame nodes stay hit-testable, so that their callbacks are still invoked
+                    if !layout_result.iframe_mapping.contains_key(&node_id) {
+                        hit_test.regular_hit_test_nodes.remove(&node_id);
+                    }
+                    hit_test.scroll_hit_test_nodes.insert(node_id, ScrollHitTestItem {
+                        point_in_viewport: *cursor_relative_to_dom,
+                        point_relative_to_item: relative_to_item,
+                        scroll_node: scroll_node.clone(),
+                    });
+                }
+            }
+
+            if new_dom_ids.is_empty() {
+                break;
+            } else {
+                dom_ids = new_dom_ids;
+            }
+        }
+
+        ret
+    }
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -691,7 +894,7 @@ impl WindowInternal {
 
         let epoch = Epoch(0);
 
-        let styled_dom = {
+        let mut styled_dom = {
 
             let layout_callback = &mut init.window_create_options.state.layout_callback;
             let layout_info = LayoutCallbackInfo::new(
@@ -720,6 +923,8 @@ impl WindowInternal {
             /*last_hit_test: */ FullHitTest::empty(/*current_focus*/ None),
         );
 
+        styled_dom.set_media_context(current_window_state.get_media_context());
+
         let SolvedLayout { mut layout_results } = SolvedLayout::new(
             styled_dom,
             epoch,
@@ -746,7 +951,7 @@ impl WindowInternal {
             current_window_state.mouse_state.mouse_down()
         );
 
-        let _ = StyleAndLayoutChanges::new(
+        let sl = StyleAndLayoutChanges::new(
             &nodes_to_check,
             &mut layout_results,
             &image_cache,
@@ -754,6 +959,8 @@ impl WindowInternal {
             current_window_state.size.get_layout_size(),
             &init.document_id,
             Some(&BTreeMap::new()),
+            None,
+            None,
             Some(&BTreeMap::new()),
             &None,
             relayout_fn,
@@ -773,6 +980,12 @@ impl WindowInternal {
             &mut inital_renderer_resources,
         );
 
+        // first frame: every node is mounted
+        let mut component_events = ComponentEvents::new_mounted(&layout_results);
+        if let Some(resized) = sl.nodes_that_changed_size.as_ref() {
+            component_events.insert_resized_nodes(resized);
+        }
+
         WindowInternal {
             renderer_resources: inital_renderer_resources,
             renderer_type: gl_context.as_ref().map(|r| r.renderer_type),
@@ -786,9 +999,61 @@ impl WindowInternal {
             timers: BTreeMap::new(),
             threads: BTreeMap::new(),
             scroll_states,
+            component_events,
+            unmounted_layout_results: Vec::new(),
         }
     }
 
+    /// Returns the timers of all CSS animations that are registered on the current DOMs
+    pub fn get_css_animation_timers(&self) -> FastBTreeSet<TimerId> {
+        self.layout_results.iter().flat_map(|lr| {
+            lr.styled_dom.get_css_property_cache().running_animations.values().flat_map(|a| a.keys().cloned())
+        }).collect()
+    }
+
+    /// Starts the CSS animations of all nodes that have an `animation-name`, but
+    /// no running animation yet (i.e. nodes of a new or regenerated DOM).
+    ///
+    /// Returns the timers to start and the timers of `previous_animations` (see
+    /// `get_css_animation_timers`) that have to be stopped because their animation
+    /// doesn't apply to the DOM anymore.
+    pub fn start_css_animations(
+        &mut self,
+        previous_animations: &FastBTreeSet<TimerId>,
+        system_callbacks: &ExternalSystemCallbacks,
+    ) -> (FastHashMap<TimerId, Timer>, FastBTreeSet<TimerId>) {
+
+        use crate::id_tree::NodeId;
+
+        let mut timers_to_add = FastHashMap::new();
+
+        for (dom_id, layout_result) in self.layout_results.iter_mut().enumerate() {
+
+            if layout_result.styled_dom.get_css_property_cache().keyframes.is_empty() {
+                continue;
+            }
+
+            for node_id in (0..layout_result.styled_dom.node_data.len()).map(NodeId::new) {
+
+                if layout_result.styled_dom.get_css_property_cache().running_animations.contains_key(&node_id) {
+                    continue;
+                }
+
+                for animation in layout_result.styled_dom.get_css_animations(&node_id) {
+                    let timer_id = TimerId::unique();
+                    let node = DomNodeId { dom: DomId { inner: dom_id }, node: AzNodeId::from_crate_internal(Some(node_id)) };
+                    layout_result.styled_dom.register_css_animation(&node_id, timer_id, animation.clone());
+                    timers_to_add.insert(timer_id, animation.into_timer(node, system_callbacks.get_system_time_fn));
+                }
+            }
+        }
+
+        let current_animations = self.get_css_animation_timers();
+        let timers_to_remove = previous_animations.difference(&current_animations).cloned().collect();
+
+        (timers_to_add, timers_to_remove)
+    }
+
     /// Calls the layout function again and updates the self.internal.gl_texture_cache field
     #[cfg(all(feature = "multithreading"))]
     pub fn regenerate_styled_dom<F>(
@@ -800,16 +1065,12 @@ impl WindowInternal {
         callbacks: &RenderCallbacks,
         fc_cache_real: &mut FcFontCache,
         relayout_fn: RelayoutFn,
-        mut hit_test_func: F,
+        hit_test_func: F,
     ) where F: FnMut(&FullWindowState, &ScrollStates, &[LayoutResult]) -> FullHitTest {
 
         use crate::callbacks::LayoutCallbackInfo;
-        use crate::display_list::SolvedLayout;
-        use crate::window_state::{NodesToCheck, StyleAndLayoutChanges};
         use crate::styled_dom::DefaultCallbacksCfg;
 
-        let id_namespace = self.id_namespace;
-
         let mut styled_dom = {
 
             let layout_callback = &mut self.current_window_state.layout_callback;
@@ -835,6 +1096,78 @@ impl WindowInternal {
             enable_autotab: self.current_window_state.flags.autotab_enabled,
         });
 
+        styled_dom.set_media_context(self.current_window_state.get_media_context());
+
+        // CSS animations that still apply to the new DOM keep running
+        if let Some(old_layout_result) = self.layout_results.get(DomId::ROOT_ID.inner) {
+            styled_dom.continue_css_animations(&old_layout_result.styled_dom);
+        }
+
+        self.layout_styled_dom(
+            styled_dom,
+            image_cache,
+            gl_context,
+            all_resource_updates,
+            callbacks,
+            fc_cache_real,
+            relayout_fn,
+            hit_test_func,
+        );
+    }
+
+    /// Restyles the current DOM with the `@media` rules that apply to the current window
+    /// size / theme and lays it out again. Unlike `regenerate_styled_dom()`, the layout
+    /// function is not called, so the focused node and the state of the nodes are kept.
+    #[cfg(all(feature = "multithreading"))]
+    pub fn restyle_media_queries<F>(
+        &mut self,
+        image_cache: &ImageCache,
+        gl_context: &OptionGlContextPtr,
+        all_resource_updates: &mut Vec<ResourceUpdate>,
+        callbacks: &RenderCallbacks,
+        fc_cache_real: &mut FcFontCache,
+        relayout_fn: RelayoutFn,
+        hit_test_func: F,
+    ) where F: FnMut(&FullWindowState, &ScrollStates, &[LayoutResult]) -> FullHitTest {
+
+        let mut styled_dom = match self.layout_results.get(DomId::ROOT_ID.inner) {
+            Some(s) => s.styled_dom.clone(),
+            None => return,
+        };
+
+        styled_dom.set_media_context(self.current_window_state.get_media_context());
+
+        self.layout_styled_dom(
+            styled_dom,
+            image_cache,
+            gl_context,
+            all_resource_updates,
+            callbacks,
+            fc_cache_real,
+            relayout_fn,
+            hit_test_func,
+        );
+    }
+
+    /// Lays out the root DOM (including all IFrames) and replaces the current layout results
+    #[cfg(all(feature = "multithreading"))]
+    fn layout_styled_dom<F>(
+        &mut self,
+        styled_dom: StyledDom,
+        image_cache: &ImageCache,
+        gl_context: &OptionGlContextPtr,
+        all_resource_updates: &mut Vec<ResourceUpdate>,
+        callbacks: &RenderCallbacks,
+        fc_cache_real: &mut FcFontCache,
+        relayout_fn: RelayoutFn,
+        mut hit_test_func: F,
+    ) where F: FnMut(&FullWindowState, &ScrollStates, &[LayoutResult]) -> FullHitTest {
+
+        use crate::display_list::SolvedLayout;
+        use crate::window_state::{NodesToCheck, StyleAndLayoutChanges};
+
+        let id_namespace = self.id_namespace;
+
         let SolvedLayout {
             mut layout_results,
         } = SolvedLayout::new(
@@ -850,6 +1183,22 @@ impl WindowInternal {
             &mut self.renderer_resources,
         );
 
+        // IFrames are laid out without a scroll position, re-invoke the
+        // IFrames that are scrolled out of the bounds of their rendered DOM
+        let _ = LayoutResult::scroll_iframes(
+            &self.document_id,
+            self.epoch,
+            id_namespace,
+            &mut layout_results,
+            &self.scroll_states,
+            &self.current_window_state,
+            &mut self.renderer_resources,
+            all_resource_updates,
+            image_cache,
+            &fc_cache_real,
+            callbacks,
+        );
+
         // apply the changes for the first frame
         let ht = hit_test_func(&self.current_window_state, &self.scroll_states, &layout_results);
         self.current_window_state.last_hit_test = ht.clone();
@@ -869,6 +1218,8 @@ impl WindowInternal {
             self.current_window_state.size.get_layout_size(),
             &self.document_id,
             Some(&BTreeMap::new()),
+            None,
+            None,
             Some(&BTreeMap::new()),
             &None,
             relayout_fn,
@@ -890,11 +1241,45 @@ impl WindowInternal {
 
         // Delete unused font and image keys (that were not used in this frame)
         self.renderer_resources.do_gc(all_resource_updates);
-        self.layout_results = layout_results;
+        self.replace_layout_results(layout_results, sl.nodes_that_changed_size.as_ref());
         self.gl_texture_cache = gl_texture_cache;
         self.epoch.0 += 1;
     }
 
+    /// Replaces the layout results with the ones of a regenerated DOM and queues the
+    /// `AfterMount` / `BeforeUnmount` events of the nodes that were added / removed
+    ///
+    /// If the DOM is regenerated multiple times before the events are dispatched,
+    /// the pending `AfterMount` events are carried over to the new DOM and the
+    /// `BeforeUnmount` events of each replaced DOM are kept
+    fn replace_layout_results(
+        &mut self,
+        layout_results: Vec<LayoutResult>,
+        resized_nodes: Option<&BTreeMap<DomId, Vec<NodeId>>>,
+    ) {
+        let mut component_events = ComponentEvents::new_regenerated(
+            &self.layout_results,
+            &layout_results,
+            &self.component_events.after_mount,
+        );
+
+        if let Some(resized) = resized_nodes {
+            component_events.insert_resized_nodes(resized);
+        }
+
+        let old_layout_results = core::mem::replace(&mut self.layout_results, layout_results);
+        let before_unmount = core::mem::replace(&mut component_events.before_unmount, BTreeMap::new());
+        if !before_unmount.is_empty() {
+            self.unmounted_layout_results.push(UnmountedLayoutResults {
+                layout_results: old_layout_results,
+                before_unmount,
+            });
+        }
+
+        // NodeResized events of the previous DOM don't apply to the new DOM
+        self.component_events = component_events;
+    }
+
     /// Returns a copy of the current scroll states + scroll positions
     pub fn get_current_scroll_states(&self) -> BTreeMap<DomId, BTreeMap<AzNodeId, ScrollPosition>> {
         self.layout_results
@@ -902,9 +1287,16 @@ impl WindowInternal {
         .enumerate()
         .filter_map(|(dom_id, layout_result)| {
             let scroll_positions = layout_result.scrollable_nodes.overflowing_nodes.iter().filter_map(|(node_id, overflowing_node)| {
+                // children_rect is the virtual content, offset by the current scroll position
+                let current_scroll_position = self.scroll_states
+                    .get_scroll_position(&overflowing_node.parent_external_scroll_id)
+                    .unwrap_or_default();
                 let scroll_position = ScrollPosition {
                     parent_rect: overflowing_node.parent_rect,
-                    children_rect: overflowing_node.child_rect,
+                    children_rect: LogicalRect::new(
+                        overflowing_node.parent_rect.origin + current_scroll_position,
+                        overflowing_node.virtual_child_rect.size,
+                    ),
                 };
                 Some((*node_id, scroll_position))
             }).collect::<BTreeMap<_, _>>();
@@ -917,6 +1309,51 @@ impl WindowInternal {
         }).collect()
     }
 
+    /// Scrolls all scrollable parents of the node so that the node becomes
+    /// visible (i.e. after the node received the focus via the keyboard).
+    /// Returns whether any scroll position changed.
+    pub fn scroll_node_into_view(&mut self, node: DomNodeId) -> bool {
+
+        let layout_result = match self.layout_results.get(node.dom.inner) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let node_id = match node.node.into_crate_internal() {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let node_rect = match layout_result.rects.as_ref().get(node_id) {
+            Some(r) => LogicalRect::new(r.position.get_static_offset(), r.size),
+            None => return false,
+        };
+
+        let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+        let mut scrolled = false;
+        let mut current_parent = node_hierarchy[node_id].parent_id();
+
+        while let Some(parent_id) = current_parent {
+            let parent_az_id = AzNodeId::from_crate_internal(Some(parent_id));
+            if let Some(scroll_node) = layout_result.scrollable_nodes.overflowing_nodes.get(&parent_az_id) {
+                let old_position = self.scroll_states
+                    .get_scroll_position(&scroll_node.parent_external_scroll_id)
+                    .unwrap_or(LogicalPosition::zero());
+                let new_position = LogicalPosition::new(
+                    scroll_offset_into_view(old_position.x, node_rect.origin.x, node_rect.size.width, scroll_node.parent_rect.origin.x, scroll_node.parent_rect.size.width),
+                    scroll_offset_into_view(old_position.y, node_rect.origin.y, node_rect.size.height, scroll_node.parent_rect.origin.y, scroll_node.parent_rect.size.height),
+                );
+                if new_position != old_position {
+                    self.scroll_states.set_scroll_position(scroll_node, new_position);
+                    scrolled = true;
+                }
+            }
+            current_parent = node_hierarchy[parent_id].parent_id();
+        }
+
+        scrolled
+    }
+
     /// Returns the overflowing size of the root body node. If WindowCreateOptions.size_to_content
     /// is set, the window size should be adjusted to this size before the window is shown.
     pub fn get_content_size(&self) -> LogicalSize {
@@ -929,6 +1366,14 @@ impl WindowInternal {
         LogicalSize::new(root_width, root_height)
     }
 
+    /// Returns whether the window size or theme changed in a way that changes which
+    /// `@media` rules apply: in that case the DOM has to be restyled instead of just
+    /// being resized, see `restyle_media_queries()`
+    pub fn media_queries_changed(&self) -> bool {
+        let media_context = self.current_window_state.get_media_context();
+        self.layout_results.iter().any(|lr| lr.styled_dom.media_queries_changed(&media_context))
+    }
+
     /// Does a full re-layout (without calling layout()) again:
     /// called in simple resize() scenarios
     pub fn do_quick_resize(
@@ -940,7 +1385,7 @@ impl WindowInternal {
         window_size: &WindowSize,
         window_theme: WindowTheme,
     ) -> QuickResizeResult {
-        LayoutResult::do_quick_resize(
+        let result = LayoutResult::do_quick_resize(
             &self.document_id,
             DomId::ROOT_ID,
             image_cache,
@@ -952,9 +1397,155 @@ impl WindowInternal {
             fc_cache,
             window_size,
             window_theme,
+            &self.scroll_states,
+        );
+        self.component_events.insert_resized_nodes(&result.resized_nodes);
+        result
+    }
+
+    /// Re-invokes the IFrames that were scrolled out of the bounds of their rendered DOM
+    /// and returns their DomIds - called after scroll events, if the returned Vec is not
+    /// empty, the display list has to be rebuilt
+    ///
+    /// The IFrames nested inside of a re-invoked IFrame are re-invoked as well. However,
+    /// if the new DOM of a re-invoked IFrame contains an IFrame node that was not an
+    /// IFrame in the previous DOM, that IFrame is not rendered until the next
+    /// `regenerate_styled_dom()` (no DomId is allocated for it on scroll).
+    pub fn scroll_iframes(
+        &mut self,
+        image_cache: &ImageCache,
+        callbacks: &RenderCallbacks,
+        fc_cache: &FcFontCache,
+        all_resource_updates: &mut Vec<ResourceUpdate>,
+    ) -> Vec<DomId> {
+        LayoutResult::scroll_iframes(
+            &self.document_id,
+            self.epoch,
+            self.id_namespace,
+            &mut self.layout_results,
+            &self.scroll_states,
+            &self.current_window_state,
+            &mut self.renderer_resources,
+            all_resource_updates,
+            image_cache,
+            fc_cache,
+            callbacks,
         )
     }
 
+    /// Calls the `On::Component(..)` callbacks of all pending `ComponentEvents`:
+    /// first the `BeforeUnmount` callbacks on the previous DOM, then the `AfterMount`
+    /// and `NodeResized` callbacks on the current DOM. Should be called after the
+    /// `regenerate_styled_dom()` / `do_quick_resize()`, before the next frame is rendered.
+    ///
+    /// NOTE: `ComponentEventFilter::DefaultAction` and `Selected` are not dispatched here.
+    #[cfg(feature = "multithreading")]
+    pub fn run_component_callbacks(
+        &mut self,
+        current_window_handle: &RawWindowHandle,
+        gl_context: &OptionGlContextPtr,
+        image_cache: &mut ImageCache,
+        system_fonts: &mut FcFontCache,
+        system_callbacks: &ExternalSystemCallbacks,
+    ) -> CallCallbacksResult {
+
+        use crate::window_state::CallbacksOfHitTest;
+        use crate::dom::ComponentEventFilter;
+
+        let ComponentEvents {
+            after_mount,
+            mut node_resized,
+            ..
+        } = core::mem::replace(&mut self.component_events, ComponentEvents::default());
+
+        let mut unmount_results = Vec::new();
+
+        // BeforeUnmount: the callbacks run on the old DOMs, any changes to
+        // the old DOMs (styles, text, scroll positions) are discarded
+        for unmounted in core::mem::replace(&mut self.unmounted_layout_results, Vec::new()) {
+            let UnmountedLayoutResults { mut layout_results, before_unmount } = unmounted;
+            let mut unmount_callbacks = CallbacksOfHitTest::new_component_events(
+                &before_unmount,
+                ComponentEventFilter::BeforeUnmount,
+                &layout_results,
+            );
+            let mut discarded_scroll_states = ScrollStates::default();
+            unmount_results.push(unmount_callbacks.call(
+                &self.previous_window_state,
+                &self.current_window_state,
+                current_window_handle,
+                &BTreeMap::new(),
+                gl_context,
+                &mut layout_results,
+                &mut discarded_scroll_states,
+                image_cache,
+                system_fonts,
+                system_callbacks,
+                &self.renderer_resources,
+            ));
+        }
+
+        // a node that was just mounted does not receive a NodeResized event
+        for (dom_id, mounted) in after_mount.iter() {
+            if let Some(resized) = node_resized.get_mut(dom_id) {
+                resized.retain(|n| !mounted.contains(n));
+            }
+        }
+
+        let mut callbacks = CallbacksOfHitTest::new_component_events(
+            &after_mount,
+            ComponentEventFilter::AfterMount,
+            &self.layout_results,
+        );
+
+        callbacks.append(CallbacksOfHitTest::new_component_events(
+            &node_resized,
+            ComponentEventFilter::NodeResized,
+            &self.layout_results,
+        ));
+
+        let current_scroll_states = self.get_current_scroll_states();
+
+        let mut ret = callbacks.call(
+            &self.previous_window_state,
+            &self.current_window_state,
+            current_window_handle,
+            &current_scroll_states,
+            gl_context,
+            &mut self.layout_results,
+            &mut self.scroll_states,
+            image_cache,
+            system_fonts,
+            system_callbacks,
+            &self.renderer_resources,
+        );
+
+        // the windows created by the earlier callbacks come first
+        for unmount_result in unmount_results.into_iter().rev() {
+            ret.callbacks_update_screen.max_self(unmount_result.callbacks_update_screen);
+            if ret.modified_window_state.is_none() {
+                ret.modified_window_state = unmount_result.modified_window_state;
+            }
+            if let Some(timers) = unmount_result.timers {
+                ret.timers.get_or_insert_with(|| FastHashMap::new()).extend(timers.into_iter());
+            }
+            if let Some(threads) = unmount_result.threads {
+                ret.threads.get_or_insert_with(|| FastHashMap::new()).extend(threads.into_iter());
+            }
+            if let Some(timers_removed) = unmount_result.timers_removed {
+                ret.timers_removed.get_or_insert_with(|| FastBTreeSet::new()).extend(timers_removed.into_iter());
+            }
+            if let Some(threads_removed) = unmount_result.threads_removed {
+                ret.threads_removed.get_or_insert_with(|| FastBTreeSet::new()).extend(threads_removed.into_iter());
+            }
+            let mut windows_created = unmount_result.windows_created;
+            windows_created.append(&mut ret.windows_created);
+            ret.windows_created = windows_created;
+        }
+
+        ret
+    }
+
     // Compares the previous and current window size and returns
     // true only if the rendering area increased, but not if it
     // decreased. This is useful to prevent unnecessary redraws
@@ -1006,53 +1597,192 @@ impl WindowInternal {
     pub fn get_context_menu<'a>(&'a self, hit_test: &FullHitTest) -> Option<&'a Box<Menu>> {
         let mut context_menu = None;
 
-        for (dom_id, hit_test) in hit_test.hovered_nodes.iter() {
-            let layout_result = self.layout_results.get(dom_id.inner)?;
-            for node_id in hit_test.regular_hit_test_nodes.keys() {
-                let ndc = layout_result.styled_dom.node_data.as_container();
-                if let Some(cm) = ndc.get_extended_lifetime(*node_id).and_then(|node| node.get_context_menu()) {
-                    context_menu = Some(cm);
-                }
-            }
+        for (dom_id, hit_test) in hit_test.hovered_nodes.iter() {
+            let layout_result = self.layout_results.get(dom_id.inner)?;
+            for node_id in hit_test.regular_hit_test_nodes.keys() {
+                let ndc = layout_result.styled_dom.node_data.as_container();
+                if let Some(cm) = ndc.get_extended_lifetime(*node_id).and_then(|node| node.get_context_menu()) {
+                    context_menu = Some(cm);
+                }
+            }
+        }
+        context_menu
+    }
+
+    /// If a key was just pressed, returns the callback of the matching accelerator or
+    /// keyboard shortcut, together with the node that the callback should be invoked on.
+    ///
+    /// Accelerators are searched in the following order:
+    ///
+    /// 1. context menus of the focused node and its parents (innermost first)
+    /// 2. the menu bar of the window
+    /// 3. the window shortcuts (`WindowState::shortcuts`)
+    pub fn get_pressed_accelerator(&self) -> Option<(DomNodeId, MenuCallback)> {
+
+        let keyboard_state = &self.current_window_state.keyboard_state;
+        let previous_key = self.previous_window_state.as_ref().and_then(|p| p.keyboard_state.current_virtual_keycode.into_option());
+
+        if keyboard_state.current_virtual_keycode.is_none() ||
+           keyboard_state.current_virtual_keycode.into_option() == previous_key {
+            return None;
+        }
+
+        let root_node = DomNodeId { dom: DomId::ROOT_ID, node: AzNodeId::from_crate_internal(Some(NodeId::ZERO)) };
+        let focused_node = self.current_window_state.focused_node;
+
+        if let Some(focused_node) = focused_node {
+            if let (Some(layout_result), Some(node_id)) = (self.layout_results.get(focused_node.dom.inner), focused_node.node.into_crate_internal()) {
+                let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+                let node_data = layout_result.styled_dom.node_data.as_container();
+                let mut current_node = Some(node_id);
+                while let Some(nid) = current_node {
+                    let callback = node_data[nid].get_context_menu().and_then(|cm| cm.get_accelerator_callback(keyboard_state));
+                    if let Some(callback) = callback {
+                        return Some((DomNodeId { dom: focused_node.dom, node: AzNodeId::from_crate_internal(Some(nid)) }, callback.clone()));
+                    }
+                    current_node = node_hierarchy[nid].parent_id();
+                }
+            }
+        }
+
+        if let Some(callback) = self.get_menu_bar().and_then(|mb| mb.get_accelerator_callback(keyboard_state)) {
+            return Some((root_node, callback.clone()));
+        }
+
+        self.current_window_state.shortcuts
+        .iter()
+        .find(|s| s.keys.matches(keyboard_state))
+        .map(|s| (focused_node.unwrap_or(root_node), s.callback.clone()))
+    }
+
+    /// Returns the window shortcuts that are shadowed by the accelerator of
+    /// the menu bar or of a context menu, see `get_shortcut_conflicts()`
+    pub fn get_shortcut_conflicts(&self) -> Vec<(VirtualKeyCodeCombo, ShortcutConflict)> {
+        let context_menus = self.layout_results.iter().flat_map(|lr| {
+            lr.styled_dom.node_data.as_ref().iter().filter_map(|nd| nd.get_context_menu().map(|cm| &**cm))
+        });
+        get_shortcut_conflicts(
+            self.current_window_state.shortcuts.as_ref(),
+            self.get_menu_bar().map(|mb| &**mb),
+            context_menus,
+        )
+    }
+
+    /// Builds a snapshot of the accessibility tree of all DOMs in this window,
+    /// see `AccessibilityTree::new()`
+    pub fn get_accessibility_tree(&self) -> Option<AccessibilityTree> {
+        AccessibilityTree::new(&self.layout_results, self.current_window_state.focused_node)
+    }
+
+    /// Invokes the callback of a menu item or keyboard shortcut on the `hit_dom_node`,
+    /// similar to `run_single_timer()`
+    pub fn invoke_menu_callback(
+        &mut self,
+        menu_callback: &mut MenuCallback,
+        hit_dom_node: DomNodeId,
+        current_window_handle: &RawWindowHandle,
+        gl_context: &OptionGlContextPtr,
+        image_cache: &mut ImageCache,
+        system_fonts: &mut FcFontCache,
+        system_callbacks: &ExternalSystemCallbacks,
+    ) -> CallCallbacksResult {
+        self.invoke_single_callback(
+            hit_dom_node,
+            current_window_handle,
+            gl_context,
+            image_cache,
+            system_fonts,
+            system_callbacks,
+            |callback_info| (menu_callback.callback.cb)(&mut menu_callback.data, callback_info),
+        )
+    }
+
+    /// Runs a single timer, similar to CallbacksOfHitTest.call()
+    ///
+    /// NOTE: The timer has to be selected first by the calling code and verified
+    /// that it is ready to run
+    pub fn run_single_timer(
+        &mut self,
+        timer_id: usize,
+        frame_start: Instant,
+        data: &mut RefAny,
+        current_window_handle: &RawWindowHandle,
+        gl_context: &OptionGlContextPtr,
+        image_cache: &mut ImageCache,
+        system_fonts: &mut FcFontCache,
+        system_callbacks: &ExternalSystemCallbacks,
+    ) -> CallCallbacksResult {
+
+        use crate::task::TerminateTimer;
+
+        let timer_id = TimerId { id: timer_id };
+
+        // take the timer out of the window while it runs, so that
+        // the CallbackInfo can borrow the rest of the window
+        let mut timer = match self.timers.remove(&timer_id) {
+            Some(s) => s,
+            None => return CallCallbacksResult::empty(),
+        };
+
+        // TODO: store the hit DOM of the timer?
+        let hit_dom_node = match timer.node_id.into_option() {
+            Some(s) => s,
+            None => DomNodeId { dom: DomId::ROOT_ID, node: AzNodeId::from_crate_internal(None) },
+        };
+
+        let mut should_terminate = TerminateTimer::Continue;
+
+        let mut ret = self.invoke_single_callback(
+            hit_dom_node,
+            current_window_handle,
+            gl_context,
+            image_cache,
+            system_fonts,
+            system_callbacks,
+            |callback_info| {
+                let tcr = timer.invoke(
+                    data,
+                    callback_info,
+                    frame_start.clone(),
+                    system_callbacks.get_system_time_fn
+                );
+                should_terminate = tcr.should_terminate;
+                tcr.should_update
+            },
+        );
+
+        self.timers.insert(timer_id, timer);
+
+        if should_terminate == TerminateTimer::Terminate {
+            ret.timers_removed.get_or_insert_with(|| BTreeSet::new()).insert(timer_id);
         }
-        context_menu
+
+        return ret;
     }
 
-    /// Runs a single timer, similar to CallbacksOfHitTest.call()
-    ///
-    /// NOTE: The timer has to be selected first by the calling code and verified
-    /// that it is ready to run
-    pub fn run_single_timer(
+    /// Creates the `CallbackInfo` for a single callback on the `hit_dom_node`,
+    /// invokes the `callback` with it and collects everything that the callback
+    /// changed into a `CallCallbacksResult` (shared by timers and menu callbacks)
+    fn invoke_single_callback<F: FnOnce(crate::callbacks::CallbackInfo) -> Update>(
         &mut self,
-        timer_id: usize,
-        frame_start: Instant,
-        data: &mut RefAny,
+        hit_dom_node: DomNodeId,
         current_window_handle: &RawWindowHandle,
         gl_context: &OptionGlContextPtr,
         image_cache: &mut ImageCache,
         system_fonts: &mut FcFontCache,
         system_callbacks: &ExternalSystemCallbacks,
+        callback: F,
     ) -> CallCallbacksResult {
 
-        use crate::task::TerminateTimer;
         use crate::callbacks::CallbackInfo;
 
-        let mut ret = CallCallbacksResult {
-            should_scroll_render: false,
-            callbacks_update_screen: Update::DoNothing,
-            modified_window_state: None,
-            css_properties_changed: None,
-            words_changed: None,
-            images_changed: None,
-            image_masks_changed: None,
-            nodes_scrolled_in_callbacks: None,
-            update_focused_node: None,
-            timers: None,
-            threads: None,
-            timers_removed: None,
-            threads_removed: None,
-            windows_created: Vec::new(),
-            cursor_changed: false,
+        let mut ret = CallCallbacksResult::empty();
+
+        let current_scroll_states = self.get_current_scroll_states();
+
+        let layout_result = match self.layout_results.get_mut(hit_dom_node.dom.inner) {
+            Some(s) => s,
+            None => return ret,
         };
 
         let mut ret_modified_window_state: WindowState = self.current_window_state.clone().into();
@@ -1065,92 +1795,72 @@ impl WindowInternal {
         let mut ret_images_changed = BTreeMap::new();
         let mut ret_image_masks_changed = BTreeMap::new();
         let mut ret_css_properties_changed = BTreeMap::new();
+        let mut ret_css_variables_changed = BTreeMap::new();
+        let mut ret_css_transitions_changed = BTreeMap::new();
         let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
-
-        let mut should_terminate = TerminateTimer::Continue;
         let mut new_focus_target = None;
+        let mut stop_propagation = false;
 
-        let current_scroll_states = self.get_current_scroll_states();
-
-        if let Some(timer) = self.timers.get_mut(&TimerId { id: timer_id }) {
-
-            let mut stop_propagation = false;
-
-            // TODO: store the hit DOM of the timer?
-            let hit_dom_node = match timer.node_id.into_option() {
-                Some(s) => s,
-                None => DomNodeId { dom: DomId::ROOT_ID, node: AzNodeId::from_crate_internal(None) },
-            };
-            let cursor_relative_to_item = OptionLogicalPosition::None;
-            let cursor_in_viewport = OptionLogicalPosition::None;
-
-            let layout_result = &mut self.layout_results[hit_dom_node.dom.inner];
-            let mut datasets = layout_result.styled_dom.node_data.split_into_callbacks_and_dataset(
-                &layout_result.styled_dom.css_property_cache,
-                &layout_result.styled_dom.styled_nodes.as_container(),
-                &self.renderer_resources,
-            );
-
-            let callback_info = CallbackInfo::new(
-                &layout_result.styled_dom.css_property_cache.ptr,
-                &layout_result.styled_dom.styled_nodes,
-                &self.previous_window_state,
-                &self.current_window_state,
-                &mut ret_modified_window_state,
-                gl_context,
-                image_cache,
-                system_fonts,
-                &mut ret_timers,
-                &mut ret_threads,
-                &mut ret_timers_removed,
-                &mut ret_threads_removed,
-                &mut ret.windows_created,
-                current_window_handle,
-                &layout_result.styled_dom.node_hierarchy,
-                system_callbacks,
-                &layout_result.words_cache,
-                &layout_result.shaped_words_cache,
-                &layout_result.positioned_words_cache,
-                &layout_result.rects,
-                &datasets.2,
-                &mut datasets.1,
-                &mut stop_propagation,
-                &mut new_focus_target,
-                &mut ret_words_changed,
-                &mut ret_images_changed,
-                &mut ret_image_masks_changed,
-                &mut ret_css_properties_changed,
-                &current_scroll_states,
-                &mut ret_nodes_scrolled_in_callbacks,
-                hit_dom_node,
-                cursor_relative_to_item,
-                cursor_in_viewport,
-            );
-
-            let tcr = timer.invoke(
-                data,
-                callback_info,
-                frame_start.clone(),
-                system_callbacks.get_system_time_fn
-            );
+        let mut datasets = layout_result.styled_dom.node_data.split_into_callbacks_and_dataset(
+            &layout_result.styled_dom.css_property_cache,
+            &layout_result.styled_dom.styled_nodes.as_container(),
+            &self.renderer_resources,
+        );
 
-            ret.callbacks_update_screen = tcr.should_update;
-            should_terminate = tcr.should_terminate;
+        let callback_info = CallbackInfo::new(
+            &layout_result.styled_dom.css_property_cache.ptr,
+            &layout_result.styled_dom.styled_nodes,
+            &self.previous_window_state,
+            &self.current_window_state,
+            &mut ret_modified_window_state,
+            gl_context,
+            image_cache,
+            system_fonts,
+            &mut ret_timers,
+            &mut ret_threads,
+            &mut ret_timers_removed,
+            &mut ret_threads_removed,
+            &mut ret.windows_created,
+            current_window_handle,
+            &layout_result.styled_dom.node_hierarchy,
+            system_callbacks,
+            &layout_result.words_cache,
+            &layout_result.shaped_words_cache,
+            &layout_result.positioned_words_cache,
+            &layout_result.rects,
+            &datasets.2,
+            &mut datasets.1,
+            &mut stop_propagation,
+            &mut new_focus_target,
+            &mut ret_words_changed,
+            &mut ret_images_changed,
+            &mut ret_image_masks_changed,
+            &mut ret_css_properties_changed,
+            &mut ret_css_variables_changed,
+            &mut ret_css_transitions_changed,
+            &current_scroll_states,
+            &mut ret_nodes_scrolled_in_callbacks,
+            hit_dom_node,
+            OptionLogicalPosition::None,
+            OptionLogicalPosition::None,
+        );
 
-            if !ret_timers.is_empty() { ret.timers = Some(ret_timers); }
-            if !ret_threads.is_empty() { ret.threads = Some(ret_threads); }
-            if ret_modified_window_state != ret_window_state {
-                ret.modified_window_state = Some(ret_modified_window_state);
-            }
-            if !ret_threads_removed.is_empty() { ret.threads_removed = Some(ret_threads_removed); }
-            if !ret_timers_removed.is_empty() { ret.timers_removed = Some(ret_timers_removed); }
-            if !ret_words_changed.is_empty() { ret.words_changed = Some(ret_words_changed); }
-            if !ret_images_changed.is_empty() { ret.images_changed = Some(ret_images_changed); }
-            if !ret_image_masks_changed.is_empty() { ret.image_masks_changed = Some(ret_image_masks_changed); }
-            if !ret_css_properties_changed.is_empty() { ret.css_properties_changed = Some(ret_css_properties_changed); }
-            if !ret_nodes_scrolled_in_callbacks.is_empty() { ret.nodes_scrolled_in_callbacks = Some(ret_nodes_scrolled_in_callbacks); }
+        ret.callbacks_update_screen = callback(callback_info);
 
+        if !ret_timers.is_empty() { ret.timers = Some(ret_timers); }
+        if !ret_threads.is_empty() { ret.threads = Some(ret_threads); }
+        if ret_modified_window_state != ret_window_state {
+            ret.modified_window_state = Some(ret_modified_window_state);
         }
+        if !ret_threads_removed.is_empty() { ret.threads_removed = Some(ret_threads_removed); }
+        if !ret_timers_removed.is_empty() { ret.timers_removed = Some(ret_timers_removed); }
+        if !ret_words_changed.is_empty() { ret.words_changed = Some(ret_words_changed); }
+        if !ret_images_changed.is_empty() { ret.images_changed = Some(ret_images_changed); }
+        if !ret_image_masks_changed.is_empty() { ret.image_masks_changed = Some(ret_image_masks_changed); }
+        if !ret_css_properties_changed.is_empty() { ret.css_properties_changed = Some(ret_css_properties_changed); }
+        if !ret_css_variables_changed.is_empty() { ret.css_variables_changed = Some(ret_css_variables_changed); }
+        if !ret_css_transitions_changed.is_empty() { ret.css_transitions_changed = Some(ret_css_transitions_changed); }
+        if !ret_nodes_scrolled_in_callbacks.is_empty() { ret.nodes_scrolled_in_callbacks = Some(ret_nodes_scrolled_in_callbacks); }
 
         if let Some(ft) = new_focus_target {
             if let Ok(new_focus_node) = ft.resolve(&self.layout_results, self.current_window_state.focused_node) {
@@ -1158,11 +1868,7 @@ impl WindowInternal {
             }
         }
 
-        if should_terminate == TerminateTimer::Terminate {
-            ret.timers_removed.get_or_insert_with(|| BTreeSet::new()).insert(TimerId { id: timer_id });
-        }
-
-        return ret;
+        ret
     }
 
     pub fn run_all_threads(
@@ -1187,6 +1893,8 @@ impl WindowInternal {
             callbacks_update_screen: Update::DoNothing,
             modified_window_state: None,
             css_properties_changed: None,
+            css_variables_changed: None,
+            css_transitions_changed: None,
             words_changed: None,
             images_changed: None,
             image_masks_changed: None,
@@ -1210,6 +1918,8 @@ impl WindowInternal {
         let mut ret_images_changed = BTreeMap::new();
         let mut ret_image_masks_changed = BTreeMap::new();
         let mut ret_css_properties_changed = BTreeMap::new();
+        let mut ret_css_variables_changed = BTreeMap::new();
+        let mut ret_css_transitions_changed = BTreeMap::new();
         let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
         let mut new_focus_target = None;
         let mut stop_propagation = false;
@@ -1281,6 +1991,8 @@ impl WindowInternal {
                 &mut ret_images_changed,
                 &mut ret_image_masks_changed,
                 &mut ret_css_properties_changed,
+                &mut ret_css_variables_changed,
+                &mut ret_css_transitions_changed,
                 &current_scroll_states,
                 &mut ret_nodes_scrolled_in_callbacks,
                 hit_dom_node,
@@ -1307,6 +2019,8 @@ impl WindowInternal {
         if !ret_images_changed.is_empty() { ret.images_changed = Some(ret_images_changed); }
         if !ret_image_masks_changed.is_empty() { ret.image_masks_changed = Some(ret_image_masks_changed); }
         if !ret_css_properties_changed.is_empty() { ret.css_properties_changed = Some(ret_css_properties_changed); }
+        if !ret_css_variables_changed.is_empty() { ret.css_variables_changed = Some(ret_css_variables_changed); }
+        if !ret_css_transitions_changed.is_empty() { ret.css_transitions_changed = Some(ret_css_transitions_changed); }
         if !ret_nodes_scrolled_in_callbacks.is_empty() { ret.nodes_scrolled_in_callbacks = Some(ret_nodes_scrolled_in_callbacks); }
 
         if let Some(ft) = new_focus_target {
@@ -1443,6 +2157,9 @@ pub struct WindowState {
     pub layout_callback: LayoutCallback,
     /// Optional callback to run when the window closes
     pub close_callback: OptionCallback,
+    /// Keyboard shortcuts that are not attached to a menu item,
+    /// see `add_shortcut()`
+    pub shortcuts: KeyboardShortcutVec,
 }
 
 impl_option!(WindowState, OptionWindowState, copy = false, [Debug, Clone, PartialEq]);
@@ -1521,6 +2238,8 @@ pub struct FullWindowState {
     /// Callback to run before the window closes. If this callback returns `DoNothing`,
     /// the window won't close, otherwise it'll close regardless
     pub close_callback: OptionCallback,
+    /// Window-wide keyboard shortcuts
+    pub shortcuts: KeyboardShortcutVec,
     // --
     /// Current monitor
     pub monitor: Monitor,
@@ -1555,6 +2274,7 @@ impl Default for FullWindowState {
             background_color: ColorU::WHITE,
             layout_callback: LayoutCallback::default(),
             close_callback: OptionCallback::None,
+            shortcuts: KeyboardShortcutVec::from_const_slice(&[]),
             renderer_options: RendererOptions::default(),
             monitor: Monitor::default(),
             // --
@@ -1596,6 +2316,19 @@ impl FullWindowState {
         }
     }
 
+    /// Returns the window size, HiDPI factor and theme that `@media` rules are evaluated against
+    pub fn get_media_context(&self) -> CssMediaContext {
+        CssMediaContext {
+            width: self.size.dimensions.width,
+            height: self.size.dimensions.height,
+            dppx: self.size.hidpi_factor,
+            color_scheme: match self.theme {
+                WindowTheme::DarkMode => CssMediaColorScheme::Dark,
+                WindowTheme::LightMode => CssMediaColorScheme::Light,
+            },
+        }
+    }
+
     /// Creates a FullWindowState from a regular WindowState,
     /// fills non-available fields with the given values
     ///
@@ -1624,6 +2357,7 @@ impl FullWindowState {
             background_color: window_state.background_color,
             layout_callback: window_state.layout_callback.clone(),
             close_callback: window_state.close_callback,
+            shortcuts: window_state.shortcuts.clone(),
             renderer_options: window_state.renderer_options,
             dropped_file,
             hovered_file,
@@ -1657,6 +2391,7 @@ impl From<FullWindowState> for WindowState {
             background_color: full_window_state.background_color,
             layout_callback: full_window_state.layout_callback,
             close_callback: full_window_state.close_callback,
+            shortcuts: full_window_state.shortcuts,
             renderer_options: full_window_state.renderer_options,
         }
     }
@@ -1682,6 +2417,11 @@ pub struct CallCallbacksResult {
     /// If the focus target changes in the callbacks, the function will automatically
     /// restyle the DOM and set the new focus target
     pub css_properties_changed: Option<BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>>,
+    /// CSS variables (`--accent`) that were changed in the callbacks, the `var()` references
+    /// of the stylesheet get re-resolved before the next frame
+    pub css_variables_changed: Option<BTreeMap<DomId, BTreeMap<NodeId, Vec<CssCustomProperty>>>>,
+    /// Interpolated values of the running CSS transitions, written by the timers driving the transitions
+    pub css_transitions_changed: Option<BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>>,
     /// If the callbacks have scrolled any nodes, the new scroll position will be stored here
     pub nodes_scrolled_in_callbacks: Option<BTreeMap<DomId, BTreeMap<AzNodeId, LogicalPosition>>>,
     /// Whether the focused node was changed from the callbacks
@@ -1701,6 +2441,28 @@ pub struct CallCallbacksResult {
 }
 
 impl CallCallbacksResult {
+    /// Result of a callback that didn't change anything
+    pub fn empty() -> Self {
+        Self {
+            should_scroll_render: false,
+            callbacks_update_screen: Update::DoNothing,
+            modified_window_state: None,
+            css_properties_changed: None,
+            css_variables_changed: None,
+            css_transitions_changed: None,
+            words_changed: None,
+            images_changed: None,
+            image_masks_changed: None,
+            nodes_scrolled_in_callbacks: None,
+            update_focused_node: None,
+            timers: None,
+            threads: None,
+            timers_removed: None,
+            threads_removed: None,
+            windows_created: Vec::new(),
+            cursor_changed: false,
+        }
+    }
     pub fn cursor_changed(&self) -> bool {
         self.cursor_changed
     }
@@ -1999,6 +2761,32 @@ impl WindowState {
         &self.keyboard_state
     }
 
+    /// Registers a window-wide keyboard shortcut. If another shortcut already
+    /// uses the same key combination, the shortcut is not registered.
+    ///
+    /// Note that accelerators of the menu bar and of context menus take
+    /// precedence over window shortcuts, see `WindowInternal::get_shortcut_conflicts()`
+    pub fn add_shortcut(&mut self, shortcut: KeyboardShortcut) -> Result<(), ShortcutConflict> {
+        if let Some(existing) = self.shortcuts.iter().find(|s| s.keys.is_equivalent(&shortcut.keys)) {
+            return Err(ShortcutConflict::Shortcut(existing.keys.clone()));
+        }
+        let mut shortcuts = self.shortcuts.clone().into_library_owned_vec();
+        shortcuts.push(shortcut);
+        self.shortcuts = shortcuts.into();
+        Ok(())
+    }
+
+    /// Removes the shortcut with the given key combination,
+    /// returns whether a shortcut was removed
+    pub fn remove_shortcut(&mut self, keys: &VirtualKeyCodeCombo) -> bool {
+        let mut shortcuts = self.shortcuts.clone().into_library_owned_vec();
+        let len_before = shortcuts.len();
+        shortcuts.retain(|s| !s.keys.is_equivalent(keys));
+        let removed = shortcuts.len() != len_before;
+        self.shortcuts = shortcuts.into();
+        removed
+    }
+
     /// Returns the physical (width, height) in pixel of this window
     pub fn get_physical_size(&self) -> (usize, usize) {
         (self.size.dimensions.width as usize, self.size.dimensions.height as usize)
@@ -2871,6 +3659,83 @@ pub enum MenuPopupPosition {
     AutoHitRect,
 }
 
+impl MenuPopupPosition {
+
+    /// Returns where a popup of `popup_size` should be placed, given the rect that was
+    /// clicked on and the cursor position (all in window coordinates). The popup is
+    /// moved / shrunk so that it stays within the window.
+    pub fn get_popup_rect(
+        &self,
+        hit_rect: LogicalRect,
+        cursor: LogicalPosition,
+        popup_size: LogicalSize,
+        window_size: LogicalSize,
+    ) -> LogicalRect {
+
+        use self::MenuPopupPosition::*;
+
+        let LogicalSize { width, height } = popup_size;
+
+        let space_below_cursor = window_size.height - cursor.y;
+        let space_right_of_cursor = window_size.width - cursor.x;
+        let space_below_hit_rect = window_size.height - hit_rect.max_y();
+
+        let resolved = match self {
+            AutoCursor => {
+                let fits_below = height <= space_below_cursor || space_below_cursor >= cursor.y;
+                let fits_right = width <= space_right_of_cursor || space_right_of_cursor >= cursor.x;
+                match (fits_below, fits_right) {
+                    (true, true) => BottomRightOfCursor,
+                    (true, false) => BottomLeftOfCursor,
+                    (false, true) => TopRightOfCursor,
+                    (false, false) => TopLeftOfCursor,
+                }
+            },
+            AutoHitRect => {
+                if height <= space_below_hit_rect || space_below_hit_rect >= hit_rect.min_y() {
+                    BottomOfHitRect
+                } else {
+                    TopOfHitRect
+                }
+            },
+            other => *other,
+        };
+
+        let origin = match resolved {
+            BottomLeftOfCursor => LogicalPosition::new(cursor.x - width, cursor.y),
+            BottomRightOfCursor => LogicalPosition::new(cursor.x, cursor.y),
+            TopLeftOfCursor => LogicalPosition::new(cursor.x - width, cursor.y - height),
+            TopRightOfCursor => LogicalPosition::new(cursor.x, cursor.y - height),
+            BottomOfHitRect => LogicalPosition::new(hit_rect.min_x(), hit_rect.max_y()),
+            LeftOfHitRect => LogicalPosition::new(hit_rect.min_x() - width, hit_rect.min_y()),
+            TopOfHitRect => LogicalPosition::new(hit_rect.min_x(), hit_rect.min_y() - height),
+            RightOfHitRect => LogicalPosition::new(hit_rect.max_x(), hit_rect.min_y()),
+            AutoCursor | AutoHitRect => unreachable!(),
+        };
+
+        // cut off the part of the popup that is outside of the window, but
+        // don't move it over the hit rect / cursor it is attached to
+        let mut rect = LogicalRect::new(origin, popup_size);
+
+        if rect.origin.x < 0.0 {
+            rect.origin.x = 0.0;
+        }
+        if rect.max_x() > window_size.width {
+            rect.origin.x = (window_size.width - width).max(0.0);
+            rect.size.width = rect.size.width.min(window_size.width);
+        }
+        if rect.origin.y < 0.0 {
+            rect.size.height = (rect.size.height + rect.origin.y).max(0.0);
+            rect.origin.y = 0.0;
+        }
+        if rect.max_y() > window_size.height {
+            rect.size.height = (window_size.height - rect.origin.y).max(0.0);
+        }
+
+        rect
+    }
+}
+
 impl Menu {
     pub fn get_hash(&self) -> u64 {
         use ahash::AHasher as HashAlgorithm;
@@ -2881,6 +3746,48 @@ impl Menu {
 
         hasher.finish()
     }
+
+    /// Returns the callback of the menu item whose accelerator matches the
+    /// currently pressed keys (including sub-menus). Greyed out or disabled
+    /// items (and their sub-menus) are ignored.
+    pub fn get_accelerator_callback(&self, keyboard_state: &KeyboardState) -> Option<&MenuCallback> {
+        find_menu_item(self.items.as_ref(), false, &|mi| {
+            mi.callback.is_some() &&
+            mi.accelerator.as_ref().map(|a| a.matches(keyboard_state)).unwrap_or(false)
+        }).and_then(|mi| mi.callback.as_ref())
+    }
+
+    /// Returns the menu item (including sub-menus and disabled items)
+    /// whose accelerator uses the same key combination as `keys`
+    pub fn find_accelerator(&self, keys: &VirtualKeyCodeCombo) -> Option<&StringMenuItem> {
+        find_menu_item(self.items.as_ref(), true, &|mi| {
+            mi.accelerator.as_ref().map(|a| a.is_equivalent(keys)).unwrap_or(false)
+        })
+    }
+}
+
+// Depth-first search for the first menu item matching the predicate
+fn find_menu_item<'a>(
+    items: &'a [MenuItem],
+    include_disabled: bool,
+    predicate: &dyn Fn(&StringMenuItem) -> bool,
+) -> Option<&'a StringMenuItem> {
+    for item in items.iter() {
+        let mi = match item {
+            MenuItem::String(mi) => mi,
+            MenuItem::Separator | MenuItem::BreakLine => continue,
+        };
+        if !include_disabled && mi.state != MenuItemState::Normal {
+            continue;
+        }
+        if predicate(mi) {
+            return Some(mi);
+        }
+        if let Some(found) = find_menu_item(mi.children.as_ref(), include_disabled, predicate) {
+            return Some(found);
+        }
+    }
+    None
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
@@ -2943,6 +3850,149 @@ pub struct VirtualKeyCodeCombo {
 
 impl_option!(VirtualKeyCodeCombo, OptionVirtualKeyCodeCombo, copy = false, [Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord]);
 
+impl VirtualKeyCodeCombo {
+
+    /// Returns whether the key combination was just pressed: all keys of the
+    /// combination have to be held down, the key that was pressed last has to
+    /// be part of the combination and no other modifier key may be held down
+    /// (so that "Ctrl + Shift + S" does not trigger "Ctrl + S").
+    ///
+    /// Left and right modifier keys (i.e. `LControl` / `RControl`) are interchangeable.
+    pub fn matches(&self, keyboard_state: &KeyboardState) -> bool {
+
+        use self::VirtualKeyCode::*;
+
+        let keys = self.keys.as_ref();
+
+        let current_key = match keyboard_state.current_virtual_keycode.as_ref() {
+            Some(s) => normalize_modifier_key(*s),
+            None => return false,
+        };
+
+        if !keys.iter().any(|k| normalize_modifier_key(*k) == current_key) {
+            return false;
+        }
+
+        let all_keys_down = keys.iter().all(|k| match k {
+            LShift | RShift => keyboard_state.shift_down,
+            LControl | RControl => keyboard_state.ctrl_down,
+            LAlt | RAlt => keyboard_state.alt_down,
+            LWin | RWin => keyboard_state.super_down,
+            other => keyboard_state.pressed_virtual_keycodes.contains_hm_item(other),
+        });
+
+        let contains = |key: VirtualKeyCode| keys.iter().any(|k| normalize_modifier_key(*k) == key);
+
+        all_keys_down &&
+        keyboard_state.shift_down == contains(LShift) &&
+        keyboard_state.ctrl_down == contains(LControl) &&
+        keyboard_state.alt_down == contains(LAlt) &&
+        keyboard_state.super_down == contains(LWin)
+    }
+
+    /// Returns whether both combinations consist of the same keys, regardless
+    /// of the order of the keys and of left / right modifier keys
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        self.get_normalized_keys() == other.get_normalized_keys()
+    }
+
+    fn get_normalized_keys(&self) -> Vec<VirtualKeyCode> {
+        let mut keys = self.keys.iter().map(|k| normalize_modifier_key(*k)).collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+}
+
+// Maps right modifier keys to their left equivalent
+fn normalize_modifier_key(key: VirtualKeyCode) -> VirtualKeyCode {
+    use self::VirtualKeyCode::*;
+    match key {
+        RShift => LShift,
+        RControl => LControl,
+        RAlt => LAlt,
+        RWin => LWin,
+        other => other,
+    }
+}
+
+/// Keyboard shortcut that is registered on the window instead of on a menu item,
+/// see `WindowState::add_shortcut()` and `CallbackInfo::add_shortcut()`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+#[repr(C)]
+pub struct KeyboardShortcut {
+    /// Key combination that triggers the shortcut
+    pub keys: VirtualKeyCodeCombo,
+    /// Callback to invoke - the hit node of the callback is the
+    /// currently focused node (or the root node if nothing is focused)
+    pub callback: MenuCallback,
+}
+
+impl_vec!(KeyboardShortcut, KeyboardShortcutVec, KeyboardShortcutVecDestructor);
+impl_vec_clone!(KeyboardShortcut, KeyboardShortcutVec, KeyboardShortcutVecDestructor);
+impl_vec_debug!(KeyboardShortcut, KeyboardShortcutVec);
+impl_vec_partialeq!(KeyboardShortcut, KeyboardShortcutVec);
+impl_vec_partialord!(KeyboardShortcut, KeyboardShortcutVec);
+impl_vec_hash!(KeyboardShortcut, KeyboardShortcutVec);
+impl_vec_eq!(KeyboardShortcut, KeyboardShortcutVec);
+impl_vec_ord!(KeyboardShortcut, KeyboardShortcutVec);
+
+/// Returned when a keyboard shortcut can't be registered
+/// because its key combination is already in use
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+#[repr(C, u8)]
+pub enum ShortcutConflict {
+    /// Another window shortcut uses the same key combination
+    Shortcut(VirtualKeyCodeCombo),
+    /// The menu item with the given label uses the
+    /// same key combination as its accelerator
+    MenuItem(AzString),
+    /// The item of a context menu with the given label uses the same key
+    /// combination as its accelerator (the shortcut is shadowed while
+    /// the focus is inside of the node with the context menu)
+    ContextMenuItem(AzString),
+}
+
+impl core::fmt::Display for ShortcutConflict {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        use self::ShortcutConflict::*;
+        match self {
+            Shortcut(keys) => write!(f, "key combination {:?} is already used by another shortcut", keys.keys.as_ref()),
+            MenuItem(label) => write!(f, "key combination is already used by the menu item \"{}\"", label.as_str()),
+            ContextMenuItem(label) => write!(f, "key combination is already used by the context menu item \"{}\"", label.as_str()),
+        }
+    }
+}
+
+/// Returns the `shortcuts` whose key combination is already used by a menu accelerator
+/// (menu accelerators take precedence over window shortcuts, see `WindowInternal::get_pressed_accelerator()`):
+///
+/// - an accelerator of the `menu_bar` shadows the shortcut completely
+/// - an accelerator of one of the `context_menus` shadows the shortcut
+///   while the focus is inside of the node with the context menu
+pub fn get_shortcut_conflicts<'a, I: Iterator<Item = &'a Menu>>(
+    shortcuts: &[KeyboardShortcut],
+    menu_bar: Option<&Menu>,
+    context_menus: I,
+) -> Vec<(VirtualKeyCodeCombo, ShortcutConflict)> {
+
+    let context_menus = context_menus.collect::<Vec<_>>();
+    let mut conflicts = Vec::new();
+
+    for s in shortcuts.iter() {
+        if let Some(mi) = menu_bar.and_then(|mb| mb.find_accelerator(&s.keys)) {
+            conflicts.push((s.keys.clone(), ShortcutConflict::MenuItem(mi.label.clone())));
+        }
+        for cm in context_menus.iter() {
+            if let Some(mi) = cm.find_accelerator(&s.keys) {
+                conflicts.push((s.keys.clone(), ShortcutConflict::ContextMenuItem(mi.label.clone())));
+            }
+        }
+    }
+
+    conflicts
+}
+
 /// Menu callback: What data / function pointer should
 /// be called when the menu item is clicked?
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
@@ -2975,4 +4025,274 @@ pub enum MenuItemState {
     Greyed,
     /// Menu item is disabled, but NOT greyed out
     Disabled,
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_virtual_key_code_combo_matches() {
+
+    use self::VirtualKeyCode::*;
+
+    let ctrl_s = VirtualKeyCodeCombo { keys: vec![LControl, S].into() };
+    let ctrl_shift_s = VirtualKeyCodeCombo { keys: vec![S, RShift, RControl].into() };
+
+    let mut keyboard_state = KeyboardState::default();
+    keyboard_state.ctrl_down = true;
+    keyboard_state.pressed_virtual_keycodes = vec![LControl, S].into();
+    keyboard_state.current_virtual_keycode = Some(S).into();
+
+    assert!(ctrl_s.matches(&keyboard_state));
+    assert!(!ctrl_shift_s.matches(&keyboard_state));
+
+    // additional modifier: only the combination including Shift matches
+    keyboard_state.shift_down = true;
+    keyboard_state.pressed_virtual_keycodes = vec![LControl, LShift, S].into();
+
+    assert!(!ctrl_s.matches(&keyboard_state));
+    assert!(ctrl_shift_s.matches(&keyboard_state));
+
+    // the last pressed key has to be part of the combination
+    keyboard_state.current_virtual_keycode = Some(A).into();
+    assert!(!ctrl_shift_s.matches(&keyboard_state));
+
+    assert!(ctrl_shift_s.is_equivalent(&VirtualKeyCodeCombo { keys: vec![LControl, LShift, S].into() }));
+    assert!(!ctrl_s.is_equivalent(&ctrl_shift_s));
+}
+
+#[test]
+fn test_shortcut_conflicts() {
+
+    use self::VirtualKeyCode::*;
+    use crate::callbacks::CallbackInfo;
+
+    extern "C" fn do_nothing(_: &mut RefAny, _: CallbackInfo) -> Update { Update::DoNothing }
+
+    let ctrl_s = VirtualKeyCodeCombo { keys: vec![LControl, S].into() };
+    let ctrl_c = VirtualKeyCodeCombo { keys: vec![LControl, C].into() };
+    let ctrl_q = VirtualKeyCodeCombo { keys: vec![LControl, Q].into() };
+
+    let shortcut = |keys: &VirtualKeyCodeCombo| KeyboardShortcut {
+        keys: keys.clone(),
+        callback: MenuCallback { callback: Callback { cb: do_nothing }, data: RefAny::new(()) },
+    };
+    let menu = |label: &'static str, keys: &VirtualKeyCodeCombo| Menu {
+        items: vec![MenuItem::String(StringMenuItem {
+            label: label.into(),
+            accelerator: Some(keys.clone()).into(),
+            callback: None.into(),
+            state: MenuItemState::Normal,
+            icon: None.into(),
+            children: Vec::new().into(),
+        })].into(),
+        position: MenuPopupPosition::AutoCursor,
+    };
+
+    let shortcuts = vec![shortcut(&ctrl_s), shortcut(&ctrl_c), shortcut(&ctrl_q)];
+    let menu_bar = menu("Save", &ctrl_s);
+    let context_menus = vec![menu("Copy", &ctrl_c), menu("Copy row", &ctrl_c)];
+
+    assert_eq!(get_shortcut_conflicts(&shortcuts, Some(&menu_bar), context_menus.iter()), vec![
+        (ctrl_s.clone(), ShortcutConflict::MenuItem("Save".into())),
+        (ctrl_c.clone(), ShortcutConflict::ContextMenuItem("Copy".into())),
+        (ctrl_c.clone(), ShortcutConflict::ContextMenuItem("Copy row".into())),
+    ]);
+
+    // without menus, no shortcut is shadowed
+    assert!(get_shortcut_conflicts(&shortcuts, None, core::iter::empty()).is_empty());
+}
+
+#[test]
+fn test_menu_popup_position() {
+
+    let window_size = LogicalSize::new(400.0, 300.0);
+    let hit_rect = LogicalRect::new(LogicalPosition::new(10.0, 250.0), LogicalSize::new(100.0, 20.0));
+    let cursor = LogicalPosition::new(20.0, 260.0);
+    let popup_size = LogicalSize::new(100.0, 120.0);
+
+    // not enough space below the hit rect, but enough above
+    let rect = MenuPopupPosition::AutoHitRect.get_popup_rect(hit_rect, cursor, popup_size, window_size);
+    assert_eq!(rect, LogicalRect::new(LogicalPosition::new(10.0, 130.0), popup_size));
+
+    // forced below the hit rect: the popup is cut off at the bottom of the window
+    let rect = MenuPopupPosition::BottomOfHitRect.get_popup_rect(hit_rect, cursor, popup_size, window_size);
+    assert_eq!(rect, LogicalRect::new(LogicalPosition::new(10.0, 270.0), LogicalSize::new(100.0, 30.0)));
+
+    // left of the cursor would be outside of the window
+    let rect = MenuPopupPosition::TopLeftOfCursor.get_popup_rect(hit_rect, cursor, popup_size, window_size);
+    assert_eq!(rect, LogicalRect::new(LogicalPosition::new(0.0, 140.0), popup_size));
+
+    let rect = MenuPopupPosition::AutoCursor.get_popup_rect(hit_rect, cursor, popup_size, window_size);
+    assert_eq!(rect, LogicalRect::new(LogicalPosition::new(20.0, 140.0), popup_size));
+}
+
+#[test]
+fn test_iframe_virtual_scroll_sizes() {
+
+    let mut scroll_node = OverflowingScrollNode::default();
+    scroll_node.parent_rect = LogicalRect::new(LogicalPosition::new(10.0, 20.0), LogicalSize::new(100.0, 200.0));
+
+    // 500px of rendered rows, starting at row 1000px of a 10000px list
+    scroll_node.set_iframe_scroll_sizes(
+        LogicalSize::new(100.0, 500.0),
+        LogicalSize::new(100.0, 10000.0),
+        LogicalPosition::new(0.0, 1000.0),
+    );
+
+    assert_eq!(scroll_node.child_rect, LogicalRect::new(LogicalPosition::new(10.0, 1020.0), LogicalSize::new(100.0, 500.0)));
+    assert_eq!(scroll_node.virtual_child_rect, LogicalRect::new(LogicalPosition::new(10.0, 20.0), LogicalSize::new(100.0, 10000.0)));
+    assert_eq!(scroll_node.get_virtual_scroll_offset(), LogicalPosition::new(0.0, 1000.0));
+    assert_eq!(scroll_node.get_scroll_offset(LogicalPosition::new(0.0, 1100.0)), LogicalPosition::new(0.0, 100.0));
+
+    // visible area still inside of the rendered rows
+    assert!(!scroll_node.iframe_needs_rerender(LogicalPosition::new(0.0, 1100.0)));
+    // scrolled past the end / the start of the rendered rows
+    assert!(scroll_node.iframe_needs_rerender(LogicalPosition::new(0.0, 1400.0)));
+    assert!(scroll_node.iframe_needs_rerender(LogicalPosition::new(0.0, 900.0)));
+
+    // scroll positions are clamped to the virtual size, not to the rendered size
+    let mut scroll_states = ScrollStates::new();
+    scroll_states.set_scroll_position(&scroll_node, LogicalPosition::new(0.0, 5000.0));
+    assert_eq!(scroll_states.get_scroll_position(&scroll_node.parent_external_scroll_id), Some(LogicalPosition::new(0.0, 5000.0)));
+
+    // IFrames without virtual content never have to be re-invoked on scroll
+    scroll_node.set_iframe_scroll_sizes(LogicalSize::new(100.0, 50.0), LogicalSize::zero(), LogicalPosition::zero());
+    assert_eq!(scroll_node.virtual_child_rect.size, LogicalSize::new(100.0, 50.0));
+    assert!(!scroll_node.iframe_needs_rerender(LogicalPosition::zero()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_component_events_of_regenerated_doms() {
+
+    use std::sync::{Arc, Mutex};
+    use azul_css::{Css, CssPropertyValue};
+    use crate::dom::{Dom, IdOrClass, CallbackData, EventFilter, ComponentEventFilter};
+    use crate::callbacks::CallbackInfo;
+    use crate::ui_solver::{HorizontalSolvedPosition, VerticalSolvedPosition, GpuValueCache, ScrolledNodes};
+    use crate::id_tree::NodeDataContainer;
+
+    struct LogEntry {
+        event: &'static str,
+        class: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    extern "C" fn log_event(data: &mut RefAny, _: CallbackInfo) -> Update {
+        let entry = data.downcast_ref::<LogEntry>().unwrap();
+        entry.log.lock().unwrap().push(format!("{} {}", entry.event, entry.class));
+        Update::DoNothing
+    }
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let layout_results = |classes: &[&'static str]| -> Vec<LayoutResult> {
+
+        let callback = |filter, event, class| CallbackData {
+            event: EventFilter::Component(filter),
+            callback: Callback { cb: log_event },
+            data: RefAny::new(LogEntry { event, class, log: log.clone() }),
+        };
+
+        let styled_dom = Dom::body().with_children(classes.iter().map(|class| {
+            Dom::div()
+            .with_ids_and_classes(vec![IdOrClass::Class((*class).into())].into())
+            .with_callbacks(vec![
+                callback(ComponentEventFilter::AfterMount, "mount", *class),
+                callback(ComponentEventFilter::BeforeUnmount, "unmount", *class),
+            ].into())
+        }).collect::<Vec<_>>().into()).style(&mut Css::empty());
+
+        let n = styled_dom.node_data.len();
+
+        vec![LayoutResult {
+            dom_id: DomId::ROOT_ID,
+            parent_dom_id: None,
+            styled_dom,
+            root_size: LayoutSize::zero(),
+            root_position: LayoutPoint::zero(),
+            preferred_widths: NodeDataContainer::new(vec![None; n]),
+            preferred_heights: NodeDataContainer::new(vec![None; n]),
+            width_calculated_rects: NodeDataContainer::new(vec![Default::default(); n]),
+            height_calculated_rects: NodeDataContainer::new(vec![Default::default(); n]),
+            solved_pos_x: NodeDataContainer::new(vec![HorizontalSolvedPosition(0.0); n]),
+            solved_pos_y: NodeDataContainer::new(vec![VerticalSolvedPosition(0.0); n]),
+            layout_flex_grows: NodeDataContainer::new(vec![0.0; n]),
+            layout_displays: NodeDataContainer::new(vec![CssPropertyValue::Auto; n]),
+            layout_positions: NodeDataContainer::new(vec![Default::default(); n]),
+            layout_flex_directions: NodeDataContainer::new(vec![Default::default(); n]),
+            layout_justify_contents: NodeDataContainer::new(vec![Default::default(); n]),
+            layout_grids: BTreeMap::new(),
+            rects: NodeDataContainer::new(vec![Default::default(); n]),
+            words_cache: BTreeMap::new(),
+            shaped_words_cache: BTreeMap::new(),
+            positioned_words_cache: BTreeMap::new(),
+            scrollable_nodes: ScrolledNodes::default(),
+            iframe_mapping: BTreeMap::new(),
+            gpu_value_cache: GpuValueCache::default(),
+        }]
+    };
+
+    let first_dom = layout_results(&["a", "b"]);
+    let component_events = ComponentEvents::new_mounted(&first_dom);
+
+    let mut window = WindowInternal {
+        renderer_resources: RendererResources::default(),
+        renderer_type: None,
+        id_namespace: IdNamespace(0),
+        previous_window_state: None,
+        current_window_state: FullWindowState::default(),
+        document_id: DocumentId { namespace_id: IdNamespace(0), id: 0 },
+        epoch: Epoch(0),
+        layout_results: first_dom,
+        gl_texture_cache: GlTextureCache::empty(),
+        scroll_states: ScrollStates::default(),
+        timers: BTreeMap::new(),
+        threads: BTreeMap::new(),
+        component_events,
+        unmounted_layout_results: Vec::new(),
+    };
+
+    let run_component_callbacks = |window: &mut WindowInternal| {
+        window.run_component_callbacks(
+            &RawWindowHandle::Unsupported,
+            &OptionGlContextPtr::None,
+            &mut ImageCache::new(),
+            &mut FcFontCache::default(),
+            &ExternalSystemCallbacks::rust_internal(),
+        );
+        core::mem::replace(&mut *log.lock().unwrap(), Vec::new())
+    };
+
+    assert_eq!(run_component_callbacks(&mut window), vec!["mount a", "mount b"]);
+
+    // DOM is regenerated twice before the events are dispatched:
+    // "a" is removed from the first DOM, "b" from the second DOM,
+    // "c" is mounted in the second DOM and keeps its mount event
+    window.replace_layout_results(layout_results(&["b", "c"]), None);
+    window.replace_layout_results(layout_results(&["c", "d"]), None);
+
+    assert_eq!(window.unmounted_layout_results.len(), 2);
+    assert_eq!(run_component_callbacks(&mut window), vec!["unmount a", "unmount b", "mount c", "mount d"]);
+    assert!(window.unmounted_layout_results.is_empty());
+
+    // node that is mounted and removed again before the events
+    // are dispatched receives neither of the two events
+    window.replace_layout_results(layout_results(&["c", "d", "e"]), None);
+    window.replace_layout_results(layout_results(&["c", "f"]), None);
+
+    assert_eq!(run_component_callbacks(&mut window), vec!["unmount d", "mount f"]);
+}
+
+#[test]
+fn test_scroll_offset_into_view() {
+
+    // container at 100px with a size of 200px, currently scrolled by 50px (visible: 50 - 250)
+
+    // item already visible: the scroll offset doesn't change
+    assert_eq!(scroll_offset_into_view(50.0, 200.0, 50.0, 100.0, 200.0), 50.0);
+    // item above the visible area: aligned with the start of the container
+    assert_eq!(scroll_offset_into_view(50.0, 120.0, 20.0, 100.0, 200.0), 20.0);
+    // item below the visible area: aligned with the end of the container
+    assert_eq!(scroll_offset_into_view(50.0, 400.0, 30.0, 100.0, 200.0), 130.0);
+    // item larger than the container: its start is aligned with the start of the container
+    assert_eq!(scroll_offset_into_view(50.0, 400.0, 300.0, 100.0, 200.0), 300.0);
+}
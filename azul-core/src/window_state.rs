@@ -70,16 +70,16 @@ use alloc::collections::btree_set::BTreeSet;
 use crate::{
     FastHashMap, FastBTreeSet,
     app_resources::{RendererResources, ImageCache},
-    dom::{EventFilter, NotEventFilter, HoverEventFilter, FocusEventFilter, WindowEventFilter},
-    callbacks:: {ScrollPosition, DocumentId, DomNodeId, HitTestItem, Update},
+    dom::{EventFilter, NotEventFilter, HoverEventFilter, FocusEventFilter, WindowEventFilter, ComponentEventFilter},
+    callbacks:: {ScrollPosition, DocumentId, DomNodeId, HitTestItem, Update, CssTransition, CssAnimation},
     id_tree::NodeId,
     styled_dom::{DomId, ChangedCssProperty, AzNodeId},
     ui_solver::{LayoutResult, RelayoutChanges, GpuEventChanges},
-    task::ExternalSystemCallbacks,
+    task::{ExternalSystemCallbacks, TimerId, Timer, Duration as AzDuration, SystemTimeDiff},
     window::{FullHitTest, RawWindowHandle, FullWindowState, ScrollStates, CallCallbacksResult},
 };
 use azul_css::{
-    AzString, LayoutSize, CssProperty,
+    AzString, LayoutSize, CssProperty, CssPropertyType, CssCustomProperty,
     LayoutPoint, LayoutRect
 };
 use crate::gl::OptionGlContextPtr;
@@ -256,6 +256,60 @@ impl NodesToCheck {
         }
     }
 
+    /// If `Tab` was pressed, moves the focus to the next node in the tab order
+    /// (`Shift + Tab`: to the previous node), wrapping around at the end of the
+    /// tab order. Returns whether the focused node changed.
+    ///
+    /// Must be called before the callbacks are invoked, so that the
+    /// `FocusLost` / `FocusReceived` callbacks and the `:focus` restyle
+    /// happen in the same frame.
+    pub fn apply_tab_navigation(
+        &mut self,
+        current_window_state: &FullWindowState,
+        previous_window_state: &Option<FullWindowState>,
+        layout_results: &[LayoutResult],
+    ) -> bool {
+
+        use crate::callbacks::FocusTarget;
+        use crate::window::{VirtualKeyCode, OptionVirtualKeyCode};
+
+        let keyboard_state = &current_window_state.keyboard_state;
+        let tab = OptionVirtualKeyCode::Some(VirtualKeyCode::Tab);
+
+        // checks the keycode directly: `VirtualKeyDown` doesn't fire if Tab is pressed
+        // while `Shift` is held down, a held Tab (auto-repeat) keeps the keycode
+        let tab_pressed = keyboard_state.current_virtual_keycode == tab &&
+            previous_window_state.as_ref().map(|p| p.keyboard_state.current_virtual_keycode) != Some(tab);
+
+        if !tab_pressed {
+            return false;
+        }
+
+        // Ctrl + Tab, Alt + Tab, etc. are left to the application / the OS
+        if keyboard_state.ctrl_down || keyboard_state.alt_down || keyboard_state.super_down {
+            return false;
+        }
+
+        let (target, wrap_around) = if keyboard_state.shift_down {
+            (FocusTarget::Previous, FocusTarget::Last)
+        } else {
+            (FocusTarget::Next, FocusTarget::First)
+        };
+
+        let new_focus_node = match target.resolve(layout_results, self.new_focus_node) {
+            Ok(Some(s)) => Some(s),
+            Ok(None) => wrap_around.resolve(layout_results, None).ok().and_then(|s| s),
+            Err(_) => return false,
+        };
+
+        if new_focus_node.is_none() || new_focus_node == self.new_focus_node {
+            return false;
+        }
+
+        self.new_focus_node = new_focus_node;
+        true
+    }
+
     pub fn empty(mouse_down: bool, old_focus_node: Option<DomNodeId>) -> Self {
         Self {
             new_hit_node_ids: BTreeMap::new(),
@@ -277,6 +331,151 @@ impl NodesToCheck {
     }
 }
 
+/// Lifecycle events of DOM nodes: In difference to the `Events`, these are not
+/// caused by user input, but by (re)generating the DOM or by a relayout
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ComponentEvents {
+    /// Nodes that were added to the DOM (on the first layout: all nodes)
+    pub after_mount: BTreeMap<DomId, BTreeSet<NodeId>>,
+    /// Nodes of the previous DOM that are not present in the current DOM anymore
+    pub before_unmount: BTreeMap<DomId, BTreeSet<NodeId>>,
+    /// Nodes whose width or height changed during a relayout
+    pub node_resized: BTreeMap<DomId, BTreeSet<NodeId>>,
+}
+
+impl ComponentEvents {
+
+    /// Every node with an `AfterMount` callback is mounted (first layout of the window)
+    pub fn new_mounted(layout_results: &[LayoutResult]) -> Self {
+        let after_mount = layout_results.iter().enumerate().filter_map(|(dom_id, lr)| {
+            let nodes = lr.styled_dom.node_data.as_container()
+                .linear_iter()
+                .filter(|node_id| has_component_callback(lr, *node_id, ComponentEventFilter::AfterMount))
+                .collect::<BTreeSet<_>>();
+            if nodes.is_empty() { None } else { Some((DomId { inner: dom_id }, nodes)) }
+        }).collect();
+
+        Self {
+            after_mount,
+            .. Default::default()
+        }
+    }
+
+    /// Diffs the DOMs of the previous layout against the DOMs of the current layout
+    /// in order to determine which nodes were mounted / unmounted
+    ///
+    /// `pending_after_mount` are the `AfterMount` events of the previous DOMs that were not
+    /// dispatched yet: nodes that are still present in the current DOMs are mounted there,
+    /// nodes that were removed again receive neither an `AfterMount` nor a `BeforeUnmount` event
+    pub fn new_regenerated(
+        old_layout_results: &[LayoutResult],
+        new_layout_results: &[LayoutResult],
+        pending_after_mount: &BTreeMap<DomId, BTreeSet<NodeId>>,
+    ) -> Self {
+
+        use crate::diff::DomDiff;
+
+        let mut after_mount = BTreeMap::new();
+        let mut before_unmount = BTreeMap::new();
+
+        let no_pending_nodes = BTreeSet::new();
+
+        for dom_id in 0..old_layout_results.len().max(new_layout_results.len()) {
+
+            let pending_nodes = pending_after_mount.get(&DomId { inner: dom_id }).unwrap_or(&no_pending_nodes);
+
+            let (removed, added) = match (old_layout_results.get(dom_id), new_layout_results.get(dom_id)) {
+                // no component callbacks = nothing to diff
+                (Some(old), Some(new)) if !has_component_callbacks(old) && !has_component_callbacks(new) => continue,
+                (Some(old), Some(new)) => {
+                    let diff = DomDiff::new(&old.styled_dom, &new.styled_dom);
+                    let mut added = diff.added_nodes()
+                        .filter(|n| has_component_callback(new, *n, ComponentEventFilter::AfterMount))
+                        .collect::<BTreeSet<_>>();
+                    added.extend(pending_nodes.iter()
+                        .filter_map(|n| diff.matched_nodes.get(n).copied())
+                        .filter(|n| has_component_callback(new, *n, ComponentEventFilter::AfterMount)));
+                    (
+                        diff.removed_nodes()
+                        .filter(|n| !pending_nodes.contains(n))
+                        .filter(|n| has_component_callback(old, *n, ComponentEventFilter::BeforeUnmount))
+                        .collect::<BTreeSet<_>>(),
+                        added,
+                    )
+                },
+                // IFrame DOM does not exist anymore
+                (Some(old), None) => (
+                    old.styled_dom.node_data.as_container().linear_iter()
+                    .filter(|n| !pending_nodes.contains(n))
+                    .filter(|n| has_component_callback(old, *n, ComponentEventFilter::BeforeUnmount)).collect(),
+                    BTreeSet::new(),
+                ),
+                // IFrame DOM was newly created
+                (None, Some(new)) => (
+                    BTreeSet::new(),
+                    new.styled_dom.node_data.as_container().linear_iter()
+                    .filter(|n| has_component_callback(new, *n, ComponentEventFilter::AfterMount)).collect(),
+                ),
+                (None, None) => continue,
+            };
+
+            if !removed.is_empty() { before_unmount.insert(DomId { inner: dom_id }, removed); }
+            if !added.is_empty() { after_mount.insert(DomId { inner: dom_id }, added); }
+        }
+
+        Self {
+            after_mount,
+            before_unmount,
+            node_resized: BTreeMap::new(),
+        }
+    }
+
+    /// Inserts the `StyleAndLayoutChanges::nodes_that_changed_size`
+    pub fn insert_resized_nodes(&mut self, resized_nodes: &BTreeMap<DomId, Vec<NodeId>>) {
+        for (dom_id, nodes) in resized_nodes.iter() {
+            if nodes.is_empty() { continue; }
+            self.node_resized.entry(*dom_id).or_insert_with(|| BTreeSet::new()).extend(nodes.iter().copied());
+        }
+    }
+
+    /// Merges the events of `other` into `self`
+    pub fn append(&mut self, other: Self) {
+        for (dom_id, nodes) in other.after_mount {
+            self.after_mount.entry(dom_id).or_insert_with(|| BTreeSet::new()).extend(nodes);
+        }
+        for (dom_id, nodes) in other.before_unmount {
+            self.before_unmount.entry(dom_id).or_insert_with(|| BTreeSet::new()).extend(nodes);
+        }
+        for (dom_id, nodes) in other.node_resized {
+            self.node_resized.entry(dom_id).or_insert_with(|| BTreeSet::new()).extend(nodes);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.after_mount.is_empty() &&
+        self.before_unmount.is_empty() &&
+        self.node_resized.is_empty()
+    }
+}
+
+fn has_component_callback(layout_result: &LayoutResult, node_id: NodeId, filter: ComponentEventFilter) -> bool {
+    layout_result.styled_dom.node_data.as_container()[node_id]
+    .get_callbacks().iter()
+    .any(|cb| cb.event == EventFilter::Component(filter))
+}
+
+/// Whether any node of the DOM has an `AfterMount`, `BeforeUnmount` or `NodeResized` callback
+fn has_component_callbacks(layout_result: &LayoutResult) -> bool {
+    layout_result.styled_dom.node_data.as_ref().iter()
+    .flat_map(|node_data| node_data.get_callbacks().iter())
+    .any(|cb| match cb.event {
+        EventFilter::Component(ComponentEventFilter::AfterMount) |
+        EventFilter::Component(ComponentEventFilter::BeforeUnmount) |
+        EventFilter::Component(ComponentEventFilter::NodeResized) => true,
+        _ => false,
+    })
+}
+
 pub type RestyleNodes = BTreeMap<NodeId, Vec<ChangedCssProperty>>;
 pub type RelayoutNodes = BTreeMap<NodeId, Vec<ChangedCssProperty>>;
 pub type RelayoutWords = BTreeMap<NodeId, AzString>;
@@ -296,6 +495,13 @@ pub struct StyleAndLayoutChanges {
     pub nodes_that_changed_text_content: Option<BTreeMap<DomId, Vec<NodeId>>>,
     /// Changes to GPU-cached opacity / transform values
     pub gpu_key_changes: Option<BTreeMap<DomId, GpuEventChanges>>,
+    /// CSS transitions that were started by the changes, see `get_transition_timers`
+    pub transitions_started: Option<BTreeMap<TimerId, CssTransition>>,
+    /// Timers of the running transitions that were replaced by a new transition
+    /// (or of the CSS animations whose `animation-name` changed)
+    pub transitions_interrupted: Option<FastBTreeSet<TimerId>>,
+    /// CSS animations that were (re-)started because the `animation-name` of a node changed
+    pub animations_started: Option<BTreeMap<TimerId, (DomNodeId, CssAnimation)>>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -328,6 +534,8 @@ impl StyleAndLayoutChanges {
         window_size: LayoutSize,
         document_id: &DocumentId,
         css_changes: Option<&BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>>,
+        css_variable_changes: Option<&BTreeMap<DomId, BTreeMap<NodeId, Vec<CssCustomProperty>>>>,
+        css_transition_changes: Option<&BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>>,
         word_changes: Option<&BTreeMap<DomId, BTreeMap<NodeId, AzString>>>,
         callbacks_new_focus: &Option<Option<DomNodeId>>,
         relayout_cb: RelayoutFn,
@@ -415,11 +623,53 @@ impl StyleAndLayoutChanges {
                 }
             }
 
+            // `:focus-within`: the focused node and all of its parents
+            let focus_within_nodes = |layout_results: &[LayoutResult], focus: &Option<DomNodeId>| -> Option<(DomId, Vec<NodeId>)> {
+                let DomNodeId { dom, node } = focus.as_ref()?;
+                let node_hierarchy = layout_results[dom.inner].styled_dom.node_hierarchy.as_container();
+                let mut nodes = Vec::new();
+                let mut current = node.into_crate_internal();
+                while let Some(node_id) = current {
+                    nodes.push(node_id);
+                    current = node_hierarchy[node_id].parent_id();
+                }
+                Some((*dom, nodes))
+            };
+
+            let old_focus_within = focus_within_nodes(layout_results, &nodes.old_focus_node);
+            let new_focus_within = focus_within_nodes(layout_results, new_focus_node);
+
+            for (focus_within, other, new_state) in [
+                (&old_focus_within, &new_focus_within, false),
+                (&new_focus_within, &old_focus_within, true),
+            ].iter() {
+                if let Some((dom_id, node_ids)) = focus_within.as_ref() {
+                    let changed_nodes = node_ids.iter().filter(|node_id| match other.as_ref() {
+                        Some((other_dom_id, other_node_ids)) => other_dom_id != dom_id || !other_node_ids.contains(node_id),
+                        None => true,
+                    }).copied().collect::<Vec<_>>();
+                    let focus_within_restyle_props = layout_results[dom_id.inner].styled_dom.restyle_nodes_focus_within(&changed_nodes, *new_state);
+                    insert_props!(*dom_id, focus_within_restyle_props);
+                }
+            }
+
             Some(FocusChange { old: nodes.old_focus_node, new: *new_focus_node })
         } else {
             None
         };
 
+        // re-resolve the var() references of the nodes below the changed CSS variables
+        if let Some(css_variable_changes) = css_variable_changes {
+            for (dom_id, existing_changes_map) in css_variable_changes.iter() {
+                let layout_result = &mut layout_results[dom_id.inner];
+                let dom_id: DomId = *dom_id;
+                for (node_id, changed_css_variable_vec) in existing_changes_map.iter() {
+                    let current_prop_changes = layout_result.styled_dom.restyle_css_variables(node_id, &changed_css_variable_vec);
+                    insert_props!(dom_id, current_prop_changes);
+                }
+            }
+        }
+
         // restyle all the nodes according to the existing_changed_styles
         if let Some(css_changes) = css_changes {
             for (dom_id, existing_changes_map) in css_changes.iter() {
@@ -432,6 +682,122 @@ impl StyleAndLayoutChanges {
             }
         }
 
+        // start a transition for every changed property that has a `transition` -
+        // the node keeps displaying the old value until the first frame of the transition.
+        // `css_transition_changes` is None for the initial style of a new DOM, which doesn't transition
+        let mut transitions_started = None;
+        let mut transitions_interrupted = None;
+        let mut animations_started = None;
+
+        if css_transition_changes.is_some() {
+            for changes in [&style_changes, &layout_changes].iter() {
+                let changes = match changes.as_ref() {
+                    Some(s) => s,
+                    None => continue,
+                };
+                for (dom_id, restyle_nodes) in changes.iter() {
+                    let layout_result = &mut layout_results[dom_id.inner];
+                    for (node_id, changed_props) in restyle_nodes.iter() {
+                        for changed_prop in changed_props.iter() {
+
+                            let prop_type = changed_prop.current_prop.get_type();
+                            let (duration, timing_function, delay) = match layout_result.styled_dom.get_css_transition(node_id, prop_type) {
+                                Some(s) => s,
+                                None => continue,
+                            };
+
+                            // an interrupted transition continues from the currently displayed value
+                            let from = layout_result.styled_dom.get_css_property_cache().transition_props
+                                .get(node_id)
+                                .and_then(|p| p.get(&prop_type))
+                                .cloned()
+                                .unwrap_or_else(|| changed_prop.previous_prop.clone());
+
+                            if from == changed_prop.current_prop {
+                                continue;
+                            }
+
+                            let parent_id = layout_result.styled_dom.node_hierarchy.as_container()[*node_id].parent_id().unwrap_or(NodeId::ZERO);
+                            let current_size = layout_result.rects.as_ref().get(*node_id).map(|r| r.size).unwrap_or_default();
+                            let parent_size = layout_result.rects.as_ref().get(parent_id).map(|r| r.size).unwrap_or_default();
+
+                            let timer_id = TimerId::unique();
+                            if let Some(interrupted) = layout_result.styled_dom.start_css_transition(node_id, from.clone(), timer_id) {
+                                transitions_interrupted
+                                .get_or_insert_with(|| FastBTreeSet::new())
+                                .insert(interrupted);
+                            }
+
+                            transitions_started
+                            .get_or_insert_with(|| BTreeMap::new())
+                            .insert(timer_id, CssTransition {
+                                node: DomNodeId { dom: *dom_id, node: AzNodeId::from_crate_internal(Some(*node_id)) },
+                                from,
+                                to: changed_prop.current_prop.clone(),
+                                duration: AzDuration::System(SystemTimeDiff::from_millis(duration.ms as u64)),
+                                delay: AzDuration::System(SystemTimeDiff::from_millis(delay.ms as u64)),
+                                easing: timing_function.to_interpolation_function(),
+                                parent_rect_width: parent_size.width,
+                                parent_rect_height: parent_size.height,
+                                current_rect_width: current_size.width,
+                                current_rect_height: current_size.height,
+                            });
+                        }
+                    }
+                }
+            }
+
+            // restart the CSS animations of nodes whose `animation-name` changed
+            // (i.e. a `:hover` / `:focus` style that declares an animation)
+            let animation_name_changed = style_changes.as_ref().map(|style_changes| {
+                style_changes.iter().flat_map(|(dom_id, restyle_nodes)| {
+                    restyle_nodes.iter()
+                    .filter(|(_, changed_props)| {
+                        changed_props.iter().any(|p| p.current_prop.get_type() == CssPropertyType::AnimationName)
+                    })
+                    .map(move |(node_id, _)| (*dom_id, *node_id))
+                }).collect::<Vec<_>>()
+            }).unwrap_or_default();
+
+            for (dom_id, node_id) in animation_name_changed {
+
+                let layout_result = &mut layout_results[dom_id.inner];
+                let stopped_animations = layout_result.styled_dom.stop_css_animations(&node_id);
+
+                // reset the animated properties of the stopped animations
+                let mut reset_frames = Vec::new();
+                for (timer_id, animation) in stopped_animations.iter() {
+                    transitions_interrupted
+                    .get_or_insert_with(|| FastBTreeSet::new())
+                    .insert(*timer_id);
+                    reset_frames.extend(animation.get_property_types().into_iter().map(|prop_type| CssProperty::initial(prop_type)));
+                }
+                let current_prop_changes = layout_result.styled_dom.restyle_css_transitions(&node_id, &reset_frames);
+                insert_props!(dom_id, current_prop_changes);
+
+                let layout_result = &mut layout_results[dom_id.inner];
+                for animation in layout_result.styled_dom.get_css_animations(&node_id) {
+                    let timer_id = TimerId::unique();
+                    layout_result.styled_dom.register_css_animation(&node_id, timer_id, animation.clone());
+                    animations_started
+                    .get_or_insert_with(|| BTreeMap::new())
+                    .insert(timer_id, (DomNodeId { dom: dom_id, node: AzNodeId::from_crate_internal(Some(node_id)) }, animation));
+                }
+            }
+        }
+
+        // apply the next frame of the already running transitions
+        if let Some(css_transition_changes) = css_transition_changes {
+            for (dom_id, existing_changes_map) in css_transition_changes.iter() {
+                let layout_result = &mut layout_results[dom_id.inner];
+                let dom_id: DomId = *dom_id;
+                for (node_id, frames) in existing_changes_map.iter() {
+                    let current_prop_changes = layout_result.styled_dom.restyle_css_transitions(node_id, &frames);
+                    insert_props!(dom_id, current_prop_changes);
+                }
+            }
+        }
+
         let mut nodes_that_changed_size = None;
         let mut gpu_key_change_events = None;
 
@@ -510,6 +876,9 @@ impl StyleAndLayoutChanges {
             nodes_that_changed_text_content,
             focus_change,
             gpu_key_changes: gpu_key_change_events,
+            transitions_started,
+            transitions_interrupted,
+            animations_started,
         }
     }
 
@@ -564,7 +933,32 @@ impl StyleAndLayoutChanges {
         self.focus_change.is_none() &&
         self.nodes_that_changed_size.is_none() &&
         self.nodes_that_changed_text_content.is_none() &&
-        self.gpu_key_changes.is_none()
+        self.gpu_key_changes.is_none() &&
+        self.transitions_started.is_none() &&
+        self.transitions_interrupted.is_none() &&
+        self.animations_started.is_none()
+    }
+
+    /// Returns the timers that have to be started (and stopped)
+    /// in order to drive the CSS transitions and animations
+    pub fn get_transition_timers(&self, system_callbacks: &ExternalSystemCallbacks)
+    -> (FastHashMap<TimerId, Timer>, FastBTreeSet<TimerId>) {
+
+        let mut timers_to_add: FastHashMap<TimerId, Timer> = self.transitions_started.as_ref().map(|t| {
+            t.iter().map(|(timer_id, transition)| {
+                (*timer_id, transition.clone().into_timer(system_callbacks.get_system_time_fn))
+            }).collect()
+        }).unwrap_or_default();
+
+        if let Some(animations_started) = self.animations_started.as_ref() {
+            timers_to_add.extend(animations_started.iter().map(|(timer_id, (node, animation))| {
+                (*timer_id, animation.clone().into_timer(*node, system_callbacks.get_system_time_fn))
+            }));
+        }
+
+        let timers_to_remove = self.transitions_interrupted.clone().unwrap_or_default();
+
+        (timers_to_add, timers_to_remove)
     }
 
     pub fn need_redraw(&self) -> bool {
@@ -794,6 +1188,50 @@ impl CallbacksOfHitTest {
         }
     }
 
+    /// Collects the `On::Component(filter)` callbacks of the given nodes
+    ///
+    /// Nodes that do not have a callback for the given filter are ignored,
+    /// so that the `ComponentEvents` can be passed in unfiltered
+    pub fn new_component_events(
+        nodes: &BTreeMap<DomId, BTreeSet<NodeId>>,
+        filter: ComponentEventFilter,
+        layout_results: &[LayoutResult],
+    ) -> Self {
+
+        let event_filter = EventFilter::Component(filter);
+
+        let nodes_with_callbacks = nodes.iter().filter_map(|(dom_id, node_ids)| {
+            let layout_result = layout_results.get(dom_id.inner)?;
+            let node_data = layout_result.styled_dom.node_data.as_container();
+            let callbacks = node_ids.iter()
+            .filter(|node_id| node_id.index() < node_data.len())
+            .filter(|node_id| node_data[**node_id].get_callbacks().iter().any(|cb| cb.event == event_filter))
+            .map(|node_id| CallbackToCall {
+                node_id: *node_id,
+                hit_test_item: None,
+                event_filter: event_filter.clone(),
+            })
+            .collect::<Vec<_>>();
+            if callbacks.is_empty() { None } else { Some((*dom_id, callbacks)) }
+        }).collect();
+
+        Self { nodes_with_callbacks }
+    }
+
+    /// Appends the callbacks of `other`: if a node is present in both,
+    /// only the event filter of `self` is kept (see `call()`)
+    pub fn append(&mut self, other: Self) {
+        for (dom_id, mut callbacks) in other.nodes_with_callbacks {
+            let existing = self.nodes_with_callbacks.entry(dom_id).or_insert_with(|| Vec::new());
+            callbacks.retain(|cb| !existing.iter().any(|e| e.node_id == cb.node_id));
+            existing.append(&mut callbacks);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes_with_callbacks.is_empty()
+    }
+
     /// The actual function that calls the callbacks in their proper hierarchy and order
     pub fn call(
         &mut self,
@@ -819,6 +1257,8 @@ impl CallbacksOfHitTest {
             callbacks_update_screen: Update::DoNothing,
             modified_window_state: None,
             css_properties_changed: None,
+            css_variables_changed: None,
+            css_transitions_changed: None,
             words_changed: None,
             images_changed: None,
             image_masks_changed: None,
@@ -850,6 +1290,8 @@ impl CallbacksOfHitTest {
         let mut ret_images_changed = BTreeMap::new();
         let mut ret_image_masks_changed = BTreeMap::new();
         let mut ret_css_properties_changed = BTreeMap::new();
+        let mut ret_css_variables_changed = BTreeMap::new();
+        let mut ret_css_transitions_changed = BTreeMap::new();
         let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
 
         {
@@ -941,6 +1383,8 @@ impl CallbacksOfHitTest {
                             /*images_changed_in_callbacks:*/ &mut ret_images_changed,
                             /*image_masks_changed_in_callbacks:*/ &mut ret_image_masks_changed,
                             /*css_properties_changed_in_callbacks:*/ &mut ret_css_properties_changed,
+                            /*css_variables_changed_in_callbacks:*/ &mut ret_css_variables_changed,
+                            /*css_transitions_changed_in_callbacks:*/ &mut ret_css_transitions_changed,
                             /*current_scroll_states:*/ scroll_states,
                             /*nodes_scrolled_in_callback:*/ &mut ret_nodes_scrolled_in_callbacks,
                             /*hit_dom_node:*/ DomNodeId { dom: *dom_id, node: AzNodeId::from_crate_internal(Some(child_id)) },
@@ -1011,6 +1455,8 @@ impl CallbacksOfHitTest {
                         /*images_changed_in_callbacks:*/ &mut ret_images_changed,
                         /*image_masks_changed_in_callbacks:*/ &mut ret_image_masks_changed,
                         /*css_properties_changed_in_callbacks:*/ &mut ret_css_properties_changed,
+                        /*css_variables_changed_in_callbacks:*/ &mut ret_css_variables_changed,
+                        /*css_transitions_changed_in_callbacks:*/ &mut ret_css_transitions_changed,
                         /*current_scroll_states:*/ scroll_states,
                         /*nodes_scrolled_in_callback:*/ &mut ret_nodes_scrolled_in_callbacks,
                         /*hit_dom_node:*/ DomNodeId { dom: *dom_id, node: AzNodeId::from_crate_internal(Some(*root_id)) },
@@ -1082,6 +1528,8 @@ impl CallbacksOfHitTest {
         if !ret_images_changed.is_empty() { ret.images_changed = Some(ret_images_changed); }
         if !ret_image_masks_changed.is_empty() { ret.image_masks_changed = Some(ret_image_masks_changed); }
         if !ret_css_properties_changed.is_empty() { ret.css_properties_changed = Some(ret_css_properties_changed); }
+        if !ret_css_variables_changed.is_empty() { ret.css_variables_changed = Some(ret_css_variables_changed); }
+        if !ret_css_transitions_changed.is_empty() { ret.css_transitions_changed = Some(ret_css_transitions_changed); }
         if !ret_nodes_scrolled_in_callbacks.is_empty() { ret.nodes_scrolled_in_callbacks = Some(ret_nodes_scrolled_in_callbacks); }
 
         ret
@@ -1254,4 +1702,123 @@ fn get_hover_events(input: &[WindowEventFilter]) -> Vec<HoverEventFilter> {
 
 fn get_focus_events(input: &[HoverEventFilter]) -> Vec<FocusEventFilter> {
     input.iter().filter_map(|hover_event| hover_event.to_focus_event_filter()).collect()
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_virtual_key_down_events() {
+
+    use crate::window::VirtualKeyCode::{self, *};
+
+    // the shells set the `current_virtual_keycode` to the last pressed key
+    // and keep it while the key is held down (also during auto-repeat)
+    let fires_key_down = |previous: Option<VirtualKeyCode>, current: Option<VirtualKeyCode>, shift_down: bool| -> bool {
+        let mut previous_window_state = FullWindowState::default();
+        previous_window_state.keyboard_state.current_virtual_keycode = previous.into();
+        previous_window_state.keyboard_state.shift_down = shift_down;
+        let mut current_window_state = FullWindowState::default();
+        current_window_state.keyboard_state.current_virtual_keycode = current.into();
+        current_window_state.keyboard_state.shift_down = shift_down;
+        Events::new(&current_window_state, &Some(previous_window_state)).window_events.contains(&WindowEventFilter::VirtualKeyDown)
+    };
+
+    assert!(fires_key_down(None, Some(A), false));
+
+    // auto-repeat of a held key does not fire the event again
+    assert!(!fires_key_down(Some(A), Some(A), false));
+    assert!(!fires_key_down(Some(Tab), Some(Tab), true));
+
+    // only fires if no other key was held down before
+    assert!(fires_key_down(None, Some(LShift), true));
+    assert!(!fires_key_down(Some(LShift), Some(Tab), true));
+    assert!(!fires_key_down(Some(A), Some(B), false));
+
+    // releasing a key does not fire the event
+    assert!(!fires_key_down(Some(A), None, false));
+}
+
+#[test]
+fn test_apply_tab_navigation() {
+
+    use azul_css::{Css, CssPropertyValue, LayoutPoint};
+    use crate::dom::{Dom, TabIndex};
+    use crate::id_tree::NodeDataContainer;
+    use crate::ui_solver::{HorizontalSolvedPosition, VerticalSolvedPosition, GpuValueCache, ScrolledNodes};
+    use crate::window::{VirtualKeyCode, OptionVirtualKeyCode};
+
+    // body = 0, focusable divs = 1, 2, 3
+    let styled_dom = Dom::body().with_children(vec![
+        Dom::div().with_tab_index(TabIndex::Auto),
+        Dom::div().with_tab_index(TabIndex::Auto),
+        Dom::div().with_tab_index(TabIndex::Auto),
+    ].into()).style(&mut Css::empty());
+
+    let n = styled_dom.node_data.len();
+
+    let layout_results = vec![LayoutResult {
+        dom_id: DomId::ROOT_ID,
+        parent_dom_id: None,
+        styled_dom,
+        root_size: LayoutSize::zero(),
+        root_position: LayoutPoint::zero(),
+        preferred_widths: NodeDataContainer::new(vec![None; n]),
+        preferred_heights: NodeDataContainer::new(vec![None; n]),
+        width_calculated_rects: NodeDataContainer::new(vec![Default::default(); n]),
+        height_calculated_rects: NodeDataContainer::new(vec![Default::default(); n]),
+        solved_pos_x: NodeDataContainer::new(vec![HorizontalSolvedPosition(0.0); n]),
+        solved_pos_y: NodeDataContainer::new(vec![VerticalSolvedPosition(0.0); n]),
+        layout_flex_grows: NodeDataContainer::new(vec![0.0; n]),
+        layout_displays: NodeDataContainer::new(vec![CssPropertyValue::Auto; n]),
+        layout_positions: NodeDataContainer::new(vec![Default::default(); n]),
+        layout_flex_directions: NodeDataContainer::new(vec![Default::default(); n]),
+        layout_justify_contents: NodeDataContainer::new(vec![Default::default(); n]),
+        layout_grids: BTreeMap::new(),
+        rects: NodeDataContainer::new(vec![Default::default(); n]),
+        words_cache: BTreeMap::new(),
+        shaped_words_cache: BTreeMap::new(),
+        positioned_words_cache: BTreeMap::new(),
+        scrollable_nodes: ScrolledNodes::default(),
+        iframe_mapping: BTreeMap::new(),
+        gpu_value_cache: GpuValueCache::default(),
+    }];
+
+    let node = |i: usize| Some(DomNodeId { dom: DomId::ROOT_ID, node: AzNodeId::from_crate_internal(Some(NodeId::new(i))) });
+
+    // presses Tab (+ modifiers) after `previous_key` while `focus` is focused, returns the new focused node
+    let press_tab_after = |previous_key: OptionVirtualKeyCode, focus: Option<DomNodeId>, shift_down: bool, ctrl_down: bool| -> Option<DomNodeId> {
+        let mut previous_window_state = FullWindowState::default();
+        previous_window_state.keyboard_state.current_virtual_keycode = previous_key;
+        let mut current_window_state = FullWindowState::default();
+        current_window_state.keyboard_state.current_virtual_keycode = OptionVirtualKeyCode::Some(VirtualKeyCode::Tab);
+        current_window_state.keyboard_state.shift_down = shift_down;
+        current_window_state.keyboard_state.ctrl_down = ctrl_down;
+        let mut nodes_to_check = NodesToCheck::empty(false, focus);
+        let focus_changed = nodes_to_check.apply_tab_navigation(&current_window_state, &Some(previous_window_state), &layout_results);
+        assert_eq!(focus_changed, nodes_to_check.needs_focus_result());
+        nodes_to_check.new_focus_node
+    };
+    let press_tab = |focus: Option<DomNodeId>, shift_down: bool, ctrl_down: bool| -> Option<DomNodeId> {
+        press_tab_after(OptionVirtualKeyCode::None, focus, shift_down, ctrl_down)
+    };
+
+    // Tab: first focusable node, then the next node
+    assert_eq!(press_tab(None, false, false), node(1));
+    assert_eq!(press_tab(node(1), false, false), node(2));
+
+    // wraps around at the end of the tab order
+    assert_eq!(press_tab(node(3), false, false), node(1));
+
+    // Shift + Tab: previous node, wrapping around at the start
+    assert_eq!(press_tab(node(2), true, false), node(1));
+    assert_eq!(press_tab(node(1), true, false), node(3));
+    assert_eq!(press_tab(None, true, false), node(3));
+
+    // Tab pressed while Shift is held down
+    assert_eq!(press_tab_after(OptionVirtualKeyCode::Some(VirtualKeyCode::LShift), node(2), true, false), node(1));
+
+    // a held Tab (auto-repeat) doesn't move the focus again
+    assert_eq!(press_tab_after(OptionVirtualKeyCode::Some(VirtualKeyCode::Tab), node(1), false, false), node(1));
+
+    // Ctrl + Tab is left to the application
+    assert_eq!(press_tab(node(1), false, true), node(1));
+    assert_eq!(press_tab(node(1), true, true), node(1));
+}
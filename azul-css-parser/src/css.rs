@@ -12,10 +12,13 @@ use azul_simplecss::Tokenizer;
 use crate::css_parser;
 pub use crate::css_parser::CssParsingError;
 use azul_css::{
-    Css, CssDeclaration, Stylesheet, DynamicCssProperty,
+    Css, CssDeclaration, Stylesheet, DynamicCssProperty, CssCustomProperty,
     CssPropertyType, CssRuleBlock, CssPath, CssPathSelector,
     CssNthChildSelector, CssPathPseudoSelector, CssNthChildSelector::*,
+    CssAttributeSelector, CssAttributeSelectorOp,
     NodeTypeTag, NodeTypeTagParseError, CombinedCssPropertyType, CssKeyMap,
+    CssFontFace, CssFontFaceStyle, CssMediaQuery, CssMediaCondition,
+    CssMediaOrientation, CssMediaColorScheme, FloatValue, CssKeyframes, CssKeyframe,
 };
 
 /// Error that can happen during the parsing of a CSS value
@@ -48,6 +51,8 @@ pub enum CssParseErrorInner<'a> {
     DynamicCssParseError(DynamicCssParseError<'a>),
     /// Error while parsing a pseudo selector (like `:aldkfja`)
     PseudoSelectorParseError(CssPseudoSelectorParseError<'a>),
+    /// Error while parsing an attribute selector (like `[data-state=open]`)
+    AttributeSelectorParseError(CssAttributeSelectorParseError<'a>),
     /// The path has to be either `*`, `div`, `p` or something like that
     NodeTypeTag(NodeTypeTagParseError<'a>),
     /// A certain property has an unknown key, for example: `alsdfkj: 500px` = `unknown CSS key "alsdfkj: 500px"`
@@ -57,6 +62,12 @@ pub enum CssParseErrorInner<'a> {
     /// when setting the variable, whether all sides should be set, instead, you have to use `margin-top: var(--blah)`,
     /// `margin-bottom: var(--baz)` in order to work around this limitation.
     VarOnShorthandProperty { key: CombinedCssPropertyType, value: &'a str },
+    /// Error while parsing an `@font-face` rule
+    FontFace(CssFontFaceParseError<'a>),
+    /// Error while parsing the query of an `@media` rule
+    MediaQuery(CssMediaQueryParseError<'a>),
+    /// Error while parsing a `@keyframes` rule
+    Keyframes(CssKeyframesParseError<'a>),
 }
 
 impl_display!{ CssParseErrorInner<'a>, {
@@ -65,12 +76,16 @@ impl_display!{ CssParseErrorInner<'a>, {
     MalformedCss => "Malformed Css",
     DynamicCssParseError(e) => format!("{}", e),
     PseudoSelectorParseError(e) => format!("Failed to parse pseudo-selector: {}", e),
+    AttributeSelectorParseError(e) => format!("Failed to parse attribute selector: {}", e),
     NodeTypeTag(e) => format!("Failed to parse CSS selector path: {}", e),
     UnknownPropertyKey(k, v) => format!("Unknown CSS key: \"{}: {}\"", k, v),
     VarOnShorthandProperty { key, value } => format!(
         "Error while parsing: \"{}: {};\": var() cannot be used on shorthand properties - use `{}-top` or `{}-x` as the key instead: ",
         key, value, key, key
     ),
+    FontFace(e) => format!("Failed to parse @font-face: {}", e),
+    MediaQuery(e) => format!("Failed to parse @media: {}", e),
+    Keyframes(e) => format!("Failed to parse @keyframes: {}", e),
 }}
 
 impl<'a> From<CssSyntaxError> for CssParseErrorInner<'a> {
@@ -82,6 +97,10 @@ impl<'a> From<CssSyntaxError> for CssParseErrorInner<'a> {
 impl_from! { DynamicCssParseError<'a>, CssParseErrorInner::DynamicCssParseError }
 impl_from! { NodeTypeTagParseError<'a>, CssParseErrorInner::NodeTypeTag }
 impl_from! { CssPseudoSelectorParseError<'a>, CssParseErrorInner::PseudoSelectorParseError }
+impl_from! { CssAttributeSelectorParseError<'a>, CssParseErrorInner::AttributeSelectorParseError }
+impl_from! { CssFontFaceParseError<'a>, CssParseErrorInner::FontFace }
+impl_from! { CssMediaQueryParseError<'a>, CssParseErrorInner::MediaQuery }
+impl_from! { CssKeyframesParseError<'a>, CssParseErrorInner::Keyframes }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CssPseudoSelectorParseError<'a> {
@@ -89,6 +108,8 @@ pub enum CssPseudoSelectorParseError<'a> {
     UnknownSelector(&'a str, Option<&'a str>),
     InvalidNthChildPattern(&'a str),
     InvalidNthChild(ParseIntError),
+    /// `:not()` has to contain selectors without combinators, i.e. `:not(.a.b)`
+    InvalidNotSelector(&'a str),
 }
 
 impl<'a> From<ParseIntError> for CssPseudoSelectorParseError<'a> {
@@ -112,6 +133,90 @@ impl_display! { CssPseudoSelectorParseError<'a>, {
         number, \"even\" or \"odd\" or a pattern such as \"2n+3\"", selector
     ),
     InvalidNthChild(e) => format!("Invalid :nth-child pseudo-selector: ':{}'", e),
+    InvalidNotSelector(e) => format!("Invalid pseudo-selector :not({}) - expected selectors without combinators, such as \".a.b\"", e),
+}}
+
+/// Error that can happen while parsing an attribute selector such as `[data-state=open]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CssAttributeSelectorParseError<'a> {
+    /// Attribute name is empty or contains whitespace, i.e. `[=open]`
+    InvalidName(&'a str),
+    /// Operator is not `=`, `~=`, `|=`, `^=`, `$=` or `*=`
+    InvalidOperator(&'a str),
+}
+
+impl_display! { CssAttributeSelectorParseError<'a>, {
+    InvalidName(e) => format!("Invalid attribute name in \"[{}]\"", e),
+    InvalidOperator(e) => format!("Invalid operator in \"[{}]\": expected \"=\", \"~=\", \"|=\", \"^=\", \"$=\" or \"*=\"", e),
+}}
+
+/// Error that can happen while parsing the declarations of an `@font-face` rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CssFontFaceParseError<'a> {
+    /// `@font-face` without a `font-family` declaration
+    MissingFontFamily,
+    /// `@font-face` without a `src` declaration
+    MissingSrc,
+    /// `src` doesn't contain any `url()`, i.e. `src: local(Arial)`
+    InvalidSrc(&'a str),
+    /// `font-weight` is not `normal`, `bold` or a number between 1 and 1000
+    InvalidFontWeight(&'a str),
+    /// `font-style` is not `normal`, `italic` or `oblique`
+    InvalidFontStyle(&'a str),
+    /// `@font-face` has to be followed by a block: `@font-face { ... }`
+    MissingBlock,
+}
+
+impl_display! { CssFontFaceParseError<'a>, {
+    MissingFontFamily => format!("Missing \"font-family\" declaration"),
+    MissingSrc => format!("Missing \"src\" declaration"),
+    InvalidSrc(e) => format!("Invalid \"src: {}\": expected url(\"path/to/font.ttf\")", e),
+    InvalidFontWeight(e) => format!("Invalid \"font-weight: {}\": expected \"normal\", \"bold\" or a number between 1 and 1000", e),
+    InvalidFontStyle(e) => format!("Invalid \"font-style: {}\": expected \"normal\", \"italic\" or \"oblique\"", e),
+    MissingBlock => format!("Expected a block after @font-face"),
+}}
+
+/// Error that can happen while parsing the query of an `@media` rule
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssMediaQueryParseError<'a> {
+    /// Media type is not `all`, `screen`, `print` or `speech`
+    UnknownMediaType(&'a str),
+    /// Media feature is not supported, i.e. `(hover: hover)`
+    UnsupportedFeature(&'a str),
+    /// Value of the media feature could not be parsed, i.e. `(max-width: wide)`
+    InvalidFeatureValue { feature: &'a str, value: &'a str },
+    /// Query is empty or doesn't consist of `(feature: value)` pairs joined with `and`
+    MalformedQuery(&'a str),
+    /// `@media` has to be followed by a block: `@media (...) { ... }`
+    MissingBlock,
+}
+
+impl_display! { CssMediaQueryParseError<'a>, {
+    UnknownMediaType(e) => format!("Unknown media type \"{}\": expected \"all\", \"screen\", \"print\" or \"speech\"", e),
+    UnsupportedFeature(e) => format!("Unsupported media feature \"{}\"", e),
+    InvalidFeatureValue { feature, value } => format!("Invalid value for media feature \"({}: {})\"", feature, value),
+    MalformedQuery(e) => format!("Malformed media query \"{}\"", e),
+    MissingBlock => format!("Expected a block after @media"),
+}}
+
+/// Error that can happen while parsing a `@keyframes` rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CssKeyframesParseError<'a> {
+    /// `@keyframes` without a name, i.e. `@keyframes { ... }`
+    MissingName,
+    /// Keyframe selector is not `from`, `to` or a percentage between 0% and 100%
+    InvalidSelector(&'a str),
+    /// Keyframe selector is not followed by a (closed) block: `50% { ... }`
+    UnclosedKeyframe(&'a str),
+    /// `@keyframes` has to be followed by a block: `@keyframes name { ... }`
+    MissingBlock,
+}
+
+impl_display! { CssKeyframesParseError<'a>, {
+    MissingName => format!("Missing animation name"),
+    InvalidSelector(e) => format!("Invalid keyframe selector \"{}\": expected \"from\", \"to\" or a percentage", e),
+    UnclosedKeyframe(e) => format!("Expected a block after keyframe selector \"{}\"", e),
+    MissingBlock => format!("Expected a block after @keyframes"),
 }}
 
 /// Error that can happen during `css_parser::parse_key_value_pair`
@@ -145,17 +250,83 @@ fn pseudo_selector_from_str<'a>(selector: &'a str, value: Option<&'a str>)
         "hover" => Ok(CssPathPseudoSelector::Hover),
         "active" => Ok(CssPathPseudoSelector::Active),
         "focus" => Ok(CssPathPseudoSelector::Focus),
+        "first-child" => Ok(CssPathPseudoSelector::FirstChild),
+        "last-child" => Ok(CssPathPseudoSelector::LastChild),
+        "only-child" => Ok(CssPathPseudoSelector::OnlyChild),
+        "empty" => Ok(CssPathPseudoSelector::Empty),
+        "focus-within" => Ok(CssPathPseudoSelector::FocusWithin),
         "nth-child" => {
             let value = value.ok_or(CssPseudoSelectorParseError::EmptyNthChild)?;
             let parsed = parse_nth_child_selector(value)?;
             Ok(CssPathPseudoSelector::NthChild(parsed))
         },
+        "nth-last-child" => {
+            let value = value.ok_or(CssPseudoSelectorParseError::EmptyNthChild)?;
+            let parsed = parse_nth_child_selector(value)?;
+            Ok(CssPathPseudoSelector::NthLastChild(parsed))
+        },
         _ => {
             Err(CssPseudoSelectorParseError::UnknownSelector(selector, value))
         },
     }
 }
 
+/// Same as `pseudo_selector_from_str`, but also parses `:not(...)`,
+/// which is a selector on its own instead of a `CssPathPseudoSelector`
+fn pseudo_class_from_str<'a>(selector: &'a str, value: Option<&'a str>)
+-> Result<CssPathSelector, CssPseudoSelectorParseError<'a>>
+{
+    if selector != "not" {
+        return pseudo_selector_from_str(selector, value).map(CssPathSelector::PseudoSelector);
+    }
+
+    let value = value.unwrap_or("");
+    let path = parse_css_path(value).map_err(|_| CssPseudoSelectorParseError::InvalidNotSelector(value))?;
+    if path.selectors.iter().any(|s| s.is_combinator()) {
+        return Err(CssPseudoSelectorParseError::InvalidNotSelector(value));
+    }
+
+    Ok(CssPathSelector::Not(path.selectors))
+}
+
+/// Parses the contents of an attribute selector (without the brackets),
+/// i.e. `data-state="open"` => `CssAttributeSelector { name: "data-state", op: Equals, value: "open" }`
+fn parse_attribute_selector<'a>(input: &'a str) -> Result<CssAttributeSelector, CssAttributeSelectorParseError<'a>> {
+
+    use self::CssAttributeSelectorOp::*;
+
+    let (name, op, value) = match input.find('=') {
+        None => (input, Exists, ""),
+        Some(pos) => {
+            let (name, op) = match input[..pos].chars().last() {
+                Some('~') => (&input[..pos - 1], Includes),
+                Some('|') => (&input[..pos - 1], DashMatch),
+                Some('^') => (&input[..pos - 1], Prefix),
+                Some('$') => (&input[..pos - 1], Suffix),
+                Some('*') => (&input[..pos - 1], Substring),
+                Some(c) if !c.is_alphanumeric() && c != '-' && c != '_' && !c.is_whitespace() => {
+                    return Err(CssAttributeSelectorParseError::InvalidOperator(input));
+                },
+                _ => (&input[..pos], Equals),
+            };
+            (name, op, input[pos + 1..].trim())
+        },
+    };
+
+    let name = name.trim();
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(CssAttributeSelectorParseError::InvalidName(input));
+    }
+
+    let value = css_parser::strip_quotes(value).map(|q| q.0).unwrap_or(value);
+
+    Ok(CssAttributeSelector {
+        name: name.to_string().into(),
+        op,
+        value: value.to_string().into(),
+    })
+}
+
 /// Parses the inner value of the `:nth-child` selector, including numbers and patterns.
 ///
 /// I.e.: `"2n+3"` -> `Pattern { repeat: 2, offset: 3 }`
@@ -235,6 +406,12 @@ fn test_css_pseudo_selector_parse() {
         (("nth-child", Some("odd")), NthChild(Odd)),
         (("nth-child", Some("5n")), NthChild(Pattern(CssNthChildPattern { repeat: 5, offset: 0 }))),
         (("nth-child", Some("2n+3")), NthChild(Pattern(CssNthChildPattern { repeat: 2, offset: 3 }))),
+        (("first-child", None), FirstChild),
+        (("last-child", None), LastChild),
+        (("only-child", None), OnlyChild),
+        (("empty", None), Empty),
+        (("focus-within", None), FocusWithin),
+        (("nth-last-child", Some("2")), NthLastChild(Number(2))),
     ];
 
     let err = [
@@ -254,6 +431,58 @@ fn test_css_pseudo_selector_parse() {
     }
 }
 
+#[test]
+fn test_css_attribute_selector_parse() {
+
+    use self::CssAttributeSelectorOp::*;
+
+    let attribute = |name: &str, op, value: &str| CssAttributeSelector {
+        name: name.to_string().into(),
+        op,
+        value: value.to_string().into(),
+    };
+
+    assert_eq!(parse_attribute_selector("data-state"), Ok(attribute("data-state", Exists, "")));
+    assert_eq!(parse_attribute_selector("data-state=open"), Ok(attribute("data-state", Equals, "open")));
+    assert_eq!(parse_attribute_selector("data-state = \"open\""), Ok(attribute("data-state", Equals, "open")));
+    assert_eq!(parse_attribute_selector("data-tags~=a"), Ok(attribute("data-tags", Includes, "a")));
+    assert_eq!(parse_attribute_selector("lang|=en"), Ok(attribute("lang", DashMatch, "en")));
+    assert_eq!(parse_attribute_selector("data-x^='a'"), Ok(attribute("data-x", Prefix, "a")));
+    assert_eq!(parse_attribute_selector("data-x$=a"), Ok(attribute("data-x", Suffix, "a")));
+    assert_eq!(parse_attribute_selector("data-x*=a"), Ok(attribute("data-x", Substring, "a")));
+    assert_eq!(parse_attribute_selector("=open"), Err(CssAttributeSelectorParseError::InvalidName("=open")));
+    assert_eq!(parse_attribute_selector("data-x!=a"), Err(CssAttributeSelectorParseError::InvalidOperator("data-x!=a")));
+}
+
+#[test]
+fn test_css_path_sibling_not_attribute_parse() {
+
+    use self::CssPathSelector::*;
+
+    assert_eq!(
+        parse_css_path(".a + .b ~ div[data-state=open]:not(.c.d):first-child"),
+        Ok(CssPath { selectors: vec![
+            Class("a".to_string().into()),
+            AdjacentSibling,
+            Class("b".to_string().into()),
+            GeneralSibling,
+            Type(NodeTypeTag::Div),
+            Attribute(CssAttributeSelector {
+                name: "data-state".to_string().into(),
+                op: CssAttributeSelectorOp::Equals,
+                value: "open".to_string().into(),
+            }),
+            Not(vec![Class("c".to_string().into()), Class("d".to_string().into())].into()),
+            PseudoSelector(CssPathPseudoSelector::FirstChild),
+        ].into() })
+    );
+
+    assert_eq!(
+        pseudo_class_from_str("not", Some(".a > .b")),
+        Err(CssPseudoSelectorParseError::InvalidNotSelector(".a > .b"))
+    );
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ErrorLocation {
     pub original_pos: usize,
@@ -293,15 +522,15 @@ impl<'a> fmt::Display for CssParseError<'a> {
 }
 
 pub fn new_from_str<'a>(css_string: &'a str) -> Result<Css, CssParseError<'a>> {
-    let mut tokenizer = Tokenizer::new(css_string);
-    let (stylesheet, _warnings) = new_from_str_inner(css_string, &mut tokenizer)?;
-    Ok(Css { stylesheets: vec![stylesheet].into() })
+    let (stylesheet, font_faces, keyframes, _warnings) = new_from_str_inner(css_string)?;
+    Ok(Css { stylesheets: vec![stylesheet].into(), font_faces: font_faces.into(), keyframes: keyframes.into() })
 }
 
 /// Returns the location of where the parser is currently in the document
-fn get_error_location(tokenizer: &Tokenizer) -> ErrorLocation {
+/// (`offset` = start of the section of the document that the tokenizer is parsing)
+fn get_error_location(tokenizer: &Tokenizer, offset: usize) -> ErrorLocation {
     ErrorLocation {
-        original_pos: tokenizer.pos(),
+        original_pos: offset + tokenizer.pos(),
     }
 }
 
@@ -316,10 +545,13 @@ pub enum CssPathParseError<'a> {
     NodeTypeTag(NodeTypeTagParseError<'a>),
     /// Error while parsing a pseudo selector (like `:aldkfja`)
     PseudoSelectorParseError(CssPseudoSelectorParseError<'a>),
+    /// Error while parsing an attribute selector (like `[data-state=open]`)
+    AttributeSelectorParseError(CssAttributeSelectorParseError<'a>),
 }
 
 impl_from! { NodeTypeTagParseError<'a>, CssPathParseError::NodeTypeTag }
 impl_from! { CssPseudoSelectorParseError<'a>, CssPathParseError::PseudoSelectorParseError }
+impl_from! { CssAttributeSelectorParseError<'a>, CssPathParseError::AttributeSelectorParseError }
 
 impl<'a> From<CssSyntaxError> for CssPathParseError<'a> {
     fn from(e: CssSyntaxError) -> Self {
@@ -386,8 +618,17 @@ pub fn parse_css_path<'a>(input: &'a str) -> Result<CssPath, CssPathParseError<'
             Token::Combinator(Combinator::Space) => {
                 selectors.push(CssPathSelector::Children);
             },
+            Token::Combinator(Combinator::Plus) => {
+                selectors.push(CssPathSelector::AdjacentSibling);
+            },
+            Token::Combinator(Combinator::Tilde) => {
+                selectors.push(CssPathSelector::GeneralSibling);
+            },
+            Token::AttributeSelector(attribute) => {
+                selectors.push(CssPathSelector::Attribute(parse_attribute_selector(attribute)?));
+            },
             Token::PseudoClass { selector, value } => {
-                selectors.push(CssPathSelector::PseudoSelector(pseudo_selector_from_str(selector, value)?));
+                selectors.push(pseudo_class_from_str(selector, value)?);
             },
             Token::EndOfStream => {
                 break;
@@ -411,6 +652,8 @@ pub struct UnparsedCssRuleBlock<'a> {
     pub path: CssPath,
     /// `"justify-content" => "center"`
     pub declarations: BTreeMap<&'a str, (&'a str, (ErrorLocation, ErrorLocation))>,
+    /// Queries of the enclosing `@media` rule (empty if the block isn't in a `@media` rule)
+    pub media_queries: Vec<CssMediaQuery>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -423,19 +666,60 @@ pub struct CssParseWarnMsg<'a> {
 pub enum CssParseWarnMsgInner<'a> {
     /// Key "blah" isn't (yet) supported, so the parser didn't attempt to parse the value at all
     UnsupportedKeyValuePair { key: &'a str, value: &'a str },
+    /// At-rule "@blah" isn't (yet) supported, the rule was skipped
+    UnsupportedAtRule { name: &'a str },
 }
 
-/// Parses a CSS string (single-threaded) and returns the parsed rules in blocks
+/// Parses a CSS string (single-threaded) and returns the parsed rules in blocks,
+/// the fonts declared in `@font-face` rules and the animations declared in `@keyframes` rules
 ///
 /// May return "warning" messages, i.e. messages that just serve as a warning,
 /// instead of being actual errors. These warnings may be ignored by the caller,
 /// but can be useful for debugging.
-fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
--> Result<(Stylesheet, Vec<CssParseWarnMsg<'a>>), CssParseError<'a>> {
+fn new_from_str_inner<'a>(css_string: &'a str)
+-> Result<(Stylesheet, Vec<CssFontFace>, Vec<CssKeyframes>, Vec<CssParseWarnMsg<'a>>), CssParseError<'a>> {
 
-    use azul_simplecss::{Token, Combinator};
+    // the tokenizer can't parse at-rules, so they are cut out of the
+    // stylesheet and the sections in between are tokenized separately
+    let (sections, at_rules) = split_at_rules(css_string)?;
 
     let mut css_blocks = Vec::new();
+    let mut other_at_rules = Vec::new();
+    let mut media_warnings = Vec::new();
+
+    // every section is followed by one at-rule (except for the last section):
+    // the rules of `@media` blocks are inserted in between in order to preserve
+    // the source order of rules with the same specificity
+    let mut at_rules = at_rules.into_iter();
+    for (offset, section) in sections {
+        let mut tokenizer = Tokenizer::new(section);
+        parse_css_rule_blocks(css_string, offset, &mut tokenizer, &mut css_blocks)?;
+        match at_rules.next() {
+            Some(at_rule) if at_rule.name.eq_ignore_ascii_case("media") => {
+                parse_media_rule(css_string, &at_rule, &mut css_blocks, &mut media_warnings)?;
+            },
+            Some(at_rule) => other_at_rules.push(at_rule),
+            None => { },
+        }
+    }
+
+    let (stylesheet, mut warnings) = unparsed_css_blocks_to_stylesheet(css_blocks, css_string)?;
+    warnings.extend(media_warnings.into_iter());
+    let (font_faces, keyframes) = parse_at_rules(css_string, other_at_rules, &mut warnings)?;
+
+    Ok((stylesheet, font_faces, keyframes, warnings))
+}
+
+/// Tokenizes a section of the stylesheet (that doesn't contain any at-rules)
+/// into unparsed rule blocks, `offset` is the start of the section in the `css_string`
+fn parse_css_rule_blocks<'a>(
+    css_string: &'a str,
+    offset: usize,
+    tokenizer: &mut Tokenizer<'a>,
+    css_blocks: &mut Vec<UnparsedCssRuleBlock<'a>>,
+) -> Result<(), CssParseError<'a>> {
+
+    use azul_simplecss::{Token, Combinator};
 
     // Used for error checking / checking for closed braces
     let mut parser_in_block = false;
@@ -450,14 +734,14 @@ fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
     // Keep track of the current path during parsing
     let mut last_path = Vec::new();
 
-    let mut last_error_location = ErrorLocation { original_pos: 0 };
+    let mut last_error_location = ErrorLocation { original_pos: offset };
 
     loop {
 
         let token = tokenizer.parse_next().map_err(|e| CssParseError {
             css_string,
             error: e.into(),
-            location: (last_error_location, get_error_location(tokenizer))
+            location: (last_error_location, get_error_location(tokenizer, offset))
         })?;
 
         macro_rules! check_parser_is_outside_block {() => {
@@ -465,7 +749,7 @@ fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
                 return Err(CssParseError {
                     css_string,
                     error: CssParseErrorInner::MalformedCss,
-                    location: (last_error_location, get_error_location(tokenizer)),
+                    location: (last_error_location, get_error_location(tokenizer, offset)),
                 });
             }
         }}
@@ -475,7 +759,7 @@ fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
                 return Err(CssParseError {
                     css_string,
                     error: CssParseErrorInner::MalformedCss,
-                    location: (last_error_location, get_error_location(tokenizer)),
+                    location: (last_error_location, get_error_location(tokenizer, offset)),
                 });
             }
         }}
@@ -503,6 +787,7 @@ fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
                     UnparsedCssRuleBlock {
                         path: CssPath { selectors: path.into() },
                         declarations: current_rules.clone(),
+                        media_queries: Vec::new(),
                     }
                 }));
 
@@ -521,7 +806,7 @@ fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
                     CssParseError {
                         css_string,
                         error: e.into(),
-                        location: (last_error_location, get_error_location(tokenizer)),
+                        location: (last_error_location, get_error_location(tokenizer, offset)),
                     }
                 })?));
             },
@@ -541,19 +826,37 @@ fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
                 check_parser_is_outside_block!();
                 last_path.push(CssPathSelector::Children);
             },
-            Token::PseudoClass { selector, value } => {
+            Token::Combinator(Combinator::Plus) => {
+                check_parser_is_outside_block!();
+                last_path.push(CssPathSelector::AdjacentSibling);
+            },
+            Token::Combinator(Combinator::Tilde) => {
                 check_parser_is_outside_block!();
-                last_path.push(CssPathSelector::PseudoSelector(pseudo_selector_from_str(selector, value).map_err(|e| {
+                last_path.push(CssPathSelector::GeneralSibling);
+            },
+            Token::AttributeSelector(attribute) => {
+                check_parser_is_outside_block!();
+                last_path.push(CssPathSelector::Attribute(parse_attribute_selector(attribute).map_err(|e| {
                     CssParseError {
                         css_string,
                         error: e.into(),
-                        location: (last_error_location, get_error_location(tokenizer)),
+                        location: (last_error_location, get_error_location(tokenizer, offset)),
                     }
                 })?));
             },
+            Token::PseudoClass { selector, value } => {
+                check_parser_is_outside_block!();
+                last_path.push(pseudo_class_from_str(selector, value).map_err(|e| {
+                    CssParseError {
+                        css_string,
+                        error: e.into(),
+                        location: (last_error_location, get_error_location(tokenizer, offset)),
+                    }
+                })?);
+            },
             Token::Declaration(key, val) => {
                 check_parser_is_inside_block!();
-                current_rules.insert(key, (val, (last_error_location, get_error_location(tokenizer))));
+                current_rules.insert(key, (val, (last_error_location, get_error_location(tokenizer, offset))));
             },
             Token::EndOfStream => {
 
@@ -562,21 +865,601 @@ fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
                     return Err(CssParseError {
                         css_string,
                         error: CssParseErrorInner::UnclosedBlock,
-                        location: (last_error_location, get_error_location(tokenizer)),
+                        location: (last_error_location, get_error_location(tokenizer, offset)),
                     });
                 }
 
                 break;
             },
             _ => {
-                // attributes, lang-attributes and @keyframes are not supported
+                // lang-attributes are not supported
             }
         }
 
-        last_error_location = get_error_location(tokenizer);
+        last_error_location = get_error_location(tokenizer, offset);
     }
 
-    unparsed_css_blocks_to_stylesheet(css_blocks, css_string)
+    Ok(())
+}
+
+/// Top-level at-rule (i.e. `@font-face { ... }` or `@import "a.css";`),
+/// cut out of the stylesheet before the stylesheet is tokenized
+#[derive(Debug, Clone, PartialEq)]
+struct UnparsedAtRule<'a> {
+    /// Name of the rule without the "@", i.e. `"font-face"`
+    name: &'a str,
+    /// Everything between the name and the block, i.e. `"screen and (max-width: 600px)"`
+    prelude: &'a str,
+    /// Contents of the block (without the braces), `None` for statements that end with a `;`
+    block: Option<&'a str>,
+    /// Position of the block contents in the `css_string`
+    block_start: usize,
+    location: (ErrorLocation, ErrorLocation),
+}
+
+/// If the `css_string` has a comment or a string starting at `pos`, returns the
+/// position after the end of the comment / string (or the end of the input if unclosed)
+fn skip_comment_or_string(css_string: &str, pos: usize) -> Option<usize> {
+    let bytes = css_string.as_bytes();
+    match bytes.get(pos)? {
+        b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+            Some(css_string[pos + 2..].find("*/").map(|end| pos + 2 + end + 2).unwrap_or(bytes.len()))
+        },
+        quote @ b'"' | quote @ b'\'' => {
+            let mut i = pos + 1;
+            while i < bytes.len() {
+                match bytes[i] {
+                    b'\\' => i += 1,
+                    b if b == *quote => return Some(i + 1),
+                    _ => { },
+                }
+                i += 1;
+            }
+            Some(bytes.len())
+        },
+        _ => None,
+    }
+}
+
+/// Splits the top-level at-rules out of the stylesheet, returns the remaining
+/// sections of the stylesheet (including their position in the `css_string`)
+/// and the unparsed at-rules
+fn split_at_rules<'a>(css_string: &'a str)
+-> Result<(Vec<(usize, &'a str)>, Vec<UnparsedAtRule<'a>>), CssParseError<'a>> {
+
+    let bytes = css_string.as_bytes();
+
+    let mut sections = Vec::new();
+    let mut at_rules = Vec::new();
+
+    let mut section_start = 0;
+    let mut block_nesting = 0_usize;
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+
+        if let Some(next) = skip_comment_or_string(css_string, pos) {
+            pos = next;
+            continue;
+        }
+
+        match bytes[pos] {
+            b'{' => block_nesting += 1,
+            b'}' => block_nesting = block_nesting.saturating_sub(1),
+            b'@' if block_nesting == 0 => {
+
+                let rule_start = pos;
+                let name_start = pos + 1;
+                let name_end = css_string[name_start..]
+                    .find(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_'))
+                    .map(|p| name_start + p)
+                    .unwrap_or(bytes.len());
+
+                // find the end of the prelude: "{" or ";"
+                let mut prelude_end = name_end;
+                while prelude_end < bytes.len() && bytes[prelude_end] != b'{' && bytes[prelude_end] != b';' {
+                    prelude_end = skip_comment_or_string(css_string, prelude_end).unwrap_or(prelude_end + 1);
+                }
+
+                let block_start = prelude_end + 1;
+                let (block, rule_end) = if bytes.get(prelude_end) == Some(&b'{') {
+                    // find the matching closing brace
+                    let mut nesting = 1_usize;
+                    let mut block_end = block_start;
+                    while block_end < bytes.len() {
+                        if let Some(next) = skip_comment_or_string(css_string, block_end) {
+                            block_end = next;
+                            continue;
+                        }
+                        match bytes[block_end] {
+                            b'{' => nesting += 1,
+                            b'}' => { nesting -= 1; if nesting == 0 { break; } },
+                            _ => { },
+                        }
+                        block_end += 1;
+                    }
+
+                    if nesting != 0 {
+                        return Err(CssParseError {
+                            css_string,
+                            error: CssParseErrorInner::UnclosedBlock,
+                            location: (ErrorLocation { original_pos: rule_start }, ErrorLocation { original_pos: bytes.len() }),
+                        });
+                    }
+
+                    (Some(&css_string[block_start..block_end]), block_end + 1)
+                } else {
+                    (None, (prelude_end + 1).min(bytes.len()))
+                };
+
+                sections.push((section_start, &css_string[section_start..rule_start]));
+                at_rules.push(UnparsedAtRule {
+                    name: &css_string[name_start..name_end],
+                    prelude: css_string[name_end..prelude_end.min(bytes.len())].trim(),
+                    block,
+                    block_start,
+                    location: (ErrorLocation { original_pos: rule_start }, ErrorLocation { original_pos: rule_end }),
+                });
+
+                section_start = rule_end;
+                pos = rule_end;
+                continue;
+            },
+            _ => { },
+        }
+
+        pos += 1;
+    }
+
+    sections.push((section_start, &css_string[section_start..]));
+
+    Ok((sections, at_rules))
+}
+
+/// Splits the contents of a declaration block into `key: value` pairs,
+/// ignoring semicolons in strings and parentheses (i.e. `url(data:font/ttf;base64,...)`)
+fn split_declaration_block<'a>(block: &'a str) -> Vec<(&'a str, &'a str)> {
+
+    let bytes = block.as_bytes();
+    let mut declarations = Vec::new();
+    let mut declaration_start = 0;
+    let mut paren_nesting = 0_usize;
+    let mut pos = 0;
+
+    let mut push_declaration = |declaration: &'a str| {
+        let mut key_value = declaration.splitn(2, ':');
+        if let (Some(key), Some(value)) = (key_value.next(), key_value.next()) {
+            let key = key.trim();
+            if !key.is_empty() {
+                declarations.push((key, value.trim()));
+            }
+        }
+    };
+
+    while pos < bytes.len() {
+
+        if let Some(next) = skip_comment_or_string(block, pos) {
+            pos = next;
+            continue;
+        }
+
+        match bytes[pos] {
+            b'(' => paren_nesting += 1,
+            b')' => paren_nesting = paren_nesting.saturating_sub(1),
+            b';' if paren_nesting == 0 => {
+                push_declaration(&block[declaration_start..pos]);
+                declaration_start = pos + 1;
+            },
+            _ => { },
+        }
+
+        pos += 1;
+    }
+
+    push_declaration(&block[declaration_start..]);
+
+    declarations
+}
+
+/// Parses an `@media` rule and appends the rule blocks inside of it to the `css_blocks`.
+/// If the media type never matches (i.e. `@media print`), the rules are dropped.
+fn parse_media_rule<'a>(
+    css_string: &'a str,
+    at_rule: &UnparsedAtRule<'a>,
+    css_blocks: &mut Vec<UnparsedCssRuleBlock<'a>>,
+    warnings: &mut Vec<CssParseWarnMsg<'a>>,
+) -> Result<(), CssParseError<'a>> {
+
+    let to_css_parse_error = |e: CssMediaQueryParseError<'a>| CssParseError {
+        css_string,
+        error: e.into(),
+        location: at_rule.location,
+    };
+
+    let block = at_rule.block.ok_or(CssMediaQueryParseError::MissingBlock).map_err(to_css_parse_error)?;
+
+    let media_queries = match parse_media_query_list(at_rule.prelude).map_err(to_css_parse_error)? {
+        Some(s) => s,
+        None => return Ok(()),
+    };
+
+    // nested at-rules (`@media` in `@media`) are not supported
+    let (sections, nested_at_rules) = split_at_rules(block).map_err(|e| CssParseError {
+        css_string,
+        error: e.error,
+        location: at_rule.location,
+    })?;
+
+    for nested_at_rule in nested_at_rules {
+        warnings.push(CssParseWarnMsg {
+            warning: CssParseWarnMsgInner::UnsupportedAtRule { name: nested_at_rule.name },
+            location: at_rule.location,
+        });
+    }
+
+    let first_block = css_blocks.len();
+    for (offset, section) in sections {
+        let mut tokenizer = Tokenizer::new(section);
+        parse_css_rule_blocks(css_string, at_rule.block_start + offset, &mut tokenizer, css_blocks)?;
+    }
+
+    for css_block in css_blocks[first_block..].iter_mut() {
+        css_block.media_queries = media_queries.clone();
+    }
+
+    Ok(())
+}
+
+/// Parses a comma-separated media query list, such as
+/// `screen and (max-width: 600px), (orientation: portrait)`.
+///
+/// Returns `None` if none of the queries can ever match on a screen (i.e. `print`),
+/// returns an empty list if the queries always match (i.e. `all`)
+fn parse_media_query_list<'a>(input: &'a str)
+-> Result<Option<Vec<CssMediaQuery>>, CssMediaQueryParseError<'a>> {
+
+    let mut queries = Vec::new();
+    let mut always_matches = false;
+
+    for query in input.split(',') {
+        if let Some(conditions) = parse_media_query(query.trim())? {
+            always_matches = always_matches || conditions.is_empty();
+            queries.push(CssMediaQuery { conditions: conditions.into() });
+        }
+    }
+
+    if always_matches {
+        Ok(Some(Vec::new()))
+    } else if queries.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(queries))
+    }
+}
+
+/// Parses a single media query, returns `None` for media types other than `all` / `screen`
+fn parse_media_query<'a>(input: &'a str)
+-> Result<Option<Vec<CssMediaCondition>>, CssMediaQueryParseError<'a>> {
+
+    use self::CssMediaQueryParseError::*;
+
+    let mut conditions = Vec::new();
+    let mut rest = input.trim();
+    let mut expect_and = false;
+
+    if rest.is_empty() {
+        return Err(MalformedQuery(input));
+    }
+
+    // optional media type: "only screen and (...)"
+    if !rest.starts_with('(') {
+        let type_end = rest.find(|c: char| c.is_whitespace()).unwrap_or(rest.len());
+        let mut media_type = &rest[..type_end];
+        rest = rest[type_end..].trim_start();
+
+        if media_type.eq_ignore_ascii_case("only") {
+            let type_end = rest.find(|c: char| c.is_whitespace()).unwrap_or(rest.len());
+            media_type = &rest[..type_end];
+            rest = rest[type_end..].trim_start();
+        }
+
+        match media_type.to_ascii_lowercase().as_str() {
+            "all" | "screen" => { },
+            "print" | "speech" => return Ok(None),
+            _ => return Err(UnknownMediaType(media_type)),
+        }
+
+        expect_and = true;
+    }
+
+    while !rest.is_empty() {
+
+        if expect_and {
+            let and_end = rest.find(|c: char| c.is_whitespace() || c == '(').unwrap_or(rest.len());
+            if !rest[..and_end].eq_ignore_ascii_case("and") {
+                return Err(MalformedQuery(input));
+            }
+            rest = rest[and_end..].trim_start();
+        }
+
+        if !rest.starts_with('(') {
+            return Err(MalformedQuery(input));
+        }
+
+        let feature_end = rest.find(')').ok_or(MalformedQuery(input))?;
+        conditions.push(parse_media_condition(&rest[1..feature_end])?);
+        rest = rest[feature_end + 1..].trim_start();
+        expect_and = true;
+    }
+
+    Ok(Some(conditions))
+}
+
+/// Parses the inside of a media feature, i.e. `max-width: 600px`
+fn parse_media_condition<'a>(input: &'a str) -> Result<CssMediaCondition, CssMediaQueryParseError<'a>> {
+
+    use self::CssMediaQueryParseError::*;
+
+    let mut key_value = input.splitn(2, ':');
+    let feature = key_value.next().unwrap_or_default().trim();
+    let value = key_value.next().ok_or(MalformedQuery(input))?.trim();
+
+    let invalid_value = || InvalidFeatureValue { feature, value };
+    let pixel_value = || css_parser::parse_pixel_value(value).map_err(|_| invalid_value());
+    let resolution = || parse_media_resolution(value).ok_or_else(invalid_value);
+
+    let condition = match feature.to_ascii_lowercase().as_str() {
+        "min-width" => CssMediaCondition::MinWidth(pixel_value()?),
+        "max-width" => CssMediaCondition::MaxWidth(pixel_value()?),
+        "min-height" => CssMediaCondition::MinHeight(pixel_value()?),
+        "max-height" => CssMediaCondition::MaxHeight(pixel_value()?),
+        "min-resolution" => CssMediaCondition::MinResolution(resolution()?),
+        "max-resolution" => CssMediaCondition::MaxResolution(resolution()?),
+        "orientation" => CssMediaCondition::Orientation(match value {
+            "portrait" => CssMediaOrientation::Portrait,
+            "landscape" => CssMediaOrientation::Landscape,
+            _ => return Err(invalid_value()),
+        }),
+        "prefers-color-scheme" => CssMediaCondition::PrefersColorScheme(match value {
+            "light" => CssMediaColorScheme::Light,
+            "dark" => CssMediaColorScheme::Dark,
+            _ => return Err(invalid_value()),
+        }),
+        _ => return Err(UnsupportedFeature(feature)),
+    };
+
+    Ok(condition)
+}
+
+/// Parses a resolution (`2dppx`, `2x`, `192dpi`) into dots per pixel
+fn parse_media_resolution(input: &str) -> Option<FloatValue> {
+    let (number, factor) = if input.ends_with("dppx") {
+        (&input[..input.len() - 4], 1.0)
+    } else if input.ends_with("dpi") {
+        (&input[..input.len() - 3], 1.0 / 96.0)
+    } else if input.ends_with('x') {
+        (&input[..input.len() - 1], 1.0)
+    } else {
+        return None;
+    };
+    let number = number.trim().parse::<f32>().ok()?;
+    Some(FloatValue::new(number * factor))
+}
+
+/// Parses the at-rules of the stylesheet, returns the declared font faces and keyframes
+fn parse_at_rules<'a>(
+    css_string: &'a str,
+    at_rules: Vec<UnparsedAtRule<'a>>,
+    warnings: &mut Vec<CssParseWarnMsg<'a>>,
+) -> Result<(Vec<CssFontFace>, Vec<CssKeyframes>), CssParseError<'a>> {
+
+    let mut font_faces = Vec::new();
+    let mut keyframes = Vec::new();
+
+    for at_rule in at_rules {
+        if at_rule.name.eq_ignore_ascii_case("font-face") {
+            let font_face = parse_font_face(&at_rule, warnings).map_err(|e| CssParseError {
+                css_string,
+                error: e.into(),
+                location: at_rule.location,
+            })?;
+            font_faces.push(font_face);
+        } else if at_rule.name.eq_ignore_ascii_case("keyframes") {
+            let k = parse_keyframes(&at_rule, warnings).map_err(|e| CssParseError {
+                css_string,
+                error: e,
+                location: at_rule.location,
+            })?;
+            keyframes.push(k);
+        } else {
+            warnings.push(CssParseWarnMsg {
+                warning: CssParseWarnMsgInner::UnsupportedAtRule { name: at_rule.name },
+                location: at_rule.location,
+            });
+        }
+    }
+
+    Ok((font_faces, keyframes))
+}
+
+/// Parses the block of an `@font-face` rule, unknown declarations
+/// (such as `font-display` or `unicode-range`) are ignored with a warning
+fn parse_font_face<'a>(
+    at_rule: &UnparsedAtRule<'a>,
+    warnings: &mut Vec<CssParseWarnMsg<'a>>,
+) -> Result<CssFontFace, CssFontFaceParseError<'a>> {
+
+    use self::CssFontFaceParseError::*;
+
+    let block = at_rule.block.ok_or(MissingBlock)?;
+
+    let mut font_family = None;
+    let mut src = None;
+    let mut font_weight = CssFontFace::WEIGHT_NORMAL;
+    let mut font_style = CssFontFaceStyle::Normal;
+
+    for (key, value) in split_declaration_block(block) {
+        match key.to_ascii_lowercase().as_str() {
+            "font-family" => {
+                font_family = Some(value.trim_matches('"').trim_matches('\'').trim());
+            },
+            "src" => {
+                src = Some(parse_font_face_src(value).ok_or(InvalidSrc(value))?);
+            },
+            "font-weight" => {
+                font_weight = parse_font_face_weight(value).ok_or(InvalidFontWeight(value))?;
+            },
+            "font-style" => {
+                font_style = parse_font_face_style(value).ok_or(InvalidFontStyle(value))?;
+            },
+            _ => {
+                warnings.push(CssParseWarnMsg {
+                    warning: CssParseWarnMsgInner::UnsupportedKeyValuePair { key, value },
+                    location: at_rule.location,
+                });
+            }
+        }
+    }
+
+    Ok(CssFontFace {
+        font_family: font_family.filter(|f| !f.is_empty()).ok_or(MissingFontFamily)?.to_string().into(),
+        src: src.ok_or(MissingSrc)?.to_string().into(),
+        font_weight,
+        font_style,
+    })
+}
+
+/// Parses the block of a `@keyframes` rule into keyframes sorted by their offset.
+/// Selector lists (`0%, 100% { ... }`) declare the same keyframe multiple times,
+/// `var()` values are replaced by their default value.
+fn parse_keyframes<'a>(
+    at_rule: &UnparsedAtRule<'a>,
+    warnings: &mut Vec<CssParseWarnMsg<'a>>,
+) -> Result<CssKeyframes, CssParseErrorInner<'a>> {
+
+    use self::CssKeyframesParseError::*;
+
+    let name = at_rule.prelude.trim_matches('"').trim_matches('\'').trim();
+    if name.is_empty() {
+        return Err(MissingName.into());
+    }
+
+    let block = at_rule.block.ok_or(MissingBlock)?;
+    let bytes = block.as_bytes();
+    let css_key_map = azul_css::get_css_key_map();
+
+    let mut keyframes = Vec::new();
+    let mut selector_start = 0;
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+
+        if let Some(next) = skip_comment_or_string(block, pos) {
+            pos = next;
+            continue;
+        }
+
+        if bytes[pos] != b'{' {
+            pos += 1;
+            continue;
+        }
+
+        let selectors = &block[selector_start..pos];
+        let declarations_start = pos + 1;
+        let mut declarations_end = declarations_start;
+        while declarations_end < bytes.len() && bytes[declarations_end] != b'}' {
+            declarations_end = skip_comment_or_string(block, declarations_end).unwrap_or(declarations_end + 1);
+        }
+
+        if declarations_end >= bytes.len() {
+            return Err(UnclosedKeyframe(selectors.trim()).into());
+        }
+
+        let mut declarations = Vec::new();
+        for (key, value) in split_declaration_block(&block[declarations_start..declarations_end]) {
+            if key.starts_with("--") {
+                // custom properties can't be animated
+                warnings.push(CssParseWarnMsg {
+                    warning: CssParseWarnMsgInner::UnsupportedKeyValuePair { key, value },
+                    location: at_rule.location,
+                });
+                continue;
+            }
+            parse_css_declaration(key, value, at_rule.location, &css_key_map, warnings, &mut declarations)?;
+        }
+
+        let properties = declarations.into_iter().filter_map(|d| match d {
+            CssDeclaration::Static(s) => Some(s),
+            CssDeclaration::Dynamic(d) => Some(d.default_value),
+            CssDeclaration::Custom(_) => None,
+        }).collect::<Vec<_>>();
+
+        for selector in selectors.split(',') {
+            let selector = selector.trim();
+            let offset = parse_keyframe_selector(selector).ok_or(InvalidSelector(selector))?;
+            keyframes.push(CssKeyframe { offset: FloatValue::new(offset), properties: properties.clone().into() });
+        }
+
+        selector_start = declarations_end + 1;
+        pos = declarations_end + 1;
+    }
+
+    // stable sort: keyframes with the same offset keep their source order
+    keyframes.sort_by(|a, b| a.offset.get().partial_cmp(&b.offset.get()).unwrap_or(core::cmp::Ordering::Equal));
+
+    Ok(CssKeyframes {
+        name: name.to_string().into(),
+        keyframes: keyframes.into(),
+    })
+}
+
+/// Parses a keyframe selector: `from` = `0.0`, `to` = `1.0`, `50%` = `0.5`
+fn parse_keyframe_selector(input: &str) -> Option<f32> {
+    match input {
+        "from" => Some(0.0),
+        "to" => Some(1.0),
+        other => {
+            let percent = other.strip_suffix('%')?.trim().parse::<f32>().ok()?;
+            if percent >= 0.0 && percent <= 100.0 { Some(percent / 100.0) } else { None }
+        }
+    }
+}
+
+/// Returns the path of the first `url()` in the `src` of a `@font-face`:
+///
+/// ```no_run,ignore
+/// url("fonts/a.woff2") format("woff2"), url(fonts/a.ttf) => "fonts/a.woff2"
+/// ```
+fn parse_font_face_src<'a>(input: &'a str) -> Option<&'a str> {
+    input.split(',').find_map(|source| {
+        let source = source.trim();
+        if !source.get(..4)?.eq_ignore_ascii_case("url(") {
+            return None;
+        }
+        let url = &source[4..source.find(')')?];
+        let url = url.trim().trim_matches('"').trim_matches('\'').trim();
+        if url.is_empty() { None } else { Some(url) }
+    })
+}
+
+fn parse_font_face_weight(input: &str) -> Option<u16> {
+    // variable fonts declare a range ("100 900"): use the lower bound
+    let input = input.split_whitespace().next()?;
+    match input {
+        "normal" => Some(CssFontFace::WEIGHT_NORMAL),
+        "bold" => Some(CssFontFace::WEIGHT_BOLD),
+        other => other.parse::<u16>().ok().filter(|w| *w >= 1 && *w <= 1000),
+    }
+}
+
+fn parse_font_face_style(input: &str) -> Option<CssFontFaceStyle> {
+    // "oblique 10deg" (angle is ignored)
+    match input.split_whitespace().next()? {
+        "normal" => Some(CssFontFaceStyle::Normal),
+        "italic" => Some(CssFontFaceStyle::Italic),
+        "oblique" => Some(CssFontFaceStyle::Oblique),
+        _ => None,
+    }
 }
 
 fn unparsed_css_blocks_to_stylesheet<'a>(css_blocks: Vec<UnparsedCssRuleBlock<'a>>, css_string: &'a str)
@@ -609,6 +1492,7 @@ fn unparsed_css_blocks_to_stylesheet<'a>(css_blocks: Vec<UnparsedCssRuleBlock<'a
         Ok(CssRuleBlock {
             path: unparsed_css_block.path.into(),
             declarations: declarations.into(),
+            media_queries: unparsed_css_block.media_queries.into(),
         })
     }).collect::<Result<Vec<CssRuleBlock>, CssParseError>>()?;
 
@@ -627,7 +1511,21 @@ fn parse_css_declaration<'a>(
     use self::CssParseErrorInner::*;
     use self::CssParseWarnMsgInner::*;
 
-    if let Some(combined_key) = CombinedCssPropertyType::from_str(unparsed_css_key, &css_key_map) {
+    if unparsed_css_key.starts_with("--") {
+        // --accent: #3366ff;
+        let variable_name = &unparsed_css_key[2..];
+        if variable_name.is_empty() {
+            warnings.push(CssParseWarnMsg {
+                warning: UnsupportedKeyValuePair { key: unparsed_css_key, value: unparsed_css_value },
+                location,
+            });
+        } else {
+            declarations.push(CssDeclaration::Custom(CssCustomProperty {
+                name: variable_name.to_string().into(),
+                value: unparsed_css_value.trim().to_string().into(),
+            }));
+        }
+    } else if let Some(combined_key) = CombinedCssPropertyType::from_str(unparsed_css_key, &css_key_map) {
         if let Some(css_var) = check_if_value_is_css_var(unparsed_css_value) {
             // margin: var(--my-variable);
             return Err(VarOnShorthandProperty { key: combined_key, value: unparsed_css_value });
@@ -672,7 +1570,8 @@ fn parse_css_declaration<'a>(
 
 fn check_if_value_is_css_var<'a>(unparsed_css_value: &'a str) -> Option<Result<(&'a str, &'a str), CssParseErrorInner<'a>>> {
 
-    const DEFAULT_VARIABLE_DEFAULT: &str = "none";
+    // var(--x) without a fallback resolves to the initial value if --x is not declared
+    const DEFAULT_VARIABLE_DEFAULT: &str = "initial";
 
     let (_, brace_contents) = css_parser::parse_parentheses(unparsed_css_value, &["var"]).ok()?;
 
@@ -736,12 +1635,15 @@ fn test_css_parse_1() {
                 a: 255,
             })].into()),
         ))].into(),
+        media_queries: Vec::new().into(),
     }].into();
 
     assert_eq!(
         parsed_css,
         Css {
             stylesheets: vec![expected_css_rules].into(),
+            font_faces: Vec::new().into(),
+            keyframes: Vec::new().into(),
         }
     );
 }
@@ -765,8 +1667,11 @@ fn test_css_simple_selector_parse() {
             rules: vec![CssRuleBlock {
                 path: CssPath { selectors: parsed.into() },
                 declarations: Vec::new().into(),
+                media_queries: Vec::new().into(),
             }].into(),
         }].into(),
+        font_faces: Vec::new().into(),
+        keyframes: Vec::new().into(),
     });
 }
 
@@ -778,7 +1683,7 @@ mod stylesheet_parse {
 
     fn test_css(css: &str, expected: Vec<CssRuleBlock>) {
         let css = new_from_str(css).unwrap();
-        assert_eq!(css, Css { stylesheets: vec![expected.into()].into() });
+        assert_eq!(css, Css { stylesheets: vec![expected.into()].into(), font_faces: Vec::new().into(), keyframes: Vec::new().into() });
     }
 
     // Tests that an element with a single class always gets the CSS element applied properly
@@ -818,6 +1723,7 @@ mod stylesheet_parse {
                         selectors: vec![CssPathSelector::Class("my_class".to_string().into())].into(),
                     },
                     declarations: vec![CssDeclaration::Static(red.clone())].into(),
+                    media_queries: Vec::new().into(),
                 },
             ].into();
             test_css(css_1, expected_rules);
@@ -830,10 +1736,12 @@ mod stylesheet_parse {
                 CssRuleBlock {
                     path: CssPath { selectors: vec![CssPathSelector::Id("my_id".to_string().into())].into(), },
                     declarations: vec![CssDeclaration::Static(red.clone())].into(),
+                    media_queries: Vec::new().into(),
                 },
                 CssRuleBlock {
                     path: CssPath { selectors: vec![CssPathSelector::Class("my_class".to_string().into())].into(), },
                     declarations: vec![CssDeclaration::Static(blue.clone())].into(),
+                    media_queries: Vec::new().into(),
                 },
             ];
             test_css(css_2, expected_rules);
@@ -846,14 +1754,17 @@ mod stylesheet_parse {
                 CssRuleBlock {
                     path: CssPath { selectors: vec![CssPathSelector::Global].into() },
                     declarations: vec![CssDeclaration::Static(black.clone())].into(),
+                    media_queries: Vec::new().into(),
                 },
                 CssRuleBlock {
                     path: CssPath { selectors: vec![CssPathSelector::Class("my_class".to_string().into()), CssPathSelector::Id("my_id".to_string().into())].into(), },
                     declarations: vec![CssDeclaration::Static(red.clone())].into(),
+                    media_queries: Vec::new().into(),
                 },
                 CssRuleBlock {
                     path: CssPath { selectors: vec![CssPathSelector::Class("my_class".to_string().into())].into() },
                     declarations: vec![CssDeclaration::Static(blue.clone())].into(),
+                    media_queries: Vec::new().into(),
                 },
             ].into();
             test_css(css_3, expected_rules);
@@ -877,14 +1788,14 @@ fn test_multiple_rules() {
 
     let expected_rules = vec![
         // Rules are sorted by order of appearance in source string
-        CssRuleBlock { path: CssPath { selectors: vec![Global].into() }, declarations: Vec::new().into() },
-        CssRuleBlock { path: CssPath { selectors: vec![Global, Type(NodeTypeTag::Div), Class("my_class".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-        CssRuleBlock { path: CssPath { selectors: vec![Global, Type(NodeTypeTag::Div), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-        CssRuleBlock { path: CssPath { selectors: vec![Global, Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-        CssRuleBlock { path: CssPath { selectors: vec![Type(NodeTypeTag::Div), Class("my_class".to_string().into()), Class("specific".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
+        CssRuleBlock { path: CssPath { selectors: vec![Global].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
+        CssRuleBlock { path: CssPath { selectors: vec![Global, Type(NodeTypeTag::Div), Class("my_class".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
+        CssRuleBlock { path: CssPath { selectors: vec![Global, Type(NodeTypeTag::Div), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
+        CssRuleBlock { path: CssPath { selectors: vec![Global, Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
+        CssRuleBlock { path: CssPath { selectors: vec![Type(NodeTypeTag::Div), Class("my_class".to_string().into()), Class("specific".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
     ];
 
-    assert_eq!(parsed_css, Css { stylesheets: vec![expected_rules.into()].into() });
+    assert_eq!(parsed_css, Css { stylesheets: vec![expected_rules.into()].into(), font_faces: Vec::new().into(), keyframes: Vec::new().into() });
 }
 
 #[test]
@@ -915,6 +1826,7 @@ fn test_case_issue_93() {
             declarations: vec![CssDeclaration::Static(CssProperty::TextColor(
                 CssPropertyValue::Exact(StyleTextColor { inner: color }),
             ))].into(),
+            media_queries: Vec::new().into(),
         }
     }
 
@@ -924,5 +1836,183 @@ fn test_case_issue_93() {
         declaration(&[Class("tabwidget-tab".to_string().into()), Class("active".to_string().into()), Children, Class("tabwidget-tab-close".to_string().into())], ColorU { r: 255, g: 0, b: 0, a: 255 }),
     ];
 
-    assert_eq!(parsed_css, Css { stylesheets: vec![expected_rules.into()].into() });
-}
\ No newline at end of file
+    assert_eq!(parsed_css, Css { stylesheets: vec![expected_rules.into()].into(), font_faces: Vec::new().into(), keyframes: Vec::new().into() });
+}
+
+#[test]
+fn test_font_face_parse() {
+
+    use azul_css::*;
+
+    let parsed_css = new_from_str("
+        @font-face {
+            font-family: \"Brand Sans\";
+            src: url(\"fonts/BrandSans-Bold.woff2\") format(\"woff2\"), url(fonts/BrandSans-Bold.ttf);
+            font-weight: bold;
+            font-display: swap;
+        }
+
+        .title { font-family: \"Brand Sans\", sans-serif; }
+
+        @font-face {
+            font-family: 'Brand Sans';
+            src: url(fonts/BrandSans-Regular.ttf);
+            font-style: normal; /* ; { */
+        }
+    ").unwrap();
+
+    assert_eq!(parsed_css.font_faces.as_ref(), &[
+        CssFontFace {
+            font_family: "Brand Sans".to_string().into(),
+            src: "fonts/BrandSans-Bold.woff2".to_string().into(),
+            font_weight: 700,
+            font_style: CssFontFaceStyle::Normal,
+        },
+        CssFontFace {
+            font_family: "Brand Sans".to_string().into(),
+            src: "fonts/BrandSans-Regular.ttf".to_string().into(),
+            font_weight: 400,
+            font_style: CssFontFaceStyle::Normal,
+        },
+    ]);
+
+    assert_eq!(parsed_css.stylesheets.as_ref()[0].rules.as_ref().len(), 1);
+
+    // the regular font is preferred over the bold one
+    let mut parsed_css = parsed_css;
+    parsed_css.resolve_font_faces();
+
+    assert_eq!(
+        parsed_css.stylesheets.as_ref()[0].rules.as_ref()[0].declarations.as_ref(),
+        &[CssDeclaration::Static(CssProperty::FontFamily(CssPropertyValue::Exact(vec![
+            StyleFontFamily::File("fonts/BrandSans-Regular.ttf".to_string().into()),
+            StyleFontFamily::System("sans-serif".to_string().into()),
+        ].into())))]
+    );
+
+    assert!(new_from_str("@font-face { src: url(a.ttf); }").is_err());
+    assert!(new_from_str("@font-face { font-family: A; src: local(Arial); }").is_err());
+}
+
+#[test]
+fn test_keyframes_parse() {
+
+    use azul_css::*;
+
+    let parsed_css = new_from_str("
+        @keyframes pulse {
+            to { opacity: 1; }
+            0%, 100% { opacity: 1; }
+            50% { opacity: 0.5; transform: rotate(10deg); --ignored: 5px; }
+        }
+
+        .badge { animation: pulse 2s ease-in-out infinite; }
+    ").unwrap();
+
+    let pulse = parsed_css.get_keyframes("pulse").unwrap();
+    let offsets = pulse.keyframes.iter().map(|k| k.offset.get()).collect::<Vec<_>>();
+    assert_eq!(offsets, vec![0.0, 0.5, 1.0, 1.0]);
+    assert_eq!(pulse.keyframes.as_ref()[1].properties.as_ref(), &[
+        CssProperty::Opacity(CssPropertyValue::Exact(StyleOpacity { inner: PercentageValue::new(50.0) })),
+        CssProperty::Transform(CssPropertyValue::Exact(vec![StyleTransform::Rotate(AngleValue::deg(10.0))].into())),
+    ]);
+
+    // the shorthand expands to all seven longhands
+    assert_eq!(parsed_css.stylesheets.as_ref()[0].rules.as_ref()[0].declarations.as_ref().len(), 7);
+    assert!(parsed_css.get_keyframes("spin").is_none());
+
+    assert!(new_from_str("@keyframes { from { opacity: 0; } }").is_err());
+    assert!(new_from_str("@keyframes fade { half { opacity: 0; } }").is_err());
+    assert!(new_from_str("@keyframes fade { 150% { opacity: 0; } }").is_err());
+}
+
+#[test]
+fn test_media_query_parse() {
+
+    use azul_css::*;
+
+    let parsed_css = new_from_str("
+        .sidebar { width: 300px; }
+
+        @media screen and (max-width: 600px), (orientation: portrait) {
+            .sidebar { width: 100px; }
+        }
+
+        @media print {
+            .sidebar { display: none; }
+        }
+
+        @media (prefers-color-scheme: dark) and (min-resolution: 2dppx) {
+            .sidebar { width: 200px; }
+        }
+
+        .sidebar.wide { width: 400px; }
+    ").unwrap();
+
+    let rules = parsed_css.stylesheets.as_ref()[0].rules.as_ref();
+
+    // @media print is dropped, the other rules stay in source order
+    assert_eq!(rules.len(), 4);
+    assert!(rules[0].media_queries.is_empty());
+    assert!(rules[3].media_queries.is_empty());
+
+    assert_eq!(rules[1].media_queries.as_ref(), &[
+        CssMediaQuery { conditions: vec![CssMediaCondition::MaxWidth(PixelValue::px(600.0))].into() },
+        CssMediaQuery { conditions: vec![CssMediaCondition::Orientation(CssMediaOrientation::Portrait)].into() },
+    ]);
+
+    assert_eq!(rules[2].media_queries.as_ref(), &[
+        CssMediaQuery { conditions: vec![
+            CssMediaCondition::PrefersColorScheme(CssMediaColorScheme::Dark),
+            CssMediaCondition::MinResolution(FloatValue::new(2.0)),
+        ].into() },
+    ]);
+
+    let wide_light = CssMediaContext { width: 800.0, height: 600.0, dppx: 1.0, color_scheme: CssMediaColorScheme::Light };
+    let narrow_light = CssMediaContext { width: 500.0, .. wide_light };
+    let wide_dark_hidpi = CssMediaContext { dppx: 2.0, color_scheme: CssMediaColorScheme::Dark, .. wide_light };
+
+    assert!(!rules[1].matches_media(&wide_light));
+    assert!(rules[1].matches_media(&narrow_light));
+    assert!(!rules[2].matches_media(&wide_light));
+    assert!(rules[2].matches_media(&wide_dark_hidpi));
+
+    assert!(parsed_css.media_queries_changed(&wide_light, &narrow_light));
+    assert!(!parsed_css.media_queries_changed(&wide_light, &CssMediaContext { width: 900.0, .. wide_light }));
+
+    assert!(new_from_str("@media (hover: hover) { .a { color: red; } }").is_err());
+    assert!(new_from_str("@media (max-width: wide) { .a { color: red; } }").is_err());
+}
+
+#[test]
+fn test_css_custom_property_parse() {
+
+    use azul_css::*;
+
+    let parsed_css = new_from_str("
+        body { --accent: #3366ff; --gap: var(--base-gap, 4px); }
+        div { color: var(--accent, red); margin-left: var(--gap); }
+    ").unwrap();
+
+    let rules = parsed_css.stylesheets.as_ref()[0].rules.as_ref();
+
+    assert_eq!(rules[0].declarations.as_ref(), &[
+        CssDeclaration::Custom(CssCustomProperty { name: "accent".into(), value: "#3366ff".into() }),
+        CssDeclaration::Custom(CssCustomProperty { name: "gap".into(), value: "var(--base-gap, 4px)".into() }),
+    ]);
+
+    assert_eq!(rules[1].declarations.as_ref(), &[
+        CssDeclaration::Dynamic(DynamicCssProperty {
+            dynamic_id: "accent".into(),
+            default_value: CssProperty::TextColor(CssPropertyValue::Exact(StyleTextColor {
+                inner: ColorU { r: 255, g: 0, b: 0, a: 255 }
+            })),
+        }),
+        CssDeclaration::Dynamic(DynamicCssProperty {
+            dynamic_id: "gap".into(),
+            default_value: CssProperty::MarginLeft(CssPropertyValue::Initial),
+        }),
+    ]);
+
+    assert!(parsed_css.has_css_variables());
+}
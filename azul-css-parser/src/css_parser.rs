@@ -28,14 +28,25 @@ use azul_css::{
     StylePerspectiveOrigin, StyleBackfaceVisibility, StyleOpacity, StyleTransformVec,
     StyleBackgroundContentVec, StyleBackgroundPositionVec, StyleBackgroundSizeVec,
     StyleBackgroundRepeatVec, StyleFontFamilyVec,
-
-    LayoutDisplay, LayoutFloat, LayoutWidth, LayoutHeight, LayoutBoxSizing,
+    StyleTransitionProperty, StyleTransitionPropertyVec, StyleTransitionDuration,
+    StyleTransitionDurationVec, StyleTransitionTimingFunction, StyleTransitionTimingFunctionVec,
+    StyleTransitionDelay, StyleTransitionDelayVec, StyleCubicBezier, get_css_key_map,
+    StyleAnimationName, StyleAnimationNameVec, StyleAnimationDuration, StyleAnimationDurationVec,
+    StyleAnimationIterationCount, StyleAnimationIterationCountVec, StyleAnimationDirection,
+    StyleAnimationDirectionVec, StyleAnimationFillMode, StyleAnimationFillModeVec,
+    StyleAnimationDelay, StyleAnimationDelayVec,
+
+    LayoutDisplay, LayoutFloat, LayoutClear, LayoutWidth, LayoutHeight, LayoutBoxSizing,
     LayoutMinWidth, LayoutMinHeight, LayoutMaxWidth, LayoutMaxHeight,
     LayoutPosition, LayoutTop, LayoutRight, LayoutLeft, LayoutBottom, LayoutFlexWrap,
     LayoutFlexDirection, LayoutFlexGrow, LayoutFlexShrink, LayoutJustifyContent,
     LayoutAlignItems, LayoutAlignContent, LayoutPaddingRight, LayoutPaddingBottom,
     LayoutMarginTop, LayoutMarginLeft, LayoutMarginRight, LayoutMarginBottom,
     LayoutPaddingTop, LayoutPaddingLeft,
+
+    LayoutGridTemplateColumns, LayoutGridTemplateRows, LayoutGridColumn, LayoutGridRow,
+    LayoutColumnGap, LayoutRowGap, GridTrackSizing, GridTrackSizingVec, GridTrackBreadth,
+    GridMinMax, GridLine,
 };
 
 
@@ -218,6 +229,7 @@ pub fn parse_css_property<'a>(key: CssPropertyType, value: &'a str) -> Result<Cs
 
             Display                     => parse_layout_display(value)?.into(),
             Float                       => parse_layout_float(value)?.into(),
+            Clear                       => parse_layout_clear(value)?.into(),
             BoxSizing                   => parse_layout_box_sizing(value)?.into(),
             Width                       => parse_layout_width(value)?.into(),
             Height                      => parse_layout_height(value)?.into(),
@@ -238,6 +250,13 @@ pub fn parse_css_property<'a>(key: CssPropertyType, value: &'a str) -> Result<Cs
             AlignItems                  => parse_layout_align_items(value)?.into(),
             AlignContent                => parse_layout_align_content(value)?.into(),
 
+            GridTemplateColumns         => parse_layout_grid_template_columns(value)?.into(),
+            GridTemplateRows            => parse_layout_grid_template_rows(value)?.into(),
+            GridColumn                  => parse_layout_grid_column(value)?.into(),
+            GridRow                     => parse_layout_grid_row(value)?.into(),
+            ColumnGap                   => parse_layout_column_gap(value)?.into(),
+            RowGap                      => parse_layout_row_gap(value)?.into(),
+
             BackgroundContent           => parse_style_background_content_multiple(value)?.into(),
             BackgroundPosition          => parse_style_background_position_multiple(value)?.into(),
             BackgroundSize              => parse_style_background_size_multiple(value)?.into(),
@@ -288,6 +307,19 @@ pub fn parse_css_property<'a>(key: CssPropertyType, value: &'a str) -> Result<Cs
             TransformOrigin             => parse_style_transform_origin(value)?.into(),
             PerspectiveOrigin           => parse_style_perspective_origin(value)?.into(),
             BackfaceVisibility          => parse_style_backface_visibility(value)?.into(),
+
+            TransitionProperty          => parse_style_transition_property_multiple(value)?.into(),
+            TransitionDuration          => parse_style_transition_duration_multiple(value)?.into(),
+            TransitionTimingFunction    => parse_style_transition_timing_function_multiple(value)?.into(),
+            TransitionDelay             => parse_style_transition_delay_multiple(value)?.into(),
+
+            AnimationName               => parse_style_animation_name_multiple(value)?.into(),
+            AnimationDuration           => parse_style_animation_duration_multiple(value)?.into(),
+            AnimationTimingFunction     => CssProperty::AnimationTimingFunction(parse_style_animation_timing_function_multiple(value)?.into()),
+            AnimationIterationCount     => parse_style_animation_iteration_count_multiple(value)?.into(),
+            AnimationDirection          => parse_style_animation_direction_multiple(value)?.into(),
+            AnimationFillMode           => parse_style_animation_fill_mode_multiple(value)?.into(),
+            AnimationDelay              => parse_style_animation_delay_multiple(value)?.into(),
         }
     })
 }
@@ -326,100 +358,7 @@ pub fn parse_combined_css_property<'a>(key: CombinedCssPropertyType, value: &'a
         }
     )}
 
-    let keys = match key {
-        BorderRadius => {
-            vec![
-                CssPropertyType::BorderTopLeftRadius,
-                CssPropertyType::BorderTopRightRadius,
-                CssPropertyType::BorderBottomLeftRadius,
-                CssPropertyType::BorderBottomRightRadius,
-            ]
-        },
-        Overflow => {
-            vec![
-                CssPropertyType::OverflowX,
-                CssPropertyType::OverflowY,
-            ]
-        },
-        Padding => {
-            vec![
-                CssPropertyType::PaddingTop,
-                CssPropertyType::PaddingBottom,
-                CssPropertyType::PaddingLeft,
-                CssPropertyType::PaddingRight,
-            ]
-        },
-        Margin => {
-            vec![
-                CssPropertyType::MarginTop,
-                CssPropertyType::MarginBottom,
-                CssPropertyType::MarginLeft,
-                CssPropertyType::MarginRight,
-            ]
-        },
-        Border => {
-            vec![
-                CssPropertyType::BorderTopColor,
-                CssPropertyType::BorderRightColor,
-                CssPropertyType::BorderLeftColor,
-                CssPropertyType::BorderBottomColor,
-                CssPropertyType::BorderTopStyle,
-                CssPropertyType::BorderRightStyle,
-                CssPropertyType::BorderLeftStyle,
-                CssPropertyType::BorderBottomStyle,
-                CssPropertyType::BorderTopWidth,
-                CssPropertyType::BorderRightWidth,
-                CssPropertyType::BorderLeftWidth,
-                CssPropertyType::BorderBottomWidth,
-            ]
-        },
-        BorderLeft => {
-            vec![
-                CssPropertyType::BorderLeftColor,
-                CssPropertyType::BorderLeftStyle,
-                CssPropertyType::BorderLeftWidth,
-            ]
-        },
-        BorderRight => {
-            vec![
-                CssPropertyType::BorderRightColor,
-                CssPropertyType::BorderRightStyle,
-                CssPropertyType::BorderRightWidth,
-            ]
-        },
-        BorderTop => {
-            vec![
-                CssPropertyType::BorderTopColor,
-                CssPropertyType::BorderTopStyle,
-                CssPropertyType::BorderTopWidth,
-            ]
-        },
-        BorderBottom => {
-            vec![
-                CssPropertyType::BorderBottomColor,
-                CssPropertyType::BorderBottomStyle,
-                CssPropertyType::BorderBottomWidth,
-            ]
-        },
-        BoxShadow => {
-            vec![
-                CssPropertyType::BoxShadowLeft,
-                CssPropertyType::BoxShadowRight,
-                CssPropertyType::BoxShadowTop,
-                CssPropertyType::BoxShadowBottom,
-            ]
-        },
-        BackgroundColor => {
-            vec![
-                CssPropertyType::BackgroundContent,
-            ]
-        },
-        BackgroundImage => {
-            vec![
-                CssPropertyType::BackgroundContent,
-            ]
-        }
-    };
+    let keys = get_combined_css_property_longhands(key);
 
     match value {
         "auto" => return Ok(keys.into_iter().map(|ty| CssProperty::auto(ty)).collect()),
@@ -535,7 +474,159 @@ pub fn parse_combined_css_property<'a>(key: CombinedCssPropertyType, value: &'a
             Ok(vec![
                 CssProperty::BackgroundContent(vec.into()),
             ])
-        }
+        },
+        Gap => {
+            let (row_gap, column_gap) = parse_layout_gap(value)?;
+            Ok(vec![
+                CssProperty::RowGap(row_gap.into()),
+                CssProperty::ColumnGap(column_gap.into()),
+            ])
+        },
+        Transition => {
+            let transition = parse_style_transition(value)?;
+            Ok(vec![
+                CssProperty::TransitionProperty(transition.property.into()),
+                CssProperty::TransitionDuration(transition.duration.into()),
+                CssProperty::TransitionTimingFunction(transition.timing_function.into()),
+                CssProperty::TransitionDelay(transition.delay.into()),
+            ])
+        },
+        Animation => {
+            let animation = parse_style_animation(value)?;
+            Ok(vec![
+                CssProperty::AnimationName(animation.name.into()),
+                CssProperty::AnimationDuration(animation.duration.into()),
+                CssProperty::AnimationTimingFunction(animation.timing_function.into()),
+                CssProperty::AnimationIterationCount(animation.iteration_count.into()),
+                CssProperty::AnimationDirection(animation.direction.into()),
+                CssProperty::AnimationFillMode(animation.fill_mode.into()),
+                CssProperty::AnimationDelay(animation.delay.into()),
+            ])
+        },
+    }
+}
+
+/// Returns the longhand properties that a combined CSS property (shorthand) expands to
+pub fn get_combined_css_property_longhands(key: CombinedCssPropertyType) -> Vec<CssPropertyType> {
+    use self::CombinedCssPropertyType::*;
+    match key {
+        BorderRadius => {
+            vec![
+                CssPropertyType::BorderTopLeftRadius,
+                CssPropertyType::BorderTopRightRadius,
+                CssPropertyType::BorderBottomLeftRadius,
+                CssPropertyType::BorderBottomRightRadius,
+            ]
+        },
+        Overflow => {
+            vec![
+                CssPropertyType::OverflowX,
+                CssPropertyType::OverflowY,
+            ]
+        },
+        Padding => {
+            vec![
+                CssPropertyType::PaddingTop,
+                CssPropertyType::PaddingBottom,
+                CssPropertyType::PaddingLeft,
+                CssPropertyType::PaddingRight,
+            ]
+        },
+        Margin => {
+            vec![
+                CssPropertyType::MarginTop,
+                CssPropertyType::MarginBottom,
+                CssPropertyType::MarginLeft,
+                CssPropertyType::MarginRight,
+            ]
+        },
+        Border => {
+            vec![
+                CssPropertyType::BorderTopColor,
+                CssPropertyType::BorderRightColor,
+                CssPropertyType::BorderLeftColor,
+                CssPropertyType::BorderBottomColor,
+                CssPropertyType::BorderTopStyle,
+                CssPropertyType::BorderRightStyle,
+                CssPropertyType::BorderLeftStyle,
+                CssPropertyType::BorderBottomStyle,
+                CssPropertyType::BorderTopWidth,
+                CssPropertyType::BorderRightWidth,
+                CssPropertyType::BorderLeftWidth,
+                CssPropertyType::BorderBottomWidth,
+            ]
+        },
+        BorderLeft => {
+            vec![
+                CssPropertyType::BorderLeftColor,
+                CssPropertyType::BorderLeftStyle,
+                CssPropertyType::BorderLeftWidth,
+            ]
+        },
+        BorderRight => {
+            vec![
+                CssPropertyType::BorderRightColor,
+                CssPropertyType::BorderRightStyle,
+                CssPropertyType::BorderRightWidth,
+            ]
+        },
+        BorderTop => {
+            vec![
+                CssPropertyType::BorderTopColor,
+                CssPropertyType::BorderTopStyle,
+                CssPropertyType::BorderTopWidth,
+            ]
+        },
+        BorderBottom => {
+            vec![
+                CssPropertyType::BorderBottomColor,
+                CssPropertyType::BorderBottomStyle,
+                CssPropertyType::BorderBottomWidth,
+            ]
+        },
+        BoxShadow => {
+            vec![
+                CssPropertyType::BoxShadowLeft,
+                CssPropertyType::BoxShadowRight,
+                CssPropertyType::BoxShadowTop,
+                CssPropertyType::BoxShadowBottom,
+            ]
+        },
+        BackgroundColor => {
+            vec![
+                CssPropertyType::BackgroundContent,
+            ]
+        },
+        BackgroundImage => {
+            vec![
+                CssPropertyType::BackgroundContent,
+            ]
+        },
+        Gap => {
+            vec![
+                CssPropertyType::RowGap,
+                CssPropertyType::ColumnGap,
+            ]
+        },
+        Transition => {
+            vec![
+                CssPropertyType::TransitionProperty,
+                CssPropertyType::TransitionDuration,
+                CssPropertyType::TransitionTimingFunction,
+                CssPropertyType::TransitionDelay,
+            ]
+        },
+        Animation => {
+            vec![
+                CssPropertyType::AnimationName,
+                CssPropertyType::AnimationDuration,
+                CssPropertyType::AnimationTimingFunction,
+                CssPropertyType::AnimationIterationCount,
+                CssPropertyType::AnimationDirection,
+                CssPropertyType::AnimationFillMode,
+                CssPropertyType::AnimationDelay,
+            ]
+        },
     }
 }
 
@@ -564,6 +655,9 @@ pub enum CssParsingError<'a> {
     PerspectiveOriginParseError(CssStylePerspectiveOriginParseError<'a>),
     Opacity(OpacityParseError<'a>),
     Scrollbar(CssScrollbarStyleParseError<'a>),
+    Grid(CssGridParseError<'a>),
+    Transition(CssTransitionParseError<'a>),
+    Animation(CssAnimationParseError<'a>),
 }
 
 impl_debug_as_display!(CssParsingError<'a>);
@@ -588,6 +682,9 @@ impl_display!{ CssParsingError<'a>, {
     PerspectiveOriginParseError(e) => format!("{}", e),
     Opacity(e) => format!("{}", e),
     Scrollbar(e) => format!("{}", e),
+    Grid(e) => format!("{}", e),
+    Transition(e) => format!("{}", e),
+    Animation(e) => format!("{}", e),
 }}
 
 impl_from!(CssBorderParseError<'a>, CssParsingError::CssBorderParseError);
@@ -609,6 +706,9 @@ impl_from!(CssStyleTransformOriginParseError<'a>, CssParsingError::TransformOrig
 impl_from!(CssStylePerspectiveOriginParseError<'a>, CssParsingError::PerspectiveOriginParseError);
 impl_from!(OpacityParseError<'a>, CssParsingError::Opacity);
 impl_from!(CssScrollbarStyleParseError<'a>, CssParsingError::Scrollbar);
+impl_from!(CssGridParseError<'a>, CssParsingError::Grid);
+impl_from!(CssTransitionParseError<'a>, CssParsingError::Transition);
+impl_from!(CssAnimationParseError<'a>, CssParsingError::Animation);
 
 impl<'a> From<PercentageParseError> for CssParsingError<'a> {
     fn from(e: PercentageParseError) -> Self {
@@ -900,6 +1000,12 @@ fn parse_pixel_value_inner<'a>(input: &'a str, match_values: &[(&'static str, Si
 
 pub fn parse_pixel_value<'a>(input: &'a str)
 -> Result<PixelValue, CssPixelValueParseError<'a>> {
+
+    // zero is the only length that doesn't need a unit ("margin: 0 auto")
+    if input.trim() == "0" {
+        return Ok(PixelValue::px(0.0));
+    }
+
     parse_pixel_value_inner(input, &[
         ("px", SizeMetric::Px),
         ("em", SizeMetric::Em),
@@ -2254,6 +2360,32 @@ fn split_string_respect_comma<'a>(input: &'a str) -> Vec<&'a str> {
     comma_separated_items
 }
 
+/// Same as `split_string_respect_comma`, but splits on whitespace: `"1fr minmax(10px, 1fr)"` => `["1fr", "minmax(10px, 1fr)"]`
+fn split_string_respect_whitespace<'a>(input: &'a str) -> Vec<&'a str> {
+    let mut items = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = None;
+
+    for (idx, ch) in input.char_indices() {
+        match ch {
+            '(' => { depth += 1; if start.is_none() { start = Some(idx); } },
+            ')' => { depth = depth.saturating_sub(1); },
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(s) = start.take() {
+                    items.push(&input[s..idx]);
+                }
+            },
+            _ => { if start.is_none() { start = Some(idx); } },
+        }
+    }
+
+    if let Some(s) = start {
+        items.push(&input[s..]);
+    }
+
+    items
+}
+
 // parses a single gradient such as "to right, 50px"
 pub fn parse_gradient<'a>(input: &'a str, background_type: GradientType)
 -> Result<StyleBackgroundContent, CssBackgroundParseError<'a>>
@@ -2720,6 +2852,185 @@ pub fn parse_layout_flex_shrink<'a>(input: &'a str) -> Result<LayoutFlexShrink,
     }
 }
 
+#[derive(Clone, PartialEq)]
+pub enum CssGridParseError<'a> {
+    EmptyTrackList,
+    InvalidTrackSize(&'a str),
+    InvalidMinMax(&'a str),
+    InvalidRepeat(&'a str),
+    InvalidRepeatCount(&'a str),
+    InvalidLine(&'a str),
+    TooManyValues(&'a str),
+    InvalidParenthesis(ParenthesisParseError<'a>),
+    PixelValue(CssPixelValueParseError<'a>),
+}
+
+impl_debug_as_display!(CssGridParseError<'a>);
+impl_display!{ CssGridParseError<'a>, {
+    EmptyTrackList => format!("Grid track list is empty"),
+    InvalidTrackSize(s) => format!("Invalid grid track size: \"{}\"", s),
+    InvalidMinMax(s) => format!("Invalid minmax(), expected two comma-separated values: \"{}\"", s),
+    InvalidRepeat(s) => format!("Invalid repeat(), expected a count and a track list: \"{}\"", s),
+    InvalidRepeatCount(s) => format!("Invalid repeat() count, expected a positive integer: \"{}\"", s),
+    InvalidLine(s) => format!("Invalid grid line, expected \"auto\", a non-zero integer or \"span <n>\": \"{}\"", s),
+    TooManyValues(s) => format!("Too many values: \"{}\"", s),
+    InvalidParenthesis(e) => format!("{}", e),
+    PixelValue(e) => format!("{}", e),
+}}
+
+impl_from!(ParenthesisParseError<'a>, CssGridParseError::InvalidParenthesis);
+impl_from!(CssPixelValueParseError<'a>, CssGridParseError::PixelValue);
+
+/// Parses a single track breadth, i.e. `100px`, `1fr` or `auto`
+pub fn parse_grid_track_breadth<'a>(input: &'a str) -> Result<GridTrackBreadth, CssGridParseError<'a>> {
+    let input = input.trim();
+    if input == "auto" {
+        Ok(GridTrackBreadth::Auto)
+    } else if input.ends_with("fr") {
+        let number = &input[..input.len() - 2];
+        let number = number.trim().parse::<f32>().map_err(|_| CssGridParseError::InvalidTrackSize(input))?;
+        if number < 0.0 { return Err(CssGridParseError::InvalidTrackSize(input)); }
+        Ok(GridTrackBreadth::Fraction(FloatValue::new(number)))
+    } else {
+        Ok(GridTrackBreadth::Fixed(parse_pixel_value(input)?))
+    }
+}
+
+/// Parses a single track size, i.e. `100px`, `1fr`, `auto` or `minmax(100px, 1fr)`
+pub fn parse_grid_track_sizing<'a>(input: &'a str) -> Result<GridTrackSizing, CssGridParseError<'a>> {
+    let input = input.trim();
+
+    if input.starts_with("minmax(") {
+        let (_, inner) = parse_parentheses(input, &["minmax"])?;
+        let values = split_string_respect_comma(inner);
+        if values.len() != 2 {
+            return Err(CssGridParseError::InvalidMinMax(input));
+        }
+        let min = parse_grid_track_breadth(values[0])?;
+        let max = parse_grid_track_breadth(values[1])?;
+        // a flexible minimum is invalid, see CSS Grid 7.2.3
+        if let GridTrackBreadth::Fraction(_) = min {
+            return Err(CssGridParseError::InvalidMinMax(input));
+        }
+        return Ok(GridTrackSizing::MinMax(GridMinMax { min, max }));
+    }
+
+    Ok(match parse_grid_track_breadth(input)? {
+        GridTrackBreadth::Fixed(p) => GridTrackSizing::Fixed(p),
+        GridTrackBreadth::Fraction(f) => GridTrackSizing::Fraction(f),
+        GridTrackBreadth::Auto => GridTrackSizing::Auto,
+    })
+}
+
+/// Parses a whitespace-separated track list such as `100px repeat(2, 1fr) minmax(50px, auto)`,
+/// `repeat()` is expanded into the individual tracks.
+pub fn parse_grid_track_list<'a>(input: &'a str) -> Result<GridTrackSizingVec, CssGridParseError<'a>> {
+
+    fn parse_grid_track_list_inner<'a>(input: &'a str, tracks: &mut Vec<GridTrackSizing>) -> Result<(), CssGridParseError<'a>> {
+        for item in split_string_respect_whitespace(input) {
+            if item.starts_with("repeat(") {
+                let (_, inner) = parse_parentheses(item, &["repeat"])?;
+                let values = split_string_respect_comma(inner);
+                if values.len() != 2 {
+                    return Err(CssGridParseError::InvalidRepeat(item));
+                }
+                let count = values[0].trim().parse::<usize>().map_err(|_| CssGridParseError::InvalidRepeatCount(values[0]))?;
+                if count == 0 {
+                    return Err(CssGridParseError::InvalidRepeatCount(values[0]));
+                }
+                let mut repeated = Vec::new();
+                parse_grid_track_list_inner(values[1], &mut repeated)?;
+                if repeated.is_empty() {
+                    return Err(CssGridParseError::InvalidRepeat(item));
+                }
+                for _ in 0..count {
+                    tracks.extend(repeated.iter().cloned());
+                }
+            } else {
+                tracks.push(parse_grid_track_sizing(item)?);
+            }
+        }
+        Ok(())
+    }
+
+    let mut tracks = Vec::new();
+    parse_grid_track_list_inner(input, &mut tracks)?;
+    if tracks.is_empty() {
+        return Err(CssGridParseError::EmptyTrackList);
+    }
+    Ok(tracks.into())
+}
+
+pub fn parse_layout_grid_template_columns<'a>(input: &'a str) -> Result<LayoutGridTemplateColumns, CssGridParseError<'a>> {
+    Ok(LayoutGridTemplateColumns { tracks: parse_grid_track_list(input)? })
+}
+
+pub fn parse_layout_grid_template_rows<'a>(input: &'a str) -> Result<LayoutGridTemplateRows, CssGridParseError<'a>> {
+    Ok(LayoutGridTemplateRows { tracks: parse_grid_track_list(input)? })
+}
+
+/// Parses one end of a grid placement: `auto`, `2`, `-1` or `span 2`
+pub fn parse_grid_line<'a>(input: &'a str) -> Result<GridLine, CssGridParseError<'a>> {
+    let input = input.trim();
+    if input == "auto" {
+        return Ok(GridLine::Auto);
+    }
+
+    if input.starts_with("span") {
+        let count = input["span".len()..].trim();
+        return match count.parse::<u32>() {
+            Ok(c) if c > 0 => Ok(GridLine::Span(c)),
+            _ => Err(CssGridParseError::InvalidLine(input)),
+        };
+    }
+
+    match input.parse::<i32>() {
+        Ok(l) if l != 0 => Ok(GridLine::Line(l)),
+        _ => Err(CssGridParseError::InvalidLine(input)),
+    }
+}
+
+/// Parses a `start / end` pair, if the end is omitted it defaults to `auto`
+fn parse_grid_placement<'a>(input: &'a str) -> Result<(GridLine, GridLine), CssGridParseError<'a>> {
+    let mut iter = input.split('/');
+    let start = parse_grid_line(iter.next().unwrap_or(""))?;
+    let end = match iter.next() {
+        Some(s) => parse_grid_line(s)?,
+        None => GridLine::Auto,
+    };
+    if iter.next().is_some() {
+        return Err(CssGridParseError::TooManyValues(input));
+    }
+    Ok((start, end))
+}
+
+pub fn parse_layout_grid_column<'a>(input: &'a str) -> Result<LayoutGridColumn, CssGridParseError<'a>> {
+    let (start, end) = parse_grid_placement(input)?;
+    Ok(LayoutGridColumn { start, end })
+}
+
+pub fn parse_layout_grid_row<'a>(input: &'a str) -> Result<LayoutGridRow, CssGridParseError<'a>> {
+    let (start, end) = parse_grid_placement(input)?;
+    Ok(LayoutGridRow { start, end })
+}
+
+typed_pixel_value_parser!(parse_layout_column_gap, LayoutColumnGap);
+typed_pixel_value_parser!(parse_layout_row_gap, LayoutRowGap);
+
+/// Parses the `gap` shorthand: `10px` (both) or `10px 20px` (row, column)
+pub fn parse_layout_gap<'a>(input: &'a str) -> Result<(LayoutRowGap, LayoutColumnGap), CssGridParseError<'a>> {
+    let mut iter = input.split_whitespace();
+    let row = parse_pixel_value(iter.next().unwrap_or(""))?;
+    let column = match iter.next() {
+        Some(s) => parse_pixel_value(s)?,
+        None => row,
+    };
+    if iter.next().is_some() {
+        return Err(CssGridParseError::TooManyValues(input));
+    }
+    Ok((LayoutRowGap { inner: row }, LayoutColumnGap { inner: column }))
+}
+
 pub fn parse_style_tab_width(input: &str)
 -> Result<StyleTabWidth, PercentageParseError>
 {
@@ -2751,6 +3062,459 @@ pub fn parse_style_opacity<'a>(input: &'a str)
     .and_then(|e| Ok(StyleOpacity { inner: e }))
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssTransitionParseError<'a> {
+    InvalidProperty(&'a str),
+    InvalidTime(&'a str),
+    InvalidTimingFunction(&'a str),
+    InvalidCubicBezier(&'a str),
+    TooManyValues(&'a str),
+}
+
+impl_display!{CssTransitionParseError<'a>, {
+    InvalidProperty(e) => format!("Invalid transition-property, expected \"all\" or a CSS property name, got: \"{}\"", e),
+    InvalidTime(e) => format!("Invalid time value, expected a value like \"0.3s\" or \"300ms\", got: \"{}\"", e),
+    InvalidTimingFunction(e) => format!("Invalid transition-timing-function: \"{}\"", e),
+    InvalidCubicBezier(e) => format!("Invalid cubic-bezier(x1, y1, x2, y2) function: \"{}\"", e),
+    TooManyValues(e) => format!("Too many values in transition: \"{}\"", e),
+}}
+
+/// Parsed `transition` shorthand, one entry per comma-separated transition
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleTransition {
+    pub property: StyleTransitionPropertyVec,
+    pub duration: StyleTransitionDurationVec,
+    pub timing_function: StyleTransitionTimingFunctionVec,
+    pub delay: StyleTransitionDelayVec,
+}
+
+/// Parses a CSS time value (`"0.3s"` or `"300ms"`) into milliseconds
+///
+/// ```rust
+/// # use azul_css_parser::parse_css_time_ms;
+/// assert_eq!(parse_css_time_ms("300ms"), Ok(300));
+/// assert_eq!(parse_css_time_ms("0.5s"), Ok(500));
+/// assert!(parse_css_time_ms("5px").is_err());
+/// ```
+pub fn parse_css_time_ms<'a>(input: &'a str) -> Result<u32, CssTransitionParseError<'a>> {
+    let input = input.trim();
+    let (number, factor) = if input.ends_with("ms") {
+        (&input[..input.len() - 2], 1.0)
+    } else if input.ends_with('s') {
+        (&input[..input.len() - 1], 1000.0)
+    } else {
+        return Err(CssTransitionParseError::InvalidTime(input));
+    };
+
+    match number.trim().parse::<f32>() {
+        Ok(n) if n >= 0.0 => Ok((n * factor).round() as u32),
+        _ => Err(CssTransitionParseError::InvalidTime(input)),
+    }
+}
+
+/// Parses one entry of a `transition-property`: `all` or a property name.
+///
+/// Shorthand names (such as `background-color` or `border`) expand to their longhands.
+fn parse_style_transition_property<'a>(input: &'a str) -> Result<Vec<StyleTransitionProperty>, CssTransitionParseError<'a>> {
+    let input = input.trim();
+    if input == "all" {
+        return Ok(vec![StyleTransitionProperty::All]);
+    }
+
+    let map = get_css_key_map();
+    if let Some(ty) = CssPropertyType::from_str(input, &map) {
+        return Ok(vec![StyleTransitionProperty::Property(ty)]);
+    }
+
+    match CombinedCssPropertyType::from_str(input, &map) {
+        Some(combined) => Ok(get_combined_css_property_longhands(combined).into_iter().map(StyleTransitionProperty::Property).collect()),
+        None => Err(CssTransitionParseError::InvalidProperty(input)),
+    }
+}
+
+/// Parses a `transition-property` attribute, i.e. `opacity, background-color`
+pub fn parse_style_transition_property_multiple<'a>(input: &'a str) -> Result<StyleTransitionPropertyVec, CssTransitionParseError<'a>> {
+    let mut properties = Vec::new();
+    for item in input.split(',') {
+        properties.extend(parse_style_transition_property(item)?);
+    }
+    Ok(properties.into())
+}
+
+/// Parses a `transition-duration` attribute, i.e. `0.3s, 100ms`
+pub fn parse_style_transition_duration_multiple<'a>(input: &'a str) -> Result<StyleTransitionDurationVec, CssTransitionParseError<'a>> {
+    input.split(',')
+    .map(|i| parse_css_time_ms(i).map(StyleTransitionDuration::ms))
+    .collect::<Result<Vec<_>, _>>()
+    .map(|v| v.into())
+}
+
+/// Parses a `transition-delay` attribute, i.e. `0s, 50ms`
+pub fn parse_style_transition_delay_multiple<'a>(input: &'a str) -> Result<StyleTransitionDelayVec, CssTransitionParseError<'a>> {
+    input.split(',')
+    .map(|i| parse_css_time_ms(i).map(StyleTransitionDelay::ms))
+    .collect::<Result<Vec<_>, _>>()
+    .map(|v| v.into())
+}
+
+/// Parses a single `transition-timing-function`: one of the keywords
+/// `ease`, `linear`, `ease-in`, `ease-out`, `ease-in-out` or a `cubic-bezier(x1, y1, x2, y2)`
+///
+/// ```rust
+/// # extern crate azul_css;
+/// # extern crate azul_css_parser;
+/// # use azul_css_parser::parse_style_transition_timing_function;
+/// # use azul_css::{StyleTransitionTimingFunction, StyleCubicBezier, FloatValue};
+/// assert_eq!(parse_style_transition_timing_function("ease-in"), Ok(StyleTransitionTimingFunction::EaseIn));
+/// assert_eq!(
+///     parse_style_transition_timing_function("cubic-bezier(0.1, 0.7, 1.0, 0.1)"),
+///     Ok(StyleTransitionTimingFunction::CubicBezier(StyleCubicBezier {
+///         x1: FloatValue::new(0.1),
+///         y1: FloatValue::new(0.7),
+///         x2: FloatValue::new(1.0),
+///         y2: FloatValue::new(0.1),
+///     }))
+/// );
+/// ```
+pub fn parse_style_transition_timing_function<'a>(input: &'a str) -> Result<StyleTransitionTimingFunction, CssTransitionParseError<'a>> {
+    let input = input.trim();
+    match input {
+        "ease" => return Ok(StyleTransitionTimingFunction::Ease),
+        "linear" => return Ok(StyleTransitionTimingFunction::Linear),
+        "ease-in" => return Ok(StyleTransitionTimingFunction::EaseIn),
+        "ease-out" => return Ok(StyleTransitionTimingFunction::EaseOut),
+        "ease-in-out" => return Ok(StyleTransitionTimingFunction::EaseInOut),
+        _ => { },
+    }
+
+    let (_, args) = parse_parentheses(input, &["cubic-bezier"])
+        .map_err(|_| CssTransitionParseError::InvalidTimingFunction(input))?;
+
+    let points = args.split(',')
+        .map(|a| parse_float_value(a))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| CssTransitionParseError::InvalidCubicBezier(input))?;
+
+    match points.as_slice() {
+        // the x coordinates have to be in the [0, 1] range, otherwise the curve is not a function of time
+        [x1, y1, x2, y2] if (0.0..=1.0).contains(&x1.get()) && (0.0..=1.0).contains(&x2.get()) => {
+            Ok(StyleTransitionTimingFunction::CubicBezier(StyleCubicBezier { x1: *x1, y1: *y1, x2: *x2, y2: *y2 }))
+        },
+        _ => Err(CssTransitionParseError::InvalidCubicBezier(input)),
+    }
+}
+
+/// Parses a `transition-timing-function` attribute, i.e. `ease-in, cubic-bezier(0.1, 0.7, 1.0, 0.1)`
+pub fn parse_style_transition_timing_function_multiple<'a>(input: &'a str) -> Result<StyleTransitionTimingFunctionVec, CssTransitionParseError<'a>> {
+    split_string_respect_comma(input).into_iter()
+    .map(|i| parse_style_transition_timing_function(i))
+    .collect::<Result<Vec<_>, _>>()
+    .map(|v| v.into())
+}
+
+/// Splits a string by whitespace, but not inside of parentheses,
+/// so that `cubic-bezier(0, 0, 1, 1) 1s` yields two items
+fn split_whitespace_respect_parentheses<'a>(input: &'a str) -> Vec<&'a str> {
+    let mut items = Vec::new();
+    let mut depth = 0_usize;
+    let mut item_start = None;
+
+    for (idx, ch) in input.char_indices() {
+        match ch {
+            '(' => { depth += 1; },
+            ')' => { depth = depth.saturating_sub(1); },
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(start) = item_start.take() {
+                    items.push(&input[start..idx]);
+                }
+                continue;
+            },
+            _ => { },
+        }
+        if item_start.is_none() {
+            item_start = Some(idx);
+        }
+    }
+
+    if let Some(start) = item_start {
+        items.push(&input[start..]);
+    }
+
+    items
+}
+
+/// Parses the `transition` shorthand, i.e. `opacity 0.3s ease-in, background-color 1s 100ms`
+///
+/// Each comma-separated entry consists of (in any order) an optional property (default: `all`),
+/// an optional timing function (default: `ease`) and up to two times: the first one is the
+/// duration, the second one is the delay.
+pub fn parse_style_transition<'a>(input: &'a str) -> Result<StyleTransition, CssTransitionParseError<'a>> {
+    let mut property = Vec::new();
+    let mut duration = Vec::new();
+    let mut timing_function = Vec::new();
+    let mut delay = Vec::new();
+
+    for item in split_string_respect_comma(input) {
+        let mut item_property = None;
+        let mut item_duration = None;
+        let mut item_timing_function = None;
+        let mut item_delay = None;
+
+        for component in split_whitespace_respect_parentheses(item) {
+            if let Ok(ms) = parse_css_time_ms(component) {
+                if item_duration.is_none() {
+                    item_duration = Some(ms);
+                } else if item_delay.is_none() {
+                    item_delay = Some(ms);
+                } else {
+                    return Err(CssTransitionParseError::TooManyValues(item));
+                }
+            } else if let Ok(tf) = parse_style_transition_timing_function(component) {
+                if item_timing_function.is_some() {
+                    return Err(CssTransitionParseError::TooManyValues(item));
+                }
+                item_timing_function = Some(tf);
+            } else if item_property.is_none() {
+                item_property = Some(parse_style_transition_property(component)?);
+            } else {
+                return Err(CssTransitionParseError::TooManyValues(item));
+            }
+        }
+
+        // Every longhand gets one entry per transition, so that the lists stay aligned
+        for p in item_property.unwrap_or_else(|| vec![StyleTransitionProperty::All]) {
+            property.push(p);
+            duration.push(StyleTransitionDuration::ms(item_duration.unwrap_or(0)));
+            timing_function.push(item_timing_function.unwrap_or_default());
+            delay.push(StyleTransitionDelay::ms(item_delay.unwrap_or(0)));
+        }
+    }
+
+    Ok(StyleTransition {
+        property: property.into(),
+        duration: duration.into(),
+        timing_function: timing_function.into(),
+        delay: delay.into(),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssAnimationParseError<'a> {
+    InvalidName(&'a str),
+    InvalidTime(&'a str),
+    InvalidTimingFunction(&'a str),
+    InvalidIterationCount(&'a str),
+    InvalidDirection(&'a str),
+    InvalidFillMode(&'a str),
+    TooManyValues(&'a str),
+}
+
+impl_display!{CssAnimationParseError<'a>, {
+    InvalidName(e) => format!("Invalid animation-name, expected \"none\" or a @keyframes name, got: \"{}\"", e),
+    InvalidTime(e) => format!("Invalid time value, expected a value like \"0.3s\" or \"300ms\", got: \"{}\"", e),
+    InvalidTimingFunction(e) => format!("Invalid animation-timing-function: \"{}\"", e),
+    InvalidIterationCount(e) => format!("Invalid animation-iteration-count, expected \"infinite\" or a positive number, got: \"{}\"", e),
+    InvalidDirection(e) => format!("Invalid animation-direction, expected \"normal\", \"reverse\", \"alternate\" or \"alternate-reverse\", got: \"{}\"", e),
+    InvalidFillMode(e) => format!("Invalid animation-fill-mode, expected \"none\", \"forwards\", \"backwards\" or \"both\", got: \"{}\"", e),
+    TooManyValues(e) => format!("Too many values in animation: \"{}\"", e),
+}}
+
+/// Parsed `animation` shorthand, one entry per comma-separated animation
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleAnimation {
+    pub name: StyleAnimationNameVec,
+    pub duration: StyleAnimationDurationVec,
+    pub timing_function: StyleTransitionTimingFunctionVec,
+    pub iteration_count: StyleAnimationIterationCountVec,
+    pub direction: StyleAnimationDirectionVec,
+    pub fill_mode: StyleAnimationFillModeVec,
+    pub delay: StyleAnimationDelayVec,
+}
+
+/// Parses one entry of an `animation-name`: a (possibly quoted) `@keyframes` name.
+/// `none` is returned as `None`.
+fn parse_style_animation_name<'a>(input: &'a str) -> Result<Option<StyleAnimationName>, CssAnimationParseError<'a>> {
+    let input = input.trim();
+    if input == "none" {
+        return Ok(None);
+    }
+
+    let name = strip_quotes(input).map(|q| q.0).unwrap_or(input);
+    let is_valid_ident = !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+    if !is_valid_ident {
+        return Err(CssAnimationParseError::InvalidName(input));
+    }
+
+    Ok(Some(StyleAnimationName { name: String::from(name).into() }))
+}
+
+/// Parses an `animation-name` attribute, i.e. `spin, pulse` - `none` entries are skipped
+pub fn parse_style_animation_name_multiple<'a>(input: &'a str) -> Result<StyleAnimationNameVec, CssAnimationParseError<'a>> {
+    let mut names = Vec::new();
+    for item in split_string_respect_comma(input) {
+        names.extend(parse_style_animation_name(item)?);
+    }
+    Ok(names.into())
+}
+
+/// Parses an `animation-duration` attribute, i.e. `1s, 250ms`
+pub fn parse_style_animation_duration_multiple<'a>(input: &'a str) -> Result<StyleAnimationDurationVec, CssAnimationParseError<'a>> {
+    split_string_respect_comma(input).into_iter()
+    .map(|i| parse_css_time_ms(i).map(StyleAnimationDuration::ms).map_err(|_| CssAnimationParseError::InvalidTime(i)))
+    .collect::<Result<Vec<_>, _>>()
+    .map(|v| v.into())
+}
+
+/// Parses an `animation-delay` attribute, i.e. `0s, 500ms`
+pub fn parse_style_animation_delay_multiple<'a>(input: &'a str) -> Result<StyleAnimationDelayVec, CssAnimationParseError<'a>> {
+    split_string_respect_comma(input).into_iter()
+    .map(|i| parse_css_time_ms(i).map(StyleAnimationDelay::ms).map_err(|_| CssAnimationParseError::InvalidTime(i)))
+    .collect::<Result<Vec<_>, _>>()
+    .map(|v| v.into())
+}
+
+/// Parses an `animation-timing-function` attribute, accepts the same values as `transition-timing-function`
+pub fn parse_style_animation_timing_function_multiple<'a>(input: &'a str) -> Result<StyleTransitionTimingFunctionVec, CssAnimationParseError<'a>> {
+    split_string_respect_comma(input).into_iter()
+    .map(|i| parse_style_transition_timing_function(i).map_err(|_| CssAnimationParseError::InvalidTimingFunction(i)))
+    .collect::<Result<Vec<_>, _>>()
+    .map(|v| v.into())
+}
+
+/// Parses a single `animation-iteration-count`: `infinite` or a positive whole number
+pub fn parse_style_animation_iteration_count<'a>(input: &'a str) -> Result<StyleAnimationIterationCount, CssAnimationParseError<'a>> {
+    let input = input.trim();
+    match input {
+        "infinite" => Ok(StyleAnimationIterationCount::Infinite),
+        other => other.parse::<u32>()
+            .map(StyleAnimationIterationCount::Count)
+            .map_err(|_| CssAnimationParseError::InvalidIterationCount(input)),
+    }
+}
+
+/// Parses an `animation-iteration-count` attribute, i.e. `infinite, 3`
+pub fn parse_style_animation_iteration_count_multiple<'a>(input: &'a str) -> Result<StyleAnimationIterationCountVec, CssAnimationParseError<'a>> {
+    split_string_respect_comma(input).into_iter()
+    .map(|i| parse_style_animation_iteration_count(i))
+    .collect::<Result<Vec<_>, _>>()
+    .map(|v| v.into())
+}
+
+/// Parses a single `animation-direction`
+pub fn parse_style_animation_direction<'a>(input: &'a str) -> Result<StyleAnimationDirection, CssAnimationParseError<'a>> {
+    let input = input.trim();
+    match input {
+        "normal" => Ok(StyleAnimationDirection::Normal),
+        "reverse" => Ok(StyleAnimationDirection::Reverse),
+        "alternate" => Ok(StyleAnimationDirection::Alternate),
+        "alternate-reverse" => Ok(StyleAnimationDirection::AlternateReverse),
+        _ => Err(CssAnimationParseError::InvalidDirection(input)),
+    }
+}
+
+/// Parses an `animation-direction` attribute, i.e. `alternate, normal`
+pub fn parse_style_animation_direction_multiple<'a>(input: &'a str) -> Result<StyleAnimationDirectionVec, CssAnimationParseError<'a>> {
+    split_string_respect_comma(input).into_iter()
+    .map(|i| parse_style_animation_direction(i))
+    .collect::<Result<Vec<_>, _>>()
+    .map(|v| v.into())
+}
+
+/// Parses a single `animation-fill-mode`
+pub fn parse_style_animation_fill_mode<'a>(input: &'a str) -> Result<StyleAnimationFillMode, CssAnimationParseError<'a>> {
+    let input = input.trim();
+    match input {
+        "none" => Ok(StyleAnimationFillMode::None),
+        "forwards" => Ok(StyleAnimationFillMode::Forwards),
+        "backwards" => Ok(StyleAnimationFillMode::Backwards),
+        "both" => Ok(StyleAnimationFillMode::Both),
+        _ => Err(CssAnimationParseError::InvalidFillMode(input)),
+    }
+}
+
+/// Parses an `animation-fill-mode` attribute, i.e. `forwards, none`
+pub fn parse_style_animation_fill_mode_multiple<'a>(input: &'a str) -> Result<StyleAnimationFillModeVec, CssAnimationParseError<'a>> {
+    split_string_respect_comma(input).into_iter()
+    .map(|i| parse_style_animation_fill_mode(i))
+    .collect::<Result<Vec<_>, _>>()
+    .map(|v| v.into())
+}
+
+/// Parses the `animation` shorthand, i.e. `spin 1s linear infinite, pulse 2s ease-in-out 500ms`
+///
+/// Each comma-separated entry consists of (in any order) up to two times (the first one
+/// is the duration, the second one is the delay), a timing function, an iteration count,
+/// a direction, a fill mode and the name of the `@keyframes` rule. Keywords are matched
+/// before the name, so `none` is read as the fill mode unless the fill mode was already given.
+pub fn parse_style_animation<'a>(input: &'a str) -> Result<StyleAnimation, CssAnimationParseError<'a>> {
+    let mut name = Vec::new();
+    let mut duration = Vec::new();
+    let mut timing_function = Vec::new();
+    let mut iteration_count = Vec::new();
+    let mut direction = Vec::new();
+    let mut fill_mode = Vec::new();
+    let mut delay = Vec::new();
+
+    for item in split_string_respect_comma(input) {
+        let mut item_name = None;
+        let mut item_duration = None;
+        let mut item_timing_function = None;
+        let mut item_iteration_count = None;
+        let mut item_direction = None;
+        let mut item_fill_mode = None;
+        let mut item_delay = None;
+
+        for component in split_whitespace_respect_parentheses(item) {
+            if let Ok(ms) = parse_css_time_ms(component) {
+                if item_duration.is_none() {
+                    item_duration = Some(ms);
+                } else if item_delay.is_none() {
+                    item_delay = Some(ms);
+                } else {
+                    return Err(CssAnimationParseError::TooManyValues(item));
+                }
+            } else if let (None, Ok(tf)) = (&item_timing_function, parse_style_transition_timing_function(component)) {
+                item_timing_function = Some(tf);
+            } else if let (None, Ok(ic)) = (&item_iteration_count, parse_style_animation_iteration_count(component)) {
+                item_iteration_count = Some(ic);
+            } else if let (None, Ok(d)) = (&item_direction, parse_style_animation_direction(component)) {
+                item_direction = Some(d);
+            } else if let (None, Ok(f)) = (&item_fill_mode, parse_style_animation_fill_mode(component)) {
+                item_fill_mode = Some(f);
+            } else if item_name.is_none() {
+                item_name = Some(parse_style_animation_name(component)?);
+            } else {
+                return Err(CssAnimationParseError::TooManyValues(item));
+            }
+        }
+
+        // `animation: none` does not start an animation, the other lists are
+        // cycled through by index, so they have to stay aligned with the names
+        let item_name = match item_name.and_then(|n| n) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        name.push(item_name);
+        duration.push(StyleAnimationDuration::ms(item_duration.unwrap_or(0)));
+        timing_function.push(item_timing_function.unwrap_or_default());
+        iteration_count.push(item_iteration_count.unwrap_or_default());
+        direction.push(item_direction.unwrap_or_default());
+        fill_mode.push(item_fill_mode.unwrap_or_default());
+        delay.push(StyleAnimationDelay::ms(item_delay.unwrap_or(0)));
+    }
+
+    Ok(StyleAnimation {
+        name: name.into(),
+        duration: duration.into(),
+        timing_function: timing_function.into(),
+        iteration_count: iteration_count.into(),
+        direction: direction.into(),
+        fill_mode: fill_mode.into(),
+        delay: delay.into(),
+    })
+}
+
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub enum CssStyleFontFamilyParseError<'a> {
     InvalidStyleFontFamily(&'a str),
@@ -2779,9 +3543,9 @@ impl<'a> From<UnclosedQuotesError<'a>> for CssStyleFontFamilyParseError<'a> {
 /// # use azul_css::{StyleFontFamily, StyleFontFamilyVec};
 /// let input = "\"Helvetica\", 'Arial', Times New Roman";
 /// let fonts: StyleFontFamilyVec = vec![
-///     StyleFontFamily::Native("Helvetica".into()),
-///     StyleFontFamily::Native("Arial".into()),
-///     StyleFontFamily::Native("Times New Roman".into()),
+///     StyleFontFamily::System("Helvetica".into()),
+///     StyleFontFamily::System("Arial".into()),
+///     StyleFontFamily::System("Times New Roman".into()),
 /// ].into();
 ///
 /// assert_eq!(parse_style_font_family(input), Ok(fonts));
@@ -2952,11 +3716,19 @@ multi_type_parser!(parse_layout_display, LayoutDisplay,
                     ["none", None],
                     ["flex", Flex],
                     ["block", Block],
-                    ["inline-block", InlineBlock]);
+                    ["inline-block", InlineBlock],
+                    ["grid", Grid]);
 
 multi_type_parser!(parse_layout_float, LayoutFloat,
                     ["left", Left],
-                    ["right", Right]);
+                    ["right", Right],
+                    ["none", None]);
+
+multi_type_parser!(parse_layout_clear, LayoutClear,
+                    ["none", None],
+                    ["left", Left],
+                    ["right", Right],
+                    ["both", Both]);
 
 multi_type_parser!(parse_layout_box_sizing, LayoutBoxSizing,
     ["content-box", ContentBox],
@@ -3732,6 +4504,39 @@ mod css_tests {
         assert_eq!(parse_css_color("hsla(60.9grad, 80.3%, 40%, 0.5)"), Ok(ColorU { r: 45, g: 20, b: 184, a: 128 }));
     }
 
+    #[test]
+    fn test_parse_grid_template_columns() {
+        assert_eq!(
+            parse_layout_grid_template_columns("100px repeat(2, 1fr) minmax(50px, auto) auto"),
+            Ok(LayoutGridTemplateColumns { tracks: vec![
+                GridTrackSizing::Fixed(PixelValue::px(100.0)),
+                GridTrackSizing::Fraction(FloatValue::new(1.0)),
+                GridTrackSizing::Fraction(FloatValue::new(1.0)),
+                GridTrackSizing::MinMax(GridMinMax {
+                    min: GridTrackBreadth::Fixed(PixelValue::px(50.0)),
+                    max: GridTrackBreadth::Auto,
+                }),
+                GridTrackSizing::Auto,
+            ].into() })
+        );
+        assert_eq!(parse_layout_grid_template_columns("repeat(0, 1fr)"), Err(CssGridParseError::InvalidRepeatCount("0")));
+        assert_eq!(parse_layout_grid_template_columns("minmax(1fr, 100px)"), Err(CssGridParseError::InvalidMinMax("minmax(1fr, 100px)")));
+    }
+
+    #[test]
+    fn test_parse_grid_placement() {
+        assert_eq!(parse_layout_grid_column("1 / 3"), Ok(LayoutGridColumn { start: GridLine::Line(1), end: GridLine::Line(3) }));
+        assert_eq!(parse_layout_grid_row("span 2"), Ok(LayoutGridRow { start: GridLine::Span(2), end: GridLine::Auto }));
+        assert_eq!(parse_layout_grid_row("2 / -1"), Ok(LayoutGridRow { start: GridLine::Line(2), end: GridLine::Line(-1) }));
+        assert_eq!(parse_layout_grid_column("0"), Err(CssGridParseError::InvalidLine("0")));
+    }
+
+    #[test]
+    fn test_parse_gap() {
+        assert_eq!(parse_layout_gap("10px"), Ok((LayoutRowGap::px(10.0), LayoutColumnGap::px(10.0))));
+        assert_eq!(parse_layout_gap("10px 5%"), Ok((LayoutRowGap::px(10.0), LayoutColumnGap::percent(5.0))));
+    }
+
     #[test]
     fn test_parse_transform() {
         assert_eq!(parse_style_transform("rotate(25deg)"), Ok(StyleTransform::Rotate(AngleValue::deg(25.0))));
@@ -3817,6 +4622,12 @@ mod css_tests {
         assert_eq!(parse_pixel_value("aslkfdjasdflk"), Err(CssPixelValueParseError::InvalidPixelValue("aslkfdjasdflk")));
     }
 
+    #[test]
+    fn test_parse_pixel_value_5() {
+        assert_eq!(parse_pixel_value("0"), Ok(PixelValue::px(0.0)));
+        assert_eq!(parse_pixel_value("5"), Err(CssPixelValueParseError::InvalidPixelValue("5")));
+    }
+
     #[test]
     fn test_parse_style_border_radius_1() {
         assert_eq!(
@@ -3874,8 +4685,8 @@ mod css_tests {
         use azul_css::{AzString, StringVec};
         use crate::alloc::string::ToString;
         let fonts0: Vec<StyleFontFamily> = vec![
-            StyleFontFamily::Native("Webly Sleeky UI".to_string().into()),
-            StyleFontFamily::Native("monospace".to_string().into()),
+            StyleFontFamily::System("Webly Sleeky UI".to_string().into()),
+            StyleFontFamily::System("monospace".to_string().into()),
         ];
         let fonts0: StyleFontFamilyVec = fonts0.into();
         assert_eq!(parse_style_font_family("\"Webly Sleeky UI\", monospace"), Ok(fonts0));
@@ -3886,7 +4697,7 @@ mod css_tests {
         use azul_css::{AzString, StringVec};
         use crate::alloc::string::ToString;
         let fonts0: Vec<StyleFontFamily> = vec![
-            StyleFontFamily::Native("Webly Sleeky UI".to_string().into()),
+            StyleFontFamily::System("Webly Sleeky UI".to_string().into()),
         ];
         let fonts0: StyleFontFamilyVec = fonts0.into();
         assert_eq!(parse_style_font_family("'Webly Sleeky UI'"), Ok(fonts0));
@@ -3992,4 +4803,58 @@ mod css_tests {
             Ok(AngleValue::grad(20.4))
         );
     }
+
+    #[test]
+    fn test_parse_style_transition_1() {
+        assert_eq!(
+            parse_style_transition("opacity 0.3s ease-in 50ms, background-color 1s"),
+            Ok(StyleTransition {
+                property: vec![
+                    StyleTransitionProperty::Property(CssPropertyType::Opacity),
+                    StyleTransitionProperty::Property(CssPropertyType::BackgroundContent),
+                ].into(),
+                duration: vec![StyleTransitionDuration::ms(300), StyleTransitionDuration::ms(1000)].into(),
+                timing_function: vec![StyleTransitionTimingFunction::EaseIn, StyleTransitionTimingFunction::Ease].into(),
+                delay: vec![StyleTransitionDelay::ms(50), StyleTransitionDelay::ms(0)].into(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_style_transition_2() {
+        let transition = parse_style_transition("cubic-bezier(0, 0, 0.5, 1) 200ms").unwrap();
+        assert_eq!(transition.property, vec![StyleTransitionProperty::All].into());
+        assert_eq!(transition.duration, vec![StyleTransitionDuration::ms(200)].into());
+        assert!(parse_style_transition("opacity width 1s").is_err());
+        assert!(parse_style_transition_timing_function("cubic-bezier(2, 0, 0.5, 1)").is_err());
+    }
+
+    #[test]
+    fn test_parse_style_animation_1() {
+        assert_eq!(
+            parse_style_animation("spin 1s linear infinite, pulse 2s ease-in-out 500ms alternate both"),
+            Ok(StyleAnimation {
+                name: vec![
+                    StyleAnimationName { name: "spin".to_string().into() },
+                    StyleAnimationName { name: "pulse".to_string().into() },
+                ].into(),
+                duration: vec![StyleAnimationDuration::ms(1000), StyleAnimationDuration::ms(2000)].into(),
+                timing_function: vec![StyleTransitionTimingFunction::Linear, StyleTransitionTimingFunction::EaseInOut].into(),
+                iteration_count: vec![StyleAnimationIterationCount::Infinite, StyleAnimationIterationCount::Count(1)].into(),
+                direction: vec![StyleAnimationDirection::Normal, StyleAnimationDirection::Alternate].into(),
+                fill_mode: vec![StyleAnimationFillMode::None, StyleAnimationFillMode::Both].into(),
+                delay: vec![StyleAnimationDelay::ms(0), StyleAnimationDelay::ms(500)].into(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_style_animation_2() {
+        assert_eq!(parse_style_animation("none").unwrap().name, Vec::new().into());
+        assert_eq!(parse_style_animation("3 \"fade-in\" 300ms").unwrap().iteration_count, vec![StyleAnimationIterationCount::Count(3)].into());
+        assert_eq!(parse_style_animation_name_multiple("none, spin").unwrap().len(), 1);
+        assert!(parse_style_animation("spin pulse 1s").is_err());
+        assert!(parse_style_animation_iteration_count("-1").is_err());
+        assert!(parse_style_animation_direction_multiple("normal, sideways").is_err());
+    }
 }
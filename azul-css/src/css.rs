@@ -1,5 +1,5 @@
 //! Types and methods used to describe the style of an application
-use crate::css_properties::{CssProperty, CssPropertyType};
+use crate::css_properties::{CssProperty, CssPropertyVec, CssPropertyType, StyleFontFamily, StyleFontFamilyVec, PixelValue, FloatValue};
 use crate::AzString;
 use core::fmt;
 use alloc::vec::Vec;
@@ -14,6 +14,10 @@ pub struct Css {
     /// For example, when overriding native styles, the `.sort_by_specificy()` function
     /// should not mix the two stylesheets during sorting.
     pub stylesheets: StylesheetVec,
+    /// Fonts declared via `@font-face` rules, see `Css::resolve_font_faces`
+    pub font_faces: CssFontFaceVec,
+    /// Animations declared via `@keyframes` rules, see `Css::get_keyframes`
+    pub keyframes: CssKeyframesVec,
 }
 
 impl_vec!(Stylesheet, StylesheetVec, StylesheetVecDestructor);
@@ -30,10 +34,97 @@ impl Css {
     }
 
     pub fn new(stylesheets: Vec<Stylesheet>) -> Self {
-        Self { stylesheets: stylesheets.into() }
+        Self { stylesheets: stylesheets.into(), font_faces: Vec::new().into(), keyframes: Vec::new().into() }
     }
 }
 
+/// Font file declared via an `@font-face` rule, i.e.:
+///
+/// ```no_run,ignore
+/// @font-face {
+///     font-family: "Brand Sans";
+///     src: url(fonts/BrandSans-Bold.ttf);
+///     font-weight: bold;
+///     font-style: normal;
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct CssFontFace {
+    /// Name under which the font can be referenced in `font-family` declarations
+    pub font_family: AzString,
+    /// Path to the font file (contents of the `url()` in the `src` declaration)
+    pub src: AzString,
+    /// Numeric weight of the font, `400` = normal, `700` = bold
+    pub font_weight: u16,
+    pub font_style: CssFontFaceStyle,
+}
+
+impl CssFontFace {
+    pub const WEIGHT_NORMAL: u16 = 400;
+    pub const WEIGHT_BOLD: u16 = 700;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum CssFontFaceStyle {
+    Normal,
+    Italic,
+    Oblique,
+}
+
+impl Default for CssFontFaceStyle {
+    fn default() -> Self { CssFontFaceStyle::Normal }
+}
+
+impl_vec!(CssFontFace, CssFontFaceVec, CssFontFaceVecDestructor);
+impl_vec_mut!(CssFontFace, CssFontFaceVec);
+impl_vec_debug!(CssFontFace, CssFontFaceVec);
+impl_vec_partialord!(CssFontFace, CssFontFaceVec);
+impl_vec_clone!(CssFontFace, CssFontFaceVec, CssFontFaceVecDestructor);
+impl_vec_partialeq!(CssFontFace, CssFontFaceVec);
+
+/// Multi-step animation declared via a `@keyframes` rule, i.e.:
+///
+/// ```no_run,ignore
+/// @keyframes pulse {
+///     0%   { opacity: 1; }
+///     50%  { opacity: 0.5; transform: scale(1.1); }
+///     100% { opacity: 1; }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct CssKeyframes {
+    /// Name under which the animation can be referenced in `animation-name` declarations
+    pub name: AzString,
+    /// Keyframes, sorted by their offset
+    pub keyframes: CssKeyframeVec,
+}
+
+/// Single step (i.e. `50% { opacity: 0.5; }`) of a `@keyframes` rule
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct CssKeyframe {
+    /// Position of the keyframe in the animation, `0.0` = `from` / `0%`, `1.0` = `to` / `100%`
+    pub offset: FloatValue,
+    pub properties: CssPropertyVec,
+}
+
+impl_vec!(CssKeyframe, CssKeyframeVec, CssKeyframeVecDestructor);
+impl_vec_mut!(CssKeyframe, CssKeyframeVec);
+impl_vec_debug!(CssKeyframe, CssKeyframeVec);
+impl_vec_partialord!(CssKeyframe, CssKeyframeVec);
+impl_vec_clone!(CssKeyframe, CssKeyframeVec, CssKeyframeVecDestructor);
+impl_vec_partialeq!(CssKeyframe, CssKeyframeVec);
+
+impl_vec!(CssKeyframes, CssKeyframesVec, CssKeyframesVecDestructor);
+impl_vec_mut!(CssKeyframes, CssKeyframesVec);
+impl_vec_debug!(CssKeyframes, CssKeyframesVec);
+impl_vec_partialord!(CssKeyframes, CssKeyframesVec);
+impl_vec_clone!(CssKeyframes, CssKeyframesVec, CssKeyframesVecDestructor);
+impl_vec_partialeq!(CssKeyframes, CssKeyframesVec);
+
 #[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
 #[repr(C)]
 pub struct Stylesheet {
@@ -68,6 +159,8 @@ pub enum CssDeclaration {
     Static(CssProperty),
     /// Dynamic key-value pair with default value, such as `width: [[ my_id | 500px ]]`
     Dynamic(DynamicCssProperty),
+    /// Custom property declaration, such as `--accent: #3366ff`
+    Custom(CssCustomProperty),
 }
 
 impl CssDeclaration {
@@ -80,12 +173,18 @@ impl CssDeclaration {
         CssDeclaration::Dynamic(prop)
     }
 
-    /// Returns the type of the property (i.e. the CSS key as a typed enum)
-    pub fn get_type(&self) -> CssPropertyType {
+    pub const fn new_custom(prop: CssCustomProperty) -> Self {
+        CssDeclaration::Custom(prop)
+    }
+
+    /// Returns the type of the property (i.e. the CSS key as a typed enum),
+    /// custom properties (`--accent`) do not have a type
+    pub fn get_type(&self) -> Option<CssPropertyType> {
         use self::CssDeclaration::*;
         match self {
-            Static(s) => s.get_type(),
-            Dynamic(d) => d.default_value.get_type(),
+            Static(s) => Some(s.get_type()),
+            Dynamic(d) => Some(d.default_value.get_type()),
+            Custom(_) => None,
         }
     }
 
@@ -96,6 +195,7 @@ impl CssDeclaration {
         match self {
             Static(s) => s.get_type().is_inheritable(),
             Dynamic(d) => d.is_inheritable(),
+            Custom(c) => c.is_inheritable(),
         }
     }
 
@@ -106,6 +206,7 @@ impl CssDeclaration {
         match self {
             Static(s) => s.get_type().can_trigger_relayout(),
             Dynamic(d) => d.can_trigger_relayout(),
+            Custom(c) => c.can_trigger_relayout(),
         }
     }
 
@@ -114,6 +215,7 @@ impl CssDeclaration {
         match self {
             Static(s) => format!("{:?}", s),
             Dynamic(d) => format!("var(--{}, {:?})", d.dynamic_id, d.default_value),
+            Custom(c) => format!("--{}: {}", c.name, c.value),
         }
     }
 }
@@ -147,6 +249,32 @@ pub struct DynamicCssProperty {
     pub default_value: CssProperty,
 }
 
+/// Custom property (CSS variable) declared in a stylesheet, i.e.:
+///
+/// ```no_run,ignore
+/// body {
+///    --accent: #3366ff;
+/// }
+///
+/// button {
+///    background: var(--accent);
+/// }
+/// ```
+///
+/// Custom properties are inherited by all child nodes. The value is stored unparsed,
+/// since its type is only known once a property references it via `var(--accent)`:
+/// the reference gets resolved (and parsed) when the DOM is styled, falling back to
+/// the default value of the `DynamicCssProperty` if the variable isn't declared
+/// or its value can't be parsed as the referencing property.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct CssCustomProperty {
+    /// Name of the variable without the leading dashes, i.e. `"accent"` for `--accent`
+    pub name: AzString,
+    /// Unparsed value of the variable, may contain `var()` references to other variables
+    pub value: AzString,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[repr(C, u8)] // necessary for ABI stability
 pub enum CssPropertyValue<T> {
@@ -284,6 +412,91 @@ impl DynamicCssProperty {
     }
 }
 
+impl CssCustomProperty {
+
+    /// Maximum depth of `var()` references inside of variable values,
+    /// protects against cycles such as `--a: var(--b); --b: var(--a);`
+    pub const MAX_SUBSTITUTION_DEPTH: usize = 16;
+
+    pub fn new(name: AzString, value: AzString) -> Self {
+        Self { name, value }
+    }
+
+    pub fn is_inheritable(&self) -> bool {
+        // custom properties are always inherited, see
+        // https://www.w3.org/TR/css-variables-1/#defining-variables
+        true
+    }
+
+    pub fn can_trigger_relayout(&self) -> bool {
+        // the type of the property that references the variable is unknown
+        true
+    }
+
+    /// Replaces all `var(--name)` / `var(--name, fallback)` references in the `value`
+    /// with the result of `lookup(name)` or the fallback, recursively.
+    ///
+    /// Returns `None` if a referenced variable is not declared and has no fallback
+    /// (or if the references are nested deeper than `MAX_SUBSTITUTION_DEPTH`)
+    pub fn substitute_var_references<F: Fn(&str) -> Option<String>>(value: &str, lookup: &F) -> Option<String> {
+        Self::substitute_var_references_inner(value, lookup, 0)
+    }
+
+    fn substitute_var_references_inner<F: Fn(&str) -> Option<String>>(value: &str, lookup: &F, depth: usize) -> Option<String> {
+
+        if depth > Self::MAX_SUBSTITUTION_DEPTH {
+            return None;
+        }
+
+        let mut result = String::with_capacity(value.len());
+        let mut rest = value;
+
+        while let Some(start) = rest.find("var(") {
+
+            result.push_str(&rest[..start]);
+            let after_var = &rest[(start + 4)..];
+
+            // find the matching closing brace (the fallback may contain braces, i.e. `rgb(...)`)
+            let mut brace_depth = 1_usize;
+            let mut end = None;
+            for (idx, c) in after_var.char_indices() {
+                match c {
+                    '(' => brace_depth += 1,
+                    ')' => {
+                        brace_depth -= 1;
+                        if brace_depth == 0 {
+                            end = Some(idx);
+                            break;
+                        }
+                    },
+                    _ => { },
+                }
+            }
+
+            let end = end?;
+            let brace_contents = &after_var[..end];
+            let mut split = brace_contents.splitn(2, ',');
+            let name = split.next()?.trim();
+            let fallback = split.next().map(|f| f.trim());
+
+            if !name.starts_with("--") {
+                return None;
+            }
+
+            let resolved = match lookup(&name[2..]) {
+                Some(s) => Some(s),
+                None => fallback.map(|f| f.to_string()),
+            }?;
+
+            result.push_str(&Self::substitute_var_references_inner(&resolved, lookup, depth + 1)?);
+            rest = &after_var[(end + 1)..];
+        }
+
+        result.push_str(rest);
+        Some(result)
+    }
+}
+
 /// One block of rules that applies a bunch of rules to a "path" in the style, i.e.
 /// `div#myid.myclass -> { ("justify-content", "center") }`
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
@@ -294,6 +507,9 @@ pub struct CssRuleBlock {
     /// `"justify-content: center"` =>
     /// `CssDeclaration::Static(CssProperty::JustifyContent(LayoutJustifyContent::Center))`
     pub declarations: CssDeclarationVec,
+    /// Queries of the `@media` rule that this block was declared in: the block only
+    /// applies if any of the queries matches. Empty if the block is not in a `@media` rule.
+    pub media_queries: CssMediaQueryVec,
 }
 
 impl_vec!(CssDeclaration, CssDeclarationVec, CssDeclarationVecDestructor);
@@ -311,6 +527,126 @@ impl CssRuleBlock {
         Self {
             path,
             declarations: declarations.into(),
+            media_queries: Vec::new().into(),
+        }
+    }
+
+    /// Returns whether the block applies in the given media context
+    /// (always true if the block isn't in a `@media` rule)
+    pub fn matches_media(&self, context: &CssMediaContext) -> bool {
+        self.media_queries.is_empty() || self.media_queries.iter().any(|q| q.matches(context))
+    }
+}
+
+/// One query of an `@media` rule, i.e. `(min-width: 600px) and (orientation: landscape)`:
+/// the query matches if all of its conditions match
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct CssMediaQuery {
+    pub conditions: CssMediaConditionVec,
+}
+
+impl CssMediaQuery {
+    pub fn matches(&self, context: &CssMediaContext) -> bool {
+        self.conditions.iter().all(|c| c.matches(context))
+    }
+}
+
+/// Media feature of a `CssMediaQuery`, such as `(max-width: 600px)`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum CssMediaCondition {
+    MinWidth(PixelValue),
+    MaxWidth(PixelValue),
+    MinHeight(PixelValue),
+    MaxHeight(PixelValue),
+    /// Resolution in dots per pixel (`2dppx` / `2x` = `2.0`, `192dpi` = `2.0`)
+    MinResolution(FloatValue),
+    MaxResolution(FloatValue),
+    Orientation(CssMediaOrientation),
+    PrefersColorScheme(CssMediaColorScheme),
+}
+
+impl CssMediaCondition {
+    pub fn matches(&self, context: &CssMediaContext) -> bool {
+        use self::CssMediaCondition::*;
+        // percentages in media queries are meaningless, resolve them against 0
+        match self {
+            MinWidth(w) => context.width >= w.to_pixels(0.0),
+            MaxWidth(w) => context.width <= w.to_pixels(0.0),
+            MinHeight(h) => context.height >= h.to_pixels(0.0),
+            MaxHeight(h) => context.height <= h.to_pixels(0.0),
+            MinResolution(r) => context.dppx >= r.get(),
+            MaxResolution(r) => context.dppx <= r.get(),
+            Orientation(o) => context.get_orientation() == *o,
+            PrefersColorScheme(c) => context.color_scheme == *c,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum CssMediaOrientation {
+    Portrait,
+    Landscape,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum CssMediaColorScheme {
+    Light,
+    Dark,
+}
+
+impl Default for CssMediaColorScheme {
+    fn default() -> Self { CssMediaColorScheme::Light }
+}
+
+impl_vec!(CssMediaQuery, CssMediaQueryVec, CssMediaQueryVecDestructor);
+impl_vec_mut!(CssMediaQuery, CssMediaQueryVec);
+impl_vec_debug!(CssMediaQuery, CssMediaQueryVec);
+impl_vec_partialord!(CssMediaQuery, CssMediaQueryVec);
+impl_vec_ord!(CssMediaQuery, CssMediaQueryVec);
+impl_vec_clone!(CssMediaQuery, CssMediaQueryVec, CssMediaQueryVecDestructor);
+impl_vec_partialeq!(CssMediaQuery, CssMediaQueryVec);
+impl_vec_eq!(CssMediaQuery, CssMediaQueryVec);
+impl_vec_hash!(CssMediaQuery, CssMediaQueryVec);
+
+impl_vec!(CssMediaCondition, CssMediaConditionVec, CssMediaConditionVecDestructor);
+impl_vec_mut!(CssMediaCondition, CssMediaConditionVec);
+impl_vec_debug!(CssMediaCondition, CssMediaConditionVec);
+impl_vec_partialord!(CssMediaCondition, CssMediaConditionVec);
+impl_vec_ord!(CssMediaCondition, CssMediaConditionVec);
+impl_vec_clone!(CssMediaCondition, CssMediaConditionVec, CssMediaConditionVecDestructor);
+impl_vec_partialeq!(CssMediaCondition, CssMediaConditionVec);
+impl_vec_eq!(CssMediaCondition, CssMediaConditionVec);
+impl_vec_hash!(CssMediaCondition, CssMediaConditionVec);
+
+/// Window properties that `@media` queries are evaluated against
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct CssMediaContext {
+    /// Logical width of the window
+    pub width: f32,
+    /// Logical height of the window
+    pub height: f32,
+    /// Number of physical pixels per logical pixel (HiDPI factor)
+    pub dppx: f32,
+    pub color_scheme: CssMediaColorScheme,
+}
+
+impl Default for CssMediaContext {
+    fn default() -> Self {
+        Self { width: 0.0, height: 0.0, dppx: 1.0, color_scheme: CssMediaColorScheme::Light }
+    }
+}
+
+impl CssMediaContext {
+    pub fn get_orientation(&self) -> CssMediaOrientation {
+        if self.height >= self.width {
+            CssMediaOrientation::Portrait
+        } else {
+            CssMediaOrientation::Landscape
         }
     }
 }
@@ -429,10 +765,18 @@ pub enum CssPathSelector {
     Id(AzString),
     /// `:something`
     PseudoSelector(CssPathPseudoSelector),
+    /// `[data-state=open]`
+    Attribute(CssAttributeSelector),
+    /// `:not(.something)` - the selectors may not contain combinators
+    Not(CssPathSelectorVec),
     /// Represents the `>` selector
     DirectChildren,
     /// Represents the ` ` selector
     Children,
+    /// Represents the `+` selector
+    AdjacentSibling,
+    /// Represents the `~` selector
+    GeneralSibling,
 }
 
 impl Default for CssPathSelector {
@@ -450,12 +794,102 @@ impl fmt::Display for CssPathSelector {
             Class(c) => write!(f, ".{}", c),
             Id(i) => write!(f, "#{}", i),
             PseudoSelector(p) => write!(f, ":{}", p),
+            Attribute(a) => write!(f, "{}", a),
+            Not(n) => {
+                write!(f, ":not(")?;
+                for selector in n.as_ref() {
+                    write!(f, "{}", selector)?;
+                }
+                write!(f, ")")
+            },
             DirectChildren => write!(f, ">"),
             Children => write!(f, " "),
+            AdjacentSibling => write!(f, "+"),
+            GeneralSibling => write!(f, "~"),
+        }
+    }
+}
+
+impl CssPathSelector {
+    /// Returns whether the selector is a combinator (`>`, ` `, `+` or `~`)
+    pub fn is_combinator(&self) -> bool {
+        use self::CssPathSelector::*;
+        match self {
+            DirectChildren | Children | AdjacentSibling | GeneralSibling => true,
+            _ => false,
+        }
+    }
+}
+
+/// Attribute selector such as `[data-state=open]`, matched against the
+/// attributes of a node (see `NodeData::set_attribute`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct CssAttributeSelector {
+    /// Name of the attribute, i.e. `data-state`
+    pub name: AzString,
+    /// How the value of the attribute is compared against the `value`
+    pub op: CssAttributeSelectorOp,
+    /// Value to compare against (empty for `CssAttributeSelectorOp::Exists`)
+    pub value: AzString,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub enum CssAttributeSelectorOp {
+    /// `[name]` - attribute is set
+    Exists,
+    /// `[name=value]` - attribute is exactly `value`
+    Equals,
+    /// `[name~=value]` - whitespace-separated list of words, one of which is `value`
+    Includes,
+    /// `[name|=value]` - attribute is `value` or starts with `value-`
+    DashMatch,
+    /// `[name^=value]` - attribute starts with `value`
+    Prefix,
+    /// `[name$=value]` - attribute ends with `value`
+    Suffix,
+    /// `[name*=value]` - attribute contains `value`
+    Substring,
+}
+
+impl CssAttributeSelector {
+    /// Returns whether the attribute value (`None` if the attribute isn't set) matches the selector
+    pub fn matches(&self, attribute: Option<&str>) -> bool {
+        use self::CssAttributeSelectorOp::*;
+        let attribute = match attribute {
+            Some(s) => s,
+            None => return false,
+        };
+        let value = self.value.as_str();
+        match self.op {
+            Exists => true,
+            Equals => attribute == value,
+            Includes => !value.is_empty() && attribute.split_whitespace().any(|word| word == value),
+            DashMatch => attribute == value || (attribute.starts_with(value) && attribute[value.len()..].starts_with('-')),
+            Prefix => !value.is_empty() && attribute.starts_with(value),
+            Suffix => !value.is_empty() && attribute.ends_with(value),
+            Substring => !value.is_empty() && attribute.contains(value),
         }
     }
 }
 
+impl fmt::Display for CssAttributeSelector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::CssAttributeSelectorOp::*;
+        let op = match self.op {
+            Exists => return write!(f, "[{}]", self.name),
+            Equals => "=",
+            Includes => "~=",
+            DashMatch => "|=",
+            Prefix => "^=",
+            Suffix => "$=",
+            Substring => "*=",
+        };
+        write!(f, "[{}{}{:?}]", self.name, op, self.value.as_str())
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[repr(C)]
 pub enum CssPathPseudoSelector {
@@ -471,6 +905,30 @@ pub enum CssPathPseudoSelector {
     Active,
     /// `:focus` - element has received focus
     Focus,
+    /// `:first-child`
+    FirstChild,
+    /// `:last-child`
+    LastChild,
+    /// `:only-child`
+    OnlyChild,
+    /// `:nth-last-child` - same as `:nth-child`, but counted from the last child
+    NthLastChild(CssNthChildSelector),
+    /// `:empty` - element has no children (and no text)
+    Empty,
+    /// `:focus-within` - element or one of its children has received focus
+    FocusWithin,
+}
+
+impl CssPathPseudoSelector {
+    /// Returns whether the pseudo-class depends on the (hover / active / focus) state of the node,
+    /// the styles of these rules are only applied when the node changes its state
+    pub fn is_dynamic(&self) -> bool {
+        use self::CssPathPseudoSelector::*;
+        match self {
+            Hover | Active | Focus | FocusWithin => true,
+            _ => false,
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -511,6 +969,12 @@ impl fmt::Display for CssPathPseudoSelector {
             Hover => write!(f, "hover"),
             Active => write!(f, "active"),
             Focus => write!(f, "focus"),
+            FirstChild => write!(f, "first-child"),
+            LastChild => write!(f, "last-child"),
+            OnlyChild => write!(f, "only-child"),
+            NthLastChild(u) => write!(f, "nth-last-child({})", u),
+            Empty => write!(f, "empty"),
+            FocusWithin => write!(f, "focus-within"),
         }
     }
 }
@@ -527,6 +991,91 @@ impl Css {
         .for_each(|s| s.sort_by_specificity());
     }
 
+    /// Returns the `@font-face` declared for the given family name (case-insensitive).
+    /// If multiple files are declared for the same family, the upright,
+    /// normal-weight file is preferred.
+    pub fn get_font_face(&self, font_family: &str) -> Option<&CssFontFace> {
+        let font_family = font_family.trim();
+        self.font_faces.iter()
+        .filter(|f| f.font_family.as_str().eq_ignore_ascii_case(font_family))
+        .min_by_key(|f| (
+            f.font_style != CssFontFaceStyle::Normal,
+            (f.font_weight as i32 - CssFontFace::WEIGHT_NORMAL as i32).abs(),
+        ))
+    }
+
+    /// Returns the `@keyframes` rule declared with the given name -
+    /// if the name is declared multiple times, the last declaration wins
+    pub fn get_keyframes(&self, name: &str) -> Option<&CssKeyframes> {
+        let name = name.trim();
+        self.keyframes.iter().rev().find(|k| k.name.as_str() == name)
+    }
+
+    /// Replaces every family in the `font-family` declarations of the stylesheets
+    /// that was declared via `@font-face` with the `StyleFontFamily::File` of the declared font,
+    /// so that i.e. `font-family: "Brand Sans", sans-serif` loads the app-bundled font file first
+    pub fn resolve_font_faces(&mut self) {
+
+        if self.font_faces.is_empty() {
+            return;
+        }
+
+        let resolve_families = |families: &StyleFontFamilyVec, css: &Css| -> Option<StyleFontFamilyVec> {
+            let mut changed = false;
+            let resolved = families.iter().map(|family| match family {
+                StyleFontFamily::System(name) => match css.get_font_face(name.as_str()) {
+                    Some(font_face) => { changed = true; StyleFontFamily::File(font_face.src.clone()) },
+                    None => family.clone(),
+                },
+                other => other.clone(),
+            }).collect::<Vec<_>>();
+            if changed { Some(resolved.into()) } else { None }
+        };
+
+        let mut stylesheets = core::mem::replace(&mut self.stylesheets, Vec::new().into());
+
+        for stylesheet in stylesheets.as_mut().iter_mut() {
+            for rule in stylesheet.rules.as_mut().iter_mut() {
+                for declaration in rule.declarations.as_mut().iter_mut() {
+                    let property = match declaration {
+                        CssDeclaration::Static(s) => s,
+                        CssDeclaration::Dynamic(d) => &mut d.default_value,
+                        CssDeclaration::Custom(_) => continue,
+                    };
+                    if let CssProperty::FontFamily(CssPropertyValue::Exact(families)) = property {
+                        if let Some(resolved) = resolve_families(families, self) {
+                            *families = resolved;
+                        }
+                    }
+                }
+            }
+        }
+
+        self.stylesheets = stylesheets;
+    }
+
+    /// Returns whether any rule of the stylesheets declares a custom property (`--accent: red`)
+    /// or references a variable (`color: var(--accent)`)
+    pub fn has_css_variables(&self) -> bool {
+        self.rules().any(|r| r.declarations.iter().any(|d| match d {
+            CssDeclaration::Static(_) => false,
+            CssDeclaration::Dynamic(_) | CssDeclaration::Custom(_) => true,
+        }))
+    }
+
+    /// Returns whether any rule of the stylesheets is declared in an `@media` rule
+    pub fn has_media_queries(&self) -> bool {
+        self.rules().any(|r| !r.media_queries.is_empty())
+    }
+
+    /// Returns whether switching from the `old` to the `new` media context changes
+    /// which `@media` rules apply, i.e. whether the window crossed a breakpoint
+    pub fn media_queries_changed(&self, old: &CssMediaContext, new: &CssMediaContext) -> bool {
+        self.rules()
+        .filter(|r| !r.media_queries.is_empty())
+        .any(|r| r.matches_media(old) != r.matches_media(new))
+    }
+
     pub fn rules<'a>(&'a self) -> RuleIterator<'a> {
         RuleIterator {
             current_stylesheet: 0,
@@ -580,13 +1129,30 @@ impl Stylesheet {
 
 /// Returns specificity of the given css path. Further information can be found on
 /// [the w3 website](http://www.w3.org/TR/selectors/#specificity).
+///
+/// Attribute selectors and pseudo-classes count as classes, `:not()` itself doesn't count,
+/// but the selectors inside of it do.
 fn get_specificity(path: &CssPath) -> (usize, usize, usize, usize) {
-    let id_count = path.selectors.iter().filter(|x|     if let CssPathSelector::Id(_) = x {     true } else { false }).count();
-    let class_count = path.selectors.iter().filter(|x|  if let CssPathSelector::Class(_) = x {  true } else { false }).count();
-    let div_count = path.selectors.iter().filter(|x|    if let CssPathSelector::Type(_) = x {   true } else { false }).count();
+    let (id_count, class_count, div_count) = count_selectors(path.selectors.as_ref());
     (id_count, class_count, div_count, path.selectors.len())
 }
 
+/// Returns the number of (id, class, type) selectors, see `get_specificity`
+fn count_selectors(selectors: &[CssPathSelector]) -> (usize, usize, usize) {
+    selectors.iter().fold((0, 0, 0), |(ids, classes, types), selector| match selector {
+        CssPathSelector::Id(_) => (ids + 1, classes, types),
+        CssPathSelector::Class(_) |
+        CssPathSelector::Attribute(_) |
+        CssPathSelector::PseudoSelector(_) => (ids, classes + 1, types),
+        CssPathSelector::Type(_) => (ids, classes, types + 1),
+        CssPathSelector::Not(n) => {
+            let (i, c, t) = count_selectors(n.as_ref());
+            (ids + i, classes + c, types + t)
+        },
+        _ => (ids, classes, types),
+    })
+}
+
 #[test]
 fn test_specificity() {
     use self::CssPathSelector::*;
@@ -597,6 +1163,43 @@ fn test_specificity() {
     assert_eq!(get_specificity(&CssPath { selectors: vec![Id("hello".to_string().into()), Type(NodeTypeTag::Div)].into() }), (1, 0, 1, 2));
 }
 
+#[test]
+fn test_specificity_attribute_not_pseudo() {
+    use self::CssPathSelector::*;
+    use alloc::string::ToString;
+    let attribute = Attribute(CssAttributeSelector {
+        name: "data-state".to_string().into(),
+        op: CssAttributeSelectorOp::Equals,
+        value: "open".to_string().into(),
+    });
+    // div[data-state=open]:first-child
+    assert_eq!(get_specificity(&CssPath { selectors: vec![
+        Type(NodeTypeTag::Div), attribute, PseudoSelector(CssPathPseudoSelector::FirstChild),
+    ].into() }), (0, 2, 1, 3));
+    // .a + div:not(#b.c)
+    assert_eq!(get_specificity(&CssPath { selectors: vec![
+        Class("a".to_string().into()), AdjacentSibling, Type(NodeTypeTag::Div),
+        Not(vec![Id("b".to_string().into()), Class("c".to_string().into())].into()),
+    ].into() }), (1, 2, 1, 4));
+}
+
+#[test]
+fn test_attribute_selector_matches() {
+    use self::CssAttributeSelectorOp::*;
+    use alloc::string::ToString;
+    let selector = |op, value: &str| CssAttributeSelector { name: "data-x".to_string().into(), op, value: value.to_string().into() };
+    assert!(selector(Exists, "").matches(Some("")));
+    assert!(!selector(Exists, "").matches(None));
+    assert!(selector(Equals, "open").matches(Some("open")));
+    assert!(!selector(Equals, "open").matches(Some("opened")));
+    assert!(selector(Includes, "b").matches(Some("a b c")));
+    assert!(selector(DashMatch, "en").matches(Some("en-US")));
+    assert!(!selector(DashMatch, "en").matches(Some("english")));
+    assert!(selector(Prefix, "op").matches(Some("open")));
+    assert!(selector(Suffix, "en").matches(Some("open")));
+    assert!(selector(Substring, "pe").matches(Some("open")));
+}
+
 // Assert that order of the style items is correct
 // (in order of CSS path specificity, lowest-to-highest)
 #[test]
@@ -605,27 +1208,46 @@ fn test_specificity_sort() {
     use crate::NodeTypeTag::*;
     use alloc::string::ToString;
 
-    let input_style = Stylesheet {
+    let mut input_style = Stylesheet {
         rules: vec![
             // Rules are sorted from lowest-specificity to highest specificity
-            CssRuleBlock { path: CssPath { selectors: vec![Global].into() }, declarations: Vec::new().into() },
-            CssRuleBlock { path: CssPath { selectors: vec![Global, Type(Div), Class("my_class".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-            CssRuleBlock { path: CssPath { selectors: vec![Global, Type(Div), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-            CssRuleBlock { path: CssPath { selectors: vec![Global, Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-            CssRuleBlock { path: CssPath { selectors: vec![Type(Div), Class("my_class".to_string().into()), Class("specific".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
+            CssRuleBlock { path: CssPath { selectors: vec![Global].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
+            CssRuleBlock { path: CssPath { selectors: vec![Global, Type(Div), Class("my_class".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
+            CssRuleBlock { path: CssPath { selectors: vec![Global, Type(Div), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
+            CssRuleBlock { path: CssPath { selectors: vec![Global, Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
+            CssRuleBlock { path: CssPath { selectors: vec![Type(Div), Class("my_class".to_string().into()), Class("specific".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
         ].into(),
-    }.sort_by_specificity();
+    };
+    input_style.sort_by_specificity();
 
     let expected_style = Stylesheet {
         rules: vec![
             // Rules are sorted from lowest-specificity to highest specificity
-            CssRuleBlock { path: CssPath { selectors: vec![Global].into() }, declarations: Vec::new().into() },
-            CssRuleBlock { path: CssPath { selectors: vec![Global, Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-            CssRuleBlock { path: CssPath { selectors: vec![Global, Type(Div), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-            CssRuleBlock { path: CssPath { selectors: vec![Global, Type(Div), Class("my_class".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-            CssRuleBlock { path: CssPath { selectors: vec![Type(Div), Class("my_class".to_string().into()), Class("specific".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
+            CssRuleBlock { path: CssPath { selectors: vec![Global].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
+            CssRuleBlock { path: CssPath { selectors: vec![Global, Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
+            CssRuleBlock { path: CssPath { selectors: vec![Global, Type(Div), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
+            CssRuleBlock { path: CssPath { selectors: vec![Global, Type(Div), Class("my_class".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
+            CssRuleBlock { path: CssPath { selectors: vec![Type(Div), Class("my_class".to_string().into()), Class("specific".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media_queries: Vec::new().into() },
         ].into(),
     };
 
     assert_eq!(input_style, expected_style);
-}
\ No newline at end of file
+}
+#[test]
+fn test_css_var_substitution() {
+    use alloc::string::ToString;
+
+    let lookup = |name: &str| match name {
+        "accent" => Some("#3366ff".to_string()),
+        "border" => Some("1px solid var(--accent)".to_string()),
+        "cycle" => Some("var(--cycle)".to_string()),
+        _ => None,
+    };
+
+    assert_eq!(CssCustomProperty::substitute_var_references("var(--accent)", &lookup), Some("#3366ff".to_string()));
+    assert_eq!(CssCustomProperty::substitute_var_references("var(--border)", &lookup), Some("1px solid #3366ff".to_string()));
+    assert_eq!(CssCustomProperty::substitute_var_references("var(--missing, rgb(0, 0, 0))", &lookup), Some("rgb(0, 0, 0)".to_string()));
+    assert_eq!(CssCustomProperty::substitute_var_references("var(--missing)", &lookup), None);
+    assert_eq!(CssCustomProperty::substitute_var_references("var(--cycle)", &lookup), None);
+    assert_eq!(CssCustomProperty::substitute_var_references("4px", &lookup), Some("4px".to_string()));
+}
@@ -19,7 +19,7 @@ use crate::{
 pub const EM_HEIGHT: f32 = 16.0;
 pub const PT_TO_PX: f32 = 96.0 / 72.0;
 
-const COMBINED_CSS_PROPERTIES_KEY_MAP: [(CombinedCssPropertyType, &'static str);12] = [
+const COMBINED_CSS_PROPERTIES_KEY_MAP: [(CombinedCssPropertyType, &'static str);15] = [
     (CombinedCssPropertyType::BorderRadius,         "border-radius"),
     (CombinedCssPropertyType::Overflow,             "overflow"),
     (CombinedCssPropertyType::Padding,              "padding"),
@@ -32,13 +32,17 @@ const COMBINED_CSS_PROPERTIES_KEY_MAP: [(CombinedCssPropertyType, &'static str);
     (CombinedCssPropertyType::BoxShadow,            "box-shadow"),
     (CombinedCssPropertyType::BackgroundColor,      "background-color"),
     (CombinedCssPropertyType::BackgroundImage,      "background-image"),
+    (CombinedCssPropertyType::Gap,                  "gap"),
+    (CombinedCssPropertyType::Transition,           "transition"),
+    (CombinedCssPropertyType::Animation,            "animation"),
 ];
 
 /// Map between CSS keys and a statically typed enum
-const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str);70] = [
+const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str);88] = [
 
     (CssPropertyType::Display,              "display"),
     (CssPropertyType::Float,                "float"),
+    (CssPropertyType::Clear,                "clear"),
     (CssPropertyType::BoxSizing,            "box-sizing"),
 
     (CssPropertyType::TextColor,            "color"),
@@ -73,6 +77,13 @@ const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str);70] = [
     (CssPropertyType::AlignItems,           "align-items"),
     (CssPropertyType::AlignContent,         "align-content"),
 
+    (CssPropertyType::GridTemplateColumns,  "grid-template-columns"),
+    (CssPropertyType::GridTemplateRows,     "grid-template-rows"),
+    (CssPropertyType::GridColumn,           "grid-column"),
+    (CssPropertyType::GridRow,              "grid-row"),
+    (CssPropertyType::ColumnGap,            "column-gap"),
+    (CssPropertyType::RowGap,               "row-gap"),
+
     (CssPropertyType::OverflowX,            "overflow-x"),
     (CssPropertyType::OverflowY,            "overflow-y"),
 
@@ -123,6 +134,19 @@ const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str);70] = [
     (CssPropertyType::PerspectiveOrigin, "perspective-origin"),
     (CssPropertyType::TransformOrigin, "transform-origin"),
     (CssPropertyType::BackfaceVisibility, "backface-visibility"),
+
+    (CssPropertyType::TransitionProperty, "transition-property"),
+    (CssPropertyType::TransitionDuration, "transition-duration"),
+    (CssPropertyType::TransitionTimingFunction, "transition-timing-function"),
+    (CssPropertyType::TransitionDelay, "transition-delay"),
+
+    (CssPropertyType::AnimationName, "animation-name"),
+    (CssPropertyType::AnimationDuration, "animation-duration"),
+    (CssPropertyType::AnimationTimingFunction, "animation-timing-function"),
+    (CssPropertyType::AnimationIterationCount, "animation-iteration-count"),
+    (CssPropertyType::AnimationDirection, "animation-direction"),
+    (CssPropertyType::AnimationFillMode, "animation-fill-mode"),
+    (CssPropertyType::AnimationDelay, "animation-delay"),
 ];
 
 // The following types are present in webrender, however, azul-css should not
@@ -744,6 +768,9 @@ pub enum CombinedCssPropertyType {
     BoxShadow,
     BackgroundColor, // BackgroundContent::Colo
     BackgroundImage, // BackgroundContent::Colo
+    Gap,
+    Transition,
+    Animation,
 }
 
 impl fmt::Display for CombinedCssPropertyType {
@@ -807,6 +834,7 @@ pub enum CssPropertyType {
     Cursor,
     Display,
     Float,
+    Clear,
     BoxSizing,
     Width,
     Height,
@@ -826,6 +854,12 @@ pub enum CssPropertyType {
     JustifyContent,
     AlignItems,
     AlignContent,
+    GridTemplateColumns,
+    GridTemplateRows,
+    GridColumn,
+    GridRow,
+    ColumnGap,
+    RowGap,
     BackgroundContent,
     BackgroundPosition,
     BackgroundSize,
@@ -866,6 +900,17 @@ pub enum CssPropertyType {
     TransformOrigin,
     PerspectiveOrigin,
     BackfaceVisibility,
+    TransitionProperty,
+    TransitionDuration,
+    TransitionTimingFunction,
+    TransitionDelay,
+    AnimationName,
+    AnimationDuration,
+    AnimationTimingFunction,
+    AnimationIterationCount,
+    AnimationDirection,
+    AnimationFillMode,
+    AnimationDelay,
 }
 
 impl CssPropertyType {
@@ -900,6 +945,7 @@ impl CssPropertyType {
             CssPropertyType::Cursor => "cursor",
             CssPropertyType::Display => "display",
             CssPropertyType::Float => "float",
+            CssPropertyType::Clear => "clear",
             CssPropertyType::BoxSizing => "box-sizing",
             CssPropertyType::Width => "width",
             CssPropertyType::Height => "height",
@@ -919,6 +965,12 @@ impl CssPropertyType {
             CssPropertyType::JustifyContent => "justify-content",
             CssPropertyType::AlignItems => "align-items",
             CssPropertyType::AlignContent => "align-content",
+            CssPropertyType::GridTemplateColumns => "grid-template-columns",
+            CssPropertyType::GridTemplateRows => "grid-template-rows",
+            CssPropertyType::GridColumn => "grid-column",
+            CssPropertyType::GridRow => "grid-row",
+            CssPropertyType::ColumnGap => "column-gap",
+            CssPropertyType::RowGap => "row-gap",
             CssPropertyType::BackgroundContent => "background",
             CssPropertyType::BackgroundPosition => "background-position",
             CssPropertyType::BackgroundSize => "background-size",
@@ -959,6 +1011,17 @@ impl CssPropertyType {
             CssPropertyType::TransformOrigin => "transform-origin",
             CssPropertyType::PerspectiveOrigin => "perspective-origin",
             CssPropertyType::BackfaceVisibility => "backface-visibility",
+            CssPropertyType::TransitionProperty => "transition-property",
+            CssPropertyType::TransitionDuration => "transition-duration",
+            CssPropertyType::TransitionTimingFunction => "transition-timing-function",
+            CssPropertyType::TransitionDelay => "transition-delay",
+            CssPropertyType::AnimationName => "animation-name",
+            CssPropertyType::AnimationDuration => "animation-duration",
+            CssPropertyType::AnimationTimingFunction => "animation-timing-function",
+            CssPropertyType::AnimationIterationCount => "animation-iteration-count",
+            CssPropertyType::AnimationDirection => "animation-direction",
+            CssPropertyType::AnimationFillMode => "animation-fill-mode",
+            CssPropertyType::AnimationDelay => "animation-delay",
         }
     }
 
@@ -1009,6 +1072,17 @@ impl CssPropertyType {
             | BoxShadowRight
             | BoxShadowTop
             | BoxShadowBottom
+            | TransitionProperty
+            | TransitionDuration
+            | TransitionTimingFunction
+            | TransitionDelay
+            | AnimationName
+            | AnimationDuration
+            | AnimationTimingFunction
+            | AnimationIterationCount
+            | AnimationDirection
+            | AnimationFillMode
+            | AnimationDelay
             => false,
             _ => true,
         }
@@ -1046,6 +1120,7 @@ pub enum CssProperty {
     Cursor(StyleCursorValue),
     Display(LayoutDisplayValue),
     Float(LayoutFloatValue),
+    Clear(LayoutClearValue),
     BoxSizing(LayoutBoxSizingValue),
     Width(LayoutWidthValue),
     Height(LayoutHeightValue),
@@ -1065,6 +1140,12 @@ pub enum CssProperty {
     JustifyContent(LayoutJustifyContentValue),
     AlignItems(LayoutAlignItemsValue),
     AlignContent(LayoutAlignContentValue),
+    GridTemplateColumns(LayoutGridTemplateColumnsValue),
+    GridTemplateRows(LayoutGridTemplateRowsValue),
+    GridColumn(LayoutGridColumnValue),
+    GridRow(LayoutGridRowValue),
+    ColumnGap(LayoutColumnGapValue),
+    RowGap(LayoutRowGapValue),
     BackgroundContent(StyleBackgroundContentVecValue),
     BackgroundPosition(StyleBackgroundPositionVecValue),
     BackgroundSize(StyleBackgroundSizeVecValue),
@@ -1105,6 +1186,17 @@ pub enum CssProperty {
     TransformOrigin(StyleTransformOriginValue),
     PerspectiveOrigin(StylePerspectiveOriginValue),
     BackfaceVisibility(StyleBackfaceVisibilityValue),
+    TransitionProperty(StyleTransitionPropertyVecValue),
+    TransitionDuration(StyleTransitionDurationVecValue),
+    TransitionTimingFunction(StyleTransitionTimingFunctionVecValue),
+    TransitionDelay(StyleTransitionDelayVecValue),
+    AnimationName(StyleAnimationNameVecValue),
+    AnimationDuration(StyleAnimationDurationVecValue),
+    AnimationTimingFunction(StyleTransitionTimingFunctionVecValue),
+    AnimationIterationCount(StyleAnimationIterationCountVecValue),
+    AnimationDirection(StyleAnimationDirectionVecValue),
+    AnimationFillMode(StyleAnimationFillModeVecValue),
+    AnimationDelay(StyleAnimationDelayVecValue),
 }
 
 impl_option!(CssProperty, OptionCssProperty, copy = false, [Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord]);
@@ -1122,6 +1214,7 @@ macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => (
         CssPropertyType::Cursor => CssProperty::Cursor(StyleCursorValue::$content_type),
         CssPropertyType::Display => CssProperty::Display(LayoutDisplayValue::$content_type),
         CssPropertyType::Float => CssProperty::Float(LayoutFloatValue::$content_type),
+        CssPropertyType::Clear => CssProperty::Clear(LayoutClearValue::$content_type),
         CssPropertyType::BoxSizing => CssProperty::BoxSizing(LayoutBoxSizingValue::$content_type),
         CssPropertyType::Width => CssProperty::Width(LayoutWidthValue::$content_type),
         CssPropertyType::Height => CssProperty::Height(LayoutHeightValue::$content_type),
@@ -1141,6 +1234,12 @@ macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => (
         CssPropertyType::JustifyContent => CssProperty::JustifyContent(LayoutJustifyContentValue::$content_type),
         CssPropertyType::AlignItems => CssProperty::AlignItems(LayoutAlignItemsValue::$content_type),
         CssPropertyType::AlignContent => CssProperty::AlignContent(LayoutAlignContentValue::$content_type),
+        CssPropertyType::GridTemplateColumns => CssProperty::GridTemplateColumns(LayoutGridTemplateColumnsValue::$content_type),
+        CssPropertyType::GridTemplateRows => CssProperty::GridTemplateRows(LayoutGridTemplateRowsValue::$content_type),
+        CssPropertyType::GridColumn => CssProperty::GridColumn(LayoutGridColumnValue::$content_type),
+        CssPropertyType::GridRow => CssProperty::GridRow(LayoutGridRowValue::$content_type),
+        CssPropertyType::ColumnGap => CssProperty::ColumnGap(LayoutColumnGapValue::$content_type),
+        CssPropertyType::RowGap => CssProperty::RowGap(LayoutRowGapValue::$content_type),
         CssPropertyType::BackgroundContent => CssProperty::BackgroundContent(StyleBackgroundContentVecValue::$content_type),
         CssPropertyType::BackgroundPosition => CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::$content_type),
         CssPropertyType::BackgroundSize => CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::$content_type),
@@ -1181,6 +1280,17 @@ macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => (
         CssPropertyType::PerspectiveOrigin => CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::$content_type),
         CssPropertyType::TransformOrigin => CssProperty::TransformOrigin(StyleTransformOriginValue::$content_type),
         CssPropertyType::BackfaceVisibility => CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::$content_type),
+        CssPropertyType::TransitionProperty => CssProperty::TransitionProperty(StyleTransitionPropertyVecValue::$content_type),
+        CssPropertyType::TransitionDuration => CssProperty::TransitionDuration(StyleTransitionDurationVecValue::$content_type),
+        CssPropertyType::TransitionTimingFunction => CssProperty::TransitionTimingFunction(StyleTransitionTimingFunctionVecValue::$content_type),
+        CssPropertyType::TransitionDelay => CssProperty::TransitionDelay(StyleTransitionDelayVecValue::$content_type),
+        CssPropertyType::AnimationName => CssProperty::AnimationName(StyleAnimationNameVecValue::$content_type),
+        CssPropertyType::AnimationDuration => CssProperty::AnimationDuration(StyleAnimationDurationVecValue::$content_type),
+        CssPropertyType::AnimationTimingFunction => CssProperty::AnimationTimingFunction(StyleTransitionTimingFunctionVecValue::$content_type),
+        CssPropertyType::AnimationIterationCount => CssProperty::AnimationIterationCount(StyleAnimationIterationCountVecValue::$content_type),
+        CssPropertyType::AnimationDirection => CssProperty::AnimationDirection(StyleAnimationDirectionVecValue::$content_type),
+        CssPropertyType::AnimationFillMode => CssProperty::AnimationFillMode(StyleAnimationFillModeVecValue::$content_type),
+        CssPropertyType::AnimationDelay => CssProperty::AnimationDelay(StyleAnimationDelayVecValue::$content_type),
     }
 })}
 
@@ -1199,6 +1309,7 @@ impl CssProperty {
             Cursor(c) => c.is_initial(),
             Display(c) => c.is_initial(),
             Float(c) => c.is_initial(),
+            Clear(c) => c.is_initial(),
             BoxSizing(c) => c.is_initial(),
             Width(c) => c.is_initial(),
             Height(c) => c.is_initial(),
@@ -1218,6 +1329,12 @@ impl CssProperty {
             JustifyContent(c) => c.is_initial(),
             AlignItems(c) => c.is_initial(),
             AlignContent(c) => c.is_initial(),
+            GridTemplateColumns(c) => c.is_initial(),
+            GridTemplateRows(c) => c.is_initial(),
+            GridColumn(c) => c.is_initial(),
+            GridRow(c) => c.is_initial(),
+            ColumnGap(c) => c.is_initial(),
+            RowGap(c) => c.is_initial(),
             BackgroundContent(c) => c.is_initial(),
             BackgroundPosition(c) => c.is_initial(),
             BackgroundSize(c) => c.is_initial(),
@@ -1258,6 +1375,17 @@ impl CssProperty {
             TransformOrigin(c) => c.is_initial(),
             PerspectiveOrigin(c) => c.is_initial(),
             BackfaceVisibility(c) => c.is_initial(),
+            TransitionProperty(c) => c.is_initial(),
+            TransitionDuration(c) => c.is_initial(),
+            TransitionTimingFunction(c) => c.is_initial(),
+            TransitionDelay(c) => c.is_initial(),
+            AnimationName(c) => c.is_initial(),
+            AnimationDuration(c) => c.is_initial(),
+            AnimationTimingFunction(c) => c.is_initial(),
+            AnimationIterationCount(c) => c.is_initial(),
+            AnimationDirection(c) => c.is_initial(),
+            AnimationFillMode(c) => c.is_initial(),
+            AnimationDelay(c) => c.is_initial(),
         }
     }
 
@@ -1277,6 +1405,7 @@ impl CssProperty {
     pub const fn const_cursor(input: StyleCursor) -> Self { CssProperty::Cursor(StyleCursorValue::Exact(input)) }
     pub const fn const_display(input: LayoutDisplay) -> Self { CssProperty::Display(LayoutDisplayValue::Exact(input)) }
     pub const fn const_float(input: LayoutFloat) -> Self { CssProperty::Float(LayoutFloatValue::Exact(input)) }
+    pub const fn const_clear(input: LayoutClear) -> Self { CssProperty::Clear(LayoutClearValue::Exact(input)) }
     pub const fn const_box_sizing(input: LayoutBoxSizing) -> Self { CssProperty::BoxSizing(LayoutBoxSizingValue::Exact(input)) }
     pub const fn const_width(input: LayoutWidth) -> Self { CssProperty::Width(LayoutWidthValue::Exact(input)) }
     pub const fn const_height(input: LayoutHeight) -> Self { CssProperty::Height(LayoutHeightValue::Exact(input)) }
@@ -1296,6 +1425,12 @@ impl CssProperty {
     pub const fn const_justify_content(input: LayoutJustifyContent) -> Self { CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(input)) }
     pub const fn const_align_items(input: LayoutAlignItems) -> Self { CssProperty::AlignItems(LayoutAlignItemsValue::Exact(input)) }
     pub const fn const_align_content(input: LayoutAlignContent) -> Self { CssProperty::AlignContent(LayoutAlignContentValue::Exact(input)) }
+    pub const fn const_grid_template_columns(input: LayoutGridTemplateColumns) -> Self { CssProperty::GridTemplateColumns(LayoutGridTemplateColumnsValue::Exact(input)) }
+    pub const fn const_grid_template_rows(input: LayoutGridTemplateRows) -> Self { CssProperty::GridTemplateRows(LayoutGridTemplateRowsValue::Exact(input)) }
+    pub const fn const_grid_column(input: LayoutGridColumn) -> Self { CssProperty::GridColumn(LayoutGridColumnValue::Exact(input)) }
+    pub const fn const_grid_row(input: LayoutGridRow) -> Self { CssProperty::GridRow(LayoutGridRowValue::Exact(input)) }
+    pub const fn const_column_gap(input: LayoutColumnGap) -> Self { CssProperty::ColumnGap(LayoutColumnGapValue::Exact(input)) }
+    pub const fn const_row_gap(input: LayoutRowGap) -> Self { CssProperty::RowGap(LayoutRowGapValue::Exact(input)) }
     pub const fn const_background_content(input: StyleBackgroundContentVec) -> Self { CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(input)) }
     pub const fn const_background_position(input: StyleBackgroundPositionVec) -> Self { CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::Exact(input)) }
     pub const fn const_background_size(input: StyleBackgroundSizeVec) -> Self { CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::Exact(input)) }
@@ -1335,6 +1470,17 @@ impl CssProperty {
     pub const fn const_transform_origin(input: StyleTransformOrigin) -> Self { CssProperty::TransformOrigin(StyleTransformOriginValue::Exact(input)) }
     pub const fn const_perspective_origin(input: StylePerspectiveOrigin) -> Self { CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::Exact(input)) }
     pub const fn const_backface_visiblity(input: StyleBackfaceVisibility) -> Self { CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::Exact(input)) }
+    pub const fn const_transition_property(input: StyleTransitionPropertyVec) -> Self { CssProperty::TransitionProperty(StyleTransitionPropertyVecValue::Exact(input)) }
+    pub const fn const_transition_duration(input: StyleTransitionDurationVec) -> Self { CssProperty::TransitionDuration(StyleTransitionDurationVecValue::Exact(input)) }
+    pub const fn const_transition_timing_function(input: StyleTransitionTimingFunctionVec) -> Self { CssProperty::TransitionTimingFunction(StyleTransitionTimingFunctionVecValue::Exact(input)) }
+    pub const fn const_transition_delay(input: StyleTransitionDelayVec) -> Self { CssProperty::TransitionDelay(StyleTransitionDelayVecValue::Exact(input)) }
+    pub const fn const_animation_name(input: StyleAnimationNameVec) -> Self { CssProperty::AnimationName(StyleAnimationNameVecValue::Exact(input)) }
+    pub const fn const_animation_duration(input: StyleAnimationDurationVec) -> Self { CssProperty::AnimationDuration(StyleAnimationDurationVecValue::Exact(input)) }
+    pub const fn const_animation_timing_function(input: StyleTransitionTimingFunctionVec) -> Self { CssProperty::AnimationTimingFunction(StyleTransitionTimingFunctionVecValue::Exact(input)) }
+    pub const fn const_animation_iteration_count(input: StyleAnimationIterationCountVec) -> Self { CssProperty::AnimationIterationCount(StyleAnimationIterationCountVecValue::Exact(input)) }
+    pub const fn const_animation_direction(input: StyleAnimationDirectionVec) -> Self { CssProperty::AnimationDirection(StyleAnimationDirectionVecValue::Exact(input)) }
+    pub const fn const_animation_fill_mode(input: StyleAnimationFillModeVec) -> Self { CssProperty::AnimationFillMode(StyleAnimationFillModeVecValue::Exact(input)) }
+    pub const fn const_animation_delay(input: StyleAnimationDelayVec) -> Self { CssProperty::AnimationDelay(StyleAnimationDelayVecValue::Exact(input)) }
 
 }
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -1462,6 +1608,7 @@ impl CssProperty {
             CssProperty::Cursor(v) => v.get_css_value_fmt(),
             CssProperty::Display(v) => v.get_css_value_fmt(),
             CssProperty::Float(v) => v.get_css_value_fmt(),
+            CssProperty::Clear(v) => v.get_css_value_fmt(),
             CssProperty::BoxSizing(v) => v.get_css_value_fmt(),
             CssProperty::Width(v) => v.get_css_value_fmt(),
             CssProperty::Height(v) => v.get_css_value_fmt(),
@@ -1481,6 +1628,12 @@ impl CssProperty {
             CssProperty::JustifyContent(v) => v.get_css_value_fmt(),
             CssProperty::AlignItems(v) => v.get_css_value_fmt(),
             CssProperty::AlignContent(v) => v.get_css_value_fmt(),
+            CssProperty::GridTemplateColumns(v) => v.get_css_value_fmt(),
+            CssProperty::GridTemplateRows(v) => v.get_css_value_fmt(),
+            CssProperty::GridColumn(v) => v.get_css_value_fmt(),
+            CssProperty::GridRow(v) => v.get_css_value_fmt(),
+            CssProperty::ColumnGap(v) => v.get_css_value_fmt(),
+            CssProperty::RowGap(v) => v.get_css_value_fmt(),
             CssProperty::BackgroundContent(v) => v.get_css_value_fmt(),
             CssProperty::BackgroundPosition(v) => v.get_css_value_fmt(),
             CssProperty::BackgroundSize(v) => v.get_css_value_fmt(),
@@ -1521,6 +1674,17 @@ impl CssProperty {
             CssProperty::TransformOrigin(v) => v.get_css_value_fmt(),
             CssProperty::PerspectiveOrigin(v) => v.get_css_value_fmt(),
             CssProperty::BackfaceVisibility(v) => v.get_css_value_fmt(),
+            CssProperty::TransitionProperty(v) => v.get_css_value_fmt(),
+            CssProperty::TransitionDuration(v) => v.get_css_value_fmt(),
+            CssProperty::TransitionTimingFunction(v) => v.get_css_value_fmt(),
+            CssProperty::TransitionDelay(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationName(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationDuration(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationTimingFunction(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationIterationCount(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationDirection(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationFillMode(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationDelay(v) => v.get_css_value_fmt(),
         }
     }
 
@@ -1639,6 +1803,16 @@ impl CssProperty {
                 let end = end.get_property().copied().unwrap_or_default();
                 CssProperty::FlexShrink(CssPropertyValue::Exact(start.interpolate(&end, t)))
             },
+            (CssProperty::ColumnGap(start), CssProperty::ColumnGap(end)) => {
+                let start = start.get_property().copied().unwrap_or_default();
+                let end = end.get_property().copied().unwrap_or_default();
+                CssProperty::ColumnGap(CssPropertyValue::Exact(start.interpolate(&end, t)))
+            },
+            (CssProperty::RowGap(start), CssProperty::RowGap(end)) => {
+                let start = start.get_property().copied().unwrap_or_default();
+                let end = end.get_property().copied().unwrap_or_default();
+                CssProperty::RowGap(CssPropertyValue::Exact(start.interpolate(&end, t)))
+            },
             (CssProperty::PaddingTop(start), CssProperty::PaddingTop(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
@@ -1754,10 +1928,30 @@ impl CssProperty {
                 let end = end.get_property().copied().unwrap_or_default();
                 CssProperty::PerspectiveOrigin(CssPropertyValue::Exact(start.interpolate(&end, t)))
             },
+            (CssProperty::BackgroundContent(start), CssProperty::BackgroundContent(end)) => {
+                // only solid colors can be interpolated, a missing background fades in from
+                // the transparent version of the other color
+                let color_of = |c: &StyleBackgroundContentVecValue| match c.get_property().map(|v| v.as_ref()) {
+                    Some([StyleBackgroundContent::Color(c)]) => Some(Some(*c)),
+                    None | Some([]) => Some(None),
+                    _ => None,
+                };
+                match (color_of(start), color_of(end)) {
+                    (Some(Some(s)), Some(Some(e))) => CssProperty::background_content(vec![StyleBackgroundContent::Color(s.interpolate(&e, t))].into()),
+                    (Some(Some(s)), Some(None)) => CssProperty::background_content(vec![StyleBackgroundContent::Color(s.interpolate(&ColorU { a: 0, .. s }, t))].into()),
+                    (Some(None), Some(Some(e))) => CssProperty::background_content(vec![StyleBackgroundContent::Color(ColorU { a: 0, .. e }.interpolate(&e, t))].into()),
+                    _ => if t > 0.5 { other.clone() } else { self.clone() },
+                }
+            },
+            (CssProperty::Transform(start), CssProperty::Transform(end)) => {
+                let start = start.get_property().cloned().unwrap_or_else(|| Vec::new().into());
+                let end = end.get_property().cloned().unwrap_or_else(|| Vec::new().into());
+                match start.interpolate(&end, t) {
+                    Some(s) => CssProperty::Transform(CssPropertyValue::Exact(s)),
+                    None => if t > 0.5 { other.clone() } else { self.clone() },
+                }
+            },
             /*
-            animate transform:
-            CssProperty::Transform(CssPropertyValue<StyleTransformVec>),
-
             animate box shadow:
             CssProperty::BoxShadowLeft(CssPropertyValue<StyleBoxShadow>),
             CssProperty::BoxShadowRight(CssPropertyValue<StyleBoxShadow>),
@@ -1803,6 +1997,7 @@ macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => (
         CssPropertyType::Cursor => CssProperty::Cursor(CssPropertyValue::$content_type),
         CssPropertyType::Display => CssProperty::Display(CssPropertyValue::$content_type),
         CssPropertyType::Float => CssProperty::Float(CssPropertyValue::$content_type),
+        CssPropertyType::Clear => CssProperty::Clear(CssPropertyValue::$content_type),
         CssPropertyType::BoxSizing => CssProperty::BoxSizing(CssPropertyValue::$content_type),
         CssPropertyType::Width => CssProperty::Width(CssPropertyValue::$content_type),
         CssPropertyType::Height => CssProperty::Height(CssPropertyValue::$content_type),
@@ -1822,6 +2017,12 @@ macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => (
         CssPropertyType::JustifyContent => CssProperty::JustifyContent(CssPropertyValue::$content_type),
         CssPropertyType::AlignItems => CssProperty::AlignItems(CssPropertyValue::$content_type),
         CssPropertyType::AlignContent => CssProperty::AlignContent(CssPropertyValue::$content_type),
+        CssPropertyType::GridTemplateColumns => CssProperty::GridTemplateColumns(CssPropertyValue::$content_type),
+        CssPropertyType::GridTemplateRows => CssProperty::GridTemplateRows(CssPropertyValue::$content_type),
+        CssPropertyType::GridColumn => CssProperty::GridColumn(CssPropertyValue::$content_type),
+        CssPropertyType::GridRow => CssProperty::GridRow(CssPropertyValue::$content_type),
+        CssPropertyType::ColumnGap => CssProperty::ColumnGap(CssPropertyValue::$content_type),
+        CssPropertyType::RowGap => CssProperty::RowGap(CssPropertyValue::$content_type),
         CssPropertyType::OverflowX => CssProperty::OverflowX(CssPropertyValue::$content_type),
         CssPropertyType::OverflowY => CssProperty::OverflowY(CssPropertyValue::$content_type),
         CssPropertyType::PaddingTop => CssProperty::PaddingTop(CssPropertyValue::$content_type),
@@ -1862,6 +2063,17 @@ macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => (
         CssPropertyType::PerspectiveOrigin => CssProperty::PerspectiveOrigin(CssPropertyValue::$content_type),
         CssPropertyType::TransformOrigin => CssProperty::TransformOrigin(CssPropertyValue::$content_type),
         CssPropertyType::BackfaceVisibility => CssProperty::BackfaceVisibility(CssPropertyValue::$content_type),
+        CssPropertyType::TransitionProperty => CssProperty::TransitionProperty(CssPropertyValue::$content_type),
+        CssPropertyType::TransitionDuration => CssProperty::TransitionDuration(CssPropertyValue::$content_type),
+        CssPropertyType::TransitionTimingFunction => CssProperty::TransitionTimingFunction(CssPropertyValue::$content_type),
+        CssPropertyType::TransitionDelay => CssProperty::TransitionDelay(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationName => CssProperty::AnimationName(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationDuration => CssProperty::AnimationDuration(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationTimingFunction => CssProperty::AnimationTimingFunction(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationIterationCount => CssProperty::AnimationIterationCount(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationDirection => CssProperty::AnimationDirection(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationFillMode => CssProperty::AnimationFillMode(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationDelay => CssProperty::AnimationDelay(CssPropertyValue::$content_type),
     }
 })}
 
@@ -1881,6 +2093,7 @@ impl CssProperty {
             CssProperty::Cursor(_) => CssPropertyType::Cursor,
             CssProperty::Display(_) => CssPropertyType::Display,
             CssProperty::Float(_) => CssPropertyType::Float,
+            CssProperty::Clear(_) => CssPropertyType::Clear,
             CssProperty::BoxSizing(_) => CssPropertyType::BoxSizing,
             CssProperty::Width(_) => CssPropertyType::Width,
             CssProperty::Height(_) => CssPropertyType::Height,
@@ -1900,6 +2113,12 @@ impl CssProperty {
             CssProperty::JustifyContent(_) => CssPropertyType::JustifyContent,
             CssProperty::AlignItems(_) => CssPropertyType::AlignItems,
             CssProperty::AlignContent(_) => CssPropertyType::AlignContent,
+            CssProperty::GridTemplateColumns(_) => CssPropertyType::GridTemplateColumns,
+            CssProperty::GridTemplateRows(_) => CssPropertyType::GridTemplateRows,
+            CssProperty::GridColumn(_) => CssPropertyType::GridColumn,
+            CssProperty::GridRow(_) => CssPropertyType::GridRow,
+            CssProperty::ColumnGap(_) => CssPropertyType::ColumnGap,
+            CssProperty::RowGap(_) => CssPropertyType::RowGap,
             CssProperty::BackgroundContent(_) => CssPropertyType::BackgroundContent,
             CssProperty::BackgroundPosition(_) => CssPropertyType::BackgroundPosition,
             CssProperty::BackgroundSize(_) => CssPropertyType::BackgroundSize,
@@ -1940,6 +2159,17 @@ impl CssProperty {
             CssProperty::PerspectiveOrigin(_) => CssPropertyType::PerspectiveOrigin,
             CssProperty::TransformOrigin(_) => CssPropertyType::TransformOrigin,
             CssProperty::BackfaceVisibility(_) => CssPropertyType::BackfaceVisibility,
+            CssProperty::TransitionProperty(_) => CssPropertyType::TransitionProperty,
+            CssProperty::TransitionDuration(_) => CssPropertyType::TransitionDuration,
+            CssProperty::TransitionTimingFunction(_) => CssPropertyType::TransitionTimingFunction,
+            CssProperty::TransitionDelay(_) => CssPropertyType::TransitionDelay,
+            CssProperty::AnimationName(_) => CssPropertyType::AnimationName,
+            CssProperty::AnimationDuration(_) => CssPropertyType::AnimationDuration,
+            CssProperty::AnimationTimingFunction(_) => CssPropertyType::AnimationTimingFunction,
+            CssProperty::AnimationIterationCount(_) => CssPropertyType::AnimationIterationCount,
+            CssProperty::AnimationDirection(_) => CssPropertyType::AnimationDirection,
+            CssProperty::AnimationFillMode(_) => CssPropertyType::AnimationFillMode,
+            CssProperty::AnimationDelay(_) => CssPropertyType::AnimationDelay,
         }
     }
 
@@ -1961,6 +2191,7 @@ impl CssProperty {
     pub const fn cursor(input: StyleCursor) -> Self { CssProperty::Cursor(CssPropertyValue::Exact(input)) }
     pub const fn display(input: LayoutDisplay) -> Self { CssProperty::Display(CssPropertyValue::Exact(input)) }
     pub const fn float(input: LayoutFloat) -> Self { CssProperty::Float(CssPropertyValue::Exact(input)) }
+    pub const fn clear(input: LayoutClear) -> Self { CssProperty::Clear(CssPropertyValue::Exact(input)) }
     pub const fn box_sizing(input: LayoutBoxSizing) -> Self { CssProperty::BoxSizing(CssPropertyValue::Exact(input)) }
     pub const fn width(input: LayoutWidth) -> Self { CssProperty::Width(CssPropertyValue::Exact(input)) }
     pub const fn height(input: LayoutHeight) -> Self { CssProperty::Height(CssPropertyValue::Exact(input)) }
@@ -1980,6 +2211,12 @@ impl CssProperty {
     pub const fn justify_content(input: LayoutJustifyContent) -> Self { CssProperty::JustifyContent(CssPropertyValue::Exact(input)) }
     pub const fn align_items(input: LayoutAlignItems) -> Self { CssProperty::AlignItems(CssPropertyValue::Exact(input)) }
     pub const fn align_content(input: LayoutAlignContent) -> Self { CssProperty::AlignContent(CssPropertyValue::Exact(input)) }
+    pub const fn grid_template_columns(input: LayoutGridTemplateColumns) -> Self { CssProperty::GridTemplateColumns(CssPropertyValue::Exact(input)) }
+    pub const fn grid_template_rows(input: LayoutGridTemplateRows) -> Self { CssProperty::GridTemplateRows(CssPropertyValue::Exact(input)) }
+    pub const fn grid_column(input: LayoutGridColumn) -> Self { CssProperty::GridColumn(CssPropertyValue::Exact(input)) }
+    pub const fn grid_row(input: LayoutGridRow) -> Self { CssProperty::GridRow(CssPropertyValue::Exact(input)) }
+    pub const fn column_gap(input: LayoutColumnGap) -> Self { CssProperty::ColumnGap(CssPropertyValue::Exact(input)) }
+    pub const fn row_gap(input: LayoutRowGap) -> Self { CssProperty::RowGap(CssPropertyValue::Exact(input)) }
     pub const fn background_content(input: StyleBackgroundContentVec) -> Self { CssProperty::BackgroundContent(CssPropertyValue::Exact(input)) }
     pub const fn background_position(input: StyleBackgroundPositionVec) -> Self { CssProperty::BackgroundPosition(CssPropertyValue::Exact(input)) }
     pub const fn background_size(input: StyleBackgroundSizeVec) -> Self { CssProperty::BackgroundSize(CssPropertyValue::Exact(input)) }
@@ -2019,6 +2256,17 @@ impl CssProperty {
     pub const fn transform_origin(input: StyleTransformOrigin) -> Self { CssProperty::TransformOrigin(CssPropertyValue::Exact(input)) }
     pub const fn perspective_origin(input: StylePerspectiveOrigin) -> Self { CssProperty::PerspectiveOrigin(CssPropertyValue::Exact(input)) }
     pub const fn backface_visiblity(input: StyleBackfaceVisibility) -> Self { CssProperty::BackfaceVisibility(CssPropertyValue::Exact(input)) }
+    pub const fn transition_property(input: StyleTransitionPropertyVec) -> Self { CssProperty::TransitionProperty(CssPropertyValue::Exact(input)) }
+    pub const fn transition_duration(input: StyleTransitionDurationVec) -> Self { CssProperty::TransitionDuration(CssPropertyValue::Exact(input)) }
+    pub const fn transition_timing_function(input: StyleTransitionTimingFunctionVec) -> Self { CssProperty::TransitionTimingFunction(CssPropertyValue::Exact(input)) }
+    pub const fn transition_delay(input: StyleTransitionDelayVec) -> Self { CssProperty::TransitionDelay(CssPropertyValue::Exact(input)) }
+    pub const fn animation_name(input: StyleAnimationNameVec) -> Self { CssProperty::AnimationName(CssPropertyValue::Exact(input)) }
+    pub const fn animation_duration(input: StyleAnimationDurationVec) -> Self { CssProperty::AnimationDuration(CssPropertyValue::Exact(input)) }
+    pub const fn animation_timing_function(input: StyleTransitionTimingFunctionVec) -> Self { CssProperty::AnimationTimingFunction(CssPropertyValue::Exact(input)) }
+    pub const fn animation_iteration_count(input: StyleAnimationIterationCountVec) -> Self { CssProperty::AnimationIterationCount(CssPropertyValue::Exact(input)) }
+    pub const fn animation_direction(input: StyleAnimationDirectionVec) -> Self { CssProperty::AnimationDirection(CssPropertyValue::Exact(input)) }
+    pub const fn animation_fill_mode(input: StyleAnimationFillModeVec) -> Self { CssProperty::AnimationFillMode(CssPropertyValue::Exact(input)) }
+    pub const fn animation_delay(input: StyleAnimationDelayVec) -> Self { CssProperty::AnimationDelay(CssPropertyValue::Exact(input)) }
 
     // functions that downcast to the concrete CSS type (style)
 
@@ -2056,11 +2304,23 @@ impl CssProperty {
     pub const fn as_transform_origin(&self) -> Option<&StyleTransformOriginValue> { match self { CssProperty::TransformOrigin(f) => Some(f), _ => None, } }
     pub const fn as_perspective_origin(&self) -> Option<&StylePerspectiveOriginValue> { match self { CssProperty::PerspectiveOrigin(f) => Some(f), _ => None, } }
     pub const fn as_backface_visibility(&self) -> Option<&StyleBackfaceVisibilityValue> { match self { CssProperty::BackfaceVisibility(f) => Some(f), _ => None, } }
+    pub const fn as_transition_property(&self) -> Option<&StyleTransitionPropertyVecValue> { match self { CssProperty::TransitionProperty(f) => Some(f), _ => None, } }
+    pub const fn as_transition_duration(&self) -> Option<&StyleTransitionDurationVecValue> { match self { CssProperty::TransitionDuration(f) => Some(f), _ => None, } }
+    pub const fn as_transition_timing_function(&self) -> Option<&StyleTransitionTimingFunctionVecValue> { match self { CssProperty::TransitionTimingFunction(f) => Some(f), _ => None, } }
+    pub const fn as_transition_delay(&self) -> Option<&StyleTransitionDelayVecValue> { match self { CssProperty::TransitionDelay(f) => Some(f), _ => None, } }
+    pub const fn as_animation_name(&self) -> Option<&StyleAnimationNameVecValue> { match self { CssProperty::AnimationName(f) => Some(f), _ => None, } }
+    pub const fn as_animation_duration(&self) -> Option<&StyleAnimationDurationVecValue> { match self { CssProperty::AnimationDuration(f) => Some(f), _ => None, } }
+    pub const fn as_animation_timing_function(&self) -> Option<&StyleTransitionTimingFunctionVecValue> { match self { CssProperty::AnimationTimingFunction(f) => Some(f), _ => None, } }
+    pub const fn as_animation_iteration_count(&self) -> Option<&StyleAnimationIterationCountVecValue> { match self { CssProperty::AnimationIterationCount(f) => Some(f), _ => None, } }
+    pub const fn as_animation_direction(&self) -> Option<&StyleAnimationDirectionVecValue> { match self { CssProperty::AnimationDirection(f) => Some(f), _ => None, } }
+    pub const fn as_animation_fill_mode(&self) -> Option<&StyleAnimationFillModeVecValue> { match self { CssProperty::AnimationFillMode(f) => Some(f), _ => None, } }
+    pub const fn as_animation_delay(&self) -> Option<&StyleAnimationDelayVecValue> { match self { CssProperty::AnimationDelay(f) => Some(f), _ => None, } }
 
     // functions that downcast to the concrete CSS type (layout)
 
     pub const fn as_display(&self) -> Option<&LayoutDisplayValue> { match self { CssProperty::Display(f) => Some(f), _ => None, } }
     pub const fn as_float(&self) -> Option<&LayoutFloatValue> { match self { CssProperty::Float(f) => Some(f), _ => None, } }
+    pub const fn as_clear(&self) -> Option<&LayoutClearValue> { match self { CssProperty::Clear(f) => Some(f), _ => None, } }
     pub const fn as_box_sizing(&self) -> Option<&LayoutBoxSizingValue> { match self { CssProperty::BoxSizing(f) => Some(f), _ => None, } }
     pub const fn as_width(&self) -> Option<&LayoutWidthValue> { match self { CssProperty::Width(f) => Some(f), _ => None, } }
     pub const fn as_height(&self) -> Option<&LayoutHeightValue> { match self { CssProperty::Height(f) => Some(f), _ => None, } }
@@ -2094,6 +2354,12 @@ impl CssProperty {
     pub const fn as_justify_content(&self) -> Option<&LayoutJustifyContentValue> { match self { CssProperty::JustifyContent(f) => Some(f), _ => None, } }
     pub const fn as_align_items(&self) -> Option<&LayoutAlignItemsValue> { match self { CssProperty::AlignItems(f) => Some(f), _ => None, } }
     pub const fn as_align_content(&self) -> Option<&LayoutAlignContentValue> { match self { CssProperty::AlignContent(f) => Some(f), _ => None, } }
+    pub const fn as_grid_template_columns(&self) -> Option<&LayoutGridTemplateColumnsValue> { match self { CssProperty::GridTemplateColumns(f) => Some(f), _ => None, } }
+    pub const fn as_grid_template_rows(&self) -> Option<&LayoutGridTemplateRowsValue> { match self { CssProperty::GridTemplateRows(f) => Some(f), _ => None, } }
+    pub const fn as_grid_column(&self) -> Option<&LayoutGridColumnValue> { match self { CssProperty::GridColumn(f) => Some(f), _ => None, } }
+    pub const fn as_grid_row(&self) -> Option<&LayoutGridRowValue> { match self { CssProperty::GridRow(f) => Some(f), _ => None, } }
+    pub const fn as_column_gap(&self) -> Option<&LayoutColumnGapValue> { match self { CssProperty::ColumnGap(f) => Some(f), _ => None, } }
+    pub const fn as_row_gap(&self) -> Option<&LayoutRowGapValue> { match self { CssProperty::RowGap(f) => Some(f), _ => None, } }
 }
 
 macro_rules! impl_from_css_prop {
@@ -2117,6 +2383,7 @@ impl_from_css_prop!(StyleTabWidth, CssProperty::TabWidth);
 impl_from_css_prop!(StyleCursor, CssProperty::Cursor);
 impl_from_css_prop!(LayoutDisplay, CssProperty::Display);
 impl_from_css_prop!(LayoutFloat, CssProperty::Float);
+impl_from_css_prop!(LayoutClear, CssProperty::Clear);
 impl_from_css_prop!(LayoutBoxSizing, CssProperty::BoxSizing);
 impl_from_css_prop!(LayoutWidth, CssProperty::Width);
 impl_from_css_prop!(LayoutHeight, CssProperty::Height);
@@ -2136,6 +2403,12 @@ impl_from_css_prop!(LayoutFlexShrink, CssProperty::FlexShrink);
 impl_from_css_prop!(LayoutJustifyContent, CssProperty::JustifyContent);
 impl_from_css_prop!(LayoutAlignItems, CssProperty::AlignItems);
 impl_from_css_prop!(LayoutAlignContent, CssProperty::AlignContent);
+impl_from_css_prop!(LayoutGridTemplateColumns, CssProperty::GridTemplateColumns);
+impl_from_css_prop!(LayoutGridTemplateRows, CssProperty::GridTemplateRows);
+impl_from_css_prop!(LayoutGridColumn, CssProperty::GridColumn);
+impl_from_css_prop!(LayoutGridRow, CssProperty::GridRow);
+impl_from_css_prop!(LayoutColumnGap, CssProperty::ColumnGap);
+impl_from_css_prop!(LayoutRowGap, CssProperty::RowGap);
 impl_from_css_prop!(StyleBackgroundContentVec, CssProperty::BackgroundContent);
 impl_from_css_prop!(StyleBackgroundPositionVec, CssProperty::BackgroundPosition);
 impl_from_css_prop!(StyleBackgroundSizeVec, CssProperty::BackgroundSize);
@@ -2170,6 +2443,16 @@ impl_from_css_prop!(StyleTransformVec, CssProperty::Transform);
 impl_from_css_prop!(StyleTransformOrigin, CssProperty::TransformOrigin);
 impl_from_css_prop!(StylePerspectiveOrigin, CssProperty::PerspectiveOrigin);
 impl_from_css_prop!(StyleBackfaceVisibility, CssProperty::BackfaceVisibility);
+impl_from_css_prop!(StyleTransitionPropertyVec, CssProperty::TransitionProperty);
+impl_from_css_prop!(StyleTransitionDurationVec, CssProperty::TransitionDuration);
+impl_from_css_prop!(StyleTransitionTimingFunctionVec, CssProperty::TransitionTimingFunction);
+impl_from_css_prop!(StyleTransitionDelayVec, CssProperty::TransitionDelay);
+impl_from_css_prop!(StyleAnimationNameVec, CssProperty::AnimationName);
+impl_from_css_prop!(StyleAnimationDurationVec, CssProperty::AnimationDuration);
+impl_from_css_prop!(StyleAnimationIterationCountVec, CssProperty::AnimationIterationCount);
+impl_from_css_prop!(StyleAnimationDirectionVec, CssProperty::AnimationDirection);
+impl_from_css_prop!(StyleAnimationFillModeVec, CssProperty::AnimationFillMode);
+impl_from_css_prop!(StyleAnimationDelayVec, CssProperty::AnimationDelay);
 
 /// Multiplier for floating point accuracy. Elements such as px or %
 /// are only accurate until a certain number of decimal points, therefore
@@ -2334,23 +2617,38 @@ impl AngleValue {
         }
     }
 
+    #[inline]
+    pub fn interpolate(&self, other: &Self, t: f32) -> Self {
+        if self.metric == other.metric {
+            // not normalized, so that 0deg -> 360deg is a full rotation
+            Self { metric: self.metric, number: self.number.interpolate(&other.number, t) }
+        } else {
+            let self_deg = self.to_degrees_unclamped();
+            let other_deg = other.to_degrees_unclamped();
+            Self::deg(self_deg + (other_deg - self_deg) * t)
+        }
+    }
+
     /// Returns the value of the AngleMetric in degrees
     #[inline]
     pub fn to_degrees(&self) -> f32 {
-        let val = match self.metric {
-            AngleMetric::Degree => self.number.get(),
-            AngleMetric::Radians => self.number.get() / 400.0 * 360.0,
-            AngleMetric::Grad => self.number.get() / (2.0 * core::f32::consts::PI) * 360.0,
-            AngleMetric::Turn => self.number.get() * 360.0,
-            AngleMetric::Percent => self.number.get() / 100.0 * 360.0,
-        };
-
+        let val = self.to_degrees_unclamped();
 
         // clamp the degree to a positive value from 0 to 360 (so 410deg = 50deg)
         let mut val = val % 360.0;
         if val < 0.0 { val = 360.0 + val; }
         val
     }
+
+    fn to_degrees_unclamped(&self) -> f32 {
+        match self.metric {
+            AngleMetric::Degree => self.number.get(),
+            AngleMetric::Radians => self.number.get() / 400.0 * 360.0,
+            AngleMetric::Grad => self.number.get() / (2.0 * core::f32::consts::PI) * 360.0,
+            AngleMetric::Turn => self.number.get() * 360.0,
+            AngleMetric::Percent => self.number.get() / 100.0 * 360.0,
+        }
+    }
 }
 
 #[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -3618,6 +3916,7 @@ pub enum LayoutDisplay {
     Flex,
     Block,
     InlineBlock,
+    Grid,
 }
 
 impl Default for LayoutDisplay {
@@ -3632,11 +3931,29 @@ impl Default for LayoutDisplay {
 pub enum LayoutFloat {
     Left,
     Right,
+    None,
 }
 
 impl Default for LayoutFloat {
     fn default() -> Self {
-        LayoutFloat::Left
+        LayoutFloat::None
+    }
+}
+
+/// Represents a `clear` attribute: on which sides the node may not be
+/// placed next to floating nodes that come before it
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum LayoutClear {
+    None,
+    Left,
+    Right,
+    Both,
+}
+
+impl Default for LayoutClear {
+    fn default() -> Self {
+        LayoutClear::None
     }
 }
 
@@ -3748,6 +4065,123 @@ impl Default for LayoutAlignContent {
     }
 }
 
+/// Breadth of a single grid track, used as the minimum / maximum inside of a `minmax()`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum GridTrackBreadth {
+    /// Fixed size, i.e. `100px` or `20%` (percentages resolve against the size of the grid container)
+    Fixed(PixelValue),
+    /// Flexible size, i.e. `1fr`
+    Fraction(FloatValue),
+    /// Sized to the minimum content size of the items in the track
+    Auto,
+}
+
+/// Represents a `minmax(min, max)` track size
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct GridMinMax {
+    pub min: GridTrackBreadth,
+    pub max: GridTrackBreadth,
+}
+
+/// Represents one track inside of a `grid-template-columns` or `grid-template-rows` attribute
+///
+/// `repeat(n, ...)` is expanded while parsing, so it has no representation here.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum GridTrackSizing {
+    /// `100px`, `20%`, `5em`
+    Fixed(PixelValue),
+    /// `1fr`, `2.5fr`
+    Fraction(FloatValue),
+    /// `auto`
+    Auto,
+    /// `minmax(100px, 1fr)`
+    MinMax(GridMinMax),
+}
+
+impl GridTrackSizing {
+    /// Returns the minimum sizing function of this track
+    pub const fn min_breadth(&self) -> GridTrackBreadth {
+        match self {
+            GridTrackSizing::Fixed(p) => GridTrackBreadth::Fixed(*p),
+            // the minimum of a flexible track is `auto`, see CSS Grid 7.2.4
+            GridTrackSizing::Fraction(_) => GridTrackBreadth::Auto,
+            GridTrackSizing::Auto => GridTrackBreadth::Auto,
+            GridTrackSizing::MinMax(m) => m.min,
+        }
+    }
+
+    /// Returns the maximum sizing function of this track
+    pub const fn max_breadth(&self) -> GridTrackBreadth {
+        match self {
+            GridTrackSizing::Fixed(p) => GridTrackBreadth::Fixed(*p),
+            GridTrackSizing::Fraction(f) => GridTrackBreadth::Fraction(*f),
+            GridTrackSizing::Auto => GridTrackBreadth::Auto,
+            GridTrackSizing::MinMax(m) => m.max,
+        }
+    }
+}
+
+impl_vec!(GridTrackSizing, GridTrackSizingVec, GridTrackSizingVecDestructor);
+impl_vec_debug!(GridTrackSizing, GridTrackSizingVec);
+impl_vec_partialord!(GridTrackSizing, GridTrackSizingVec);
+impl_vec_ord!(GridTrackSizing, GridTrackSizingVec);
+impl_vec_clone!(GridTrackSizing, GridTrackSizingVec, GridTrackSizingVecDestructor);
+impl_vec_partialeq!(GridTrackSizing, GridTrackSizingVec);
+impl_vec_eq!(GridTrackSizing, GridTrackSizingVec);
+impl_vec_hash!(GridTrackSizing, GridTrackSizingVec);
+
+/// Represents a `grid-template-columns` attribute - default: no explicit tracks
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct LayoutGridTemplateColumns { pub tracks: GridTrackSizingVec }
+
+/// Represents a `grid-template-rows` attribute - default: no explicit tracks
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct LayoutGridTemplateRows { pub tracks: GridTrackSizingVec }
+
+/// One end of a `grid-column` / `grid-row` placement
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum GridLine {
+    /// `auto`: placed by the auto-placement algorithm
+    Auto,
+    /// `3` or `-1`: 1-based line number, negative numbers count from the end of the explicit grid
+    Line(i32),
+    /// `span 2`: the item spans the given number of tracks
+    Span(u32),
+}
+
+impl Default for GridLine {
+    fn default() -> Self { GridLine::Auto }
+}
+
+/// Represents a `grid-column` attribute (`start / end`) - default: `auto / auto`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct LayoutGridColumn { pub start: GridLine, pub end: GridLine }
+
+/// Represents a `grid-row` attribute (`start / end`) - default: `auto / auto`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct LayoutGridRow { pub start: GridLine, pub end: GridLine }
+
+/// Represents a `column-gap` attribute
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct LayoutColumnGap { pub inner: PixelValue }
+
+/// Represents a `row-gap` attribute
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct LayoutRowGap { pub inner: PixelValue }
+
+impl_pixel_value!(LayoutColumnGap);
+impl_pixel_value!(LayoutRowGap);
+
 /// Represents a `overflow-x` or `overflow-y` property, see
 /// [`TextOverflowBehaviour`](./struct.TextOverflowBehaviour.html) - default: `Auto`
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -3896,6 +4330,281 @@ impl Default for StyleBackfaceVisibility {
     fn default() -> Self { StyleBackfaceVisibility::Visible }
 }
 
+/// Represents one entry of a `transition-property` attribute
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum StyleTransitionProperty {
+    /// `transition-property: all`
+    All,
+    /// Single property, i.e. `transition-property: opacity`
+    Property(CssPropertyType),
+}
+
+impl Default for StyleTransitionProperty {
+    fn default() -> Self { StyleTransitionProperty::All }
+}
+
+impl StyleTransitionProperty {
+    /// Returns whether a change of the `property` should be transitioned
+    pub fn matches(&self, property: &CssPropertyType) -> bool {
+        match self {
+            StyleTransitionProperty::All => true,
+            StyleTransitionProperty::Property(p) => p == property,
+        }
+    }
+}
+
+impl_vec!(StyleTransitionProperty, StyleTransitionPropertyVec, StyleTransitionPropertyVecDestructor);
+impl_vec_debug!(StyleTransitionProperty, StyleTransitionPropertyVec);
+impl_vec_partialord!(StyleTransitionProperty, StyleTransitionPropertyVec);
+impl_vec_ord!(StyleTransitionProperty, StyleTransitionPropertyVec);
+impl_vec_clone!(StyleTransitionProperty, StyleTransitionPropertyVec, StyleTransitionPropertyVecDestructor);
+impl_vec_partialeq!(StyleTransitionProperty, StyleTransitionPropertyVec);
+impl_vec_eq!(StyleTransitionProperty, StyleTransitionPropertyVec);
+impl_vec_hash!(StyleTransitionProperty, StyleTransitionPropertyVec);
+
+/// Represents one entry of a `transition-duration` attribute, in milliseconds
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleTransitionDuration { pub ms: u32 }
+
+impl StyleTransitionDuration {
+    pub const fn ms(ms: u32) -> Self { Self { ms } }
+}
+
+impl_vec!(StyleTransitionDuration, StyleTransitionDurationVec, StyleTransitionDurationVecDestructor);
+impl_vec_debug!(StyleTransitionDuration, StyleTransitionDurationVec);
+impl_vec_partialord!(StyleTransitionDuration, StyleTransitionDurationVec);
+impl_vec_ord!(StyleTransitionDuration, StyleTransitionDurationVec);
+impl_vec_clone!(StyleTransitionDuration, StyleTransitionDurationVec, StyleTransitionDurationVecDestructor);
+impl_vec_partialeq!(StyleTransitionDuration, StyleTransitionDurationVec);
+impl_vec_eq!(StyleTransitionDuration, StyleTransitionDurationVec);
+impl_vec_hash!(StyleTransitionDuration, StyleTransitionDurationVec);
+
+/// Represents one entry of a `transition-delay` attribute, in milliseconds
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleTransitionDelay { pub ms: u32 }
+
+impl StyleTransitionDelay {
+    pub const fn ms(ms: u32) -> Self { Self { ms } }
+}
+
+impl_vec!(StyleTransitionDelay, StyleTransitionDelayVec, StyleTransitionDelayVecDestructor);
+impl_vec_debug!(StyleTransitionDelay, StyleTransitionDelayVec);
+impl_vec_partialord!(StyleTransitionDelay, StyleTransitionDelayVec);
+impl_vec_ord!(StyleTransitionDelay, StyleTransitionDelayVec);
+impl_vec_clone!(StyleTransitionDelay, StyleTransitionDelayVec, StyleTransitionDelayVecDestructor);
+impl_vec_partialeq!(StyleTransitionDelay, StyleTransitionDelayVec);
+impl_vec_eq!(StyleTransitionDelay, StyleTransitionDelayVec);
+impl_vec_hash!(StyleTransitionDelay, StyleTransitionDelayVec);
+
+/// Represents one entry of a `transition-timing-function` attribute
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum StyleTransitionTimingFunction {
+    Ease,
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    /// `cubic-bezier(x1, y1, x2, y2)`
+    CubicBezier(StyleCubicBezier),
+}
+
+impl Default for StyleTransitionTimingFunction {
+    fn default() -> Self { StyleTransitionTimingFunction::Ease }
+}
+
+impl StyleTransitionTimingFunction {
+    /// Converts the (hashable) CSS value into the easing curve used for interpolating properties
+    pub fn to_interpolation_function(&self) -> AnimationInterpolationFunction {
+        match self {
+            StyleTransitionTimingFunction::Ease => AnimationInterpolationFunction::Ease,
+            StyleTransitionTimingFunction::Linear => AnimationInterpolationFunction::Linear,
+            StyleTransitionTimingFunction::EaseIn => AnimationInterpolationFunction::EaseIn,
+            StyleTransitionTimingFunction::EaseOut => AnimationInterpolationFunction::EaseOut,
+            StyleTransitionTimingFunction::EaseInOut => AnimationInterpolationFunction::EaseInOut,
+            StyleTransitionTimingFunction::CubicBezier(c) => AnimationInterpolationFunction::CubicBezier(SvgCubicCurve {
+                start: SvgPoint { x: 0.0, y: 0.0 },
+                ctrl_1: SvgPoint { x: c.x1.get(), y: c.y1.get() },
+                ctrl_2: SvgPoint { x: c.x2.get(), y: c.y2.get() },
+                end: SvgPoint { x: 1.0, y: 1.0 },
+            }),
+        }
+    }
+}
+
+impl_vec!(StyleTransitionTimingFunction, StyleTransitionTimingFunctionVec, StyleTransitionTimingFunctionVecDestructor);
+impl_vec_debug!(StyleTransitionTimingFunction, StyleTransitionTimingFunctionVec);
+impl_vec_partialord!(StyleTransitionTimingFunction, StyleTransitionTimingFunctionVec);
+impl_vec_ord!(StyleTransitionTimingFunction, StyleTransitionTimingFunctionVec);
+impl_vec_clone!(StyleTransitionTimingFunction, StyleTransitionTimingFunctionVec, StyleTransitionTimingFunctionVecDestructor);
+impl_vec_partialeq!(StyleTransitionTimingFunction, StyleTransitionTimingFunctionVec);
+impl_vec_eq!(StyleTransitionTimingFunction, StyleTransitionTimingFunctionVec);
+impl_vec_hash!(StyleTransitionTimingFunction, StyleTransitionTimingFunctionVec);
+
+/// Control points of a `cubic-bezier(x1, y1, x2, y2)` timing function
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleCubicBezier {
+    pub x1: FloatValue,
+    pub y1: FloatValue,
+    pub x2: FloatValue,
+    pub y2: FloatValue,
+}
+
+/// Represents one entry of an `animation-name` attribute, references an `@keyframes` rule
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleAnimationName { pub name: AzString }
+
+impl_vec!(StyleAnimationName, StyleAnimationNameVec, StyleAnimationNameVecDestructor);
+impl_vec_debug!(StyleAnimationName, StyleAnimationNameVec);
+impl_vec_partialord!(StyleAnimationName, StyleAnimationNameVec);
+impl_vec_ord!(StyleAnimationName, StyleAnimationNameVec);
+impl_vec_clone!(StyleAnimationName, StyleAnimationNameVec, StyleAnimationNameVecDestructor);
+impl_vec_partialeq!(StyleAnimationName, StyleAnimationNameVec);
+impl_vec_eq!(StyleAnimationName, StyleAnimationNameVec);
+impl_vec_hash!(StyleAnimationName, StyleAnimationNameVec);
+
+/// Represents one entry of an `animation-duration` attribute, in milliseconds
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleAnimationDuration { pub ms: u32 }
+
+impl StyleAnimationDuration {
+    pub const fn ms(ms: u32) -> Self { Self { ms } }
+}
+
+impl_vec!(StyleAnimationDuration, StyleAnimationDurationVec, StyleAnimationDurationVecDestructor);
+impl_vec_debug!(StyleAnimationDuration, StyleAnimationDurationVec);
+impl_vec_partialord!(StyleAnimationDuration, StyleAnimationDurationVec);
+impl_vec_ord!(StyleAnimationDuration, StyleAnimationDurationVec);
+impl_vec_clone!(StyleAnimationDuration, StyleAnimationDurationVec, StyleAnimationDurationVecDestructor);
+impl_vec_partialeq!(StyleAnimationDuration, StyleAnimationDurationVec);
+impl_vec_eq!(StyleAnimationDuration, StyleAnimationDurationVec);
+impl_vec_hash!(StyleAnimationDuration, StyleAnimationDurationVec);
+
+/// Represents one entry of an `animation-delay` attribute, in milliseconds
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleAnimationDelay { pub ms: u32 }
+
+impl StyleAnimationDelay {
+    pub const fn ms(ms: u32) -> Self { Self { ms } }
+}
+
+impl_vec!(StyleAnimationDelay, StyleAnimationDelayVec, StyleAnimationDelayVecDestructor);
+impl_vec_debug!(StyleAnimationDelay, StyleAnimationDelayVec);
+impl_vec_partialord!(StyleAnimationDelay, StyleAnimationDelayVec);
+impl_vec_ord!(StyleAnimationDelay, StyleAnimationDelayVec);
+impl_vec_clone!(StyleAnimationDelay, StyleAnimationDelayVec, StyleAnimationDelayVecDestructor);
+impl_vec_partialeq!(StyleAnimationDelay, StyleAnimationDelayVec);
+impl_vec_eq!(StyleAnimationDelay, StyleAnimationDelayVec);
+impl_vec_hash!(StyleAnimationDelay, StyleAnimationDelayVec);
+
+/// Represents one entry of an `animation-iteration-count` attribute
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum StyleAnimationIterationCount {
+    Count(u32),
+    Infinite,
+}
+
+impl Default for StyleAnimationIterationCount {
+    fn default() -> Self { StyleAnimationIterationCount::Count(1) }
+}
+
+impl_vec!(StyleAnimationIterationCount, StyleAnimationIterationCountVec, StyleAnimationIterationCountVecDestructor);
+impl_vec_debug!(StyleAnimationIterationCount, StyleAnimationIterationCountVec);
+impl_vec_partialord!(StyleAnimationIterationCount, StyleAnimationIterationCountVec);
+impl_vec_ord!(StyleAnimationIterationCount, StyleAnimationIterationCountVec);
+impl_vec_clone!(StyleAnimationIterationCount, StyleAnimationIterationCountVec, StyleAnimationIterationCountVecDestructor);
+impl_vec_partialeq!(StyleAnimationIterationCount, StyleAnimationIterationCountVec);
+impl_vec_eq!(StyleAnimationIterationCount, StyleAnimationIterationCountVec);
+impl_vec_hash!(StyleAnimationIterationCount, StyleAnimationIterationCountVec);
+
+/// Represents one entry of an `animation-direction` attribute
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleAnimationDirection {
+    Normal,
+    Reverse,
+    Alternate,
+    AlternateReverse,
+}
+
+impl Default for StyleAnimationDirection {
+    fn default() -> Self { StyleAnimationDirection::Normal }
+}
+
+impl StyleAnimationDirection {
+    /// Maps the `progress` (0.0 - 1.0) of the `iteration`th iteration to the
+    /// position on the keyframes, i.e. `reverse` plays the keyframes from 100% to 0%
+    pub fn get_keyframe_offset(&self, iteration: u32, progress: f32) -> f32 {
+        let is_odd_iteration = iteration % 2 == 1;
+        let reverse = match self {
+            StyleAnimationDirection::Normal => false,
+            StyleAnimationDirection::Reverse => true,
+            StyleAnimationDirection::Alternate => is_odd_iteration,
+            StyleAnimationDirection::AlternateReverse => !is_odd_iteration,
+        };
+        if reverse { 1.0 - progress } else { progress }
+    }
+}
+
+impl_vec!(StyleAnimationDirection, StyleAnimationDirectionVec, StyleAnimationDirectionVecDestructor);
+impl_vec_debug!(StyleAnimationDirection, StyleAnimationDirectionVec);
+impl_vec_partialord!(StyleAnimationDirection, StyleAnimationDirectionVec);
+impl_vec_ord!(StyleAnimationDirection, StyleAnimationDirectionVec);
+impl_vec_clone!(StyleAnimationDirection, StyleAnimationDirectionVec, StyleAnimationDirectionVecDestructor);
+impl_vec_partialeq!(StyleAnimationDirection, StyleAnimationDirectionVec);
+impl_vec_eq!(StyleAnimationDirection, StyleAnimationDirectionVec);
+impl_vec_hash!(StyleAnimationDirection, StyleAnimationDirectionVec);
+
+/// Represents one entry of an `animation-fill-mode` attribute
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleAnimationFillMode {
+    /// Animated values are only applied while the animation is running
+    None,
+    /// The last keyframe stays applied after the animation has finished
+    Forwards,
+    /// The first keyframe is already applied during the `animation-delay`
+    Backwards,
+    /// Both `forwards` and `backwards`
+    Both,
+}
+
+impl Default for StyleAnimationFillMode {
+    fn default() -> Self { StyleAnimationFillMode::None }
+}
+
+impl StyleAnimationFillMode {
+    pub fn fills_forwards(&self) -> bool {
+        match self {
+            StyleAnimationFillMode::Forwards | StyleAnimationFillMode::Both => true,
+            _ => false,
+        }
+    }
+    pub fn fills_backwards(&self) -> bool {
+        match self {
+            StyleAnimationFillMode::Backwards | StyleAnimationFillMode::Both => true,
+            _ => false,
+        }
+    }
+}
+
+impl_vec!(StyleAnimationFillMode, StyleAnimationFillModeVec, StyleAnimationFillModeVecDestructor);
+impl_vec_debug!(StyleAnimationFillMode, StyleAnimationFillModeVec);
+impl_vec_partialord!(StyleAnimationFillMode, StyleAnimationFillModeVec);
+impl_vec_ord!(StyleAnimationFillMode, StyleAnimationFillModeVec);
+impl_vec_clone!(StyleAnimationFillMode, StyleAnimationFillModeVec, StyleAnimationFillModeVecDestructor);
+impl_vec_partialeq!(StyleAnimationFillMode, StyleAnimationFillModeVec);
+impl_vec_eq!(StyleAnimationFillMode, StyleAnimationFillModeVec);
+impl_vec_hash!(StyleAnimationFillMode, StyleAnimationFillModeVec);
+
 /// Represents an `opacity` attribute
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C, u8)]
@@ -3923,6 +4632,112 @@ pub enum StyleTransform {
     Perspective(PixelValue),
 }
 
+impl StyleTransform {
+
+    /// Returns the transform function of the same kind that doesn't transform anything,
+    /// i.e. `rotate(0deg)` for `rotate(45deg)`
+    fn identity(&self) -> Option<Self> {
+        use self::StyleTransform::*;
+        let one = PercentageValue::new(100.0);
+        let zero = PercentageValue::new(0.0);
+        match self {
+            Translate(_) => Some(Translate(StyleTransformTranslate2D { x: PixelValue::zero(), y: PixelValue::zero() })),
+            Translate3D(_) => Some(Translate3D(StyleTransformTranslate3D { x: PixelValue::zero(), y: PixelValue::zero(), z: PixelValue::zero() })),
+            TranslateX(_) => Some(TranslateX(PixelValue::zero())),
+            TranslateY(_) => Some(TranslateY(PixelValue::zero())),
+            TranslateZ(_) => Some(TranslateZ(PixelValue::zero())),
+            Rotate(a) => Some(Rotate(AngleValue::from_metric(a.metric, 0.0))),
+            Rotate3D(r) => Some(Rotate3D(StyleTransformRotate3D { angle: AngleValue::from_metric(r.angle.metric, 0.0), .. *r })),
+            RotateX(a) => Some(RotateX(AngleValue::from_metric(a.metric, 0.0))),
+            RotateY(a) => Some(RotateY(AngleValue::from_metric(a.metric, 0.0))),
+            RotateZ(a) => Some(RotateZ(AngleValue::from_metric(a.metric, 0.0))),
+            Scale(_) => Some(Scale(StyleTransformScale2D { x: one, y: one })),
+            Scale3D(_) => Some(Scale3D(StyleTransformScale3D { x: one, y: one, z: one })),
+            ScaleX(_) => Some(ScaleX(one)),
+            ScaleY(_) => Some(ScaleY(one)),
+            ScaleZ(_) => Some(ScaleZ(one)),
+            Skew(_) => Some(Skew(StyleTransformSkew2D { x: zero, y: zero })),
+            SkewX(_) => Some(SkewX(zero)),
+            SkewY(_) => Some(SkewY(zero)),
+            Matrix(_) | Matrix3D(_) | Perspective(_) => None,
+        }
+    }
+
+    /// Interpolates two transform functions of the same kind,
+    /// returns `None` if the functions can't be interpolated
+    pub fn interpolate(&self, other: &Self, t: f32) -> Option<Self> {
+        use self::StyleTransform::*;
+        match (self, other) {
+            (Translate(a), Translate(b)) => Some(Translate(StyleTransformTranslate2D {
+                x: a.x.interpolate(&b.x, t),
+                y: a.y.interpolate(&b.y, t),
+            })),
+            (Translate3D(a), Translate3D(b)) => Some(Translate3D(StyleTransformTranslate3D {
+                x: a.x.interpolate(&b.x, t),
+                y: a.y.interpolate(&b.y, t),
+                z: a.z.interpolate(&b.z, t),
+            })),
+            (TranslateX(a), TranslateX(b)) => Some(TranslateX(a.interpolate(b, t))),
+            (TranslateY(a), TranslateY(b)) => Some(TranslateY(a.interpolate(b, t))),
+            (TranslateZ(a), TranslateZ(b)) => Some(TranslateZ(a.interpolate(b, t))),
+            (Rotate(a), Rotate(b)) => Some(Rotate(a.interpolate(b, t))),
+            (Rotate3D(a), Rotate3D(b)) if a.x == b.x && a.y == b.y && a.z == b.z => {
+                Some(Rotate3D(StyleTransformRotate3D { angle: a.angle.interpolate(&b.angle, t), .. *a }))
+            },
+            (RotateX(a), RotateX(b)) => Some(RotateX(a.interpolate(b, t))),
+            (RotateY(a), RotateY(b)) => Some(RotateY(a.interpolate(b, t))),
+            (RotateZ(a), RotateZ(b)) => Some(RotateZ(a.interpolate(b, t))),
+            (Scale(a), Scale(b)) => Some(Scale(StyleTransformScale2D {
+                x: a.x.interpolate(&b.x, t),
+                y: a.y.interpolate(&b.y, t),
+            })),
+            (Scale3D(a), Scale3D(b)) => Some(Scale3D(StyleTransformScale3D {
+                x: a.x.interpolate(&b.x, t),
+                y: a.y.interpolate(&b.y, t),
+                z: a.z.interpolate(&b.z, t),
+            })),
+            (ScaleX(a), ScaleX(b)) => Some(ScaleX(a.interpolate(b, t))),
+            (ScaleY(a), ScaleY(b)) => Some(ScaleY(a.interpolate(b, t))),
+            (ScaleZ(a), ScaleZ(b)) => Some(ScaleZ(a.interpolate(b, t))),
+            (Skew(a), Skew(b)) => Some(Skew(StyleTransformSkew2D {
+                x: a.x.interpolate(&b.x, t),
+                y: a.y.interpolate(&b.y, t),
+            })),
+            (SkewX(a), SkewX(b)) => Some(SkewX(a.interpolate(b, t))),
+            (SkewY(a), SkewY(b)) => Some(SkewY(a.interpolate(b, t))),
+            (Perspective(a), Perspective(b)) => Some(Perspective(a.interpolate(b, t))),
+            _ => None,
+        }
+    }
+}
+
+impl StyleTransformVec {
+    /// Interpolates two lists of transform functions, a missing (empty) list is
+    /// treated as the identity of the other list, i.e. `none` -> `rotate(360deg)`.
+    ///
+    /// Returns `None` if the lists don't consist of the same transform functions.
+    pub fn interpolate(&self, other: &Self, t: f32) -> Option<Self> {
+
+        let identity = |transforms: &Self| transforms.iter().map(|t| t.identity()).collect::<Option<Vec<_>>>();
+
+        let (start, end) = match (self.is_empty(), other.is_empty()) {
+            (true, true) => return Some(self.clone()),
+            (true, false) => (identity(other)?, other.clone().into_library_owned_vec()),
+            (false, true) => (self.clone().into_library_owned_vec(), identity(self)?),
+            (false, false) => (self.clone().into_library_owned_vec(), other.clone().into_library_owned_vec()),
+        };
+
+        if start.len() != end.len() {
+            return None;
+        }
+
+        start.iter().zip(end.iter())
+        .map(|(s, e)| s.interpolate(e, t))
+        .collect::<Option<Vec<_>>>()
+        .map(|v| v.into())
+    }
+}
+
 impl_vec!(StyleTransform, StyleTransformVec, StyleTransformVecDestructor);
 impl_vec_debug!(StyleTransform, StyleTransformVec);
 impl_vec_partialord!(StyleTransform, StyleTransformVec);
@@ -4042,11 +4857,23 @@ pub type StyleTransformVecValue = CssPropertyValue<StyleTransformVec>;
 pub type StyleTransformOriginValue = CssPropertyValue<StyleTransformOrigin>;
 pub type StylePerspectiveOriginValue = CssPropertyValue<StylePerspectiveOrigin>;
 pub type StyleBackfaceVisibilityValue = CssPropertyValue<StyleBackfaceVisibility>;
+pub type StyleTransitionPropertyVecValue = CssPropertyValue<StyleTransitionPropertyVec>;
+pub type StyleTransitionDurationVecValue = CssPropertyValue<StyleTransitionDurationVec>;
+pub type StyleTransitionTimingFunctionVecValue = CssPropertyValue<StyleTransitionTimingFunctionVec>;
+pub type StyleTransitionDelayVecValue = CssPropertyValue<StyleTransitionDelayVec>;
+pub type StyleAnimationNameVecValue = CssPropertyValue<StyleAnimationNameVec>;
+pub type StyleAnimationDurationVecValue = CssPropertyValue<StyleAnimationDurationVec>;
+pub type StyleAnimationIterationCountVecValue = CssPropertyValue<StyleAnimationIterationCountVec>;
+pub type StyleAnimationDirectionVecValue = CssPropertyValue<StyleAnimationDirectionVec>;
+pub type StyleAnimationFillModeVecValue = CssPropertyValue<StyleAnimationFillModeVec>;
+pub type StyleAnimationDelayVecValue = CssPropertyValue<StyleAnimationDelayVec>;
 pub type ScrollbarStyleValue = CssPropertyValue<ScrollbarStyle>;
 pub type LayoutDisplayValue = CssPropertyValue<LayoutDisplay>;
 impl_option!(LayoutDisplayValue, OptionLayoutDisplayValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
 pub type LayoutFloatValue = CssPropertyValue<LayoutFloat>;
+pub type LayoutClearValue = CssPropertyValue<LayoutClear>;
 impl_option!(LayoutFloatValue, OptionLayoutFloatValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+impl_option!(LayoutClearValue, OptionLayoutClearValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
 pub type LayoutBoxSizingValue = CssPropertyValue<LayoutBoxSizing>;
 impl_option!(LayoutBoxSizingValue, OptionLayoutBoxSizingValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
 pub type LayoutWidthValue = CssPropertyValue<LayoutWidth>;
@@ -4107,6 +4934,18 @@ pub type LayoutAlignItemsValue = CssPropertyValue<LayoutAlignItems>;
 impl_option!(LayoutAlignItemsValue, OptionLayoutAlignItemsValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
 pub type LayoutAlignContentValue = CssPropertyValue<LayoutAlignContent>;
 impl_option!(LayoutAlignContentValue, OptionLayoutAlignContentValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type LayoutGridTemplateColumnsValue = CssPropertyValue<LayoutGridTemplateColumns>;
+impl_option!(LayoutGridTemplateColumnsValue, OptionLayoutGridTemplateColumnsValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type LayoutGridTemplateRowsValue = CssPropertyValue<LayoutGridTemplateRows>;
+impl_option!(LayoutGridTemplateRowsValue, OptionLayoutGridTemplateRowsValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type LayoutGridColumnValue = CssPropertyValue<LayoutGridColumn>;
+impl_option!(LayoutGridColumnValue, OptionLayoutGridColumnValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type LayoutGridRowValue = CssPropertyValue<LayoutGridRow>;
+impl_option!(LayoutGridRowValue, OptionLayoutGridRowValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type LayoutColumnGapValue = CssPropertyValue<LayoutColumnGap>;
+impl_option!(LayoutColumnGapValue, OptionLayoutColumnGapValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type LayoutRowGapValue = CssPropertyValue<LayoutRowGap>;
+impl_option!(LayoutRowGapValue, OptionLayoutRowGapValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
 
 /// Holds info necessary for layouting / styling scrollbars (-webkit-scrollbar)
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -100,6 +100,7 @@ impl PrintAsCssValue for LayoutDisplay {
             LayoutDisplay::Flex => "flex",
             LayoutDisplay::Block => "block",
             LayoutDisplay::InlineBlock => "inline-block",
+            LayoutDisplay::Grid => "grid",
         })
     }
 }
@@ -109,6 +110,18 @@ impl PrintAsCssValue for LayoutFloat {
         String::from(match self {
             LayoutFloat::Left => "left",
             LayoutFloat::Right => "right",
+            LayoutFloat::None => "none",
+        })
+    }
+}
+
+impl PrintAsCssValue for LayoutClear {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            LayoutClear::None => "none",
+            LayoutClear::Left => "left",
+            LayoutClear::Right => "right",
+            LayoutClear::Both => "both",
         })
     }
 }
@@ -262,6 +275,48 @@ impl PrintAsCssValue for LayoutAlignContent {
     }
 }
 
+impl PrintAsCssValue for LayoutGridTemplateColumns {
+    fn print_as_css_value(&self) -> String {
+        self.tracks.print_as_css_value()
+    }
+}
+
+impl PrintAsCssValue for LayoutGridTemplateRows {
+    fn print_as_css_value(&self) -> String {
+        self.tracks.print_as_css_value()
+    }
+}
+
+impl PrintAsCssValue for GridTrackSizingVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(" ")
+    }
+}
+
+impl PrintAsCssValue for LayoutGridColumn {
+    fn print_as_css_value(&self) -> String {
+        format!("{} / {}", self.start.print_as_css_value(), self.end.print_as_css_value())
+    }
+}
+
+impl PrintAsCssValue for LayoutGridRow {
+    fn print_as_css_value(&self) -> String {
+        format!("{} / {}", self.start.print_as_css_value(), self.end.print_as_css_value())
+    }
+}
+
+impl PrintAsCssValue for LayoutColumnGap {
+    fn print_as_css_value(&self) -> String {
+        format!("{}", self.inner)
+    }
+}
+
+impl PrintAsCssValue for LayoutRowGap {
+    fn print_as_css_value(&self) -> String {
+        format!("{}", self.inner)
+    }
+}
+
 impl PrintAsCssValue for StyleBackgroundContentVec {
     fn print_as_css_value(&self) -> String {
         self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
@@ -493,8 +548,182 @@ impl PrintAsCssValue for StyleBackfaceVisibility {
     }
 }
 
+impl PrintAsCssValue for StyleTransitionPropertyVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleTransitionProperty {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            StyleTransitionProperty::All => String::from("all"),
+            StyleTransitionProperty::Property(p) => String::from(p.to_str()),
+        }
+    }
+}
+
+impl PrintAsCssValue for StyleTransitionDurationVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleTransitionDuration {
+    fn print_as_css_value(&self) -> String {
+        format!("{}ms", self.ms)
+    }
+}
+
+impl PrintAsCssValue for StyleTransitionDelayVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleTransitionDelay {
+    fn print_as_css_value(&self) -> String {
+        format!("{}ms", self.ms)
+    }
+}
+
+impl PrintAsCssValue for StyleTransitionTimingFunctionVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleTransitionTimingFunction {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            StyleTransitionTimingFunction::Ease => String::from("ease"),
+            StyleTransitionTimingFunction::Linear => String::from("linear"),
+            StyleTransitionTimingFunction::EaseIn => String::from("ease-in"),
+            StyleTransitionTimingFunction::EaseOut => String::from("ease-out"),
+            StyleTransitionTimingFunction::EaseInOut => String::from("ease-in-out"),
+            StyleTransitionTimingFunction::CubicBezier(c) => format!("cubic-bezier({}, {}, {}, {})", c.x1, c.y1, c.x2, c.y2),
+        }
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationNameVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationName {
+    fn print_as_css_value(&self) -> String {
+        String::from(self.name.as_str())
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationDurationVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationDuration {
+    fn print_as_css_value(&self) -> String {
+        format!("{}ms", self.ms)
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationDelayVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationDelay {
+    fn print_as_css_value(&self) -> String {
+        format!("{}ms", self.ms)
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationIterationCountVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationIterationCount {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            StyleAnimationIterationCount::Count(c) => format!("{}", c),
+            StyleAnimationIterationCount::Infinite => String::from("infinite"),
+        }
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationDirectionVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationDirection {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleAnimationDirection::Normal => "normal",
+            StyleAnimationDirection::Reverse => "reverse",
+            StyleAnimationDirection::Alternate => "alternate",
+            StyleAnimationDirection::AlternateReverse => "alternate-reverse",
+        })
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationFillModeVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationFillMode {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleAnimationFillMode::None => "none",
+            StyleAnimationFillMode::Forwards => "forwards",
+            StyleAnimationFillMode::Backwards => "backwards",
+            StyleAnimationFillMode::Both => "both",
+        })
+    }
+}
+
 // extra ---
 
+impl PrintAsCssValue for GridTrackBreadth {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            GridTrackBreadth::Fixed(p) => format!("{}", p),
+            GridTrackBreadth::Fraction(f) => format!("{}fr", f),
+            GridTrackBreadth::Auto => format!("auto"),
+        }
+    }
+}
+
+impl PrintAsCssValue for GridTrackSizing {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            GridTrackSizing::Fixed(p) => format!("{}", p),
+            GridTrackSizing::Fraction(f) => format!("{}fr", f),
+            GridTrackSizing::Auto => format!("auto"),
+            GridTrackSizing::MinMax(m) => format!("minmax({}, {})", m.min.print_as_css_value(), m.max.print_as_css_value()),
+        }
+    }
+}
+
+impl PrintAsCssValue for GridLine {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            GridLine::Auto => format!("auto"),
+            GridLine::Line(l) => format!("{}", l),
+            GridLine::Span(s) => format!("span {}", s),
+        }
+    }
+}
+
 impl PrintAsCssValue for StyleTransform {
     fn print_as_css_value(&self) -> String {
         match self {
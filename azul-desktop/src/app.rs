@@ -12,10 +12,15 @@ use rust_fontconfig::FcFontCache;
 use std::fmt;
 use std::sync::Mutex;
 use std::thread::JoinHandle;
+#[cfg(not(target_os = "windows"))]
+use glutin::event_loop::EventLoop as GlutinEventLoop;
+#[cfg(not(target_os = "windows"))]
+use crate::window::UserEvent;
 
 pub(crate) const CALLBACKS: RenderCallbacks = RenderCallbacks {
     insert_into_active_gl_textures_fn: azul_core::gl::insert_into_active_gl_textures,
     layout_fn: azul_layout::do_the_layout,
+    layout_iframe_fn: azul_layout::do_the_iframe_layout,
     load_font_fn: azulc_lib::font_loading::font_source_get_bytes,
     parse_font_fn: azul_text_layout::parse_font_fn,
 };
@@ -27,6 +32,7 @@ pub struct AzAppPtr {
 }
 
 impl AzAppPtr {
+    #[cfg(not(test))]
     pub fn new(initial_data: RefAny, app_config: AppConfig) -> Self {
         Self {
             ptr: Box::new(Arc::new(Mutex::new(App::new(initial_data, app_config)))),
@@ -52,6 +58,7 @@ impl AzAppPtr {
             .unwrap_or(MonitorVec::from_const_slice(&[]))
     }
 
+    #[cfg(all(not(test), feature = "std"))]
     pub fn run(&self, root_window: WindowCreateOptions) {
         if let Ok(mut l) = self.ptr.try_lock() {
             let mut app = App::new(l.data.clone(), l.config.clone());
@@ -20,12 +20,9 @@ extern crate libm;
 #[cfg(not(target_os = "windows"))]
 extern crate glutin;
 #[cfg(not(target_os = "windows"))]
-extern crate gleam;
-#[cfg(not(target_os = "windows"))]
 extern crate coarsetime;
 #[cfg(target_os = "windows")]
 extern crate winapi;
-#[cfg(target_os = "windows")]
 extern crate gl_context_loader;
 
 #[macro_use]
@@ -0,0 +1,788 @@
+//! OpenGL function loading for the glutin windows (the win32 shell loads its own functions)
+
+use gl_context_loader::{GenericGlContext, c_void};
+
+/// Loads all OpenGL function pointers of the currently active context,
+/// `get_func` resolves a function name to its address (or null if unavailable)
+pub(crate) fn load_gl_context<F: Fn(&str) -> *mut c_void>(get_func: F) -> GenericGlContext {
+    GenericGlContext {
+        glAccum: get_func("glAccum"),
+        glActiveTexture: get_func("glActiveTexture"),
+        glAlphaFunc: get_func("glAlphaFunc"),
+        glAreTexturesResident: get_func("glAreTexturesResident"),
+        glArrayElement: get_func("glArrayElement"),
+        glAttachShader: get_func("glAttachShader"),
+        glBegin: get_func("glBegin"),
+        glBeginConditionalRender: get_func("glBeginConditionalRender"),
+        glBeginQuery: get_func("glBeginQuery"),
+        glBeginTransformFeedback: get_func("glBeginTransformFeedback"),
+        glBindAttribLocation: get_func("glBindAttribLocation"),
+        glBindBuffer: get_func("glBindBuffer"),
+        glBindBufferBase: get_func("glBindBufferBase"),
+        glBindBufferRange: get_func("glBindBufferRange"),
+        glBindFragDataLocation: get_func("glBindFragDataLocation"),
+        glBindFragDataLocationIndexed: get_func("glBindFragDataLocationIndexed"),
+        glBindFramebuffer: get_func("glBindFramebuffer"),
+        glBindRenderbuffer: get_func("glBindRenderbuffer"),
+        glBindSampler: get_func("glBindSampler"),
+        glBindTexture: get_func("glBindTexture"),
+        glBindVertexArray: get_func("glBindVertexArray"),
+        glBindVertexArrayAPPLE: get_func("glBindVertexArrayAPPLE"),
+        glBitmap: get_func("glBitmap"),
+        glBlendBarrierKHR: get_func("glBlendBarrierKHR"),
+        glBlendColor: get_func("glBlendColor"),
+        glBlendEquation: get_func("glBlendEquation"),
+        glBlendEquationSeparate: get_func("glBlendEquationSeparate"),
+        glBlendFunc: get_func("glBlendFunc"),
+        glBlendFuncSeparate: get_func("glBlendFuncSeparate"),
+        glBlitFramebuffer: get_func("glBlitFramebuffer"),
+        glBufferData: get_func("glBufferData"),
+        glBufferStorage: get_func("glBufferStorage"),
+        glBufferSubData: get_func("glBufferSubData"),
+        glCallList: get_func("glCallList"),
+        glCallLists: get_func("glCallLists"),
+        glCheckFramebufferStatus: get_func("glCheckFramebufferStatus"),
+        glClampColor: get_func("glClampColor"),
+        glClear: get_func("glClear"),
+        glClearAccum: get_func("glClearAccum"),
+        glClearBufferfi: get_func("glClearBufferfi"),
+        glClearBufferfv: get_func("glClearBufferfv"),
+        glClearBufferiv: get_func("glClearBufferiv"),
+        glClearBufferuiv: get_func("glClearBufferuiv"),
+        glClearColor: get_func("glClearColor"),
+        glClearDepth: get_func("glClearDepth"),
+        glClearIndex: get_func("glClearIndex"),
+        glClearStencil: get_func("glClearStencil"),
+        glClientActiveTexture: get_func("glClientActiveTexture"),
+        glClientWaitSync: get_func("glClientWaitSync"),
+        glClipPlane: get_func("glClipPlane"),
+        glColor3b: get_func("glColor3b"),
+        glColor3bv: get_func("glColor3bv"),
+        glColor3d: get_func("glColor3d"),
+        glColor3dv: get_func("glColor3dv"),
+        glColor3f: get_func("glColor3f"),
+        glColor3fv: get_func("glColor3fv"),
+        glColor3i: get_func("glColor3i"),
+        glColor3iv: get_func("glColor3iv"),
+        glColor3s: get_func("glColor3s"),
+        glColor3sv: get_func("glColor3sv"),
+        glColor3ub: get_func("glColor3ub"),
+        glColor3ubv: get_func("glColor3ubv"),
+        glColor3ui: get_func("glColor3ui"),
+        glColor3uiv: get_func("glColor3uiv"),
+        glColor3us: get_func("glColor3us"),
+        glColor3usv: get_func("glColor3usv"),
+        glColor4b: get_func("glColor4b"),
+        glColor4bv: get_func("glColor4bv"),
+        glColor4d: get_func("glColor4d"),
+        glColor4dv: get_func("glColor4dv"),
+        glColor4f: get_func("glColor4f"),
+        glColor4fv: get_func("glColor4fv"),
+        glColor4i: get_func("glColor4i"),
+        glColor4iv: get_func("glColor4iv"),
+        glColor4s: get_func("glColor4s"),
+        glColor4sv: get_func("glColor4sv"),
+        glColor4ub: get_func("glColor4ub"),
+        glColor4ubv: get_func("glColor4ubv"),
+        glColor4ui: get_func("glColor4ui"),
+        glColor4uiv: get_func("glColor4uiv"),
+        glColor4us: get_func("glColor4us"),
+        glColor4usv: get_func("glColor4usv"),
+        glColorMask: get_func("glColorMask"),
+        glColorMaski: get_func("glColorMaski"),
+        glColorMaterial: get_func("glColorMaterial"),
+        glColorP3ui: get_func("glColorP3ui"),
+        glColorP3uiv: get_func("glColorP3uiv"),
+        glColorP4ui: get_func("glColorP4ui"),
+        glColorP4uiv: get_func("glColorP4uiv"),
+        glColorPointer: get_func("glColorPointer"),
+        glCompileShader: get_func("glCompileShader"),
+        glCompressedTexImage1D: get_func("glCompressedTexImage1D"),
+        glCompressedTexImage2D: get_func("glCompressedTexImage2D"),
+        glCompressedTexImage3D: get_func("glCompressedTexImage3D"),
+        glCompressedTexSubImage1D: get_func("glCompressedTexSubImage1D"),
+        glCompressedTexSubImage2D: get_func("glCompressedTexSubImage2D"),
+        glCompressedTexSubImage3D: get_func("glCompressedTexSubImage3D"),
+        glCopyBufferSubData: get_func("glCopyBufferSubData"),
+        glCopyImageSubData: get_func("glCopyImageSubData"),
+        glCopyPixels: get_func("glCopyPixels"),
+        glCopyTexImage1D: get_func("glCopyTexImage1D"),
+        glCopyTexImage2D: get_func("glCopyTexImage2D"),
+        glCopyTexSubImage1D: get_func("glCopyTexSubImage1D"),
+        glCopyTexSubImage2D: get_func("glCopyTexSubImage2D"),
+        glCopyTexSubImage3D: get_func("glCopyTexSubImage3D"),
+        glCreateProgram: get_func("glCreateProgram"),
+        glCreateShader: get_func("glCreateShader"),
+        glCullFace: get_func("glCullFace"),
+        glDebugMessageCallback: get_func("glDebugMessageCallback"),
+        glDebugMessageCallbackKHR: get_func("glDebugMessageCallbackKHR"),
+        glDebugMessageControl: get_func("glDebugMessageControl"),
+        glDebugMessageControlKHR: get_func("glDebugMessageControlKHR"),
+        glDebugMessageInsert: get_func("glDebugMessageInsert"),
+        glDebugMessageInsertKHR: get_func("glDebugMessageInsertKHR"),
+        glDeleteBuffers: get_func("glDeleteBuffers"),
+        glDeleteFencesAPPLE: get_func("glDeleteFencesAPPLE"),
+        glDeleteFramebuffers: get_func("glDeleteFramebuffers"),
+        glDeleteLists: get_func("glDeleteLists"),
+        glDeleteProgram: get_func("glDeleteProgram"),
+        glDeleteQueries: get_func("glDeleteQueries"),
+        glDeleteRenderbuffers: get_func("glDeleteRenderbuffers"),
+        glDeleteSamplers: get_func("glDeleteSamplers"),
+        glDeleteShader: get_func("glDeleteShader"),
+        glDeleteSync: get_func("glDeleteSync"),
+        glDeleteTextures: get_func("glDeleteTextures"),
+        glDeleteVertexArrays: get_func("glDeleteVertexArrays"),
+        glDeleteVertexArraysAPPLE: get_func("glDeleteVertexArraysAPPLE"),
+        glDepthFunc: get_func("glDepthFunc"),
+        glDepthMask: get_func("glDepthMask"),
+        glDepthRange: get_func("glDepthRange"),
+        glDetachShader: get_func("glDetachShader"),
+        glDisable: get_func("glDisable"),
+        glDisableClientState: get_func("glDisableClientState"),
+        glDisableVertexAttribArray: get_func("glDisableVertexAttribArray"),
+        glDisablei: get_func("glDisablei"),
+        glDrawArrays: get_func("glDrawArrays"),
+        glDrawArraysInstanced: get_func("glDrawArraysInstanced"),
+        glDrawBuffer: get_func("glDrawBuffer"),
+        glDrawBuffers: get_func("glDrawBuffers"),
+        glDrawElements: get_func("glDrawElements"),
+        glDrawElementsBaseVertex: get_func("glDrawElementsBaseVertex"),
+        glDrawElementsInstanced: get_func("glDrawElementsInstanced"),
+        glDrawElementsInstancedBaseVertex: get_func("glDrawElementsInstancedBaseVertex"),
+        glDrawPixels: get_func("glDrawPixels"),
+        glDrawRangeElements: get_func("glDrawRangeElements"),
+        glDrawRangeElementsBaseVertex: get_func("glDrawRangeElementsBaseVertex"),
+        glEdgeFlag: get_func("glEdgeFlag"),
+        glEdgeFlagPointer: get_func("glEdgeFlagPointer"),
+        glEdgeFlagv: get_func("glEdgeFlagv"),
+        glEnable: get_func("glEnable"),
+        glEnableClientState: get_func("glEnableClientState"),
+        glEnableVertexAttribArray: get_func("glEnableVertexAttribArray"),
+        glEnablei: get_func("glEnablei"),
+        glEnd: get_func("glEnd"),
+        glEndConditionalRender: get_func("glEndConditionalRender"),
+        glEndList: get_func("glEndList"),
+        glEndQuery: get_func("glEndQuery"),
+        glEndTransformFeedback: get_func("glEndTransformFeedback"),
+        glEvalCoord1d: get_func("glEvalCoord1d"),
+        glEvalCoord1dv: get_func("glEvalCoord1dv"),
+        glEvalCoord1f: get_func("glEvalCoord1f"),
+        glEvalCoord1fv: get_func("glEvalCoord1fv"),
+        glEvalCoord2d: get_func("glEvalCoord2d"),
+        glEvalCoord2dv: get_func("glEvalCoord2dv"),
+        glEvalCoord2f: get_func("glEvalCoord2f"),
+        glEvalCoord2fv: get_func("glEvalCoord2fv"),
+        glEvalMesh1: get_func("glEvalMesh1"),
+        glEvalMesh2: get_func("glEvalMesh2"),
+        glEvalPoint1: get_func("glEvalPoint1"),
+        glEvalPoint2: get_func("glEvalPoint2"),
+        glFeedbackBuffer: get_func("glFeedbackBuffer"),
+        glFenceSync: get_func("glFenceSync"),
+        glFinish: get_func("glFinish"),
+        glFinishFenceAPPLE: get_func("glFinishFenceAPPLE"),
+        glFinishObjectAPPLE: get_func("glFinishObjectAPPLE"),
+        glFlush: get_func("glFlush"),
+        glFlushMappedBufferRange: get_func("glFlushMappedBufferRange"),
+        glFogCoordPointer: get_func("glFogCoordPointer"),
+        glFogCoordd: get_func("glFogCoordd"),
+        glFogCoorddv: get_func("glFogCoorddv"),
+        glFogCoordf: get_func("glFogCoordf"),
+        glFogCoordfv: get_func("glFogCoordfv"),
+        glFogf: get_func("glFogf"),
+        glFogfv: get_func("glFogfv"),
+        glFogi: get_func("glFogi"),
+        glFogiv: get_func("glFogiv"),
+        glFramebufferRenderbuffer: get_func("glFramebufferRenderbuffer"),
+        glFramebufferTexture: get_func("glFramebufferTexture"),
+        glFramebufferTexture1D: get_func("glFramebufferTexture1D"),
+        glFramebufferTexture2D: get_func("glFramebufferTexture2D"),
+        glFramebufferTexture3D: get_func("glFramebufferTexture3D"),
+        glFramebufferTextureLayer: get_func("glFramebufferTextureLayer"),
+        glFrontFace: get_func("glFrontFace"),
+        glFrustum: get_func("glFrustum"),
+        glGenBuffers: get_func("glGenBuffers"),
+        glGenFencesAPPLE: get_func("glGenFencesAPPLE"),
+        glGenFramebuffers: get_func("glGenFramebuffers"),
+        glGenLists: get_func("glGenLists"),
+        glGenQueries: get_func("glGenQueries"),
+        glGenRenderbuffers: get_func("glGenRenderbuffers"),
+        glGenSamplers: get_func("glGenSamplers"),
+        glGenTextures: get_func("glGenTextures"),
+        glGenVertexArrays: get_func("glGenVertexArrays"),
+        glGenVertexArraysAPPLE: get_func("glGenVertexArraysAPPLE"),
+        glGenerateMipmap: get_func("glGenerateMipmap"),
+        glGetActiveAttrib: get_func("glGetActiveAttrib"),
+        glGetActiveUniform: get_func("glGetActiveUniform"),
+        glGetActiveUniformBlockName: get_func("glGetActiveUniformBlockName"),
+        glGetActiveUniformBlockiv: get_func("glGetActiveUniformBlockiv"),
+        glGetActiveUniformName: get_func("glGetActiveUniformName"),
+        glGetActiveUniformsiv: get_func("glGetActiveUniformsiv"),
+        glGetAttachedShaders: get_func("glGetAttachedShaders"),
+        glGetAttribLocation: get_func("glGetAttribLocation"),
+        glGetBooleani_v: get_func("glGetBooleani_v"),
+        glGetBooleanv: get_func("glGetBooleanv"),
+        glGetBufferParameteri64v: get_func("glGetBufferParameteri64v"),
+        glGetBufferParameteriv: get_func("glGetBufferParameteriv"),
+        glGetBufferPointerv: get_func("glGetBufferPointerv"),
+        glGetBufferSubData: get_func("glGetBufferSubData"),
+        glGetClipPlane: get_func("glGetClipPlane"),
+        glGetCompressedTexImage: get_func("glGetCompressedTexImage"),
+        glGetDebugMessageLog: get_func("glGetDebugMessageLog"),
+        glGetDebugMessageLogKHR: get_func("glGetDebugMessageLogKHR"),
+        glGetDoublev: get_func("glGetDoublev"),
+        glGetError: get_func("glGetError"),
+        glGetFloatv: get_func("glGetFloatv"),
+        glGetFragDataIndex: get_func("glGetFragDataIndex"),
+        glGetFragDataLocation: get_func("glGetFragDataLocation"),
+        glGetFramebufferAttachmentParameteriv: get_func("glGetFramebufferAttachmentParameteriv"),
+        glGetInteger64i_v: get_func("glGetInteger64i_v"),
+        glGetInteger64v: get_func("glGetInteger64v"),
+        glGetIntegeri_v: get_func("glGetIntegeri_v"),
+        glGetIntegerv: get_func("glGetIntegerv"),
+        glGetLightfv: get_func("glGetLightfv"),
+        glGetLightiv: get_func("glGetLightiv"),
+        glGetMapdv: get_func("glGetMapdv"),
+        glGetMapfv: get_func("glGetMapfv"),
+        glGetMapiv: get_func("glGetMapiv"),
+        glGetMaterialfv: get_func("glGetMaterialfv"),
+        glGetMaterialiv: get_func("glGetMaterialiv"),
+        glGetMultisamplefv: get_func("glGetMultisamplefv"),
+        glGetObjectLabel: get_func("glGetObjectLabel"),
+        glGetObjectLabelKHR: get_func("glGetObjectLabelKHR"),
+        glGetObjectPtrLabel: get_func("glGetObjectPtrLabel"),
+        glGetObjectPtrLabelKHR: get_func("glGetObjectPtrLabelKHR"),
+        glGetPixelMapfv: get_func("glGetPixelMapfv"),
+        glGetPixelMapuiv: get_func("glGetPixelMapuiv"),
+        glGetPixelMapusv: get_func("glGetPixelMapusv"),
+        glGetPointerv: get_func("glGetPointerv"),
+        glGetPointervKHR: get_func("glGetPointervKHR"),
+        glGetPolygonStipple: get_func("glGetPolygonStipple"),
+        glGetProgramBinary: get_func("glGetProgramBinary"),
+        glGetProgramInfoLog: get_func("glGetProgramInfoLog"),
+        glGetProgramiv: get_func("glGetProgramiv"),
+        glGetQueryObjecti64v: get_func("glGetQueryObjecti64v"),
+        glGetQueryObjectiv: get_func("glGetQueryObjectiv"),
+        glGetQueryObjectui64v: get_func("glGetQueryObjectui64v"),
+        glGetQueryObjectuiv: get_func("glGetQueryObjectuiv"),
+        glGetQueryiv: get_func("glGetQueryiv"),
+        glGetRenderbufferParameteriv: get_func("glGetRenderbufferParameteriv"),
+        glGetSamplerParameterIiv: get_func("glGetSamplerParameterIiv"),
+        glGetSamplerParameterIuiv: get_func("glGetSamplerParameterIuiv"),
+        glGetSamplerParameterfv: get_func("glGetSamplerParameterfv"),
+        glGetSamplerParameteriv: get_func("glGetSamplerParameteriv"),
+        glGetShaderInfoLog: get_func("glGetShaderInfoLog"),
+        glGetShaderSource: get_func("glGetShaderSource"),
+        glGetShaderiv: get_func("glGetShaderiv"),
+        glGetString: get_func("glGetString"),
+        glGetStringi: get_func("glGetStringi"),
+        glGetSynciv: get_func("glGetSynciv"),
+        glGetTexEnvfv: get_func("glGetTexEnvfv"),
+        glGetTexEnviv: get_func("glGetTexEnviv"),
+        glGetTexGendv: get_func("glGetTexGendv"),
+        glGetTexGenfv: get_func("glGetTexGenfv"),
+        glGetTexGeniv: get_func("glGetTexGeniv"),
+        glGetTexImage: get_func("glGetTexImage"),
+        glGetTexLevelParameterfv: get_func("glGetTexLevelParameterfv"),
+        glGetTexLevelParameteriv: get_func("glGetTexLevelParameteriv"),
+        glGetTexParameterIiv: get_func("glGetTexParameterIiv"),
+        glGetTexParameterIuiv: get_func("glGetTexParameterIuiv"),
+        glGetTexParameterPointervAPPLE: get_func("glGetTexParameterPointervAPPLE"),
+        glGetTexParameterfv: get_func("glGetTexParameterfv"),
+        glGetTexParameteriv: get_func("glGetTexParameteriv"),
+        glGetTransformFeedbackVarying: get_func("glGetTransformFeedbackVarying"),
+        glGetUniformBlockIndex: get_func("glGetUniformBlockIndex"),
+        glGetUniformIndices: get_func("glGetUniformIndices"),
+        glGetUniformLocation: get_func("glGetUniformLocation"),
+        glGetUniformfv: get_func("glGetUniformfv"),
+        glGetUniformiv: get_func("glGetUniformiv"),
+        glGetUniformuiv: get_func("glGetUniformuiv"),
+        glGetVertexAttribIiv: get_func("glGetVertexAttribIiv"),
+        glGetVertexAttribIuiv: get_func("glGetVertexAttribIuiv"),
+        glGetVertexAttribPointerv: get_func("glGetVertexAttribPointerv"),
+        glGetVertexAttribdv: get_func("glGetVertexAttribdv"),
+        glGetVertexAttribfv: get_func("glGetVertexAttribfv"),
+        glGetVertexAttribiv: get_func("glGetVertexAttribiv"),
+        glHint: get_func("glHint"),
+        glIndexMask: get_func("glIndexMask"),
+        glIndexPointer: get_func("glIndexPointer"),
+        glIndexd: get_func("glIndexd"),
+        glIndexdv: get_func("glIndexdv"),
+        glIndexf: get_func("glIndexf"),
+        glIndexfv: get_func("glIndexfv"),
+        glIndexi: get_func("glIndexi"),
+        glIndexiv: get_func("glIndexiv"),
+        glIndexs: get_func("glIndexs"),
+        glIndexsv: get_func("glIndexsv"),
+        glIndexub: get_func("glIndexub"),
+        glIndexubv: get_func("glIndexubv"),
+        glInitNames: get_func("glInitNames"),
+        glInsertEventMarkerEXT: get_func("glInsertEventMarkerEXT"),
+        glInterleavedArrays: get_func("glInterleavedArrays"),
+        glInvalidateBufferData: get_func("glInvalidateBufferData"),
+        glInvalidateBufferSubData: get_func("glInvalidateBufferSubData"),
+        glInvalidateFramebuffer: get_func("glInvalidateFramebuffer"),
+        glInvalidateSubFramebuffer: get_func("glInvalidateSubFramebuffer"),
+        glInvalidateTexImage: get_func("glInvalidateTexImage"),
+        glInvalidateTexSubImage: get_func("glInvalidateTexSubImage"),
+        glIsBuffer: get_func("glIsBuffer"),
+        glIsEnabled: get_func("glIsEnabled"),
+        glIsEnabledi: get_func("glIsEnabledi"),
+        glIsFenceAPPLE: get_func("glIsFenceAPPLE"),
+        glIsFramebuffer: get_func("glIsFramebuffer"),
+        glIsList: get_func("glIsList"),
+        glIsProgram: get_func("glIsProgram"),
+        glIsQuery: get_func("glIsQuery"),
+        glIsRenderbuffer: get_func("glIsRenderbuffer"),
+        glIsSampler: get_func("glIsSampler"),
+        glIsShader: get_func("glIsShader"),
+        glIsSync: get_func("glIsSync"),
+        glIsTexture: get_func("glIsTexture"),
+        glIsVertexArray: get_func("glIsVertexArray"),
+        glIsVertexArrayAPPLE: get_func("glIsVertexArrayAPPLE"),
+        glLightModelf: get_func("glLightModelf"),
+        glLightModelfv: get_func("glLightModelfv"),
+        glLightModeli: get_func("glLightModeli"),
+        glLightModeliv: get_func("glLightModeliv"),
+        glLightf: get_func("glLightf"),
+        glLightfv: get_func("glLightfv"),
+        glLighti: get_func("glLighti"),
+        glLightiv: get_func("glLightiv"),
+        glLineStipple: get_func("glLineStipple"),
+        glLineWidth: get_func("glLineWidth"),
+        glLinkProgram: get_func("glLinkProgram"),
+        glListBase: get_func("glListBase"),
+        glLoadIdentity: get_func("glLoadIdentity"),
+        glLoadMatrixd: get_func("glLoadMatrixd"),
+        glLoadMatrixf: get_func("glLoadMatrixf"),
+        glLoadName: get_func("glLoadName"),
+        glLoadTransposeMatrixd: get_func("glLoadTransposeMatrixd"),
+        glLoadTransposeMatrixf: get_func("glLoadTransposeMatrixf"),
+        glLogicOp: get_func("glLogicOp"),
+        glMap1d: get_func("glMap1d"),
+        glMap1f: get_func("glMap1f"),
+        glMap2d: get_func("glMap2d"),
+        glMap2f: get_func("glMap2f"),
+        glMapBuffer: get_func("glMapBuffer"),
+        glMapBufferRange: get_func("glMapBufferRange"),
+        glMapGrid1d: get_func("glMapGrid1d"),
+        glMapGrid1f: get_func("glMapGrid1f"),
+        glMapGrid2d: get_func("glMapGrid2d"),
+        glMapGrid2f: get_func("glMapGrid2f"),
+        glMaterialf: get_func("glMaterialf"),
+        glMaterialfv: get_func("glMaterialfv"),
+        glMateriali: get_func("glMateriali"),
+        glMaterialiv: get_func("glMaterialiv"),
+        glMatrixMode: get_func("glMatrixMode"),
+        glMultMatrixd: get_func("glMultMatrixd"),
+        glMultMatrixf: get_func("glMultMatrixf"),
+        glMultTransposeMatrixd: get_func("glMultTransposeMatrixd"),
+        glMultTransposeMatrixf: get_func("glMultTransposeMatrixf"),
+        glMultiDrawArrays: get_func("glMultiDrawArrays"),
+        glMultiDrawElements: get_func("glMultiDrawElements"),
+        glMultiDrawElementsBaseVertex: get_func("glMultiDrawElementsBaseVertex"),
+        glMultiTexCoord1d: get_func("glMultiTexCoord1d"),
+        glMultiTexCoord1dv: get_func("glMultiTexCoord1dv"),
+        glMultiTexCoord1f: get_func("glMultiTexCoord1f"),
+        glMultiTexCoord1fv: get_func("glMultiTexCoord1fv"),
+        glMultiTexCoord1i: get_func("glMultiTexCoord1i"),
+        glMultiTexCoord1iv: get_func("glMultiTexCoord1iv"),
+        glMultiTexCoord1s: get_func("glMultiTexCoord1s"),
+        glMultiTexCoord1sv: get_func("glMultiTexCoord1sv"),
+        glMultiTexCoord2d: get_func("glMultiTexCoord2d"),
+        glMultiTexCoord2dv: get_func("glMultiTexCoord2dv"),
+        glMultiTexCoord2f: get_func("glMultiTexCoord2f"),
+        glMultiTexCoord2fv: get_func("glMultiTexCoord2fv"),
+        glMultiTexCoord2i: get_func("glMultiTexCoord2i"),
+        glMultiTexCoord2iv: get_func("glMultiTexCoord2iv"),
+        glMultiTexCoord2s: get_func("glMultiTexCoord2s"),
+        glMultiTexCoord2sv: get_func("glMultiTexCoord2sv"),
+        glMultiTexCoord3d: get_func("glMultiTexCoord3d"),
+        glMultiTexCoord3dv: get_func("glMultiTexCoord3dv"),
+        glMultiTexCoord3f: get_func("glMultiTexCoord3f"),
+        glMultiTexCoord3fv: get_func("glMultiTexCoord3fv"),
+        glMultiTexCoord3i: get_func("glMultiTexCoord3i"),
+        glMultiTexCoord3iv: get_func("glMultiTexCoord3iv"),
+        glMultiTexCoord3s: get_func("glMultiTexCoord3s"),
+        glMultiTexCoord3sv: get_func("glMultiTexCoord3sv"),
+        glMultiTexCoord4d: get_func("glMultiTexCoord4d"),
+        glMultiTexCoord4dv: get_func("glMultiTexCoord4dv"),
+        glMultiTexCoord4f: get_func("glMultiTexCoord4f"),
+        glMultiTexCoord4fv: get_func("glMultiTexCoord4fv"),
+        glMultiTexCoord4i: get_func("glMultiTexCoord4i"),
+        glMultiTexCoord4iv: get_func("glMultiTexCoord4iv"),
+        glMultiTexCoord4s: get_func("glMultiTexCoord4s"),
+        glMultiTexCoord4sv: get_func("glMultiTexCoord4sv"),
+        glMultiTexCoordP1ui: get_func("glMultiTexCoordP1ui"),
+        glMultiTexCoordP1uiv: get_func("glMultiTexCoordP1uiv"),
+        glMultiTexCoordP2ui: get_func("glMultiTexCoordP2ui"),
+        glMultiTexCoordP2uiv: get_func("glMultiTexCoordP2uiv"),
+        glMultiTexCoordP3ui: get_func("glMultiTexCoordP3ui"),
+        glMultiTexCoordP3uiv: get_func("glMultiTexCoordP3uiv"),
+        glMultiTexCoordP4ui: get_func("glMultiTexCoordP4ui"),
+        glMultiTexCoordP4uiv: get_func("glMultiTexCoordP4uiv"),
+        glNewList: get_func("glNewList"),
+        glNormal3b: get_func("glNormal3b"),
+        glNormal3bv: get_func("glNormal3bv"),
+        glNormal3d: get_func("glNormal3d"),
+        glNormal3dv: get_func("glNormal3dv"),
+        glNormal3f: get_func("glNormal3f"),
+        glNormal3fv: get_func("glNormal3fv"),
+        glNormal3i: get_func("glNormal3i"),
+        glNormal3iv: get_func("glNormal3iv"),
+        glNormal3s: get_func("glNormal3s"),
+        glNormal3sv: get_func("glNormal3sv"),
+        glNormalP3ui: get_func("glNormalP3ui"),
+        glNormalP3uiv: get_func("glNormalP3uiv"),
+        glNormalPointer: get_func("glNormalPointer"),
+        glObjectLabel: get_func("glObjectLabel"),
+        glObjectLabelKHR: get_func("glObjectLabelKHR"),
+        glObjectPtrLabel: get_func("glObjectPtrLabel"),
+        glObjectPtrLabelKHR: get_func("glObjectPtrLabelKHR"),
+        glOrtho: get_func("glOrtho"),
+        glPassThrough: get_func("glPassThrough"),
+        glPixelMapfv: get_func("glPixelMapfv"),
+        glPixelMapuiv: get_func("glPixelMapuiv"),
+        glPixelMapusv: get_func("glPixelMapusv"),
+        glPixelStoref: get_func("glPixelStoref"),
+        glPixelStorei: get_func("glPixelStorei"),
+        glPixelTransferf: get_func("glPixelTransferf"),
+        glPixelTransferi: get_func("glPixelTransferi"),
+        glPixelZoom: get_func("glPixelZoom"),
+        glPointParameterf: get_func("glPointParameterf"),
+        glPointParameterfv: get_func("glPointParameterfv"),
+        glPointParameteri: get_func("glPointParameteri"),
+        glPointParameteriv: get_func("glPointParameteriv"),
+        glPointSize: get_func("glPointSize"),
+        glPolygonMode: get_func("glPolygonMode"),
+        glPolygonOffset: get_func("glPolygonOffset"),
+        glPolygonStipple: get_func("glPolygonStipple"),
+        glPopAttrib: get_func("glPopAttrib"),
+        glPopClientAttrib: get_func("glPopClientAttrib"),
+        glPopDebugGroup: get_func("glPopDebugGroup"),
+        glPopDebugGroupKHR: get_func("glPopDebugGroupKHR"),
+        glPopGroupMarkerEXT: get_func("glPopGroupMarkerEXT"),
+        glPopMatrix: get_func("glPopMatrix"),
+        glPopName: get_func("glPopName"),
+        glPrimitiveRestartIndex: get_func("glPrimitiveRestartIndex"),
+        glPrioritizeTextures: get_func("glPrioritizeTextures"),
+        glProgramBinary: get_func("glProgramBinary"),
+        glProgramParameteri: get_func("glProgramParameteri"),
+        glProvokingVertex: get_func("glProvokingVertex"),
+        glPushAttrib: get_func("glPushAttrib"),
+        glPushClientAttrib: get_func("glPushClientAttrib"),
+        glPushDebugGroup: get_func("glPushDebugGroup"),
+        glPushDebugGroupKHR: get_func("glPushDebugGroupKHR"),
+        glPushGroupMarkerEXT: get_func("glPushGroupMarkerEXT"),
+        glPushMatrix: get_func("glPushMatrix"),
+        glPushName: get_func("glPushName"),
+        glQueryCounter: get_func("glQueryCounter"),
+        glRasterPos2d: get_func("glRasterPos2d"),
+        glRasterPos2dv: get_func("glRasterPos2dv"),
+        glRasterPos2f: get_func("glRasterPos2f"),
+        glRasterPos2fv: get_func("glRasterPos2fv"),
+        glRasterPos2i: get_func("glRasterPos2i"),
+        glRasterPos2iv: get_func("glRasterPos2iv"),
+        glRasterPos2s: get_func("glRasterPos2s"),
+        glRasterPos2sv: get_func("glRasterPos2sv"),
+        glRasterPos3d: get_func("glRasterPos3d"),
+        glRasterPos3dv: get_func("glRasterPos3dv"),
+        glRasterPos3f: get_func("glRasterPos3f"),
+        glRasterPos3fv: get_func("glRasterPos3fv"),
+        glRasterPos3i: get_func("glRasterPos3i"),
+        glRasterPos3iv: get_func("glRasterPos3iv"),
+        glRasterPos3s: get_func("glRasterPos3s"),
+        glRasterPos3sv: get_func("glRasterPos3sv"),
+        glRasterPos4d: get_func("glRasterPos4d"),
+        glRasterPos4dv: get_func("glRasterPos4dv"),
+        glRasterPos4f: get_func("glRasterPos4f"),
+        glRasterPos4fv: get_func("glRasterPos4fv"),
+        glRasterPos4i: get_func("glRasterPos4i"),
+        glRasterPos4iv: get_func("glRasterPos4iv"),
+        glRasterPos4s: get_func("glRasterPos4s"),
+        glRasterPos4sv: get_func("glRasterPos4sv"),
+        glReadBuffer: get_func("glReadBuffer"),
+        glReadPixels: get_func("glReadPixels"),
+        glRectd: get_func("glRectd"),
+        glRectdv: get_func("glRectdv"),
+        glRectf: get_func("glRectf"),
+        glRectfv: get_func("glRectfv"),
+        glRecti: get_func("glRecti"),
+        glRectiv: get_func("glRectiv"),
+        glRects: get_func("glRects"),
+        glRectsv: get_func("glRectsv"),
+        glRenderMode: get_func("glRenderMode"),
+        glRenderbufferStorage: get_func("glRenderbufferStorage"),
+        glRenderbufferStorageMultisample: get_func("glRenderbufferStorageMultisample"),
+        glRotated: get_func("glRotated"),
+        glRotatef: get_func("glRotatef"),
+        glSampleCoverage: get_func("glSampleCoverage"),
+        glSampleMaski: get_func("glSampleMaski"),
+        glSamplerParameterIiv: get_func("glSamplerParameterIiv"),
+        glSamplerParameterIuiv: get_func("glSamplerParameterIuiv"),
+        glSamplerParameterf: get_func("glSamplerParameterf"),
+        glSamplerParameterfv: get_func("glSamplerParameterfv"),
+        glSamplerParameteri: get_func("glSamplerParameteri"),
+        glSamplerParameteriv: get_func("glSamplerParameteriv"),
+        glScaled: get_func("glScaled"),
+        glScalef: get_func("glScalef"),
+        glScissor: get_func("glScissor"),
+        glSecondaryColor3b: get_func("glSecondaryColor3b"),
+        glSecondaryColor3bv: get_func("glSecondaryColor3bv"),
+        glSecondaryColor3d: get_func("glSecondaryColor3d"),
+        glSecondaryColor3dv: get_func("glSecondaryColor3dv"),
+        glSecondaryColor3f: get_func("glSecondaryColor3f"),
+        glSecondaryColor3fv: get_func("glSecondaryColor3fv"),
+        glSecondaryColor3i: get_func("glSecondaryColor3i"),
+        glSecondaryColor3iv: get_func("glSecondaryColor3iv"),
+        glSecondaryColor3s: get_func("glSecondaryColor3s"),
+        glSecondaryColor3sv: get_func("glSecondaryColor3sv"),
+        glSecondaryColor3ub: get_func("glSecondaryColor3ub"),
+        glSecondaryColor3ubv: get_func("glSecondaryColor3ubv"),
+        glSecondaryColor3ui: get_func("glSecondaryColor3ui"),
+        glSecondaryColor3uiv: get_func("glSecondaryColor3uiv"),
+        glSecondaryColor3us: get_func("glSecondaryColor3us"),
+        glSecondaryColor3usv: get_func("glSecondaryColor3usv"),
+        glSecondaryColorP3ui: get_func("glSecondaryColorP3ui"),
+        glSecondaryColorP3uiv: get_func("glSecondaryColorP3uiv"),
+        glSecondaryColorPointer: get_func("glSecondaryColorPointer"),
+        glSelectBuffer: get_func("glSelectBuffer"),
+        glSetFenceAPPLE: get_func("glSetFenceAPPLE"),
+        glShadeModel: get_func("glShadeModel"),
+        glShaderSource: get_func("glShaderSource"),
+        glShaderStorageBlockBinding: get_func("glShaderStorageBlockBinding"),
+        glStencilFunc: get_func("glStencilFunc"),
+        glStencilFuncSeparate: get_func("glStencilFuncSeparate"),
+        glStencilMask: get_func("glStencilMask"),
+        glStencilMaskSeparate: get_func("glStencilMaskSeparate"),
+        glStencilOp: get_func("glStencilOp"),
+        glStencilOpSeparate: get_func("glStencilOpSeparate"),
+        glTestFenceAPPLE: get_func("glTestFenceAPPLE"),
+        glTestObjectAPPLE: get_func("glTestObjectAPPLE"),
+        glTexBuffer: get_func("glTexBuffer"),
+        glTexCoord1d: get_func("glTexCoord1d"),
+        glTexCoord1dv: get_func("glTexCoord1dv"),
+        glTexCoord1f: get_func("glTexCoord1f"),
+        glTexCoord1fv: get_func("glTexCoord1fv"),
+        glTexCoord1i: get_func("glTexCoord1i"),
+        glTexCoord1iv: get_func("glTexCoord1iv"),
+        glTexCoord1s: get_func("glTexCoord1s"),
+        glTexCoord1sv: get_func("glTexCoord1sv"),
+        glTexCoord2d: get_func("glTexCoord2d"),
+        glTexCoord2dv: get_func("glTexCoord2dv"),
+        glTexCoord2f: get_func("glTexCoord2f"),
+        glTexCoord2fv: get_func("glTexCoord2fv"),
+        glTexCoord2i: get_func("glTexCoord2i"),
+        glTexCoord2iv: get_func("glTexCoord2iv"),
+        glTexCoord2s: get_func("glTexCoord2s"),
+        glTexCoord2sv: get_func("glTexCoord2sv"),
+        glTexCoord3d: get_func("glTexCoord3d"),
+        glTexCoord3dv: get_func("glTexCoord3dv"),
+        glTexCoord3f: get_func("glTexCoord3f"),
+        glTexCoord3fv: get_func("glTexCoord3fv"),
+        glTexCoord3i: get_func("glTexCoord3i"),
+        glTexCoord3iv: get_func("glTexCoord3iv"),
+        glTexCoord3s: get_func("glTexCoord3s"),
+        glTexCoord3sv: get_func("glTexCoord3sv"),
+        glTexCoord4d: get_func("glTexCoord4d"),
+        glTexCoord4dv: get_func("glTexCoord4dv"),
+        glTexCoord4f: get_func("glTexCoord4f"),
+        glTexCoord4fv: get_func("glTexCoord4fv"),
+        glTexCoord4i: get_func("glTexCoord4i"),
+        glTexCoord4iv: get_func("glTexCoord4iv"),
+        glTexCoord4s: get_func("glTexCoord4s"),
+        glTexCoord4sv: get_func("glTexCoord4sv"),
+        glTexCoordP1ui: get_func("glTexCoordP1ui"),
+        glTexCoordP1uiv: get_func("glTexCoordP1uiv"),
+        glTexCoordP2ui: get_func("glTexCoordP2ui"),
+        glTexCoordP2uiv: get_func("glTexCoordP2uiv"),
+        glTexCoordP3ui: get_func("glTexCoordP3ui"),
+        glTexCoordP3uiv: get_func("glTexCoordP3uiv"),
+        glTexCoordP4ui: get_func("glTexCoordP4ui"),
+        glTexCoordP4uiv: get_func("glTexCoordP4uiv"),
+        glTexCoordPointer: get_func("glTexCoordPointer"),
+        glTexEnvf: get_func("glTexEnvf"),
+        glTexEnvfv: get_func("glTexEnvfv"),
+        glTexEnvi: get_func("glTexEnvi"),
+        glTexEnviv: get_func("glTexEnviv"),
+        glTexGend: get_func("glTexGend"),
+        glTexGendv: get_func("glTexGendv"),
+        glTexGenf: get_func("glTexGenf"),
+        glTexGenfv: get_func("glTexGenfv"),
+        glTexGeni: get_func("glTexGeni"),
+        glTexGeniv: get_func("glTexGeniv"),
+        glTexImage1D: get_func("glTexImage1D"),
+        glTexImage2D: get_func("glTexImage2D"),
+        glTexImage2DMultisample: get_func("glTexImage2DMultisample"),
+        glTexImage3D: get_func("glTexImage3D"),
+        glTexImage3DMultisample: get_func("glTexImage3DMultisample"),
+        glTexParameterIiv: get_func("glTexParameterIiv"),
+        glTexParameterIuiv: get_func("glTexParameterIuiv"),
+        glTexParameterf: get_func("glTexParameterf"),
+        glTexParameterfv: get_func("glTexParameterfv"),
+        glTexParameteri: get_func("glTexParameteri"),
+        glTexParameteriv: get_func("glTexParameteriv"),
+        glTexStorage1D: get_func("glTexStorage1D"),
+        glTexStorage2D: get_func("glTexStorage2D"),
+        glTexStorage3D: get_func("glTexStorage3D"),
+        glTexSubImage1D: get_func("glTexSubImage1D"),
+        glTexSubImage2D: get_func("glTexSubImage2D"),
+        glTexSubImage3D: get_func("glTexSubImage3D"),
+        glTextureRangeAPPLE: get_func("glTextureRangeAPPLE"),
+        glTransformFeedbackVaryings: get_func("glTransformFeedbackVaryings"),
+        glTranslated: get_func("glTranslated"),
+        glTranslatef: get_func("glTranslatef"),
+        glUniform1f: get_func("glUniform1f"),
+        glUniform1fv: get_func("glUniform1fv"),
+        glUniform1i: get_func("glUniform1i"),
+        glUniform1iv: get_func("glUniform1iv"),
+        glUniform1ui: get_func("glUniform1ui"),
+        glUniform1uiv: get_func("glUniform1uiv"),
+        glUniform2f: get_func("glUniform2f"),
+        glUniform2fv: get_func("glUniform2fv"),
+        glUniform2i: get_func("glUniform2i"),
+        glUniform2iv: get_func("glUniform2iv"),
+        glUniform2ui: get_func("glUniform2ui"),
+        glUniform2uiv: get_func("glUniform2uiv"),
+        glUniform3f: get_func("glUniform3f"),
+        glUniform3fv: get_func("glUniform3fv"),
+        glUniform3i: get_func("glUniform3i"),
+        glUniform3iv: get_func("glUniform3iv"),
+        glUniform3ui: get_func("glUniform3ui"),
+        glUniform3uiv: get_func("glUniform3uiv"),
+        glUniform4f: get_func("glUniform4f"),
+        glUniform4fv: get_func("glUniform4fv"),
+        glUniform4i: get_func("glUniform4i"),
+        glUniform4iv: get_func("glUniform4iv"),
+        glUniform4ui: get_func("glUniform4ui"),
+        glUniform4uiv: get_func("glUniform4uiv"),
+        glUniformBlockBinding: get_func("glUniformBlockBinding"),
+        glUniformMatrix2fv: get_func("glUniformMatrix2fv"),
+        glUniformMatrix2x3fv: get_func("glUniformMatrix2x3fv"),
+        glUniformMatrix2x4fv: get_func("glUniformMatrix2x4fv"),
+        glUniformMatrix3fv: get_func("glUniformMatrix3fv"),
+        glUniformMatrix3x2fv: get_func("glUniformMatrix3x2fv"),
+        glUniformMatrix3x4fv: get_func("glUniformMatrix3x4fv"),
+        glUniformMatrix4fv: get_func("glUniformMatrix4fv"),
+        glUniformMatrix4x2fv: get_func("glUniformMatrix4x2fv"),
+        glUniformMatrix4x3fv: get_func("glUniformMatrix4x3fv"),
+        glUnmapBuffer: get_func("glUnmapBuffer"),
+        glUseProgram: get_func("glUseProgram"),
+        glValidateProgram: get_func("glValidateProgram"),
+        glVertex2d: get_func("glVertex2d"),
+        glVertex2dv: get_func("glVertex2dv"),
+        glVertex2f: get_func("glVertex2f"),
+        glVertex2fv: get_func("glVertex2fv"),
+        glVertex2i: get_func("glVertex2i"),
+        glVertex2iv: get_func("glVertex2iv"),
+        glVertex2s: get_func("glVertex2s"),
+        glVertex2sv: get_func("glVertex2sv"),
+        glVertex3d: get_func("glVertex3d"),
+        glVertex3dv: get_func("glVertex3dv"),
+        glVertex3f: get_func("glVertex3f"),
+        glVertex3fv: get_func("glVertex3fv"),
+        glVertex3i: get_func("glVertex3i"),
+        glVertex3iv: get_func("glVertex3iv"),
+        glVertex3s: get_func("glVertex3s"),
+        glVertex3sv: get_func("glVertex3sv"),
+        glVertex4d: get_func("glVertex4d"),
+        glVertex4dv: get_func("glVertex4dv"),
+        glVertex4f: get_func("glVertex4f"),
+        glVertex4fv: get_func("glVertex4fv"),
+        glVertex4i: get_func("glVertex4i"),
+        glVertex4iv: get_func("glVertex4iv"),
+        glVertex4s: get_func("glVertex4s"),
+        glVertex4sv: get_func("glVertex4sv"),
+        glVertexAttrib1d: get_func("glVertexAttrib1d"),
+        glVertexAttrib1dv: get_func("glVertexAttrib1dv"),
+        glVertexAttrib1f: get_func("glVertexAttrib1f"),
+        glVertexAttrib1fv: get_func("glVertexAttrib1fv"),
+        glVertexAttrib1s: get_func("glVertexAttrib1s"),
+        glVertexAttrib1sv: get_func("glVertexAttrib1sv"),
+        glVertexAttrib2d: get_func("glVertexAttrib2d"),
+        glVertexAttrib2dv: get_func("glVertexAttrib2dv"),
+        glVertexAttrib2f: get_func("glVertexAttrib2f"),
+        glVertexAttrib2fv: get_func("glVertexAttrib2fv"),
+        glVertexAttrib2s: get_func("glVertexAttrib2s"),
+        glVertexAttrib2sv: get_func("glVertexAttrib2sv"),
+        glVertexAttrib3d: get_func("glVertexAttrib3d"),
+        glVertexAttrib3dv: get_func("glVertexAttrib3dv"),
+        glVertexAttrib3f: get_func("glVertexAttrib3f"),
+        glVertexAttrib3fv: get_func("glVertexAttrib3fv"),
+        glVertexAttrib3s: get_func("glVertexAttrib3s"),
+        glVertexAttrib3sv: get_func("glVertexAttrib3sv"),
+        glVertexAttrib4Nbv: get_func("glVertexAttrib4Nbv"),
+        glVertexAttrib4Niv: get_func("glVertexAttrib4Niv"),
+        glVertexAttrib4Nsv: get_func("glVertexAttrib4Nsv"),
+        glVertexAttrib4Nub: get_func("glVertexAttrib4Nub"),
+        glVertexAttrib4Nubv: get_func("glVertexAttrib4Nubv"),
+        glVertexAttrib4Nuiv: get_func("glVertexAttrib4Nuiv"),
+        glVertexAttrib4Nusv: get_func("glVertexAttrib4Nusv"),
+        glVertexAttrib4bv: get_func("glVertexAttrib4bv"),
+        glVertexAttrib4d: get_func("glVertexAttrib4d"),
+        glVertexAttrib4dv: get_func("glVertexAttrib4dv"),
+        glVertexAttrib4f: get_func("glVertexAttrib4f"),
+        glVertexAttrib4fv: get_func("glVertexAttrib4fv"),
+        glVertexAttrib4iv: get_func("glVertexAttrib4iv"),
+        glVertexAttrib4s: get_func("glVertexAttrib4s"),
+        glVertexAttrib4sv: get_func("glVertexAttrib4sv"),
+        glVertexAttrib4ubv: get_func("glVertexAttrib4ubv"),
+        glVertexAttrib4uiv: get_func("glVertexAttrib4uiv"),
+        glVertexAttrib4usv: get_func("glVertexAttrib4usv"),
+        glVertexAttribDivisor: get_func("glVertexAttribDivisor"),
+        glVertexAttribI1i: get_func("glVertexAttribI1i"),
+        glVertexAttribI1iv: get_func("glVertexAttribI1iv"),
+        glVertexAttribI1ui: get_func("glVertexAttribI1ui"),
+        glVertexAttribI1uiv: get_func("glVertexAttribI1uiv"),
+        glVertexAttribI2i: get_func("glVertexAttribI2i"),
+        glVertexAttribI2iv: get_func("glVertexAttribI2iv"),
+        glVertexAttribI2ui: get_func("glVertexAttribI2ui"),
+        glVertexAttribI2uiv: get_func("glVertexAttribI2uiv"),
+        glVertexAttribI3i: get_func("glVertexAttribI3i"),
+        glVertexAttribI3iv: get_func("glVertexAttribI3iv"),
+        glVertexAttribI3ui: get_func("glVertexAttribI3ui"),
+        glVertexAttribI3uiv: get_func("glVertexAttribI3uiv"),
+        glVertexAttribI4bv: get_func("glVertexAttribI4bv"),
+        glVertexAttribI4i: get_func("glVertexAttribI4i"),
+        glVertexAttribI4iv: get_func("glVertexAttribI4iv"),
+        glVertexAttribI4sv: get_func("glVertexAttribI4sv"),
+        glVertexAttribI4ubv: get_func("glVertexAttribI4ubv"),
+        glVertexAttribI4ui: get_func("glVertexAttribI4ui"),
+        glVertexAttribI4uiv: get_func("glVertexAttribI4uiv"),
+        glVertexAttribI4usv: get_func("glVertexAttribI4usv"),
+        glVertexAttribIPointer: get_func("glVertexAttribIPointer"),
+        glVertexAttribP1ui: get_func("glVertexAttribP1ui"),
+        glVertexAttribP1uiv: get_func("glVertexAttribP1uiv"),
+        glVertexAttribP2ui: get_func("glVertexAttribP2ui"),
+        glVertexAttribP2uiv: get_func("glVertexAttribP2uiv"),
+        glVertexAttribP3ui: get_func("glVertexAttribP3ui"),
+        glVertexAttribP3uiv: get_func("glVertexAttribP3uiv"),
+        glVertexAttribP4ui: get_func("glVertexAttribP4ui"),
+        glVertexAttribP4uiv: get_func("glVertexAttribP4uiv"),
+        glVertexAttribPointer: get_func("glVertexAttribPointer"),
+        glVertexP2ui: get_func("glVertexP2ui"),
+        glVertexP2uiv: get_func("glVertexP2uiv"),
+        glVertexP3ui: get_func("glVertexP3ui"),
+        glVertexP3uiv: get_func("glVertexP3uiv"),
+        glVertexP4ui: get_func("glVertexP4ui"),
+        glVertexP4uiv: get_func("glVertexP4uiv"),
+        glVertexPointer: get_func("glVertexPointer"),
+        glViewport: get_func("glViewport"),
+        glWaitSync: get_func("glWaitSync"),
+        glWindowPos2d: get_func("glWindowPos2d"),
+        glWindowPos2dv: get_func("glWindowPos2dv"),
+        glWindowPos2f: get_func("glWindowPos2f"),
+        glWindowPos2fv: get_func("glWindowPos2fv"),
+        glWindowPos2i: get_func("glWindowPos2i"),
+        glWindowPos2iv: get_func("glWindowPos2iv"),
+        glWindowPos2s: get_func("glWindowPos2s"),
+        glWindowPos2sv: get_func("glWindowPos2sv"),
+        glWindowPos3d: get_func("glWindowPos3d"),
+        glWindowPos3dv: get_func("glWindowPos3dv"),
+        glWindowPos3f: get_func("glWindowPos3f"),
+        glWindowPos3fv: get_func("glWindowPos3fv"),
+        glWindowPos3i: get_func("glWindowPos3i"),
+        glWindowPos3iv: get_func("glWindowPos3iv"),
+        glWindowPos3s: get_func("glWindowPos3s"),
+        glWindowPos3sv: get_func("glWindowPos3sv"),
+    }
+}
@@ -0,0 +1,850 @@
+//! Headless "shell" that runs an azul application without a window or a GPU
+//!
+//! Drives the same `WindowInternal` / `CallbacksOfHitTest` / `StyleAndLayoutChanges`
+//! pipeline as the native shells, but the hit-testing is done on the CPU and
+//! frames are rendered into an in-memory RGBA buffer. Events are simulated by
+//! submitting a new `FullWindowState` (i.e. "the mouse moved to (20, 30) and
+//! the left button is down"), which makes it possible to run real applications
+//! in containers / on CI machines for integration tests.
+
+use crate::app::LazyFcCache;
+use alloc::collections::BTreeMap;
+use azul_core::{
+    FastHashMap, FastBTreeSet,
+    app_resources::{
+        AppConfig, ImageCache, ImageKey, ImageDescriptor, ImageData,
+        RawImage, ResourceUpdate, IdNamespace,
+    },
+    callbacks::{RefAny, DocumentId, UpdateImageType},
+    gl::OptionGlContextPtr,
+    task::{Thread, ThreadId, Timer, TimerId},
+    window::{
+        WindowInternal, WindowInternalInit, WindowCreateOptions,
+        FullWindowState, FullHitTest, RawWindowHandle, CallCallbacksResult,
+    },
+    window_state::NodesToCheck,
+    display_list::CachedDisplayList,
+};
+use core::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use rust_fontconfig::FcFontCache;
+
+static LAST_HEADLESS_ID: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ProcessEventResult {
+    DoNothing,
+    ShouldRegenerateDomCurrentWindow,
+    ShouldRegenerateDomAllWindows,
+    ShouldUpdateDisplayListCurrentWindow,
+    // Nodes changed their size: do another hit-test and
+    // recurse until nothing has changed anymore
+    UpdateHitTesterAndProcessAgain,
+    // Only refresh the display (in case of pure scroll or GPU-only events)
+    ShouldReRenderCurrentWindow,
+}
+
+impl ProcessEventResult {
+    fn order(&self) -> usize {
+        use self::ProcessEventResult::*;
+        match self {
+            DoNothing => 0,
+            ShouldReRenderCurrentWindow => 1,
+            ShouldUpdateDisplayListCurrentWindow => 2,
+            UpdateHitTesterAndProcessAgain => 3,
+            ShouldRegenerateDomCurrentWindow => 4,
+            ShouldRegenerateDomAllWindows => 5,
+        }
+    }
+
+    // Returns the result that requires more work from the window
+    fn max_self(self, other: Self) -> Self {
+        if other.order() > self.order() { other } else { self }
+    }
+}
+
+/// Window without an OS-level window: renders into an RGBA framebuffer
+#[derive(Debug)]
+pub struct HeadlessWindow {
+    /// Application data, shared by all callbacks
+    pub data: RefAny,
+    /// Application configuration (system callbacks, logging, etc.)
+    pub config: AppConfig,
+    /// Images that can be referenced in the CSS
+    pub image_cache: ImageCache,
+    /// Font cache, built on a background thread while the window is being created
+    pub fc_cache: LazyFcCache,
+    /// Layout results, window state, timers and threads
+    pub internal: WindowInternal,
+    /// Since there is no renderer that would store the image data
+    /// (like webrender does), the image data has to be stored in the shell
+    pub images: FastHashMap<ImageKey, (ImageDescriptor, ImageData)>,
+    /// Windows that callbacks requested to be opened - a headless window
+    /// can't open other windows, so they are only stored for inspection
+    pub created_windows: Vec<WindowCreateOptions>,
+    /// Set when a callback set `flags.is_about_to_close`
+    pub is_about_to_close: bool,
+    /// Whether the framebuffer is out of date
+    needs_redraw: bool,
+    /// Last rendered frame (RGBA8, in physical pixels)
+    framebuffer: RawImage,
+}
+
+impl HeadlessWindow {
+
+    /// Creates the window and invokes the layout() callback for the first time
+    pub fn new(
+        mut data: RefAny,
+        config: AppConfig,
+        image_cache: ImageCache,
+        root_window: WindowCreateOptions,
+    ) -> Self {
+
+        use std::thread;
+
+        let mut fc_cache = LazyFcCache::InProgress(Some(thread::spawn(move || FcFontCache::build())));
+
+        let id = LAST_HEADLESS_ID.fetch_add(1, AtomicOrdering::SeqCst) as u32;
+        let id_namespace = IdNamespace(id);
+        let document_id = DocumentId { namespace_id: id_namespace, id };
+
+        let mut initial_resource_updates = Vec::new();
+        let internal = fc_cache.apply_closure(|fc_cache| {
+            WindowInternal::new(
+                WindowInternalInit {
+                    window_create_options: root_window,
+                    document_id,
+                    id_namespace,
+                },
+                &mut data,
+                &image_cache,
+                &OptionGlContextPtr::None,
+                &mut initial_resource_updates,
+                &crate::app::CALLBACKS,
+                fc_cache,
+                azul_layout::do_the_relayout,
+                |window_state, scroll_states, layout_results| {
+                    FullHitTest::new(layout_results, &window_state.mouse_state.cursor_position, scroll_states)
+                },
+            )
+        });
+
+        let mut window = Self {
+            data,
+            config,
+            image_cache,
+            fc_cache,
+            internal,
+            images: FastHashMap::new(),
+            created_windows: Vec::new(),
+            is_about_to_close: false,
+            needs_redraw: true,
+            framebuffer: RawImage::null_image(),
+        };
+
+        window.apply_resource_updates(initial_resource_updates);
+
+        let (animations_added, animations_removed) = window.internal.start_css_animations(&FastBTreeSet::new(), &window.config.system_callbacks);
+        window.start_stop_timers(animations_added, animations_removed);
+
+        window.run_component_callbacks();
+        window
+    }
+
+    /// Returns the current window state, which can be modified and
+    /// submitted via `set_window_state` in order to simulate an event
+    pub fn get_window_state(&self) -> &FullWindowState {
+        &self.internal.current_window_state
+    }
+
+    /// Simulates an OS event: replaces the current window state with `new_state`,
+    /// re-runs the hit-test and invokes all callbacks that are affected by the
+    /// difference between the old and the new state (resize, mouse move, key press, ...)
+    pub fn set_window_state(&mut self, new_state: FullWindowState) {
+
+        let previous_state = self.internal.current_window_state.clone();
+        let size_changed = previous_state.size.dimensions != new_state.size.dimensions ||
+                           previous_state.size.hidpi_factor != new_state.size.hidpi_factor;
+
+        self.internal.previous_window_state = Some(previous_state);
+        self.internal.current_window_state = new_state;
+
+        if self.internal.media_queries_changed() {
+            // the window crossed a breakpoint of an `@media` rule: restyle the DOM
+            self.restyle_dom();
+        } else if size_changed {
+            self.do_resize();
+        }
+
+        self.update_hit_test();
+        let result = self.process_event();
+        let result = result.max_self(self.process_accelerators());
+        self.handle_process_event_result(result);
+    }
+
+    /// Convenience function: clones the current window state,
+    /// lets `f` modify it and submits it via `set_window_state`
+    pub fn update_window_state<F: FnOnce(&mut FullWindowState)>(&mut self, f: F) {
+        let mut new_state = self.internal.current_window_state.clone();
+        f(&mut new_state);
+        self.set_window_state(new_state);
+    }
+
+    /// Runs all timers that are due at the current system time
+    pub fn run_timers(&mut self) {
+
+        let now = (self.config.system_callbacks.get_system_time_fn.cb)();
+
+        let timers_to_run = self.internal.timers.iter()
+        .filter(|(_, timer)| timer.instant_of_next_run() <= now)
+        .map(|(id, _)| *id)
+        .collect::<Vec<_>>();
+
+        for timer_id in timers_to_run {
+
+            let internal = &mut self.internal;
+            let data = &mut self.data;
+            let image_cache = &mut self.image_cache;
+            let system_callbacks = &self.config.system_callbacks;
+            let frame_start = now.clone();
+
+            let callback_result = self.fc_cache.apply_closure(|fc_cache| {
+                internal.run_single_timer(
+                    timer_id.id,
+                    frame_start,
+                    data,
+                    &RawWindowHandle::Unsupported,
+                    &OptionGlContextPtr::None,
+                    image_cache,
+                    fc_cache,
+                    system_callbacks,
+                )
+            });
+
+            let nodes_to_check = NodesToCheck::empty(
+                self.internal.current_window_state.mouse_state.mouse_down(),
+                self.internal.current_window_state.focused_node,
+            );
+
+            let result = self.process_callback_results(callback_result, &nodes_to_check);
+            self.handle_process_event_result(result);
+        }
+    }
+
+    /// Polls all running background threads once and
+    /// processes the messages they sent back
+    pub fn run_threads(&mut self) {
+
+        if self.internal.threads.is_empty() {
+            return;
+        }
+
+        let internal = &mut self.internal;
+        let data = &mut self.data;
+        let image_cache = &mut self.image_cache;
+        let system_callbacks = &self.config.system_callbacks;
+
+        let callback_result = self.fc_cache.apply_closure(|fc_cache| {
+            internal.run_all_threads(
+                data,
+                &RawWindowHandle::Unsupported,
+                &OptionGlContextPtr::None,
+                image_cache,
+                fc_cache,
+                system_callbacks,
+            )
+        });
+
+        let nodes_to_check = NodesToCheck::empty(
+            self.internal.current_window_state.mouse_state.mouse_down(),
+            self.internal.current_window_state.focused_node,
+        );
+
+        let result = self.process_callback_results(callback_result, &nodes_to_check);
+        self.handle_process_event_result(result);
+    }
+
+    /// Calls the layout() callback again and restyles the new DOM
+    pub fn regenerate_dom(&mut self) {
+
+        use azul_core::window_state::StyleAndLayoutChanges;
+
+        // unset the focus
+        self.internal.current_window_state.focused_node = None;
+
+        let previous_animations = self.internal.get_css_animation_timers();
+
+        let internal = &mut self.internal;
+        let data = &mut self.data;
+        let image_cache = &self.image_cache;
+
+        let mut resource_updates = Vec::new();
+        self.fc_cache.apply_closure(|fc_cache| {
+            internal.regenerate_styled_dom(
+                data,
+                image_cache,
+                &OptionGlContextPtr::None,
+                &mut resource_updates,
+                &crate::app::CALLBACKS,
+                fc_cache,
+                azul_layout::do_the_relayout,
+                |window_state, scroll_states, layout_results| {
+                    FullHitTest::new(layout_results, &window_state.mouse_state.cursor_position, scroll_states)
+                }
+            );
+        });
+
+        self.apply_resource_updates(resource_updates);
+
+        let (animations_added, animations_removed) = self.internal.start_css_animations(&previous_animations, &self.config.system_callbacks);
+        self.start_stop_timers(animations_added, animations_removed);
+
+        self.internal.previous_window_state = None;
+        self.update_hit_test();
+
+        let nodes_to_check = NodesToCheck::simulated_mouse_move(
+            &self.internal.current_window_state.last_hit_test,
+            self.internal.current_window_state.focused_node,
+            self.internal.current_window_state.mouse_state.mouse_down()
+        );
+
+        let sl = StyleAndLayoutChanges::new(
+            &nodes_to_check,
+            &mut self.internal.layout_results,
+            &self.image_cache,
+            &mut self.internal.renderer_resources,
+            self.internal.current_window_state.size.get_layout_size(),
+            &self.internal.document_id,
+            None,
+            None,
+            None,
+            None,
+            &None,
+            azul_layout::do_the_relayout,
+        );
+
+        if let Some(resized) = sl.nodes_that_changed_size.as_ref() {
+            self.internal.component_events.insert_resized_nodes(resized);
+        }
+
+        self.needs_redraw = true;
+    }
+
+    /// Restyles the current DOM with the `@media` rules that apply to the current
+    /// window size / theme and lays it out again. Unlike `regenerate_dom()`, the
+    /// layout callback is not called and the focused node is kept.
+    fn restyle_dom(&mut self) {
+
+        let previous_animations = self.internal.get_css_animation_timers();
+
+        let internal = &mut self.internal;
+        let image_cache = &self.image_cache;
+
+        let mut resource_updates = Vec::new();
+        self.fc_cache.apply_closure(|fc_cache| {
+            internal.restyle_media_queries(
+                image_cache,
+                &OptionGlContextPtr::None,
+                &mut resource_updates,
+                &crate::app::CALLBACKS,
+                fc_cache,
+                azul_layout::do_the_relayout,
+                |window_state, scroll_states, layout_results| {
+                    FullHitTest::new(layout_results, &window_state.mouse_state.cursor_position, scroll_states)
+                }
+            );
+        });
+
+        self.apply_resource_updates(resource_updates);
+
+        let (animations_added, animations_removed) = self.internal.start_css_animations(&previous_animations, &self.config.system_callbacks);
+        self.start_stop_timers(animations_added, animations_removed);
+
+        self.update_hit_test();
+        self.needs_redraw = true;
+    }
+
+    /// Builds the display list for the current frame
+    pub fn get_display_list(&self) -> CachedDisplayList {
+        use azul_core::styled_dom::DomId;
+        use azul_core::ui_solver::LayoutResult;
+
+        LayoutResult::get_cached_display_list(
+            &self.internal.document_id,
+            DomId::ROOT_ID,
+            self.internal.epoch,
+            &self.internal.layout_results,
+            &self.internal.current_window_state,
+            &self.internal.gl_texture_cache,
+            &self.internal.renderer_resources,
+            &self.image_cache,
+        )
+    }
+
+    /// Renders the current frame (if necessary) and returns the
+    /// RGBA8 framebuffer, the size is the physical size of the window
+    pub fn render(&mut self) -> &RawImage {
+        if self.needs_redraw {
+            let display_list = self.get_display_list();
+            self.framebuffer = self.rasterize(&display_list);
+            self.needs_redraw = false;
+        }
+        &self.framebuffer
+    }
+
+    /// Returns the last rendered frame without re-rendering
+    pub fn get_framebuffer(&self) -> &RawImage {
+        &self.framebuffer
+    }
+
+    // only returns an empty image if the window has a size of zero
+    fn rasterize(&self, display_list: &CachedDisplayList) -> RawImage {
+        use azulc_lib::cpurender::{render_display_list, CpuRenderOptions};
+
+        let window_state = &self.internal.current_window_state;
+        let physical_size = window_state.size.get_physical_size();
+
+        render_display_list(display_list, &CpuRenderOptions {
+            width: physical_size.width as usize,
+            height: physical_size.height as usize,
+            hidpi_factor: window_state.size.hidpi_factor,
+            background_color: window_state.background_color,
+            renderer_resources: &self.internal.renderer_resources,
+            images: &self.images,
+            scroll_states: &self.internal.scroll_states,
+        }).unwrap_or_else(RawImage::null_image)
+    }
+
+    fn do_resize(&mut self) {
+        let internal = &mut self.internal;
+        let image_cache = &self.image_cache;
+        let size = internal.current_window_state.size.clone();
+        let theme = internal.current_window_state.theme;
+        self.fc_cache.apply_closure(|fc_cache| {
+            internal.do_quick_resize(
+                image_cache,
+                &crate::app::CALLBACKS,
+                azul_layout::do_the_relayout,
+                fc_cache,
+                &size,
+                theme,
+            );
+        });
+        self.needs_redraw = true;
+    }
+
+    /// Re-invokes the IFrames that were scrolled out of their rendered DOM,
+    /// returns whether any IFrame DOM changed
+    fn scroll_iframes(&mut self) -> bool {
+        let internal = &mut self.internal;
+        let image_cache = &self.image_cache;
+        let mut resource_updates = Vec::new();
+        let iframes_changed = self.fc_cache.apply_closure(|fc_cache| {
+            internal.scroll_iframes(
+                image_cache,
+                &crate::app::CALLBACKS,
+                fc_cache,
+                &mut resource_updates,
+            )
+        });
+        self.apply_resource_updates(resource_updates);
+        !iframes_changed.is_empty()
+    }
+
+    fn update_hit_test(&mut self) {
+        let hit_test = FullHitTest::new(
+            &self.internal.layout_results,
+            &self.internal.current_window_state.mouse_state.cursor_position,
+            &self.internal.scroll_states,
+        );
+        self.internal.current_window_state.last_hit_test = hit_test;
+    }
+
+    fn handle_process_event_result(&mut self, mut result: ProcessEventResult) {
+        loop {
+            match result {
+                ProcessEventResult::DoNothing => break,
+                ProcessEventResult::ShouldRegenerateDomCurrentWindow |
+                ProcessEventResult::ShouldRegenerateDomAllWindows => {
+                    self.regenerate_dom();
+                    break;
+                },
+                ProcessEventResult::ShouldUpdateDisplayListCurrentWindow |
+                ProcessEventResult::ShouldReRenderCurrentWindow => {
+                    self.needs_redraw = true;
+                    break;
+                },
+                ProcessEventResult::UpdateHitTesterAndProcessAgain => {
+                    // nodes were resized: there is no asynchronous hit-tester,
+                    // so the new hit-test can be done immediately
+                    self.needs_redraw = true;
+                    self.internal.previous_window_state = Some(self.internal.current_window_state.clone());
+                    self.update_hit_test();
+                    result = self.process_event();
+                },
+            }
+        }
+
+        self.run_component_callbacks();
+    }
+
+    /// Calls the AfterMount / BeforeUnmount / NodeResized callbacks
+    /// that were queued by the last DOM regeneration or relayout
+    fn run_component_callbacks(&mut self) {
+
+        if self.internal.component_events.is_empty() &&
+           self.internal.unmounted_layout_results.is_empty() {
+            return;
+        }
+
+        let internal = &mut self.internal;
+        let image_cache = &mut self.image_cache;
+        let system_callbacks = &self.config.system_callbacks;
+
+        let callback_result = self.fc_cache.apply_closure(|fc_cache| {
+            internal.run_component_callbacks(
+                &RawWindowHandle::Unsupported,
+                &OptionGlContextPtr::None,
+                image_cache,
+                fc_cache,
+                system_callbacks,
+            )
+        });
+
+        let nodes_to_check = NodesToCheck::empty(
+            self.internal.current_window_state.mouse_state.mouse_down(),
+            self.internal.current_window_state.focused_node,
+        );
+
+        let result = self.process_callback_results(callback_result, &nodes_to_check);
+        self.handle_process_event_result(result);
+    }
+
+    // Assuming that current_window_state and the previous_window_state of the window
+    // are set correctly and the hit-test has been performed, will call the callbacks
+    // and return what the application should do next
+    #[must_use]
+    fn process_event(&mut self) -> ProcessEventResult {
+
+        use azul_core::window_state::{Events, CallbacksOfHitTest};
+
+        let events = Events::new(
+            &self.internal.current_window_state,
+            &self.internal.previous_window_state,
+        );
+
+        let mut nodes_to_check = NodesToCheck::new(
+            &self.internal.current_window_state.last_hit_test,
+            &events
+        );
+
+        // Tab / Shift + Tab: move the focus to the next / previous focusable node
+        if self.config.enable_tab_navigation {
+            nodes_to_check.apply_tab_navigation(
+                &self.internal.current_window_state,
+                &self.internal.previous_window_state,
+                &self.internal.layout_results,
+            );
+        }
+
+        let internal = &mut self.internal;
+        let image_cache = &mut self.image_cache;
+        let system_callbacks = &self.config.system_callbacks;
+
+        let callback_result = self.fc_cache.apply_closure(|fc_cache| {
+
+            let mut callbacks = CallbacksOfHitTest::new(&nodes_to_check, &events, &internal.layout_results);
+            let current_scroll_states = internal.get_current_scroll_states();
+
+            callbacks.call(
+                &internal.previous_window_state,
+                &internal.current_window_state,
+                &RawWindowHandle::Unsupported,
+                &current_scroll_states,
+                &OptionGlContextPtr::None,
+                &mut internal.layout_results,
+                &mut internal.scroll_states,
+                image_cache,
+                fc_cache,
+                system_callbacks,
+                &internal.renderer_resources,
+            )
+        });
+
+        self.process_callback_results(callback_result, &nodes_to_check)
+    }
+
+    // Invokes the callback of the menu accelerator or window
+    // shortcut that matches the keys that were just pressed
+    #[must_use]
+    fn process_accelerators(&mut self) -> ProcessEventResult {
+
+        let (hit_dom_node, mut menu_callback) = match self.internal.get_pressed_accelerator() {
+            Some(s) => s,
+            None => return ProcessEventResult::DoNothing,
+        };
+
+        let internal = &mut self.internal;
+        let image_cache = &mut self.image_cache;
+        let system_callbacks = &self.config.system_callbacks;
+
+        let callback_result = self.fc_cache.apply_closure(|fc_cache| {
+            internal.invoke_menu_callback(
+                &mut menu_callback,
+                hit_dom_node,
+                &RawWindowHandle::Unsupported,
+                &OptionGlContextPtr::None,
+                image_cache,
+                fc_cache,
+                system_callbacks,
+            )
+        });
+
+        let nodes_to_check = NodesToCheck::empty(
+            self.internal.current_window_state.mouse_state.mouse_down(),
+            self.internal.current_window_state.focused_node,
+        );
+
+        self.process_callback_results(callback_result, &nodes_to_check)
+    }
+
+    #[must_use]
+    fn process_callback_results(
+        &mut self,
+        callback_results: CallCallbacksResult,
+        nodes_to_check: &NodesToCheck,
+    ) -> ProcessEventResult {
+
+        use azul_core::callbacks::Update;
+        use azul_core::window_state::StyleAndLayoutChanges;
+
+        if let Some(images_changed) = callback_results.images_changed {
+            self.update_image_resources(images_changed);
+        }
+
+        self.start_stop_timers(
+            callback_results.timers.unwrap_or_default(),
+            callback_results.timers_removed.unwrap_or_default()
+        );
+        self.start_stop_threads(
+            callback_results.threads.unwrap_or_default(),
+            callback_results.threads_removed.unwrap_or_default()
+        );
+
+        self.created_windows.extend(callback_results.windows_created.into_iter());
+
+        let mut result = ProcessEventResult::DoNothing;
+
+        // system scroll event: scroll the hovered nodes
+        let mut need_scroll_render = match self.internal.current_window_state.get_scroll_amount() {
+            Some(scroll_amount) => self.internal.scroll_states.should_scroll_render(
+                &scroll_amount,
+                &self.internal.current_window_state.last_hit_test,
+            ),
+            None => false,
+        };
+        self.internal.current_window_state.mouse_state.reset_scroll_to_zero();
+
+        if let Some(modified) = callback_results.modified_window_state.as_ref() {
+            if modified.flags.is_about_to_close {
+                self.is_about_to_close = true;
+            }
+            let size_changed = modified.size.get_layout_size() != self.internal.current_window_state.size.get_layout_size();
+            self.internal.current_window_state = FullWindowState::from_window_state(
+                modified,
+                self.internal.current_window_state.dropped_file.clone(),
+                self.internal.current_window_state.hovered_file.clone(),
+                self.internal.current_window_state.focused_node.clone(),
+                self.internal.current_window_state.last_hit_test.clone(),
+            );
+            if self.internal.media_queries_changed() {
+                // callbacks changed the window size / theme across a breakpoint of an `@media` rule
+                self.restyle_dom();
+                result = ProcessEventResult::UpdateHitTesterAndProcessAgain;
+            } else if size_changed {
+                self.do_resize();
+                result = ProcessEventResult::UpdateHitTesterAndProcessAgain;
+            } else if !need_scroll_render {
+                result = ProcessEventResult::ShouldReRenderCurrentWindow;
+            }
+        }
+
+        let layout_callback_changed = self.internal.current_window_state.layout_callback_changed(
+            &self.internal.previous_window_state
+        );
+
+        if layout_callback_changed {
+            return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+        } else {
+            match callback_results.callbacks_update_screen {
+                Update::RegenerateStyledDomForCurrentWindow => {
+                    return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+                },
+                Update::RegenerateStyledDomForAllWindows => {
+                    return ProcessEventResult::ShouldRegenerateDomAllWindows;
+                },
+                Update::DoNothing => { },
+            }
+        }
+
+        let css_transitions_changed = callback_results.css_transitions_changed.clone().unwrap_or_default();
+        let style_layout_changes = StyleAndLayoutChanges::new(
+            nodes_to_check,
+            &mut self.internal.layout_results,
+            &self.image_cache,
+            &mut self.internal.renderer_resources,
+            self.internal.current_window_state.size.get_layout_size(),
+            &self.internal.document_id,
+            callback_results.css_properties_changed.as_ref(),
+            callback_results.css_variables_changed.as_ref(),
+            Some(&css_transitions_changed),
+            callback_results.words_changed.as_ref(),
+            &callback_results.update_focused_node,
+            azul_layout::do_the_relayout,
+        );
+
+        // start the timers of the CSS transitions
+        let (transitions_added, transitions_removed) = style_layout_changes.get_transition_timers(&self.config.system_callbacks);
+        self.start_stop_timers(transitions_added, transitions_removed);
+
+        if let Some(focus_change) = style_layout_changes.focus_change.clone() {
+            self.internal.current_window_state.focused_node = focus_change.new;
+            if let Some(new_focus) = focus_change.new {
+                need_scroll_render |= self.internal.scroll_node_into_view(new_focus);
+            }
+        }
+
+        if let Some(resized) = style_layout_changes.nodes_that_changed_size.as_ref() {
+            self.internal.component_events.insert_resized_nodes(resized);
+        }
+
+        // IFrames that were scrolled out of their rendered DOM have to be re-invoked
+        let iframes_changed = (need_scroll_render || callback_results.should_scroll_render) && self.scroll_iframes();
+
+        if style_layout_changes.did_resize_nodes() || iframes_changed {
+            ProcessEventResult::UpdateHitTesterAndProcessAgain
+        } else if style_layout_changes.need_regenerate_display_list() {
+            ProcessEventResult::ShouldUpdateDisplayListCurrentWindow
+        } else if need_scroll_render || style_layout_changes.need_redraw() {
+            ProcessEventResult::ShouldReRenderCurrentWindow
+        } else {
+            result
+        }
+    }
+
+    fn start_stop_timers(&mut self, added: FastHashMap<TimerId, Timer>, removed: FastBTreeSet<TimerId>) {
+        for (id, timer) in added {
+            self.internal.timers.insert(id, timer);
+        }
+        for id in removed {
+            self.internal.timers.remove(&id);
+        }
+    }
+
+    fn start_stop_threads(&mut self, mut added: FastHashMap<ThreadId, Thread>, removed: FastBTreeSet<ThreadId>) {
+        self.internal.threads.append(&mut added);
+        self.internal.threads.retain(|r, _| !removed.contains(r));
+    }
+
+    /// Stores the image data of the resource updates, since there
+    /// is no renderer that could hold on to the image data
+    fn apply_resource_updates(&mut self, resource_updates: Vec<ResourceUpdate>) {
+        for update in resource_updates {
+            match update {
+                ResourceUpdate::AddImage(add) => {
+                    self.images.insert(add.key, (add.descriptor, add.data));
+                },
+                ResourceUpdate::UpdateImage(update) => {
+                    self.images.insert(update.key, (update.descriptor, update.data));
+                },
+                ResourceUpdate::DeleteImage(key) => {
+                    self.images.remove(&key);
+                },
+                // fonts are stored as parsed fonts in the RendererResources
+                _ => { },
+            }
+        }
+        self.needs_redraw = true;
+    }
+
+    // Same as the win32 update_image_resources, but only for CPU-backed images
+    fn update_image_resources(
+        &mut self,
+        images_to_update: BTreeMap<azul_core::styled_dom::DomId, BTreeMap<azul_core::id_tree::NodeId, (azul_core::app_resources::ImageRef, UpdateImageType)>>,
+    ) {
+
+        use azul_core::dom::NodeType;
+        use azul_core::app_resources::DecodedImage;
+
+        for (dom_id, image_map) in images_to_update {
+
+            let layout_result = match self.internal.layout_results.get(dom_id.inner) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            for (node_id, (image_ref, image_type)) in image_map {
+
+                let existing_image_ref_hash = match image_type {
+                    UpdateImageType::Content => {
+                        match layout_result.styled_dom.node_data.as_container().get(node_id).map(|n| n.get_node_type()) {
+                            Some(NodeType::Image(image_ref)) => image_ref.get_hash(),
+                            _ => continue,
+                        }
+                    },
+                    UpdateImageType::Background => {
+
+                        let node_data = layout_result.styled_dom.node_data.as_container();
+                        let node_data = match node_data.get(node_id) {
+                            Some(s) => s,
+                            None => continue,
+                        };
+
+                        let styled_node_states = layout_result.styled_dom.styled_nodes.as_container();
+                        let node_state = match styled_node_states.get(node_id) {
+                            Some(s) => s.state.clone(),
+                            None => continue,
+                        };
+
+                        let default = azul_css::StyleBackgroundContentVec::from_const_slice(&[]);
+                        let image_cache = &self.image_cache;
+
+                        // TODO: only updates the first image background - usually not a problem
+                        let bg_hash = layout_result.styled_dom.css_property_cache.ptr
+                        .get_background_content(node_data, &node_id, &node_state)
+                        .and_then(|bg| bg.get_property().unwrap_or(&default).as_ref().iter().find_map(|b| match b {
+                            azul_css::StyleBackgroundContent::Image(id) => {
+                                let image_ref = image_cache.get_css_image_id(id)?;
+                                Some(image_ref.get_hash())
+                            },
+                            _ => None,
+                        }));
+
+                        match bg_hash {
+                            Some(h) => h,
+                            None => continue,
+                        }
+                    }
+                };
+
+                let (descriptor, data) = match image_ref.into_inner() {
+                    Some(DecodedImage::Raw((descriptor, data))) => (descriptor, data),
+                    // GL textures and image callbacks can't be rendered without a GPU
+                    _ => continue,
+                };
+
+                let key = match self.internal.renderer_resources.currently_registered_images.get_mut(&existing_image_ref_hash) {
+                    Some(s) => {
+                        s.1 = descriptor.clone();
+                        s.0
+                    },
+                    None => continue, // updating an image requires at
+                                      // least one image to be present
+                };
+
+                self.images.insert(key, (descriptor, data));
+            }
+        }
+
+        self.needs_redraw = true;
+    }
+}
@@ -1,2 +1,13 @@
 #[cfg(target_os = "windows")]
-pub mod win32;
\ No newline at end of file
+pub mod win32;
+#[cfg(not(target_os = "windows"))]
+pub mod other;
+/// Window-less shell that renders into an RGBA buffer (for tests / CI),
+/// frames are rasterized with tiny-skia, so it requires the `svg` feature
+#[cfg(feature = "svg")]
+pub mod headless;
+#[cfg(not(target_os = "windows"))]
+mod gl;
+
+#[cfg(not(target_os = "windows"))]
+pub(crate) use self::gl::load_gl_context;
@@ -1,39 +1,102 @@
-#![not(target_os = "windows")]
-
-use glutin::{
+//! Event loop for Linux / macOS, built on top of glutin / winit
+//!
+//! Follows the same structure as the win32 shell: OS events only update
+//! the `FullWindowState` of the window, then the hit-test is redone and the
+//! callbacks are invoked, the result of the callbacks determines whether the
+//! DOM, the display list or only the frame has to be regenerated.
+
+use alloc::collections::BTreeMap;
+use azul_core::{
+    FastHashMap, FastBTreeSet,
+    app_resources::{AppConfig, ImageCache},
+    callbacks::{RefAny, DomNodeId},
+    task::{Instant, Thread, ThreadId, Timer, TimerId},
     window::{
-        WindowId as GlutinWindowId,
-    },
-    event::{
-        WindowEvent as GlutinWindowEvent,
+        CallCallbacksResult, MenuCallback,
+        Monitor, MonitorVec, WindowCreateOptions,
     },
+    window_state::NodesToCheck,
+};
+use glutin::{
+    event::WindowEvent as GlutinWindowEvent,
     event_loop::{
+        ControlFlow,
         EventLoopProxy as GlutinEventLoopProxy,
         EventLoopWindowTarget as GlutinEventLoopWindowTarget,
-        EventLoop as GlutinEventLoop,
     },
+    window::WindowId as GlutinWindowId,
+};
+use std::time::{Duration as StdDuration, Instant as StdInstant};
+use crate::{
+    app::{App, LazyFcCache},
+    window::{Window, UserEvent},
 };
-use azul_core::window::{MonitorVec, WindowCreateOptions};
-use alloc::collections::btree_map::BTreeMap;
-use webrender::Transaction as WrTransaction;
 
-use crate::app::App;
-use crate::window::{Window, UserEvent, Monitor, MonitorVec};
+/// Interval in which the messages of running background threads are polled
+const THREAD_POLL_INTERVAL: StdDuration = StdDuration::from_millis(16);
+
+/// Interval in which the `layout()` callback is re-invoked if the window was
+/// created with `hot_reload = true`
+const HOT_RELOAD_INTERVAL: StdDuration = StdDuration::from_millis(200);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ProcessEventResult {
+    DoNothing,
+    ShouldRegenerateDomCurrentWindow,
+    ShouldRegenerateDomAllWindows,
+    ShouldUpdateDisplayListCurrentWindow,
+    // Nodes changed their size: do another hit-test and
+    // recurse until nothing has changed anymore
+    UpdateHitTesterAndProcessAgain,
+    // Only refresh the display (in case of pure scroll or GPU-only events)
+    ShouldReRenderCurrentWindow,
+}
+
+impl ProcessEventResult {
+    fn order(&self) -> usize {
+        use self::ProcessEventResult::*;
+        match self {
+            DoNothing => 0,
+            ShouldReRenderCurrentWindow => 1,
+            ShouldUpdateDisplayListCurrentWindow => 2,
+            UpdateHitTesterAndProcessAgain => 3,
+            ShouldRegenerateDomCurrentWindow => 4,
+            ShouldRegenerateDomAllWindows => 5,
+        }
+    }
 
-pub fn get_monitors(&App) -> MonitorVec {
+    // Returns the result that requires more work from the window
+    fn max_self(self, other: Self) -> Self {
+        if other.order() > self.order() { other } else { self }
+    }
+}
+
+/// Everything the event loop owns, except for the event loop itself
+struct ApplicationData {
+    data: RefAny,
+    config: AppConfig,
+    image_cache: ImageCache,
+    fc_cache: LazyFcCache,
+    windows: BTreeMap<GlutinWindowId, Window>,
+}
+
+pub fn get_monitors(app: &App) -> MonitorVec {
     use crate::window::{monitor_new, monitor_handle_get_id};
-    let mut monitors = self.event_loop.available_monitors()
+
+    let mut monitors = app.event_loop.available_monitors()
     .map(|mh| monitor_new(mh, false))
     .collect::<Vec<Monitor>>();
-    if let Some(primary) = self.event_loop.primary_monitor() {
+
+    if let Some(primary) = app.event_loop.primary_monitor() {
         if let Some(pm) = monitors.iter_mut().find(|i| i.id == monitor_handle_get_id(&primary)) {
             pm.is_primary_monitor = true;
         }
     }
+
     monitors.into()
 }
 
-pub fn run(mut app: App, root_window: WindowCreateOptions) {
+pub fn run(app: App, root_window: WindowCreateOptions) -> ! {
 
     #[cfg(target_os = "macos")]
     {
@@ -46,1133 +109,307 @@ pub fn run(mut app: App, root_window: WindowCreateOptions) {
         );
     }
 
-    self.add_window(root_window);
-    run_inner(self)
-}
-
-#[cfg(all(not(test), feature = "std"))]
-#[allow(unused_variables)]
-fn run_inner(app: App) {
-
-    use azul_core::styled_dom::DomId;
-
     let App {
-        mut data,
-        event_loop,
+        data,
         config,
         windows,
-        mut image_cache,
-        mut fc_cache,
+        image_cache,
+        fc_cache,
+        event_loop,
     } = app;
 
-    let mut timers = BTreeMap::new();
-    let mut threads = BTreeMap::new();
-    let mut active_windows = BTreeMap::new();
+    let mut app = ApplicationData {
+        data,
+        config,
+        image_cache,
+        fc_cache,
+        windows: BTreeMap::new(),
+    };
 
     let proxy = event_loop.create_proxy();
 
-    // Create the windows (makes them actually show up on the screen)
-    for window_create_options in windows {
-        let create_callback = window_create_options.create_callback.clone();
-
-        let id = create_window(
-            &mut data,
-            window_create_options,
-            &event_loop,
-            &proxy,
-            &mut active_windows,
-            &image_cache,
-            &mut fc_cache,
-            &mut timers,
-            &config,
-        );
+    let mut new_windows = windows;
+    new_windows.push(root_window);
+    create_windows(&mut app, new_windows, &event_loop, &proxy);
 
-        if let Some(init_callback) = create_callback.as_ref() {
-            if let Some(window_id) = id.as_ref() {
-
-                use azul_core::callbacks::DomNodeId;
-                use azul_core::callbacks::CallbackInfo;
-                use azul_core::window::WindowState;
-
-                let window = match active_windows.get_mut(&window_id) {
-                    Some(s) => s,
-                    None => continue,
-                };
-
-                let mut window_state: WindowState = window.internal.current_window_state.clone().into();
-                let mut new_windows = Vec::new();
-                let mut stop_propagation = false;
-                let mut focus_target = None; // TODO: useful to implement autofocus
-                let scroll_states = window.internal.get_current_scroll_states();
-
-                let mut words_changed = BTreeMap::new();
-                let mut images_changed = BTreeMap::new();
-                let mut image_masks_changed = BTreeMap::new();
-                let mut css_properties_changed = BTreeMap::new();
-                let mut nodes_scrolled_in_callback = BTreeMap::new();
-
-                let mut new_timers = BTreeMap::new();
-                let mut new_threads = BTreeMap::new();
-
-                let gl_context_ptr = &window.gl_context_ptr;
-                let layout_result = &mut window.internal.layout_results[DomId::ROOT_ID.inner];
-                let mut datasets = layout_result.styled_dom.node_data.split_into_callbacks_and_dataset();
-                let current_window_state = &window.internal.current_window_state;
-                let previous_window_state = &window.internal.previous_window_state;
-                let words_cache = &layout_result.words_cache;
-                let shaped_words_cache = &layout_result.shaped_words_cache;
-                let positioned_words_cache = &layout_result.positioned_words_cache;
-                let rects = &layout_result.rects;
-                let node_hierarchy = &layout_result.styled_dom.node_hierarchy;
-                let raw_window_handle = &window.window_handle;
-                let css_property_cache = &layout_result.styled_dom.css_property_cache.ptr;
-                let styled_nodes = &layout_result.styled_dom.styled_nodes;
-
-                let callback_info = fc_cache.apply_closure(|fc_cache| {
-                    CallbackInfo::new(
-                        css_property_cache,
-                        styled_nodes,
-                        previous_window_state,
-                        current_window_state,
-                        &mut window_state,
-                        &gl_context_ptr,
-                        &mut image_cache,
-                        fc_cache,
-                        &mut new_timers,
-                        &mut new_threads,
-                        &mut new_windows,
-                        raw_window_handle,
-                        node_hierarchy,
-                        &config.system_callbacks,
-                        words_cache,
-                        shaped_words_cache,
-                        positioned_words_cache,
-                        rects,
-                        &mut datasets.1,
-                        &mut stop_propagation,
-                        &mut focus_target,
-                        &mut words_changed,
-                        &mut images_changed,
-                        &mut image_masks_changed,
-                        &mut css_properties_changed,
-                        &scroll_states,
-                        &mut nodes_scrolled_in_callback,
-                        DomNodeId::ROOT,
-                        None.into(),
-                        None.into(),
-                    )
-                });
-
-                let _ = (init_callback.cb)(&mut data, callback_info);
-
-
-                for (timer_id, timer) in new_timers {
-                    timers.entry(*window_id).or_insert_with(|| BTreeMap::new()).insert(timer_id, timer);
-                }
-                if timers.get(window_id).map(|w| w.is_empty()) == Some(true) { timers.remove(window_id); }
-                for (thread_id, thread) in new_threads {
-                    threads.entry(*window_id).or_insert_with(|| BTreeMap::new()).insert(thread_id, thread);
-                }
-                if threads.get(window_id).map(|w| w.is_empty()) == Some(true) { threads.remove(window_id); }
-            }
-        }
-    };
+    event_loop.run(move |event, event_loop_target, control_flow| {
 
+        use glutin::event::Event;
 
-    // In order to prevent syscalls on every frame
-    // simply use a std::Instant and a coarsetime::Instant
-    //
-    // In order to get the current time, call timer_time_coarse.recent(),
-    // then add the duration (since application startup) to the std::Instant
-    //
-    // This avoids frequent system calls on every frame
-    let timer_std_start = std::time::Instant::now();
-    let timer_coarse_start = coarsetime::Instant::now();
-    let timer_coarse_frame = coarsetime::Instant::now();
-
-    event_loop.run_return(move |event, event_loop_target, control_flow| {
-
-        use glutin::event::{Event, StartCause};
-        use glutin::event_loop::ControlFlow;
-        use alloc::collections::btree_set::BTreeSet;
-        use azul_core::task::{run_all_timers, clean_up_finished_threads};
-        use azul_core::window_state::StyleAndLayoutChanges;
-        use azul_core::window_state::{Events, NodesToCheck};
-        use azul_core::window::{FullHitTest, CursorTypeHitTest};
-        use crate::wr_translate::wr_translate_document_id;
-
-        // Immediately return on DeviceEvent before doing anything else
-        match &event {
-            Event::DeviceEvent { .. } => {
-                *control_flow = ControlFlow::Wait;
-                return;
-            },
-            _ => { },
-        }
-
-        let mut windows_created = Vec::<WindowCreateOptions>::new();
+        let mut new_windows = Vec::new();
 
         match event {
-            Event::DeviceEvent { .. } => {
-                // ignore high-frequency events
-                *control_flow = ControlFlow::Wait;
-                return;
+            Event::WindowEvent { window_id, event } => {
+                process_window_event(&mut app, window_id, &event, event_loop_target, &mut new_windows);
             },
-            Event::NewEvents(StartCause::ResumeTimeReached { .. }) |
-            Event::NewEvents(StartCause::Poll) => {
-                // run timers / tasks only every 60ms, not on every window event
-                use azul_core::task::Instant;
-
-                let mut update_screen_timers_tasks = Update::DoNothing;
-                coarsetime::Instant::update();
-                let frame_start = Instant::System((timer_std_start + translate_duration(
-                    timer_coarse_frame.duration_since(timer_coarse_start)
-                )).into());
-
-                // run timers
-                let mut all_new_current_timers = BTreeMap::new();
-                let mut all_new_current_threads = BTreeMap::new();
-
-                // run threads
-                // TODO: threads should not depend on the window being active (?)
-                for (window_id, mut thread_map) in threads.iter_mut() {
-                    let window = match active_windows.get_mut(&window_id) {
-                        Some(s) => s,
-                        None => continue,
-                    };
-
-                    let mut words_changed_in_threads = BTreeMap::new();
-                    let mut images_changed_in_threads = BTreeMap::new();
-                    let mut image_masks_changed_in_threads = BTreeMap::new();
-                    let mut css_properties_changed_in_threads = BTreeMap::new();
-
-                    let mut nodes_scrolled_in_threads = BTreeMap::new();
-                    let mut new_focus_node = None;
-                    let mut modifiable_window_state = window.internal.current_window_state.clone().into();
-                    let mut timers_uninitialized = BTreeMap::new();
-                    let mut cur_timers = timers.get_mut(window_id).unwrap_or(&mut timers_uninitialized);
-                    let mut new_threads = BTreeMap::new();
-
-                    let current_scroll_states = window.internal.get_current_scroll_states();
-                    let update_screen_threads = fc_cache.apply_closure(|fc_cache| {
-                        clean_up_finished_threads(
-                            &mut thread_map,
-
-                            &window.internal.previous_window_state,
-                            &window.internal.current_window_state,
-                            &mut modifiable_window_state,
-                            &window.gl_context_ptr,
-                            &mut image_cache,
-                            fc_cache,
-                            &config.system_callbacks,
-                            &mut cur_timers,
-                            &mut new_threads,
-                            &mut windows_created,
-                            &window.window_handle,
-                            &mut window.internal.layout_results,
-                            &mut false, // stop_propagation - can't be set in timer
-                            &mut new_focus_node,
-                            &mut words_changed_in_threads,
-                            &mut images_changed_in_threads,
-                            &mut image_masks_changed_in_threads,
-                            &mut css_properties_changed_in_threads,
-                            &current_scroll_states,
-                            &mut nodes_scrolled_in_threads,
-                        )
-                    });
-
-                    match update_screen_threads {
-                        Update::DoNothing => {
-                            let new_focus_node = new_focus_node.and_then(|ft| {
-                                ft.resolve(
-                                    &window.internal.layout_results,
-                                    window.internal.current_window_state.focused_node
-                                ).ok()
-                            });
-
-                            let window_size = window.internal.get_layout_size();
-
-                            // re-layouts and re-styles the window.internal.layout_results
-                            let changes = StyleAndLayoutChanges::new(
-                                &NodesToCheck::empty(
-                                    window.internal.current_window_state.mouse_state.mouse_down(),
-                                    window.internal.current_window_state.focused_node,
-                                ),
-                                &mut window.internal.layout_results,
-                                &image_cache,
-                                &mut window.internal.renderer_resources,
-                                window_size,
-                                &window.internal.document_id,
-                                Some(&css_properties_changed_in_threads),
-                                Some(&words_changed_in_threads),
-                                &new_focus_node,
-                                azul_layout::do_the_relayout,
-                            );
-
-                            let changes_need_regenerate_dl = changes.need_regenerate_display_list();
-                            let mut transaction = WrTransaction::new();
-
-                            if changes_need_regenerate_dl {
-                                let resource_updates = Vec::new(); // when re-generating the display list, no resource updates necessary
-                                window.rebuild_display_list(&mut transaction, &image_cache, resource_updates);
-                            }
-
-                            if changes_need_regenerate_dl || changes.need_redraw() {
-                                window.render_async(transaction, changes_need_regenerate_dl);
-                            }
-
-                            if changes.did_resize_nodes() {
-                                // window.force_synchronize_hit_tester_during();
-                            }
-
-                            if let Some(focus_change) = changes.focus_change {
-                                window.internal.current_window_state.focused_node = focus_change.new;
-                            }
-                        },
-                        Update::RegenerateStyledDomForCurrentWindow => {
-                            let mut resource_updates = Vec::new();
-                            let mut transaction = WrTransaction::new();
-                            window.regenerate_styled_dom(&mut data, &image_cache, &mut resource_updates, &mut fc_cache);
-                            window.rebuild_display_list(&mut transaction, &image_cache, resource_updates);
-                            window.render_async(transaction, /* display list was rebuilt */ true);
-                            // window.force_synchronize_hit_tester_during();
-                            window.internal.current_window_state.focused_node = None; // unset the focus
-                        },
-                        Update::RegenerateStyledDomForAllWindows => {
-                            if update_screen_timers_tasks == Update::DoNothing ||
-                               update_screen_timers_tasks == Update::RegenerateStyledDomForCurrentWindow {
-                                update_screen_timers_tasks = update_screen_threads;
-                            }
-                        }
-                    }
-
-                    for (thread_id, thread) in new_threads {
-                        all_new_current_threads.entry(*window_id).or_insert_with(|| BTreeMap::new()).insert(thread_id, thread);
-                    }
-
-                    let window_monitor = {
-                        let w = window.display.window();
-                        let primary_monitor = w.primary_monitor();
-                        w.current_monitor()
-                        .map(|m| {
-                            let mut mon = crate::window::monitor_new(m, false);
-                            if let Some(p) = primary_monitor.as_ref() {
-                                mon.is_primary_monitor = mon.id == crate::window::monitor_handle_get_id(p);
-                            }
-                            mon
-                        })
-                        .unwrap_or_default()
-                    };
-
-                    let current_window_save_state = window.internal.current_window_state.clone();
-                    let window_state_changed_in_callbacks = window.synchronize_window_state_with_os(modifiable_window_state, window_monitor);
-                    window.internal.previous_window_state = Some(current_window_save_state);
-                }
-
-                for (window_id, mut timer_map) in timers.iter_mut() {
-
-                    // for timers it makes sense to call them on the window,
-                    // since that's mostly what they're for (animations, etc.)
-                    //
-                    // for threads this model doesn't make that much sense
-                    let window = match active_windows.get_mut(&window_id) {
-                        Some(s) => s,
-                        None => continue,
-                    };
-
-                    let mut words_changed_in_timers = BTreeMap::new();
-                    let mut images_changed_in_timers = BTreeMap::new();
-                    let mut image_masks_changed_in_timers = BTreeMap::new();
-                    let mut css_properties_changed_in_timers = BTreeMap::new();
-
-                    let mut nodes_scrolled_in_timers = BTreeMap::new();
-                    let mut new_focus_node = None;
-                    let mut new_timers = BTreeMap::new();
-                    let mut modifiable_window_state = window.internal.current_window_state.clone().into();
-
-                    let mut threads_uninitialized = BTreeMap::new();
-                    let mut cur_threads = threads.get_mut(window_id).unwrap_or(&mut threads_uninitialized);
-                    let current_scroll_states = window.internal.get_current_scroll_states();
-
-
-                    let update_screen_timers = fc_cache.apply_closure(|fc_cache| {
-                        run_all_timers(
-                            &mut data,
-                            &mut timer_map,
-                            frame_start.clone(),
-
-                            &window.internal.previous_window_state,
-                            &window.internal.current_window_state,
-                            &mut modifiable_window_state,
-                            &window.gl_context_ptr,
-                            &mut image_cache,
-                            fc_cache,
-                            &config.system_callbacks,
-                            &mut new_timers,
-                            &mut cur_threads,
-                            &mut windows_created,
-                            &window.window_handle,
-                            &mut window.internal.layout_results,
-                            &mut false, // stop_propagation - can't be set in timer
-                            &mut new_focus_node,
-                            &mut words_changed_in_timers,
-                            &mut images_changed_in_timers,
-                            &mut image_masks_changed_in_timers,
-                            &mut css_properties_changed_in_timers,
-                            &current_scroll_states,
-                            &mut nodes_scrolled_in_timers,
-                        )
-                    });
-
-
-                    match update_screen_timers {
-                        Update::DoNothing => {
-                            let new_focus_node = new_focus_node.and_then(|ft| ft.resolve(&window.internal.layout_results, window.internal.current_window_state.focused_node).ok());
-                            let window_size = window.internal.get_layout_size();
-
-                            // re-layouts and re-styles the window.internal.layout_results
-                            let changes = StyleAndLayoutChanges::new(
-                                &NodesToCheck::empty(window.internal.current_window_state.mouse_state.mouse_down(), window.internal.current_window_state.focused_node),
-                                &mut window.internal.layout_results,
-                                &mut image_cache,
-                                &mut window.internal.renderer_resources,
-                                window_size,
-                                &window.internal.document_id,
-                                Some(&css_properties_changed_in_timers),
-                                Some(&words_changed_in_timers),
-                                &new_focus_node,
-                                azul_layout::do_the_relayout,
-                            );
-
-                            let changes_need_regenerate_dl = changes.need_regenerate_display_list();
-
-                            let mut transaction = WrTransaction::new();
-
-                            if changes_need_regenerate_dl {
-                                let resource_updates = Vec::new(); // when re-generating the display list, no resource updates necessary
-                                window.rebuild_display_list(&mut transaction, &image_cache, resource_updates);
-                            }
-
-                            if changes_need_regenerate_dl || changes.need_redraw() {
-                                window.render_async(transaction, changes_need_regenerate_dl);
-                            }
-
-                            if changes.did_resize_nodes() {
-                                // window.force_synchronize_hit_tester_during();
-                            }
-
-                            if let Some(focus_change) = changes.focus_change {
-                                window.internal.current_window_state.focused_node = focus_change.new;
-                            }
-                        },
-                        Update::RegenerateStyledDomForCurrentWindow => {
-                            let mut resource_updates = Vec::new();
-                            let mut transaction = WrTransaction::new();
-                            window.regenerate_styled_dom(&mut data, &image_cache, &mut resource_updates, &mut fc_cache);
-                            window.rebuild_display_list(&mut transaction, &image_cache, resource_updates);
-                            window.render_async(transaction, /* display list was rebuilt */ true);
-                            // window.force_synchronize_hit_tester_during();
-                            window.internal.current_window_state.focused_node = None; // unset the focus
-                        },
-                        Update::RegenerateStyledDomForAllWindows => {
-                            if update_screen_timers_tasks == Update::DoNothing ||
-                               update_screen_timers_tasks == Update::RegenerateStyledDomForCurrentWindow {
-                                update_screen_timers_tasks = update_screen_timers;
-                            }
-                        }
-                    }
-
-                    for (timer_id, timer) in new_timers {
-                        all_new_current_timers.entry(*window_id).or_insert_with(|| BTreeMap::new()).insert(timer_id, timer);
-                    }
-
-                    let window_monitor = {
-                        let w = window.display.window();
-                        let primary_monitor = w.primary_monitor();
-                        w.current_monitor()
-                        .map(|m| {
-                            let mut mon = crate::window::monitor_new(m, false);
-                            if let Some(p) = primary_monitor.as_ref() {
-                                mon.is_primary_monitor = mon.id == crate::window::monitor_handle_get_id(p);
-                            }
-                            mon
-                        })
-                        .unwrap_or_default()
-                    };
-
-                    let current_window_save_state = window.internal.current_window_state.clone();
-                    let window_state_changed_in_callbacks = window.synchronize_window_state_with_os(modifiable_window_state, window_monitor);
-                    window.internal.previous_window_state = Some(current_window_save_state);
-                }
-
-                for (window_id, new_current_timers) in all_new_current_timers {
-                    for (timer_id, timer) in new_current_timers {
-                        timers.entry(window_id).or_insert_with(|| BTreeMap::default()).insert(timer_id, timer);
-                    }
-                }
-                for window_id in active_windows.keys() {
-                    if timers.get(&window_id).map(|w| w.is_empty()) == Some(true) { timers.remove(&window_id); }
-                }
-
-                for (window_id, new_current_threads) in all_new_current_threads {
-                    for (thread_id, thread) in new_current_threads {
-                        threads.entry(window_id).or_insert_with(|| BTreeMap::default()).insert(thread_id, thread);
-                    }
-                }
-                for window_id in active_windows.keys() {
-                    if threads.get(&window_id).map(|w| w.is_empty()) == Some(true) { threads.remove(&window_id); }
-                }
-
-                if update_screen_timers_tasks == Update::RegenerateStyledDomForAllWindows {
-                    for (window_id, window) in active_windows.iter_mut() {
-                        let mut resource_updates = Vec::new();
-                        let mut transaction = WrTransaction::new();
-
-                        window.regenerate_styled_dom(&mut data, &image_cache, &mut resource_updates, &mut fc_cache);
-                        window.rebuild_display_list(&mut transaction, &image_cache, resource_updates);
-                        window.render_async(transaction, /* display list was rebuilt */ true);
-                        // window.force_synchronize_hit_tester_during();
-                        window.internal.current_window_state.focused_node = None; // unset the focus
-                    }
+            Event::UserEvent(UserEvent { window_id, .. }) => {
+                // webrender finished rendering the frame, swap it on the next redraw
+                if let Some(window) = app.windows.get(&window_id) {
+                    window.display.window().request_redraw();
                 }
             },
             Event::RedrawRequested(window_id) => {
-
-                // Ignore this event
-                //
-                // If we redraw here, the screen will flicker because the
-                // screen may not be finished painting
-
-                let window = match active_windows.get_mut(&window_id) {
-                    Some(s) => s,
-                    None => {return; },
-                };
-
-                if let Some(lock) = window.frame_lock.clone() {
-                    if lock {
-                        // frame is currently rendering, skip frame and try again
-                        window.display.window().request_redraw();
-                    } else {
-                        // frame finished rendering should be swapped
-                        window.display.window().set_visible(window.internal.current_window_state.flags.is_visible);
-                        #[cfg(target_os = "windows")] {
-                            // workaround for windows bug: window cannot be maximized without being visible
-                            if window.internal.current_window_state.flags.is_maximized {
-                                window.display.window().set_maximized(true);
-                            }
-                        }
-                        window.render_block_and_swap();
-                        window.frame_lock = None;
-                    }
+                if let Some(window) = app.windows.get_mut(&window_id) {
+                    window.render_block_and_swap();
                 }
-
-                *control_flow = ControlFlow::Wait;
-                return;
             },
-            Event::WindowEvent { event: GlutinWindowEvent::Command(c), window_id } => {
-                println!("got command from menu: {}", c);
-
-                let window = match active_windows.get_mut(&window_id) {
-                    Some(s) => s,
-                    None => { return; },
-                };
-
-                // if let Some(update) = window.invoke_menubar_callback(c, CallbackInfo { ... }) {
-                    //
-                // } else if let Some(update) = window.invoke_context_menu_callback(c, CallbackInfo { ... }) {
-                    //
-                // }
+            Event::MainEventsCleared => {
+                run_timers_and_threads(&mut app, &mut new_windows);
             },
-            Event::WindowEvent { event, window_id } => {
+            _ => { },
+        }
 
-                let mut window = match active_windows.get_mut(&window_id) {
-                    Some(s) => s,
-                    None => {return; },
-                };
-                let is_first_frame = window.internal.previous_window_state.is_none();
+        close_windows(&mut app, &mut new_windows);
+        create_windows(&mut app, new_windows, event_loop_target, &proxy);
 
-                // update timer_coarse_frame if necessary
-                if !timers.is_empty() && !threads.is_empty() {
-                    coarsetime::Instant::update();
-                }
+        *control_flow = get_control_flow(&app);
+    })
+}
 
-                // ONLY update the window_state of the window, don't do anything else
-                process_window_event(is_first_frame, &mut window, &event_loop_target, &event);
-
-                let mut need_regenerate_display_list = false;
-                let mut need_refresh_hit_test = false;
-                let mut should_scroll_render = false;
-                let mut should_callback_render = false;
-
-                let mut updated_resources = Vec::new();
-
-                // NOTE: Has to be done every frame, since there is no real
-                // way to detect if the monitor changed
-                if window.internal.may_have_changed_monitor() {
-                    let w = window.display.window();
-                    let primary_monitor = w.primary_monitor();
-                    let current_monitor = w.current_monitor()
-                    .map(|m| {
-                        let mut mon = crate::window::monitor_new(m, false);
-                        if let Some(p) = primary_monitor.as_ref() {
-                            mon.is_primary_monitor = mon.id == crate::window::monitor_handle_get_id(p);
-                        }
-                        mon
-                    })
-                    .unwrap_or_default();
-                    window.internal.current_window_state.monitor = current_monitor;
-                }
+fn create_windows(
+    app: &mut ApplicationData,
+    mut new_windows: Vec<WindowCreateOptions>,
+    event_loop: &GlutinEventLoopWindowTarget<UserEvent>,
+    proxy: &GlutinEventLoopProxy<UserEvent>,
+) {
+    // the create callback of a window can open more windows
+    while !new_windows.is_empty() {
+        for options in core::mem::replace(&mut new_windows, Vec::new()) {
+            create_window(app, options, event_loop, proxy, &mut new_windows);
+        }
+    }
+}
 
-                loop {
-                    let events = Events::new(&window.internal.current_window_state, &window.internal.previous_window_state);
-                    let layout_callback_changed = window.internal.current_window_state.layout_callback_changed(&window.internal.previous_window_state);
-                    let hit_test = {
-                        let ht = crate::wr_translate::fullhittest_new_webrender(
-                            &*window.hit_tester,
-                            window.internal.document_id,
-                            window.internal.current_window_state.focused_node,
-
-                            &window.internal.layout_results,
-                            &window.internal.current_window_state.mouse_state.cursor_position,
-                            window.internal.current_window_state.size.hidpi_factor,
-                        );
-                        window.internal.current_window_state.hovered_nodes = ht.hovered_nodes.clone();
-                        ht
-                    };
-
-                    // previous_window_state = current_window_state, nothing to do
-                    if (events.is_empty() && !is_first_frame) || layout_callback_changed { break; }
-
-                    let nodes_to_check = NodesToCheck::new(&hit_test, &events);
-                    let mut callback_results = fc_cache.apply_closure(|fc_cache| {
-                        window.call_callbacks(
-                            &nodes_to_check,
-                            &events,
-                            &mut image_cache,
-                            fc_cache,
-                            &config.system_callbacks
-                        )
-                    });
-
-
-                    // user callback scroll event
-                    let cur_should_callback_render = callback_results.should_scroll_render;
-                    if cur_should_callback_render {
-                        should_callback_render = true;
-                    }
+fn create_window(
+    app: &mut ApplicationData,
+    options: WindowCreateOptions,
+    event_loop: &GlutinEventLoopWindowTarget<UserEvent>,
+    proxy: &GlutinEventLoopProxy<UserEvent>,
+    new_windows: &mut Vec<WindowCreateOptions>,
+) {
 
-                    // system scroll event
-                    let cur_should_scroll_render = window.internal.current_window_state
-                    .get_scroll_amount().as_ref().map(|se| {
-                        window.internal.scroll_states.should_scroll_render(se, &hit_test)
-                    }).unwrap_or(false);
+    let create_callback = options.create_callback.clone();
+    let hot_reload = options.hot_reload;
 
-                    if cur_should_scroll_render {
-                        should_scroll_render = true;
-                    }
+    let window = Window::new(
+        &mut app.data,
+        options,
+        event_loop,
+        proxy,
+        &app.image_cache,
+        &mut app.fc_cache,
+    );
 
-                    window.internal.current_window_state.mouse_state.reset_scroll_to_zero();
-
-                    if layout_callback_changed {
-                        window.regenerate_styled_dom(&mut data, &image_cache, &mut updated_resources, &mut fc_cache);
-                        need_regenerate_display_list = true;
-                        need_refresh_hit_test = true;
-                        callback_results.update_focused_node = Some(None); // unset the focus
-                    } else {
-                        match callback_results.callbacks_update_screen {
-                            Update::RegenerateStyledDomForCurrentWindow => {
-                                window.regenerate_styled_dom(&mut data, &image_cache, &mut updated_resources, &mut fc_cache);
-                                need_regenerate_display_list = true;
-                                need_refresh_hit_test = true;
-                                callback_results.update_focused_node = Some(None); // unset the focus
-                            },
-                            Update::RegenerateStyledDomForAllWindows => {
-                                /* for window in active_windows { window.regenerate_styled_dom(); } */
-                            },
-                            Update::DoNothing => {
-
-                                let window_size = window.internal.get_layout_size();
-
-                                // re-layouts and re-styles the window.internal.layout_results
-                                let changes = StyleAndLayoutChanges::new(
-                                    &nodes_to_check,
-                                    &mut window.internal.layout_results,
-                                    &image_cache,
-                                    &mut window.internal.renderer_resources,
-                                    window_size,
-                                    &window.internal.document_id,
-                                    callback_results.css_properties_changed.as_ref(),
-                                    callback_results.words_changed.as_ref(),
-                                    &callback_results.update_focused_node,
-                                    azul_layout::do_the_relayout,
-                                );
-
-                                if changes.did_resize_nodes() {
-                                    need_refresh_hit_test = true;
-                                }
-
-                                if changes.need_regenerate_display_list() ||
-                                   (events.contains_resize_event() && window.internal.resized_area_increased())
-                                {
-                                    // this can be false in case that only opacity: / transform: properties changed!
-                                    need_regenerate_display_list = true;
-                                }
-
-                                if changes.need_redraw() {
-                                    should_callback_render = true;
-                                }
-
-                                if let Some(focus_change) = changes.focus_change {
-                                    window.internal.current_window_state.focused_node = focus_change.new;
-                                }
-                            }
-                        }
-                    }
+    let mut window = match window {
+        Ok(o) => o,
+        Err(e) => {
+            #[cfg(feature = "logging")] {
+                error!("Error initializing window: {}", e);
+            }
+            return;
+        }
+    };
 
-                    if !callback_results.windows_created.is_empty() {
-                        windows_created.extend(callback_results.windows_created.drain(..));
-                    }
+    let window_id = window.display.window().id();
 
-                    let callbacks_changed_cursor = callback_results.cursor_changed();
+    // start the CSS animations of the initial DOM
+    let (animations_added, animations_removed) = window.internal.start_css_animations(&FastBTreeSet::new(), &app.config.system_callbacks);
+    start_stop_timers(&mut window, animations_added, animations_removed);
 
-                    if let Some(timer_map) = callback_results.timers {
-                        for (timer_id, timer) in timer_map {
-                            timers.entry(window_id).or_insert_with(|| BTreeMap::new()).insert(timer_id, timer);
-                        }
-                    }
-                    if timers.get(&window_id).map(|w| w.is_empty()) == Some(true) { timers.remove(&window_id); }
+    if hot_reload {
+        let timer = Timer::new(app.data.clone(), hot_reload_timer, app.config.system_callbacks.get_system_time_fn)
+        .with_interval(HOT_RELOAD_INTERVAL.into());
+        window.internal.timers.insert(TimerId::unique(), timer);
+    }
 
-                    if let Some(thread_map) = callback_results.threads {
-                        for (thread_id, thread) in thread_map {
-                            threads.entry(window_id).or_insert_with(|| BTreeMap::new()).insert(thread_id, thread);
-                        }
-                    }
-                    if threads.get(&window_id).map(|w| w.is_empty()) == Some(true) { threads.remove(&window_id); }
-
-                    // see if the callbacks modified the WindowState - if yes, re-determine the events
-                    let current_window_save_state = window.internal.current_window_state.clone();
-                    if !callbacks_changed_cursor {
-                        let ht = crate::wr_translate::fullhittest_new_webrender(
-                           &*window.hit_tester,
-                           window.internal.document_id,
-                           window.internal.current_window_state.focused_node,
-
-                           &window.internal.layout_results,
-                           &window.internal.current_window_state.mouse_state.cursor_position,
-                           window.internal.current_window_state.size.hidpi_factor,
-                        );
-
-
-                        let cht = CursorTypeHitTest::new(&ht, &window.internal.layout_results);
-                        if let Some(m) = callback_results.modified_window_state.as_mut() {
-                            m.mouse_state.mouse_cursor_type = Some(cht.cursor_icon).into();
-                        } else {
-                            let mut new = window.internal.current_window_state.clone();
-                            new.mouse_state.mouse_cursor_type = Some(cht.cursor_icon).into();
-                            callback_results.modified_window_state = Some(new.into());
-                        }
-                    }
+    app.windows.insert(window_id, window);
 
-                    if let Some(callback_new_focus) = callback_results.update_focused_node.as_ref() {
-                        window.internal.current_window_state.focused_node = *callback_new_focus;
-                    }
+    let ApplicationData { data, config, image_cache, fc_cache, windows } = app;
+    let window = match windows.get_mut(&window_id) {
+        Some(s) => s,
+        None => return,
+    };
 
-                    let window_state_changed_in_callbacks = match callback_results.modified_window_state {
-                        Some(modified_window_state) => {
-                            window.synchronize_window_state_with_os(
-                                modified_window_state,
-                                window.internal.current_window_state.monitor.clone()
-                            )
-                        },
-                        None => false,
-                    };
+    let mut result = process_component_events(window, image_cache, fc_cache, config, new_windows);
 
+    if let Some(callback) = create_callback.into_option() {
+        let mut menu_callback = MenuCallback { callback, data: data.clone() };
+        result = result.max_self(invoke_window_callback(&mut menu_callback, window, image_cache, fc_cache, config, new_windows));
+    }
 
-                    window.internal.previous_window_state = Some(current_window_save_state);
+    handle_process_event_result(app, window_id, result, new_windows);
+}
 
-                    if !window_state_changed_in_callbacks {
-                        break;
-                    } else {
-                        continue;
-                    }
-                }
+extern "C" fn hot_reload_timer(
+    _: &mut RefAny,
+    _: &mut RefAny,
+    _: azul_core::callbacks::TimerCallbackInfo,
+) -> azul_core::callbacks::TimerCallbackReturn {
+    azul_core::callbacks::TimerCallbackReturn {
+        should_update: azul_core::callbacks::Update::RegenerateStyledDomForCurrentWindow,
+        should_terminate: azul_core::task::TerminateTimer::Continue,
+    }
+}
 
-                if need_regenerate_display_list {
-                    let mut transaction = WrTransaction::new();
-                    window.rebuild_display_list(&mut transaction, &image_cache, updated_resources);
-                    window.render_async(transaction, need_regenerate_display_list);
-                } else if should_scroll_render || should_callback_render {
-                    let transaction = WrTransaction::new();
-                    window.render_async(transaction, need_regenerate_display_list);
-                }
+// Windows that have `flags.is_about_to_close` set are destroyed, unless
+// their close callback sets `flags.is_about_to_close` back to false
+fn close_windows(app: &mut ApplicationData, new_windows: &mut Vec<WindowCreateOptions>) {
 
-                if need_refresh_hit_test {
-                    // window.force_synchronize_hit_tester_during();
-                }
-            },
-            Event::UserEvent(UserEvent { window_id, composite_needed: _ }) => {
-
-                let window = match active_windows.get_mut(&window_id) {
-                    Some(s) => s,
-                    None => {return; },
-                };
-
-                // transaction has finished, now render
-                window.force_synchronize_hit_tester_during();
-                window.frame_lock = Some(false); // set the window to start rendering
-                window.display.window().request_redraw();
-                *control_flow = ControlFlow::Wait;
-                return;
-            },
-            Event::MainEventsCleared |
-            Event::NewEvents(StartCause::Init) => {
-                for w in active_windows.values() {
-                    w.display.window().request_redraw();
-                }
+    let windows_to_close = app.windows.iter()
+    .filter(|(_, w)| w.internal.current_window_state.flags.is_about_to_close)
+    .map(|(id, _)| *id)
+    .collect::<Vec<_>>();
+
+    for window_id in windows_to_close {
+
+        let ApplicationData { data, config, image_cache, fc_cache, windows } = &mut *app;
+        let window = match windows.get_mut(&window_id) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        let close_callback = window.internal.current_window_state.close_callback.clone();
+        let result = match close_callback.into_option() {
+            Some(callback) => {
+                let mut menu_callback = MenuCallback { callback, data: data.clone() };
+                invoke_window_callback(&mut menu_callback, window, image_cache, fc_cache, config, new_windows)
             },
-            _ => { },
-        }
+            None => ProcessEventResult::DoNothing,
+        };
 
-        // close windows
-        let mut windows_to_remove = Vec::new();
-        for (id, window) in active_windows.iter() {
-            if window.internal.current_window_state.flags.is_about_to_close {
-                windows_to_remove.push(id.clone());
-            }
+        if window.internal.current_window_state.flags.is_about_to_close {
+            app.windows.remove(&window_id);
+        } else {
+            handle_process_event_result(app, window_id, result, new_windows);
         }
+    }
+}
 
-        for window_id in windows_to_remove {
-
-            let mut window_should_close = true;
-
-            {
-                let window = match active_windows.get_mut(&window_id) {
-                    Some(s) => s,
-                    None => continue,
-                };
-                let close_callback = window.internal.current_window_state.close_callback.clone();
-
-                if let Some(close_callback) = close_callback.as_ref() {
-
-                    use azul_core::callbacks::DomNodeId;
-                    use azul_core::callbacks::CallbackInfo;
-                    use azul_core::window::WindowState;
-
-                    let mut window_state: WindowState = window.internal.current_window_state.clone().into();
-                    let mut new_windows = Vec::new();
-                    let mut stop_propagation = false;
-                    let mut focus_target = None; // TODO: useful to implement autofocus
-                    let scroll_states = window.internal.get_current_scroll_states();
-
-                    let mut words_changed = BTreeMap::new();
-                    let mut images_changed = BTreeMap::new();
-                    let mut image_masks_changed = BTreeMap::new();
-                    let mut css_properties_changed = BTreeMap::new();
-                    let mut nodes_scrolled_in_callback = BTreeMap::new();
-
-                    let mut new_timers = BTreeMap::new();
-                    let mut new_threads = BTreeMap::new();
-                    let gl_context_ptr = &window.gl_context_ptr;
-
-                    let layout_result = &mut window.internal.layout_results[DomId::ROOT_ID.inner];
-                    let current_window_state = &window.internal.current_window_state;
-                    let previous_window_state = &window.internal.previous_window_state;
-                    let mut datasets = layout_result.styled_dom.node_data.split_into_callbacks_and_dataset();
-                    let node_hierarchy = &layout_result.styled_dom.node_hierarchy;
-                    let words_cache = &layout_result.words_cache;
-                    let shaped_words_cache = &layout_result.shaped_words_cache;
-                    let positioned_words_cache = &layout_result.positioned_words_cache;
-                    let rects = &layout_result.rects;
-                    let window_handle = &window.window_handle;
-                    let css_property_cache = &layout_result.styled_dom.css_property_cache.ptr;
-                    let styled_nodes = &layout_result.styled_dom.styled_nodes;
-
-                    let callback_info = fc_cache.apply_closure(|fc_cache| {
-                        CallbackInfo::new(
-                            css_property_cache,
-                            styled_nodes,
-                            previous_window_state,
-                            current_window_state,
-                            &mut window_state,
-                            &gl_context_ptr,
-                            &mut image_cache,
-                            fc_cache,
-                            &mut new_timers,
-                            &mut new_threads,
-                            &mut new_windows,
-                            &window_handle,
-                            node_hierarchy,
-                            &config.system_callbacks,
-                            words_cache,
-                            shaped_words_cache,
-                            positioned_words_cache,
-                            rects,
-                            &mut datasets.1,
-                            &mut stop_propagation,
-                            &mut focus_target,
-                            &mut words_changed,
-                            &mut images_changed,
-                            &mut image_masks_changed,
-                            &mut css_properties_changed,
-                            &scroll_states,
-                            &mut nodes_scrolled_in_callback,
-                            DomNodeId::ROOT,
-                            None.into(),
-                            None.into(),
-                        )
-                    });
-
-                    let result = (close_callback.cb)(&mut data, callback_info);
-
-                    for (timer_id, timer) in new_timers {
-                        timers.entry(window_id).or_insert_with(|| BTreeMap::new()).insert(timer_id, timer);
-                    }
-                    if timers.get(&window_id).map(|w| w.is_empty()) == Some(true) { timers.remove(&window_id); }
+// Invokes the create / close callback of a window on the root node
+#[must_use]
+fn invoke_window_callback(
+    menu_callback: &mut MenuCallback,
+    window: &mut Window,
+    image_cache: &mut ImageCache,
+    fc_cache: &mut LazyFcCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+) -> ProcessEventResult {
+
+    window.display.make_current();
+
+    let internal = &mut window.internal;
+    let window_handle = &window.window_handle;
+    let gl_context = &window.gl_context_ptr;
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+        internal.invoke_menu_callback(
+            menu_callback,
+            DomNodeId::ROOT,
+            window_handle,
+            gl_context,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    let nodes_to_check = NodesToCheck::empty(
+        window.internal.current_window_state.mouse_state.mouse_down(),
+        window.internal.current_window_state.focused_node,
+    );
 
-                    for (thread_id, thread) in new_threads {
-                        threads.entry(window_id).or_insert_with(|| BTreeMap::new()).insert(thread_id, thread);
-                    }
-                    if threads.get(&window_id).map(|w| w.is_empty()) == Some(true) { threads.remove(&window_id); }
+    process_callback_results(callback_result, window, &nodes_to_check, image_cache, fc_cache, config, new_windows)
+}
 
-                    if !window_state.flags.is_about_to_close {
-                        window_should_close = false;
-                        window.internal.current_window_state.flags.is_about_to_close = false;
-                    }
-                }
-            }
+// Updates the window state with the OS event, then re-runs the hit-test and the callbacks
+fn process_window_event(
+    app: &mut ApplicationData,
+    window_id: GlutinWindowId,
+    event: &GlutinWindowEvent,
+    event_loop: &GlutinEventLoopWindowTarget<UserEvent>,
+    new_windows: &mut Vec<WindowCreateOptions>,
+) {
 
-            if window_should_close {
-                active_windows.remove(&window_id);
-            }
-        }
+    let ApplicationData { config, image_cache, fc_cache, windows, .. } = &mut *app;
+    let window = match windows.get_mut(&window_id) {
+        Some(s) => s,
+        None => return,
+    };
 
-        // open windows
-        for window_create_options in windows_created.into_iter() {
-
-            let create_callback = window_create_options.create_callback.clone();
-
-            let id = create_window(
-                &mut data,
-                window_create_options,
-                &event_loop_target,
-                &proxy,
-                &mut active_windows,
-                &image_cache,
-                &mut fc_cache,
-                &mut timers,
-                &config,
-            );
-
-            if let Some(init_callback) = create_callback.as_ref() {
-                if let Some(window_id) = id.as_ref() {
-
-                    use azul_core::callbacks::DomNodeId;
-                    use azul_core::callbacks::CallbackInfo;
-                    use azul_core::window::WindowState;
-
-                    let window = match active_windows.get_mut(&window_id) {
-                        Some(s) => s,
-                        None => continue,
-                    };
-
-                    let mut window_state: WindowState = window.internal.current_window_state.clone().into();
-                    let mut new_windows = Vec::new();
-                    let mut stop_propagation = false;
-                    let mut focus_target = None; // TODO: useful to implement autofocus
-                    let scroll_states = window.internal.get_current_scroll_states();
-
-                    let mut words_changed = BTreeMap::new();
-                    let mut images_changed = BTreeMap::new();
-                    let mut image_masks_changed = BTreeMap::new();
-                    let mut css_properties_changed = BTreeMap::new();
-                    let mut nodes_scrolled_in_callback = BTreeMap::new();
-
-                    let mut new_timers = BTreeMap::new();
-                    let mut new_threads = BTreeMap::new();
-
-                    let gl_context_ptr = &window.gl_context_ptr;
-                    let layout_result = &mut window.internal.layout_results[DomId::ROOT_ID.inner];
-                    let node_hierarchy = &layout_result.styled_dom.node_hierarchy;
-                    let current_window_state = &window.internal.current_window_state;
-                    let previous_window_state = &window.internal.previous_window_state;
-                    let mut datasets = layout_result.styled_dom.node_data.split_into_callbacks_and_dataset();
-                    let words_cache = &layout_result.words_cache;
-                    let shaped_words_cache = &layout_result.shaped_words_cache;
-                    let positioned_words_cache = &layout_result.positioned_words_cache;
-                    let rects = &layout_result.rects;
-                    let window_handle = &window.window_handle;
-                    let css_property_cache = &layout_result.styled_dom.css_property_cache.ptr;
-                    let styled_nodes = &layout_result.styled_dom.styled_nodes;
-
-                    let callback_info = fc_cache.apply_closure(|fc_cache| {
-                        CallbackInfo::new(
-                            css_property_cache,
-                            styled_nodes,
-                            previous_window_state,
-                            current_window_state,
-                            &mut window_state,
-                            &gl_context_ptr,
-                            &mut image_cache,
-                            fc_cache,
-                            &mut new_timers,
-                            &mut new_threads,
-                            &mut new_windows,
-                            &window_handle,
-                            node_hierarchy,
-                            &config.system_callbacks,
-                            words_cache,
-                            shaped_words_cache,
-                            positioned_words_cache,
-                            rects,
-                            &mut datasets.1,
-                            &mut stop_propagation,
-                            &mut focus_target,
-                            &mut words_changed,
-                            &mut images_changed,
-                            &mut image_masks_changed,
-                            &mut css_properties_changed,
-                            &scroll_states,
-                            &mut nodes_scrolled_in_callback,
-                            DomNodeId::ROOT,
-                            None.into(),
-                            None.into(),
-                        )
-                    });
-
-                    let _ = (init_callback.cb)(&mut data, callback_info);
-
-                    for (timer_id, timer) in new_timers {
-                        timers.entry(*window_id).or_insert_with(|| BTreeMap::new()).insert(timer_id, timer);
-                    }
-                    if timers.get(&window_id).map(|w| w.is_empty()) == Some(true) { timers.remove(&window_id); }
+    let previous_state = window.internal.current_window_state.clone();
+    window.internal.previous_window_state = Some(previous_state.clone());
 
-                    for (thread_id, thread) in new_threads {
-                        threads.entry(*window_id).or_insert_with(|| BTreeMap::new()).insert(thread_id, thread);
-                    }
-                    if threads.get(&window_id).map(|w| w.is_empty()) == Some(true) { threads.remove(&window_id); }
+    // ONLY update the window_state of the window, don't do anything else
+    update_window_state(window, event_loop, event);
 
-                }
-            }
-        }
+    // closing the window is handled after all events are processed
+    if window.internal.current_window_state.flags.is_about_to_close {
+        return;
+    }
 
-        // end: handle control flow and app shutdown
-        let new_control_flow = if !active_windows.is_empty() {
-
-            use azul_core::task::Duration;
-            use azul_core::task::Instant;
-
-            // If no timers / threads are running, wait until next user event
-            if (threads.is_empty() || threads.values().all(|t| t.is_empty()))
-            && (timers.is_empty() || timers.values().all(|t| t.is_empty())) {
-
-                ControlFlow::Wait
-            } else {
-
-                // determine minimum refresh rate from monitor
-                let minimum_refresh_rate = active_windows.values()
-                .filter_map(|w| {
-                    crate::window::monitor_get_max_supported_framerate(
-                        &w.internal.current_window_state.monitor
-                    )
-                })
-                .min()
-                .map(|d| Duration::System(d.into()));
-
-                if threads.is_empty() {
-
-
-                    // timers running
-                    if timers.values().any(|timer_map| timer_map.values().any(|t| t.interval.as_ref().is_none())) {
-                        ControlFlow::Poll
-                    } else {
-
-                        // calulcate frame_start as a std::time::Instant while
-                        // avoiding calling std::Instant::now()
-                        let frame_start = Instant::System((timer_std_start + translate_duration(
-                            timer_coarse_frame.duration_since(timer_coarse_start)
-                        )).into());
-
-                        // timers are not empty, select the minimum time that the next timer needs to run
-                        // ex. if one timer is set to run every 2 seconds, then we only need
-                        // to poll in 2 seconds, not every 16ms
-                        let min_timer_time = timers
-                        .values()
-                        .filter_map(|t| {
-                            t.values()
-                            .map(|t| {
-                                frame_start
-                                .clone()
-                                .max(t.instant_of_next_run())
-                                .duration_since(&frame_start)
-                            }).min()
-                        }).min();
-
-                        let instant_of_nearest_timer = frame_start.clone()
-                        .add_optional_duration(min_timer_time.as_ref());
-
-                        let instant_of_next_frame_sync = frame_start.clone()
-                        .add_optional_duration(minimum_refresh_rate.as_ref());
-
-                        // in case the callback is handled slower than 16ms, this would panic
-                        coarsetime::Instant::update();
-                        let current_time_instant = Instant::System((timer_std_start + translate_duration(
-                            timer_coarse_frame.duration_since(timer_coarse_start)
-                        )).into());
-
-                        ControlFlow::WaitUntil(
-                            current_time_instant
-                            .max(instant_of_next_frame_sync)
-                            .max(instant_of_nearest_timer)
-                            .into_std_instant()
-                        )
-                    }
-                } else {
-
-                    // in case the callback is handled slower than 16ms, this would panic
-                    let frame_start = Instant::System((timer_std_start + translate_duration(
-                        timer_coarse_frame.duration_since(timer_coarse_start)
-                    )).into());
-
-                    coarsetime::Instant::update();
-                    let current_time_instant = Instant::System((timer_std_start + translate_duration(
-                        timer_coarse_frame.duration_since(timer_coarse_start)
-                    )).into());
-
-                    ControlFlow::WaitUntil(
-                        // if current_time_instant < frame_start + minimum_refresh_rate { WaitUntil(now) }
-                        current_time_instant
-                        .max(frame_start.add_optional_duration(minimum_refresh_rate.as_ref()))
-                        .into_std_instant()
-                    )
-                }
-            }
-        } else {
-            // Application shutdown
-            timers = BTreeMap::new();
-            threads = BTreeMap::new();
-            ControlFlow::Exit
-        };
+    // NOTE: Has to be done on every event, since there is no real
+    // way to detect if the monitor changed
+    if window.internal.may_have_changed_monitor() {
+        window.internal.current_window_state.monitor = get_window_monitor(window);
+    }
 
+    window.display.make_current();
 
-        *control_flow = new_control_flow;
-    })
+    let current_size = &window.internal.current_window_state.size;
+    let size_changed = previous_state.size.dimensions != current_size.dimensions ||
+                       previous_state.size.hidpi_factor != current_size.hidpi_factor;
+
+    if window.internal.media_queries_changed() {
+        // the window crossed a breakpoint of an `@media` rule: restyle the DOM
+        restyle_dom(window, image_cache, fc_cache, config);
+    } else if size_changed {
+        do_resize(window, image_cache, fc_cache);
+    }
+
+    update_hit_test(window);
+
+    let result = process_event(window, image_cache, fc_cache, config, new_windows);
+    let result = result.max_self(process_accelerators(window, image_cache, fc_cache, config, new_windows));
+
+    handle_process_event_result(app, window_id, result, new_windows);
 }
 
 /// Updates the `FullWindowState` with the new event
-fn process_window_event(
-    is_first_frame: bool,
+fn update_window_state(
     window: &mut Window,
     event_loop: &GlutinEventLoopWindowTarget<UserEvent>,
-    event: &GlutinWindowEvent
+    event: &GlutinWindowEvent,
 ) {
 
-    use glutin::event::{KeyboardInput, Touch};
+    use glutin::event::{KeyboardInput, Touch, ElementState};
     use azul_core::window::{CursorPosition, WindowPosition, LogicalPosition};
     use crate::wr_translate::winit_translate::{
         winit_translate_physical_size, winit_translate_physical_position,
+        translate_virtual_keycode, translate_winit_theme,
     };
 
-    let mut current_window_state = &mut window.internal.current_window_state;
+    match event {
+        GlutinWindowEvent::Resized(physical_size) => {
+            window.display.make_current();
+            if let Some(context) = window.display.windowed_context() {
+                context.resize(*physical_size);
+            }
+        },
+        GlutinWindowEvent::ScaleFactorChanged { .. } => {
+            use crate::window::get_hidpi_factor;
+            let (hidpi_factor, _) = get_hidpi_factor(&window.display.window(), event_loop);
+            window.internal.current_window_state.size.hidpi_factor = hidpi_factor;
+        },
+        _ => { },
+    }
+
+    let current_window_state = &mut window.internal.current_window_state;
+    let keyboard_state = &mut current_window_state.keyboard_state;
+
+    // the char event is sliced inbetween a keydown and a keyup event,
+    // so every other event has to clear the character again
+    keyboard_state.current_char = None.into();
 
     match event {
         GlutinWindowEvent::CursorMoved { position, .. } => {
@@ -1187,76 +424,50 @@ fn process_window_event(
             current_window_state.mouse_state.cursor_position = CursorPosition::InWindow(LogicalPosition::new(0.0, 0.0));
         },
         GlutinWindowEvent::ModifiersChanged(modifier_state) => {
-            current_window_state.keyboard_state.shift_down = modifier_state.shift();
-            current_window_state.keyboard_state.ctrl_down = modifier_state.ctrl();
-            current_window_state.keyboard_state.alt_down = modifier_state.alt();
-            current_window_state.keyboard_state.super_down = modifier_state.logo();
+            keyboard_state.shift_down = modifier_state.shift();
+            keyboard_state.ctrl_down = modifier_state.ctrl();
+            keyboard_state.alt_down = modifier_state.alt();
+            keyboard_state.super_down = modifier_state.logo();
         },
         GlutinWindowEvent::Resized(physical_size) => {
-            // window.display.make_current();
-            // window.display.windowed_context().unwrap().resize(*physical_size);
-            if !is_first_frame {
-                current_window_state.flags.is_minimized = false;
-                current_window_state.flags.is_maximized = false;
-            }
-            current_window_state.size.dimensions = winit_translate_physical_size(*physical_size).to_logical(current_window_state.size.system_hidpi_factor as f32);
+            current_window_state.size.dimensions = winit_translate_physical_size(*physical_size).to_logical(current_window_state.size.system_hidpi_factor);
         },
         GlutinWindowEvent::ScaleFactorChanged { scale_factor, new_inner_size } => {
-            use crate::window::get_hidpi_factor;
-            let (hidpi_factor, _) = get_hidpi_factor(&window.display.window(), event_loop);
             current_window_state.size.system_hidpi_factor = *scale_factor as f32;
-            current_window_state.size.hidpi_factor = hidpi_factor;
-            current_window_state.size.dimensions = winit_translate_physical_size(**new_inner_size).to_logical(current_window_state.size.system_hidpi_factor as f32);
+            current_window_state.size.dimensions = winit_translate_physical_size(**new_inner_size).to_logical(current_window_state.size.system_hidpi_factor);
         },
         GlutinWindowEvent::Moved(new_window_position) => {
             current_window_state.position = WindowPosition::Initialized(winit_translate_physical_position(*new_window_position));
         },
         GlutinWindowEvent::KeyboardInput { input: KeyboardInput { state, virtual_keycode, scancode, .. }, .. } => {
-            use crate::wr_translate::winit_translate::translate_virtual_keycode;
-            use glutin::event::ElementState;
             match state {
                 ElementState::Pressed => {
                     if let Some(vk) = virtual_keycode.map(translate_virtual_keycode) {
-                        current_window_state.keyboard_state.pressed_virtual_keycodes.insert_hm_item(vk);
-                        current_window_state.keyboard_state.current_virtual_keycode = Some(vk).into();
+                        keyboard_state.pressed_virtual_keycodes.insert_hm_item(vk);
+                        keyboard_state.current_virtual_keycode = Some(vk).into();
                     }
-                    current_window_state.keyboard_state.pressed_scancodes.insert_hm_item(*scancode);
-                    current_window_state.keyboard_state.current_char = None.into();
+                    keyboard_state.pressed_scancodes.insert_hm_item(*scancode);
                 },
                 ElementState::Released => {
                     if let Some(vk) = virtual_keycode.map(translate_virtual_keycode) {
-                        current_window_state.keyboard_state.pressed_virtual_keycodes.remove_hm_item(&vk);
-                        current_window_state.keyboard_state.current_virtual_keycode = None.into();
+                        keyboard_state.pressed_virtual_keycodes.remove_hm_item(&vk);
+                        keyboard_state.current_virtual_keycode = None.into();
                     }
-                    current_window_state.keyboard_state.pressed_scancodes.remove_hm_item(scancode);
-                    current_window_state.keyboard_state.current_char = None.into();
+                    keyboard_state.pressed_scancodes.remove_hm_item(scancode);
                 }
             }
         },
-        // The char event is sliced inbetween a keydown and a keyup event, so the keyup
-        // has to clear the character again
         GlutinWindowEvent::ReceivedCharacter(c) => {
-            current_window_state.keyboard_state.current_char = Some((*c) as u32).into();
+            keyboard_state.current_char = Some((*c) as u32).into();
         },
         GlutinWindowEvent::MouseInput { state, button, .. } => {
-            use glutin::event::{ElementState::*, MouseButton::*};
-            match state {
-                Pressed => {
-                    match button {
-                        Left => current_window_state.mouse_state.left_down = true,
-                        Right => current_window_state.mouse_state.right_down = true,
-                        Middle => current_window_state.mouse_state.middle_down = true,
-                        _ => current_window_state.mouse_state.left_down = true,
-                    }
-                },
-                Released => {
-                    match button {
-                        Left => current_window_state.mouse_state.left_down = false,
-                        Right => current_window_state.mouse_state.right_down = false,
-                        Middle => current_window_state.mouse_state.middle_down = false,
-                        _ => current_window_state.mouse_state.left_down = false,
-                    }
-                },
+            use glutin::event::MouseButton::*;
+            let is_down = *state == ElementState::Pressed;
+            match button {
+                Left => current_window_state.mouse_state.left_down = is_down,
+                Right => current_window_state.mouse_state.right_down = is_down,
+                Middle => current_window_state.mouse_state.middle_down = is_down,
+                Other(_) => current_window_state.mouse_state.left_down = is_down,
             }
         },
         GlutinWindowEvent::MouseWheel { delta, .. } => {
@@ -1299,92 +510,638 @@ fn process_window_event(
             let world_pos_y = location.y as f32 / current_window_state.size.hidpi_factor * current_window_state.size.system_hidpi_factor;
             current_window_state.mouse_state.cursor_position = CursorPosition::InWindow(LogicalPosition::new(world_pos_x, world_pos_y));
         },
-        GlutinWindowEvent::TouchpadPressure { .. } => {
-            // At the moment, only supported on Apple forcetouch-capable macbooks.
-            // The parameters are: pressure level (value between 0 and 1 representing how hard the touchpad is being pressed) and stage
-            // (integer representing the click level).
-
-            // TODO!
-        },
         GlutinWindowEvent::ThemeChanged(new_theme) => {
-            use crate::wr_translate::winit_translate::translate_winit_theme;
             current_window_state.theme = translate_winit_theme(*new_theme);
         },
-        GlutinWindowEvent::AxisMotion { .. } => {
-            // Motion on some analog axis. May report data redundant to other, more specific events.
-
-            // TODO!
-        },
-        GlutinWindowEvent::Destroyed => { },
-        GlutinWindowEvent::Command(_) => { }, // handled before this function
+        // TODO: TouchpadPressure, AxisMotion
+        _ => { },
     }
 }
 
-fn translate_duration(input: coarsetime::Duration) -> std::time::Duration {
-    std::time::Duration::new(input.as_secs(), input.subsec_nanos())
+fn get_window_monitor(window: &Window) -> Monitor {
+    use crate::window::{monitor_new, monitor_handle_get_id};
+
+    let w = window.display.window();
+    let primary_monitor = w.primary_monitor();
+    w.current_monitor()
+    .map(|m| {
+        let mut mon = monitor_new(m, false);
+        if let Some(p) = primary_monitor.as_ref() {
+            mon.is_primary_monitor = mon.id == monitor_handle_get_id(p);
+        }
+        mon
+    })
+    .unwrap_or_default()
 }
 
-fn create_window(
+fn handle_process_event_result(
+    app: &mut ApplicationData,
+    window_id: GlutinWindowId,
+    mut result: ProcessEventResult,
+    new_windows: &mut Vec<WindowCreateOptions>,
+) {
+
+    if result == ProcessEventResult::ShouldRegenerateDomAllWindows {
+        let window_ids = app.windows.keys().copied().collect::<Vec<_>>();
+        for id in window_ids {
+            handle_process_event_result(app, id, ProcessEventResult::ShouldRegenerateDomCurrentWindow, new_windows);
+        }
+        return;
+    }
+
+    let ApplicationData { data, config, image_cache, fc_cache, windows } = &mut *app;
+    let window = match windows.get_mut(&window_id) {
+        Some(s) => s,
+        None => return,
+    };
+
+    window.display.make_current();
+
+    loop {
+        match result {
+            ProcessEventResult::DoNothing => break,
+            ProcessEventResult::ShouldRegenerateDomCurrentWindow |
+            ProcessEventResult::ShouldRegenerateDomAllWindows => {
+                regenerate_dom(window, data, image_cache, fc_cache, config);
+                break;
+            },
+            ProcessEventResult::ShouldUpdateDisplayListCurrentWindow => {
+                window.rebuild_display_list(image_cache, Vec::new());
+                window.render_async(true);
+                window.force_synchronize_hit_tester();
+                break;
+            },
+            ProcessEventResult::UpdateHitTesterAndProcessAgain => {
+                window.rebuild_display_list(image_cache, Vec::new());
+                window.render_async(true);
+                window.force_synchronize_hit_tester();
+                window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+                update_hit_test(window);
+                result = process_event(window, image_cache, fc_cache, config, new_windows);
+            },
+            ProcessEventResult::ShouldReRenderCurrentWindow => {
+                window.render_async(false);
+                break;
+            },
+        }
+    }
+
+    // dispatch the AfterMount / BeforeUnmount / NodeResized events
+    let result = process_component_events(window, image_cache, fc_cache, config, new_windows);
+    if result != ProcessEventResult::DoNothing {
+        handle_process_event_result(app, window_id, result, new_windows);
+    }
+}
+
+// Calls the layout() callback again, restyles the new DOM and sends the new display list
+fn regenerate_dom(
+    window: &mut Window,
     data: &mut RefAny,
-    window_create_options: WindowCreateOptions,
-    events_loop: &GlutinEventLoopWindowTarget<UserEvent>,
-    proxy: &GlutinEventLoopProxy<UserEvent>,
-    active_windows: &mut BTreeMap<GlutinWindowId, Window>,
-    image_cache: &ImageCache,
+    image_cache: &mut ImageCache,
     fc_cache: &mut LazyFcCache,
-    timers: &mut BTreeMap<GlutinWindowId, BTreeMap<TimerId, Timer>>,
     config: &AppConfig,
-) -> Option<GlutinWindowId> {
+) {
 
-    let should_hot_reload_window = window_create_options.hot_reload;
+    use azul_core::window_state::StyleAndLayoutChanges;
 
-    let window = Window::new(
-         data,
-         window_create_options,
-         events_loop,
-         proxy,
-         image_cache,
-         fc_cache,
+    let previous_animations = window.internal.get_css_animation_timers();
+
+    // unset the focus
+    window.internal.current_window_state.focused_node = None;
+
+    let mut resource_updates = Vec::new();
+    window.regenerate_styled_dom(data, image_cache, &mut resource_updates, fc_cache);
+
+    // start the CSS animations of the new DOM
+    let (animations_added, animations_removed) = window.internal.start_css_animations(&previous_animations, &config.system_callbacks);
+    start_stop_timers(window, animations_added, animations_removed);
+
+    window.rebuild_display_list(image_cache, resource_updates);
+    window.force_synchronize_hit_tester();
+
+    window.internal.previous_window_state = None;
+    update_hit_test(window);
+
+    let nodes_to_check = NodesToCheck::simulated_mouse_move(
+        &window.internal.current_window_state.last_hit_test,
+        window.internal.current_window_state.focused_node,
+        window.internal.current_window_state.mouse_state.mouse_down()
     );
 
-    let window = match window {
-        Ok(o) => o,
-        Err(e) => {
-            #[cfg(feature = "logging")] {
-                error!("Error initializing window: {}", e);
-            }
-            return None;
-        }
+    let style_layout_changes = StyleAndLayoutChanges::new(
+        &nodes_to_check,
+        &mut window.internal.layout_results,
+        &image_cache,
+        &mut window.internal.renderer_resources,
+        window.internal.current_window_state.size.get_layout_size(),
+        &window.internal.document_id,
+        None,
+        None,
+        None,
+        None,
+        &None,
+        azul_layout::do_the_relayout,
+    );
+
+    if let Some(resized) = style_layout_changes.nodes_that_changed_size.as_ref() {
+        window.internal.component_events.insert_resized_nodes(resized);
+    }
+
+    // the hover / active styles of the new DOM changed the display list
+    if style_layout_changes.need_regenerate_display_list() {
+        window.rebuild_display_list(image_cache, Vec::new());
+        window.force_synchronize_hit_tester();
+    }
+
+    window.render_async(true);
+}
+
+/// Restyles the DOM with the `@media` rules that apply to the current window size / theme.
+/// Unlike `regenerate_dom()`, the layout callback is not called and the focus is kept.
+fn restyle_dom(
+    window: &mut Window,
+    image_cache: &mut ImageCache,
+    fc_cache: &mut LazyFcCache,
+    config: &AppConfig,
+) {
+    let previous_animations = window.internal.get_css_animation_timers();
+
+    let mut resource_updates = Vec::new();
+    window.restyle_media_queries(image_cache, &mut resource_updates, fc_cache);
+
+    let (animations_added, animations_removed) = window.internal.start_css_animations(&previous_animations, &config.system_callbacks);
+    start_stop_timers(window, animations_added, animations_removed);
+
+    window.rebuild_display_list(image_cache, resource_updates);
+    window.force_synchronize_hit_tester();
+    update_hit_test(window);
+
+    window.render_async(true);
+}
+
+fn do_resize(window: &mut Window, image_cache: &ImageCache, fc_cache: &mut LazyFcCache) {
+
+    let internal = &mut window.internal;
+    let size = internal.current_window_state.size.clone();
+    let theme = internal.current_window_state.theme;
+
+    fc_cache.apply_closure(|fc_cache| {
+        internal.do_quick_resize(
+            image_cache,
+            &crate::app::CALLBACKS,
+            azul_layout::do_the_relayout,
+            fc_cache,
+            &size,
+            theme,
+        );
+    });
+
+    window.rebuild_display_list(image_cache, Vec::new());
+    window.render_async(true);
+    window.force_synchronize_hit_tester();
+}
+
+// Hit-tests the current cursor position and updates the mouse cursor if necessary
+fn update_hit_test(window: &mut Window) {
+
+    use azul_core::window::{CursorTypeHitTest, OptionMouseCursorType};
+    use crate::wr_translate::winit_translate::translate_cursor_icon;
+
+    let hit_test = crate::wr_translate::fullhittest_new_webrender(
+        &*window.hit_tester.resolve(),
+        window.internal.document_id,
+        window.internal.current_window_state.focused_node,
+        &window.internal.layout_results,
+        &window.internal.current_window_state.mouse_state.cursor_position,
+        window.internal.current_window_state.size.hidpi_factor,
+    );
+
+    let cht = CursorTypeHitTest::new(&hit_test, &window.internal.layout_results);
+    window.internal.current_window_state.last_hit_test = hit_test;
+
+    let mouse_state = &mut window.internal.current_window_state.mouse_state;
+    if mouse_state.mouse_cursor_type != OptionMouseCursorType::Some(cht.cursor_icon) {
+        mouse_state.mouse_cursor_type = OptionMouseCursorType::Some(cht.cursor_icon);
+        window.display.window().set_cursor_icon(translate_cursor_icon(cht.cursor_icon));
+    }
+}
+
+// Assuming that current_window_state and the previous_window_state of the window
+// are set correctly and the hit-test has been performed, will call the callbacks
+// and return what the application should do next
+#[must_use]
+fn process_event(
+    window: &mut Window,
+    image_cache: &mut ImageCache,
+    fc_cache: &mut LazyFcCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+) -> ProcessEventResult {
+
+    use azul_core::window_state::Events;
+
+    let events = Events::new(
+        &window.internal.current_window_state,
+        &window.internal.previous_window_state,
+    );
+
+    let mut nodes_to_check = NodesToCheck::new(
+        &window.internal.current_window_state.last_hit_test,
+        &events
+    );
+
+    // Tab / Shift + Tab: move the focus to the next / previous focusable node
+    if config.enable_tab_navigation {
+        nodes_to_check.apply_tab_navigation(
+            &window.internal.current_window_state,
+            &window.internal.previous_window_state,
+            &window.internal.layout_results,
+        );
+    }
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+        window.call_callbacks(
+            &nodes_to_check,
+            &events,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    process_callback_results(callback_result, window, &nodes_to_check, image_cache, fc_cache, config, new_windows)
+}
+
+// Invokes the callback of the menu accelerator or window
+// shortcut that matches the keys that were just pressed
+#[must_use]
+fn process_accelerators(
+    window: &mut Window,
+    image_cache: &mut ImageCache,
+    fc_cache: &mut LazyFcCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+) -> ProcessEventResult {
+
+    let (hit_dom_node, mut menu_callback) = match window.internal.get_pressed_accelerator() {
+        Some(s) => s,
+        None => return ProcessEventResult::DoNothing,
     };
 
-    let glutin_window_id = window.display.window().id();
-    active_windows.insert(glutin_window_id, window);
+    let internal = &mut window.internal;
+    let window_handle = &window.window_handle;
+    let gl_context = &window.gl_context_ptr;
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+        internal.invoke_menu_callback(
+            &mut menu_callback,
+            hit_dom_node,
+            window_handle,
+            gl_context,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    let nodes_to_check = NodesToCheck::empty(
+        window.internal.current_window_state.mouse_state.mouse_down(),
+        window.internal.current_window_state.focused_node,
+    );
 
-    // push hot reload timer that triggers a UI restyle every 200ms
-    if should_hot_reload_window {
+    process_callback_results(callback_result, window, &nodes_to_check, image_cache, fc_cache, config, new_windows)
+}
 
-        use azul_core::task::{Timer, TerminateTimer};
-        use azul_core::callbacks::{
-            TimerCallbackInfo,
-            TimerCallbackReturn,
+// Calls the AfterMount / BeforeUnmount / NodeResized callbacks
+// that were queued by the last DOM regeneration or relayout
+#[must_use]
+fn process_component_events(
+    window: &mut Window,
+    image_cache: &mut ImageCache,
+    fc_cache: &mut LazyFcCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+) -> ProcessEventResult {
+
+    if window.internal.component_events.is_empty() &&
+       window.internal.unmounted_layout_results.is_empty() {
+        return ProcessEventResult::DoNothing;
+    }
+
+    let internal = &mut window.internal;
+    let window_handle = &window.window_handle;
+    let gl_context = &window.gl_context_ptr;
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+        internal.run_component_callbacks(
+            window_handle,
+            gl_context,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    let nodes_to_check = NodesToCheck::empty(
+        window.internal.current_window_state.mouse_state.mouse_down(),
+        window.internal.current_window_state.focused_node,
+    );
+
+    process_callback_results(callback_result, window, &nodes_to_check, image_cache, fc_cache, config, new_windows)
+}
+
+// Runs the timers that are due and polls the running threads of all windows
+fn run_timers_and_threads(app: &mut ApplicationData, new_windows: &mut Vec<WindowCreateOptions>) {
+
+    let window_ids = app.windows.keys().copied().collect::<Vec<_>>();
+
+    for window_id in window_ids {
+
+        let ApplicationData { data, config, image_cache, fc_cache, windows } = &mut *app;
+        let window = match windows.get_mut(&window_id) {
+            Some(s) => s,
+            None => continue,
         };
-        use std::time::Duration as StdDuration;
 
-        extern "C" fn hot_reload_timer(_: &mut RefAny, _: &mut RefAny, _: TimerCallbackInfo) -> TimerCallbackReturn {
-            TimerCallbackReturn {
-                should_update: Update::RegenerateStyledDomForCurrentWindow,
-                should_terminate: TerminateTimer::Continue,
-            }
+        let frame_start = (config.system_callbacks.get_system_time_fn.cb)();
+
+        let timers_to_run = window.internal.timers.iter()
+        .filter(|(_, timer)| timer.instant_of_next_run() <= frame_start)
+        .map(|(id, _)| *id)
+        .collect::<Vec<_>>();
+
+        if timers_to_run.is_empty() && window.internal.threads.is_empty() {
+            continue;
+        }
+
+        window.display.make_current();
+
+        let mut result = ProcessEventResult::DoNothing;
+
+        for timer_id in timers_to_run {
+            let timer_result = process_timer(timer_id, frame_start.clone(), data, window, image_cache, fc_cache, config, new_windows);
+            result = result.max_self(timer_result);
+        }
+
+        if !window.internal.threads.is_empty() {
+            let thread_result = process_threads(data, window, image_cache, fc_cache, config, new_windows);
+            result = result.max_self(thread_result);
+        }
+
+        handle_process_event_result(app, window_id, result, new_windows);
+    }
+}
+
+#[must_use]
+fn process_timer(
+    timer_id: TimerId,
+    frame_start: Instant,
+    data: &mut RefAny,
+    window: &mut Window,
+    image_cache: &mut ImageCache,
+    fc_cache: &mut LazyFcCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+) -> ProcessEventResult {
+
+    let internal = &mut window.internal;
+    let window_handle = &window.window_handle;
+    let gl_context = &window.gl_context_ptr;
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+        internal.run_single_timer(
+            timer_id.id,
+            frame_start,
+            data,
+            window_handle,
+            gl_context,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    let nodes_to_check = NodesToCheck::empty(
+        window.internal.current_window_state.mouse_state.mouse_down(),
+        window.internal.current_window_state.focused_node,
+    );
+
+    process_callback_results(callback_result, window, &nodes_to_check, image_cache, fc_cache, config, new_windows)
+}
+
+#[must_use]
+fn process_threads(
+    data: &mut RefAny,
+    window: &mut Window,
+    image_cache: &mut ImageCache,
+    fc_cache: &mut LazyFcCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+) -> ProcessEventResult {
+
+    let internal = &mut window.internal;
+    let window_handle = &window.window_handle;
+    let gl_context = &window.gl_context_ptr;
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+        internal.run_all_threads(
+            data,
+            window_handle,
+            gl_context,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    let nodes_to_check = NodesToCheck::empty(
+        window.internal.current_window_state.mouse_state.mouse_down(),
+        window.internal.current_window_state.focused_node,
+    );
+
+    process_callback_results(callback_result, window, &nodes_to_check, image_cache, fc_cache, config, new_windows)
+}
+
+#[must_use]
+fn process_callback_results(
+    callback_results: CallCallbacksResult,
+    window: &mut Window,
+    nodes_to_check: &NodesToCheck,
+    image_cache: &mut ImageCache,
+    fc_cache: &mut LazyFcCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+) -> ProcessEventResult {
+
+    use azul_core::callbacks::Update;
+    use azul_core::window_state::StyleAndLayoutChanges;
+    use crate::wr_translate::update_image_resources;
+
+    if callback_results.images_changed.is_some() ||
+       callback_results.image_masks_changed.is_some() {
+        update_image_resources(
+            &mut window.render_api,
+            &window.internal.layout_results,
+            callback_results.images_changed.clone().unwrap_or_default(),
+            callback_results.image_masks_changed.clone().unwrap_or_default(),
+            &crate::app::CALLBACKS,
+            &*image_cache,
+            &mut window.internal.gl_texture_cache,
+            &mut window.internal.renderer_resources,
+            window.internal.document_id,
+            window.internal.epoch,
+        );
+    }
+
+    start_stop_timers(
+        window,
+        callback_results.timers.clone().unwrap_or_default(),
+        callback_results.timers_removed.clone().unwrap_or_default()
+    );
+    start_stop_threads(
+        window,
+        callback_results.threads.unwrap_or_default(),
+        callback_results.threads_removed.unwrap_or_default()
+    );
+
+    new_windows.extend(callback_results.windows_created.into_iter());
+
+    let mut result = ProcessEventResult::DoNothing;
+
+    // system scroll event: scroll the hovered nodes
+    let mut need_scroll_render = match window.internal.current_window_state.get_scroll_amount() {
+        Some(scroll_amount) => window.internal.scroll_states.should_scroll_render(
+            &scroll_amount,
+            &window.internal.current_window_state.last_hit_test,
+        ),
+        None => false,
+    };
+    window.internal.current_window_state.mouse_state.reset_scroll_to_zero();
+
+    if let Some(modified) = callback_results.modified_window_state {
+        let size_changed = modified.size.get_layout_size() != window.internal.current_window_state.size.get_layout_size();
+        let monitor = window.internal.current_window_state.monitor.clone();
+        window.synchronize_window_state_with_os(modified, monitor);
+        if window.internal.media_queries_changed() {
+            // callbacks changed the window size / theme across a breakpoint of an `@media` rule
+            restyle_dom(window, image_cache, fc_cache, config);
+            result = ProcessEventResult::UpdateHitTesterAndProcessAgain;
+        } else if size_changed {
+            do_resize(window, image_cache, fc_cache);
+            result = ProcessEventResult::UpdateHitTesterAndProcessAgain;
+        } else if !need_scroll_render {
+            result = ProcessEventResult::ShouldReRenderCurrentWindow;
+        }
+    }
+
+    let layout_callback_changed = window.internal.current_window_state.layout_callback_changed(
+        &window.internal.previous_window_state
+    );
+
+    if layout_callback_changed {
+        return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+    } else {
+        match callback_results.callbacks_update_screen {
+            Update::RegenerateStyledDomForCurrentWindow => {
+                return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+            },
+            Update::RegenerateStyledDomForAllWindows => {
+                return ProcessEventResult::ShouldRegenerateDomAllWindows;
+            },
+            Update::DoNothing => { },
+        }
+    }
+
+    // Re-layout and re-style the window.internal.layout_results
+    let css_transitions_changed = callback_results.css_transitions_changed.clone().unwrap_or_default();
+    let style_layout_changes = StyleAndLayoutChanges::new(
+        nodes_to_check,
+        &mut window.internal.layout_results,
+        &image_cache,
+        &mut window.internal.renderer_resources,
+        window.internal.current_window_state.size.get_layout_size(),
+        &window.internal.document_id,
+        callback_results.css_properties_changed.as_ref(),
+        callback_results.css_variables_changed.as_ref(),
+        Some(&css_transitions_changed),
+        callback_results.words_changed.as_ref(),
+        &callback_results.update_focused_node,
+        azul_layout::do_the_relayout,
+    );
+
+    // start the timers of the CSS transitions
+    let (transitions_added, transitions_removed) = style_layout_changes.get_transition_timers(&config.system_callbacks);
+    start_stop_timers(window, transitions_added, transitions_removed);
+
+    if let Some(focus_change) = style_layout_changes.focus_change.clone() {
+        window.internal.current_window_state.focused_node = focus_change.new;
+        if let Some(new_focus) = focus_change.new {
+            need_scroll_render |= window.internal.scroll_node_into_view(new_focus);
         }
+    }
+
+    // NodeResized callbacks are called in handle_process_event_result
+    if let Some(resized) = style_layout_changes.nodes_that_changed_size.as_ref() {
+        window.internal.component_events.insert_resized_nodes(resized);
+    }
+
+    // IFrames that were scrolled out of their rendered DOM have to be re-invoked
+    let iframes_changed = if need_scroll_render || callback_results.should_scroll_render {
+        let mut resource_updates = Vec::new();
+        let iframes_changed = window.scroll_iframes(image_cache, &mut resource_updates, fc_cache);
+        if iframes_changed {
+            window.rebuild_display_list(image_cache, resource_updates);
+        }
+        iframes_changed
+    } else {
+        false
+    };
+
+    if style_layout_changes.did_resize_nodes() || iframes_changed {
+        ProcessEventResult::UpdateHitTesterAndProcessAgain
+    } else if style_layout_changes.need_regenerate_display_list() {
+        ProcessEventResult::ShouldUpdateDisplayListCurrentWindow
+    } else if need_scroll_render || callback_results.should_scroll_render || style_layout_changes.need_redraw() {
+        ProcessEventResult::ShouldReRenderCurrentWindow
+    } else {
+        result
+    }
+}
 
-        let timer = Timer::new(data.clone(), hot_reload_timer, config.system_callbacks.get_system_time_fn)
-        .with_interval(StdDuration::from_millis(800).into());
+fn start_stop_timers(window: &mut Window, added: FastHashMap<TimerId, Timer>, removed: FastBTreeSet<TimerId>) {
+    for (id, timer) in added {
+        window.internal.timers.insert(id, timer);
+    }
+    for id in removed {
+        window.internal.timers.remove(&id);
+    }
+}
+
+fn start_stop_threads(window: &mut Window, mut added: FastHashMap<ThreadId, Thread>, removed: FastBTreeSet<ThreadId>) {
+    window.internal.threads.append(&mut added);
+    window.internal.threads.retain(|r, _| !removed.contains(r));
+}
+
+// Sleeps until the next timer is due, polls if threads are running
+// and exits the application once all windows are closed
+fn get_control_flow(app: &ApplicationData) -> ControlFlow {
 
-        timers
-        .entry(glutin_window_id)
-        .or_insert_with(|| BTreeMap::default())
-        .insert(TimerId::unique(), timer);
+    if app.windows.is_empty() {
+        return ControlFlow::Exit;
     }
 
-    Some(glutin_window_id)
-}
\ No newline at end of file
+    if app.windows.values().any(|w| !w.internal.threads.is_empty()) {
+        return ControlFlow::WaitUntil(StdInstant::now() + THREAD_POLL_INTERVAL);
+    }
+
+    let next_timer = app.windows.values()
+    .flat_map(|w| w.internal.timers.values())
+    .map(|t| t.instant_of_next_run())
+    .min();
+
+    match next_timer {
+        Some(Instant::System(s)) => ControlFlow::WaitUntil(s.into()),
+        Some(Instant::Tick(_)) => ControlFlow::Poll,
+        None => ControlFlow::Wait,
+    }
+}
@@ -9,6 +9,7 @@ use crate::{
     wr_translate::{
         rebuild_display_list,
         generate_frame,
+        update_image_resources,
         synchronize_gpu_values,
         scroll_all_nodes,
     }
@@ -23,7 +24,7 @@ use azul_core::{
     },
     callbacks::{RefAny, UpdateImageType, DocumentId},
     gl::OptionGlContextPtr,
-    task::{Thread, ThreadId, Timer, TimerId},
+    task::{Thread, ThreadId, Timer, TimerId, ExternalSystemCallbacks},
     ui_solver::LayoutResult,
     styled_dom::DomId,
     dom::NodeId,
@@ -31,8 +32,9 @@ use azul_core::{
     window::{
         LogicalSize, Menu, MenuCallback, MenuItem,
         MonitorVec, WindowCreateOptions, WindowInternal,
-        WindowState, FullWindowState, ScrollResult,
-        MouseCursorType, CallCallbacksResult
+        WindowState, FullWindowState,
+        MouseCursorType, CallCallbacksResult,
+        KeyboardState, VirtualKeyCode,
     },
     window_state::NodesToCheck,
 };
@@ -2006,7 +2008,7 @@ impl Window {
         use winapi::um::winuser::{SetTimer, KillTimer};
 
         for (id, timer) in added {
-            let res = unsafe { SetTimer(self.hwnd, id.id, timer.tick_millis().max(u32::MAX as u64) as u32, None) };
+            let res = unsafe { SetTimer(self.hwnd, id.id, timer.tick_millis().min(u32::MAX as u64) as u32, None) };
             self.internal.timers.insert(id, timer);
             self.timers.insert(id, res);
         }
@@ -2042,18 +2044,75 @@ impl Window {
         }
     }
 
-    // ScrollResult contains information about what nodes need to be scrolled,
-    // whether they were scrolled by the system or by the user and how far they
-    // need to be scrolled
-    fn do_system_scroll(&mut self, scroll: ScrollResult) {
-        println!("scroll: {:#?}", scroll); // TODO
-        // for scrolled_node in scroll {
-        //      self.render_api.scroll_node_with_id();
-        //      let scrolled_rect = LogicalRect { origin: scroll_offset, size: visible.size };
-        //      if !scrolled_node.scroll_bounds.contains(&scroll_rect) {
-        //
-        //      }
-        // }
+    /// Re-invokes the IFrames that were scrolled out of their rendered DOM and
+    /// rebuilds the display list if necessary, returns whether any IFrame DOM changed
+    fn scroll_iframes(&mut self, image_cache: &ImageCache, fc_cache: &mut LazyFcCache) -> bool {
+
+        let internal = &mut self.internal;
+        let mut resource_updates = Vec::new();
+        let iframes_changed = fc_cache.apply_closure(|fc_cache| {
+            internal.scroll_iframes(image_cache, &crate::app::CALLBACKS, fc_cache, &mut resource_updates)
+        });
+
+        if iframes_changed.is_empty() {
+            return false;
+        }
+
+        rebuild_display_list(
+            &mut self.internal,
+            &mut self.render_api,
+            image_cache,
+            resource_updates,
+        );
+
+        true
+    }
+
+    /// Re-applies the `@media` rules of the current DOM after the window crossed
+    /// a breakpoint, relayouts the DOM and rebuilds the display list
+    fn restyle_media_queries(
+        &mut self,
+        image_cache: &ImageCache,
+        fc_cache: &mut LazyFcCache,
+        system_callbacks: &ExternalSystemCallbacks,
+    ) {
+        let previous_animations = self.internal.get_css_animation_timers();
+        let document_id = self.internal.document_id;
+        let internal = &mut self.internal;
+        let hit_tester = &mut self.hit_tester;
+        let gl_context = &self.gl_context_ptr;
+        let mut resource_updates = Vec::new();
+
+        fc_cache.apply_closure(|fc_cache| {
+            internal.restyle_media_queries(
+                image_cache,
+                gl_context,
+                &mut resource_updates,
+                &crate::app::CALLBACKS,
+                fc_cache,
+                azul_layout::do_the_relayout,
+                |window_state, _scroll_states, layout_results| {
+                    crate::wr_translate::fullhittest_new_webrender(
+                         &*hit_tester.resolve(),
+                         document_id,
+                         window_state.focused_node,
+                         layout_results,
+                         &window_state.mouse_state.cursor_position,
+                         window_state.size.hidpi_factor,
+                    )
+                }
+            );
+        });
+
+        let (animations_added, animations_removed) = self.internal.start_css_animations(&previous_animations, system_callbacks);
+        self.start_stop_timers(animations_added, animations_removed);
+
+        rebuild_display_list(
+            &mut self.internal,
+            &mut self.render_api,
+            image_cache,
+            resource_updates,
+        );
     }
 }
 
@@ -2457,9 +2516,12 @@ unsafe extern "system" fn WindowProc(
                 let fc_cache = &mut ab.fc_cache;
                 let data = &mut ab.data;
                 let image_cache = &mut ab.image_cache;
+                let config = &ab.config;
 
                 if let Some(current_window) = windows.get_mut(&hwnd_key) {
 
+                    let previous_animations = current_window.internal.get_css_animation_timers();
+
                     let document_id = current_window.internal.document_id;
                     let mut hit_tester = &mut current_window.hit_tester;
                     let internal = &mut current_window.internal;
@@ -2491,6 +2553,10 @@ unsafe extern "system" fn WindowProc(
                         );
                     });
 
+                    // start the CSS animations of the new DOM
+                    let (animations_added, animations_removed) = current_window.internal.start_css_animations(&previous_animations, &config.system_callbacks);
+                    current_window.start_stop_timers(animations_added, animations_removed);
+
                     // rebuild the display list and send it
                     rebuild_display_list(
                         &mut current_window.internal,
@@ -2533,11 +2599,23 @@ unsafe extern "system" fn WindowProc(
                         &current_window.internal.document_id,
                         None,
                         None,
+                        None,
+                        None,
                         &None,
                         azul_layout::do_the_relayout,
                     );
 
+                    if let Some(resized) = style_layout_changes.nodes_that_changed_size.as_ref() {
+                        current_window.internal.component_events.insert_resized_nodes(resized);
+                    }
+
                     PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
+
+                    // dispatch the AfterMount / BeforeUnmount events
+                    if !current_window.internal.component_events.is_empty() ||
+                       !current_window.internal.unmounted_layout_results.is_empty() {
+                        PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    }
                 }
 
                 mem::drop(app_borrow);
@@ -2594,6 +2672,30 @@ unsafe extern "system" fn WindowProc(
                             &mut destroyed_windows,
                         );
 
+                        let accelerator_ret = process_accelerators(
+                            hinstance,
+                            current_window,
+                            fc_cache,
+                            image_cache,
+                            config,
+                            &mut new_windows,
+                            &mut destroyed_windows,
+                        );
+
+                        ret = ret.max_self(accelerator_ret);
+
+                        let component_ret = process_component_events(
+                            hinstance,
+                            current_window,
+                            fc_cache,
+                            image_cache,
+                            config,
+                            &mut new_windows,
+                            &mut destroyed_windows,
+                        );
+
+                        ret = ret.max_self(component_ret);
+
                         let mut gl = &mut current_window.gl_functions.functions;
                         gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
                         gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
@@ -2787,23 +2889,37 @@ unsafe extern "system" fn WindowProc(
             },
             WM_KEYDOWN => {
                 println!("WM_KEYDOWN: {:0x}", wparam);
-                /*
-                    if let Some(vk) = virtual_keycode.map(translate_virtual_keycode) {
-                        current_window_state.keyboard_state.pressed_virtual_keycodes.insert_hm_item(vk);
-                        current_window_state.keyboard_state.current_virtual_keycode = Some(vk).into();
-                    }
-                    current_window_state.keyboard_state.pressed_scancodes.insert_hm_item(*scancode);
-                */
                 if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
                     current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
-                    current_window.internal.current_window_state.keyboard_state.current_char = None.into();
+                    let keyboard_state = &mut current_window.internal.current_window_state.keyboard_state;
+                    keyboard_state.current_char = None.into();
+                    if let Some(vk) = win32_translate_vkey(wparam as i32) {
+                        keyboard_state.pressed_virtual_keycodes.insert_hm_item(vk);
+                        keyboard_state.current_virtual_keycode = Some(vk).into();
+                    }
+                    keyboard_state.pressed_scancodes.insert_hm_item(((lparam >> 16) & 0xff) as u32);
+                    win32_update_modifier_keys(keyboard_state);
                     PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
                 }
                 mem::drop(app_borrow);
                 return 0;
             },
             WM_SYSKEYDOWN => {
+                // Alt + key or F10: update the keyboard state for accelerators,
+                // but let the OS handle system shortcuts such as Alt + F4
                 println!("WM_SYSKEYDOWN: {:0x}", wparam);
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                    let keyboard_state = &mut current_window.internal.current_window_state.keyboard_state;
+                    keyboard_state.current_char = None.into();
+                    if let Some(vk) = win32_translate_vkey(wparam as i32) {
+                        keyboard_state.pressed_virtual_keycodes.insert_hm_item(vk);
+                        keyboard_state.current_virtual_keycode = Some(vk).into();
+                    }
+                    keyboard_state.pressed_scancodes.insert_hm_item(((lparam >> 16) & 0xff) as u32);
+                    win32_update_modifier_keys(keyboard_state);
+                    PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                }
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
@@ -2833,23 +2949,37 @@ unsafe extern "system" fn WindowProc(
             },
             WM_KEYUP => {
                 println!("WM_KEYUP: {:0x}", wparam);
-                /*
-                    if let Some(vk) = virtual_keycode.map(translate_virtual_keycode) {
-                        current_window_state.keyboard_state.pressed_virtual_keycodes.remove_hm_item(&vk);
-                        current_window_state.keyboard_state.current_virtual_keycode = None.into();
-                    }
-                    current_window_state.keyboard_state.pressed_scancodes.remove_hm_item(scancode);
-                */
                 if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
                     current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
-                    current_window.internal.current_window_state.keyboard_state.current_char = None.into();
+                    let keyboard_state = &mut current_window.internal.current_window_state.keyboard_state;
+                    keyboard_state.current_char = None.into();
+                    if let Some(vk) = win32_translate_vkey(wparam as i32) {
+                        keyboard_state.pressed_virtual_keycodes.remove_hm_item(&vk);
+                        keyboard_state.current_virtual_keycode = None.into();
+                    }
+                    keyboard_state.pressed_scancodes.remove_hm_item(&(((lparam >> 16) & 0xff) as u32));
+                    win32_update_modifier_keys(keyboard_state);
                     PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
                 }
                 mem::drop(app_borrow);
                 return 0;
             },
             WM_SYSKEYUP => {
+                // Alt + key or F10: update the keyboard state for accelerators,
+                // but let the OS handle system shortcuts such as Alt + F4
                 println!("WM_SYSKEYUP: {:0x}", wparam);
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                    let keyboard_state = &mut current_window.internal.current_window_state.keyboard_state;
+                    keyboard_state.current_char = None.into();
+                    if let Some(vk) = win32_translate_vkey(wparam as i32) {
+                        keyboard_state.pressed_virtual_keycodes.remove_hm_item(&vk);
+                        keyboard_state.current_virtual_keycode = None.into();
+                    }
+                    keyboard_state.pressed_scancodes.remove_hm_item(&(((lparam >> 16) & 0xff) as u32));
+                    win32_update_modifier_keys(keyboard_state);
+                    PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                }
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
@@ -2984,6 +3114,7 @@ unsafe extern "system" fn WindowProc(
                 let fc_cache = &mut ab.fc_cache;
                 let windows = &mut ab.windows;
                 let image_cache = &ab.image_cache;
+                let config = &ab.config;
 
                 if let Some(current_window) = windows.get_mut(&hwnd_key) {
                     fc_cache.apply_closure(|fc_cache| {
@@ -3003,18 +3134,50 @@ unsafe extern "system" fn WindowProc(
                             _ => { }
                         }
 
-                        current_window.internal.do_quick_resize(
-                            &image_cache,
-                            &crate::app::CALLBACKS,
-                            azul_layout::do_the_relayout,
-                            fc_cache,
-                            &new_window_state.size,
-                            new_window_state.theme,
-                        );
-
                         current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
                         current_window.internal.current_window_state = new_window_state;
 
+                        let mut resource_updates = Vec::new();
+
+                        if current_window.internal.media_queries_changed() {
+                            // the window crossed a breakpoint of an `@media` rule: restyle the DOM
+                            let previous_animations = current_window.internal.get_css_animation_timers();
+                            let document_id = current_window.internal.document_id;
+                            let hit_tester = &mut current_window.hit_tester;
+                            let gl_context = &current_window.gl_context_ptr;
+                            current_window.internal.restyle_media_queries(
+                                image_cache,
+                                gl_context,
+                                &mut resource_updates,
+                                &crate::app::CALLBACKS,
+                                fc_cache,
+                                azul_layout::do_the_relayout,
+                                |window_state, scroll_states, layout_results| {
+                                    crate::wr_translate::fullhittest_new_webrender(
+                                         &*hit_tester.resolve(),
+                                         document_id,
+                                         window_state.focused_node,
+                                         layout_results,
+                                         &window_state.mouse_state.cursor_position,
+                                         window_state.size.hidpi_factor,
+                                    )
+                                }
+                            );
+                            let (animations_added, animations_removed) = current_window.internal.start_css_animations(&previous_animations, &config.system_callbacks);
+                            current_window.start_stop_timers(animations_added, animations_removed);
+                        } else {
+                            let new_window_size = current_window.internal.current_window_state.size.clone();
+                            let new_window_theme = current_window.internal.current_window_state.theme;
+                            current_window.internal.do_quick_resize(
+                                &image_cache,
+                                &crate::app::CALLBACKS,
+                                azul_layout::do_the_relayout,
+                                fc_cache,
+                                &new_window_size,
+                                new_window_theme,
+                            );
+                        }
+
                         let mut txn = WrTransaction::new();
                         txn.set_document_view(
                             WrDeviceIntRect::from_size(
@@ -3027,7 +3190,7 @@ unsafe extern "system" fn WindowProc(
                             &mut current_window.internal,
                             &mut current_window.render_api,
                             image_cache,
-                            Vec::new(),
+                            resource_updates,
                         );
 
                         let wr_document_id = wr_translate_document_id(current_window.internal.document_id);
@@ -3358,6 +3521,25 @@ enum ProcessEventResult {
     ShouldReRenderCurrentWindow,
 }
 
+impl ProcessEventResult {
+    fn order(&self) -> usize {
+        use self::ProcessEventResult::*;
+        match self {
+            DoNothing => 0,
+            ShouldReRenderCurrentWindow => 1,
+            ShouldUpdateDisplayListCurrentWindow => 2,
+            UpdateHitTesterAndProcessAgain => 3,
+            ShouldRegenerateDomCurrentWindow => 4,
+            ShouldRegenerateDomAllWindows => 5,
+        }
+    }
+
+    // Returns the result that requires more work from the window
+    fn max_self(self, other: Self) -> Self {
+        if other.order() > self.order() { other } else { self }
+    }
+}
+
 // Assuming that current_window_state and the previous_window_state of the window
 // are set correctly and the hit-test has been performed, will call the callbacks
 // and return what the application should do next
@@ -3390,11 +3572,20 @@ fn process_event(
     );
 
     // Get nodes for events
-    let nodes_to_check = NodesToCheck::new(
+    let mut nodes_to_check = NodesToCheck::new(
         &window.internal.current_window_state.last_hit_test,
         &events
     );
 
+    // Tab / Shift + Tab: move the focus to the next / previous focusable node
+    if config.enable_tab_navigation {
+        nodes_to_check.apply_tab_navigation(
+            &window.internal.current_window_state,
+            &window.internal.previous_window_state,
+            &window.internal.layout_results,
+        );
+    }
+
     // Invoke callbacks on nodes
     let callback_result = fc_cache.apply_closure(|fc_cache| {
 
@@ -3430,6 +3621,112 @@ fn process_event(
         window,
         &nodes_to_check,
         image_cache,
+        fc_cache,
+        &config.system_callbacks,
+        new_windows,
+        destroyed_windows
+    );
+}
+
+// Invokes the callback of the menu accelerator or window
+// shortcut that matches the keys that were just pressed
+#[must_use]
+fn process_accelerators(
+    hinstance: HINSTANCE,
+    window: &mut Window,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>,
+) -> ProcessEventResult {
+
+    use azul_core::window::{RawWindowHandle, WindowsHandle};
+
+    let (hit_dom_node, mut menu_callback) = match window.internal.get_pressed_accelerator() {
+        Some(s) => s,
+        None => return ProcessEventResult::DoNothing,
+    };
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+
+        let window_handle = RawWindowHandle::Windows(WindowsHandle {
+            hwnd: window.hwnd as *mut _,
+            hinstance: hinstance as *mut _,
+        });
+
+        window.internal.invoke_menu_callback(
+            &mut menu_callback,
+            hit_dom_node,
+            &window_handle,
+            &window.gl_context_ptr,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    return process_callback_results(
+        callback_result,
+        window,
+        &NodesToCheck::empty(
+            window.internal.current_window_state.mouse_state.mouse_down(),
+            window.internal.current_window_state.focused_node,
+        ),
+        image_cache,
+        fc_cache,
+        &config.system_callbacks,
+        new_windows,
+        destroyed_windows
+    );
+}
+
+// Calls the AfterMount / BeforeUnmount / NodeResized callbacks
+// that were queued by the last DOM regeneration or relayout
+#[must_use]
+fn process_component_events(
+    hinstance: HINSTANCE,
+    window: &mut Window,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>,
+) -> ProcessEventResult {
+
+    use azul_core::window::{RawWindowHandle, WindowsHandle};
+
+    if window.internal.component_events.is_empty() &&
+       window.internal.unmounted_layout_results.is_empty() {
+        return ProcessEventResult::DoNothing;
+    }
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+
+        let window_handle = RawWindowHandle::Windows(WindowsHandle {
+            hwnd: window.hwnd as *mut _,
+            hinstance: hinstance as *mut _,
+        });
+
+        window.internal.run_component_callbacks(
+            &window_handle,
+            &window.gl_context_ptr,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    return process_callback_results(
+        callback_result,
+        window,
+        &NodesToCheck::empty(
+            window.internal.current_window_state.mouse_state.mouse_down(),
+            window.internal.current_window_state.focused_node,
+        ),
+        image_cache,
+        fc_cache,
+        &config.system_callbacks,
         new_windows,
         destroyed_windows
     );
@@ -3478,6 +3775,8 @@ fn process_timer(
             window.internal.current_window_state.focused_node,
         ),
         image_cache,
+        fc_cache,
+        &config.system_callbacks,
         new_windows,
         destroyed_windows
     );
@@ -3523,6 +3822,8 @@ fn process_threads(
             window.internal.current_window_state.focused_node,
         ),
         image_cache,
+        fc_cache,
+        &config.system_callbacks,
         new_windows,
         destroyed_windows
     );
@@ -3534,6 +3835,8 @@ fn process_callback_results(
     window: &mut Window,
     nodes_to_check: &NodesToCheck,
     image_cache: &mut ImageCache,
+    fc_cache: &mut LazyFcCache,
+    system_callbacks: &ExternalSystemCallbacks,
     new_windows: &mut Vec<WindowCreateOptions>,
     destroyed_windows: &mut Vec<usize>,
 ) -> ProcessEventResult {
@@ -3572,8 +3875,15 @@ fn process_callback_results(
 
     let mut result = ProcessEventResult::DoNothing;
 
-    let scroll = window.internal.current_window_state.process_system_scroll(&window.internal.scroll_states);
-    let need_scroll_render = scroll.is_some();
+    // system scroll event: scroll the hovered nodes
+    let mut need_scroll_render = match window.internal.current_window_state.get_scroll_amount() {
+        Some(scroll_amount) => window.internal.scroll_states.should_scroll_render(
+            &scroll_amount,
+            &window.internal.current_window_state.last_hit_test,
+        ),
+        None => false,
+    };
+    window.internal.current_window_state.mouse_state.reset_scroll_to_zero();
 
     if let Some(modified) = callback_results.modified_window_state.as_ref() {
         if modified.flags.is_about_to_close {
@@ -3617,7 +3927,15 @@ fn process_callback_results(
         }
     }
 
+    // callbacks changed the window size / theme across a breakpoint of an `@media` rule:
+    // restyle and relayout the current DOM instead of regenerating it
+    if window.internal.media_queries_changed() {
+        window.restyle_media_queries(image_cache, fc_cache, system_callbacks);
+        result = ProcessEventResult::UpdateHitTesterAndProcessAgain;
+    }
+
     // Re-layout and re-style the window.internal.layout_results
+    let css_transitions_changed = callback_results.css_transitions_changed.clone().unwrap_or_default();
     let mut style_layout_changes = StyleAndLayoutChanges::new(
         &nodes_to_check,
         &mut window.internal.layout_results,
@@ -3626,234 +3944,46 @@ fn process_callback_results(
         window.internal.current_window_state.size.get_layout_size(),
         &window.internal.document_id,
         callback_results.css_properties_changed.as_ref(),
+        callback_results.css_variables_changed.as_ref(),
+        Some(&css_transitions_changed),
         callback_results.words_changed.as_ref(),
         &callback_results.update_focused_node,
         azul_layout::do_the_relayout,
     );
 
+    // start the timers of the CSS transitions
+    let (transitions_added, transitions_removed) = style_layout_changes.get_transition_timers(system_callbacks);
+    window.start_stop_timers(transitions_added, transitions_removed);
+
     // FOCUS CHANGE HAPPENS HERE!
     if let Some(focus_change) = style_layout_changes.focus_change.clone() {
          window.internal.current_window_state.focused_node = focus_change.new;
+         if let Some(new_focus) = focus_change.new {
+             need_scroll_render |= window.internal.scroll_node_into_view(new_focus);
+         }
     }
 
-    // Perform a system or user scroll event: only
-    // scroll nodes that were not scrolled in the current frame
-    //
-    // Update the scroll states of the nodes, returning what nodes were actually scrolled this frame
-    if let Some(scroll) = scroll {
-        // Does a system scroll and re-invokes the IFrame
-        // callbacks if scrolled out of view
-        window.do_system_scroll(scroll);
-        window.internal.current_window_state.mouse_state.reset_scroll_to_zero();
+    // NodeResized callbacks are called on the next AZ_REDO_HIT_TEST
+    if let Some(resized) = style_layout_changes.nodes_that_changed_size.as_ref() {
+        window.internal.component_events.insert_resized_nodes(resized);
     }
 
-    if style_layout_changes.did_resize_nodes() {
+    // IFrames that were scrolled out of their rendered DOM have to be re-invoked
+    let iframes_changed = (need_scroll_render || callback_results.should_scroll_render) &&
+        window.scroll_iframes(image_cache, fc_cache);
+
+    if style_layout_changes.did_resize_nodes() || iframes_changed {
         // at least update the hit-tester
         ProcessEventResult::UpdateHitTesterAndProcessAgain
     } else if style_layout_changes.need_regenerate_display_list() {
         ProcessEventResult::ShouldUpdateDisplayListCurrentWindow
-    } else if need_scroll_render || style_layout_changes.need_redraw() {
+    } else if need_scroll_render || callback_results.should_scroll_render || style_layout_changes.need_redraw() {
         ProcessEventResult::ShouldReRenderCurrentWindow
     } else {
         result
     }
 }
 
-// Updates images and image mask resources
-// NOTE: assumes the GL context is made current
-fn update_image_resources(
-    render_api: &mut WrRenderApi,
-    layout_results: &[LayoutResult],
-    images_to_update: BTreeMap<DomId, BTreeMap<NodeId, (ImageRef, UpdateImageType)>>,
-    image_masks_to_update: BTreeMap<DomId, BTreeMap<NodeId, ImageMask>>,
-    callbacks: &RenderCallbacks,
-    image_cache: &ImageCache,
-    gl_texture_cache: &mut GlTextureCache,
-    renderer_resources: &mut RendererResources,
-    document_id: DocumentId,
-    epoch: Epoch,
-) {
-
-    use webrender::api::units::ImageDirtyRect as WrImageDirtyRect;
-    use crate::wr_translate::{
-        wr_translate_image_key,
-        wr_translate_image_descriptor,
-        wr_translate_image_data,
-        wr_translate_document_id,
-    };
-    use azul_core::dom::NodeType;
-    use azul_core::app_resources::{
-        ImageData, ExternalImageType,
-        ExternalImageData, DecodedImage,
-        ImageBufferKind
-    };
-
-    let mut txn = WrTransaction::new();
-
-    // update images
-    for (dom_id, image_map) in images_to_update {
-
-        let layout_result = match layout_results.get(dom_id.inner) {
-            Some(s) => s,
-            None => continue,
-        };
-
-        for (node_id, (image_ref, image_type)) in image_map {
-
-            // get the existing key + extents of the image
-            let existing_image_ref_hash = match image_type {
-                UpdateImageType::Content => {
-                    match layout_result.styled_dom.node_data.as_container().get(node_id).map(|n| n.get_node_type()) {
-                        Some(NodeType::Image(image_ref)) => image_ref.get_hash(),
-                        _ => continue,
-                    }
-                },
-                UpdateImageType::Background => {
-
-                    let node_data = layout_result.styled_dom.node_data.as_container();
-                    let node_data = match node_data.get(node_id) {
-                        Some(s) => s,
-                        None => continue,
-                    };
-
-                    let styled_node_states = layout_result.styled_dom.styled_nodes.as_container();
-                    let node_state = match styled_node_states.get(node_id) {
-                        Some(s) => s.state.clone(),
-                        None => continue,
-                    };
-
-                    let default = azul_css::StyleBackgroundContentVec::from_const_slice(&[]);
-
-                    // TODO: only updates the first image background - usually not a problem
-                    let bg_hash = layout_result.styled_dom.css_property_cache.ptr
-                    .get_background_content(node_data, &node_id, &node_state)
-                    .and_then(|bg| bg.get_property().unwrap_or(&default).as_ref().iter().find_map(|b| match b {
-                        azul_css::StyleBackgroundContent::Image(id) => {
-                            let image_ref = image_cache.get_css_image_id(id)?;
-                            Some(image_ref.get_hash())
-                        },
-                        _ => None,
-                    }));
-
-                    match bg_hash {
-                        Some(h) => h,
-                        None => continue,
-                    }
-                }
-            };
-
-            let decoded_image = match image_ref.into_inner() {
-                Some(s) => s,
-                None => continue,
-            };
-
-            // Try getting the existing image key either
-            // from the textures or from the renderer resources
-            let existing_key = gl_texture_cache.solved_textures
-                .get(&dom_id)
-                .and_then(|map| map.get(&node_id))
-                .map(|val| val.0);
-
-            let existing_key = match existing_key {
-                Some(s) => Some(s),
-                None => {
-                    renderer_resources.currently_registered_images
-                    .get(&existing_image_ref_hash)
-                    .map(|(key, _desc)| *key)
-                },
-            };
-
-            let key = match existing_key {
-                Some(s) => s,
-                None => continue, // updating an image requires at
-                                  // least one image to be present
-            };
-
-            let (descriptor, data) = match decoded_image {
-                DecodedImage::Gl(texture) => {
-
-                    let descriptor = texture.get_descriptor();
-                    let external_image_id = (callbacks.insert_into_active_gl_textures_fn)(document_id, epoch, texture);
-
-
-                    gl_texture_cache.solved_textures
-                        .entry(dom_id.clone())
-                        .or_insert_with(|| BTreeMap::new())
-                        .insert(node_id, (key, descriptor));
-
-                    let data = ImageData::External(ExternalImageData {
-                        id: external_image_id,
-                        channel_index: 0,
-                        image_type: ExternalImageType::TextureHandle(ImageBufferKind::Texture2D),
-                    });
-
-                    (descriptor, data)
-                },
-                DecodedImage::Raw((descriptor, data)) => {
-                    // use the hash to get the existing image key
-                    // TODO: may lead to problems when the same ImageRef is used more than once?
-                    renderer_resources.currently_registered_images.get_mut(&existing_image_ref_hash).unwrap().1 = descriptor.clone();
-                    (descriptor, data)
-                },
-                DecodedImage::NullImage { .. } => continue, // TODO: NULL image descriptor?
-                DecodedImage::Callback(callback) => {
-                    // TODO: re-render image callbacks?
-                    /*
-                    let (key, descriptor) = match gl_texture_cache.solved_textures.get(&dom_id).and_then(|textures| textures.get(&node_id)) {
-                        Some((k, d)) => (k, d),
-                        None => continue,
-                    };*/
-
-                    continue
-                },
-            };
-
-            // update the image descriptor in the renderer resources
-
-            txn.update_image(
-                wr_translate_image_key(key),
-                wr_translate_image_descriptor(descriptor),
-                wr_translate_image_data(data),
-                &WrImageDirtyRect::All,
-            );
-        }
-    }
-
-    // update image masks
-    for (dom_id, image_mask_map) in image_masks_to_update {
-
-        let layout_result = match layout_results.get(dom_id.inner) {
-            Some(s) => s,
-            None => continue,
-        };
-
-        /*
-
-        for (node_id, image_ref) in image_mask_map {
-                let key = match existing_key {
-                    Some(s) => s,
-                    None => continue, // updating an image requires at
-                                      // least one image to be present
-                };
-            let decoded_image = match image_ref.into_inner() {
-                Some(s) => s,
-                None => continue,
-            };
-
-            txn.update_image(
-                wr_translate_image_key(key),
-                wr_translate_image_descriptor(descriptor),
-                wr_translate_image_data(data),
-                &WrImageDirtyRect::All,
-            );
-        }
-        */
-
-    }
-
-    render_api.send_transaction(wr_translate_document_id(document_id), txn);
-}
-
 fn create_windows(hinstance: HINSTANCE, app: &mut SharedApplicationData, new: Vec<WindowCreateOptions>) {
     for opts in new {
         if let Ok(w) = Window::create(hinstance, opts, app.clone()) {
@@ -3973,4 +4103,131 @@ const fn win32_translate_cursor(input: MouseCursorType) -> *const wchar_t {
         Help => winuser::IDC_HELP,
         _ => winuser::IDC_ARROW,
     }
+}
+
+// Updates the shift_down / ctrl_down / alt_down / super_down
+// flags from the currently pressed virtual keycodes
+fn win32_update_modifier_keys(keyboard_state: &mut KeyboardState) {
+    use azul_core::window::VirtualKeyCode::*;
+    let pressed = &keyboard_state.pressed_virtual_keycodes;
+    keyboard_state.shift_down = pressed.contains_hm_item(&LShift) || pressed.contains_hm_item(&RShift);
+    keyboard_state.ctrl_down = pressed.contains_hm_item(&LControl) || pressed.contains_hm_item(&RControl);
+    keyboard_state.alt_down = pressed.contains_hm_item(&LAlt) || pressed.contains_hm_item(&RAlt);
+    keyboard_state.super_down = pressed.contains_hm_item(&LWin) || pressed.contains_hm_item(&RWin);
+}
+
+// translates a win32 VK_* virtual key code to a VirtualKeyCode
+// note: adapted from https://github.com/rust-windowing/winit/blob/1c4d6e7613c3a3870cecb4cfa0eecc97409d45ff/src/platform_impl/windows/event.rs
+fn win32_translate_vkey(vkey: i32) -> Option<VirtualKeyCode> {
+    use azul_core::window::VirtualKeyCode::*;
+    use winapi::um::winuser;
+
+    match vkey {
+        winuser::VK_BACK => Some(Back),
+        winuser::VK_TAB => Some(Tab),
+        winuser::VK_RETURN => Some(Return),
+        winuser::VK_SHIFT | winuser::VK_LSHIFT => Some(LShift),
+        winuser::VK_RSHIFT => Some(RShift),
+        winuser::VK_CONTROL | winuser::VK_LCONTROL => Some(LControl),
+        winuser::VK_RCONTROL => Some(RControl),
+        winuser::VK_MENU | winuser::VK_LMENU => Some(LAlt),
+        winuser::VK_RMENU => Some(RAlt),
+        winuser::VK_LWIN => Some(LWin),
+        winuser::VK_RWIN => Some(RWin),
+        winuser::VK_PAUSE => Some(Pause),
+        winuser::VK_CAPITAL => Some(Capital),
+        winuser::VK_ESCAPE => Some(Escape),
+        winuser::VK_SPACE => Some(Space),
+        winuser::VK_PRIOR => Some(PageUp),
+        winuser::VK_NEXT => Some(PageDown),
+        winuser::VK_END => Some(End),
+        winuser::VK_HOME => Some(Home),
+        winuser::VK_LEFT => Some(Left),
+        winuser::VK_UP => Some(Up),
+        winuser::VK_RIGHT => Some(Right),
+        winuser::VK_DOWN => Some(Down),
+        winuser::VK_SNAPSHOT => Some(Snapshot),
+        winuser::VK_INSERT => Some(Insert),
+        winuser::VK_DELETE => Some(Delete),
+        0x30 => Some(Key0),
+        0x31 => Some(Key1),
+        0x32 => Some(Key2),
+        0x33 => Some(Key3),
+        0x34 => Some(Key4),
+        0x35 => Some(Key5),
+        0x36 => Some(Key6),
+        0x37 => Some(Key7),
+        0x38 => Some(Key8),
+        0x39 => Some(Key9),
+        0x41 => Some(A),
+        0x42 => Some(B),
+        0x43 => Some(C),
+        0x44 => Some(D),
+        0x45 => Some(E),
+        0x46 => Some(F),
+        0x47 => Some(G),
+        0x48 => Some(H),
+        0x49 => Some(I),
+        0x4A => Some(J),
+        0x4B => Some(K),
+        0x4C => Some(L),
+        0x4D => Some(M),
+        0x4E => Some(N),
+        0x4F => Some(O),
+        0x50 => Some(P),
+        0x51 => Some(Q),
+        0x52 => Some(R),
+        0x53 => Some(S),
+        0x54 => Some(T),
+        0x55 => Some(U),
+        0x56 => Some(V),
+        0x57 => Some(W),
+        0x58 => Some(X),
+        0x59 => Some(Y),
+        0x5A => Some(Z),
+        winuser::VK_APPS => Some(Apps),
+        winuser::VK_SLEEP => Some(Sleep),
+        winuser::VK_NUMPAD0 => Some(Numpad0),
+        winuser::VK_NUMPAD1 => Some(Numpad1),
+        winuser::VK_NUMPAD2 => Some(Numpad2),
+        winuser::VK_NUMPAD3 => Some(Numpad3),
+        winuser::VK_NUMPAD4 => Some(Numpad4),
+        winuser::VK_NUMPAD5 => Some(Numpad5),
+        winuser::VK_NUMPAD6 => Some(Numpad6),
+        winuser::VK_NUMPAD7 => Some(Numpad7),
+        winuser::VK_NUMPAD8 => Some(Numpad8),
+        winuser::VK_NUMPAD9 => Some(Numpad9),
+        winuser::VK_MULTIPLY => Some(NumpadMultiply),
+        winuser::VK_ADD => Some(NumpadAdd),
+        winuser::VK_SUBTRACT => Some(NumpadSubtract),
+        winuser::VK_DECIMAL => Some(NumpadDecimal),
+        winuser::VK_DIVIDE => Some(NumpadDivide),
+        winuser::VK_F1 => Some(F1),
+        winuser::VK_F2 => Some(F2),
+        winuser::VK_F3 => Some(F3),
+        winuser::VK_F4 => Some(F4),
+        winuser::VK_F5 => Some(F5),
+        winuser::VK_F6 => Some(F6),
+        winuser::VK_F7 => Some(F7),
+        winuser::VK_F8 => Some(F8),
+        winuser::VK_F9 => Some(F9),
+        winuser::VK_F10 => Some(F10),
+        winuser::VK_F11 => Some(F11),
+        winuser::VK_F12 => Some(F12),
+        winuser::VK_NUMLOCK => Some(Numlock),
+        winuser::VK_SCROLL => Some(Scroll),
+        winuser::VK_OEM_PLUS => Some(Equals),
+        winuser::VK_OEM_COMMA => Some(Comma),
+        winuser::VK_OEM_MINUS => Some(Minus),
+        winuser::VK_OEM_PERIOD => Some(Period),
+        winuser::VK_OEM_1 => Some(Semicolon),
+        winuser::VK_OEM_2 => Some(Slash),
+        winuser::VK_OEM_3 => Some(Grave),
+        winuser::VK_OEM_4 => Some(LBracket),
+        winuser::VK_OEM_5 => Some(Backslash),
+        winuser::VK_OEM_6 => Some(RBracket),
+        winuser::VK_OEM_7 => Some(Apostrophe),
+        winuser::VK_OEM_102 => Some(OEM102),
+        _ => None,
+    }
 }
\ No newline at end of file
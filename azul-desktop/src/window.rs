@@ -1,20 +1,14 @@
-#![not(target_os = "windows")]
-
 use std::time::Duration as StdDuration;
 use std::thread::JoinHandle;
 use std::sync::Mutex;
 use core::fmt;
 use core::cell::RefCell;
-use alloc::{
-    rc::Rc,
-    sync::Arc,
-};
+use alloc::rc::Rc;
 use webrender::{
     render_api::{
         RenderApi as WrRenderApi,
     },
     api::{
-        ApiHitTester as WrApiHitTester,
         DocumentId as WrDocumentId,
         units::{
             LayoutSize as WrLayoutSize,
@@ -45,8 +39,10 @@ use glutin::{
     NotCurrent, PossiblyCurrent,
     Context as GlutinContext,
 };
-use gleam::gl::{self, Gl};
+use gl_context_loader::{gl, GenericGlContext};
+use crate::app::LazyFcCache;
 use crate::compositor::Compositor;
+use crate::wr_translate::AsyncHitTester;
 use azul_core::{
     callbacks::{PipelineId, DocumentId, RefAny, CallbackInfo, Update},
     task::ExternalSystemCallbacks,
@@ -283,9 +279,9 @@ pub struct Window {
     /// Main render API that can be used to register and un-register fonts and images
     pub(crate) render_api: WrRenderApi,
     /// Hit-tester, lazily initialized and updated every time the display list changes layout
-    pub(crate) hit_tester: Arc<dyn WrApiHitTester>,
+    pub(crate) hit_tester: AsyncHitTester,
     // software_context: Option<Rc<swgl::Context>>
-    hardware_gl: Rc<dyn Gl>,
+    hardware_gl: Rc<GenericGlContext>,
     /// Cached gl context pointer that includes the compiled shaders for
     /// drawing vertex buffers to the screen
     pub(crate) gl_context_ptr: OptionGlContextPtr,
@@ -333,7 +329,7 @@ impl Window {
     }
     */
 
-    fn get_gl_context(&self) -> Rc<dyn Gl> {
+    fn get_gl_context(&self) -> Rc<GenericGlContext> {
         /*
         match self.software_gl.as_ref() {
             Some(sw) => sw.clone(),
@@ -419,12 +415,13 @@ impl Window {
             // Therefore the background color has to be set before render() is invoked.
             WrRendererOptions {
                 resource_override_path: None,
+                use_optimized_shaders: true,
                 precache_flags: WrShaderPrecacheFlags::EMPTY,
-                device_pixel_ratio: hidpi_factor,
                 enable_subpixel_aa: true,
                 enable_aa: true,
                 cached_programs: Some(WrProgramCache::new(None)),
-                clear_color: Some(WrColorF { r: 0.0, g: 0.0, b: 0.0, a: 0.0 }), // transparent
+                clear_color: WrColorF { r: 0.0, g: 0.0, b: 0.0, a: 0.0 }, // transparent
+                panic_on_gl_error: false,
                 enable_multithreading: false,
                 debug_flags: wr_translate_debug_flags(&options.state.debug_state),
                 .. WrRendererOptions::default()
@@ -515,8 +512,8 @@ impl Window {
         };
         */
 
-        let hit_tester = hit_tester.resolve();
-        let hit_tester_ref = &*hit_tester;
+        let mut hit_tester = AsyncHitTester::Requested(hit_tester);
+        let hit_tester_ref = &*hit_tester.resolve();
 
         let internal = fc_cache.apply_closure(|fc_cache| {
             WindowInternal::new(
@@ -529,7 +526,7 @@ impl Window {
                 image_cache,
                 &gl_context_ptr,
                 &mut initial_resource_updates,
-                &Window::CALLBACKS,
+                &crate::app::CALLBACKS,
                 fc_cache,
                 azul_layout::do_the_relayout,
                 |window_state, scroll_states, layout_results| {
@@ -566,10 +563,9 @@ impl Window {
             context_menu: None,
         };
 
-        let mut txn = WrTransaction::new();
-        window.rebuild_display_list(&mut txn, image_cache, initial_resource_updates);
-        window.render_async(txn, true);
-        window.force_synchronize_hit_tester_initial(image_cache);
+        window.rebuild_display_list(image_cache, initial_resource_updates);
+        window.render_async(true);
+        window.force_synchronize_hit_tester();
         // window.set_menu_bar();
 
         Ok(window)
@@ -642,11 +638,11 @@ impl Window {
         Err(last_err.unwrap_or(GlutinCreationError::NoAvailablePixelFormat))
     }
 
-    fn initialize_hardware_gl_context(gl_context: &GlutinContext<PossiblyCurrent>) -> Result<Rc<dyn Gl>, GlutinCreationError> {
+    fn initialize_hardware_gl_context(gl_context: &GlutinContext<PossiblyCurrent>) -> Result<Rc<GenericGlContext>, GlutinCreationError> {
         use glutin::Api;
+        use crate::shell::load_gl_context;
         match gl_context.get_api() {
-            Api::OpenGl => Ok(unsafe { gl::GlFns::load_with(|symbol| gl_context.get_proc_address(symbol) as *const _) }),
-            Api::OpenGlEs => Ok(unsafe { gl::GlesFns::load_with(|symbol| gl_context.get_proc_address(symbol) as *const _ ) }),
+            Api::OpenGl | Api::OpenGlEs => Ok(Rc::new(load_gl_context(|symbol| gl_context.get_proc_address(symbol) as *mut _))),
             Api::WebGl => Err(GlutinCreationError::NoBackendAvailable("WebGL".into())),
         }
     }
@@ -666,7 +662,7 @@ impl Window {
         fc_cache: &mut LazyFcCache,
     ) {
         let document_id = self.internal.document_id;
-        let hit_tester = &*self.hit_tester;
+        let hit_tester = &*self.hit_tester.resolve();
         let internal = &mut self.internal;
         let gl_context = &self.gl_context_ptr;
 
@@ -676,7 +672,7 @@ impl Window {
                 image_cache,
                 &gl_context,
                 resource_updates,
-                &Window::CALLBACKS,
+                &crate::app::CALLBACKS,
                 fc_cache,
                 azul_layout::do_the_relayout,
                 |window_state, scroll_states, layout_results| {
@@ -695,60 +691,59 @@ impl Window {
         // self.set_menu_bar();
     }
 
-    /// Only re-build the display list and send it to webrender
-    #[cfg(not(test))]
-    pub fn rebuild_display_list(
+    /// Restyles the DOM with the `@media` rules that apply to the current window
+    /// size / theme and updates the self.internal.gl_texture_cache field,
+    /// without calling the layout function
+    pub fn restyle_media_queries(
         &mut self,
-        txn: &mut WrTransaction,
         image_cache: &ImageCache,
-        resources: Vec<ResourceUpdate>
+        resource_updates: &mut Vec<ResourceUpdate>,
+        fc_cache: &mut LazyFcCache,
     ) {
+        let document_id = self.internal.document_id;
+        let hit_tester = &*self.hit_tester.resolve();
+        let internal = &mut self.internal;
+        let gl_context = &self.gl_context_ptr;
 
-        use crate::wr_translate::{
-            wr_translate_pipeline_id,
-            wr_translate_document_id,
-            wr_translate_display_list,
-            wr_translate_epoch,
-            wr_translate_resource_update,
-        };
-        use azul_core::styled_dom::DomId;
-        use azul_core::ui_solver::LayoutResult;
-
-        // NOTE: Display list has to be rebuilt every frame, otherwise, the epochs get out of sync
-        let root_id = DomId { inner: 0 };
-        let cached_display_list = LayoutResult::get_cached_display_list(
-             &self.internal.document_id,
-             root_id,
-             self.internal.epoch,
-             &self.internal.layout_results,
-             &self.internal.current_window_state,
-             &self.internal.gl_texture_cache,
-             &self.internal.renderer_resources,
-             image_cache,
-        );
-
-        let root_pipeline_id = PipelineId(0, self.internal.document_id.id);
-        let display_list = wr_translate_display_list(
-            self.internal.document_id,
-            &mut self.render_api,
-            cached_display_list,
-            root_pipeline_id,
-            self.internal.current_window_state.size.hidpi_factor
-        );
-
-        let logical_size = WrLayoutSize::new(
-            self.internal.current_window_state.size.dimensions.width,
-            self.internal.current_window_state.size.dimensions.height
-        );
-
-        txn.update_resources(resources.into_iter().map(wr_translate_resource_update).collect());
-        txn.set_display_list(
-            wr_translate_epoch(self.internal.epoch),
-            None,
-            logical_size.clone(),
-            (wr_translate_pipeline_id(root_pipeline_id), display_list),
-            true,
-        );
+        fc_cache.apply_closure(|fc_cache| {
+            internal.restyle_media_queries(
+                image_cache,
+                &gl_context,
+                resource_updates,
+                &crate::app::CALLBACKS,
+                fc_cache,
+                azul_layout::do_the_relayout,
+                |window_state, scroll_states, layout_results| {
+                    crate::wr_translate::fullhittest_new_webrender(
+                         hit_tester,
+                         document_id,
+                         window_state.focused_node,
+                         layout_results,
+                         &window_state.mouse_state.cursor_position,
+                         window_state.size.hidpi_factor,
+                    )
+                }
+            );
+        });
+    }
+
+    /// Re-invokes the IFrames that were scrolled out of their rendered DOM,
+    /// returns whether the display list has to be rebuilt
+    pub fn scroll_iframes(
+        &mut self,
+        image_cache: &ImageCache,
+        resource_updates: &mut Vec<ResourceUpdate>,
+        fc_cache: &mut LazyFcCache,
+    ) -> bool {
+        let internal = &mut self.internal;
+        fc_cache.apply_closure(|fc_cache| {
+            internal.scroll_iframes(image_cache, &crate::app::CALLBACKS, fc_cache, resource_updates)
+        }).is_empty() == false
+    }
+
+    /// Only re-build the display list and send it to webrender
+    pub fn rebuild_display_list(&mut self, image_cache: &ImageCache, resources: Vec<ResourceUpdate>) {
+        crate::wr_translate::rebuild_display_list(&mut self.internal, &mut self.render_api, image_cache, resources);
     }
 
     /// Synchronize the `self.internal.previous_window_state` with the `self.internal.current_window_state`
@@ -814,25 +809,10 @@ impl Window {
 
 
 
-        // flags:is_maximized, flags:is_minimized
-        if self.internal.current_window_state.flags.is_maximized != new_state.flags.is_maximized {
-            self.display.window().set_maximized(new_state.flags.is_maximized);
+        // flags:frame (normal / minimized / maximized / fullscreen)
+        if self.internal.current_window_state.flags.frame != new_state.flags.frame {
+            set_window_frame(self.display.window(), new_state.flags.frame);
             window_was_updated = true;
-        } else if self.internal.current_window_state.flags.is_minimized != new_state.flags.is_minimized {
-            self.display.window().set_minimized(new_state.flags.is_maximized);
-            window_was_updated = true;
-        }
-
-        // flags:is_fullscreen
-        if self.internal.current_window_state.flags.is_fullscreen != new_state.flags.is_fullscreen {
-            if new_state.flags.is_fullscreen {
-                // TODO: implement exclusive fullscreen!
-                self.display.window().set_fullscreen(Some(Fullscreen::Borderless(self.display.window().current_monitor())));
-                window_was_updated = true;
-            } else {
-                self.display.window().set_fullscreen(None);
-                window_was_updated = true;
-            }
         }
 
         // flags:has_decorations
@@ -950,6 +930,7 @@ impl Window {
             background_color,
             layout_callback,
             close_callback,
+            shortcuts,
             renderer_options: _,
             monitor: _,
         } = new_state;
@@ -968,6 +949,7 @@ impl Window {
         self.internal.current_window_state.background_color = background_color;
         self.internal.current_window_state.layout_callback = layout_callback;
         self.internal.current_window_state.close_callback = close_callback;
+        self.internal.current_window_state.shortcuts = shortcuts;
         self.internal.current_window_state.monitor = current_window_monitor;
 
         window_was_updated
@@ -998,6 +980,7 @@ impl Window {
             image_cache,
             system_fonts,
             external_callbacks,
+            &self.internal.renderer_resources,
         )
     }
 
@@ -1099,62 +1082,17 @@ impl Window {
     // Function wrapper that is invoked on scrolling and normal rendering - only renders the
     // window contents and updates the screen, assumes that all transactions via the WrRenderApi
     // have been committed before this function is called.
-    //
-    // WebRender doesn't reset the active shader back to what it was, but rather sets it
-    // to zero, which glutin doesn't know about, so on the next frame it tries to draw with shader 0.
-    // This leads to problems when invoking GlCallbacks, because those don't expect
-    // the OpenGL state to change between calls. Also see: https://github.com/servo/webrender/pull/2880
-    //
-    // NOTE: For some reason, webrender allows rendering to a framebuffer with a
-    // negative width / height, although that doesn't make sense
-    pub(crate) fn render_async(&mut self, mut txn: WrTransaction, display_list_was_rebuilt: bool) {
-
-        use crate::wr_translate;
-        use azul_core::ui_solver::LayoutResult;
-
-        let physical_size = self.internal.current_window_state.size.get_physical_size();
-        let framebuffer_size = WrDeviceIntSize::new(physical_size.width as i32, physical_size.height as i32);
-
-        // Especially during minimization / maximization of a window, it can happen that the window
-        // width or height is zero. In that case, no rendering is necessary (doing so would crash
-        // the application, since glTexImage2D may never have a 0 as the width or height.
-        if framebuffer_size.width == 0 || framebuffer_size.height == 0 {
-            return;
-        }
-
-        self.internal.epoch.increment();
-
-        txn.set_root_pipeline(wr_translate::wr_translate_pipeline_id(PipelineId(0, self.internal.document_id.id)));
-        txn.set_document_view(WrDeviceIntRect::new(WrDeviceIntPoint::new(0, 0), framebuffer_size), self.internal.current_window_state.size.hidpi_factor);
-        scroll_all_nodes(&mut self.internal.scroll_states, &mut txn);
-        synchronize_gpu_values(&self.internal.layout_results, &mut txn);
-
-        if !display_list_was_rebuilt {
-            txn.skip_scene_builder(); // avoid rebuilding the scene if DL hasn't changed
-        }
-
-        txn.generate_frame(0);
-
-        // Update WR texture cache
-        self.render_api.send_transaction(wr_translate::wr_translate_document_id(self.internal.document_id), txn);
+    pub(crate) fn render_async(&mut self, display_list_was_rebuilt: bool) {
+        crate::wr_translate::generate_frame(&mut self.internal, &mut self.render_api, display_list_was_rebuilt);
     }
 
-    /// Force-synchronizes the hit-tester
-    pub(crate) fn force_synchronize_hit_tester_during(&mut self) {
+    /// Waits until the scene is built and requests a new hit-tester
+    /// for the current display list
+    pub(crate) fn force_synchronize_hit_tester(&mut self) {
         use crate::wr_translate::wr_translate_document_id;
-        self.hit_tester = self.render_api.request_hit_tester(wr_translate_document_id(self.internal.document_id)).resolve();
-    }
-
-    pub(crate) fn force_synchronize_hit_tester_initial(&mut self, image_cache: &ImageCache) {
-        use crate::wr_translate::wr_translate_document_id;
-
-        let mut txn = WrTransaction::new();
-        self.rebuild_display_list(&mut txn, image_cache, Vec::new());
-        scroll_all_nodes(&self.internal.scroll_states, &mut txn);
-        synchronize_gpu_values(&self.internal.layout_results, &mut txn);
-        self.render_api.send_transaction(wr_translate_document_id(self.internal.document_id), txn);
-
-        self.hit_tester = self.render_api.request_hit_tester(wr_translate_document_id(self.internal.document_id)).resolve();
+        self.render_api.flush_scene_builder();
+        let wr_document_id = wr_translate_document_id(self.internal.document_id);
+        self.hit_tester = AsyncHitTester::Requested(self.render_api.request_hit_tester(wr_document_id));
     }
 
     /// Does the actual rendering + swapping
@@ -1460,13 +1398,7 @@ fn initialize_os_window(
     use glutin::window::Fullscreen;
 
     window.set_title(new_state.title.as_str());
-    window.set_maximized(new_state.flags.is_maximized);
-
-    if new_state.flags.is_fullscreen {
-        window.set_fullscreen(Some(Fullscreen::Borderless(window.current_monitor())));
-    } else {
-        window.set_fullscreen(None);
-    }
+    set_window_frame(window, new_state.flags.frame);
 
     window.set_decorations(new_state.flags.has_decorations);
     window.set_inner_size(translate_logical_size(new_state.size.dimensions));
@@ -1492,6 +1424,29 @@ fn initialize_os_window(
     initialize_os_window_platform_extensions(&new_state.platform_specific_options, &window);
 }
 
+/// Minimizes / maximizes the window or makes it fullscreen
+fn set_window_frame(window: &GlutinWindow, frame: WindowFrame) {
+    use glutin::window::Fullscreen;
+    match frame {
+        WindowFrame::Normal => {
+            window.set_fullscreen(None);
+            window.set_minimized(false);
+            window.set_maximized(false);
+        },
+        WindowFrame::Minimized => {
+            window.set_minimized(true);
+        },
+        WindowFrame::Maximized => {
+            window.set_fullscreen(None);
+            window.set_maximized(true);
+        },
+        WindowFrame::Fullscreen => {
+            // TODO: implement exclusive fullscreen!
+            window.set_fullscreen(Some(Fullscreen::Borderless(window.current_monitor())));
+        },
+    }
+}
+
 fn initialize_os_window_platform_extensions(
     platform_options: &PlatformSpecificOptions,
     window: &GlutinWindow,
@@ -81,7 +81,7 @@ use webrender::api::{
 };
 
 use azul_core::{
-    callbacks::{PipelineId, DocumentId, DomNodeId},
+    callbacks::{PipelineId, DocumentId, DomNodeId, UpdateImageType},
     app_resources::{
         FontKey, Au, FontInstanceKey, ImageKey, ImageCache,
         IdNamespace, RawImageFormat as ImageFormat, ImageDescriptor, ImageDescriptorFlags,
@@ -90,14 +90,15 @@ use azul_core::{
         ExternalImageType, ImageBufferKind, UpdateImage, ImageDirtyRect,
         Epoch, AddFontInstance, FontVariation, FontInstanceOptions,
         FontInstancePlatformOptions, SyntheticItalics, PrimitiveFlags,
-        TransformKey,
+        TransformKey, ImageRef, ImageMask, RendererResources,
     },
     display_list::{
-        CachedDisplayList, GlyphInstance, DisplayListScrollFrame,
+        RenderCallbacks, GlTextureCache, CachedDisplayList, GlyphInstance, DisplayListScrollFrame,
         DisplayListFrame, LayoutRectContent, DisplayListMsg,
         AlphaType, ImageRendering, StyleBorderRadius, BoxShadow,
     },
-    dom::TagId,
+    dom::{NodeId, TagId},
+    styled_dom::DomId,
     display_list::DisplayListImageMask,
     ui_solver::{LayoutResult, ExternalScrollId, PositionInfo, ComputedTransform3D},
     window::{
@@ -122,7 +123,7 @@ use azul_css::{
     U8Vec,
 };
 use webrender::Renderer;
-use alloc::sync::Arc;
+use alloc::{collections::BTreeMap, sync::Arc};
 use core::mem;
 
 #[cfg(not(target_os = "windows"))]
@@ -723,10 +724,9 @@ pub(crate) fn fullhittest_new_webrender(
      hidpi_factor: f32,
 ) -> FullHitTest {
 
-    use alloc::collections::BTreeMap;
     use webrender::api::units::WorldPoint as WrWorldPoint;
     use azul_core::callbacks::{HitTestItem, ScrollHitTestItem};
-    use azul_core::styled_dom::{DomId, AzNodeId};
+    use azul_core::styled_dom::AzNodeId;
 
     let mut cursor_location = match cursor_position {
         CursorPosition::OutOfWindow | CursorPosition::Uninitialized => return FullHitTest::empty(old_focus_node),
@@ -776,7 +776,13 @@ pub(crate) fn fullhittest_new_webrender(
                     point_in_viewport: LogicalPosition::new(i.point_in_viewport.x, i.point_in_viewport.y),
                     point_relative_to_item: relative_to_item,
                     is_iframe_hit: layout_result.iframe_mapping.get(&node_id).map(|iframe_dom_id| {
-                        (*iframe_dom_id, relative_to_item)
+                        // the tag of the IFrame is pushed in the scrolled space, so the cursor
+                        // only has to be translated by the virtual offset of the rendered DOM
+                        let cursor_relative_to_iframe = match layout_result.scrollable_nodes.overflowing_nodes.get(&AzNodeId::from_crate_internal(Some(node_id))) {
+                            Some(scroll_node) => relative_to_item - scroll_node.get_virtual_scroll_offset(),
+                            None => relative_to_item,
+                        };
+                        (*iframe_dom_id, cursor_relative_to_iframe)
                     }),
                     is_focusable: layout_result.styled_dom.node_data.as_container().get(node_id)?.get_tab_index().is_some(),
                 }))
@@ -806,7 +812,11 @@ pub(crate) fn fullhittest_new_webrender(
                         point_relative_to_item: item.point_relative_to_item,
                         scroll_node: scroll_node.clone(),
                     });
-                } else {
+                }
+
+                // IFrame nodes stay hit-testable, so that their callbacks are still invoked
+                if !layout_result.scrollable_nodes.overflowing_nodes.contains_key(&az_node_id) ||
+                   layout_result.iframe_mapping.contains_key(&node_id) {
                     ret.hovered_nodes
                     .entry(*dom_id)
                     .or_insert_with(|| HitTest::empty())
@@ -885,7 +895,6 @@ pub(crate) fn synchronize_gpu_values(layout_results: &[LayoutResult], txn: &mut
 }
 
 /// Returns the size fo the built display list
-#[cfg(not(test))]
 pub(crate) fn rebuild_display_list(
     internal: &mut WindowInternal,
     render_api: &mut WrRenderApi,
@@ -947,7 +956,6 @@ pub(crate) fn rebuild_display_list(
 }
 
 /// Generates a new frame for webrender
-#[cfg(not(test))]
 pub(crate) fn generate_frame(
     internal: &mut WindowInternal,
     render_api: &mut WrRenderApi,
@@ -987,6 +995,201 @@ pub(crate) fn generate_frame(
     render_api.send_transaction(wr_translate_document_id(internal.document_id), txn);
 }
 
+// Updates images and image mask resources
+// NOTE: assumes the GL context is made current
+pub(crate) fn update_image_resources(
+    render_api: &mut WrRenderApi,
+    layout_results: &[LayoutResult],
+    images_to_update: BTreeMap<DomId, BTreeMap<NodeId, (ImageRef, UpdateImageType)>>,
+    image_masks_to_update: BTreeMap<DomId, BTreeMap<NodeId, ImageMask>>,
+    callbacks: &RenderCallbacks,
+    image_cache: &ImageCache,
+    gl_texture_cache: &mut GlTextureCache,
+    renderer_resources: &mut RendererResources,
+    document_id: DocumentId,
+    epoch: Epoch,
+) {
+
+    use webrender::api::units::ImageDirtyRect as WrImageDirtyRect;
+    use crate::wr_translate::{
+        wr_translate_image_key,
+        wr_translate_image_descriptor,
+        wr_translate_image_data,
+        wr_translate_document_id,
+    };
+    use azul_core::dom::NodeType;
+    use azul_core::app_resources::{
+        ImageData, ExternalImageType,
+        ExternalImageData, DecodedImage,
+        ImageBufferKind
+    };
+
+    let mut txn = WrTransaction::new();
+
+    // update images
+    for (dom_id, image_map) in images_to_update {
+
+        let layout_result = match layout_results.get(dom_id.inner) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        for (node_id, (image_ref, image_type)) in image_map {
+
+            // get the existing key + extents of the image
+            let existing_image_ref_hash = match image_type {
+                UpdateImageType::Content => {
+                    match layout_result.styled_dom.node_data.as_container().get(node_id).map(|n| n.get_node_type()) {
+                        Some(NodeType::Image(image_ref)) => image_ref.get_hash(),
+                        _ => continue,
+                    }
+                },
+                UpdateImageType::Background => {
+
+                    let node_data = layout_result.styled_dom.node_data.as_container();
+                    let node_data = match node_data.get(node_id) {
+                        Some(s) => s,
+                        None => continue,
+                    };
+
+                    let styled_node_states = layout_result.styled_dom.styled_nodes.as_container();
+                    let node_state = match styled_node_states.get(node_id) {
+                        Some(s) => s.state.clone(),
+                        None => continue,
+                    };
+
+                    let default = azul_css::StyleBackgroundContentVec::from_const_slice(&[]);
+
+                    // TODO: only updates the first image background - usually not a problem
+                    let bg_hash = layout_result.styled_dom.css_property_cache.ptr
+                    .get_background_content(node_data, &node_id, &node_state)
+                    .and_then(|bg| bg.get_property().unwrap_or(&default).as_ref().iter().find_map(|b| match b {
+                        azul_css::StyleBackgroundContent::Image(id) => {
+                            let image_ref = image_cache.get_css_image_id(&id)?;
+                            Some(image_ref.get_hash())
+                        },
+                        _ => None,
+                    }));
+
+                    match bg_hash {
+                        Some(h) => h,
+                        None => continue,
+                    }
+                }
+            };
+
+            let decoded_image = match image_ref.into_inner() {
+                Some(s) => s,
+                None => continue,
+            };
+
+            // Try getting the existing image key either
+            // from the textures or from the renderer resources
+            let existing_key = gl_texture_cache.solved_textures
+                .get(&dom_id)
+                .and_then(|map| map.get(&node_id))
+                .map(|val| val.0);
+
+            let existing_key = match existing_key {
+                Some(s) => Some(s),
+                None => {
+                    renderer_resources.currently_registered_images
+                    .get(&existing_image_ref_hash)
+                    .map(|(key, _desc)| *key)
+                },
+            };
+
+            let key = match existing_key {
+                Some(s) => s,
+                None => continue, // updating an image requires at
+                                  // least one image to be present
+            };
+
+            let (descriptor, data) = match decoded_image {
+                DecodedImage::Gl(texture) => {
+
+                    let descriptor = texture.get_descriptor();
+                    let external_image_id = (callbacks.insert_into_active_gl_textures_fn)(document_id, epoch, texture);
+
+
+                    gl_texture_cache.solved_textures
+                        .entry(dom_id.clone())
+                        .or_insert_with(|| BTreeMap::new())
+                        .insert(node_id, (key, descriptor));
+
+                    let data = ImageData::External(ExternalImageData {
+                        id: external_image_id,
+                        channel_index: 0,
+                        image_type: ExternalImageType::TextureHandle(ImageBufferKind::Texture2D),
+                    });
+
+                    (descriptor, data)
+                },
+                DecodedImage::Raw((descriptor, data)) => {
+                    // use the hash to get the existing image key
+                    // TODO: may lead to problems when the same ImageRef is used more than once?
+                    renderer_resources.currently_registered_images.get_mut(&existing_image_ref_hash).unwrap().1 = descriptor.clone();
+                    (descriptor, data)
+                },
+                DecodedImage::NullImage { .. } => continue, // TODO: NULL image descriptor?
+                DecodedImage::Callback(callback) => {
+                    // TODO: re-render image callbacks?
+                    /*
+                    let (key, descriptor) = match gl_texture_cache.solved_textures.get(&dom_id).and_then(|textures| textures.get(&node_id)) {
+                        Some((k, d)) => (k, d),
+                        None => continue,
+                    };*/
+
+                    continue
+                },
+            };
+
+            // update the image descriptor in the renderer resources
+
+            txn.update_image(
+                wr_translate_image_key(key),
+                wr_translate_image_descriptor(descriptor),
+                wr_translate_image_data(data),
+                &WrImageDirtyRect::All,
+            );
+        }
+    }
+
+    // update image masks
+    for (dom_id, image_mask_map) in image_masks_to_update {
+
+        let layout_result = match layout_results.get(dom_id.inner) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        /*
+
+        for (node_id, image_ref) in image_mask_map {
+                let key = match existing_key {
+                    Some(s) => s,
+                    None => continue, // updating an image requires at
+                                      // least one image to be present
+                };
+            let decoded_image = match image_ref.into_inner() {
+                Some(s) => s,
+                None => continue,
+            };
+
+            txn.update_image(
+                wr_translate_image_key(key),
+                wr_translate_image_descriptor(descriptor),
+                wr_translate_image_data(data),
+                &WrImageDirtyRect::All,
+            );
+        }
+        */
+
+    }
+
+    render_api.send_transaction(wr_translate_document_id(document_id), txn);
+}
+
 
 #[inline]
 fn wr_translate_image_mask(input: &DisplayListImageMask) -> WrImageMask {
@@ -0,0 +1,253 @@
+//! Runs a DOM through the headless shell: layout, a simulated click and a rendered frame
+
+// frames are only rendered with the tiny-skia rasterizer
+#![cfg(feature = "svg")]
+
+use azul_core::{
+    app_resources::{AppConfig, ImageCache, LayoutSolverVersion, RawImageData},
+    callbacks::{
+        Callback, CallbackInfo, IFrameCallbackInfo, IFrameCallbackReturn,
+        LayoutCallbackInfo, RefAny, Update,
+    },
+    dom::{CallbackData, Dom, EventFilter, HoverEventFilter, NodeDataInlineCssProperty},
+    styled_dom::StyledDom,
+    window::{CursorPosition, LogicalPosition, LogicalSize, WindowCreateOptions},
+};
+use azul_css::{
+    ColorU, Css, CssProperty, LayoutHeight, LayoutWidth, OptionF32, StyleBackgroundContent,
+};
+use azul_desktop::shell::headless::HeadlessWindow;
+
+const RED: ColorU = ColorU { r: 255, g: 0, b: 0, a: 255 };
+const BLUE: ColorU = ColorU { r: 0, g: 0, b: 255, a: 255 };
+
+struct Counter {
+    clicks: usize,
+}
+
+extern "C" fn layout(data: &mut RefAny, _: LayoutCallbackInfo) -> StyledDom {
+
+    let clicks = data.downcast_ref::<Counter>().map(|c| c.clicks).unwrap_or_default();
+    let color = if clicks == 0 { RED } else { BLUE };
+
+    let button = Dom::div()
+    .with_inline_css_props(vec![
+        NodeDataInlineCssProperty::Normal(CssProperty::width(LayoutWidth::px(100.0))),
+        NodeDataInlineCssProperty::Normal(CssProperty::height(LayoutHeight::px(100.0))),
+        NodeDataInlineCssProperty::Normal(CssProperty::background_content(vec![StyleBackgroundContent::Color(color)].into())),
+    ].into())
+    .with_callbacks(vec![CallbackData {
+        event: EventFilter::Hover(HoverEventFilter::MouseUp),
+        callback: Callback { cb: on_click },
+        data: data.clone(),
+    }].into());
+
+    Dom::body()
+    .with_children(vec![button].into())
+    .style(&mut Css::empty())
+}
+
+extern "C" fn on_click(data: &mut RefAny, _: CallbackInfo) -> Update {
+    match data.downcast_mut::<Counter>() {
+        Some(mut c) => {
+            c.clicks += 1;
+            Update::RegenerateStyledDomForCurrentWindow
+        },
+        None => Update::DoNothing,
+    }
+}
+
+fn pixel_at(window: &mut HeadlessWindow, x: usize, y: usize) -> ColorU {
+    let frame = window.render();
+    let pixels = match &frame.pixels {
+        RawImageData::U8(u) => u.as_ref(),
+        _ => panic!("headless framebuffer is not RGBA8"),
+    };
+    let offset = (y * frame.width + x) * 4;
+    ColorU { r: pixels[offset], g: pixels[offset + 1], b: pixels[offset + 2], a: pixels[offset + 3] }
+}
+
+#[test]
+fn click_regenerates_dom_and_rerenders() {
+
+    let data = RefAny::new(Counter { clicks: 0 });
+
+    let mut options = WindowCreateOptions::new(layout);
+    options.state.size.dimensions = LogicalSize::new(200.0, 200.0);
+    options.state.size.hidpi_factor = 1.0;
+
+    let mut window = HeadlessWindow::new(
+        data.clone(),
+        AppConfig::default(LayoutSolverVersion::March2021),
+        ImageCache::new(),
+        options,
+    );
+
+    let frame = window.render();
+    assert_eq!((frame.width, frame.height), (200, 200));
+    assert_eq!(pixel_at(&mut window, 50, 50), RED);
+
+    let cursor = CursorPosition::InWindow(LogicalPosition::new(50.0, 50.0));
+    window.update_window_state(|s| s.mouse_state.cursor_position = cursor);
+    window.update_window_state(|s| s.mouse_state.left_down = true);
+    window.update_window_state(|s| s.mouse_state.left_down = false);
+
+    let mut data = data;
+    assert_eq!(data.downcast_ref::<Counter>().map(|c| c.clicks), Some(1));
+    assert_eq!(pixel_at(&mut window, 50, 50), BLUE);
+    // pixels outside of the clicked node are not painted by the DOM
+    assert_ne!(pixel_at(&mut window, 150, 150), BLUE);
+}
+
+struct IFrameInvocations {
+    outer: usize,
+    inner: usize,
+    /// Virtual scroll offset of the last outer IFrame invocation
+    outer_offset: f32,
+}
+
+extern "C" fn iframe_layout(data: &mut RefAny, _: LayoutCallbackInfo) -> StyledDom {
+    Dom::body()
+    .with_children(vec![
+        Dom::iframe(data.clone(), render_outer_iframe)
+        .with_inline_css_props(vec![
+            NodeDataInlineCssProperty::Normal(CssProperty::width(LayoutWidth::px(200.0))),
+            NodeDataInlineCssProperty::Normal(CssProperty::height(LayoutHeight::px(200.0))),
+        ].into())
+    ].into())
+    .style(&mut Css::empty())
+}
+
+// Virtual list of 10000px height, only renders 400px starting at the scroll position
+extern "C" fn render_outer_iframe(data: &mut RefAny, info: IFrameCallbackInfo) -> IFrameCallbackReturn {
+
+    let inner_data = data.clone();
+    let offset = info.virtual_scroll_offset.y + info.scroll_offset.y;
+
+    if let Some(mut invocations) = data.downcast_mut::<IFrameInvocations>() {
+        invocations.outer += 1;
+        invocations.outer_offset = offset;
+    }
+
+    let dom = Dom::body()
+    .with_children(vec![
+        Dom::iframe(inner_data, render_inner_iframe)
+        .with_inline_css_props(vec![
+            NodeDataInlineCssProperty::Normal(CssProperty::width(LayoutWidth::px(100.0))),
+            NodeDataInlineCssProperty::Normal(CssProperty::height(LayoutHeight::px(100.0))),
+        ].into()),
+        Dom::div()
+        .with_inline_css_props(vec![
+            NodeDataInlineCssProperty::Normal(CssProperty::height(LayoutHeight::px(300.0))),
+        ].into()),
+    ].into())
+    .style(&mut Css::empty());
+
+    IFrameCallbackReturn {
+        dom,
+        scroll_size: LogicalSize::new(200.0, 400.0),
+        scroll_offset: LogicalPosition::zero(),
+        virtual_scroll_size: LogicalSize::new(200.0, 10000.0),
+        virtual_scroll_offset: LogicalPosition::new(0.0, offset),
+    }
+}
+
+extern "C" fn render_inner_iframe(data: &mut RefAny, _: IFrameCallbackInfo) -> IFrameCallbackReturn {
+    if let Some(mut invocations) = data.downcast_mut::<IFrameInvocations>() {
+        invocations.inner += 1;
+    }
+    IFrameCallbackReturn {
+        dom: Dom::body().style(&mut Css::empty()),
+        .. Default::default()
+    }
+}
+
+#[test]
+fn scrolling_reinvokes_iframe_and_nested_iframes() {
+
+    let data = RefAny::new(IFrameInvocations { outer: 0, inner: 0, outer_offset: 0.0 });
+
+    let mut options = WindowCreateOptions::new(iframe_layout);
+    options.state.size.dimensions = LogicalSize::new(200.0, 200.0);
+    options.state.size.hidpi_factor = 1.0;
+
+    let mut window = HeadlessWindow::new(
+        data.clone(),
+        AppConfig::default(LayoutSolverVersion::March2021),
+        ImageCache::new(),
+        options,
+    );
+
+    let mut data = data;
+    let get_invocations = |data: &mut RefAny| data.downcast_ref::<IFrameInvocations>().map(|i| (i.outer, i.inner, i.outer_offset));
+    assert_eq!(get_invocations(&mut data), Some((1, 1, 0.0)));
+
+    // hover the outer IFrame below the nested IFrame
+    let cursor = CursorPosition::InWindow(LogicalPosition::new(150.0, 150.0));
+    window.update_window_state(|s| s.mouse_state.cursor_position = cursor);
+
+    // still inside of the rendered 400px: no re-invocation
+    window.update_window_state(|s| s.mouse_state.scroll_y = OptionF32::Some(100.0));
+    assert_eq!(get_invocations(&mut data), Some((1, 1, 0.0)));
+
+    // scrolled past the rendered DOM: the outer IFrame and the
+    // IFrame nested in its previous DOM are invoked again
+    window.update_window_state(|s| s.mouse_state.scroll_y = OptionF32::Some(500.0));
+    assert_eq!(get_invocations(&mut data), Some((2, 2, 600.0)));
+}
+
+#[cfg(feature = "css_parser")]
+struct LayoutInvocations {
+    count: usize,
+}
+
+#[cfg(feature = "css_parser")]
+extern "C" fn media_layout(data: &mut RefAny, _: LayoutCallbackInfo) -> StyledDom {
+
+    if let Some(mut invocations) = data.downcast_mut::<LayoutInvocations>() {
+        invocations.count += 1;
+    }
+
+    let css = "
+        .a { width: 100px; height: 100px; background: red; }
+        @media (min-width: 300px) { .a { background: blue; } }
+    ";
+
+    Dom::body()
+    .with_children(vec![
+        Dom::div().with_ids_and_classes(vec![azul_core::dom::IdOrClass::Class("a".into())].into())
+    ].into())
+    .style(&mut azul_css_parser::new_from_str(css).unwrap())
+}
+
+#[cfg(feature = "css_parser")]
+#[test]
+fn crossing_breakpoint_restyles_without_layout_callback() {
+
+    let data = RefAny::new(LayoutInvocations { count: 0 });
+
+    let mut options = WindowCreateOptions::new(media_layout);
+    options.state.size.dimensions = LogicalSize::new(200.0, 200.0);
+    options.state.size.hidpi_factor = 1.0;
+
+    let mut window = HeadlessWindow::new(
+        data.clone(),
+        AppConfig::default(LayoutSolverVersion::March2021),
+        ImageCache::new(),
+        options,
+    );
+
+    let mut data = data;
+    let get_count = |data: &mut RefAny| data.downcast_ref::<LayoutInvocations>().map(|i| i.count);
+    assert_eq!(get_count(&mut data), Some(1));
+    assert_eq!(pixel_at(&mut window, 50, 50), RED);
+
+    // the `@media` rule applies, but the DOM is not regenerated
+    window.update_window_state(|s| s.size.dimensions = LogicalSize::new(400.0, 200.0));
+    assert_eq!(get_count(&mut data), Some(1));
+    assert_eq!(pixel_at(&mut window, 50, 50), BLUE);
+
+    window.update_window_state(|s| s.size.dimensions = LogicalSize::new(200.0, 200.0));
+    assert_eq!(get_count(&mut data), Some(1));
+    assert_eq!(pixel_at(&mut window, 50, 50), RED);
+}
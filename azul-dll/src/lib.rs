@@ -9,6 +9,7 @@ pub mod widgets;
 #[cfg(feature = "python-extension")]
 pub mod python;
 
+extern crate alloc;
 extern crate azul_core;
 
 #[cfg(target_arch = "wasm32")]
@@ -364,6 +365,8 @@ pub use AzCallbackInfoTT as AzCallbackInfo;
 #[no_mangle] pub extern "C" fn AzCallbackInfo_setFocus(callbackinfo: &mut AzCallbackInfo, target: AzFocusTarget) { callbackinfo.set_focus(target); }
 /// Sets a `CssProperty` on a given node to its new value. If this property change affects the layout, this will automatically trigger a relayout and redraw of the screen.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_setCssProperty(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_property: AzCssProperty) { callbackinfo.set_css_property(node_id, new_property);  }
+/// Sets the value of the CSS variable `--name` on the given node and all of its children (which inherit the variable), i.e. setting a variable on the root node switches the theme of the entire window. All `var(--name)` references are re-resolved before the next frame. An empty value removes the variable again.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setCssVariable(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, name: AzString, value: AzString) { callbackinfo.set_css_variable(node_id, name, value) }
 /// Sets the scroll position of the node
 #[no_mangle] pub extern "C" fn AzCallbackInfo_setScrollPosition(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) { callbackinfo.set_scroll_amount(node_id, scroll_position) }
 /// If the node is a `Text` node, overwrites the `Text` content with the new string, without requiring the entire UI to be rebuilt.
@@ -680,6 +683,10 @@ pub use AzDomTT as AzDom;
 #[no_mangle] pub extern "C" fn AzDom_setClipMask(dom: &mut AzDom, clip_mask: AzImageMask) { dom.root.set_clip_mask(clip_mask) }
 /// Same as set_clip_mask, but as a builder method
 #[no_mangle] pub extern "C" fn AzDom_withClipMask(dom: &mut AzDom, clip_mask: AzImageMask) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_clip_mask(clip_mask); dom }
+/// Sets a string attribute (i.e. `data-state: open`) on the DOM root node, matched by CSS attribute selectors (`[data-state=open]`)
+#[no_mangle] pub extern "C" fn AzDom_setAttribute(dom: &mut AzDom, name: AzString, value: AzString) { dom.root.set_attribute(name, value) }
+/// Same as set_attribute, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withAttribute(dom: &mut AzDom, name: AzString, value: AzString) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_attribute(name, value); dom }
 /// Sets the tab index for the DOM root node.
 #[no_mangle] pub extern "C" fn AzDom_setTabIndex(dom: &mut AzDom, tab_index: AzTabIndex) { dom.root.set_tab_index(tab_index) }
 /// Same as set_tab_index, but as a builder method
@@ -764,6 +771,8 @@ pub use AzNodeDataTT as AzNodeData;
 #[no_mangle] pub extern "C" fn AzNodeData_withInlineCssProps(nodedata: &mut AzNodeData, css_properties: AzNodeDataInlineCssPropertyVec) -> AzNodeData { let mut nodedata = nodedata.swap_with_default(); nodedata.set_inline_css_props(css_properties); nodedata }
 /// Sets the `extra.clip_mask` field for this node
 #[no_mangle] pub extern "C" fn AzNodeData_setClipMask(nodedata: &mut AzNodeData, image_mask: AzImageMask) { nodedata.set_clip_mask(image_mask) }
+/// Sets a string attribute (i.e. `data-state: open`) on this node, matched by CSS attribute selectors (`[data-state=open]`)
+#[no_mangle] pub extern "C" fn AzNodeData_setAttribute(nodedata: &mut AzNodeData, name: AzString, value: AzString) { nodedata.set_attribute(name, value) }
 /// Sets the tab index for this node
 #[no_mangle] pub extern "C" fn AzNodeData_setTabIndex(nodedata: &mut AzNodeData, tab_index: AzTabIndex) { nodedata.set_tab_index(tab_index) }
 /// Sets accessibility attributes for this node
@@ -825,6 +834,18 @@ pub use AzAccessibilityRoleTT as AzAccessibilityRole;
 pub type AzAccessibilityStateTT = azul_impl::dom::AccessibilityState;
 pub use AzAccessibilityStateTT as AzAccessibilityState;
 
+/// Single node in the accessibility tree of a window, see `AccessibilityTree`
+pub type AzAccessibilityNodeTT = azul_core::accessibility::AccessibilityNode;
+pub use AzAccessibilityNodeTT as AzAccessibilityNode;
+/// Returns whether the node has the given accessibility state
+#[no_mangle] pub extern "C" fn AzAccessibilityNode_hasState(accessibilitynode: &AzAccessibilityNode, state: AzAccessibilityState) -> bool { accessibilitynode.has_state(state) }
+
+/// Platform-neutral snapshot of the accessibility tree of all DOMs in a window, can be fed into an assistive-technology bridge (AT-SPI, UIA, NSAccessibility)
+pub type AzAccessibilityTreeTT = azul_core::accessibility::AccessibilityTree;
+pub use AzAccessibilityTreeTT as AzAccessibilityTree;
+/// Returns a copy of the node that currently has the keyboard focus, if any
+#[no_mangle] pub extern "C" fn AzAccessibilityTree_getFocusedNode(accessibilitytree: &AzAccessibilityTree) -> AzOptionAccessibilityNode { accessibilitytree.get_focused_node_cloned() }
+
 /// Re-export of rust-allocated (stack based) `TabIndex` struct
 pub type AzTabIndexTT = azul_impl::dom::TabIndex;
 pub use AzTabIndexTT as AzTabIndex;
@@ -869,6 +890,14 @@ pub use AzVirtualKeyCodeComboTT as AzVirtualKeyCodeCombo;
 pub type AzMenuCallbackTT = azul_core::window::MenuCallback;
 pub use AzMenuCallbackTT as AzMenuCallback;
 
+/// Window-wide keyboard shortcut that is not attached to a menu item
+pub type AzKeyboardShortcutTT = azul_core::window::KeyboardShortcut;
+pub use AzKeyboardShortcutTT as AzKeyboardShortcut;
+
+/// Returned when a keyboard shortcut can't be registered because its key combination is already in use
+pub type AzShortcutConflictTT = azul_core::window::ShortcutConflict;
+pub use AzShortcutConflictTT as AzShortcutConflict;
+
 /// Icon of a menu entry
 pub type AzMenuItemIconTT = azul_core::window::MenuItemIcon;
 pub use AzMenuItemIconTT as AzMenuItemIcon;
@@ -881,6 +910,22 @@ pub use AzMenuItemStateTT as AzMenuItemState;
 pub type AzCssRuleBlockTT = azul_impl::css::CssRuleBlock;
 pub use AzCssRuleBlockTT as AzCssRuleBlock;
 
+/// One query of an `@media` rule, matches if all conditions match
+pub type AzCssMediaQueryTT = azul_impl::css::CssMediaQuery;
+pub use AzCssMediaQueryTT as AzCssMediaQuery;
+
+/// Re-export of rust-allocated (stack based) `CssMediaCondition` struct
+pub type AzCssMediaConditionTT = azul_impl::css::CssMediaCondition;
+pub use AzCssMediaConditionTT as AzCssMediaCondition;
+
+/// Re-export of rust-allocated (stack based) `CssMediaOrientation` struct
+pub type AzCssMediaOrientationTT = azul_impl::css::CssMediaOrientation;
+pub use AzCssMediaOrientationTT as AzCssMediaOrientation;
+
+/// Re-export of rust-allocated (stack based) `CssMediaColorScheme` struct
+pub type AzCssMediaColorSchemeTT = azul_impl::css::CssMediaColorScheme;
+pub use AzCssMediaColorSchemeTT as AzCssMediaColorScheme;
+
 /// Re-export of rust-allocated (stack based) `CssDeclaration` struct
 pub type AzCssDeclarationTT = azul_impl::css::CssDeclaration;
 pub use AzCssDeclarationTT as AzCssDeclaration;
@@ -889,6 +934,10 @@ pub use AzCssDeclarationTT as AzCssDeclaration;
 pub type AzDynamicCssPropertyTT = azul_impl::css::DynamicCssProperty;
 pub use AzDynamicCssPropertyTT as AzDynamicCssProperty;
 
+/// Custom property (CSS variable) declaration, i.e. `--accent: #3366ff`
+pub type AzCssCustomPropertyTT = azul_impl::css::CssCustomProperty;
+pub use AzCssCustomPropertyTT as AzCssCustomProperty;
+
 /// Re-export of rust-allocated (stack based) `CssPath` struct
 pub type AzCssPathTT = azul_impl::css::CssPath;
 pub use AzCssPathTT as AzCssPath;
@@ -905,6 +954,14 @@ pub use AzNodeTypeKeyTT as AzNodeTypeKey;
 pub type AzCssPathPseudoSelectorTT = azul_impl::css::CssPathPseudoSelector;
 pub use AzCssPathPseudoSelectorTT as AzCssPathPseudoSelector;
 
+/// Attribute selector such as `[data-state=open]`, matched against the attributes of a node
+pub type AzCssAttributeSelectorTT = azul_impl::css::CssAttributeSelector;
+pub use AzCssAttributeSelectorTT as AzCssAttributeSelector;
+
+/// Re-export of rust-allocated (stack based) `CssAttributeSelectorOp` struct
+pub type AzCssAttributeSelectorOpTT = azul_impl::css::CssAttributeSelectorOp;
+pub use AzCssAttributeSelectorOpTT as AzCssAttributeSelectorOp;
+
 /// Re-export of rust-allocated (stack based) `CssNthChildSelector` struct
 pub type AzCssNthChildSelectorTT = azul_impl::css::CssNthChildSelector;
 pub use AzCssNthChildSelectorTT as AzCssNthChildSelector;
@@ -925,6 +982,22 @@ pub use AzCssTT as AzCss;
 /// Returns a CSS style parsed from a `String`
 #[no_mangle] pub extern "C" fn AzCss_fromString(s: AzString) -> AzCss { azul_impl::css::from_str(s.as_str()).unwrap_or_default() }
 
+/// Font file declared via an `@font-face` rule
+pub type AzCssFontFaceTT = azul_impl::css::CssFontFace;
+pub use AzCssFontFaceTT as AzCssFontFace;
+
+/// Re-export of rust-allocated (stack based) `CssFontFaceStyle` struct
+pub type AzCssFontFaceStyleTT = azul_impl::css::CssFontFaceStyle;
+pub use AzCssFontFaceStyleTT as AzCssFontFaceStyle;
+
+/// Animation declared via a `@keyframes` rule
+pub type AzCssKeyframesTT = azul_impl::css::CssKeyframes;
+pub use AzCssKeyframesTT as AzCssKeyframes;
+
+/// Single step (i.e. `50% { opacity: 0.5; }`) of a `@keyframes` rule
+pub type AzCssKeyframeTT = azul_impl::css::CssKeyframe;
+pub use AzCssKeyframeTT as AzCssKeyframe;
+
 /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
 pub type AzCssPropertyTypeTT = azul_impl::css::CssPropertyType;
 pub use AzCssPropertyTypeTT as AzCssPropertyType;
@@ -974,6 +1047,46 @@ pub use AzStyleBoxShadowTT as AzStyleBoxShadow;
 pub type AzLayoutAlignContentTT = azul_impl::css::LayoutAlignContent;
 pub use AzLayoutAlignContentTT as AzLayoutAlignContent;
 
+/// Re-export of rust-allocated (stack based) `GridTrackBreadth` struct
+pub type AzGridTrackBreadthTT = azul_impl::css::GridTrackBreadth;
+pub use AzGridTrackBreadthTT as AzGridTrackBreadth;
+
+/// `minmax(min, max)` track size
+pub type AzGridMinMaxTT = azul_impl::css::GridMinMax;
+pub use AzGridMinMaxTT as AzGridMinMax;
+
+/// Size of a single column / row track of a `grid-template-*` declaration
+pub type AzGridTrackSizingTT = azul_impl::css::GridTrackSizing;
+pub use AzGridTrackSizingTT as AzGridTrackSizing;
+
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumns` struct
+pub type AzLayoutGridTemplateColumnsTT = azul_impl::css::LayoutGridTemplateColumns;
+pub use AzLayoutGridTemplateColumnsTT as AzLayoutGridTemplateColumns;
+
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateRows` struct
+pub type AzLayoutGridTemplateRowsTT = azul_impl::css::LayoutGridTemplateRows;
+pub use AzLayoutGridTemplateRowsTT as AzLayoutGridTemplateRows;
+
+/// Start or end line of a grid item (`auto`, `2` or `span 2`)
+pub type AzGridLineTT = azul_impl::css::GridLine;
+pub use AzGridLineTT as AzGridLine;
+
+/// Re-export of rust-allocated (stack based) `LayoutGridColumn` struct
+pub type AzLayoutGridColumnTT = azul_impl::css::LayoutGridColumn;
+pub use AzLayoutGridColumnTT as AzLayoutGridColumn;
+
+/// Re-export of rust-allocated (stack based) `LayoutGridRow` struct
+pub type AzLayoutGridRowTT = azul_impl::css::LayoutGridRow;
+pub use AzLayoutGridRowTT as AzLayoutGridRow;
+
+/// Re-export of rust-allocated (stack based) `LayoutColumnGap` struct
+pub type AzLayoutColumnGapTT = azul_impl::css::LayoutColumnGap;
+pub use AzLayoutColumnGapTT as AzLayoutColumnGap;
+
+/// Re-export of rust-allocated (stack based) `LayoutRowGap` struct
+pub type AzLayoutRowGapTT = azul_impl::css::LayoutRowGap;
+pub use AzLayoutRowGapTT as AzLayoutRowGap;
+
 /// Re-export of rust-allocated (stack based) `LayoutAlignItems` struct
 pub type AzLayoutAlignItemsTT = azul_impl::css::LayoutAlignItems;
 pub use AzLayoutAlignItemsTT as AzLayoutAlignItems;
@@ -1006,6 +1119,10 @@ pub use AzLayoutFlexShrinkTT as AzLayoutFlexShrink;
 pub type AzLayoutFloatTT = azul_impl::css::LayoutFloat;
 pub use AzLayoutFloatTT as AzLayoutFloat;
 
+/// Represents a `clear` attribute: on which sides the node may not be placed next to floating nodes that come before it
+pub type AzLayoutClearTT = azul_impl::css::LayoutClear;
+pub use AzLayoutClearTT as AzLayoutClear;
+
 /// Re-export of rust-allocated (stack based) `LayoutHeight` struct
 pub type AzLayoutHeightTT = azul_impl::css::LayoutHeight;
 pub use AzLayoutHeightTT as AzLayoutHeight;
@@ -1286,6 +1403,50 @@ pub use AzStylePerspectiveOriginTT as AzStylePerspectiveOrigin;
 pub type AzStyleBackfaceVisibilityTT = azul_impl::css::StyleBackfaceVisibility;
 pub use AzStyleBackfaceVisibilityTT as AzStyleBackfaceVisibility;
 
+/// Property that is animated by a `transition` (`all` or a single property)
+pub type AzStyleTransitionPropertyTT = azul_impl::css::StyleTransitionProperty;
+pub use AzStyleTransitionPropertyTT as AzStyleTransitionProperty;
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionDuration` struct
+pub type AzStyleTransitionDurationTT = azul_impl::css::StyleTransitionDuration;
+pub use AzStyleTransitionDurationTT as AzStyleTransitionDuration;
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionDelay` struct
+pub type AzStyleTransitionDelayTT = azul_impl::css::StyleTransitionDelay;
+pub use AzStyleTransitionDelayTT as AzStyleTransitionDelay;
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunction` struct
+pub type AzStyleTransitionTimingFunctionTT = azul_impl::css::StyleTransitionTimingFunction;
+pub use AzStyleTransitionTimingFunctionTT as AzStyleTransitionTimingFunction;
+
+/// Control points of a `cubic-bezier(x1, y1, x2, y2)` timing function
+pub type AzStyleCubicBezierTT = azul_impl::css::StyleCubicBezier;
+pub use AzStyleCubicBezierTT as AzStyleCubicBezier;
+
+/// Name of the `@keyframes` rule that an `animation-name` refers to
+pub type AzStyleAnimationNameTT = azul_impl::css::StyleAnimationName;
+pub use AzStyleAnimationNameTT as AzStyleAnimationName;
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDuration` struct
+pub type AzStyleAnimationDurationTT = azul_impl::css::StyleAnimationDuration;
+pub use AzStyleAnimationDurationTT as AzStyleAnimationDuration;
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDelay` struct
+pub type AzStyleAnimationDelayTT = azul_impl::css::StyleAnimationDelay;
+pub use AzStyleAnimationDelayTT as AzStyleAnimationDelay;
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationIterationCount` struct
+pub type AzStyleAnimationIterationCountTT = azul_impl::css::StyleAnimationIterationCount;
+pub use AzStyleAnimationIterationCountTT as AzStyleAnimationIterationCount;
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDirection` struct
+pub type AzStyleAnimationDirectionTT = azul_impl::css::StyleAnimationDirection;
+pub use AzStyleAnimationDirectionTT as AzStyleAnimationDirection;
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationFillMode` struct
+pub type AzStyleAnimationFillModeTT = azul_impl::css::StyleAnimationFillMode;
+pub use AzStyleAnimationFillModeTT as AzStyleAnimationFillMode;
+
 /// Re-export of rust-allocated (stack based) `StyleTransform` struct
 pub type AzStyleTransformTT = azul_impl::css::StyleTransform;
 pub use AzStyleTransformTT as AzStyleTransform;
@@ -1342,6 +1503,30 @@ pub use AzStyleBoxShadowValueTT as AzStyleBoxShadowValue;
 pub type AzLayoutAlignContentValueTT = azul_impl::css::LayoutAlignContentValue;
 pub use AzLayoutAlignContentValueTT as AzLayoutAlignContentValue;
 
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumnsValue` struct
+pub type AzLayoutGridTemplateColumnsValueTT = azul_impl::css::LayoutGridTemplateColumnsValue;
+pub use AzLayoutGridTemplateColumnsValueTT as AzLayoutGridTemplateColumnsValue;
+
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateRowsValue` struct
+pub type AzLayoutGridTemplateRowsValueTT = azul_impl::css::LayoutGridTemplateRowsValue;
+pub use AzLayoutGridTemplateRowsValueTT as AzLayoutGridTemplateRowsValue;
+
+/// Re-export of rust-allocated (stack based) `LayoutGridColumnValue` struct
+pub type AzLayoutGridColumnValueTT = azul_impl::css::LayoutGridColumnValue;
+pub use AzLayoutGridColumnValueTT as AzLayoutGridColumnValue;
+
+/// Re-export of rust-allocated (stack based) `LayoutGridRowValue` struct
+pub type AzLayoutGridRowValueTT = azul_impl::css::LayoutGridRowValue;
+pub use AzLayoutGridRowValueTT as AzLayoutGridRowValue;
+
+/// Re-export of rust-allocated (stack based) `LayoutColumnGapValue` struct
+pub type AzLayoutColumnGapValueTT = azul_impl::css::LayoutColumnGapValue;
+pub use AzLayoutColumnGapValueTT as AzLayoutColumnGapValue;
+
+/// Re-export of rust-allocated (stack based) `LayoutRowGapValue` struct
+pub type AzLayoutRowGapValueTT = azul_impl::css::LayoutRowGapValue;
+pub use AzLayoutRowGapValueTT as AzLayoutRowGapValue;
+
 /// Re-export of rust-allocated (stack based) `LayoutAlignItemsValue` struct
 pub type AzLayoutAlignItemsValueTT = azul_impl::css::LayoutAlignItemsValue;
 pub use AzLayoutAlignItemsValueTT as AzLayoutAlignItemsValue;
@@ -1374,6 +1559,10 @@ pub use AzLayoutFlexShrinkValueTT as AzLayoutFlexShrinkValue;
 pub type AzLayoutFloatValueTT = azul_impl::css::LayoutFloatValue;
 pub use AzLayoutFloatValueTT as AzLayoutFloatValue;
 
+/// Re-export of rust-allocated (stack based) `LayoutClearValue` struct
+pub type AzLayoutClearValueTT = azul_impl::css::LayoutClearValue;
+pub use AzLayoutClearValueTT as AzLayoutClearValue;
+
 /// Re-export of rust-allocated (stack based) `LayoutHeightValue` struct
 pub type AzLayoutHeightValueTT = azul_impl::css::LayoutHeightValue;
 pub use AzLayoutHeightValueTT as AzLayoutHeightValue;
@@ -1598,6 +1787,46 @@ pub use AzStylePerspectiveOriginValueTT as AzStylePerspectiveOriginValue;
 pub type AzStyleBackfaceVisibilityValueTT = azul_impl::css::StyleBackfaceVisibilityValue;
 pub use AzStyleBackfaceVisibilityValueTT as AzStyleBackfaceVisibilityValue;
 
+/// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecValue` struct
+pub type AzStyleTransitionPropertyVecValueTT = azul_impl::css::StyleTransitionPropertyVecValue;
+pub use AzStyleTransitionPropertyVecValueTT as AzStyleTransitionPropertyVecValue;
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionDurationVecValue` struct
+pub type AzStyleTransitionDurationVecValueTT = azul_impl::css::StyleTransitionDurationVecValue;
+pub use AzStyleTransitionDurationVecValueTT as AzStyleTransitionDurationVecValue;
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunctionVecValue` struct
+pub type AzStyleTransitionTimingFunctionVecValueTT = azul_impl::css::StyleTransitionTimingFunctionVecValue;
+pub use AzStyleTransitionTimingFunctionVecValueTT as AzStyleTransitionTimingFunctionVecValue;
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionDelayVecValue` struct
+pub type AzStyleTransitionDelayVecValueTT = azul_impl::css::StyleTransitionDelayVecValue;
+pub use AzStyleTransitionDelayVecValueTT as AzStyleTransitionDelayVecValue;
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationNameVecValue` struct
+pub type AzStyleAnimationNameVecValueTT = azul_impl::css::StyleAnimationNameVecValue;
+pub use AzStyleAnimationNameVecValueTT as AzStyleAnimationNameVecValue;
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDurationVecValue` struct
+pub type AzStyleAnimationDurationVecValueTT = azul_impl::css::StyleAnimationDurationVecValue;
+pub use AzStyleAnimationDurationVecValueTT as AzStyleAnimationDurationVecValue;
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecValue` struct
+pub type AzStyleAnimationIterationCountVecValueTT = azul_impl::css::StyleAnimationIterationCountVecValue;
+pub use AzStyleAnimationIterationCountVecValueTT as AzStyleAnimationIterationCountVecValue;
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecValue` struct
+pub type AzStyleAnimationDirectionVecValueTT = azul_impl::css::StyleAnimationDirectionVecValue;
+pub use AzStyleAnimationDirectionVecValueTT as AzStyleAnimationDirectionVecValue;
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecValue` struct
+pub type AzStyleAnimationFillModeVecValueTT = azul_impl::css::StyleAnimationFillModeVecValue;
+pub use AzStyleAnimationFillModeVecValueTT as AzStyleAnimationFillModeVecValue;
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDelayVecValue` struct
+pub type AzStyleAnimationDelayVecValueTT = azul_impl::css::StyleAnimationDelayVecValue;
+pub use AzStyleAnimationDelayVecValueTT as AzStyleAnimationDelayVecValue;
+
 /// Parsed CSS key-value pair
 pub type AzCssPropertyTT = azul_impl::css::CssProperty;
 pub use AzCssPropertyTT as AzCssProperty;
@@ -1804,6 +2033,359 @@ pub type AzNumberInputOnValueChangeCallbackTT = crate::widgets::number_input::Nu
 pub use AzNumberInputOnValueChangeCallbackTT as AzNumberInputOnValueChangeCallback;
 
 pub type AzNumberInputOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzNumberInputState, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TextEdit` struct
+pub type AzTextEditTT = crate::widgets::text_edit::TextEdit;
+pub use AzTextEditTT as AzTextEdit;
+/// Creates a new `TextEdit` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TextEdit::new()` constructor.
+#[no_mangle] pub extern "C" fn AzTextEdit_new(initial_text: AzString) -> AzTextEdit { AzTextEdit::new(initial_text) }
+/// Equivalent to the Rust `TextEdit::set_on_text_change()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_setOnTextChange(textedit: &mut AzTextEdit, data: AzRefAny, callback: AzTextEditOnTextChangeCallbackType) { textedit.set_on_text_change(data, callback) }
+/// Equivalent to the Rust `TextEdit::with_on_text_change()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_withOnTextChange(textedit: &mut AzTextEdit, data: AzRefAny, callback: AzTextEditOnTextChangeCallbackType) -> AzTextEdit { let mut textedit = textedit.swap_with_default(); textedit.set_on_text_change(data, callback); textedit }
+/// Equivalent to the Rust `TextEdit::set_on_focus_lost()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_setOnFocusLost(textedit: &mut AzTextEdit, data: AzRefAny, callback: AzTextEditOnFocusLostCallbackType) { textedit.set_on_focus_lost(data, callback) }
+/// Equivalent to the Rust `TextEdit::with_on_focus_lost()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_withOnFocusLost(textedit: &mut AzTextEdit, data: AzRefAny, callback: AzTextEditOnFocusLostCallbackType) -> AzTextEdit { let mut textedit = textedit.swap_with_default(); textedit.set_on_focus_lost(data, callback); textedit }
+/// Equivalent to the Rust `TextEdit::set_show_line_numbers()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_setShowLineNumbers(textedit: &mut AzTextEdit, show_line_numbers: bool) { textedit.set_show_line_numbers(show_line_numbers) }
+/// Equivalent to the Rust `TextEdit::with_show_line_numbers()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_withShowLineNumbers(textedit: &mut AzTextEdit, show_line_numbers: bool) -> AzTextEdit { let mut textedit = textedit.swap_with_default(); textedit.set_show_line_numbers(show_line_numbers); textedit }
+/// Equivalent to the Rust `TextEdit::set_container_style()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_setContainerStyle(textedit: &mut AzTextEdit, container_style: AzNodeDataInlineCssPropertyVec) { textedit.set_container_style(container_style) }
+/// Equivalent to the Rust `TextEdit::with_container_style()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_withContainerStyle(textedit: &mut AzTextEdit, container_style: AzNodeDataInlineCssPropertyVec) -> AzTextEdit { let mut textedit = textedit.swap_with_default(); textedit.set_container_style(container_style); textedit }
+/// Equivalent to the Rust `TextEdit::set_line_numbers_style()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_setLineNumbersStyle(textedit: &mut AzTextEdit, line_numbers_style: AzNodeDataInlineCssPropertyVec) { textedit.set_line_numbers_style(line_numbers_style) }
+/// Equivalent to the Rust `TextEdit::with_line_numbers_style()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_withLineNumbersStyle(textedit: &mut AzTextEdit, line_numbers_style: AzNodeDataInlineCssPropertyVec) -> AzTextEdit { let mut textedit = textedit.swap_with_default(); textedit.set_line_numbers_style(line_numbers_style); textedit }
+/// Equivalent to the Rust `TextEdit::set_label_style()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_setLabelStyle(textedit: &mut AzTextEdit, label_style: AzNodeDataInlineCssPropertyVec) { textedit.set_label_style(label_style) }
+/// Equivalent to the Rust `TextEdit::with_label_style()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_withLabelStyle(textedit: &mut AzTextEdit, label_style: AzNodeDataInlineCssPropertyVec) -> AzTextEdit { let mut textedit = textedit.swap_with_default(); textedit.set_label_style(label_style); textedit }
+/// Equivalent to the Rust `TextEdit::dom()` function.
+#[no_mangle] pub extern "C" fn AzTextEdit_dom(textedit: &mut AzTextEdit) -> AzDom { textedit.swap_with_default().dom() }
+
+/// Re-export of rust-allocated (stack based) `TextEditStateWrapper` struct
+pub type AzTextEditStateWrapperTT = crate::widgets::text_edit::TextEditStateWrapper;
+pub use AzTextEditStateWrapperTT as AzTextEditStateWrapper;
+
+/// Re-export of rust-allocated (stack based) `TextEditState` struct
+pub type AzTextEditStateTT = crate::widgets::text_edit::TextEditState;
+pub use AzTextEditStateTT as AzTextEditState;
+
+/// Re-export of rust-allocated (stack based) `TextEditHistory` struct
+pub type AzTextEditHistoryTT = crate::widgets::text_edit::TextEditHistory;
+pub use AzTextEditHistoryTT as AzTextEditHistory;
+
+/// Re-export of rust-allocated (stack based) `TextEditSnapshot` struct
+pub type AzTextEditSnapshotTT = crate::widgets::text_edit::TextEditSnapshot;
+pub use AzTextEditSnapshotTT as AzTextEditSnapshot;
+
+/// Re-export of rust-allocated (stack based) `TextEditKind` struct
+pub type AzTextEditKindTT = crate::widgets::text_edit::TextEditKind;
+pub use AzTextEditKindTT as AzTextEditKind;
+
+/// Re-export of rust-allocated (stack based) `TextEditView` struct
+pub type AzTextEditViewTT = crate::widgets::text_edit::TextEditView;
+pub use AzTextEditViewTT as AzTextEditView;
+
+/// Re-export of rust-allocated (stack based) `TextEditOnTextChange` struct
+pub type AzTextEditOnTextChangeTT = crate::widgets::text_edit::TextEditOnTextChange;
+pub use AzTextEditOnTextChangeTT as AzTextEditOnTextChange;
+
+/// Re-export of rust-allocated (stack based) `TextEditOnTextChangeCallback` struct
+pub type AzTextEditOnTextChangeCallbackTT = crate::widgets::text_edit::TextEditOnTextChangeCallback;
+pub use AzTextEditOnTextChangeCallbackTT as AzTextEditOnTextChangeCallback;
+
+pub type AzTextEditOnTextChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTextEditState, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TextEditOnFocusLost` struct
+pub type AzTextEditOnFocusLostTT = crate::widgets::text_edit::TextEditOnFocusLost;
+pub use AzTextEditOnFocusLostTT as AzTextEditOnFocusLost;
+
+/// Re-export of rust-allocated (stack based) `TextEditOnFocusLostCallback` struct
+pub type AzTextEditOnFocusLostCallbackTT = crate::widgets::text_edit::TextEditOnFocusLostCallback;
+pub use AzTextEditOnFocusLostCallbackTT as AzTextEditOnFocusLostCallback;
+
+pub type AzTextEditOnFocusLostCallbackType = extern "C" fn(&mut AzRefAny, &AzTextEditState, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `Slider` struct
+pub type AzSliderTT = crate::widgets::slider::Slider;
+pub use AzSliderTT as AzSlider;
+/// Creates a new `Slider` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Slider::new()` constructor.
+#[no_mangle] pub extern "C" fn AzSlider_new(value: f32, min: f32, max: f32) -> AzSlider { AzSlider::new(value, min, max) }
+/// Equivalent to the Rust `Slider::set_step()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setStep(slider: &mut AzSlider, step: f32) { slider.set_step(step) }
+/// Equivalent to the Rust `Slider::with_step()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withStep(slider: &mut AzSlider, step: f32) -> AzSlider { let mut slider = slider.swap_with_default(); slider.set_step(step); slider }
+/// Equivalent to the Rust `Slider::set_orientation()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setOrientation(slider: &mut AzSlider, orientation: AzSliderOrientation) { slider.set_orientation(orientation) }
+/// Equivalent to the Rust `Slider::with_orientation()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withOrientation(slider: &mut AzSlider, orientation: AzSliderOrientation) -> AzSlider { let mut slider = slider.swap_with_default(); slider.set_orientation(orientation); slider }
+/// Equivalent to the Rust `Slider::set_tick_count()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setTickCount(slider: &mut AzSlider, tick_count: usize) { slider.set_tick_count(tick_count) }
+/// Equivalent to the Rust `Slider::with_tick_count()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withTickCount(slider: &mut AzSlider, tick_count: usize) -> AzSlider { let mut slider = slider.swap_with_default(); slider.set_tick_count(tick_count); slider }
+/// Equivalent to the Rust `Slider::set_show_tick_labels()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setShowTickLabels(slider: &mut AzSlider, show_tick_labels: bool) { slider.set_show_tick_labels(show_tick_labels) }
+/// Equivalent to the Rust `Slider::with_show_tick_labels()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withShowTickLabels(slider: &mut AzSlider, show_tick_labels: bool) -> AzSlider { let mut slider = slider.swap_with_default(); slider.set_show_tick_labels(show_tick_labels); slider }
+/// Equivalent to the Rust `Slider::set_on_value_change()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setOnValueChange(slider: &mut AzSlider, data: AzRefAny, callback: AzSliderOnValueChangeCallbackType) { slider.set_on_value_change(data, callback) }
+/// Equivalent to the Rust `Slider::with_on_value_change()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withOnValueChange(slider: &mut AzSlider, data: AzRefAny, callback: AzSliderOnValueChangeCallbackType) -> AzSlider { let mut slider = slider.swap_with_default(); slider.set_on_value_change(data, callback); slider }
+/// Equivalent to the Rust `Slider::set_container_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setContainerStyle(slider: &mut AzSlider, container_style: AzNodeDataInlineCssPropertyVec) { slider.set_container_style(container_style) }
+/// Equivalent to the Rust `Slider::with_container_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withContainerStyle(slider: &mut AzSlider, container_style: AzNodeDataInlineCssPropertyVec) -> AzSlider { let mut slider = slider.swap_with_default(); slider.set_container_style(container_style); slider }
+/// Equivalent to the Rust `Slider::set_track_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setTrackStyle(slider: &mut AzSlider, track_style: AzNodeDataInlineCssPropertyVec) { slider.set_track_style(track_style) }
+/// Equivalent to the Rust `Slider::with_track_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withTrackStyle(slider: &mut AzSlider, track_style: AzNodeDataInlineCssPropertyVec) -> AzSlider { let mut slider = slider.swap_with_default(); slider.set_track_style(track_style); slider }
+/// Equivalent to the Rust `Slider::set_thumb_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setThumbStyle(slider: &mut AzSlider, thumb_style: AzNodeDataInlineCssPropertyVec) { slider.set_thumb_style(thumb_style) }
+/// Equivalent to the Rust `Slider::with_thumb_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withThumbStyle(slider: &mut AzSlider, thumb_style: AzNodeDataInlineCssPropertyVec) -> AzSlider { let mut slider = slider.swap_with_default(); slider.set_thumb_style(thumb_style); slider }
+/// Equivalent to the Rust `Slider::set_tick_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setTickStyle(slider: &mut AzSlider, tick_style: AzNodeDataInlineCssPropertyVec) { slider.set_tick_style(tick_style) }
+/// Equivalent to the Rust `Slider::with_tick_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withTickStyle(slider: &mut AzSlider, tick_style: AzNodeDataInlineCssPropertyVec) -> AzSlider { let mut slider = slider.swap_with_default(); slider.set_tick_style(tick_style); slider }
+/// Equivalent to the Rust `Slider::set_label_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setLabelStyle(slider: &mut AzSlider, label_style: AzNodeDataInlineCssPropertyVec) { slider.set_label_style(label_style) }
+/// Equivalent to the Rust `Slider::with_label_style()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withLabelStyle(slider: &mut AzSlider, label_style: AzNodeDataInlineCssPropertyVec) -> AzSlider { let mut slider = slider.swap_with_default(); slider.set_label_style(label_style); slider }
+/// Equivalent to the Rust `Slider::dom()` function.
+#[no_mangle] pub extern "C" fn AzSlider_dom(slider: &mut AzSlider) -> AzDom { slider.swap_with_default().dom() }
+
+/// Re-export of rust-allocated (stack based) `SliderStateWrapper` struct
+pub type AzSliderStateWrapperTT = crate::widgets::slider::SliderStateWrapper;
+pub use AzSliderStateWrapperTT as AzSliderStateWrapper;
+
+/// Re-export of rust-allocated (stack based) `SliderState` struct
+pub type AzSliderStateTT = crate::widgets::slider::SliderState;
+pub use AzSliderStateTT as AzSliderState;
+
+/// Re-export of rust-allocated (stack based) `SliderOrientation` struct
+pub type AzSliderOrientationTT = crate::widgets::slider::SliderOrientation;
+pub use AzSliderOrientationTT as AzSliderOrientation;
+
+/// Re-export of rust-allocated (stack based) `SliderOnValueChange` struct
+pub type AzSliderOnValueChangeTT = crate::widgets::slider::SliderOnValueChange;
+pub use AzSliderOnValueChangeTT as AzSliderOnValueChange;
+
+/// Re-export of rust-allocated (stack based) `SliderOnValueChangeCallback` struct
+pub type AzSliderOnValueChangeCallbackTT = crate::widgets::slider::SliderOnValueChangeCallback;
+pub use AzSliderOnValueChangeCallbackTT as AzSliderOnValueChangeCallback;
+
+pub type AzSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSliderState, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `DropDown` struct
+pub type AzDropDownTT = crate::widgets::drop_down::DropDown;
+pub use AzDropDownTT as AzDropDown;
+/// Creates a new `DropDown` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `DropDown::new()` constructor.
+#[no_mangle] pub extern "C" fn AzDropDown_new(choices: AzStringVec) -> AzDropDown { AzDropDown::new(choices) }
+/// Equivalent to the Rust `DropDown::set_selected()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setSelected(dropdown: &mut AzDropDown, selected: usize) { dropdown.set_selected(selected) }
+/// Equivalent to the Rust `DropDown::with_selected()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_withSelected(dropdown: &mut AzDropDown, selected: usize) -> AzDropDown { let mut dropdown = dropdown.swap_with_default(); dropdown.set_selected(selected); dropdown }
+/// Equivalent to the Rust `DropDown::set_disabled_choices()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setDisabledChoices(dropdown: &mut AzDropDown, disabled_choices: AzU32Vec) { dropdown.set_disabled_choices(disabled_choices) }
+/// Equivalent to the Rust `DropDown::with_disabled_choices()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_withDisabledChoices(dropdown: &mut AzDropDown, disabled_choices: AzU32Vec) -> AzDropDown { let mut dropdown = dropdown.swap_with_default(); dropdown.set_disabled_choices(disabled_choices); dropdown }
+/// Equivalent to the Rust `DropDown::set_popup_position()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setPopupPosition(dropdown: &mut AzDropDown, popup_position: AzMenuPopupPosition) { dropdown.set_popup_position(popup_position) }
+/// Equivalent to the Rust `DropDown::with_popup_position()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_withPopupPosition(dropdown: &mut AzDropDown, popup_position: AzMenuPopupPosition) -> AzDropDown { let mut dropdown = dropdown.swap_with_default(); dropdown.set_popup_position(popup_position); dropdown }
+/// Equivalent to the Rust `DropDown::set_on_choice_change()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setOnChoiceChange(dropdown: &mut AzDropDown, data: AzRefAny, callback: AzDropDownOnChoiceChangeCallbackType) { dropdown.set_on_choice_change(data, callback) }
+/// Equivalent to the Rust `DropDown::with_on_choice_change()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_withOnChoiceChange(dropdown: &mut AzDropDown, data: AzRefAny, callback: AzDropDownOnChoiceChangeCallbackType) -> AzDropDown { let mut dropdown = dropdown.swap_with_default(); dropdown.set_on_choice_change(data, callback); dropdown }
+/// Equivalent to the Rust `DropDown::set_container_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setContainerStyle(dropdown: &mut AzDropDown, container_style: AzNodeDataInlineCssPropertyVec) { dropdown.set_container_style(container_style) }
+/// Equivalent to the Rust `DropDown::with_container_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_withContainerStyle(dropdown: &mut AzDropDown, container_style: AzNodeDataInlineCssPropertyVec) -> AzDropDown { let mut dropdown = dropdown.swap_with_default(); dropdown.set_container_style(container_style); dropdown }
+/// Equivalent to the Rust `DropDown::set_label_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setLabelStyle(dropdown: &mut AzDropDown, label_style: AzNodeDataInlineCssPropertyVec) { dropdown.set_label_style(label_style) }
+/// Equivalent to the Rust `DropDown::with_label_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_withLabelStyle(dropdown: &mut AzDropDown, label_style: AzNodeDataInlineCssPropertyVec) -> AzDropDown { let mut dropdown = dropdown.swap_with_default(); dropdown.set_label_style(label_style); dropdown }
+/// Equivalent to the Rust `DropDown::set_popup_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setPopupStyle(dropdown: &mut AzDropDown, popup_style: AzNodeDataInlineCssPropertyVec) { dropdown.set_popup_style(popup_style) }
+/// Equivalent to the Rust `DropDown::with_popup_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_withPopupStyle(dropdown: &mut AzDropDown, popup_style: AzNodeDataInlineCssPropertyVec) -> AzDropDown { let mut dropdown = dropdown.swap_with_default(); dropdown.set_popup_style(popup_style); dropdown }
+/// Equivalent to the Rust `DropDown::set_entry_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setEntryStyle(dropdown: &mut AzDropDown, entry_style: AzNodeDataInlineCssPropertyVec) { dropdown.set_entry_style(entry_style) }
+/// Equivalent to the Rust `DropDown::with_entry_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_withEntryStyle(dropdown: &mut AzDropDown, entry_style: AzNodeDataInlineCssPropertyVec) -> AzDropDown { let mut dropdown = dropdown.swap_with_default(); dropdown.set_entry_style(entry_style); dropdown }
+/// Equivalent to the Rust `DropDown::set_disabled_entry_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setDisabledEntryStyle(dropdown: &mut AzDropDown, disabled_entry_style: AzNodeDataInlineCssPropertyVec) { dropdown.set_disabled_entry_style(disabled_entry_style) }
+/// Equivalent to the Rust `DropDown::with_disabled_entry_style()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_withDisabledEntryStyle(dropdown: &mut AzDropDown, disabled_entry_style: AzNodeDataInlineCssPropertyVec) -> AzDropDown { let mut dropdown = dropdown.swap_with_default(); dropdown.set_disabled_entry_style(disabled_entry_style); dropdown }
+/// Equivalent to the Rust `DropDown::dom()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_dom(dropdown: &mut AzDropDown) -> AzDom { dropdown.swap_with_default().dom() }
+
+/// Re-export of rust-allocated (stack based) `DropDownStateWrapper` struct
+pub type AzDropDownStateWrapperTT = crate::widgets::drop_down::DropDownStateWrapper;
+pub use AzDropDownStateWrapperTT as AzDropDownStateWrapper;
+
+/// Re-export of rust-allocated (stack based) `DropDownState` struct
+pub type AzDropDownStateTT = crate::widgets::drop_down::DropDownState;
+pub use AzDropDownStateTT as AzDropDownState;
+
+/// Re-export of rust-allocated (stack based) `DropDownOnChoiceChange` struct
+pub type AzDropDownOnChoiceChangeTT = crate::widgets::drop_down::DropDownOnChoiceChange;
+pub use AzDropDownOnChoiceChangeTT as AzDropDownOnChoiceChange;
+
+/// Re-export of rust-allocated (stack based) `DropDownOnChoiceChangeCallback` struct
+pub type AzDropDownOnChoiceChangeCallbackTT = crate::widgets::drop_down::DropDownOnChoiceChangeCallback;
+pub use AzDropDownOnChoiceChangeCallbackTT as AzDropDownOnChoiceChangeCallback;
+
+pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzDropDownState, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `Spreadsheet` struct
+pub type AzSpreadsheetTT = crate::widgets::spreadsheet::Spreadsheet;
+pub use AzSpreadsheetTT as AzSpreadsheet;
+/// Creates a new `Spreadsheet` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Spreadsheet::new()` constructor.
+#[no_mangle] pub extern "C" fn AzSpreadsheet_new(state: AzSpreadsheetState) -> AzSpreadsheet { AzSpreadsheet::new(state) }
+/// Equivalent to the Rust `Spreadsheet::set_on_cells_change()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheet_setOnCellsChange(spreadsheet: &mut AzSpreadsheet, data: AzRefAny, callback: AzSpreadsheetOnCellsChangeCallbackType) { spreadsheet.set_on_cells_change(data, callback) }
+/// Equivalent to the Rust `Spreadsheet::with_on_cells_change()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheet_withOnCellsChange(spreadsheet: &mut AzSpreadsheet, data: AzRefAny, callback: AzSpreadsheetOnCellsChangeCallbackType) -> AzSpreadsheet { let mut spreadsheet = spreadsheet.swap_with_default(); spreadsheet.set_on_cells_change(data, callback); spreadsheet }
+/// Equivalent to the Rust `Spreadsheet::set_on_selection_change()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheet_setOnSelectionChange(spreadsheet: &mut AzSpreadsheet, data: AzRefAny, callback: AzSpreadsheetOnSelectionChangeCallbackType) { spreadsheet.set_on_selection_change(data, callback) }
+/// Equivalent to the Rust `Spreadsheet::with_on_selection_change()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheet_withOnSelectionChange(spreadsheet: &mut AzSpreadsheet, data: AzRefAny, callback: AzSpreadsheetOnSelectionChangeCallbackType) -> AzSpreadsheet { let mut spreadsheet = spreadsheet.swap_with_default(); spreadsheet.set_on_selection_change(data, callback); spreadsheet }
+/// Equivalent to the Rust `Spreadsheet::set_on_column_resize()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheet_setOnColumnResize(spreadsheet: &mut AzSpreadsheet, data: AzRefAny, callback: AzSpreadsheetOnColumnResizeCallbackType) { spreadsheet.set_on_column_resize(data, callback) }
+/// Equivalent to the Rust `Spreadsheet::with_on_column_resize()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheet_withOnColumnResize(spreadsheet: &mut AzSpreadsheet, data: AzRefAny, callback: AzSpreadsheetOnColumnResizeCallbackType) -> AzSpreadsheet { let mut spreadsheet = spreadsheet.swap_with_default(); spreadsheet.set_on_column_resize(data, callback); spreadsheet }
+/// Equivalent to the Rust `Spreadsheet::set_container_style()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheet_setContainerStyle(spreadsheet: &mut AzSpreadsheet, style: AzNodeDataInlineCssPropertyVec) { spreadsheet.set_container_style(style) }
+/// Equivalent to the Rust `Spreadsheet::with_container_style()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheet_withContainerStyle(spreadsheet: &mut AzSpreadsheet, style: AzNodeDataInlineCssPropertyVec) -> AzSpreadsheet { let mut spreadsheet = spreadsheet.swap_with_default(); spreadsheet.set_container_style(style); spreadsheet }
+/// Equivalent to the Rust `Spreadsheet::dom()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheet_dom(spreadsheet: &mut AzSpreadsheet) -> AzDom { spreadsheet.swap_with_default().dom() }
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetStateWrapper` struct
+pub type AzSpreadsheetStateWrapperTT = crate::widgets::spreadsheet::SpreadsheetStateWrapper;
+pub use AzSpreadsheetStateWrapperTT as AzSpreadsheetStateWrapper;
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetState` struct
+pub type AzSpreadsheetStateTT = crate::widgets::spreadsheet::SpreadsheetState;
+pub use AzSpreadsheetStateTT as AzSpreadsheetState;
+/// Creates a new `SpreadsheetState` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `SpreadsheetState::new()` constructor.
+#[no_mangle] pub extern "C" fn AzSpreadsheetState_new() -> AzSpreadsheetState { AzSpreadsheetState::new() }
+/// Sets the contents of the cell, an empty string clears the cell
+#[no_mangle] pub extern "C" fn AzSpreadsheetState_setCellContent(spreadsheetstate: &mut AzSpreadsheetState, cell: AzSpreadsheetCellIndex, value: AzString) { spreadsheetstate.set_cell_content(cell, value) }
+/// Equivalent to the Rust `SpreadsheetState::get_cell_content()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheetState_getCellContent(spreadsheetstate: &AzSpreadsheetState, cell: AzSpreadsheetCellIndex) -> AzOptionString { spreadsheetstate.get_cell_content(&cell).cloned().into() }
+/// Equivalent to the Rust `SpreadsheetState::set_selection()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheetState_setSelection(spreadsheetstate: &mut AzSpreadsheetState, selection: AzOptionSpreadsheetCellSelection) { spreadsheetstate.set_selection(selection.into_option()) }
+/// Selects all cells of the table
+#[no_mangle] pub extern "C" fn AzSpreadsheetState_selectAll(spreadsheetstate: &mut AzSpreadsheetState) { spreadsheetstate.select_all() }
+/// Equivalent to the Rust `SpreadsheetState::get_column_width()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheetState_getColumnWidth(spreadsheetstate: &AzSpreadsheetState, column: usize) -> f32 { spreadsheetstate.get_column_width(column) }
+/// Equivalent to the Rust `SpreadsheetState::get_row_height()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheetState_getRowHeight(spreadsheetstate: &AzSpreadsheetState, row: usize) -> f32 { spreadsheetstate.get_row_height(row) }
+/// Overrides the `default_column_width` for the column
+#[no_mangle] pub extern "C" fn AzSpreadsheetState_setColumnWidth(spreadsheetstate: &mut AzSpreadsheetState, column: usize, width: f32) { spreadsheetstate.set_column_width(column, width) }
+/// Overrides the `default_row_height` for the row
+#[no_mangle] pub extern "C" fn AzSpreadsheetState_setRowHeight(spreadsheetstate: &mut AzSpreadsheetState, row: usize, height: f32) { spreadsheetstate.set_row_height(row, height) }
+/// Returns the size of all cells (without the row numbers and column names)
+#[no_mangle] pub extern "C" fn AzSpreadsheetState_getTableSize(spreadsheetstate: &AzSpreadsheetState) -> AzLogicalSize { spreadsheetstate.get_table_size() }
+/// Returns the rect of the cell, relative to the first cell of the table
+#[no_mangle] pub extern "C" fn AzSpreadsheetState_getCellRect(spreadsheetstate: &AzSpreadsheetState, cell: AzSpreadsheetCellIndex) -> AzLogicalRect { spreadsheetstate.get_cell_rect(cell) }
+/// Returns the contents of the selected cells as tab-separated values
+#[no_mangle] pub extern "C" fn AzSpreadsheetState_getSelectionTsv(spreadsheetstate: &AzSpreadsheetState, selection: AzSpreadsheetCellSelection) -> AzString { spreadsheetstate.get_selection_tsv(selection).into() }
+/// Pastes the tab-separated values, starting at the top left cell of the selection. Selects and returns the range of pasted cells.
+#[no_mangle] pub extern "C" fn AzSpreadsheetState_pasteTsv(spreadsheetstate: &mut AzSpreadsheetState, tsv: AzString) -> AzOptionSpreadsheetCellSelection { spreadsheetstate.paste_tsv(tsv.as_str()).into() }
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetStyle` struct
+pub type AzSpreadsheetStyleTT = crate::widgets::spreadsheet::SpreadsheetStyle;
+pub use AzSpreadsheetStyleTT as AzSpreadsheetStyle;
+
+/// Represents the index of a single cell (row + column)
+pub type AzSpreadsheetCellIndexTT = crate::widgets::spreadsheet::SpreadsheetCellIndex;
+pub use AzSpreadsheetCellIndexTT as AzSpreadsheetCellIndex;
+
+/// Represents a rectangular selection of table cells
+pub type AzSpreadsheetCellSelectionTT = crate::widgets::spreadsheet::SpreadsheetCellSelection;
+pub use AzSpreadsheetCellSelectionTT as AzSpreadsheetCellSelection;
+/// Creates a new `SpreadsheetCellSelection` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `SpreadsheetCellSelection::from()` constructor.
+#[no_mangle] pub extern "C" fn AzSpreadsheetCellSelection_from(row: usize, column: usize) -> AzSpreadsheetCellSelection { AzSpreadsheetCellSelection::from(row, column) }
+/// Equivalent to the Rust `SpreadsheetCellSelection::to()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheetCellSelection_to(spreadsheetcellselection: AzSpreadsheetCellSelection, row: usize, column: usize) -> AzSpreadsheetCellSelection { spreadsheetcellselection.to(row, column) }
+/// Equivalent to the Rust `SpreadsheetCellSelection::number_of_rows_selected()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheetCellSelection_numberOfRowsSelected(spreadsheetcellselection: &AzSpreadsheetCellSelection) -> usize { spreadsheetcellselection.number_of_rows_selected() }
+/// Equivalent to the Rust `SpreadsheetCellSelection::number_of_columns_selected()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheetCellSelection_numberOfColumnsSelected(spreadsheetcellselection: &AzSpreadsheetCellSelection) -> usize { spreadsheetcellselection.number_of_columns_selected() }
+/// Returns the top left cell of the selection
+#[no_mangle] pub extern "C" fn AzSpreadsheetCellSelection_getTopLeft(spreadsheetcellselection: &AzSpreadsheetCellSelection) -> AzSpreadsheetCellIndex { spreadsheetcellselection.get_top_left() }
+/// Returns the bottom right cell of the selection
+#[no_mangle] pub extern "C" fn AzSpreadsheetCellSelection_getBottomRight(spreadsheetcellselection: &AzSpreadsheetCellSelection) -> AzSpreadsheetCellIndex { spreadsheetcellselection.get_bottom_right() }
+/// Equivalent to the Rust `SpreadsheetCellSelection::contains()` function.
+#[no_mangle] pub extern "C" fn AzSpreadsheetCellSelection_contains(spreadsheetcellselection: &AzSpreadsheetCellSelection, cell: AzSpreadsheetCellIndex) -> bool { spreadsheetcellselection.contains(&cell) }
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetSizeOverride` struct
+pub type AzSpreadsheetSizeOverrideTT = crate::widgets::spreadsheet::SpreadsheetSizeOverride;
+pub use AzSpreadsheetSizeOverrideTT as AzSpreadsheetSizeOverride;
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetCellContent` struct
+pub type AzSpreadsheetCellContentTT = crate::widgets::spreadsheet::SpreadsheetCellContent;
+pub use AzSpreadsheetCellContentTT as AzSpreadsheetCellContent;
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetView` struct
+pub type AzSpreadsheetViewTT = crate::widgets::spreadsheet::SpreadsheetView;
+pub use AzSpreadsheetViewTT as AzSpreadsheetView;
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetRenderWindow` struct
+pub type AzSpreadsheetRenderWindowTT = crate::widgets::spreadsheet::SpreadsheetRenderWindow;
+pub use AzSpreadsheetRenderWindowTT as AzSpreadsheetRenderWindow;
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetDrag` struct
+pub type AzSpreadsheetDragTT = crate::widgets::spreadsheet::SpreadsheetDrag;
+pub use AzSpreadsheetDragTT as AzSpreadsheetDrag;
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetColumnResize` struct
+pub type AzSpreadsheetColumnResizeTT = crate::widgets::spreadsheet::SpreadsheetColumnResize;
+pub use AzSpreadsheetColumnResizeTT as AzSpreadsheetColumnResize;
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetCellClick` struct
+pub type AzSpreadsheetCellClickTT = crate::widgets::spreadsheet::SpreadsheetCellClick;
+pub use AzSpreadsheetCellClickTT as AzSpreadsheetCellClick;
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetCellEdit` struct
+pub type AzSpreadsheetCellEditTT = crate::widgets::spreadsheet::SpreadsheetCellEdit;
+pub use AzSpreadsheetCellEditTT as AzSpreadsheetCellEdit;
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetOnCellsChange` struct
+pub type AzSpreadsheetOnCellsChangeTT = crate::widgets::spreadsheet::SpreadsheetOnCellsChange;
+pub use AzSpreadsheetOnCellsChangeTT as AzSpreadsheetOnCellsChange;
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetOnCellsChangeCallback` struct
+pub type AzSpreadsheetOnCellsChangeCallbackTT = crate::widgets::spreadsheet::SpreadsheetOnCellsChangeCallback;
+pub use AzSpreadsheetOnCellsChangeCallbackTT as AzSpreadsheetOnCellsChangeCallback;
+
+pub type AzSpreadsheetOnCellsChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSpreadsheetState, AzSpreadsheetCellSelection, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `SpreadsheetOnSelectionChange` struct
+pub type AzSpreadsheetOnSelectionChangeTT = crate::widgets::spreadsheet::SpreadsheetOnSelectionChange;
+pub use AzSpreadsheetOnSelectionChangeTT as AzSpreadsheetOnSelectionChange;
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetOnSelectionChangeCallback` struct
+pub type AzSpreadsheetOnSelectionChangeCallbackTT = crate::widgets::spreadsheet::SpreadsheetOnSelectionChangeCallback;
+pub use AzSpreadsheetOnSelectionChangeCallbackTT as AzSpreadsheetOnSelectionChangeCallback;
+
+pub type AzSpreadsheetOnSelectionChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSpreadsheetState, &mut AzCallbackInfo) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `SpreadsheetOnColumnResize` struct
+pub type AzSpreadsheetOnColumnResizeTT = crate::widgets::spreadsheet::SpreadsheetOnColumnResize;
+pub use AzSpreadsheetOnColumnResizeTT as AzSpreadsheetOnColumnResize;
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetOnColumnResizeCallback` struct
+pub type AzSpreadsheetOnColumnResizeCallbackTT = crate::widgets::spreadsheet::SpreadsheetOnColumnResizeCallback;
+pub use AzSpreadsheetOnColumnResizeCallbackTT as AzSpreadsheetOnColumnResizeCallback;
+
+pub type AzSpreadsheetOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &AzSpreadsheetState, usize, &mut AzCallbackInfo) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `Node` struct
 pub type AzNodeTT = azul_impl::styled_dom::AzNode;
 pub use AzNodeTT as AzNode;
@@ -3011,6 +3593,12 @@ pub use AzAccessibilityStateVecTT as AzAccessibilityStateVec;
 /// Destructor: Takes ownership of the `AccessibilityStateVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzAccessibilityStateVec_delete(object: &mut AzAccessibilityStateVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<AccessibilityNode>`
+pub type AzAccessibilityNodeVecTT = azul_core::accessibility::AccessibilityNodeVec;
+pub use AzAccessibilityNodeVecTT as AzAccessibilityNodeVec;
+/// Destructor: Takes ownership of the `AccessibilityNodeVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzAccessibilityNodeVec_delete(object: &mut AzAccessibilityNodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `Vec<MenuItem>`
 pub type AzMenuItemVecTT = azul_core::window::MenuItemVec;
 pub use AzMenuItemVecTT as AzMenuItemVec;
@@ -3181,6 +3769,12 @@ pub use AzVirtualKeyCodeVecTT as AzVirtualKeyCodeVec;
 /// Destructor: Takes ownership of the `VirtualKeyCodeVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzVirtualKeyCodeVec_delete(object: &mut AzVirtualKeyCodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<KeyboardShortcut>`
+pub type AzKeyboardShortcutVecTT = azul_core::window::KeyboardShortcutVec;
+pub use AzKeyboardShortcutVecTT as AzKeyboardShortcutVec;
+/// Destructor: Takes ownership of the `KeyboardShortcutVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzKeyboardShortcutVec_delete(object: &mut AzKeyboardShortcutVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `CascadeInfo`
 pub type AzCascadeInfoVecTT = azul_impl::style::CascadeInfoVec;
 pub use AzCascadeInfoVecTT as AzCascadeInfoVec;
@@ -3211,6 +3805,102 @@ pub use AzStylesheetVecTT as AzStylesheetVec;
 /// Destructor: Takes ownership of the `StylesheetVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzStylesheetVec_delete(object: &mut AzStylesheetVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `CssMediaQuery`
+pub type AzCssMediaQueryVecTT = azul_impl::css::CssMediaQueryVec;
+pub use AzCssMediaQueryVecTT as AzCssMediaQueryVec;
+/// Destructor: Takes ownership of the `CssMediaQueryVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssMediaQueryVec_delete(object: &mut AzCssMediaQueryVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `CssMediaCondition`
+pub type AzCssMediaConditionVecTT = azul_impl::css::CssMediaConditionVec;
+pub use AzCssMediaConditionVecTT as AzCssMediaConditionVec;
+/// Destructor: Takes ownership of the `CssMediaConditionVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssMediaConditionVec_delete(object: &mut AzCssMediaConditionVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `CssFontFace`
+pub type AzCssFontFaceVecTT = azul_impl::css::CssFontFaceVec;
+pub use AzCssFontFaceVecTT as AzCssFontFaceVec;
+/// Destructor: Takes ownership of the `CssFontFaceVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssFontFaceVec_delete(object: &mut AzCssFontFaceVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleTransitionProperty>`
+pub type AzStyleTransitionPropertyVecTT = azul_impl::css::StyleTransitionPropertyVec;
+pub use AzStyleTransitionPropertyVecTT as AzStyleTransitionPropertyVec;
+/// Destructor: Takes ownership of the `StyleTransitionPropertyVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleTransitionPropertyVec_delete(object: &mut AzStyleTransitionPropertyVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleTransitionDuration>`
+pub type AzStyleTransitionDurationVecTT = azul_impl::css::StyleTransitionDurationVec;
+pub use AzStyleTransitionDurationVecTT as AzStyleTransitionDurationVec;
+/// Destructor: Takes ownership of the `StyleTransitionDurationVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleTransitionDurationVec_delete(object: &mut AzStyleTransitionDurationVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleTransitionTimingFunction>`
+pub type AzStyleTransitionTimingFunctionVecTT = azul_impl::css::StyleTransitionTimingFunctionVec;
+pub use AzStyleTransitionTimingFunctionVecTT as AzStyleTransitionTimingFunctionVec;
+/// Destructor: Takes ownership of the `StyleTransitionTimingFunctionVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleTransitionTimingFunctionVec_delete(object: &mut AzStyleTransitionTimingFunctionVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleTransitionDelay>`
+pub type AzStyleTransitionDelayVecTT = azul_impl::css::StyleTransitionDelayVec;
+pub use AzStyleTransitionDelayVecTT as AzStyleTransitionDelayVec;
+/// Destructor: Takes ownership of the `StyleTransitionDelayVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleTransitionDelayVec_delete(object: &mut AzStyleTransitionDelayVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationName>`
+pub type AzStyleAnimationNameVecTT = azul_impl::css::StyleAnimationNameVec;
+pub use AzStyleAnimationNameVecTT as AzStyleAnimationNameVec;
+/// Destructor: Takes ownership of the `StyleAnimationNameVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationNameVec_delete(object: &mut AzStyleAnimationNameVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationDuration>`
+pub type AzStyleAnimationDurationVecTT = azul_impl::css::StyleAnimationDurationVec;
+pub use AzStyleAnimationDurationVecTT as AzStyleAnimationDurationVec;
+/// Destructor: Takes ownership of the `StyleAnimationDurationVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationDurationVec_delete(object: &mut AzStyleAnimationDurationVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationIterationCount>`
+pub type AzStyleAnimationIterationCountVecTT = azul_impl::css::StyleAnimationIterationCountVec;
+pub use AzStyleAnimationIterationCountVecTT as AzStyleAnimationIterationCountVec;
+/// Destructor: Takes ownership of the `StyleAnimationIterationCountVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationIterationCountVec_delete(object: &mut AzStyleAnimationIterationCountVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationDirection>`
+pub type AzStyleAnimationDirectionVecTT = azul_impl::css::StyleAnimationDirectionVec;
+pub use AzStyleAnimationDirectionVecTT as AzStyleAnimationDirectionVec;
+/// Destructor: Takes ownership of the `StyleAnimationDirectionVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationDirectionVec_delete(object: &mut AzStyleAnimationDirectionVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationFillMode>`
+pub type AzStyleAnimationFillModeVecTT = azul_impl::css::StyleAnimationFillModeVec;
+pub use AzStyleAnimationFillModeVecTT as AzStyleAnimationFillModeVec;
+/// Destructor: Takes ownership of the `StyleAnimationFillModeVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationFillModeVec_delete(object: &mut AzStyleAnimationFillModeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationDelay>`
+pub type AzStyleAnimationDelayVecTT = azul_impl::css::StyleAnimationDelayVec;
+pub use AzStyleAnimationDelayVecTT as AzStyleAnimationDelayVec;
+/// Destructor: Takes ownership of the `StyleAnimationDelayVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationDelayVec_delete(object: &mut AzStyleAnimationDelayVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<CssKeyframe>`
+pub type AzCssKeyframeVecTT = azul_impl::css::CssKeyframeVec;
+pub use AzCssKeyframeVecTT as AzCssKeyframeVec;
+/// Destructor: Takes ownership of the `CssKeyframeVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssKeyframeVec_delete(object: &mut AzCssKeyframeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<CssKeyframes>`
+pub type AzCssKeyframesVecTT = azul_impl::css::CssKeyframesVec;
+pub use AzCssKeyframesVecTT as AzCssKeyframesVec;
+/// Destructor: Takes ownership of the `CssKeyframesVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssKeyframesVec_delete(object: &mut AzCssKeyframesVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<GridTrackSizing>`
+pub type AzGridTrackSizingVecTT = azul_impl::css::GridTrackSizingVec;
+pub use AzGridTrackSizingVecTT as AzGridTrackSizingVec;
+/// Destructor: Takes ownership of the `GridTrackSizingVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzGridTrackSizingVec_delete(object: &mut AzGridTrackSizingVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `CssRuleBlock`
 pub type AzCssRuleBlockVecTT = azul_impl::css::CssRuleBlockVec;
 pub use AzCssRuleBlockVecTT as AzCssRuleBlockVec;
@@ -3323,6 +4013,24 @@ pub use AzNodeDataVecTT as AzNodeDataVec;
 /// Destructor: Takes ownership of the `NodeDataVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzNodeDataVec_delete(object: &mut AzNodeDataVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<TextEditSnapshot>`
+pub type AzTextEditSnapshotVecTT = crate::widgets::text_edit::TextEditSnapshotVec;
+pub use AzTextEditSnapshotVecTT as AzTextEditSnapshotVec;
+/// Destructor: Takes ownership of the `TextEditSnapshotVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextEditSnapshotVec_delete(object: &mut AzTextEditSnapshotVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<SpreadsheetSizeOverride>`
+pub type AzSpreadsheetSizeOverrideVecTT = crate::widgets::spreadsheet::SpreadsheetSizeOverrideVec;
+pub use AzSpreadsheetSizeOverrideVecTT as AzSpreadsheetSizeOverrideVec;
+/// Destructor: Takes ownership of the `SpreadsheetSizeOverrideVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSpreadsheetSizeOverrideVec_delete(object: &mut AzSpreadsheetSizeOverrideVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<SpreadsheetCellContent>`
+pub type AzSpreadsheetCellContentVecTT = crate::widgets::spreadsheet::SpreadsheetCellContentVec;
+pub use AzSpreadsheetCellContentVecTT as AzSpreadsheetCellContentVec;
+/// Destructor: Takes ownership of the `SpreadsheetCellContentVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSpreadsheetCellContentVec_delete(object: &mut AzSpreadsheetCellContentVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecDestructor` struct
 pub type AzStyleFontFamilyVecDestructorTT = azul_impl::css::StyleFontFamilyVecDestructor;
 pub use AzStyleFontFamilyVecDestructorTT as AzStyleFontFamilyVecDestructor;
@@ -3333,6 +4041,11 @@ pub type AzAccessibilityStateVecDestructorTT = azul_impl::dom::AccessibilityStat
 pub use AzAccessibilityStateVecDestructorTT as AzAccessibilityStateVecDestructor;
 
 pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
+/// Re-export of rust-allocated (stack based) `AccessibilityNodeVecDestructor` struct
+pub type AzAccessibilityNodeVecDestructorTT = azul_core::accessibility::AccessibilityNodeVecDestructor;
+pub use AzAccessibilityNodeVecDestructorTT as AzAccessibilityNodeVecDestructor;
+
+pub type AzAccessibilityNodeVecDestructorType = extern "C" fn(&mut AzAccessibilityNodeVec);
 /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
 pub type AzMenuItemVecDestructorTT = azul_core::window::MenuItemVecDestructor;
 pub use AzMenuItemVecDestructorTT as AzMenuItemVecDestructor;
@@ -3468,6 +4181,11 @@ pub type AzVirtualKeyCodeVecDestructorTT = azul_core::window::VirtualKeyCodeVecD
 pub use AzVirtualKeyCodeVecDestructorTT as AzVirtualKeyCodeVecDestructor;
 
 pub type AzVirtualKeyCodeVecDestructorType = extern "C" fn(&mut AzVirtualKeyCodeVec);
+/// Re-export of rust-allocated (stack based) `KeyboardShortcutVecDestructor` struct
+pub type AzKeyboardShortcutVecDestructorTT = azul_core::window::KeyboardShortcutVecDestructor;
+pub use AzKeyboardShortcutVecDestructorTT as AzKeyboardShortcutVecDestructor;
+
+pub type AzKeyboardShortcutVecDestructorType = extern "C" fn(&mut AzKeyboardShortcutVec);
 /// Re-export of rust-allocated (stack based) `CascadeInfoVecDestructor` struct
 pub type AzCascadeInfoVecDestructorTT = azul_impl::style::CascadeInfoVecDestructor;
 pub use AzCascadeInfoVecDestructorTT as AzCascadeInfoVecDestructor;
@@ -3493,6 +4211,86 @@ pub type AzStylesheetVecDestructorTT = azul_impl::css::StylesheetVecDestructor;
 pub use AzStylesheetVecDestructorTT as AzStylesheetVecDestructor;
 
 pub type AzStylesheetVecDestructorType = extern "C" fn(&mut AzStylesheetVec);
+/// Re-export of rust-allocated (stack based) `CssFontFaceVecDestructor` struct
+pub type AzCssFontFaceVecDestructorTT = azul_impl::css::CssFontFaceVecDestructor;
+pub use AzCssFontFaceVecDestructorTT as AzCssFontFaceVecDestructor;
+
+pub type AzCssFontFaceVecDestructorType = extern "C" fn(&mut AzCssFontFaceVec);
+/// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecDestructor` struct
+pub type AzStyleTransitionPropertyVecDestructorTT = azul_impl::css::StyleTransitionPropertyVecDestructor;
+pub use AzStyleTransitionPropertyVecDestructorTT as AzStyleTransitionPropertyVecDestructor;
+
+pub type AzStyleTransitionPropertyVecDestructorType = extern "C" fn(&mut AzStyleTransitionPropertyVec);
+/// Re-export of rust-allocated (stack based) `StyleTransitionDurationVecDestructor` struct
+pub type AzStyleTransitionDurationVecDestructorTT = azul_impl::css::StyleTransitionDurationVecDestructor;
+pub use AzStyleTransitionDurationVecDestructorTT as AzStyleTransitionDurationVecDestructor;
+
+pub type AzStyleTransitionDurationVecDestructorType = extern "C" fn(&mut AzStyleTransitionDurationVec);
+/// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunctionVecDestructor` struct
+pub type AzStyleTransitionTimingFunctionVecDestructorTT = azul_impl::css::StyleTransitionTimingFunctionVecDestructor;
+pub use AzStyleTransitionTimingFunctionVecDestructorTT as AzStyleTransitionTimingFunctionVecDestructor;
+
+pub type AzStyleTransitionTimingFunctionVecDestructorType = extern "C" fn(&mut AzStyleTransitionTimingFunctionVec);
+/// Re-export of rust-allocated (stack based) `StyleTransitionDelayVecDestructor` struct
+pub type AzStyleTransitionDelayVecDestructorTT = azul_impl::css::StyleTransitionDelayVecDestructor;
+pub use AzStyleTransitionDelayVecDestructorTT as AzStyleTransitionDelayVecDestructor;
+
+pub type AzStyleTransitionDelayVecDestructorType = extern "C" fn(&mut AzStyleTransitionDelayVec);
+/// Re-export of rust-allocated (stack based) `StyleAnimationNameVecDestructor` struct
+pub type AzStyleAnimationNameVecDestructorTT = azul_impl::css::StyleAnimationNameVecDestructor;
+pub use AzStyleAnimationNameVecDestructorTT as AzStyleAnimationNameVecDestructor;
+
+pub type AzStyleAnimationNameVecDestructorType = extern "C" fn(&mut AzStyleAnimationNameVec);
+/// Re-export of rust-allocated (stack based) `StyleAnimationDurationVecDestructor` struct
+pub type AzStyleAnimationDurationVecDestructorTT = azul_impl::css::StyleAnimationDurationVecDestructor;
+pub use AzStyleAnimationDurationVecDestructorTT as AzStyleAnimationDurationVecDestructor;
+
+pub type AzStyleAnimationDurationVecDestructorType = extern "C" fn(&mut AzStyleAnimationDurationVec);
+/// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecDestructor` struct
+pub type AzStyleAnimationIterationCountVecDestructorTT = azul_impl::css::StyleAnimationIterationCountVecDestructor;
+pub use AzStyleAnimationIterationCountVecDestructorTT as AzStyleAnimationIterationCountVecDestructor;
+
+pub type AzStyleAnimationIterationCountVecDestructorType = extern "C" fn(&mut AzStyleAnimationIterationCountVec);
+/// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecDestructor` struct
+pub type AzStyleAnimationDirectionVecDestructorTT = azul_impl::css::StyleAnimationDirectionVecDestructor;
+pub use AzStyleAnimationDirectionVecDestructorTT as AzStyleAnimationDirectionVecDestructor;
+
+pub type AzStyleAnimationDirectionVecDestructorType = extern "C" fn(&mut AzStyleAnimationDirectionVec);
+/// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecDestructor` struct
+pub type AzStyleAnimationFillModeVecDestructorTT = azul_impl::css::StyleAnimationFillModeVecDestructor;
+pub use AzStyleAnimationFillModeVecDestructorTT as AzStyleAnimationFillModeVecDestructor;
+
+pub type AzStyleAnimationFillModeVecDestructorType = extern "C" fn(&mut AzStyleAnimationFillModeVec);
+/// Re-export of rust-allocated (stack based) `StyleAnimationDelayVecDestructor` struct
+pub type AzStyleAnimationDelayVecDestructorTT = azul_impl::css::StyleAnimationDelayVecDestructor;
+pub use AzStyleAnimationDelayVecDestructorTT as AzStyleAnimationDelayVecDestructor;
+
+pub type AzStyleAnimationDelayVecDestructorType = extern "C" fn(&mut AzStyleAnimationDelayVec);
+/// Re-export of rust-allocated (stack based) `CssKeyframeVecDestructor` struct
+pub type AzCssKeyframeVecDestructorTT = azul_impl::css::CssKeyframeVecDestructor;
+pub use AzCssKeyframeVecDestructorTT as AzCssKeyframeVecDestructor;
+
+pub type AzCssKeyframeVecDestructorType = extern "C" fn(&mut AzCssKeyframeVec);
+/// Re-export of rust-allocated (stack based) `CssKeyframesVecDestructor` struct
+pub type AzCssKeyframesVecDestructorTT = azul_impl::css::CssKeyframesVecDestructor;
+pub use AzCssKeyframesVecDestructorTT as AzCssKeyframesVecDestructor;
+
+pub type AzCssKeyframesVecDestructorType = extern "C" fn(&mut AzCssKeyframesVec);
+/// Re-export of rust-allocated (stack based) `GridTrackSizingVecDestructor` struct
+pub type AzGridTrackSizingVecDestructorTT = azul_impl::css::GridTrackSizingVecDestructor;
+pub use AzGridTrackSizingVecDestructorTT as AzGridTrackSizingVecDestructor;
+
+pub type AzGridTrackSizingVecDestructorType = extern "C" fn(&mut AzGridTrackSizingVec);
+/// Re-export of rust-allocated (stack based) `CssMediaQueryVecDestructor` struct
+pub type AzCssMediaQueryVecDestructorTT = azul_impl::css::CssMediaQueryVecDestructor;
+pub use AzCssMediaQueryVecDestructorTT as AzCssMediaQueryVecDestructor;
+
+pub type AzCssMediaQueryVecDestructorType = extern "C" fn(&mut AzCssMediaQueryVec);
+/// Re-export of rust-allocated (stack based) `CssMediaConditionVecDestructor` struct
+pub type AzCssMediaConditionVecDestructorTT = azul_impl::css::CssMediaConditionVecDestructor;
+pub use AzCssMediaConditionVecDestructorTT as AzCssMediaConditionVecDestructor;
+
+pub type AzCssMediaConditionVecDestructorType = extern "C" fn(&mut AzCssMediaConditionVec);
 /// Re-export of rust-allocated (stack based) `CssRuleBlockVecDestructor` struct
 pub type AzCssRuleBlockVecDestructorTT = azul_impl::css::CssRuleBlockVecDestructor;
 pub use AzCssRuleBlockVecDestructorTT as AzCssRuleBlockVecDestructor;
@@ -3583,6 +4381,21 @@ pub type AzNodeDataVecDestructorTT = azul_impl::dom::NodeDataVecDestructor;
 pub use AzNodeDataVecDestructorTT as AzNodeDataVecDestructor;
 
 pub type AzNodeDataVecDestructorType = extern "C" fn(&mut AzNodeDataVec);
+/// Re-export of rust-allocated (stack based) `TextEditSnapshotVecDestructor` struct
+pub type AzTextEditSnapshotVecDestructorTT = crate::widgets::text_edit::TextEditSnapshotVecDestructor;
+pub use AzTextEditSnapshotVecDestructorTT as AzTextEditSnapshotVecDestructor;
+
+pub type AzTextEditSnapshotVecDestructorType = extern "C" fn(&mut AzTextEditSnapshotVec);
+/// Re-export of rust-allocated (stack based) `SpreadsheetSizeOverrideVecDestructor` struct
+pub type AzSpreadsheetSizeOverrideVecDestructorTT = crate::widgets::spreadsheet::SpreadsheetSizeOverrideVecDestructor;
+pub use AzSpreadsheetSizeOverrideVecDestructorTT as AzSpreadsheetSizeOverrideVecDestructor;
+
+pub type AzSpreadsheetSizeOverrideVecDestructorType = extern "C" fn(&mut AzSpreadsheetSizeOverrideVec);
+/// Re-export of rust-allocated (stack based) `SpreadsheetCellContentVecDestructor` struct
+pub type AzSpreadsheetCellContentVecDestructorTT = crate::widgets::spreadsheet::SpreadsheetCellContentVecDestructor;
+pub use AzSpreadsheetCellContentVecDestructorTT as AzSpreadsheetCellContentVecDestructor;
+
+pub type AzSpreadsheetCellContentVecDestructorType = extern "C" fn(&mut AzSpreadsheetCellContentVec);
 /// Re-export of rust-allocated (stack based) `OptionColorInputOnValueChange` struct
 pub type AzOptionColorInputOnValueChangeTT = crate::widgets::color_input::OptionColorInputOnValueChange;
 pub use AzOptionColorInputOnValueChangeTT as AzOptionColorInputOnValueChange;
@@ -3615,6 +4428,54 @@ pub use AzOptionTextInputSelectionTT as AzOptionTextInputSelection;
 pub type AzOptionNumberInputOnValueChangeTT = crate::widgets::number_input::OptionNumberInputOnValueChange;
 pub use AzOptionNumberInputOnValueChangeTT as AzOptionNumberInputOnValueChange;
 
+/// Re-export of rust-allocated (stack based) `OptionTextEditOnTextChange` struct
+pub type AzOptionTextEditOnTextChangeTT = crate::widgets::text_edit::OptionTextEditOnTextChange;
+pub use AzOptionTextEditOnTextChangeTT as AzOptionTextEditOnTextChange;
+
+/// Re-export of rust-allocated (stack based) `OptionTextEditOnFocusLost` struct
+pub type AzOptionTextEditOnFocusLostTT = crate::widgets::text_edit::OptionTextEditOnFocusLost;
+pub use AzOptionTextEditOnFocusLostTT as AzOptionTextEditOnFocusLost;
+
+/// Re-export of rust-allocated (stack based) `OptionTextEditKind` struct
+pub type AzOptionTextEditKindTT = crate::widgets::text_edit::OptionTextEditKind;
+pub use AzOptionTextEditKindTT as AzOptionTextEditKind;
+
+/// Re-export of rust-allocated (stack based) `OptionSpreadsheetCellClick` struct
+pub type AzOptionSpreadsheetCellClickTT = crate::widgets::spreadsheet::OptionSpreadsheetCellClick;
+pub use AzOptionSpreadsheetCellClickTT as AzOptionSpreadsheetCellClick;
+
+/// Re-export of rust-allocated (stack based) `OptionSpreadsheetCellEdit` struct
+pub type AzOptionSpreadsheetCellEditTT = crate::widgets::spreadsheet::OptionSpreadsheetCellEdit;
+pub use AzOptionSpreadsheetCellEditTT as AzOptionSpreadsheetCellEdit;
+
+/// Re-export of rust-allocated (stack based) `OptionSliderOnValueChange` struct
+pub type AzOptionSliderOnValueChangeTT = crate::widgets::slider::OptionSliderOnValueChange;
+pub use AzOptionSliderOnValueChangeTT as AzOptionSliderOnValueChange;
+
+/// Re-export of rust-allocated (stack based) `OptionDropDownOnChoiceChange` struct
+pub type AzOptionDropDownOnChoiceChangeTT = crate::widgets::drop_down::OptionDropDownOnChoiceChange;
+pub use AzOptionDropDownOnChoiceChangeTT as AzOptionDropDownOnChoiceChange;
+
+/// Re-export of rust-allocated (stack based) `OptionSpreadsheetOnCellsChange` struct
+pub type AzOptionSpreadsheetOnCellsChangeTT = crate::widgets::spreadsheet::OptionSpreadsheetOnCellsChange;
+pub use AzOptionSpreadsheetOnCellsChangeTT as AzOptionSpreadsheetOnCellsChange;
+
+/// Re-export of rust-allocated (stack based) `OptionSpreadsheetOnSelectionChange` struct
+pub type AzOptionSpreadsheetOnSelectionChangeTT = crate::widgets::spreadsheet::OptionSpreadsheetOnSelectionChange;
+pub use AzOptionSpreadsheetOnSelectionChangeTT as AzOptionSpreadsheetOnSelectionChange;
+
+/// Re-export of rust-allocated (stack based) `OptionSpreadsheetOnColumnResize` struct
+pub type AzOptionSpreadsheetOnColumnResizeTT = crate::widgets::spreadsheet::OptionSpreadsheetOnColumnResize;
+pub use AzOptionSpreadsheetOnColumnResizeTT as AzOptionSpreadsheetOnColumnResize;
+
+/// Re-export of rust-allocated (stack based) `OptionSpreadsheetCellSelection` struct
+pub type AzOptionSpreadsheetCellSelectionTT = crate::widgets::spreadsheet::OptionSpreadsheetCellSelection;
+pub use AzOptionSpreadsheetCellSelectionTT as AzOptionSpreadsheetCellSelection;
+
+/// Re-export of rust-allocated (stack based) `OptionAccessibilityNode` struct
+pub type AzOptionAccessibilityNodeTT = azul_core::accessibility::OptionAccessibilityNode;
+pub use AzOptionAccessibilityNodeTT as AzOptionAccessibilityNode;
+
 /// Re-export of rust-allocated (stack based) `OptionMenuItemIcon` struct
 pub type AzOptionMenuItemIconTT = azul_core::window::OptionMenuItemIcon;
 pub use AzOptionMenuItemIconTT as AzOptionMenuItemIcon;
@@ -3987,6 +4848,13 @@ mod test_sizes {
     impl ::core::fmt::Debug for AzTextInputOnVirtualKeyDownCallback { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
     impl ::core::fmt::Debug for AzTextInputOnFocusLostCallback      { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
     impl ::core::fmt::Debug for AzNumberInputOnValueChangeCallback  { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzSpreadsheetOnCellsChangeCallback  { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzSpreadsheetOnSelectionChangeCallback{ fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzSpreadsheetOnColumnResizeCallback { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzDropDownOnChoiceChangeCallback    { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzSliderOnValueChangeCallback       { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzTextEditOnTextChangeCallback      { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
+    impl ::core::fmt::Debug for AzTextEditOnFocusLostCallback       { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
 
     impl ::core::fmt::Debug for AzDomVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzDomVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
     impl ::core::fmt::Debug for AzIdOrClassVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzIdOrClassVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
@@ -4868,6 +5736,20 @@ mod test_sizes {
         Disabled,
     }
 
+    /// Re-export of rust-allocated (stack based) `CssMediaOrientation` struct
+    #[repr(C)]
+    pub enum AzCssMediaOrientation {
+        Portrait,
+        Landscape,
+    }
+
+    /// Re-export of rust-allocated (stack based) `CssMediaColorScheme` struct
+    #[repr(C)]
+    pub enum AzCssMediaColorScheme {
+        Light,
+        Dark,
+    }
+
     /// Re-export of rust-allocated (stack based) `NodeTypeKey` struct
     #[repr(C)]
     pub enum AzNodeTypeKey {
@@ -4879,6 +5761,18 @@ mod test_sizes {
         IFrame,
     }
 
+    /// Re-export of rust-allocated (stack based) `CssAttributeSelectorOp` struct
+    #[repr(C)]
+    pub enum AzCssAttributeSelectorOp {
+        Exists,
+        Equals,
+        Includes,
+        DashMatch,
+        Prefix,
+        Suffix,
+        Substring,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssNthChildPattern` struct
     #[repr(C)]
     pub struct AzCssNthChildPattern {
@@ -4886,6 +5780,14 @@ mod test_sizes {
         pub offset: u32,
     }
 
+    /// Re-export of rust-allocated (stack based) `CssFontFaceStyle` struct
+    #[repr(C)]
+    pub enum AzCssFontFaceStyle {
+        Normal,
+        Italic,
+        Oblique,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
     #[repr(C)]
     pub enum AzCssPropertyType {
@@ -4900,6 +5802,7 @@ mod test_sizes {
         Cursor,
         Display,
         Float,
+        Clear,
         BoxSizing,
         Width,
         Height,
@@ -4919,6 +5822,12 @@ mod test_sizes {
         JustifyContent,
         AlignItems,
         AlignContent,
+        GridTemplateColumns,
+        GridTemplateRows,
+        GridColumn,
+        GridRow,
+        ColumnGap,
+        RowGap,
         BackgroundContent,
         BackgroundPosition,
         BackgroundSize,
@@ -4959,6 +5868,17 @@ mod test_sizes {
         TransformOrigin,
         PerspectiveOrigin,
         BackfaceVisibility,
+        TransitionProperty,
+        TransitionDuration,
+        TransitionTimingFunction,
+        TransitionDelay,
+        AnimationName,
+        AnimationDuration,
+        AnimationTimingFunction,
+        AnimationIterationCount,
+        AnimationDirection,
+        AnimationFillMode,
+        AnimationDelay,
     }
 
     /// Re-export of rust-allocated (stack based) `ColorU` struct
@@ -5003,6 +5923,14 @@ mod test_sizes {
         SpaceAround,
     }
 
+    /// Start or end line of a grid item (`auto`, `2` or `span 2`)
+    #[repr(C, u8)]
+    pub enum AzGridLine {
+        Auto,
+        Line(i32),
+        Span(u32),
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutAlignItems` struct
     #[repr(C)]
     pub enum AzLayoutAlignItems {
@@ -5035,6 +5963,7 @@ mod test_sizes {
         Flex,
         Block,
         InlineBlock,
+        Grid,
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutFloat` struct
@@ -5042,6 +5971,16 @@ mod test_sizes {
     pub enum AzLayoutFloat {
         Left,
         Right,
+        None,
+    }
+
+    /// Represents a `clear` attribute: on which sides the node may not be placed next to floating nodes that come before it
+    #[repr(C)]
+    pub enum AzLayoutClear {
+        None,
+        Left,
+        Right,
+        Both,
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutJustifyContent` struct
@@ -5192,6 +6131,55 @@ mod test_sizes {
         Visible,
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleTransitionDuration` struct
+    #[repr(C)]
+    pub struct AzStyleTransitionDuration {
+        pub ms: u32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransitionDelay` struct
+    #[repr(C)]
+    pub struct AzStyleTransitionDelay {
+        pub ms: u32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDuration` struct
+    #[repr(C)]
+    pub struct AzStyleAnimationDuration {
+        pub ms: u32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDelay` struct
+    #[repr(C)]
+    pub struct AzStyleAnimationDelay {
+        pub ms: u32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationIterationCount` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationIterationCount {
+        Count(u32),
+        Infinite,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDirection` struct
+    #[repr(C)]
+    pub enum AzStyleAnimationDirection {
+        Normal,
+        Reverse,
+        Alternate,
+        AlternateReverse,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationFillMode` struct
+    #[repr(C)]
+    pub enum AzStyleAnimationFillMode {
+        None,
+        Forwards,
+        Backwards,
+        Both,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTextAlign` struct
     #[repr(C)]
     pub enum AzStyleTextAlign {
@@ -5283,6 +6271,105 @@ mod test_sizes {
     /// `AzNumberInputOnValueChangeCallbackType` struct
     pub type AzNumberInputOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzNumberInputState, &mut AzCallbackInfo) -> AzUpdate;
 
+    /// Re-export of rust-allocated (stack based) `TextEditKind` struct
+    #[repr(C)]
+    pub enum AzTextEditKind {
+        Typing,
+        Deleting,
+        Other,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditOnTextChangeCallback` struct
+    #[repr(C)]
+    pub struct AzTextEditOnTextChangeCallback {
+        pub cb: AzTextEditOnTextChangeCallbackType,
+    }
+
+    /// `AzTextEditOnTextChangeCallbackType` struct
+    pub type AzTextEditOnTextChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTextEditState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TextEditOnFocusLostCallback` struct
+    #[repr(C)]
+    pub struct AzTextEditOnFocusLostCallback {
+        pub cb: AzTextEditOnFocusLostCallbackType,
+    }
+
+    /// `AzTextEditOnFocusLostCallbackType` struct
+    pub type AzTextEditOnFocusLostCallbackType = extern "C" fn(&mut AzRefAny, &AzTextEditState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `SliderOrientation` struct
+    #[repr(C)]
+    pub enum AzSliderOrientation {
+        Horizontal,
+        Vertical,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderOnValueChangeCallback` struct
+    #[repr(C)]
+    pub struct AzSliderOnValueChangeCallback {
+        pub cb: AzSliderOnValueChangeCallbackType,
+    }
+
+    /// `AzSliderOnValueChangeCallbackType` struct
+    pub type AzSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSliderState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChangeCallback` struct
+    #[repr(C)]
+    pub struct AzDropDownOnChoiceChangeCallback {
+        pub cb: AzDropDownOnChoiceChangeCallbackType,
+    }
+
+    /// `AzDropDownOnChoiceChangeCallbackType` struct
+    pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzDropDownState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Represents the index of a single cell (row + column)
+    #[repr(C)]
+    pub struct AzSpreadsheetCellIndex {
+        pub row: usize,
+        pub column: usize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetSizeOverride` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetSizeOverride {
+        pub index: usize,
+        pub size: f32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetColumnResize` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetColumnResize {
+        pub column: usize,
+        pub width: f32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetOnCellsChangeCallback` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetOnCellsChangeCallback {
+        pub cb: AzSpreadsheetOnCellsChangeCallbackType,
+    }
+
+    /// `AzSpreadsheetOnCellsChangeCallbackType` struct
+    pub type AzSpreadsheetOnCellsChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSpreadsheetState, AzSpreadsheetCellSelection, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetOnSelectionChangeCallback` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetOnSelectionChangeCallback {
+        pub cb: AzSpreadsheetOnSelectionChangeCallbackType,
+    }
+
+    /// `AzSpreadsheetOnSelectionChangeCallbackType` struct
+    pub type AzSpreadsheetOnSelectionChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSpreadsheetState, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetOnColumnResizeCallback` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetOnColumnResizeCallback {
+        pub cb: AzSpreadsheetOnColumnResizeCallbackType,
+    }
+
+    /// `AzSpreadsheetOnColumnResizeCallbackType` struct
+    pub type AzSpreadsheetOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &AzSpreadsheetState, usize, &mut AzCallbackInfo) -> AzUpdate;
+
     /// Re-export of rust-allocated (stack based) `Node` struct
     #[repr(C)]
     pub struct AzNode {
@@ -5881,6 +6968,17 @@ mod test_sizes {
     /// `AzAccessibilityStateVecDestructorType` struct
     pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
 
+    /// Re-export of rust-allocated (stack based) `AccessibilityNodeVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzAccessibilityNodeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzAccessibilityNodeVecDestructorType),
+    }
+
+    /// `AzAccessibilityNodeVecDestructorType` struct
+    pub type AzAccessibilityNodeVecDestructorType = extern "C" fn(&mut AzAccessibilityNodeVec);
+
     /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzMenuItemVecDestructor {
@@ -6178,6 +7276,17 @@ mod test_sizes {
     /// `AzVirtualKeyCodeVecDestructorType` struct
     pub type AzVirtualKeyCodeVecDestructorType = extern "C" fn(&mut AzVirtualKeyCodeVec);
 
+    /// Re-export of rust-allocated (stack based) `KeyboardShortcutVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzKeyboardShortcutVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzKeyboardShortcutVecDestructorType),
+    }
+
+    /// `AzKeyboardShortcutVecDestructorType` struct
+    pub type AzKeyboardShortcutVecDestructorType = extern "C" fn(&mut AzKeyboardShortcutVec);
+
     /// Re-export of rust-allocated (stack based) `CascadeInfoVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzCascadeInfoVecDestructor {
@@ -6233,169 +7342,345 @@ mod test_sizes {
     /// `AzStylesheetVecDestructorType` struct
     pub type AzStylesheetVecDestructorType = extern "C" fn(&mut AzStylesheetVec);
 
-    /// Re-export of rust-allocated (stack based) `CssRuleBlockVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `CssFontFaceVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzCssRuleBlockVecDestructor {
+    pub enum AzCssFontFaceVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzCssRuleBlockVecDestructorType),
+        External(AzCssFontFaceVecDestructorType),
     }
 
-    /// `AzCssRuleBlockVecDestructorType` struct
-    pub type AzCssRuleBlockVecDestructorType = extern "C" fn(&mut AzCssRuleBlockVec);
+    /// `AzCssFontFaceVecDestructorType` struct
+    pub type AzCssFontFaceVecDestructorType = extern "C" fn(&mut AzCssFontFaceVec);
 
-    /// Re-export of rust-allocated (stack based) `F32VecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzF32VecDestructor {
+    pub enum AzStyleTransitionPropertyVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzF32VecDestructorType),
+        External(AzStyleTransitionPropertyVecDestructorType),
     }
 
-    /// `AzF32VecDestructorType` struct
-    pub type AzF32VecDestructorType = extern "C" fn(&mut AzF32Vec);
+    /// `AzStyleTransitionPropertyVecDestructorType` struct
+    pub type AzStyleTransitionPropertyVecDestructorType = extern "C" fn(&mut AzStyleTransitionPropertyVec);
 
-    /// Re-export of rust-allocated (stack based) `U16VecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleTransitionDurationVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzU16VecDestructor {
+    pub enum AzStyleTransitionDurationVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzU16VecDestructorType),
+        External(AzStyleTransitionDurationVecDestructorType),
     }
 
-    /// `AzU16VecDestructorType` struct
-    pub type AzU16VecDestructorType = extern "C" fn(&mut AzU16Vec);
+    /// `AzStyleTransitionDurationVecDestructorType` struct
+    pub type AzStyleTransitionDurationVecDestructorType = extern "C" fn(&mut AzStyleTransitionDurationVec);
 
-    /// Re-export of rust-allocated (stack based) `U8VecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunctionVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzU8VecDestructor {
+    pub enum AzStyleTransitionTimingFunctionVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzU8VecDestructorType),
+        External(AzStyleTransitionTimingFunctionVecDestructorType),
     }
 
-    /// `AzU8VecDestructorType` struct
-    pub type AzU8VecDestructorType = extern "C" fn(&mut AzU8Vec);
+    /// `AzStyleTransitionTimingFunctionVecDestructorType` struct
+    pub type AzStyleTransitionTimingFunctionVecDestructorType = extern "C" fn(&mut AzStyleTransitionTimingFunctionVec);
 
-    /// Re-export of rust-allocated (stack based) `CallbackDataVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleTransitionDelayVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzCallbackDataVecDestructor {
+    pub enum AzStyleTransitionDelayVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzCallbackDataVecDestructorType),
+        External(AzStyleTransitionDelayVecDestructorType),
     }
 
-    /// `AzCallbackDataVecDestructorType` struct
-    pub type AzCallbackDataVecDestructorType = extern "C" fn(&mut AzCallbackDataVec);
+    /// `AzStyleTransitionDelayVecDestructorType` struct
+    pub type AzStyleTransitionDelayVecDestructorType = extern "C" fn(&mut AzStyleTransitionDelayVec);
 
-    /// Re-export of rust-allocated (stack based) `DebugMessageVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleAnimationNameVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzDebugMessageVecDestructor {
+    pub enum AzStyleAnimationNameVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzDebugMessageVecDestructorType),
+        External(AzStyleAnimationNameVecDestructorType),
     }
 
-    /// `AzDebugMessageVecDestructorType` struct
-    pub type AzDebugMessageVecDestructorType = extern "C" fn(&mut AzDebugMessageVec);
+    /// `AzStyleAnimationNameVecDestructorType` struct
+    pub type AzStyleAnimationNameVecDestructorType = extern "C" fn(&mut AzStyleAnimationNameVec);
 
-    /// Re-export of rust-allocated (stack based) `GLuintVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDurationVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzGLuintVecDestructor {
+    pub enum AzStyleAnimationDurationVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzGLuintVecDestructorType),
+        External(AzStyleAnimationDurationVecDestructorType),
     }
 
-    /// `AzGLuintVecDestructorType` struct
-    pub type AzGLuintVecDestructorType = extern "C" fn(&mut AzGLuintVec);
+    /// `AzStyleAnimationDurationVecDestructorType` struct
+    pub type AzStyleAnimationDurationVecDestructorType = extern "C" fn(&mut AzStyleAnimationDurationVec);
 
-    /// Re-export of rust-allocated (stack based) `GLintVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzGLintVecDestructor {
+    pub enum AzStyleAnimationIterationCountVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzGLintVecDestructorType),
+        External(AzStyleAnimationIterationCountVecDestructorType),
     }
 
-    /// `AzGLintVecDestructorType` struct
-    pub type AzGLintVecDestructorType = extern "C" fn(&mut AzGLintVec);
+    /// `AzStyleAnimationIterationCountVecDestructorType` struct
+    pub type AzStyleAnimationIterationCountVecDestructorType = extern "C" fn(&mut AzStyleAnimationIterationCountVec);
 
-    /// Re-export of rust-allocated (stack based) `StringVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzStringVecDestructor {
+    pub enum AzStyleAnimationDirectionVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzStringVecDestructorType),
+        External(AzStyleAnimationDirectionVecDestructorType),
     }
 
-    /// `AzStringVecDestructorType` struct
-    pub type AzStringVecDestructorType = extern "C" fn(&mut AzStringVec);
+    /// `AzStyleAnimationDirectionVecDestructorType` struct
+    pub type AzStyleAnimationDirectionVecDestructorType = extern "C" fn(&mut AzStyleAnimationDirectionVec);
 
-    /// Re-export of rust-allocated (stack based) `StringPairVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzStringPairVecDestructor {
+    pub enum AzStyleAnimationFillModeVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzStringPairVecDestructorType),
+        External(AzStyleAnimationFillModeVecDestructorType),
     }
 
-    /// `AzStringPairVecDestructorType` struct
-    pub type AzStringPairVecDestructorType = extern "C" fn(&mut AzStringPairVec);
+    /// `AzStyleAnimationFillModeVecDestructorType` struct
+    pub type AzStyleAnimationFillModeVecDestructorType = extern "C" fn(&mut AzStyleAnimationFillModeVec);
 
-    /// Re-export of rust-allocated (stack based) `NormalizedLinearColorStopVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDelayVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzNormalizedLinearColorStopVecDestructor {
+    pub enum AzStyleAnimationDelayVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzNormalizedLinearColorStopVecDestructorType),
+        External(AzStyleAnimationDelayVecDestructorType),
     }
 
-    /// `AzNormalizedLinearColorStopVecDestructorType` struct
-    pub type AzNormalizedLinearColorStopVecDestructorType = extern "C" fn(&mut AzNormalizedLinearColorStopVec);
+    /// `AzStyleAnimationDelayVecDestructorType` struct
+    pub type AzStyleAnimationDelayVecDestructorType = extern "C" fn(&mut AzStyleAnimationDelayVec);
 
-    /// Re-export of rust-allocated (stack based) `NormalizedRadialColorStopVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `CssKeyframeVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzNormalizedRadialColorStopVecDestructor {
+    pub enum AzCssKeyframeVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzNormalizedRadialColorStopVecDestructorType),
+        External(AzCssKeyframeVecDestructorType),
     }
 
-    /// `AzNormalizedRadialColorStopVecDestructorType` struct
-    pub type AzNormalizedRadialColorStopVecDestructorType = extern "C" fn(&mut AzNormalizedRadialColorStopVec);
+    /// `AzCssKeyframeVecDestructorType` struct
+    pub type AzCssKeyframeVecDestructorType = extern "C" fn(&mut AzCssKeyframeVec);
 
-    /// Re-export of rust-allocated (stack based) `NodeIdVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `CssKeyframesVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzNodeIdVecDestructor {
+    pub enum AzCssKeyframesVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzNodeIdVecDestructorType),
+        External(AzCssKeyframesVecDestructorType),
     }
 
-    /// `AzNodeIdVecDestructorType` struct
-    pub type AzNodeIdVecDestructorType = extern "C" fn(&mut AzNodeIdVec);
+    /// `AzCssKeyframesVecDestructorType` struct
+    pub type AzCssKeyframesVecDestructorType = extern "C" fn(&mut AzCssKeyframesVec);
 
-    /// Re-export of rust-allocated (stack based) `NodeVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `GridTrackSizingVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzNodeVecDestructor {
+    pub enum AzGridTrackSizingVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzNodeVecDestructorType),
+        External(AzGridTrackSizingVecDestructorType),
     }
 
-    /// `AzNodeVecDestructorType` struct
-    pub type AzNodeVecDestructorType = extern "C" fn(&mut AzNodeVec);
+    /// `AzGridTrackSizingVecDestructorType` struct
+    pub type AzGridTrackSizingVecDestructorType = extern "C" fn(&mut AzGridTrackSizingVec);
 
-    /// Re-export of rust-allocated (stack based) `StyledNodeVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `CssMediaQueryVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzStyledNodeVecDestructor {
+    pub enum AzCssMediaQueryVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzStyledNodeVecDestructorType),
+        External(AzCssMediaQueryVecDestructorType),
     }
 
-    /// `AzStyledNodeVecDestructorType` struct
+    /// `AzCssMediaQueryVecDestructorType` struct
+    pub type AzCssMediaQueryVecDestructorType = extern "C" fn(&mut AzCssMediaQueryVec);
+
+    /// Re-export of rust-allocated (stack based) `CssMediaConditionVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzCssMediaConditionVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssMediaConditionVecDestructorType),
+    }
+
+    /// `AzCssMediaConditionVecDestructorType` struct
+    pub type AzCssMediaConditionVecDestructorType = extern "C" fn(&mut AzCssMediaConditionVec);
+
+    /// Re-export of rust-allocated (stack based) `CssRuleBlockVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzCssRuleBlockVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssRuleBlockVecDestructorType),
+    }
+
+    /// `AzCssRuleBlockVecDestructorType` struct
+    pub type AzCssRuleBlockVecDestructorType = extern "C" fn(&mut AzCssRuleBlockVec);
+
+    /// Re-export of rust-allocated (stack based) `F32VecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzF32VecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzF32VecDestructorType),
+    }
+
+    /// `AzF32VecDestructorType` struct
+    pub type AzF32VecDestructorType = extern "C" fn(&mut AzF32Vec);
+
+    /// Re-export of rust-allocated (stack based) `U16VecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzU16VecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzU16VecDestructorType),
+    }
+
+    /// `AzU16VecDestructorType` struct
+    pub type AzU16VecDestructorType = extern "C" fn(&mut AzU16Vec);
+
+    /// Re-export of rust-allocated (stack based) `U8VecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzU8VecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzU8VecDestructorType),
+    }
+
+    /// `AzU8VecDestructorType` struct
+    pub type AzU8VecDestructorType = extern "C" fn(&mut AzU8Vec);
+
+    /// Re-export of rust-allocated (stack based) `CallbackDataVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzCallbackDataVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCallbackDataVecDestructorType),
+    }
+
+    /// `AzCallbackDataVecDestructorType` struct
+    pub type AzCallbackDataVecDestructorType = extern "C" fn(&mut AzCallbackDataVec);
+
+    /// Re-export of rust-allocated (stack based) `DebugMessageVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzDebugMessageVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzDebugMessageVecDestructorType),
+    }
+
+    /// `AzDebugMessageVecDestructorType` struct
+    pub type AzDebugMessageVecDestructorType = extern "C" fn(&mut AzDebugMessageVec);
+
+    /// Re-export of rust-allocated (stack based) `GLuintVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzGLuintVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzGLuintVecDestructorType),
+    }
+
+    /// `AzGLuintVecDestructorType` struct
+    pub type AzGLuintVecDestructorType = extern "C" fn(&mut AzGLuintVec);
+
+    /// Re-export of rust-allocated (stack based) `GLintVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzGLintVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzGLintVecDestructorType),
+    }
+
+    /// `AzGLintVecDestructorType` struct
+    pub type AzGLintVecDestructorType = extern "C" fn(&mut AzGLintVec);
+
+    /// Re-export of rust-allocated (stack based) `StringVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzStringVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStringVecDestructorType),
+    }
+
+    /// `AzStringVecDestructorType` struct
+    pub type AzStringVecDestructorType = extern "C" fn(&mut AzStringVec);
+
+    /// Re-export of rust-allocated (stack based) `StringPairVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzStringPairVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStringPairVecDestructorType),
+    }
+
+    /// `AzStringPairVecDestructorType` struct
+    pub type AzStringPairVecDestructorType = extern "C" fn(&mut AzStringPairVec);
+
+    /// Re-export of rust-allocated (stack based) `NormalizedLinearColorStopVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzNormalizedLinearColorStopVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzNormalizedLinearColorStopVecDestructorType),
+    }
+
+    /// `AzNormalizedLinearColorStopVecDestructorType` struct
+    pub type AzNormalizedLinearColorStopVecDestructorType = extern "C" fn(&mut AzNormalizedLinearColorStopVec);
+
+    /// Re-export of rust-allocated (stack based) `NormalizedRadialColorStopVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzNormalizedRadialColorStopVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzNormalizedRadialColorStopVecDestructorType),
+    }
+
+    /// `AzNormalizedRadialColorStopVecDestructorType` struct
+    pub type AzNormalizedRadialColorStopVecDestructorType = extern "C" fn(&mut AzNormalizedRadialColorStopVec);
+
+    /// Re-export of rust-allocated (stack based) `NodeIdVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzNodeIdVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzNodeIdVecDestructorType),
+    }
+
+    /// `AzNodeIdVecDestructorType` struct
+    pub type AzNodeIdVecDestructorType = extern "C" fn(&mut AzNodeIdVec);
+
+    /// Re-export of rust-allocated (stack based) `NodeVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzNodeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzNodeVecDestructorType),
+    }
+
+    /// `AzNodeVecDestructorType` struct
+    pub type AzNodeVecDestructorType = extern "C" fn(&mut AzNodeVec);
+
+    /// Re-export of rust-allocated (stack based) `StyledNodeVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzStyledNodeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyledNodeVecDestructorType),
+    }
+
+    /// `AzStyledNodeVecDestructorType` struct
     pub type AzStyledNodeVecDestructorType = extern "C" fn(&mut AzStyledNodeVec);
 
     /// Re-export of rust-allocated (stack based) `TagIdToNodeIdMappingVecDestructor` struct
@@ -6431,6 +7716,39 @@ mod test_sizes {
     /// `AzNodeDataVecDestructorType` struct
     pub type AzNodeDataVecDestructorType = extern "C" fn(&mut AzNodeDataVec);
 
+    /// Re-export of rust-allocated (stack based) `TextEditSnapshotVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzTextEditSnapshotVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTextEditSnapshotVecDestructorType),
+    }
+
+    /// `AzTextEditSnapshotVecDestructorType` struct
+    pub type AzTextEditSnapshotVecDestructorType = extern "C" fn(&mut AzTextEditSnapshotVec);
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetSizeOverrideVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzSpreadsheetSizeOverrideVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzSpreadsheetSizeOverrideVecDestructorType),
+    }
+
+    /// `AzSpreadsheetSizeOverrideVecDestructorType` struct
+    pub type AzSpreadsheetSizeOverrideVecDestructorType = extern "C" fn(&mut AzSpreadsheetSizeOverrideVec);
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetCellContentVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzSpreadsheetCellContentVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzSpreadsheetCellContentVecDestructorType),
+    }
+
+    /// `AzSpreadsheetCellContentVecDestructorType` struct
+    pub type AzSpreadsheetCellContentVecDestructorType = extern "C" fn(&mut AzSpreadsheetCellContentVec);
+
     /// Re-export of rust-allocated (stack based) `OptionI16` struct
     #[repr(C, u8)]
     pub enum AzOptionI16 {
@@ -6628,6 +7946,7 @@ mod test_sizes {
         pub bounds: AzLogicalRect,
         pub unicode_codepoint: AzOptionChar,
         pub glyph_index: u32,
+        pub fallback_font_index: usize,
     }
 
     /// Re-export of rust-allocated (stack based) `InlineTextHit` struct
@@ -6649,6 +7968,8 @@ mod test_sizes {
         pub char_index_relative_to_line: usize,
         pub glyph_index_relative_to_word: usize,
         pub char_index_relative_to_word: usize,
+        pub is_rtl: bool,
+        pub cursor_char_index_relative_to_text: usize,
     }
 
     /// Re-export of rust-allocated (stack based) `IFrameCallbackInfo` struct
@@ -6740,6 +8061,56 @@ mod test_sizes {
         pub clip_mode: AzBoxShadowClipMode,
     }
 
+    /// Re-export of rust-allocated (stack based) `GridTrackBreadth` struct
+    #[repr(C, u8)]
+    pub enum AzGridTrackBreadth {
+        Fixed(AzPixelValue),
+        Fraction(AzFloatValue),
+        Auto,
+    }
+
+    /// `minmax(min, max)` track size
+    #[repr(C)]
+    pub struct AzGridMinMax {
+        pub min: AzGridTrackBreadth,
+        pub max: AzGridTrackBreadth,
+    }
+
+    /// Size of a single column / row track of a `grid-template-*` declaration
+    #[repr(C, u8)]
+    pub enum AzGridTrackSizing {
+        Fixed(AzPixelValue),
+        Fraction(AzFloatValue),
+        Auto,
+        MinMax(AzGridMinMax),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridColumn` struct
+    #[repr(C)]
+    pub struct AzLayoutGridColumn {
+        pub start: AzGridLine,
+        pub end: AzGridLine,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridRow` struct
+    #[repr(C)]
+    pub struct AzLayoutGridRow {
+        pub start: AzGridLine,
+        pub end: AzGridLine,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutColumnGap` struct
+    #[repr(C)]
+    pub struct AzLayoutColumnGap {
+        pub inner: AzPixelValue,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutRowGap` struct
+    #[repr(C)]
+    pub struct AzLayoutRowGap {
+        pub inner: AzPixelValue,
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutBottom` struct
     #[repr(C)]
     pub struct AzLayoutBottom {
@@ -7074,6 +8445,22 @@ mod test_sizes {
         pub y: AzPixelValue,
     }
 
+    /// Property that is animated by a `transition` (`all` or a single property)
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionProperty {
+        All,
+        Property(AzCssPropertyType),
+    }
+
+    /// Control points of a `cubic-bezier(x1, y1, x2, y2)` timing function
+    #[repr(C)]
+    pub struct AzStyleCubicBezier {
+        pub x1: AzFloatValue,
+        pub y1: AzFloatValue,
+        pub x2: AzFloatValue,
+        pub y2: AzFloatValue,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTransformMatrix2D` struct
     #[repr(C)]
     pub struct AzStyleTransformMatrix2D {
@@ -7184,6 +8571,46 @@ mod test_sizes {
         Exact(AzLayoutAlignContent),
     }
 
+    /// Re-export of rust-allocated (stack based) `LayoutGridColumnValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutGridColumnValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutGridColumn),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridRowValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutGridRowValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutGridRow),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutColumnGapValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutColumnGapValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutColumnGap),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutRowGapValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutRowGapValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutRowGap),
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutAlignItemsValue` struct
     #[repr(C, u8)]
     pub enum AzLayoutAlignItemsValue {
@@ -7264,6 +8691,16 @@ mod test_sizes {
         Exact(AzLayoutFloat),
     }
 
+    /// Re-export of rust-allocated (stack based) `LayoutClearValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutClearValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutClear),
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutHeightValue` struct
     #[repr(C, u8)]
     pub enum AzLayoutHeightValue {
@@ -7823,7 +9260,108 @@ mod test_sizes {
         pub callback: AzNumberInputOnValueChangeCallback,
     }
 
-    /// Re-export of rust-allocated (stack based) `ParentWithNodeDepth` struct
+    /// Re-export of rust-allocated (stack based) `TextEditView` struct
+    #[repr(C)]
+    pub struct AzTextEditView {
+        pub preferred_caret_x: AzOptionF32,
+        pub scroll_y: f32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditOnTextChange` struct
+    #[repr(C)]
+    pub struct AzTextEditOnTextChange {
+        pub data: AzRefAny,
+        pub callback: AzTextEditOnTextChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditOnFocusLost` struct
+    #[repr(C)]
+    pub struct AzTextEditOnFocusLost {
+        pub data: AzRefAny,
+        pub callback: AzTextEditOnFocusLostCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderState` struct
+    #[repr(C)]
+    pub struct AzSliderState {
+        pub value: f32,
+        pub min: f32,
+        pub max: f32,
+        pub step: f32,
+        pub orientation: AzSliderOrientation,
+        pub tick_count: usize,
+        pub show_tick_labels: bool,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderOnValueChange` struct
+    #[repr(C)]
+    pub struct AzSliderOnValueChange {
+        pub data: AzRefAny,
+        pub callback: AzSliderOnValueChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChange` struct
+    #[repr(C)]
+    pub struct AzDropDownOnChoiceChange {
+        pub data: AzRefAny,
+        pub callback: AzDropDownOnChoiceChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetStyle` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetStyle {
+        pub accent_color: AzColorU,
+    }
+
+    /// Represents a rectangular selection of table cells
+    #[repr(C)]
+    pub struct AzSpreadsheetCellSelection {
+        pub from_top_left: AzSpreadsheetCellIndex,
+        pub to_bottom_right: AzSpreadsheetCellIndex,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetRenderWindow` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetRenderWindow {
+        pub row_start: usize,
+        pub row_end: usize,
+        pub column_start: usize,
+        pub column_end: usize,
+        pub origin: AzLogicalPosition,
+        pub scroll_position: AzLogicalPosition,
+        pub viewport: AzLogicalSize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetDrag` struct
+    #[repr(C, u8)]
+    pub enum AzSpreadsheetDrag {
+        None,
+        SelectCells,
+        ResizeColumn(AzSpreadsheetColumnResize),
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetOnCellsChange` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetOnCellsChange {
+        pub data: AzRefAny,
+        pub callback: AzSpreadsheetOnCellsChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetOnSelectionChange` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetOnSelectionChange {
+        pub data: AzRefAny,
+        pub callback: AzSpreadsheetOnSelectionChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetOnColumnResize` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetOnColumnResize {
+        pub data: AzRefAny,
+        pub callback: AzSpreadsheetOnColumnResizeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `ParentWithNodeDepth` struct
     #[repr(C)]
     pub struct AzParentWithNodeDepth {
         pub depth: usize,
@@ -7995,6 +9533,15 @@ mod test_sizes {
         pub destructor: AzAccessibilityStateVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<AccessibilityNode>`
+    #[repr(C)]
+    pub struct AzAccessibilityNodeVec {
+        pub(crate) ptr: *const AzAccessibilityNode,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzAccessibilityNodeVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<MenuItem>`
     #[repr(C)]
     pub struct AzMenuItemVec {
@@ -8130,6 +9677,96 @@ mod test_sizes {
         pub destructor: AzScanCodeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `CssPathSelector`
+    #[repr(C)]
+    pub struct AzCssPathSelectorVec {
+        pub(crate) ptr: *const AzCssPathSelector,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssPathSelectorVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionProperty>`
+    #[repr(C)]
+    pub struct AzStyleTransitionPropertyVec {
+        pub(crate) ptr: *const AzStyleTransitionProperty,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleTransitionPropertyVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionDuration>`
+    #[repr(C)]
+    pub struct AzStyleTransitionDurationVec {
+        pub(crate) ptr: *const AzStyleTransitionDuration,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleTransitionDurationVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionDelay>`
+    #[repr(C)]
+    pub struct AzStyleTransitionDelayVec {
+        pub(crate) ptr: *const AzStyleTransitionDelay,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleTransitionDelayVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationDuration>`
+    #[repr(C)]
+    pub struct AzStyleAnimationDurationVec {
+        pub(crate) ptr: *const AzStyleAnimationDuration,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationDurationVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationIterationCount>`
+    #[repr(C)]
+    pub struct AzStyleAnimationIterationCountVec {
+        pub(crate) ptr: *const AzStyleAnimationIterationCount,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationIterationCountVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationDirection>`
+    #[repr(C)]
+    pub struct AzStyleAnimationDirectionVec {
+        pub(crate) ptr: *const AzStyleAnimationDirection,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationDirectionVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationFillMode>`
+    #[repr(C)]
+    pub struct AzStyleAnimationFillModeVec {
+        pub(crate) ptr: *const AzStyleAnimationFillMode,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationFillModeVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationDelay>`
+    #[repr(C)]
+    pub struct AzStyleAnimationDelayVec {
+        pub(crate) ptr: *const AzStyleAnimationDelay,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationDelayVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<GridTrackSizing>`
+    #[repr(C)]
+    pub struct AzGridTrackSizingVec {
+        pub(crate) ptr: *const AzGridTrackSizing,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzGridTrackSizingVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<u16>`
     #[repr(C)]
     pub struct AzU16Vec {
@@ -8220,6 +9857,15 @@ mod test_sizes {
         pub destructor: AzParentWithNodeDepthVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<SpreadsheetSizeOverride>`
+    #[repr(C)]
+    pub struct AzSpreadsheetSizeOverrideVec {
+        pub(crate) ptr: *const AzSpreadsheetSizeOverride,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzSpreadsheetSizeOverrideVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionColorInputOnValueChange` struct
     #[repr(C, u8)]
     pub enum AzOptionColorInputOnValueChange {
@@ -8276,6 +9922,69 @@ mod test_sizes {
         Some(AzNumberInputOnValueChange),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTextEditOnTextChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTextEditOnTextChange {
+        None,
+        Some(AzTextEditOnTextChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTextEditOnFocusLost` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTextEditOnFocusLost {
+        None,
+        Some(AzTextEditOnFocusLost),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTextEditKind` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTextEditKind {
+        None,
+        Some(AzTextEditKind),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSliderOnValueChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionSliderOnValueChange {
+        None,
+        Some(AzSliderOnValueChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionDropDownOnChoiceChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionDropDownOnChoiceChange {
+        None,
+        Some(AzDropDownOnChoiceChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSpreadsheetOnCellsChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionSpreadsheetOnCellsChange {
+        None,
+        Some(AzSpreadsheetOnCellsChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSpreadsheetOnSelectionChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionSpreadsheetOnSelectionChange {
+        None,
+        Some(AzSpreadsheetOnSelectionChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSpreadsheetOnColumnResize` struct
+    #[repr(C, u8)]
+    pub enum AzOptionSpreadsheetOnColumnResize {
+        None,
+        Some(AzSpreadsheetOnColumnResize),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSpreadsheetCellSelection` struct
+    #[repr(C, u8)]
+    pub enum AzOptionSpreadsheetCellSelection {
+        None,
+        Some(AzSpreadsheetCellSelection),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionMenuItemIcon` struct
     #[repr(C, u8)]
     pub enum AzOptionMenuItemIcon {
@@ -8646,6 +10355,7 @@ mod test_sizes {
     pub struct AzInlineTextContents {
         pub glyphs: AzInlineGlyphVec,
         pub bounds: AzLogicalRect,
+        pub is_rtl: bool,
     }
 
     /// Easing function of the animation (ease-in, ease-out, ease-in-out, custom)
@@ -8712,6 +10422,32 @@ mod test_sizes {
         pub keys: AzVirtualKeyCodeVec,
     }
 
+    /// Window-wide keyboard shortcut that is not attached to a menu item
+    #[repr(C)]
+    pub struct AzKeyboardShortcut {
+        pub keys: AzVirtualKeyCodeCombo,
+        pub callback: AzMenuCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `CssMediaCondition` struct
+    #[repr(C, u8)]
+    pub enum AzCssMediaCondition {
+        MinWidth(AzPixelValue),
+        MaxWidth(AzPixelValue),
+        MinHeight(AzPixelValue),
+        MaxHeight(AzPixelValue),
+        MinResolution(AzFloatValue),
+        MaxResolution(AzFloatValue),
+        Orientation(AzCssMediaOrientation),
+        PrefersColorScheme(AzCssMediaColorScheme),
+    }
+
+    /// Re-export of rust-allocated (stack based) `CssPath` struct
+    #[repr(C)]
+    pub struct AzCssPath {
+        pub selectors: AzCssPathSelectorVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssPathPseudoSelector` struct
     #[repr(C, u8)]
     pub enum AzCssPathPseudoSelector {
@@ -8721,6 +10457,12 @@ mod test_sizes {
         Hover,
         Active,
         Focus,
+        FirstChild,
+        LastChild,
+        OnlyChild,
+        NthLastChild(AzCssNthChildSelector),
+        Empty,
+        FocusWithin,
     }
 
     /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
@@ -8744,6 +10486,18 @@ mod test_sizes {
         pub current_rect_height: f32,
     }
 
+    /// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumns` struct
+    #[repr(C)]
+    pub struct AzLayoutGridTemplateColumns {
+        pub tracks: AzGridTrackSizingVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridTemplateRows` struct
+    #[repr(C)]
+    pub struct AzLayoutGridTemplateRows {
+        pub tracks: AzGridTrackSizingVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `LinearGradient` struct
     #[repr(C)]
     pub struct AzLinearGradient {
@@ -8771,6 +10525,17 @@ mod test_sizes {
         pub stops: AzNormalizedRadialColorStopVec,
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunction` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionTimingFunction {
+        Ease,
+        Linear,
+        EaseIn,
+        EaseOut,
+        EaseInOut,
+        CubicBezier(AzStyleCubicBezier),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTransform` struct
     #[repr(C, u8)]
     pub enum AzStyleTransform {
@@ -8797,6 +10562,26 @@ mod test_sizes {
         Perspective(AzPixelValue),
     }
 
+    /// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumnsValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutGridTemplateColumnsValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutGridTemplateColumns),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridTemplateRowsValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutGridTemplateRowsValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutGridTemplateRows),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBackgroundPositionVecValue` struct
     #[repr(C, u8)]
     pub enum AzStyleBackgroundPositionVecValue {
@@ -8827,18 +10612,130 @@ mod test_sizes {
         Exact(AzStyleBackgroundSizeVec),
     }
 
-    /// Re-export of rust-allocated (stack based) `CheckBoxStateWrapper` struct
+    /// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionPropertyVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTransitionPropertyVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransitionDurationVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionDurationVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTransitionDurationVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransitionDelayVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionDelayVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTransitionDelayVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDurationVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationDurationVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationDurationVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationIterationCountVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationIterationCountVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationDirectionVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationDirectionVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationFillModeVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationFillModeVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDelayVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationDelayVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationDelayVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `CheckBoxStateWrapper` struct
+    #[repr(C)]
+    pub struct AzCheckBoxStateWrapper {
+        pub inner: AzCheckBoxState,
+        pub on_toggle: AzOptionCheckBoxOnToggle,
+    }
+
+    /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
+    #[repr(C)]
+    pub struct AzNumberInputStateWrapper {
+        pub inner: AzNumberInputState,
+        pub on_value_change: AzOptionNumberInputOnValueChange,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditState` struct
+    #[repr(C)]
+    pub struct AzTextEditState {
+        pub text: AzU32Vec,
+        pub selection: AzOptionTextInputSelection,
+        pub cursor_pos: usize,
+        pub show_line_numbers: bool,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TextEditSnapshot` struct
+    #[repr(C)]
+    pub struct AzTextEditSnapshot {
+        pub text: AzU32Vec,
+        pub cursor_pos: usize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderStateWrapper` struct
     #[repr(C)]
-    pub struct AzCheckBoxStateWrapper {
-        pub inner: AzCheckBoxState,
-        pub on_toggle: AzOptionCheckBoxOnToggle,
+    pub struct AzSliderStateWrapper {
+        pub inner: AzSliderState,
+        pub on_value_change: AzOptionSliderOnValueChange,
+        pub is_dragging: bool,
+        pub drag_value: f32,
     }
 
-    /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
-    #[repr(C)]
-    pub struct AzNumberInputStateWrapper {
-        pub inner: AzNumberInputState,
-        pub on_value_change: AzOptionNumberInputOnValueChange,
+    /// Re-export of rust-allocated (stack based) `CssPropertySource` struct
+    #[repr(C, u8)]
+    pub enum AzCssPropertySource {
+        Css(AzCssPath),
+        Inline,
     }
 
     /// Re-export of rust-allocated (stack based) `StyledNode` struct
@@ -8989,6 +10886,33 @@ mod test_sizes {
         pub destructor: AzSvgPathElementVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<KeyboardShortcut>`
+    #[repr(C)]
+    pub struct AzKeyboardShortcutVec {
+        pub(crate) ptr: *const AzKeyboardShortcut,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzKeyboardShortcutVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `CssMediaCondition`
+    #[repr(C)]
+    pub struct AzCssMediaConditionVec {
+        pub(crate) ptr: *const AzCssMediaCondition,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssMediaConditionVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionTimingFunction>`
+    #[repr(C)]
+    pub struct AzStyleTransitionTimingFunctionVec {
+        pub(crate) ptr: *const AzStyleTransitionTimingFunction,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleTransitionTimingFunctionVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `StringVec`
     #[repr(C)]
     pub struct AzStringVec {
@@ -9016,6 +10940,15 @@ mod test_sizes {
         pub destructor: AzTagIdToNodeIdMappingVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<TextEditSnapshot>`
+    #[repr(C)]
+    pub struct AzTextEditSnapshotVec {
+        pub(crate) ptr: *const AzTextEditSnapshot,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTextEditSnapshotVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionVirtualKeyCodeCombo` struct
     #[repr(C, u8)]
     pub enum AzOptionVirtualKeyCodeCombo {
@@ -9223,6 +11156,13 @@ mod test_sizes {
         Word(AzInlineTextContents),
     }
 
+    /// CSS path to set the keyboard input focus
+    #[repr(C)]
+    pub struct AzFocusTargetPath {
+        pub dom: AzDomId,
+        pub css_path: AzCssPath,
+    }
+
     /// Re-export of rust-allocated (stack based) `CallbackData` struct
     #[repr(C)]
     pub struct AzCallbackData {
@@ -9253,6 +11193,26 @@ mod test_sizes {
         pub default_action: AzOptionString,
     }
 
+    /// Single node in the accessibility tree of a window, see `AccessibilityTree`
+    #[repr(C)]
+    pub struct AzAccessibilityNode {
+        pub node_id: AzDomNodeId,
+        pub role: AzAccessibilityRole,
+        pub name: AzOptionString,
+        pub value: AzOptionString,
+        pub states: AzAccessibilityStateVec,
+        pub accelerator: AzOptionVirtualKeyCodeCombo,
+        pub default_action: AzOptionString,
+        pub bounds: AzLogicalRect,
+        pub children: AzAccessibilityNodeVec,
+    }
+
+    /// Platform-neutral snapshot of the accessibility tree of all DOMs in a window, can be fed into an assistive-technology bridge (AT-SPI, UIA, NSAccessibility)
+    #[repr(C)]
+    pub struct AzAccessibilityTree {
+        pub root: AzAccessibilityNode,
+    }
+
     /// Re-export of rust-allocated (stack based) `IdOrClass` struct
     #[repr(C, u8)]
     pub enum AzIdOrClass {
@@ -9271,16 +11231,42 @@ mod test_sizes {
         pub children: AzMenuItemVec,
     }
 
-    /// Re-export of rust-allocated (stack based) `CssPathSelector` struct
+    /// Returned when a keyboard shortcut can't be registered because its key combination is already in use
     #[repr(C, u8)]
-    pub enum AzCssPathSelector {
-        Global,
-        Type(AzNodeTypeKey),
-        Class(AzString),
-        Id(AzString),
-        PseudoSelector(AzCssPathPseudoSelector),
-        DirectChildren,
-        Children,
+    pub enum AzShortcutConflict {
+        Shortcut(AzVirtualKeyCodeCombo),
+        MenuItem(AzString),
+        ContextMenuItem(AzString),
+    }
+
+    /// One query of an `@media` rule, matches if all conditions match
+    #[repr(C)]
+    pub struct AzCssMediaQuery {
+        pub conditions: AzCssMediaConditionVec,
+    }
+
+    /// Custom property (CSS variable) declaration, i.e. `--accent: #3366ff`
+    #[repr(C)]
+    pub struct AzCssCustomProperty {
+        pub name: AzString,
+        pub value: AzString,
+    }
+
+    /// Attribute selector such as `[data-state=open]`, matched against the attributes of a node
+    #[repr(C)]
+    pub struct AzCssAttributeSelector {
+        pub name: AzString,
+        pub op: AzCssAttributeSelectorOp,
+        pub value: AzString,
+    }
+
+    /// Font file declared via an `@font-face` rule
+    #[repr(C)]
+    pub struct AzCssFontFace {
+        pub font_family: AzString,
+        pub src: AzString,
+        pub font_weight: u16,
+        pub font_style: AzCssFontFaceStyle,
     }
 
     /// Re-export of rust-allocated (stack based) `StyleBackgroundContent` struct
@@ -9321,6 +11307,12 @@ mod test_sizes {
         Ref(AzFontRef),
     }
 
+    /// Name of the `@keyframes` rule that an `animation-name` refers to
+    #[repr(C)]
+    pub struct AzStyleAnimationName {
+        pub name: AzString,
+    }
+
     /// Re-export of rust-allocated (stack based) `ScrollbarStyleValue` struct
     #[repr(C, u8)]
     pub enum AzScrollbarStyleValue {
@@ -9341,6 +11333,16 @@ mod test_sizes {
         Exact(AzStyleTransformVec),
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunctionVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionTimingFunctionVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTransitionTimingFunctionVec),
+    }
+
     /// Re-export of rust-allocated (stack based) `ColorInputStateWrapper` struct
     #[repr(C)]
     pub struct AzColorInputStateWrapper {
@@ -9359,6 +11361,44 @@ mod test_sizes {
         pub cursor_pos: usize,
     }
 
+    /// Re-export of rust-allocated (stack based) `TextEditHistory` struct
+    #[repr(C)]
+    pub struct AzTextEditHistory {
+        pub undo_stack: AzTextEditSnapshotVec,
+        pub redo_stack: AzTextEditSnapshotVec,
+        pub last_edit: AzOptionTextEditKind,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DropDownState` struct
+    #[repr(C)]
+    pub struct AzDropDownState {
+        pub choices: AzStringVec,
+        pub disabled_choices: AzU32Vec,
+        pub selected: AzOptionUsize,
+        pub popup_position: AzMenuPopupPosition,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetCellContent` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetCellContent {
+        pub cell: AzSpreadsheetCellIndex,
+        pub text: AzString,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetCellClick` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetCellClick {
+        pub cell: AzSpreadsheetCellIndex,
+        pub time: AzInstant,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetCellEdit` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetCellEdit {
+        pub cell: AzSpreadsheetCellIndex,
+        pub text: AzString,
+    }
+
     /// Re-export of rust-allocated (stack based) `VertexAttribute` struct
     #[repr(C)]
     pub struct AzVertexAttribute {
@@ -9559,13 +11599,31 @@ mod test_sizes {
         pub destructor: AzVertexAttributeVecDestructor,
     }
 
-    /// Wrapper over a Rust-allocated `CssPathSelector`
+    /// Wrapper over a Rust-allocated `CssMediaQuery`
     #[repr(C)]
-    pub struct AzCssPathSelectorVec {
-        pub(crate) ptr: *const AzCssPathSelector,
+    pub struct AzCssMediaQueryVec {
+        pub(crate) ptr: *const AzCssMediaQuery,
         pub len: usize,
         pub cap: usize,
-        pub destructor: AzCssPathSelectorVecDestructor,
+        pub destructor: AzCssMediaQueryVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `CssFontFace`
+    #[repr(C)]
+    pub struct AzCssFontFaceVec {
+        pub(crate) ptr: *const AzCssFontFace,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssFontFaceVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationName>`
+    #[repr(C)]
+    pub struct AzStyleAnimationNameVec {
+        pub(crate) ptr: *const AzStyleAnimationName,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationNameVecDestructor,
     }
 
     /// Wrapper over a Rust-allocated `CallbackData`
@@ -9595,6 +11653,36 @@ mod test_sizes {
         pub destructor: AzStringPairVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<SpreadsheetCellContent>`
+    #[repr(C)]
+    pub struct AzSpreadsheetCellContentVec {
+        pub(crate) ptr: *const AzSpreadsheetCellContent,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzSpreadsheetCellContentVecDestructor,
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSpreadsheetCellClick` struct
+    #[repr(C, u8)]
+    pub enum AzOptionSpreadsheetCellClick {
+        None,
+        Some(AzSpreadsheetCellClick),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSpreadsheetCellEdit` struct
+    #[repr(C, u8)]
+    pub enum AzOptionSpreadsheetCellEdit {
+        None,
+        Some(AzSpreadsheetCellEdit),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionAccessibilityNode` struct
+    #[repr(C, u8)]
+    pub enum AzOptionAccessibilityNode {
+        None,
+        Some(AzAccessibilityNode),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionFileTypeList` struct
     #[repr(C, u8)]
     pub enum AzOptionFileTypeList {
@@ -9672,6 +11760,18 @@ mod test_sizes {
         pub bounds: AzLogicalRect,
     }
 
+    /// Defines the keyboard input focus target
+    #[repr(C, u8)]
+    pub enum AzFocusTarget {
+        Id(AzDomNodeId),
+        Path(AzFocusTargetPath),
+        Previous,
+        Next,
+        First,
+        Last,
+        NoFocus,
+    }
+
     /// Item entry in a menu or menu bar
     #[repr(C, u8)]
     pub enum AzMenuItem {
@@ -9680,10 +11780,20 @@ mod test_sizes {
         BreakLine,
     }
 
-    /// Re-export of rust-allocated (stack based) `CssPath` struct
-    #[repr(C)]
-    pub struct AzCssPath {
-        pub selectors: AzCssPathSelectorVec,
+    /// Re-export of rust-allocated (stack based) `CssPathSelector` struct
+    #[repr(C, u8)]
+    pub enum AzCssPathSelector {
+        Global,
+        Type(AzNodeTypeKey),
+        Class(AzString),
+        Id(AzString),
+        PseudoSelector(AzCssPathPseudoSelector),
+        Attribute(AzCssAttributeSelector),
+        Not(AzCssPathSelectorVec),
+        DirectChildren,
+        Children,
+        AdjacentSibling,
+        GeneralSibling,
     }
 
     /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
@@ -9706,6 +11816,16 @@ mod test_sizes {
         Exact(AzStyleFontFamilyVec),
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleAnimationNameVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationNameVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationNameVec),
+    }
+
     /// Parsed CSS key-value pair
     #[repr(C, u8)]
     pub enum AzCssProperty {
@@ -9720,6 +11840,7 @@ mod test_sizes {
         Cursor(AzStyleCursorValue),
         Display(AzLayoutDisplayValue),
         Float(AzLayoutFloatValue),
+        Clear(AzLayoutClearValue),
         BoxSizing(AzLayoutBoxSizingValue),
         Width(AzLayoutWidthValue),
         Height(AzLayoutHeightValue),
@@ -9739,6 +11860,12 @@ mod test_sizes {
         JustifyContent(AzLayoutJustifyContentValue),
         AlignItems(AzLayoutAlignItemsValue),
         AlignContent(AzLayoutAlignContentValue),
+        GridTemplateColumns(AzLayoutGridTemplateColumnsValue),
+        GridTemplateRows(AzLayoutGridTemplateRowsValue),
+        GridColumn(AzLayoutGridColumnValue),
+        GridRow(AzLayoutGridRowValue),
+        ColumnGap(AzLayoutColumnGapValue),
+        RowGap(AzLayoutRowGapValue),
         BackgroundContent(AzStyleBackgroundContentVecValue),
         BackgroundPosition(AzStyleBackgroundPositionVecValue),
         BackgroundSize(AzStyleBackgroundSizeVecValue),
@@ -9779,6 +11906,17 @@ mod test_sizes {
         TransformOrigin(AzStyleTransformOriginValue),
         PerspectiveOrigin(AzStylePerspectiveOriginValue),
         BackfaceVisibility(AzStyleBackfaceVisibilityValue),
+        TransitionProperty(AzStyleTransitionPropertyVecValue),
+        TransitionDuration(AzStyleTransitionDurationVecValue),
+        TransitionTimingFunction(AzStyleTransitionTimingFunctionVecValue),
+        TransitionDelay(AzStyleTransitionDelayVecValue),
+        AnimationName(AzStyleAnimationNameVecValue),
+        AnimationDuration(AzStyleAnimationDurationVecValue),
+        AnimationTimingFunction(AzStyleTransitionTimingFunctionVecValue),
+        AnimationIterationCount(AzStyleAnimationIterationCountVecValue),
+        AnimationDirection(AzStyleAnimationDirectionVecValue),
+        AnimationFillMode(AzStyleAnimationFillModeVecValue),
+        AnimationDelay(AzStyleAnimationDelayVecValue),
     }
 
     /// Re-export of rust-allocated (stack based) `TextInputStateWrapper` struct
@@ -9790,13 +11928,52 @@ mod test_sizes {
         pub on_focus_lost: AzOptionTextInputOnFocusLost,
         pub update_text_input_before_calling_focus_lost_fn: bool,
         pub update_text_input_before_calling_vk_down_fn: bool,
+        pub is_dragging: bool,
     }
 
-    /// Re-export of rust-allocated (stack based) `CssPropertySource` struct
-    #[repr(C, u8)]
-    pub enum AzCssPropertySource {
-        Css(AzCssPath),
-        Inline,
+    /// Re-export of rust-allocated (stack based) `TextEditStateWrapper` struct
+    #[repr(C)]
+    pub struct AzTextEditStateWrapper {
+        pub inner: AzTextEditState,
+        pub on_text_change: AzOptionTextEditOnTextChange,
+        pub on_focus_lost: AzOptionTextEditOnFocusLost,
+        pub history: AzTextEditHistory,
+        pub view: AzTextEditView,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DropDownStateWrapper` struct
+    #[repr(C)]
+    pub struct AzDropDownStateWrapper {
+        pub inner: AzDropDownState,
+        pub on_choice_change: AzOptionDropDownOnChoiceChange,
+        pub is_open: bool,
+        pub highlighted: AzOptionUsize,
+        pub search: AzString,
+        pub last_search_input: AzOptionInstant,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetState` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetState {
+        pub style: AzSpreadsheetStyle,
+        pub row_count: usize,
+        pub column_count: usize,
+        pub default_column_width: f32,
+        pub default_row_height: f32,
+        pub selection: AzOptionSpreadsheetCellSelection,
+        pub column_width_overrides: AzSpreadsheetSizeOverrideVec,
+        pub row_height_overrides: AzSpreadsheetSizeOverrideVec,
+        pub cell_contents: AzSpreadsheetCellContentVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetView` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetView {
+        pub rendered: AzSpreadsheetRenderWindow,
+        pub iframe_node: AzOptionDomNodeId,
+        pub drag: AzSpreadsheetDrag,
+        pub last_click: AzOptionSpreadsheetCellClick,
+        pub editing: AzOptionSpreadsheetCellEdit,
     }
 
     /// Re-export of rust-allocated (stack based) `VertexLayout` struct
@@ -9908,6 +12085,7 @@ mod test_sizes {
         pub background_color: AzColorU,
         pub layout_callback: AzLayoutCallback,
         pub close_callback: AzOptionCallback,
+        pub shortcuts: AzKeyboardShortcutVec,
     }
 
     /// Re-export of rust-allocated (stack based) `CallbackInfo` struct
@@ -9941,6 +12119,8 @@ mod test_sizes {
         pub images_changed_in_callbacks: *mut c_void,
         pub image_masks_changed_in_callbacks: *mut c_void,
         pub css_properties_changed_in_callbacks: *mut c_void,
+        pub css_variables_changed_in_callbacks: *mut c_void,
+        pub css_transitions_changed_in_callbacks: *mut c_void,
         pub current_scroll_states: *const c_void,
         pub nodes_scrolled_in_callback: *mut c_void,
         pub hit_dom_node: AzDomNodeId,
@@ -9960,13 +12140,6 @@ mod test_sizes {
         pub baseline_descender_px: f32,
     }
 
-    /// CSS path to set the keyboard input focus
-    #[repr(C)]
-    pub struct AzFocusTargetPath {
-        pub dom: AzDomId,
-        pub css_path: AzCssPath,
-    }
-
     /// Animation struct to start a new animation
     #[repr(C)]
     pub struct AzAnimation {
@@ -10007,6 +12180,23 @@ mod test_sizes {
         pub default_value: AzCssProperty,
     }
 
+    /// Single step (i.e. `50% { opacity: 0.5; }`) of a `@keyframes` rule
+    #[repr(C)]
+    pub struct AzCssKeyframe {
+        pub offset: AzFloatValue,
+        pub properties: AzCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SpreadsheetStateWrapper` struct
+    #[repr(C)]
+    pub struct AzSpreadsheetStateWrapper {
+        pub inner: AzSpreadsheetState,
+        pub on_cells_change: AzOptionSpreadsheetOnCellsChange,
+        pub on_selection_change: AzOptionSpreadsheetOnSelectionChange,
+        pub on_column_resize: AzOptionSpreadsheetOnColumnResize,
+        pub view: AzSpreadsheetView,
+    }
+
     /// Re-export of rust-allocated (stack based) `SvgNode` struct
     #[repr(C, u8)]
     pub enum AzSvgNode {
@@ -10033,6 +12223,15 @@ mod test_sizes {
         pub destructor: AzNodeDataInlineCssPropertyVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssKeyframe>`
+    #[repr(C)]
+    pub struct AzCssKeyframeVec {
+        pub(crate) ptr: *const AzCssKeyframe,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssKeyframeVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionWindowState` struct
     #[repr(C, u8)]
     pub enum AzOptionWindowState {
@@ -10073,18 +12272,6 @@ mod test_sizes {
         pub hot_reload: bool,
     }
 
-    /// Defines the keyboard input focus target
-    #[repr(C, u8)]
-    pub enum AzFocusTarget {
-        Id(AzDomNodeId),
-        Path(AzFocusTargetPath),
-        Previous,
-        Next,
-        First,
-        Last,
-        NoFocus,
-    }
-
     /// Represents one single DOM node (node type, classes, ids and callbacks are stored here)
     #[repr(C)]
     pub struct AzNodeData {
@@ -10101,6 +12288,14 @@ mod test_sizes {
     pub enum AzCssDeclaration {
         Static(AzCssProperty),
         Dynamic(AzDynamicCssProperty),
+        Custom(AzCssCustomProperty),
+    }
+
+    /// Animation declared via a `@keyframes` rule
+    #[repr(C)]
+    pub struct AzCssKeyframes {
+        pub name: AzString,
+        pub keyframes: AzCssKeyframeVec,
     }
 
     /// Re-export of rust-allocated (stack based) `Button` struct
@@ -10152,6 +12347,44 @@ mod test_sizes {
         pub state: AzNumberInputStateWrapper,
     }
 
+    /// Re-export of rust-allocated (stack based) `TextEdit` struct
+    #[repr(C)]
+    pub struct AzTextEdit {
+        pub state: AzTextEditStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub line_numbers_style: AzNodeDataInlineCssPropertyVec,
+        pub label_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `Slider` struct
+    #[repr(C)]
+    pub struct AzSlider {
+        pub state: AzSliderStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub track_style: AzNodeDataInlineCssPropertyVec,
+        pub thumb_style: AzNodeDataInlineCssPropertyVec,
+        pub tick_style: AzNodeDataInlineCssPropertyVec,
+        pub label_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `DropDown` struct
+    #[repr(C)]
+    pub struct AzDropDown {
+        pub state: AzDropDownStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub label_style: AzNodeDataInlineCssPropertyVec,
+        pub popup_style: AzNodeDataInlineCssPropertyVec,
+        pub entry_style: AzNodeDataInlineCssPropertyVec,
+        pub disabled_entry_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `Spreadsheet` struct
+    #[repr(C)]
+    pub struct AzSpreadsheet {
+        pub state: AzSpreadsheetStateWrapper,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+    }
+
     /// Wrapper over a Rust-allocated `CssDeclaration`
     #[repr(C)]
     pub struct AzCssDeclarationVec {
@@ -10161,6 +12394,15 @@ mod test_sizes {
         pub destructor: AzCssDeclarationVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssKeyframes>`
+    #[repr(C)]
+    pub struct AzCssKeyframesVec {
+        pub(crate) ptr: *const AzCssKeyframes,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssKeyframesVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `NodeDataVec`
     #[repr(C)]
     pub struct AzNodeDataVec {
@@ -10205,6 +12447,7 @@ mod test_sizes {
     pub struct AzCssRuleBlock {
         pub path: AzCssPath,
         pub declarations: AzCssDeclarationVec,
+        pub media_queries: AzCssMediaQueryVec,
     }
 
     /// Re-export of rust-allocated (stack based) `StyledDom` struct
@@ -10301,6 +12544,8 @@ mod test_sizes {
     #[repr(C)]
     pub struct AzCss {
         pub stylesheets: AzStylesheetVec,
+        pub font_faces: AzCssFontFaceVec,
+        pub keyframes: AzCssKeyframesVec,
     }
 
     use core::ffi::c_void;
@@ -10368,19 +12613,25 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::dom::TabIndex>(), "AzTabIndex"), (Layout::new::<AzTabIndex>(), "AzTabIndex"));
         assert_eq!((Layout::new::<azul_core::window::MenuPopupPosition>(), "AzMenuPopupPosition"), (Layout::new::<AzMenuPopupPosition>(), "AzMenuPopupPosition"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemState>(), "AzMenuItemState"), (Layout::new::<AzMenuItemState>(), "AzMenuItemState"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaOrientation>(), "AzCssMediaOrientation"), (Layout::new::<AzCssMediaOrientation>(), "AzCssMediaOrientation"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaColorScheme>(), "AzCssMediaColorScheme"), (Layout::new::<AzCssMediaColorScheme>(), "AzCssMediaColorScheme"));
         assert_eq!((Layout::new::<azul_impl::css::NodeTypeTag>(), "AzNodeTypeKey"), (Layout::new::<AzNodeTypeKey>(), "AzNodeTypeKey"));
+        assert_eq!((Layout::new::<azul_impl::css::CssAttributeSelectorOp>(), "AzCssAttributeSelectorOp"), (Layout::new::<AzCssAttributeSelectorOp>(), "AzCssAttributeSelectorOp"));
         assert_eq!((Layout::new::<azul_impl::css::CssNthChildPattern>(), "AzCssNthChildPattern"), (Layout::new::<AzCssNthChildPattern>(), "AzCssNthChildPattern"));
+        assert_eq!((Layout::new::<azul_impl::css::CssFontFaceStyle>(), "AzCssFontFaceStyle"), (Layout::new::<AzCssFontFaceStyle>(), "AzCssFontFaceStyle"));
         assert_eq!((Layout::new::<azul_impl::css::CssPropertyType>(), "AzCssPropertyType"), (Layout::new::<AzCssPropertyType>(), "AzCssPropertyType"));
         assert_eq!((Layout::new::<azul_impl::css::ColorU>(), "AzColorU"), (Layout::new::<AzColorU>(), "AzColorU"));
         assert_eq!((Layout::new::<azul_impl::css::SizeMetric>(), "AzSizeMetric"), (Layout::new::<AzSizeMetric>(), "AzSizeMetric"));
         assert_eq!((Layout::new::<azul_impl::css::FloatValue>(), "AzFloatValue"), (Layout::new::<AzFloatValue>(), "AzFloatValue"));
         assert_eq!((Layout::new::<azul_impl::css::BoxShadowClipMode>(), "AzBoxShadowClipMode"), (Layout::new::<AzBoxShadowClipMode>(), "AzBoxShadowClipMode"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutAlignContent>(), "AzLayoutAlignContent"), (Layout::new::<AzLayoutAlignContent>(), "AzLayoutAlignContent"));
+        assert_eq!((Layout::new::<azul_impl::css::GridLine>(), "AzGridLine"), (Layout::new::<AzGridLine>(), "AzGridLine"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutAlignItems>(), "AzLayoutAlignItems"), (Layout::new::<AzLayoutAlignItems>(), "AzLayoutAlignItems"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBoxSizing>(), "AzLayoutBoxSizing"), (Layout::new::<AzLayoutBoxSizing>(), "AzLayoutBoxSizing"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexDirection>(), "AzLayoutFlexDirection"), (Layout::new::<AzLayoutFlexDirection>(), "AzLayoutFlexDirection"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutDisplay>(), "AzLayoutDisplay"), (Layout::new::<AzLayoutDisplay>(), "AzLayoutDisplay"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFloat>(), "AzLayoutFloat"), (Layout::new::<AzLayoutFloat>(), "AzLayoutFloat"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutClear>(), "AzLayoutClear"), (Layout::new::<AzLayoutClear>(), "AzLayoutClear"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutJustifyContent>(), "AzLayoutJustifyContent"), (Layout::new::<AzLayoutJustifyContent>(), "AzLayoutJustifyContent"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutPosition>(), "AzLayoutPosition"), (Layout::new::<AzLayoutPosition>(), "AzLayoutPosition"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexWrap>(), "AzLayoutFlexWrap"), (Layout::new::<AzLayoutFlexWrap>(), "AzLayoutFlexWrap"));
@@ -10394,6 +12645,13 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::BorderStyle>(), "AzBorderStyle"), (Layout::new::<AzBorderStyle>(), "AzBorderStyle"));
         assert_eq!((Layout::new::<azul_impl::css::StyleCursor>(), "AzStyleCursor"), (Layout::new::<AzStyleCursor>(), "AzStyleCursor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackfaceVisibility>(), "AzStyleBackfaceVisibility"), (Layout::new::<AzStyleBackfaceVisibility>(), "AzStyleBackfaceVisibility"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionDuration>(), "AzStyleTransitionDuration"), (Layout::new::<AzStyleTransitionDuration>(), "AzStyleTransitionDuration"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionDelay>(), "AzStyleTransitionDelay"), (Layout::new::<AzStyleTransitionDelay>(), "AzStyleTransitionDelay"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDuration>(), "AzStyleAnimationDuration"), (Layout::new::<AzStyleAnimationDuration>(), "AzStyleAnimationDuration"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDelay>(), "AzStyleAnimationDelay"), (Layout::new::<AzStyleAnimationDelay>(), "AzStyleAnimationDelay"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationIterationCount>(), "AzStyleAnimationIterationCount"), (Layout::new::<AzStyleAnimationIterationCount>(), "AzStyleAnimationIterationCount"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDirection>(), "AzStyleAnimationDirection"), (Layout::new::<AzStyleAnimationDirection>(), "AzStyleAnimationDirection"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationFillMode>(), "AzStyleAnimationFillMode"), (Layout::new::<AzStyleAnimationFillMode>(), "AzStyleAnimationFillMode"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextAlign>(), "AzStyleTextAlign"), (Layout::new::<AzStyleTextAlign>(), "AzStyleTextAlign"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBoxOnToggleCallback>(), "AzCheckBoxOnToggleCallback"), (Layout::new::<AzCheckBoxOnToggleCallback>(), "AzCheckBoxOnToggleCallback"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBoxState>(), "AzCheckBoxState"), (Layout::new::<AzCheckBoxState>(), "AzCheckBoxState"));
@@ -10405,6 +12663,18 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputValid>(), "AzTextInputValid"), (Layout::new::<AzTextInputValid>(), "AzTextInputValid"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputState>(), "AzNumberInputState"), (Layout::new::<AzNumberInputState>(), "AzNumberInputState"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputOnValueChangeCallback>(), "AzNumberInputOnValueChangeCallback"), (Layout::new::<AzNumberInputOnValueChangeCallback>(), "AzNumberInputOnValueChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditKind>(), "AzTextEditKind"), (Layout::new::<AzTextEditKind>(), "AzTextEditKind"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditOnTextChangeCallback>(), "AzTextEditOnTextChangeCallback"), (Layout::new::<AzTextEditOnTextChangeCallback>(), "AzTextEditOnTextChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditOnFocusLostCallback>(), "AzTextEditOnFocusLostCallback"), (Layout::new::<AzTextEditOnFocusLostCallback>(), "AzTextEditOnFocusLostCallback"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderOrientation>(), "AzSliderOrientation"), (Layout::new::<AzSliderOrientation>(), "AzSliderOrientation"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderOnValueChangeCallback>(), "AzSliderOnValueChangeCallback"), (Layout::new::<AzSliderOnValueChangeCallback>(), "AzSliderOnValueChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownOnChoiceChangeCallback>(), "AzDropDownOnChoiceChangeCallback"), (Layout::new::<AzDropDownOnChoiceChangeCallback>(), "AzDropDownOnChoiceChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetCellIndex>(), "AzSpreadsheetCellIndex"), (Layout::new::<AzSpreadsheetCellIndex>(), "AzSpreadsheetCellIndex"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetSizeOverride>(), "AzSpreadsheetSizeOverride"), (Layout::new::<AzSpreadsheetSizeOverride>(), "AzSpreadsheetSizeOverride"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetColumnResize>(), "AzSpreadsheetColumnResize"), (Layout::new::<AzSpreadsheetColumnResize>(), "AzSpreadsheetColumnResize"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetOnCellsChangeCallback>(), "AzSpreadsheetOnCellsChangeCallback"), (Layout::new::<AzSpreadsheetOnCellsChangeCallback>(), "AzSpreadsheetOnCellsChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetOnSelectionChangeCallback>(), "AzSpreadsheetOnSelectionChangeCallback"), (Layout::new::<AzSpreadsheetOnSelectionChangeCallback>(), "AzSpreadsheetOnSelectionChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetOnColumnResizeCallback>(), "AzSpreadsheetOnColumnResizeCallback"), (Layout::new::<AzSpreadsheetOnColumnResizeCallback>(), "AzSpreadsheetOnColumnResizeCallback"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::AzNode>(), "AzNode"), (Layout::new::<AzNode>(), "AzNode"));
         assert_eq!((Layout::new::<azul_impl::style::CascadeInfo>(), "AzCascadeInfo"), (Layout::new::<AzCascadeInfo>(), "AzCascadeInfo"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::StyledNodeState>(), "AzStyledNodeState"), (Layout::new::<AzStyledNodeState>(), "AzStyledNodeState"));
@@ -10481,6 +12751,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::ThreadSenderDestructorCallback>(), "AzThreadSenderDestructorFn"), (Layout::new::<AzThreadSenderDestructorFn>(), "AzThreadSenderDestructorFn"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVecDestructor>(), "AzStyleFontFamilyVecDestructor"), (Layout::new::<AzStyleFontFamilyVecDestructor>(), "AzStyleFontFamilyVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityStateVecDestructor>(), "AzAccessibilityStateVecDestructor"), (Layout::new::<AzAccessibilityStateVecDestructor>(), "AzAccessibilityStateVecDestructor"));
+        assert_eq!((Layout::new::<azul_core::accessibility::AccessibilityNodeVecDestructor>(), "AzAccessibilityNodeVecDestructor"), (Layout::new::<AzAccessibilityNodeVecDestructor>(), "AzAccessibilityNodeVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemVecDestructor>(), "AzMenuItemVecDestructor"), (Layout::new::<AzMenuItemVecDestructor>(), "AzMenuItemVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedSvgNodeVecDestructor>(), "AzTessellatedSvgNodeVecDestructor"), (Layout::new::<AzTessellatedSvgNodeVecDestructor>(), "AzTessellatedSvgNodeVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlNodeVecDestructor>(), "AzXmlNodeVecDestructor"), (Layout::new::<AzXmlNodeVecDestructor>(), "AzXmlNodeVecDestructor"));
@@ -10508,11 +12779,28 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::U32VecDestructor>(), "AzU32VecDestructor"), (Layout::new::<AzU32VecDestructor>(), "AzU32VecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::XWindowTypeVecDestructor>(), "AzXWindowTypeVecDestructor"), (Layout::new::<AzXWindowTypeVecDestructor>(), "AzXWindowTypeVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::VirtualKeyCodeVecDestructor>(), "AzVirtualKeyCodeVecDestructor"), (Layout::new::<AzVirtualKeyCodeVecDestructor>(), "AzVirtualKeyCodeVecDestructor"));
+        assert_eq!((Layout::new::<azul_core::window::KeyboardShortcutVecDestructor>(), "AzKeyboardShortcutVecDestructor"), (Layout::new::<AzKeyboardShortcutVecDestructor>(), "AzKeyboardShortcutVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::style::CascadeInfoVecDestructor>(), "AzCascadeInfoVecDestructor"), (Layout::new::<AzCascadeInfoVecDestructor>(), "AzCascadeInfoVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::ScanCodeVecDestructor>(), "AzScanCodeVecDestructor"), (Layout::new::<AzScanCodeVecDestructor>(), "AzScanCodeVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::CssDeclarationVecDestructor>(), "AzCssDeclarationVecDestructor"), (Layout::new::<AzCssDeclarationVecDestructor>(), "AzCssDeclarationVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathSelectorVecDestructor>(), "AzCssPathSelectorVecDestructor"), (Layout::new::<AzCssPathSelectorVecDestructor>(), "AzCssPathSelectorVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::StylesheetVecDestructor>(), "AzStylesheetVecDestructor"), (Layout::new::<AzStylesheetVecDestructor>(), "AzStylesheetVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssFontFaceVecDestructor>(), "AzCssFontFaceVecDestructor"), (Layout::new::<AzCssFontFaceVecDestructor>(), "AzCssFontFaceVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionPropertyVecDestructor>(), "AzStyleTransitionPropertyVecDestructor"), (Layout::new::<AzStyleTransitionPropertyVecDestructor>(), "AzStyleTransitionPropertyVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionDurationVecDestructor>(), "AzStyleTransitionDurationVecDestructor"), (Layout::new::<AzStyleTransitionDurationVecDestructor>(), "AzStyleTransitionDurationVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionTimingFunctionVecDestructor>(), "AzStyleTransitionTimingFunctionVecDestructor"), (Layout::new::<AzStyleTransitionTimingFunctionVecDestructor>(), "AzStyleTransitionTimingFunctionVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionDelayVecDestructor>(), "AzStyleTransitionDelayVecDestructor"), (Layout::new::<AzStyleTransitionDelayVecDestructor>(), "AzStyleTransitionDelayVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationNameVecDestructor>(), "AzStyleAnimationNameVecDestructor"), (Layout::new::<AzStyleAnimationNameVecDestructor>(), "AzStyleAnimationNameVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDurationVecDestructor>(), "AzStyleAnimationDurationVecDestructor"), (Layout::new::<AzStyleAnimationDurationVecDestructor>(), "AzStyleAnimationDurationVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationIterationCountVecDestructor>(), "AzStyleAnimationIterationCountVecDestructor"), (Layout::new::<AzStyleAnimationIterationCountVecDestructor>(), "AzStyleAnimationIterationCountVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDirectionVecDestructor>(), "AzStyleAnimationDirectionVecDestructor"), (Layout::new::<AzStyleAnimationDirectionVecDestructor>(), "AzStyleAnimationDirectionVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationFillModeVecDestructor>(), "AzStyleAnimationFillModeVecDestructor"), (Layout::new::<AzStyleAnimationFillModeVecDestructor>(), "AzStyleAnimationFillModeVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDelayVecDestructor>(), "AzStyleAnimationDelayVecDestructor"), (Layout::new::<AzStyleAnimationDelayVecDestructor>(), "AzStyleAnimationDelayVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssKeyframeVecDestructor>(), "AzCssKeyframeVecDestructor"), (Layout::new::<AzCssKeyframeVecDestructor>(), "AzCssKeyframeVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssKeyframesVecDestructor>(), "AzCssKeyframesVecDestructor"), (Layout::new::<AzCssKeyframesVecDestructor>(), "AzCssKeyframesVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::GridTrackSizingVecDestructor>(), "AzGridTrackSizingVecDestructor"), (Layout::new::<AzGridTrackSizingVecDestructor>(), "AzGridTrackSizingVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaQueryVecDestructor>(), "AzCssMediaQueryVecDestructor"), (Layout::new::<AzCssMediaQueryVecDestructor>(), "AzCssMediaQueryVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaConditionVecDestructor>(), "AzCssMediaConditionVecDestructor"), (Layout::new::<AzCssMediaConditionVecDestructor>(), "AzCssMediaConditionVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::CssRuleBlockVecDestructor>(), "AzCssRuleBlockVecDestructor"), (Layout::new::<AzCssRuleBlockVecDestructor>(), "AzCssRuleBlockVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::F32VecDestructor>(), "AzF32VecDestructor"), (Layout::new::<AzF32VecDestructor>(), "AzF32VecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::U16VecDestructor>(), "AzU16VecDestructor"), (Layout::new::<AzU16VecDestructor>(), "AzU16VecDestructor"));
@@ -10531,6 +12819,9 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::styled_dom::TagIdToNodeIdMappingVecDestructor>(), "AzTagIdToNodeIdMappingVecDestructor"), (Layout::new::<AzTagIdToNodeIdMappingVecDestructor>(), "AzTagIdToNodeIdMappingVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::ParentWithNodeDepthVecDestructor>(), "AzParentWithNodeDepthVecDestructor"), (Layout::new::<AzParentWithNodeDepthVecDestructor>(), "AzParentWithNodeDepthVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataVecDestructor>(), "AzNodeDataVecDestructor"), (Layout::new::<AzNodeDataVecDestructor>(), "AzNodeDataVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditSnapshotVecDestructor>(), "AzTextEditSnapshotVecDestructor"), (Layout::new::<AzTextEditSnapshotVecDestructor>(), "AzTextEditSnapshotVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetSizeOverrideVecDestructor>(), "AzSpreadsheetSizeOverrideVecDestructor"), (Layout::new::<AzSpreadsheetSizeOverrideVecDestructor>(), "AzSpreadsheetSizeOverrideVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetCellContentVecDestructor>(), "AzSpreadsheetCellContentVecDestructor"), (Layout::new::<AzSpreadsheetCellContentVecDestructor>(), "AzSpreadsheetCellContentVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::OptionI16>(), "AzOptionI16"), (Layout::new::<AzOptionI16>(), "AzOptionI16"));
         assert_eq!((Layout::new::<azul_impl::css::OptionU16>(), "AzOptionU16"), (Layout::new::<AzOptionU16>(), "AzOptionU16"));
         assert_eq!((Layout::new::<azul_impl::css::OptionU32>(), "AzOptionU32"), (Layout::new::<AzOptionU32>(), "AzOptionU32"));
@@ -10568,6 +12859,13 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::PixelValue>(), "AzPixelValue"), (Layout::new::<AzPixelValue>(), "AzPixelValue"));
         assert_eq!((Layout::new::<azul_impl::css::PixelValueNoPercent>(), "AzPixelValueNoPercent"), (Layout::new::<AzPixelValueNoPercent>(), "AzPixelValueNoPercent"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBoxShadow>(), "AzStyleBoxShadow"), (Layout::new::<AzStyleBoxShadow>(), "AzStyleBoxShadow"));
+        assert_eq!((Layout::new::<azul_impl::css::GridTrackBreadth>(), "AzGridTrackBreadth"), (Layout::new::<AzGridTrackBreadth>(), "AzGridTrackBreadth"));
+        assert_eq!((Layout::new::<azul_impl::css::GridMinMax>(), "AzGridMinMax"), (Layout::new::<AzGridMinMax>(), "AzGridMinMax"));
+        assert_eq!((Layout::new::<azul_impl::css::GridTrackSizing>(), "AzGridTrackSizing"), (Layout::new::<AzGridTrackSizing>(), "AzGridTrackSizing"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridColumn>(), "AzLayoutGridColumn"), (Layout::new::<AzLayoutGridColumn>(), "AzLayoutGridColumn"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridRow>(), "AzLayoutGridRow"), (Layout::new::<AzLayoutGridRow>(), "AzLayoutGridRow"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutColumnGap>(), "AzLayoutColumnGap"), (Layout::new::<AzLayoutColumnGap>(), "AzLayoutColumnGap"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutRowGap>(), "AzLayoutRowGap"), (Layout::new::<AzLayoutRowGap>(), "AzLayoutRowGap"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBottom>(), "AzLayoutBottom"), (Layout::new::<AzLayoutBottom>(), "AzLayoutBottom"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexGrow>(), "AzLayoutFlexGrow"), (Layout::new::<AzLayoutFlexGrow>(), "AzLayoutFlexGrow"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexShrink>(), "AzLayoutFlexShrink"), (Layout::new::<AzLayoutFlexShrink>(), "AzLayoutFlexShrink"));
@@ -10621,6 +12919,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleOpacity>(), "AzStyleOpacity"), (Layout::new::<AzStyleOpacity>(), "AzStyleOpacity"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformOrigin>(), "AzStyleTransformOrigin"), (Layout::new::<AzStyleTransformOrigin>(), "AzStyleTransformOrigin"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformOrigin>(), "AzStylePerspectiveOrigin"), (Layout::new::<AzStylePerspectiveOrigin>(), "AzStylePerspectiveOrigin"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionProperty>(), "AzStyleTransitionProperty"), (Layout::new::<AzStyleTransitionProperty>(), "AzStyleTransitionProperty"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCubicBezier>(), "AzStyleCubicBezier"), (Layout::new::<AzStyleCubicBezier>(), "AzStyleCubicBezier"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformMatrix2D>(), "AzStyleTransformMatrix2D"), (Layout::new::<AzStyleTransformMatrix2D>(), "AzStyleTransformMatrix2D"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformMatrix3D>(), "AzStyleTransformMatrix3D"), (Layout::new::<AzStyleTransformMatrix3D>(), "AzStyleTransformMatrix3D"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformTranslate2D>(), "AzStyleTransformTranslate2D"), (Layout::new::<AzStyleTransformTranslate2D>(), "AzStyleTransformTranslate2D"));
@@ -10633,6 +12933,10 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleWordSpacing>(), "AzStyleWordSpacing"), (Layout::new::<AzStyleWordSpacing>(), "AzStyleWordSpacing"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBoxShadowValue>(), "AzStyleBoxShadowValue"), (Layout::new::<AzStyleBoxShadowValue>(), "AzStyleBoxShadowValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutAlignContentValue>(), "AzLayoutAlignContentValue"), (Layout::new::<AzLayoutAlignContentValue>(), "AzLayoutAlignContentValue"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridColumnValue>(), "AzLayoutGridColumnValue"), (Layout::new::<AzLayoutGridColumnValue>(), "AzLayoutGridColumnValue"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridRowValue>(), "AzLayoutGridRowValue"), (Layout::new::<AzLayoutGridRowValue>(), "AzLayoutGridRowValue"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutColumnGapValue>(), "AzLayoutColumnGapValue"), (Layout::new::<AzLayoutColumnGapValue>(), "AzLayoutColumnGapValue"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutRowGapValue>(), "AzLayoutRowGapValue"), (Layout::new::<AzLayoutRowGapValue>(), "AzLayoutRowGapValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutAlignItemsValue>(), "AzLayoutAlignItemsValue"), (Layout::new::<AzLayoutAlignItemsValue>(), "AzLayoutAlignItemsValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBottomValue>(), "AzLayoutBottomValue"), (Layout::new::<AzLayoutBottomValue>(), "AzLayoutBottomValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBoxSizingValue>(), "AzLayoutBoxSizingValue"), (Layout::new::<AzLayoutBoxSizingValue>(), "AzLayoutBoxSizingValue"));
@@ -10641,6 +12945,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexGrowValue>(), "AzLayoutFlexGrowValue"), (Layout::new::<AzLayoutFlexGrowValue>(), "AzLayoutFlexGrowValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexShrinkValue>(), "AzLayoutFlexShrinkValue"), (Layout::new::<AzLayoutFlexShrinkValue>(), "AzLayoutFlexShrinkValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFloatValue>(), "AzLayoutFloatValue"), (Layout::new::<AzLayoutFloatValue>(), "AzLayoutFloatValue"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutClearValue>(), "AzLayoutClearValue"), (Layout::new::<AzLayoutClearValue>(), "AzLayoutClearValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutHeightValue>(), "AzLayoutHeightValue"), (Layout::new::<AzLayoutHeightValue>(), "AzLayoutHeightValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutJustifyContentValue>(), "AzLayoutJustifyContentValue"), (Layout::new::<AzLayoutJustifyContentValue>(), "AzLayoutJustifyContentValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutLeftValue>(), "AzLayoutLeftValue"), (Layout::new::<AzLayoutLeftValue>(), "AzLayoutLeftValue"));
@@ -10700,6 +13005,19 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnFocusLost>(), "AzTextInputOnFocusLost"), (Layout::new::<AzTextInputOnFocusLost>(), "AzTextInputOnFocusLost"));
         assert_eq!((Layout::new::<crate::widgets::text_input::OnTextInputReturn>(), "AzOnTextInputReturn"), (Layout::new::<AzOnTextInputReturn>(), "AzOnTextInputReturn"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputOnValueChange>(), "AzNumberInputOnValueChange"), (Layout::new::<AzNumberInputOnValueChange>(), "AzNumberInputOnValueChange"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditView>(), "AzTextEditView"), (Layout::new::<AzTextEditView>(), "AzTextEditView"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditOnTextChange>(), "AzTextEditOnTextChange"), (Layout::new::<AzTextEditOnTextChange>(), "AzTextEditOnTextChange"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditOnFocusLost>(), "AzTextEditOnFocusLost"), (Layout::new::<AzTextEditOnFocusLost>(), "AzTextEditOnFocusLost"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderState>(), "AzSliderState"), (Layout::new::<AzSliderState>(), "AzSliderState"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderOnValueChange>(), "AzSliderOnValueChange"), (Layout::new::<AzSliderOnValueChange>(), "AzSliderOnValueChange"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownOnChoiceChange>(), "AzDropDownOnChoiceChange"), (Layout::new::<AzDropDownOnChoiceChange>(), "AzDropDownOnChoiceChange"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetStyle>(), "AzSpreadsheetStyle"), (Layout::new::<AzSpreadsheetStyle>(), "AzSpreadsheetStyle"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetCellSelection>(), "AzSpreadsheetCellSelection"), (Layout::new::<AzSpreadsheetCellSelection>(), "AzSpreadsheetCellSelection"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetRenderWindow>(), "AzSpreadsheetRenderWindow"), (Layout::new::<AzSpreadsheetRenderWindow>(), "AzSpreadsheetRenderWindow"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetDrag>(), "AzSpreadsheetDrag"), (Layout::new::<AzSpreadsheetDrag>(), "AzSpreadsheetDrag"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetOnCellsChange>(), "AzSpreadsheetOnCellsChange"), (Layout::new::<AzSpreadsheetOnCellsChange>(), "AzSpreadsheetOnCellsChange"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetOnSelectionChange>(), "AzSpreadsheetOnSelectionChange"), (Layout::new::<AzSpreadsheetOnSelectionChange>(), "AzSpreadsheetOnSelectionChange"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetOnColumnResize>(), "AzSpreadsheetOnColumnResize"), (Layout::new::<AzSpreadsheetOnColumnResize>(), "AzSpreadsheetOnColumnResize"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::ParentWithNodeDepth>(), "AzParentWithNodeDepth"), (Layout::new::<AzParentWithNodeDepth>(), "AzParentWithNodeDepth"));
         assert_eq!((Layout::new::<azul_impl::gl::GlContextPtr>(), "AzGl"), (Layout::new::<AzGl>(), "AzGl"));
         assert_eq!((Layout::new::<azul_impl::gl::RefstrVecRef>(), "AzRefstrVecRef"), (Layout::new::<AzRefstrVecRef>(), "AzRefstrVecRef"));
@@ -10715,6 +13033,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::ThreadSendMsg>(), "AzThreadSendMsg"), (Layout::new::<AzThreadSendMsg>(), "AzThreadSendMsg"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadWriteBackMsg>(), "AzThreadWriteBackMsg"), (Layout::new::<AzThreadWriteBackMsg>(), "AzThreadWriteBackMsg"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityStateVec>(), "AzAccessibilityStateVec"), (Layout::new::<AzAccessibilityStateVec>(), "AzAccessibilityStateVec"));
+        assert_eq!((Layout::new::<azul_core::accessibility::AccessibilityNodeVec>(), "AzAccessibilityNodeVec"), (Layout::new::<AzAccessibilityNodeVec>(), "AzAccessibilityNodeVec"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemVec>(), "AzMenuItemVec"), (Layout::new::<AzMenuItemVec>(), "AzMenuItemVec"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlNodeVec>(), "AzXmlNodeVec"), (Layout::new::<AzXmlNodeVec>(), "AzXmlNodeVec"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineGlyphVec>(), "AzInlineGlyphVec"), (Layout::new::<AzInlineGlyphVec>(), "AzInlineGlyphVec"));
@@ -10730,6 +13049,16 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::VirtualKeyCodeVec>(), "AzVirtualKeyCodeVec"), (Layout::new::<AzVirtualKeyCodeVec>(), "AzVirtualKeyCodeVec"));
         assert_eq!((Layout::new::<azul_impl::style::CascadeInfoVec>(), "AzCascadeInfoVec"), (Layout::new::<AzCascadeInfoVec>(), "AzCascadeInfoVec"));
         assert_eq!((Layout::new::<azul_core::window::ScanCodeVec>(), "AzScanCodeVec"), (Layout::new::<AzScanCodeVec>(), "AzScanCodeVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssPathSelectorVec>(), "AzCssPathSelectorVec"), (Layout::new::<AzCssPathSelectorVec>(), "AzCssPathSelectorVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionPropertyVec>(), "AzStyleTransitionPropertyVec"), (Layout::new::<AzStyleTransitionPropertyVec>(), "AzStyleTransitionPropertyVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionDurationVec>(), "AzStyleTransitionDurationVec"), (Layout::new::<AzStyleTransitionDurationVec>(), "AzStyleTransitionDurationVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionDelayVec>(), "AzStyleTransitionDelayVec"), (Layout::new::<AzStyleTransitionDelayVec>(), "AzStyleTransitionDelayVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDurationVec>(), "AzStyleAnimationDurationVec"), (Layout::new::<AzStyleAnimationDurationVec>(), "AzStyleAnimationDurationVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationIterationCountVec>(), "AzStyleAnimationIterationCountVec"), (Layout::new::<AzStyleAnimationIterationCountVec>(), "AzStyleAnimationIterationCountVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDirectionVec>(), "AzStyleAnimationDirectionVec"), (Layout::new::<AzStyleAnimationDirectionVec>(), "AzStyleAnimationDirectionVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationFillModeVec>(), "AzStyleAnimationFillModeVec"), (Layout::new::<AzStyleAnimationFillModeVec>(), "AzStyleAnimationFillModeVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDelayVec>(), "AzStyleAnimationDelayVec"), (Layout::new::<AzStyleAnimationDelayVec>(), "AzStyleAnimationDelayVec"));
+        assert_eq!((Layout::new::<azul_impl::css::GridTrackSizingVec>(), "AzGridTrackSizingVec"), (Layout::new::<AzGridTrackSizingVec>(), "AzGridTrackSizingVec"));
         assert_eq!((Layout::new::<azul_impl::css::U16Vec>(), "AzU16Vec"), (Layout::new::<AzU16Vec>(), "AzU16Vec"));
         assert_eq!((Layout::new::<azul_impl::css::F32Vec>(), "AzF32Vec"), (Layout::new::<AzF32Vec>(), "AzF32Vec"));
         assert_eq!((Layout::new::<azul_impl::css::U8Vec>(), "AzU8Vec"), (Layout::new::<AzU8Vec>(), "AzU8Vec"));
@@ -10740,6 +13069,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::styled_dom::NodeIdVec>(), "AzNodeIdVec"), (Layout::new::<AzNodeIdVec>(), "AzNodeIdVec"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::AzNodeVec>(), "AzNodeVec"), (Layout::new::<AzNodeVec>(), "AzNodeVec"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::ParentWithNodeDepthVec>(), "AzParentWithNodeDepthVec"), (Layout::new::<AzParentWithNodeDepthVec>(), "AzParentWithNodeDepthVec"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetSizeOverrideVec>(), "AzSpreadsheetSizeOverrideVec"), (Layout::new::<AzSpreadsheetSizeOverrideVec>(), "AzSpreadsheetSizeOverrideVec"));
         assert_eq!((Layout::new::<crate::widgets::color_input::OptionColorInputOnValueChange>(), "AzOptionColorInputOnValueChange"), (Layout::new::<AzOptionColorInputOnValueChange>(), "AzOptionColorInputOnValueChange"));
         assert_eq!((Layout::new::<crate::widgets::button::OptionButtonOnClick>(), "AzOptionButtonOnClick"), (Layout::new::<AzOptionButtonOnClick>(), "AzOptionButtonOnClick"));
         assert_eq!((Layout::new::<crate::widgets::check_box::OptionCheckBoxOnToggle>(), "AzOptionCheckBoxOnToggle"), (Layout::new::<AzOptionCheckBoxOnToggle>(), "AzOptionCheckBoxOnToggle"));
@@ -10748,6 +13078,15 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputOnFocusLost>(), "AzOptionTextInputOnFocusLost"), (Layout::new::<AzOptionTextInputOnFocusLost>(), "AzOptionTextInputOnFocusLost"));
         assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputSelection>(), "AzOptionTextInputSelection"), (Layout::new::<AzOptionTextInputSelection>(), "AzOptionTextInputSelection"));
         assert_eq!((Layout::new::<crate::widgets::number_input::OptionNumberInputOnValueChange>(), "AzOptionNumberInputOnValueChange"), (Layout::new::<AzOptionNumberInputOnValueChange>(), "AzOptionNumberInputOnValueChange"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::OptionTextEditOnTextChange>(), "AzOptionTextEditOnTextChange"), (Layout::new::<AzOptionTextEditOnTextChange>(), "AzOptionTextEditOnTextChange"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::OptionTextEditOnFocusLost>(), "AzOptionTextEditOnFocusLost"), (Layout::new::<AzOptionTextEditOnFocusLost>(), "AzOptionTextEditOnFocusLost"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::OptionTextEditKind>(), "AzOptionTextEditKind"), (Layout::new::<AzOptionTextEditKind>(), "AzOptionTextEditKind"));
+        assert_eq!((Layout::new::<crate::widgets::slider::OptionSliderOnValueChange>(), "AzOptionSliderOnValueChange"), (Layout::new::<AzOptionSliderOnValueChange>(), "AzOptionSliderOnValueChange"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::OptionDropDownOnChoiceChange>(), "AzOptionDropDownOnChoiceChange"), (Layout::new::<AzOptionDropDownOnChoiceChange>(), "AzOptionDropDownOnChoiceChange"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionSpreadsheetOnCellsChange>(), "AzOptionSpreadsheetOnCellsChange"), (Layout::new::<AzOptionSpreadsheetOnCellsChange>(), "AzOptionSpreadsheetOnCellsChange"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionSpreadsheetOnSelectionChange>(), "AzOptionSpreadsheetOnSelectionChange"), (Layout::new::<AzOptionSpreadsheetOnSelectionChange>(), "AzOptionSpreadsheetOnSelectionChange"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionSpreadsheetOnColumnResize>(), "AzOptionSpreadsheetOnColumnResize"), (Layout::new::<AzOptionSpreadsheetOnColumnResize>(), "AzOptionSpreadsheetOnColumnResize"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionSpreadsheetCellSelection>(), "AzOptionSpreadsheetCellSelection"), (Layout::new::<AzOptionSpreadsheetCellSelection>(), "AzOptionSpreadsheetCellSelection"));
         assert_eq!((Layout::new::<azul_core::window::OptionMenuItemIcon>(), "AzOptionMenuItemIcon"), (Layout::new::<AzOptionMenuItemIcon>(), "AzOptionMenuItemIcon"));
         assert_eq!((Layout::new::<azul_core::window::OptionMenuCallback>(), "AzOptionMenuCallback"), (Layout::new::<AzOptionMenuCallback>(), "AzOptionMenuCallback"));
         assert_eq!((Layout::new::<azul_impl::ui_solver::OptionPositionInfo>(), "AzOptionPositionInfo"), (Layout::new::<AzOptionPositionInfo>(), "AzOptionPositionInfo"));
@@ -10804,18 +13143,38 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::dom::EventFilter>(), "AzEventFilter"), (Layout::new::<AzEventFilter>(), "AzEventFilter"));
         assert_eq!((Layout::new::<azul_core::window::Menu>(), "AzMenu"), (Layout::new::<AzMenu>(), "AzMenu"));
         assert_eq!((Layout::new::<azul_core::window::VirtualKeyCodeCombo>(), "AzVirtualKeyCodeCombo"), (Layout::new::<AzVirtualKeyCodeCombo>(), "AzVirtualKeyCodeCombo"));
+        assert_eq!((Layout::new::<azul_core::window::KeyboardShortcut>(), "AzKeyboardShortcut"), (Layout::new::<AzKeyboardShortcut>(), "AzKeyboardShortcut"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaCondition>(), "AzCssMediaCondition"), (Layout::new::<AzCssMediaCondition>(), "AzCssMediaCondition"));
+        assert_eq!((Layout::new::<azul_impl::css::CssPath>(), "AzCssPath"), (Layout::new::<AzCssPath>(), "AzCssPath"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathPseudoSelector>(), "AzCssPathPseudoSelector"), (Layout::new::<AzCssPathPseudoSelector>(), "AzCssPathPseudoSelector"));
         assert_eq!((Layout::new::<azul_impl::css::AnimationInterpolationFunction>(), "AzAnimationInterpolationFunction"), (Layout::new::<AzAnimationInterpolationFunction>(), "AzAnimationInterpolationFunction"));
         assert_eq!((Layout::new::<azul_impl::css::InterpolateResolver>(), "AzInterpolateContext"), (Layout::new::<AzInterpolateContext>(), "AzInterpolateContext"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridTemplateColumns>(), "AzLayoutGridTemplateColumns"), (Layout::new::<AzLayoutGridTemplateColumns>(), "AzLayoutGridTemplateColumns"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridTemplateRows>(), "AzLayoutGridTemplateRows"), (Layout::new::<AzLayoutGridTemplateRows>(), "AzLayoutGridTemplateRows"));
         assert_eq!((Layout::new::<azul_impl::css::LinearGradient>(), "AzLinearGradient"), (Layout::new::<AzLinearGradient>(), "AzLinearGradient"));
         assert_eq!((Layout::new::<azul_impl::css::RadialGradient>(), "AzRadialGradient"), (Layout::new::<AzRadialGradient>(), "AzRadialGradient"));
         assert_eq!((Layout::new::<azul_impl::css::ConicGradient>(), "AzConicGradient"), (Layout::new::<AzConicGradient>(), "AzConicGradient"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionTimingFunction>(), "AzStyleTransitionTimingFunction"), (Layout::new::<AzStyleTransitionTimingFunction>(), "AzStyleTransitionTimingFunction"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransform>(), "AzStyleTransform"), (Layout::new::<AzStyleTransform>(), "AzStyleTransform"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridTemplateColumnsValue>(), "AzLayoutGridTemplateColumnsValue"), (Layout::new::<AzLayoutGridTemplateColumnsValue>(), "AzLayoutGridTemplateColumnsValue"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridTemplateRowsValue>(), "AzLayoutGridTemplateRowsValue"), (Layout::new::<AzLayoutGridTemplateRowsValue>(), "AzLayoutGridTemplateRowsValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundPositionVecValue>(), "AzStyleBackgroundPositionVecValue"), (Layout::new::<AzStyleBackgroundPositionVecValue>(), "AzStyleBackgroundPositionVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundRepeatVecValue>(), "AzStyleBackgroundRepeatVecValue"), (Layout::new::<AzStyleBackgroundRepeatVecValue>(), "AzStyleBackgroundRepeatVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundSizeVecValue>(), "AzStyleBackgroundSizeVecValue"), (Layout::new::<AzStyleBackgroundSizeVecValue>(), "AzStyleBackgroundSizeVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionPropertyVecValue>(), "AzStyleTransitionPropertyVecValue"), (Layout::new::<AzStyleTransitionPropertyVecValue>(), "AzStyleTransitionPropertyVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionDurationVecValue>(), "AzStyleTransitionDurationVecValue"), (Layout::new::<AzStyleTransitionDurationVecValue>(), "AzStyleTransitionDurationVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionDelayVecValue>(), "AzStyleTransitionDelayVecValue"), (Layout::new::<AzStyleTransitionDelayVecValue>(), "AzStyleTransitionDelayVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDurationVecValue>(), "AzStyleAnimationDurationVecValue"), (Layout::new::<AzStyleAnimationDurationVecValue>(), "AzStyleAnimationDurationVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationIterationCountVecValue>(), "AzStyleAnimationIterationCountVecValue"), (Layout::new::<AzStyleAnimationIterationCountVecValue>(), "AzStyleAnimationIterationCountVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDirectionVecValue>(), "AzStyleAnimationDirectionVecValue"), (Layout::new::<AzStyleAnimationDirectionVecValue>(), "AzStyleAnimationDirectionVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationFillModeVecValue>(), "AzStyleAnimationFillModeVecValue"), (Layout::new::<AzStyleAnimationFillModeVecValue>(), "AzStyleAnimationFillModeVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDelayVecValue>(), "AzStyleAnimationDelayVecValue"), (Layout::new::<AzStyleAnimationDelayVecValue>(), "AzStyleAnimationDelayVecValue"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBoxStateWrapper>(), "AzCheckBoxStateWrapper"), (Layout::new::<AzCheckBoxStateWrapper>(), "AzCheckBoxStateWrapper"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputStateWrapper>(), "AzNumberInputStateWrapper"), (Layout::new::<AzNumberInputStateWrapper>(), "AzNumberInputStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditState>(), "AzTextEditState"), (Layout::new::<AzTextEditState>(), "AzTextEditState"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditSnapshot>(), "AzTextEditSnapshot"), (Layout::new::<AzTextEditSnapshot>(), "AzTextEditSnapshot"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderStateWrapper>(), "AzSliderStateWrapper"), (Layout::new::<AzSliderStateWrapper>(), "AzSliderStateWrapper"));
+        assert_eq!((Layout::new::<azul_impl::styled_dom::CssPropertySource>(), "AzCssPropertySource"), (Layout::new::<AzCssPropertySource>(), "AzCssPropertySource"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::StyledNode>(), "AzStyledNode"), (Layout::new::<AzStyledNode>(), "AzStyledNode"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::TagIdToNodeIdMapping>(), "AzTagIdToNodeIdMapping"), (Layout::new::<AzTagIdToNodeIdMapping>(), "AzTagIdToNodeIdMapping"));
         assert_eq!((Layout::new::<azul_impl::gl::Texture>(), "AzTexture"), (Layout::new::<AzTexture>(), "AzTexture"));
@@ -10834,9 +13193,13 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedSvgNodeVec>(), "AzTessellatedSvgNodeVec"), (Layout::new::<AzTessellatedSvgNodeVec>(), "AzTessellatedSvgNodeVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVec>(), "AzStyleTransformVec"), (Layout::new::<AzStyleTransformVec>(), "AzStyleTransformVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgPathElementVec>(), "AzSvgPathElementVec"), (Layout::new::<AzSvgPathElementVec>(), "AzSvgPathElementVec"));
+        assert_eq!((Layout::new::<azul_core::window::KeyboardShortcutVec>(), "AzKeyboardShortcutVec"), (Layout::new::<AzKeyboardShortcutVec>(), "AzKeyboardShortcutVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaConditionVec>(), "AzCssMediaConditionVec"), (Layout::new::<AzCssMediaConditionVec>(), "AzCssMediaConditionVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionTimingFunctionVec>(), "AzStyleTransitionTimingFunctionVec"), (Layout::new::<AzStyleTransitionTimingFunctionVec>(), "AzStyleTransitionTimingFunctionVec"));
         assert_eq!((Layout::new::<azul_impl::css::StringVec>(), "AzStringVec"), (Layout::new::<AzStringVec>(), "AzStringVec"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::StyledNodeVec>(), "AzStyledNodeVec"), (Layout::new::<AzStyledNodeVec>(), "AzStyledNodeVec"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::TagIdToNodeIdMappingVec>(), "AzTagIdToNodeIdMappingVec"), (Layout::new::<AzTagIdToNodeIdMappingVec>(), "AzTagIdToNodeIdMappingVec"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditSnapshotVec>(), "AzTextEditSnapshotVec"), (Layout::new::<AzTextEditSnapshotVec>(), "AzTextEditSnapshotVec"));
         assert_eq!((Layout::new::<azul_core::window::OptionVirtualKeyCodeCombo>(), "AzOptionVirtualKeyCodeCombo"), (Layout::new::<AzOptionVirtualKeyCodeCombo>(), "AzOptionVirtualKeyCodeCombo"));
         assert_eq!((Layout::new::<azul_core::window::OptionMouseState>(), "AzOptionMouseState"), (Layout::new::<AzOptionMouseState>(), "AzOptionMouseState"));
         assert_eq!((Layout::new::<azul_core::window::OptionKeyboardState>(), "AzOptionKeyboardState"), (Layout::new::<AzOptionKeyboardState>(), "AzOptionKeyboardState"));
@@ -10859,20 +13222,34 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::Monitor>(), "AzMonitor"), (Layout::new::<AzMonitor>(), "AzMonitor"));
         assert_eq!((Layout::new::<azul_impl::callbacks::LayoutCallback>(), "AzLayoutCallback"), (Layout::new::<AzLayoutCallback>(), "AzLayoutCallback"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineWord>(), "AzInlineWord"), (Layout::new::<AzInlineWord>(), "AzInlineWord"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::FocusTargetPath>(), "AzFocusTargetPath"), (Layout::new::<AzFocusTargetPath>(), "AzFocusTargetPath"));
         assert_eq!((Layout::new::<azul_impl::dom::CallbackData>(), "AzCallbackData"), (Layout::new::<AzCallbackData>(), "AzCallbackData"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeType>(), "AzNodeType"), (Layout::new::<AzNodeType>(), "AzNodeType"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityInfo>(), "AzAccessibilityInfo"), (Layout::new::<AzAccessibilityInfo>(), "AzAccessibilityInfo"));
+        assert_eq!((Layout::new::<azul_core::accessibility::AccessibilityNode>(), "AzAccessibilityNode"), (Layout::new::<AzAccessibilityNode>(), "AzAccessibilityNode"));
+        assert_eq!((Layout::new::<azul_core::accessibility::AccessibilityTree>(), "AzAccessibilityTree"), (Layout::new::<AzAccessibilityTree>(), "AzAccessibilityTree"));
         assert_eq!((Layout::new::<azul_impl::dom::IdOrClass>(), "AzIdOrClass"), (Layout::new::<AzIdOrClass>(), "AzIdOrClass"));
         assert_eq!((Layout::new::<azul_core::window::StringMenuItem>(), "AzStringMenuItem"), (Layout::new::<AzStringMenuItem>(), "AzStringMenuItem"));
-        assert_eq!((Layout::new::<azul_impl::css::CssPathSelector>(), "AzCssPathSelector"), (Layout::new::<AzCssPathSelector>(), "AzCssPathSelector"));
+        assert_eq!((Layout::new::<azul_core::window::ShortcutConflict>(), "AzShortcutConflict"), (Layout::new::<AzShortcutConflict>(), "AzShortcutConflict"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaQuery>(), "AzCssMediaQuery"), (Layout::new::<AzCssMediaQuery>(), "AzCssMediaQuery"));
+        assert_eq!((Layout::new::<azul_impl::css::CssCustomProperty>(), "AzCssCustomProperty"), (Layout::new::<AzCssCustomProperty>(), "AzCssCustomProperty"));
+        assert_eq!((Layout::new::<azul_impl::css::CssAttributeSelector>(), "AzCssAttributeSelector"), (Layout::new::<AzCssAttributeSelector>(), "AzCssAttributeSelector"));
+        assert_eq!((Layout::new::<azul_impl::css::CssFontFace>(), "AzCssFontFace"), (Layout::new::<AzCssFontFace>(), "AzCssFontFace"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContent>(), "AzStyleBackgroundContent"), (Layout::new::<AzStyleBackgroundContent>(), "AzStyleBackgroundContent"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarInfo>(), "AzScrollbarInfo"), (Layout::new::<AzScrollbarInfo>(), "AzScrollbarInfo"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarStyle>(), "AzScrollbarStyle"), (Layout::new::<AzScrollbarStyle>(), "AzScrollbarStyle"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamily>(), "AzStyleFontFamily"), (Layout::new::<AzStyleFontFamily>(), "AzStyleFontFamily"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationName>(), "AzStyleAnimationName"), (Layout::new::<AzStyleAnimationName>(), "AzStyleAnimationName"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarStyleValue>(), "AzScrollbarStyleValue"), (Layout::new::<AzScrollbarStyleValue>(), "AzScrollbarStyleValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVecValue>(), "AzStyleTransformVecValue"), (Layout::new::<AzStyleTransformVecValue>(), "AzStyleTransformVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionTimingFunctionVecValue>(), "AzStyleTransitionTimingFunctionVecValue"), (Layout::new::<AzStyleTransitionTimingFunctionVecValue>(), "AzStyleTransitionTimingFunctionVecValue"));
         assert_eq!((Layout::new::<crate::widgets::color_input::ColorInputStateWrapper>(), "AzColorInputStateWrapper"), (Layout::new::<AzColorInputStateWrapper>(), "AzColorInputStateWrapper"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputState>(), "AzTextInputState"), (Layout::new::<AzTextInputState>(), "AzTextInputState"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditHistory>(), "AzTextEditHistory"), (Layout::new::<AzTextEditHistory>(), "AzTextEditHistory"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownState>(), "AzDropDownState"), (Layout::new::<AzDropDownState>(), "AzDropDownState"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetCellContent>(), "AzSpreadsheetCellContent"), (Layout::new::<AzSpreadsheetCellContent>(), "AzSpreadsheetCellContent"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetCellClick>(), "AzSpreadsheetCellClick"), (Layout::new::<AzSpreadsheetCellClick>(), "AzSpreadsheetCellClick"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetCellEdit>(), "AzSpreadsheetCellEdit"), (Layout::new::<AzSpreadsheetCellEdit>(), "AzSpreadsheetCellEdit"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexAttribute>(), "AzVertexAttribute"), (Layout::new::<AzVertexAttribute>(), "AzVertexAttribute"));
         assert_eq!((Layout::new::<azul_impl::gl::AzDebugMessage>(), "AzDebugMessage"), (Layout::new::<AzDebugMessage>(), "AzDebugMessage"));
         assert_eq!((Layout::new::<azul_impl::gl::GetActiveAttribReturn>(), "AzGetActiveAttribReturn"), (Layout::new::<AzGetActiveAttribReturn>(), "AzGetActiveAttribReturn"));
@@ -10894,10 +13271,16 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContentVec>(), "AzStyleBackgroundContentVec"), (Layout::new::<AzStyleBackgroundContentVec>(), "AzStyleBackgroundContentVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgPathVec>(), "AzSvgPathVec"), (Layout::new::<AzSvgPathVec>(), "AzSvgPathVec"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexAttributeVec>(), "AzVertexAttributeVec"), (Layout::new::<AzVertexAttributeVec>(), "AzVertexAttributeVec"));
-        assert_eq!((Layout::new::<azul_impl::css::CssPathSelectorVec>(), "AzCssPathSelectorVec"), (Layout::new::<AzCssPathSelectorVec>(), "AzCssPathSelectorVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaQueryVec>(), "AzCssMediaQueryVec"), (Layout::new::<AzCssMediaQueryVec>(), "AzCssMediaQueryVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssFontFaceVec>(), "AzCssFontFaceVec"), (Layout::new::<AzCssFontFaceVec>(), "AzCssFontFaceVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationNameVec>(), "AzStyleAnimationNameVec"), (Layout::new::<AzStyleAnimationNameVec>(), "AzStyleAnimationNameVec"));
         assert_eq!((Layout::new::<azul_impl::dom::CallbackDataVec>(), "AzCallbackDataVec"), (Layout::new::<AzCallbackDataVec>(), "AzCallbackDataVec"));
         assert_eq!((Layout::new::<azul_impl::gl::AzDebugMessageVec>(), "AzDebugMessageVec"), (Layout::new::<AzDebugMessageVec>(), "AzDebugMessageVec"));
         assert_eq!((Layout::new::<azul_core::window::StringPairVec>(), "AzStringPairVec"), (Layout::new::<AzStringPairVec>(), "AzStringPairVec"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetCellContentVec>(), "AzSpreadsheetCellContentVec"), (Layout::new::<AzSpreadsheetCellContentVec>(), "AzSpreadsheetCellContentVec"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionSpreadsheetCellClick>(), "AzOptionSpreadsheetCellClick"), (Layout::new::<AzOptionSpreadsheetCellClick>(), "AzOptionSpreadsheetCellClick"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::OptionSpreadsheetCellEdit>(), "AzOptionSpreadsheetCellEdit"), (Layout::new::<AzOptionSpreadsheetCellEdit>(), "AzOptionSpreadsheetCellEdit"));
+        assert_eq!((Layout::new::<azul_core::accessibility::OptionAccessibilityNode>(), "AzOptionAccessibilityNode"), (Layout::new::<AzOptionAccessibilityNode>(), "AzOptionAccessibilityNode"));
         assert_eq!((Layout::new::<azul_impl::dialogs::OptionFileTypeList>(), "AzOptionFileTypeList"), (Layout::new::<AzOptionFileTypeList>(), "AzOptionFileTypeList"));
         assert_eq!((Layout::new::<azul_impl::file::OptionFile>(), "AzOptionFile"), (Layout::new::<AzOptionFile>(), "AzOptionFile"));
         assert_eq!((Layout::new::<azul_impl::resources::OptionRawImage>(), "AzOptionRawImage"), (Layout::new::<AzOptionRawImage>(), "AzOptionRawImage"));
@@ -10906,13 +13289,18 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::xml::XmlStreamError>(), "AzXmlStreamError"), (Layout::new::<AzXmlStreamError>(), "AzXmlStreamError"));
         assert_eq!((Layout::new::<azul_core::window::LinuxWindowOptions>(), "AzLinuxWindowOptions"), (Layout::new::<AzLinuxWindowOptions>(), "AzLinuxWindowOptions"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineLine>(), "AzInlineLine"), (Layout::new::<AzInlineLine>(), "AzInlineLine"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::FocusTarget>(), "AzFocusTarget"), (Layout::new::<AzFocusTarget>(), "AzFocusTarget"));
         assert_eq!((Layout::new::<azul_core::window::MenuItem>(), "AzMenuItem"), (Layout::new::<AzMenuItem>(), "AzMenuItem"));
-        assert_eq!((Layout::new::<azul_impl::css::CssPath>(), "AzCssPath"), (Layout::new::<AzCssPath>(), "AzCssPath"));
+        assert_eq!((Layout::new::<azul_impl::css::CssPathSelector>(), "AzCssPathSelector"), (Layout::new::<AzCssPathSelector>(), "AzCssPathSelector"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContentVecValue>(), "AzStyleBackgroundContentVecValue"), (Layout::new::<AzStyleBackgroundContentVecValue>(), "AzStyleBackgroundContentVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVecValue>(), "AzStyleFontFamilyVecValue"), (Layout::new::<AzStyleFontFamilyVecValue>(), "AzStyleFontFamilyVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationNameVecValue>(), "AzStyleAnimationNameVecValue"), (Layout::new::<AzStyleAnimationNameVecValue>(), "AzStyleAnimationNameVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::CssProperty>(), "AzCssProperty"), (Layout::new::<AzCssProperty>(), "AzCssProperty"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputStateWrapper>(), "AzTextInputStateWrapper"), (Layout::new::<AzTextInputStateWrapper>(), "AzTextInputStateWrapper"));
-        assert_eq!((Layout::new::<azul_impl::styled_dom::CssPropertySource>(), "AzCssPropertySource"), (Layout::new::<AzCssPropertySource>(), "AzCssPropertySource"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEditStateWrapper>(), "AzTextEditStateWrapper"), (Layout::new::<AzTextEditStateWrapper>(), "AzTextEditStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownStateWrapper>(), "AzDropDownStateWrapper"), (Layout::new::<AzDropDownStateWrapper>(), "AzDropDownStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetState>(), "AzSpreadsheetState"), (Layout::new::<AzSpreadsheetState>(), "AzSpreadsheetState"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetView>(), "AzSpreadsheetView"), (Layout::new::<AzSpreadsheetView>(), "AzSpreadsheetView"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexLayout>(), "AzVertexLayout"), (Layout::new::<AzVertexLayout>(), "AzVertexLayout"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexArrayObject>(), "AzVertexArrayObject"), (Layout::new::<AzVertexArrayObject>(), "AzVertexArrayObject"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexBuffer>(), "AzVertexBuffer"), (Layout::new::<AzVertexBuffer>(), "AzVertexBuffer"));
@@ -10927,28 +13315,35 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::WindowState>(), "AzWindowState"), (Layout::new::<AzWindowState>(), "AzWindowState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::CallbackInfo>(), "AzCallbackInfo"), (Layout::new::<AzCallbackInfo>(), "AzCallbackInfo"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineText>(), "AzInlineText"), (Layout::new::<AzInlineText>(), "AzInlineText"));
-        assert_eq!((Layout::new::<azul_impl::callbacks::FocusTargetPath>(), "AzFocusTargetPath"), (Layout::new::<AzFocusTargetPath>(), "AzFocusTargetPath"));
         assert_eq!((Layout::new::<azul_impl::callbacks::Animation>(), "AzAnimation"), (Layout::new::<AzAnimation>(), "AzAnimation"));
         assert_eq!((Layout::new::<azul_impl::callbacks::TimerCallbackInfo>(), "AzTimerCallbackInfo"), (Layout::new::<AzTimerCallbackInfo>(), "AzTimerCallbackInfo"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataInlineCssProperty>(), "AzNodeDataInlineCssProperty"), (Layout::new::<AzNodeDataInlineCssProperty>(), "AzNodeDataInlineCssProperty"));
         assert_eq!((Layout::new::<azul_impl::css::DynamicCssProperty>(), "AzDynamicCssProperty"), (Layout::new::<AzDynamicCssProperty>(), "AzDynamicCssProperty"));
+        assert_eq!((Layout::new::<azul_impl::css::CssKeyframe>(), "AzCssKeyframe"), (Layout::new::<AzCssKeyframe>(), "AzCssKeyframe"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::SpreadsheetStateWrapper>(), "AzSpreadsheetStateWrapper"), (Layout::new::<AzSpreadsheetStateWrapper>(), "AzSpreadsheetStateWrapper"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgNode>(), "AzSvgNode"), (Layout::new::<AzSvgNode>(), "AzSvgNode"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgStyledNode>(), "AzSvgStyledNode"), (Layout::new::<AzSvgStyledNode>(), "AzSvgStyledNode"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataInlineCssPropertyVec>(), "AzNodeDataInlineCssPropertyVec"), (Layout::new::<AzNodeDataInlineCssPropertyVec>(), "AzNodeDataInlineCssPropertyVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssKeyframeVec>(), "AzCssKeyframeVec"), (Layout::new::<AzCssKeyframeVec>(), "AzCssKeyframeVec"));
         assert_eq!((Layout::new::<azul_core::window::OptionWindowState>(), "AzOptionWindowState"), (Layout::new::<AzOptionWindowState>(), "AzOptionWindowState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::OptionInlineText>(), "AzOptionInlineText"), (Layout::new::<AzOptionInlineText>(), "AzOptionInlineText"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlParseError>(), "AzXmlParseError"), (Layout::new::<AzXmlParseError>(), "AzXmlParseError"));
         assert_eq!((Layout::new::<azul_core::window::WindowCreateOptions>(), "AzWindowCreateOptions"), (Layout::new::<AzWindowCreateOptions>(), "AzWindowCreateOptions"));
-        assert_eq!((Layout::new::<azul_impl::callbacks::FocusTarget>(), "AzFocusTarget"), (Layout::new::<AzFocusTarget>(), "AzFocusTarget"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeData>(), "AzNodeData"), (Layout::new::<AzNodeData>(), "AzNodeData"));
         assert_eq!((Layout::new::<azul_impl::css::CssDeclaration>(), "AzCssDeclaration"), (Layout::new::<AzCssDeclaration>(), "AzCssDeclaration"));
+        assert_eq!((Layout::new::<azul_impl::css::CssKeyframes>(), "AzCssKeyframes"), (Layout::new::<AzCssKeyframes>(), "AzCssKeyframes"));
         assert_eq!((Layout::new::<crate::widgets::button::Button>(), "AzButton"), (Layout::new::<AzButton>(), "AzButton"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBox>(), "AzCheckBox"), (Layout::new::<AzCheckBox>(), "AzCheckBox"));
         assert_eq!((Layout::new::<crate::widgets::label::Label>(), "AzLabel"), (Layout::new::<AzLabel>(), "AzLabel"));
         assert_eq!((Layout::new::<crate::widgets::color_input::ColorInput>(), "AzColorInput"), (Layout::new::<AzColorInput>(), "AzColorInput"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInput>(), "AzTextInput"), (Layout::new::<AzTextInput>(), "AzTextInput"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInput>(), "AzNumberInput"), (Layout::new::<AzNumberInput>(), "AzNumberInput"));
+        assert_eq!((Layout::new::<crate::widgets::text_edit::TextEdit>(), "AzTextEdit"), (Layout::new::<AzTextEdit>(), "AzTextEdit"));
+        assert_eq!((Layout::new::<crate::widgets::slider::Slider>(), "AzSlider"), (Layout::new::<AzSlider>(), "AzSlider"));
+        assert_eq!((Layout::new::<crate::widgets::drop_down::DropDown>(), "AzDropDown"), (Layout::new::<AzDropDown>(), "AzDropDown"));
+        assert_eq!((Layout::new::<crate::widgets::spreadsheet::Spreadsheet>(), "AzSpreadsheet"), (Layout::new::<AzSpreadsheet>(), "AzSpreadsheet"));
         assert_eq!((Layout::new::<azul_impl::css::CssDeclarationVec>(), "AzCssDeclarationVec"), (Layout::new::<AzCssDeclarationVec>(), "AzCssDeclarationVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssKeyframesVec>(), "AzCssKeyframesVec"), (Layout::new::<AzCssKeyframesVec>(), "AzCssKeyframesVec"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataVec>(), "AzNodeDataVec"), (Layout::new::<AzNodeDataVec>(), "AzNodeDataVec"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlError>(), "AzXmlError"), (Layout::new::<AzXmlError>(), "AzXmlError"));
         assert_eq!((Layout::new::<azul_impl::dom::Dom>(), "AzDom"), (Layout::new::<AzDom>(), "AzDom"));
@@ -1,5 +1,6 @@
 #![allow(non_snake_case)]
 
+extern crate alloc;
 extern crate azul_core;
 
 #[cfg(target_arch = "wasm32")]
@@ -1640,6 +1641,20 @@ pub enum AzMenuItemState {
     Disabled,
 }
 
+/// Re-export of rust-allocated (stack based) `CssMediaOrientation` struct
+#[repr(C)]
+pub enum AzCssMediaOrientation {
+    Portrait,
+    Landscape,
+}
+
+/// Re-export of rust-allocated (stack based) `CssMediaColorScheme` struct
+#[repr(C)]
+pub enum AzCssMediaColorScheme {
+    Light,
+    Dark,
+}
+
 /// Re-export of rust-allocated (stack based) `NodeTypeKey` struct
 #[repr(C)]
 pub enum AzNodeTypeKey {
@@ -1651,6 +1666,18 @@ pub enum AzNodeTypeKey {
     IFrame,
 }
 
+/// Re-export of rust-allocated (stack based) `CssAttributeSelectorOp` struct
+#[repr(C)]
+pub enum AzCssAttributeSelectorOp {
+    Exists,
+    Equals,
+    Includes,
+    DashMatch,
+    Prefix,
+    Suffix,
+    Substring,
+}
+
 /// Re-export of rust-allocated (stack based) `CssNthChildPattern` struct
 #[repr(C)]
 #[pyclass(name = "CssNthChildPattern")]
@@ -1661,6 +1688,14 @@ pub struct AzCssNthChildPattern {
     pub offset: u32,
 }
 
+/// Re-export of rust-allocated (stack based) `CssFontFaceStyle` struct
+#[repr(C)]
+pub enum AzCssFontFaceStyle {
+    Normal,
+    Italic,
+    Oblique,
+}
+
 /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
 #[repr(C)]
 pub enum AzCssPropertyType {
@@ -1675,6 +1710,7 @@ pub enum AzCssPropertyType {
     Cursor,
     Display,
     Float,
+    Clear,
     BoxSizing,
     Width,
     Height,
@@ -1694,6 +1730,12 @@ pub enum AzCssPropertyType {
     JustifyContent,
     AlignItems,
     AlignContent,
+    GridTemplateColumns,
+    GridTemplateRows,
+    GridColumn,
+    GridRow,
+    ColumnGap,
+    RowGap,
     BackgroundContent,
     BackgroundPosition,
     BackgroundSize,
@@ -1734,6 +1776,17 @@ pub enum AzCssPropertyType {
     TransformOrigin,
     PerspectiveOrigin,
     BackfaceVisibility,
+    TransitionProperty,
+    TransitionDuration,
+    TransitionTimingFunction,
+    TransitionDelay,
+    AnimationName,
+    AnimationDuration,
+    AnimationTimingFunction,
+    AnimationIterationCount,
+    AnimationDirection,
+    AnimationFillMode,
+    AnimationDelay,
 }
 
 /// Re-export of rust-allocated (stack based) `ColorU` struct
@@ -1785,6 +1838,14 @@ pub enum AzLayoutAlignContent {
     SpaceAround,
 }
 
+/// Start or end line of a grid item (`auto`, `2` or `span 2`)
+#[repr(C, u8)]
+pub enum AzGridLine {
+    Auto,
+    Line(i32),
+    Span(u32),
+}
+
 /// Re-export of rust-allocated (stack based) `LayoutAlignItems` struct
 #[repr(C)]
 pub enum AzLayoutAlignItems {
@@ -1817,6 +1878,7 @@ pub enum AzLayoutDisplay {
     Flex,
     Block,
     InlineBlock,
+    Grid,
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutFloat` struct
@@ -1824,6 +1886,16 @@ pub enum AzLayoutDisplay {
 pub enum AzLayoutFloat {
     Left,
     Right,
+    None,
+}
+
+/// Represents a `clear` attribute: on which sides the node may not be placed next to floating nodes that come before it
+#[repr(C)]
+pub enum AzLayoutClear {
+    None,
+    Left,
+    Right,
+    Both,
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutJustifyContent` struct
@@ -1974,6 +2046,63 @@ pub enum AzStyleBackfaceVisibility {
     Visible,
 }
 
+/// Re-export of rust-allocated (stack based) `StyleTransitionDuration` struct
+#[repr(C)]
+#[pyclass(name = "StyleTransitionDuration")]
+pub struct AzStyleTransitionDuration {
+    #[pyo3(get, set)]
+    pub ms: u32,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionDelay` struct
+#[repr(C)]
+#[pyclass(name = "StyleTransitionDelay")]
+pub struct AzStyleTransitionDelay {
+    #[pyo3(get, set)]
+    pub ms: u32,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDuration` struct
+#[repr(C)]
+#[pyclass(name = "StyleAnimationDuration")]
+pub struct AzStyleAnimationDuration {
+    #[pyo3(get, set)]
+    pub ms: u32,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDelay` struct
+#[repr(C)]
+#[pyclass(name = "StyleAnimationDelay")]
+pub struct AzStyleAnimationDelay {
+    #[pyo3(get, set)]
+    pub ms: u32,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationIterationCount` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationIterationCount {
+    Count(u32),
+    Infinite,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDirection` struct
+#[repr(C)]
+pub enum AzStyleAnimationDirection {
+    Normal,
+    Reverse,
+    Alternate,
+    AlternateReverse,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationFillMode` struct
+#[repr(C)]
+pub enum AzStyleAnimationFillMode {
+    None,
+    Forwards,
+    Backwards,
+    Both,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleTextAlign` struct
 #[repr(C)]
 pub enum AzStyleTextAlign {
@@ -2081,6 +2210,121 @@ pub struct AzNumberInputOnValueChangeCallback {
 /// `AzNumberInputOnValueChangeCallbackType` struct
 pub type AzNumberInputOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzNumberInputState, &mut AzCallbackInfo) -> AzUpdate;
 
+/// Re-export of rust-allocated (stack based) `TextEditKind` struct
+#[repr(C)]
+pub enum AzTextEditKind {
+    Typing,
+    Deleting,
+    Other,
+}
+
+/// Re-export of rust-allocated (stack based) `TextEditOnTextChangeCallback` struct
+#[repr(C)]
+#[pyclass(name = "TextEditOnTextChangeCallback")]
+pub struct AzTextEditOnTextChangeCallback {
+    pub cb: AzTextEditOnTextChangeCallbackType,
+}
+
+/// `AzTextEditOnTextChangeCallbackType` struct
+pub type AzTextEditOnTextChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzTextEditState, &mut AzCallbackInfo) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TextEditOnFocusLostCallback` struct
+#[repr(C)]
+#[pyclass(name = "TextEditOnFocusLostCallback")]
+pub struct AzTextEditOnFocusLostCallback {
+    pub cb: AzTextEditOnFocusLostCallbackType,
+}
+
+/// `AzTextEditOnFocusLostCallbackType` struct
+pub type AzTextEditOnFocusLostCallbackType = extern "C" fn(&mut AzRefAny, &AzTextEditState, &mut AzCallbackInfo) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `SliderOrientation` struct
+#[repr(C)]
+pub enum AzSliderOrientation {
+    Horizontal,
+    Vertical,
+}
+
+/// Re-export of rust-allocated (stack based) `SliderOnValueChangeCallback` struct
+#[repr(C)]
+#[pyclass(name = "SliderOnValueChangeCallback")]
+pub struct AzSliderOnValueChangeCallback {
+    pub cb: AzSliderOnValueChangeCallbackType,
+}
+
+/// `AzSliderOnValueChangeCallbackType` struct
+pub type AzSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSliderState, &mut AzCallbackInfo) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `DropDownOnChoiceChangeCallback` struct
+#[repr(C)]
+#[pyclass(name = "DropDownOnChoiceChangeCallback")]
+pub struct AzDropDownOnChoiceChangeCallback {
+    pub cb: AzDropDownOnChoiceChangeCallbackType,
+}
+
+/// `AzDropDownOnChoiceChangeCallbackType` struct
+pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzDropDownState, &mut AzCallbackInfo) -> AzUpdate;
+
+/// Represents the index of a single cell (row + column)
+#[repr(C)]
+#[pyclass(name = "SpreadsheetCellIndex")]
+pub struct AzSpreadsheetCellIndex {
+    #[pyo3(get, set)]
+    pub row: usize,
+    #[pyo3(get, set)]
+    pub column: usize,
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetSizeOverride` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetSizeOverride")]
+pub struct AzSpreadsheetSizeOverride {
+    #[pyo3(get, set)]
+    pub index: usize,
+    #[pyo3(get, set)]
+    pub size: f32,
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetColumnResize` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetColumnResize")]
+pub struct AzSpreadsheetColumnResize {
+    #[pyo3(get, set)]
+    pub column: usize,
+    #[pyo3(get, set)]
+    pub width: f32,
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetOnCellsChangeCallback` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetOnCellsChangeCallback")]
+pub struct AzSpreadsheetOnCellsChangeCallback {
+    pub cb: AzSpreadsheetOnCellsChangeCallbackType,
+}
+
+/// `AzSpreadsheetOnCellsChangeCallbackType` struct
+pub type AzSpreadsheetOnCellsChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSpreadsheetState, AzSpreadsheetCellSelection, &mut AzCallbackInfo) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetOnSelectionChangeCallback` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetOnSelectionChangeCallback")]
+pub struct AzSpreadsheetOnSelectionChangeCallback {
+    pub cb: AzSpreadsheetOnSelectionChangeCallbackType,
+}
+
+/// `AzSpreadsheetOnSelectionChangeCallbackType` struct
+pub type AzSpreadsheetOnSelectionChangeCallbackType = extern "C" fn(&mut AzRefAny, &AzSpreadsheetState, &mut AzCallbackInfo) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetOnColumnResizeCallback` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetOnColumnResizeCallback")]
+pub struct AzSpreadsheetOnColumnResizeCallback {
+    pub cb: AzSpreadsheetOnColumnResizeCallbackType,
+}
+
+/// `AzSpreadsheetOnColumnResizeCallbackType` struct
+pub type AzSpreadsheetOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &AzSpreadsheetState, usize, &mut AzCallbackInfo) -> AzUpdate;
+
 /// Re-export of rust-allocated (stack based) `Node` struct
 #[repr(C)]
 #[pyclass(name = "Node")]
@@ -2798,6 +3042,17 @@ pub enum AzAccessibilityStateVecDestructor {
 /// `AzAccessibilityStateVecDestructorType` struct
 pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
 
+/// Re-export of rust-allocated (stack based) `AccessibilityNodeVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzAccessibilityNodeVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzAccessibilityNodeVecDestructorType),
+}
+
+/// `AzAccessibilityNodeVecDestructorType` struct
+pub type AzAccessibilityNodeVecDestructorType = extern "C" fn(&mut AzAccessibilityNodeVec);
+
 /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzMenuItemVecDestructor {
@@ -3095,6 +3350,17 @@ pub enum AzVirtualKeyCodeVecDestructor {
 /// `AzVirtualKeyCodeVecDestructorType` struct
 pub type AzVirtualKeyCodeVecDestructorType = extern "C" fn(&mut AzVirtualKeyCodeVec);
 
+/// Re-export of rust-allocated (stack based) `KeyboardShortcutVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzKeyboardShortcutVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzKeyboardShortcutVecDestructorType),
+}
+
+/// `AzKeyboardShortcutVecDestructorType` struct
+pub type AzKeyboardShortcutVecDestructorType = extern "C" fn(&mut AzKeyboardShortcutVec);
+
 /// Re-export of rust-allocated (stack based) `CascadeInfoVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzCascadeInfoVecDestructor {
@@ -3150,6 +3416,182 @@ pub enum AzStylesheetVecDestructor {
 /// `AzStylesheetVecDestructorType` struct
 pub type AzStylesheetVecDestructorType = extern "C" fn(&mut AzStylesheetVec);
 
+/// Re-export of rust-allocated (stack based) `CssFontFaceVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssFontFaceVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssFontFaceVecDestructorType),
+}
+
+/// `AzCssFontFaceVecDestructorType` struct
+pub type AzCssFontFaceVecDestructorType = extern "C" fn(&mut AzCssFontFaceVec);
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionPropertyVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleTransitionPropertyVecDestructorType),
+}
+
+/// `AzStyleTransitionPropertyVecDestructorType` struct
+pub type AzStyleTransitionPropertyVecDestructorType = extern "C" fn(&mut AzStyleTransitionPropertyVec);
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionDurationVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionDurationVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleTransitionDurationVecDestructorType),
+}
+
+/// `AzStyleTransitionDurationVecDestructorType` struct
+pub type AzStyleTransitionDurationVecDestructorType = extern "C" fn(&mut AzStyleTransitionDurationVec);
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunctionVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionTimingFunctionVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleTransitionTimingFunctionVecDestructorType),
+}
+
+/// `AzStyleTransitionTimingFunctionVecDestructorType` struct
+pub type AzStyleTransitionTimingFunctionVecDestructorType = extern "C" fn(&mut AzStyleTransitionTimingFunctionVec);
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionDelayVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionDelayVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleTransitionDelayVecDestructorType),
+}
+
+/// `AzStyleTransitionDelayVecDestructorType` struct
+pub type AzStyleTransitionDelayVecDestructorType = extern "C" fn(&mut AzStyleTransitionDelayVec);
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationNameVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationNameVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleAnimationNameVecDestructorType),
+}
+
+/// `AzStyleAnimationNameVecDestructorType` struct
+pub type AzStyleAnimationNameVecDestructorType = extern "C" fn(&mut AzStyleAnimationNameVec);
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDurationVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationDurationVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleAnimationDurationVecDestructorType),
+}
+
+/// `AzStyleAnimationDurationVecDestructorType` struct
+pub type AzStyleAnimationDurationVecDestructorType = extern "C" fn(&mut AzStyleAnimationDurationVec);
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationIterationCountVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleAnimationIterationCountVecDestructorType),
+}
+
+/// `AzStyleAnimationIterationCountVecDestructorType` struct
+pub type AzStyleAnimationIterationCountVecDestructorType = extern "C" fn(&mut AzStyleAnimationIterationCountVec);
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationDirectionVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleAnimationDirectionVecDestructorType),
+}
+
+/// `AzStyleAnimationDirectionVecDestructorType` struct
+pub type AzStyleAnimationDirectionVecDestructorType = extern "C" fn(&mut AzStyleAnimationDirectionVec);
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationFillModeVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleAnimationFillModeVecDestructorType),
+}
+
+/// `AzStyleAnimationFillModeVecDestructorType` struct
+pub type AzStyleAnimationFillModeVecDestructorType = extern "C" fn(&mut AzStyleAnimationFillModeVec);
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDelayVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationDelayVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleAnimationDelayVecDestructorType),
+}
+
+/// `AzStyleAnimationDelayVecDestructorType` struct
+pub type AzStyleAnimationDelayVecDestructorType = extern "C" fn(&mut AzStyleAnimationDelayVec);
+
+/// Re-export of rust-allocated (stack based) `CssKeyframeVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssKeyframeVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssKeyframeVecDestructorType),
+}
+
+/// `AzCssKeyframeVecDestructorType` struct
+pub type AzCssKeyframeVecDestructorType = extern "C" fn(&mut AzCssKeyframeVec);
+
+/// Re-export of rust-allocated (stack based) `CssKeyframesVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssKeyframesVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssKeyframesVecDestructorType),
+}
+
+/// `AzCssKeyframesVecDestructorType` struct
+pub type AzCssKeyframesVecDestructorType = extern "C" fn(&mut AzCssKeyframesVec);
+
+/// Re-export of rust-allocated (stack based) `GridTrackSizingVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzGridTrackSizingVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzGridTrackSizingVecDestructorType),
+}
+
+/// `AzGridTrackSizingVecDestructorType` struct
+pub type AzGridTrackSizingVecDestructorType = extern "C" fn(&mut AzGridTrackSizingVec);
+
+/// Re-export of rust-allocated (stack based) `CssMediaQueryVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssMediaQueryVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssMediaQueryVecDestructorType),
+}
+
+/// `AzCssMediaQueryVecDestructorType` struct
+pub type AzCssMediaQueryVecDestructorType = extern "C" fn(&mut AzCssMediaQueryVec);
+
+/// Re-export of rust-allocated (stack based) `CssMediaConditionVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssMediaConditionVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssMediaConditionVecDestructorType),
+}
+
+/// `AzCssMediaConditionVecDestructorType` struct
+pub type AzCssMediaConditionVecDestructorType = extern "C" fn(&mut AzCssMediaConditionVec);
+
 /// Re-export of rust-allocated (stack based) `CssRuleBlockVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzCssRuleBlockVecDestructor {
@@ -3348,6 +3790,39 @@ pub enum AzNodeDataVecDestructor {
 /// `AzNodeDataVecDestructorType` struct
 pub type AzNodeDataVecDestructorType = extern "C" fn(&mut AzNodeDataVec);
 
+/// Re-export of rust-allocated (stack based) `TextEditSnapshotVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTextEditSnapshotVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTextEditSnapshotVecDestructorType),
+}
+
+/// `AzTextEditSnapshotVecDestructorType` struct
+pub type AzTextEditSnapshotVecDestructorType = extern "C" fn(&mut AzTextEditSnapshotVec);
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetSizeOverrideVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzSpreadsheetSizeOverrideVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzSpreadsheetSizeOverrideVecDestructorType),
+}
+
+/// `AzSpreadsheetSizeOverrideVecDestructorType` struct
+pub type AzSpreadsheetSizeOverrideVecDestructorType = extern "C" fn(&mut AzSpreadsheetSizeOverrideVec);
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetCellContentVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzSpreadsheetCellContentVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzSpreadsheetCellContentVecDestructorType),
+}
+
+/// `AzSpreadsheetCellContentVecDestructorType` struct
+pub type AzSpreadsheetCellContentVecDestructorType = extern "C" fn(&mut AzSpreadsheetCellContentVec);
+
 /// Re-export of rust-allocated (stack based) `OptionI16` struct
 #[repr(C, u8)]
 pub enum AzOptionI16 {
@@ -3587,6 +4062,8 @@ pub struct AzInlineGlyph {
     pub unicode_codepoint: AzOptionCharEnumWrapper,
     #[pyo3(get, set)]
     pub glyph_index: u32,
+    #[pyo3(get, set)]
+    pub fallback_font_index: usize,
 }
 
 /// Re-export of rust-allocated (stack based) `InlineTextHit` struct
@@ -3625,6 +4102,10 @@ pub struct AzInlineTextHit {
     pub glyph_index_relative_to_word: usize,
     #[pyo3(get, set)]
     pub char_index_relative_to_word: usize,
+    #[pyo3(get, set)]
+    pub is_rtl: bool,
+    #[pyo3(get, set)]
+    pub cursor_char_index_relative_to_text: usize,
 }
 
 /// Re-export of rust-allocated (stack based) `IFrameCallbackInfo` struct
@@ -3744,6 +4225,69 @@ pub struct AzStyleBoxShadow {
     pub clip_mode: AzBoxShadowClipModeEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `GridTrackBreadth` struct
+#[repr(C, u8)]
+pub enum AzGridTrackBreadth {
+    Fixed(AzPixelValue),
+    Fraction(AzFloatValue),
+    Auto,
+}
+
+/// `minmax(min, max)` track size
+#[repr(C)]
+#[pyclass(name = "GridMinMax")]
+pub struct AzGridMinMax {
+    #[pyo3(get, set)]
+    pub min: AzGridTrackBreadthEnumWrapper,
+    #[pyo3(get, set)]
+    pub max: AzGridTrackBreadthEnumWrapper,
+}
+
+/// Size of a single column / row track of a `grid-template-*` declaration
+#[repr(C, u8)]
+pub enum AzGridTrackSizing {
+    Fixed(AzPixelValue),
+    Fraction(AzFloatValue),
+    Auto,
+    MinMax(AzGridMinMax),
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutGridColumn` struct
+#[repr(C)]
+#[pyclass(name = "LayoutGridColumn")]
+pub struct AzLayoutGridColumn {
+    #[pyo3(get, set)]
+    pub start: AzGridLineEnumWrapper,
+    #[pyo3(get, set)]
+    pub end: AzGridLineEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutGridRow` struct
+#[repr(C)]
+#[pyclass(name = "LayoutGridRow")]
+pub struct AzLayoutGridRow {
+    #[pyo3(get, set)]
+    pub start: AzGridLineEnumWrapper,
+    #[pyo3(get, set)]
+    pub end: AzGridLineEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutColumnGap` struct
+#[repr(C)]
+#[pyclass(name = "LayoutColumnGap")]
+pub struct AzLayoutColumnGap {
+    #[pyo3(get, set)]
+    pub inner: AzPixelValue,
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutRowGap` struct
+#[repr(C)]
+#[pyclass(name = "LayoutRowGap")]
+pub struct AzLayoutRowGap {
+    #[pyo3(get, set)]
+    pub inner: AzPixelValue,
+}
+
 /// Re-export of rust-allocated (stack based) `LayoutBottom` struct
 #[repr(C)]
 #[pyclass(name = "LayoutBottom")]
@@ -4183,6 +4727,27 @@ pub struct AzStylePerspectiveOrigin {
     pub y: AzPixelValue,
 }
 
+/// Property that is animated by a `transition` (`all` or a single property)
+#[repr(C, u8)]
+pub enum AzStyleTransitionProperty {
+    All,
+    Property(AzCssPropertyType),
+}
+
+/// Control points of a `cubic-bezier(x1, y1, x2, y2)` timing function
+#[repr(C)]
+#[pyclass(name = "StyleCubicBezier")]
+pub struct AzStyleCubicBezier {
+    #[pyo3(get, set)]
+    pub x1: AzFloatValue,
+    #[pyo3(get, set)]
+    pub y1: AzFloatValue,
+    #[pyo3(get, set)]
+    pub x2: AzFloatValue,
+    #[pyo3(get, set)]
+    pub y2: AzFloatValue,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleTransformMatrix2D` struct
 #[repr(C)]
 #[pyclass(name = "StyleTransformMatrix2D")]
@@ -4343,6 +4908,46 @@ pub enum AzLayoutAlignContentValue {
     Exact(AzLayoutAlignContent),
 }
 
+/// Re-export of rust-allocated (stack based) `LayoutGridColumnValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutGridColumnValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzLayoutGridColumn),
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutGridRowValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutGridRowValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzLayoutGridRow),
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutColumnGapValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutColumnGapValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzLayoutColumnGap),
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutRowGapValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutRowGapValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzLayoutRowGap),
+}
+
 /// Re-export of rust-allocated (stack based) `LayoutAlignItemsValue` struct
 #[repr(C, u8)]
 pub enum AzLayoutAlignItemsValue {
@@ -4423,6 +5028,16 @@ pub enum AzLayoutFloatValue {
     Exact(AzLayoutFloat),
 }
 
+/// Re-export of rust-allocated (stack based) `LayoutClearValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutClearValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzLayoutClear),
+}
+
 /// Re-export of rust-allocated (stack based) `LayoutHeightValue` struct
 #[repr(C, u8)]
 pub enum AzLayoutHeightValue {
@@ -5008,6 +5623,152 @@ pub struct AzNumberInputOnValueChange {
     pub callback: AzNumberInputOnValueChangeCallback,
 }
 
+/// Re-export of rust-allocated (stack based) `TextEditView` struct
+#[repr(C)]
+#[pyclass(name = "TextEditView")]
+pub struct AzTextEditView {
+    #[pyo3(get, set)]
+    pub preferred_caret_x: AzOptionF32EnumWrapper,
+    #[pyo3(get, set)]
+    pub scroll_y: f32,
+}
+
+/// Re-export of rust-allocated (stack based) `TextEditOnTextChange` struct
+#[repr(C)]
+#[pyclass(name = "TextEditOnTextChange")]
+pub struct AzTextEditOnTextChange {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzTextEditOnTextChangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TextEditOnFocusLost` struct
+#[repr(C)]
+#[pyclass(name = "TextEditOnFocusLost")]
+pub struct AzTextEditOnFocusLost {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzTextEditOnFocusLostCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `SliderState` struct
+#[repr(C)]
+#[pyclass(name = "SliderState")]
+pub struct AzSliderState {
+    #[pyo3(get, set)]
+    pub value: f32,
+    #[pyo3(get, set)]
+    pub min: f32,
+    #[pyo3(get, set)]
+    pub max: f32,
+    #[pyo3(get, set)]
+    pub step: f32,
+    #[pyo3(get, set)]
+    pub orientation: AzSliderOrientationEnumWrapper,
+    #[pyo3(get, set)]
+    pub tick_count: usize,
+    #[pyo3(get, set)]
+    pub show_tick_labels: bool,
+}
+
+/// Re-export of rust-allocated (stack based) `SliderOnValueChange` struct
+#[repr(C)]
+#[pyclass(name = "SliderOnValueChange")]
+pub struct AzSliderOnValueChange {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzSliderOnValueChangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `DropDownOnChoiceChange` struct
+#[repr(C)]
+#[pyclass(name = "DropDownOnChoiceChange")]
+pub struct AzDropDownOnChoiceChange {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzDropDownOnChoiceChangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetStyle` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetStyle")]
+pub struct AzSpreadsheetStyle {
+    #[pyo3(get, set)]
+    pub accent_color: AzColorU,
+}
+
+/// Represents a rectangular selection of table cells
+#[repr(C)]
+#[pyclass(name = "SpreadsheetCellSelection")]
+pub struct AzSpreadsheetCellSelection {
+    #[pyo3(get, set)]
+    pub from_top_left: AzSpreadsheetCellIndex,
+    #[pyo3(get, set)]
+    pub to_bottom_right: AzSpreadsheetCellIndex,
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetRenderWindow` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetRenderWindow")]
+pub struct AzSpreadsheetRenderWindow {
+    #[pyo3(get, set)]
+    pub row_start: usize,
+    #[pyo3(get, set)]
+    pub row_end: usize,
+    #[pyo3(get, set)]
+    pub column_start: usize,
+    #[pyo3(get, set)]
+    pub column_end: usize,
+    #[pyo3(get, set)]
+    pub origin: AzLogicalPosition,
+    #[pyo3(get, set)]
+    pub scroll_position: AzLogicalPosition,
+    #[pyo3(get, set)]
+    pub viewport: AzLogicalSize,
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetDrag` struct
+#[repr(C, u8)]
+pub enum AzSpreadsheetDrag {
+    None,
+    SelectCells,
+    ResizeColumn(AzSpreadsheetColumnResize),
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetOnCellsChange` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetOnCellsChange")]
+pub struct AzSpreadsheetOnCellsChange {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzSpreadsheetOnCellsChangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetOnSelectionChange` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetOnSelectionChange")]
+pub struct AzSpreadsheetOnSelectionChange {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzSpreadsheetOnSelectionChangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetOnColumnResize` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetOnColumnResize")]
+pub struct AzSpreadsheetOnColumnResize {
+    #[pyo3(get, set)]
+    pub data: AzRefAny,
+    #[pyo3(get, set)]
+    pub callback: AzSpreadsheetOnColumnResizeCallback,
+}
+
 /// Re-export of rust-allocated (stack based) `ParentWithNodeDepth` struct
 #[repr(C)]
 #[pyclass(name = "ParentWithNodeDepth")]
@@ -5280,6 +6041,19 @@ pub struct AzAccessibilityStateVec {
     pub destructor: AzAccessibilityStateVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<AccessibilityNode>`
+#[repr(C)]
+#[pyclass(name = "AccessibilityNodeVec")]
+pub struct AzAccessibilityNodeVec {
+    pub(crate) ptr: *const AzAccessibilityNode,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzAccessibilityNodeVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<MenuItem>`
 #[repr(C)]
 #[pyclass(name = "MenuItemVec")]
@@ -5475,6 +6249,136 @@ pub struct AzScanCodeVec {
     pub destructor: AzScanCodeVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `CssPathSelector`
+#[repr(C)]
+#[pyclass(name = "CssPathSelectorVec")]
+pub struct AzCssPathSelectorVec {
+    pub(crate) ptr: *const AzCssPathSelectorEnumWrapper,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzCssPathSelectorVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleTransitionProperty>`
+#[repr(C)]
+#[pyclass(name = "StyleTransitionPropertyVec")]
+pub struct AzStyleTransitionPropertyVec {
+    pub(crate) ptr: *const AzStyleTransitionPropertyEnumWrapper,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzStyleTransitionPropertyVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleTransitionDuration>`
+#[repr(C)]
+#[pyclass(name = "StyleTransitionDurationVec")]
+pub struct AzStyleTransitionDurationVec {
+    pub(crate) ptr: *const AzStyleTransitionDuration,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzStyleTransitionDurationVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleTransitionDelay>`
+#[repr(C)]
+#[pyclass(name = "StyleTransitionDelayVec")]
+pub struct AzStyleTransitionDelayVec {
+    pub(crate) ptr: *const AzStyleTransitionDelay,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzStyleTransitionDelayVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationDuration>`
+#[repr(C)]
+#[pyclass(name = "StyleAnimationDurationVec")]
+pub struct AzStyleAnimationDurationVec {
+    pub(crate) ptr: *const AzStyleAnimationDuration,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzStyleAnimationDurationVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationIterationCount>`
+#[repr(C)]
+#[pyclass(name = "StyleAnimationIterationCountVec")]
+pub struct AzStyleAnimationIterationCountVec {
+    pub(crate) ptr: *const AzStyleAnimationIterationCountEnumWrapper,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzStyleAnimationIterationCountVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationDirection>`
+#[repr(C)]
+#[pyclass(name = "StyleAnimationDirectionVec")]
+pub struct AzStyleAnimationDirectionVec {
+    pub(crate) ptr: *const AzStyleAnimationDirectionEnumWrapper,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzStyleAnimationDirectionVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationFillMode>`
+#[repr(C)]
+#[pyclass(name = "StyleAnimationFillModeVec")]
+pub struct AzStyleAnimationFillModeVec {
+    pub(crate) ptr: *const AzStyleAnimationFillModeEnumWrapper,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzStyleAnimationFillModeVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationDelay>`
+#[repr(C)]
+#[pyclass(name = "StyleAnimationDelayVec")]
+pub struct AzStyleAnimationDelayVec {
+    pub(crate) ptr: *const AzStyleAnimationDelay,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzStyleAnimationDelayVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<GridTrackSizing>`
+#[repr(C)]
+#[pyclass(name = "GridTrackSizingVec")]
+pub struct AzGridTrackSizingVec {
+    pub(crate) ptr: *const AzGridTrackSizingEnumWrapper,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzGridTrackSizingVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<u16>`
 #[repr(C)]
 #[pyclass(name = "U16Vec")]
@@ -5605,6 +6509,19 @@ pub struct AzParentWithNodeDepthVec {
     pub destructor: AzParentWithNodeDepthVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<SpreadsheetSizeOverride>`
+#[repr(C)]
+#[pyclass(name = "SpreadsheetSizeOverrideVec")]
+pub struct AzSpreadsheetSizeOverrideVec {
+    pub(crate) ptr: *const AzSpreadsheetSizeOverride,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzSpreadsheetSizeOverrideVecDestructorEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `OptionColorInputOnValueChange` struct
 #[repr(C, u8)]
 pub enum AzOptionColorInputOnValueChange {
@@ -5661,6 +6578,69 @@ pub enum AzOptionNumberInputOnValueChange {
     Some(AzNumberInputOnValueChange),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionTextEditOnTextChange` struct
+#[repr(C, u8)]
+pub enum AzOptionTextEditOnTextChange {
+    None,
+    Some(AzTextEditOnTextChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTextEditOnFocusLost` struct
+#[repr(C, u8)]
+pub enum AzOptionTextEditOnFocusLost {
+    None,
+    Some(AzTextEditOnFocusLost),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTextEditKind` struct
+#[repr(C, u8)]
+pub enum AzOptionTextEditKind {
+    None,
+    Some(AzTextEditKind),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionSliderOnValueChange` struct
+#[repr(C, u8)]
+pub enum AzOptionSliderOnValueChange {
+    None,
+    Some(AzSliderOnValueChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionDropDownOnChoiceChange` struct
+#[repr(C, u8)]
+pub enum AzOptionDropDownOnChoiceChange {
+    None,
+    Some(AzDropDownOnChoiceChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionSpreadsheetOnCellsChange` struct
+#[repr(C, u8)]
+pub enum AzOptionSpreadsheetOnCellsChange {
+    None,
+    Some(AzSpreadsheetOnCellsChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionSpreadsheetOnSelectionChange` struct
+#[repr(C, u8)]
+pub enum AzOptionSpreadsheetOnSelectionChange {
+    None,
+    Some(AzSpreadsheetOnSelectionChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionSpreadsheetOnColumnResize` struct
+#[repr(C, u8)]
+pub enum AzOptionSpreadsheetOnColumnResize {
+    None,
+    Some(AzSpreadsheetOnColumnResize),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionSpreadsheetCellSelection` struct
+#[repr(C, u8)]
+pub enum AzOptionSpreadsheetCellSelection {
+    None,
+    Some(AzSpreadsheetCellSelection),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionMenuItemIcon` struct
 #[repr(C, u8)]
 pub enum AzOptionMenuItemIcon {
@@ -6094,6 +7074,8 @@ pub struct AzInlineTextContents {
     pub glyphs: AzInlineGlyphVec,
     #[pyo3(get, set)]
     pub bounds: AzLogicalRect,
+    #[pyo3(get, set)]
+    pub is_rtl: bool,
 }
 
 /// Easing function of the animation (ease-in, ease-out, ease-in-out, custom)
@@ -6171,6 +7153,37 @@ pub struct AzVirtualKeyCodeCombo {
     pub keys: AzVirtualKeyCodeVec,
 }
 
+/// Window-wide keyboard shortcut that is not attached to a menu item
+#[repr(C)]
+#[pyclass(name = "KeyboardShortcut")]
+pub struct AzKeyboardShortcut {
+    #[pyo3(get, set)]
+    pub keys: AzVirtualKeyCodeCombo,
+    #[pyo3(get, set)]
+    pub callback: AzMenuCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `CssMediaCondition` struct
+#[repr(C, u8)]
+pub enum AzCssMediaCondition {
+    MinWidth(AzPixelValue),
+    MaxWidth(AzPixelValue),
+    MinHeight(AzPixelValue),
+    MaxHeight(AzPixelValue),
+    MinResolution(AzFloatValue),
+    MaxResolution(AzFloatValue),
+    Orientation(AzCssMediaOrientation),
+    PrefersColorScheme(AzCssMediaColorScheme),
+}
+
+/// Re-export of rust-allocated (stack based) `CssPath` struct
+#[repr(C)]
+#[pyclass(name = "CssPath")]
+pub struct AzCssPath {
+    #[pyo3(get, set)]
+    pub selectors: AzCssPathSelectorVec,
+}
+
 /// Re-export of rust-allocated (stack based) `CssPathPseudoSelector` struct
 #[repr(C, u8)]
 pub enum AzCssPathPseudoSelector {
@@ -6180,6 +7193,12 @@ pub enum AzCssPathPseudoSelector {
     Hover,
     Active,
     Focus,
+    FirstChild,
+    LastChild,
+    OnlyChild,
+    NthLastChild(AzCssNthChildSelector),
+    Empty,
+    FocusWithin,
 }
 
 /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
@@ -6209,6 +7228,22 @@ pub struct AzInterpolateContext {
     pub current_rect_height: f32,
 }
 
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumns` struct
+#[repr(C)]
+#[pyclass(name = "LayoutGridTemplateColumns")]
+pub struct AzLayoutGridTemplateColumns {
+    #[pyo3(get, set)]
+    pub tracks: AzGridTrackSizingVec,
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateRows` struct
+#[repr(C)]
+#[pyclass(name = "LayoutGridTemplateRows")]
+pub struct AzLayoutGridTemplateRows {
+    #[pyo3(get, set)]
+    pub tracks: AzGridTrackSizingVec,
+}
+
 /// Re-export of rust-allocated (stack based) `LinearGradient` struct
 #[repr(C)]
 #[pyclass(name = "LinearGradient")]
@@ -6251,6 +7286,17 @@ pub struct AzConicGradient {
     pub stops: AzNormalizedRadialColorStopVec,
 }
 
+/// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunction` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionTimingFunction {
+    Ease,
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    CubicBezier(AzStyleCubicBezier),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleTransform` struct
 #[repr(C, u8)]
 pub enum AzStyleTransform {
@@ -6277,6 +7323,26 @@ pub enum AzStyleTransform {
     Perspective(AzPixelValue),
 }
 
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumnsValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutGridTemplateColumnsValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzLayoutGridTemplateColumns),
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateRowsValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutGridTemplateRowsValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzLayoutGridTemplateRows),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleBackgroundPositionVecValue` struct
 #[repr(C, u8)]
 pub enum AzStyleBackgroundPositionVecValue {
@@ -6307,6 +7373,86 @@ pub enum AzStyleBackgroundSizeVecValue {
     Exact(AzStyleBackgroundSizeVec),
 }
 
+/// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionPropertyVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleTransitionPropertyVec),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionDurationVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionDurationVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleTransitionDurationVec),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionDelayVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionDelayVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleTransitionDelayVec),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDurationVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationDurationVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleAnimationDurationVec),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationIterationCountVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleAnimationIterationCountVec),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationDirectionVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleAnimationDirectionVec),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationFillModeVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleAnimationFillModeVec),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDelayVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationDelayVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleAnimationDelayVec),
+}
+
 /// Re-export of rust-allocated (stack based) `CheckBoxStateWrapper` struct
 #[repr(C)]
 #[pyclass(name = "CheckBoxStateWrapper")]
@@ -6327,6 +7473,51 @@ pub struct AzNumberInputStateWrapper {
     pub on_value_change: AzOptionNumberInputOnValueChangeEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `TextEditState` struct
+#[repr(C)]
+#[pyclass(name = "TextEditState")]
+pub struct AzTextEditState {
+    #[pyo3(get, set)]
+    pub text: AzU32Vec,
+    #[pyo3(get, set)]
+    pub selection: AzOptionTextInputSelectionEnumWrapper,
+    #[pyo3(get, set)]
+    pub cursor_pos: usize,
+    #[pyo3(get, set)]
+    pub show_line_numbers: bool,
+}
+
+/// Re-export of rust-allocated (stack based) `TextEditSnapshot` struct
+#[repr(C)]
+#[pyclass(name = "TextEditSnapshot")]
+pub struct AzTextEditSnapshot {
+    #[pyo3(get, set)]
+    pub text: AzU32Vec,
+    #[pyo3(get, set)]
+    pub cursor_pos: usize,
+}
+
+/// Re-export of rust-allocated (stack based) `SliderStateWrapper` struct
+#[repr(C)]
+#[pyclass(name = "SliderStateWrapper")]
+pub struct AzSliderStateWrapper {
+    #[pyo3(get, set)]
+    pub inner: AzSliderState,
+    #[pyo3(get, set)]
+    pub on_value_change: AzOptionSliderOnValueChangeEnumWrapper,
+    #[pyo3(get, set)]
+    pub is_dragging: bool,
+    #[pyo3(get, set)]
+    pub drag_value: f32,
+}
+
+/// Re-export of rust-allocated (stack based) `CssPropertySource` struct
+#[repr(C, u8)]
+pub enum AzCssPropertySource {
+    Css(AzCssPath),
+    Inline,
+}
+
 /// Re-export of rust-allocated (stack based) `StyledNode` struct
 #[repr(C)]
 #[pyclass(name = "StyledNode")]
@@ -6533,6 +7724,45 @@ pub struct AzSvgPathElementVec {
     pub destructor: AzSvgPathElementVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<KeyboardShortcut>`
+#[repr(C)]
+#[pyclass(name = "KeyboardShortcutVec")]
+pub struct AzKeyboardShortcutVec {
+    pub(crate) ptr: *const AzKeyboardShortcut,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzKeyboardShortcutVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `CssMediaCondition`
+#[repr(C)]
+#[pyclass(name = "CssMediaConditionVec")]
+pub struct AzCssMediaConditionVec {
+    pub(crate) ptr: *const AzCssMediaConditionEnumWrapper,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzCssMediaConditionVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleTransitionTimingFunction>`
+#[repr(C)]
+#[pyclass(name = "StyleTransitionTimingFunctionVec")]
+pub struct AzStyleTransitionTimingFunctionVec {
+    pub(crate) ptr: *const AzStyleTransitionTimingFunctionEnumWrapper,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzStyleTransitionTimingFunctionVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `StringVec`
 #[repr(C)]
 #[pyclass(name = "StringVec")]
@@ -6572,6 +7802,19 @@ pub struct AzTagIdToNodeIdMappingVec {
     pub destructor: AzTagIdToNodeIdMappingVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<TextEditSnapshot>`
+#[repr(C)]
+#[pyclass(name = "TextEditSnapshotVec")]
+pub struct AzTextEditSnapshotVec {
+    pub(crate) ptr: *const AzTextEditSnapshot,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzTextEditSnapshotVecDestructorEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `OptionVirtualKeyCodeCombo` struct
 #[repr(C, u8)]
 pub enum AzOptionVirtualKeyCodeCombo {
@@ -6818,6 +8061,16 @@ pub enum AzInlineWord {
     Word(AzInlineTextContents),
 }
 
+/// CSS path to set the keyboard input focus
+#[repr(C)]
+#[pyclass(name = "FocusTargetPath")]
+pub struct AzFocusTargetPath {
+    #[pyo3(get, set)]
+    pub dom: AzDomId,
+    #[pyo3(get, set)]
+    pub css_path: AzCssPath,
+}
+
 /// Re-export of rust-allocated (stack based) `CallbackData` struct
 #[repr(C)]
 #[pyclass(name = "CallbackData")]
@@ -6859,6 +8112,38 @@ pub struct AzAccessibilityInfo {
     pub default_action: AzOptionStringEnumWrapper,
 }
 
+/// Single node in the accessibility tree of a window, see `AccessibilityTree`
+#[repr(C)]
+#[pyclass(name = "AccessibilityNode")]
+pub struct AzAccessibilityNode {
+    #[pyo3(get, set)]
+    pub node_id: AzDomNodeId,
+    #[pyo3(get, set)]
+    pub role: AzAccessibilityRoleEnumWrapper,
+    #[pyo3(get, set)]
+    pub name: AzOptionStringEnumWrapper,
+    #[pyo3(get, set)]
+    pub value: AzOptionStringEnumWrapper,
+    #[pyo3(get, set)]
+    pub states: AzAccessibilityStateVec,
+    #[pyo3(get, set)]
+    pub accelerator: AzOptionVirtualKeyCodeComboEnumWrapper,
+    #[pyo3(get, set)]
+    pub default_action: AzOptionStringEnumWrapper,
+    #[pyo3(get, set)]
+    pub bounds: AzLogicalRect,
+    #[pyo3(get, set)]
+    pub children: AzAccessibilityNodeVec,
+}
+
+/// Platform-neutral snapshot of the accessibility tree of all DOMs in a window, can be fed into an assistive-technology bridge (AT-SPI, UIA, NSAccessibility)
+#[repr(C)]
+#[pyclass(name = "AccessibilityTree")]
+pub struct AzAccessibilityTree {
+    #[pyo3(get, set)]
+    pub root: AzAccessibilityNode,
+}
+
 /// Re-export of rust-allocated (stack based) `IdOrClass` struct
 #[repr(C, u8)]
 pub enum AzIdOrClass {
@@ -6884,16 +8169,56 @@ pub struct AzStringMenuItem {
     pub children: AzMenuItemVec,
 }
 
-/// Re-export of rust-allocated (stack based) `CssPathSelector` struct
+/// Returned when a keyboard shortcut can't be registered because its key combination is already in use
 #[repr(C, u8)]
-pub enum AzCssPathSelector {
-    Global,
-    Type(AzNodeTypeKey),
-    Class(AzString),
-    Id(AzString),
-    PseudoSelector(AzCssPathPseudoSelector),
-    DirectChildren,
-    Children,
+pub enum AzShortcutConflict {
+    Shortcut(AzVirtualKeyCodeCombo),
+    MenuItem(AzString),
+    ContextMenuItem(AzString),
+}
+
+/// One query of an `@media` rule, matches if all conditions match
+#[repr(C)]
+#[pyclass(name = "CssMediaQuery")]
+pub struct AzCssMediaQuery {
+    #[pyo3(get, set)]
+    pub conditions: AzCssMediaConditionVec,
+}
+
+/// Custom property (CSS variable) declaration, i.e. `--accent: #3366ff`
+#[repr(C)]
+#[pyclass(name = "CssCustomProperty")]
+pub struct AzCssCustomProperty {
+    #[pyo3(get, set)]
+    pub name: AzString,
+    #[pyo3(get, set)]
+    pub value: AzString,
+}
+
+/// Attribute selector such as `[data-state=open]`, matched against the attributes of a node
+#[repr(C)]
+#[pyclass(name = "CssAttributeSelector")]
+pub struct AzCssAttributeSelector {
+    #[pyo3(get, set)]
+    pub name: AzString,
+    #[pyo3(get, set)]
+    pub op: AzCssAttributeSelectorOpEnumWrapper,
+    #[pyo3(get, set)]
+    pub value: AzString,
+}
+
+/// Font file declared via an `@font-face` rule
+#[repr(C)]
+#[pyclass(name = "CssFontFace")]
+pub struct AzCssFontFace {
+    #[pyo3(get, set)]
+    pub font_family: AzString,
+    #[pyo3(get, set)]
+    pub src: AzString,
+    #[pyo3(get, set)]
+    pub font_weight: u16,
+    #[pyo3(get, set)]
+    pub font_style: AzCssFontFaceStyleEnumWrapper,
 }
 
 /// Re-export of rust-allocated (stack based) `StyleBackgroundContent` struct
@@ -6946,6 +8271,14 @@ pub enum AzStyleFontFamily {
     Ref(AzFontRef),
 }
 
+/// Name of the `@keyframes` rule that an `animation-name` refers to
+#[repr(C)]
+#[pyclass(name = "StyleAnimationName")]
+pub struct AzStyleAnimationName {
+    #[pyo3(get, set)]
+    pub name: AzString,
+}
+
 /// Re-export of rust-allocated (stack based) `ScrollbarStyleValue` struct
 #[repr(C, u8)]
 pub enum AzScrollbarStyleValue {
@@ -6966,6 +8299,16 @@ pub enum AzStyleTransformVecValue {
     Exact(AzStyleTransformVec),
 }
 
+/// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunctionVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionTimingFunctionVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleTransitionTimingFunctionVec),
+}
+
 /// Re-export of rust-allocated (stack based) `ColorInputStateWrapper` struct
 #[repr(C)]
 #[pyclass(name = "ColorInputStateWrapper")]
@@ -6994,6 +8337,62 @@ pub struct AzTextInputState {
     pub cursor_pos: usize,
 }
 
+/// Re-export of rust-allocated (stack based) `TextEditHistory` struct
+#[repr(C)]
+#[pyclass(name = "TextEditHistory")]
+pub struct AzTextEditHistory {
+    #[pyo3(get, set)]
+    pub undo_stack: AzTextEditSnapshotVec,
+    #[pyo3(get, set)]
+    pub redo_stack: AzTextEditSnapshotVec,
+    #[pyo3(get, set)]
+    pub last_edit: AzOptionTextEditKindEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `DropDownState` struct
+#[repr(C)]
+#[pyclass(name = "DropDownState")]
+pub struct AzDropDownState {
+    #[pyo3(get, set)]
+    pub choices: AzStringVec,
+    #[pyo3(get, set)]
+    pub disabled_choices: AzU32Vec,
+    #[pyo3(get, set)]
+    pub selected: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub popup_position: AzMenuPopupPositionEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetCellContent` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetCellContent")]
+pub struct AzSpreadsheetCellContent {
+    #[pyo3(get, set)]
+    pub cell: AzSpreadsheetCellIndex,
+    #[pyo3(get, set)]
+    pub text: AzString,
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetCellClick` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetCellClick")]
+pub struct AzSpreadsheetCellClick {
+    #[pyo3(get, set)]
+    pub cell: AzSpreadsheetCellIndex,
+    #[pyo3(get, set)]
+    pub time: AzInstantEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetCellEdit` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetCellEdit")]
+pub struct AzSpreadsheetCellEdit {
+    #[pyo3(get, set)]
+    pub cell: AzSpreadsheetCellIndex,
+    #[pyo3(get, set)]
+    pub text: AzString,
+}
+
 /// Re-export of rust-allocated (stack based) `VertexAttribute` struct
 #[repr(C)]
 #[pyclass(name = "VertexAttribute")]
@@ -7282,17 +8681,43 @@ pub struct AzVertexAttributeVec {
     pub destructor: AzVertexAttributeVecDestructorEnumWrapper,
 }
 
-/// Wrapper over a Rust-allocated `CssPathSelector`
+/// Wrapper over a Rust-allocated `CssMediaQuery`
 #[repr(C)]
-#[pyclass(name = "CssPathSelectorVec")]
-pub struct AzCssPathSelectorVec {
-    pub(crate) ptr: *const AzCssPathSelectorEnumWrapper,
+#[pyclass(name = "CssMediaQueryVec")]
+pub struct AzCssMediaQueryVec {
+    pub(crate) ptr: *const AzCssMediaQuery,
     #[pyo3(get, set)]
     pub len: usize,
     #[pyo3(get, set)]
     pub cap: usize,
     #[pyo3(get, set)]
-    pub destructor: AzCssPathSelectorVecDestructorEnumWrapper,
+    pub destructor: AzCssMediaQueryVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `CssFontFace`
+#[repr(C)]
+#[pyclass(name = "CssFontFaceVec")]
+pub struct AzCssFontFaceVec {
+    pub(crate) ptr: *const AzCssFontFace,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzCssFontFaceVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationName>`
+#[repr(C)]
+#[pyclass(name = "StyleAnimationNameVec")]
+pub struct AzStyleAnimationNameVec {
+    pub(crate) ptr: *const AzStyleAnimationName,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzStyleAnimationNameVecDestructorEnumWrapper,
 }
 
 /// Wrapper over a Rust-allocated `CallbackData`
@@ -7334,6 +8759,40 @@ pub struct AzStringPairVec {
     pub destructor: AzStringPairVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<SpreadsheetCellContent>`
+#[repr(C)]
+#[pyclass(name = "SpreadsheetCellContentVec")]
+pub struct AzSpreadsheetCellContentVec {
+    pub(crate) ptr: *const AzSpreadsheetCellContent,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzSpreadsheetCellContentVecDestructorEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `OptionSpreadsheetCellClick` struct
+#[repr(C, u8)]
+pub enum AzOptionSpreadsheetCellClick {
+    None,
+    Some(AzSpreadsheetCellClick),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionSpreadsheetCellEdit` struct
+#[repr(C, u8)]
+pub enum AzOptionSpreadsheetCellEdit {
+    None,
+    Some(AzSpreadsheetCellEdit),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionAccessibilityNode` struct
+#[repr(C, u8)]
+pub enum AzOptionAccessibilityNode {
+    None,
+    Some(AzAccessibilityNode),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionFileTypeList` struct
 #[repr(C, u8)]
 pub enum AzOptionFileTypeList {
@@ -7427,6 +8886,18 @@ pub struct AzInlineLine {
     pub bounds: AzLogicalRect,
 }
 
+/// Defines the keyboard input focus target
+#[repr(C, u8)]
+pub enum AzFocusTarget {
+    Id(AzDomNodeId),
+    Path(AzFocusTargetPath),
+    Previous,
+    Next,
+    First,
+    Last,
+    NoFocus,
+}
+
 /// Item entry in a menu or menu bar
 #[repr(C, u8)]
 pub enum AzMenuItem {
@@ -7435,12 +8906,20 @@ pub enum AzMenuItem {
     BreakLine,
 }
 
-/// Re-export of rust-allocated (stack based) `CssPath` struct
-#[repr(C)]
-#[pyclass(name = "CssPath")]
-pub struct AzCssPath {
-    #[pyo3(get, set)]
-    pub selectors: AzCssPathSelectorVec,
+/// Re-export of rust-allocated (stack based) `CssPathSelector` struct
+#[repr(C, u8)]
+pub enum AzCssPathSelector {
+    Global,
+    Type(AzNodeTypeKey),
+    Class(AzString),
+    Id(AzString),
+    PseudoSelector(AzCssPathPseudoSelector),
+    Attribute(AzCssAttributeSelector),
+    Not(AzCssPathSelectorVec),
+    DirectChildren,
+    Children,
+    AdjacentSibling,
+    GeneralSibling,
 }
 
 /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
@@ -7463,6 +8942,16 @@ pub enum AzStyleFontFamilyVecValue {
     Exact(AzStyleFontFamilyVec),
 }
 
+/// Re-export of rust-allocated (stack based) `StyleAnimationNameVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationNameVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleAnimationNameVec),
+}
+
 /// Parsed CSS key-value pair
 #[repr(C, u8)]
 pub enum AzCssProperty {
@@ -7477,6 +8966,7 @@ pub enum AzCssProperty {
     Cursor(AzStyleCursorValue),
     Display(AzLayoutDisplayValue),
     Float(AzLayoutFloatValue),
+    Clear(AzLayoutClearValue),
     BoxSizing(AzLayoutBoxSizingValue),
     Width(AzLayoutWidthValue),
     Height(AzLayoutHeightValue),
@@ -7496,6 +8986,12 @@ pub enum AzCssProperty {
     JustifyContent(AzLayoutJustifyContentValue),
     AlignItems(AzLayoutAlignItemsValue),
     AlignContent(AzLayoutAlignContentValue),
+    GridTemplateColumns(AzLayoutGridTemplateColumnsValue),
+    GridTemplateRows(AzLayoutGridTemplateRowsValue),
+    GridColumn(AzLayoutGridColumnValue),
+    GridRow(AzLayoutGridRowValue),
+    ColumnGap(AzLayoutColumnGapValue),
+    RowGap(AzLayoutRowGapValue),
     BackgroundContent(AzStyleBackgroundContentVecValue),
     BackgroundPosition(AzStyleBackgroundPositionVecValue),
     BackgroundSize(AzStyleBackgroundSizeVecValue),
@@ -7536,6 +9032,17 @@ pub enum AzCssProperty {
     TransformOrigin(AzStyleTransformOriginValue),
     PerspectiveOrigin(AzStylePerspectiveOriginValue),
     BackfaceVisibility(AzStyleBackfaceVisibilityValue),
+    TransitionProperty(AzStyleTransitionPropertyVecValue),
+    TransitionDuration(AzStyleTransitionDurationVecValue),
+    TransitionTimingFunction(AzStyleTransitionTimingFunctionVecValue),
+    TransitionDelay(AzStyleTransitionDelayVecValue),
+    AnimationName(AzStyleAnimationNameVecValue),
+    AnimationDuration(AzStyleAnimationDurationVecValue),
+    AnimationTimingFunction(AzStyleTransitionTimingFunctionVecValue),
+    AnimationIterationCount(AzStyleAnimationIterationCountVecValue),
+    AnimationDirection(AzStyleAnimationDirectionVecValue),
+    AnimationFillMode(AzStyleAnimationFillModeVecValue),
+    AnimationDelay(AzStyleAnimationDelayVecValue),
 }
 
 /// Re-export of rust-allocated (stack based) `TextInputStateWrapper` struct
@@ -7554,13 +9061,82 @@ pub struct AzTextInputStateWrapper {
     pub update_text_input_before_calling_focus_lost_fn: bool,
     #[pyo3(get, set)]
     pub update_text_input_before_calling_vk_down_fn: bool,
+    #[pyo3(get, set)]
+    pub is_dragging: bool,
 }
 
-/// Re-export of rust-allocated (stack based) `CssPropertySource` struct
-#[repr(C, u8)]
-pub enum AzCssPropertySource {
-    Css(AzCssPath),
-    Inline,
+/// Re-export of rust-allocated (stack based) `TextEditStateWrapper` struct
+#[repr(C)]
+#[pyclass(name = "TextEditStateWrapper")]
+pub struct AzTextEditStateWrapper {
+    #[pyo3(get, set)]
+    pub inner: AzTextEditState,
+    #[pyo3(get, set)]
+    pub on_text_change: AzOptionTextEditOnTextChangeEnumWrapper,
+    #[pyo3(get, set)]
+    pub on_focus_lost: AzOptionTextEditOnFocusLostEnumWrapper,
+    #[pyo3(get, set)]
+    pub history: AzTextEditHistory,
+    #[pyo3(get, set)]
+    pub view: AzTextEditView,
+}
+
+/// Re-export of rust-allocated (stack based) `DropDownStateWrapper` struct
+#[repr(C)]
+#[pyclass(name = "DropDownStateWrapper")]
+pub struct AzDropDownStateWrapper {
+    #[pyo3(get, set)]
+    pub inner: AzDropDownState,
+    #[pyo3(get, set)]
+    pub on_choice_change: AzOptionDropDownOnChoiceChangeEnumWrapper,
+    #[pyo3(get, set)]
+    pub is_open: bool,
+    #[pyo3(get, set)]
+    pub highlighted: AzOptionUsizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub search: AzString,
+    #[pyo3(get, set)]
+    pub last_search_input: AzOptionInstantEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetState` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetState")]
+pub struct AzSpreadsheetState {
+    #[pyo3(get, set)]
+    pub style: AzSpreadsheetStyle,
+    #[pyo3(get, set)]
+    pub row_count: usize,
+    #[pyo3(get, set)]
+    pub column_count: usize,
+    #[pyo3(get, set)]
+    pub default_column_width: f32,
+    #[pyo3(get, set)]
+    pub default_row_height: f32,
+    #[pyo3(get, set)]
+    pub selection: AzOptionSpreadsheetCellSelectionEnumWrapper,
+    #[pyo3(get, set)]
+    pub column_width_overrides: AzSpreadsheetSizeOverrideVec,
+    #[pyo3(get, set)]
+    pub row_height_overrides: AzSpreadsheetSizeOverrideVec,
+    #[pyo3(get, set)]
+    pub cell_contents: AzSpreadsheetCellContentVec,
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetView` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetView")]
+pub struct AzSpreadsheetView {
+    #[pyo3(get, set)]
+    pub rendered: AzSpreadsheetRenderWindow,
+    #[pyo3(get, set)]
+    pub iframe_node: AzOptionDomNodeIdEnumWrapper,
+    #[pyo3(get, set)]
+    pub drag: AzSpreadsheetDragEnumWrapper,
+    #[pyo3(get, set)]
+    pub last_click: AzOptionSpreadsheetCellClickEnumWrapper,
+    #[pyo3(get, set)]
+    pub editing: AzOptionSpreadsheetCellEditEnumWrapper,
 }
 
 /// Re-export of rust-allocated (stack based) `VertexLayout` struct
@@ -7729,6 +9305,8 @@ pub struct AzWindowState {
     pub layout_callback: AzLayoutCallbackEnumWrapper,
     #[pyo3(get, set)]
     pub close_callback: AzOptionCallbackEnumWrapper,
+    #[pyo3(get, set)]
+    pub shortcuts: AzKeyboardShortcutVec,
 }
 
 /// Re-export of rust-allocated (stack based) `CallbackInfo` struct
@@ -7763,6 +9341,8 @@ pub struct AzCallbackInfo {
     pub images_changed_in_callbacks: *mut c_void,
     pub image_masks_changed_in_callbacks: *mut c_void,
     pub css_properties_changed_in_callbacks: *mut c_void,
+    pub css_variables_changed_in_callbacks: *mut c_void,
+    pub css_transitions_changed_in_callbacks: *mut c_void,
     pub current_scroll_states: *const c_void,
     pub nodes_scrolled_in_callback: *mut c_void,
     #[pyo3(get, set)]
@@ -7791,16 +9371,6 @@ pub struct AzInlineText {
     pub baseline_descender_px: f32,
 }
 
-/// CSS path to set the keyboard input focus
-#[repr(C)]
-#[pyclass(name = "FocusTargetPath")]
-pub struct AzFocusTargetPath {
-    #[pyo3(get, set)]
-    pub dom: AzDomId,
-    #[pyo3(get, set)]
-    pub css_path: AzCssPath,
-}
-
 /// Animation struct to start a new animation
 #[repr(C)]
 #[pyclass(name = "Animation")]
@@ -7858,6 +9428,32 @@ pub struct AzDynamicCssProperty {
     pub default_value: AzCssPropertyEnumWrapper,
 }
 
+/// Single step (i.e. `50% { opacity: 0.5; }`) of a `@keyframes` rule
+#[repr(C)]
+#[pyclass(name = "CssKeyframe")]
+pub struct AzCssKeyframe {
+    #[pyo3(get, set)]
+    pub offset: AzFloatValue,
+    #[pyo3(get, set)]
+    pub properties: AzCssPropertyVec,
+}
+
+/// Re-export of rust-allocated (stack based) `SpreadsheetStateWrapper` struct
+#[repr(C)]
+#[pyclass(name = "SpreadsheetStateWrapper")]
+pub struct AzSpreadsheetStateWrapper {
+    #[pyo3(get, set)]
+    pub inner: AzSpreadsheetState,
+    #[pyo3(get, set)]
+    pub on_cells_change: AzOptionSpreadsheetOnCellsChangeEnumWrapper,
+    #[pyo3(get, set)]
+    pub on_selection_change: AzOptionSpreadsheetOnSelectionChangeEnumWrapper,
+    #[pyo3(get, set)]
+    pub on_column_resize: AzOptionSpreadsheetOnColumnResizeEnumWrapper,
+    #[pyo3(get, set)]
+    pub view: AzSpreadsheetView,
+}
+
 /// Re-export of rust-allocated (stack based) `SvgNode` struct
 #[repr(C, u8)]
 pub enum AzSvgNode {
@@ -7891,6 +9487,19 @@ pub struct AzNodeDataInlineCssPropertyVec {
     pub destructor: AzNodeDataInlineCssPropertyVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<CssKeyframe>`
+#[repr(C)]
+#[pyclass(name = "CssKeyframeVec")]
+pub struct AzCssKeyframeVec {
+    pub(crate) ptr: *const AzCssKeyframe,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzCssKeyframeVecDestructorEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `OptionWindowState` struct
 #[repr(C, u8)]
 pub enum AzOptionWindowState {
@@ -7938,18 +9547,6 @@ pub struct AzWindowCreateOptions {
     pub hot_reload: bool,
 }
 
-/// Defines the keyboard input focus target
-#[repr(C, u8)]
-pub enum AzFocusTarget {
-    Id(AzDomNodeId),
-    Path(AzFocusTargetPath),
-    Previous,
-    Next,
-    First,
-    Last,
-    NoFocus,
-}
-
 /// Represents one single DOM node (node type, classes, ids and callbacks are stored here)
 #[repr(C)]
 #[pyclass(name = "NodeData")]
@@ -7972,6 +9569,17 @@ pub struct AzNodeData {
 pub enum AzCssDeclaration {
     Static(AzCssProperty),
     Dynamic(AzDynamicCssProperty),
+    Custom(AzCssCustomProperty),
+}
+
+/// Animation declared via a `@keyframes` rule
+#[repr(C)]
+#[pyclass(name = "CssKeyframes")]
+pub struct AzCssKeyframes {
+    #[pyo3(get, set)]
+    pub name: AzString,
+    #[pyo3(get, set)]
+    pub keyframes: AzCssKeyframeVec,
 }
 
 /// Re-export of rust-allocated (stack based) `Button` struct
@@ -8048,6 +9656,66 @@ pub struct AzNumberInput {
     pub state: AzNumberInputStateWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `TextEdit` struct
+#[repr(C)]
+#[pyclass(name = "TextEdit")]
+pub struct AzTextEdit {
+    #[pyo3(get, set)]
+    pub state: AzTextEditStateWrapper,
+    #[pyo3(get, set)]
+    pub container_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub line_numbers_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub label_style: AzNodeDataInlineCssPropertyVec,
+}
+
+/// Re-export of rust-allocated (stack based) `Slider` struct
+#[repr(C)]
+#[pyclass(name = "Slider")]
+pub struct AzSlider {
+    #[pyo3(get, set)]
+    pub state: AzSliderStateWrapper,
+    #[pyo3(get, set)]
+    pub container_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub track_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub thumb_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub tick_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub label_style: AzNodeDataInlineCssPropertyVec,
+}
+
+/// Re-export of rust-allocated (stack based) `DropDown` struct
+#[repr(C)]
+#[pyclass(name = "DropDown")]
+pub struct AzDropDown {
+    #[pyo3(get, set)]
+    pub state: AzDropDownStateWrapper,
+    #[pyo3(get, set)]
+    pub container_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub label_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub popup_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub entry_style: AzNodeDataInlineCssPropertyVec,
+    #[pyo3(get, set)]
+    pub disabled_entry_style: AzNodeDataInlineCssPropertyVec,
+}
+
+/// Re-export of rust-allocated (stack based) `Spreadsheet` struct
+#[repr(C)]
+#[pyclass(name = "Spreadsheet")]
+pub struct AzSpreadsheet {
+    #[pyo3(get, set)]
+    pub state: AzSpreadsheetStateWrapper,
+    #[pyo3(get, set)]
+    pub container_style: AzNodeDataInlineCssPropertyVec,
+}
+
 /// Wrapper over a Rust-allocated `CssDeclaration`
 #[repr(C)]
 #[pyclass(name = "CssDeclarationVec")]
@@ -8061,6 +9729,19 @@ pub struct AzCssDeclarationVec {
     pub destructor: AzCssDeclarationVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<CssKeyframes>`
+#[repr(C)]
+#[pyclass(name = "CssKeyframesVec")]
+pub struct AzCssKeyframesVec {
+    pub(crate) ptr: *const AzCssKeyframes,
+    #[pyo3(get, set)]
+    pub len: usize,
+    #[pyo3(get, set)]
+    pub cap: usize,
+    #[pyo3(get, set)]
+    pub destructor: AzCssKeyframesVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `NodeDataVec`
 #[repr(C)]
 #[pyclass(name = "NodeDataVec")]
@@ -8116,6 +9797,8 @@ pub struct AzCssRuleBlock {
     pub path: AzCssPath,
     #[pyo3(get, set)]
     pub declarations: AzCssDeclarationVec,
+    #[pyo3(get, set)]
+    pub media_queries: AzCssMediaQueryVec,
 }
 
 /// Re-export of rust-allocated (stack based) `StyledDom` struct
@@ -8242,6 +9925,10 @@ pub enum AzResultSvgSvgParseError {
 pub struct AzCss {
     #[pyo3(get, set)]
     pub stylesheets: AzStylesheetVec,
+    #[pyo3(get, set)]
+    pub font_faces: AzCssFontFaceVec,
+    #[pyo3(get, set)]
+    pub keyframes: AzCssKeyframesVec,
 }
 
 /// `AzAppLogLevelEnumWrapper` struct
@@ -8433,6 +10120,20 @@ pub struct AzMenuItemStateEnumWrapper {
     pub inner: AzMenuItemState,
 }
 
+/// `AzCssMediaOrientationEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "CssMediaOrientation")]
+pub struct AzCssMediaOrientationEnumWrapper {
+    pub inner: AzCssMediaOrientation,
+}
+
+/// `AzCssMediaColorSchemeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "CssMediaColorScheme")]
+pub struct AzCssMediaColorSchemeEnumWrapper {
+    pub inner: AzCssMediaColorScheme,
+}
+
 /// `AzNodeTypeKeyEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "NodeTypeKey")]
@@ -8440,6 +10141,20 @@ pub struct AzNodeTypeKeyEnumWrapper {
     pub inner: AzNodeTypeKey,
 }
 
+/// `AzCssAttributeSelectorOpEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "CssAttributeSelectorOp")]
+pub struct AzCssAttributeSelectorOpEnumWrapper {
+    pub inner: AzCssAttributeSelectorOp,
+}
+
+/// `AzCssFontFaceStyleEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "CssFontFaceStyle")]
+pub struct AzCssFontFaceStyleEnumWrapper {
+    pub inner: AzCssFontFaceStyle,
+}
+
 /// `AzCssPropertyTypeEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "CssPropertyType")]
@@ -8468,6 +10183,13 @@ pub struct AzLayoutAlignContentEnumWrapper {
     pub inner: AzLayoutAlignContent,
 }
 
+/// `AzGridLineEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "GridLine")]
+pub struct AzGridLineEnumWrapper {
+    pub inner: AzGridLine,
+}
+
 /// `AzLayoutAlignItemsEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "LayoutAlignItems")]
@@ -8503,6 +10225,13 @@ pub struct AzLayoutFloatEnumWrapper {
     pub inner: AzLayoutFloat,
 }
 
+/// `AzLayoutClearEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "LayoutClear")]
+pub struct AzLayoutClearEnumWrapper {
+    pub inner: AzLayoutClear,
+}
+
 /// `AzLayoutJustifyContentEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "LayoutJustifyContent")]
@@ -8594,6 +10323,27 @@ pub struct AzStyleBackfaceVisibilityEnumWrapper {
     pub inner: AzStyleBackfaceVisibility,
 }
 
+/// `AzStyleAnimationIterationCountEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationIterationCount")]
+pub struct AzStyleAnimationIterationCountEnumWrapper {
+    pub inner: AzStyleAnimationIterationCount,
+}
+
+/// `AzStyleAnimationDirectionEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationDirection")]
+pub struct AzStyleAnimationDirectionEnumWrapper {
+    pub inner: AzStyleAnimationDirection,
+}
+
+/// `AzStyleAnimationFillModeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationFillMode")]
+pub struct AzStyleAnimationFillModeEnumWrapper {
+    pub inner: AzStyleAnimationFillMode,
+}
+
 /// `AzStyleTextAlignEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "StyleTextAlign")]
@@ -8608,6 +10358,20 @@ pub struct AzTextInputValidEnumWrapper {
     pub inner: AzTextInputValid,
 }
 
+/// `AzTextEditKindEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TextEditKind")]
+pub struct AzTextEditKindEnumWrapper {
+    pub inner: AzTextEditKind,
+}
+
+/// `AzSliderOrientationEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "SliderOrientation")]
+pub struct AzSliderOrientationEnumWrapper {
+    pub inner: AzSliderOrientation,
+}
+
 /// `AzVertexAttributeTypeEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "VertexAttributeType")]
@@ -8755,6 +10519,13 @@ pub struct AzAccessibilityStateVecDestructorEnumWrapper {
     pub inner: AzAccessibilityStateVecDestructor,
 }
 
+/// `AzAccessibilityNodeVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "AccessibilityNodeVecDestructor")]
+pub struct AzAccessibilityNodeVecDestructorEnumWrapper {
+    pub inner: AzAccessibilityNodeVecDestructor,
+}
+
 /// `AzMenuItemVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "MenuItemVecDestructor")]
@@ -8944,6 +10715,13 @@ pub struct AzVirtualKeyCodeVecDestructorEnumWrapper {
     pub inner: AzVirtualKeyCodeVecDestructor,
 }
 
+/// `AzKeyboardShortcutVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "KeyboardShortcutVecDestructor")]
+pub struct AzKeyboardShortcutVecDestructorEnumWrapper {
+    pub inner: AzKeyboardShortcutVecDestructor,
+}
+
 /// `AzCascadeInfoVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "CascadeInfoVecDestructor")]
@@ -8979,6 +10757,118 @@ pub struct AzStylesheetVecDestructorEnumWrapper {
     pub inner: AzStylesheetVecDestructor,
 }
 
+/// `AzCssFontFaceVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "CssFontFaceVecDestructor")]
+pub struct AzCssFontFaceVecDestructorEnumWrapper {
+    pub inner: AzCssFontFaceVecDestructor,
+}
+
+/// `AzStyleTransitionPropertyVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleTransitionPropertyVecDestructor")]
+pub struct AzStyleTransitionPropertyVecDestructorEnumWrapper {
+    pub inner: AzStyleTransitionPropertyVecDestructor,
+}
+
+/// `AzStyleTransitionDurationVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleTransitionDurationVecDestructor")]
+pub struct AzStyleTransitionDurationVecDestructorEnumWrapper {
+    pub inner: AzStyleTransitionDurationVecDestructor,
+}
+
+/// `AzStyleTransitionTimingFunctionVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleTransitionTimingFunctionVecDestructor")]
+pub struct AzStyleTransitionTimingFunctionVecDestructorEnumWrapper {
+    pub inner: AzStyleTransitionTimingFunctionVecDestructor,
+}
+
+/// `AzStyleTransitionDelayVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleTransitionDelayVecDestructor")]
+pub struct AzStyleTransitionDelayVecDestructorEnumWrapper {
+    pub inner: AzStyleTransitionDelayVecDestructor,
+}
+
+/// `AzStyleAnimationNameVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationNameVecDestructor")]
+pub struct AzStyleAnimationNameVecDestructorEnumWrapper {
+    pub inner: AzStyleAnimationNameVecDestructor,
+}
+
+/// `AzStyleAnimationDurationVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationDurationVecDestructor")]
+pub struct AzStyleAnimationDurationVecDestructorEnumWrapper {
+    pub inner: AzStyleAnimationDurationVecDestructor,
+}
+
+/// `AzStyleAnimationIterationCountVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationIterationCountVecDestructor")]
+pub struct AzStyleAnimationIterationCountVecDestructorEnumWrapper {
+    pub inner: AzStyleAnimationIterationCountVecDestructor,
+}
+
+/// `AzStyleAnimationDirectionVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationDirectionVecDestructor")]
+pub struct AzStyleAnimationDirectionVecDestructorEnumWrapper {
+    pub inner: AzStyleAnimationDirectionVecDestructor,
+}
+
+/// `AzStyleAnimationFillModeVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationFillModeVecDestructor")]
+pub struct AzStyleAnimationFillModeVecDestructorEnumWrapper {
+    pub inner: AzStyleAnimationFillModeVecDestructor,
+}
+
+/// `AzStyleAnimationDelayVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationDelayVecDestructor")]
+pub struct AzStyleAnimationDelayVecDestructorEnumWrapper {
+    pub inner: AzStyleAnimationDelayVecDestructor,
+}
+
+/// `AzCssKeyframeVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "CssKeyframeVecDestructor")]
+pub struct AzCssKeyframeVecDestructorEnumWrapper {
+    pub inner: AzCssKeyframeVecDestructor,
+}
+
+/// `AzCssKeyframesVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "CssKeyframesVecDestructor")]
+pub struct AzCssKeyframesVecDestructorEnumWrapper {
+    pub inner: AzCssKeyframesVecDestructor,
+}
+
+/// `AzGridTrackSizingVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "GridTrackSizingVecDestructor")]
+pub struct AzGridTrackSizingVecDestructorEnumWrapper {
+    pub inner: AzGridTrackSizingVecDestructor,
+}
+
+/// `AzCssMediaQueryVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "CssMediaQueryVecDestructor")]
+pub struct AzCssMediaQueryVecDestructorEnumWrapper {
+    pub inner: AzCssMediaQueryVecDestructor,
+}
+
+/// `AzCssMediaConditionVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "CssMediaConditionVecDestructor")]
+pub struct AzCssMediaConditionVecDestructorEnumWrapper {
+    pub inner: AzCssMediaConditionVecDestructor,
+}
+
 /// `AzCssRuleBlockVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "CssRuleBlockVecDestructor")]
@@ -9105,6 +10995,27 @@ pub struct AzNodeDataVecDestructorEnumWrapper {
     pub inner: AzNodeDataVecDestructor,
 }
 
+/// `AzTextEditSnapshotVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "TextEditSnapshotVecDestructor")]
+pub struct AzTextEditSnapshotVecDestructorEnumWrapper {
+    pub inner: AzTextEditSnapshotVecDestructor,
+}
+
+/// `AzSpreadsheetSizeOverrideVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "SpreadsheetSizeOverrideVecDestructor")]
+pub struct AzSpreadsheetSizeOverrideVecDestructorEnumWrapper {
+    pub inner: AzSpreadsheetSizeOverrideVecDestructor,
+}
+
+/// `AzSpreadsheetCellContentVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "SpreadsheetCellContentVecDestructor")]
+pub struct AzSpreadsheetCellContentVecDestructorEnumWrapper {
+    pub inner: AzSpreadsheetCellContentVecDestructor,
+}
+
 /// `AzOptionI16EnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "OptionI16")]
@@ -9231,6 +11142,20 @@ pub struct AzCssNthChildSelectorEnumWrapper {
     pub inner: AzCssNthChildSelector,
 }
 
+/// `AzGridTrackBreadthEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "GridTrackBreadth")]
+pub struct AzGridTrackBreadthEnumWrapper {
+    pub inner: AzGridTrackBreadth,
+}
+
+/// `AzGridTrackSizingEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "GridTrackSizing")]
+pub struct AzGridTrackSizingEnumWrapper {
+    pub inner: AzGridTrackSizing,
+}
+
 /// `AzDirectionEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "Direction")]
@@ -9259,6 +11184,13 @@ pub struct AzStyleBackgroundSizeEnumWrapper {
     pub inner: AzStyleBackgroundSize,
 }
 
+/// `AzStyleTransitionPropertyEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleTransitionProperty")]
+pub struct AzStyleTransitionPropertyEnumWrapper {
+    pub inner: AzStyleTransitionProperty,
+}
+
 /// `AzStyleBoxShadowValueEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "StyleBoxShadowValue")]
@@ -9273,6 +11205,34 @@ pub struct AzLayoutAlignContentValueEnumWrapper {
     pub inner: AzLayoutAlignContentValue,
 }
 
+/// `AzLayoutGridColumnValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "LayoutGridColumnValue")]
+pub struct AzLayoutGridColumnValueEnumWrapper {
+    pub inner: AzLayoutGridColumnValue,
+}
+
+/// `AzLayoutGridRowValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "LayoutGridRowValue")]
+pub struct AzLayoutGridRowValueEnumWrapper {
+    pub inner: AzLayoutGridRowValue,
+}
+
+/// `AzLayoutColumnGapValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "LayoutColumnGapValue")]
+pub struct AzLayoutColumnGapValueEnumWrapper {
+    pub inner: AzLayoutColumnGapValue,
+}
+
+/// `AzLayoutRowGapValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "LayoutRowGapValue")]
+pub struct AzLayoutRowGapValueEnumWrapper {
+    pub inner: AzLayoutRowGapValue,
+}
+
 /// `AzLayoutAlignItemsValueEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "LayoutAlignItemsValue")]
@@ -9329,6 +11289,13 @@ pub struct AzLayoutFloatValueEnumWrapper {
     pub inner: AzLayoutFloatValue,
 }
 
+/// `AzLayoutClearValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "LayoutClearValue")]
+pub struct AzLayoutClearValueEnumWrapper {
+    pub inner: AzLayoutClearValue,
+}
+
 /// `AzLayoutHeightValueEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "LayoutHeightValue")]
@@ -9679,6 +11646,13 @@ pub struct AzTextInputSelectionEnumWrapper {
     pub inner: AzTextInputSelection,
 }
 
+/// `AzSpreadsheetDragEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "SpreadsheetDrag")]
+pub struct AzSpreadsheetDragEnumWrapper {
+    pub inner: AzSpreadsheetDrag,
+}
+
 /// `AzDurationEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "Duration")]
@@ -9749,6 +11723,69 @@ pub struct AzOptionNumberInputOnValueChangeEnumWrapper {
     pub inner: AzOptionNumberInputOnValueChange,
 }
 
+/// `AzOptionTextEditOnTextChangeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionTextEditOnTextChange")]
+pub struct AzOptionTextEditOnTextChangeEnumWrapper {
+    pub inner: AzOptionTextEditOnTextChange,
+}
+
+/// `AzOptionTextEditOnFocusLostEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionTextEditOnFocusLost")]
+pub struct AzOptionTextEditOnFocusLostEnumWrapper {
+    pub inner: AzOptionTextEditOnFocusLost,
+}
+
+/// `AzOptionTextEditKindEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionTextEditKind")]
+pub struct AzOptionTextEditKindEnumWrapper {
+    pub inner: AzOptionTextEditKind,
+}
+
+/// `AzOptionSliderOnValueChangeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionSliderOnValueChange")]
+pub struct AzOptionSliderOnValueChangeEnumWrapper {
+    pub inner: AzOptionSliderOnValueChange,
+}
+
+/// `AzOptionDropDownOnChoiceChangeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionDropDownOnChoiceChange")]
+pub struct AzOptionDropDownOnChoiceChangeEnumWrapper {
+    pub inner: AzOptionDropDownOnChoiceChange,
+}
+
+/// `AzOptionSpreadsheetOnCellsChangeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionSpreadsheetOnCellsChange")]
+pub struct AzOptionSpreadsheetOnCellsChangeEnumWrapper {
+    pub inner: AzOptionSpreadsheetOnCellsChange,
+}
+
+/// `AzOptionSpreadsheetOnSelectionChangeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionSpreadsheetOnSelectionChange")]
+pub struct AzOptionSpreadsheetOnSelectionChangeEnumWrapper {
+    pub inner: AzOptionSpreadsheetOnSelectionChange,
+}
+
+/// `AzOptionSpreadsheetOnColumnResizeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionSpreadsheetOnColumnResize")]
+pub struct AzOptionSpreadsheetOnColumnResizeEnumWrapper {
+    pub inner: AzOptionSpreadsheetOnColumnResize,
+}
+
+/// `AzOptionSpreadsheetCellSelectionEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionSpreadsheetCellSelection")]
+pub struct AzOptionSpreadsheetCellSelectionEnumWrapper {
+    pub inner: AzOptionSpreadsheetCellSelection,
+}
+
 /// `AzOptionMenuItemIconEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "OptionMenuItemIcon")]
@@ -10015,6 +12052,13 @@ pub struct AzEventFilterEnumWrapper {
     pub inner: AzEventFilter,
 }
 
+/// `AzCssMediaConditionEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "CssMediaCondition")]
+pub struct AzCssMediaConditionEnumWrapper {
+    pub inner: AzCssMediaCondition,
+}
+
 /// `AzCssPathPseudoSelectorEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "CssPathPseudoSelector")]
@@ -10029,6 +12073,13 @@ pub struct AzAnimationInterpolationFunctionEnumWrapper {
     pub inner: AzAnimationInterpolationFunction,
 }
 
+/// `AzStyleTransitionTimingFunctionEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleTransitionTimingFunction")]
+pub struct AzStyleTransitionTimingFunctionEnumWrapper {
+    pub inner: AzStyleTransitionTimingFunction,
+}
+
 /// `AzStyleTransformEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "StyleTransform")]
@@ -10036,6 +12087,20 @@ pub struct AzStyleTransformEnumWrapper {
     pub inner: AzStyleTransform,
 }
 
+/// `AzLayoutGridTemplateColumnsValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "LayoutGridTemplateColumnsValue")]
+pub struct AzLayoutGridTemplateColumnsValueEnumWrapper {
+    pub inner: AzLayoutGridTemplateColumnsValue,
+}
+
+/// `AzLayoutGridTemplateRowsValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "LayoutGridTemplateRowsValue")]
+pub struct AzLayoutGridTemplateRowsValueEnumWrapper {
+    pub inner: AzLayoutGridTemplateRowsValue,
+}
+
 /// `AzStyleBackgroundPositionVecValueEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "StyleBackgroundPositionVecValue")]
@@ -10057,6 +12122,69 @@ pub struct AzStyleBackgroundSizeVecValueEnumWrapper {
     pub inner: AzStyleBackgroundSizeVecValue,
 }
 
+/// `AzStyleTransitionPropertyVecValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleTransitionPropertyVecValue")]
+pub struct AzStyleTransitionPropertyVecValueEnumWrapper {
+    pub inner: AzStyleTransitionPropertyVecValue,
+}
+
+/// `AzStyleTransitionDurationVecValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleTransitionDurationVecValue")]
+pub struct AzStyleTransitionDurationVecValueEnumWrapper {
+    pub inner: AzStyleTransitionDurationVecValue,
+}
+
+/// `AzStyleTransitionDelayVecValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleTransitionDelayVecValue")]
+pub struct AzStyleTransitionDelayVecValueEnumWrapper {
+    pub inner: AzStyleTransitionDelayVecValue,
+}
+
+/// `AzStyleAnimationDurationVecValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationDurationVecValue")]
+pub struct AzStyleAnimationDurationVecValueEnumWrapper {
+    pub inner: AzStyleAnimationDurationVecValue,
+}
+
+/// `AzStyleAnimationIterationCountVecValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationIterationCountVecValue")]
+pub struct AzStyleAnimationIterationCountVecValueEnumWrapper {
+    pub inner: AzStyleAnimationIterationCountVecValue,
+}
+
+/// `AzStyleAnimationDirectionVecValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationDirectionVecValue")]
+pub struct AzStyleAnimationDirectionVecValueEnumWrapper {
+    pub inner: AzStyleAnimationDirectionVecValue,
+}
+
+/// `AzStyleAnimationFillModeVecValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationFillModeVecValue")]
+pub struct AzStyleAnimationFillModeVecValueEnumWrapper {
+    pub inner: AzStyleAnimationFillModeVecValue,
+}
+
+/// `AzStyleAnimationDelayVecValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationDelayVecValue")]
+pub struct AzStyleAnimationDelayVecValueEnumWrapper {
+    pub inner: AzStyleAnimationDelayVecValue,
+}
+
+/// `AzCssPropertySourceEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "CssPropertySource")]
+pub struct AzCssPropertySourceEnumWrapper {
+    pub inner: AzCssPropertySource,
+}
+
 /// `AzRawImageDataEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "RawImageData")]
@@ -10183,11 +12311,11 @@ pub struct AzIdOrClassEnumWrapper {
     pub inner: AzIdOrClass,
 }
 
-/// `AzCssPathSelectorEnumWrapper` struct
+/// `AzShortcutConflictEnumWrapper` struct
 #[repr(transparent)]
-#[pyclass(name = "CssPathSelector")]
-pub struct AzCssPathSelectorEnumWrapper {
-    pub inner: AzCssPathSelector,
+#[pyclass(name = "ShortcutConflict")]
+pub struct AzShortcutConflictEnumWrapper {
+    pub inner: AzShortcutConflict,
 }
 
 /// `AzStyleBackgroundContentEnumWrapper` struct
@@ -10218,6 +12346,13 @@ pub struct AzStyleTransformVecValueEnumWrapper {
     pub inner: AzStyleTransformVecValue,
 }
 
+/// `AzStyleTransitionTimingFunctionVecValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleTransitionTimingFunctionVecValue")]
+pub struct AzStyleTransitionTimingFunctionVecValueEnumWrapper {
+    pub inner: AzStyleTransitionTimingFunctionVecValue,
+}
+
 /// `AzSvgStyleEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "SvgStyle")]
@@ -10232,6 +12367,27 @@ pub struct AzFmtValueEnumWrapper {
     pub inner: AzFmtValue,
 }
 
+/// `AzOptionSpreadsheetCellClickEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionSpreadsheetCellClick")]
+pub struct AzOptionSpreadsheetCellClickEnumWrapper {
+    pub inner: AzOptionSpreadsheetCellClick,
+}
+
+/// `AzOptionSpreadsheetCellEditEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionSpreadsheetCellEdit")]
+pub struct AzOptionSpreadsheetCellEditEnumWrapper {
+    pub inner: AzOptionSpreadsheetCellEdit,
+}
+
+/// `AzOptionAccessibilityNodeEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "OptionAccessibilityNode")]
+pub struct AzOptionAccessibilityNodeEnumWrapper {
+    pub inner: AzOptionAccessibilityNode,
+}
+
 /// `AzOptionFileTypeListEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "OptionFileTypeList")]
@@ -10274,6 +12430,13 @@ pub struct AzXmlStreamErrorEnumWrapper {
     pub inner: AzXmlStreamError,
 }
 
+/// `AzFocusTargetEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "FocusTarget")]
+pub struct AzFocusTargetEnumWrapper {
+    pub inner: AzFocusTarget,
+}
+
 /// `AzMenuItemEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "MenuItem")]
@@ -10281,6 +12444,13 @@ pub struct AzMenuItemEnumWrapper {
     pub inner: AzMenuItem,
 }
 
+/// `AzCssPathSelectorEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "CssPathSelector")]
+pub struct AzCssPathSelectorEnumWrapper {
+    pub inner: AzCssPathSelector,
+}
+
 /// `AzStyleBackgroundContentVecValueEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "StyleBackgroundContentVecValue")]
@@ -10295,6 +12465,13 @@ pub struct AzStyleFontFamilyVecValueEnumWrapper {
     pub inner: AzStyleFontFamilyVecValue,
 }
 
+/// `AzStyleAnimationNameVecValueEnumWrapper` struct
+#[repr(transparent)]
+#[pyclass(name = "StyleAnimationNameVecValue")]
+pub struct AzStyleAnimationNameVecValueEnumWrapper {
+    pub inner: AzStyleAnimationNameVecValue,
+}
+
 /// `AzCssPropertyEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "CssProperty")]
@@ -10302,13 +12479,6 @@ pub struct AzCssPropertyEnumWrapper {
     pub inner: AzCssProperty,
 }
 
-/// `AzCssPropertySourceEnumWrapper` struct
-#[repr(transparent)]
-#[pyclass(name = "CssPropertySource")]
-pub struct AzCssPropertySourceEnumWrapper {
-    pub inner: AzCssPropertySource,
-}
-
 /// `AzOptionCssPropertyEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "OptionCssProperty")]
@@ -10351,13 +12521,6 @@ pub struct AzXmlParseErrorEnumWrapper {
     pub inner: AzXmlParseError,
 }
 
-/// `AzFocusTargetEnumWrapper` struct
-#[repr(transparent)]
-#[pyclass(name = "FocusTarget")]
-pub struct AzFocusTargetEnumWrapper {
-    pub inner: AzFocusTarget,
-}
-
 /// `AzCssDeclarationEnumWrapper` struct
 #[repr(transparent)]
 #[pyclass(name = "CssDeclaration")]
@@ -10452,6 +12615,7 @@ unsafe impl Send for AzRefstrVecRef { }
 unsafe impl Send for AzFontMetrics { }
 unsafe impl Send for AzInstantPtr { }
 unsafe impl Send for AzAccessibilityStateVec { }
+unsafe impl Send for AzAccessibilityNodeVec { }
 unsafe impl Send for AzMenuItemVec { }
 unsafe impl Send for AzXmlNodeVec { }
 unsafe impl Send for AzInlineGlyphVec { }
@@ -10467,6 +12631,16 @@ unsafe impl Send for AzXWindowTypeVec { }
 unsafe impl Send for AzVirtualKeyCodeVec { }
 unsafe impl Send for AzCascadeInfoVec { }
 unsafe impl Send for AzScanCodeVec { }
+unsafe impl Send for AzCssPathSelectorVec { }
+unsafe impl Send for AzStyleTransitionPropertyVec { }
+unsafe impl Send for AzStyleTransitionDurationVec { }
+unsafe impl Send for AzStyleTransitionDelayVec { }
+unsafe impl Send for AzStyleAnimationDurationVec { }
+unsafe impl Send for AzStyleAnimationIterationCountVec { }
+unsafe impl Send for AzStyleAnimationDirectionVec { }
+unsafe impl Send for AzStyleAnimationFillModeVec { }
+unsafe impl Send for AzStyleAnimationDelayVec { }
+unsafe impl Send for AzGridTrackSizingVec { }
 unsafe impl Send for AzU16Vec { }
 unsafe impl Send for AzF32Vec { }
 unsafe impl Send for AzU8Vec { }
@@ -10477,15 +12651,20 @@ unsafe impl Send for AzNormalizedRadialColorStopVec { }
 unsafe impl Send for AzNodeIdVec { }
 unsafe impl Send for AzNodeVec { }
 unsafe impl Send for AzParentWithNodeDepthVec { }
+unsafe impl Send for AzSpreadsheetSizeOverrideVec { }
 unsafe impl Send for AzRenderImageCallbackInfo { }
 unsafe impl Send for AzLayoutCallbackInfo { }
 unsafe impl Send for AzTessellatedSvgNodeVecRef { }
 unsafe impl Send for AzTessellatedSvgNodeVec { }
 unsafe impl Send for AzStyleTransformVec { }
 unsafe impl Send for AzSvgPathElementVec { }
+unsafe impl Send for AzKeyboardShortcutVec { }
+unsafe impl Send for AzCssMediaConditionVec { }
+unsafe impl Send for AzStyleTransitionTimingFunctionVec { }
 unsafe impl Send for AzStringVec { }
 unsafe impl Send for AzStyledNodeVec { }
 unsafe impl Send for AzTagIdToNodeIdMappingVec { }
+unsafe impl Send for AzTextEditSnapshotVec { }
 unsafe impl Send for AzWaylandTheme { }
 unsafe impl Send for AzFile { }
 unsafe impl Send for AzStyleFontFamilyVec { }
@@ -10496,18 +12675,23 @@ unsafe impl Send for AzIdOrClassVec { }
 unsafe impl Send for AzStyleBackgroundContentVec { }
 unsafe impl Send for AzSvgPathVec { }
 unsafe impl Send for AzVertexAttributeVec { }
-unsafe impl Send for AzCssPathSelectorVec { }
+unsafe impl Send for AzCssMediaQueryVec { }
+unsafe impl Send for AzCssFontFaceVec { }
+unsafe impl Send for AzStyleAnimationNameVec { }
 unsafe impl Send for AzCallbackDataVec { }
 unsafe impl Send for AzDebugMessageVec { }
 unsafe impl Send for AzStringPairVec { }
+unsafe impl Send for AzSpreadsheetCellContentVec { }
 unsafe impl Send for AzInlineLineVec { }
 unsafe impl Send for AzCssPropertyVec { }
 unsafe impl Send for AzSvgMultiPolygonVec { }
 unsafe impl Send for AzCallbackInfo { }
 unsafe impl Send for AzTimerCallbackInfo { }
 unsafe impl Send for AzNodeDataInlineCssPropertyVec { }
+unsafe impl Send for AzCssKeyframeVec { }
 unsafe impl Send for AzNodeData { }
 unsafe impl Send for AzCssDeclarationVec { }
+unsafe impl Send for AzCssKeyframesVec { }
 unsafe impl Send for AzNodeDataVec { }
 unsafe impl Send for AzCssRuleBlockVec { }
 unsafe impl Send for AzStylesheetVec { }
@@ -10573,19 +12757,25 @@ impl Clone for AzAccessibilityStateEnumWrapper { fn clone(&self) -> Self { let r
 impl Clone for AzTabIndexEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::TabIndex = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuPopupPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuPopupPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaOrientationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaOrientation = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaColorSchemeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaColorScheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeKeyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::NodeTypeTag = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssAttributeSelectorOpEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssAttributeSelectorOp = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssNthChildPattern { fn clone(&self) -> Self { let r: &azul_impl::css::CssNthChildPattern = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssFontFaceStyleEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssFontFaceStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPropertyType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorU { fn clone(&self) -> Self { let r: &azul_impl::css::ColorU = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSizeMetricEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::SizeMetric = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFloatValue { fn clone(&self) -> Self { let r: &azul_impl::css::FloatValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzBoxShadowClipModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::BoxShadowClipMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutAlignContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutAlignContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridLineEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::GridLine = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutAlignItemsEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutAlignItems = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBoxSizingEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBoxSizing = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexDirectionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexDirection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutDisplayEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutDisplay = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFloatEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFloat = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutClearEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutClear = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutJustifyContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutJustifyContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexWrapEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexWrap = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10599,6 +12789,13 @@ impl Clone for AzStyleBackgroundRepeatEnumWrapper { fn clone(&self) -> Self { le
 impl Clone for AzBorderStyleEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::BorderStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleCursorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackfaceVisibilityEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackfaceVisibility = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionDuration { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionDuration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionDelay { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionDelay = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDuration { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDuration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDelay { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDelay = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationIterationCountEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationIterationCount = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDirectionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDirection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationFillModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationFillMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextAlignEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextAlign = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBoxOnToggleCallback { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBoxOnToggleCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBoxState { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBoxState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10610,6 +12807,18 @@ impl Clone for AzTextInputOnFocusLostCallback { fn clone(&self) -> Self { let r:
 impl Clone for AzTextInputValidEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputValid = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputState { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputOnValueChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputOnValueChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditKindEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditKind = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditOnTextChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditOnTextChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditOnFocusLostCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditOnFocusLostCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderOrientationEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderOrientation = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderOnValueChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderOnValueChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDropDownOnChoiceChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownOnChoiceChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetCellIndex { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetCellIndex = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetSizeOverride { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetSizeOverride = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetColumnResize { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetColumnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetOnCellsChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetOnCellsChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetOnSelectionChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetOnSelectionChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetOnColumnResizeCallback { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetOnColumnResizeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNode { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::AzNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCascadeInfo { fn clone(&self) -> Self { let r: &azul_impl::style::CascadeInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyledNodeState { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::StyledNodeState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10686,6 +12895,7 @@ impl Clone for AzThreadReceiverDestructorFn { fn clone(&self) -> Self { let r: &
 impl Clone for AzThreadSenderDestructorFn { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadSenderDestructorCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityStateVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityStateVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAccessibilityNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::accessibility::AccessibilityNodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedSvgNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedSvgNodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlNodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10713,11 +12923,28 @@ impl Clone for AzSvgVertexVecDestructorEnumWrapper { fn clone(&self) -> Self { l
 impl Clone for AzU32VecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::U32VecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXWindowTypeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::XWindowTypeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVirtualKeyCodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzKeyboardShortcutVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardShortcutVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCascadeInfoVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::style::CascadeInfoVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScanCodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ScanCodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssDeclarationVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclarationVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathSelectorVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelectorVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStylesheetVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StylesheetVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssFontFaceVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssFontFaceVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionPropertyVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionPropertyVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionDurationVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionDurationVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionTimingFunctionVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionTimingFunctionVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionDelayVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionDelayVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationNameVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationNameVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDurationVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDurationVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationIterationCountVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationIterationCountVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDirectionVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDirectionVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationFillModeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationFillModeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDelayVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDelayVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssKeyframeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssKeyframeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssKeyframesVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssKeyframesVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridTrackSizingVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::GridTrackSizingVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaQueryVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaQueryVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaConditionVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaConditionVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssRuleBlockVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssRuleBlockVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzF32VecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::F32VecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzU16VecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::U16VecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10736,6 +12963,9 @@ impl Clone for AzStyledNodeVecDestructorEnumWrapper { fn clone(&self) -> Self {
 impl Clone for AzTagIdToNodeIdMappingVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::TagIdToNodeIdMappingVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzParentWithNodeDepthVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::ParentWithNodeDepthVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditSnapshotVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditSnapshotVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetSizeOverrideVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetSizeOverrideVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetCellContentVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetCellContentVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionI16EnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionI16 = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionU16EnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionU16 = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionU32EnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionU32 = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10773,6 +13003,13 @@ impl Clone for AzCssNthChildSelectorEnumWrapper { fn clone(&self) -> Self { let
 impl Clone for AzPixelValue { fn clone(&self) -> Self { let r: &azul_impl::css::PixelValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPixelValueNoPercent { fn clone(&self) -> Self { let r: &azul_impl::css::PixelValueNoPercent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBoxShadow { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBoxShadow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridTrackBreadthEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::GridTrackBreadth = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridMinMax { fn clone(&self) -> Self { let r: &azul_impl::css::GridMinMax = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridTrackSizingEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::GridTrackSizing = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridColumn { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridColumn = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridRow { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridRow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutColumnGap { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutColumnGap = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutRowGap { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutRowGap = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBottom { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBottom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexGrow { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexGrow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexShrink { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexShrink = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10826,6 +13063,8 @@ impl Clone for AzStyleTabWidth { fn clone(&self) -> Self { let r: &azul_impl::cs
 impl Clone for AzStyleOpacity { fn clone(&self) -> Self { let r: &azul_impl::css::StyleOpacity = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformOrigin { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformOrigin = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStylePerspectiveOrigin { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformOrigin = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCubicBezier { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCubicBezier = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformMatrix2D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformMatrix2D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformMatrix3D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformMatrix3D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformTranslate2D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformTranslate2D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10838,6 +13077,10 @@ impl Clone for AzStyleTextColor { fn clone(&self) -> Self { let r: &azul_impl::c
 impl Clone for AzStyleWordSpacing { fn clone(&self) -> Self { let r: &azul_impl::css::StyleWordSpacing = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBoxShadowValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBoxShadowValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutAlignContentValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutAlignContentValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridColumnValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridColumnValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridRowValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridRowValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutColumnGapValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutColumnGapValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutRowGapValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutRowGapValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutAlignItemsValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutAlignItemsValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBottomValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBottomValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBoxSizingValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBoxSizingValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10846,6 +13089,7 @@ impl Clone for AzLayoutDisplayValueEnumWrapper { fn clone(&self) -> Self { let r
 impl Clone for AzLayoutFlexGrowValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexGrowValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexShrinkValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexShrinkValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFloatValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFloatValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutClearValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutClearValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutHeightValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutHeightValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutJustifyContentValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutJustifyContentValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutLeftValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutLeftValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10905,6 +13149,19 @@ impl Clone for AzTextInputOnVirtualKeyDown { fn clone(&self) -> Self { let r: &c
 impl Clone for AzTextInputOnFocusLost { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnFocusLost = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOnTextInputReturn { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OnTextInputReturn = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputOnValueChange { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditView { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditView = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditOnTextChange { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditOnTextChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditOnFocusLost { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditOnFocusLost = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderState { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderOnValueChange { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDropDownOnChoiceChange { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownOnChoiceChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetStyle { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetCellSelection { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetCellSelection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetRenderWindow { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetRenderWindow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetDragEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetDrag = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetOnCellsChange { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetOnCellsChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetOnSelectionChange { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetOnSelectionChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetOnColumnResize { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetOnColumnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzParentWithNodeDepth { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::ParentWithNodeDepth = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzGl { fn clone(&self) -> Self { let r: &azul_impl::gl::GlContextPtr = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRefstrVecRef { fn clone(&self) -> Self { let r: &azul_impl::gl::RefstrVecRef = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10920,6 +13177,7 @@ impl Clone for AzDurationEnumWrapper { fn clone(&self) -> Self { let r: &azul_im
 impl Clone for AzThreadSendMsgEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadSendMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadWriteBackMsg { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadWriteBackMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityStateVec { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityStateVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAccessibilityNodeVec { fn clone(&self) -> Self { let r: &azul_core::accessibility::AccessibilityNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemVec { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlNodeVec { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineGlyphVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineGlyphVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10935,6 +13193,16 @@ impl Clone for AzXWindowTypeVec { fn clone(&self) -> Self { let r: &azul_core::w
 impl Clone for AzVirtualKeyCodeVec { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCascadeInfoVec { fn clone(&self) -> Self { let r: &azul_impl::style::CascadeInfoVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScanCodeVec { fn clone(&self) -> Self { let r: &azul_core::window::ScanCodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssPathSelectorVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelectorVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionPropertyVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionDurationVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionDurationVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionDelayVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionDelayVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDurationVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDurationVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationIterationCountVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationIterationCountVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDirectionVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDirectionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationFillModeVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationFillModeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDelayVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDelayVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridTrackSizingVec { fn clone(&self) -> Self { let r: &azul_impl::css::GridTrackSizingVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzU16Vec { fn clone(&self) -> Self { let r: &azul_impl::css::U16Vec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzF32Vec { fn clone(&self) -> Self { let r: &azul_impl::css::F32Vec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzU8Vec { fn clone(&self) -> Self { let r: &azul_impl::css::U8Vec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10945,6 +13213,7 @@ impl Clone for AzNormalizedRadialColorStopVec { fn clone(&self) -> Self { let r:
 impl Clone for AzNodeIdVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::NodeIdVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::AzNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzParentWithNodeDepthVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::ParentWithNodeDepthVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetSizeOverrideVec { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetSizeOverrideVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionColorInputOnValueChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::color_input::OptionColorInputOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionButtonOnClickEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::button::OptionButtonOnClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionCheckBoxOnToggleEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::check_box::OptionCheckBoxOnToggle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10953,6 +13222,15 @@ impl Clone for AzOptionTextInputOnVirtualKeyDownEnumWrapper { fn clone(&self) ->
 impl Clone for AzOptionTextInputOnFocusLostEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputOnFocusLost = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTextInputSelectionEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputSelection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionNumberInputOnValueChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::number_input::OptionNumberInputOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTextEditOnTextChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::OptionTextEditOnTextChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTextEditOnFocusLostEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::OptionTextEditOnFocusLost = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTextEditKindEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::OptionTextEditKind = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionSliderOnValueChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::slider::OptionSliderOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionDropDownOnChoiceChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::OptionDropDownOnChoiceChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionSpreadsheetOnCellsChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionSpreadsheetOnCellsChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionSpreadsheetOnSelectionChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionSpreadsheetOnSelectionChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionSpreadsheetOnColumnResizeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionSpreadsheetOnColumnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionSpreadsheetCellSelectionEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionSpreadsheetCellSelection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMenuItemIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMenuItemIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMenuCallbackEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMenuCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionPositionInfoEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::OptionPositionInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11009,18 +13287,38 @@ impl Clone for AzLayoutCallbackInfo { fn clone(&self) -> Self { let r: &azul_imp
 impl Clone for AzEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::EventFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenu { fn clone(&self) -> Self { let r: &azul_core::window::Menu = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVirtualKeyCodeCombo { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCodeCombo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzKeyboardShortcut { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardShortcut = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaConditionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaCondition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssPath { fn clone(&self) -> Self { let r: &azul_impl::css::CssPath = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathPseudoSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathPseudoSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimationInterpolationFunctionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::AnimationInterpolationFunction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInterpolateContext { fn clone(&self) -> Self { let r: &azul_impl::css::InterpolateResolver = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridTemplateColumns { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridTemplateColumns = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridTemplateRows { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridTemplateRows = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLinearGradient { fn clone(&self) -> Self { let r: &azul_impl::css::LinearGradient = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRadialGradient { fn clone(&self) -> Self { let r: &azul_impl::css::RadialGradient = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzConicGradient { fn clone(&self) -> Self { let r: &azul_impl::css::ConicGradient = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionTimingFunctionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionTimingFunction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransform = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridTemplateColumnsValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridTemplateColumnsValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridTemplateRowsValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridTemplateRowsValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundPositionVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundPositionVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundRepeatVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundRepeatVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundSizeVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundSizeVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionPropertyVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionPropertyVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionDurationVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionDurationVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionDelayVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionDelayVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDurationVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDurationVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationIterationCountVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationIterationCountVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDirectionVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDirectionVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationFillModeVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationFillModeVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDelayVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDelayVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBoxStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBoxStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditState { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditSnapshot { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditSnapshot = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssPropertySourceEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::CssPropertySource = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyledNode { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::StyledNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTagIdToNodeIdMapping { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::TagIdToNodeIdMapping = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTexture { fn clone(&self) -> Self { let r: &azul_impl::gl::Texture = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11039,9 +13337,13 @@ impl Clone for AzString { fn clone(&self) -> Self { let r: &azul_impl::css::AzSt
 impl Clone for AzTessellatedSvgNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedSvgNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgPathElementVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgPathElementVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzKeyboardShortcutVec { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardShortcutVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaConditionVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaConditionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionTimingFunctionVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionTimingFunctionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringVec { fn clone(&self) -> Self { let r: &azul_impl::css::StringVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyledNodeVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::StyledNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTagIdToNodeIdMappingVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::TagIdToNodeIdMappingVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditSnapshotVec { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditSnapshotVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionVirtualKeyCodeComboEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionVirtualKeyCodeCombo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMouseStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMouseState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionKeyboardStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionKeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11064,20 +13366,34 @@ impl Clone for AzStringPair { fn clone(&self) -> Self { let r: &azul_core::windo
 impl Clone for AzMonitor { fn clone(&self) -> Self { let r: &azul_core::window::Monitor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutCallbackEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::LayoutCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineWordEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineWord = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzFocusTargetPath { fn clone(&self) -> Self { let r: &azul_impl::callbacks::FocusTargetPath = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCallbackData { fn clone(&self) -> Self { let r: &azul_impl::dom::CallbackData = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityInfo { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAccessibilityNode { fn clone(&self) -> Self { let r: &azul_core::accessibility::AccessibilityNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAccessibilityTree { fn clone(&self) -> Self { let r: &azul_core::accessibility::AccessibilityTree = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIdOrClassEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::IdOrClass = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringMenuItem { fn clone(&self) -> Self { let r: &azul_core::window::StringMenuItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzCssPathSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcutConflictEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ShortcutConflict = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaQuery { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaQuery = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssCustomProperty { fn clone(&self) -> Self { let r: &azul_impl::css::CssCustomProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssAttributeSelector { fn clone(&self) -> Self { let r: &azul_impl::css::CssAttributeSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssFontFace { fn clone(&self) -> Self { let r: &azul_impl::css::CssFontFace = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarInfo { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarStyle { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamily = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationName { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationName = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarStyleValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarStyleValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionTimingFunctionVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionTimingFunctionVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::color_input::ColorInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputState { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditHistory { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditHistory = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDropDownState { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetCellContent { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetCellContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetCellClick { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetCellClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetCellEdit { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetCellEdit = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexAttribute { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexAttribute = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDebugMessage { fn clone(&self) -> Self { let r: &azul_impl::gl::AzDebugMessage = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzGetActiveAttribReturn { fn clone(&self) -> Self { let r: &azul_impl::gl::GetActiveAttribReturn = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11099,10 +13415,16 @@ impl Clone for AzIdOrClassVec { fn clone(&self) -> Self { let r: &azul_impl::dom
 impl Clone for AzStyleBackgroundContentVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContentVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgPathVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgPathVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexAttributeVec { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexAttributeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzCssPathSelectorVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelectorVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaQueryVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaQueryVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssFontFaceVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssFontFaceVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationNameVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationNameVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCallbackDataVec { fn clone(&self) -> Self { let r: &azul_impl::dom::CallbackDataVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDebugMessageVec { fn clone(&self) -> Self { let r: &azul_impl::gl::AzDebugMessageVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringPairVec { fn clone(&self) -> Self { let r: &azul_core::window::StringPairVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetCellContentVec { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetCellContentVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionSpreadsheetCellClickEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionSpreadsheetCellClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionSpreadsheetCellEditEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::OptionSpreadsheetCellEdit = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionAccessibilityNodeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::accessibility::OptionAccessibilityNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionFileTypeListEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dialogs::OptionFileTypeList = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionFileEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::file::OptionFile = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionRawImageEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::OptionRawImage = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11111,13 +13433,18 @@ impl Clone for AzResultRawImageDecodeImageErrorEnumWrapper { fn clone(&self) ->
 impl Clone for AzXmlStreamErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlStreamError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLinuxWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::LinuxWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineLine { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineLine = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzFocusTargetEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::FocusTarget = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzCssPath { fn clone(&self) -> Self { let r: &azul_impl::css::CssPath = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssPathSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundContentVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContentVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationNameVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationNameVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzCssPropertySourceEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::CssPropertySource = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEditStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEditStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDropDownStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetState { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetView { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetView = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexLayout { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexLayout = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexArrayObject { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexArrayObject = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexBuffer { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexBuffer = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11132,28 +13459,35 @@ impl Clone for AzPlatformSpecificOptions { fn clone(&self) -> Self { let r: &azu
 impl Clone for AzWindowState { fn clone(&self) -> Self { let r: &azul_core::window::WindowState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::CallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineText { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineText = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzFocusTargetPath { fn clone(&self) -> Self { let r: &azul_impl::callbacks::FocusTargetPath = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimation { fn clone(&self) -> Self { let r: &azul_impl::callbacks::Animation = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTimerCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TimerCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataInlineCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataInlineCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDynamicCssProperty { fn clone(&self) -> Self { let r: &azul_impl::css::DynamicCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssKeyframe { fn clone(&self) -> Self { let r: &azul_impl::css::CssKeyframe = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheetStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::SpreadsheetStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgNodeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgStyledNode { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgStyledNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataInlineCssPropertyVec { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataInlineCssPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssKeyframeVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssKeyframeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionWindowStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWindowState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionInlineTextEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionInlineText = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlParseErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlParseError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowCreateOptions { fn clone(&self) -> Self { let r: &azul_core::window::WindowCreateOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzFocusTargetEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::FocusTarget = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeData { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeData = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssDeclarationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclaration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssKeyframes { fn clone(&self) -> Self { let r: &azul_impl::css::CssKeyframes = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzButton { fn clone(&self) -> Self { let r: &crate::widgets::button::Button = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBox { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBox = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLabel { fn clone(&self) -> Self { let r: &crate::widgets::label::Label = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorInput { fn clone(&self) -> Self { let r: &crate::widgets::color_input::ColorInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInput { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInput { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextEdit { fn clone(&self) -> Self { let r: &crate::widgets::text_edit::TextEdit = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSlider { fn clone(&self) -> Self { let r: &crate::widgets::slider::Slider = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzDropDown { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDown = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpreadsheet { fn clone(&self) -> Self { let r: &crate::widgets::spreadsheet::Spreadsheet = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssDeclarationVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclarationVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssKeyframesVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssKeyframesVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataVec { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDom { fn clone(&self) -> Self { let r: &azul_impl::dom::Dom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11188,6 +13522,7 @@ impl Drop for AzRefAny { fn drop(&mut self) { crate::AzRefAny_delete(unsafe { me
 impl Drop for AzGl { fn drop(&mut self) { crate::AzGl_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInstantPtr { fn drop(&mut self) { crate::AzInstantPtr_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzAccessibilityStateVec { fn drop(&mut self) { crate::AzAccessibilityStateVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzAccessibilityNodeVec { fn drop(&mut self) { crate::AzAccessibilityNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzMenuItemVec { fn drop(&mut self) { crate::AzMenuItemVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzXmlNodeVec { fn drop(&mut self) { crate::AzXmlNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInlineGlyphVec { fn drop(&mut self) { crate::AzInlineGlyphVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11203,6 +13538,16 @@ impl Drop for AzXWindowTypeVec { fn drop(&mut self) { crate::AzXWindowTypeVec_de
 impl Drop for AzVirtualKeyCodeVec { fn drop(&mut self) { crate::AzVirtualKeyCodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCascadeInfoVec { fn drop(&mut self) { crate::AzCascadeInfoVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzScanCodeVec { fn drop(&mut self) { crate::AzScanCodeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssPathSelectorVec { fn drop(&mut self) { crate::AzCssPathSelectorVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleTransitionPropertyVec { fn drop(&mut self) { crate::AzStyleTransitionPropertyVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleTransitionDurationVec { fn drop(&mut self) { crate::AzStyleTransitionDurationVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleTransitionDelayVec { fn drop(&mut self) { crate::AzStyleTransitionDelayVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleAnimationDurationVec { fn drop(&mut self) { crate::AzStyleAnimationDurationVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleAnimationIterationCountVec { fn drop(&mut self) { crate::AzStyleAnimationIterationCountVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleAnimationDirectionVec { fn drop(&mut self) { crate::AzStyleAnimationDirectionVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleAnimationFillModeVec { fn drop(&mut self) { crate::AzStyleAnimationFillModeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleAnimationDelayVec { fn drop(&mut self) { crate::AzStyleAnimationDelayVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzGridTrackSizingVec { fn drop(&mut self) { crate::AzGridTrackSizingVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzU16Vec { fn drop(&mut self) { crate::AzU16Vec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzF32Vec { fn drop(&mut self) { crate::AzF32Vec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzU8Vec { fn drop(&mut self) { crate::AzU8Vec_delete(unsafe { mem::transmute(self) }); } }
@@ -11213,13 +13558,18 @@ impl Drop for AzNormalizedRadialColorStopVec { fn drop(&mut self) { crate::AzNor
 impl Drop for AzNodeIdVec { fn drop(&mut self) { crate::AzNodeIdVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeVec { fn drop(&mut self) { crate::AzNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzParentWithNodeDepthVec { fn drop(&mut self) { crate::AzParentWithNodeDepthVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzSpreadsheetSizeOverrideVec { fn drop(&mut self) { crate::AzSpreadsheetSizeOverrideVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTexture { fn drop(&mut self) { crate::AzTexture_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTessellatedSvgNodeVec { fn drop(&mut self) { crate::AzTessellatedSvgNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleTransformVec { fn drop(&mut self) { crate::AzStyleTransformVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgPathElementVec { fn drop(&mut self) { crate::AzSvgPathElementVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzKeyboardShortcutVec { fn drop(&mut self) { crate::AzKeyboardShortcutVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssMediaConditionVec { fn drop(&mut self) { crate::AzCssMediaConditionVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleTransitionTimingFunctionVec { fn drop(&mut self) { crate::AzStyleTransitionTimingFunctionVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStringVec { fn drop(&mut self) { crate::AzStringVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyledNodeVec { fn drop(&mut self) { crate::AzStyledNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTagIdToNodeIdMappingVec { fn drop(&mut self) { crate::AzTagIdToNodeIdMappingVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTextEditSnapshotVec { fn drop(&mut self) { crate::AzTextEditSnapshotVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzFile { fn drop(&mut self) { crate::AzFile_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleFontFamilyVec { fn drop(&mut self) { crate::AzStyleFontFamilyVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzFmtArgVec { fn drop(&mut self) { crate::AzFmtArgVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11229,15 +13579,20 @@ impl Drop for AzIdOrClassVec { fn drop(&mut self) { crate::AzIdOrClassVec_delete
 impl Drop for AzStyleBackgroundContentVec { fn drop(&mut self) { crate::AzStyleBackgroundContentVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgPathVec { fn drop(&mut self) { crate::AzSvgPathVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzVertexAttributeVec { fn drop(&mut self) { crate::AzVertexAttributeVec_delete(unsafe { mem::transmute(self) }); } }
-impl Drop for AzCssPathSelectorVec { fn drop(&mut self) { crate::AzCssPathSelectorVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssMediaQueryVec { fn drop(&mut self) { crate::AzCssMediaQueryVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssFontFaceVec { fn drop(&mut self) { crate::AzCssFontFaceVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleAnimationNameVec { fn drop(&mut self) { crate::AzStyleAnimationNameVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCallbackDataVec { fn drop(&mut self) { crate::AzCallbackDataVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzDebugMessageVec { fn drop(&mut self) { crate::AzDebugMessageVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStringPairVec { fn drop(&mut self) { crate::AzStringPairVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzSpreadsheetCellContentVec { fn drop(&mut self) { crate::AzSpreadsheetCellContentVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInlineLineVec { fn drop(&mut self) { crate::AzInlineLineVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssPropertyVec { fn drop(&mut self) { crate::AzCssPropertyVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgMultiPolygonVec { fn drop(&mut self) { crate::AzSvgMultiPolygonVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeDataInlineCssPropertyVec { fn drop(&mut self) { crate::AzNodeDataInlineCssPropertyVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssKeyframeVec { fn drop(&mut self) { crate::AzCssKeyframeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssDeclarationVec { fn drop(&mut self) { crate::AzCssDeclarationVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssKeyframesVec { fn drop(&mut self) { crate::AzCssKeyframesVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeDataVec { fn drop(&mut self) { crate::AzNodeDataVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssRuleBlockVec { fn drop(&mut self) { crate::AzCssRuleBlockVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStylesheetVec { fn drop(&mut self) { crate::AzStylesheetVec_delete(unsafe { mem::transmute(self) }); } }
@@ -13539,6 +15894,16 @@ impl AzCallbackInfo {
             mem::transmute(new_property),
         )) }
     }
+    fn set_css_variable(&mut self, node_id: AzDomNodeId, name: String, value: String) -> () {
+        let name = pystring_to_azstring(&name);
+        let value = pystring_to_azstring(&value);
+        unsafe { mem::transmute(crate::AzCallbackInfo_setCssVariable(
+            mem::transmute(self),
+            mem::transmute(node_id),
+            mem::transmute(name),
+            mem::transmute(value),
+        )) }
+    }
     fn set_scroll_position(&mut self, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) -> () {
         unsafe { mem::transmute(crate::AzCallbackInfo_setScrollPosition(
             mem::transmute(self),
@@ -13977,10 +16342,11 @@ impl PyObjectProtocol for AzInlineWordEnumWrapper {
 #[pymethods]
 impl AzInlineTextContents {
     #[new]
-    fn __new__(glyphs: AzInlineGlyphVec, bounds: AzLogicalRect) -> Self {
+    fn __new__(glyphs: AzInlineGlyphVec, bounds: AzLogicalRect, is_rtl: bool) -> Self {
         Self {
             glyphs,
             bounds,
+            is_rtl,
         }
     }
 
@@ -13999,11 +16365,12 @@ impl PyObjectProtocol for AzInlineTextContents {
 #[pymethods]
 impl AzInlineGlyph {
     #[new]
-    fn __new__(bounds: AzLogicalRect, unicode_codepoint: AzOptionCharEnumWrapper, glyph_index: u32) -> Self {
+    fn __new__(bounds: AzLogicalRect, unicode_codepoint: AzOptionCharEnumWrapper, glyph_index: u32, fallback_font_index: usize) -> Self {
         Self {
             bounds,
             unicode_codepoint,
             glyph_index,
+            fallback_font_index,
         }
     }
 
@@ -14022,7 +16389,7 @@ impl PyObjectProtocol for AzInlineGlyph {
 #[pymethods]
 impl AzInlineTextHit {
     #[new]
-    fn __new__(unicode_codepoint: AzOptionCharEnumWrapper, hit_relative_to_inline_text: AzLogicalPosition, hit_relative_to_line: AzLogicalPosition, hit_relative_to_text_content: AzLogicalPosition, hit_relative_to_glyph: AzLogicalPosition, line_index_relative_to_text: usize, word_index_relative_to_text: usize, text_content_index_relative_to_text: usize, glyph_index_relative_to_text: usize, char_index_relative_to_text: usize, word_index_relative_to_line: usize, text_content_index_relative_to_line: usize, glyph_index_relative_to_line: usize, char_index_relative_to_line: usize, glyph_index_relative_to_word: usize, char_index_relative_to_word: usize) -> Self {
+    fn __new__(unicode_codepoint: AzOptionCharEnumWrapper, hit_relative_to_inline_text: AzLogicalPosition, hit_relative_to_line: AzLogicalPosition, hit_relative_to_text_content: AzLogicalPosition, hit_relative_to_glyph: AzLogicalPosition, line_index_relative_to_text: usize, word_index_relative_to_text: usize, text_content_index_relative_to_text: usize, glyph_index_relative_to_text: usize, char_index_relative_to_text: usize, word_index_relative_to_line: usize, text_content_index_relative_to_line: usize, glyph_index_relative_to_line: usize, char_index_relative_to_line: usize, glyph_index_relative_to_word: usize, char_index_relative_to_word: usize, is_rtl: bool, cursor_char_index_relative_to_text: usize) -> Self {
         Self {
             unicode_codepoint,
             hit_relative_to_inline_text,
@@ -14040,6 +16407,8 @@ impl AzInlineTextHit {
             char_index_relative_to_line,
             glyph_index_relative_to_word,
             char_index_relative_to_word,
+            is_rtl,
+            cursor_char_index_relative_to_text,
         }
     }
 
@@ -14801,6 +17170,24 @@ impl AzDom {
             mem::transmute(clip_mask),
         )) }
     }
+    fn set_attribute(&mut self, name: String, value: String) -> () {
+        let name = pystring_to_azstring(&name);
+        let value = pystring_to_azstring(&value);
+        unsafe { mem::transmute(crate::AzDom_setAttribute(
+            mem::transmute(self),
+            mem::transmute(name),
+            mem::transmute(value),
+        )) }
+    }
+    fn with_attribute(&mut self, name: String, value: String) -> AzDom {
+        let name = pystring_to_azstring(&name);
+        let value = pystring_to_azstring(&value);
+        unsafe { mem::transmute(crate::AzDom_withAttribute(
+            mem::transmute(self),
+            mem::transmute(name),
+            mem::transmute(value),
+        )) }
+    }
     fn set_tab_index(&mut self, tab_index: AzTabIndexEnumWrapper) -> () {
         unsafe { mem::transmute(crate::AzDom_setTabIndex(
             mem::transmute(self),
@@ -15101,6 +17488,15 @@ impl AzNodeData {
             mem::transmute(image_mask),
         )) }
     }
+    fn set_attribute(&mut self, name: String, value: String) -> () {
+        let name = pystring_to_azstring(&name);
+        let value = pystring_to_azstring(&value);
+        unsafe { mem::transmute(crate::AzNodeData_setAttribute(
+            mem::transmute(self),
+            mem::transmute(name),
+            mem::transmute(value),
+        )) }
+    }
     fn set_tab_index(&mut self, tab_index: AzTabIndexEnumWrapper) -> () {
         unsafe { mem::transmute(crate::AzNodeData_setTabIndex(
             mem::transmute(self),
@@ -15912,6 +18308,72 @@ impl PyObjectProtocol for AzAccessibilityStateEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzAccessibilityNode {
+    #[new]
+    fn __new__(node_id: AzDomNodeId, role: AzAccessibilityRoleEnumWrapper, name: AzOptionStringEnumWrapper, value: AzOptionStringEnumWrapper, states: AzAccessibilityStateVec, accelerator: AzOptionVirtualKeyCodeComboEnumWrapper, default_action: AzOptionStringEnumWrapper, bounds: AzLogicalRect, children: AzAccessibilityNodeVec) -> Self {
+        Self {
+            node_id,
+            role,
+            name,
+            value,
+            states,
+            accelerator,
+            default_action,
+            bounds,
+            children,
+        }
+    }
+
+    fn has_state(&self, state: AzAccessibilityStateEnumWrapper) -> bool {
+        unsafe { mem::transmute(crate::AzAccessibilityNode_hasState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzAccessibilityNode {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::accessibility::AccessibilityNode = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::accessibility::AccessibilityNode = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzAccessibilityTree {
+    #[new]
+    fn __new__(root: AzAccessibilityNode) -> Self {
+        Self {
+            root,
+        }
+    }
+
+    fn get_focused_node(&self) -> Option<AzAccessibilityNode> {
+        let m: AzOptionAccessibilityNode = unsafe { mem::transmute(crate::AzAccessibilityTree_getFocusedNode(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionAccessibilityNode::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionAccessibilityNode::None => None,
+        }
+
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzAccessibilityTree {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::accessibility::AccessibilityTree = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::accessibility::AccessibilityTree = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzTabIndexEnumWrapper {
     #[classattr]
@@ -16182,6 +18644,60 @@ impl PyObjectProtocol for AzMenuCallback {
     }
 }
 
+#[pymethods]
+impl AzKeyboardShortcut {
+    #[new]
+    fn __new__(keys: AzVirtualKeyCodeCombo, callback: AzMenuCallback) -> Self {
+        Self {
+            keys,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzKeyboardShortcut {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::KeyboardShortcut = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::KeyboardShortcut = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzShortcutConflictEnumWrapper {
+    #[staticmethod]
+    fn Shortcut(v: AzVirtualKeyCodeCombo) -> AzShortcutConflictEnumWrapper { AzShortcutConflictEnumWrapper { inner: AzShortcutConflict::Shortcut(v) } }
+    #[staticmethod]
+    fn MenuItem(v: AzString) -> AzShortcutConflictEnumWrapper { AzShortcutConflictEnumWrapper { inner: AzShortcutConflict::MenuItem(v) } }
+    #[staticmethod]
+    fn ContextMenuItem(v: AzString) -> AzShortcutConflictEnumWrapper { AzShortcutConflictEnumWrapper { inner: AzShortcutConflict::ContextMenuItem(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzShortcutConflict;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzShortcutConflict::Shortcut(v) => Ok(vec!["Shortcut".into_py(py), v.clone().into_py(py)]),
+            AzShortcutConflict::MenuItem(v) => Ok(vec!["MenuItem".into_py(py), v.clone().into_py(py)]),
+            AzShortcutConflict::ContextMenuItem(v) => Ok(vec!["ContextMenuItem".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcutConflictEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutConflict = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutConflict = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMenuItemIconEnumWrapper {
     #[staticmethod]
@@ -16244,10 +18760,11 @@ impl PyObjectProtocol for AzMenuItemStateEnumWrapper {
 #[pymethods]
 impl AzCssRuleBlock {
     #[new]
-    fn __new__(path: AzCssPath, declarations: AzCssDeclarationVec) -> Self {
+    fn __new__(path: AzCssPath, declarations: AzCssDeclarationVec, media_queries: AzCssMediaQueryVec) -> Self {
         Self {
             path,
             declarations,
+            media_queries,
         }
     }
 
@@ -16263,12 +18780,138 @@ impl PyObjectProtocol for AzCssRuleBlock {
     }
 }
 
+#[pymethods]
+impl AzCssMediaQuery {
+    #[new]
+    fn __new__(conditions: AzCssMediaConditionVec) -> Self {
+        Self {
+            conditions,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaQuery {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaQuery = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaQuery = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaConditionEnumWrapper {
+    #[staticmethod]
+    fn MinWidth(v: AzPixelValue) -> AzCssMediaConditionEnumWrapper { AzCssMediaConditionEnumWrapper { inner: AzCssMediaCondition::MinWidth(v) } }
+    #[staticmethod]
+    fn MaxWidth(v: AzPixelValue) -> AzCssMediaConditionEnumWrapper { AzCssMediaConditionEnumWrapper { inner: AzCssMediaCondition::MaxWidth(v) } }
+    #[staticmethod]
+    fn MinHeight(v: AzPixelValue) -> AzCssMediaConditionEnumWrapper { AzCssMediaConditionEnumWrapper { inner: AzCssMediaCondition::MinHeight(v) } }
+    #[staticmethod]
+    fn MaxHeight(v: AzPixelValue) -> AzCssMediaConditionEnumWrapper { AzCssMediaConditionEnumWrapper { inner: AzCssMediaCondition::MaxHeight(v) } }
+    #[staticmethod]
+    fn MinResolution(v: AzFloatValue) -> AzCssMediaConditionEnumWrapper { AzCssMediaConditionEnumWrapper { inner: AzCssMediaCondition::MinResolution(v) } }
+    #[staticmethod]
+    fn MaxResolution(v: AzFloatValue) -> AzCssMediaConditionEnumWrapper { AzCssMediaConditionEnumWrapper { inner: AzCssMediaCondition::MaxResolution(v) } }
+    #[staticmethod]
+    fn Orientation(v: AzCssMediaOrientationEnumWrapper) -> AzCssMediaConditionEnumWrapper { AzCssMediaConditionEnumWrapper { inner: AzCssMediaCondition::Orientation(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn PrefersColorScheme(v: AzCssMediaColorSchemeEnumWrapper) -> AzCssMediaConditionEnumWrapper { AzCssMediaConditionEnumWrapper { inner: AzCssMediaCondition::PrefersColorScheme(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssMediaCondition;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssMediaCondition::MinWidth(v) => Ok(vec!["MinWidth".into_py(py), v.clone().into_py(py)]),
+            AzCssMediaCondition::MaxWidth(v) => Ok(vec!["MaxWidth".into_py(py), v.clone().into_py(py)]),
+            AzCssMediaCondition::MinHeight(v) => Ok(vec!["MinHeight".into_py(py), v.clone().into_py(py)]),
+            AzCssMediaCondition::MaxHeight(v) => Ok(vec!["MaxHeight".into_py(py), v.clone().into_py(py)]),
+            AzCssMediaCondition::MinResolution(v) => Ok(vec!["MinResolution".into_py(py), v.clone().into_py(py)]),
+            AzCssMediaCondition::MaxResolution(v) => Ok(vec!["MaxResolution".into_py(py), v.clone().into_py(py)]),
+            AzCssMediaCondition::Orientation(v) => Ok(vec!["Orientation".into_py(py), { let m: &AzCssMediaOrientationEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssMediaCondition::PrefersColorScheme(v) => Ok(vec!["PrefersColorScheme".into_py(py), { let m: &AzCssMediaColorSchemeEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaConditionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaCondition = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaCondition = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaOrientationEnumWrapper {
+    #[classattr]
+    fn Portrait() -> AzCssMediaOrientationEnumWrapper { AzCssMediaOrientationEnumWrapper { inner: AzCssMediaOrientation::Portrait } }
+    #[classattr]
+    fn Landscape() -> AzCssMediaOrientationEnumWrapper { AzCssMediaOrientationEnumWrapper { inner: AzCssMediaOrientation::Landscape } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaOrientationEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzCssMediaOrientationEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzCssMediaColorSchemeEnumWrapper {
+    #[classattr]
+    fn Light() -> AzCssMediaColorSchemeEnumWrapper { AzCssMediaColorSchemeEnumWrapper { inner: AzCssMediaColorScheme::Light } }
+    #[classattr]
+    fn Dark() -> AzCssMediaColorSchemeEnumWrapper { AzCssMediaColorSchemeEnumWrapper { inner: AzCssMediaColorScheme::Dark } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaColorSchemeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaColorScheme = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaColorScheme = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzCssMediaColorSchemeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzCssDeclarationEnumWrapper {
     #[staticmethod]
     fn Static(v: AzCssPropertyEnumWrapper) -> AzCssDeclarationEnumWrapper { AzCssDeclarationEnumWrapper { inner: AzCssDeclaration::Static(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn Dynamic(v: AzDynamicCssProperty) -> AzCssDeclarationEnumWrapper { AzCssDeclarationEnumWrapper { inner: AzCssDeclaration::Dynamic(v) } }
+    #[staticmethod]
+    fn Custom(v: AzCssCustomProperty) -> AzCssDeclarationEnumWrapper { AzCssDeclarationEnumWrapper { inner: AzCssDeclaration::Custom(v) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssDeclaration;
@@ -16278,6 +18921,7 @@ impl AzCssDeclarationEnumWrapper {
         match &self.inner {
             AzCssDeclaration::Static(v) => Ok(vec!["Static".into_py(py), { let m: &AzCssPropertyEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssDeclaration::Dynamic(v) => Ok(vec!["Dynamic".into_py(py), v.clone().into_py(py)]),
+            AzCssDeclaration::Custom(v) => Ok(vec!["Custom".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -16314,6 +18958,28 @@ impl PyObjectProtocol for AzDynamicCssProperty {
     }
 }
 
+#[pymethods]
+impl AzCssCustomProperty {
+    #[new]
+    fn __new__(name: AzString, value: AzString) -> Self {
+        Self {
+            name,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssCustomProperty {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssCustomProperty = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssCustomProperty = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssPath {
     #[new]
@@ -16347,10 +19013,18 @@ impl AzCssPathSelectorEnumWrapper {
     fn Id(v: AzString) -> AzCssPathSelectorEnumWrapper { AzCssPathSelectorEnumWrapper { inner: AzCssPathSelector::Id(v) } }
     #[staticmethod]
     fn PseudoSelector(v: AzCssPathPseudoSelectorEnumWrapper) -> AzCssPathSelectorEnumWrapper { AzCssPathSelectorEnumWrapper { inner: AzCssPathSelector::PseudoSelector(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn Attribute(v: AzCssAttributeSelector) -> AzCssPathSelectorEnumWrapper { AzCssPathSelectorEnumWrapper { inner: AzCssPathSelector::Attribute(v) } }
+    #[staticmethod]
+    fn Not(v: AzCssPathSelectorVec) -> AzCssPathSelectorEnumWrapper { AzCssPathSelectorEnumWrapper { inner: AzCssPathSelector::Not(v) } }
     #[classattr]
     fn DirectChildren() -> AzCssPathSelectorEnumWrapper { AzCssPathSelectorEnumWrapper { inner: AzCssPathSelector::DirectChildren } }
     #[classattr]
     fn Children() -> AzCssPathSelectorEnumWrapper { AzCssPathSelectorEnumWrapper { inner: AzCssPathSelector::Children } }
+    #[classattr]
+    fn AdjacentSibling() -> AzCssPathSelectorEnumWrapper { AzCssPathSelectorEnumWrapper { inner: AzCssPathSelector::AdjacentSibling } }
+    #[classattr]
+    fn GeneralSibling() -> AzCssPathSelectorEnumWrapper { AzCssPathSelectorEnumWrapper { inner: AzCssPathSelector::GeneralSibling } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssPathSelector;
@@ -16363,8 +19037,12 @@ impl AzCssPathSelectorEnumWrapper {
             AzCssPathSelector::Class(v) => Ok(vec!["Class".into_py(py), v.clone().into_py(py)]),
             AzCssPathSelector::Id(v) => Ok(vec!["Id".into_py(py), v.clone().into_py(py)]),
             AzCssPathSelector::PseudoSelector(v) => Ok(vec!["PseudoSelector".into_py(py), { let m: &AzCssPathPseudoSelectorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssPathSelector::Attribute(v) => Ok(vec!["Attribute".into_py(py), v.clone().into_py(py)]),
+            AzCssPathSelector::Not(v) => Ok(vec!["Not".into_py(py), v.clone().into_py(py)]),
             AzCssPathSelector::DirectChildren => Ok(vec!["DirectChildren".into_py(py), ().into_py(py)]),
             AzCssPathSelector::Children => Ok(vec!["Children".into_py(py), ().into_py(py)]),
+            AzCssPathSelector::AdjacentSibling => Ok(vec!["AdjacentSibling".into_py(py), ().into_py(py)]),
+            AzCssPathSelector::GeneralSibling => Ok(vec!["GeneralSibling".into_py(py), ().into_py(py)]),
         }
     }
 }
@@ -16429,6 +19107,18 @@ impl AzCssPathPseudoSelectorEnumWrapper {
     fn Active() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::Active } }
     #[classattr]
     fn Focus() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::Focus } }
+    #[classattr]
+    fn FirstChild() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::FirstChild } }
+    #[classattr]
+    fn LastChild() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::LastChild } }
+    #[classattr]
+    fn OnlyChild() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::OnlyChild } }
+    #[staticmethod]
+    fn NthLastChild(v: AzCssNthChildSelectorEnumWrapper) -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::NthLastChild(unsafe { mem::transmute(v) }) } }
+    #[classattr]
+    fn Empty() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::Empty } }
+    #[classattr]
+    fn FocusWithin() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::FocusWithin } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssPathPseudoSelector;
@@ -16442,6 +19132,12 @@ impl AzCssPathPseudoSelectorEnumWrapper {
             AzCssPathPseudoSelector::Hover => Ok(vec!["Hover".into_py(py), ().into_py(py)]),
             AzCssPathPseudoSelector::Active => Ok(vec!["Active".into_py(py), ().into_py(py)]),
             AzCssPathPseudoSelector::Focus => Ok(vec!["Focus".into_py(py), ().into_py(py)]),
+            AzCssPathPseudoSelector::FirstChild => Ok(vec!["FirstChild".into_py(py), ().into_py(py)]),
+            AzCssPathPseudoSelector::LastChild => Ok(vec!["LastChild".into_py(py), ().into_py(py)]),
+            AzCssPathPseudoSelector::OnlyChild => Ok(vec!["OnlyChild".into_py(py), ().into_py(py)]),
+            AzCssPathPseudoSelector::NthLastChild(v) => Ok(vec!["NthLastChild".into_py(py), { let m: &AzCssNthChildSelectorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssPathPseudoSelector::Empty => Ok(vec!["Empty".into_py(py), ().into_py(py)]),
+            AzCssPathPseudoSelector::FocusWithin => Ok(vec!["FocusWithin".into_py(py), ().into_py(py)]),
         }
     }
 }
@@ -16456,6 +19152,67 @@ impl PyObjectProtocol for AzCssPathPseudoSelectorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzCssAttributeSelector {
+    #[new]
+    fn __new__(name: AzString, op: AzCssAttributeSelectorOpEnumWrapper, value: AzString) -> Self {
+        Self {
+            name,
+            op,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssAttributeSelector {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssAttributeSelector = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssAttributeSelector = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssAttributeSelectorOpEnumWrapper {
+    #[classattr]
+    fn Exists() -> AzCssAttributeSelectorOpEnumWrapper { AzCssAttributeSelectorOpEnumWrapper { inner: AzCssAttributeSelectorOp::Exists } }
+    #[classattr]
+    fn Equals() -> AzCssAttributeSelectorOpEnumWrapper { AzCssAttributeSelectorOpEnumWrapper { inner: AzCssAttributeSelectorOp::Equals } }
+    #[classattr]
+    fn Includes() -> AzCssAttributeSelectorOpEnumWrapper { AzCssAttributeSelectorOpEnumWrapper { inner: AzCssAttributeSelectorOp::Includes } }
+    #[classattr]
+    fn DashMatch() -> AzCssAttributeSelectorOpEnumWrapper { AzCssAttributeSelectorOpEnumWrapper { inner: AzCssAttributeSelectorOp::DashMatch } }
+    #[classattr]
+    fn Prefix() -> AzCssAttributeSelectorOpEnumWrapper { AzCssAttributeSelectorOpEnumWrapper { inner: AzCssAttributeSelectorOp::Prefix } }
+    #[classattr]
+    fn Suffix() -> AzCssAttributeSelectorOpEnumWrapper { AzCssAttributeSelectorOpEnumWrapper { inner: AzCssAttributeSelectorOp::Suffix } }
+    #[classattr]
+    fn Substring() -> AzCssAttributeSelectorOpEnumWrapper { AzCssAttributeSelectorOpEnumWrapper { inner: AzCssAttributeSelectorOp::Substring } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssAttributeSelectorOpEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssAttributeSelectorOp = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssAttributeSelectorOp = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzCssAttributeSelectorOpEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzCssNthChildSelectorEnumWrapper {
     #[staticmethod]
@@ -16559,6 +19316,104 @@ impl PyObjectProtocol for AzCss {
     }
 }
 
+#[pymethods]
+impl AzCssFontFace {
+    #[new]
+    fn __new__(font_family: AzString, src: AzString, font_weight: u16, font_style: AzCssFontFaceStyleEnumWrapper) -> Self {
+        Self {
+            font_family,
+            src,
+            font_weight,
+            font_style,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssFontFace {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFace = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFace = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssFontFaceStyleEnumWrapper {
+    #[classattr]
+    fn Normal() -> AzCssFontFaceStyleEnumWrapper { AzCssFontFaceStyleEnumWrapper { inner: AzCssFontFaceStyle::Normal } }
+    #[classattr]
+    fn Italic() -> AzCssFontFaceStyleEnumWrapper { AzCssFontFaceStyleEnumWrapper { inner: AzCssFontFaceStyle::Italic } }
+    #[classattr]
+    fn Oblique() -> AzCssFontFaceStyleEnumWrapper { AzCssFontFaceStyleEnumWrapper { inner: AzCssFontFaceStyle::Oblique } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssFontFaceStyleEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceStyle = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceStyle = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzCssFontFaceStyleEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzCssKeyframes {
+    #[new]
+    fn __new__(name: AzString, keyframes: AzCssKeyframeVec) -> Self {
+        Self {
+            name,
+            keyframes,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssKeyframes {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframes = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframes = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssKeyframe {
+    #[new]
+    fn __new__(offset: AzFloatValue, properties: AzCssPropertyVec) -> Self {
+        Self {
+            offset,
+            properties,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssKeyframe {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframe = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframe = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssPropertyTypeEnumWrapper {
     #[classattr]
@@ -16584,6 +19439,8 @@ impl AzCssPropertyTypeEnumWrapper {
     #[classattr]
     fn Float() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Float } }
     #[classattr]
+    fn Clear() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Clear } }
+    #[classattr]
     fn BoxSizing() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::BoxSizing } }
     #[classattr]
     fn Width() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Width } }
@@ -16622,6 +19479,18 @@ impl AzCssPropertyTypeEnumWrapper {
     #[classattr]
     fn AlignContent() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AlignContent } }
     #[classattr]
+    fn GridTemplateColumns() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridTemplateColumns } }
+    #[classattr]
+    fn GridTemplateRows() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridTemplateRows } }
+    #[classattr]
+    fn GridColumn() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridColumn } }
+    #[classattr]
+    fn GridRow() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridRow } }
+    #[classattr]
+    fn ColumnGap() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::ColumnGap } }
+    #[classattr]
+    fn RowGap() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::RowGap } }
+    #[classattr]
     fn BackgroundContent() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::BackgroundContent } }
     #[classattr]
     fn BackgroundPosition() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::BackgroundPosition } }
@@ -16701,6 +19570,28 @@ impl AzCssPropertyTypeEnumWrapper {
     fn PerspectiveOrigin() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::PerspectiveOrigin } }
     #[classattr]
     fn BackfaceVisibility() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::BackfaceVisibility } }
+    #[classattr]
+    fn TransitionProperty() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TransitionProperty } }
+    #[classattr]
+    fn TransitionDuration() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TransitionDuration } }
+    #[classattr]
+    fn TransitionTimingFunction() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TransitionTimingFunction } }
+    #[classattr]
+    fn TransitionDelay() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TransitionDelay } }
+    #[classattr]
+    fn AnimationName() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationName } }
+    #[classattr]
+    fn AnimationDuration() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationDuration } }
+    #[classattr]
+    fn AnimationTimingFunction() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationTimingFunction } }
+    #[classattr]
+    fn AnimationIterationCount() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationIterationCount } }
+    #[classattr]
+    fn AnimationDirection() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationDirection } }
+    #[classattr]
+    fn AnimationFillMode() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationFillMode } }
+    #[classattr]
+    fn AnimationDelay() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationDelay } }
 }
 
 #[pyproto]
@@ -16989,6 +19880,255 @@ impl PyObjectProtocol for AzLayoutAlignContentEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzGridTrackBreadthEnumWrapper {
+    #[staticmethod]
+    fn Fixed(v: AzPixelValue) -> AzGridTrackBreadthEnumWrapper { AzGridTrackBreadthEnumWrapper { inner: AzGridTrackBreadth::Fixed(v) } }
+    #[staticmethod]
+    fn Fraction(v: AzFloatValue) -> AzGridTrackBreadthEnumWrapper { AzGridTrackBreadthEnumWrapper { inner: AzGridTrackBreadth::Fraction(v) } }
+    #[classattr]
+    fn Auto() -> AzGridTrackBreadthEnumWrapper { AzGridTrackBreadthEnumWrapper { inner: AzGridTrackBreadth::Auto } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzGridTrackBreadth;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzGridTrackBreadth::Fixed(v) => Ok(vec!["Fixed".into_py(py), v.clone().into_py(py)]),
+            AzGridTrackBreadth::Fraction(v) => Ok(vec!["Fraction".into_py(py), v.clone().into_py(py)]),
+            AzGridTrackBreadth::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridTrackBreadthEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackBreadth = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackBreadth = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGridMinMax {
+    #[new]
+    fn __new__(min: AzGridTrackBreadthEnumWrapper, max: AzGridTrackBreadthEnumWrapper) -> Self {
+        Self {
+            min,
+            max,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridMinMax {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridMinMax = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridMinMax = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGridTrackSizingEnumWrapper {
+    #[staticmethod]
+    fn Fixed(v: AzPixelValue) -> AzGridTrackSizingEnumWrapper { AzGridTrackSizingEnumWrapper { inner: AzGridTrackSizing::Fixed(v) } }
+    #[staticmethod]
+    fn Fraction(v: AzFloatValue) -> AzGridTrackSizingEnumWrapper { AzGridTrackSizingEnumWrapper { inner: AzGridTrackSizing::Fraction(v) } }
+    #[classattr]
+    fn Auto() -> AzGridTrackSizingEnumWrapper { AzGridTrackSizingEnumWrapper { inner: AzGridTrackSizing::Auto } }
+    #[staticmethod]
+    fn MinMax(v: AzGridMinMax) -> AzGridTrackSizingEnumWrapper { AzGridTrackSizingEnumWrapper { inner: AzGridTrackSizing::MinMax(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzGridTrackSizing;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzGridTrackSizing::Fixed(v) => Ok(vec!["Fixed".into_py(py), v.clone().into_py(py)]),
+            AzGridTrackSizing::Fraction(v) => Ok(vec!["Fraction".into_py(py), v.clone().into_py(py)]),
+            AzGridTrackSizing::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzGridTrackSizing::MinMax(v) => Ok(vec!["MinMax".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridTrackSizingEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizing = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizing = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridTemplateColumns {
+    #[new]
+    fn __new__(tracks: AzGridTrackSizingVec) -> Self {
+        Self {
+            tracks,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridTemplateColumns {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateColumns = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateColumns = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridTemplateRows {
+    #[new]
+    fn __new__(tracks: AzGridTrackSizingVec) -> Self {
+        Self {
+            tracks,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridTemplateRows {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateRows = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateRows = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGridLineEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzGridLineEnumWrapper { AzGridLineEnumWrapper { inner: AzGridLine::Auto } }
+    #[staticmethod]
+    fn Line(v: i32) -> AzGridLineEnumWrapper { AzGridLineEnumWrapper { inner: AzGridLine::Line(v) } }
+    #[staticmethod]
+    fn Span(v: u32) -> AzGridLineEnumWrapper { AzGridLineEnumWrapper { inner: AzGridLine::Span(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzGridLine;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzGridLine::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzGridLine::Line(v) => Ok(vec!["Line".into_py(py), v.into_py(py)]),
+            AzGridLine::Span(v) => Ok(vec!["Span".into_py(py), v.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridLineEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridLine = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridLine = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridColumn {
+    #[new]
+    fn __new__(start: AzGridLineEnumWrapper, end: AzGridLineEnumWrapper) -> Self {
+        Self {
+            start,
+            end,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridColumn {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridColumn = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridColumn = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridRow {
+    #[new]
+    fn __new__(start: AzGridLineEnumWrapper, end: AzGridLineEnumWrapper) -> Self {
+        Self {
+            start,
+            end,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridRow {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridRow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridRow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutColumnGap {
+    #[new]
+    fn __new__(inner: AzPixelValue) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutColumnGap {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutColumnGap = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutColumnGap = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutRowGap {
+    #[new]
+    fn __new__(inner: AzPixelValue) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutRowGap {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutRowGap = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutRowGap = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzLayoutAlignItemsEnumWrapper {
     #[classattr]
@@ -17112,6 +20252,8 @@ impl AzLayoutDisplayEnumWrapper {
     fn Block() -> AzLayoutDisplayEnumWrapper { AzLayoutDisplayEnumWrapper { inner: AzLayoutDisplay::Block } }
     #[classattr]
     fn InlineBlock() -> AzLayoutDisplayEnumWrapper { AzLayoutDisplayEnumWrapper { inner: AzLayoutDisplay::InlineBlock } }
+    #[classattr]
+    fn Grid() -> AzLayoutDisplayEnumWrapper { AzLayoutDisplayEnumWrapper { inner: AzLayoutDisplay::Grid } }
 }
 
 #[pyproto]
@@ -17182,6 +20324,8 @@ impl AzLayoutFloatEnumWrapper {
     fn Left() -> AzLayoutFloatEnumWrapper { AzLayoutFloatEnumWrapper { inner: AzLayoutFloat::Left } }
     #[classattr]
     fn Right() -> AzLayoutFloatEnumWrapper { AzLayoutFloatEnumWrapper { inner: AzLayoutFloat::Right } }
+    #[classattr]
+    fn None() -> AzLayoutFloatEnumWrapper { AzLayoutFloatEnumWrapper { inner: AzLayoutFloat::None } }
 }
 
 #[pyproto]
@@ -17204,6 +20348,38 @@ impl PyObjectProtocol for AzLayoutFloatEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzLayoutClearEnumWrapper {
+    #[classattr]
+    fn None() -> AzLayoutClearEnumWrapper { AzLayoutClearEnumWrapper { inner: AzLayoutClear::None } }
+    #[classattr]
+    fn Left() -> AzLayoutClearEnumWrapper { AzLayoutClearEnumWrapper { inner: AzLayoutClear::Left } }
+    #[classattr]
+    fn Right() -> AzLayoutClearEnumWrapper { AzLayoutClearEnumWrapper { inner: AzLayoutClear::Right } }
+    #[classattr]
+    fn Both() -> AzLayoutClearEnumWrapper { AzLayoutClearEnumWrapper { inner: AzLayoutClear::Both } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutClearEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutClear = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutClear = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzLayoutClearEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzLayoutHeight {
     #[new]
@@ -18976,6 +22152,298 @@ impl PyObjectProtocol for AzStyleBackfaceVisibilityEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleTransitionPropertyEnumWrapper {
+    #[classattr]
+    fn All() -> AzStyleTransitionPropertyEnumWrapper { AzStyleTransitionPropertyEnumWrapper { inner: AzStyleTransitionProperty::All } }
+    #[staticmethod]
+    fn Property(v: AzCssPropertyTypeEnumWrapper) -> AzStyleTransitionPropertyEnumWrapper { AzStyleTransitionPropertyEnumWrapper { inner: AzStyleTransitionProperty::Property(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionProperty;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionProperty::All => Ok(vec!["All".into_py(py), ().into_py(py)]),
+            AzStyleTransitionProperty::Property(v) => Ok(vec!["Property".into_py(py), { let m: &AzCssPropertyTypeEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionPropertyEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionProperty = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionProperty = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionDuration {
+    #[new]
+    fn __new__(ms: u32) -> Self {
+        Self {
+            ms,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionDuration {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDuration = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDuration = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionDelay {
+    #[new]
+    fn __new__(ms: u32) -> Self {
+        Self {
+            ms,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionDelay {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDelay = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDelay = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionTimingFunctionEnumWrapper {
+    #[classattr]
+    fn Ease() -> AzStyleTransitionTimingFunctionEnumWrapper { AzStyleTransitionTimingFunctionEnumWrapper { inner: AzStyleTransitionTimingFunction::Ease } }
+    #[classattr]
+    fn Linear() -> AzStyleTransitionTimingFunctionEnumWrapper { AzStyleTransitionTimingFunctionEnumWrapper { inner: AzStyleTransitionTimingFunction::Linear } }
+    #[classattr]
+    fn EaseIn() -> AzStyleTransitionTimingFunctionEnumWrapper { AzStyleTransitionTimingFunctionEnumWrapper { inner: AzStyleTransitionTimingFunction::EaseIn } }
+    #[classattr]
+    fn EaseOut() -> AzStyleTransitionTimingFunctionEnumWrapper { AzStyleTransitionTimingFunctionEnumWrapper { inner: AzStyleTransitionTimingFunction::EaseOut } }
+    #[classattr]
+    fn EaseInOut() -> AzStyleTransitionTimingFunctionEnumWrapper { AzStyleTransitionTimingFunctionEnumWrapper { inner: AzStyleTransitionTimingFunction::EaseInOut } }
+    #[staticmethod]
+    fn CubicBezier(v: AzStyleCubicBezier) -> AzStyleTransitionTimingFunctionEnumWrapper { AzStyleTransitionTimingFunctionEnumWrapper { inner: AzStyleTransitionTimingFunction::CubicBezier(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionTimingFunction;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionTimingFunction::Ease => Ok(vec!["Ease".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunction::Linear => Ok(vec!["Linear".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunction::EaseIn => Ok(vec!["EaseIn".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunction::EaseOut => Ok(vec!["EaseOut".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunction::EaseInOut => Ok(vec!["EaseInOut".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunction::CubicBezier(v) => Ok(vec!["CubicBezier".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionTimingFunctionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionTimingFunction = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionTimingFunction = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleCubicBezier {
+    #[new]
+    fn __new__(x1: AzFloatValue, y1: AzFloatValue, x2: AzFloatValue, y2: AzFloatValue) -> Self {
+        Self {
+            x1,
+            y1,
+            x2,
+            y2,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCubicBezier {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCubicBezier = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCubicBezier = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationName {
+    #[new]
+    fn __new__(name: AzString) -> Self {
+        Self {
+            name,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationName {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationName = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationName = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDuration {
+    #[new]
+    fn __new__(ms: u32) -> Self {
+        Self {
+            ms,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDuration {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDuration = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDuration = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDelay {
+    #[new]
+    fn __new__(ms: u32) -> Self {
+        Self {
+            ms,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDelay {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDelay = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDelay = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationIterationCountEnumWrapper {
+    #[staticmethod]
+    fn Count(v: u32) -> AzStyleAnimationIterationCountEnumWrapper { AzStyleAnimationIterationCountEnumWrapper { inner: AzStyleAnimationIterationCount::Count(v) } }
+    #[classattr]
+    fn Infinite() -> AzStyleAnimationIterationCountEnumWrapper { AzStyleAnimationIterationCountEnumWrapper { inner: AzStyleAnimationIterationCount::Infinite } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationIterationCount;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationIterationCount::Count(v) => Ok(vec!["Count".into_py(py), v.into_py(py)]),
+            AzStyleAnimationIterationCount::Infinite => Ok(vec!["Infinite".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationIterationCountEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCount = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCount = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDirectionEnumWrapper {
+    #[classattr]
+    fn Normal() -> AzStyleAnimationDirectionEnumWrapper { AzStyleAnimationDirectionEnumWrapper { inner: AzStyleAnimationDirection::Normal } }
+    #[classattr]
+    fn Reverse() -> AzStyleAnimationDirectionEnumWrapper { AzStyleAnimationDirectionEnumWrapper { inner: AzStyleAnimationDirection::Reverse } }
+    #[classattr]
+    fn Alternate() -> AzStyleAnimationDirectionEnumWrapper { AzStyleAnimationDirectionEnumWrapper { inner: AzStyleAnimationDirection::Alternate } }
+    #[classattr]
+    fn AlternateReverse() -> AzStyleAnimationDirectionEnumWrapper { AzStyleAnimationDirectionEnumWrapper { inner: AzStyleAnimationDirection::AlternateReverse } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDirectionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleAnimationDirectionEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationFillModeEnumWrapper {
+    #[classattr]
+    fn None() -> AzStyleAnimationFillModeEnumWrapper { AzStyleAnimationFillModeEnumWrapper { inner: AzStyleAnimationFillMode::None } }
+    #[classattr]
+    fn Forwards() -> AzStyleAnimationFillModeEnumWrapper { AzStyleAnimationFillModeEnumWrapper { inner: AzStyleAnimationFillMode::Forwards } }
+    #[classattr]
+    fn Backwards() -> AzStyleAnimationFillModeEnumWrapper { AzStyleAnimationFillModeEnumWrapper { inner: AzStyleAnimationFillMode::Backwards } }
+    #[classattr]
+    fn Both() -> AzStyleAnimationFillModeEnumWrapper { AzStyleAnimationFillModeEnumWrapper { inner: AzStyleAnimationFillMode::Both } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationFillModeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleAnimationFillModeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzStyleTransformEnumWrapper {
     #[staticmethod]
@@ -19408,6 +22876,234 @@ impl PyObjectProtocol for AzLayoutAlignContentValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzLayoutGridTemplateColumnsValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutGridTemplateColumnsValueEnumWrapper { AzLayoutGridTemplateColumnsValueEnumWrapper { inner: AzLayoutGridTemplateColumnsValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutGridTemplateColumnsValueEnumWrapper { AzLayoutGridTemplateColumnsValueEnumWrapper { inner: AzLayoutGridTemplateColumnsValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutGridTemplateColumnsValueEnumWrapper { AzLayoutGridTemplateColumnsValueEnumWrapper { inner: AzLayoutGridTemplateColumnsValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutGridTemplateColumnsValueEnumWrapper { AzLayoutGridTemplateColumnsValueEnumWrapper { inner: AzLayoutGridTemplateColumnsValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzLayoutGridTemplateColumns) -> AzLayoutGridTemplateColumnsValueEnumWrapper { AzLayoutGridTemplateColumnsValueEnumWrapper { inner: AzLayoutGridTemplateColumnsValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutGridTemplateColumnsValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutGridTemplateColumnsValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateColumnsValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateColumnsValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateColumnsValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateColumnsValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridTemplateColumnsValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateColumnsValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateColumnsValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridTemplateRowsValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutGridTemplateRowsValueEnumWrapper { AzLayoutGridTemplateRowsValueEnumWrapper { inner: AzLayoutGridTemplateRowsValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutGridTemplateRowsValueEnumWrapper { AzLayoutGridTemplateRowsValueEnumWrapper { inner: AzLayoutGridTemplateRowsValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutGridTemplateRowsValueEnumWrapper { AzLayoutGridTemplateRowsValueEnumWrapper { inner: AzLayoutGridTemplateRowsValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutGridTemplateRowsValueEnumWrapper { AzLayoutGridTemplateRowsValueEnumWrapper { inner: AzLayoutGridTemplateRowsValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzLayoutGridTemplateRows) -> AzLayoutGridTemplateRowsValueEnumWrapper { AzLayoutGridTemplateRowsValueEnumWrapper { inner: AzLayoutGridTemplateRowsValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutGridTemplateRowsValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutGridTemplateRowsValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateRowsValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateRowsValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateRowsValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateRowsValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridTemplateRowsValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateRowsValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateRowsValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridColumnValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutGridColumnValueEnumWrapper { AzLayoutGridColumnValueEnumWrapper { inner: AzLayoutGridColumnValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutGridColumnValueEnumWrapper { AzLayoutGridColumnValueEnumWrapper { inner: AzLayoutGridColumnValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutGridColumnValueEnumWrapper { AzLayoutGridColumnValueEnumWrapper { inner: AzLayoutGridColumnValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutGridColumnValueEnumWrapper { AzLayoutGridColumnValueEnumWrapper { inner: AzLayoutGridColumnValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzLayoutGridColumn) -> AzLayoutGridColumnValueEnumWrapper { AzLayoutGridColumnValueEnumWrapper { inner: AzLayoutGridColumnValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutGridColumnValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutGridColumnValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutGridColumnValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutGridColumnValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutGridColumnValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutGridColumnValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridColumnValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridColumnValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridColumnValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridRowValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutGridRowValueEnumWrapper { AzLayoutGridRowValueEnumWrapper { inner: AzLayoutGridRowValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutGridRowValueEnumWrapper { AzLayoutGridRowValueEnumWrapper { inner: AzLayoutGridRowValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutGridRowValueEnumWrapper { AzLayoutGridRowValueEnumWrapper { inner: AzLayoutGridRowValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutGridRowValueEnumWrapper { AzLayoutGridRowValueEnumWrapper { inner: AzLayoutGridRowValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzLayoutGridRow) -> AzLayoutGridRowValueEnumWrapper { AzLayoutGridRowValueEnumWrapper { inner: AzLayoutGridRowValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutGridRowValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutGridRowValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutGridRowValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutGridRowValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutGridRowValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutGridRowValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridRowValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridRowValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridRowValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutColumnGapValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutColumnGapValueEnumWrapper { AzLayoutColumnGapValueEnumWrapper { inner: AzLayoutColumnGapValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutColumnGapValueEnumWrapper { AzLayoutColumnGapValueEnumWrapper { inner: AzLayoutColumnGapValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutColumnGapValueEnumWrapper { AzLayoutColumnGapValueEnumWrapper { inner: AzLayoutColumnGapValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutColumnGapValueEnumWrapper { AzLayoutColumnGapValueEnumWrapper { inner: AzLayoutColumnGapValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzLayoutColumnGap) -> AzLayoutColumnGapValueEnumWrapper { AzLayoutColumnGapValueEnumWrapper { inner: AzLayoutColumnGapValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutColumnGapValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutColumnGapValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutColumnGapValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutColumnGapValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutColumnGapValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutColumnGapValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutColumnGapValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutColumnGapValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutColumnGapValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutRowGapValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutRowGapValueEnumWrapper { AzLayoutRowGapValueEnumWrapper { inner: AzLayoutRowGapValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutRowGapValueEnumWrapper { AzLayoutRowGapValueEnumWrapper { inner: AzLayoutRowGapValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutRowGapValueEnumWrapper { AzLayoutRowGapValueEnumWrapper { inner: AzLayoutRowGapValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutRowGapValueEnumWrapper { AzLayoutRowGapValueEnumWrapper { inner: AzLayoutRowGapValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzLayoutRowGap) -> AzLayoutRowGapValueEnumWrapper { AzLayoutRowGapValueEnumWrapper { inner: AzLayoutRowGapValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutRowGapValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutRowGapValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutRowGapValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutRowGapValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutRowGapValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutRowGapValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutRowGapValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutRowGapValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutRowGapValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzLayoutAlignItemsValueEnumWrapper {
     #[classattr]
@@ -19712,6 +23408,44 @@ impl PyObjectProtocol for AzLayoutFloatValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzLayoutClearValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutClearValueEnumWrapper { AzLayoutClearValueEnumWrapper { inner: AzLayoutClearValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutClearValueEnumWrapper { AzLayoutClearValueEnumWrapper { inner: AzLayoutClearValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutClearValueEnumWrapper { AzLayoutClearValueEnumWrapper { inner: AzLayoutClearValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutClearValueEnumWrapper { AzLayoutClearValueEnumWrapper { inner: AzLayoutClearValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzLayoutClearEnumWrapper) -> AzLayoutClearValueEnumWrapper { AzLayoutClearValueEnumWrapper { inner: AzLayoutClearValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutClearValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutClearValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutClearValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutClearValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutClearValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutClearValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzLayoutClearEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutClearValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutClearValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutClearValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzLayoutHeightValueEnumWrapper {
     #[classattr]
@@ -21840,6 +25574,386 @@ impl PyObjectProtocol for AzStyleBackfaceVisibilityValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleTransitionPropertyVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleTransitionPropertyVecValueEnumWrapper { AzStyleTransitionPropertyVecValueEnumWrapper { inner: AzStyleTransitionPropertyVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleTransitionPropertyVecValueEnumWrapper { AzStyleTransitionPropertyVecValueEnumWrapper { inner: AzStyleTransitionPropertyVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleTransitionPropertyVecValueEnumWrapper { AzStyleTransitionPropertyVecValueEnumWrapper { inner: AzStyleTransitionPropertyVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleTransitionPropertyVecValueEnumWrapper { AzStyleTransitionPropertyVecValueEnumWrapper { inner: AzStyleTransitionPropertyVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleTransitionPropertyVec) -> AzStyleTransitionPropertyVecValueEnumWrapper { AzStyleTransitionPropertyVecValueEnumWrapper { inner: AzStyleTransitionPropertyVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionPropertyVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionPropertyVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionPropertyVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionDurationVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleTransitionDurationVecValueEnumWrapper { AzStyleTransitionDurationVecValueEnumWrapper { inner: AzStyleTransitionDurationVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleTransitionDurationVecValueEnumWrapper { AzStyleTransitionDurationVecValueEnumWrapper { inner: AzStyleTransitionDurationVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleTransitionDurationVecValueEnumWrapper { AzStyleTransitionDurationVecValueEnumWrapper { inner: AzStyleTransitionDurationVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleTransitionDurationVecValueEnumWrapper { AzStyleTransitionDurationVecValueEnumWrapper { inner: AzStyleTransitionDurationVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleTransitionDurationVec) -> AzStyleTransitionDurationVecValueEnumWrapper { AzStyleTransitionDurationVecValueEnumWrapper { inner: AzStyleTransitionDurationVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionDurationVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionDurationVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDurationVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDurationVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDurationVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDurationVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionDurationVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDurationVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDurationVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionTimingFunctionVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleTransitionTimingFunctionVecValueEnumWrapper { AzStyleTransitionTimingFunctionVecValueEnumWrapper { inner: AzStyleTransitionTimingFunctionVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleTransitionTimingFunctionVecValueEnumWrapper { AzStyleTransitionTimingFunctionVecValueEnumWrapper { inner: AzStyleTransitionTimingFunctionVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleTransitionTimingFunctionVecValueEnumWrapper { AzStyleTransitionTimingFunctionVecValueEnumWrapper { inner: AzStyleTransitionTimingFunctionVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleTransitionTimingFunctionVecValueEnumWrapper { AzStyleTransitionTimingFunctionVecValueEnumWrapper { inner: AzStyleTransitionTimingFunctionVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleTransitionTimingFunctionVec) -> AzStyleTransitionTimingFunctionVecValueEnumWrapper { AzStyleTransitionTimingFunctionVecValueEnumWrapper { inner: AzStyleTransitionTimingFunctionVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionTimingFunctionVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionTimingFunctionVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunctionVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunctionVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunctionVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunctionVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionTimingFunctionVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionTimingFunctionVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionTimingFunctionVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionDelayVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleTransitionDelayVecValueEnumWrapper { AzStyleTransitionDelayVecValueEnumWrapper { inner: AzStyleTransitionDelayVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleTransitionDelayVecValueEnumWrapper { AzStyleTransitionDelayVecValueEnumWrapper { inner: AzStyleTransitionDelayVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleTransitionDelayVecValueEnumWrapper { AzStyleTransitionDelayVecValueEnumWrapper { inner: AzStyleTransitionDelayVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleTransitionDelayVecValueEnumWrapper { AzStyleTransitionDelayVecValueEnumWrapper { inner: AzStyleTransitionDelayVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleTransitionDelayVec) -> AzStyleTransitionDelayVecValueEnumWrapper { AzStyleTransitionDelayVecValueEnumWrapper { inner: AzStyleTransitionDelayVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionDelayVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionDelayVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDelayVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDelayVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDelayVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDelayVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionDelayVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDelayVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDelayVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationNameVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleAnimationNameVecValueEnumWrapper { AzStyleAnimationNameVecValueEnumWrapper { inner: AzStyleAnimationNameVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleAnimationNameVecValueEnumWrapper { AzStyleAnimationNameVecValueEnumWrapper { inner: AzStyleAnimationNameVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleAnimationNameVecValueEnumWrapper { AzStyleAnimationNameVecValueEnumWrapper { inner: AzStyleAnimationNameVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleAnimationNameVecValueEnumWrapper { AzStyleAnimationNameVecValueEnumWrapper { inner: AzStyleAnimationNameVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleAnimationNameVec) -> AzStyleAnimationNameVecValueEnumWrapper { AzStyleAnimationNameVecValueEnumWrapper { inner: AzStyleAnimationNameVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationNameVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationNameVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleAnimationNameVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleAnimationNameVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleAnimationNameVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleAnimationNameVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationNameVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationNameVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationNameVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDurationVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleAnimationDurationVecValueEnumWrapper { AzStyleAnimationDurationVecValueEnumWrapper { inner: AzStyleAnimationDurationVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleAnimationDurationVecValueEnumWrapper { AzStyleAnimationDurationVecValueEnumWrapper { inner: AzStyleAnimationDurationVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleAnimationDurationVecValueEnumWrapper { AzStyleAnimationDurationVecValueEnumWrapper { inner: AzStyleAnimationDurationVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleAnimationDurationVecValueEnumWrapper { AzStyleAnimationDurationVecValueEnumWrapper { inner: AzStyleAnimationDurationVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleAnimationDurationVec) -> AzStyleAnimationDurationVecValueEnumWrapper { AzStyleAnimationDurationVecValueEnumWrapper { inner: AzStyleAnimationDurationVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationDurationVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationDurationVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDurationVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDurationVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDurationVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDurationVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDurationVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDurationVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDurationVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationIterationCountVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleAnimationIterationCountVecValueEnumWrapper { AzStyleAnimationIterationCountVecValueEnumWrapper { inner: AzStyleAnimationIterationCountVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleAnimationIterationCountVecValueEnumWrapper { AzStyleAnimationIterationCountVecValueEnumWrapper { inner: AzStyleAnimationIterationCountVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleAnimationIterationCountVecValueEnumWrapper { AzStyleAnimationIterationCountVecValueEnumWrapper { inner: AzStyleAnimationIterationCountVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleAnimationIterationCountVecValueEnumWrapper { AzStyleAnimationIterationCountVecValueEnumWrapper { inner: AzStyleAnimationIterationCountVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleAnimationIterationCountVec) -> AzStyleAnimationIterationCountVecValueEnumWrapper { AzStyleAnimationIterationCountVecValueEnumWrapper { inner: AzStyleAnimationIterationCountVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationIterationCountVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationIterationCountVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleAnimationIterationCountVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleAnimationIterationCountVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleAnimationIterationCountVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleAnimationIterationCountVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationIterationCountVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCountVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCountVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDirectionVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleAnimationDirectionVecValueEnumWrapper { AzStyleAnimationDirectionVecValueEnumWrapper { inner: AzStyleAnimationDirectionVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleAnimationDirectionVecValueEnumWrapper { AzStyleAnimationDirectionVecValueEnumWrapper { inner: AzStyleAnimationDirectionVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleAnimationDirectionVecValueEnumWrapper { AzStyleAnimationDirectionVecValueEnumWrapper { inner: AzStyleAnimationDirectionVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleAnimationDirectionVecValueEnumWrapper { AzStyleAnimationDirectionVecValueEnumWrapper { inner: AzStyleAnimationDirectionVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleAnimationDirectionVec) -> AzStyleAnimationDirectionVecValueEnumWrapper { AzStyleAnimationDirectionVecValueEnumWrapper { inner: AzStyleAnimationDirectionVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationDirectionVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationDirectionVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDirectionVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDirectionVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDirectionVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDirectionVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDirectionVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirectionVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirectionVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationFillModeVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleAnimationFillModeVecValueEnumWrapper { AzStyleAnimationFillModeVecValueEnumWrapper { inner: AzStyleAnimationFillModeVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleAnimationFillModeVecValueEnumWrapper { AzStyleAnimationFillModeVecValueEnumWrapper { inner: AzStyleAnimationFillModeVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleAnimationFillModeVecValueEnumWrapper { AzStyleAnimationFillModeVecValueEnumWrapper { inner: AzStyleAnimationFillModeVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleAnimationFillModeVecValueEnumWrapper { AzStyleAnimationFillModeVecValueEnumWrapper { inner: AzStyleAnimationFillModeVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleAnimationFillModeVec) -> AzStyleAnimationFillModeVecValueEnumWrapper { AzStyleAnimationFillModeVecValueEnumWrapper { inner: AzStyleAnimationFillModeVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationFillModeVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationFillModeVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleAnimationFillModeVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleAnimationFillModeVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleAnimationFillModeVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleAnimationFillModeVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationFillModeVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillModeVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillModeVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDelayVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleAnimationDelayVecValueEnumWrapper { AzStyleAnimationDelayVecValueEnumWrapper { inner: AzStyleAnimationDelayVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleAnimationDelayVecValueEnumWrapper { AzStyleAnimationDelayVecValueEnumWrapper { inner: AzStyleAnimationDelayVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleAnimationDelayVecValueEnumWrapper { AzStyleAnimationDelayVecValueEnumWrapper { inner: AzStyleAnimationDelayVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleAnimationDelayVecValueEnumWrapper { AzStyleAnimationDelayVecValueEnumWrapper { inner: AzStyleAnimationDelayVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleAnimationDelayVec) -> AzStyleAnimationDelayVecValueEnumWrapper { AzStyleAnimationDelayVecValueEnumWrapper { inner: AzStyleAnimationDelayVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationDelayVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationDelayVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDelayVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDelayVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDelayVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDelayVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDelayVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDelayVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDelayVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssPropertyEnumWrapper {
     #[staticmethod]
@@ -21865,6 +25979,8 @@ impl AzCssPropertyEnumWrapper {
     #[staticmethod]
     fn Float(v: AzLayoutFloatValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Float(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
+    fn Clear(v: AzLayoutClearValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Clear(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
     fn BoxSizing(v: AzLayoutBoxSizingValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::BoxSizing(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn Width(v: AzLayoutWidthValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Width(unsafe { mem::transmute(v) }) } }
@@ -21903,6 +26019,18 @@ impl AzCssPropertyEnumWrapper {
     #[staticmethod]
     fn AlignContent(v: AzLayoutAlignContentValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AlignContent(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
+    fn GridTemplateColumns(v: AzLayoutGridTemplateColumnsValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridTemplateColumns(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn GridTemplateRows(v: AzLayoutGridTemplateRowsValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridTemplateRows(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn GridColumn(v: AzLayoutGridColumnValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridColumn(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn GridRow(v: AzLayoutGridRowValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridRow(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn ColumnGap(v: AzLayoutColumnGapValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::ColumnGap(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn RowGap(v: AzLayoutRowGapValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::RowGap(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
     fn BackgroundContent(v: AzStyleBackgroundContentVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::BackgroundContent(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn BackgroundPosition(v: AzStyleBackgroundPositionVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::BackgroundPosition(unsafe { mem::transmute(v) }) } }
@@ -21982,6 +26110,28 @@ impl AzCssPropertyEnumWrapper {
     fn PerspectiveOrigin(v: AzStylePerspectiveOriginValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::PerspectiveOrigin(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn BackfaceVisibility(v: AzStyleBackfaceVisibilityValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::BackfaceVisibility(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn TransitionProperty(v: AzStyleTransitionPropertyVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TransitionProperty(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn TransitionDuration(v: AzStyleTransitionDurationVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TransitionDuration(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn TransitionTimingFunction(v: AzStyleTransitionTimingFunctionVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TransitionTimingFunction(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn TransitionDelay(v: AzStyleTransitionDelayVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TransitionDelay(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationName(v: AzStyleAnimationNameVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationName(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationDuration(v: AzStyleAnimationDurationVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationDuration(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationTimingFunction(v: AzStyleTransitionTimingFunctionVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationTimingFunction(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationIterationCount(v: AzStyleAnimationIterationCountVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationIterationCount(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationDirection(v: AzStyleAnimationDirectionVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationDirection(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationFillMode(v: AzStyleAnimationFillModeVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationFillMode(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationDelay(v: AzStyleAnimationDelayVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationDelay(unsafe { mem::transmute(v) }) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssProperty;
@@ -22000,6 +26150,7 @@ impl AzCssPropertyEnumWrapper {
             AzCssProperty::Cursor(v) => Ok(vec!["Cursor".into_py(py), { let m: &AzStyleCursorValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::Display(v) => Ok(vec!["Display".into_py(py), { let m: &AzLayoutDisplayValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::Float(v) => Ok(vec!["Float".into_py(py), { let m: &AzLayoutFloatValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::Clear(v) => Ok(vec!["Clear".into_py(py), { let m: &AzLayoutClearValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BoxSizing(v) => Ok(vec!["BoxSizing".into_py(py), { let m: &AzLayoutBoxSizingValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::Width(v) => Ok(vec!["Width".into_py(py), { let m: &AzLayoutWidthValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::Height(v) => Ok(vec!["Height".into_py(py), { let m: &AzLayoutHeightValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
@@ -22019,6 +26170,12 @@ impl AzCssPropertyEnumWrapper {
             AzCssProperty::JustifyContent(v) => Ok(vec!["JustifyContent".into_py(py), { let m: &AzLayoutJustifyContentValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::AlignItems(v) => Ok(vec!["AlignItems".into_py(py), { let m: &AzLayoutAlignItemsValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::AlignContent(v) => Ok(vec!["AlignContent".into_py(py), { let m: &AzLayoutAlignContentValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridTemplateColumns(v) => Ok(vec!["GridTemplateColumns".into_py(py), { let m: &AzLayoutGridTemplateColumnsValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridTemplateRows(v) => Ok(vec!["GridTemplateRows".into_py(py), { let m: &AzLayoutGridTemplateRowsValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridColumn(v) => Ok(vec!["GridColumn".into_py(py), { let m: &AzLayoutGridColumnValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridRow(v) => Ok(vec!["GridRow".into_py(py), { let m: &AzLayoutGridRowValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::ColumnGap(v) => Ok(vec!["ColumnGap".into_py(py), { let m: &AzLayoutColumnGapValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::RowGap(v) => Ok(vec!["RowGap".into_py(py), { let m: &AzLayoutRowGapValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackgroundContent(v) => Ok(vec!["BackgroundContent".into_py(py), { let m: &AzStyleBackgroundContentVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackgroundPosition(v) => Ok(vec!["BackgroundPosition".into_py(py), { let m: &AzStyleBackgroundPositionVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackgroundSize(v) => Ok(vec!["BackgroundSize".into_py(py), { let m: &AzStyleBackgroundSizeVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
@@ -22059,6 +26216,17 @@ impl AzCssPropertyEnumWrapper {
             AzCssProperty::TransformOrigin(v) => Ok(vec!["TransformOrigin".into_py(py), { let m: &AzStyleTransformOriginValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::PerspectiveOrigin(v) => Ok(vec!["PerspectiveOrigin".into_py(py), { let m: &AzStylePerspectiveOriginValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackfaceVisibility(v) => Ok(vec!["BackfaceVisibility".into_py(py), { let m: &AzStyleBackfaceVisibilityValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::TransitionProperty(v) => Ok(vec!["TransitionProperty".into_py(py), { let m: &AzStyleTransitionPropertyVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::TransitionDuration(v) => Ok(vec!["TransitionDuration".into_py(py), { let m: &AzStyleTransitionDurationVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::TransitionTimingFunction(v) => Ok(vec!["TransitionTimingFunction".into_py(py), { let m: &AzStyleTransitionTimingFunctionVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::TransitionDelay(v) => Ok(vec!["TransitionDelay".into_py(py), { let m: &AzStyleTransitionDelayVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationName(v) => Ok(vec!["AnimationName".into_py(py), { let m: &AzStyleAnimationNameVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationDuration(v) => Ok(vec!["AnimationDuration".into_py(py), { let m: &AzStyleAnimationDurationVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationTimingFunction(v) => Ok(vec!["AnimationTimingFunction".into_py(py), { let m: &AzStyleTransitionTimingFunctionVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationIterationCount(v) => Ok(vec!["AnimationIterationCount".into_py(py), { let m: &AzStyleAnimationIterationCountVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationDirection(v) => Ok(vec!["AnimationDirection".into_py(py), { let m: &AzStyleAnimationDirectionVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationFillMode(v) => Ok(vec!["AnimationFillMode".into_py(py), { let m: &AzStyleAnimationFillModeVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationDelay(v) => Ok(vec!["AnimationDelay".into_py(py), { let m: &AzStyleAnimationDelayVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
         }
     }
 }
@@ -22457,7 +26625,7 @@ impl PyObjectProtocol for AzTextInput {
 #[pymethods]
 impl AzTextInputStateWrapper {
     #[new]
-    fn __new__(inner: AzTextInputState, on_text_input: AzOptionTextInputOnTextInputEnumWrapper, on_virtual_key_down: AzOptionTextInputOnVirtualKeyDownEnumWrapper, on_focus_lost: AzOptionTextInputOnFocusLostEnumWrapper, update_text_input_before_calling_focus_lost_fn: bool, update_text_input_before_calling_vk_down_fn: bool) -> Self {
+    fn __new__(inner: AzTextInputState, on_text_input: AzOptionTextInputOnTextInputEnumWrapper, on_virtual_key_down: AzOptionTextInputOnVirtualKeyDownEnumWrapper, on_focus_lost: AzOptionTextInputOnFocusLostEnumWrapper, update_text_input_before_calling_focus_lost_fn: bool, update_text_input_before_calling_vk_down_fn: bool, is_dragging: bool) -> Self {
         Self {
             inner,
             on_text_input,
@@ -22465,6 +26633,7 @@ impl AzTextInputStateWrapper {
             on_focus_lost,
             update_text_input_before_calling_focus_lost_fn,
             update_text_input_before_calling_vk_down_fn,
+            is_dragging,
         }
     }
 
@@ -22821,6 +26990,1319 @@ impl PyObjectProtocol for AzNumberInputOnValueChangeCallback {
     }
 }
 
+#[pymethods]
+impl AzTextEdit {
+    #[new]
+    fn new(initial_text: String) -> AzTextEdit {
+        let initial_text = pystring_to_azstring(&initial_text);
+        unsafe { mem::transmute(crate::AzTextEdit_new(
+            mem::transmute(initial_text),
+        )) }
+    }
+    fn set_show_line_numbers(&mut self, show_line_numbers: bool) -> () {
+        unsafe { mem::transmute(crate::AzTextEdit_setShowLineNumbers(
+            mem::transmute(self),
+            mem::transmute(show_line_numbers),
+        )) }
+    }
+    fn with_show_line_numbers(&mut self, show_line_numbers: bool) -> AzTextEdit {
+        unsafe { mem::transmute(crate::AzTextEdit_withShowLineNumbers(
+            mem::transmute(self),
+            mem::transmute(show_line_numbers),
+        )) }
+    }
+    fn set_container_style(&mut self, container_style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTextEdit_setContainerStyle(
+            mem::transmute(self),
+            mem::transmute(container_style),
+        )) }
+    }
+    fn with_container_style(&mut self, container_style: AzNodeDataInlineCssPropertyVec) -> AzTextEdit {
+        unsafe { mem::transmute(crate::AzTextEdit_withContainerStyle(
+            mem::transmute(self),
+            mem::transmute(container_style),
+        )) }
+    }
+    fn set_line_numbers_style(&mut self, line_numbers_style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTextEdit_setLineNumbersStyle(
+            mem::transmute(self),
+            mem::transmute(line_numbers_style),
+        )) }
+    }
+    fn with_line_numbers_style(&mut self, line_numbers_style: AzNodeDataInlineCssPropertyVec) -> AzTextEdit {
+        unsafe { mem::transmute(crate::AzTextEdit_withLineNumbersStyle(
+            mem::transmute(self),
+            mem::transmute(line_numbers_style),
+        )) }
+    }
+    fn set_label_style(&mut self, label_style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzTextEdit_setLabelStyle(
+            mem::transmute(self),
+            mem::transmute(label_style),
+        )) }
+    }
+    fn with_label_style(&mut self, label_style: AzNodeDataInlineCssPropertyVec) -> AzTextEdit {
+        unsafe { mem::transmute(crate::AzTextEdit_withLabelStyle(
+            mem::transmute(self),
+            mem::transmute(label_style),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzTextEdit_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEdit {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEdit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEdit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditStateWrapper {
+    #[new]
+    fn __new__(inner: AzTextEditState, on_text_change: AzOptionTextEditOnTextChangeEnumWrapper, on_focus_lost: AzOptionTextEditOnFocusLostEnumWrapper, history: AzTextEditHistory, view: AzTextEditView) -> Self {
+        Self {
+            inner,
+            on_text_change,
+            on_focus_lost,
+            history,
+            view,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditStateWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditState {
+    #[new]
+    fn __new__(text: AzU32Vec, selection: AzOptionTextInputSelectionEnumWrapper, cursor_pos: usize, show_line_numbers: bool) -> Self {
+        Self {
+            text,
+            selection,
+            cursor_pos,
+            show_line_numbers,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditHistory {
+    #[new]
+    fn __new__(undo_stack: AzTextEditSnapshotVec, redo_stack: AzTextEditSnapshotVec, last_edit: AzOptionTextEditKindEnumWrapper) -> Self {
+        Self {
+            undo_stack,
+            redo_stack,
+            last_edit,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditHistory {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditHistory = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditHistory = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditSnapshot {
+    #[new]
+    fn __new__(text: AzU32Vec, cursor_pos: usize) -> Self {
+        Self {
+            text,
+            cursor_pos,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditSnapshot {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditSnapshot = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditSnapshot = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditKindEnumWrapper {
+    #[classattr]
+    fn Typing() -> AzTextEditKindEnumWrapper { AzTextEditKindEnumWrapper { inner: AzTextEditKind::Typing } }
+    #[classattr]
+    fn Deleting() -> AzTextEditKindEnumWrapper { AzTextEditKindEnumWrapper { inner: AzTextEditKind::Deleting } }
+    #[classattr]
+    fn Other() -> AzTextEditKindEnumWrapper { AzTextEditKindEnumWrapper { inner: AzTextEditKind::Other } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditKindEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditKind = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditKind = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzTextEditKindEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzTextEditView {
+    #[new]
+    fn __new__(preferred_caret_x: AzOptionF32EnumWrapper, scroll_y: f32) -> Self {
+        Self {
+            preferred_caret_x,
+            scroll_y,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditView {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditView = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditView = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditOnTextChange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTextEditOnTextChangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditOnTextChange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditOnTextChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditOnTextChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditOnTextChangeCallback {
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditOnTextChangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditOnTextChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditOnTextChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditOnFocusLost {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTextEditOnFocusLostCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditOnFocusLost {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditOnFocusLost = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditOnFocusLost = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextEditOnFocusLostCallback {
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditOnFocusLostCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditOnFocusLostCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditOnFocusLostCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSlider {
+    #[new]
+    fn new(value: f32, min: f32, max: f32) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_new(
+            mem::transmute(value),
+            mem::transmute(min),
+            mem::transmute(max),
+        )) }
+    }
+    fn set_step(&mut self, step: f32) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setStep(
+            mem::transmute(self),
+            mem::transmute(step),
+        )) }
+    }
+    fn with_step(&mut self, step: f32) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_withStep(
+            mem::transmute(self),
+            mem::transmute(step),
+        )) }
+    }
+    fn set_orientation(&mut self, orientation: AzSliderOrientationEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setOrientation(
+            mem::transmute(self),
+            mem::transmute(orientation),
+        )) }
+    }
+    fn with_orientation(&mut self, orientation: AzSliderOrientationEnumWrapper) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_withOrientation(
+            mem::transmute(self),
+            mem::transmute(orientation),
+        )) }
+    }
+    fn set_tick_count(&mut self, tick_count: usize) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setTickCount(
+            mem::transmute(self),
+            mem::transmute(tick_count),
+        )) }
+    }
+    fn with_tick_count(&mut self, tick_count: usize) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_withTickCount(
+            mem::transmute(self),
+            mem::transmute(tick_count),
+        )) }
+    }
+    fn set_show_tick_labels(&mut self, show_tick_labels: bool) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setShowTickLabels(
+            mem::transmute(self),
+            mem::transmute(show_tick_labels),
+        )) }
+    }
+    fn with_show_tick_labels(&mut self, show_tick_labels: bool) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_withShowTickLabels(
+            mem::transmute(self),
+            mem::transmute(show_tick_labels),
+        )) }
+    }
+    fn set_container_style(&mut self, container_style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setContainerStyle(
+            mem::transmute(self),
+            mem::transmute(container_style),
+        )) }
+    }
+    fn with_container_style(&mut self, container_style: AzNodeDataInlineCssPropertyVec) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_withContainerStyle(
+            mem::transmute(self),
+            mem::transmute(container_style),
+        )) }
+    }
+    fn set_track_style(&mut self, track_style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setTrackStyle(
+            mem::transmute(self),
+            mem::transmute(track_style),
+        )) }
+    }
+    fn with_track_style(&mut self, track_style: AzNodeDataInlineCssPropertyVec) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_withTrackStyle(
+            mem::transmute(self),
+            mem::transmute(track_style),
+        )) }
+    }
+    fn set_thumb_style(&mut self, thumb_style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setThumbStyle(
+            mem::transmute(self),
+            mem::transmute(thumb_style),
+        )) }
+    }
+    fn with_thumb_style(&mut self, thumb_style: AzNodeDataInlineCssPropertyVec) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_withThumbStyle(
+            mem::transmute(self),
+            mem::transmute(thumb_style),
+        )) }
+    }
+    fn set_tick_style(&mut self, tick_style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setTickStyle(
+            mem::transmute(self),
+            mem::transmute(tick_style),
+        )) }
+    }
+    fn with_tick_style(&mut self, tick_style: AzNodeDataInlineCssPropertyVec) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_withTickStyle(
+            mem::transmute(self),
+            mem::transmute(tick_style),
+        )) }
+    }
+    fn set_label_style(&mut self, label_style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setLabelStyle(
+            mem::transmute(self),
+            mem::transmute(label_style),
+        )) }
+    }
+    fn with_label_style(&mut self, label_style: AzNodeDataInlineCssPropertyVec) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_withLabelStyle(
+            mem::transmute(self),
+            mem::transmute(label_style),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzSlider_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSlider {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::Slider = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::Slider = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSliderStateWrapper {
+    #[new]
+    fn __new__(inner: AzSliderState, on_value_change: AzOptionSliderOnValueChangeEnumWrapper, is_dragging: bool, drag_value: f32) -> Self {
+        Self {
+            inner,
+            on_value_change,
+            is_dragging,
+            drag_value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderStateWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSliderState {
+    #[new]
+    fn __new__(value: f32, min: f32, max: f32, step: f32, orientation: AzSliderOrientationEnumWrapper, tick_count: usize, show_tick_labels: bool) -> Self {
+        Self {
+            value,
+            min,
+            max,
+            step,
+            orientation,
+            tick_count,
+            show_tick_labels,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSliderOrientationEnumWrapper {
+    #[classattr]
+    fn Horizontal() -> AzSliderOrientationEnumWrapper { AzSliderOrientationEnumWrapper { inner: AzSliderOrientation::Horizontal } }
+    #[classattr]
+    fn Vertical() -> AzSliderOrientationEnumWrapper { AzSliderOrientationEnumWrapper { inner: AzSliderOrientation::Vertical } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderOrientationEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzSliderOrientationEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzSliderOnValueChange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzSliderOnValueChangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderOnValueChange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOnValueChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOnValueChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSliderOnValueChangeCallback {
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderOnValueChangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOnValueChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOnValueChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDropDown {
+    #[new]
+    fn new(choices: AzStringVec) -> AzDropDown {
+        unsafe { mem::transmute(crate::AzDropDown_new(
+            mem::transmute(choices),
+        )) }
+    }
+    fn set_selected(&mut self, selected: usize) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setSelected(
+            mem::transmute(self),
+            mem::transmute(selected),
+        )) }
+    }
+    fn with_selected(&mut self, selected: usize) -> AzDropDown {
+        unsafe { mem::transmute(crate::AzDropDown_withSelected(
+            mem::transmute(self),
+            mem::transmute(selected),
+        )) }
+    }
+    fn set_disabled_choices(&mut self, disabled_choices: AzU32Vec) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setDisabledChoices(
+            mem::transmute(self),
+            mem::transmute(disabled_choices),
+        )) }
+    }
+    fn with_disabled_choices(&mut self, disabled_choices: AzU32Vec) -> AzDropDown {
+        unsafe { mem::transmute(crate::AzDropDown_withDisabledChoices(
+            mem::transmute(self),
+            mem::transmute(disabled_choices),
+        )) }
+    }
+    fn set_popup_position(&mut self, popup_position: AzMenuPopupPositionEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setPopupPosition(
+            mem::transmute(self),
+            mem::transmute(popup_position),
+        )) }
+    }
+    fn with_popup_position(&mut self, popup_position: AzMenuPopupPositionEnumWrapper) -> AzDropDown {
+        unsafe { mem::transmute(crate::AzDropDown_withPopupPosition(
+            mem::transmute(self),
+            mem::transmute(popup_position),
+        )) }
+    }
+    fn set_container_style(&mut self, container_style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setContainerStyle(
+            mem::transmute(self),
+            mem::transmute(container_style),
+        )) }
+    }
+    fn with_container_style(&mut self, container_style: AzNodeDataInlineCssPropertyVec) -> AzDropDown {
+        unsafe { mem::transmute(crate::AzDropDown_withContainerStyle(
+            mem::transmute(self),
+            mem::transmute(container_style),
+        )) }
+    }
+    fn set_label_style(&mut self, label_style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setLabelStyle(
+            mem::transmute(self),
+            mem::transmute(label_style),
+        )) }
+    }
+    fn with_label_style(&mut self, label_style: AzNodeDataInlineCssPropertyVec) -> AzDropDown {
+        unsafe { mem::transmute(crate::AzDropDown_withLabelStyle(
+            mem::transmute(self),
+            mem::transmute(label_style),
+        )) }
+    }
+    fn set_popup_style(&mut self, popup_style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setPopupStyle(
+            mem::transmute(self),
+            mem::transmute(popup_style),
+        )) }
+    }
+    fn with_popup_style(&mut self, popup_style: AzNodeDataInlineCssPropertyVec) -> AzDropDown {
+        unsafe { mem::transmute(crate::AzDropDown_withPopupStyle(
+            mem::transmute(self),
+            mem::transmute(popup_style),
+        )) }
+    }
+    fn set_entry_style(&mut self, entry_style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setEntryStyle(
+            mem::transmute(self),
+            mem::transmute(entry_style),
+        )) }
+    }
+    fn with_entry_style(&mut self, entry_style: AzNodeDataInlineCssPropertyVec) -> AzDropDown {
+        unsafe { mem::transmute(crate::AzDropDown_withEntryStyle(
+            mem::transmute(self),
+            mem::transmute(entry_style),
+        )) }
+    }
+    fn set_disabled_entry_style(&mut self, disabled_entry_style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setDisabledEntryStyle(
+            mem::transmute(self),
+            mem::transmute(disabled_entry_style),
+        )) }
+    }
+    fn with_disabled_entry_style(&mut self, disabled_entry_style: AzNodeDataInlineCssPropertyVec) -> AzDropDown {
+        unsafe { mem::transmute(crate::AzDropDown_withDisabledEntryStyle(
+            mem::transmute(self),
+            mem::transmute(disabled_entry_style),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzDropDown_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDropDown {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDown = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDown = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDropDownStateWrapper {
+    #[new]
+    fn __new__(inner: AzDropDownState, on_choice_change: AzOptionDropDownOnChoiceChangeEnumWrapper, is_open: bool, highlighted: AzOptionUsizeEnumWrapper, search: AzString, last_search_input: AzOptionInstantEnumWrapper) -> Self {
+        Self {
+            inner,
+            on_choice_change,
+            is_open,
+            highlighted,
+            search,
+            last_search_input,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDropDownStateWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDropDownState {
+    #[new]
+    fn __new__(choices: AzStringVec, disabled_choices: AzU32Vec, selected: AzOptionUsizeEnumWrapper, popup_position: AzMenuPopupPositionEnumWrapper) -> Self {
+        Self {
+            choices,
+            disabled_choices,
+            selected,
+            popup_position,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDropDownState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDropDownOnChoiceChange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzDropDownOnChoiceChangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDropDownOnChoiceChange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownOnChoiceChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownOnChoiceChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzDropDownOnChoiceChangeCallback {
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzDropDownOnChoiceChangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownOnChoiceChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::DropDownOnChoiceChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheet {
+    #[new]
+    fn new(state: AzSpreadsheetState) -> AzSpreadsheet {
+        unsafe { mem::transmute(crate::AzSpreadsheet_new(
+            mem::transmute(state),
+        )) }
+    }
+    fn set_container_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzSpreadsheet_setContainerStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn with_container_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> AzSpreadsheet {
+        unsafe { mem::transmute(crate::AzSpreadsheet_withContainerStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzSpreadsheet_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheet {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::Spreadsheet = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::Spreadsheet = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetStateWrapper {
+    #[new]
+    fn __new__(inner: AzSpreadsheetState, on_cells_change: AzOptionSpreadsheetOnCellsChangeEnumWrapper, on_selection_change: AzOptionSpreadsheetOnSelectionChangeEnumWrapper, on_column_resize: AzOptionSpreadsheetOnColumnResizeEnumWrapper, view: AzSpreadsheetView) -> Self {
+        Self {
+            inner,
+            on_cells_change,
+            on_selection_change,
+            on_column_resize,
+            view,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetStateWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetStateWrapper = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetState {
+    #[new]
+    fn new() -> AzSpreadsheetState {
+        unsafe { mem::transmute(crate::AzSpreadsheetState_new()) }
+    }
+    fn set_cell_content(&mut self, cell: AzSpreadsheetCellIndex, value: String) -> () {
+        let value = pystring_to_azstring(&value);
+        unsafe { mem::transmute(crate::AzSpreadsheetState_setCellContent(
+            mem::transmute(self),
+            mem::transmute(cell),
+            mem::transmute(value),
+        )) }
+    }
+    fn get_cell_content(&self, cell: AzSpreadsheetCellIndex) -> Option<String> {
+        let m: AzOptionString = unsafe { mem::transmute(crate::AzSpreadsheetState_getCellContent(
+            mem::transmute(self),
+            mem::transmute(cell),
+        )) };
+        match m {
+            AzOptionString::Some(s) => Some({ let s: AzString = unsafe { mem::transmute(s) }; s.into() }),
+            AzOptionString::None => None,
+        }
+
+    }
+    fn set_selection(&mut self, selection: AzOptionSpreadsheetCellSelectionEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzSpreadsheetState_setSelection(
+            mem::transmute(self),
+            mem::transmute(selection),
+        )) }
+    }
+    fn select_all(&mut self) -> () {
+        unsafe { mem::transmute(crate::AzSpreadsheetState_selectAll(
+            mem::transmute(self),
+        )) }
+    }
+    fn get_column_width(&self, column: usize) -> f32 {
+        unsafe { mem::transmute(crate::AzSpreadsheetState_getColumnWidth(
+            mem::transmute(self),
+            mem::transmute(column),
+        )) }
+    }
+    fn get_row_height(&self, row: usize) -> f32 {
+        unsafe { mem::transmute(crate::AzSpreadsheetState_getRowHeight(
+            mem::transmute(self),
+            mem::transmute(row),
+        )) }
+    }
+    fn set_column_width(&mut self, column: usize, width: f32) -> () {
+        unsafe { mem::transmute(crate::AzSpreadsheetState_setColumnWidth(
+            mem::transmute(self),
+            mem::transmute(column),
+            mem::transmute(width),
+        )) }
+    }
+    fn set_row_height(&mut self, row: usize, height: f32) -> () {
+        unsafe { mem::transmute(crate::AzSpreadsheetState_setRowHeight(
+            mem::transmute(self),
+            mem::transmute(row),
+            mem::transmute(height),
+        )) }
+    }
+    fn get_table_size(&self) -> AzLogicalSize {
+        unsafe { mem::transmute(crate::AzSpreadsheetState_getTableSize(
+            mem::transmute(self),
+        )) }
+    }
+    fn get_cell_rect(&self, cell: AzSpreadsheetCellIndex) -> AzLogicalRect {
+        unsafe { mem::transmute(crate::AzSpreadsheetState_getCellRect(
+            mem::transmute(self),
+            mem::transmute(cell),
+        )) }
+    }
+    fn get_selection_tsv(&self, selection: AzSpreadsheetCellSelection) -> String {
+        az_string_to_py_string(unsafe { mem::transmute(crate::AzSpreadsheetState_getSelectionTsv(
+            mem::transmute(self),
+            mem::transmute(selection),
+        )) })
+    }
+    fn paste_tsv(&mut self, tsv: String) -> Option<AzSpreadsheetCellSelection> {
+        let tsv = pystring_to_azstring(&tsv);
+        let m: AzOptionSpreadsheetCellSelection = unsafe { mem::transmute(crate::AzSpreadsheetState_pasteTsv(
+            mem::transmute(self),
+            mem::transmute(tsv),
+        )) };
+        match m {
+            AzOptionSpreadsheetCellSelection::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionSpreadsheetCellSelection::None => None,
+        }
+
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetStyle {
+    #[new]
+    fn __new__(accent_color: AzColorU) -> Self {
+        Self {
+            accent_color,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetStyle {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetStyle = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetStyle = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetCellIndex {
+    #[new]
+    fn __new__(row: usize, column: usize) -> Self {
+        Self {
+            row,
+            column,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetCellIndex {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellIndex = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellIndex = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetCellSelection {
+    #[staticmethod]
+    fn from(row: usize, column: usize) -> AzSpreadsheetCellSelection {
+        unsafe { mem::transmute(crate::AzSpreadsheetCellSelection_from(
+            mem::transmute(row),
+            mem::transmute(column),
+        )) }
+    }
+    fn to(self, row: usize, column: usize) -> AzSpreadsheetCellSelection {
+        unsafe { mem::transmute(crate::AzSpreadsheetCellSelection_to(
+            mem::transmute(self),
+            mem::transmute(row),
+            mem::transmute(column),
+        )) }
+    }
+    fn number_of_rows_selected(&self) -> usize {
+        unsafe { mem::transmute(crate::AzSpreadsheetCellSelection_numberOfRowsSelected(
+            mem::transmute(self),
+        )) }
+    }
+    fn number_of_columns_selected(&self) -> usize {
+        unsafe { mem::transmute(crate::AzSpreadsheetCellSelection_numberOfColumnsSelected(
+            mem::transmute(self),
+        )) }
+    }
+    fn get_top_left(&self) -> AzSpreadsheetCellIndex {
+        unsafe { mem::transmute(crate::AzSpreadsheetCellSelection_getTopLeft(
+            mem::transmute(self),
+        )) }
+    }
+    fn get_bottom_right(&self) -> AzSpreadsheetCellIndex {
+        unsafe { mem::transmute(crate::AzSpreadsheetCellSelection_getBottomRight(
+            mem::transmute(self),
+        )) }
+    }
+    fn contains(&self, cell: AzSpreadsheetCellIndex) -> bool {
+        unsafe { mem::transmute(crate::AzSpreadsheetCellSelection_contains(
+            mem::transmute(self),
+            mem::transmute(cell),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetCellSelection {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellSelection = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellSelection = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetSizeOverride {
+    #[new]
+    fn __new__(index: usize, size: f32) -> Self {
+        Self {
+            index,
+            size,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetSizeOverride {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetSizeOverride = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetSizeOverride = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetCellContent {
+    #[new]
+    fn __new__(cell: AzSpreadsheetCellIndex, text: AzString) -> Self {
+        Self {
+            cell,
+            text,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetCellContent {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellContent = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellContent = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetView {
+    #[new]
+    fn __new__(rendered: AzSpreadsheetRenderWindow, iframe_node: AzOptionDomNodeIdEnumWrapper, drag: AzSpreadsheetDragEnumWrapper, last_click: AzOptionSpreadsheetCellClickEnumWrapper, editing: AzOptionSpreadsheetCellEditEnumWrapper) -> Self {
+        Self {
+            rendered,
+            iframe_node,
+            drag,
+            last_click,
+            editing,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetView {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetView = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetView = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetRenderWindow {
+    #[new]
+    fn __new__(row_start: usize, row_end: usize, column_start: usize, column_end: usize, origin: AzLogicalPosition, scroll_position: AzLogicalPosition, viewport: AzLogicalSize) -> Self {
+        Self {
+            row_start,
+            row_end,
+            column_start,
+            column_end,
+            origin,
+            scroll_position,
+            viewport,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetRenderWindow {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetRenderWindow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetRenderWindow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetDragEnumWrapper {
+    #[classattr]
+    fn None() -> AzSpreadsheetDragEnumWrapper { AzSpreadsheetDragEnumWrapper { inner: AzSpreadsheetDrag::None } }
+    #[classattr]
+    fn SelectCells() -> AzSpreadsheetDragEnumWrapper { AzSpreadsheetDragEnumWrapper { inner: AzSpreadsheetDrag::SelectCells } }
+    #[staticmethod]
+    fn ResizeColumn(v: AzSpreadsheetColumnResize) -> AzSpreadsheetDragEnumWrapper { AzSpreadsheetDragEnumWrapper { inner: AzSpreadsheetDrag::ResizeColumn(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzSpreadsheetDrag;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzSpreadsheetDrag::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzSpreadsheetDrag::SelectCells => Ok(vec!["SelectCells".into_py(py), ().into_py(py)]),
+            AzSpreadsheetDrag::ResizeColumn(v) => Ok(vec!["ResizeColumn".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetDragEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetDrag = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetDrag = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetColumnResize {
+    #[new]
+    fn __new__(column: usize, width: f32) -> Self {
+        Self {
+            column,
+            width,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetColumnResize {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetColumnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetColumnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetCellClick {
+    #[new]
+    fn __new__(cell: AzSpreadsheetCellIndex, time: AzInstantEnumWrapper) -> Self {
+        Self {
+            cell,
+            time,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetCellClick {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellClick = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellClick = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetCellEdit {
+    #[new]
+    fn __new__(cell: AzSpreadsheetCellIndex, text: AzString) -> Self {
+        Self {
+            cell,
+            text,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetCellEdit {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellEdit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellEdit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetOnCellsChange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzSpreadsheetOnCellsChangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetOnCellsChange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetOnCellsChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetOnCellsChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetOnCellsChangeCallback {
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetOnCellsChangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetOnCellsChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetOnCellsChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetOnSelectionChange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzSpreadsheetOnSelectionChangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetOnSelectionChange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetOnSelectionChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetOnSelectionChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetOnSelectionChangeCallback {
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetOnSelectionChangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetOnSelectionChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetOnSelectionChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetOnColumnResize {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzSpreadsheetOnColumnResizeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetOnColumnResize {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetOnColumnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetOnColumnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetOnColumnResizeCallback {
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetOnColumnResizeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetOnColumnResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetOnColumnResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzNode {
     #[new]
@@ -30822,6 +36304,31 @@ impl PyObjectProtocol for AzAccessibilityStateVec {
     }
 }
 
+#[pymethods]
+impl AzAccessibilityNodeVec {
+    /// Creates a new `AccessibilityNodeVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzAccessibilityNode>) -> Self {
+        let m: azul_core::accessibility::AccessibilityNodeVec = azul_core::accessibility::AccessibilityNodeVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the AccessibilityNode as a Python array
+    fn array(&self) -> Vec<AzAccessibilityNode> {
+        let m: &azul_core::accessibility::AccessibilityNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzAccessibilityNodeVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::accessibility::AccessibilityNodeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::accessibility::AccessibilityNodeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMenuItemVec {
     /// Creates a new `MenuItemEnumWrapperVec` from a Python array
@@ -31527,6 +37034,31 @@ impl PyObjectProtocol for AzVirtualKeyCodeVec {
     }
 }
 
+#[pymethods]
+impl AzKeyboardShortcutVec {
+    /// Creates a new `KeyboardShortcutVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzKeyboardShortcut>) -> Self {
+        let m: azul_core::window::KeyboardShortcutVec = azul_core::window::KeyboardShortcutVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the KeyboardShortcut as a Python array
+    fn array(&self) -> Vec<AzKeyboardShortcut> {
+        let m: &azul_core::window::KeyboardShortcutVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzKeyboardShortcutVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::KeyboardShortcutVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::KeyboardShortcutVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCascadeInfoVec {
     /// Creates a new `CascadeInfoVec` from a Python array
@@ -31652,6 +37184,406 @@ impl PyObjectProtocol for AzStylesheetVec {
     }
 }
 
+#[pymethods]
+impl AzCssMediaQueryVec {
+    /// Creates a new `CssMediaQueryVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssMediaQuery>) -> Self {
+        let m: azul_impl::css::CssMediaQueryVec = azul_impl::css::CssMediaQueryVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssMediaQuery as a Python array
+    fn array(&self) -> Vec<AzCssMediaQuery> {
+        let m: &azul_impl::css::CssMediaQueryVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaQueryVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaQueryVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaQueryVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaConditionVec {
+    /// Creates a new `CssMediaConditionEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssMediaConditionEnumWrapper>) -> Self {
+        let m: azul_impl::css::CssMediaConditionVec = azul_impl::css::CssMediaConditionVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssMediaConditionEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzCssMediaConditionEnumWrapper> {
+        let m: &azul_impl::css::CssMediaConditionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaConditionVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaConditionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaConditionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssFontFaceVec {
+    /// Creates a new `CssFontFaceVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssFontFace>) -> Self {
+        let m: azul_impl::css::CssFontFaceVec = azul_impl::css::CssFontFaceVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssFontFace as a Python array
+    fn array(&self) -> Vec<AzCssFontFace> {
+        let m: &azul_impl::css::CssFontFaceVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssFontFaceVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionPropertyVec {
+    /// Creates a new `StyleTransitionPropertyEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleTransitionPropertyEnumWrapper>) -> Self {
+        let m: azul_impl::css::StyleTransitionPropertyVec = azul_impl::css::StyleTransitionPropertyVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleTransitionPropertyEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzStyleTransitionPropertyEnumWrapper> {
+        let m: &azul_impl::css::StyleTransitionPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionPropertyVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionDurationVec {
+    /// Creates a new `StyleTransitionDurationVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleTransitionDuration>) -> Self {
+        let m: azul_impl::css::StyleTransitionDurationVec = azul_impl::css::StyleTransitionDurationVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleTransitionDuration as a Python array
+    fn array(&self) -> Vec<AzStyleTransitionDuration> {
+        let m: &azul_impl::css::StyleTransitionDurationVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionDurationVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDurationVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDurationVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionTimingFunctionVec {
+    /// Creates a new `StyleTransitionTimingFunctionEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleTransitionTimingFunctionEnumWrapper>) -> Self {
+        let m: azul_impl::css::StyleTransitionTimingFunctionVec = azul_impl::css::StyleTransitionTimingFunctionVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleTransitionTimingFunctionEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzStyleTransitionTimingFunctionEnumWrapper> {
+        let m: &azul_impl::css::StyleTransitionTimingFunctionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionTimingFunctionVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionTimingFunctionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionTimingFunctionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionDelayVec {
+    /// Creates a new `StyleTransitionDelayVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleTransitionDelay>) -> Self {
+        let m: azul_impl::css::StyleTransitionDelayVec = azul_impl::css::StyleTransitionDelayVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleTransitionDelay as a Python array
+    fn array(&self) -> Vec<AzStyleTransitionDelay> {
+        let m: &azul_impl::css::StyleTransitionDelayVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionDelayVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDelayVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDelayVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationNameVec {
+    /// Creates a new `StyleAnimationNameVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleAnimationName>) -> Self {
+        let m: azul_impl::css::StyleAnimationNameVec = azul_impl::css::StyleAnimationNameVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleAnimationName as a Python array
+    fn array(&self) -> Vec<AzStyleAnimationName> {
+        let m: &azul_impl::css::StyleAnimationNameVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationNameVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationNameVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationNameVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDurationVec {
+    /// Creates a new `StyleAnimationDurationVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleAnimationDuration>) -> Self {
+        let m: azul_impl::css::StyleAnimationDurationVec = azul_impl::css::StyleAnimationDurationVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleAnimationDuration as a Python array
+    fn array(&self) -> Vec<AzStyleAnimationDuration> {
+        let m: &azul_impl::css::StyleAnimationDurationVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDurationVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDurationVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDurationVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationIterationCountVec {
+    /// Creates a new `StyleAnimationIterationCountEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleAnimationIterationCountEnumWrapper>) -> Self {
+        let m: azul_impl::css::StyleAnimationIterationCountVec = azul_impl::css::StyleAnimationIterationCountVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleAnimationIterationCountEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzStyleAnimationIterationCountEnumWrapper> {
+        let m: &azul_impl::css::StyleAnimationIterationCountVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationIterationCountVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCountVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCountVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDirectionVec {
+    /// Creates a new `StyleAnimationDirectionEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleAnimationDirectionEnumWrapper>) -> Self {
+        let m: azul_impl::css::StyleAnimationDirectionVec = azul_impl::css::StyleAnimationDirectionVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleAnimationDirectionEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzStyleAnimationDirectionEnumWrapper> {
+        let m: &azul_impl::css::StyleAnimationDirectionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDirectionVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirectionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirectionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationFillModeVec {
+    /// Creates a new `StyleAnimationFillModeEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleAnimationFillModeEnumWrapper>) -> Self {
+        let m: azul_impl::css::StyleAnimationFillModeVec = azul_impl::css::StyleAnimationFillModeVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleAnimationFillModeEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzStyleAnimationFillModeEnumWrapper> {
+        let m: &azul_impl::css::StyleAnimationFillModeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationFillModeVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillModeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillModeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDelayVec {
+    /// Creates a new `StyleAnimationDelayVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleAnimationDelay>) -> Self {
+        let m: azul_impl::css::StyleAnimationDelayVec = azul_impl::css::StyleAnimationDelayVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleAnimationDelay as a Python array
+    fn array(&self) -> Vec<AzStyleAnimationDelay> {
+        let m: &azul_impl::css::StyleAnimationDelayVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDelayVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDelayVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDelayVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssKeyframeVec {
+    /// Creates a new `CssKeyframeVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssKeyframe>) -> Self {
+        let m: azul_impl::css::CssKeyframeVec = azul_impl::css::CssKeyframeVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssKeyframe as a Python array
+    fn array(&self) -> Vec<AzCssKeyframe> {
+        let m: &azul_impl::css::CssKeyframeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssKeyframeVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssKeyframesVec {
+    /// Creates a new `CssKeyframesVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssKeyframes>) -> Self {
+        let m: azul_impl::css::CssKeyframesVec = azul_impl::css::CssKeyframesVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssKeyframes as a Python array
+    fn array(&self) -> Vec<AzCssKeyframes> {
+        let m: &azul_impl::css::CssKeyframesVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssKeyframesVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframesVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframesVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGridTrackSizingVec {
+    /// Creates a new `GridTrackSizingEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzGridTrackSizingEnumWrapper>) -> Self {
+        let m: azul_impl::css::GridTrackSizingVec = azul_impl::css::GridTrackSizingVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the GridTrackSizingEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzGridTrackSizingEnumWrapper> {
+        let m: &azul_impl::css::GridTrackSizingVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridTrackSizingVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizingVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizingVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssRuleBlockVec {
     /// Creates a new `CssRuleBlockVec` from a Python array
@@ -32091,6 +38023,81 @@ impl PyObjectProtocol for AzNodeDataVec {
     }
 }
 
+#[pymethods]
+impl AzTextEditSnapshotVec {
+    /// Creates a new `TextEditSnapshotVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTextEditSnapshot>) -> Self {
+        let m: crate::widgets::text_edit::TextEditSnapshotVec = crate::widgets::text_edit::TextEditSnapshotVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TextEditSnapshot as a Python array
+    fn array(&self) -> Vec<AzTextEditSnapshot> {
+        let m: &crate::widgets::text_edit::TextEditSnapshotVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditSnapshotVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditSnapshotVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditSnapshotVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetSizeOverrideVec {
+    /// Creates a new `SpreadsheetSizeOverrideVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzSpreadsheetSizeOverride>) -> Self {
+        let m: crate::widgets::spreadsheet::SpreadsheetSizeOverrideVec = crate::widgets::spreadsheet::SpreadsheetSizeOverrideVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the SpreadsheetSizeOverride as a Python array
+    fn array(&self) -> Vec<AzSpreadsheetSizeOverride> {
+        let m: &crate::widgets::spreadsheet::SpreadsheetSizeOverrideVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetSizeOverrideVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetSizeOverrideVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetSizeOverrideVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetCellContentVec {
+    /// Creates a new `SpreadsheetCellContentVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzSpreadsheetCellContent>) -> Self {
+        let m: crate::widgets::spreadsheet::SpreadsheetCellContentVec = crate::widgets::spreadsheet::SpreadsheetCellContentVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the SpreadsheetCellContent as a Python array
+    fn array(&self) -> Vec<AzSpreadsheetCellContent> {
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellContentVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetCellContentVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellContentVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellContentVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleFontFamilyVecDestructorEnumWrapper {
     #[classattr]
@@ -32151,6 +38158,36 @@ impl PyObjectProtocol for AzAccessibilityStateVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzAccessibilityNodeVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzAccessibilityNodeVecDestructorEnumWrapper { AzAccessibilityNodeVecDestructorEnumWrapper { inner: AzAccessibilityNodeVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzAccessibilityNodeVecDestructorEnumWrapper { AzAccessibilityNodeVecDestructorEnumWrapper { inner: AzAccessibilityNodeVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzAccessibilityNodeVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzAccessibilityNodeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzAccessibilityNodeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzAccessibilityNodeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzAccessibilityNodeVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::accessibility::AccessibilityNodeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::accessibility::AccessibilityNodeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMenuItemVecDestructorEnumWrapper {
     #[classattr]
@@ -32961,6 +38998,36 @@ impl PyObjectProtocol for AzVirtualKeyCodeVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzKeyboardShortcutVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzKeyboardShortcutVecDestructorEnumWrapper { AzKeyboardShortcutVecDestructorEnumWrapper { inner: AzKeyboardShortcutVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzKeyboardShortcutVecDestructorEnumWrapper { AzKeyboardShortcutVecDestructorEnumWrapper { inner: AzKeyboardShortcutVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzKeyboardShortcutVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzKeyboardShortcutVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzKeyboardShortcutVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzKeyboardShortcutVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzKeyboardShortcutVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::KeyboardShortcutVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::KeyboardShortcutVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCascadeInfoVecDestructorEnumWrapper {
     #[classattr]
@@ -33111,6 +39178,486 @@ impl PyObjectProtocol for AzStylesheetVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzCssFontFaceVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssFontFaceVecDestructorEnumWrapper { AzCssFontFaceVecDestructorEnumWrapper { inner: AzCssFontFaceVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssFontFaceVecDestructorEnumWrapper { AzCssFontFaceVecDestructorEnumWrapper { inner: AzCssFontFaceVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssFontFaceVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssFontFaceVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssFontFaceVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssFontFaceVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssFontFaceVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssFontFaceVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionPropertyVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleTransitionPropertyVecDestructorEnumWrapper { AzStyleTransitionPropertyVecDestructorEnumWrapper { inner: AzStyleTransitionPropertyVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleTransitionPropertyVecDestructorEnumWrapper { AzStyleTransitionPropertyVecDestructorEnumWrapper { inner: AzStyleTransitionPropertyVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionPropertyVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionPropertyVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionPropertyVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionDurationVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleTransitionDurationVecDestructorEnumWrapper { AzStyleTransitionDurationVecDestructorEnumWrapper { inner: AzStyleTransitionDurationVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleTransitionDurationVecDestructorEnumWrapper { AzStyleTransitionDurationVecDestructorEnumWrapper { inner: AzStyleTransitionDurationVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionDurationVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionDurationVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDurationVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDurationVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionDurationVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDurationVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDurationVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionTimingFunctionVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleTransitionTimingFunctionVecDestructorEnumWrapper { AzStyleTransitionTimingFunctionVecDestructorEnumWrapper { inner: AzStyleTransitionTimingFunctionVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleTransitionTimingFunctionVecDestructorEnumWrapper { AzStyleTransitionTimingFunctionVecDestructorEnumWrapper { inner: AzStyleTransitionTimingFunctionVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionTimingFunctionVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionTimingFunctionVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunctionVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunctionVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionTimingFunctionVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionTimingFunctionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionTimingFunctionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionDelayVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleTransitionDelayVecDestructorEnumWrapper { AzStyleTransitionDelayVecDestructorEnumWrapper { inner: AzStyleTransitionDelayVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleTransitionDelayVecDestructorEnumWrapper { AzStyleTransitionDelayVecDestructorEnumWrapper { inner: AzStyleTransitionDelayVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionDelayVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionDelayVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDelayVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDelayVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionDelayVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDelayVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDelayVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationNameVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleAnimationNameVecDestructorEnumWrapper { AzStyleAnimationNameVecDestructorEnumWrapper { inner: AzStyleAnimationNameVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleAnimationNameVecDestructorEnumWrapper { AzStyleAnimationNameVecDestructorEnumWrapper { inner: AzStyleAnimationNameVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationNameVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationNameVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleAnimationNameVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleAnimationNameVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationNameVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationNameVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationNameVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDurationVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleAnimationDurationVecDestructorEnumWrapper { AzStyleAnimationDurationVecDestructorEnumWrapper { inner: AzStyleAnimationDurationVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleAnimationDurationVecDestructorEnumWrapper { AzStyleAnimationDurationVecDestructorEnumWrapper { inner: AzStyleAnimationDurationVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationDurationVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationDurationVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDurationVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDurationVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDurationVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDurationVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDurationVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationIterationCountVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleAnimationIterationCountVecDestructorEnumWrapper { AzStyleAnimationIterationCountVecDestructorEnumWrapper { inner: AzStyleAnimationIterationCountVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleAnimationIterationCountVecDestructorEnumWrapper { AzStyleAnimationIterationCountVecDestructorEnumWrapper { inner: AzStyleAnimationIterationCountVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationIterationCountVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationIterationCountVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleAnimationIterationCountVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleAnimationIterationCountVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationIterationCountVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCountVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCountVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDirectionVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleAnimationDirectionVecDestructorEnumWrapper { AzStyleAnimationDirectionVecDestructorEnumWrapper { inner: AzStyleAnimationDirectionVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleAnimationDirectionVecDestructorEnumWrapper { AzStyleAnimationDirectionVecDestructorEnumWrapper { inner: AzStyleAnimationDirectionVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationDirectionVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationDirectionVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDirectionVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDirectionVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDirectionVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirectionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirectionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationFillModeVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleAnimationFillModeVecDestructorEnumWrapper { AzStyleAnimationFillModeVecDestructorEnumWrapper { inner: AzStyleAnimationFillModeVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleAnimationFillModeVecDestructorEnumWrapper { AzStyleAnimationFillModeVecDestructorEnumWrapper { inner: AzStyleAnimationFillModeVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationFillModeVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationFillModeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleAnimationFillModeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleAnimationFillModeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationFillModeVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillModeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillModeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDelayVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleAnimationDelayVecDestructorEnumWrapper { AzStyleAnimationDelayVecDestructorEnumWrapper { inner: AzStyleAnimationDelayVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleAnimationDelayVecDestructorEnumWrapper { AzStyleAnimationDelayVecDestructorEnumWrapper { inner: AzStyleAnimationDelayVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationDelayVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationDelayVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDelayVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDelayVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDelayVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDelayVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDelayVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssKeyframeVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssKeyframeVecDestructorEnumWrapper { AzCssKeyframeVecDestructorEnumWrapper { inner: AzCssKeyframeVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssKeyframeVecDestructorEnumWrapper { AzCssKeyframeVecDestructorEnumWrapper { inner: AzCssKeyframeVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssKeyframeVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssKeyframeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssKeyframeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssKeyframeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssKeyframeVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssKeyframesVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssKeyframesVecDestructorEnumWrapper { AzCssKeyframesVecDestructorEnumWrapper { inner: AzCssKeyframesVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssKeyframesVecDestructorEnumWrapper { AzCssKeyframesVecDestructorEnumWrapper { inner: AzCssKeyframesVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssKeyframesVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssKeyframesVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssKeyframesVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssKeyframesVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssKeyframesVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframesVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframesVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGridTrackSizingVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzGridTrackSizingVecDestructorEnumWrapper { AzGridTrackSizingVecDestructorEnumWrapper { inner: AzGridTrackSizingVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzGridTrackSizingVecDestructorEnumWrapper { AzGridTrackSizingVecDestructorEnumWrapper { inner: AzGridTrackSizingVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzGridTrackSizingVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzGridTrackSizingVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzGridTrackSizingVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzGridTrackSizingVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridTrackSizingVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizingVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizingVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaQueryVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssMediaQueryVecDestructorEnumWrapper { AzCssMediaQueryVecDestructorEnumWrapper { inner: AzCssMediaQueryVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssMediaQueryVecDestructorEnumWrapper { AzCssMediaQueryVecDestructorEnumWrapper { inner: AzCssMediaQueryVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssMediaQueryVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssMediaQueryVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssMediaQueryVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssMediaQueryVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaQueryVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaQueryVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaQueryVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaConditionVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssMediaConditionVecDestructorEnumWrapper { AzCssMediaConditionVecDestructorEnumWrapper { inner: AzCssMediaConditionVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssMediaConditionVecDestructorEnumWrapper { AzCssMediaConditionVecDestructorEnumWrapper { inner: AzCssMediaConditionVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssMediaConditionVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssMediaConditionVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssMediaConditionVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssMediaConditionVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaConditionVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaConditionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaConditionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssRuleBlockVecDestructorEnumWrapper {
     #[classattr]
@@ -33651,6 +40198,96 @@ impl PyObjectProtocol for AzNodeDataVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzTextEditSnapshotVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTextEditSnapshotVecDestructorEnumWrapper { AzTextEditSnapshotVecDestructorEnumWrapper { inner: AzTextEditSnapshotVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTextEditSnapshotVecDestructorEnumWrapper { AzTextEditSnapshotVecDestructorEnumWrapper { inner: AzTextEditSnapshotVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTextEditSnapshotVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTextEditSnapshotVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTextEditSnapshotVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTextEditSnapshotVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextEditSnapshotVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditSnapshotVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::TextEditSnapshotVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetSizeOverrideVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzSpreadsheetSizeOverrideVecDestructorEnumWrapper { AzSpreadsheetSizeOverrideVecDestructorEnumWrapper { inner: AzSpreadsheetSizeOverrideVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzSpreadsheetSizeOverrideVecDestructorEnumWrapper { AzSpreadsheetSizeOverrideVecDestructorEnumWrapper { inner: AzSpreadsheetSizeOverrideVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzSpreadsheetSizeOverrideVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzSpreadsheetSizeOverrideVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzSpreadsheetSizeOverrideVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzSpreadsheetSizeOverrideVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetSizeOverrideVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetSizeOverrideVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetSizeOverrideVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSpreadsheetCellContentVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzSpreadsheetCellContentVecDestructorEnumWrapper { AzSpreadsheetCellContentVecDestructorEnumWrapper { inner: AzSpreadsheetCellContentVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzSpreadsheetCellContentVecDestructorEnumWrapper { AzSpreadsheetCellContentVecDestructorEnumWrapper { inner: AzSpreadsheetCellContentVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzSpreadsheetCellContentVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzSpreadsheetCellContentVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzSpreadsheetCellContentVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzSpreadsheetCellContentVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpreadsheetCellContentVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellContentVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::SpreadsheetCellContentVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionColorInputOnValueChangeEnumWrapper {
     #[classattr]
@@ -33883,6 +40520,354 @@ impl PyObjectProtocol for AzOptionNumberInputOnValueChangeEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionTextEditOnTextChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTextEditOnTextChangeEnumWrapper { AzOptionTextEditOnTextChangeEnumWrapper { inner: AzOptionTextEditOnTextChange::None } }
+    #[staticmethod]
+    fn Some(v: AzTextEditOnTextChange) -> AzOptionTextEditOnTextChangeEnumWrapper { AzOptionTextEditOnTextChangeEnumWrapper { inner: AzOptionTextEditOnTextChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTextEditOnTextChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTextEditOnTextChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTextEditOnTextChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTextEditOnTextChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::OptionTextEditOnTextChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::OptionTextEditOnTextChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTextEditOnFocusLostEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTextEditOnFocusLostEnumWrapper { AzOptionTextEditOnFocusLostEnumWrapper { inner: AzOptionTextEditOnFocusLost::None } }
+    #[staticmethod]
+    fn Some(v: AzTextEditOnFocusLost) -> AzOptionTextEditOnFocusLostEnumWrapper { AzOptionTextEditOnFocusLostEnumWrapper { inner: AzOptionTextEditOnFocusLost::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTextEditOnFocusLost;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTextEditOnFocusLost::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTextEditOnFocusLost::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTextEditOnFocusLostEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::OptionTextEditOnFocusLost = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::OptionTextEditOnFocusLost = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTextEditKindEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTextEditKindEnumWrapper { AzOptionTextEditKindEnumWrapper { inner: AzOptionTextEditKind::None } }
+    #[staticmethod]
+    fn Some(v: AzTextEditKindEnumWrapper) -> AzOptionTextEditKindEnumWrapper { AzOptionTextEditKindEnumWrapper { inner: AzOptionTextEditKind::Some(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTextEditKind;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTextEditKind::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTextEditKind::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzTextEditKindEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTextEditKindEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::OptionTextEditKind = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_edit::OptionTextEditKind = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionSpreadsheetCellClickEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionSpreadsheetCellClickEnumWrapper { AzOptionSpreadsheetCellClickEnumWrapper { inner: AzOptionSpreadsheetCellClick::None } }
+    #[staticmethod]
+    fn Some(v: AzSpreadsheetCellClick) -> AzOptionSpreadsheetCellClickEnumWrapper { AzOptionSpreadsheetCellClickEnumWrapper { inner: AzOptionSpreadsheetCellClick::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionSpreadsheetCellClick;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionSpreadsheetCellClick::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionSpreadsheetCellClick::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionSpreadsheetCellClickEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionSpreadsheetCellClick = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionSpreadsheetCellClick = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionSpreadsheetCellEditEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionSpreadsheetCellEditEnumWrapper { AzOptionSpreadsheetCellEditEnumWrapper { inner: AzOptionSpreadsheetCellEdit::None } }
+    #[staticmethod]
+    fn Some(v: AzSpreadsheetCellEdit) -> AzOptionSpreadsheetCellEditEnumWrapper { AzOptionSpreadsheetCellEditEnumWrapper { inner: AzOptionSpreadsheetCellEdit::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionSpreadsheetCellEdit;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionSpreadsheetCellEdit::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionSpreadsheetCellEdit::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionSpreadsheetCellEditEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionSpreadsheetCellEdit = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionSpreadsheetCellEdit = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionSliderOnValueChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionSliderOnValueChangeEnumWrapper { AzOptionSliderOnValueChangeEnumWrapper { inner: AzOptionSliderOnValueChange::None } }
+    #[staticmethod]
+    fn Some(v: AzSliderOnValueChange) -> AzOptionSliderOnValueChangeEnumWrapper { AzOptionSliderOnValueChangeEnumWrapper { inner: AzOptionSliderOnValueChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionSliderOnValueChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionSliderOnValueChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionSliderOnValueChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionSliderOnValueChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::OptionSliderOnValueChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::OptionSliderOnValueChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionDropDownOnChoiceChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionDropDownOnChoiceChangeEnumWrapper { AzOptionDropDownOnChoiceChangeEnumWrapper { inner: AzOptionDropDownOnChoiceChange::None } }
+    #[staticmethod]
+    fn Some(v: AzDropDownOnChoiceChange) -> AzOptionDropDownOnChoiceChangeEnumWrapper { AzOptionDropDownOnChoiceChangeEnumWrapper { inner: AzOptionDropDownOnChoiceChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionDropDownOnChoiceChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionDropDownOnChoiceChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionDropDownOnChoiceChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionDropDownOnChoiceChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::OptionDropDownOnChoiceChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::drop_down::OptionDropDownOnChoiceChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionSpreadsheetOnCellsChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionSpreadsheetOnCellsChangeEnumWrapper { AzOptionSpreadsheetOnCellsChangeEnumWrapper { inner: AzOptionSpreadsheetOnCellsChange::None } }
+    #[staticmethod]
+    fn Some(v: AzSpreadsheetOnCellsChange) -> AzOptionSpreadsheetOnCellsChangeEnumWrapper { AzOptionSpreadsheetOnCellsChangeEnumWrapper { inner: AzOptionSpreadsheetOnCellsChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionSpreadsheetOnCellsChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionSpreadsheetOnCellsChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionSpreadsheetOnCellsChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionSpreadsheetOnCellsChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionSpreadsheetOnCellsChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionSpreadsheetOnCellsChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionSpreadsheetOnSelectionChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionSpreadsheetOnSelectionChangeEnumWrapper { AzOptionSpreadsheetOnSelectionChangeEnumWrapper { inner: AzOptionSpreadsheetOnSelectionChange::None } }
+    #[staticmethod]
+    fn Some(v: AzSpreadsheetOnSelectionChange) -> AzOptionSpreadsheetOnSelectionChangeEnumWrapper { AzOptionSpreadsheetOnSelectionChangeEnumWrapper { inner: AzOptionSpreadsheetOnSelectionChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionSpreadsheetOnSelectionChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionSpreadsheetOnSelectionChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionSpreadsheetOnSelectionChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionSpreadsheetOnSelectionChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionSpreadsheetOnSelectionChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionSpreadsheetOnSelectionChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionSpreadsheetOnColumnResizeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionSpreadsheetOnColumnResizeEnumWrapper { AzOptionSpreadsheetOnColumnResizeEnumWrapper { inner: AzOptionSpreadsheetOnColumnResize::None } }
+    #[staticmethod]
+    fn Some(v: AzSpreadsheetOnColumnResize) -> AzOptionSpreadsheetOnColumnResizeEnumWrapper { AzOptionSpreadsheetOnColumnResizeEnumWrapper { inner: AzOptionSpreadsheetOnColumnResize::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionSpreadsheetOnColumnResize;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionSpreadsheetOnColumnResize::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionSpreadsheetOnColumnResize::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionSpreadsheetOnColumnResizeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionSpreadsheetOnColumnResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionSpreadsheetOnColumnResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionSpreadsheetCellSelectionEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionSpreadsheetCellSelectionEnumWrapper { AzOptionSpreadsheetCellSelectionEnumWrapper { inner: AzOptionSpreadsheetCellSelection::None } }
+    #[staticmethod]
+    fn Some(v: AzSpreadsheetCellSelection) -> AzOptionSpreadsheetCellSelectionEnumWrapper { AzOptionSpreadsheetCellSelectionEnumWrapper { inner: AzOptionSpreadsheetCellSelection::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionSpreadsheetCellSelection;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionSpreadsheetCellSelection::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionSpreadsheetCellSelection::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionSpreadsheetCellSelectionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionSpreadsheetCellSelection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spreadsheet::OptionSpreadsheetCellSelection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionAccessibilityNodeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionAccessibilityNodeEnumWrapper { AzOptionAccessibilityNodeEnumWrapper { inner: AzOptionAccessibilityNode::None } }
+    #[staticmethod]
+    fn Some(v: AzAccessibilityNode) -> AzOptionAccessibilityNodeEnumWrapper { AzOptionAccessibilityNodeEnumWrapper { inner: AzOptionAccessibilityNode::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionAccessibilityNode;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionAccessibilityNode::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionAccessibilityNode::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionAccessibilityNodeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::accessibility::OptionAccessibilityNode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::accessibility::OptionAccessibilityNode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionMenuItemIconEnumWrapper {
     #[classattr]
@@ -36476,6 +43461,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzAccessibilityInfo>()?;
     m.add_class::<AzAccessibilityRoleEnumWrapper>()?;
     m.add_class::<AzAccessibilityStateEnumWrapper>()?;
+    m.add_class::<AzAccessibilityNode>()?;
+    m.add_class::<AzAccessibilityTree>()?;
     m.add_class::<AzTabIndexEnumWrapper>()?;
     m.add_class::<AzIdOrClassEnumWrapper>()?;
     m.add_class::<AzNodeDataInlineCssPropertyEnumWrapper>()?;
@@ -36486,20 +43473,33 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStringMenuItem>()?;
     m.add_class::<AzVirtualKeyCodeCombo>()?;
     m.add_class::<AzMenuCallback>()?;
+    m.add_class::<AzKeyboardShortcut>()?;
+    m.add_class::<AzShortcutConflictEnumWrapper>()?;
     m.add_class::<AzMenuItemIconEnumWrapper>()?;
     m.add_class::<AzMenuItemStateEnumWrapper>()?;
 
     m.add_class::<AzCssRuleBlock>()?;
+    m.add_class::<AzCssMediaQuery>()?;
+    m.add_class::<AzCssMediaConditionEnumWrapper>()?;
+    m.add_class::<AzCssMediaOrientationEnumWrapper>()?;
+    m.add_class::<AzCssMediaColorSchemeEnumWrapper>()?;
     m.add_class::<AzCssDeclarationEnumWrapper>()?;
     m.add_class::<AzDynamicCssProperty>()?;
+    m.add_class::<AzCssCustomProperty>()?;
     m.add_class::<AzCssPath>()?;
     m.add_class::<AzCssPathSelectorEnumWrapper>()?;
     m.add_class::<AzNodeTypeKeyEnumWrapper>()?;
     m.add_class::<AzCssPathPseudoSelectorEnumWrapper>()?;
+    m.add_class::<AzCssAttributeSelector>()?;
+    m.add_class::<AzCssAttributeSelectorOpEnumWrapper>()?;
     m.add_class::<AzCssNthChildSelectorEnumWrapper>()?;
     m.add_class::<AzCssNthChildPattern>()?;
     m.add_class::<AzStylesheet>()?;
     m.add_class::<AzCss>()?;
+    m.add_class::<AzCssFontFace>()?;
+    m.add_class::<AzCssFontFaceStyleEnumWrapper>()?;
+    m.add_class::<AzCssKeyframes>()?;
+    m.add_class::<AzCssKeyframe>()?;
     m.add_class::<AzCssPropertyTypeEnumWrapper>()?;
     m.add_class::<AzAnimationInterpolationFunctionEnumWrapper>()?;
     m.add_class::<AzInterpolateContext>()?;
@@ -36511,6 +43511,16 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzBoxShadowClipModeEnumWrapper>()?;
     m.add_class::<AzStyleBoxShadow>()?;
     m.add_class::<AzLayoutAlignContentEnumWrapper>()?;
+    m.add_class::<AzGridTrackBreadthEnumWrapper>()?;
+    m.add_class::<AzGridMinMax>()?;
+    m.add_class::<AzGridTrackSizingEnumWrapper>()?;
+    m.add_class::<AzLayoutGridTemplateColumns>()?;
+    m.add_class::<AzLayoutGridTemplateRows>()?;
+    m.add_class::<AzGridLineEnumWrapper>()?;
+    m.add_class::<AzLayoutGridColumn>()?;
+    m.add_class::<AzLayoutGridRow>()?;
+    m.add_class::<AzLayoutColumnGap>()?;
+    m.add_class::<AzLayoutRowGap>()?;
     m.add_class::<AzLayoutAlignItemsEnumWrapper>()?;
     m.add_class::<AzLayoutBottom>()?;
     m.add_class::<AzLayoutBoxSizingEnumWrapper>()?;
@@ -36519,6 +43529,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzLayoutFlexGrow>()?;
     m.add_class::<AzLayoutFlexShrink>()?;
     m.add_class::<AzLayoutFloatEnumWrapper>()?;
+    m.add_class::<AzLayoutClearEnumWrapper>()?;
     m.add_class::<AzLayoutHeight>()?;
     m.add_class::<AzLayoutJustifyContentEnumWrapper>()?;
     m.add_class::<AzLayoutLeft>()?;
@@ -36589,6 +43600,17 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleTransformOrigin>()?;
     m.add_class::<AzStylePerspectiveOrigin>()?;
     m.add_class::<AzStyleBackfaceVisibilityEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionPropertyEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionDuration>()?;
+    m.add_class::<AzStyleTransitionDelay>()?;
+    m.add_class::<AzStyleTransitionTimingFunctionEnumWrapper>()?;
+    m.add_class::<AzStyleCubicBezier>()?;
+    m.add_class::<AzStyleAnimationName>()?;
+    m.add_class::<AzStyleAnimationDuration>()?;
+    m.add_class::<AzStyleAnimationDelay>()?;
+    m.add_class::<AzStyleAnimationIterationCountEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationDirectionEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationFillModeEnumWrapper>()?;
     m.add_class::<AzStyleTransformEnumWrapper>()?;
     m.add_class::<AzStyleTransformMatrix2D>()?;
     m.add_class::<AzStyleTransformMatrix3D>()?;
@@ -36603,6 +43625,12 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleWordSpacing>()?;
     m.add_class::<AzStyleBoxShadowValueEnumWrapper>()?;
     m.add_class::<AzLayoutAlignContentValueEnumWrapper>()?;
+    m.add_class::<AzLayoutGridTemplateColumnsValueEnumWrapper>()?;
+    m.add_class::<AzLayoutGridTemplateRowsValueEnumWrapper>()?;
+    m.add_class::<AzLayoutGridColumnValueEnumWrapper>()?;
+    m.add_class::<AzLayoutGridRowValueEnumWrapper>()?;
+    m.add_class::<AzLayoutColumnGapValueEnumWrapper>()?;
+    m.add_class::<AzLayoutRowGapValueEnumWrapper>()?;
     m.add_class::<AzLayoutAlignItemsValueEnumWrapper>()?;
     m.add_class::<AzLayoutBottomValueEnumWrapper>()?;
     m.add_class::<AzLayoutBoxSizingValueEnumWrapper>()?;
@@ -36611,6 +43639,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzLayoutFlexGrowValueEnumWrapper>()?;
     m.add_class::<AzLayoutFlexShrinkValueEnumWrapper>()?;
     m.add_class::<AzLayoutFloatValueEnumWrapper>()?;
+    m.add_class::<AzLayoutClearValueEnumWrapper>()?;
     m.add_class::<AzLayoutHeightValueEnumWrapper>()?;
     m.add_class::<AzLayoutJustifyContentValueEnumWrapper>()?;
     m.add_class::<AzLayoutLeftValueEnumWrapper>()?;
@@ -36667,6 +43696,16 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleTransformOriginValueEnumWrapper>()?;
     m.add_class::<AzStylePerspectiveOriginValueEnumWrapper>()?;
     m.add_class::<AzStyleBackfaceVisibilityValueEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionPropertyVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionDurationVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionTimingFunctionVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionDelayVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationNameVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationDurationVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationIterationCountVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationDirectionVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationFillModeVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationDelayVecValueEnumWrapper>()?;
     m.add_class::<AzCssPropertyEnumWrapper>()?;
 
     m.add_class::<AzButton>()?;
@@ -36700,6 +43739,48 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzNumberInputState>()?;
     m.add_class::<AzNumberInputOnValueChange>()?;
     m.add_class::<AzNumberInputOnValueChangeCallback>()?;
+    m.add_class::<AzTextEdit>()?;
+    m.add_class::<AzTextEditStateWrapper>()?;
+    m.add_class::<AzTextEditState>()?;
+    m.add_class::<AzTextEditHistory>()?;
+    m.add_class::<AzTextEditSnapshot>()?;
+    m.add_class::<AzTextEditKindEnumWrapper>()?;
+    m.add_class::<AzTextEditView>()?;
+    m.add_class::<AzTextEditOnTextChange>()?;
+    m.add_class::<AzTextEditOnTextChangeCallback>()?;
+    m.add_class::<AzTextEditOnFocusLost>()?;
+    m.add_class::<AzTextEditOnFocusLostCallback>()?;
+    m.add_class::<AzSlider>()?;
+    m.add_class::<AzSliderStateWrapper>()?;
+    m.add_class::<AzSliderState>()?;
+    m.add_class::<AzSliderOrientationEnumWrapper>()?;
+    m.add_class::<AzSliderOnValueChange>()?;
+    m.add_class::<AzSliderOnValueChangeCallback>()?;
+    m.add_class::<AzDropDown>()?;
+    m.add_class::<AzDropDownStateWrapper>()?;
+    m.add_class::<AzDropDownState>()?;
+    m.add_class::<AzDropDownOnChoiceChange>()?;
+    m.add_class::<AzDropDownOnChoiceChangeCallback>()?;
+    m.add_class::<AzSpreadsheet>()?;
+    m.add_class::<AzSpreadsheetStateWrapper>()?;
+    m.add_class::<AzSpreadsheetState>()?;
+    m.add_class::<AzSpreadsheetStyle>()?;
+    m.add_class::<AzSpreadsheetCellIndex>()?;
+    m.add_class::<AzSpreadsheetCellSelection>()?;
+    m.add_class::<AzSpreadsheetSizeOverride>()?;
+    m.add_class::<AzSpreadsheetCellContent>()?;
+    m.add_class::<AzSpreadsheetView>()?;
+    m.add_class::<AzSpreadsheetRenderWindow>()?;
+    m.add_class::<AzSpreadsheetDragEnumWrapper>()?;
+    m.add_class::<AzSpreadsheetColumnResize>()?;
+    m.add_class::<AzSpreadsheetCellClick>()?;
+    m.add_class::<AzSpreadsheetCellEdit>()?;
+    m.add_class::<AzSpreadsheetOnCellsChange>()?;
+    m.add_class::<AzSpreadsheetOnCellsChangeCallback>()?;
+    m.add_class::<AzSpreadsheetOnSelectionChange>()?;
+    m.add_class::<AzSpreadsheetOnSelectionChangeCallback>()?;
+    m.add_class::<AzSpreadsheetOnColumnResize>()?;
+    m.add_class::<AzSpreadsheetOnColumnResizeCallback>()?;
 
     m.add_class::<AzNode>()?;
     m.add_class::<AzCascadeInfo>()?;
@@ -36839,6 +43920,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzString>()?;
 
     m.add_class::<AzAccessibilityStateVec>()?;
+    m.add_class::<AzAccessibilityNodeVec>()?;
     m.add_class::<AzMenuItemVec>()?;
     m.add_class::<AzTessellatedSvgNodeVec>()?;
     m.add_class::<AzStyleFontFamilyVec>()?;
@@ -36867,11 +43949,28 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzU32Vec>()?;
     m.add_class::<AzXWindowTypeVec>()?;
     m.add_class::<AzVirtualKeyCodeVec>()?;
+    m.add_class::<AzKeyboardShortcutVec>()?;
     m.add_class::<AzCascadeInfoVec>()?;
     m.add_class::<AzScanCodeVec>()?;
     m.add_class::<AzCssDeclarationVec>()?;
     m.add_class::<AzCssPathSelectorVec>()?;
     m.add_class::<AzStylesheetVec>()?;
+    m.add_class::<AzCssMediaQueryVec>()?;
+    m.add_class::<AzCssMediaConditionVec>()?;
+    m.add_class::<AzCssFontFaceVec>()?;
+    m.add_class::<AzStyleTransitionPropertyVec>()?;
+    m.add_class::<AzStyleTransitionDurationVec>()?;
+    m.add_class::<AzStyleTransitionTimingFunctionVec>()?;
+    m.add_class::<AzStyleTransitionDelayVec>()?;
+    m.add_class::<AzStyleAnimationNameVec>()?;
+    m.add_class::<AzStyleAnimationDurationVec>()?;
+    m.add_class::<AzStyleAnimationIterationCountVec>()?;
+    m.add_class::<AzStyleAnimationDirectionVec>()?;
+    m.add_class::<AzStyleAnimationFillModeVec>()?;
+    m.add_class::<AzStyleAnimationDelayVec>()?;
+    m.add_class::<AzCssKeyframeVec>()?;
+    m.add_class::<AzCssKeyframesVec>()?;
+    m.add_class::<AzGridTrackSizingVec>()?;
     m.add_class::<AzCssRuleBlockVec>()?;
     m.add_class::<AzU16Vec>()?;
     m.add_class::<AzF32Vec>()?;
@@ -36890,8 +43989,12 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzTagIdToNodeIdMappingVec>()?;
     m.add_class::<AzParentWithNodeDepthVec>()?;
     m.add_class::<AzNodeDataVec>()?;
+    m.add_class::<AzTextEditSnapshotVec>()?;
+    m.add_class::<AzSpreadsheetSizeOverrideVec>()?;
+    m.add_class::<AzSpreadsheetCellContentVec>()?;
     m.add_class::<AzStyleFontFamilyVecDestructorEnumWrapper>()?;
     m.add_class::<AzAccessibilityStateVecDestructorEnumWrapper>()?;
+    m.add_class::<AzAccessibilityNodeVecDestructorEnumWrapper>()?;
     m.add_class::<AzMenuItemVecDestructorEnumWrapper>()?;
     m.add_class::<AzTessellatedSvgNodeVecDestructorEnumWrapper>()?;
     m.add_class::<AzXmlNodeVecDestructorEnumWrapper>()?;
@@ -36919,11 +44022,28 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzU32VecDestructorEnumWrapper>()?;
     m.add_class::<AzXWindowTypeVecDestructorEnumWrapper>()?;
     m.add_class::<AzVirtualKeyCodeVecDestructorEnumWrapper>()?;
+    m.add_class::<AzKeyboardShortcutVecDestructorEnumWrapper>()?;
     m.add_class::<AzCascadeInfoVecDestructorEnumWrapper>()?;
     m.add_class::<AzScanCodeVecDestructorEnumWrapper>()?;
     m.add_class::<AzCssDeclarationVecDestructorEnumWrapper>()?;
     m.add_class::<AzCssPathSelectorVecDestructorEnumWrapper>()?;
     m.add_class::<AzStylesheetVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssFontFaceVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionPropertyVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionDurationVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionTimingFunctionVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionDelayVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationNameVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationDurationVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationIterationCountVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationDirectionVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationFillModeVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationDelayVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssKeyframeVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssKeyframesVecDestructorEnumWrapper>()?;
+    m.add_class::<AzGridTrackSizingVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssMediaQueryVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssMediaConditionVecDestructorEnumWrapper>()?;
     m.add_class::<AzCssRuleBlockVecDestructorEnumWrapper>()?;
     m.add_class::<AzF32VecDestructorEnumWrapper>()?;
     m.add_class::<AzU16VecDestructorEnumWrapper>()?;
@@ -36942,6 +44062,9 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzTagIdToNodeIdMappingVecDestructorEnumWrapper>()?;
     m.add_class::<AzParentWithNodeDepthVecDestructorEnumWrapper>()?;
     m.add_class::<AzNodeDataVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTextEditSnapshotVecDestructorEnumWrapper>()?;
+    m.add_class::<AzSpreadsheetSizeOverrideVecDestructorEnumWrapper>()?;
+    m.add_class::<AzSpreadsheetCellContentVecDestructorEnumWrapper>()?;
 
     m.add_class::<AzOptionColorInputOnValueChangeEnumWrapper>()?;
     m.add_class::<AzOptionButtonOnClickEnumWrapper>()?;
@@ -36951,6 +44074,18 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionTextInputOnFocusLostEnumWrapper>()?;
     m.add_class::<AzOptionTextInputSelectionEnumWrapper>()?;
     m.add_class::<AzOptionNumberInputOnValueChangeEnumWrapper>()?;
+    m.add_class::<AzOptionTextEditOnTextChangeEnumWrapper>()?;
+    m.add_class::<AzOptionTextEditOnFocusLostEnumWrapper>()?;
+    m.add_class::<AzOptionTextEditKindEnumWrapper>()?;
+    m.add_class::<AzOptionSpreadsheetCellClickEnumWrapper>()?;
+    m.add_class::<AzOptionSpreadsheetCellEditEnumWrapper>()?;
+    m.add_class::<AzOptionSliderOnValueChangeEnumWrapper>()?;
+    m.add_class::<AzOptionDropDownOnChoiceChangeEnumWrapper>()?;
+    m.add_class::<AzOptionSpreadsheetOnCellsChangeEnumWrapper>()?;
+    m.add_class::<AzOptionSpreadsheetOnSelectionChangeEnumWrapper>()?;
+    m.add_class::<AzOptionSpreadsheetOnColumnResizeEnumWrapper>()?;
+    m.add_class::<AzOptionSpreadsheetCellSelectionEnumWrapper>()?;
+    m.add_class::<AzOptionAccessibilityNodeEnumWrapper>()?;
     m.add_class::<AzOptionMenuItemIconEnumWrapper>()?;
     m.add_class::<AzOptionMenuCallbackEnumWrapper>()?;
     m.add_class::<AzOptionVirtualKeyCodeComboEnumWrapper>()?;
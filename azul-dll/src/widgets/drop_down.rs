@@ -0,0 +1,1036 @@
+//! Drop-down / select widget, the list of choices is shown in a popup rendered by azul
+
+use azul_desktop::{
+    css::*,
+    css::AzString,
+    dom::{
+        Dom, TabIndex, IdOrClass, IdOrClass::Class, IdOrClassVec,
+        AccessibilityInfo, AccessibilityRole,
+        NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
+        NodeDataInlineCssProperty::{Normal, Hover, Focus},
+    },
+    callbacks::{RefAny, Callback, CallbackInfo, Update},
+    task::OptionInstant,
+};
+use azul_core::gl::OptionUsize;
+use azul_core::window::{KeyboardState, VirtualKeyCode, MenuPopupPosition};
+use std::vec::Vec;
+use std::string::String;
+
+/// Height of a single choice in the popup
+const ENTRY_HEIGHT: isize = 22;
+/// Minimum width of the popup
+const POPUP_MIN_WIDTH: f32 = 100.0;
+/// PageUp / PageDown moves the highlighted choice by this many entries
+const PAGE_ENTRIES: usize = 10;
+/// Characters typed within this time (in milliseconds) are searched for as one word
+const TYPE_AHEAD_TIMEOUT_MS: u64 = 1000;
+
+static DROP_DOWN_CONTAINER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-drop-down-container"))];
+static DROP_DOWN_BUTTON_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-drop-down-button"))];
+static DROP_DOWN_LABEL_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-drop-down-label"))];
+static DROP_DOWN_ARROW_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-drop-down-arrow"))];
+static DROP_DOWN_POPUP_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-drop-down-popup"))];
+static DROP_DOWN_ENTRY_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-drop-down-entry"))];
+static DROP_DOWN_DISABLED_ENTRY_CLASS: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-drop-down-entry")),
+    Class(AzString::from_const_str("__azul-native-drop-down-entry-disabled")),
+];
+
+pub type DropDownOnChoiceChangeCallbackType = extern "C" fn(&mut RefAny, &DropDownState, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct DropDownOnChoiceChangeCallback {
+    pub cb: DropDownOnChoiceChangeCallbackType,
+}
+
+impl_callback!(DropDownOnChoiceChangeCallback);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct DropDown {
+    pub state: DropDownStateWrapper,
+    /// Style for the drop-down container (the closed drop-down)
+    pub container_style: NodeDataInlineCssPropertyVec,
+    /// Style for the text of the selected choice
+    pub label_style: NodeDataInlineCssPropertyVec,
+    /// Style for the popup containing the choices
+    pub popup_style: NodeDataInlineCssPropertyVec,
+    /// Style for a choice in the popup
+    pub entry_style: NodeDataInlineCssPropertyVec,
+    /// Style for a choice that can't be selected
+    pub disabled_entry_style: NodeDataInlineCssPropertyVec,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+#[repr(C)]
+pub struct DropDownStateWrapper {
+    /// Choices and the currently selected choice
+    pub inner: DropDownState,
+    /// Optional: Function to call when the user selects a different choice
+    pub on_choice_change: OptionDropDownOnChoiceChange,
+    // whether the popup with the choices is open
+    is_open: bool,
+    // choice that is highlighted via the keyboard while the popup is open
+    highlighted: OptionUsize,
+    // characters typed so far for the type-ahead search
+    search: AzString,
+    last_search_input: OptionInstant,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct DropDownOnChoiceChange {
+    pub data: RefAny,
+    pub callback: DropDownOnChoiceChangeCallback,
+}
+
+impl_option!(DropDownOnChoiceChange, OptionDropDownOnChoiceChange, copy = false, [Debug, Clone, PartialEq, PartialOrd]);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct DropDownState {
+    pub choices: StringVec,
+    /// Indices of the choices that can't be selected
+    pub disabled_choices: U32Vec,
+    pub selected: OptionUsize,
+    /// Where the popup opens, relative to the drop-down (`HitRect`)
+    /// or to the cursor position when the drop-down was clicked
+    pub popup_position: MenuPopupPosition,
+}
+
+impl Default for DropDownState {
+    fn default() -> Self {
+        Self {
+            choices: StringVec::from_const_slice(&[]),
+            disabled_choices: U32Vec::from_const_slice(&[]),
+            selected: None.into(),
+            popup_position: MenuPopupPosition::AutoHitRect,
+        }
+    }
+}
+
+const BACKGROUND_COLOR: ColorU = ColorU { r: 255,  g: 255,  b: 255,  a: 255 }; // white
+const COLOR_9B9B9B: ColorU = ColorU { r: 155, g: 155, b: 155, a: 255 }; // #9b9b9b
+const COLOR_4286F4: ColorU = ColorU { r: 66, g: 134, b: 244, a: 255 }; // #4286f4
+const COLOR_4C4C4C: ColorU = ColorU { r: 76, g: 76, b: 76, a: 255 }; // #4C4C4C
+const COLOR_E5E5E5: ColorU = ColorU { r: 229, g: 229, b: 229, a: 255 }; // #e5e5e5
+const COLOR_B3D7FF: ColorU = ColorU { r: 179, g: 215, b: 255, a: 255 }; // #b3d7ff
+const COLOR_TRANSPARENT: ColorU = ColorU { r: 0, g: 0, b: 0, a: 0 };
+
+const BACKGROUND_THEME_LIGHT: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(BACKGROUND_COLOR)];
+const BACKGROUND_COLOR_LIGHT: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(BACKGROUND_THEME_LIGHT);
+const ENTRY_HOVER_THEME: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_E5E5E5)];
+const ENTRY_HIGHLIGHTED_THEME: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_B3D7FF)];
+const ENTRY_NOT_HIGHLIGHTED_THEME: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_TRANSPARENT)];
+
+const SANS_SERIF_STR: &str = "sans-serif";
+const SANS_SERIF: AzString = AzString::from_const_str(SANS_SERIF_STR);
+const SANS_SERIF_FAMILIES: &[StyleFontFamily] = &[StyleFontFamily::System(SANS_SERIF)];
+const SANS_SERIF_FAMILY: StyleFontFamilyVec = StyleFontFamilyVec::from_const_slice(SANS_SERIF_FAMILIES);
+
+static DEFAULT_DROP_DOWN_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
+
+    Normal(CssProperty::const_cursor(StyleCursor::Pointer)),
+    Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(100))),
+    Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
+
+    // border: 1px solid #9b9b9b;
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_9B9B9B })),
+
+    // Hover(border-color: #4286f4;)
+
+    Hover(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_4286F4 })),
+    Hover(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4286F4 })),
+    Hover(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_4286F4 })),
+    Hover(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_4286F4 })),
+
+    // Focus(border-color: #4286f4;)
+
+    Focus(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_4286F4 })),
+];
+
+static DEFAULT_DROP_DOWN_LABEL_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+];
+
+static DEFAULT_DROP_DOWN_POPUP_STYLE: &[NodeDataInlineCssProperty] = &[
+
+    Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_9B9B9B })),
+];
+
+static DEFAULT_DROP_DOWN_ENTRY_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(ENTRY_HOVER_THEME))),
+];
+
+static DEFAULT_DROP_DOWN_DISABLED_ENTRY_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_cursor(StyleCursor::Default)),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+];
+
+// layout properties, appended to the user-defined styles
+
+static CONTAINER_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    // the popup is positioned relative to the container
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+];
+
+static BUTTON_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(ENTRY_HEIGHT))),
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(4))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(4))),
+];
+
+static LABEL_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+];
+
+static ARROW_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(4))),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(10))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+];
+
+// hidden until the drop-down is opened, then moved + resized via set_css_property
+static POPUP_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::None)),
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Auto)),
+];
+
+static ENTRY_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_height(LayoutHeight::const_px(ENTRY_HEIGHT))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(4))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(4))),
+];
+
+impl Default for DropDown {
+    fn default() -> Self {
+        Self {
+            state: DropDownStateWrapper::default(),
+            container_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_DROP_DOWN_CONTAINER_STYLE),
+            label_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_DROP_DOWN_LABEL_STYLE),
+            popup_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_DROP_DOWN_POPUP_STYLE),
+            entry_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_DROP_DOWN_ENTRY_STYLE),
+            disabled_entry_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_DROP_DOWN_DISABLED_ENTRY_STYLE),
+        }
+    }
+}
+
+impl DropDown {
+
+    pub fn new(choices: StringVec) -> Self {
+        let mut s = Self::default();
+        s.state.inner.choices = choices;
+        s.state.inner.selected = s.state.inner.get_first_enabled_choice().into();
+        s
+    }
+
+    pub fn set_selected(&mut self, selected: usize) {
+        if self.state.inner.is_choice_enabled(selected) {
+            self.state.inner.selected = Some(selected).into();
+        }
+    }
+
+    pub fn set_disabled_choices(&mut self, disabled_choices: U32Vec) {
+        self.state.inner.disabled_choices = disabled_choices;
+        if !self.state.inner.selected.into_option().map(|s| self.state.inner.is_choice_enabled(s)).unwrap_or(false) {
+            self.state.inner.selected = self.state.inner.get_first_enabled_choice().into();
+        }
+    }
+
+    pub fn set_popup_position(&mut self, popup_position: MenuPopupPosition) {
+        self.state.inner.popup_position = popup_position;
+    }
+
+    pub fn set_on_choice_change(&mut self, data: RefAny, callback: DropDownOnChoiceChangeCallbackType) {
+        self.state.on_choice_change = Some(DropDownOnChoiceChange {
+            callback: DropDownOnChoiceChangeCallback { cb: callback },
+            data
+        }).into();
+    }
+
+    pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.container_style = style;
+    }
+
+    pub fn set_label_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.label_style = style;
+    }
+
+    pub fn set_popup_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.popup_style = style;
+    }
+
+    pub fn set_entry_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.entry_style = style;
+    }
+
+    pub fn set_disabled_entry_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.disabled_entry_style = style;
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::default();
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    pub fn dom(self) -> Dom {
+
+        use azul_desktop::dom::{CallbackData, EventFilter, HoverEventFilter, FocusEventFilter};
+
+        let selected_label = self.state.inner.get_selected_label();
+
+        let entries = self.state.inner.choices.iter().enumerate().map(|(i, choice)| {
+            let (classes, style) = if self.state.inner.is_choice_enabled(i) {
+                (DROP_DOWN_ENTRY_CLASS, self.entry_style.clone())
+            } else {
+                (DROP_DOWN_DISABLED_ENTRY_CLASS, self.disabled_entry_style.clone())
+            };
+            Dom::text(choice.clone())
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(classes))
+            .with_inline_css_props(with_layout(style, ENTRY_LAYOUT))
+        }).collect::<Vec<_>>();
+
+        let accessibility_info = AccessibilityInfo {
+            name: None.into(),
+            value: Some(selected_label.clone()).into(),
+            role: AccessibilityRole::ComboBox,
+            states: Vec::new().into(),
+            accelerator: None.into(),
+            default_action: None.into(),
+        };
+
+        let state_ref = RefAny::new(self.state);
+
+        Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(DROP_DOWN_CONTAINER_CLASS))
+        .with_inline_css_props(with_layout(self.container_style, CONTAINER_LAYOUT))
+        .with_tab_index(TabIndex::Auto)
+        .with_accessibility_info(accessibility_info)
+        .with_dataset(Some(state_ref.clone()).into())
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_container_click }
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_virtual_key_down }
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::TextInput),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_text_input }
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::FocusLost),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_focus_lost }
+            },
+        ].into())
+        .with_children(vec![
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(DROP_DOWN_BUTTON_CLASS))
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(BUTTON_LAYOUT))
+            .with_children(vec![
+                Dom::text(selected_label)
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(DROP_DOWN_LABEL_CLASS))
+                .with_inline_css_props(with_layout(self.label_style, LABEL_LAYOUT)),
+                Dom::text(AzString::from_const_str("\u{25BC}"))
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(DROP_DOWN_ARROW_CLASS))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(ARROW_LAYOUT)),
+            ].into()),
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(DROP_DOWN_POPUP_CLASS))
+            .with_inline_css_props(with_layout(self.popup_style, POPUP_LAYOUT))
+            // the popup is outside of the container, so clicks on it don't hit the container
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_popup_click }
+                },
+            ].into())
+            .with_children(entries.into()),
+        ].into())
+    }
+}
+
+/// Appends the layout properties to the user-defined style
+fn with_layout(style: NodeDataInlineCssPropertyVec, layout: &[NodeDataInlineCssProperty]) -> NodeDataInlineCssPropertyVec {
+    let mut style = style.into_library_owned_vec();
+    style.extend(layout.iter().cloned());
+    style.into()
+}
+
+impl DropDownState {
+
+    pub fn is_choice_enabled(&self, index: usize) -> bool {
+        index < self.choices.len() && !self.disabled_choices.iter().any(|d| *d as usize == index)
+    }
+
+    /// Returns the label of the selected choice or an empty string
+    pub fn get_selected_label(&self) -> AzString {
+        self.selected.into_option()
+        .and_then(|s| self.choices.get(s))
+        .cloned()
+        .unwrap_or_else(|| AzString::from_const_str(""))
+    }
+
+    fn get_first_enabled_choice(&self) -> Option<usize> {
+        (0..self.choices.len()).find(|i| self.is_choice_enabled(*i))
+    }
+
+    fn get_last_enabled_choice(&self) -> Option<usize> {
+        (0..self.choices.len()).rev().find(|i| self.is_choice_enabled(*i))
+    }
+
+    /// Returns the enabled choice `distance` entries after / before `current`
+    /// (skipping disabled choices), stops at the first / last enabled choice
+    fn get_enabled_choice_relative_to(&self, current: Option<usize>, distance: usize, forward: bool) -> Option<usize> {
+        let current = match current {
+            Some(s) => s,
+            None => return if forward { self.get_first_enabled_choice() } else { self.get_last_enabled_choice() },
+        };
+        let candidates: Vec<usize> = if forward {
+            (current + 1..self.choices.len()).filter(|i| self.is_choice_enabled(*i)).take(distance).collect()
+        } else {
+            (0..current).rev().filter(|i| self.is_choice_enabled(*i)).take(distance).collect()
+        };
+        candidates.last().copied().or(Some(current))
+    }
+
+    /// Returns the first enabled choice starting with `search` (case-insensitive),
+    /// beginning at the choice `start` and wrapping around
+    fn find_choice(&self, search: &str, start: usize) -> Option<usize> {
+        let search = search.to_lowercase();
+        let len = self.choices.len();
+        (0..len)
+        .map(|i| (start + i) % len)
+        .find(|i| self.is_choice_enabled(*i) && self.choices.as_ref()[*i].as_str().to_lowercase().starts_with(&search))
+    }
+}
+
+impl DropDownStateWrapper {
+
+    fn open_popup(&mut self) {
+        self.is_open = true;
+        self.highlighted = self.inner.selected;
+    }
+
+    fn close_popup(&mut self) {
+        self.is_open = false;
+        self.highlighted = None.into();
+    }
+
+    /// Selects the choice, returns whether the selection changed
+    fn select(&mut self, choice: Option<usize>) -> bool {
+        let choice = match choice {
+            Some(s) if self.inner.is_choice_enabled(s) => s,
+            _ => return false,
+        };
+        let changed = self.inner.selected.into_option() != Some(choice);
+        self.inner.selected = Some(choice).into();
+        changed
+    }
+
+    /// Highlights the choice (popup open) or selects it directly (popup closed),
+    /// returns whether the selection changed
+    fn move_to(&mut self, choice: Option<usize>) -> bool {
+        if self.is_open {
+            if choice.is_some() {
+                self.highlighted = choice.into();
+            }
+            false
+        } else {
+            self.select(choice)
+        }
+    }
+
+    /// Returns whether the selection changed
+    fn handle_on_virtual_key_down(&mut self, virtual_key: VirtualKeyCode, keyboard_state: &KeyboardState) -> bool {
+
+        let current = if self.is_open { self.highlighted.into_option() } else { self.inner.selected.into_option() };
+
+        // any key other than a character ends the type-ahead search
+        self.search = AzString::default();
+
+        match virtual_key {
+            VirtualKeyCode::Down if keyboard_state.alt_down && !self.is_open => {
+                self.open_popup();
+                false
+            },
+            VirtualKeyCode::Up if keyboard_state.alt_down && self.is_open => {
+                self.close_popup();
+                false
+            },
+            VirtualKeyCode::Down => self.move_to(self.inner.get_enabled_choice_relative_to(current, 1, true)),
+            VirtualKeyCode::Up => self.move_to(self.inner.get_enabled_choice_relative_to(current, 1, false)),
+            VirtualKeyCode::PageDown => self.move_to(self.inner.get_enabled_choice_relative_to(current, PAGE_ENTRIES, true)),
+            VirtualKeyCode::PageUp => self.move_to(self.inner.get_enabled_choice_relative_to(current, PAGE_ENTRIES, false)),
+            VirtualKeyCode::Home => self.move_to(self.inner.get_first_enabled_choice()),
+            VirtualKeyCode::End => self.move_to(self.inner.get_last_enabled_choice()),
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter | VirtualKeyCode::Space => {
+                if self.is_open {
+                    let highlighted = self.highlighted.into_option();
+                    self.close_popup();
+                    self.select(highlighted)
+                } else {
+                    self.open_popup();
+                    false
+                }
+            },
+            VirtualKeyCode::Escape | VirtualKeyCode::Tab => {
+                self.close_popup();
+                false
+            },
+            _ => false,
+        }
+    }
+
+    /// Type-ahead search: jumps to the first choice starting with the typed characters,
+    /// `reset_search` starts a new search. Returns whether the selection changed.
+    fn handle_on_text_input(&mut self, c: char, reset_search: bool) -> bool {
+
+        if c.is_control() || (c == ' ' && self.search.as_str().is_empty()) {
+            return false;
+        }
+
+        if reset_search {
+            self.search = AzString::default();
+        }
+
+        let current = if self.is_open { self.highlighted.into_option() } else { self.inner.selected.into_option() };
+
+        // typing the same letter repeatedly cycles through the choices starting with that letter
+        let mut search = self.search.as_str().to_string();
+        let is_repeated_char = !search.is_empty() && search.chars().all(|s| s == c);
+        search.push(c);
+        self.search = search.into();
+
+        let found = if is_repeated_char {
+            let single_char: String = core::iter::once(c).collect();
+            self.inner.find_choice(&single_char, current.map(|c| c + 1).unwrap_or(0))
+        } else {
+            // the current choice still matches if more characters of it are typed
+            self.inner.find_choice(self.search.as_str(), current.unwrap_or(0))
+        };
+
+        self.move_to(found)
+    }
+}
+
+/// Returns the index of the choice at `y` pixels below the top of the first choice
+fn get_entry_at(y: f32, choice_count: usize) -> Option<usize> {
+    if y < 0.0 {
+        return None;
+    }
+    let index = (y / ENTRY_HEIGHT as f32) as usize;
+    if index < choice_count { Some(index) } else { None }
+}
+
+// handle input events for the DropDown
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, DomNodeId};
+    use azul_desktop::css::*;
+    use azul_desktop::task::Duration;
+    use azul_core::window::{LogicalRect, LogicalPosition, LogicalSize};
+    use super::{
+        DropDownStateWrapper, DropDownOnChoiceChange,
+        ENTRY_HEIGHT, POPUP_MIN_WIDTH, TYPE_AHEAD_TIMEOUT_MS,
+        ENTRY_HIGHLIGHTED_THEME, ENTRY_NOT_HIGHLIGHTED_THEME,
+    };
+
+    /// Nodes of the drop-down, see `DropDown::dom()`
+    struct DropDownNodes {
+        container: DomNodeId,
+        button: DomNodeId,
+        label: DomNodeId,
+        popup: DomNodeId,
+    }
+
+    impl DropDownNodes {
+        fn new(container: DomNodeId, info: &CallbackInfo) -> Option<Self> {
+            let button = info.get_first_child(container)?;
+            let label = info.get_first_child(button)?;
+            let popup = info.get_next_sibling(button)?;
+            Some(Self { container, button, label, popup })
+        }
+
+        fn get_entries(&self, info: &CallbackInfo) -> Vec<DomNodeId> {
+            let mut entries = Vec::new();
+            let mut next = info.get_first_child(self.popup);
+            while let Some(entry) = next {
+                entries.push(entry);
+                next = info.get_next_sibling(entry);
+            }
+            entries
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_container_click(drop_down: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut drop_down = match drop_down.downcast_mut::<DropDownStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let nodes = match DropDownNodes::new(info.get_hit_node(), &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if drop_down.is_open {
+            drop_down.close_popup();
+        } else {
+            drop_down.open_popup();
+        }
+
+        let cursor = info.get_current_mouse_state().cursor_position.get_position();
+        update_drop_down_display(&mut *drop_down, &nodes, cursor, &mut info);
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_popup_click(drop_down: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut drop_down = match drop_down.downcast_mut::<DropDownStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let popup = info.get_hit_node();
+        let nodes = match info.get_parent(popup).and_then(|container| DropDownNodes::new(container, &info)) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let cursor = match info.get_cursor_relative_to_node().into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // offset of the first choice from the top of the popup (border)
+        let first_entry_offset = match (get_node_rect(popup, &info), info.get_first_child(popup).and_then(|e| get_node_rect(e, &info))) {
+            (Some(popup_rect), Some(entry_rect)) => entry_rect.origin.y - popup_rect.origin.y,
+            _ => 0.0,
+        };
+
+        // clicking on a disabled choice does nothing
+        let choice = match super::get_entry_at(cursor.y - first_entry_offset, drop_down.inner.choices.len()) {
+            Some(c) if drop_down.inner.is_choice_enabled(c) => c,
+            _ => return Update::DoNothing,
+        };
+
+        drop_down.close_popup();
+        let selection_changed = drop_down.select(Some(choice));
+        update_drop_down_display(&mut *drop_down, &nodes, None, &mut info);
+
+        if selection_changed { call_on_choice_change(&mut *drop_down, &mut info) } else { Update::DoNothing }
+    }
+
+    pub(in super) extern "C" fn default_on_virtual_key_down(drop_down: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut drop_down = match drop_down.downcast_mut::<DropDownStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let last_keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let selection_changed = drop_down.handle_on_virtual_key_down(last_keycode, &keyboard_state);
+
+        if let Some(nodes) = DropDownNodes::new(info.get_hit_node(), &info) {
+            update_drop_down_display(&mut *drop_down, &nodes, None, &mut info);
+        }
+
+        if selection_changed { call_on_choice_change(&mut *drop_down, &mut info) } else { Update::DoNothing }
+    }
+
+    pub(in super) extern "C" fn default_on_text_input(drop_down: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut drop_down = match drop_down.downcast_mut::<DropDownStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let c = match keyboard_state.current_char.into_option().and_then(core::char::from_u32) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // start a new search if the last character was typed a while ago
+        let now = info.get_current_time();
+        let reset_search = match drop_down.last_search_input.as_ref() {
+            Some(last) => match now.duration_since(last) {
+                Duration::System(d) => {
+                    let d: std::time::Duration = d.into();
+                    d.as_millis() as u64 > TYPE_AHEAD_TIMEOUT_MS
+                },
+                Duration::Tick(_) => false,
+            },
+            None => true,
+        };
+        drop_down.last_search_input = Some(now).into();
+
+        let selection_changed = drop_down.handle_on_text_input(c, reset_search);
+
+        if let Some(nodes) = DropDownNodes::new(info.get_hit_node(), &info) {
+            update_drop_down_display(&mut *drop_down, &nodes, None, &mut info);
+        }
+
+        if selection_changed { call_on_choice_change(&mut *drop_down, &mut info) } else { Update::DoNothing }
+    }
+
+    pub(in super) extern "C" fn default_on_focus_lost(drop_down: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut drop_down = match drop_down.downcast_mut::<DropDownStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if drop_down.is_open {
+            drop_down.close_popup();
+            if let Some(nodes) = DropDownNodes::new(info.get_hit_node(), &info) {
+                update_drop_down_display(&mut *drop_down, &nodes, None, &mut info);
+            }
+        }
+
+        Update::DoNothing
+    }
+
+    fn call_on_choice_change(drop_down: &mut DropDownStateWrapper, info: &mut CallbackInfo) -> Update {
+        let inner = &drop_down.inner;
+        match drop_down.on_choice_change.as_mut() {
+            Some(DropDownOnChoiceChange { callback, data }) => (callback.cb)(data, &inner, info),
+            None => Update::DoNothing,
+        }
+    }
+
+    /// Returns the rect of the node in window coordinates
+    fn get_node_rect(node_id: DomNodeId, info: &CallbackInfo) -> Option<LogicalRect> {
+        let position = info.get_node_position(node_id)?.get_static_offset();
+        let size = info.get_node_size(node_id)?;
+        Some(LogicalRect::new(position, size))
+    }
+
+    /// Updates the label, shows / hides and positions the popup and highlights the current choice
+    fn update_drop_down_display(
+        drop_down: &mut DropDownStateWrapper,
+        nodes: &DropDownNodes,
+        cursor: Option<LogicalPosition>,
+        info: &mut CallbackInfo,
+    ) {
+
+        info.set_string_contents(nodes.label, drop_down.inner.get_selected_label());
+
+        if !drop_down.is_open {
+            info.set_css_property(nodes.popup, CssProperty::const_display(LayoutDisplay::None));
+            return;
+        }
+
+        let (container_rect, button_rect) = match (get_node_rect(nodes.container, info), get_node_rect(nodes.button, info)) {
+            (Some(c), Some(b)) => (c, b),
+            _ => return,
+        };
+
+        let window_size = info.get_current_window_state().size.get_logical_size();
+        let popup_size = LogicalSize::new(
+            container_rect.size.width.max(POPUP_MIN_WIDTH),
+            // + 2px for the top / bottom border
+            (drop_down.inner.choices.len() as isize * ENTRY_HEIGHT) as f32 + 2.0,
+        );
+
+        // opened via the keyboard: the popup opens at the bottom left corner of the drop-down
+        let cursor = cursor.unwrap_or(LogicalPosition::new(button_rect.min_x(), button_rect.max_y()));
+        let popup_rect = drop_down.inner.popup_position.get_popup_rect(container_rect, cursor, popup_size, window_size);
+
+        // the popup is positioned relative to the container
+        let container_border = button_rect.origin - container_rect.origin;
+        let left = popup_rect.origin.x - container_rect.origin.x - container_border.x;
+        let top = popup_rect.origin.y - container_rect.origin.y - container_border.y;
+
+        info.set_css_property(nodes.popup, CssProperty::const_display(LayoutDisplay::Flex));
+        info.set_css_property(nodes.popup, CssProperty::const_left(LayoutLeft::px(left)));
+        info.set_css_property(nodes.popup, CssProperty::const_top(LayoutTop::px(top)));
+        info.set_css_property(nodes.popup, CssProperty::const_width(LayoutWidth::px(popup_rect.size.width)));
+        info.set_css_property(nodes.popup, CssProperty::const_height(LayoutHeight::px(popup_rect.size.height)));
+
+        let highlighted = drop_down.highlighted.into_option();
+        for (i, entry) in nodes.get_entries(info).into_iter().enumerate() {
+            let background = if highlighted == Some(i) { ENTRY_HIGHLIGHTED_THEME } else { ENTRY_NOT_HIGHLIGHTED_THEME };
+            info.set_css_property(entry, CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(background)));
+        }
+    }
+}
+
+impl From<DropDown> for Dom {
+    fn from(d: DropDown) -> Dom {
+        d.dom()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_drop_down_keyboard() {
+
+        let choices = vec!["Apple", "Banana", "Blueberry", "Cherry", "Date"]
+            .into_iter()
+            .map(|s| AzString::from(String::from(s)))
+            .collect::<Vec<_>>();
+
+        let mut drop_down = DropDown::new(choices.into());
+        drop_down.set_disabled_choices(vec![0u32, 2].into());
+        let mut state = drop_down.state;
+        let keyboard_state = KeyboardState::default();
+
+        // the first choice is disabled, so the second one is selected by default
+        assert_eq!(state.inner.selected.into_option(), Some(1));
+
+        // disabled choices are skipped
+        assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Down, &keyboard_state));
+        assert_eq!(state.inner.selected.into_option(), Some(3));
+        assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Home, &keyboard_state));
+        assert_eq!(state.inner.selected.into_option(), Some(1));
+
+        // while the popup is open, choices are only highlighted
+        state.handle_on_virtual_key_down(VirtualKeyCode::Return, &keyboard_state);
+        assert!(state.is_open);
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::End, &keyboard_state));
+        assert_eq!(state.highlighted.into_option(), Some(4));
+        assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Return, &keyboard_state));
+        assert!(!state.is_open);
+        assert_eq!(state.inner.selected.into_option(), Some(4));
+
+        // type-ahead search
+        assert!(state.handle_on_text_input('c', true));
+        assert_eq!(state.inner.selected.into_option(), Some(3));
+        assert!(state.handle_on_text_input('b', true));
+        assert_eq!(state.inner.selected.into_option(), Some(1));
+        assert!(!state.handle_on_text_input('a', false));
+        assert_eq!(state.inner.get_selected_label().as_str(), "Banana");
+
+        assert_eq!(get_entry_at(50.0, 5), Some(2));
+        assert_eq!(get_entry_at(500.0, 5), None);
+    }
+
+    fn drop_down_with_choices(choices: &[&str]) -> DropDown {
+        let choices = choices.iter()
+            .map(|s| AzString::from(String::from(*s)))
+            .collect::<Vec<_>>();
+        DropDown::new(choices.into())
+    }
+
+    #[test]
+    fn test_drop_down_keyboard_navigation() {
+
+        let choices = (0..25).map(|i| format!("Choice {}", i)).collect::<Vec<_>>();
+        let choices = choices.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        let mut state = drop_down_with_choices(&choices).state;
+        let keyboard_state = KeyboardState::default();
+        let alt_down = KeyboardState { alt_down: true, .. KeyboardState::default() };
+
+        // moving past the first / last choice does not wrap around
+        assert_eq!(state.inner.selected.into_option(), Some(0));
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Up, &keyboard_state));
+        assert_eq!(state.inner.selected.into_option(), Some(0));
+
+        assert!(state.handle_on_virtual_key_down(VirtualKeyCode::PageDown, &keyboard_state));
+        assert_eq!(state.inner.selected.into_option(), Some(10));
+        assert!(state.handle_on_virtual_key_down(VirtualKeyCode::PageDown, &keyboard_state));
+        assert!(state.handle_on_virtual_key_down(VirtualKeyCode::PageDown, &keyboard_state));
+        assert_eq!(state.inner.selected.into_option(), Some(24));
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::End, &keyboard_state));
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Down, &keyboard_state));
+        assert!(state.handle_on_virtual_key_down(VirtualKeyCode::PageUp, &keyboard_state));
+        assert_eq!(state.inner.selected.into_option(), Some(14));
+        assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Home, &keyboard_state));
+        assert_eq!(state.inner.selected.into_option(), Some(0));
+
+        // Alt + Down opens the popup, Escape closes it without changing the selection
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Down, &alt_down));
+        assert!(state.is_open);
+        assert_eq!(state.highlighted.into_option(), Some(0));
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Down, &keyboard_state));
+        assert_eq!(state.highlighted.into_option(), Some(1));
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Escape, &keyboard_state));
+        assert!(!state.is_open);
+        assert_eq!(state.highlighted.into_option(), None);
+        assert_eq!(state.inner.selected.into_option(), Some(0));
+
+        // the popup starts at the selected choice again, Tab and Alt + Up close it as well
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Space, &keyboard_state));
+        assert_eq!(state.highlighted.into_option(), Some(0));
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::PageDown, &keyboard_state));
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Tab, &keyboard_state));
+        assert!(!state.is_open);
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Down, &alt_down));
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Up, &alt_down));
+        assert!(!state.is_open);
+        assert_eq!(state.inner.selected.into_option(), Some(0));
+
+        // Enter selects the highlighted choice
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::NumpadEnter, &keyboard_state));
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::End, &keyboard_state));
+        assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Return, &keyboard_state));
+        assert!(!state.is_open);
+        assert_eq!(state.inner.selected.into_option(), Some(24));
+    }
+
+    #[test]
+    fn test_drop_down_type_ahead() {
+
+        let mut state = drop_down_with_choices(&["Apple", "Banana", "Blueberry", "Blue moon", "Cherry"]).state;
+        let keyboard_state = KeyboardState::default();
+
+        // typing the same letter repeatedly cycles through the matching choices
+        assert!(state.handle_on_text_input('b', true));
+        assert_eq!(state.inner.selected.into_option(), Some(1));
+        assert!(state.handle_on_text_input('b', false));
+        assert_eq!(state.inner.selected.into_option(), Some(2));
+        assert!(state.handle_on_text_input('b', false));
+        assert_eq!(state.inner.selected.into_option(), Some(3));
+        assert!(state.handle_on_text_input('b', false));
+        assert_eq!(state.inner.selected.into_option(), Some(1));
+
+        // typing more characters refines the search (case-insensitive),
+        // spaces are only part of the search if it is not empty
+        assert!(!state.handle_on_text_input('B', true));
+        assert!(state.handle_on_text_input('L', false));
+        assert_eq!(state.inner.selected.into_option(), Some(2));
+        assert!(!state.handle_on_text_input('u', false));
+        assert!(!state.handle_on_text_input('e', false));
+        assert!(state.handle_on_text_input(' ', false));
+        assert_eq!(state.inner.get_selected_label().as_str(), "Blue moon");
+
+        // any other key ends the search
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Left, &keyboard_state));
+        assert!(state.search.as_str().is_empty());
+        assert!(!state.handle_on_text_input(' ', false));
+        assert!(!state.handle_on_text_input('\u{8}', false));
+        assert!(state.search.as_str().is_empty());
+
+        // no match keeps the selection
+        assert!(!state.handle_on_text_input('z', true));
+        assert_eq!(state.inner.selected.into_option(), Some(3));
+
+        // while the popup is open, the match is only highlighted
+        state.handle_on_virtual_key_down(VirtualKeyCode::Return, &keyboard_state);
+        assert!(!state.handle_on_text_input('c', true));
+        assert_eq!(state.highlighted.into_option(), Some(4));
+        assert_eq!(state.inner.selected.into_option(), Some(3));
+        assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Return, &keyboard_state));
+        assert_eq!(state.inner.get_selected_label().as_str(), "Cherry");
+    }
+
+    #[test]
+    fn test_drop_down_disabled_choices() {
+
+        let mut drop_down = drop_down_with_choices(&["Apple", "Banana", "Cherry", "Date", "Elderberry"]);
+
+        // disabling the selected choice selects the first enabled one
+        drop_down.set_disabled_choices(vec![0u32, 1, 4].into());
+        assert_eq!(drop_down.state.inner.selected.into_option(), Some(2));
+
+        // disabled or out-of-range choices can't be selected
+        drop_down.set_selected(1);
+        drop_down.set_selected(10);
+        assert_eq!(drop_down.state.inner.selected.into_option(), Some(2));
+        drop_down.set_selected(3);
+        assert_eq!(drop_down.state.inner.selected.into_option(), Some(3));
+
+        let mut state = drop_down.state;
+        let keyboard_state = KeyboardState::default();
+        assert!(!state.select(Some(4)));
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::End, &keyboard_state));
+        assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Home, &keyboard_state));
+        assert_eq!(state.inner.selected.into_option(), Some(2));
+        assert!(!state.handle_on_text_input('a', true));
+        assert!(!state.handle_on_text_input('e', true));
+        assert_eq!(state.inner.selected.into_option(), Some(2));
+
+        // the popup skips disabled choices as well
+        state.handle_on_virtual_key_down(VirtualKeyCode::Return, &keyboard_state);
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Up, &keyboard_state));
+        assert_eq!(state.highlighted.into_option(), Some(2));
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::PageDown, &keyboard_state));
+        assert_eq!(state.highlighted.into_option(), Some(3));
+        state.handle_on_virtual_key_down(VirtualKeyCode::Escape, &keyboard_state);
+
+        // nothing can be selected if all choices are disabled
+        let mut drop_down = drop_down_with_choices(&["Apple", "Banana"]);
+        drop_down.set_disabled_choices(vec![0u32, 1].into());
+        assert_eq!(drop_down.state.inner.selected.into_option(), None);
+        assert_eq!(drop_down.state.inner.get_selected_label().as_str(), "");
+        let mut state = drop_down.state;
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Down, &keyboard_state));
+        assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::End, &keyboard_state));
+        assert!(!state.handle_on_text_input('b', true));
+        assert_eq!(state.inner.selected.into_option(), None);
+
+        assert_eq!(get_entry_at(-1.0, 2), None);
+        assert_eq!(get_entry_at(0.0, 2), Some(0));
+        assert_eq!(get_entry_at(ENTRY_HEIGHT as f32 * 2.0, 2), None);
+    }
+}
@@ -73,16 +73,16 @@ pub mod check_box;
 /// Box displaying a color which opens a color picker dialog on being clicked
 pub mod color_input;
 /// Dropdown selection widget
-pub mod drop_down; // TODO
+pub mod drop_down;
 /// Label widget (centered text)
 pub mod label;
 // /// Single line text input widget
 pub mod text_input;
 /// Same as text input, but only allows numeric input
 pub mod number_input;
-// /// Spreadsheet (iframe) widget
-// pub mod spreadsheet;
-// /// Slider widget
-// pub mod slider;
-// /// Multi-line text input
-// pub mod text_edit;
\ No newline at end of file
+/// Spreadsheet (iframe) widget
+pub mod spreadsheet;
+/// Slider widget
+pub mod slider;
+/// Multi-line text input
+pub mod text_edit;
\ No newline at end of file
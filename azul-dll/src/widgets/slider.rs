@@ -0,0 +1,958 @@
+//! Horizontal or vertical slider with optional tick marks and labels
+
+use azul_desktop::{
+    css::*,
+    css::AzString,
+    dom::{
+        Dom, TabIndex, IdOrClass, IdOrClass::Class, IdOrClassVec,
+        AccessibilityInfo, AccessibilityRole,
+        NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
+        NodeDataInlineCssProperty::{Normal, Hover, Focus},
+    },
+    callbacks::{RefAny, Callback, CallbackInfo, Update},
+};
+use azul_core::window::{KeyboardState, VirtualKeyCode};
+use std::vec::Vec;
+use std::string::String;
+
+/// Size of the thumb along the slider axis
+const THUMB_LENGTH: isize = 12;
+/// Size of the thumb across the slider axis
+const THUMB_THICKNESS: isize = 20;
+/// PageUp / PageDown moves the slider by a tenth of its range
+const PAGE_FRACTION: f32 = 0.1;
+
+static SLIDER_CONTAINER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-container"))];
+static SLIDER_TRACK_AREA_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-track-area"))];
+static SLIDER_TRACK_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-track"))];
+static SLIDER_THUMB_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-thumb"))];
+static SLIDER_TICKS_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-ticks"))];
+static SLIDER_TICK_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-tick"))];
+static SLIDER_LABELS_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-labels"))];
+static SLIDER_LABEL_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-label"))];
+
+pub type SliderOnValueChangeCallbackType = extern "C" fn(&mut RefAny, &SliderState, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct SliderOnValueChangeCallback {
+    pub cb: SliderOnValueChangeCallbackType,
+}
+
+impl_callback!(SliderOnValueChangeCallback);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct Slider {
+    pub state: SliderStateWrapper,
+    /// Style for the slider container
+    pub container_style: NodeDataInlineCssPropertyVec,
+    /// Style for the (thin) track the thumb moves along
+    pub track_style: NodeDataInlineCssPropertyVec,
+    /// Style for the draggable thumb
+    pub thumb_style: NodeDataInlineCssPropertyVec,
+    /// Style for the tick marks
+    pub tick_style: NodeDataInlineCssPropertyVec,
+    /// Style for the tick labels
+    pub label_style: NodeDataInlineCssPropertyVec,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+#[repr(C)]
+pub struct SliderStateWrapper {
+    /// Current value, range and layout of the slider
+    pub inner: SliderState,
+    /// Optional: Function to call when the value of the slider changes
+    pub on_value_change: OptionSliderOnValueChange,
+    // whether the thumb is currently dragged with the mouse
+    is_dragging: bool,
+    // value of the drag before snapping to the step, so that small mouse movements add up
+    drag_value: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct SliderOnValueChange {
+    pub data: RefAny,
+    pub callback: SliderOnValueChangeCallback,
+}
+
+impl_option!(SliderOnValueChange, OptionSliderOnValueChange, copy = false, [Debug, Clone, PartialEq, PartialOrd]);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum SliderOrientation {
+    /// Minimum on the left, maximum on the right
+    Horizontal,
+    /// Minimum at the bottom, maximum at the top
+    Vertical,
+}
+
+impl Default for SliderOrientation {
+    fn default() -> Self { SliderOrientation::Horizontal }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct SliderState {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    /// Values are rounded to `min + n * step`, a step of 0 allows any value
+    pub step: f32,
+    pub orientation: SliderOrientation,
+    /// Number of evenly spaced tick marks (including the first and last one), 0 = no tick marks
+    pub tick_count: usize,
+    /// Whether to show the value of each tick mark
+    pub show_tick_labels: bool,
+}
+
+impl Default for SliderState {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            min: 0.0,
+            max: 100.0,
+            step: 1.0,
+            orientation: SliderOrientation::Horizontal,
+            tick_count: 0,
+            show_tick_labels: false,
+        }
+    }
+}
+
+const COLOR_9B9B9B: ColorU = ColorU { r: 155, g: 155, b: 155, a: 255 }; // #9b9b9b
+const COLOR_4286F4: ColorU = ColorU { r: 66, g: 134, b: 244, a: 255 }; // #4286f4
+const COLOR_4C4C4C: ColorU = ColorU { r: 76, g: 76, b: 76, a: 255 }; // #4C4C4C
+const COLOR_E5E5E5: ColorU = ColorU { r: 229, g: 229, b: 229, a: 255 }; // #e5e5e5
+const COLOR_TRANSPARENT: ColorU = ColorU { r: 0, g: 0, b: 0, a: 0 };
+
+const TRACK_THEME: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_E5E5E5)];
+const THUMB_THEME: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_9B9B9B)];
+const THUMB_THEME_HOVER: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_4286F4)];
+const TICK_THEME: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_9B9B9B)];
+
+const SANS_SERIF_STR: &str = "sans-serif";
+const SANS_SERIF: AzString = AzString::from_const_str(SANS_SERIF_STR);
+const SANS_SERIF_FAMILIES: &[StyleFontFamily] = &[StyleFontFamily::System(SANS_SERIF)];
+const SANS_SERIF_FAMILY: StyleFontFamilyVec = StyleFontFamilyVec::from_const_slice(SANS_SERIF_FAMILIES);
+
+static DEFAULT_SLIDER_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
+
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(2))),
+    Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(2))),
+    Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(2))),
+
+    // border: 1px solid transparent, only visible when focused
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_TRANSPARENT })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_TRANSPARENT })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_TRANSPARENT })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_TRANSPARENT })),
+
+    // Focus(border-color: #4286f4;)
+
+    Focus(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_4286F4 })),
+];
+
+static DEFAULT_SLIDER_TRACK_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(TRACK_THEME))),
+    Normal(CssProperty::const_border_top_left_radius(StyleBorderTopLeftRadius::const_px(2))),
+    Normal(CssProperty::const_border_top_right_radius(StyleBorderTopRightRadius::const_px(2))),
+    Normal(CssProperty::const_border_bottom_left_radius(StyleBorderBottomLeftRadius::const_px(2))),
+    Normal(CssProperty::const_border_bottom_right_radius(StyleBorderBottomRightRadius::const_px(2))),
+];
+
+static DEFAULT_SLIDER_THUMB_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_cursor(StyleCursor::Pointer)),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(THUMB_THEME))),
+    Normal(CssProperty::const_border_top_left_radius(StyleBorderTopLeftRadius::const_px(3))),
+    Normal(CssProperty::const_border_top_right_radius(StyleBorderTopRightRadius::const_px(3))),
+    Normal(CssProperty::const_border_bottom_left_radius(StyleBorderBottomLeftRadius::const_px(3))),
+    Normal(CssProperty::const_border_bottom_right_radius(StyleBorderBottomRightRadius::const_px(3))),
+    Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(THUMB_THEME_HOVER))),
+];
+
+static DEFAULT_SLIDER_TICK_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(TICK_THEME))),
+];
+
+static DEFAULT_SLIDER_LABEL_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+];
+
+// layout properties, appended to the user-defined styles
+
+static HORIZONTAL_CONTAINER_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(100))),
+];
+
+static VERTICAL_CONTAINER_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_min_height(LayoutMinHeight::const_px(100))),
+];
+
+static HORIZONTAL_TRACK_AREA_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(THUMB_THICKNESS))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+];
+
+static VERTICAL_TRACK_AREA_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(THUMB_THICKNESS))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+];
+
+static HORIZONTAL_TRACK_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    Normal(CssProperty::const_top(LayoutTop::const_px(THUMB_THICKNESS / 2 - 2))),
+    Normal(CssProperty::const_width(LayoutWidth::const_percent(100))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(4))),
+];
+
+static VERTICAL_TRACK_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(THUMB_THICKNESS / 2 - 2))),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(4))),
+    Normal(CssProperty::const_height(LayoutHeight::const_percent(100))),
+];
+
+// the anchor is positioned at "value%" of the track area, the thumb
+// inside is moved back by "value%" of its own size, so that the thumb
+// is never drawn outside of the track area
+static HORIZONTAL_THUMB_ANCHOR_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(0))),
+];
+
+static VERTICAL_THUMB_ANCHOR_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(0))),
+];
+
+static HORIZONTAL_THUMB_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(THUMB_LENGTH))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(THUMB_THICKNESS))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+];
+
+static VERTICAL_THUMB_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(THUMB_THICKNESS))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(THUMB_LENGTH))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+];
+
+// ticks and labels are spread out evenly between the centers of the leftmost and rightmost thumb position
+
+static HORIZONTAL_TICKS_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_justify_content(LayoutJustifyContent::SpaceBetween)),
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(THUMB_LENGTH / 2))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(THUMB_LENGTH / 2))),
+    Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(2))),
+];
+
+static VERTICAL_TICKS_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_justify_content(LayoutJustifyContent::SpaceBetween)),
+    Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(THUMB_LENGTH / 2))),
+    Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(THUMB_LENGTH / 2))),
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
+];
+
+static HORIZONTAL_TICK_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_width(LayoutWidth::const_px(1))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(4))),
+];
+
+static VERTICAL_TICK_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_width(LayoutWidth::const_px(4))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(1))),
+];
+
+impl Default for Slider {
+    fn default() -> Self {
+        Self {
+            state: SliderStateWrapper::default(),
+            container_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_SLIDER_CONTAINER_STYLE),
+            track_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_SLIDER_TRACK_STYLE),
+            thumb_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_SLIDER_THUMB_STYLE),
+            tick_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_SLIDER_TICK_STYLE),
+            label_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_SLIDER_LABEL_STYLE),
+        }
+    }
+}
+
+impl Slider {
+
+    pub fn new(value: f32, min: f32, max: f32) -> Self {
+        let mut s = Self::default();
+        s.state.inner.min = min.min(max);
+        s.state.inner.max = max.max(min);
+        s.state.inner.value = s.state.inner.snap_value(value);
+        s
+    }
+
+    pub fn set_step(&mut self, step: f32) {
+        self.state.inner.step = step.max(0.0);
+        self.state.inner.value = self.state.inner.snap_value(self.state.inner.value);
+    }
+
+    pub fn set_orientation(&mut self, orientation: SliderOrientation) {
+        self.state.inner.orientation = orientation;
+    }
+
+    pub fn set_tick_count(&mut self, tick_count: usize) {
+        self.state.inner.tick_count = tick_count;
+    }
+
+    pub fn set_show_tick_labels(&mut self, show_tick_labels: bool) {
+        self.state.inner.show_tick_labels = show_tick_labels;
+    }
+
+    pub fn set_on_value_change(&mut self, data: RefAny, callback: SliderOnValueChangeCallbackType) {
+        self.state.on_value_change = Some(SliderOnValueChange {
+            callback: SliderOnValueChangeCallback { cb: callback },
+            data
+        }).into();
+    }
+
+    pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.container_style = style;
+    }
+
+    pub fn set_track_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.track_style = style;
+    }
+
+    pub fn set_thumb_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.thumb_style = style;
+    }
+
+    pub fn set_tick_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.tick_style = style;
+    }
+
+    pub fn set_label_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.label_style = style;
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::default();
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    pub fn dom(self) -> Dom {
+
+        use azul_desktop::dom::{CallbackData, EventFilter, HoverEventFilter, FocusEventFilter, WindowEventFilter};
+
+        let horizontal = self.state.inner.orientation == SliderOrientation::Horizontal;
+        let layout = |horizontal_props, vertical_props| if horizontal { horizontal_props } else { vertical_props };
+
+        let tick_values = self.state.inner.get_tick_values();
+        let value_string = self.state.inner.format_value(self.state.inner.value);
+        let (anchor_position, thumb_position) = get_thumb_position(&self.state.inner);
+
+        let mut children = vec![
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(SLIDER_TRACK_AREA_CLASS))
+            .with_inline_css_props(layout(HORIZONTAL_TRACK_AREA_LAYOUT, VERTICAL_TRACK_AREA_LAYOUT).into())
+            .with_children(vec![
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(SLIDER_TRACK_CLASS))
+                .with_inline_css_props(with_layout(self.track_style, layout(HORIZONTAL_TRACK_LAYOUT, VERTICAL_TRACK_LAYOUT), &[])),
+                Dom::div()
+                .with_inline_css_props(with_layout(
+                    NodeDataInlineCssPropertyVec::from_const_slice(&[]),
+                    layout(HORIZONTAL_THUMB_ANCHOR_LAYOUT, VERTICAL_THUMB_ANCHOR_LAYOUT),
+                    &[Normal(anchor_position)],
+                ))
+                .with_children(vec![
+                    Dom::div()
+                    .with_ids_and_classes(IdOrClassVec::from_const_slice(SLIDER_THUMB_CLASS))
+                    .with_inline_css_props(with_layout(
+                        self.thumb_style,
+                        layout(HORIZONTAL_THUMB_LAYOUT, VERTICAL_THUMB_LAYOUT),
+                        &[Normal(thumb_position)],
+                    ))
+                ].into()),
+            ].into()),
+        ];
+
+        if !tick_values.is_empty() {
+            let tick_style = &self.tick_style;
+            children.push(
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(SLIDER_TICKS_CLASS))
+                .with_inline_css_props(layout(HORIZONTAL_TICKS_LAYOUT, VERTICAL_TICKS_LAYOUT).into())
+                .with_children(tick_values.iter().map(|_| {
+                    Dom::div()
+                    .with_ids_and_classes(IdOrClassVec::from_const_slice(SLIDER_TICK_CLASS))
+                    .with_inline_css_props(with_layout(tick_style.clone(), layout(HORIZONTAL_TICK_LAYOUT, VERTICAL_TICK_LAYOUT), &[]))
+                }).collect::<Vec<_>>().into())
+            );
+        }
+
+        if !tick_values.is_empty() && self.state.inner.show_tick_labels {
+            let (slider, label_style) = (&self.state.inner, &self.label_style);
+            children.push(
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(SLIDER_LABELS_CLASS))
+                .with_inline_css_props(layout(HORIZONTAL_TICKS_LAYOUT, VERTICAL_TICKS_LAYOUT).into())
+                .with_children(tick_values.iter().map(|v| {
+                    Dom::text(slider.format_value(*v))
+                    .with_ids_and_classes(IdOrClassVec::from_const_slice(SLIDER_LABEL_CLASS))
+                    .with_inline_css_props(label_style.clone())
+                }).collect::<Vec<_>>().into())
+            );
+        }
+
+        let accessibility_info = AccessibilityInfo {
+            name: None.into(),
+            value: Some(AzString::from(value_string)).into(),
+            role: AccessibilityRole::Slider,
+            states: Vec::new().into(),
+            accelerator: None.into(),
+            default_action: None.into(),
+        };
+
+        let container_style = with_layout(self.container_style, layout(HORIZONTAL_CONTAINER_LAYOUT, VERTICAL_CONTAINER_LAYOUT), &[]);
+        let state_ref = RefAny::new(self.state);
+
+        Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(SLIDER_CONTAINER_CLASS))
+        .with_inline_css_props(container_style)
+        .with_tab_index(TabIndex::Auto)
+        .with_accessibility_info(accessibility_info)
+        .with_dataset(Some(state_ref.clone()).into())
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_virtual_key_down }
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_down }
+            },
+            // dragging continues when the mouse leaves the slider
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::MouseOver),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_drag }
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::LeftMouseUp),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_up }
+            },
+        ].into())
+        .with_children(children.into())
+    }
+}
+
+/// Appends the layout properties (and the dynamic properties) to the user-defined style
+fn with_layout(
+    style: NodeDataInlineCssPropertyVec,
+    layout: &[NodeDataInlineCssProperty],
+    dynamic: &[NodeDataInlineCssProperty],
+) -> NodeDataInlineCssPropertyVec {
+    let mut style = style.into_library_owned_vec();
+    style.extend(layout.iter().cloned());
+    style.extend(dynamic.iter().cloned());
+    style.into()
+}
+
+/// Returns the position of the thumb anchor (in percent of the track area)
+/// and of the thumb (in pixels, relative to the anchor)
+fn get_thumb_position(state: &SliderState) -> (CssProperty, CssProperty) {
+    match state.orientation {
+        SliderOrientation::Horizontal => {
+            let ratio = state.get_ratio();
+            (
+                CssProperty::const_left(LayoutLeft::percent(ratio * 100.0)),
+                CssProperty::const_left(LayoutLeft::px(-ratio * THUMB_LENGTH as f32)),
+            )
+        },
+        SliderOrientation::Vertical => {
+            let ratio = 1.0 - state.get_ratio();
+            (
+                CssProperty::const_top(LayoutTop::percent(ratio * 100.0)),
+                CssProperty::const_top(LayoutTop::px(-ratio * THUMB_LENGTH as f32)),
+            )
+        },
+    }
+}
+
+impl SliderState {
+
+    /// Clamps the value to `min..=max` and rounds it to the nearest step
+    pub fn snap_value(&self, value: f32) -> f32 {
+        let value = if value.is_nan() { self.min } else { value };
+        let value = value.max(self.min).min(self.max);
+        if self.step <= 0.0 {
+            return value;
+        }
+        let mut snapped = self.min + ((value - self.min) / self.step).round() * self.step;
+        // if the range isn't a multiple of the step, the last step below max is the largest value
+        if snapped > self.max + self.step * 0.001 {
+            snapped -= self.step;
+        }
+        snapped.max(self.min).min(self.max)
+    }
+
+    /// Position of the value within the range, from 0.0 (min) to 1.0 (max)
+    pub fn get_ratio(&self) -> f32 {
+        let range = self.max - self.min;
+        if range <= 0.0 {
+            0.0
+        } else {
+            ((self.value - self.min) / range).max(0.0).min(1.0)
+        }
+    }
+
+    /// Returns the values of the tick marks, from min to max
+    pub fn get_tick_values(&self) -> Vec<f32> {
+        match self.tick_count {
+            0 => Vec::new(),
+            1 => vec![self.min],
+            n => (0..n).map(|i| self.min + (self.max - self.min) * i as f32 / (n - 1) as f32).collect(),
+        }
+    }
+
+    /// Formats the value with as many decimal places as the step has
+    fn format_value(&self, value: f32) -> String {
+        let mut decimals = 0;
+        while decimals < 6 && (self.step * 10.0_f32.powi(decimals as i32)).fract().abs() > 0.0001 {
+            decimals += 1;
+        }
+        format!("{:.*}", decimals, value)
+    }
+
+    /// Amount that the arrow keys change the value by
+    fn get_key_step(&self) -> f32 {
+        if self.step > 0.0 { self.step } else { (self.max - self.min) / 100.0 }
+    }
+
+    /// Amount that PageUp / PageDown change the value by (at least one step)
+    fn get_page_step(&self) -> f32 {
+        ((self.max - self.min) * PAGE_FRACTION).max(self.get_key_step())
+    }
+}
+
+impl SliderStateWrapper {
+
+    /// Sets the value (snapped to the step), returns whether the value changed
+    fn set_value(&mut self, value: f32) -> bool {
+        let new_value = self.inner.snap_value(value);
+        let changed = new_value != self.inner.value;
+        self.inner.value = new_value;
+        changed
+    }
+
+    /// Returns whether the value changed
+    fn handle_on_virtual_key_down(&mut self, virtual_key: VirtualKeyCode, keyboard_state: &KeyboardState) -> bool {
+
+        // shift moves the slider by a page instead of a single step
+        let step = if keyboard_state.shift_down { self.inner.get_page_step() } else { self.inner.get_key_step() };
+        let value = self.inner.value;
+
+        let new_value = match virtual_key {
+            VirtualKeyCode::Right | VirtualKeyCode::Up => value + step,
+            VirtualKeyCode::Left | VirtualKeyCode::Down => value - step,
+            VirtualKeyCode::PageUp => value + self.inner.get_page_step(),
+            VirtualKeyCode::PageDown => value - self.inner.get_page_step(),
+            VirtualKeyCode::Home => self.inner.min,
+            VirtualKeyCode::End => self.inner.max,
+            _ => return false,
+        };
+
+        self.set_value(new_value)
+    }
+
+    /// Clicking on the track (not on the thumb) moves the thumb to the cursor, `cursor` is
+    /// the position along the track (0.0 = min), returns whether the value changed
+    fn handle_mouse_down(&mut self, cursor: f32, track_length: f32) -> bool {
+        let thumb_start = self.inner.get_ratio() * track_length;
+        let value_changed = if track_length > 0.0 && (cursor < thumb_start || cursor > thumb_start + THUMB_LENGTH as f32) {
+            let ratio = ((cursor - THUMB_LENGTH as f32 / 2.0) / track_length).max(0.0).min(1.0);
+            self.set_value(self.inner.min + ratio * (self.inner.max - self.inner.min))
+        } else {
+            false
+        };
+        self.start_drag();
+        value_changed
+    }
+
+    fn start_drag(&mut self) {
+        self.is_dragging = true;
+        self.drag_value = self.inner.value;
+    }
+
+    /// Moves the slider by the mouse movement `delta` (in pixels along the slider axis,
+    /// positive = towards max) on a track of `track_length` pixels, returns whether the value changed
+    fn handle_drag(&mut self, delta: f32, track_length: f32) -> bool {
+        if !self.is_dragging || track_length <= 0.0 {
+            return false;
+        }
+        let drag_value = self.drag_value + delta / track_length * (self.inner.max - self.inner.min);
+        self.drag_value = drag_value.max(self.inner.min).min(self.inner.max);
+        self.set_value(self.drag_value)
+    }
+}
+
+// handle input events for the Slider
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, DomNodeId};
+    use azul_core::window::LogicalPosition;
+    use super::{SliderStateWrapper, SliderOnValueChange, SliderOrientation, THUMB_LENGTH};
+
+    /// Nodes of the slider, see `Slider::dom()`
+    struct SliderNodes {
+        container: DomNodeId,
+        track_area: DomNodeId,
+        thumb_anchor: DomNodeId,
+        thumb: DomNodeId,
+    }
+
+    impl SliderNodes {
+        fn new(info: &CallbackInfo) -> Option<Self> {
+            let container = info.get_hit_node();
+            let track_area = info.get_first_child(container)?;
+            let track = info.get_first_child(track_area)?;
+            let thumb_anchor = info.get_next_sibling(track)?;
+            let thumb = info.get_first_child(thumb_anchor)?;
+            Some(Self { container, track_area, thumb_anchor, thumb })
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_virtual_key_down(slider: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut slider = match slider.downcast_mut::<SliderStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let last_keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if !slider.handle_on_virtual_key_down(last_keycode, &keyboard_state) {
+            return Update::DoNothing;
+        }
+
+        on_value_changed(&mut *slider, &mut info)
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_down(slider: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut slider = match slider.downcast_mut::<SliderStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let nodes = match SliderNodes::new(&info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let (cursor, track_length) = match get_cursor_on_track(&slider, &nodes, &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if slider.handle_mouse_down(cursor, track_length) {
+            on_value_changed(&mut *slider, &mut info)
+        } else {
+            Update::DoNothing
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_drag(slider: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut slider = match slider.downcast_mut::<SliderStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if !slider.is_dragging {
+            return Update::DoNothing;
+        }
+
+        // the mouse button might have been released outside of the window
+        let current_mouse_state = info.get_current_mouse_state();
+        if !current_mouse_state.left_down {
+            slider.is_dragging = false;
+            return Update::DoNothing;
+        }
+
+        let current_position = current_mouse_state.cursor_position.get_position();
+        let previous_position = info.get_previous_mouse_state().and_then(|m| m.cursor_position.get_position());
+        let delta = match (current_position, previous_position) {
+            (Some(c), Some(p)) => c - p,
+            _ => return Update::DoNothing,
+        };
+
+        let nodes = match SliderNodes::new(&info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let track_length = match get_track_length(&slider, &nodes, &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let delta = match slider.inner.orientation {
+            SliderOrientation::Horizontal => delta.x,
+            SliderOrientation::Vertical => -delta.y,
+        };
+
+        if !slider.handle_drag(delta, track_length) {
+            return Update::DoNothing;
+        }
+
+        on_value_changed(&mut *slider, &mut info)
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_up(slider: &mut RefAny, _info: CallbackInfo) -> Update {
+        if let Some(mut slider) = slider.downcast_mut::<SliderStateWrapper>() {
+            slider.is_dragging = false;
+        }
+        Update::DoNothing
+    }
+
+    /// Returns the length of the track that the left / top edge of the thumb can move along
+    fn get_track_length(slider: &SliderStateWrapper, nodes: &SliderNodes, info: &CallbackInfo) -> Option<f32> {
+        let size = info.get_node_size(nodes.track_area)?;
+        let length = match slider.inner.orientation {
+            SliderOrientation::Horizontal => size.width,
+            SliderOrientation::Vertical => size.height,
+        };
+        Some((length - THUMB_LENGTH as f32).max(0.0))
+    }
+
+    /// Returns the cursor position along the track (0.0 = min) and the length of the track
+    fn get_cursor_on_track(slider: &SliderStateWrapper, nodes: &SliderNodes, info: &CallbackInfo) -> Option<(f32, f32)> {
+        let track_length = get_track_length(slider, nodes, info)?;
+        let cursor_relative_to_container = info.get_cursor_relative_to_node().into_option()?;
+        let container_position = info.get_node_position(nodes.container)?.get_static_offset();
+        let track_area_position = info.get_node_position(nodes.track_area)?.get_static_offset();
+        let cursor: LogicalPosition = cursor_relative_to_container - (track_area_position - container_position);
+        let cursor = match slider.inner.orientation {
+            SliderOrientation::Horizontal => cursor.x,
+            SliderOrientation::Vertical => track_length + THUMB_LENGTH as f32 - cursor.y,
+        };
+        Some((cursor, track_length))
+    }
+
+    /// Moves the thumb and calls the on_value_change callback
+    fn on_value_changed(slider: &mut SliderStateWrapper, info: &mut CallbackInfo) -> Update {
+
+        if let Some(nodes) = SliderNodes::new(info) {
+            let (anchor_position, thumb_position) = super::get_thumb_position(&slider.inner);
+            info.set_css_property(nodes.thumb_anchor, anchor_position);
+            info.set_css_property(nodes.thumb, thumb_position);
+        }
+
+        let inner = &slider.inner;
+        match slider.on_value_change.as_mut() {
+            Some(SliderOnValueChange { callback, data }) => (callback.cb)(data, &inner, info),
+            None => Update::DoNothing,
+        }
+    }
+}
+
+impl From<Slider> for Dom {
+    fn from(s: Slider) -> Dom {
+        s.dom()
+    }
+}
+
+#[test]
+fn test_slider_value() {
+
+    let mut slider = Slider::new(33.0, 0.0, 100.0);
+    slider.set_step(5.0);
+    let mut state = slider.state;
+    assert_eq!(state.inner.value, 35.0);
+    assert_eq!(state.inner.snap_value(250.0), 100.0);
+
+    let keyboard_state = KeyboardState::default();
+    assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Right, &keyboard_state));
+    assert_eq!(state.inner.value, 40.0);
+    assert!(state.handle_on_virtual_key_down(VirtualKeyCode::PageDown, &keyboard_state));
+    assert_eq!(state.inner.value, 30.0);
+    assert!(state.handle_on_virtual_key_down(VirtualKeyCode::End, &keyboard_state));
+    assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Up, &keyboard_state));
+
+    // small mouse movements add up until they reach the next step
+    state.start_drag();
+    assert!(!state.handle_drag(-10.0, 1000.0));
+    assert!(!state.handle_drag(-10.0, 1000.0));
+    assert!(state.handle_drag(-10.0, 1000.0));
+    assert_eq!(state.inner.value, 95.0);
+
+    state.inner.tick_count = 3;
+    assert_eq!(state.inner.get_tick_values(), vec![0.0, 50.0, 100.0]);
+    state.inner.step = 0.25;
+    assert_eq!(state.inner.format_value(0.5), "0.50");
+}
+
+#[test]
+fn test_slider_step_clamping() {
+
+    // min and max are swapped if necessary
+    let mut slider = Slider::new(7.0, 10.0, 0.0);
+    assert_eq!((slider.state.inner.min, slider.state.inner.max), (0.0, 10.0));
+    assert_eq!(slider.state.inner.value, 7.0);
+
+    // changing the step snaps the current value
+    slider.set_step(2.5);
+    assert_eq!(slider.state.inner.value, 7.5);
+
+    let state = &slider.state.inner;
+    assert_eq!(state.snap_value(-3.0), 0.0);
+    assert_eq!(state.snap_value(11.0), 10.0);
+    assert_eq!(state.snap_value(f32::NAN), 0.0);
+    assert_eq!(state.snap_value(1.2), 0.0);
+    assert_eq!(state.snap_value(1.3), 2.5);
+
+    // if the range isn't a multiple of the step, max is not reachable
+    slider.set_step(3.0);
+    let state = &slider.state.inner;
+    assert_eq!(state.snap_value(10.0), 9.0);
+    assert_eq!(state.snap_value(100.0), 9.0);
+    assert_eq!(state.snap_value(7.4), 6.0);
+
+    // a step of 0 allows any value, negative steps are treated as 0
+    slider.set_step(-1.0);
+    assert_eq!(slider.state.inner.step, 0.0);
+    assert_eq!(slider.state.inner.snap_value(3.3), 3.3);
+    assert_eq!(slider.state.inner.snap_value(10.5), 10.0);
+
+    // the step is relative to min, not to 0
+    let slider = Slider::new(0.0, 1.0, 11.0);
+    let mut state = slider.state.inner;
+    state.step = 4.0;
+    assert_eq!(state.snap_value(4.0), 5.0);
+    assert_eq!(state.snap_value(11.0), 9.0);
+}
+
+#[test]
+fn test_slider_keyboard() {
+
+    let mut state = Slider::new(50.0, 0.0, 100.0).state;
+    let keyboard_state = KeyboardState::default();
+    let shift = KeyboardState { shift_down: true, .. Default::default() };
+
+    assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Left, &keyboard_state));
+    assert_eq!(state.inner.value, 49.0);
+    assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Up, &keyboard_state));
+    assert_eq!(state.inner.value, 50.0);
+
+    // shift + arrow key moves by a page (a tenth of the range)
+    assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Right, &shift));
+    assert_eq!(state.inner.value, 60.0);
+    assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Down, &shift));
+    assert_eq!(state.inner.value, 50.0);
+    assert!(state.handle_on_virtual_key_down(VirtualKeyCode::PageUp, &keyboard_state));
+    assert_eq!(state.inner.value, 60.0);
+
+    // Home / End jump to min / max, the value doesn't go past them
+    assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Home, &keyboard_state));
+    assert_eq!(state.inner.value, 0.0);
+    assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Left, &keyboard_state));
+    assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::PageDown, &keyboard_state));
+    assert!(state.handle_on_virtual_key_down(VirtualKeyCode::End, &keyboard_state));
+    assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Right, &shift));
+    assert_eq!(state.inner.value, 100.0);
+
+    // other keys are ignored
+    assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::A, &keyboard_state));
+    assert!(!state.handle_on_virtual_key_down(VirtualKeyCode::Return, &keyboard_state));
+
+    // without a step, the arrow keys move by a hundredth of the range
+    let mut slider = Slider::new(0.0, 0.0, 1.0);
+    slider.set_step(0.0);
+    let mut state = slider.state;
+    state.inner.value = 0.5;
+    assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Right, &keyboard_state));
+    assert!((state.inner.value - 0.51).abs() < 0.0001);
+
+    // a page is at least one step
+    let mut slider = Slider::new(0.0, 0.0, 10.0);
+    slider.set_step(5.0);
+    let mut state = slider.state;
+    assert!(state.handle_on_virtual_key_down(VirtualKeyCode::PageUp, &keyboard_state));
+    assert_eq!(state.inner.value, 5.0);
+}
+
+#[test]
+fn test_slider_drag() {
+
+    let mut slider = Slider::new(50.0, 0.0, 100.0);
+    slider.set_step(10.0);
+    let mut state = slider.state;
+
+    // dragging only works after the mouse went down on the slider
+    assert!(!state.handle_drag(500.0, 1000.0));
+    assert_eq!(state.inner.value, 50.0);
+
+    // mouse down on the thumb doesn't change the value
+    assert!(!state.handle_mouse_down(505.0, 1000.0));
+    assert!(state.handle_drag(200.0, 1000.0));
+    assert_eq!(state.inner.value, 70.0);
+
+    // dragging past the end clamps the value, dragging back moves it immediately
+    assert!(state.handle_drag(10_000.0, 1000.0));
+    assert_eq!(state.inner.value, 100.0);
+    assert!(state.handle_drag(-100.0, 1000.0));
+    assert_eq!(state.inner.value, 90.0);
+    assert!(!state.handle_drag(0.0, 0.0));
+
+    // after the mouse went up, moving the mouse doesn't change the value
+    state.is_dragging = false;
+    assert!(!state.handle_drag(-500.0, 1000.0));
+
+    // mouse down on the track jumps to the cursor and starts dragging from there
+    assert!(state.handle_mouse_down(206.0, 1000.0));
+    assert_eq!(state.inner.value, 20.0);
+    assert!(state.handle_drag(-1000.0, 1000.0));
+    assert_eq!(state.inner.value, 0.0);
+
+    // vertical sliders: the thumb position is relative to the bottom of the track
+    let mut slider = Slider::new(0.0, 0.0, 100.0);
+    slider.set_orientation(SliderOrientation::Vertical);
+    let mut state = slider.state;
+    assert!(state.handle_mouse_down(1006.0, 1000.0));
+    assert_eq!(state.inner.value, 100.0);
+}
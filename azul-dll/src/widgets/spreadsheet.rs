@@ -1,88 +1,208 @@
-//! Spreadsheet view
+//! Spreadsheet view, only the visible rows and columns are rendered (via an iframe)
 
+use core::mem;
 use core::ops::Range;
-use alloc::collections::BTreeMap;
-use alloc::string::String;
-use alloc::vec::Vec;
-use azul::{
-    style::StyledDom,
-    dom::{Dom, NodeData, NodeType},
-    callbacks::Update,
-    callbacks::{RefAny, IFrameCallbackInfo, IFrameCallbackReturn},
+use std::string::String;
+use std::vec::Vec;
+use azul_desktop::{
+    css::*,
+    css::AzString,
+    dom::{
+        Dom, TabIndex, IdOrClass, IdOrClass::Class, IdOrClassVec,
+        AccessibilityInfo, AccessibilityRole,
+        NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
+        NodeDataInlineCssProperty::Normal,
+    },
+    callbacks::{
+        RefAny, Callback, CallbackInfo, Update, OptionDomNodeId,
+        IFrameCallbackInfo, IFrameCallbackReturn,
+    },
+    task::Instant,
 };
+use azul_core::window::{KeyboardState, VirtualKeyCode, LogicalRect, LogicalPosition, LogicalSize};
+use crate::widgets::text_input::TextInput;
 
 pub type RowIndex = usize;
 pub type ColumnIndex = usize;
 
+/// Width of the row numbers on the left side of the table
+const ROW_HEADER_WIDTH: isize = 40;
+/// Height of the column names on the top of the table
+const COLUMN_HEADER_HEIGHT: isize = 20;
+/// Columns can't be resized to be smaller than this
+const MIN_COLUMN_WIDTH: f32 = 20.0;
+/// Width of the area at the right edge of a column name that resizes the column when dragged
+const RESIZE_HANDLE_WIDTH: isize = 5;
+/// Two clicks on the same cell within this time (in milliseconds) start editing the cell
+const DOUBLE_CLICK_TIMEOUT_MS: u64 = 500;
+
 pub const COLOR_407C40: ColorU = ColorU { r: 64, g: 124, b: 64, a: 255 }; // green
 
-#[derive(Debug, Clone)]
+static SPREADSHEET_CONTAINER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-spreadsheet-container"))];
+static SPREADSHEET_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-spreadsheet"))];
+static SPREADSHEET_CELLS_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-spreadsheet-cells"))];
+static SPREADSHEET_ROW_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-spreadsheet-row"))];
+static SPREADSHEET_CELL_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-spreadsheet-cell"))];
+static SPREADSHEET_SELECTION_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-spreadsheet-selection"))];
+static SPREADSHEET_COLUMN_NAMES_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-spreadsheet-column-names"))];
+static SPREADSHEET_COLUMN_NAME_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-spreadsheet-column-name"))];
+static SPREADSHEET_RESIZE_HANDLE_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-spreadsheet-resize-handle"))];
+static SPREADSHEET_ROW_NUMBERS_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-spreadsheet-row-numbers"))];
+static SPREADSHEET_ROW_NUMBER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-spreadsheet-row-number"))];
+static SPREADSHEET_TOP_LEFT_RECT_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-spreadsheet-top-left-rect"))];
+
+pub type SpreadsheetOnCellsChangeCallbackType = extern "C" fn(&mut RefAny, &SpreadsheetState, SpreadsheetCellSelection, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct SpreadsheetOnCellsChangeCallback {
+    pub cb: SpreadsheetOnCellsChangeCallbackType,
+}
+
+impl_callback!(SpreadsheetOnCellsChangeCallback);
+
+pub type SpreadsheetOnSelectionChangeCallbackType = extern "C" fn(&mut RefAny, &SpreadsheetState, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct SpreadsheetOnSelectionChangeCallback {
+    pub cb: SpreadsheetOnSelectionChangeCallbackType,
+}
+
+impl_callback!(SpreadsheetOnSelectionChangeCallback);
+
+pub type SpreadsheetOnColumnResizeCallbackType = extern "C" fn(&mut RefAny, &SpreadsheetState, ColumnIndex, &mut CallbackInfo) -> Update;
+
+#[repr(C)]
+pub struct SpreadsheetOnColumnResizeCallback {
+    pub cb: SpreadsheetOnColumnResizeCallbackType,
+}
+
+impl_callback!(SpreadsheetOnColumnResizeCallback);
+
+#[derive(Debug, Clone, PartialEq)]
 #[repr(C)]
 pub struct Spreadsheet {
-    pub state: SpreadsheetState,
-    // pub disable_selection: bool,
-    // pub on_cell_focus_received: Option<(OnCellEditFinishCallback, RefAny)>
-    // pub on_cell_focus_lost: Option<(OnCellEditFinishCallback, RefAny)>
-    // pub on_cell_edit_finish: Option<(OnCellEditFinishCallback, RefAny)>
+    pub state: SpreadsheetStateWrapper,
+    /// Style for the iframe containing the table
+    pub container_style: NodeDataInlineCssPropertyVec,
 }
 
-impl Default for SpreadsheetState {
-    fn default() -> Self {
-        Self {
-            state: SpreadsheetStateState::default(),
-        }
-    }
+#[derive(Debug, Default, Clone, PartialEq)]
+#[repr(C)]
+pub struct SpreadsheetStateWrapper {
+    /// Cell contents, sizes and the selection of the table
+    pub inner: SpreadsheetState,
+    /// Optional: Function to call when the user edits, pastes or deletes cell contents
+    pub on_cells_change: OptionSpreadsheetOnCellsChange,
+    /// Optional: Function to call when the user selects different cells
+    pub on_selection_change: OptionSpreadsheetOnSelectionChange,
+    /// Optional: Function to call when the user has resized a column
+    pub on_column_resize: OptionSpreadsheetOnColumnResize,
+    view: SpreadsheetView,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct SpreadsheetOnCellsChange {
+    pub data: RefAny,
+    pub callback: SpreadsheetOnCellsChangeCallback,
+}
+
+impl_option!(SpreadsheetOnCellsChange, OptionSpreadsheetOnCellsChange, copy = false, [Debug, Clone, PartialEq, PartialOrd]);
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct SpreadsheetOnSelectionChange {
+    pub data: RefAny,
+    pub callback: SpreadsheetOnSelectionChangeCallback,
+}
+
+impl_option!(SpreadsheetOnSelectionChange, OptionSpreadsheetOnSelectionChange, copy = false, [Debug, Clone, PartialEq, PartialOrd]);
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct SpreadsheetOnColumnResize {
+    pub data: RefAny,
+    pub callback: SpreadsheetOnColumnResizeCallback,
 }
 
-pub type OnCellFocusReceivedCallback = extern "C" fn(&mut RefAny, &SpreadsheetState, SpreadsheetCellIndex) -> Update;
-pub type OnCellFocusLostCallback = extern "C" fn(&mut RefAny, &SpreadsheetState, SpreadsheetCellIndex) -> Update;
-pub type OnCellEditFinishCallback = extern "C" fn(&mut RefAny, &SpreadsheetState, SpreadsheetCellIndex) -> Update;
-pub type OnCellInputCallback = extern "C" fn(&mut RefAny, &SpreadsheetState, SpreadsheetCellIndex) -> Update;
+impl_option!(SpreadsheetOnColumnResize, OptionSpreadsheetOnColumnResize, copy = false, [Debug, Clone, PartialEq, PartialOrd]);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 #[repr(C)]
 pub struct SpreadsheetStyle {
     // TODO: styling args (background / border, etc.)
+    /// Color of the border around the selected cells
     pub accent_color: ColorU,
 }
 
 impl Default for SpreadsheetStyle {
-    pub fn default() -> SpreadsheetStyle {
+    fn default() -> SpreadsheetStyle {
         SpreadsheetStyle {
             accent_color: COLOR_407C40,
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 #[repr(C)]
 pub struct SpreadsheetState {
     pub style: SpreadsheetStyle,
+    /// Number of rows in the table
+    pub row_count: usize,
+    /// Number of columns in the table
+    pub column_count: usize,
     /// Width of the column in pixels
     pub default_column_width: f32,
-    /// Height of the column in pixels
+    /// Height of the row in pixels
     pub default_row_height: f32,
-    /// Overrides the `default_column_width` for column X
-    pub column_width_overrides: Option<Box<BTreeMap<ColumnIndex, f32>>>,
-    /// Overrides the `default_row_height` for row X
-    pub row_height_overrides: Option<Box<BTreeMap<RowIndex, f32>>>,
     /// Optional selection
     pub selection: OptionSpreadsheetCellSelection,
-    /// Current cell contents
-    pub cell_contents: Option<Box<BTreeMap<SpreadsheetCellIndex, String>>>,
+    /// Overrides the `default_column_width` for column X, sorted by column
+    column_width_overrides: SpreadsheetSizeOverrideVec,
+    /// Overrides the `default_row_height` for row X, sorted by row
+    row_height_overrides: SpreadsheetSizeOverrideVec,
+    /// Current cell contents sorted by cell, empty cells are not stored
+    cell_contents: SpreadsheetCellContentVec,
+}
+
+/// Width of a column or height of a row that differs from the default size
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct SpreadsheetSizeOverride {
+    index: usize,
+    size: f32,
 }
 
+impl_vec!(SpreadsheetSizeOverride, SpreadsheetSizeOverrideVec, SpreadsheetSizeOverrideVecDestructor);
+impl_vec_debug!(SpreadsheetSizeOverride, SpreadsheetSizeOverrideVec);
+impl_vec_clone!(SpreadsheetSizeOverride, SpreadsheetSizeOverrideVec, SpreadsheetSizeOverrideVecDestructor);
+impl_vec_partialeq!(SpreadsheetSizeOverride, SpreadsheetSizeOverrideVec);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct SpreadsheetCellContent {
+    cell: SpreadsheetCellIndex,
+    text: AzString,
+}
+
+impl_vec!(SpreadsheetCellContent, SpreadsheetCellContentVec, SpreadsheetCellContentVecDestructor);
+impl_vec_debug!(SpreadsheetCellContent, SpreadsheetCellContentVec);
+impl_vec_clone!(SpreadsheetCellContent, SpreadsheetCellContentVec, SpreadsheetCellContentVecDestructor);
+impl_vec_partialeq!(SpreadsheetCellContent, SpreadsheetCellContentVec);
+
 impl Default for SpreadsheetState {
     #[inline]
     fn default() -> Self {
         Self {
             style: SpreadsheetStyle::default(),
+            row_count: 1000,
+            column_count: 26,
             default_column_width: 100.0,
             default_row_height: 20.0,
-            column_width_overrides: BTreeMap::default(),
-            row_height_overrides: BTreeMap::default(),
-            selection: None,
-            cell_contents: BTreeMap::default(),
+            selection: None.into(),
+            column_width_overrides: SpreadsheetSizeOverrideVec::new(),
+            row_height_overrides: SpreadsheetSizeOverrideVec::new(),
+            cell_contents: SpreadsheetCellContentVec::new(),
         }
     }
 }
@@ -95,7 +215,11 @@ pub struct SpreadsheetCellIndex {
     pub column: ColumnIndex,
 }
 
-/// Represents a selection of table cells from the top left to the bottom right cell
+/// Represents a rectangular selection of table cells
+///
+/// NOTE: `from_top_left` is the cell where the selection was started,
+/// `to_bottom_right` is the end that moves when the selection is extended,
+/// so `from_top_left` is not necessarily the top left cell of the selection
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 #[repr(C)]
 pub struct SpreadsheetCellSelection {
@@ -103,7 +227,364 @@ pub struct SpreadsheetCellSelection {
     pub to_bottom_right: SpreadsheetCellIndex,
 }
 
-impl_option!(SpreadsheetCellSelection, OptionSpreadsheetCellSelection, [Debug, Clone, PartialEq, PartialOrd]);
+impl_option!(SpreadsheetCellSelection, OptionSpreadsheetCellSelection, [Debug, Copy, Clone, PartialEq, PartialOrd]);
+
+#[derive(Debug, Default, Clone, PartialEq)]
+#[repr(C)]
+pub struct SpreadsheetView {
+    /// Cells rendered by the last invocation of the iframe callback
+    rendered: SpreadsheetRenderWindow,
+    /// IFrame node in the parent DOM, necessary to scroll the table via the keyboard
+    iframe_node: OptionDomNodeId,
+    drag: SpreadsheetDrag,
+    /// Last click on a cell, for detecting double clicks
+    last_click: OptionSpreadsheetCellClick,
+    /// Cell that is currently edited and the current text of the editor
+    editing: OptionSpreadsheetCellEdit,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C, u8)]
+pub enum SpreadsheetDrag {
+    None,
+    SelectCells,
+    ResizeColumn(SpreadsheetColumnResize),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct SpreadsheetColumnResize {
+    column: ColumnIndex,
+    /// Width of the column without the minimum width applied
+    width: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct SpreadsheetCellClick {
+    cell: SpreadsheetCellIndex,
+    time: Instant,
+}
+
+impl_option!(SpreadsheetCellClick, OptionSpreadsheetCellClick, copy = false, [Debug, Clone, PartialEq]);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct SpreadsheetCellEdit {
+    cell: SpreadsheetCellIndex,
+    text: AzString,
+}
+
+impl_option!(SpreadsheetCellEdit, OptionSpreadsheetCellEdit, copy = false, [Debug, Clone, PartialEq]);
+
+impl Default for SpreadsheetDrag {
+    fn default() -> Self {
+        SpreadsheetDrag::None
+    }
+}
+
+/// Rows and columns that are rendered into the iframe
+#[derive(Debug, Default, Clone, PartialEq)]
+#[repr(C)]
+pub struct SpreadsheetRenderWindow {
+    /// First rendered row
+    row_start: RowIndex,
+    /// Row after the last rendered row
+    row_end: RowIndex,
+    /// First rendered column
+    column_start: ColumnIndex,
+    /// Column after the last rendered column
+    column_end: ColumnIndex,
+    /// Position of the first rendered cell, relative to the first cell of the table
+    origin: LogicalPosition,
+    /// Position of the viewport, relative to the first cell of the table
+    scroll_position: LogicalPosition,
+    /// Size of the iframe
+    viewport: LogicalSize,
+}
+
+/// Row heights or column widths of the table
+struct SpreadsheetAxis<'a> {
+    count: usize,
+    default_size: f32,
+    /// Sorted by index
+    overrides: &'a [SpreadsheetSizeOverride],
+}
+
+const COLOR_WHITE: ColorU = ColorU { r: 255, g: 255, b: 255, a: 255 };
+const COLOR_2D2D2D: ColorU = ColorU { r: 45, g: 45, b: 45, a: 255 };
+const COLOR_E6E6E6: ColorU = ColorU { r: 230, g: 230, b: 230, a: 255 };
+const COLOR_B5B5B5: ColorU = ColorU { r: 181, g: 181, b: 181, a: 255 };
+const COLOR_D1D1D1: ColorU = ColorU { r: 209, g: 209, b: 209, a: 255 };
+const COLOR_BLACK: ColorU = ColorU { r: 0, g: 0, b: 0, a: 255 };
+
+const WHITE_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_WHITE)];
+const COLOR_E6E6E6_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_E6E6E6)];
+
+const SANS_SERIF_STR: &str = "sans-serif";
+const SANS_SERIF: AzString = AzString::from_const_str(SANS_SERIF_STR);
+const SANS_SERIF_FAMILIES: &[StyleFontFamily] = &[StyleFontFamily::System(SANS_SERIF)];
+const SANS_SERIF_FAMILY: StyleFontFamilyVec = StyleFontFamilyVec::from_const_slice(SANS_SERIF_FAMILIES);
+
+static DEFAULT_SPREADSHEET_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+    Normal(CssProperty::const_width(LayoutWidth::const_percent(100))),
+    Normal(CssProperty::const_height(LayoutHeight::const_percent(100))),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+];
+
+// layout properties of the iframe contents, width / height / position is appended when rendering
+
+static SPREADSHEET_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(WHITE_BACKGROUND))),
+];
+
+static CELLS_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(ROW_HEADER_WIDTH))),
+    Normal(CssProperty::const_top(LayoutTop::const_px(COLUMN_HEADER_HEIGHT))),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+];
+
+static ROW_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+];
+
+static CELL_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(14))),
+    Normal(CssProperty::const_text_align(StyleTextAlign::Left)),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_BLACK })),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_D1D1D1 })),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_D1D1D1 })),
+];
+
+static SELECTION_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(2))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(2))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(2))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(2))),
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+];
+
+static COLUMN_NAMES_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(ROW_HEADER_WIDTH))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(COLUMN_HEADER_HEIGHT))),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+];
+
+static COLUMN_NAME_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(COLOR_E6E6E6_BACKGROUND))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_B5B5B5 })),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_B5B5B5 })),
+];
+
+static HEADER_TEXT_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(14))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_2D2D2D })),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+];
+
+static RESIZE_HANDLE_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_right(LayoutRight::const_px(0))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(RESIZE_HANDLE_WIDTH))),
+    Normal(CssProperty::const_height(LayoutHeight::const_percent(100))),
+    Normal(CssProperty::const_cursor(StyleCursor::ColResize)),
+];
+
+static ROW_NUMBERS_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(COLUMN_HEADER_HEIGHT))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(ROW_HEADER_WIDTH))),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+];
+
+static ROW_NUMBER_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(14))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_2D2D2D })),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(COLOR_E6E6E6_BACKGROUND))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_B5B5B5 })),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_B5B5B5 })),
+];
+
+static TOP_LEFT_RECT_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(ROW_HEADER_WIDTH))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(COLUMN_HEADER_HEIGHT))),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(COLOR_E6E6E6_BACKGROUND))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_B5B5B5 })),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_B5B5B5 })),
+];
+
+// hidden until a cell is edited, then moved on top of the cell via set_css_property
+static EDITOR_LAYOUT: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+];
+
+impl Default for Spreadsheet {
+    fn default() -> Self {
+        Self {
+            state: SpreadsheetStateWrapper::default(),
+            container_style: NodeDataInlineCssPropertyVec::from_const_slice(DEFAULT_SPREADSHEET_CONTAINER_STYLE),
+        }
+    }
+}
+
+impl Spreadsheet {
+
+    #[inline]
+    pub fn new(state: SpreadsheetState) -> Self {
+        let mut s = Self::default();
+        s.state.inner = state;
+        s
+    }
+
+    pub fn set_on_cells_change(&mut self, data: RefAny, callback: SpreadsheetOnCellsChangeCallbackType) {
+        self.state.on_cells_change = Some(SpreadsheetOnCellsChange {
+            callback: SpreadsheetOnCellsChangeCallback { cb: callback },
+            data
+        }).into();
+    }
+
+    pub fn set_on_selection_change(&mut self, data: RefAny, callback: SpreadsheetOnSelectionChangeCallbackType) {
+        self.state.on_selection_change = Some(SpreadsheetOnSelectionChange {
+            callback: SpreadsheetOnSelectionChangeCallback { cb: callback },
+            data
+        }).into();
+    }
+
+    pub fn set_on_column_resize(&mut self, data: RefAny, callback: SpreadsheetOnColumnResizeCallbackType) {
+        self.state.on_column_resize = Some(SpreadsheetOnColumnResize {
+            callback: SpreadsheetOnColumnResizeCallback { cb: callback },
+            data
+        }).into();
+    }
+
+    pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.container_style = style;
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::default();
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    pub fn dom(self) -> Dom {
+
+        use azul_desktop::dom::{CallbackData, EventFilter, HoverEventFilter};
+
+        let accessibility_info = AccessibilityInfo {
+            name: None.into(),
+            value: None.into(),
+            role: AccessibilityRole::Table,
+            states: Vec::new().into(),
+            accelerator: None.into(),
+            default_action: None.into(),
+        };
+
+        let state_ref = RefAny::new(self.state);
+
+        Dom::iframe(state_ref.clone(), render_spreadsheet_iframe)
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(SPREADSHEET_CONTAINER_CLASS))
+        .with_inline_css_props(self.container_style)
+        .with_accessibility_info(accessibility_info)
+        .with_dataset(Some(state_ref.clone()).into())
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_iframe_mouse_down }
+            },
+        ].into())
+    }
+}
+
+/// Invoked with the scroll position of the iframe, renders the visible cells
+extern "C" fn render_spreadsheet_iframe(data: &mut RefAny, info: IFrameCallbackInfo) -> IFrameCallbackReturn {
+
+    let state_ref = data.clone();
+    let mut spreadsheet = match data.downcast_mut::<SpreadsheetStateWrapper>() {
+        Some(s) => s,
+        None => return IFrameCallbackReturn::default(),
+    };
+
+    // see /examples/assets/images/scrollbounds.png: the virtual scroll offset is the
+    // position of the rendered cells in the table, the scroll offset is the position
+    // of the viewport relative to the rendered cells
+    let scroll_position = LogicalPosition::new(
+        info.virtual_scroll_offset.x + info.scroll_offset.x,
+        info.virtual_scroll_offset.y + info.scroll_offset.y,
+    );
+
+    let window = spreadsheet.inner.get_render_window(scroll_position, info.get_bounds().get_logical_size());
+    let scroll_size = window.get_size(&spreadsheet.inner);
+    let scroll_offset = window.get_scroll_offset();
+    let virtual_scroll_offset = window.origin;
+    spreadsheet.view.rendered = window;
+
+    let mut dom = spreadsheet.render(&state_ref);
+
+    IFrameCallbackReturn {
+        dom: dom.style(&mut Css::empty()),
+        scroll_size,
+        scroll_offset,
+        virtual_scroll_size: spreadsheet.inner.get_virtual_size(),
+        virtual_scroll_offset,
+    }
+}
+
+/// Appends the dynamic properties to the layout
+fn with_layout(layout: &[NodeDataInlineCssProperty], dynamic: &[NodeDataInlineCssProperty]) -> NodeDataInlineCssPropertyVec {
+    let mut style = layout.to_vec();
+    style.extend(dynamic.iter().cloned());
+    style.into()
+}
 
 impl SpreadsheetCellSelection {
 
@@ -133,6 +614,103 @@ impl SpreadsheetCellSelection {
         let min_col = self.from_top_left.column.min(self.to_bottom_right.column);
         if max_col < min_col { 0 } else { (max_col - min_col) + 1 }
     }
+
+    /// Returns the top left cell of the selection
+    #[inline]
+    pub fn get_top_left(&self) -> SpreadsheetCellIndex {
+        SpreadsheetCellIndex {
+            row: self.from_top_left.row.min(self.to_bottom_right.row),
+            column: self.from_top_left.column.min(self.to_bottom_right.column),
+        }
+    }
+
+    /// Returns the bottom right cell of the selection
+    #[inline]
+    pub fn get_bottom_right(&self) -> SpreadsheetCellIndex {
+        SpreadsheetCellIndex {
+            row: self.from_top_left.row.max(self.to_bottom_right.row),
+            column: self.from_top_left.column.max(self.to_bottom_right.column),
+        }
+    }
+
+    #[inline]
+    pub fn contains(&self, cell: &SpreadsheetCellIndex) -> bool {
+        let top_left = self.get_top_left();
+        let bottom_right = self.get_bottom_right();
+        cell.row >= top_left.row && cell.row <= bottom_right.row &&
+        cell.column >= top_left.column && cell.column <= bottom_right.column
+    }
+}
+
+impl<'a> SpreadsheetAxis<'a> {
+
+    fn get_size(&self, index: usize) -> f32 {
+        match self.overrides.binary_search_by_key(&index, |o| o.index) {
+            Ok(i) => self.overrides[i].size,
+            Err(_) => self.default_size,
+        }
+    }
+
+    /// Returns the overrides of the rows / columns before `index`
+    fn get_overrides_before(&self, index: usize) -> &'a [SpreadsheetSizeOverride] {
+        &self.overrides[..self.overrides.partition_point(|o| o.index < index)]
+    }
+
+    /// Returns the distance from the first row / column to the start of the row / column `index`
+    fn get_offset(&self, index: usize) -> f32 {
+        let index = index.min(self.count);
+        let overrides = self.get_overrides_before(index).iter().map(|o| o.size - self.default_size).sum::<f32>();
+        index as f32 * self.default_size + overrides
+    }
+
+    fn get_total_size(&self) -> f32 {
+        self.get_offset(self.count)
+    }
+
+    /// Returns the row / column at the distance `position` from the first row / column,
+    /// None if the position is outside of the table
+    fn get_index_at(&self, position: f32) -> Option<usize> {
+
+        if position < 0.0 {
+            return None;
+        }
+
+        // rows / columns between the overrides have the default size
+        let mut start_index = 0;
+        let mut start_offset = 0.0;
+
+        for &SpreadsheetSizeOverride { index, size } in self.get_overrides_before(self.count) {
+            let override_offset = start_offset + (index - start_index) as f32 * self.default_size;
+            if position < override_offset {
+                break;
+            }
+            if position < override_offset + size {
+                return Some(index);
+            }
+            start_index = index + 1;
+            start_offset = override_offset + size;
+        }
+
+        let index = start_index + ((position - start_offset) / self.default_size) as usize;
+        if index < self.count { Some(index) } else { None }
+    }
+
+    /// Returns the rows / columns that are (partially) visible between `start` and `start + length`
+    fn get_visible_range(&self, start: f32, length: f32) -> Range<usize> {
+        let start = start.max(0.0);
+        let end = start + length;
+        let first = match self.get_index_at(start) {
+            Some(s) if length > 0.0 => s,
+            _ => return 0..0,
+        };
+        let last = match self.get_index_at(end) {
+            // the row / column starting at `end` is not visible
+            Some(s) if s > first && self.get_offset(s) >= end => s - 1,
+            Some(s) => s,
+            None => self.count - 1,
+        };
+        first..(last + 1)
+    }
 }
 
 impl SpreadsheetState {
@@ -142,374 +720,1301 @@ impl SpreadsheetState {
         SpreadsheetState::default()
     }
 
+    /// Sets the contents of the cell, an empty string clears the cell
     #[inline]
-    pub fn set_cell_content<I: Into<String>>(&mut self, cell: SpreadsheetCellIndex, value: I) {
-        self.cell_contents.insert(cell, value.into());
+    pub fn set_cell_content<I: Into<AzString>>(&mut self, cell: SpreadsheetCellIndex, value: I) {
+        let text = value.into();
+        modify_cell_contents(&mut self.cell_contents, |cell_contents| {
+            match (cell_contents.binary_search_by_key(&cell, |c| c.cell), text.as_str().is_empty()) {
+                (Ok(i), true) => { cell_contents.remove(i); },
+                (Ok(i), false) => cell_contents[i].text = text,
+                (Err(_), true) => { },
+                (Err(i), false) => cell_contents.insert(i, SpreadsheetCellContent { cell, text }),
+            }
+        });
     }
 
     #[inline]
-    pub fn get_cell_content(&self, cell: &SpreadsheetCellIndex) -> Option<&String> {
-        self.cell_contents.get(cell)
+    pub fn get_cell_content(&self, cell: &SpreadsheetCellIndex) -> Option<&AzString> {
+        let cell_contents = self.cell_contents.as_slice();
+        cell_contents.binary_search_by_key(cell, |c| c.cell).ok().map(|i| &cell_contents[i].text)
     }
 
     #[inline]
     pub fn set_selection(&mut self, selection: Option<SpreadsheetCellSelection>) {
-        self.selection = selection;
-    }
-
-    /// Renders a cutout of the table from, horizontally from (col_start..col_end)
-    /// and vertically from (row_start..row_end)
-    pub fn dom(&self, rows: Range<usize>, columns: Range<usize>) -> Dom {
-
-        use azul::css::*;
-        use azul::str::String as AzString;
-        use azul::vec::DomVec as AzDomVec;
-        use azul::vec::StyleBackgroundContentVec;
-        use azul::vec::NodeDataInlineCssPropertyVec;
-        use azul::vec::StyleFontFamilyVec;
-        use azul::vec::StyleTransformVec;
-        use azul::vec::IdOrClassVec;
-        use azul::dom::IdOrClass;
-        use azul::dom::NodeDataInlineCssProperty;
-        use azul::dom::NodeDataInlineCssProperty::Normal;
-        use alloc::string::ToString;
-
-        const FONT_STRING: AzString = AzString::from_const_str("sans-serif");
-        const FONT_VEC: &[StyleFontFamily] = &[StyleFontFamily::System(FONT_STRING)];
-        const SANS_SERIF_FONT_FAMILY: StyleFontFamilyVec = StyleFontFamilyVec::from_const_slice(FONT_VEC);
-
-        const COLOR_2D2D2D: ColorU = ColorU { r: 45, g: 45, b: 45, a: 255 };
-        const COLOR_E6E6E6: ColorU = ColorU { r: 230, g: 230, b: 230, a: 255 };
-        const COLOR_B5B5B5: ColorU = ColorU { r: 181, g: 181, b: 181, a: 255 };
-        const COLOR_D1D1D1: ColorU = ColorU { r: 209, g: 209, b: 209, a: 255 };
-        const COLOR_BLACK: ColorU = ColorU { r: 0, g: 0, b: 0, a: 255 };
-
-        static COLOR_407C40_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_407C40)];
-        static COLOR_E6E6E6_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_E6E6E6)];
-
-        static SELECTED_CELL_BORDER_WIDTH: isize = 2;
-        static SELECTED_CELL_BORDER_STYLE: BorderStyle = BorderStyle::Solid;
-        static SELECTED_CELL_BORDER_COLOR: ColorU = COLOR_407C40;
-
-        const DEFAULT_TABLE_CELL_STRING: AzString = AzString::from_const_str("");
-
-        static SHADOW: StyleBoxShadow = StyleBoxShadow {
-            offset: [PixelValueNoPercent::zero(), PixelValueNoPercent::zero()],
-            color: COLOR_2D2D2D,
-            blur_radius: PixelValueNoPercent::const_px(3),
-            spread_radius: PixelValueNoPercent::const_px(3),
-            clip_mode: BoxShadowClipMode::Outset,
-        };
-
-        static TOP_LEFT_EMPTY_RECT_STYLE: &[NodeDataInlineCssProperty] = &[
-            Normal(CssProperty::const_min_height(LayoutMinHeight::const_px(20))),
-            Normal(CssProperty::const_height(LayoutHeight::const_px(20))),
-            Normal(CssProperty::const_max_height(LayoutMaxHeight::const_px(20))),
-            Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(COLOR_E6E6E6_BACKGROUND))),
-            Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_B5B5B5 })),
-            Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_B5B5B5 })),
-        ];
-        static TOP_LEFT_EMPTY_RECT_CLASS: &[IdOrClass] = &[
-            IdOrClass::Class(AzString::from_const_str("az-table-top-left-rect"))
-        ];
+        self.selection = selection.into();
+    }
 
-        // Empty rectangle at the top left of the table
-        let top_left_empty_rect = Dom::div()
-        .with_ids_and_classes(IdOrClassVec::from_const_slice(TOP_LEFT_EMPTY_RECT_CLASS))
-        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TOP_LEFT_EMPTY_RECT_STYLE));
-
-        static ROW_NUMBERS_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
-            Normal(CssProperty::const_font_family(SANS_SERIF_FONT_FAMILY)),
-            Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_2D2D2D })),
-            Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(COLOR_E6E6E6_BACKGROUND))),
-            Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
-            Normal(CssProperty::const_box_shadow_right(SHADOW)),
-        ];
-        static ROW_NUMBERS_CONTAINER_CLASS: &[IdOrClass] = &[
-            IdOrClass::Class(AzString::from_const_str("az-table-row-numbers-container"))
-        ];
+    /// Selects all cells of the table
+    pub fn select_all(&mut self) {
+        if self.row_count == 0 || self.column_count == 0 {
+            return;
+        }
+        self.selection = Some(SpreadsheetCellSelection::from(0, 0).to(self.row_count - 1, self.column_count - 1)).into();
+    }
 
-        // Row numbers (first column - laid out vertical) - "1", "2", "3"
-        let row_numbers = (rows.start..rows.end).map(|row_idx| {
-
-            static ROW_NUMBERS_STYLE: &[NodeDataInlineCssProperty] = &[
-                Normal(CssProperty::const_font_size(StyleFontSize::const_px(14))),
-                Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
-                Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
-                Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
-                Normal(CssProperty::const_min_height(LayoutMinHeight::const_px(20))),
-                Normal(CssProperty::const_height(LayoutHeight::const_px(20))),
-                Normal(CssProperty::const_max_height(LayoutMaxHeight::const_px(20))),
-                Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
-                Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
-                Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_B5B5B5 })),
-            ];
-            static ROW_NUMBERS_CLASS: &[IdOrClass] = &[
-                IdOrClass::Class(AzString::from_const_str("az-table-row-numbers"))
-            ];
+    fn get_rows(&self) -> SpreadsheetAxis<'_> {
+        SpreadsheetAxis {
+            count: self.row_count,
+            default_size: self.default_row_height.max(1.0),
+            overrides: self.row_height_overrides.as_slice(),
+        }
+    }
 
-            // NOTE: to_string() heap allocation is unavoidable
-            NodeData::text((row_idx + 1).to_string().into())
-            .with_ids_and_classes(IdOrClassVec::from_const_slice(ROW_NUMBERS_CLASS))
-            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(ROW_NUMBERS_STYLE))
+    fn get_columns(&self) -> SpreadsheetAxis<'_> {
+        SpreadsheetAxis {
+            count: self.column_count,
+            default_size: self.default_column_width.max(1.0),
+            overrides: self.column_width_overrides.as_slice(),
+        }
+    }
+
+    pub fn get_column_width(&self, column: ColumnIndex) -> f32 {
+        self.get_columns().get_size(column)
+    }
+
+    pub fn get_row_height(&self, row: RowIndex) -> f32 {
+        self.get_rows().get_size(row)
+    }
+
+    /// Overrides the `default_column_width` for the column
+    pub fn set_column_width(&mut self, column: ColumnIndex, width: f32) {
+        set_size_override(&mut self.column_width_overrides, column, width);
+    }
+
+    /// Overrides the `default_row_height` for the row
+    pub fn set_row_height(&mut self, row: RowIndex, height: f32) {
+        set_size_override(&mut self.row_height_overrides, row, height);
+    }
+
+    /// Returns the size of all cells (without the row numbers and column names)
+    pub fn get_table_size(&self) -> LogicalSize {
+        LogicalSize::new(self.get_columns().get_total_size(), self.get_rows().get_total_size())
+    }
+
+    /// Returns the size of all cells including the row numbers and column names
+    fn get_virtual_size(&self) -> LogicalSize {
+        let table_size = self.get_table_size();
+        LogicalSize::new(
+            table_size.width + ROW_HEADER_WIDTH as f32,
+            table_size.height + COLUMN_HEADER_HEIGHT as f32,
+        )
+    }
+
+    /// Returns the rect of the cell, relative to the first cell of the table
+    pub fn get_cell_rect(&self, cell: SpreadsheetCellIndex) -> LogicalRect {
+        let columns = self.get_columns();
+        let rows = self.get_rows();
+        LogicalRect::new(
+            LogicalPosition::new(columns.get_offset(cell.column), rows.get_offset(cell.row)),
+            LogicalSize::new(columns.get_size(cell.column), rows.get_size(cell.row)),
+        )
+    }
+
+    /// Returns the cell at the position (relative to the first cell of the table)
+    pub fn get_cell_at(&self, position: LogicalPosition) -> Option<SpreadsheetCellIndex> {
+        Some(SpreadsheetCellIndex {
+            row: self.get_rows().get_index_at(position.y)?,
+            column: self.get_columns().get_index_at(position.x)?,
         })
-        .collect::<Dom>()
-        .with_ids_and_classes(IdOrClassVec::from_const_slice(ROW_NUMBERS_CONTAINER_CLASS))
-        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(ROW_NUMBERS_CONTAINER_STYLE));
-
-        static ROW_NUMBER_WRAPPER_STYLE: &[NodeDataInlineCssProperty] = &[
-            Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
-            Normal(CssProperty::const_max_width(LayoutMaxWidth::const_px(30))),
-            Normal(CssProperty::const_width(LayoutWidth::const_px(30))),
-            Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(30))),
-        ];
-        static ROW_NUMBERS_WRAPPER_CLASS: &[IdOrClass] = &[
-            IdOrClass::Class(AzString::from_const_str("az-table-row-numbers-wrapper"))
-        ];
+    }
 
-        // first column: contains the "top left rect" + the column
-        let row_number_wrapper = Dom::div()
-        .with_ids_and_classes(IdOrClassVec::from_const_slice(ROW_NUMBERS_WRAPPER_CLASS))
-        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(ROW_NUMBER_WRAPPER_STYLE))
-        .with_children(AzDomVec::from(vec![top_left_empty_rect, row_numbers]));
-
-        static ACTIVE_SELECTION_HANDLE_STYLE: &[NodeDataInlineCssProperty] = &[
-            Normal(CssProperty::const_position(LayoutPosition::Absolute)),
-            Normal(CssProperty::const_width(LayoutWidth::const_px(10))),
-            Normal(CssProperty::const_height(LayoutHeight::const_px(10))),
-            Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(COLOR_407C40_BACKGROUND))),
-            Normal(CssProperty::const_bottom(LayoutBottom::const_px(-5))),
-            Normal(CssProperty::const_right(LayoutRight::const_px(-5))),
-        ];
-        static ACTIVE_SELECTION_HANDLE_CLASS: &[IdOrClass] = &[
-            IdOrClass::Class(AzString::from_const_str("az-table-active-selection-handle"))
-        ];
+    /// Returns the column whose right edge is at `x` (relative to the first column)
+    fn get_column_border_at(&self, x: f32) -> Option<ColumnIndex> {
+        let columns = self.get_columns();
+        let column = columns.get_index_at(x)?;
+        let right_edge = columns.get_offset(column) + columns.get_size(column);
+        if right_edge - x <= RESIZE_HANDLE_WIDTH as f32 { Some(column) } else { None }
+    }
 
-        // currently active cell handle
-        // TODO: add callbacks to modify selection
-        let current_active_selection_handle = Dom::div()
-        .with_ids_and_classes(IdOrClassVec::from_const_slice(ACTIVE_SELECTION_HANDLE_CLASS))
-        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(ACTIVE_SELECTION_HANDLE_STYLE));
-
-        // currently selected cell(s)
-        static ACTIVE_SELECTION_CLASS: &[IdOrClass] = &[IdOrClass::Class(AzString::from_const_str("az-table-active-selection"))];
-
-        let current_active_selection = Dom::div()
-        .with_inline_css_props(NodeDataInlineCssPropertyVec::from(vec![
-            Normal(CssProperty::const_position(LayoutPosition::Absolute)),
-            Normal(CssProperty::const_width({
-                self.selection.as_ref()
-                .map(|selection| LayoutWidth::px(selection.number_of_columns_selected() as f32 * self.default_column_width))
-                .unwrap_or(LayoutWidth::zero()) // TODO: replace with transform: scale-x
-            })),
-            Normal(CssProperty::const_height({
-                self.selection.as_ref()
-                .map(|selection| LayoutHeight::px(selection.number_of_rows_selected() as f32 * self.default_row_height))
-                .unwrap_or(LayoutHeight::zero())  // TODO: replace with transform: scale-y
-            })),
-            Normal(CssProperty::const_margin_left({
-                self.selection.as_ref()
-                .map(|selection| LayoutMarginLeft::px(selection.from_top_left.column as f32 * self.default_column_width))
-                .unwrap_or(LayoutMarginLeft::zero()) // TODO: replace with transform-y
-            })),
-            Normal(CssProperty::const_margin_top({
-                self.selection.as_ref()
-                .map(|selection| LayoutMarginTop::px(selection.from_top_left.row as f32 * self.default_row_height))
-                .unwrap_or(LayoutMarginTop::zero()) // TODO: replace with transform-y
-            })),
-            Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(SELECTED_CELL_BORDER_WIDTH))),
-            Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: SELECTED_CELL_BORDER_STYLE })),
-            Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: SELECTED_CELL_BORDER_COLOR })),
-            Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(SELECTED_CELL_BORDER_WIDTH))),
-            Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: SELECTED_CELL_BORDER_STYLE })),
-            Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: SELECTED_CELL_BORDER_COLOR })),
-            Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(SELECTED_CELL_BORDER_WIDTH))),
-            Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: SELECTED_CELL_BORDER_STYLE })),
-            Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: SELECTED_CELL_BORDER_COLOR })),
-            Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(SELECTED_CELL_BORDER_WIDTH))),
-            Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: SELECTED_CELL_BORDER_STYLE })),
-            Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: SELECTED_CELL_BORDER_COLOR })),
-            // don't show the selection when the table doesn't have one
-            // TODO: animate / fade in / fade out
-            Normal(CssProperty::const_opacity(if self.selection.is_some() { StyleOpacity::const_new(1) } else { StyleOpacity::const_new(0) })),
-        ]))
-        .with_ids_and_classes(IdOrClassVec::from_const_slice(ACTIVE_SELECTION_CLASS))
-        .with_children(AzDomVec::from(vec![current_active_selection_handle]));
-
-        let mut column_doms = columns.map(|col_idx| {
-
-            static COLUMN_NAME_STYLE: &[NodeDataInlineCssProperty] = &[
-                Normal(CssProperty::const_height(LayoutHeight::const_px(20))),
-                Normal(CssProperty::const_font_family(SANS_SERIF_FONT_FAMILY)),
-                Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_2D2D2D })),
-                Normal(CssProperty::const_font_size(StyleFontSize::const_px(14))),
-                Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(COLOR_E6E6E6_BACKGROUND))),
-                Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
-                Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
-                Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
-                Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
-                Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
-                Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_B5B5B5 })),
-                Normal(CssProperty::const_box_shadow_bottom(SHADOW)),
-            ];
-            static COLUMN_NAME_CLASS: &[IdOrClass] = &[IdOrClass::Class(AzString::from_const_str("az-table-column-name"))];
-
-            static ROWS_IN_COLUMN_STYLE: &[NodeDataInlineCssProperty] = &[
-                Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
-            ];
-            static ROWS_IN_COLUMN_CLASS: &[IdOrClass] = &[IdOrClass::Class(AzString::from_const_str("az-table-rows"))];
-
-            let column_names = Dom::text(column_name_from_number(col_idx).into())
-            .with_ids_and_classes(IdOrClassVec::from_const_slice(COLUMN_NAME_CLASS))
-            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(COLUMN_NAME_STYLE));
-
-            // rows in this column, laid out vertically
-            let rows_in_this_column = (rows.start..rows.end).map(|row_idx| {
-
-                    let node_type = match self.get_cell_content(&SpreadsheetCellIndex { row: row_idx, column: col_idx }) {
-                        Some(string) => NodeType::Text(string.clone().into()),
-                        None => NodeType::Text(DEFAULT_TABLE_CELL_STRING),
-                    };
-
-                    static CELL_STYLE: &[NodeDataInlineCssProperty] = &[
-                        Normal(CssProperty::const_align_items(LayoutAlignItems::FlexStart)),
-                        Normal(CssProperty::const_height(LayoutHeight::const_px(20))),
-                        Normal(CssProperty::const_min_height(LayoutMinHeight::const_px(20))),
-                        Normal(CssProperty::const_max_height(LayoutMaxHeight::const_px(20))),
-                        Normal(CssProperty::const_font_size(StyleFontSize::const_px(14))),
-                        Normal(CssProperty::const_text_align(StyleTextAlign::Left)),
-                        Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_BLACK })),
-                        Normal(CssProperty::const_font_family(SANS_SERIF_FONT_FAMILY)),
-                        Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
-                        Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
-                        Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_D1D1D1 })),
-                    ];
-                    static CELL_CLASS: &[IdOrClass] = &[IdOrClass::Class(AzString::from_const_str("az-table-cell"))];
-
-                    NodeData::new(node_type)
-                    .with_ids_and_classes(IdOrClassVec::from_const_slice(CELL_CLASS))
-                    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(CELL_STYLE))
-            })
-            .collect::<Dom>()
-            .with_ids_and_classes(IdOrClassVec::from_const_slice(ROWS_IN_COLUMN_CLASS))
-            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(ROWS_IN_COLUMN_STYLE));
-
-            static COLUMN_STYLE: &[NodeDataInlineCssProperty] = &[
-                Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
-                Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(100))),
-                Normal(CssProperty::const_max_width(LayoutMaxWidth::const_px(100))),
-                Normal(CssProperty::const_width(LayoutWidth::const_px(100))),
-                Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
-                Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
-                Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_D1D1D1 })),
-            ];
-            static COLUMN_CLASS: &[IdOrClass] = &[IdOrClass::Class(AzString::from_const_str("az-table-column"))];
-
-            // Column name
-            Dom::div()
-            .with_ids_and_classes(IdOrClassVec::from_const_slice(COLUMN_CLASS))
-            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(COLUMN_STYLE))
-            .with_children(AzDomVec::from(vec![column_names, rows_in_this_column]))
+    /// Returns the rows and columns visible in a viewport of `viewport` size,
+    /// scrolled to `scroll_position` (relative to the first cell of the table)
+    fn get_render_window(&self, scroll_position: LogicalPosition, viewport: LogicalSize) -> SpreadsheetRenderWindow {
+
+        let table_size = self.get_table_size();
+        let cells_width = (viewport.width - ROW_HEADER_WIDTH as f32).max(0.0);
+        let cells_height = (viewport.height - COLUMN_HEADER_HEIGHT as f32).max(0.0);
+
+        // the table can't be scrolled past the last row / column
+        let scroll_position = LogicalPosition::new(
+            scroll_position.x.min(table_size.width - cells_width).max(0.0),
+            scroll_position.y.min(table_size.height - cells_height).max(0.0),
+        );
+
+        let rows = self.get_rows().get_visible_range(scroll_position.y, cells_height);
+        let columns = self.get_columns().get_visible_range(scroll_position.x, cells_width);
+        let origin = LogicalPosition::new(
+            self.get_columns().get_offset(columns.start),
+            self.get_rows().get_offset(rows.start),
+        );
+
+        SpreadsheetRenderWindow {
+            row_start: rows.start,
+            row_end: rows.end,
+            column_start: columns.start,
+            column_end: columns.end,
+            origin,
+            scroll_position,
+            viewport,
+        }
+    }
+
+    /// Returns the scroll position that makes the cell fully visible,
+    /// None if the cell is already visible at the current scroll position
+    fn get_scroll_position_to_show(&self, cell: SpreadsheetCellIndex, scroll_position: LogicalPosition, viewport: LogicalSize) -> Option<LogicalPosition> {
+
+        let cell_rect = self.get_cell_rect(cell);
+        let cells_width = (viewport.width - ROW_HEADER_WIDTH as f32).max(0.0);
+        let cells_height = (viewport.height - COLUMN_HEADER_HEIGHT as f32).max(0.0);
+        let mut new_position = scroll_position;
+
+        if cell_rect.origin.x < new_position.x {
+            new_position.x = cell_rect.origin.x;
+        } else if cell_rect.max_x() > new_position.x + cells_width {
+            new_position.x = (cell_rect.max_x() - cells_width).min(cell_rect.origin.x);
+        }
+
+        if cell_rect.origin.y < new_position.y {
+            new_position.y = cell_rect.origin.y;
+        } else if cell_rect.max_y() > new_position.y + cells_height {
+            new_position.y = (cell_rect.max_y() - cells_height).min(cell_rect.origin.y);
+        }
+
+        if new_position == scroll_position { None } else { Some(new_position) }
+    }
+
+    /// Moves the selection (or extends it, if shift is held down),
+    /// returns whether the selection changed
+    fn handle_on_virtual_key_down(&mut self, virtual_key: VirtualKeyCode, keyboard_state: &KeyboardState, page_rows: usize) -> bool {
+
+        if self.row_count == 0 || self.column_count == 0 {
+            return false;
+        }
+
+        let selection = match self.selection.into_option() {
+            Some(s) => s,
+            None => {
+                self.selection = Some(SpreadsheetCellSelection::from(0, 0)).into();
+                return true;
+            }
+        };
+
+        let shift = keyboard_state.shift_down;
+        let ctrl = keyboard_state.ctrl_down;
+        let last_row = self.row_count - 1;
+        let last_column = self.column_count - 1;
+        let SpreadsheetCellIndex { row, column } = selection.to_bottom_right;
+
+        let (row, column) = match virtual_key {
+            VirtualKeyCode::Up if ctrl => (0, column),
+            VirtualKeyCode::Up => (row.saturating_sub(1), column),
+            VirtualKeyCode::Down if ctrl => (last_row, column),
+            VirtualKeyCode::Down => ((row + 1).min(last_row), column),
+            VirtualKeyCode::Left if ctrl => (row, 0),
+            VirtualKeyCode::Left => (row, column.saturating_sub(1)),
+            VirtualKeyCode::Right if ctrl => (row, last_column),
+            VirtualKeyCode::Right => (row, (column + 1).min(last_column)),
+            VirtualKeyCode::PageUp => (row.saturating_sub(page_rows), column),
+            VirtualKeyCode::PageDown => ((row + page_rows).min(last_row), column),
+            VirtualKeyCode::Home if ctrl => (0, 0),
+            VirtualKeyCode::Home => (row, 0),
+            VirtualKeyCode::End if ctrl => (last_row, last_column),
+            VirtualKeyCode::End => (row, last_column),
+            VirtualKeyCode::Tab if shift => (row, column.saturating_sub(1)),
+            VirtualKeyCode::Tab => (row, (column + 1).min(last_column)),
+            _ => return false,
+        };
+
+        // tab never extends the selection
+        let new_selection = if shift && virtual_key != VirtualKeyCode::Tab {
+            selection.to(row, column)
+        } else {
+            SpreadsheetCellSelection::from(row, column)
+        };
+
+        if new_selection == selection {
+            return false;
+        }
+
+        self.selection = Some(new_selection).into();
+        true
+    }
+
+    /// Removes the contents of all cells in the selection, returns whether any cell was cleared
+    fn clear_cells(&mut self, selection: SpreadsheetCellSelection) -> bool {
+        modify_cell_contents(&mut self.cell_contents, |cell_contents| {
+            let len = cell_contents.len();
+            cell_contents.retain(|c| !selection.contains(&c.cell));
+            cell_contents.len() != len
         })
-        .collect::<Vec<Dom>>();
+    }
 
-        column_doms.push(current_active_selection);
+    /// Returns the contents of the selected cells as tab-separated values
+    /// (the format used by other spreadsheet applications for copy / paste)
+    pub fn get_selection_tsv(&self, selection: SpreadsheetCellSelection) -> String {
+
+        let top_left = selection.get_top_left();
+        let bottom_right = selection.get_bottom_right();
+        let mut tsv = String::new();
+
+        for row in top_left.row..=bottom_right.row {
+
+            if row != top_left.row {
+                tsv.push('\n');
+            }
+
+            // only the non-empty cells are stored
+            let mut next_column = top_left.column;
+            let row_start = SpreadsheetCellIndex { row, column: top_left.column };
+            let row_end = SpreadsheetCellIndex { row, column: bottom_right.column };
+            let cell_contents = self.cell_contents.as_slice();
+            let first = cell_contents.partition_point(|c| c.cell < row_start);
+            for SpreadsheetCellContent { cell, text } in cell_contents[first..].iter().take_while(|c| c.cell <= row_end) {
+                for _ in next_column..cell.column {
+                    tsv.push('\t');
+                }
+                push_tsv_cell(&mut tsv, text.as_str());
+                next_column = cell.column;
+            }
+            for _ in next_column..bottom_right.column {
+                tsv.push('\t');
+            }
+        }
 
-        let columns_table_container = Dom::div()
-        .with_ids_and_classes(IdOrClassVec::from_const_slice(COLUMNS_TABLE_CONTAINER_CLASS))
-        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(COLUMNS_TABLE_CONTAINER_STYLE))
-        .with_children(column_doms.into());
+        tsv
+    }
 
+    /// Pastes the tab-separated values, starting at the top left cell of the selection.
+    /// Selects and returns the range of pasted cells.
+    pub fn paste_tsv(&mut self, tsv: &str) -> Option<SpreadsheetCellSelection> {
 
-        static COLUMNS_TABLE_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
-            Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
-            Normal(CssProperty::const_position(LayoutPosition::Relative)),
-        ];
-        static COLUMNS_TABLE_CONTAINER_CLASS: &[IdOrClass] = &[IdOrClass::Class(AzString::from_const_str("az-table-container"))];
+        let top_left = self.selection.into_option()?.get_top_left();
+        let rows = parse_tsv(tsv);
+        let mut bottom_right = top_left;
 
-        static IFRAME_DOM_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
-            Normal(CssProperty::const_display(LayoutDisplay::Flex)),
-            Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
-            Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
-        ];
-        static IFRAME_DOM_CONTAINER_CLASS: &[IdOrClass] = &[IdOrClass::Class(AzString::from_const_str("az-table-iframe-container"))];
+        for (row, cells) in (top_left.row..self.row_count).zip(rows.into_iter()) {
+            for (column, content) in (top_left.column..self.column_count).zip(cells.into_iter()) {
+                self.set_cell_content(SpreadsheetCellIndex { row, column }, content);
+                bottom_right.row = bottom_right.row.max(row);
+                bottom_right.column = bottom_right.column.max(column);
+            }
+        }
 
-        let dom = Dom::div()
-        .with_ids_and_classes(IdOrClassVec::from_const_slice(IFRAME_DOM_CONTAINER_CLASS))
-        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(IFRAME_DOM_CONTAINER_STYLE))
-        .with_children(AzDomVec::from(vec![row_number_wrapper, columns_table_container]));
+        let pasted = SpreadsheetCellSelection { from_top_left: top_left, to_bottom_right: bottom_right };
+        self.selection = Some(pasted).into();
+        Some(pasted)
+    }
+}
 
-        dom
+/// Overrides the size of the row / column, keeps the overrides sorted by index
+fn set_size_override(overrides: &mut SpreadsheetSizeOverrideVec, index: usize, size: f32) {
+    let mut v = SpreadsheetSizeOverrideVec::new();
+    mem::swap(&mut v, overrides);
+    let mut v = v.into_library_owned_vec();
+    match v.binary_search_by_key(&index, |o| o.index) {
+        Ok(i) => v[i].size = size,
+        Err(i) => v.insert(i, SpreadsheetSizeOverride { index, size }),
     }
+    *overrides = v.into();
 }
 
-impl Spreadsheet {
+fn modify_cell_contents<T, F: FnOnce(&mut Vec<SpreadsheetCellContent>) -> T>(cell_contents: &mut SpreadsheetCellContentVec, f: F) -> T {
+    let mut v = SpreadsheetCellContentVec::new();
+    mem::swap(&mut v, cell_contents);
+    let mut v = v.into_library_owned_vec();
+    let result = f(&mut v);
+    *cell_contents = v.into();
+    result
+}
 
-    #[inline]
-    pub const fn new(state: SpreadsheetState) -> Self {
-        Self { state }
+/// Appends the cell to the tab-separated values, cells containing
+/// tabs, newlines or quotes are quoted
+fn push_tsv_cell(tsv: &mut String, content: &str) {
+    if content.contains(|c| c == '\t' || c == '\n' || c == '\r' || c == '"') {
+        tsv.push('"');
+        tsv.push_str(&content.replace('"', "\"\""));
+        tsv.push('"');
+    } else {
+        tsv.push_str(content);
     }
+}
 
-    #[inline]
-    pub fn dom(self) -> Dom {
+/// Parses tab-separated values into rows of cells
+fn parse_tsv(tsv: &str) -> Vec<Vec<String>> {
+
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut chars = tsv.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                // "" is an escaped quote
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    cell.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            },
+            '"' if cell.is_empty() => in_quotes = true,
+            '\t' if !in_quotes => row.push(core::mem::take(&mut cell)),
+            '\r' if !in_quotes => { },
+            '\n' if !in_quotes => {
+                row.push(core::mem::take(&mut cell));
+                rows.push(core::mem::take(&mut row));
+            },
+            c => cell.push(c),
+        }
+    }
+
+    // a trailing newline doesn't start a new row
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+
+    rows
+}
+
+impl SpreadsheetRenderWindow {
+
+    fn rows(&self) -> Range<RowIndex> {
+        self.row_start..self.row_end
+    }
+
+    fn columns(&self) -> Range<ColumnIndex> {
+        self.column_start..self.column_end
+    }
+
+    fn contains(&self, cell: &SpreadsheetCellIndex) -> bool {
+        self.rows().contains(&cell.row) && self.columns().contains(&cell.column)
+    }
+
+    /// Returns the size of the rendered DOM, including the row numbers and column names
+    fn get_size(&self, state: &SpreadsheetState) -> LogicalSize {
+        LogicalSize::new(
+            ROW_HEADER_WIDTH as f32 + state.get_columns().get_offset(self.column_end) - self.origin.x,
+            COLUMN_HEADER_HEIGHT as f32 + state.get_rows().get_offset(self.row_end) - self.origin.y,
+        )
+    }
+
+    /// Returns the position of the viewport relative to the rendered DOM
+    fn get_scroll_offset(&self) -> LogicalPosition {
+        self.scroll_position - self.origin
+    }
+
+    /// Converts a rect relative to the first cell of the table into a rect relative to the rendered DOM
+    fn get_dom_rect(&self, rect: LogicalRect) -> LogicalRect {
+        LogicalRect::new(
+            LogicalPosition::new(
+                rect.origin.x - self.origin.x + ROW_HEADER_WIDTH as f32,
+                rect.origin.y - self.origin.y + COLUMN_HEADER_HEIGHT as f32,
+            ),
+            rect.size,
+        )
+    }
+
+    /// Returns the cell at the position relative to the rendered cells
+    fn get_cell_at(&self, state: &SpreadsheetState, position: LogicalPosition) -> Option<SpreadsheetCellIndex> {
+        state.get_cell_at(self.origin + position)
+    }
+}
+
+impl SpreadsheetStateWrapper {
 
-        use azul::css::*;
-        use azul::vec::NodeDataInlineCssPropertyVec;
-        use azul::dom::NodeDataInlineCssProperty;
-        use azul::dom::NodeDataInlineCssProperty::*;
-
-        const IFRAME_STYLE: &[NodeDataInlineCssProperty] = &[
-            Normal(CssProperty::const_display(LayoutDisplay::Flex)),
-            Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
-            Normal(CssProperty::const_width(LayoutWidth::const_percent(100))),
-            Normal(CssProperty::const_height(LayoutHeight::const_percent(100))),
-            Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    /// Returns the position of the selection rectangle relative to the rendered DOM
+    fn get_selection_props(&self) -> Vec<CssProperty> {
+        let selection = match self.inner.selection.into_option() {
+            Some(s) => s,
+            None => return vec![CssProperty::const_display(LayoutDisplay::None)],
+        };
+        let top_left = self.inner.get_cell_rect(selection.get_top_left());
+        let bottom_right = self.inner.get_cell_rect(selection.get_bottom_right());
+        let rect = self.view.rendered.get_dom_rect(LogicalRect::new(
+            top_left.origin,
+            LogicalSize::new(bottom_right.max_x() - top_left.origin.x, bottom_right.max_y() - top_left.origin.y),
+        ));
+        vec![
+            CssProperty::const_display(LayoutDisplay::Block),
+            CssProperty::const_left(LayoutLeft::px(rect.origin.x)),
+            CssProperty::const_top(LayoutTop::px(rect.origin.y)),
+            CssProperty::const_width(LayoutWidth::px(rect.size.width)),
+            CssProperty::const_height(LayoutHeight::px(rect.size.height)),
+        ]
+    }
+
+    /// Returns the position of the editor (on top of the edited cell) relative to the rendered DOM
+    fn get_editor_props(&self) -> Vec<CssProperty> {
+        let cell = match self.view.editing.as_ref() {
+            Some(edit) if self.view.rendered.contains(&edit.cell) => edit.cell,
+            _ => return vec![CssProperty::const_display(LayoutDisplay::None)],
+        };
+        let rect = self.view.rendered.get_dom_rect(self.inner.get_cell_rect(cell));
+        vec![
+            CssProperty::const_display(LayoutDisplay::Flex),
+            CssProperty::const_left(LayoutLeft::px(rect.origin.x)),
+            CssProperty::const_top(LayoutTop::px(rect.origin.y)),
+            CssProperty::const_width(LayoutWidth::px(rect.size.width)),
+            CssProperty::const_height(LayoutHeight::px(rect.size.height)),
+        ]
+    }
+
+    /// Renders the cells of `self.view.rendered` + the row numbers and column names,
+    /// see `input::SpreadsheetNodes` for the order of the nodes
+    fn render(&self, state_ref: &RefAny) -> Dom {
+
+        use azul_desktop::dom::{CallbackData, EventFilter, HoverEventFilter, FocusEventFilter, WindowEventFilter};
+
+        let window = &self.view.rendered;
+        let size = window.get_size(&self.inner);
+        let scroll_offset = window.get_scroll_offset();
+
+        let cells = window.rows().map(|row| {
+            let row_cells = window.columns().map(|column| {
+                let content = self.inner.get_cell_content(&SpreadsheetCellIndex { row, column }).cloned().unwrap_or_default();
+                Dom::text(content)
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(SPREADSHEET_CELL_CLASS))
+                .with_inline_css_props(with_layout(CELL_STYLE, &[
+                    Normal(CssProperty::const_width(LayoutWidth::px(self.inner.get_column_width(column)))),
+                ]))
+            }).collect::<Vec<_>>();
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(SPREADSHEET_ROW_CLASS))
+            .with_inline_css_props(with_layout(ROW_LAYOUT, &[
+                Normal(CssProperty::const_height(LayoutHeight::px(self.inner.get_row_height(row)))),
+            ]))
+            .with_children(row_cells.into())
+        }).collect::<Vec<_>>();
+
+        let selection_color = self.inner.style.accent_color;
+        let selection_background = ColorU { a: 40, .. selection_color };
+        let mut selection_style = vec![
+            Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: selection_color })),
+            Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: selection_color })),
+            Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: selection_color })),
+            Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: selection_color })),
+            Normal(CssProperty::const_background_content(vec![StyleBackgroundContent::Color(selection_background)].into())),
         ];
+        selection_style.extend(self.get_selection_props().into_iter().map(Normal));
+
+        let column_names = window.columns().map(|column| {
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(SPREADSHEET_COLUMN_NAME_CLASS))
+            .with_inline_css_props(with_layout(COLUMN_NAME_STYLE, &[
+                Normal(CssProperty::const_width(LayoutWidth::px(self.inner.get_column_width(column)))),
+            ]))
+            .with_children(vec![
+                Dom::text(column_name_from_number(column))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(HEADER_TEXT_STYLE)),
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(SPREADSHEET_RESIZE_HANDLE_CLASS))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(RESIZE_HANDLE_LAYOUT)),
+            ].into())
+        }).collect::<Vec<_>>();
+
+        let row_numbers = window.rows().map(|row| {
+            // NOTE: to_string() heap allocation is unavoidable
+            Dom::text((row + 1).to_string())
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(SPREADSHEET_ROW_NUMBER_CLASS))
+            .with_inline_css_props(with_layout(ROW_NUMBER_STYLE, &[
+                Normal(CssProperty::const_height(LayoutHeight::px(self.inner.get_row_height(row)))),
+            ]))
+        }).collect::<Vec<_>>();
+
+        // the editor is always rendered, but hidden if no cell is being edited
+        let editor_text = self.view.editing.as_ref().map(|edit| edit.text.clone()).unwrap_or_default();
+        let mut editor = TextInput::new(editor_text);
+        editor.set_on_text_input(state_ref.clone(), self::input::default_on_editor_text_input);
+        editor.set_on_virtual_key_down(state_ref.clone(), self::input::default_on_editor_virtual_key_down);
+        editor.set_on_focus_lost(state_ref.clone(), self::input::default_on_editor_focus_lost);
+        let mut editor_style = editor.container_style.clone().into_library_owned_vec();
+        editor_style.extend(EDITOR_LAYOUT.iter().cloned());
+        editor_style.extend(self.get_editor_props().into_iter().map(Normal));
+        editor.set_container_style(editor_style.into());
+
+        // the row numbers / column names stay at the top left of the viewport
+        Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(SPREADSHEET_CLASS))
+        .with_inline_css_props(with_layout(SPREADSHEET_LAYOUT, &[
+            Normal(CssProperty::const_width(LayoutWidth::px(size.width))),
+            Normal(CssProperty::const_height(LayoutHeight::px(size.height))),
+        ]))
+        .with_tab_index(TabIndex::Auto)
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_virtual_key_down }
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::TextInput),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_text_input }
+            },
+            // resizing a column continues when the mouse leaves the column name
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::MouseOver),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_drag }
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::LeftMouseUp),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_up }
+            },
+        ].into())
+        .with_children(vec![
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(SPREADSHEET_CELLS_CLASS))
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(CELLS_LAYOUT))
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_cells_mouse_down }
+                },
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::MouseOver),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_cells_mouse_over }
+                },
+            ].into())
+            .with_children(cells.into()),
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(SPREADSHEET_SELECTION_CLASS))
+            .with_inline_css_props(with_layout(SELECTION_LAYOUT, &selection_style)),
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(SPREADSHEET_COLUMN_NAMES_CLASS))
+            .with_inline_css_props(with_layout(COLUMN_NAMES_LAYOUT, &[
+                Normal(CssProperty::const_top(LayoutTop::px(scroll_offset.y))),
+            ]))
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_column_names_mouse_down }
+                },
+            ].into())
+            .with_children(column_names.into()),
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(SPREADSHEET_ROW_NUMBERS_CLASS))
+            .with_inline_css_props(with_layout(ROW_NUMBERS_LAYOUT, &[
+                Normal(CssProperty::const_left(LayoutLeft::px(scroll_offset.x))),
+            ]))
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_row_numbers_mouse_down }
+                },
+            ].into())
+            .with_children(row_numbers.into()),
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(SPREADSHEET_TOP_LEFT_RECT_CLASS))
+            .with_inline_css_props(with_layout(TOP_LEFT_RECT_STYLE, &[
+                Normal(CssProperty::const_left(LayoutLeft::px(scroll_offset.x))),
+                Normal(CssProperty::const_top(LayoutTop::px(scroll_offset.y))),
+            ]))
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    data: state_ref.clone(),
+                    callback: Callback { cb: self::input::default_on_top_left_rect_mouse_down }
+                },
+            ].into()),
+            editor.dom(),
+        ].into())
+    }
+}
 
-        Dom::iframe(RefAny::new(self.state), Self::render_table_iframe_contents)
-        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(IFRAME_STYLE))
+// handle input events for the Spreadsheet
+mod input {
+
+    use core::mem;
+    use std::string::String;
+    use std::vec::Vec;
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, DomNodeId, FocusTarget};
+    use azul_desktop::css::*;
+    use azul_desktop::task::Duration;
+    use azul_desktop::app::Clipboard;
+    use azul_core::window::{KeyboardState, VirtualKeyCode};
+    use crate::widgets::text_input::{TextInputState, TextInputStateWrapper, OnTextInputReturn, TextInputValid};
+    use super::{
+        SpreadsheetStateWrapper, SpreadsheetCellIndex, SpreadsheetCellSelection, SpreadsheetDrag,
+        SpreadsheetColumnResize, SpreadsheetCellClick, SpreadsheetCellEdit, SpreadsheetOnCellsChange, SpreadsheetOnSelectionChange, SpreadsheetOnColumnResize,
+        ColumnIndex, MIN_COLUMN_WIDTH, DOUBLE_CLICK_TIMEOUT_MS,
+    };
+
+    /// Nodes of the rendered table, see `SpreadsheetStateWrapper::render()`
+    struct SpreadsheetNodes {
+        root: DomNodeId,
+        cells: DomNodeId,
+        selection: DomNodeId,
+        column_names: DomNodeId,
+        editor: DomNodeId,
     }
 
-    extern "C" fn render_table_iframe_contents(state: &mut RefAny, info: IFrameCallbackInfo) -> IFrameCallbackReturn {
+    impl SpreadsheetNodes {
+        fn new(root: DomNodeId, info: &CallbackInfo) -> Option<Self> {
+            let cells = info.get_first_child(root)?;
+            let selection = info.get_next_sibling(cells)?;
+            let column_names = info.get_next_sibling(selection)?;
+            let row_numbers = info.get_next_sibling(column_names)?;
+            let top_left_rect = info.get_next_sibling(row_numbers)?;
+            let editor = info.get_next_sibling(top_left_rect)?;
+            Some(Self { root, cells, selection, column_names, editor })
+        }
 
-        use azul::css::Css;
+        /// For callbacks on the direct children of the root node
+        fn from_child(child: DomNodeId, info: &CallbackInfo) -> Option<Self> {
+            Self::new(info.get_parent(child)?, info)
+        }
+    }
 
-        let table_view_state = state.downcast_ref::<SpreadsheetState>().unwrap();
+    fn get_children(parent: DomNodeId, info: &CallbackInfo) -> Vec<DomNodeId> {
+        let mut children = Vec::new();
+        let mut next = info.get_first_child(parent);
+        while let Some(child) = next {
+            children.push(child);
+            next = info.get_next_sibling(child);
+        }
+        children
+    }
 
-        let logical_size = info.bounds.get_logical_size();
-        let padding_rows = 0;
-        let padding_columns = 0;
-        let row_start = 0; // bounds.top / table_view_state.row_height
-        let column_start = 0; // bounds.left / table_view_state.column_width
+    fn get_nth_child(parent: DomNodeId, n: usize, info: &CallbackInfo) -> Option<DomNodeId> {
+        let mut child = info.get_first_child(parent)?;
+        for _ in 0..n {
+            child = info.get_next_sibling(child)?;
+        }
+        Some(child)
+    }
 
-        // workaround for necessary_rows.ceil() not being available on no_std
-        let necessary_rows_f32 = logical_size.height as f32 / table_view_state.default_row_height;
-        let necessary_rows = if (necessary_rows_f32 * 10.0) as isize % 10_isize != 0 {
-            necessary_rows_f32 as usize + 1
-        } else {
-            necessary_rows_f32 as usize
+    pub(in super) extern "C" fn default_on_iframe_mouse_down(spreadsheet: &mut RefAny, info: CallbackInfo) -> Update {
+        // remember the iframe node, so that the table can be scrolled when using the keyboard
+        if let Some(mut spreadsheet) = spreadsheet.downcast_mut::<SpreadsheetStateWrapper>() {
+            spreadsheet.view.iframe_node = Some(info.get_hit_node()).into();
+        }
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_cells_mouse_down(spreadsheet: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut spreadsheet = match spreadsheet.downcast_mut::<SpreadsheetStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
         };
-        let necessary_columns_f32 = logical_size.width as f32 / table_view_state.default_column_width;
-        let necessary_columns = if (necessary_columns_f32 * 10.0) as isize % 10_isize != 0 {
-            necessary_columns_f32 as usize + 1
-        } else {
-            necessary_columns_f32 as usize
+
+        let nodes = match SpreadsheetNodes::from_child(info.get_hit_node(), &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
         };
 
-        let table_height = (necessary_rows + padding_rows) as f32 * table_view_state.default_row_height;
-        let table_width = (necessary_columns + padding_columns) as f32 * table_view_state.default_column_width;
+        let cell = match info.get_cursor_relative_to_node().into_option()
+            .and_then(|cursor| spreadsheet.view.rendered.get_cell_at(&spreadsheet.inner, cursor)) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
 
-        let mut dom = table_view_state.dom(
-            row_start..((row_start + necessary_rows + padding_rows)),
-            column_start..((column_start + necessary_columns + padding_columns))
-        );
+        // clicks into the editor are handled by the editor
+        if spreadsheet.view.editing.as_ref().map(|edit| edit.cell) == Some(cell) {
+            return Update::DoNothing;
+        }
+
+        let now = info.get_current_time();
+        let is_double_click = match spreadsheet.view.last_click.as_ref() {
+            Some(last_click) if last_click.cell == cell => match now.duration_since(&last_click.time) {
+                Duration::System(d) => {
+                    let d: std::time::Duration = d.into();
+                    d.as_millis() as u64 <= DOUBLE_CLICK_TIMEOUT_MS
+                },
+                Duration::Tick(_) => false,
+            },
+            _ => false,
+        };
+        spreadsheet.view.last_click = if is_double_click { None } else { Some(SpreadsheetCellClick { cell, time: now }) }.into();
+
+        // focusing the table finishes editing the previous cell
+        info.set_focus(FocusTarget::Id(nodes.root));
+
+        // shift + click extends the current selection
+        let selection = match spreadsheet.inner.selection.into_option() {
+            Some(s) if info.get_current_keyboard_state().shift_down => s.to(cell.row, cell.column),
+            _ => SpreadsheetCellSelection::from(cell.row, cell.column),
+        };
+
+        // the selection change callback is called when the mouse is released
+        spreadsheet.view.drag = SpreadsheetDrag::SelectCells;
+        set_selection(&mut *spreadsheet, selection, &nodes, &mut info);
+
+        if is_double_click {
+            start_editing(&mut *spreadsheet, cell, None, &nodes, &mut info);
+        }
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_cells_mouse_over(spreadsheet: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut spreadsheet = match spreadsheet.downcast_mut::<SpreadsheetStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if spreadsheet.view.drag != SpreadsheetDrag::SelectCells || !info.get_current_mouse_state().left_down {
+            return Update::DoNothing;
+        }
+
+        let nodes = match SpreadsheetNodes::from_child(info.get_hit_node(), &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let cell = match info.get_cursor_relative_to_node().into_option()
+            .and_then(|cursor| spreadsheet.view.rendered.get_cell_at(&spreadsheet.inner, cursor)) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let selection = match spreadsheet.inner.selection.into_option() {
+            Some(s) => s.to(cell.row, cell.column),
+            None => SpreadsheetCellSelection::from(cell.row, cell.column),
+        };
+
+        set_selection(&mut *spreadsheet, selection, &nodes, &mut info);
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_column_names_mouse_down(spreadsheet: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut spreadsheet = match spreadsheet.downcast_mut::<SpreadsheetStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let nodes = match SpreadsheetNodes::from_child(info.get_hit_node(), &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let x = match info.get_cursor_relative_to_node().into_option() {
+            Some(cursor) => spreadsheet.view.rendered.origin.x + cursor.x,
+            None => return Update::DoNothing,
+        };
+
+        // dragging the right edge of a column name resizes the column
+        if let Some(column) = spreadsheet.inner.get_column_border_at(x) {
+            spreadsheet.view.drag = SpreadsheetDrag::ResizeColumn(SpreadsheetColumnResize {
+                column,
+                width: spreadsheet.inner.get_column_width(column),
+            });
+            return Update::DoNothing;
+        }
+
+        // clicking on a column name selects the entire column
+        let column = match spreadsheet.inner.get_columns().get_index_at(x) {
+            Some(s) if spreadsheet.inner.row_count > 0 => s,
+            _ => return Update::DoNothing,
+        };
+
+        info.set_focus(FocusTarget::Id(nodes.root));
+        let last_row = spreadsheet.inner.row_count - 1;
+        let selection = SpreadsheetCellSelection::from(0, column).to(last_row, column);
+        if !set_selection(&mut *spreadsheet, selection, &nodes, &mut info) {
+            return Update::DoNothing;
+        }
+
+        call_on_selection_change(&mut *spreadsheet, &mut info)
+    }
+
+    pub(in super) extern "C" fn default_on_row_numbers_mouse_down(spreadsheet: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut spreadsheet = match spreadsheet.downcast_mut::<SpreadsheetStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let nodes = match SpreadsheetNodes::from_child(info.get_hit_node(), &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // clicking on a row number selects the entire row
+        let row = match info.get_cursor_relative_to_node().into_option()
+            .and_then(|cursor| spreadsheet.inner.get_rows().get_index_at(spreadsheet.view.rendered.origin.y + cursor.y)) {
+            Some(s) if spreadsheet.inner.column_count > 0 => s,
+            _ => return Update::DoNothing,
+        };
+
+        info.set_focus(FocusTarget::Id(nodes.root));
+        let last_column = spreadsheet.inner.column_count - 1;
+        let selection = SpreadsheetCellSelection::from(row, 0).to(row, last_column);
+        if !set_selection(&mut *spreadsheet, selection, &nodes, &mut info) {
+            return Update::DoNothing;
+        }
+
+        call_on_selection_change(&mut *spreadsheet, &mut info)
+    }
+
+    pub(in super) extern "C" fn default_on_top_left_rect_mouse_down(spreadsheet: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut spreadsheet = match spreadsheet.downcast_mut::<SpreadsheetStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let nodes = match SpreadsheetNodes::from_child(info.get_hit_node(), &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        info.set_focus(FocusTarget::Id(nodes.root));
+
+        let previous_selection = spreadsheet.inner.selection.into_option();
+        spreadsheet.inner.select_all();
+        if spreadsheet.inner.selection.into_option() == previous_selection {
+            return Update::DoNothing;
+        }
+
+        update_selection_display(&*spreadsheet, &nodes, &mut info);
+        call_on_selection_change(&mut *spreadsheet, &mut info)
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_drag(spreadsheet: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut spreadsheet = match spreadsheet.downcast_mut::<SpreadsheetStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let (column, width) = match spreadsheet.view.drag {
+            SpreadsheetDrag::ResizeColumn(SpreadsheetColumnResize { column, width }) => (column, width),
+            _ => return Update::DoNothing,
+        };
+
+        // the mouse button might have been released outside of the window
+        let current_mouse_state = info.get_current_mouse_state();
+        if !current_mouse_state.left_down {
+            spreadsheet.view.drag = SpreadsheetDrag::None;
+            return call_on_column_resize(&mut *spreadsheet, column, &mut info);
+        }
+
+        let current_position = current_mouse_state.cursor_position.get_position();
+        let previous_position = info.get_previous_mouse_state().and_then(|m| m.cursor_position.get_position());
+        let delta = match (current_position, previous_position) {
+            (Some(c), Some(p)) => c.x - p.x,
+            _ => return Update::DoNothing,
+        };
+
+        let width = width + delta;
+        spreadsheet.view.drag = SpreadsheetDrag::ResizeColumn(SpreadsheetColumnResize { column, width });
+
+        let new_width = width.max(MIN_COLUMN_WIDTH);
+        if new_width == spreadsheet.inner.get_column_width(column) {
+            return Update::DoNothing;
+        }
+
+        spreadsheet.inner.set_column_width(column, new_width);
+
+        if let Some(nodes) = SpreadsheetNodes::new(info.get_hit_node(), &info) {
+            update_column_width_display(&*spreadsheet, column, &nodes, &mut info);
+        }
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_up(spreadsheet: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut spreadsheet = match spreadsheet.downcast_mut::<SpreadsheetStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let drag = spreadsheet.view.drag;
+        spreadsheet.view.drag = SpreadsheetDrag::None;
+
+        match drag {
+            SpreadsheetDrag::None => Update::DoNothing,
+            SpreadsheetDrag::SelectCells => call_on_selection_change(&mut *spreadsheet, &mut info),
+            SpreadsheetDrag::ResizeColumn(SpreadsheetColumnResize { column, .. }) => call_on_column_resize(&mut *spreadsheet, column, &mut info),
+        }
+    }
 
-        IFrameCallbackReturn {
-            dom: dom.style(Css::empty()),
-            scroll_size: info.scroll_size,
-            scroll_offset: info.scroll_offset,
-            virtual_scroll_size: info.virtual_scroll_size,
-            virtual_scroll_offset: info.virtual_scroll_offset,
+    pub(in super) extern "C" fn default_on_virtual_key_down(spreadsheet: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut spreadsheet = match spreadsheet.downcast_mut::<SpreadsheetStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let nodes = match SpreadsheetNodes::new(info.get_hit_node(), &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let last_keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let ctrl = keyboard_state.ctrl_down;
+        let selection = spreadsheet.inner.selection.into_option();
+
+        match (last_keycode, selection) {
+            // ctrl + a
+            (VirtualKeyCode::A, _) if ctrl => {
+                spreadsheet.inner.select_all();
+                if spreadsheet.inner.selection.into_option() == selection {
+                    return Update::DoNothing;
+                }
+                update_selection_display(&*spreadsheet, &nodes, &mut info);
+                call_on_selection_change(&mut *spreadsheet, &mut info)
+            },
+            // ctrl + c
+            (VirtualKeyCode::C, Some(selection)) if ctrl => {
+                if let Some(mut clipboard) = Clipboard::new() {
+                    let _ = clipboard.set_clipboard_string(spreadsheet.inner.get_selection_tsv(selection).into());
+                }
+                Update::DoNothing
+            },
+            // ctrl + x
+            (VirtualKeyCode::X, Some(selection)) if ctrl => {
+                let copied = Clipboard::new()
+                    .and_then(|mut c| c.set_clipboard_string(spreadsheet.inner.get_selection_tsv(selection).into()))
+                    .is_some();
+                if !copied || !spreadsheet.inner.clear_cells(selection) {
+                    return Update::DoNothing;
+                }
+                update_cell_display(&*spreadsheet, selection, &nodes, &mut info);
+                call_on_cells_change(&mut *spreadsheet, selection, &mut info)
+            },
+            // ctrl + v
+            (VirtualKeyCode::V, Some(_)) if ctrl => {
+                let pasted = match Clipboard::new()
+                    .and_then(|c| c.get_clipboard_string())
+                    .and_then(|s| spreadsheet.inner.paste_tsv(s.as_str())) {
+                    Some(s) => s,
+                    None => return Update::DoNothing,
+                };
+                update_cell_display(&*spreadsheet, pasted, &nodes, &mut info);
+                update_selection_display(&*spreadsheet, &nodes, &mut info);
+                let mut update = call_on_cells_change(&mut *spreadsheet, pasted, &mut info);
+                update.max_self(call_on_selection_change(&mut *spreadsheet, &mut info));
+                update
+            },
+            (VirtualKeyCode::Delete, Some(selection)) | (VirtualKeyCode::Back, Some(selection)) => {
+                if !spreadsheet.inner.clear_cells(selection) {
+                    return Update::DoNothing;
+                }
+                update_cell_display(&*spreadsheet, selection, &nodes, &mut info);
+                call_on_cells_change(&mut *spreadsheet, selection, &mut info)
+            },
+            (VirtualKeyCode::Return, Some(selection)) |
+            (VirtualKeyCode::NumpadEnter, Some(selection)) |
+            (VirtualKeyCode::F2, Some(selection)) => {
+                start_editing(&mut *spreadsheet, selection.to_bottom_right, None, &nodes, &mut info);
+                Update::DoNothing
+            },
+            _ => {
+                // PageUp / PageDown moves by the number of fully visible rows
+                let page_rows = spreadsheet.view.rendered.rows().len().saturating_sub(1).max(1);
+                if !spreadsheet.inner.handle_on_virtual_key_down(last_keycode, &keyboard_state, page_rows) {
+                    return Update::DoNothing;
+                }
+                update_selection_display(&*spreadsheet, &nodes, &mut info);
+                scroll_to_active_cell(&*spreadsheet, &mut info);
+                call_on_selection_change(&mut *spreadsheet, &mut info)
+            },
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_text_input(spreadsheet: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut spreadsheet = match spreadsheet.downcast_mut::<SpreadsheetStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let nodes = match SpreadsheetNodes::new(info.get_hit_node(), &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let c = match info.get_current_keyboard_state().current_char.into_option().and_then(core::char::from_u32) {
+            Some(c) if !c.is_control() => c,
+            _ => return Update::DoNothing,
+        };
+
+        let cell = match spreadsheet.inner.selection.into_option() {
+            Some(s) => s.to_bottom_right,
+            None => return Update::DoNothing,
+        };
+
+        // typing into a selected cell replaces its contents
+        start_editing(&mut *spreadsheet, cell, Some(c.to_string()), &nodes, &mut info);
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_editor_text_input(spreadsheet: &mut RefAny, state: &TextInputState, _info: &mut CallbackInfo) -> OnTextInputReturn {
+        if let Some(mut spreadsheet) = spreadsheet.downcast_mut::<SpreadsheetStateWrapper>() {
+            if let Some(edit) = spreadsheet.view.editing.as_mut() {
+                edit.text = state.get_text().into();
+            }
+        }
+        OnTextInputReturn { update: Update::DoNothing, valid: TextInputValid::Yes }
+    }
+
+    pub(in super) extern "C" fn default_on_editor_virtual_key_down(spreadsheet: &mut RefAny, state: &TextInputState, info: &mut CallbackInfo) -> OnTextInputReturn {
+
+        let handled_by_editor = OnTextInputReturn { update: Update::DoNothing, valid: TextInputValid::Yes };
+
+        let mut spreadsheet = match spreadsheet.downcast_mut::<SpreadsheetStateWrapper>() {
+            Some(s) => s,
+            None => return handled_by_editor,
+        };
+
+        if let Some(edit) = spreadsheet.view.editing.as_mut() {
+            edit.text = state.get_text().into();
+        }
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let (commit, next_cell_key) = match keyboard_state.current_virtual_keycode.into_option() {
+            // return moves to the cell below (shift + return to the cell above)
+            Some(VirtualKeyCode::Return) | Some(VirtualKeyCode::NumpadEnter) => {
+                (true, Some(if keyboard_state.shift_down { VirtualKeyCode::Up } else { VirtualKeyCode::Down }))
+            },
+            // tab moves to the cell on the right (shift + tab to the cell on the left)
+            Some(VirtualKeyCode::Tab) => (true, Some(VirtualKeyCode::Tab)),
+            Some(VirtualKeyCode::Escape) => (false, None),
+            _ => return handled_by_editor,
+        };
+
+        let nodes = match SpreadsheetNodes::from_child(info.get_hit_node(), info) {
+            Some(s) => s,
+            None => return handled_by_editor,
+        };
+
+        let mut update = finish_editing(&mut *spreadsheet, commit, &nodes, info);
+        info.set_focus(FocusTarget::Id(nodes.root));
+
+        if let Some(next_cell_key) = next_cell_key {
+            let keyboard_state = KeyboardState { shift_down: keyboard_state.shift_down, .. KeyboardState::default() };
+            if spreadsheet.inner.handle_on_virtual_key_down(next_cell_key, &keyboard_state, 1) {
+                update_selection_display(&*spreadsheet, &nodes, info);
+                scroll_to_active_cell(&*spreadsheet, info);
+                update.max_self(call_on_selection_change(&mut *spreadsheet, info));
+            }
+        }
+
+        // the editor must not handle the key (tab would move the focus)
+        OnTextInputReturn { update, valid: TextInputValid::No }
+    }
+
+    pub(in super) extern "C" fn default_on_editor_focus_lost(spreadsheet: &mut RefAny, state: &TextInputState, info: &mut CallbackInfo) -> Update {
+
+        let mut spreadsheet = match spreadsheet.downcast_mut::<SpreadsheetStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // already finished via return / tab / escape
+        match spreadsheet.view.editing.as_mut() {
+            Some(edit) => edit.text = state.get_text().into(),
+            None => return Update::DoNothing,
+        }
+
+        match SpreadsheetNodes::from_child(info.get_hit_node(), info) {
+            Some(nodes) => finish_editing(&mut *spreadsheet, true, &nodes, info),
+            None => Update::DoNothing,
+        }
+    }
+
+    /// Sets the selection and updates the selection rectangle, returns whether the selection changed
+    fn set_selection(
+        spreadsheet: &mut SpreadsheetStateWrapper,
+        selection: SpreadsheetCellSelection,
+        nodes: &SpreadsheetNodes,
+        info: &mut CallbackInfo,
+    ) -> bool {
+        if spreadsheet.inner.selection.into_option() == Some(selection) {
+            return false;
+        }
+        spreadsheet.inner.selection = Some(selection).into();
+        update_selection_display(spreadsheet, nodes, info);
+        true
+    }
+
+    /// Shows the editor on top of the cell and focuses it,
+    /// `text` replaces the cell contents (typing into a selected cell)
+    fn start_editing(
+        spreadsheet: &mut SpreadsheetStateWrapper,
+        cell: SpreadsheetCellIndex,
+        text: Option<String>,
+        nodes: &SpreadsheetNodes,
+        info: &mut CallbackInfo,
+    ) {
+
+        // the editor can only be shown on top of a rendered cell
+        if !spreadsheet.view.rendered.contains(&cell) {
+            return;
+        }
+
+        let text = match text {
+            Some(s) => s.into(),
+            None => spreadsheet.inner.get_cell_content(&cell).cloned().unwrap_or_default(),
+        };
+
+        if let Some(mut editor_state) = info.get_dataset(nodes.editor) {
+            if let Some(mut editor_state) = editor_state.downcast_mut::<TextInputStateWrapper>() {
+                let chars = text.as_str().chars().map(|c| c as u32).collect::<Vec<_>>();
+                editor_state.inner.cursor_pos = chars.len();
+                editor_state.inner.selection = None.into();
+                editor_state.inner.text = chars.into();
+            }
+        }
+
+        // the label is the last child of the text input
+        if let Some(label) = info.get_last_child(nodes.editor) {
+            info.set_string_contents(label, text.clone());
+        }
+
+        spreadsheet.view.editing = Some(SpreadsheetCellEdit { cell, text }).into();
+        update_editor_display(spreadsheet, nodes, info);
+        info.set_focus(FocusTarget::Id(nodes.editor));
+    }
+
+    /// Hides the editor and (if `commit` is set) writes the text of the editor into the cell
+    fn finish_editing(
+        spreadsheet: &mut SpreadsheetStateWrapper,
+        commit: bool,
+        nodes: &SpreadsheetNodes,
+        info: &mut CallbackInfo,
+    ) -> Update {
+
+        let SpreadsheetCellEdit { cell, text } = match mem::replace(&mut spreadsheet.view.editing, None.into()).into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        update_editor_display(spreadsheet, nodes, info);
+
+        let old_text = spreadsheet.inner.get_cell_content(&cell).map(|s| s.as_str()).unwrap_or("");
+        if !commit || old_text == text.as_str() {
+            return Update::DoNothing;
+        }
+
+        spreadsheet.inner.set_cell_content(cell, text);
+        let changed = SpreadsheetCellSelection::from(cell.row, cell.column);
+        update_cell_display(spreadsheet, changed, nodes, info);
+        call_on_cells_change(spreadsheet, changed, info)
+    }
+
+    /// Scrolls the iframe so that the active cell of the selection is visible
+    fn scroll_to_active_cell(spreadsheet: &SpreadsheetStateWrapper, info: &mut CallbackInfo) {
+
+        let (iframe_node, cell) = match (spreadsheet.view.iframe_node.into_option(), spreadsheet.inner.selection.into_option()) {
+            (Some(n), Some(s)) => (n, s.to_bottom_right),
+            _ => return,
+        };
+
+        let rendered = &spreadsheet.view.rendered;
+        let scroll_position = info.get_scroll_amount(iframe_node).unwrap_or(rendered.scroll_position);
+        if let Some(new_position) = spreadsheet.inner.get_scroll_position_to_show(cell, scroll_position, rendered.viewport) {
+            info.set_scroll_amount(iframe_node, new_position);
+        }
+    }
+
+    fn update_selection_display(spreadsheet: &SpreadsheetStateWrapper, nodes: &SpreadsheetNodes, info: &mut CallbackInfo) {
+        for prop in spreadsheet.get_selection_props() {
+            info.set_css_property(nodes.selection, prop);
+        }
+    }
+
+    fn update_editor_display(spreadsheet: &SpreadsheetStateWrapper, nodes: &SpreadsheetNodes, info: &mut CallbackInfo) {
+        for prop in spreadsheet.get_editor_props() {
+            info.set_css_property(nodes.editor, prop);
+        }
+    }
+
+    /// Updates the text of the rendered cells in the range
+    fn update_cell_display(
+        spreadsheet: &SpreadsheetStateWrapper,
+        range: SpreadsheetCellSelection,
+        nodes: &SpreadsheetNodes,
+        info: &mut CallbackInfo,
+    ) {
+        let rendered = &spreadsheet.view.rendered;
+        for (row, row_node) in rendered.rows().zip(get_children(nodes.cells, info)) {
+            for (column, cell_node) in rendered.columns().zip(get_children(row_node, info)) {
+                let cell = SpreadsheetCellIndex { row, column };
+                if !range.contains(&cell) {
+                    continue;
+                }
+                let content = spreadsheet.inner.get_cell_content(&cell).cloned().unwrap_or_default();
+                info.set_string_contents(cell_node, content);
+            }
+        }
+    }
+
+    /// Updates the width of the column name and the cells of a resized column
+    fn update_column_width_display(
+        spreadsheet: &SpreadsheetStateWrapper,
+        column: ColumnIndex,
+        nodes: &SpreadsheetNodes,
+        info: &mut CallbackInfo,
+    ) {
+
+        let rendered = &spreadsheet.view.rendered;
+        if !rendered.columns().contains(&column) {
+            return;
+        }
+
+        let index = column - rendered.column_start;
+        let width = CssProperty::const_width(LayoutWidth::px(spreadsheet.inner.get_column_width(column)));
+
+        if let Some(column_name) = get_nth_child(nodes.column_names, index, info) {
+            info.set_css_property(column_name, width.clone());
+        }
+
+        for row_node in get_children(nodes.cells, info) {
+            if let Some(cell_node) = get_nth_child(row_node, index, info) {
+                info.set_css_property(cell_node, width.clone());
+            }
+        }
+
+        let size = rendered.get_size(&spreadsheet.inner);
+        info.set_css_property(nodes.root, CssProperty::const_width(LayoutWidth::px(size.width)));
+
+        update_selection_display(spreadsheet, nodes, info);
+        update_editor_display(spreadsheet, nodes, info);
+    }
+
+    fn call_on_cells_change(spreadsheet: &mut SpreadsheetStateWrapper, changed: SpreadsheetCellSelection, info: &mut CallbackInfo) -> Update {
+        let inner = &spreadsheet.inner;
+        match spreadsheet.on_cells_change.as_mut() {
+            Some(SpreadsheetOnCellsChange { callback, data }) => (callback.cb)(data, inner, changed, info),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn call_on_selection_change(spreadsheet: &mut SpreadsheetStateWrapper, info: &mut CallbackInfo) -> Update {
+        let inner = &spreadsheet.inner;
+        match spreadsheet.on_selection_change.as_mut() {
+            Some(SpreadsheetOnSelectionChange { callback, data }) => (callback.cb)(data, inner, info),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn call_on_column_resize(spreadsheet: &mut SpreadsheetStateWrapper, column: ColumnIndex, info: &mut CallbackInfo) -> Update {
+        let inner = &spreadsheet.inner;
+        match spreadsheet.on_column_resize.as_mut() {
+            Some(SpreadsheetOnColumnResize { callback, data }) => (callback.cb)(data, inner, column, info),
+            None => Update::DoNothing,
         }
     }
 }
@@ -585,4 +2090,253 @@ fn test_column_name_from_number() {
     assert_eq!(column_name_from_number(26), String::from("AA"));
     assert_eq!(column_name_from_number(27), String::from("AB"));
     assert_eq!(column_name_from_number(225), String::from("HR"));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_spreadsheet_render_window() {
+
+    let mut state = SpreadsheetState::default();
+    state.row_count = 100_000;
+    state.set_column_width(1, 50.0);
+    state.set_row_height(2, 40.0);
+
+    // column B is 50px wide, row 3 is 40px high
+    assert_eq!(state.get_cell_at(LogicalPosition::new(149.0, 59.0)), Some(SpreadsheetCellIndex { row: 2, column: 1 }));
+    assert_eq!(state.get_cell_at(LogicalPosition::new(150.0, 80.0)), Some(SpreadsheetCellIndex { row: 3, column: 2 }));
+    assert_eq!(state.get_cell_at(LogicalPosition::new(-1.0, 0.0)), None);
+    assert_eq!(state.get_table_size(), LogicalSize::new(2550.0, 2_000_020.0));
+    assert_eq!(state.get_column_border_at(148.0), Some(1));
+    assert_eq!(state.get_column_border_at(120.0), None);
+
+    // only the (partially) visible rows are rendered
+    let viewport = LogicalSize::new(340.0, 220.0);
+    let window = state.get_render_window(LogicalPosition::new(0.0, 1_000_010.0), viewport);
+    assert_eq!(window.rows(), 49_999..50_010);
+    assert_eq!(window.columns(), 0..4);
+    assert_eq!(window.origin, LogicalPosition::new(0.0, 1_000_000.0));
+    assert_eq!(window.get_scroll_offset(), LogicalPosition::new(0.0, 10.0));
+
+    // the table can't be scrolled past the last row
+    let window = state.get_render_window(LogicalPosition::new(0.0, 5_000_000.0), viewport);
+    assert_eq!(window.rows(), 99_990..100_000);
+    assert_eq!(window.scroll_position.y, 1_999_820.0);
+
+    let cell = SpreadsheetCellIndex { row: 50_020, column: 0 };
+    let scroll_position = state.get_scroll_position_to_show(cell, LogicalPosition::new(0.0, 1_000_010.0), viewport);
+    assert_eq!(scroll_position, Some(LogicalPosition::new(0.0, 1_000_240.0)));
+}
+
+#[test]
+fn test_spreadsheet_tsv() {
+
+    let mut state = SpreadsheetState::default();
+    state.set_cell_content(SpreadsheetCellIndex { row: 0, column: 0 }, "a");
+    state.set_cell_content(SpreadsheetCellIndex { row: 0, column: 2 }, "c\td");
+    state.set_cell_content(SpreadsheetCellIndex { row: 1, column: 1 }, "say \"hi\"");
+
+    let tsv = state.get_selection_tsv(SpreadsheetCellSelection::from(1, 2).to(0, 0));
+    assert_eq!(tsv, "a\t\t\"c\td\"\n\t\"say \"\"hi\"\"\"\t");
+    assert_eq!(parse_tsv(&tsv), vec![
+        vec![String::from("a"), String::new(), String::from("c\td")],
+        vec![String::new(), String::from("say \"hi\""), String::new()],
+    ]);
+
+    // pasting starts at the top left cell of the selection
+    state.set_selection(Some(SpreadsheetCellSelection::from(5, 3).to(4, 2)));
+    let pasted = state.paste_tsv("1\t2\r\n3\t\r\n");
+    assert_eq!(pasted, Some(SpreadsheetCellSelection::from(4, 2).to(5, 3)));
+    assert_eq!(state.get_cell_content(&SpreadsheetCellIndex { row: 4, column: 3 }).map(|s| s.as_str()), Some("2"));
+    assert_eq!(state.get_cell_content(&SpreadsheetCellIndex { row: 5, column: 2 }).map(|s| s.as_str()), Some("3"));
+    assert_eq!(state.get_cell_content(&SpreadsheetCellIndex { row: 5, column: 3 }), None);
+
+    // shift + arrow keys extend the selection
+    let shift = KeyboardState { shift_down: true, .. KeyboardState::default() };
+    assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Down, &shift, 10));
+    assert_eq!(state.selection.into_option(), Some(SpreadsheetCellSelection::from(4, 2).to(6, 3)));
+    assert!(state.handle_on_virtual_key_down(VirtualKeyCode::Left, &KeyboardState::default(), 10));
+    assert_eq!(state.selection.into_option(), Some(SpreadsheetCellSelection::from(6, 2)));
+
+    assert!(state.clear_cells(SpreadsheetCellSelection::from(0, 0).to(4, 4)));
+    assert_eq!(state.cell_contents.len(), 1);
+}
+
+#[test]
+fn test_spreadsheet_virtualization_bounds() {
+
+    let mut state = SpreadsheetState::default();
+    state.set_column_width(1, 50.0);
+    state.set_row_height(2, 40.0);
+    let viewport = LogicalSize::new(340.0, 220.0);
+
+    // the scroll position is clamped to the table, the row starting
+    // exactly at the bottom edge of the viewport is not rendered
+    let window = state.get_render_window(LogicalPosition::new(-50.0, -50.0), viewport);
+    assert_eq!(window.scroll_position, LogicalPosition::new(0.0, 0.0));
+    assert_eq!(window.rows(), 0..9);
+    assert_eq!(window.columns(), 0..4);
+    assert_eq!(window.get_size(&state), LogicalSize::new(390.0, 220.0));
+
+    // scrolled to the last column
+    let window = state.get_render_window(LogicalPosition::new(10_000.0, 0.0), viewport);
+    assert_eq!(window.scroll_position.x, 2250.0);
+    assert_eq!(window.columns(), 23..26);
+    assert_eq!(window.origin.x, 2250.0);
+
+    // the rendered rows always cover the viewport, but nothing outside of it
+    let rows = state.get_rows();
+    let cells_height = viewport.height - COLUMN_HEADER_HEIGHT as f32;
+    for i in 0..200 {
+        let window = state.get_render_window(LogicalPosition::new(0.0, i as f32 * 137.0), viewport);
+        let top = window.scroll_position.y;
+        let bottom = top + cells_height;
+        assert!(rows.get_offset(window.rows().start) <= top);
+        assert!(rows.get_offset(window.rows().start + 1) > top);
+        assert!(rows.get_offset(window.rows().end - 1) < bottom);
+        assert!(window.rows().end == state.row_count || rows.get_offset(window.rows().end) >= bottom);
+        assert_eq!(window.origin.y, rows.get_offset(window.rows().start));
+    }
+
+    // tables smaller than the viewport are rendered completely
+    let mut small = state.clone();
+    small.row_count = 3;
+    small.column_count = 2;
+    let window = small.get_render_window(LogicalPosition::new(100.0, 100.0), viewport);
+    assert_eq!(window.scroll_position, LogicalPosition::new(0.0, 0.0));
+    assert_eq!(window.rows(), 0..3);
+    assert_eq!(window.columns(), 0..2);
+
+    // nothing is rendered for empty tables or if only the headers are visible
+    small.row_count = 0;
+    let window = small.get_render_window(LogicalPosition::new(0.0, 0.0), viewport);
+    assert_eq!(window.rows(), 0..0);
+    assert_eq!(window.get_size(&small).height, COLUMN_HEADER_HEIGHT as f32);
+    let window = state.get_render_window(LogicalPosition::new(0.0, 0.0), LogicalSize::new(30.0, 10.0));
+    assert_eq!(window.rows(), 0..0);
+    assert_eq!(window.columns(), 0..0);
+
+    // the DOM only contains the rendered cells
+    let mut spreadsheet = SpreadsheetStateWrapper::default();
+    spreadsheet.inner = state.clone();
+    spreadsheet.view.rendered = state.get_render_window(LogicalPosition::new(0.0, 1_000.0), viewport);
+    assert_eq!(spreadsheet.view.rendered.rows(), 49..59);
+    let dom = spreadsheet.render(&RefAny::new(spreadsheet.clone()));
+    let children = dom.children.as_ref();
+    let cells = children[0].children.as_ref();
+    assert_eq!(cells.len(), 10);
+    assert!(cells.iter().all(|row| row.children.as_ref().len() == 4));
+    assert_eq!(children[2].children.as_ref().len(), 4);
+    assert_eq!(children[3].children.as_ref().len(), 10);
+}
+
+#[test]
+fn test_spreadsheet_tsv_copy_paste() {
+
+    let cell = |row, column| SpreadsheetCellIndex { row, column };
+    let mut state = SpreadsheetState::default();
+
+    // empty cells are copied as empty strings
+    assert_eq!(state.get_selection_tsv(SpreadsheetCellSelection::from(0, 0)), "");
+    state.set_cell_content(cell(1, 1), "x");
+    let tsv = state.get_selection_tsv(SpreadsheetCellSelection::from(0, 0).to(2, 1));
+    assert_eq!(tsv, "\t\n\tx\n\t");
+    assert_eq!(parse_tsv(&tsv), vec![
+        vec![String::new(), String::new()],
+        vec![String::new(), String::from("x")],
+        vec![String::new(), String::new()],
+    ]);
+
+    // quotes are only special at the start of a cell, quoted cells may contain tabs and newlines
+    assert_eq!(parse_tsv("a\"b\t\"c\"\"d\""), vec![vec![String::from("a\"b"), String::from("c\"d")]]);
+    assert_eq!(parse_tsv("\"1\t2\r\n3\"\t4\r\n5"), vec![
+        vec![String::from("1\t2\r\n3"), String::from("4")],
+        vec![String::from("5")],
+    ]);
+
+    // copying and pasting the cells somewhere else results in the same cells
+    state.set_cell_content(cell(0, 0), "line 1\nline 2");
+    state.set_cell_content(cell(2, 0), "tab\there");
+    let copied = SpreadsheetCellSelection::from(0, 0).to(2, 1);
+    let tsv = state.get_selection_tsv(copied);
+    state.set_selection(Some(SpreadsheetCellSelection::from(10, 5)));
+    assert_eq!(state.paste_tsv(&tsv), Some(SpreadsheetCellSelection::from(10, 5).to(12, 6)));
+    assert_eq!(state.get_selection_tsv(SpreadsheetCellSelection::from(10, 5).to(12, 6)), tsv);
+    assert_eq!(state.get_cell_content(&cell(10, 5)).map(|s| s.as_str()), Some("line 1\nline 2"));
+    assert_eq!(state.get_cell_content(&cell(12, 5)).map(|s| s.as_str()), Some("tab\there"));
+
+    // pasted empty cells clear the existing contents
+    state.set_selection(Some(SpreadsheetCellSelection::from(0, 0)));
+    state.paste_tsv("y\t");
+    assert_eq!(state.get_cell_content(&cell(0, 0)).map(|s| s.as_str()), Some("y"));
+    assert_eq!(state.get_cell_content(&cell(0, 1)), None);
+
+    // cells outside of the table are not pasted
+    let mut small = SpreadsheetState::default();
+    small.row_count = 3;
+    small.column_count = 2;
+    assert_eq!(small.paste_tsv("a"), None);
+    assert!(small.cell_contents.is_empty());
+    small.set_selection(Some(SpreadsheetCellSelection::from(2, 1)));
+    assert_eq!(small.paste_tsv("a\tb\nc\td"), Some(SpreadsheetCellSelection::from(2, 1)));
+    assert_eq!(small.cell_contents.len(), 1);
+    assert_eq!(small.get_cell_content(&cell(2, 1)).map(|s| s.as_str()), Some("a"));
+}
+
+#[cfg(feature = "svg")]
+#[test]
+fn test_spreadsheet_scroll_renders_new_rows() {
+
+    use azul_desktop::resources::{AppConfig, ImageCache, LayoutSolverVersion};
+    use azul_desktop::callbacks::LayoutCallbackInfo;
+    use azul_desktop::dom::NodeType;
+    use azul_desktop::shell::headless::HeadlessWindow;
+    use azul_desktop::styled_dom::StyledDom;
+    use azul_core::window::{CursorPosition, WindowCreateOptions};
+
+    extern "C" fn layout(_: &mut RefAny, _: LayoutCallbackInfo) -> StyledDom {
+        let mut state = SpreadsheetState::default();
+        state.row_count = 10_000;
+        Dom::body()
+        .with_children(vec![Spreadsheet::new(state).dom()].into())
+        .style(&mut Css::empty())
+    }
+
+    // rows rendered by the last invocation of the iframe callback + the row numbers in its DOM
+    let get_rendered_rows = |window: &HeadlessWindow| {
+        let results = &window.internal.layout_results;
+        let mut spreadsheet = results[0].styled_dom.node_data.as_ref().iter()
+            .find_map(|n| n.get_dataset().clone().into_option())
+            .expect("no spreadsheet in the root DOM");
+        let rows = spreadsheet.downcast_ref::<SpreadsheetStateWrapper>().unwrap().view.rendered.rows();
+        let iframe_dom = &results.iter().find(|r| r.parent_dom_id.is_some()).expect("spreadsheet iframe was not rendered").styled_dom;
+        let row_numbers = iframe_dom.node_data.as_ref().iter().filter_map(|n| match n.get_node_type() {
+            NodeType::Text(s) => s.as_str().parse::<usize>().ok(),
+            _ => None,
+        }).collect::<Vec<_>>();
+        (rows, row_numbers)
+    };
+
+    let mut options = WindowCreateOptions::new(layout);
+    options.state.size.dimensions = LogicalSize::new(400.0, 300.0);
+    options.state.size.hidpi_factor = 1.0;
+
+    let mut window = HeadlessWindow::new(
+        RefAny::new(()),
+        AppConfig::default(LayoutSolverVersion::March2021),
+        ImageCache::new(),
+        options,
+    );
+
+    // 300px - 20px column header: 14 rows of 20px
+    let (rows, row_numbers) = get_rendered_rows(&window);
+    assert_eq!(rows, 0..14);
+    assert_eq!(row_numbers, (1..=14).collect::<Vec<_>>());
+
+    let cursor = CursorPosition::InWindow(LogicalPosition::new(200.0, 150.0));
+    window.update_window_state(|s| s.mouse_state.cursor_position = cursor);
+
+    // scrolling past the rendered rows re-invokes the iframe with the new scroll position
+    window.update_window_state(|s| s.mouse_state.scroll_y = OptionF32::Some(1000.0));
+    let (rows, row_numbers) = get_rendered_rows(&window);
+    assert_eq!(rows, 50..64);
+    assert_eq!(row_numbers, (51..=64).collect::<Vec<_>>());
+}
@@ -0,0 +1,1498 @@
+//! Multi-line text editor (line wrapping, selection, clipboard, undo / redo)
+
+use core::ops::Range;
+use azul_desktop::{
+    css::*,
+    css::AzString,
+    dom::{
+        Dom, NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
+        NodeDataInlineCssProperty::{Normal, Hover, Focus},
+    },
+    callbacks::{RefAny, Callback, CallbackInfo, Update, InlineText},
+};
+use azul_core::window::{KeyboardState, VirtualKeyCode, LogicalRect, LogicalPosition, LogicalSize};
+use core::mem;
+use std::vec::Vec;
+use std::string::{String, ToString};
+use crate::widgets::text_input::{
+    TextInputSelection, TextInputSelectionRange, OptionTextInputSelection,
+    get_glyph_char_bounds, get_glyph_char_text_indices,
+};
+
+/// Maximum number of edits that can be undone
+const MAX_UNDO_STEPS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextEdit {
+    pub state: TextEditStateWrapper,
+    pub container_style: NodeDataInlineCssPropertyVec,
+    pub line_numbers_style: NodeDataInlineCssPropertyVec,
+    pub label_style: NodeDataInlineCssPropertyVec,
+}
+
+pub type TextEditOnTextChangeCallbackType = extern "C" fn(&mut RefAny, &TextEditState, &mut CallbackInfo) -> Update;
+#[repr(C)]
+pub struct TextEditOnTextChangeCallback { pub cb: TextEditOnTextChangeCallbackType }
+impl_callback!(TextEditOnTextChangeCallback);
+
+pub type TextEditOnFocusLostCallbackType = extern "C" fn(&mut RefAny, &TextEditState, &mut CallbackInfo) -> Update;
+#[repr(C)]
+pub struct TextEditOnFocusLostCallback { pub cb: TextEditOnFocusLostCallbackType }
+impl_callback!(TextEditOnFocusLostCallback);
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextEditState {
+    pub text: U32Vec, // Vec<char>, lines are separated by '\n'
+    pub selection: OptionTextInputSelection,
+    pub cursor_pos: usize,
+    pub show_line_numbers: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextEditStateWrapper {
+    pub inner: TextEditState,
+    pub on_text_change: OptionTextEditOnTextChange,
+    pub on_focus_lost: OptionTextEditOnFocusLost,
+    history: TextEditHistory,
+    view: TextEditView,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TextEditOnTextChange {
+    pub data: RefAny,
+    pub callback: TextEditOnTextChangeCallback,
+}
+
+impl_option!(TextEditOnTextChange, OptionTextEditOnTextChange, copy = false, [Debug, Clone, PartialEq, PartialOrd]);
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TextEditOnFocusLost {
+    pub data: RefAny,
+    pub callback: TextEditOnFocusLostCallback,
+}
+
+impl_option!(TextEditOnFocusLost, OptionTextEditOnFocusLost, copy = false, [Debug, Clone, PartialEq, PartialOrd]);
+
+#[derive(Debug, Default, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextEditHistory {
+    undo_stack: TextEditSnapshotVec,
+    redo_stack: TextEditSnapshotVec,
+    /// Consecutive edits of the same kind (i.e. typing a word) are undone in one step
+    last_edit: OptionTextEditKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextEditSnapshot {
+    text: U32Vec,
+    cursor_pos: usize,
+}
+
+impl_vec!(TextEditSnapshot, TextEditSnapshotVec, TextEditSnapshotVecDestructor);
+impl_vec_debug!(TextEditSnapshot, TextEditSnapshotVec);
+impl_vec_clone!(TextEditSnapshot, TextEditSnapshotVec, TextEditSnapshotVecDestructor);
+impl_vec_partialeq!(TextEditSnapshot, TextEditSnapshotVec);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub enum TextEditKind {
+    Typing,
+    Deleting,
+    Other,
+}
+
+impl_option!(TextEditKind, OptionTextEditKind, [Debug, Copy, Clone, PartialEq, Eq]);
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextEditView {
+    /// x-position that the caret tries to keep when moving up / down
+    preferred_caret_x: OptionF32,
+    /// How far the content is scrolled down
+    scroll_y: f32,
+}
+
+const BACKGROUND_COLOR: ColorU = ColorU { r: 255,  g: 255,  b: 255,  a: 255 }; // white
+const COLOR_9B9B9B: ColorU = ColorU { r: 155, g: 155, b: 155, a: 255 }; // #9b9b9b
+const COLOR_4286F4: ColorU = ColorU { r: 66, g: 134, b: 244, a: 255 }; // #4286f4
+const COLOR_4C4C4C: ColorU = ColorU { r: 76, g: 76, b: 76, a: 255 }; // #4C4C4C
+const COLOR_B3D7FF: ColorU = ColorU { r: 179, g: 215, b: 255, a: 255 }; // #b3d7ff
+const BACKGROUND_THEME_LIGHT: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(BACKGROUND_COLOR)];
+const BACKGROUND_COLOR_LIGHT: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(BACKGROUND_THEME_LIGHT);
+const SELECTION_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_B3D7FF)];
+const CARET_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_4C4C4C)];
+
+const SANS_SERIF_STR: &str = "sans-serif";
+const SANS_SERIF: AzString = AzString::from_const_str(SANS_SERIF_STR);
+const SANS_SERIF_FAMILIES: &[StyleFontFamily] = &[StyleFontFamily::System(SANS_SERIF)];
+const SANS_SERIF_FAMILY: StyleFontFamilyVec = StyleFontFamilyVec::from_const_slice(SANS_SERIF_FAMILIES);
+
+static TEXT_EDIT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
+
+    Normal(CssProperty::const_cursor(StyleCursor::Text)),
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(200))),
+    Normal(CssProperty::const_min_height(LayoutMinHeight::const_px(100))),
+    Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(2))),
+    Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(1))),
+    Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(1))),
+
+    // border: 1px inset #9b9b9b;
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Inset })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Inset })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Inset })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Inset })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_9B9B9B })),
+
+    // the content is scrolled by the widget itself (see update_text_edit_scroll)
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+
+    // Hover(border-color: #4286f4;)
+
+    Hover(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_4286F4 })),
+    Hover(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4286F4 })),
+    Hover(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_4286F4 })),
+    Hover(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_4286F4 })),
+
+    // Focus(border-color: #4286f4;)
+
+    Focus(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_4286F4 })),
+];
+
+// line numbers + text, moved up via "top" when scrolling
+static TEXT_EDIT_CONTENT_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+];
+
+static TEXT_EDIT_LINE_NUMBERS_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(20))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(4))),
+    Normal(CssProperty::const_text_align(StyleTextAlign::Right)),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+];
+
+// the selection and caret are positioned absolutely relative to the text area
+static TEXT_EDIT_TEXT_AREA_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+];
+
+static TEXT_EDIT_LABEL_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_text_align(StyleTextAlign::Left)),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+];
+
+// moved + resized via set_css_property whenever the selection changes
+static TEXT_EDIT_SELECTION_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SELECTION_BACKGROUND))),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(0))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(0))),
+];
+
+// hidden (zero-sized) until the text edit is focused
+static TEXT_EDIT_CARET_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(CARET_BACKGROUND))),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(0))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(0))),
+];
+
+impl Default for TextEdit {
+    fn default() -> Self {
+        TextEdit {
+            state: TextEditStateWrapper::default(),
+            container_style: NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_CONTAINER_PROPS),
+            line_numbers_style: NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_LINE_NUMBERS_PROPS),
+            label_style: NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_LABEL_PROPS),
+        }
+    }
+}
+
+impl Default for TextEditState {
+    fn default() -> Self {
+        TextEditState {
+            text: Vec::new().into(),
+            selection: None.into(),
+            cursor_pos: 0,
+            show_line_numbers: false,
+        }
+    }
+}
+
+impl Default for TextEditStateWrapper {
+    fn default() -> Self {
+        TextEditStateWrapper {
+            inner: TextEditState::default(),
+            on_text_change: None.into(),
+            on_focus_lost: None.into(),
+            history: TextEditHistory::default(),
+            view: TextEditView::default(),
+        }
+    }
+}
+
+impl TextEdit {
+
+    pub fn new(s: AzString) -> Self {
+        Self {
+            state: TextEditStateWrapper {
+                inner: TextEditState {
+                    text: normalize_text(s.as_str()).into(),
+                    .. Default::default()
+                },
+                .. Default::default()
+            },
+            .. Default::default()
+        }
+    }
+
+    pub fn set_on_text_change(&mut self, data: RefAny, callback: TextEditOnTextChangeCallbackType) {
+        self.state.on_text_change = Some(TextEditOnTextChange {
+            callback: TextEditOnTextChangeCallback { cb: callback },
+            data
+        }).into();
+    }
+
+    pub fn set_on_focus_lost(&mut self, data: RefAny, callback: TextEditOnFocusLostCallbackType) {
+        self.state.on_focus_lost = Some(TextEditOnFocusLost {
+            callback: TextEditOnFocusLostCallback { cb: callback },
+            data
+        }).into();
+    }
+
+    pub fn set_show_line_numbers(&mut self, show_line_numbers: bool) {
+        self.state.inner.show_line_numbers = show_line_numbers;
+    }
+
+    pub fn set_container_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.container_style = style;
+    }
+
+    pub fn set_line_numbers_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.line_numbers_style = style;
+    }
+
+    pub fn set_label_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.label_style = style;
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::new(AzString::from_const_str(""));
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    pub fn dom(self) -> Dom {
+
+        use azul_desktop::dom::{
+            CallbackData, EventFilter,
+            HoverEventFilter, FocusEventFilter,
+            IdOrClass::Class, TabIndex,
+        };
+
+        let label_text = self.state.inner.get_text();
+        let line_numbers_text = self.state.inner.get_line_numbers_text();
+        let show_line_numbers = self.state.inner.show_line_numbers;
+
+        let state_ref = RefAny::new(self.state);
+
+        let text_area = Dom::div()
+        .with_ids_and_classes(vec![Class("__azul-native-text-edit-text-area".into())].into())
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_TEXT_AREA_PROPS))
+        .with_children(vec![
+            // the selection has to come first, so that it is drawn behind the text:
+            // first line of the selection, lines in between, last line of the selection
+            Dom::div()
+            .with_ids_and_classes(vec![Class("__azul-native-text-edit-selection".into())].into())
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_SELECTION_PROPS)),
+            Dom::div()
+            .with_ids_and_classes(vec![Class("__azul-native-text-edit-selection".into())].into())
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_SELECTION_PROPS)),
+            Dom::div()
+            .with_ids_and_classes(vec![Class("__azul-native-text-edit-selection".into())].into())
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_SELECTION_PROPS)),
+            Dom::div()
+            .with_ids_and_classes(vec![Class("__azul-native-text-edit-caret".into())].into())
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_CARET_PROPS)),
+            Dom::text(label_text)
+            .with_ids_and_classes(vec![Class("__azul-native-text-edit-label".into())].into())
+            .with_inline_css_props(self.label_style),
+        ].into());
+
+        let mut content = Vec::new();
+        if show_line_numbers {
+            content.push(
+                Dom::text(line_numbers_text)
+                .with_ids_and_classes(vec![Class("__azul-native-text-edit-line-numbers".into())].into())
+                .with_inline_css_props(self.line_numbers_style)
+            );
+        }
+        content.push(text_area);
+
+        Dom::div()
+        .with_ids_and_classes(vec![Class("__azul-native-text-edit-container".into())].into())
+        .with_inline_css_props(self.container_style)
+        .with_tab_index(TabIndex::Auto)
+        .with_dataset(Some(state_ref.clone()).into())
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::FocusLost),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_focus_lost }
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::TextInput),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_text_input }
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_virtual_key_down }
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_container_click }
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::MouseOver),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_drag }
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::Scroll),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_scroll }
+            },
+        ].into())
+        .with_children(vec![
+            Dom::div()
+            .with_ids_and_classes(vec![Class("__azul-native-text-edit-content".into())].into())
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_EDIT_CONTENT_PROPS))
+            .with_children(content.into())
+        ].into())
+    }
+}
+
+/// Converts the text into chars, "\r\n" and "\r" line endings are converted to "\n"
+fn normalize_text(s: &str) -> Vec<u32> {
+    s.replace("\r\n", "\n")
+    .replace('\r', "\n")
+    .chars()
+    .map(|c| c as u32)
+    .collect()
+}
+
+fn is_newline(c: u32) -> bool {
+    c == '\n' as u32
+}
+
+/// Pushes to / pops from the undo or redo stack
+fn modify_snapshots<T, F: FnOnce(&mut Vec<TextEditSnapshot>) -> T>(stack: &mut TextEditSnapshotVec, f: F) -> T {
+    let mut v = TextEditSnapshotVec::new();
+    mem::swap(&mut v, stack);
+    let mut v = v.into_library_owned_vec();
+    let result = f(&mut v);
+    *stack = v.into();
+    result
+}
+
+/// Characters of the same class form a word for Ctrl + Left / Right
+fn get_char_class(c: u32) -> u8 {
+    match core::char::from_u32(c) {
+        Some(c) if c.is_whitespace() => 0,
+        Some(c) if c.is_alphanumeric() || c == '_' => 1,
+        _ => 2,
+    }
+}
+
+impl TextEditState {
+
+    pub fn get_text(&self) -> String {
+        self.text
+        .iter()
+        .filter_map(|c| core::char::from_u32(*c))
+        .collect()
+    }
+
+    /// Returns the currently selected text or an empty string if nothing is selected
+    pub fn get_selected_text(&self) -> String {
+        match self.get_selection_range() {
+            Some(r) => self.text.as_ref()[r].iter().filter_map(|c| core::char::from_u32(*c)).collect(),
+            None => String::new(),
+        }
+    }
+
+    /// Returns the number of (unwrapped) lines
+    pub fn get_line_count(&self) -> usize {
+        self.text.iter().filter(|c| is_newline(**c)).count() + 1
+    }
+
+    /// Returns the (ordered) selected range, `None` if the selection is empty
+    fn get_selection_range(&self) -> Option<Range<usize>> {
+        let range = self.selection.as_ref()?.get_range(self.text.len());
+        if range.start == range.end { None } else { Some(range) }
+    }
+
+    /// Returns the index at which the current selection was started:
+    /// if nothing is selected, the selection starts at the cursor
+    fn get_selection_anchor(&self) -> usize {
+        match self.selection.as_ref() {
+            None => self.cursor_pos,
+            Some(TextInputSelection::All) => if self.cursor_pos == 0 { self.text.len() } else { 0 },
+            Some(TextInputSelection::FromTo(r)) => r.from.min(self.text.len()),
+        }
+    }
+
+    /// Moves the cursor to `new_pos`, if `extend_selection` is set, the text
+    /// between the selection anchor and the new cursor position gets selected
+    fn move_cursor(&mut self, new_pos: usize, extend_selection: bool) {
+        let new_pos = new_pos.min(self.text.len());
+        let anchor = self.get_selection_anchor();
+        self.cursor_pos = new_pos;
+        self.selection = if extend_selection && anchor != new_pos {
+            Some(TextInputSelection::FromTo(TextInputSelectionRange { from: anchor, to: new_pos })).into()
+        } else {
+            None.into()
+        };
+    }
+
+    /// Deletes the selected text (if any) and inserts the `new_text` at the cursor
+    fn replace_selection(&mut self, new_text: &[u32]) {
+
+        let mut text = self.text.as_ref().to_vec();
+
+        let insert_pos = match self.get_selection_range() {
+            Some(r) => { text.drain(r.clone()); r.start },
+            None => self.cursor_pos.min(text.len()),
+        };
+
+        text.splice(insert_pos..insert_pos, new_text.iter().copied());
+
+        self.text = text.into();
+        self.cursor_pos = insert_pos + new_text.len();
+        self.selection = None.into();
+    }
+
+    /// Returns the range of the (unwrapped) line containing `pos`, excluding the '\n'
+    fn get_line_range(&self, pos: usize) -> Range<usize> {
+        let text = self.text.as_ref();
+        let pos = pos.min(text.len());
+        let start = text[..pos].iter().rposition(|c| is_newline(*c)).map(|p| p + 1).unwrap_or(0);
+        let end = text[pos..].iter().position(|c| is_newline(*c)).map(|p| pos + p).unwrap_or(text.len());
+        start..end
+    }
+
+    /// Returns the cursor position in the same column of the previous / next line,
+    /// used for moving the cursor up / down if the text hasn't been laid out yet
+    fn get_cursor_pos_in_adjacent_line(&self, pos: usize, up: bool) -> usize {
+        let line = self.get_line_range(pos);
+        let column = pos.min(self.text.len()) - line.start;
+        if up {
+            if line.start == 0 { return 0; }
+            let previous_line = self.get_line_range(line.start - 1);
+            previous_line.start + column.min(previous_line.len())
+        } else {
+            if line.end >= self.text.len() { return self.text.len(); }
+            let next_line = self.get_line_range(line.end + 1);
+            next_line.start + column.min(next_line.len())
+        }
+    }
+
+    /// Returns the start of the word left of `pos` (Ctrl + Left)
+    fn get_previous_word_start(&self, pos: usize) -> usize {
+        let text = self.text.as_ref();
+        let mut pos = pos.min(text.len());
+        while pos > 0 && get_char_class(text[pos - 1]) == 0 {
+            pos -= 1;
+        }
+        if pos > 0 {
+            let class = get_char_class(text[pos - 1]);
+            while pos > 0 && get_char_class(text[pos - 1]) == class {
+                pos -= 1;
+            }
+        }
+        pos
+    }
+
+    /// Returns the end of the word right of `pos` (Ctrl + Right)
+    fn get_next_word_end(&self, pos: usize) -> usize {
+        let text = self.text.as_ref();
+        let mut pos = pos.min(text.len());
+        while pos < text.len() && get_char_class(text[pos]) == 0 {
+            pos += 1;
+        }
+        if pos < text.len() {
+            let class = get_char_class(text[pos]);
+            while pos < text.len() && get_char_class(text[pos]) == class {
+                pos += 1;
+            }
+        }
+        pos
+    }
+
+    /// Line numbers for the line number column, used until the text is laid out
+    /// (afterwards `TextEditLayout::get_line_numbers_text` accounts for wrapped lines)
+    fn get_line_numbers_text(&self) -> String {
+        (1..=self.get_line_count()).map(|i| i.to_string()).collect::<Vec<_>>().join("\n")
+    }
+}
+
+impl TextEditStateWrapper {
+
+    /// Replaces the selection with `new_text` and records the change in the undo history
+    fn edit(&mut self, kind: TextEditKind, new_text: &[u32]) {
+
+        let snapshot = self.get_snapshot();
+        self.inner.replace_selection(new_text);
+
+        if self.inner.text == snapshot.text {
+            return;
+        }
+
+        if kind == TextEditKind::Other || self.history.last_edit.into_option() != Some(kind) {
+            modify_snapshots(&mut self.history.undo_stack, |undo_stack| {
+                undo_stack.push(snapshot);
+                if undo_stack.len() > MAX_UNDO_STEPS {
+                    undo_stack.remove(0);
+                }
+            });
+        }
+
+        self.history.redo_stack = TextEditSnapshotVec::new();
+        self.history.last_edit = OptionTextEditKind::Some(kind);
+    }
+
+    fn undo(&mut self) -> bool {
+        let snapshot = match modify_snapshots(&mut self.history.undo_stack, |undo_stack| undo_stack.pop()) {
+            Some(s) => s,
+            None => return false,
+        };
+        let current = self.get_snapshot();
+        modify_snapshots(&mut self.history.redo_stack, |redo_stack| redo_stack.push(current));
+        self.restore_snapshot(snapshot);
+        true
+    }
+
+    fn redo(&mut self) -> bool {
+        let snapshot = match modify_snapshots(&mut self.history.redo_stack, |redo_stack| redo_stack.pop()) {
+            Some(s) => s,
+            None => return false,
+        };
+        let current = self.get_snapshot();
+        modify_snapshots(&mut self.history.undo_stack, |undo_stack| undo_stack.push(current));
+        self.restore_snapshot(snapshot);
+        true
+    }
+
+    fn get_snapshot(&self) -> TextEditSnapshot {
+        TextEditSnapshot {
+            text: self.inner.text.clone(),
+            cursor_pos: self.inner.cursor_pos,
+        }
+    }
+
+    fn restore_snapshot(&mut self, snapshot: TextEditSnapshot) {
+        self.inner.text = snapshot.text;
+        self.inner.cursor_pos = snapshot.cursor_pos.min(self.inner.text.len());
+        self.inner.selection = None.into();
+        self.history.last_edit = None.into();
+    }
+
+    /// Moves the cursor and ends the current group of edits in the undo history
+    fn move_cursor(&mut self, new_pos: usize, extend_selection: bool) {
+        self.inner.move_cursor(new_pos, extend_selection);
+        self.history.last_edit = None.into();
+    }
+
+    /// Replaces the selection with the pasted text: newlines and tabs
+    /// are kept, all other control characters are stripped
+    fn paste(&mut self, s: &str) {
+        let text = normalize_text(s)
+            .into_iter()
+            .filter(|c| is_newline(*c) || *c == '\t' as u32 || !core::char::from_u32(*c).map(|c| c.is_control()).unwrap_or(true))
+            .collect::<Vec<_>>();
+        self.edit(TextEditKind::Other, &text);
+    }
+
+    fn handle_on_text_input(&mut self, c: char) {
+        // control characters (return, tab, Ctrl + A on Windows, ...) are handled in the VirtualKeyDown callback
+        if c.is_control() {
+            return;
+        }
+        self.edit(TextEditKind::Typing, &[c as u32]);
+    }
+
+    /// Moves the cursor one line (or one page) up or down, keeping the x-position of the caret
+    fn move_cursor_vertically(&mut self, layout: Option<&TextEditLayout>, up: bool, page: bool, extend_selection: bool) {
+
+        let new_pos = match layout {
+            Some(layout) => {
+                let caret = layout.get_caret(self.inner.cursor_pos);
+                let x = self.view.preferred_caret_x.into_option().unwrap_or(caret.origin.x);
+                self.view.preferred_caret_x = OptionF32::Some(x);
+                let distance = if page { layout.page_height.max(layout.row_height) } else { layout.row_height };
+                let direction = if up { -1.0 } else { 1.0 };
+                let y = caret.origin.y + caret.size.height / 2.0 + direction * distance;
+                layout.get_cursor_pos_at(LogicalPosition::new(x, y))
+            },
+            None if page => if up { 0 } else { self.inner.text.len() },
+            None => self.inner.get_cursor_pos_in_adjacent_line(self.inner.cursor_pos, up),
+        };
+
+        // keep the preferred x-position for the next up / down movement
+        let preferred_caret_x = self.view.preferred_caret_x;
+        self.move_cursor(new_pos, extend_selection);
+        self.view.preferred_caret_x = preferred_caret_x;
+    }
+
+    fn handle_on_virtual_key_down(
+        &mut self,
+        virtual_key: VirtualKeyCode,
+        keyboard_state: &KeyboardState,
+        layout: Option<&TextEditLayout>,
+    ) {
+        use azul_desktop::app::Clipboard;
+
+        let shift = keyboard_state.shift_down;
+        let ctrl = keyboard_state.ctrl_down || (cfg!(target_os = "macos") && keyboard_state.super_down);
+
+        // only vertical movement keeps the x-position of the caret
+        match virtual_key {
+            VirtualKeyCode::Up | VirtualKeyCode::Down | VirtualKeyCode::PageUp | VirtualKeyCode::PageDown => { },
+            _ => { self.view.preferred_caret_x = None.into(); },
+        }
+
+        match virtual_key {
+            VirtualKeyCode::Back => {
+                if self.inner.get_selection_range().is_none() && self.inner.cursor_pos > 0 {
+                    let new_pos = if ctrl { self.inner.get_previous_word_start(self.inner.cursor_pos) } else { self.inner.cursor_pos - 1 };
+                    self.inner.move_cursor(new_pos, true);
+                }
+                self.edit(TextEditKind::Deleting, &[]);
+            },
+            VirtualKeyCode::Delete => {
+                if self.inner.get_selection_range().is_none() && self.inner.cursor_pos < self.inner.text.len() {
+                    let new_pos = if ctrl { self.inner.get_next_word_end(self.inner.cursor_pos) } else { self.inner.cursor_pos + 1 };
+                    self.inner.move_cursor(new_pos, true);
+                }
+                self.edit(TextEditKind::Deleting, &[]);
+            },
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                self.edit(TextEditKind::Other, &['\n' as u32]);
+            },
+            VirtualKeyCode::Home => {
+                let new_pos = match layout {
+                    _ if ctrl => 0,
+                    Some(layout) => layout.get_row_range(self.inner.cursor_pos).start,
+                    None => self.inner.get_line_range(self.inner.cursor_pos).start,
+                };
+                self.move_cursor(new_pos, shift);
+            },
+            VirtualKeyCode::End => {
+                let new_pos = match layout {
+                    _ if ctrl => self.inner.text.len(),
+                    Some(layout) => layout.get_row_range(self.inner.cursor_pos).end,
+                    None => self.inner.get_line_range(self.inner.cursor_pos).end,
+                };
+                self.move_cursor(new_pos, shift);
+            },
+            VirtualKeyCode::Up => self.move_cursor_vertically(layout, true, false, shift),
+            VirtualKeyCode::Down => self.move_cursor_vertically(layout, false, false, shift),
+            VirtualKeyCode::PageUp => self.move_cursor_vertically(layout, true, true, shift),
+            VirtualKeyCode::PageDown => self.move_cursor_vertically(layout, false, true, shift),
+            VirtualKeyCode::Escape => {
+                self.inner.selection = None.into();
+            },
+            VirtualKeyCode::Right => {
+                match self.inner.get_selection_range() {
+                    Some(r) if !shift && !ctrl => self.move_cursor(r.end, false),
+                    _ if ctrl => self.move_cursor(self.inner.get_next_word_end(self.inner.cursor_pos), shift),
+                    _ => self.move_cursor(self.inner.cursor_pos.saturating_add(1), shift),
+                }
+            },
+            VirtualKeyCode::Left => {
+                match self.inner.get_selection_range() {
+                    Some(r) if !shift && !ctrl => self.move_cursor(r.start, false),
+                    _ if ctrl => self.move_cursor(self.inner.get_previous_word_start(self.inner.cursor_pos), shift),
+                    _ => self.move_cursor(self.inner.cursor_pos.saturating_sub(1), shift),
+                }
+            },
+            // ctrl + a
+            VirtualKeyCode::A if ctrl => {
+                self.inner.cursor_pos = self.inner.text.len();
+                self.inner.selection = Some(TextInputSelection::All).into();
+            },
+            // ctrl + c
+            VirtualKeyCode::C if ctrl => {
+                if self.inner.get_selection_range().is_some() {
+                    if let Some(mut clipboard) = Clipboard::new() {
+                        let _ = clipboard.set_clipboard_string(self.inner.get_selected_text().into());
+                    }
+                }
+            },
+            // ctrl + x
+            VirtualKeyCode::X if ctrl => {
+                if self.inner.get_selection_range().is_some() {
+                    if let Some(mut clipboard) = Clipboard::new() {
+                        if clipboard.set_clipboard_string(self.inner.get_selected_text().into()).is_some() {
+                            self.edit(TextEditKind::Other, &[]);
+                        }
+                    }
+                }
+            },
+            // ctrl + v
+            VirtualKeyCode::V if ctrl => {
+                if let Some(clipboard_contents) = Clipboard::new().and_then(|c| c.get_clipboard_string()) {
+                    self.paste(clipboard_contents.as_str());
+                }
+            },
+            // ctrl + z, ctrl + shift + z
+            VirtualKeyCode::Z if ctrl => {
+                if shift { self.redo(); } else { self.undo(); }
+            },
+            // ctrl + y
+            VirtualKeyCode::Y if ctrl => {
+                self.redo();
+            },
+            _ => { },
+        }
+    }
+}
+
+/// Returns whether two carets are on the same (visual) row
+fn is_same_row(a: f32, b: f32) -> bool {
+    (a - b).abs() < 0.5
+}
+
+/// Positions of the caret for the laid out text of the text edit
+#[derive(Debug, Clone, PartialEq)]
+struct TextEditLayout {
+    /// Caret rectangle (zero width, relative to the label) for every cursor position in `0..=text.len()`
+    carets: Vec<LogicalRect>,
+    /// Height of a single row of text
+    row_height: f32,
+    /// Height of the visible part of the text edit (for PageUp / PageDown)
+    page_height: f32,
+}
+
+impl TextEditLayout {
+
+    /// NOTE: The `InlineText` only stores the glyphs of non-whitespace characters,
+    /// so whitespace is positioned relative to the previous glyph and newlines
+    /// move the caret one row down.
+    fn new(text: &[u32], inline_text: &InlineText, page_height: f32) -> Self {
+
+        let mut glyphs = vec![None; text.len()];
+        for (bounds, text_index) in get_glyph_char_bounds(inline_text).iter().zip(get_glyph_char_text_indices(text).iter()) {
+            glyphs[*text_index] = Some(*bounds);
+        }
+
+        let first_line = inline_text.lines.as_ref().first();
+        let row_height = first_line.map(|l| l.bounds.size.height).unwrap_or(inline_text.font_size_px);
+        let space_width = inline_text.font_size_px / 4.0;
+
+        // line.bounds.origin is the bottom left corner of the line
+        let mut top = first_line.map(|l| l.bounds.origin.y - l.bounds.size.height).unwrap_or(0.0);
+        let mut x = 0.0;
+        let mut carets = Vec::with_capacity(text.len() + 1);
+
+        for (c, glyph) in text.iter().zip(glyphs.iter()) {
+
+            // glyphs are at their actual position, even if the line was wrapped
+            if let Some(g) = glyph {
+                x = g.origin.x;
+                top = g.origin.y;
+            }
+
+            carets.push(LogicalRect::new(LogicalPosition::new(x, top), LogicalSize::new(0.0, row_height)));
+
+            match glyph {
+                Some(g) => { x = g.origin.x + g.size.width; },
+                None if is_newline(*c) => { x = 0.0; top += row_height; },
+                None if *c == '\t' as u32 => { x += space_width * 4.0; },
+                None => { x += space_width; },
+            }
+        }
+
+        carets.push(LogicalRect::new(LogicalPosition::new(x, top), LogicalSize::new(0.0, row_height)));
+
+        Self { carets, row_height, page_height }
+    }
+
+    fn get_caret(&self, pos: usize) -> LogicalRect {
+        self.carets[pos.min(self.carets.len() - 1)]
+    }
+
+    /// Returns the cursor position closest to `position` (relative to the label)
+    fn get_cursor_pos_at(&self, position: LogicalPosition) -> usize {
+
+        let first = self.carets[0];
+        let last = self.carets[self.carets.len() - 1];
+
+        if position.y < first.origin.y {
+            return 0;
+        } else if position.y >= last.origin.y + last.size.height {
+            return self.carets.len() - 1;
+        }
+
+        let distance = |r: &LogicalRect| {
+            let dy = if position.y < r.origin.y {
+                r.origin.y - position.y
+            } else if position.y > r.origin.y + r.size.height {
+                position.y - r.origin.y - r.size.height
+            } else {
+                0.0
+            };
+            (dy, (r.origin.x - position.x).abs())
+        };
+
+        self.carets
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| distance(a).partial_cmp(&distance(b)).unwrap_or(core::cmp::Ordering::Equal))
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+    }
+
+    /// Returns the first and last cursor position on the row of `pos` (Home / End)
+    fn get_row_range(&self, pos: usize) -> Range<usize> {
+        let pos = pos.min(self.carets.len() - 1);
+        let top = self.carets[pos].origin.y;
+        let mut start = pos;
+        while start > 0 && is_same_row(self.carets[start - 1].origin.y, top) {
+            start -= 1;
+        }
+        let mut end = pos;
+        while end + 1 < self.carets.len() && is_same_row(self.carets[end + 1].origin.y, top) {
+            end += 1;
+        }
+        start..end
+    }
+
+    /// Returns the rectangles of the selection highlight: the selected part of the first row,
+    /// the rows in between and the selected part of the last row (relative to the label)
+    fn get_selection_rects(&self, selection: Range<usize>, width: f32) -> [LogicalRect; 3] {
+
+        let mut rects = [LogicalRect::zero(); 3];
+
+        if selection.start >= selection.end {
+            return rects;
+        }
+
+        let start = self.get_caret(selection.start);
+        let end = self.get_caret(selection.end);
+
+        if is_same_row(start.origin.y, end.origin.y) {
+            rects[0] = LogicalRect::new(start.origin, LogicalSize::new(end.origin.x - start.origin.x, start.size.height));
+        } else {
+            let first_row_bottom = start.origin.y + start.size.height;
+            rects[0] = LogicalRect::new(start.origin, LogicalSize::new((width - start.origin.x).max(0.0), start.size.height));
+            rects[1] = LogicalRect::new(
+                LogicalPosition::new(0.0, first_row_bottom),
+                LogicalSize::new(width, (end.origin.y - first_row_bottom).max(0.0)),
+            );
+            rects[2] = LogicalRect::new(LogicalPosition::new(0.0, end.origin.y), LogicalSize::new(end.origin.x, end.size.height));
+        }
+
+        rects
+    }
+
+    /// Line numbers, one row per visual row, so that the numbers of
+    /// lines following a wrapped line stay next to their line
+    fn get_line_numbers_text(&self, text: &[u32]) -> String {
+
+        let mut rows: Vec<(f32, Option<usize>)> = Vec::new();
+        for caret in self.carets.iter() {
+            if rows.last().map(|(top, _)| !is_same_row(*top, caret.origin.y)).unwrap_or(true) {
+                rows.push((caret.origin.y, None));
+            }
+        }
+
+        let line_starts = core::iter::once(0).chain(
+            text.iter().enumerate().filter(|(_, c)| is_newline(**c)).map(|(i, _)| i + 1)
+        );
+
+        for (line_index, line_start) in line_starts.enumerate() {
+            let top = self.get_caret(line_start).origin.y;
+            if let Some(row) = rows.iter_mut().find(|(row_top, _)| is_same_row(*row_top, top)) {
+                row.1.get_or_insert(line_index + 1);
+            }
+        }
+
+        rows.iter()
+        .map(|(_, line_number)| line_number.map(|n| n.to_string()).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n")
+    }
+}
+
+// Returns the scroll offset that makes the item visible, scrolling as little as possible
+fn scroll_into_view(scroll_offset: f32, item_start: f32, item_size: f32, viewport_size: f32) -> f32 {
+    if item_start < scroll_offset {
+        item_start
+    } else if item_start + item_size > scroll_offset + viewport_size {
+        item_start + item_size - viewport_size
+    } else {
+        scroll_offset
+    }
+}
+
+// handle input events for the TextEdit
+mod input {
+
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, DomNodeId};
+    use azul_desktop::css::*;
+    use azul_core::window::{LogicalPosition, LogicalRect};
+    use super::{
+        TextEditState, TextEditStateWrapper, TextEditLayout, TextEditView,
+        TextEditOnTextChange, TextEditOnFocusLost,
+    };
+
+    /// Nodes of the text edit, see `TextEdit::dom()`
+    struct TextEditNodes {
+        container: DomNodeId,
+        content: DomNodeId,
+        line_numbers: Option<DomNodeId>,
+        text_area: DomNodeId,
+        selection: [DomNodeId; 3],
+        caret: DomNodeId,
+        label: DomNodeId,
+    }
+
+    impl TextEditNodes {
+        fn new(text_edit: &TextEditState, info: &CallbackInfo) -> Option<Self> {
+            let container = info.get_hit_node();
+            let content = info.get_first_child(container)?;
+            let (line_numbers, text_area) = if text_edit.show_line_numbers {
+                let line_numbers = info.get_first_child(content)?;
+                (Some(line_numbers), info.get_next_sibling(line_numbers)?)
+            } else {
+                (None, info.get_first_child(content)?)
+            };
+            let selection_1 = info.get_first_child(text_area)?;
+            let selection_2 = info.get_next_sibling(selection_1)?;
+            let selection_3 = info.get_next_sibling(selection_2)?;
+            let caret = info.get_next_sibling(selection_3)?;
+            let label = info.get_next_sibling(caret)?;
+            Some(Self {
+                container,
+                content,
+                line_numbers,
+                text_area,
+                selection: [selection_1, selection_2, selection_3],
+                caret,
+                label,
+            })
+        }
+    }
+
+    pub(in super) extern "C" fn default_on_text_input(text_edit: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut text_edit = match text_edit.downcast_mut::<TextEditStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+
+        let c = match keyboard_state.current_char.into_option().and_then(core::char::from_u32) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let text_before = text_edit.inner.text.clone();
+        text_edit.handle_on_text_input(c);
+        let text_changed = text_edit.inner.text != text_before;
+        update_text_edit_display(&mut *text_edit, text_changed, &mut info);
+
+        if text_changed { call_on_text_change(&mut *text_edit, &mut info) } else { Update::DoNothing }
+    }
+
+    pub(in super) extern "C" fn default_on_virtual_key_down(text_edit: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut text_edit = match text_edit.downcast_mut::<TextEditStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let last_keycode = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // layout of the text before the key was pressed, for moving the cursor up / down
+        let layout = TextEditNodes::new(&text_edit.inner, &info)
+            .and_then(|nodes| get_text_edit_layout(&text_edit.inner, &nodes, false, &info));
+
+        let text_before = text_edit.inner.text.clone();
+        text_edit.handle_on_virtual_key_down(last_keycode, &keyboard_state, layout.as_ref());
+        let text_changed = text_edit.inner.text != text_before;
+        update_text_edit_display(&mut *text_edit, text_changed, &mut info);
+
+        if text_changed { call_on_text_change(&mut *text_edit, &mut info) } else { Update::DoNothing }
+    }
+
+    pub(in super) extern "C" fn default_on_container_click(text_edit: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut text_edit = match text_edit.downcast_mut::<TextEditStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let cursor_pos = match get_hit_cursor_pos(&text_edit.inner, &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // shift + click extends the current selection
+        let extend_selection = info.get_current_keyboard_state().shift_down;
+        text_edit.view.preferred_caret_x = None.into();
+        text_edit.move_cursor(cursor_pos, extend_selection);
+        update_text_edit_display(&mut *text_edit, false, &mut info);
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_drag(text_edit: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut text_edit = match text_edit.downcast_mut::<TextEditStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // only a drag if the mouse button was already down before
+        // (the initial mouse down is handled by default_on_container_click)
+        let is_dragging = info.get_current_mouse_state().left_down &&
+            info.get_previous_mouse_state().map(|m| m.left_down).unwrap_or(false);
+
+        if !is_dragging {
+            return Update::DoNothing;
+        }
+
+        let cursor_pos = match get_hit_cursor_pos(&text_edit.inner, &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if cursor_pos == text_edit.inner.cursor_pos {
+            return Update::DoNothing;
+        }
+
+        text_edit.move_cursor(cursor_pos, true);
+        update_text_edit_display(&mut *text_edit, false, &mut info);
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_scroll(text_edit: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut text_edit = match text_edit.downcast_mut::<TextEditStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let nodes = match TextEditNodes::new(&text_edit.inner, &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let (viewport_height, content_height) = match (info.get_node_size(nodes.container), info.get_node_size(nodes.content)) {
+            (Some(v), Some(c)) => (v.height, c.height),
+            _ => return Update::DoNothing,
+        };
+
+        let scroll_y = info.get_current_mouse_state().get_scroll_y();
+        let max_scroll_y = (content_height - viewport_height).max(0.0);
+        let new_scroll_y = (text_edit.view.scroll_y + scroll_y).max(0.0).min(max_scroll_y);
+
+        if new_scroll_y != text_edit.view.scroll_y {
+            text_edit.view.scroll_y = new_scroll_y;
+            info.set_css_property(nodes.content, CssProperty::const_top(LayoutTop::px(-new_scroll_y)));
+        }
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_focus_lost(text_edit: &mut RefAny, mut info: CallbackInfo) -> Update {
+
+        let mut text_edit = match text_edit.downcast_mut::<TextEditStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // hide the caret
+        if let Some(nodes) = TextEditNodes::new(&text_edit.inner, &info) {
+            info.set_css_property(nodes.caret, CssProperty::const_width(LayoutWidth::px(0.0)));
+        }
+
+        let text_edit = &mut *text_edit;
+        let inner = &text_edit.inner;
+
+        match text_edit.on_focus_lost.as_mut() {
+            Some(TextEditOnFocusLost { callback, data }) => (callback.cb)(data, &inner, &mut info),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn call_on_text_change(text_edit: &mut TextEditStateWrapper, info: &mut CallbackInfo) -> Update {
+        let inner = &text_edit.inner;
+        match text_edit.on_text_change.as_mut() {
+            Some(TextEditOnTextChange { callback, data }) => (callback.cb)(data, &inner, info),
+            None => Update::DoNothing,
+        }
+    }
+
+    /// Returns the offset of `node_id` relative to the top left corner of `parent_id`
+    fn get_relative_offset(parent_id: DomNodeId, node_id: DomNodeId, info: &CallbackInfo) -> Option<LogicalPosition> {
+        let parent_position = info.get_node_position(parent_id)?.get_static_offset();
+        let node_position = info.get_node_position(node_id)?.get_static_offset();
+        Some(node_position - parent_position)
+    }
+
+    /// Returns the caret positions of the label. If the text changed in this callback,
+    /// the layout of the label is out of date, so the new text has to be shaped again
+    fn get_text_edit_layout(
+        text_edit: &TextEditState,
+        nodes: &TextEditNodes,
+        text_changed: bool,
+        info: &CallbackInfo
+    ) -> Option<TextEditLayout> {
+        use azul_desktop::text_layout::callback_info_shape_text;
+        let inline_text = if text_changed {
+            callback_info_shape_text(info, nodes.label, text_edit.get_text().into())?
+        } else {
+            info.get_inline_text(nodes.label)?
+        };
+        let page_height = info.get_node_size(nodes.container)?.height;
+        Some(TextEditLayout::new(text_edit.text.as_ref(), &inline_text, page_height))
+    }
+
+    /// Returns the cursor position for the current mouse position
+    fn get_hit_cursor_pos(text_edit: &TextEditState, info: &CallbackInfo) -> Option<usize> {
+        let nodes = TextEditNodes::new(text_edit, info)?;
+        let layout = get_text_edit_layout(text_edit, &nodes, false, info)?;
+        let cursor_relative_to_container = info.get_cursor_relative_to_node().into_option()?;
+        let cursor_relative_to_label = cursor_relative_to_container - get_relative_offset(nodes.container, nodes.label, info)?;
+        Some(layout.get_cursor_pos_at(cursor_relative_to_label))
+    }
+
+    fn set_node_rect(node_id: DomNodeId, rect: LogicalRect, info: &mut CallbackInfo) {
+        info.set_css_property(node_id, CssProperty::const_left(LayoutLeft::px(rect.origin.x)));
+        info.set_css_property(node_id, CssProperty::const_top(LayoutTop::px(rect.origin.y)));
+        info.set_css_property(node_id, CssProperty::const_width(LayoutWidth::px(rect.size.width)));
+        info.set_css_property(node_id, CssProperty::const_height(LayoutHeight::px(rect.size.height)));
+    }
+
+    /// Updates the label text and line numbers, moves the caret and the
+    /// selection highlight and scrolls the content so that the caret is visible
+    fn update_text_edit_display(text_edit: &mut TextEditStateWrapper, text_changed: bool, info: &mut CallbackInfo) {
+
+        let nodes = match TextEditNodes::new(&text_edit.inner, info) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let layout = get_text_edit_layout(&text_edit.inner, &nodes, text_changed, info);
+
+        if text_changed {
+            info.set_string_contents(nodes.label, text_edit.inner.get_text().into());
+            if let Some(line_numbers) = nodes.line_numbers {
+                let line_numbers_text = match layout.as_ref() {
+                    Some(layout) => layout.get_line_numbers_text(text_edit.inner.text.as_ref()),
+                    None => text_edit.inner.get_line_numbers_text(),
+                };
+                info.set_string_contents(line_numbers, line_numbers_text.into());
+            }
+        }
+
+        let layout = match layout {
+            Some(s) => s,
+            None => return,
+        };
+
+        let label_offset = match get_relative_offset(nodes.text_area, nodes.label, info) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let text_area_width = info.get_node_size(nodes.text_area).map(|s| s.width).unwrap_or(0.0);
+        let selection = text_edit.inner.get_selection_range().unwrap_or(0..0);
+        let selection_rects = layout.get_selection_rects(selection, text_area_width);
+
+        for (node_id, mut rect) in nodes.selection.iter().zip(selection_rects.iter().copied()) {
+            if rect.size.width > 0.0 && rect.size.height > 0.0 {
+                rect.origin += label_offset;
+            }
+            set_node_rect(*node_id, rect, info);
+        }
+
+        let mut caret = layout.get_caret(text_edit.inner.cursor_pos);
+        caret.origin += label_offset;
+        caret.size.width = 1.0;
+        set_node_rect(nodes.caret, caret, info);
+
+        update_text_edit_scroll(&mut text_edit.view, &nodes, caret, info);
+    }
+
+    /// Scrolls the content so that the caret is visible
+    fn update_text_edit_scroll(view: &mut TextEditView, nodes: &TextEditNodes, caret: LogicalRect, info: &mut CallbackInfo) {
+
+        let viewport_height = match info.get_node_size(nodes.container) {
+            Some(s) => s.height,
+            None => return,
+        };
+
+        let caret_top_in_content = match get_relative_offset(nodes.content, nodes.text_area, info) {
+            Some(s) => s.y + caret.origin.y,
+            None => return,
+        };
+
+        let new_scroll_y = super::scroll_into_view(view.scroll_y, caret_top_in_content, caret.size.height, viewport_height).max(0.0);
+
+        if new_scroll_y != view.scroll_y {
+            view.scroll_y = new_scroll_y;
+            info.set_css_property(nodes.content, CssProperty::const_top(LayoutTop::px(-new_scroll_y)));
+        }
+    }
+}
+
+impl From<TextEdit> for Dom {
+    fn from(t: TextEdit) -> Dom {
+        t.dom()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_text_edit_history() {
+
+        let mut text_edit = TextEditStateWrapper::default();
+
+        for c in "hello world".chars() {
+            text_edit.handle_on_text_input(c);
+        }
+
+        assert_eq!(text_edit.inner.get_previous_word_start(11), 6);
+        assert_eq!(text_edit.inner.get_next_word_end(0), 5);
+
+        text_edit.edit(TextEditKind::Other, &['\n' as u32]);
+        text_edit.handle_on_text_input('!');
+        assert_eq!(text_edit.inner.get_text(), "hello world\n!");
+        assert_eq!(text_edit.inner.get_line_count(), 2);
+        assert_eq!(text_edit.inner.get_line_range(13), 12..13);
+        assert_eq!(text_edit.inner.get_cursor_pos_in_adjacent_line(13, true), 1);
+
+        // typing is undone word by word, not character by character
+        assert!(text_edit.undo());
+        assert_eq!(text_edit.inner.get_text(), "hello world\n");
+        assert!(text_edit.undo());
+        assert_eq!(text_edit.inner.get_text(), "hello world");
+        assert!(text_edit.undo());
+        assert_eq!(text_edit.inner.get_text(), "");
+        assert!(!text_edit.undo());
+
+        assert!(text_edit.redo());
+        assert_eq!(text_edit.inner.get_text(), "hello world");
+        assert_eq!(text_edit.inner.cursor_pos, 11);
+    }
+
+    fn text_edit_with_text(s: &str) -> TextEditStateWrapper {
+        let mut text_edit = TextEditStateWrapper::default();
+        text_edit.paste(s);
+        text_edit
+    }
+
+    fn press(text_edit: &mut TextEditStateWrapper, virtual_key: VirtualKeyCode, shift: bool, ctrl: bool) {
+        let keyboard_state = KeyboardState { shift_down: shift, ctrl_down: ctrl, .. Default::default() };
+        text_edit.handle_on_virtual_key_down(virtual_key, &keyboard_state, None);
+    }
+
+    #[test]
+    fn test_text_edit_caret_movement() {
+
+        let mut text_edit = text_edit_with_text("hello world\nfoo\nbar baz");
+        assert_eq!(text_edit.inner.cursor_pos, 23);
+
+        // Ctrl + Home / Ctrl + End jump to the start / end of the text
+        press(&mut text_edit, VirtualKeyCode::Home, false, true);
+        assert_eq!(text_edit.inner.cursor_pos, 0);
+        press(&mut text_edit, VirtualKeyCode::End, false, true);
+        assert_eq!(text_edit.inner.cursor_pos, 23);
+
+        // Home / End stay on the current line
+        press(&mut text_edit, VirtualKeyCode::Home, false, false);
+        assert_eq!(text_edit.inner.cursor_pos, 16);
+        press(&mut text_edit, VirtualKeyCode::Right, false, true);
+        assert_eq!(text_edit.inner.cursor_pos, 19);
+        press(&mut text_edit, VirtualKeyCode::Right, false, true);
+        assert_eq!(text_edit.inner.cursor_pos, 23);
+        press(&mut text_edit, VirtualKeyCode::Left, false, true);
+        assert_eq!(text_edit.inner.cursor_pos, 20);
+
+        // up / down keep the column, clamped to the length of the line
+        press(&mut text_edit, VirtualKeyCode::Up, false, false);
+        assert_eq!(text_edit.inner.cursor_pos, 15);
+        press(&mut text_edit, VirtualKeyCode::Up, false, false);
+        assert_eq!(text_edit.inner.cursor_pos, 3);
+        press(&mut text_edit, VirtualKeyCode::Up, false, false);
+        assert_eq!(text_edit.inner.cursor_pos, 0);
+        press(&mut text_edit, VirtualKeyCode::Down, false, false);
+        assert_eq!(text_edit.inner.cursor_pos, 12);
+
+        // left / right don't move past the start or end of the text
+        press(&mut text_edit, VirtualKeyCode::Home, false, true);
+        press(&mut text_edit, VirtualKeyCode::Left, false, false);
+        assert_eq!(text_edit.inner.cursor_pos, 0);
+        press(&mut text_edit, VirtualKeyCode::End, false, true);
+        press(&mut text_edit, VirtualKeyCode::Right, false, false);
+        assert_eq!(text_edit.inner.cursor_pos, 23);
+    }
+
+    #[test]
+    fn test_text_edit_selection() {
+
+        let mut text_edit = text_edit_with_text("hello world\nfoo");
+
+        // Shift + Up selects from the anchor to the same column in the previous line
+        press(&mut text_edit, VirtualKeyCode::Up, true, false);
+        assert_eq!(text_edit.inner.cursor_pos, 3);
+        assert_eq!(text_edit.inner.get_selected_text(), "lo world\nfoo");
+
+        // Left without Shift collapses the selection to its start
+        press(&mut text_edit, VirtualKeyCode::Left, false, false);
+        assert_eq!(text_edit.inner.cursor_pos, 3);
+        assert_eq!(text_edit.inner.selection, None.into());
+
+        // Ctrl + Shift + Right selects to the end of the word
+        press(&mut text_edit, VirtualKeyCode::Right, true, true);
+        assert_eq!(text_edit.inner.get_selected_text(), "lo");
+
+        // typing replaces the selection
+        text_edit.handle_on_text_input('p');
+        assert_eq!(text_edit.inner.get_text(), "help world\nfoo");
+        assert_eq!(text_edit.inner.cursor_pos, 4);
+
+        // Ctrl + A selects everything, Escape clears the selection
+        press(&mut text_edit, VirtualKeyCode::A, false, true);
+        assert_eq!(text_edit.inner.get_selected_text(), "help world\nfoo");
+        press(&mut text_edit, VirtualKeyCode::Escape, false, false);
+        assert_eq!(text_edit.inner.get_selected_text(), "");
+
+        // Backspace / Delete remove the selection or a single character
+        press(&mut text_edit, VirtualKeyCode::Home, false, true);
+        press(&mut text_edit, VirtualKeyCode::End, true, false);
+        press(&mut text_edit, VirtualKeyCode::Back, false, false);
+        assert_eq!(text_edit.inner.get_text(), "\nfoo");
+        press(&mut text_edit, VirtualKeyCode::Delete, false, false);
+        assert_eq!(text_edit.inner.get_text(), "foo");
+        assert_eq!(text_edit.inner.cursor_pos, 0);
+    }
+
+    #[test]
+    fn test_text_edit_undo_redo() {
+
+        let mut text_edit = text_edit_with_text("hello world");
+
+        // consecutive deletions are undone in one step
+        press(&mut text_edit, VirtualKeyCode::Back, false, false);
+        press(&mut text_edit, VirtualKeyCode::Back, false, false);
+        assert_eq!(text_edit.inner.get_text(), "hello wor");
+        press(&mut text_edit, VirtualKeyCode::Z, false, true);
+        assert_eq!(text_edit.inner.get_text(), "hello world");
+
+        // Ctrl + Y and Ctrl + Shift + Z redo
+        press(&mut text_edit, VirtualKeyCode::Y, false, true);
+        assert_eq!(text_edit.inner.get_text(), "hello wor");
+        press(&mut text_edit, VirtualKeyCode::Z, false, true);
+        press(&mut text_edit, VirtualKeyCode::Z, true, true);
+        assert_eq!(text_edit.inner.get_text(), "hello wor");
+
+        // moving the cursor starts a new undo step
+        text_edit.handle_on_text_input('l');
+        press(&mut text_edit, VirtualKeyCode::Home, false, false);
+        text_edit.handle_on_text_input('>');
+        assert_eq!(text_edit.inner.get_text(), ">hello worl");
+        assert!(text_edit.undo());
+        assert_eq!(text_edit.inner.get_text(), "hello worl");
+
+        // a new edit clears the redo stack
+        text_edit.handle_on_text_input('!');
+        assert!(!text_edit.redo());
+
+        // undo of the initial paste restores the empty text
+        while text_edit.undo() { }
+        assert_eq!(text_edit.inner.get_text(), "");
+    }
+
+    #[test]
+    fn test_text_edit_clipboard() {
+
+        // pasting normalizes line endings and strips control characters except tabs
+        let mut text_edit = text_edit_with_text("a\r\nb\rc\td\u{7}e");
+        assert_eq!(text_edit.inner.get_text(), "a\nb\nc\tde");
+        assert_eq!(text_edit.inner.cursor_pos, 8);
+
+        // copy: the selected text spans multiple lines
+        press(&mut text_edit, VirtualKeyCode::Home, false, true);
+        press(&mut text_edit, VirtualKeyCode::Down, true, false);
+        assert_eq!(text_edit.inner.get_selected_text(), "a\n");
+
+        // paste replaces the selection and is undone in one step
+        text_edit.paste("x\ny\n");
+        assert_eq!(text_edit.inner.get_text(), "x\ny\nb\nc\tde");
+        assert_eq!(text_edit.inner.cursor_pos, 4);
+        assert!(text_edit.undo());
+        assert_eq!(text_edit.inner.get_text(), "a\nb\nc\tde");
+
+        // pasting an empty string doesn't create an undo step
+        let mut text_edit = TextEditStateWrapper::default();
+        text_edit.paste("");
+        assert!(!text_edit.undo());
+    }
+}
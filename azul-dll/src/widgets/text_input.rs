@@ -9,9 +9,9 @@ use azul_desktop::{
         Dom, NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
         NodeDataInlineCssProperty::{Normal, Hover, Focus}
     },
-    callbacks::{RefAny, Callback, CallbackInfo, Update},
+    callbacks::{RefAny, Callback, CallbackInfo, Update, InlineText},
 };
-use azul_core::window::{KeyboardState, VirtualKeyCode};
+use azul_core::window::{KeyboardState, VirtualKeyCode, LogicalRect, LogicalPosition, LogicalSize};
 use std::vec::Vec;
 use std::string::String;
 
@@ -74,6 +74,9 @@ pub struct TextInputStateWrapper {
     pub on_focus_lost: OptionTextInputOnFocusLost,
     pub update_text_input_before_calling_focus_lost_fn: bool,
     pub update_text_input_before_calling_vk_down_fn: bool,
+    // whether the selection is currently extended by dragging the mouse,
+    // only accessed from the default callbacks
+    is_dragging: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -122,6 +125,7 @@ const SANS_SERIF_FAMILY: StyleFontFamilyVec = StyleFontFamilyVec::from_const_sli
 static TEXT_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
 
     Normal(CssProperty::const_cursor(StyleCursor::Text)),
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
     Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
     Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(200))),
     Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
@@ -171,6 +175,7 @@ static TEXT_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
 static TEXT_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
 
     Normal(CssProperty::const_cursor(StyleCursor::Text)),
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
     Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
     Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
     Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(200))),
@@ -226,6 +231,7 @@ static TEXT_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
 static TEXT_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
 
     Normal(CssProperty::const_cursor(StyleCursor::Text)),
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
     Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
     Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(200))),
     Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
@@ -295,6 +301,22 @@ static TEXT_INPUT_LABEL_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
 ];
 
+// -- selection style
+
+const COLOR_B3D7FF: ColorU = ColorU { r: 179, g: 215, b: 255, a: 255 }; // #b3d7ff
+const SELECTION_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_B3D7FF)];
+
+// positioned absolutely behind the label, moved + resized
+// via set_css_property whenever the selection changes
+static TEXT_INPUT_SELECTION_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SELECTION_BACKGROUND))),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(0))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(0))),
+];
+
 impl Default for TextInput {
     fn default() -> Self {
         TextInput {
@@ -327,6 +349,7 @@ impl Default for TextInputStateWrapper {
             on_focus_lost: None.into(),
             update_text_input_before_calling_focus_lost_fn: true,
             update_text_input_before_calling_vk_down_fn: true,
+            is_dragging: false,
         }
     }
 }
@@ -389,7 +412,7 @@ impl TextInput {
 
         use azul_desktop::dom::{
             CallbackData, EventFilter,
-            HoverEventFilter, FocusEventFilter,
+            HoverEventFilter, FocusEventFilter, WindowEventFilter,
             IdOrClass::Class, TabIndex,
         };
 
@@ -430,13 +453,27 @@ impl TextInput {
                 data: state_ref.clone(),
                 callback: Callback { cb: self::input::default_on_container_click }
             },
+            // the selection continues to follow the mouse when it leaves the text input
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::MouseOver),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_drag }
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::LeftMouseUp),
+                data: state_ref.clone(),
+                callback: Callback { cb: self::input::default_on_mouse_up }
+            },
         ].into())
         .with_children(vec![
+            // the selection has to come first, so that it is drawn behind the text
+            Dom::div()
+            .with_ids_and_classes(vec![Class("__azul-native-text-input-selection".into())].into())
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_INPUT_SELECTION_PROPS)),
             Dom::text(label_text)
             .with_ids_and_classes(vec![Class("__azul-native-text-input-label".into())].into())
             .with_inline_css_props(self.label_style),
             // let cursor = Dom::div().with_class("__azul-native-text-input-cursor");
-            // let text_selection = Dom::div().with_class("__azul-native-text-input-selection".into());
         ].into())
     }
 }
@@ -458,10 +495,15 @@ pub struct TextInputSelectionRange {
 }
 
 impl TextInputSelection {
+    /// Returns the selected range, ordered from the lower to the higher index
+    ///
+    /// NOTE: `TextInputSelectionRange::from` is the anchor of the selection
+    /// (where the selection was started), `to` is the end that moves with
+    /// the cursor, so `from` may be larger than `to`
     pub fn get_range(&self, text_len: usize) -> Range<usize> {
         match self {
             TextInputSelection::All => 0..text_len,
-            TextInputSelection::FromTo(r) => r.from..r.to,
+            TextInputSelection::FromTo(r) => r.from.min(r.to).min(text_len)..r.from.max(r.to).min(text_len),
         }
     }
 }
@@ -475,26 +517,77 @@ impl TextInputState {
         .collect()
     }
 
+    /// Returns the currently selected text or an empty string if nothing is selected
+    pub fn get_selected_text(&self) -> String {
+        match self.get_selection_range() {
+            Some(r) => self.text.as_ref()[r].iter().filter_map(|c| core::char::from_u32(*c)).collect(),
+            None => String::new(),
+        }
+    }
+
+    /// Returns the (ordered) selected range, `None` if the selection is empty
+    fn get_selection_range(&self) -> Option<Range<usize>> {
+        let range = self.selection.as_ref()?.get_range(self.text.len());
+        if range.start == range.end { None } else { Some(range) }
+    }
+
+    /// Returns the index at which the current selection was started:
+    /// if nothing is selected, the selection starts at the cursor
+    fn get_selection_anchor(&self) -> usize {
+        match self.selection.as_ref() {
+            None => self.cursor_pos,
+            Some(TextInputSelection::All) => if self.cursor_pos == 0 { self.text.len() } else { 0 },
+            Some(TextInputSelection::FromTo(r)) => r.from.min(self.text.len()),
+        }
+    }
+
+    /// Moves the cursor to `new_pos`, if `extend_selection` is set, the text
+    /// between the selection anchor and the new cursor position gets selected
+    fn move_cursor(&mut self, new_pos: usize, extend_selection: bool) {
+        let new_pos = new_pos.min(self.text.len());
+        let anchor = self.get_selection_anchor();
+        self.cursor_pos = new_pos;
+        self.selection = if extend_selection && anchor != new_pos {
+            Some(TextInputSelection::FromTo(TextInputSelectionRange { from: anchor, to: new_pos })).into()
+        } else {
+            None.into()
+        };
+    }
+
+    /// Deletes the selected text (if any) and inserts the `new_text` at the cursor,
+    /// the inserted text is truncated so that the text doesn't exceed the `max_len`
+    fn replace_selection(&mut self, new_text: &[u32]) {
+
+        let mut text = self.text.as_ref().to_vec();
+
+        let insert_pos = match self.get_selection_range() {
+            Some(r) => { text.drain(r.clone()); r.start },
+            None => self.cursor_pos.min(text.len()),
+        };
+
+        let free_space = self.max_len.saturating_sub(text.len());
+        let new_text = &new_text[..new_text.len().min(free_space)];
+
+        text.splice(insert_pos..insert_pos, new_text.iter().copied());
+
+        self.text = text.into();
+        self.cursor_pos = insert_pos + new_text.len();
+        self.selection = None.into();
+    }
+
+    /// Replaces the selection with the pasted text: the text input is
+    /// single-line, so newlines and other control characters are stripped
+    fn paste(&mut self, s: &str) {
+        let text = s.chars().filter(|c| !c.is_control()).map(|c| c as u32).collect::<Vec<_>>();
+        self.replace_selection(&text);
+    }
+
     fn handle_on_text_input(&mut self, c: char) {
-        match self.selection.clone().into_option() {
-            None => {
-                if self.cursor_pos == self.text.len() {
-                    self.text.push(c as u32);
-                } else {
-                    // TODO: insert character at the cursor location!
-                    self.text.insert(self.cursor_pos, c as u32);
-                }
-                self.cursor_pos = self.cursor_pos.saturating_add(1).min(self.text.len() - 1);
-            },
-            Some(TextInputSelection::All) => {
-                self.text = vec![c as u32].into();
-                self.cursor_pos = 1;
-                self.selection = None.into();
-            },
-            Some(TextInputSelection::FromTo(range)) => {
-                self.delete_selection(range.from..range.to, Some(c));
-            },
+        // control characters (i.e. Ctrl + A on Windows) are handled in the VirtualKeyDown callback
+        if c.is_control() {
+            return;
         }
+        self.replace_selection(&[c as u32]);
     }
 
     fn handle_on_virtual_key_down(
@@ -503,126 +596,221 @@ impl TextInputState {
         keyboard_state: &KeyboardState,
         info: &mut CallbackInfo,
     ) {
+        use azul_desktop::app::Clipboard;
+
+        let shift = keyboard_state.shift_down;
+        let ctrl = keyboard_state.ctrl_down || (cfg!(target_os = "macos") && keyboard_state.super_down);
+
         match virtual_key {
             VirtualKeyCode::Back => {
-                // TODO: shift + back = delete last word
-                let selection = self.selection.clone();
-                match selection.as_ref() {
-                    None => {
-                        if self.cursor_pos == (self.text.len() - 1) {
-                            self.text.pop();
-                        } else {
-                            self.text.remove(self.cursor_pos);
-                        }
-                        self.cursor_pos = self.cursor_pos.saturating_sub(1);
-                    },
-                    Some(TextInputSelection::All) => {
-                        self.text = Vec::new().into();
-                        self.cursor_pos = 0;
-                        self.selection = None.into();
-                    },
-                    Some(TextInputSelection::FromTo(range)) => {
-                        self.delete_selection(range.from..range.to, None);
-                    },
+                // TODO: ctrl + back = delete last word
+                if self.get_selection_range().is_none() && self.cursor_pos > 0 {
+                    self.move_cursor(self.cursor_pos - 1, true);
+                }
+                self.replace_selection(&[]);
+            },
+            VirtualKeyCode::Delete => {
+                if self.get_selection_range().is_none() && self.cursor_pos < self.text.len() {
+                    self.move_cursor(self.cursor_pos + 1, true);
                 }
+                self.replace_selection(&[]);
             },
             VirtualKeyCode::Return => { /* ignore return keys */ },
             VirtualKeyCode::Home => {
-                self.cursor_pos = 0;
-                self.selection = None.into();
+                self.move_cursor(0, shift);
             },
             VirtualKeyCode::End => {
-                self.cursor_pos = self.text.len().saturating_sub(1);
-                self.selection = None.into();
+                self.move_cursor(self.text.len(), shift);
             },
             VirtualKeyCode::Tab => {
                 use azul_desktop::callbacks::FocusTarget;
-                if keyboard_state.shift_down {
-                    info.set_focus(FocusTarget::Next);
-                } else {
+                if shift {
                     info.set_focus(FocusTarget::Previous);
+                } else {
+                    info.set_focus(FocusTarget::Next);
                 }
             },
             VirtualKeyCode::Escape => {
                 self.selection = None.into();
             },
             VirtualKeyCode::Right => {
-                self.cursor_pos = self.cursor_pos.saturating_add(1).min(self.text.len());
+                match self.get_selection_range() {
+                    Some(r) if !shift => self.move_cursor(r.end, false),
+                    _ => self.move_cursor(self.cursor_pos.saturating_add(1), shift),
+                }
             },
             VirtualKeyCode::Left => {
-                self.cursor_pos = self.cursor_pos.saturating_sub(1).min(self.text.len());
+                match self.get_selection_range() {
+                    Some(r) if !shift => self.move_cursor(r.start, false),
+                    _ => self.move_cursor(self.cursor_pos.saturating_sub(1), shift),
+                }
             },
             // ctrl + a
-            VirtualKeyCode::A if keyboard_state.ctrl_down => {
+            VirtualKeyCode::A if ctrl => {
+                self.cursor_pos = self.text.len();
                 self.selection = Some(TextInputSelection::All).into();
             },
-            /*
             // ctrl + c
-            VirtualKeyCode::C if keyboard_state.ctrl_down => {
-                Clipboard::new().set_string_contents(self.text[self.selection]);
-            },
-            // ctrl + v
-            VirtualKeyCode::V if keyboard_state.ctrl_down => {
-                let clipboard_contents = Clipboard::new().get_string_contents(self.text[self.selection]).into_option().unwrap_or_default();
-                let clipboard_contents: Vec<char> = clipboard_contents.as_str().chars().collect();
-                match self.selection {
-                    None => {
-
-                    },
-                    Some(TextInputSelection::All) => {
-                        self.text = ;
-                    },
-                    Some(TextInputSelection::Range(r)) => {
-                        self.delete_selection(r);
-
-
+            VirtualKeyCode::C if ctrl => {
+                if self.get_selection_range().is_some() {
+                    if let Some(mut clipboard) = Clipboard::new() {
+                        let _ = clipboard.set_clipboard_string(self.get_selected_text().into());
                     }
                 }
-                self.selection = None;
-                self.cursor = ...;
             },
             // ctrl + x
-            VirtualKeyCode::X if keyboard_state.ctrl_down => {
-                Clipboard::new().set_string_contents(self.text[self.selection.get_range(self.text.len())]);
-            }
-            */
+            VirtualKeyCode::X if ctrl => {
+                if self.get_selection_range().is_some() {
+                    if let Some(mut clipboard) = Clipboard::new() {
+                        if clipboard.set_clipboard_string(self.get_selected_text().into()).is_some() {
+                            self.replace_selection(&[]);
+                        }
+                    }
+                }
+            },
+            // ctrl + v
+            VirtualKeyCode::V if ctrl => {
+                let clipboard_contents = match Clipboard::new().and_then(|c| c.get_clipboard_string()) {
+                    Some(s) => s,
+                    None => return,
+                };
+                self.paste(clipboard_contents.as_str());
+            },
             _ => { },
         }
     }
 
-    fn delete_selection(&mut self, selection: Range<usize>, new_text: Option<char>) {
-        let Range { start, end } = selection;
-        let max = end.min(self.text.len() - 1);
+}
 
-        if max == (self.text.len() - 1) {
-            self.text.truncate(start);
-            if let Some(new) = new_text {
-                self.text.push(new as u32);
-                self.cursor_pos = start + 1;
-            } else {
-                self.cursor_pos = start;
-            }
-        } else {
-            let end = self.text.as_ref()[max..].to_vec();
-            self.text.truncate(start);
-            if let Some(new) = new_text {
-                self.text.push(new as u32);
-                self.cursor_pos = start + 1;
-            } else {
-                self.cursor_pos = start;
+/// Returns whether the character is laid out without a glyph
+/// (the `InlineText` only stores glyphs for non-whitespace characters)
+fn is_whitespace_char(c: u32) -> bool {
+    match core::char::from_u32(c) {
+        Some(c) => c.is_whitespace() || c == '\u{200B}',
+        None => true,
+    }
+}
+
+/// Returns the bounds of every glyph with a codepoint, relative to the top left
+/// corner of the inline text (same order as the char indices of `InlineText::hit_test`)
+pub(crate) fn get_glyph_char_bounds(inline_text: &InlineText) -> Vec<LogicalRect> {
+    let mut bounds = Vec::new();
+    for line in inline_text.lines.iter() {
+        // line.bounds.origin is the bottom left corner of the line
+        let line_top = line.bounds.origin.y - line.bounds.size.height;
+        for word in line.words.iter() {
+            let text_content = match word.get_text_content() {
+                Some(s) => s,
+                None => continue,
+            };
+            for glyph in text_content.glyphs.iter().filter(|g| g.has_codepoint()) {
+                bounds.push(LogicalRect::new(
+                    LogicalPosition::new(
+                        line.bounds.origin.x + text_content.bounds.origin.x + glyph.bounds.origin.x,
+                        line_top,
+                    ),
+                    LogicalSize::new(glyph.bounds.size.width, line.bounds.size.height),
+                ));
             }
-            let mut end: U32Vec = end.into();
-            self.text.append(&mut end);
         }
     }
+    bounds
+}
+
+/// Maps the char indices of the `InlineText` (which skip whitespace)
+/// to the char indices in the text of the input
+pub(crate) fn get_glyph_char_text_indices(text: &[u32]) -> Vec<usize> {
+    text.iter().enumerate().filter(|(_, c)| !is_whitespace_char(**c)).map(|(i, _)| i).collect()
+}
+
+/// Returns the char index at which the cursor should be placed
+/// when clicking at `position` (relative to the label node)
+fn get_cursor_index_at_position(text: &[u32], inline_text: &InlineText, position: LogicalPosition) -> usize {
+
+    let text_indices = get_glyph_char_text_indices(text);
+
+    // clicks (or drags) outside of the text should still hit the line
+    let max_y = (inline_text.content_size.height - 1.0).max(0.0);
+    let position = LogicalPosition::new(
+        position.x.max(0.0).min(inline_text.content_size.width),
+        position.y.max(0.0).min(max_y),
+    );
+
+    if let Some(hit) = inline_text.hit_test(position).into_iter().find(|h| h.unicode_codepoint.is_some()) {
+        if let Some(text_index) = text_indices.get(hit.char_index_relative_to_text) {
+            let after_char = hit.cursor_char_index_relative_to_text - hit.char_index_relative_to_text;
+            return (text_index + after_char).min(text.len());
+        }
+    }
+
+    // whitespace / empty space hit: place the cursor before the next glyph on the right
+    get_glyph_char_bounds(inline_text)
+    .iter()
+    .zip(text_indices.iter())
+    .find(|(bounds, _)| position.x < bounds.origin.x + bounds.size.width / 2.0)
+    .map(|(_, text_index)| *text_index)
+    .unwrap_or(text.len())
+}
+
+/// Returns the rectangle (relative to the label node) of the selection highlight
+fn get_selection_bounds(text: &[u32], inline_text: &InlineText, selection: Range<usize>) -> Option<LogicalRect> {
+
+    if selection.start >= selection.end {
+        return None;
+    }
+
+    let glyph_bounds = get_glyph_char_bounds(inline_text);
+    let text_indices = get_glyph_char_text_indices(text);
+    let glyphs = glyph_bounds.iter().zip(text_indices.iter());
+
+    let right_of_previous_glyph = glyphs.clone()
+        .filter(|(_, i)| **i < selection.start)
+        .last()
+        .map(|(b, _)| b.origin.x + b.size.width)
+        .unwrap_or(0.0);
+
+    let left_of_next_glyph = glyphs.clone()
+        .find(|(_, i)| **i >= selection.end)
+        .map(|(b, _)| b.origin.x)
+        .unwrap_or(inline_text.content_size.width);
+
+    let selected = glyphs.filter(|(_, i)| selection.contains(*i)).map(|(b, _)| *b).collect::<Vec<_>>();
+
+    let starts_with_whitespace = text.get(selection.start).copied().map(is_whitespace_char).unwrap_or(false);
+    let ends_with_whitespace = text.get(selection.end - 1).copied().map(is_whitespace_char).unwrap_or(false);
+
+    let left = match selected.first() {
+        Some(b) if !starts_with_whitespace => b.origin.x,
+        _ => right_of_previous_glyph,
+    };
+
+    let right = match selected.last() {
+        Some(_) if !ends_with_whitespace => selected.iter().map(|b| b.origin.x + b.size.width).fold(f32::MIN, f32::max),
+        _ => left_of_next_glyph,
+    };
+
+    let (top, bottom) = match selected.first() {
+        Some(_) => (
+            selected.iter().map(|b| b.origin.y).fold(f32::MAX, f32::min),
+            selected.iter().map(|b| b.origin.y + b.size.height).fold(f32::MIN, f32::max),
+        ),
+        None => (0.0, inline_text.content_size.height),
+    };
+
+    Some(LogicalRect::new(
+        LogicalPosition::new(left.min(right), top),
+        LogicalSize::new((right - left).abs(), bottom - top),
+    ))
 }
 
 // handle input events for the TextInput
 mod input {
 
-    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update};
+    use azul_desktop::callbacks::{RefAny, CallbackInfo, Update, DomNodeId};
+    use azul_desktop::css::*;
+    use azul_core::window::LogicalPosition;
     use super::{
-        TextInputStateWrapper, OnTextInputReturn, TextInputValid,
+        TextInputState, TextInputStateWrapper, OnTextInputReturn, TextInputValid,
         TextInputOnTextInput, TextInputOnFocusLost, TextInputOnVirtualKeyDown,
     };
     use std::string::String;
@@ -646,11 +834,6 @@ mod input {
             None => return Update::DoNothing,
         };
 
-        let label_node_id = match info.get_first_child(info.get_hit_node()) {
-            Some(s) => s,
-            None => return Update::DoNothing,
-        };
-
         let result = {
             // rustc doesn't understand the borrowing lifetime here
             let text_input = &mut *text_input;
@@ -670,19 +853,12 @@ mod input {
         };
 
         if result.valid == TextInputValid::Yes {
+            let text_before = text_input.inner.text.clone();
             text_input.inner.handle_on_text_input(c);
+            // TODO: restart the timer for cursor blinking
+            update_text_input_display(&text_input.inner, text_input.inner.text != text_before, &mut info);
         }
 
-        // Update the string, cursor position on the screen and selection background
-        // TODO: restart the timer for cursor blinking
-        let text_now: String = text_input.inner.text.iter()
-            .filter_map(|s| core::char::from_u32(*s))
-            .collect();
-
-        info.set_string_contents(label_node_id, text_now.into());
-        // info.set_css_property(cursor_node_id, CssProperty::const_transform(get_cursor_transform(info.get_text_contents()[self.cursor_pos])))
-        // info.update_image(selection_node_id, render_selection(self.selection));
-
         result.update
     }
 
@@ -719,18 +895,71 @@ mod input {
         };
 
         if result.valid == TextInputValid::Yes {
+            let text_before = text_input.inner.text.clone();
             text_input.inner.handle_on_virtual_key_down(last_keycode, &kb_state, &mut info);
+            update_text_input_display(&text_input.inner, text_input.inner.text != text_before, &mut info);
         }
 
         result.update
     }
 
-    pub(in super) extern "C" fn default_on_container_click(text_input: &mut RefAny, info: CallbackInfo) -> Update {
+    pub(in super) extern "C" fn default_on_container_click(text_input: &mut RefAny, mut info: CallbackInfo) -> Update {
+        let mut text_input = match text_input.downcast_mut::<TextInputStateWrapper>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let char_index = match get_hit_char_index(&text_input.inner, &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        // shift + click extends the current selection
+        let extend_selection = info.get_current_keyboard_state().shift_down;
+        text_input.inner.move_cursor(char_index, extend_selection);
+        text_input.is_dragging = true;
+        update_text_input_display(&text_input.inner, false, &mut info);
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_drag(text_input: &mut RefAny, mut info: CallbackInfo) -> Update {
         let mut text_input = match text_input.downcast_mut::<TextInputStateWrapper>() {
             Some(s) => s,
             None => return Update::DoNothing,
         };
-        // TODO: clear selection, set cursor to text hit
+
+        // only a drag if the mouse went down on this text input
+        // (the initial mouse down is handled by default_on_container_click)
+        if !text_input.is_dragging {
+            return Update::DoNothing;
+        }
+
+        // the mouse button might have been released outside of the window
+        if !info.get_current_mouse_state().left_down {
+            text_input.is_dragging = false;
+            return Update::DoNothing;
+        }
+
+        let char_index = match get_hit_char_index(&text_input.inner, &info) {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        if char_index == text_input.inner.cursor_pos {
+            return Update::DoNothing;
+        }
+
+        text_input.inner.move_cursor(char_index, true);
+        update_text_input_display(&text_input.inner, false, &mut info);
+
+        Update::DoNothing
+    }
+
+    pub(in super) extern "C" fn default_on_mouse_up(text_input: &mut RefAny, _info: CallbackInfo) -> Update {
+        if let Some(mut text_input) = text_input.downcast_mut::<TextInputStateWrapper>() {
+            text_input.is_dragging = false;
+        }
         Update::DoNothing
     }
 
@@ -773,10 +1002,138 @@ mod input {
 
         result
     }
+
+    /// Returns the index in the text at which the cursor has to be placed
+    /// for the current mouse position (hit-tests the label of the hit container)
+    fn get_hit_char_index(text_input: &TextInputState, info: &CallbackInfo) -> Option<usize> {
+        let container_node_id = info.get_hit_node();
+        let label_node_id = info.get_last_child(container_node_id)?;
+        let inline_text = info.get_inline_text(label_node_id)?;
+        let cursor_relative_to_container = match info.get_cursor_relative_to_node().into_option() {
+            Some(s) => s,
+            // window events (i.e. dragging outside of the text input) don't hit the container
+            None => {
+                let container_position = info.get_node_position(container_node_id)?.get_static_offset();
+                info.get_cursor_relative_to_viewport().into_option()? - container_position
+            },
+        };
+        let cursor_relative_to_label = cursor_relative_to_container - get_label_offset(container_node_id, label_node_id, info)?;
+        Some(super::get_cursor_index_at_position(text_input.text.as_ref(), &inline_text, cursor_relative_to_label))
+    }
+
+    /// Returns the offset of the label relative to the top left corner of the container
+    fn get_label_offset(container_node_id: DomNodeId, label_node_id: DomNodeId, info: &CallbackInfo) -> Option<LogicalPosition> {
+        let container_position = info.get_node_position(container_node_id)?.get_static_offset();
+        let label_position = info.get_node_position(label_node_id)?.get_static_offset();
+        Some(label_position - container_position)
+    }
+
+    /// Updates the label text and moves the selection rectangle behind the selected glyphs
+    fn update_text_input_display(text_input: &TextInputState, text_changed: bool, info: &mut CallbackInfo) {
+
+        let container_node_id = info.get_hit_node();
+        let selection_node_id = match info.get_first_child(container_node_id) { Some(s) => s, None => return, };
+        let label_node_id = match info.get_last_child(container_node_id) { Some(s) => s, None => return, };
+
+        if text_changed {
+            let text_now: String = text_input.get_text();
+            info.set_string_contents(label_node_id, text_now.into());
+        }
+
+        // NOTE: if the text changed, the layout of the label is out of date,
+        // but editing the text always clears the selection anyway
+        let selection_bounds = match text_input.get_selection_range() {
+            Some(r) if !text_changed => {
+                info.get_inline_text(label_node_id)
+                .and_then(|inline_text| super::get_selection_bounds(text_input.text.as_ref(), &inline_text, r))
+                .and_then(|b| Some((b, get_label_offset(container_node_id, label_node_id, info)?)))
+                .map(|(mut b, label_offset)| { b.origin += label_offset; b })
+            },
+            _ => None,
+        };
+
+        let (left, top, width, height) = match selection_bounds {
+            Some(b) => (b.origin.x, b.origin.y, b.size.width, b.size.height),
+            None => (0.0, 0.0, 0.0, 0.0),
+        };
+
+        info.set_css_property(selection_node_id, CssProperty::const_left(LayoutLeft::px(left)));
+        info.set_css_property(selection_node_id, CssProperty::const_top(LayoutTop::px(top)));
+        info.set_css_property(selection_node_id, CssProperty::const_width(LayoutWidth::px(width)));
+        info.set_css_property(selection_node_id, CssProperty::const_height(LayoutHeight::px(height)));
+    }
 }
 
 impl From<TextInput> for Dom {
     fn from(t: TextInput) -> Dom {
         t.dom()
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_text_input_shift_selection() {
+
+    let mut text_input = TextInputState::default();
+    for c in "hello".chars() {
+        text_input.handle_on_text_input(c);
+    }
+
+    // shift + left twice selects "lo", the anchor stays at the end
+    text_input.move_cursor(4, true);
+    text_input.move_cursor(3, true);
+    assert_eq!(text_input.selection, Some(TextInputSelection::FromTo(TextInputSelectionRange { from: 5, to: 3 })).into());
+    assert_eq!(text_input.get_selected_text(), "lo");
+
+    // moving back to the anchor clears the selection
+    text_input.move_cursor(5, true);
+    assert_eq!(text_input.selection, None.into());
+
+    // shift + right extends the selection forwards
+    text_input.move_cursor(0, false);
+    text_input.move_cursor(1, true);
+    text_input.move_cursor(2, true);
+    assert_eq!(text_input.get_selected_text(), "he");
+
+    // moving without shift clears the selection
+    text_input.move_cursor(1, false);
+    assert_eq!(text_input.selection, None.into());
+    assert_eq!(text_input.cursor_pos, 1);
+}
+
+#[test]
+fn test_text_input_edit_multibyte() {
+
+    let mut text_input = TextInputState::default();
+    for c in "grüße 日本".chars() {
+        text_input.handle_on_text_input(c);
+    }
+
+    // indices are in characters, not in bytes
+    assert_eq!(text_input.cursor_pos, 8);
+    text_input.move_cursor(6, true);
+    assert_eq!(text_input.get_selected_text(), "日本");
+
+    text_input.replace_selection(&['✓' as u32]);
+    assert_eq!(text_input.get_text(), "grüße ✓");
+    assert_eq!(text_input.cursor_pos, 7);
+
+    // cut: copy the selected text, then delete it
+    text_input.move_cursor(2, false);
+    text_input.move_cursor(4, true);
+    let cut = text_input.get_selected_text();
+    text_input.replace_selection(&[]);
+    assert_eq!(cut, "üß");
+    assert_eq!(text_input.get_text(), "gre ✓");
+    assert_eq!(text_input.cursor_pos, 2);
+
+    // paste: newlines are stripped, text is inserted at the cursor
+    text_input.paste("ü\nß");
+    assert_eq!(text_input.get_text(), "grüße ✓");
+    assert_eq!(text_input.cursor_pos, 4);
+
+    // select all + paste replaces the entire text
+    text_input.selection = Some(TextInputSelection::All).into();
+    text_input.paste("日本語");
+    assert_eq!(text_input.get_text(), "日本語");
+    assert_eq!(text_input.cursor_pos, 3);
+}
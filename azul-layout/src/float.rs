@@ -0,0 +1,464 @@
+//! CSS floats: placement of `float: left / right` boxes and `clear`
+//!
+//! Floats are solved after the regular layout: `get_layout_floats` collects
+//! the block containers that have floating children, the width / height
+//! solver does not stretch floating children along the cross axis (floats
+//! are "shrink-to-fit") and does not add their height to the flow of the
+//! container. After the x / y positions have been solved, `position_floats`
+//! takes the floating children out of the flow, moves them to the left /
+//! right edge of the container and pulls up the following siblings
+//! (respecting `clear`). Finally `get_float_holes` returns the floats that
+//! overlap with text nodes, so that the text can wrap around them.
+//!
+//! Only a subset of the CSS float model is implemented: floats only apply to
+//! the direct children of `display: block / inline-block` containers with a
+//! `flex-direction: column` (the default), a float only affects the text of
+//! the siblings that come after it and floats never escape their container.
+
+use alloc::collections::btree_map::BTreeMap;
+use alloc::collections::btree_set::BTreeSet;
+use alloc::vec::Vec;
+use azul_css::*;
+use azul_core::{
+    id_tree::{NodeId, NodeDataContainer, NodeDataContainerRef},
+    styled_dom::{StyledDom, AzNode},
+    ui_solver::{
+        WidthCalculatedRect, HeightCalculatedRect,
+        HorizontalSolvedPosition, VerticalSolvedPosition,
+    },
+    window::{LogicalRect, LogicalPosition, LogicalSize},
+};
+
+/// In-flow or floating child of a block container
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct FlowItem {
+    pub node_id: NodeId,
+    pub float: LayoutFloat,
+    pub clear: LayoutClear,
+}
+
+/// All in-flow children of a block container (in DOM order),
+/// at least one of them is floating
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct FloatLayout {
+    pub items: Vec<FlowItem>,
+}
+
+impl FloatLayout {
+    pub fn is_floating(&self, node_id: &NodeId) -> bool {
+        self.items.iter().any(|item| item.node_id == *node_id && item.float != LayoutFloat::None)
+    }
+}
+
+/// Size of the margin box of a child of a block container
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct FlowBox {
+    float: LayoutFloat,
+    clear: LayoutClear,
+    width: f32,
+    height: f32,
+}
+
+fn is_block_container(display: &CssPropertyValue<LayoutDisplay>, direction: LayoutFlexDirection) -> bool {
+    let display = display.clone().get_property_or_default().unwrap_or_default();
+    (display == LayoutDisplay::Block || display == LayoutDisplay::InlineBlock) &&
+    direction == LayoutFlexDirection::Column
+}
+
+/// Returns the block containers that have at least one floating child
+pub(crate) fn get_layout_floats<'a>(
+    styled_dom: &StyledDom,
+    layout_displays: &NodeDataContainerRef<'a, CssPropertyValue<LayoutDisplay>>,
+    layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
+    layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
+) -> BTreeMap<NodeId, FloatLayout> {
+
+    let css_property_cache = styled_dom.get_css_property_cache();
+    let node_hierarchy = styled_dom.node_hierarchy.as_container();
+    let node_data = styled_dom.node_data.as_container();
+    let styled_nodes = styled_dom.styled_nodes.as_container();
+
+    styled_dom.non_leaf_nodes.iter()
+    .filter_map(|p| p.node_id.into_crate_internal())
+    .filter(|parent_id| is_block_container(&layout_displays[*parent_id], layout_directions[*parent_id]))
+    .filter_map(|parent_id| {
+
+        let items = parent_id
+        .az_children(&node_hierarchy)
+        .filter(|child_id| layout_positions[*child_id] != LayoutPosition::Absolute && layout_positions[*child_id] != LayoutPosition::Fixed)
+        .map(|child_id| {
+            let state = &styled_nodes[child_id].state;
+            FlowItem {
+                node_id: child_id,
+                float: css_property_cache.get_float(&node_data[child_id], &child_id, state)
+                    .and_then(|f| f.get_property().copied())
+                    .unwrap_or_default(),
+                clear: css_property_cache.get_clear(&node_data[child_id], &child_id, state)
+                    .and_then(|c| c.get_property().copied())
+                    .unwrap_or_default(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+        if items.iter().any(|item| item.float != LayoutFloat::None) {
+            Some((parent_id, FloatLayout { items }))
+        } else {
+            None
+        }
+    })
+    .collect()
+}
+
+/// Stacks the in-flow boxes below each other (ignoring the floats), places the
+/// floating boxes at the left / right edge of the container and moves boxes
+/// with `clear` below the floats before them.
+///
+/// Returns the top left corner of the margin box of every item, relative to
+/// the content box of the container (the x position of in-flow boxes is 0.0)
+fn place_floats(container_width: f32, boxes: &[FlowBox]) -> Vec<LogicalPosition> {
+
+    // floats that were already placed: (side, margin box)
+    let mut placed = Vec::<(LayoutFloat, LogicalRect)>::new();
+    // top of the next in-flow box
+    let mut flow_y = 0.0_f32;
+    // a float can never be placed higher than a float before it
+    let mut min_float_y = 0.0_f32;
+
+    boxes.iter().map(|b| {
+
+        let clear_y = placed.iter()
+        .filter(|(side, _)| match b.clear {
+            LayoutClear::None => false,
+            LayoutClear::Left => *side == LayoutFloat::Left,
+            LayoutClear::Right => *side == LayoutFloat::Right,
+            LayoutClear::Both => true,
+        })
+        .map(|(_, rect)| rect.origin.y + rect.size.height)
+        .fold(flow_y, f32::max);
+
+        if b.float == LayoutFloat::None {
+            flow_y = clear_y + b.height;
+            return LogicalPosition::new(0.0, clear_y);
+        }
+
+        // floats are taken out of the flow
+        let mut y = clear_y.max(min_float_y);
+
+        // move the float down until it fits next to the previous floats
+        let x = loop {
+
+            let overlapping = placed.iter()
+            .filter(|(_, rect)| rect.origin.y < y + b.height.max(1.0) && rect.origin.y + rect.size.height > y)
+            .collect::<Vec<_>>();
+
+            let left_edge = overlapping.iter()
+            .filter(|(side, _)| *side == LayoutFloat::Left)
+            .map(|(_, rect)| rect.origin.x + rect.size.width)
+            .fold(0.0, f32::max);
+
+            let right_edge = overlapping.iter()
+            .filter(|(side, _)| *side == LayoutFloat::Right)
+            .map(|(_, rect)| rect.origin.x)
+            .fold(container_width, f32::min);
+
+            if overlapping.is_empty() || right_edge - left_edge >= b.width {
+                break match b.float {
+                    LayoutFloat::Right => right_edge - b.width,
+                    _ => left_edge,
+                };
+            }
+
+            y = overlapping.iter()
+            .map(|(_, rect)| rect.origin.y + rect.size.height)
+            .fold(f32::MAX, f32::min);
+        };
+
+        min_float_y = y;
+        placed.push((b.float, LogicalRect::new(LogicalPosition::new(x, y), LogicalSize::new(b.width, b.height))));
+        LogicalPosition::new(x, y)
+    })
+    .collect()
+}
+
+/// Moves the node and all of its children by the given amount
+fn translate_subtree<'a>(
+    node_id: NodeId,
+    node_hierarchy: &NodeDataContainerRef<'a, AzNode>,
+    x_positions: &mut NodeDataContainer<HorizontalSolvedPosition>,
+    y_positions: &mut NodeDataContainer<VerticalSolvedPosition>,
+    dx: f32,
+    dy: f32,
+) {
+    if dx == 0.0 && dy == 0.0 {
+        return;
+    }
+
+    // the subtree ends at the next sibling of the node (or of its nearest ancestor)
+    let mut subtree_end = node_hierarchy.len();
+    let mut current = Some(node_id);
+    while let Some(c) = current {
+        if let Some(next_sibling) = node_hierarchy[c].next_sibling_id() {
+            subtree_end = next_sibling.index();
+            break;
+        }
+        current = node_hierarchy[c].parent_id();
+    }
+
+    for index in node_id.index()..subtree_end {
+        x_positions.internal[index].0 += dx;
+        y_positions.internal[index].0 += dy;
+    }
+}
+
+/// Returns the content box of the container (absolute position)
+fn get_content_box<'a>(
+    parent_id: NodeId,
+    node_hierarchy: &NodeDataContainerRef<'a, AzNode>,
+    solved_widths: &NodeDataContainerRef<'a, WidthCalculatedRect>,
+    solved_heights: &NodeDataContainerRef<'a, HeightCalculatedRect>,
+    x_positions: &NodeDataContainer<HorizontalSolvedPosition>,
+    y_positions: &NodeDataContainer<VerticalSolvedPosition>,
+) -> LogicalRect {
+
+    let parent_width = &solved_widths[parent_id];
+    let parent_height = &solved_heights[parent_id];
+    let (parent_parent_width, parent_parent_height) = node_hierarchy[parent_id].parent_id()
+        .map(|p| (solved_widths[p].total(), solved_heights[p].total()))
+        .unwrap_or((0.0, 0.0));
+
+    LogicalRect::new(
+        LogicalPosition::new(
+            x_positions.as_ref()[parent_id].0 + parent_width.get_padding_left(parent_parent_width),
+            y_positions.as_ref()[parent_id].0 + parent_height.get_padding_top(parent_parent_height),
+        ),
+        LogicalSize::new(
+            parent_width.total() - parent_width.get_horizontal_padding(parent_parent_width),
+            parent_height.total() - parent_height.get_vertical_padding(parent_parent_height),
+        ),
+    )
+}
+
+/// Returns the margin box of a child of a container (absolute position)
+fn get_margin_box<'a>(
+    node_id: NodeId,
+    container_width: f32,
+    solved_widths: &NodeDataContainerRef<'a, WidthCalculatedRect>,
+    solved_heights: &NodeDataContainerRef<'a, HeightCalculatedRect>,
+    x_positions: &NodeDataContainer<HorizontalSolvedPosition>,
+    y_positions: &NodeDataContainer<VerticalSolvedPosition>,
+) -> LogicalRect {
+    let width = &solved_widths[node_id];
+    let height = &solved_heights[node_id];
+    LogicalRect::new(
+        LogicalPosition::new(
+            x_positions.as_ref()[node_id].0 - width.get_margin_left(container_width),
+            y_positions.as_ref()[node_id].0 - height.get_margin_top(container_width),
+        ),
+        LogicalSize::new(
+            width.total() + width.get_horizontal_margin(container_width),
+            height.total() + height.get_vertical_margin(container_width),
+        ),
+    )
+}
+
+/// Moves the floating children of the block containers to the left / right
+/// edge of their container (must be called after the x / y positions have
+/// been solved, the positions are absolute)
+pub(crate) fn position_floats<'a>(
+    x_positions: &mut NodeDataContainer<HorizontalSolvedPosition>,
+    y_positions: &mut NodeDataContainer<VerticalSolvedPosition>,
+    solved_widths: &NodeDataContainerRef<'a, WidthCalculatedRect>,
+    solved_heights: &NodeDataContainerRef<'a, HeightCalculatedRect>,
+    node_hierarchy: &NodeDataContainerRef<'a, AzNode>,
+    layout_floats: &BTreeMap<NodeId, FloatLayout>,
+    parents_to_solve: &BTreeSet<NodeId>,
+) {
+    // parents have a lower NodeId than their children, so the
+    // containers are always moved before their floating children
+    for (parent_id, float_layout) in layout_floats.iter() {
+
+        if !parents_to_solve.contains(parent_id) {
+            continue;
+        }
+
+        let content_box = get_content_box(
+            *parent_id,
+            node_hierarchy,
+            solved_widths,
+            solved_heights,
+            x_positions,
+            y_positions,
+        );
+
+        let boxes = float_layout.items.iter().map(|item| {
+            let margin_box = get_margin_box(
+                item.node_id,
+                content_box.size.width,
+                solved_widths,
+                solved_heights,
+                x_positions,
+                y_positions,
+            );
+            FlowBox {
+                float: item.float,
+                clear: item.clear,
+                width: margin_box.size.width,
+                height: margin_box.size.height,
+            }
+        }).collect::<Vec<_>>();
+
+        let placed = place_floats(content_box.size.width, &boxes);
+
+        for (item, new_position) in float_layout.items.iter().zip(placed.into_iter()) {
+
+            let width = &solved_widths[item.node_id];
+            let height = &solved_heights[item.node_id];
+
+            let new_x = if item.float == LayoutFloat::None {
+                x_positions.as_ref()[item.node_id].0
+            } else {
+                content_box.origin.x + new_position.x + width.get_margin_left(content_box.size.width)
+            };
+            let new_y = content_box.origin.y + new_position.y + height.get_margin_top(content_box.size.width);
+            let dx = new_x - x_positions.as_ref()[item.node_id].0;
+            let dy = new_y - y_positions.as_ref()[item.node_id].0;
+
+            translate_subtree(item.node_id, node_hierarchy, x_positions, y_positions, dx, dy);
+        }
+    }
+}
+
+/// Returns the floating boxes that text nodes have to flow around, relative
+/// to the top left corner of the text (must be called after `position_floats`)
+pub(crate) fn get_float_holes<'a>(
+    x_positions: &NodeDataContainer<HorizontalSolvedPosition>,
+    y_positions: &NodeDataContainer<VerticalSolvedPosition>,
+    solved_widths: &NodeDataContainerRef<'a, WidthCalculatedRect>,
+    solved_heights: &NodeDataContainerRef<'a, HeightCalculatedRect>,
+    node_hierarchy: &NodeDataContainerRef<'a, AzNode>,
+    layout_floats: &BTreeMap<NodeId, FloatLayout>,
+    text_nodes: &BTreeSet<NodeId>,
+) -> BTreeMap<NodeId, Vec<LayoutRect>> {
+
+    let mut holes = BTreeMap::new();
+
+    for (parent_id, float_layout) in layout_floats.iter() {
+
+        let content_box = get_content_box(
+            *parent_id,
+            node_hierarchy,
+            solved_widths,
+            solved_heights,
+            x_positions,
+            y_positions,
+        );
+
+        // margin boxes of the floats that were already placed
+        let mut floats = Vec::<LogicalRect>::new();
+
+        for item in float_layout.items.iter() {
+
+            if item.float != LayoutFloat::None {
+                floats.push(get_margin_box(
+                    item.node_id,
+                    content_box.size.width,
+                    solved_widths,
+                    solved_heights,
+                    x_positions,
+                    y_positions,
+                ));
+                continue;
+            }
+
+            if !text_nodes.contains(&item.node_id) {
+                continue;
+            }
+
+            let text_x = x_positions.as_ref()[item.node_id].0 + solved_widths[item.node_id].get_padding_left(content_box.size.width);
+            let text_y = y_positions.as_ref()[item.node_id].0 + solved_heights[item.node_id].get_padding_top(content_box.size.width);
+
+            let text_holes = floats.iter()
+            .filter(|rect| rect.origin.y + rect.size.height > text_y)
+            .map(|rect| LayoutRect::new(
+                LayoutPoint::new(
+                    (rect.origin.x - text_x).round() as isize,
+                    (rect.origin.y - text_y).round() as isize,
+                ),
+                LayoutSize::new(
+                    rect.size.width.round() as isize,
+                    rect.size.height.round() as isize,
+                ),
+            ))
+            .collect::<Vec<_>>();
+
+            if !text_holes.is_empty() {
+                holes.insert(item.node_id, text_holes);
+            }
+        }
+    }
+
+    holes
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn flow(height: f32) -> FlowBox {
+        FlowBox { float: LayoutFloat::None, clear: LayoutClear::None, width: 300.0, height }
+    }
+
+    fn float(float: LayoutFloat, width: f32, height: f32) -> FlowBox {
+        FlowBox { float, clear: LayoutClear::None, width, height }
+    }
+
+    #[test]
+    fn test_float_is_taken_out_of_flow() {
+        let boxes = [
+            float(LayoutFloat::Left, 100.0, 50.0),
+            flow(20.0),
+            flow(20.0),
+        ];
+        assert_eq!(place_floats(300.0, &boxes), vec![
+            LogicalPosition::new(0.0, 0.0),
+            LogicalPosition::new(0.0, 0.0),
+            LogicalPosition::new(0.0, 20.0),
+        ]);
+    }
+
+    #[test]
+    fn test_left_and_right_floats() {
+        let boxes = [
+            float(LayoutFloat::Left, 100.0, 50.0),
+            float(LayoutFloat::Right, 100.0, 50.0),
+            float(LayoutFloat::Left, 100.0, 50.0),
+            // does not fit next to the other floats anymore
+            float(LayoutFloat::Left, 150.0, 50.0),
+        ];
+        assert_eq!(place_floats(300.0, &boxes), vec![
+            LogicalPosition::new(0.0, 0.0),
+            LogicalPosition::new(200.0, 0.0),
+            LogicalPosition::new(100.0, 0.0),
+            LogicalPosition::new(0.0, 50.0),
+        ]);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut cleared = flow(20.0);
+        cleared.clear = LayoutClear::Left;
+        let boxes = [
+            float(LayoutFloat::Left, 100.0, 50.0),
+            flow(10.0),
+            cleared,
+            flow(20.0),
+        ];
+        assert_eq!(place_floats(300.0, &boxes), vec![
+            LogicalPosition::new(0.0, 0.0),
+            LogicalPosition::new(0.0, 0.0),
+            LogicalPosition::new(0.0, 50.0),
+            LogicalPosition::new(0.0, 70.0),
+        ]);
+    }
+}
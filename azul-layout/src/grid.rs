@@ -0,0 +1,548 @@
+//! CSS grid layout: placement of grid items and sizing of grid tracks
+//!
+//! The grid is solved in two steps: `get_layout_grids` resolves the
+//! `grid-template-*`, `grid-row` / `grid-column` and `*-gap` properties
+//! of every `display: grid` container into a `GridLayout` (explicit +
+//! implicit tracks and the area of every item), afterwards the layout
+//! solver calls `solve_grid_tracks` once per axis to determine the size
+//! of each track (see `typed_arena!` in `layout_solver.rs`).
+//!
+//! Only a subset of the CSS Grid spec is implemented: `grid-auto-flow`
+//! is always `row` (sparse), implicit tracks are always `auto`, named
+//! lines and `grid-template-areas` are not supported.
+
+use alloc::collections::btree_map::BTreeMap;
+use alloc::collections::btree_set::BTreeSet;
+use alloc::vec::Vec;
+use azul_css::*;
+use azul_core::{
+    id_tree::{NodeId, NodeDataContainerRef},
+    styled_dom::StyledDom,
+    ui_solver::{GridArea, GridLayout, GridTracks},
+};
+
+/// Placement of an item along one axis, after the lines have been resolved
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ResolvedPlacement {
+    /// Item has a definite position: start and end track index (end exclusive)
+    Definite(usize, usize),
+    /// Item has to be auto-placed and spans the given number of tracks
+    Auto(usize),
+}
+
+impl ResolvedPlacement {
+    fn span(&self) -> usize {
+        match self {
+            ResolvedPlacement::Definite(start, end) => end - start,
+            ResolvedPlacement::Auto(span) => *span,
+        }
+    }
+}
+
+/// Converts a 1-based (possibly negative) grid line into a 0-based line index,
+/// returns `None` for the invalid line `0`
+fn resolve_line_index(line: i32, explicit_track_count: usize) -> Option<usize> {
+    if line > 0 {
+        Some((line - 1) as usize)
+    } else if line < 0 {
+        // -1 is the last line of the explicit grid
+        Some((explicit_track_count as i32 + 1 + line).max(0) as usize)
+    } else {
+        None
+    }
+}
+
+fn resolve_placement(start: GridLine, end: GridLine, explicit_track_count: usize) -> ResolvedPlacement {
+
+    use azul_css::GridLine::*;
+
+    let line = |l: i32| resolve_line_index(l, explicit_track_count);
+    let span = |s: u32| (s as usize).max(1);
+
+    match (start, end) {
+        (Line(s), Line(e)) => match (line(s), line(e)) {
+            (Some(s), Some(e)) if e > s => ResolvedPlacement::Definite(s, e),
+            (Some(s), Some(e)) if e < s => ResolvedPlacement::Definite(e, s),
+            (Some(s), _) => ResolvedPlacement::Definite(s, s + 1),
+            (None, Some(e)) => ResolvedPlacement::Definite(e.saturating_sub(1), e.max(1)),
+            (None, None) => ResolvedPlacement::Auto(1),
+        },
+        (Line(s), Span(n)) => match line(s) {
+            Some(s) => ResolvedPlacement::Definite(s, s + span(n)),
+            None => ResolvedPlacement::Auto(span(n)),
+        },
+        (Line(s), Auto) => match line(s) {
+            Some(s) => ResolvedPlacement::Definite(s, s + 1),
+            None => ResolvedPlacement::Auto(1),
+        },
+        (Span(n), Line(e)) => match line(e) {
+            Some(e) => {
+                let start = e.saturating_sub(span(n));
+                ResolvedPlacement::Definite(start, e.max(start + 1))
+            },
+            None => ResolvedPlacement::Auto(span(n)),
+        },
+        (Auto, Line(e)) => match line(e) {
+            Some(e) => ResolvedPlacement::Definite(e.saturating_sub(1), e.max(1)),
+            None => ResolvedPlacement::Auto(1),
+        },
+        (Span(n), _) | (Auto, Span(n)) => ResolvedPlacement::Auto(span(n)),
+        (Auto, Auto) => ResolvedPlacement::Auto(1),
+    }
+}
+
+/// Set of occupied grid cells, stored as `(row, column)`
+#[derive(Debug, Default)]
+struct GridOccupancy {
+    cells: BTreeSet<(usize, usize)>,
+}
+
+impl GridOccupancy {
+
+    fn is_free(&self, row: usize, row_span: usize, column: usize, column_span: usize) -> bool {
+        (row..row + row_span).all(|r| {
+            (column..column + column_span).all(|c| !self.cells.contains(&(r, c)))
+        })
+    }
+
+    fn occupy(&mut self, area: &GridArea) {
+        for r in area.row_start..area.row_end {
+            for c in area.column_start..area.column_end {
+                self.cells.insert((r, c));
+            }
+        }
+    }
+}
+
+/// Places the items on the grid, following the CSS grid item placement
+/// algorithm with `grid-auto-flow: row`. Returns the number of columns,
+/// the number of rows (explicit + implicit) and the area of each item.
+pub(crate) fn place_grid_items(
+    explicit_columns: usize,
+    explicit_rows: usize,
+    items: &[(NodeId, LayoutGridColumn, LayoutGridRow)],
+) -> (usize, usize, BTreeMap<NodeId, GridArea>) {
+
+    let resolved = items.iter().map(|(node_id, column, row)| {
+        (
+            *node_id,
+            resolve_placement(column.start, column.end, explicit_columns),
+            resolve_placement(row.start, row.end, explicit_rows),
+        )
+    }).collect::<Vec<_>>();
+
+    let mut occupancy = GridOccupancy::default();
+    let mut areas = BTreeMap::new();
+
+    // 1. Place everything that is locked to a row and a column
+    for (node_id, column, row) in resolved.iter() {
+        if let (ResolvedPlacement::Definite(cs, ce), ResolvedPlacement::Definite(rs, re)) = (column, row) {
+            let area = GridArea { column_start: *cs, column_end: *ce, row_start: *rs, row_end: *re };
+            occupancy.occupy(&area);
+            areas.insert(*node_id, area);
+        }
+    }
+
+    // 2. Place items locked to a row, each row has its own cursor
+    let mut row_cursors = BTreeMap::<usize, usize>::new();
+    for (node_id, column, row) in resolved.iter() {
+        if let (ResolvedPlacement::Auto(column_span), ResolvedPlacement::Definite(rs, re)) = (column, row) {
+            let cursor = row_cursors.entry(*rs).or_insert(0);
+            let mut column_start = *cursor;
+            while !occupancy.is_free(*rs, re - rs, column_start, *column_span) {
+                column_start += 1;
+            }
+            let area = GridArea {
+                column_start,
+                column_end: column_start + column_span,
+                row_start: *rs,
+                row_end: *re,
+            };
+            *cursor = area.column_end;
+            occupancy.occupy(&area);
+            areas.insert(*node_id, area);
+        }
+    }
+
+    // 3. Determine the number of columns of the implicit grid
+    let column_count = resolved.iter().fold(explicit_columns, |count, (node_id, column, _)| {
+        match areas.get(node_id) {
+            Some(area) => count.max(area.column_end),
+            None => match column {
+                ResolvedPlacement::Definite(_, ce) => count.max(*ce),
+                ResolvedPlacement::Auto(span) => count.max(*span),
+            },
+        }
+    });
+
+    // 4. Place the remaining items using a (sparse) auto-placement cursor
+    let mut cursor_row = 0;
+    let mut cursor_column = 0;
+    for (node_id, column, row) in resolved.iter() {
+
+        if areas.contains_key(node_id) {
+            continue;
+        }
+
+        let row_span = row.span();
+
+        let area = match column {
+            ResolvedPlacement::Definite(cs, ce) => {
+                if *cs < cursor_column {
+                    cursor_row += 1;
+                }
+                while !occupancy.is_free(cursor_row, row_span, *cs, ce - cs) {
+                    cursor_row += 1;
+                }
+                GridArea { column_start: *cs, column_end: *ce, row_start: cursor_row, row_end: cursor_row + row_span }
+            },
+            ResolvedPlacement::Auto(column_span) => {
+                loop {
+                    if cursor_column + column_span > column_count {
+                        cursor_row += 1;
+                        cursor_column = 0;
+                    }
+                    if occupancy.is_free(cursor_row, row_span, cursor_column, *column_span) {
+                        break;
+                    }
+                    cursor_column += 1;
+                }
+                GridArea {
+                    column_start: cursor_column,
+                    column_end: cursor_column + column_span,
+                    row_start: cursor_row,
+                    row_end: cursor_row + row_span,
+                }
+            },
+        };
+
+        cursor_column = area.column_end;
+        occupancy.occupy(&area);
+        areas.insert(*node_id, area);
+    }
+
+    let row_count = areas.values().fold(explicit_rows, |count, area| count.max(area.row_end));
+
+    (column_count, row_count, areas)
+}
+
+/// Builds the tracks of one axis: explicit tracks from the template,
+/// padded with implicit `auto` tracks up to `track_count`
+fn build_tracks(template: &[GridTrackSizing], track_count: usize, gap: PixelValue) -> GridTracks {
+    let mut templates = template.to_vec();
+    templates.resize(track_count.max(template.len()), GridTrackSizing::Auto);
+    GridTracks {
+        templates,
+        gap,
+        gap_px: 0.0,
+        sizes: Vec::new(),
+    }
+}
+
+/// Resolves the `display: grid` containers of the DOM into a `GridLayout`
+///
+/// Children with `position: absolute` or `display: none` do not participate
+/// in the grid and are not contained in `GridLayout::items`.
+pub(crate) fn get_layout_grids<'a>(
+    styled_dom: &StyledDom,
+    layout_displays: &NodeDataContainerRef<'a, CssPropertyValue<LayoutDisplay>>,
+    layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
+) -> BTreeMap<NodeId, GridLayout> {
+    let node_hierarchy = styled_dom.node_hierarchy.as_container();
+    (0..layout_displays.len())
+    .map(NodeId::new)
+    .filter(|node_id| layout_displays[*node_id].get_property() == Some(&LayoutDisplay::Grid))
+    .map(|node_id| (node_id, get_layout_grid(styled_dom, &node_id, &node_hierarchy, layout_displays, layout_positions)))
+    .collect()
+}
+
+/// Resolves a single `display: grid` container, see `get_layout_grids`
+pub(crate) fn get_layout_grid<'a>(
+    styled_dom: &StyledDom,
+    node_id: &NodeId,
+    node_hierarchy: &NodeDataContainerRef<'a, azul_core::styled_dom::AzNode>,
+    layout_displays: &NodeDataContainerRef<'a, CssPropertyValue<LayoutDisplay>>,
+    layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
+) -> GridLayout {
+
+    let cache = styled_dom.get_css_property_cache();
+    let node_data = styled_dom.node_data.as_container();
+    let styled_nodes = styled_dom.styled_nodes.as_container();
+
+    let state = &styled_nodes[*node_id].state;
+    let nd = &node_data[*node_id];
+
+    let template_columns = cache.get_grid_template_columns(nd, node_id, state)
+        .and_then(|p| p.get_property().cloned()).unwrap_or_default();
+    let template_rows = cache.get_grid_template_rows(nd, node_id, state)
+        .and_then(|p| p.get_property().cloned()).unwrap_or_default();
+    let column_gap = cache.get_column_gap(nd, node_id, state)
+        .and_then(|p| p.get_property().copied()).unwrap_or_default();
+    let row_gap = cache.get_row_gap(nd, node_id, state)
+        .and_then(|p| p.get_property().copied()).unwrap_or_default();
+
+    let items = node_id
+    .az_children(node_hierarchy)
+    .filter(|child_id| layout_positions[*child_id] != LayoutPosition::Absolute)
+    .filter(|child_id| layout_displays[*child_id].get_property() != Some(&LayoutDisplay::None))
+    .map(|child_id| {
+        let child_state = &styled_nodes[child_id].state;
+        let child_nd = &node_data[child_id];
+        let column = cache.get_grid_column(child_nd, &child_id, child_state)
+            .and_then(|p| p.get_property().copied()).unwrap_or_default();
+        let row = cache.get_grid_row(child_nd, &child_id, child_state)
+            .and_then(|p| p.get_property().copied()).unwrap_or_default();
+        (child_id, column, row)
+    })
+    .collect::<Vec<_>>();
+
+    let template_columns: &[GridTrackSizing] = template_columns.tracks.as_ref();
+    let template_rows: &[GridTrackSizing] = template_rows.tracks.as_ref();
+
+    let (column_count, row_count, items) = place_grid_items(
+        template_columns.len(),
+        template_rows.len(),
+        &items,
+    );
+
+    GridLayout {
+        columns: build_tracks(template_columns, column_count, column_gap.inner),
+        rows: build_tracks(template_rows, row_count, row_gap.inner),
+        items,
+    }
+}
+
+/// Sizes the tracks of one axis of a grid
+///
+/// `items` contains the `(start, end, size)` of each grid item along this axis,
+/// where `size` is the minimum outer size of the item (including margins).
+/// If `available_space` is `None`, only the minimum track sizes are calculated
+/// (used to determine the intrinsic size of the grid container).
+pub(crate) fn solve_grid_tracks(
+    templates: &[GridTrackSizing],
+    gap_px: f32,
+    available_space: Option<f32>,
+    items: &[(usize, usize, f32)],
+) -> Vec<f32> {
+
+    let track_count = templates.len();
+    if track_count == 0 {
+        return Vec::new();
+    }
+
+    let percent_resolve = available_space.unwrap_or(0.0);
+    let resolve_breadth = |breadth: GridTrackBreadth| match breadth {
+        GridTrackBreadth::Fixed(px) => Some(px.to_pixels(percent_resolve)),
+        _ => None,
+    };
+
+    // 1. Initialize the base sizes: fixed tracks are solved, `auto` tracks start at 0
+    let mut base_sizes = templates.iter().map(|t| {
+        resolve_breadth(t.min_breadth()).unwrap_or(0.0)
+    }).collect::<Vec<f32>>();
+
+    let has_auto_min = templates.iter().map(|t| {
+        t.min_breadth() == GridTrackBreadth::Auto
+    }).collect::<Vec<bool>>();
+
+    // 2. Resolve the intrinsic (auto) minimums, items that span a single track first
+    for (start, _, size) in items.iter().filter(|(s, e, _)| e - s == 1) {
+        if *start < track_count && has_auto_min[*start] {
+            base_sizes[*start] = base_sizes[*start].max(*size);
+        }
+    }
+
+    // then distribute the size of spanning items across the `auto` tracks they span
+    for (start, end, size) in items.iter().filter(|(s, e, _)| e - s > 1) {
+        let end = (*end).min(track_count);
+        if *start >= end {
+            continue;
+        }
+        let spanned_size = base_sizes[*start..end].iter().sum::<f32>() + gap_px * (end - start - 1) as f32;
+        let extra = size - spanned_size;
+        let auto_tracks = (*start..end).filter(|i| has_auto_min[*i]).collect::<Vec<_>>();
+        if extra <= 0.0 || auto_tracks.is_empty() {
+            continue;
+        }
+        let extra_per_track = extra / auto_tracks.len() as f32;
+        for i in auto_tracks {
+            base_sizes[i] += extra_per_track;
+        }
+    }
+
+    let available_space = match available_space {
+        Some(s) => s,
+        None => return base_sizes,
+    };
+
+    let gaps = gap_px * (track_count - 1) as f32;
+
+    // 3. Maximize the tracks that have a fixed maximum (i.e. `minmax(auto, 100px)`)
+    let growth_limits = templates.iter().zip(base_sizes.iter()).map(|(t, base)| {
+        resolve_breadth(t.max_breadth()).map(|max| max.max(*base))
+    }).collect::<Vec<Option<f32>>>();
+
+    let mut free_space = available_space - gaps - base_sizes.iter().sum::<f32>();
+    let mut growable = (0..track_count)
+        .filter(|i| growth_limits[*i].map(|l| l > base_sizes[*i]).unwrap_or(false))
+        .collect::<Vec<_>>();
+
+    while free_space > 0.0 && !growable.is_empty() {
+        let share = free_space / growable.len() as f32;
+        let mut still_growable = Vec::new();
+        for i in growable {
+            let limit = growth_limits[i].unwrap_or(base_sizes[i]);
+            let grow = share.min(limit - base_sizes[i]);
+            base_sizes[i] += grow;
+            free_space -= grow;
+            if base_sizes[i] < limit {
+                still_growable.push(i);
+            }
+        }
+        growable = still_growable;
+    }
+
+    // 4. Expand the flexible (`fr`) tracks
+    let flex_factors = templates.iter().map(|t| match t.max_breadth() {
+        GridTrackBreadth::Fraction(f) => Some(f.get().max(0.0)),
+        _ => None,
+    }).collect::<Vec<Option<f32>>>();
+
+    let mut flexible = (0..track_count).filter(|i| flex_factors[*i].is_some()).collect::<BTreeSet<_>>();
+
+    if !flexible.is_empty() {
+        // tracks whose base size is larger than their share of the
+        // leftover space are treated as inflexible, then try again
+        loop {
+            let inflexible_space = (0..track_count)
+                .filter(|i| !flexible.contains(i))
+                .map(|i| base_sizes[i])
+                .sum::<f32>();
+            let flex_sum = flexible.iter().filter_map(|i| flex_factors[*i]).sum::<f32>();
+            let leftover = (available_space - gaps - inflexible_space).max(0.0);
+            let fr_size = leftover / flex_sum.max(1.0);
+
+            let inflexible = flexible.iter().copied().filter(|i| {
+                base_sizes[*i] > fr_size * flex_factors[*i].unwrap_or(0.0)
+            }).collect::<Vec<_>>();
+
+            if inflexible.is_empty() {
+                for i in flexible.iter() {
+                    base_sizes[*i] = fr_size * flex_factors[*i].unwrap_or(0.0);
+                }
+                break;
+            }
+
+            for i in inflexible {
+                flexible.remove(&i);
+            }
+
+            if flexible.is_empty() {
+                break;
+            }
+        }
+    } else if free_space > 0.0 {
+        // 5. No flexible tracks: stretch the `auto` tracks
+        let auto_tracks = (0..track_count)
+            .filter(|i| templates[*i].max_breadth() == GridTrackBreadth::Auto)
+            .collect::<Vec<_>>();
+        if !auto_tracks.is_empty() {
+            let share = free_space / auto_tracks.len() as f32;
+            for i in auto_tracks {
+                base_sizes[i] += share;
+            }
+        }
+    }
+
+    base_sizes
+}
+
+/// Returns the minimum size of the grid tracks along one axis, including the gaps
+pub(crate) fn get_grid_intrinsic_size(
+    templates: &[GridTrackSizing],
+    gap_px: f32,
+    items: &[(usize, usize, f32)],
+) -> f32 {
+    let sizes = solve_grid_tracks(templates, gap_px, None, items);
+    sizes.iter().sum::<f32>() + gap_px * sizes.len().saturating_sub(1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn px(value: f32) -> GridTrackSizing {
+        GridTrackSizing::Fixed(PixelValue::px(value))
+    }
+
+    fn fr(value: f32) -> GridTrackSizing {
+        GridTrackSizing::Fraction(FloatValue::new(value))
+    }
+
+    fn auto_item(node_id: usize) -> (NodeId, LayoutGridColumn, LayoutGridRow) {
+        (NodeId::new(node_id), LayoutGridColumn::default(), LayoutGridRow::default())
+    }
+
+    #[test]
+    fn test_resolve_placement() {
+        use azul_css::GridLine::*;
+        assert_eq!(resolve_placement(Line(1), Line(3), 3), ResolvedPlacement::Definite(0, 2));
+        assert_eq!(resolve_placement(Line(3), Line(1), 3), ResolvedPlacement::Definite(0, 2));
+        assert_eq!(resolve_placement(Line(1), Line(-1), 3), ResolvedPlacement::Definite(0, 3));
+        assert_eq!(resolve_placement(Line(2), Span(2), 3), ResolvedPlacement::Definite(1, 3));
+        assert_eq!(resolve_placement(Span(2), Line(4), 3), ResolvedPlacement::Definite(1, 3));
+        assert_eq!(resolve_placement(Line(2), Auto, 3), ResolvedPlacement::Definite(1, 2));
+        assert_eq!(resolve_placement(Span(3), Auto, 3), ResolvedPlacement::Auto(3));
+        assert_eq!(resolve_placement(Auto, Auto, 3), ResolvedPlacement::Auto(1));
+    }
+
+    #[test]
+    fn test_auto_placement() {
+        let items = (0..5).map(auto_item).collect::<Vec<_>>();
+        let (columns, rows, areas) = place_grid_items(2, 0, &items);
+        assert_eq!(columns, 2);
+        assert_eq!(rows, 3);
+        assert_eq!(areas[&NodeId::new(2)], GridArea { column_start: 0, column_end: 1, row_start: 1, row_end: 2 });
+        assert_eq!(areas[&NodeId::new(4)], GridArea { column_start: 0, column_end: 1, row_start: 2, row_end: 3 });
+    }
+
+    #[test]
+    fn test_auto_placement_skips_occupied_cells() {
+        let mut items = (0..3).map(auto_item).collect::<Vec<_>>();
+        // first item is pinned to the second column, spanning two rows
+        items[0].1 = LayoutGridColumn { start: GridLine::Line(2), end: GridLine::Auto };
+        items[0].2 = LayoutGridRow { start: GridLine::Line(1), end: GridLine::Span(2) };
+        let (columns, rows, areas) = place_grid_items(2, 0, &items);
+        assert_eq!(columns, 2);
+        assert_eq!(rows, 2);
+        assert_eq!(areas[&NodeId::new(1)], GridArea { column_start: 0, column_end: 1, row_start: 0, row_end: 1 });
+        assert_eq!(areas[&NodeId::new(2)], GridArea { column_start: 0, column_end: 1, row_start: 1, row_end: 2 });
+    }
+
+    #[test]
+    fn test_solve_fixed_and_fr_tracks() {
+        let sizes = solve_grid_tracks(&[px(100.0), fr(1.0), fr(2.0)], 10.0, Some(420.0), &[]);
+        assert_eq!(sizes, vec![100.0, 100.0, 200.0]);
+    }
+
+    #[test]
+    fn test_fr_track_respects_content_minimum() {
+        // the first fr track can't shrink below its content, so it becomes inflexible
+        let sizes = solve_grid_tracks(&[fr(1.0), fr(1.0)], 0.0, Some(200.0), &[(0, 1, 150.0)]);
+        assert_eq!(sizes, vec![150.0, 50.0]);
+    }
+
+    #[test]
+    fn test_auto_tracks_stretch() {
+        let sizes = solve_grid_tracks(&[GridTrackSizing::Auto, px(50.0)], 0.0, Some(200.0), &[(0, 1, 30.0)]);
+        assert_eq!(sizes, vec![150.0, 50.0]);
+        assert_eq!(get_grid_intrinsic_size(&[GridTrackSizing::Auto, px(50.0)], 5.0, &[(0, 1, 30.0)]), 85.0);
+    }
+
+    #[test]
+    fn test_spanning_item_grows_auto_tracks() {
+        let sizes = solve_grid_tracks(&[GridTrackSizing::Auto, GridTrackSizing::Auto], 10.0, None, &[(0, 2, 110.0)]);
+        assert_eq!(sizes, vec![50.0, 50.0]);
+    }
+}
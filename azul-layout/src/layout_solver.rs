@@ -22,7 +22,7 @@ use azul_core::{
         WidthCalculatedRect, HeightCalculatedRect,
         HorizontalSolvedPosition, VerticalSolvedPosition,
         GpuValueCache, RelayoutChanges, PositionInfoInner,
-        StyleBoxShadowOffsets,
+        StyleBoxShadowOffsets, GridAxis, GridLayout, GridTracks,
     },
     app_resources::{
         ResourceUpdate, IdNamespace, RendererResources,
@@ -37,13 +37,15 @@ use azul_core::{
     },
 };
 use rust_fontconfig::FcFontCache;
+use crate::grid::{get_layout_grids, get_grid_intrinsic_size, solve_grid_tracks};
+use crate::float::{FloatLayout, get_layout_floats, get_float_holes, position_floats};
 #[cfg(feature = "text_layout")]
 use azul_core::callbacks::{InlineText, DomNodeId, CallbackInfo};
 
 const DEFAULT_FLEX_GROW_FACTOR: f32 = 0.0;
 
 #[derive(Debug)]
-struct WhConfig {
+pub(crate) struct WhConfig {
     width: WidthConfig,
     height: HeightConfig,
 }
@@ -62,7 +64,7 @@ struct HeightConfig {
     min: Option<LayoutMinHeight>,
 }
 
-fn precalculate_wh_config(styled_dom: &StyledDom) -> NodeDataContainer<WhConfig> {
+pub(crate) fn precalculate_wh_config(styled_dom: &StyledDom) -> NodeDataContainer<WhConfig> {
 
     use rayon::prelude::*;
 
@@ -125,7 +127,7 @@ macro_rules! determine_preferred {
     ///
     /// For example, if you have an image, the `preferred_inner_width` is the images width,
     /// if the node type is an text, the `preferred_inner_width` is the text height.
-    fn $fn_name(config: &WhConfig, preferred_width: Option<f32>, parent_width: f32) -> WhConstraint {
+    pub(crate) fn $fn_name(config: &WhConfig, preferred_width: Option<f32>, parent_width: f32) -> WhConstraint {
 
         let width     = config.$width.exact.as_ref().map(|x| x.inner.to_pixels(parent_width).max(0.0));
         let min_width = config.$width.min.as_ref().map(|x| x.inner.to_pixels(parent_width).max(0.0));
@@ -181,7 +183,7 @@ determine_preferred!(determine_preferred_width, width);
 // fn determine_preferred_height(layout: &RectLayout) -> Option<f32>
 determine_preferred!(determine_preferred_height, height);
 
-/// ```rust
+/// ```no_run,ignore
 /// typed_arena!(
 ///     WidthCalculatedRect,
 ///     preferred_width,
@@ -193,6 +195,7 @@ determine_preferred!(determine_preferred_height, height);
 ///     width_calculated_rect_arena_apply_flex_grow,
 ///     width_calculated_rect_arena_sum_children_flex_basis,
 ///     Horizontal,
+///     Column,
 /// )
 /// ```
 macro_rules! typed_arena {(
@@ -207,6 +210,7 @@ macro_rules! typed_arena {(
     $bubble_fn_name:ident,
     $apply_flex_grow_fn_name:ident,
     $main_axis:ident,
+    $grid_axis:ident,
     $margin_left:ident,
     $margin_right:ident,
     $padding_left:ident,
@@ -228,7 +232,7 @@ macro_rules! typed_arena {(
     ///
     /// NOTE: Later on, this could maybe be a NodeDataContainer<&'a RectLayout>.
     #[must_use]
-    fn $from_rect_layout_arena_fn_name<'a>(
+    pub(crate) fn $from_rect_layout_arena_fn_name<'a>(
         wh_configs: &NodeDataContainerRef<'a, WhConfig>,
         offsets: &NodeDataContainerRef<'a, AllOffsets>,
         widths: &NodeDataContainerRef<'a, Option<f32>>,
@@ -293,7 +297,9 @@ macro_rules! typed_arena {(
                 let nd = &wh_configs[child_id];
                 let child_offsets = &offsets[child_id];
                 let width = match widths.get(child_id) { Some(s) => *s, None => continue, };
-                let parent_available_space = parent_width.max_available_space().unwrap_or(0.0);
+                // percentages of an unconstrained parent resolve against
+                // the space that is available to the parent itself
+                let parent_available_space = parent_width.max_available_space().unwrap_or(parent_parent_width);
                 let child_width = $determine_preferred_fn(&nd, width, parent_available_space);
                 let mut child = $struct_name {
                     // TODO: get the initial width of the rect content
@@ -327,11 +333,13 @@ macro_rules! typed_arena {(
     /// Bubble the inner sizes to their parents -  on any parent nodes, fill out
     /// the width so that the `preferred_width` can contain the child nodes (if
     /// that doesn't violate the constraints of the parent)
-    fn $bubble_fn_name<'a, 'b>(
+    pub(crate) fn $bubble_fn_name<'a, 'b>(
         node_data: &mut NodeDataContainerRefMut<'b, $struct_name>,
         node_hierarchy: &NodeDataContainerRef<'a, AzNode>,
         layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
         layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
+        layout_grids: &BTreeMap<NodeId, GridLayout>,
+        layout_floats: &BTreeMap<NodeId, FloatLayout>,
         node_depths: &[ParentWithNodeDepth],
         root_size_width: f32,
     ) {
@@ -358,18 +366,40 @@ macro_rules! typed_arena {(
 
             let mut children_flex_basis = 0.0_f32;
 
-            parent_id
-            .az_children(node_hierarchy)
-            .filter(|child_id| layout_positions[*child_id] != LayoutPosition::Absolute)
-            .map(|child_id| (child_id, node_data[child_id].min_inner_size_px))
-            .for_each(|(_, flex_basis)| {
-                if flex_axis == LayoutAxis::$main_axis {
-                    children_flex_basis += flex_basis;
-                } else {
-                    // cross direction: take max flex basis of children
-                    children_flex_basis = children_flex_basis.max(flex_basis);
-                }
-            });
+            if let Some(grid) = layout_grids.get(&parent_id) {
+                // grid container: minimum size of all tracks, including the gaps
+                let tracks = grid.get_tracks(GridAxis::$grid_axis);
+                let items = grid.items.iter().map(|(child_id, area)| {
+                    let (start, end) = area.get_range(GridAxis::$grid_axis);
+                    let child = &node_data[*child_id];
+                    (start, end, child.min_inner_size_px + child.$get_margin_fn(parent_width))
+                }).collect::<Vec<_>>();
+                children_flex_basis = get_grid_intrinsic_size(
+                    &tracks.templates,
+                    tracks.gap.to_pixels(parent_width),
+                    &items,
+                );
+            } else {
+                let float_layout = layout_floats.get(&parent_id);
+                let mut floats_flex_basis = 0.0_f32;
+                parent_id
+                .az_children(node_hierarchy)
+                .filter(|child_id| layout_positions[*child_id] != LayoutPosition::Absolute)
+                .map(|child_id| (child_id, node_data[child_id].min_inner_size_px))
+                .for_each(|(child_id, flex_basis)| {
+                    if flex_axis != LayoutAxis::$main_axis {
+                        // cross direction: take max flex basis of children
+                        children_flex_basis = children_flex_basis.max(flex_basis);
+                    } else if float_layout.map(|f| f.is_floating(&child_id)).unwrap_or(false) {
+                        // floats are not part of the flow, but the container
+                        // has to be at least as large as the largest float
+                        floats_flex_basis = floats_flex_basis.max(flex_basis);
+                    } else {
+                        children_flex_basis += flex_basis;
+                    }
+                });
+                children_flex_basis = children_flex_basis.max(floats_flex_basis);
+            }
 
             // if the children overflow, then the maximum width / height that can be
             // bubbled is the max_height / max_width of the parent
@@ -393,12 +423,14 @@ macro_rules! typed_arena {(
     ///
     /// The layout step doesn't account for the min_width
     /// and max_width constraints, so we have to adjust them manually
-    fn $apply_flex_grow_fn_name<'a, 'b>(
+    pub(crate) fn $apply_flex_grow_fn_name<'a, 'b>(
         node_data: &mut NodeDataContainer<$struct_name>,
         node_hierarchy: &NodeDataContainerRef<'a, AzNode>,
         layout_flex_grows: &NodeDataContainerRef<'a, f32>,
         layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
         layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
+        layout_grids: &mut BTreeMap<NodeId, GridLayout>,
+        layout_floats: &BTreeMap<NodeId, FloatLayout>,
         node_depths: &[ParentWithNodeDepth],
         root_width: f32,
         parents_to_recalc: &BTreeSet<NodeId>,
@@ -642,6 +674,77 @@ macro_rules! typed_arena {(
             .collect()
         }
 
+        /// Sizes the tracks of a grid container along this axis, then stretches
+        /// each grid item to the size of its grid area (minus the margin of the item)
+        ///
+        /// Returns the flex_grow_px of the children and the solved (track sizes, gap)
+        fn distribute_space_in_grid<'a>(
+            parent_id: &NodeId,
+            children: &[NodeId],
+            node_hierarchy: &NodeDataContainerRef<'a, AzNode>,
+            grid: &GridLayout,
+            width_calculated_arena: &'a NodeDataContainerRef<$struct_name>,
+            root_width: f32
+        ) -> (Vec<f32>, (Vec<f32>, f32)) {
+
+            let parent_node_inner_width = {
+                // The inner space of the parent node, without the padding
+                let parent_node = &width_calculated_arena[*parent_id];
+                let parent_parent_width = node_hierarchy[*parent_id].parent_id()
+                .and_then(|p| width_calculated_arena[p].$preferred_field.max_available_space())
+                .unwrap_or(root_width);
+
+                parent_node.total() - parent_node.$get_padding_fn(parent_parent_width)
+            };
+
+            let tracks = grid.get_tracks(GridAxis::$grid_axis);
+            let gap_px = tracks.gap.to_pixels(parent_node_inner_width);
+
+            let items = grid.items.iter().map(|(child_id, area)| {
+                let (start, end) = area.get_range(GridAxis::$grid_axis);
+                let child = &width_calculated_arena[*child_id];
+                (start, end, child.min_inner_size_px + child.$get_margin_fn(parent_node_inner_width))
+            }).collect::<Vec<_>>();
+
+            let solved_tracks = GridTracks {
+                templates: Vec::new(),
+                gap: tracks.gap,
+                gap_px,
+                sizes: solve_grid_tracks(&tracks.templates, gap_px, Some(parent_node_inner_width), &items),
+            };
+
+            let children_flex_grow = children
+            .iter()
+            .map(|child_id| {
+
+                let area = match grid.items.get(child_id) {
+                    Some(s) => s,
+                    // `position: absolute` and `display: none` items
+                    // are not grid items, they are sized to their content
+                    None => return 0.0,
+                };
+
+                let child = &width_calculated_arena[*child_id];
+                let (start, end) = area.get_range(GridAxis::$grid_axis);
+                let area_size = solved_tracks.get_span_size(start, end) -
+                    child.$get_margin_fn(parent_node_inner_width);
+
+                match child.$preferred_field {
+                    WhConstraint::EqualTo(exact) => exact - child.min_inner_size_px,
+                    constraint => {
+                        let preferred_size = match constraint.max_available_space() {
+                            Some(max_width) => area_size.min(max_width),
+                            None => area_size,
+                        };
+                        (preferred_size - child.min_inner_size_px).max(0.0)
+                    },
+                }
+            })
+            .collect();
+
+            (children_flex_grow, (solved_tracks.sizes, gap_px))
+        }
+
         use azul_css::{LayoutAxis, LayoutPosition};
 
         debug_assert!(node_data.as_ref()[NodeId::ZERO].flex_grow_px == 0.0);
@@ -686,11 +789,25 @@ macro_rules! typed_arena {(
             }
 
             // calculate the new flex_grow
+            let layout_grids_ref = &*layout_grids;
             let flex_grows_in_this_depth = parent_ids
             .par_iter()
             .map(|parent_id| {
 
                 let children = parent_id.az_children_collect(&node_hierarchy);
+
+                if let Some(grid) = layout_grids_ref.get(parent_id) {
+                    let (result, solved_tracks) = distribute_space_in_grid(
+                        &parent_id,
+                        &children,
+                        node_hierarchy,
+                        grid,
+                        &node_data.as_ref(),
+                        root_width
+                    );
+                    return (parent_id, result, Some(solved_tracks));
+                }
+
                 let flex_axis = layout_directions[*parent_id].get_axis();
 
                 let result = if flex_axis == LayoutAxis::$main_axis {
@@ -704,26 +821,42 @@ macro_rules! typed_arena {(
                         root_width
                     )
                 } else {
-                    distribute_space_along_cross_axis(
+                    let mut result = distribute_space_along_cross_axis(
                         &parent_id,
                         &children,
                         node_hierarchy,
                         layout_positions,
                         &node_data.as_ref(),
                         root_width
-                    )
+                    );
+                    // floats are shrink-to-fit: don't stretch them to the size of the container
+                    if let Some(float_layout) = layout_floats.get(parent_id) {
+                        for (child_id, flex_grow_px) in children.iter().zip(result.iter_mut()) {
+                            if float_layout.is_floating(child_id) {
+                                *flex_grow_px = 0.0;
+                            }
+                        }
+                    }
+                    result
                 };
 
-                (parent_id, result)
+                (parent_id, result, None)
             }).collect::<Vec<_>>();
 
             // write the new flex-grow values into the flex_grow_px
             {
                 let mut node_data_mut = node_data.as_ref_mut();
-                for (parent_id, flex_grows) in flex_grows_in_this_depth {
+                for (parent_id, flex_grows, solved_tracks) in flex_grows_in_this_depth {
                     for (child_id, flex_grow_px) in parent_id.az_children(node_hierarchy).zip(flex_grows.into_iter()) {
                         node_data_mut[child_id].flex_grow_px = flex_grow_px;
                     }
+                    if let Some((sizes, gap_px)) = solved_tracks {
+                        if let Some(grid) = layout_grids.get_mut(parent_id) {
+                            let tracks = grid.get_tracks_mut(GridAxis::$grid_axis);
+                            tracks.sizes = sizes;
+                            tracks.gap_px = gap_px;
+                        }
+                    }
                 }
             }
         }
@@ -742,6 +875,7 @@ typed_arena!(
     bubble_preferred_widths_to_parents,
     width_calculated_rect_arena_apply_flex_grow,
     Horizontal,
+    Column,
     margin_left,
     margin_right,
     padding_left,
@@ -764,6 +898,7 @@ typed_arena!(
     bubble_preferred_heights_to_parents,
     height_calculated_rect_arena_apply_flex_grow,
     Vertical,
+    Row,
     margin_top,
     margin_bottom,
     padding_top,
@@ -780,6 +915,8 @@ pub(crate) fn solve_flex_layout_width<'a, 'b>(
     layout_flex_grow: &NodeDataContainerRef<'a, f32>,
     layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
     layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
+    layout_grids: &mut BTreeMap<NodeId, GridLayout>,
+    layout_floats: &BTreeMap<NodeId, FloatLayout>,
     node_hierarchy: &'b NodeDataContainerRef<'a, AzNode>,
     node_depths: &[ParentWithNodeDepth],
     window_width: f32,
@@ -790,6 +927,8 @@ pub(crate) fn solve_flex_layout_width<'a, 'b>(
         node_hierarchy,
         layout_positions,
         layout_directions,
+        layout_grids,
+        layout_floats,
         node_depths,
         window_width,
     );
@@ -799,6 +938,8 @@ pub(crate) fn solve_flex_layout_width<'a, 'b>(
         layout_flex_grow,
         layout_positions,
         layout_directions,
+        layout_grids,
+        layout_floats,
         node_depths,
         window_width,
         parents_to_recalc
@@ -811,6 +952,8 @@ pub(crate) fn solve_flex_layout_height<'a, 'b>(
     layout_flex_grow: &NodeDataContainerRef<'a, f32>,
     layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
     layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
+    layout_grids: &mut BTreeMap<NodeId, GridLayout>,
+    layout_floats: &BTreeMap<NodeId, FloatLayout>,
     node_hierarchy: &'b NodeDataContainerRef<'a, AzNode>,
     node_depths: &[ParentWithNodeDepth],
     window_height: f32,
@@ -821,6 +964,8 @@ pub(crate) fn solve_flex_layout_height<'a, 'b>(
         node_hierarchy,
         layout_positions,
         layout_directions,
+        layout_grids,
+        layout_floats,
         node_depths,
         window_height
     );
@@ -830,6 +975,8 @@ pub(crate) fn solve_flex_layout_height<'a, 'b>(
         layout_flex_grow,
         layout_positions,
         layout_directions,
+        layout_grids,
+        layout_floats,
         node_depths,
         window_height,
         parents_to_recalc
@@ -847,7 +994,8 @@ macro_rules! get_position {(
     $margin_right:ident,
     $get_padding_left:ident,
     $get_padding_right:ident,
-    $axis:ident
+    $axis:ident,
+    $grid_axis:ident
 ) => (
     /// Traverses along the DOM and solve for the X or Y position
     fn $fn_name<'a>(
@@ -856,6 +1004,7 @@ macro_rules! get_position {(
         layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
         layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
         layout_justify_contents: &NodeDataContainerRef<'a, LayoutJustifyContent>,
+        layout_grids: &BTreeMap<NodeId, GridLayout>,
         node_depths: &[ParentWithNodeDepth],
         solved_widths: &NodeDataContainerRef<'a, $width_layout>,
         parents_to_solve: &BTreeSet<NodeId>
@@ -992,6 +1141,9 @@ macro_rules! get_position {(
                 Some(x.get_property()?.inner.to_pixels(parent_inner_width))
             }).unwrap_or(0.0);
 
+            let is_auto_margin_left = child_node.$margin_left.as_ref().map(|m| m.is_auto()).unwrap_or(false);
+            let is_auto_margin_right = child_node.$margin_right.as_ref().map(|m| m.is_auto()).unwrap_or(false);
+
             if layout_positions[child_id] == LayoutPosition::Absolute {
                 determine_child_x_absolute(
                     child_id,
@@ -999,6 +1151,17 @@ macro_rules! get_position {(
                     layout_positions,
                     node_hierarchy,
                 )
+            } else if is_auto_margin_left {
+                // "margin: 0 auto" centers the child, "margin-left: auto" aligns it to the end
+                let child_margin_right = child_node.$margin_right.and_then(|x| {
+                    Some(x.get_property()?.inner.to_pixels(parent_inner_width))
+                }).unwrap_or(0.0);
+                let free_space = (parent_inner_width - child_node.total() - child_margin_right).max(0.0);
+                if is_auto_margin_right {
+                    parent_x_position + free_space / 2.0
+                } else {
+                    parent_x_position + free_space
+                }
             } else {
                 parent_x_position + child_margin_left
             }
@@ -1031,6 +1194,24 @@ macro_rules! get_position {(
                 parent_node.total() - (parent_padding_left + parent_padding_right)
             };
 
+            if let Some(grid) = layout_grids.get(&parent_id) {
+                // Grid container: place each item at the start of its grid area
+                for child_id in parent_id.az_children(node_hierarchy) {
+                    let area_offset = grid.get_item_bounds(&child_id, GridAxis::$grid_axis)
+                        .map(|(offset, _)| offset)
+                        .unwrap_or(0.0);
+                    arena.as_ref_mut()[child_id].0 = determine_child_x_along_cross_axis(
+                        layout_positions,
+                        solved_widths,
+                        child_id,
+                        parent_x_position + area_offset,
+                        parent_inner_width,
+                        node_hierarchy,
+                    );
+                }
+                continue;
+            }
+
             if parent_direction.get_axis() == LayoutAxis::$axis {
 
                 // Along main axis: Increase X with width of current element
@@ -1123,6 +1304,7 @@ fn get_x_positions<'a>(
     layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
     layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
     layout_justify_contents: &NodeDataContainerRef<'a, LayoutJustifyContent>,
+    layout_grids: &BTreeMap<NodeId, GridLayout>,
     node_depths: &[ParentWithNodeDepth],
     origin: LogicalPosition,
     parents_to_solve: &BTreeSet<NodeId>,
@@ -1138,7 +1320,8 @@ fn get_x_positions<'a>(
         margin_right,
         get_padding_left,
         get_padding_right,
-        Horizontal
+        Horizontal,
+        Column
     );
 
     get_pos_x(
@@ -1147,6 +1330,7 @@ fn get_x_positions<'a>(
         layout_positions,
         layout_directions,
         layout_justify_contents,
+        layout_grids,
         node_depths,
         solved_widths,
         &parents_to_solve
@@ -1163,6 +1347,7 @@ fn get_y_positions<'a>(
     layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
     layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
     layout_justify_contents: &NodeDataContainerRef<'a, LayoutJustifyContent>,
+    layout_grids: &BTreeMap<NodeId, GridLayout>,
     node_depths: &[ParentWithNodeDepth],
     origin: LogicalPosition,
     parents_to_solve: &BTreeSet<NodeId>,
@@ -1178,7 +1363,8 @@ fn get_y_positions<'a>(
         margin_bottom,
         get_padding_top,
         get_padding_bottom,
-        Vertical
+        Vertical,
+        Row
     );
 
     get_pos_y(
@@ -1187,6 +1373,7 @@ fn get_y_positions<'a>(
         layout_positions,
         layout_directions,
         layout_justify_contents,
+        layout_grids,
         node_depths,
         solved_heights,
         &parents_to_solve
@@ -1315,7 +1502,7 @@ pub fn get_layout_displays<'a>(styled_dom: &StyledDom) -> NodeDataContainer<CssP
     }
 }
 
-fn precalculate_all_offsets(styled_dom: &StyledDom) -> NodeDataContainer<AllOffsets> {
+pub(crate) fn precalculate_all_offsets(styled_dom: &StyledDom) -> NodeDataContainer<AllOffsets> {
 
     use rayon::prelude::*;
 
@@ -1342,7 +1529,7 @@ fn precalculate_all_offsets(styled_dom: &StyledDom) -> NodeDataContainer<AllOffs
     }
 }
 
-struct AllOffsets {
+pub(crate) struct AllOffsets {
     position: LayoutAbsolutePositions,
     border_widths: LayoutBorderOffsets,
     padding: LayoutPaddingOffsets,
@@ -1460,13 +1647,6 @@ impl LayoutMarginOffsets {
     }
 }
 
-struct NewIframeScrollState {
-    dom_id: DomId,
-    node_id: NodeId,
-    child_rect: LogicalRect,
-    virtual_child_rect: LogicalRect,
-}
-
 // Adds the image and font resources to the app_resources but does NOT add them to the RenderAPI
 pub fn do_the_layout(
     styled_dom: StyledDom,
@@ -1482,7 +1662,6 @@ pub fn do_the_layout(
 ) -> Vec<LayoutResult> {
 
     use azul_core::{
-        ui_solver::OverflowingScrollNode,
         styled_dom::AzNodeId,
         callbacks::{HidpiAdjustedBounds, IFrameCallbackInfo, IFrameCallbackReturn}
     };
@@ -1498,7 +1677,6 @@ pub fn do_the_layout(
         ),
     ];
     let mut resolved_doms = Vec::new();
-    let mut new_scroll_states = Vec::new();
 
     loop {
 
@@ -1506,29 +1684,19 @@ pub fn do_the_layout(
 
         for (parent_dom_id, dom_id, styled_dom, rect) in doms.drain(..) {
 
-            use azul_core::app_resources::add_fonts_and_images;
-
-            add_fonts_and_images(
-                image_cache,
-                renderer_resources,
-                fc_cache,
-                id_namespace,
-                epoch,
-                document_id,
-                all_resource_updates,
-                &styled_dom,
-                callbacks.load_font_fn,
-                callbacks.parse_font_fn,
-                callbacks.insert_into_active_gl_textures_fn,
-            );
-
-            let mut layout_result = do_the_layout_internal(
+            let mut layout_result = do_the_layout_single_dom(
                 dom_id,
                 parent_dom_id,
                 styled_dom,
+                rect,
+                image_cache,
+                fc_cache,
                 renderer_resources,
+                all_resource_updates,
+                id_namespace,
                 document_id,
-                rect,
+                epoch,
+                callbacks,
             );
 
             let mut iframe_mapping = BTreeMap::new();
@@ -1550,19 +1718,27 @@ pub fn do_the_layout(
                     full_window_state.size.hidpi_factor
                 );
 
+                // the scroll frame of the IFrame is created by the layout, see get_nodes_that_need_scroll_clip
+                let scroll_node = layout_result.scrollable_nodes.overflowing_nodes
+                    .get(&AzNodeId::from_crate_internal(Some(iframe_node_id)))
+                    .cloned()
+                    .unwrap_or_default();
+
                 // Invoke the IFrame callback
                 let iframe_return: IFrameCallbackReturn = {
 
+                    // the IFrame hasn't been scrolled yet: the scroll position is applied to
+                    // the DOM later on via LayoutResult::scroll_iframes()
                     let iframe_callback_info = IFrameCallbackInfo::new(
                         fc_cache,
                         image_cache,
                         window_theme,
                         hidpi_bounds,
 
-                        // TODO - see /examples/assets/images/scrollbounds.png for documentation!
-                        /* scroll_size  */ bounds.size,
+                        // see /examples/assets/images/scrollbounds.png for documentation!
+                        /* scroll_size  */ scroll_node.child_rect.size,
                         /* scroll_offset */ LogicalPosition::zero(),
-                        /* virtual_scroll_size  */ bounds.size,
+                        /* virtual_scroll_size  */ scroll_node.virtual_child_rect.size,
                         /* virtual_scroll_offset */ LogicalPosition::zero(),
                     );
 
@@ -1578,46 +1754,19 @@ pub fn do_the_layout(
                 let IFrameCallbackReturn {
                     dom,
                     scroll_size,
-                    scroll_offset,
+                    scroll_offset: _,
                     virtual_scroll_size,
                     virtual_scroll_offset,
                 } = iframe_return;
 
-                let mut iframe_dom = dom;
-                let (scroll_node_id, scroll_dom_id) = match parent_dom_id {
-                    Some(s) => (iframe_node_id, s),
-                    None => (NodeId::ZERO, DomId { inner: 0 }),
-                };
-
-                // layout_result.scrollable_nodes.get();
-                // parent_dom_id
-
-                // TODO: use other fields of iframe_return here!
-
-                let hovered_nodes = full_window_state.last_hit_test.hovered_nodes
-                    .get(&iframe_dom_id)
-                    .map(|i| i.regular_hit_test_nodes.clone())
-                    .unwrap_or_default()
-                    .keys()
-                    .cloned()
-                    .collect::<Vec<_>>();
-
-                let active_nodes = if !full_window_state.mouse_state.mouse_down() {
-                    Vec::new()
-                } else {
-                    hovered_nodes.clone()
-                };
-
-                let _ = iframe_dom.restyle_nodes_hover(hovered_nodes.as_slice(), true);
-                let _ = iframe_dom.restyle_nodes_active(active_nodes.as_slice(), true);
-                if let Some(focused_node) = full_window_state.focused_node {
-                    if focused_node.dom == iframe_dom_id {
-                        let _ = iframe_dom.restyle_nodes_focus(&[
-                            focused_node.node.into_crate_internal().unwrap()
-                        ], true);
-                    }
+                // size the scroll frame of the IFrame by the virtual size of the content
+                if let Some(osn) = layout_result.scrollable_nodes.overflowing_nodes.get_mut(&AzNodeId::from_crate_internal(Some(iframe_node_id))) {
+                    osn.set_iframe_scroll_sizes(scroll_size, virtual_scroll_size, virtual_scroll_offset);
                 }
 
+                let mut iframe_dom = dom;
+                restyle_iframe_dom(&mut iframe_dom, iframe_dom_id, full_window_state);
+
                 // TODO: use the iframe static position here?
                 let bounds = LogicalRect::new(
                     LogicalPosition::zero(),
@@ -1626,18 +1775,6 @@ pub fn do_the_layout(
 
                 // push the styled iframe dom into the next iframes and repeat (recurse)
                 new_doms.push((Some(dom_id), iframe_dom_id, iframe_dom, bounds));
-                new_scroll_states.push(NewIframeScrollState {
-                    dom_id: scroll_dom_id,
-                    node_id: scroll_node_id,
-                    child_rect: LogicalRect {
-                        origin: scroll_offset,
-                        size: scroll_size,
-                    },
-                    virtual_child_rect: LogicalRect {
-                        origin: virtual_scroll_offset,
-                        size: virtual_scroll_size,
-                    },
-                });
             }
 
             layout_result.iframe_mapping = iframe_mapping;
@@ -1651,18 +1788,128 @@ pub fn do_the_layout(
         }
     }
 
-    for nss in new_scroll_states {
-        if let Some(lr) = resolved_doms.get_mut(nss.dom_id.inner) {
-            let mut osn = lr.scrollable_nodes.overflowing_nodes
-            .entry(AzNodeId::from_crate_internal(Some(nss.node_id)))
-            .or_insert_with(|| OverflowingScrollNode::default());
+    resolved_doms
+}
+
+/// Lays out the DOM of an IFrame that was re-invoked (for example because the IFrame
+/// was scrolled past the currently rendered DOM). Nested IFrames are not invoked again.
+pub fn do_the_iframe_layout(
+    parent_dom_id: DomId,
+    iframe_dom_id: DomId,
+    mut styled_dom: StyledDom,
+    bounds: LogicalRect,
+    image_cache: &ImageCache,
+    fc_cache: &FcFontCache,
+    renderer_resources: &mut RendererResources,
+    all_resource_updates: &mut Vec<ResourceUpdate>,
+    id_namespace: IdNamespace,
+    document_id: &DocumentId,
+    epoch: Epoch,
+    callbacks: &RenderCallbacks,
+    full_window_state: &FullWindowState,
+) -> LayoutResult {
+
+    restyle_iframe_dom(&mut styled_dom, iframe_dom_id, full_window_state);
+
+    do_the_layout_single_dom(
+        iframe_dom_id,
+        Some(parent_dom_id),
+        styled_dom,
+        bounds,
+        image_cache,
+        fc_cache,
+        renderer_resources,
+        all_resource_updates,
+        id_namespace,
+        document_id,
+        epoch,
+        callbacks,
+    )
+}
+
+/// Applies the :hover, :active and :focus styles to a newly created IFrame DOM
+fn restyle_iframe_dom(iframe_dom: &mut StyledDom, iframe_dom_id: DomId, full_window_state: &FullWindowState) {
+
+    let hovered_nodes = full_window_state.last_hit_test.hovered_nodes
+        .get(&iframe_dom_id)
+        .map(|i| i.regular_hit_test_nodes.clone())
+        .unwrap_or_default()
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let active_nodes = if !full_window_state.mouse_state.mouse_down() {
+        Vec::new()
+    } else {
+        hovered_nodes.clone()
+    };
 
-            osn.child_rect = nss.child_rect;
-            osn.virtual_child_rect = nss.virtual_child_rect;
+    let _ = iframe_dom.restyle_nodes_hover(hovered_nodes.as_slice(), true);
+    let _ = iframe_dom.restyle_nodes_active(active_nodes.as_slice(), true);
+    if let Some(focused_node) = full_window_state.focused_node {
+        if focused_node.dom == iframe_dom_id {
+            let _ = iframe_dom.restyle_nodes_focus(&[
+                focused_node.node.into_crate_internal().unwrap()
+            ], true);
         }
     }
+}
 
-    resolved_doms
+/// Adds the fonts and images of the DOM to the renderer resources, then lays out the DOM
+fn do_the_layout_single_dom(
+    dom_id: DomId,
+    parent_dom_id: Option<DomId>,
+    styled_dom: StyledDom,
+    rect: LogicalRect,
+    image_cache: &ImageCache,
+    fc_cache: &FcFontCache,
+    renderer_resources: &mut RendererResources,
+    all_resource_updates: &mut Vec<ResourceUpdate>,
+    id_namespace: IdNamespace,
+    document_id: &DocumentId,
+    epoch: Epoch,
+    callbacks: &RenderCallbacks,
+) -> LayoutResult {
+
+    use azul_core::app_resources::add_fonts_and_images;
+
+    add_fonts_and_images(
+        image_cache,
+        renderer_resources,
+        fc_cache,
+        id_namespace,
+        epoch,
+        document_id,
+        all_resource_updates,
+        &styled_dom,
+        callbacks.load_font_fn,
+        callbacks.parse_font_fn,
+        callbacks.insert_into_active_gl_textures_fn,
+    );
+
+    #[cfg(feature = "text_layout")] {
+        use azul_core::app_resources::add_fallback_fonts;
+
+        add_fallback_fonts(
+            renderer_resources,
+            fc_cache,
+            id_namespace,
+            all_resource_updates,
+            &styled_dom,
+            callbacks.load_font_fn,
+            callbacks.parse_font_fn,
+            azul_text_layout::font_has_glyph_fn,
+        );
+    }
+
+    do_the_layout_internal(
+        dom_id,
+        parent_dom_id,
+        styled_dom,
+        renderer_resources,
+        document_id,
+        rect,
+    )
 }
 
 /// At this point in time, all font keys, image keys, etc. have to be already
@@ -1703,6 +1950,19 @@ pub fn do_the_layout_internal(
         &layout_display_info.as_ref(),
     );
 
+    let mut layout_grids = get_layout_grids(
+        &styled_dom,
+        &layout_display_info.as_ref(),
+        &layout_position_info.as_ref(),
+    );
+
+    let layout_floats = get_layout_floats(
+        &styled_dom,
+        &layout_display_info.as_ref(),
+        &layout_position_info.as_ref(),
+        &layout_directions_info.as_ref(),
+    );
+
     // Break all strings into words and / or resolve the TextIds
     let word_cache = create_word_cache(&styled_dom.node_data.as_container());
     // Scale the words to the correct size - TODO: Cache this in the app_resources!
@@ -1723,6 +1983,7 @@ pub fn do_the_layout_internal(
         &shaped_words,
         &styled_dom,
         None,
+        &BTreeMap::new(),
     );
 
     // Calculate the optional "intrinsic content widths" - i.e.
@@ -1764,6 +2025,8 @@ pub fn do_the_layout_internal(
         &layout_flex_grow_info.as_ref(),
         &layout_position_info.as_ref(),
         &layout_directions_info.as_ref(),
+        &mut layout_grids,
+        &layout_floats,
         &styled_dom.node_hierarchy.as_container(),
         styled_dom.non_leaf_nodes.as_ref(),
         rect_size.width,
@@ -1791,8 +2054,9 @@ pub fn do_the_layout_internal(
         &shaped_words,
         &styled_dom,
         Some(&width_calculated_arena.as_ref()),
+        &BTreeMap::new(),
     );
-    let word_positions_with_max_width = word_positions_no_max_width;
+    let mut word_positions_with_max_width = word_positions_no_max_width;
 
     // Calculate the content height of the (text / image) content based on its width
     let mut content_heights_pre = styled_dom.node_data.as_container_mut()
@@ -1839,6 +2103,8 @@ pub fn do_the_layout_internal(
         &layout_flex_grow_info.as_ref(),
         &layout_position_info.as_ref(),
         &layout_directions_info.as_ref(),
+        &mut layout_grids,
+        &layout_floats,
         &styled_dom.node_hierarchy.as_container(),
         styled_dom.non_leaf_nodes.as_ref(),
         rect_size.height,
@@ -1856,6 +2122,7 @@ pub fn do_the_layout_internal(
         &layout_position_info.as_ref(),
         &layout_directions_info.as_ref(),
         &layout_justify_contents.as_ref(),
+        &layout_grids,
         &styled_dom.non_leaf_nodes.as_ref(),
         rect_offset.clone(),
         &all_parents_btreeset,
@@ -1872,11 +2139,112 @@ pub fn do_the_layout_internal(
         &layout_position_info.as_ref(),
         &layout_directions_info.as_ref(),
         &layout_justify_contents.as_ref(),
+        &layout_grids,
         &styled_dom.non_leaf_nodes.as_ref(),
         rect_offset,
         &all_parents_btreeset,
     );
 
+    position_floats(
+        &mut x_positions,
+        &mut y_positions,
+        &width_calculated_arena.as_ref(),
+        &height_calculated_arena.as_ref(),
+        &styled_dom.node_hierarchy.as_container(),
+        &layout_floats,
+        &all_parents_btreeset,
+    );
+
+    let text_nodes = word_positions_with_max_width.keys().copied().collect::<BTreeSet<_>>();
+    let float_holes = get_float_holes(
+        &x_positions,
+        &y_positions,
+        &width_calculated_arena.as_ref(),
+        &height_calculated_arena.as_ref(),
+        &styled_dom.node_hierarchy.as_container(),
+        &layout_floats,
+        &text_nodes,
+    );
+
+    if !float_holes.is_empty() {
+
+        // Text that flows around floats gets taller, so the word positions,
+        // the heights and the y positions have to be calculated again
+        //
+        // NOTE: this is only done once - if the text moves relative to the
+        // floats because of the new heights, the holes are not updated again
+        let texts_with_holes = float_holes.keys().copied().collect::<BTreeSet<_>>();
+
+        create_word_positions(
+            &mut word_positions_with_max_width,
+            &texts_with_holes,
+            renderer_resources,
+            &word_cache,
+            &shaped_words,
+            &styled_dom,
+            Some(&width_calculated_arena.as_ref()),
+            &float_holes,
+        );
+
+        for node_id in texts_with_holes.iter() {
+            if let Some(word_positions) = word_positions_with_max_width.get(node_id) {
+                content_heights_pre.as_ref_mut()[*node_id] = Some(word_positions.0.content_size.height);
+            }
+        }
+
+        height_calculated_arena = height_calculated_rect_arena_from_rect_layout_arena(
+            &layout_width_heights.as_ref(),
+            &layout_offsets.as_ref(),
+            &content_heights_pre.as_ref(),
+            &styled_dom.node_hierarchy.as_container(),
+            &styled_dom.non_leaf_nodes.as_ref(),
+            rect_size.height,
+        );
+
+        display_none_nodes.iter().zip(height_calculated_arena.as_ref_mut().internal.iter_mut())
+        .for_each(|(display_none, height)| if *display_none { *height = HeightCalculatedRect::default(); });
+
+        solve_flex_layout_height(
+            &mut height_calculated_arena,
+            &layout_flex_grow_info.as_ref(),
+            &layout_position_info.as_ref(),
+            &layout_directions_info.as_ref(),
+            &mut layout_grids,
+            &layout_floats,
+            &styled_dom.node_hierarchy.as_container(),
+            styled_dom.non_leaf_nodes.as_ref(),
+            rect_size.height,
+            &all_parents_btreeset,
+        );
+
+        y_positions = NodeDataContainer {
+            internal: vec![VerticalSolvedPosition(0.0); styled_dom.node_data.as_ref().len()].into(),
+        };
+
+        get_y_positions(
+            &mut y_positions,
+            &height_calculated_arena.as_ref(),
+            &styled_dom.node_hierarchy.as_container(),
+            &layout_position_info.as_ref(),
+            &layout_directions_info.as_ref(),
+            &layout_justify_contents.as_ref(),
+            &layout_grids,
+            &styled_dom.non_leaf_nodes.as_ref(),
+            rect_offset,
+            &all_parents_btreeset,
+        );
+
+        position_floats(
+            &mut x_positions,
+            &mut y_positions,
+            &width_calculated_arena.as_ref(),
+            &height_calculated_arena.as_ref(),
+            &styled_dom.node_hierarchy.as_container(),
+            &layout_floats,
+            &all_parents_btreeset,
+        );
+    }
+
     let mut positioned_rects = NodeDataContainer {
         internal: vec![PositionedRectangle::default(); styled_dom.node_data.len()].into()
     };
@@ -1934,6 +2302,7 @@ pub fn do_the_layout_internal(
         layout_positions: layout_position_info,
         layout_flex_directions: layout_directions_info,
         layout_justify_contents: layout_justify_contents,
+        layout_grids,
         rects: positioned_rects,
         words_cache: word_cache,
         shaped_words_cache: shaped_words,
@@ -2300,8 +2669,7 @@ pub fn create_shaped_words<'a>(
     styled_dom: &'a StyledDom,
 ) -> BTreeMap<NodeId, ShapedWords> {
 
-    use azul_text_layout::text_layout::shape_words;
-    use azul_text_layout::text_shaping::ParsedFont;
+    use azul_text_layout::text_layout::shape_words_with_fallback;
 
     let css_property_cache = styled_dom.get_css_property_cache();
     let styled_nodes = styled_dom.styled_nodes.as_container();
@@ -2317,20 +2685,37 @@ pub fn create_shaped_words<'a>(
         let node_data = &node_data[*node_id];
         let css_font_families = css_property_cache.get_font_id_or_default(node_data, node_id, styled_node_state);
         let css_font_families_hash = StyleFontFamiliesHash::new(css_font_families.as_ref());
-        let css_font_family = renderer_resources.font_families_map.get(&css_font_families_hash)?;
-        let font_key = renderer_resources.font_id_map.get(&css_font_family)?;
-        let (font_ref, _) = renderer_resources.currently_registered_fonts.get(&font_key)?;
-        let font_data = font_ref.get_data();
+        let parsed_fonts = get_parsed_font_fallback_chain(renderer_resources, &css_font_families_hash);
+        let (parsed_font, fallback_fonts) = parsed_fonts.split_first()?;
 
-        // downcast the loaded_font.font from *const c_void to *const ParsedFont
-        let parsed_font_downcasted = unsafe { &*(font_data.parsed as *const ParsedFont) };
-
-        let shaped_words = shape_words(words, parsed_font_downcasted);
+        let shaped_words = shape_words_with_fallback(words, parsed_font, fallback_fonts);
 
         Some((*node_id, shaped_words))
     }).collect()
 }
 
+/// Returns the parsed fonts of the font fallback chain of a `font-family`,
+/// the first font is the font that the text is laid out with
+#[cfg(feature = "text_layout")]
+fn get_parsed_font_fallback_chain<'a>(
+    renderer_resources: &'a RendererResources,
+    css_font_families_hash: &azul_core::styled_dom::StyleFontFamiliesHash,
+) -> Vec<&'a azul_text_layout::text_shaping::ParsedFont> {
+
+    use azul_text_layout::text_shaping::ParsedFont;
+
+    renderer_resources
+    .get_font_fallback_chain(css_font_families_hash)
+    .into_iter()
+    .filter_map(|(_, font_key)| {
+        let (font_ref, _) = renderer_resources.currently_registered_fonts.get(&font_key)?;
+        let font_data = font_ref.get_data();
+        // downcast the loaded_font.font from *const c_void to *const ParsedFont
+        Some(unsafe { &*(font_data.parsed as *const ParsedFont) })
+    })
+    .collect()
+}
+
 #[cfg(feature = "text_layout")]
 fn create_word_positions<'a>(
     word_positions: &mut BTreeMap<NodeId, (WordPositions, FontInstanceKey)>,
@@ -2340,6 +2725,7 @@ fn create_word_positions<'a>(
     shaped_words: &BTreeMap<NodeId, ShapedWords>,
     styled_dom: &'a StyledDom,
     solved_widths: Option<&'a NodeDataContainerRef<'a, WidthCalculatedRect>>,
+    holes: &BTreeMap<NodeId, Vec<LayoutRect>>,
 ) {
 
     use rayon::prelude::*;
@@ -2416,7 +2802,7 @@ fn create_word_positions<'a>(
         let text_layout_options = ResolvedTextLayoutOptions {
             max_horizontal_width: max_text_width.into(),
             leading: None.into(), // TODO
-            holes: Vec::new().into(), // TODO
+            holes: holes.get(node_id).cloned().unwrap_or_default().into(),
             font_size_px,
             word_spacing: word_spacing.into(),
             letter_spacing: letter_spacing.into(),
@@ -2505,9 +2891,25 @@ fn get_nodes_that_need_scroll_clip(
     .filter_map(|ParentWithNodeDepth { depth: _, node_id }| {
         let parent_id = node_id.into_crate_internal()?;
         let parent_rect = layouted_rects[parent_id].get_approximate_static_bounds();
+        // the scrollable area starts at the origin of the parent and
+        // contains the children including their margins
         let children_sum_rect = LayoutRect::union(
-            parent_id.az_children(node_hierarchy)
-            .map(|child_id| layouted_rects[child_id].get_approximate_static_bounds())
+            core::iter::once(LayoutRect::new(parent_rect.origin, LayoutSize::zero()))
+            .chain(parent_id.az_children(node_hierarchy).map(|child_id| {
+                let child_rect = &layouted_rects[child_id];
+                let bounds = child_rect.get_approximate_static_bounds();
+                let margin = &child_rect.margin;
+                LayoutRect::new(
+                    LayoutPoint::new(
+                        bounds.origin.x - margin.left.round() as isize,
+                        bounds.origin.y - margin.top.round() as isize,
+                    ),
+                    LayoutSize::new(
+                        bounds.size.width + (margin.left + margin.right).round() as isize,
+                        bounds.size.height + (margin.top + margin.bottom).round() as isize,
+                    ),
+                )
+            }))
         )?;
 
         // only register the directly overflowing children
@@ -2585,6 +2987,53 @@ fn get_nodes_that_need_scroll_clip(
         // tags_to_node_ids.insert(scroll_tag_id, parent_id)
     }
 
+    // IFrames always need a scroll frame: the content of the IFrame can be larger than
+    // the DOM that is currently rendered, see `OverflowingScrollNode::set_iframe_scroll_sizes`
+    for (node_id, node_data) in dom_rects.iter().enumerate() {
+
+        use azul_core::callbacks::PipelineId;
+        use azul_core::dom::NodeType;
+
+        match node_data.get_node_type() {
+            NodeType::IFrame(_) => { },
+            _ => continue,
+        }
+
+        let node_id = NodeId::new(node_id);
+        let az_node_id = AzNodeId::from_crate_internal(Some(node_id));
+        let iframe_rect = layouted_rects[node_id].get_approximate_static_bounds();
+        let parent_rect = LogicalRect::new(
+            LogicalPosition::new(iframe_rect.origin.x as f32, iframe_rect.origin.y as f32),
+            LogicalSize::new(iframe_rect.size.width as f32, iframe_rect.size.height as f32),
+        );
+
+        // keep the scroll sizes of the last IFrame callback invocation on relayout,
+        // until the callback is invoked the IFrame content covers the IFrame
+        let (child_rect, virtual_child_rect) = match scrolled_nodes.overflowing_nodes.get(&az_node_id) {
+            Some(s) => (
+                LogicalRect::new(parent_rect.origin + s.get_virtual_scroll_offset(), s.child_rect.size),
+                LogicalRect::new(parent_rect.origin, s.virtual_child_rect.size),
+            ),
+            None => (parent_rect, parent_rect),
+        };
+
+        let parent_dom_hash = node_data.calculate_node_data_hash();
+        let parent_external_scroll_id = ExternalScrollId(parent_dom_hash.0, PipelineId(dom_id.inner as u32, document_id.id));
+        let scroll_tag_id = match display_list_rects[node_id].tag_id.as_ref() {
+            Some(s) => ScrollTagId(s.into_crate_internal()),
+            None => ScrollTagId(TagId::unique()),
+        };
+
+        overflowing_nodes.insert(az_node_id, OverflowingScrollNode {
+            parent_rect,
+            child_rect,
+            virtual_child_rect,
+            parent_external_scroll_id,
+            parent_dom_hash,
+            scroll_tag_id,
+        });
+    }
+
     *scrolled_nodes = ScrolledNodes {
         overflowing_nodes,
         clip_nodes,
@@ -2717,18 +3166,90 @@ pub fn do_the_relayout(
         parents_that_need_to_recalc_height_of_children.insert(root_id);
     }
 
+    // re-resolve the grid containers whose template / gap changed or
+    // whose children changed their grid placement
+    if let Some(nodes_to_relayout) = nodes_to_relayout.as_ref() {
+
+        let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+        let mut grids_to_rebuild = BTreeSet::new();
+
+        for (node_id, changed_props) in nodes_to_relayout.iter() {
+            let container_changed =
+                changed_props.contains_key(&CssPropertyType::Display) ||
+                changed_props.contains_key(&CssPropertyType::GridTemplateColumns) ||
+                changed_props.contains_key(&CssPropertyType::GridTemplateRows) ||
+                changed_props.contains_key(&CssPropertyType::ColumnGap) ||
+                changed_props.contains_key(&CssPropertyType::RowGap);
+
+            let item_changed =
+                changed_props.contains_key(&CssPropertyType::Display) ||
+                changed_props.contains_key(&CssPropertyType::Position) ||
+                changed_props.contains_key(&CssPropertyType::GridColumn) ||
+                changed_props.contains_key(&CssPropertyType::GridRow);
+
+            if container_changed {
+                grids_to_rebuild.insert(*node_id);
+            }
+
+            if item_changed {
+                if let Some(parent_id) = node_hierarchy[*node_id].parent_id() {
+                    grids_to_rebuild.insert(parent_id);
+                }
+            }
+        }
+
+        for node_id in grids_to_rebuild {
+
+            let is_grid = layout_result.layout_displays.as_ref()[node_id].get_property() == Some(&LayoutDisplay::Grid);
+
+            if is_grid {
+                let grid = crate::grid::get_layout_grid(
+                    &layout_result.styled_dom,
+                    &node_id,
+                    &node_hierarchy,
+                    &layout_result.layout_displays.as_ref(),
+                    &layout_result.layout_positions.as_ref(),
+                );
+                layout_result.layout_grids.insert(node_id, grid);
+            } else if layout_result.layout_grids.remove(&node_id).is_none() {
+                continue;
+            }
+
+            parents_that_need_to_recalc_width_of_children.insert(node_id);
+            parents_that_need_to_recalc_height_of_children.insert(node_id);
+            parents_that_need_to_reposition_children_x.insert(node_id);
+            parents_that_need_to_reposition_children_y.insert(node_id);
+        }
+    }
+
+    // floating children change the size and position of their siblings
+    if let Some(nodes_to_relayout) = nodes_to_relayout.as_ref() {
+        let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+        for (node_id, changed_props) in nodes_to_relayout.iter() {
+            if !changed_props.contains_key(&CssPropertyType::Float) &&
+               !changed_props.contains_key(&CssPropertyType::Clear) {
+                continue;
+            }
+            if let Some(parent_id) = node_hierarchy[*node_id].parent_id() {
+                parents_that_need_to_recalc_width_of_children.insert(parent_id);
+                parents_that_need_to_recalc_height_of_children.insert(parent_id);
+                parents_that_need_to_reposition_children_x.insert(parent_id);
+                parents_that_need_to_reposition_children_y.insert(parent_id);
+            }
+        }
+    }
+
     // Update words cache and shaped words cache
     if let Some(words_to_relayout) = words_to_relayout {
         for (node_id, new_string) in words_to_relayout.iter() {
 
             use azul_text_layout::text_layout::split_text_into_words;
             use azul_core::styled_dom::StyleFontFamiliesHash;
-            use azul_text_layout::text_layout::shape_words;
+            use azul_text_layout::text_layout::shape_words_with_fallback;
             use azul_core::ui_solver::DEFAULT_LETTER_SPACING;
             use azul_core::ui_solver::DEFAULT_WORD_SPACING;
             use azul_core::ui_solver::ResolvedTextLayoutOptions;
             use azul_text_layout::text_layout::position_words;
-            use azul_text_layout::text_shaping::ParsedFont;
 
             if layout_result.words_cache.get(&node_id).is_none() { continue; }
             if layout_result.shaped_words_cache.get(&node_id).is_none() { continue; }
@@ -2744,21 +3265,12 @@ pub fn do_the_relayout(
 
             let css_font_families = css_property_cache.get_font_id_or_default(node_data, node_id, styled_node_state);
             let css_font_families_hash = StyleFontFamiliesHash::new(css_font_families.as_ref());
-            let css_font_family = match renderer_resources.font_families_map.get(&css_font_families_hash) {
+            let parsed_fonts = get_parsed_font_fallback_chain(renderer_resources, &css_font_families_hash);
+            let (parsed_font, fallback_fonts) = match parsed_fonts.split_first() {
                 Some(s) => s,
                 None => continue,
             };
-            let font_key = match renderer_resources.font_id_map.get(&css_font_family) {
-                Some(s) => s,
-                None => continue,
-            };
-            let (font_ref, _) = match renderer_resources.currently_registered_fonts.get(&font_key) {
-                Some(s) => s,
-                None => continue,
-            };
-            let font_data = font_ref.get_data();
-            let parsed_font_downcasted = unsafe { &*(font_data.parsed as *const ParsedFont) };
-            let new_shaped_words = shape_words(&new_words, parsed_font_downcasted);
+            let new_shaped_words = shape_words_with_fallback(&new_words, parsed_font, fallback_fonts);
 
             let font_size = css_property_cache.get_font_size_or_default(node_data, node_id, &styled_node_state);
             let font_size_px = font_size.inner.to_pixels(DEFAULT_FONT_SIZE_PX as f32);
@@ -3087,6 +3599,14 @@ pub fn do_the_relayout(
 
     // parents_that_need_to_recalc_width_of_children += parents_that_need_to_recalc_width_of_children.subtree_parents();
 
+    // `float` / `clear` or the display of a container could have changed
+    let layout_floats = get_layout_floats(
+        &layout_result.styled_dom,
+        &layout_result.layout_displays.as_ref(),
+        &layout_result.layout_positions.as_ref(),
+        &layout_result.layout_flex_directions.as_ref(),
+    );
+
     // now for all nodes that need to recalculate their width, calculate their flex_grow_px,
     // then recalculate the width of their children, but STOP recalculating once a child
     // with an exact width is found
@@ -3096,6 +3616,8 @@ pub fn do_the_relayout(
         &layout_result.layout_flex_grows.as_ref(),
         &layout_result.layout_positions.as_ref(),
         &layout_result.layout_flex_directions.as_ref(),
+        &mut layout_result.layout_grids,
+        &layout_floats,
         &layout_result.styled_dom.non_leaf_nodes.as_ref(),
         root_size.width as f32,
         // important - only recalc the widths necessary!
@@ -3108,6 +3630,8 @@ pub fn do_the_relayout(
         &layout_result.layout_flex_grows.as_ref(),
         &layout_result.layout_positions.as_ref(),
         &layout_result.layout_flex_directions.as_ref(),
+        &mut layout_result.layout_grids,
+        &layout_floats,
         &layout_result.styled_dom.non_leaf_nodes.as_ref(),
         root_size.height as f32,
         // important - only recalc the heights necessary!
@@ -3123,6 +3647,7 @@ pub fn do_the_relayout(
         &layout_result.layout_positions.as_ref(),
         &layout_result.layout_flex_directions.as_ref(),
         &layout_result.layout_justify_contents.as_ref(),
+        &layout_result.layout_grids,
         &layout_result.styled_dom.non_leaf_nodes.as_ref(),
         LogicalPosition::new(root_bounds.origin.x as f32, root_bounds.origin.y as f32),
         &parents_that_need_to_reposition_children_x, // <- important
@@ -3135,11 +3660,27 @@ pub fn do_the_relayout(
         &layout_result.layout_positions.as_ref(),
         &layout_result.layout_flex_directions.as_ref(),
         &layout_result.layout_justify_contents.as_ref(),
+        &layout_result.layout_grids,
         &layout_result.styled_dom.non_leaf_nodes.as_ref(),
         LogicalPosition::new(root_bounds.origin.x as f32, root_bounds.origin.y as f32),
         &parents_that_need_to_reposition_children_y, // <- important
     );
 
+    let parents_that_need_to_reposition_floats = parents_that_need_to_reposition_children_x
+        .union(&parents_that_need_to_reposition_children_y)
+        .copied()
+        .collect::<BTreeSet<_>>();
+
+    position_floats(
+        &mut layout_result.solved_pos_x,
+        &mut layout_result.solved_pos_y,
+        &layout_result.width_calculated_rects.as_ref(),
+        &layout_result.height_calculated_rects.as_ref(),
+        &layout_result.styled_dom.node_hierarchy.as_container(),
+        &layout_floats,
+        &parents_that_need_to_reposition_floats,
+    );
+
     // update positioned_word_cache
     let mut updated_word_caches = parents_that_need_to_recalc_width_of_children.clone();
     for parent_id in parents_that_need_to_recalc_width_of_children.iter() {
@@ -3149,6 +3690,17 @@ pub fn do_the_relayout(
         }
     }
 
+    #[cfg(feature = "text_layout")]
+    let float_holes = get_float_holes(
+        &layout_result.solved_pos_x,
+        &layout_result.solved_pos_y,
+        &layout_result.width_calculated_rects.as_ref(),
+        &layout_result.height_calculated_rects.as_ref(),
+        &layout_result.styled_dom.node_hierarchy.as_container(),
+        &layout_floats,
+        &updated_word_caches,
+    );
+
     #[cfg(feature = "text_layout")]
     create_word_positions(
         &mut layout_result.positioned_words_cache,
@@ -3158,6 +3710,7 @@ pub fn do_the_relayout(
         &layout_result.shaped_words_cache,
         &layout_result.styled_dom,
         Some(&layout_result.width_calculated_rects.as_ref()),
+        &float_holes,
     );
 
     // determine which nodes changed their size and return
@@ -3242,4 +3795,4 @@ pub fn do_the_relayout(
         resized_nodes,
         gpu_key_changes,
     }
-}
\ No newline at end of file
+}
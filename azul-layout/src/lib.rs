@@ -17,9 +17,12 @@ extern crate azul_text_layout as text_layout;
 #[cfg(test)]
 mod layout_test;
 mod layout_solver;
+mod grid;
+mod float;
 
 pub use layout_solver::{
     do_the_layout,
+    do_the_iframe_layout,
     do_the_relayout,
 };
 
@@ -79,6 +79,8 @@ extern crate alloc;
 extern crate azul_css;
 extern crate azul_core;
 extern crate unicode_normalization;
+extern crate unicode_bidi;
+extern crate unicode_linebreak;
 extern crate allsorts_no_std;
 #[macro_use]
 extern crate tinyvec;
@@ -139,7 +141,24 @@ pub fn parse_font_fn(source: LoadedFontSource) -> Option<FontRef> {
     })
 }
 
+pub fn font_has_glyph_fn(font_ref: &FontRef, c: char) -> bool {
+    let parsed_font = unsafe { &*(font_ref.get_data().parsed as *const ParsedFont) };
+    // glyph 0 is the .notdef glyph
+    parsed_font.lookup_glyph_index(c as u32).map(|glyph_index| glyph_index != 0).unwrap_or(false)
+}
+
 pub fn get_font_metrics_fontref(font_ref: &FontRef) -> FontMetrics {
     let parsed_font = unsafe { &*(font_ref.get_data().parsed as *const ParsedFont) };
     parsed_font.font_metrics.clone()
 }
+
+/// Returns the outline of the given glyph (in font units, y-up) or `None`
+/// if the glyph has no outline (whitespace, bitmap glyphs)
+pub fn get_glyph_outline_fontref(font_ref: &FontRef, glyph_index: u16) -> Option<crate::text_shaping::GlyphOutline> {
+    let font_data = font_ref.get_data();
+    let parsed_font = unsafe { &*(font_data.parsed as *const ParsedFont) };
+    if let Some(outline) = parsed_font.glyph_records_decoded.get(&glyph_index).and_then(|g| g.outline.clone()) {
+        return Some(outline);
+    }
+    crate::text_shaping::get_glyph_outline(font_data.bytes.as_ref(), font_data.font_index, glyph_index)
+}
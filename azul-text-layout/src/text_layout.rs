@@ -4,13 +4,14 @@
 pub use crate::text_shaping::ParsedFont;
 pub use azul_core::{
     app_resources::{
-        Words, Word, WordType,
+        Words, Word, WordType, WordPosition,
         ShapedWords, ShapedWord, WordIndex, GlyphIndex, LineLength, IndexOfLineBreak,
         RemainingSpaceToRight, LineBreaks, WordPositions, LayoutedGlyphs, FontMetrics,
+        GlyphInfo, Placement, Attachment, Anchor,
     },
     display_list::GlyphInstance,
     ui_solver::{
-        ResolvedTextLayoutOptions, TextLayoutOptions, InlineTextLayout,
+        ResolvedTextLayoutOptions, TextLayoutOptions, InlineTextLayout, InlineTextLine,
         DEFAULT_LINE_HEIGHT, DEFAULT_WORD_SPACING, DEFAULT_LETTER_SPACING, DEFAULT_TAB_WIDTH,
     },
     window::{LogicalRect, LogicalSize, LogicalPosition},
@@ -26,6 +27,11 @@ pub fn parse_font(font_bytes: &[u8], font_index: usize, parse_outlines: bool) ->
 }
 
 /// Splits the text by whitespace into logical units (word, tab, return, whitespace).
+///
+/// Words that contain line break opportunities according to the Unicode line
+/// breaking algorithm (UAX #14) - i.e. CJK text, which is usually not separated
+/// by spaces - are further split into multiple words, separated by a zero-length
+/// `WordType::BreakOpportunity` item.
 pub fn split_text_into_words(text: &str) -> Words {
 
     use unicode_normalization::UnicodeNormalization;
@@ -47,7 +53,9 @@ pub fn split_text_into_words(text: &str) -> Words {
     for (ch_idx, ch) in normalized_chars.iter().enumerate() {
 
         let ch = *ch;
-        let current_char_is_whitespace = ch == ' ' || ch == '\t' || ch == '\r' || ch == '\n';
+        let current_char_is_whitespace =
+            ch == ' ' || ch == '\t' || ch == '\r' || ch == '\n' ||
+            is_non_breaking_space(ch) || ch == ZERO_WIDTH_SPACE;
 
         let should_push_delimiter = match ch {
             ' ' => {
@@ -64,6 +72,20 @@ pub fn split_text_into_words(text: &str) -> Words {
                     word_type: WordType::Tab
                 })
             },
+            c if is_non_breaking_space(c) => {
+                Some(Word {
+                    start: ch_idx,
+                    end: ch_idx + 1,
+                    word_type: WordType::NonBreakingSpace
+                })
+            },
+            ZERO_WIDTH_SPACE => {
+                Some(Word {
+                    start: ch_idx,
+                    end: ch_idx + 1,
+                    word_type: WordType::ZeroWidthSpace
+                })
+            },
             '\n' => {
                 Some(if normalized_chars[last_char_idx] == '\r' {
                     // "\r\n" return
@@ -123,16 +145,239 @@ pub fn split_text_into_words(text: &str) -> Words {
         words.pop();
     }
 
+    let words = split_words_at_break_opportunities(&normalized_string, words);
+
+    let (bidi_levels, bidi_paragraph_levels) = get_bidi_levels(&normalized_string);
+
     Words {
         items: words.into(),
         internal_str: normalized_string.into(),
         internal_chars: normalized_chars.iter().map(|c| *c as u32).collect(),
+        bidi_levels: bidi_levels.into(),
+        bidi_paragraph_levels: bidi_paragraph_levels.into(),
+    }
+}
+
+const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// `U+00A0` no-break space, `U+2007` figure space, `U+202F` narrow no-break space
+#[inline]
+fn is_non_breaking_space(ch: char) -> bool {
+    ch == '\u{00A0}' || ch == '\u{2007}' || ch == '\u{202F}'
+}
+
+/// Returns whether the character belongs to a script that is written without
+/// spaces between words (CJK ideographs, kana, hangul), so that the line may be
+/// broken between two characters
+///
+/// NOTE: Thai, Lao, Khmer and Myanmar (line break class SA) are also written
+/// without spaces, but can only be broken at syllable / word boundaries, which
+/// requires a dictionary. UAX #14 doesn't provide break opportunities inside of
+/// these words, so SA text is currently only broken at spaces.
+fn is_unspaced_script_char(ch: char) -> bool {
+    use unicode_linebreak::{break_property, BreakClass::*};
+    match break_property(ch as u32) {
+        Ideographic | ConditionalJapaneseStarter |
+        HangulLvSyllable | HangulLvtSyllable |
+        HangulLJamo | HangulVJamo | HangulTJamo => true,
+        _ => false,
+    }
+}
+
+/// Splits `WordType::Word` items at the line break opportunities
+/// inside of the word (UAX #14), inserting a `WordType::BreakOpportunity` at each split
+///
+/// Only break opportunities next to a character of a script without spaces
+/// (see `is_unspaced_script_char`) are used, so that words of space-separated
+/// scripts (e.g. "well-known") are not split at hyphens or slashes
+fn split_words_at_break_opportunities(text: &str, words: Vec<Word>) -> Vec<Word> {
+
+    use unicode_linebreak::{linebreaks, BreakOpportunity};
+
+    let chars = text.chars().collect::<Vec<char>>();
+
+    // the break opportunities are returned as byte indices,
+    // so they have to be converted to char indices first
+    let mut byte_to_char_idx = vec![0; text.len() + 1];
+    let mut char_count = 0;
+    for (char_idx, (byte_idx, _)) in text.char_indices().enumerate() {
+        byte_to_char_idx[byte_idx] = char_idx;
+        char_count = char_idx + 1;
+    }
+    byte_to_char_idx[text.len()] = char_count;
+
+    // break is allowed BEFORE the char at the given index
+    let break_opportunities = linebreaks(text)
+    .filter(|(_, opportunity)| *opportunity == BreakOpportunity::Allowed)
+    .map(|(byte_idx, _)| byte_to_char_idx[byte_idx])
+    .filter(|char_idx| {
+        let before = char_idx.checked_sub(1).and_then(|i| chars.get(i)).copied();
+        let after = chars.get(*char_idx).copied();
+        before.map(is_unspaced_script_char).unwrap_or(false) ||
+        after.map(is_unspaced_script_char).unwrap_or(false)
+    })
+    .collect::<Vec<usize>>();
+
+    if break_opportunities.is_empty() {
+        return words;
+    }
+
+    let mut result = Vec::with_capacity(words.len());
+
+    for word in words {
+
+        if word.word_type != WordType::Word {
+            result.push(word);
+            continue;
+        }
+
+        // break_opportunities is sorted, find the ones inside of the word
+        let first = break_opportunities.partition_point(|b| *b <= word.start);
+        let mut current_start = word.start;
+
+        for break_idx in break_opportunities[first..].iter().take_while(|b| **b < word.end) {
+            result.push(Word { start: current_start, end: *break_idx, word_type: WordType::Word });
+            result.push(Word { start: *break_idx, end: *break_idx, word_type: WordType::BreakOpportunity });
+            current_start = *break_idx;
+        }
+
+        result.push(Word { start: current_start, end: word.end, word_type: WordType::Word });
+    }
+
+    result
+}
+
+/// Runs the Unicode Bidirectional Algorithm (UAX #9) over the text and
+/// returns the resolved embedding level + the paragraph level of each `char`.
+///
+/// The paragraph direction is determined by the first strong character of
+/// each paragraph (rules P2 / P3). If the text doesn't contain any right-to-left
+/// characters, both vectors are empty, since all levels would be 0.
+pub fn get_bidi_levels(text: &str) -> (Vec<u8>, Vec<u8>) {
+
+    use unicode_bidi::BidiInfo;
+
+    let bidi_info = BidiInfo::new(text, None);
+
+    if !bidi_info.has_rtl() {
+        return (Vec::new(), Vec::new());
+    }
+
+    let mut levels = Vec::with_capacity(text.len());
+    let mut paragraph_levels = Vec::with_capacity(text.len());
+    let mut paragraphs = bidi_info.paragraphs.iter().peekable();
+
+    // levels in the BidiInfo are stored per byte, not per char
+    for (byte_idx, _) in text.char_indices() {
+        while paragraphs.peek().map(|p| byte_idx >= p.range.end).unwrap_or(false) {
+            paragraphs.next();
+        }
+        levels.push(bidi_info.levels[byte_idx].number());
+        paragraph_levels.push(paragraphs.peek().map(|p| p.level.number()).unwrap_or(0));
+    }
+
+    (levels, paragraph_levels)
+}
+
+/// Returns the visual order of items with the given embedding levels
+/// (rule L2 of UAX #9): from the highest level down to the lowest odd level,
+/// reverse any contiguous sequence of items that are at that level or higher.
+///
+/// `result[visual_index] = logical_index`
+pub fn reorder_visual(levels: &[u8]) -> Vec<usize> {
+
+    let mut order = (0..levels.len()).collect::<Vec<usize>>();
+
+    let max_level = match levels.iter().max() {
+        Some(s) => *s,
+        None => return order,
+    };
+
+    let lowest_odd_level = match levels.iter().filter(|l| *l % 2 == 1).min() {
+        Some(s) => *s,
+        None => return order, // no right-to-left items
+    };
+
+    for level in (lowest_odd_level..=max_level).rev() {
+        let mut i = 0;
+        while i < order.len() {
+            if levels[order[i]] < level {
+                i += 1;
+                continue;
+            }
+            let run_start = i;
+            while i < order.len() && levels[order[i]] >= level {
+                i += 1;
+            }
+            order[run_start..i].reverse();
+        }
+    }
+
+    order
+}
+
+/// Reorders the words of each line from logical into visual order, so that
+/// right-to-left runs are displayed from right to left. Only the horizontal
+/// position of the words is changed, the line breaks are not affected.
+fn reorder_words_visually(words: &Words, word_positions: &mut [WordPosition], line_breaks: &[InlineTextLine]) {
+
+    use self::WordType::*;
+
+    if !words.has_rtl() {
+        return;
+    }
+
+    let word_items = words.items.as_ref();
+
+    for line in line_breaks {
+
+        let word_end = line.word_end.min(word_items.len().saturating_sub(1)).min(word_positions.len().saturating_sub(1));
+        if line.word_start > word_end {
+            continue;
+        }
+
+        let line_words = (line.word_start..=word_end).collect::<Vec<usize>>();
+
+        let mut levels = line_words.iter()
+        .map(|word_idx| words.get_word_bidi_level(&word_items[*word_idx]))
+        .collect::<Vec<u8>>();
+
+        // L1: trailing whitespace is reset to the paragraph level
+        for (i, word_idx) in line_words.iter().enumerate().rev() {
+            let word = &word_items[*word_idx];
+            match word.word_type {
+                Space | Tab | Return | ZeroWidthSpace | BreakOpportunity => {
+                    levels[i] = words.get_paragraph_bidi_level(word.start);
+                },
+                Word | NonBreakingSpace => break,
+            }
+        }
+
+        let line_start_x = line_words.iter()
+        .map(|word_idx| word_positions[*word_idx].position.x)
+        .fold(f32::MAX, f32::min);
+
+        let mut x = line_start_x;
+        for logical_index in reorder_visual(&levels) {
+            let word_position = &mut word_positions[line_words[logical_index]];
+            word_position.position.x = x;
+            x += word_position.size.width;
+        }
     }
 }
 
 /// Takes a text broken into semantic items and shape all the words
 /// (does NOT scale the words, only shapes them)
 pub fn shape_words(words: &Words, font: &ParsedFont) -> ShapedWords {
+    shape_words_with_fallback(words, font, &[])
+}
+
+/// Same as `shape_words`, but characters that are not contained in the `font`
+/// are shaped with the first of the `fallback_fonts` that contains them.
+///
+/// The font metrics (space width, ascender, etc.) are always the metrics of
+/// `font`, the glyphs of the fallback fonts are scaled to the units per em of `font`.
+pub fn shape_words_with_fallback(words: &Words, font: &ParsedFont, fallback_fonts: &[&ParsedFont]) -> ShapedWords {
 
     use crate::text_shaping;
 
@@ -151,7 +396,16 @@ pub fn shape_words(words: &Words, font: &ParsedFont) -> ShapedWords {
         use crate::text_shaping::ShapedTextBufferUnsized;
 
         let chars = &words.internal_chars.as_ref()[word.start..word.end];
-        let shaped_word = font.shape(chars, script, lang);
+
+        // mixed-direction text: the script of the entire text
+        // is not necessarily the script of the current word
+        let (script, lang) = if words.has_rtl() {
+            text_shaping::estimate_script_and_language(&words.get_substr(word))
+        } else {
+            (script, lang)
+        };
+
+        let shaped_word = shape_with_fallback(chars, script, lang, font, fallback_fonts);
         let word_width = shaped_word.get_word_visual_width_unscaled();
 
         longest_word_width = longest_word_width.max(word_width);
@@ -175,6 +429,130 @@ pub fn shape_words(words: &Words, font: &ParsedFont) -> ShapedWords {
     }
 }
 
+/// Shapes the characters of a single word, splitting the word into runs of
+/// characters which are shaped with the same font (see `shape_words_with_fallback`)
+fn shape_with_fallback(
+    chars: &[u32],
+    script: u32,
+    lang: Option<u32>,
+    font: &ParsedFont,
+    fallback_fonts: &[&ParsedFont],
+) -> crate::text_shaping::ShapedTextBufferUnsized {
+
+    use crate::text_shaping::ShapedTextBufferUnsized;
+
+    if fallback_fonts.is_empty() {
+        return font.shape(chars, script, lang);
+    }
+
+    // 0 = font, 1.. = fallback fonts
+    let get_font = |font_index: usize| if font_index == 0 { font } else { fallback_fonts[font_index - 1] };
+    let has_glyph = |font_index: usize, c: u32| {
+        // glyph 0 is the .notdef glyph
+        get_font(font_index).lookup_glyph_index(c).map(|glyph_index| glyph_index != 0).unwrap_or(false)
+    };
+
+    // (font index, start, end) of runs of characters with the same font
+    let mut runs = Vec::<(usize, usize, usize)>::new();
+
+    for (char_idx, c) in chars.iter().enumerate() {
+
+        let current_font_index = runs.last().map(|r| r.0);
+
+        // combining characters have to be shaped with the same font as their base character
+        let font_index = match current_font_index {
+            Some(f) if continues_cluster(*c) => f,
+            _ => (0..=fallback_fonts.len())
+                .find(|f| has_glyph(*f, *c))
+                .or(current_font_index)
+                .unwrap_or(0),
+        };
+
+        match runs.last_mut() {
+            Some(run) if run.0 == font_index => { run.2 = char_idx + 1; },
+            _ => { runs.push((font_index, char_idx, char_idx + 1)); },
+        }
+    }
+
+    // fast path: no fallback necessary
+    if runs.iter().all(|(font_index, _, _)| *font_index == 0) {
+        return font.shape(chars, script, lang);
+    }
+
+    let units_per_em = font.font_metrics.units_per_em;
+    let mut infos = Vec::new();
+
+    for (font_index, start, end) in runs {
+        let run_font = get_font(font_index);
+        let scale = units_per_em as f32 / run_font.font_metrics.units_per_em.max(1) as f32;
+        let glyph_index_offset = infos.len();
+        let ShapedTextBufferUnsized { infos: run_infos } = run_font.shape(&chars[start..end], script, lang);
+        infos.extend(run_infos.into_iter().map(|mut info| {
+            rescale_glyph_info(&mut info, scale, glyph_index_offset);
+            info.fallback_font_index = font_index;
+            info
+        }));
+    }
+
+    ShapedTextBufferUnsized { infos }
+}
+
+/// Returns whether the character attaches to the previous character
+/// (combining marks, zero-width joiner, variation selectors, emoji modifiers)
+fn continues_cluster(c: u32) -> bool {
+    match c {
+        0x0300..=0x036F |   // combining diacritical marks
+        0x1AB0..=0x1AFF |   // combining diacritical marks extended
+        0x1DC0..=0x1DFF |   // combining diacritical marks supplement
+        0x20D0..=0x20FF |   // combining diacritical marks for symbols
+        0xFE20..=0xFE2F |   // combining half marks
+        0x200D |            // zero-width joiner
+        0xFE00..=0xFE0F |   // variation selectors
+        0x1F3FB..=0x1F3FF | // emoji skin tone modifiers
+        0xE0020..=0xE007F | // tags
+        0xE0100..=0xE01EF   // variation selectors supplement
+        => true,
+        _ => false,
+    }
+}
+
+/// Scales the metrics of a glyph shaped with a fallback font to the units per em
+/// of the first font and offsets the glyph indices of attachments (since the
+/// glyphs of multiple runs are merged into one word)
+fn rescale_glyph_info(info: &mut GlyphInfo, scale: f32, glyph_index_offset: usize) {
+
+    let scale_i32 = |v: i32| (v as f32 * scale).round() as i32;
+    let scale_i16 = |v: i16| (v as f32 * scale).round() as i16;
+    let scale_anchor = |a: &mut Anchor| { a.x = scale_i16(a.x); a.y = scale_i16(a.y); };
+
+    info.size.advance_x = (info.size.advance_x as f32 * scale).round() as u16;
+    info.size.size_x = scale_i32(info.size.size_x);
+    info.size.size_y = scale_i32(info.size.size_y);
+    info.size.kerning = scale_i16(info.size.kerning);
+
+    if let Placement::Distance(distance) = &mut info.placement {
+        distance.x = scale_i32(distance.x);
+        distance.y = scale_i32(distance.y);
+    }
+
+    match &mut info.attachment {
+        Attachment::None => { },
+        Attachment::MarkAnchor(mark) => {
+            mark.base_glyph_index += glyph_index_offset;
+            scale_anchor(&mut mark.base_glyph_anchor);
+            scale_anchor(&mut mark.mark_anchor);
+        },
+        Attachment::MarkOverprint(base_glyph_index) => {
+            *base_glyph_index += glyph_index_offset;
+        },
+        Attachment::CursiveAnchor(cursive) => {
+            cursive.exit_glyph_index += glyph_index_offset;
+            scale_anchor(&mut cursive.exit_glyph_anchor);
+            scale_anchor(&mut cursive.entry_glyph_anchor);
+        },
+    }
+}
+
 /// Positions the words on the screen (does not layout any glyph positions!), necessary for estimating
 /// the intrinsic width + height of the text content.
 pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_options: &ResolvedTextLayoutOptions) -> WordPositions {
@@ -182,8 +560,6 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
     use self::WordType::*;
     use self::LineCaretIntersection::*;
     use core::f32;
-    use azul_core::app_resources::WordPosition;
-    use azul_core::ui_solver::InlineTextLine;
 
     let font_size_px = text_layout_options.font_size_px;
     let space_advance_px = shaped_words.get_space_advance_px(text_layout_options.font_size_px);
@@ -192,6 +568,15 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
     let tab_width_px = space_advance_px * text_layout_options.tab_width.as_ref().copied().unwrap_or(DEFAULT_TAB_WIDTH);
     let spacing_multiplier = text_layout_options.letter_spacing.as_ref().copied().unwrap_or(0.0);
 
+    // The caret y of a line is at the bottom of the line, so a line overlaps
+    // a hole until its caret is one line below the bottom of the hole
+    let holes = text_layout_options.holes.as_ref().iter().map(|hole| {
+        LogicalRect::new(
+            LogicalPosition::new(hole.origin.x as f32, hole.origin.y as f32),
+            LogicalSize::new(hole.size.width as f32, hole.size.height as f32 + font_size_px + line_height_px),
+        )
+    }).collect::<Vec<_>>();
+
     let mut line_breaks = Vec::new();
     let mut word_positions = Vec::new();
     let mut line_caret_x = text_layout_options.leading.as_ref().copied().unwrap_or(0.0);
@@ -202,6 +587,15 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
 
     let last_word_idx = words.items.len().saturating_sub(1);
 
+    let get_shaped_word_width = |shaped_word: &ShapedWord| {
+        let letter_spacing_px = spacing_multiplier * shaped_word
+        .number_of_glyphs().saturating_sub(1) as f32;
+        shaped_word.get_word_width(
+            shaped_words.font_metrics_units_per_em,
+            text_layout_options.font_size_px
+        ) + letter_spacing_px
+    };
+
     // The last word is a bit special: Any text must have at least one line break!
     for (word_idx, word) in words.items.iter().enumerate() {
         match word.word_type {
@@ -213,23 +607,81 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
                     None => continue,
                 };
 
-                let letter_spacing_px = spacing_multiplier * shaped_word
-                .number_of_glyphs().saturating_sub(1) as f32;
-
                 // Calculate where the caret would be for the next word
-                let shaped_word_width = shaped_word.get_word_width(
-                    shaped_words.font_metrics_units_per_em,
-                    text_layout_options.font_size_px
-                ) + letter_spacing_px;
+                let shaped_word_width = get_shaped_word_width(shaped_word);
+
+                // The line may only be broken before this word if the previous item
+                // is a break opportunity (i.e. not if the words are joined by a
+                // non-breaking space or the word is a continuation of the previous word)
+                let can_break_before = word_idx == 0 || words.items
+                    .get(word_idx - 1)
+                    .map(|w| w.word_type.is_break_opportunity())
+                    .unwrap_or(true);
 
                 // Determine if a line break is necessary
-                let caret_intersection = LineCaretIntersection::new(
-                    line_caret_x,
-                    shaped_word_width,
-                    line_caret_y,
-                    font_size_px + line_height_px,
-                    text_layout_options.max_horizontal_width.as_ref().copied(),
-                );
+                let caret_intersection = if can_break_before {
+
+                    // words that can't be broken apart have to fit on the line as a whole
+                    let mut cluster_width = shaped_word_width;
+                    let mut next_shaped_word_idx = shaped_word_idx + 1;
+                    for next_word in words.items.as_ref()[(word_idx + 1)..].iter() {
+                        match next_word.word_type {
+                            Word => {
+                                cluster_width += shaped_words.items
+                                    .get(next_shaped_word_idx)
+                                    .map(|w| get_shaped_word_width(w))
+                                    .unwrap_or(0.0);
+                                next_shaped_word_idx += 1;
+                            },
+                            NonBreakingSpace => { cluster_width += word_spacing_px; },
+                            _ => break,
+                        }
+                    }
+
+                    match LineCaretIntersection::new(
+                        line_caret_x,
+                        cluster_width,
+                        line_caret_y,
+                        font_size_px + line_height_px,
+                        text_layout_options.max_horizontal_width.as_ref().copied(),
+                    ) {
+                        NoLineBreak { new_y, .. } => NoLineBreak { new_x: line_caret_x + shaped_word_width, new_y },
+                        other => other,
+                    }
+                } else {
+                    NoLineBreak { new_x: line_caret_x + shaped_word_width, new_y: line_caret_y }
+                };
+
+                // Move the word out of the holes (i.e. floating boxes) of the text
+                let caret_intersection = if can_break_before && !holes.is_empty() {
+
+                    let (word_x, word_y) = match caret_intersection {
+                        LineBreak { new_x, new_y } => (new_x, new_y),
+                        _ => (line_caret_x, line_caret_y),
+                    };
+
+                    let line_box_height = font_size_px + line_height_px;
+                    let line_number = ((word_y / line_box_height).round() as usize).saturating_sub(1);
+                    let (word_x, word_line) = get_word_position_around_holes(
+                        word_x,
+                        line_number,
+                        shaped_word_width,
+                        font_size_px,
+                        line_height_px,
+                        &holes,
+                        text_layout_options.max_horizontal_width.as_ref().copied(),
+                    );
+                    let word_y = (word_line + 1) as f32 * line_box_height;
+
+                    if word_y == line_caret_y {
+                        line_caret_x = word_x;
+                        NoLineBreak { new_x: word_x + shaped_word_width, new_y: word_y }
+                    } else {
+                        LineBreak { new_x: word_x, new_y: word_y }
+                    }
+                } else {
+                    caret_intersection
+                };
 
                 // Correct and advance the line caret position
                 match caret_intersection {
@@ -262,6 +714,7 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
                         line_caret_x = new_x + shaped_word_width; // add word width for the next word
                         line_caret_y = new_y;
                     },
+                    NoIntersection | AdvanceCaretTo(_) | PushCaretOntoNextLine(..) => { }, // only returned for holes
                 }
 
                 shaped_word_idx += 1;
@@ -290,6 +743,23 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
                     line_caret_y = line_caret_y + font_size_px + line_height_px;
                 }
             },
+            NonBreakingSpace => {
+                // never break the line at a non-breaking space, the following
+                // word was already accounted for when positioning the previous word
+                word_positions.push(WordPosition {
+                    shaped_word_index: None,
+                    position: LogicalPosition::new(line_caret_x, line_caret_y),
+                    size: LogicalSize::new(word_spacing_px, font_size_px + line_height_px),
+                });
+                line_caret_x += word_spacing_px;
+            },
+            ZeroWidthSpace | BreakOpportunity => {
+                word_positions.push(WordPosition {
+                    shaped_word_index: None,
+                    position: LogicalPosition::new(line_caret_x, line_caret_y),
+                    size: LogicalSize::new(0.0, font_size_px + line_height_px),
+                });
+            },
             Space | Tab => {
                 let x_advance = match word.word_type {
                     Space => word_spacing_px,
@@ -338,6 +808,7 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
                             line_caret_y = new_y;
                         }
                     },
+                    NoIntersection | AdvanceCaretTo(_) | PushCaretOntoNextLine(..) => { }, // only returned for holes
                 }
             }
         }
@@ -352,11 +823,14 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
         ),
     });
 
+    reorder_words_visually(words, &mut word_positions, &line_breaks);
+
     let longest_line_width = line_breaks.iter()
     .map(|line| line.bounds.size.width)
     .fold(0.0_f32, f32::max);
 
-    let content_size_y = line_breaks.len() as f32 * (font_size_px + line_height_px);
+    // lines that are skipped because of holes don't have a line break
+    let content_size_y = line_caret_y.max(line_breaks.len() as f32 * (font_size_px + line_height_px));
     let content_size_x = text_layout_options.max_horizontal_width.as_ref().copied().unwrap_or(longest_line_width);
     let content_size = LogicalSize::new(content_size_x, content_size_y);
 
@@ -387,6 +861,13 @@ enum LineCaretIntersection {
     /// Caret needs to advance X number of lines and be positioned
     /// with a leading of x
     LineBreak { new_x: f32, new_y: f32 },
+    /// The caret is not inside of any hole
+    NoIntersection,
+    /// The caret is inside of a hole and has to be advanced to x (on the same line)
+    AdvanceCaretTo(f32),
+    /// There is no space left on the current line: the caret has to be pushed
+    /// N lines down and positioned with a leading of x
+    PushCaretOntoNextLine(usize, f32),
 }
 
 impl LineCaretIntersection {
@@ -427,18 +908,125 @@ impl LineCaretIntersection {
     }
 }
 
+/// Returns whether the caret on the given line is inside of one of the holes. The line
+/// occupies the holes from its caret y (`(line_number + 1) * line height`) downwards.
+///
+/// If the caret is advanced past the max width, the caret is pushed onto the next line
+fn caret_intersects_with_holes(
+    line_caret_x: f32,
+    line_number: usize,
+    font_size_px: f32,
+    line_height_px: f32,
+    holes: &[LogicalRect],
+    max_width: Option<f32>,
+) -> LineCaretIntersection {
+
+    let line_box_height = font_size_px + line_height_px;
+    let mut caret_x = line_caret_x;
+    let mut current_line = line_number;
+
+    loop {
+        let caret_y = (current_line + 1) as f32 * line_box_height;
+
+        let hole_at_caret = holes.iter().find(|hole| {
+            caret_x >= hole.origin.x &&
+            caret_x < hole.origin.x + hole.size.width &&
+            caret_y >= hole.origin.y &&
+            caret_y < hole.origin.y + hole.size.height
+        });
+
+        if let Some(hole) = hole_at_caret {
+            caret_x = hole.origin.x + hole.size.width;
+            continue;
+        }
+
+        // no space left on this line, continue at the start of the next line
+        let caret_is_past_max_width = caret_x > 0.0 && max_width.map(|max| caret_x >= max).unwrap_or(false);
+        if caret_is_past_max_width {
+            current_line += 1;
+            caret_x = 0.0;
+            continue;
+        }
+
+        break;
+    }
+
+    if current_line != line_number {
+        LineCaretIntersection::PushCaretOntoNextLine(current_line - line_number, caret_x)
+    } else if caret_x != line_caret_x {
+        LineCaretIntersection::AdvanceCaretTo(caret_x)
+    } else {
+        LineCaretIntersection::NoIntersection
+    }
+}
+
+/// Returns the x position and the line number where a word with the given width can be
+/// placed without overlapping any of the holes, starting the search at the current caret
+fn get_word_position_around_holes(
+    line_caret_x: f32,
+    line_number: usize,
+    word_width: f32,
+    font_size_px: f32,
+    line_height_px: f32,
+    holes: &[LogicalRect],
+    max_width: Option<f32>,
+) -> (f32, usize) {
+
+    use self::LineCaretIntersection::*;
+
+    let line_box_height = font_size_px + line_height_px;
+    let mut caret_x = line_caret_x;
+    let mut current_line = line_number;
+
+    loop {
+        match caret_intersects_with_holes(caret_x, current_line, font_size_px, line_height_px, holes, max_width) {
+            AdvanceCaretTo(new_x) => { caret_x = new_x; },
+            PushCaretOntoNextLine(lines, new_x) => { current_line += lines; caret_x = new_x; },
+            NoIntersection | NoLineBreak { .. } | LineBreak { .. } => { },
+        }
+
+        // the word would run into the next hole on this line: skip the hole
+        let caret_y = (current_line + 1) as f32 * line_box_height;
+        let next_hole_end = holes.iter()
+        .filter(|hole| {
+            caret_y >= hole.origin.y &&
+            caret_y < hole.origin.y + hole.size.height &&
+            hole.origin.x >= caret_x &&
+            hole.origin.x < caret_x + word_width
+        })
+        .map(|hole| hole.origin.x + hole.size.width)
+        .fold(None, |max: Option<f32>, end| Some(max.map(|m| m.max(end)).unwrap_or(end)));
+
+        if let Some(hole_end) = next_hole_end {
+            caret_x = hole_end;
+            continue;
+        }
+
+        // the word is wider than the space left on this line - words that
+        // don't even fit at the start of an empty line overflow the line
+        let word_overflows_line = caret_x > 0.0 && max_width.map(|max| caret_x + word_width > max).unwrap_or(false);
+        if word_overflows_line {
+            current_line += 1;
+            caret_x = 0.0;
+            continue;
+        }
+
+        return (caret_x, current_line);
+    }
+}
+
 #[test]
 fn test_split_words() {
 
     fn print_words(w: &Words) {
         println!("-- string: {:?}", w.get_str());
-        for item in &w.items {
+        for item in w.items.iter() {
             println!("{:?} - ({}..{}) = {:?}", w.get_substr(item), item.start, item.end, item.word_type);
         }
     }
 
-    fn string_to_vec(s: String) -> Vec<char> {
-        s.chars().collect()
+    fn string_to_vec(s: String) -> Vec<u32> {
+        s.chars().map(|c| c as u32).collect()
     }
 
     fn assert_words(expected: &Words, got_words: &Words) {
@@ -457,8 +1045,10 @@ fn test_split_words() {
     let ascii_str = String::from("abc\tdef  \nghi\r\njkl");
     let words_ascii = split_text_into_words(&ascii_str);
     let words_ascii_expected = Words {
-        internal_str: ascii_str.clone(),
-        internal_chars: string_to_vec(ascii_str),
+        internal_str: ascii_str.clone().into(),
+        internal_chars: string_to_vec(ascii_str).into(),
+        bidi_levels: Vec::new().into(),
+        bidi_paragraph_levels: Vec::new().into(),
         items: vec![
             Word { start: 0,    end: 3,     word_type: WordType::Word     }, // "abc" - (0..3) = Word
             Word { start: 3,    end: 4,     word_type: WordType::Tab      }, // "\t" - (3..4) = Tab
@@ -469,21 +1059,32 @@ fn test_split_words() {
             Word { start: 10,   end: 13,    word_type: WordType::Word     }, // "ghi" - (10..13) = Word
             Word { start: 13,   end: 15,    word_type: WordType::Return   }, // "\r\n" - (13..15) = Return
             Word { start: 15,   end: 18,    word_type: WordType::Word     }, // "jkl" - (15..18) = Word
-        ],
+        ].into(),
     };
 
     assert_words(&words_ascii_expected, &words_ascii);
 
+    // CJK ideographs can be broken between any two characters
     let unicode_str = String::from("㌊㌋㌌㌍㌎㌏㌐㌑ ㌒㌓㌔㌕㌖㌗");
     let words_unicode = split_text_into_words(&unicode_str);
+    let mut unicode_items = Vec::new();
+    for (start, end) in [(0, 8), (9, 15)].iter().copied() {
+        for i in start..end {
+            if i != start {
+                unicode_items.push(Word { start: i, end: i, word_type: WordType::BreakOpportunity });
+            }
+            unicode_items.push(Word { start: i, end: i + 1, word_type: WordType::Word });
+        }
+        if end == 8 {
+            unicode_items.push(Word { start: 8, end: 9, word_type: WordType::Space }); // " "
+        }
+    }
     let words_unicode_expected = Words {
-        internal_str: unicode_str.clone(),
-        internal_chars: string_to_vec(unicode_str),
-        items: vec![
-            Word { start: 0,        end: 8,         word_type: WordType::Word   }, // "㌊㌋㌌㌍㌎㌏㌐㌑"
-            Word { start: 8,        end: 9,         word_type: WordType::Space  }, // " "
-            Word { start: 9,        end: 15,        word_type: WordType::Word   }, // "㌒㌓㌔㌕㌖㌗"
-        ],
+        internal_str: unicode_str.clone().into(),
+        internal_chars: string_to_vec(unicode_str).into(),
+        bidi_levels: Vec::new().into(),
+        bidi_paragraph_levels: Vec::new().into(),
+        items: unicode_items.into(),
     };
 
     assert_words(&words_unicode_expected, &words_unicode);
@@ -491,16 +1092,81 @@ fn test_split_words() {
     let single_str = String::from("A");
     let words_single_str = split_text_into_words(&single_str);
     let words_single_str_expected = Words {
-        internal_str: single_str.clone(),
-        internal_chars: string_to_vec(single_str),
+        internal_str: single_str.clone().into(),
+        internal_chars: string_to_vec(single_str).into(),
+        bidi_levels: Vec::new().into(),
+        bidi_paragraph_levels: Vec::new().into(),
         items: vec![
             Word { start: 0,        end: 1,         word_type: WordType::Word   }, // "A"
-        ],
+        ].into(),
     };
 
     assert_words(&words_single_str_expected, &words_single_str);
 }
 
+#[test]
+fn test_split_words_line_breaking() {
+
+    let words = split_text_into_words("well-known a\u{00A0}b c\u{200B}d");
+    let word_types = words.items.iter().map(|w| (w.start, w.end, w.word_type)).collect::<Vec<_>>();
+
+    assert_eq!(word_types, vec![
+        (0,     10,     WordType::Word),                // "well-known"
+        (10,    11,     WordType::Space),               // " "
+        (11,    12,     WordType::Word),                // "a"
+        (12,    13,     WordType::NonBreakingSpace),    // "\u{00A0}"
+        (13,    14,     WordType::Word),                // "b"
+        (14,    15,     WordType::Space),               // " "
+        (15,    16,     WordType::Word),                // "c"
+        (16,    17,     WordType::ZeroWidthSpace),      // "\u{200B}"
+        (17,    18,     WordType::Word),                // "d"
+    ]);
+
+    // SA scripts (Thai, Lao, ...) are not broken inside of words (no dictionary)
+    let words = split_text_into_words("ภาษาไทย ดี");
+    let word_types = words.items.iter().map(|w| (w.start, w.end, w.word_type)).collect::<Vec<_>>();
+
+    assert_eq!(word_types, vec![
+        (0,     7,      WordType::Word),                // "ภาษาไทย"
+        (7,     8,      WordType::Space),               // " "
+        (8,     10,     WordType::Word),                // "ดี"
+    ]);
+
+    assert!(!WordType::Word.is_break_opportunity());
+    assert!(!WordType::NonBreakingSpace.is_break_opportunity());
+    assert!(WordType::ZeroWidthSpace.is_break_opportunity());
+    assert!(WordType::BreakOpportunity.is_break_opportunity());
+}
+
+#[test]
+fn test_bidi_levels() {
+
+    // pure LTR text: fast path, no levels
+    let (levels, paragraph_levels) = get_bidi_levels("abc def");
+    assert!(levels.is_empty());
+    assert!(paragraph_levels.is_empty());
+
+    // LTR paragraph with an embedded hebrew word
+    let (levels, paragraph_levels) = get_bidi_levels("ab \u{5D0}\u{5D1}");
+    assert_eq!(levels, vec![0, 0, 0, 1, 1]);
+    assert_eq!(paragraph_levels, vec![0, 0, 0, 0, 0]);
+
+    // RTL paragraph (first strong character is hebrew) with an embedded latin word
+    let (levels, paragraph_levels) = get_bidi_levels("\u{5D0} ab");
+    assert_eq!(levels, vec![1, 1, 2, 2]);
+    assert_eq!(paragraph_levels, vec![1, 1, 1, 1]);
+}
+
+#[test]
+fn test_reorder_visual() {
+    assert_eq!(reorder_visual(&[]), Vec::<usize>::new());
+    assert_eq!(reorder_visual(&[0, 0, 0]), vec![0, 1, 2]);
+    assert_eq!(reorder_visual(&[1, 1, 1]), vec![2, 1, 0]);
+    assert_eq!(reorder_visual(&[0, 1, 1, 0]), vec![0, 2, 1, 3]);
+    // LTR run nested inside of an RTL paragraph keeps its order
+    assert_eq!(reorder_visual(&[1, 2, 2, 1]), vec![3, 1, 2, 0]);
+}
+
 // Scenario 1:
 //
 // +---------+
@@ -637,3 +1303,115 @@ fn test_caret_intersects_with_holes_4() {
 
     assert_eq!(result, LineCaretIntersection::NoIntersection);
 }
+
+// Words that don't fit next to a hole (floating box) are moved below it
+#[test]
+fn test_word_position_around_holes() {
+    let font_size_px = 20.0;
+    let line_height_px = 0.0;
+    let max_width = Some(300.0);
+    let left_hole = vec![LogicalRect::new(LogicalPosition::new(0.0, 0.0), LogicalSize::new(100.0, 50.0))];
+    let right_hole = vec![LogicalRect::new(LogicalPosition::new(200.0, 0.0), LogicalSize::new(100.0, 50.0))];
+
+    // word fits next to the hole
+    assert_eq!(get_word_position_around_holes(0.0, 0, 150.0, font_size_px, line_height_px, &left_hole, max_width), (100.0, 0));
+    // word is too wide for the space next to the hole: continue on the first line below the hole
+    assert_eq!(get_word_position_around_holes(0.0, 0, 250.0, font_size_px, line_height_px, &left_hole, max_width), (0.0, 2));
+    // word would run into a hole on the right: break the line
+    assert_eq!(get_word_position_around_holes(0.0, 0, 150.0, font_size_px, line_height_px, &right_hole, max_width), (0.0, 0));
+    assert_eq!(get_word_position_around_holes(100.0, 0, 150.0, font_size_px, line_height_px, &right_hole, max_width), (0.0, 1));
+}
+
+// Hyphenated latin words have to be positioned as one word, the same as
+// before break opportunities inside of words were taken into account
+#[test]
+fn test_position_words_latin_hyphen() {
+
+    let words = split_text_into_words("The well-known fox");
+
+    // every character is 10px wide (at 10px font size), so are spaces
+    let shaped_words = ShapedWords {
+        items: words.items.iter()
+            .filter(|w| w.word_type == WordType::Word)
+            .map(|w| ShapedWord { glyph_infos: Vec::new().into(), word_width: (w.end - w.start) * 1000 })
+            .collect::<Vec<_>>()
+            .into(),
+        longest_word_width: 10_000,
+        space_advance: 1000,
+        font_metrics_units_per_em: 1000,
+        font_metrics_ascender: 0,
+        font_metrics_descender: 0,
+        font_metrics_line_gap: 0,
+    };
+
+    let font_size_px = 10.0;
+    let word_spacing_px = 10.0 * DEFAULT_WORD_SPACING;
+    let line_box_height = font_size_px + 10.0 * DEFAULT_LINE_HEIGHT;
+
+    // "The well-" would fit on the first line, "The well-known" does not
+    let options = ResolvedTextLayoutOptions {
+        font_size_px,
+        max_horizontal_width: Some(30.0 + word_spacing_px + 50.0).into(),
+        .. Default::default()
+    };
+
+    let word_positions = position_words(&words, &shaped_words, &options);
+    let positions = word_positions.word_positions.iter()
+        .map(|p| (p.shaped_word_index, p.position.x, p.position.y, p.size.width))
+        .collect::<Vec<_>>();
+
+    assert_eq!(positions, vec![
+        (Some(0), 0.0, line_box_height, 30.0),              // "The"
+        (None, 30.0, line_box_height, word_spacing_px),     // " "
+        (Some(1), 0.0, line_box_height * 2.0, 100.0),       // "well-known"
+        (None, 100.0, line_box_height * 2.0, word_spacing_px), // " "
+        (Some(2), 0.0, line_box_height * 3.0, 30.0),        // "fox"
+    ]);
+}
+
+#[cfg(test)]
+fn load_test_font(file_name: &str) -> ParsedFont {
+    let path = format!("{}/../examples/assets/fonts/{}", env!("CARGO_MANIFEST_DIR"), file_name);
+    let bytes = std::fs::read(&path).unwrap();
+    ParsedFont::from_bytes(&bytes, 0, false).unwrap()
+}
+
+#[test]
+fn test_shape_with_fallback_splits_runs() {
+
+    use crate::text_shaping;
+
+    // neither SourceSerifPro nor WeblySleek have Thai glyphs,
+    // so the Thai characters are shaped with KoHo
+    let font = load_test_font("SourceSerifPro-Regular.ttf");
+    let latin = load_test_font("weblysleekuil.ttf");
+    let thai = load_test_font("KoHo-Light.ttf");
+
+    let text = "abกข";
+    let chars = text.chars().map(|c| c as u32).collect::<Vec<_>>();
+    let (script, lang) = text_shaping::estimate_script_and_language(text);
+
+    let shaped = shape_with_fallback(&chars, script, lang, &font, &[&latin, &thai]);
+    let font_indices = shaped.infos.iter().map(|i| i.fallback_font_index).collect::<Vec<_>>();
+
+    assert_eq!(font_indices, vec![0, 0, 2, 2]);
+}
+
+#[test]
+fn test_shape_with_fallback_uses_primary_font_if_no_fallback_has_glyph() {
+
+    use crate::text_shaping;
+
+    let font = load_test_font("SourceSerifPro-Regular.ttf");
+    let latin = load_test_font("weblysleekuil.ttf");
+
+    // neither font has the Thai glyph: it is shaped with the
+    // primary font (as .notdef) instead of splitting the word
+    for text in ["ก", "abก"].iter() {
+        let chars = text.chars().map(|c| c as u32).collect::<Vec<_>>();
+        let (script, lang) = text_shaping::estimate_script_and_language(text);
+        let shaped = shape_with_fallback(&chars, script, lang, &font, &[&latin]);
+        assert_eq!(shaped.infos.len(), chars.len());
+        assert!(shaped.infos.iter().all(|i| i.fallback_font_index == 0));
+    }
+}
@@ -290,6 +290,17 @@ impl ttf_parser::OutlineBuilder for GlyphOutlineBuilder {
     fn close(&mut self) { self.operations.push(GlyphOutlineOperation::ClosePath); }
 }
 
+/// Parses the outline of a single glyph directly from the font bytes
+///
+/// Fonts are usually loaded without outlines (to save memory), so this is
+/// used by renderers that need to rasterize glyphs on demand
+pub fn get_glyph_outline(font_bytes: &[u8], font_index: u32, glyph_index: u16) -> Option<GlyphOutline> {
+    let face = ttf_parser::Face::from_slice(font_bytes, font_index).ok()?;
+    let mut outline = GlyphOutlineBuilder::default();
+    face.outline_glyph(ttf_parser::GlyphId(glyph_index), &mut outline)?;
+    Some(GlyphOutline { operations: outline.operations.into() })
+}
+
 impl_vec!(GlyphOutlineOperation, GlyphOutlineOperationVec, GlyphOutlineOperationVecDestructor);
 impl_vec_clone!(GlyphOutlineOperation, GlyphOutlineOperationVec, GlyphOutlineOperationVecDestructor);
 impl_vec_debug!(GlyphOutlineOperation, GlyphOutlineOperationVec);
@@ -844,6 +855,7 @@ fn translate_info(i: &allsorts_no_std::gpos::Info, size: Advance) -> GlyphInfo {
         size,
         placement: translate_placement(&i.placement),
         attachment: translate_attachment(&i.attachment),
+        fallback_font_index: 0,
     }
 }
 
@@ -0,0 +1,1949 @@
+//! CPU rasterizer for `CachedDisplayList`s
+//!
+//! Renders the same primitives as the webrender backend (text, backgrounds,
+//! gradients, images, borders, box shadows, clips, transforms, opacity and
+//! image masks) into an RGBA8 `RawImage` using tiny-skia. This makes it
+//! possible to take screenshots without a GPU (headless windows, `azulc --render`
+//! and the reftests), but the output is not guaranteed to be identical to
+//! the output of webrender.
+
+use azul_core::{
+    FastHashMap,
+    app_resources::{ImageKey, ImageDescriptor, ImageData, RawImage, RendererResources},
+    display_list::CachedDisplayList,
+    window::ScrollStates,
+};
+use azul_css::ColorU;
+
+/// Everything (except the display list itself) that is needed to render a frame
+pub struct CpuRenderOptions<'a> {
+    /// Width of the output image, in physical pixels
+    pub width: usize,
+    /// Height of the output image, in physical pixels
+    pub height: usize,
+    /// Ratio between logical and physical pixels
+    pub hidpi_factor: f32,
+    /// Color to clear the image with before rendering
+    pub background_color: ColorU,
+    /// Fonts (and their font instances) referenced by the text items
+    pub renderer_resources: &'a RendererResources,
+    /// Image data referenced by the `ImageKey`s in the display list
+    pub images: &'a FastHashMap<ImageKey, (ImageDescriptor, ImageData)>,
+    /// Current scroll positions of the scroll frames
+    pub scroll_states: &'a ScrollStates,
+}
+
+/// Renders the display list into a (non-premultiplied) RGBA8 image,
+/// returns `None` if the output size is zero
+#[cfg(feature = "svg")]
+pub fn render_display_list(display_list: &CachedDisplayList, options: &CpuRenderOptions) -> Option<RawImage> {
+    skia::render_display_list(display_list, options)
+}
+
+#[cfg(not(feature = "svg"))]
+pub fn render_display_list(_display_list: &CachedDisplayList, _options: &CpuRenderOptions) -> Option<RawImage> {
+    None
+}
+
+#[cfg(feature = "svg")]
+mod skia {
+
+    use super::CpuRenderOptions;
+    use alloc::{collections::BTreeMap, rc::Rc, vec::Vec};
+    use azul_core::{
+        app_resources::{
+            ImageKey, ImageDescriptor, ImageData, FontKey, FontInstanceKey,
+            RawImage, RawImageData, RawImageFormat,
+        },
+        display_list::{
+            CachedDisplayList, DisplayListMsg, DisplayListFrame, DisplayListImageMask,
+            LayoutRectContent, RectBackground, GlyphInstance, BoxShadow, StyleBorderRadius,
+            StyleBorderWidths, StyleBorderColors, StyleBorderStyles, ImageRendering,
+        },
+        ui_solver::ComputedTransform3D,
+        window::{LogicalPosition, LogicalRect, LogicalSize},
+    };
+    use azul_css::{
+        ColorU, BorderStyle, BoxShadowClipMode, StyleBoxShadow, CssPropertyValue,
+        LinearGradient, RadialGradient, ConicGradient, ExtendMode, Shape, RadialGradientSize,
+        StyleBackgroundSize, StyleBackgroundPosition, StyleBackgroundRepeat,
+        BackgroundPositionHorizontal, BackgroundPositionVertical,
+    };
+    use tiny_skia::{
+        Pixmap, Paint, Path, PathBuilder, FillRule, Transform, ClipMask, Rect,
+        Pattern, SpreadMode, FilterQuality, PixmapPaint, GradientStop,
+        Point, Color, Stroke, StrokeDash, LineCap, LineJoin,
+    };
+
+    // control point distance for approximating a quarter circle with a cubic bezier
+    const KAPPA: f32 = 0.552_284_8;
+    // tile pixmaps larger than this are not rendered (protects against huge allocations)
+    const MAX_TILE_SIZE: f32 = 8192.0;
+
+    pub(super) fn render_display_list(display_list: &CachedDisplayList, options: &CpuRenderOptions) -> Option<RawImage> {
+
+        let width = options.width as u32;
+        let height = options.height as u32;
+
+        let mut pixmap = Pixmap::new(width, height)?;
+        pixmap.fill(translate_color(options.background_color));
+
+        let mut renderer = Renderer {
+            options,
+            width,
+            height,
+            images: BTreeMap::new(),
+            glyphs: BTreeMap::new(),
+        };
+
+        let root = DrawContext {
+            transform: scale(options.hidpi_factor, options.hidpi_factor),
+            clip: Clip::None,
+        };
+
+        renderer.render_msg(&mut pixmap, &display_list.root, &root, &root, &mut Vec::new());
+
+        // tiny-skia uses premultiplied alpha internally
+        let mut pixels = pixmap.take();
+        for px in pixels.chunks_exact_mut(4) {
+            let a = px[3] as u32;
+            if a != 0 && a != 255 {
+                px[0] = ((px[0] as u32 * 255 + a / 2) / a).min(255) as u8;
+                px[1] = ((px[1] as u32 * 255 + a / 2) / a).min(255) as u8;
+                px[2] = ((px[2] as u32 * 255 + a / 2) / a).min(255) as u8;
+            }
+        }
+
+        Some(RawImage {
+            pixels: RawImageData::U8(pixels.into()),
+            width: options.width,
+            height: options.height,
+            premultiplied_alpha: false,
+            data_format: RawImageFormat::RGBA8,
+        })
+    }
+
+    /// Clip in device space
+    #[derive(Clone)]
+    enum Clip {
+        /// Nothing is clipped
+        None,
+        /// Everything is clipped, nothing has to be drawn
+        Empty,
+        Mask {
+            mask: Rc<ClipMask>,
+            /// Convex polygon that the mask was rasterized from, `None` if the clip
+            /// is not convex (the even-odd clip of outset box shadows).
+            ///
+            /// tiny-skia intersects masks by multiplying the coverage with `(a * b) >> 8`,
+            /// so every intersection would darken fully covered pixels to 254. Convex clips
+            /// are instead intersected geometrically and the mask is rasterized only once.
+            polygon: Option<Rc<Vec<Point>>>,
+        },
+    }
+
+    #[derive(Clone)]
+    struct DrawContext {
+        /// Transforms logical, node-local coordinates into device pixels
+        transform: Transform,
+        clip: Clip,
+    }
+
+    /// Radii of the four corners (horizontal, vertical), in logical pixels
+    #[derive(Debug, Copy, Clone, Default)]
+    struct Radii {
+        top_left: (f32, f32),
+        top_right: (f32, f32),
+        bottom_right: (f32, f32),
+        bottom_left: (f32, f32),
+    }
+
+    impl Radii {
+        fn from_border_radius(border_radius: &StyleBorderRadius, size: LogicalSize) -> Self {
+            let StyleBorderRadius { top_left, top_right, bottom_left, bottom_right } = *border_radius;
+            let (w, h) = (size.width, size.height);
+            let top_left = top_left.and_then(|tl| tl.get_property_or_default()).unwrap_or_default().inner;
+            let top_right = top_right.and_then(|tr| tr.get_property_or_default()).unwrap_or_default().inner;
+            let bottom_left = bottom_left.and_then(|bl| bl.get_property_or_default()).unwrap_or_default().inner;
+            let bottom_right = bottom_right.and_then(|br| br.get_property_or_default()).unwrap_or_default().inner;
+            Radii {
+                top_left: (top_left.to_pixels(w), top_left.to_pixels(h)),
+                top_right: (top_right.to_pixels(w), top_right.to_pixels(h)),
+                bottom_right: (bottom_right.to_pixels(w), bottom_right.to_pixels(h)),
+                bottom_left: (bottom_left.to_pixels(w), bottom_left.to_pixels(h)),
+            }
+        }
+
+        /// Radii of a rect that is shrunk by the given amounts (negative = grow)
+        fn shrink(&self, top: f32, right: f32, bottom: f32, left: f32) -> Self {
+            let s = |(h, v): (f32, f32), dh: f32, dv: f32| -> (f32, f32) {
+                if h <= 0.0 || v <= 0.0 { (0.0, 0.0) } else { ((h - dh).max(0.0), (v - dv).max(0.0)) }
+            };
+            Radii {
+                top_left: s(self.top_left, left, top),
+                top_right: s(self.top_right, right, top),
+                bottom_right: s(self.bottom_right, right, bottom),
+                bottom_left: s(self.bottom_left, left, bottom),
+            }
+        }
+    }
+
+    struct Renderer<'a> {
+        options: &'a CpuRenderOptions<'a>,
+        width: u32,
+        height: u32,
+        /// Decoded images, `None` if the image could not be converted
+        images: BTreeMap<ImageKey, Option<Pixmap>>,
+        /// Glyph outlines in font units (y-up), `None` for empty glyphs
+        glyphs: BTreeMap<(FontKey, u32), Option<Path>>,
+    }
+
+    impl<'a> Renderer<'a> {
+
+        // Mirrors wr_translate::push_display_list_msg: static and relative items are positioned
+        // relative to the parent, absolute items relative to the last positioned item and
+        // fixed items relative to the root
+        fn render_msg(
+            &mut self,
+            target: &mut Pixmap,
+            msg: &DisplayListMsg,
+            parent: &DrawContext,
+            root: &DrawContext,
+            positioned: &mut Vec<DrawContext>,
+        ) {
+            use azul_core::ui_solver::PositionInfo::*;
+
+            let msg_position = msg.get_position();
+
+            let (base, x, y) = match msg_position {
+                Static(p) | Relative(p) => (parent, p.x_offset, p.y_offset),
+                Absolute(p) => (positioned.last().unwrap_or(root), p.x_offset, p.y_offset),
+                Fixed(p) => (root, p.x_offset, p.y_offset),
+            };
+
+            let mut ctx = DrawContext {
+                transform: concat(base.transform, translate(x, y)),
+                clip: base.clip.clone(),
+            };
+
+            if let Some((_, t)) = msg.get_transform_key() {
+                ctx.transform = concat(ctx.transform, translate_computed_transform(t));
+            }
+
+            if let Clip::Empty = ctx.clip {
+                return;
+            }
+
+            let opacity = msg.get_opacity_key().map(|(_, o)| *o).unwrap_or(1.0);
+            let image_mask = msg.get_image_mask();
+
+            if opacity <= 0.0 {
+                return;
+            }
+
+            if msg_position.is_positioned() {
+                positioned.push(ctx.clone());
+            }
+
+            if opacity < 1.0 || image_mask.is_some() {
+                // render the node into a separate layer, then composite it
+                if let Some(mut layer) = Pixmap::new(self.width, self.height) {
+                    self.render_msg_content(&mut layer, msg, &ctx, root, positioned);
+                    if let Some(mask) = image_mask {
+                        self.apply_image_mask(&mut layer, mask, &ctx);
+                    }
+                    let paint = PixmapPaint { opacity, .. PixmapPaint::default() };
+                    let _ = target.draw_pixmap(0, 0, layer.as_ref(), &paint, identity(), None);
+                }
+            } else {
+                self.render_msg_content(target, msg, &ctx, root, positioned);
+            }
+
+            if msg_position.is_positioned() {
+                positioned.pop();
+            }
+        }
+
+        fn render_msg_content(
+            &mut self,
+            target: &mut Pixmap,
+            msg: &DisplayListMsg,
+            ctx: &DrawContext,
+            root: &DrawContext,
+            positioned: &mut Vec<DrawContext>,
+        ) {
+            match msg {
+                DisplayListMsg::IFrame(_, iframe_clip_size, _, cached_display_list) => {
+                    let iframe_root = DrawContext {
+                        transform: ctx.transform,
+                        clip: self.intersect_clip(&ctx.clip, rect_path(0.0, 0.0, iframe_clip_size.width, iframe_clip_size.height), ctx.transform),
+                    };
+                    self.render_msg(target, &cached_display_list.root, &iframe_root, &iframe_root, &mut Vec::new());
+                },
+                DisplayListMsg::Frame(f) => {
+                    self.render_frame(target, f, ctx);
+                    let children_ctx = self.get_children_context(f, ctx);
+                    for child in f.children.iter() {
+                        self.render_msg(target, child, &children_ctx, root, positioned);
+                    }
+                },
+                DisplayListMsg::ScrollFrame(sf) => {
+                    // Only children should scroll, not the frame itself!
+                    self.render_frame(target, &sf.frame, ctx);
+                    let mut children_ctx = self.get_children_context(&sf.frame, ctx);
+                    let scroll_position = self.options.scroll_states.get_scroll_position(&sf.scroll_id).unwrap_or_default();
+                    children_ctx.transform = concat(children_ctx.transform, translate(-scroll_position.x, -scroll_position.y));
+                    for child in sf.frame.children.iter() {
+                        self.render_msg(target, child, &children_ctx, root, positioned);
+                    }
+                },
+            }
+        }
+
+        // If the rect has an overflow:* property set, clip the children accordingly
+        fn get_children_context(&self, frame: &DisplayListFrame, ctx: &DrawContext) -> DrawContext {
+            match frame.clip_children {
+                Some(size) => {
+                    let radii = Radii::from_border_radius(&frame.border_radius, frame.size);
+                    DrawContext {
+                        transform: ctx.transform,
+                        clip: self.intersect_clip(&ctx.clip, rounded_rect_path(0.0, 0.0, size.width, size.height, &radii), ctx.transform),
+                    }
+                },
+                None => ctx.clone(),
+            }
+        }
+
+        /// Intersects the clip with a (convex) path in node-local coordinates
+        fn intersect_clip(&self, clip: &Clip, path: Option<Path>, transform: Transform) -> Clip {
+            match path.and_then(|p| p.transform(transform)) {
+                Some(s) => intersect_convex_clip(clip, &s, self.width, self.height),
+                None => Clip::Empty,
+            }
+        }
+
+        fn render_frame(&mut self, target: &mut Pixmap, frame: &DisplayListFrame, ctx: &DrawContext) {
+
+            let size = frame.size;
+            let radii = Radii::from_border_radius(&frame.border_radius, size);
+
+            // Border, text and BoxShadow::Outset are not clipped to the frame,
+            // all other content types are clipped to the (rounded) frame bounds
+            let mut content_clip = None;
+
+            if let Some(box_shadow) = frame.box_shadow.as_ref() {
+                if box_shadow.clip_mode == BoxShadowClipMode::Outset {
+                    self.render_box_shadow(target, ctx, size, &radii, box_shadow, BoxShadowClipMode::Outset);
+                }
+            }
+
+            for content in frame.content.iter() {
+                match content {
+                    LayoutRectContent::Text { glyphs, font_instance_key, color, overflow, .. } => {
+                        let text_ctx = if overflow.0 || overflow.1 {
+                            self.get_content_context(&mut content_clip, ctx, size, &radii)
+                        } else {
+                            ctx.clone()
+                        };
+                        self.render_text(target, &text_ctx, glyphs, *font_instance_key, *color);
+                    },
+                    LayoutRectContent::Background { content, size: bg_size, offset, repeat } => {
+                        let background_ctx = self.get_content_context(&mut content_clip, ctx, size, &radii);
+                        self.render_background(target, &background_ctx, size, content, *bg_size, *offset, *repeat);
+                    },
+                    LayoutRectContent::Image { size: image_size, offset, image_rendering, image_key, .. } => {
+                        let image_ctx = self.get_content_context(&mut content_clip, ctx, size, &radii);
+                        let region = LogicalRect::new(*offset, size);
+                        let tile = LogicalRect::new(*offset, *image_size);
+                        self.render_image(target, &image_ctx, *image_key, region, tile, (true, true), *image_rendering);
+                    },
+                    LayoutRectContent::Border { widths, colors, styles } => {
+                        render_border(target, ctx, size, &radii, widths, colors, styles, self.options.hidpi_factor);
+                    },
+                }
+            }
+
+            if let Some(box_shadow) = frame.box_shadow.as_ref() {
+                if box_shadow.clip_mode == BoxShadowClipMode::Inset {
+                    let inset_ctx = self.get_content_context(&mut content_clip, ctx, size, &radii);
+                    self.render_box_shadow(target, &inset_ctx, size, &radii, box_shadow, BoxShadowClipMode::Inset);
+                }
+            }
+        }
+
+        /// Returns the context for the items that are clipped to the frame, lazily
+        /// creates the clip mask (since creating clip masks is relatively expensive)
+        fn get_content_context(&self, cached: &mut Option<DrawContext>, ctx: &DrawContext, size: LogicalSize, radii: &Radii) -> DrawContext {
+            if let Some(c) = cached.as_ref() {
+                return c.clone();
+            }
+            let c = DrawContext {
+                transform: ctx.transform,
+                clip: self.intersect_clip(&ctx.clip, rounded_rect_path(0.0, 0.0, size.width, size.height, radii), ctx.transform),
+            };
+            *cached = Some(c.clone());
+            c
+        }
+
+        fn render_text(
+            &mut self,
+            target: &mut Pixmap,
+            ctx: &DrawContext,
+            glyphs: &[GlyphInstance],
+            font_instance_key: FontInstanceKey,
+            color: ColorU,
+        ) {
+            let clip_mask = match &ctx.clip {
+                Clip::Empty => return,
+                Clip::None => None,
+                Clip::Mask { mask, .. } => Some(mask.clone()),
+            };
+
+            if color.a == 0 {
+                return;
+            }
+
+            // find the font and the font size for the font instance
+            let (font_key, font_ref, font_size_px) = match self.options.renderer_resources.currently_registered_fonts
+                .iter()
+                .find_map(|(font_key, (font_ref, instances))| {
+                    instances.iter()
+                    .find(|(_, instance_key)| **instance_key == font_instance_key)
+                    .map(|(au, _)| (*font_key, font_ref, au.into_px()))
+                }) {
+                Some(s) => s,
+                None => return,
+            };
+
+            let units_per_em = get_units_per_em(font_ref);
+            if units_per_em <= 0.0 {
+                return;
+            }
+            let glyph_scale = font_size_px / units_per_em;
+
+            let mut paint = Paint::default();
+            paint.set_color(translate_color(color));
+            paint.anti_alias = true;
+
+            for glyph in glyphs.iter() {
+                let path = self.glyphs
+                    .entry((font_key, glyph.index))
+                    .or_insert_with(|| get_glyph_path(font_ref, glyph.index));
+
+                if let Some(path) = path.as_ref() {
+                    // glyph outlines are y-up, starting at the baseline
+                    let transform = concat(
+                        ctx.transform,
+                        concat(translate(glyph.point.x, glyph.point.y), scale(glyph_scale, -glyph_scale)),
+                    );
+                    let _ = target.fill_path(path, &paint, FillRule::Winding, transform, clip_mask.as_deref());
+                }
+            }
+        }
+
+        fn render_background(
+            &mut self,
+            target: &mut Pixmap,
+            ctx: &DrawContext,
+            frame_size: LogicalSize,
+            background: &RectBackground,
+            background_size: Option<StyleBackgroundSize>,
+            background_position: Option<StyleBackgroundPosition>,
+            background_repeat: Option<StyleBackgroundRepeat>,
+        ) {
+            let bg_size = calculate_background_size(frame_size, background_size, background.get_content_size());
+            let bg_position = calculate_background_position(
+                frame_size.width,
+                frame_size.height,
+                background_position.unwrap_or_default(),
+                bg_size,
+            );
+            let tile = LogicalRect::new(bg_position, bg_size);
+            let frame_rect = LogicalRect::new(LogicalPosition::zero(), frame_size);
+            let repeat = match background_repeat.unwrap_or_default() {
+                StyleBackgroundRepeat::NoRepeat => (false, false),
+                StyleBackgroundRepeat::Repeat => (true, true),
+                StyleBackgroundRepeat::RepeatX => (true, false),
+                StyleBackgroundRepeat::RepeatY => (false, true),
+            };
+
+            match background {
+                RectBackground::Color(c) => {
+                    let clip_mask = match &ctx.clip {
+                        Clip::Empty => return,
+                        Clip::None => None,
+                        Clip::Mask { mask, .. } => Some(mask.clone()),
+                    };
+                    let mut paint = Paint::default();
+                    paint.set_color(translate_color(*c));
+                    paint.anti_alias = true;
+                    if let Some(path) = rect_path(tile.origin.x, tile.origin.y, tile.size.width, tile.size.height) {
+                        let _ = target.fill_path(&path, &paint, FillRule::Winding, ctx.transform, clip_mask.as_deref());
+                    }
+                },
+                RectBackground::Image((image_key, _)) => {
+                    self.render_image(target, ctx, *image_key, frame_rect, tile, repeat, ImageRendering::Auto);
+                },
+                RectBackground::LinearGradient(g) => {
+                    let device_scale = get_device_scale(ctx.transform);
+                    if let Some(tile_pixmap) = render_linear_gradient_tile(g, bg_size, device_scale) {
+                        // gradients are always repeated (same as in webrender)
+                        render_tile(target, ctx, &tile_pixmap, frame_rect, tile, (true, true), FilterQuality::Bilinear);
+                    }
+                },
+                RectBackground::RadialGradient(g) => {
+                    let device_scale = get_device_scale(ctx.transform);
+                    if let Some(tile_pixmap) = render_radial_gradient_tile(g, bg_size, device_scale) {
+                        render_tile(target, ctx, &tile_pixmap, frame_rect, tile, (true, true), FilterQuality::Bilinear);
+                    }
+                },
+                RectBackground::ConicGradient(g) => {
+                    let device_scale = get_device_scale(ctx.transform);
+                    if let Some(tile_pixmap) = render_conic_gradient_tile(g, bg_size, device_scale) {
+                        render_tile(target, ctx, &tile_pixmap, frame_rect, tile, (true, true), FilterQuality::Bilinear);
+                    }
+                },
+            }
+        }
+
+        /// Draws the image stretched to `tile`, repeated inside of `region`
+        fn render_image(
+            &mut self,
+            target: &mut Pixmap,
+            ctx: &DrawContext,
+            image_key: ImageKey,
+            region: LogicalRect,
+            tile: LogicalRect,
+            repeat: (bool, bool),
+            image_rendering: ImageRendering,
+        ) {
+            let images = self.options.images;
+            let image = self.images
+                .entry(image_key)
+                .or_insert_with(|| images.get(&image_key).and_then(|(descriptor, data)| image_to_pixmap(descriptor, data)));
+
+            let image = match image.as_ref() {
+                Some(s) => s,
+                None => return,
+            };
+
+            let quality = match image_rendering {
+                ImageRendering::Auto => FilterQuality::Bicubic,
+                ImageRendering::CrispEdges | ImageRendering::Pixelated => FilterQuality::Nearest,
+            };
+
+            if tile.size.width <= 0.0 || tile.size.height <= 0.0 {
+                return;
+            }
+
+            // stretch the image to the tile size
+            let transform = concat(
+                translate(tile.origin.x, tile.origin.y),
+                scale(tile.size.width / image.width() as f32, tile.size.height / image.height() as f32),
+            );
+
+            fill_pattern(target, ctx, image, transform, region, tile, repeat, quality);
+        }
+
+        fn render_box_shadow(
+            &self,
+            target: &mut Pixmap,
+            ctx: &DrawContext,
+            size: LogicalSize,
+            radii: &Radii,
+            box_shadow: &BoxShadow,
+            shadow_type: BoxShadowClipMode,
+        ) {
+            fn translate_shadow_side(input: &Option<CssPropertyValue<StyleBoxShadow>>) -> Option<StyleBoxShadow> {
+                input.and_then(|prop| prop.get_property().cloned())
+            }
+
+            let BoxShadow { top, left, bottom, right, .. } = box_shadow;
+            let (top, left, bottom, right) = (
+                translate_shadow_side(top),
+                translate_shadow_side(left),
+                translate_shadow_side(bottom),
+                translate_shadow_side(right),
+            );
+
+            let bounds = LogicalRect::new(LogicalPosition::zero(), size);
+
+            match [top, left, bottom, right].iter().filter(|x| x.is_some()).count() {
+                // Assumes that all box shadows are the same, so just use the top shadow
+                4 => if let Some(shadow) = top {
+                    self.render_box_shadow_inner(target, ctx, &shadow, bounds, None, radii, shadow_type);
+                },
+                1 | 2 => {
+                    // single edges: only draw the part of the shadow that is on the side of the edge
+                    let sides = [(top, 0), (left, 1), (bottom, 2), (right, 3)];
+                    for (shadow, side) in sides.iter() {
+                        let shadow = match shadow {
+                            Some(s) => s,
+                            None => continue,
+                        };
+                        let displace = (shadow.spread_radius.to_pixels() + shadow.blur_radius.to_pixels()) * 2.0;
+                        let is_inset = shadow.clip_mode == BoxShadowClipMode::Inset;
+                        let (w, h) = (size.width, size.height);
+                        let clip_rect = match (*side, is_inset) {
+                            (0, true) => LogicalRect::new(LogicalPosition::new(0.0, 0.0), LogicalSize::new(w, displace)),
+                            (0, false) => LogicalRect::new(LogicalPosition::new(-displace, -displace), LogicalSize::new(w + displace * 2.0, displace)),
+                            (2, true) => LogicalRect::new(LogicalPosition::new(0.0, h - displace), LogicalSize::new(w, displace)),
+                            (2, false) => LogicalRect::new(LogicalPosition::new(-displace, h), LogicalSize::new(w + displace * 2.0, displace)),
+                            (1, true) => LogicalRect::new(LogicalPosition::new(0.0, 0.0), LogicalSize::new(displace, h)),
+                            (1, false) => LogicalRect::new(LogicalPosition::new(-displace, -displace), LogicalSize::new(displace, h + displace * 2.0)),
+                            (_, true) => LogicalRect::new(LogicalPosition::new(w - displace, 0.0), LogicalSize::new(displace, h)),
+                            (_, false) => LogicalRect::new(LogicalPosition::new(w, -displace), LogicalSize::new(displace, h + displace * 2.0)),
+                        };
+                        self.render_box_shadow_inner(target, ctx, shadow, bounds, Some(clip_rect), radii, shadow_type);
+                    }
+                },
+                _ => { },
+            }
+        }
+
+        fn render_box_shadow_inner(
+            &self,
+            target: &mut Pixmap,
+            ctx: &DrawContext,
+            shadow: &StyleBoxShadow,
+            bounds: LogicalRect,
+            clip_rect: Option<LogicalRect>,
+            radii: &Radii,
+            shadow_type: BoxShadowClipMode,
+        ) {
+            if shadow.clip_mode != shadow_type {
+                return;
+            }
+
+            let offset_x = shadow.offset[0].to_pixels();
+            let offset_y = shadow.offset[1].to_pixels();
+            let blur = shadow.blur_radius.to_pixels().max(0.0);
+            let spread = shadow.spread_radius.to_pixels();
+            let color = apply_gamma(shadow.color);
+
+            let (x, y, w, h) = (bounds.origin.x, bounds.origin.y, bounds.size.width, bounds.size.height);
+            let is_inset = shadow_type == BoxShadowClipMode::Inset;
+
+            // the shape that casts the shadow (before blurring)
+            let shadow_path = if is_inset {
+                // inverted shape: everything except the shrunk rect casts a shadow
+                let extent = blur * 3.0 + libm::fabsf(spread) + libm::fabsf(offset_x) + libm::fabsf(offset_y) + 1.0;
+                let mut pb = PathBuilder::new();
+                push_rect(&mut pb, x - extent, y - extent, w + extent * 2.0, h + extent * 2.0);
+                push_rounded_rect(&mut pb, x + offset_x + spread, y + offset_y + spread, w - spread * 2.0, h - spread * 2.0, &radii.shrink(spread, spread, spread, spread));
+                pb.finish()
+            } else {
+                let mut pb = PathBuilder::new();
+                push_rounded_rect(&mut pb, x + offset_x - spread, y + offset_y - spread, w + spread * 2.0, h + spread * 2.0, &radii.shrink(-spread, -spread, -spread, -spread));
+                pb.finish()
+            };
+
+            let shadow_path = match shadow_path {
+                Some(s) => s,
+                None => return,
+            };
+
+            // the shadow is only drawn outside (outset) or inside (inset) of the frame
+            let mut clip = if is_inset {
+                ctx.clip.clone()
+            } else {
+                let extent = (blur + libm::fabsf(spread)) * 3.0 + libm::fabsf(offset_x) + libm::fabsf(offset_y) + 1.0;
+                let mut pb = PathBuilder::new();
+                push_rect(&mut pb, x - extent, y - extent, w + extent * 2.0, h + extent * 2.0);
+                push_rounded_rect(&mut pb, x, y, w, h, radii);
+                match pb.finish().and_then(|p| p.transform(ctx.transform)) {
+                    Some(p) => intersect_clip_even_odd(&ctx.clip, &p, self.width, self.height),
+                    None => return,
+                }
+            };
+
+            if let Some(clip_rect) = clip_rect {
+                let clip_rect = rect_path(clip_rect.origin.x, clip_rect.origin.y, clip_rect.size.width, clip_rect.size.height);
+                clip = self.intersect_clip(&clip, clip_rect, ctx.transform);
+            }
+
+            let clip_mask = match &clip {
+                Clip::Empty => return,
+                Clip::None => None,
+                Clip::Mask { mask, .. } => Some(mask.clone()),
+            };
+
+            let mut layer = match Pixmap::new(self.width, self.height) {
+                Some(s) => s,
+                None => return,
+            };
+
+            let mut paint = Paint::default();
+            paint.set_color(translate_color(color));
+            paint.anti_alias = true;
+            let _ = layer.fill_path(&shadow_path, &paint, FillRule::EvenOdd, ctx.transform, None);
+
+            // CSS: the resulting shadow approximates a gaussian blur with a
+            // standard deviation equal to half the blur radius
+            let sigma = blur / 2.0 * get_device_scale(ctx.transform);
+            if sigma > 0.0 {
+                if let Some(device_path) = shadow_path.clone().transform(ctx.transform) {
+                    let b = device_path.bounds();
+                    let margin = sigma * 3.0 + 1.0;
+                    gaussian_blur(&mut layer, b.left() - margin, b.top() - margin, b.right() + margin, b.bottom() + margin, sigma);
+                }
+            }
+
+            let _ = target.draw_pixmap(0, 0, layer.as_ref(), &PixmapPaint::default(), identity(), clip_mask.as_deref());
+        }
+
+        /// Multiplies the layer with the alpha of the (R8) mask image
+        fn apply_image_mask(&mut self, layer: &mut Pixmap, mask: &DisplayListImageMask, ctx: &DrawContext) {
+
+            let images = self.options.images;
+            let mask_image = self.images
+                .entry(mask.image)
+                .or_insert_with(|| images.get(&mask.image).and_then(|(descriptor, data)| image_to_pixmap(descriptor, data)));
+
+            let mut mask_pixmap = match Pixmap::new(self.width, self.height) {
+                Some(s) => s,
+                None => return,
+            };
+
+            if let Some(mask_image) = mask_image.as_ref() {
+                let rect = mask.rect;
+                if rect.size.width > 0.0 && rect.size.height > 0.0 {
+                    let transform = concat(
+                        translate(rect.origin.x, rect.origin.y),
+                        scale(rect.size.width / mask_image.width() as f32, rect.size.height / mask_image.height() as f32),
+                    );
+                    let spread_mode = if mask.repeat { SpreadMode::Repeat } else { SpreadMode::Pad };
+                    let mut paint = Paint::default();
+                    paint.anti_alias = true;
+                    paint.shader = Pattern::new(mask_image.as_ref(), spread_mode, FilterQuality::Bilinear, 1.0, transform);
+
+                    let path = if mask.repeat {
+                        // cover the entire layer
+                        rect_path(0.0, 0.0, self.width as f32, self.height as f32)
+                            .and_then(|p| invert(ctx.transform).and_then(|t| p.transform(t)))
+                    } else {
+                        rect_path(rect.origin.x, rect.origin.y, rect.size.width, rect.size.height)
+                    };
+
+                    if let Some(path) = path {
+                        let _ = mask_pixmap.fill_path(&path, &paint, FillRule::Winding, ctx.transform, None);
+                    }
+                }
+            }
+
+            for (px, m) in layer.data_mut().chunks_exact_mut(4).zip(mask_pixmap.data().chunks_exact(4)) {
+                let m = m[3] as u32;
+                for c in px.iter_mut() {
+                    *c = ((*c as u32 * m + 127) / 255) as u8;
+                }
+            }
+        }
+    }
+
+    /// Fills the part of `region` that is covered by the (repeated) `tile`
+    /// with the tile pixmap (stretched to the tile size)
+    fn render_tile(
+        target: &mut Pixmap,
+        ctx: &DrawContext,
+        tile_pixmap: &Pixmap,
+        region: LogicalRect,
+        tile: LogicalRect,
+        repeat: (bool, bool),
+        quality: FilterQuality,
+    ) {
+        let transform = concat(
+            translate(tile.origin.x, tile.origin.y),
+            scale(
+                tile.size.width / tile_pixmap.width() as f32,
+                tile.size.height / tile_pixmap.height() as f32,
+            ),
+        );
+        fill_pattern(target, ctx, tile_pixmap, transform, region, tile, repeat, quality);
+    }
+
+    fn fill_pattern(
+        target: &mut Pixmap,
+        ctx: &DrawContext,
+        pattern: &Pixmap,
+        pattern_transform: Transform,
+        region: LogicalRect,
+        tile: LogicalRect,
+        repeat: (bool, bool),
+        quality: FilterQuality,
+    ) {
+        let clip_mask = match &ctx.clip {
+            Clip::Empty => return,
+            Clip::None => None,
+            Clip::Mask { mask, .. } => Some(mask.clone()),
+        };
+
+        // restrict the filled area to the tile if the tile isn't repeated
+        let (x0, x1) = if repeat.0 {
+            (region.origin.x, region.origin.x + region.size.width)
+        } else {
+            (tile.origin.x.max(region.origin.x), (tile.origin.x + tile.size.width).min(region.origin.x + region.size.width))
+        };
+
+        let (y0, y1) = if repeat.1 {
+            (region.origin.y, region.origin.y + region.size.height)
+        } else {
+            (tile.origin.y.max(region.origin.y), (tile.origin.y + tile.size.height).min(region.origin.y + region.size.height))
+        };
+
+        let path = match rect_path(x0, y0, x1 - x0, y1 - y0) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let mut paint = Paint::default();
+        paint.anti_alias = true;
+        paint.shader = Pattern::new(pattern.as_ref(), SpreadMode::Repeat, quality, 1.0, pattern_transform);
+
+        let _ = target.fill_path(&path, &paint, FillRule::Winding, ctx.transform, clip_mask.as_deref());
+    }
+
+    fn render_border(
+        target: &mut Pixmap,
+        ctx: &DrawContext,
+        size: LogicalSize,
+        radii: &Radii,
+        widths: &StyleBorderWidths,
+        colors: &StyleBorderColors,
+        styles: &StyleBorderStyles,
+        hidpi: f32,
+    ) {
+        #[derive(Copy, Clone)]
+        enum Side { Top, Right, Bottom, Left }
+
+        if let Clip::Empty = ctx.clip {
+            return;
+        }
+
+        // same as in webrender: round the widths to full device pixels
+        let round_width = |w: f32| libm::floorf(w * hidpi) / hidpi;
+
+        let (top, right, bottom, left) = (
+            round_width(widths.top_width()),
+            round_width(widths.right_width()),
+            round_width(widths.bottom_width()),
+            round_width(widths.left_width()),
+        );
+
+        let (style_top, style_right, style_bottom, style_left) = (
+            styles.top.and_then(|s| s.get_property_or_default()).map(|s| s.inner),
+            styles.right.and_then(|s| s.get_property_or_default()).map(|s| s.inner),
+            styles.bottom.and_then(|s| s.get_property_or_default()).map(|s| s.inner),
+            styles.left.and_then(|s| s.get_property_or_default()).map(|s| s.inner),
+        );
+
+        let (color_top, color_right, color_bottom, color_left) = (
+            colors.top.and_then(|c| c.get_property_or_default()).unwrap_or_default().inner,
+            colors.right.and_then(|c| c.get_property_or_default()).unwrap_or_default().inner,
+            colors.bottom.and_then(|c| c.get_property_or_default()).unwrap_or_default().inner,
+            colors.left.and_then(|c| c.get_property_or_default()).unwrap_or_default().inner,
+        );
+
+        let (w, h) = (size.width, size.height);
+
+        // Splits the border into four trapezoids, from the outer corners to the inner corners
+        let side_clip_path = |side: Side| -> Option<Path> {
+            let extend = |(rh, rv): (f32, f32), wh: f32, wv: f32| -> (f32, f32) {
+                // extend the diagonal so that it covers the rounded corner
+                let t = if wh > 0.0 && wv > 0.0 { 1.0 + (rh / wh).max(rv / wv) } else { 1.0 };
+                ((wh * t).min(w / 2.0), (wv * t).min(h / 2.0))
+            };
+            let (tl_x, tl_y) = extend(radii.top_left, left, top);
+            let (tr_x, tr_y) = extend(radii.top_right, right, top);
+            let (br_x, br_y) = extend(radii.bottom_right, right, bottom);
+            let (bl_x, bl_y) = extend(radii.bottom_left, left, bottom);
+            let points = match side {
+                Side::Top => [(0.0, 0.0), (w, 0.0), (w - tr_x, tr_y.max(top)), (tl_x, tl_y.max(top))],
+                Side::Right => [(w, 0.0), (w, h), (w - br_x.max(right), h - br_y), (w - tr_x.max(right), tr_y)],
+                Side::Bottom => [(w, h), (0.0, h), (bl_x, h - bl_y.max(bottom)), (w - br_x, h - br_y.max(bottom))],
+                Side::Left => [(0.0, h), (0.0, 0.0), (tl_x.max(left), tl_y), (bl_x.max(left), h - bl_y)],
+            };
+            let mut pb = PathBuilder::new();
+            pb.move_to(points[0].0, points[0].1);
+            for p in points[1..].iter() {
+                pb.line_to(p.0, p.1);
+            }
+            pb.close();
+            pb.finish()
+        };
+
+        // Ring between the insets `from` and `to` (as a fraction of the border widths)
+        let ring_path = |from: f32, to: f32| -> Option<Path> {
+            let mut pb = PathBuilder::new();
+            let (t0, r0, b0, l0) = (top * from, right * from, bottom * from, left * from);
+            let (t1, r1, b1, l1) = (top * to, right * to, bottom * to, left * to);
+            push_rounded_rect(&mut pb, l0, t0, w - l0 - r0, h - t0 - b0, &radii.shrink(t0, r0, b0, l0));
+            push_rounded_rect(&mut pb, l1, t1, w - l1 - r1, h - t1 - b1, &radii.shrink(t1, r1, b1, l1));
+            pb.finish()
+        };
+
+        let sides = [
+            (Side::Top, top, style_top, color_top),
+            (Side::Right, right, style_right, color_right),
+            (Side::Bottom, bottom, style_bottom, color_bottom),
+            (Side::Left, left, style_left, color_left),
+        ];
+
+        let is_top_left = |side: Side| match side { Side::Top | Side::Left => true, _ => false };
+
+        for (side, width, style, color) in sides.iter() {
+
+            let style = match style {
+                None | Some(BorderStyle::None) | Some(BorderStyle::Hidden) => continue,
+                Some(s) => *s,
+            };
+
+            if *width <= 0.0 || color.a == 0 {
+                continue;
+            }
+
+            let side_clip = match side_clip_path(*side).and_then(|p| p.transform(ctx.transform)) {
+                Some(p) => p,
+                None => continue,
+            };
+
+            let side_mask = match intersect_convex_clip(&ctx.clip, &side_clip, target.width(), target.height()) {
+                Clip::Mask { mask, .. } => mask,
+                _ => continue,
+            };
+
+            let dark = darken(*color);
+
+            // (inset from, inset to, color)
+            let rings: Vec<(f32, f32, ColorU)> = match style {
+                BorderStyle::Double => vec![(0.0, 1.0 / 3.0, *color), (2.0 / 3.0, 1.0, *color)],
+                BorderStyle::Inset => vec![(0.0, 1.0, if is_top_left(*side) { dark } else { *color })],
+                BorderStyle::Outset => vec![(0.0, 1.0, if is_top_left(*side) { *color } else { dark })],
+                BorderStyle::Groove => if is_top_left(*side) {
+                    vec![(0.0, 0.5, dark), (0.5, 1.0, *color)]
+                } else {
+                    vec![(0.0, 0.5, *color), (0.5, 1.0, dark)]
+                },
+                BorderStyle::Ridge => if is_top_left(*side) {
+                    vec![(0.0, 0.5, *color), (0.5, 1.0, dark)]
+                } else {
+                    vec![(0.0, 0.5, dark), (0.5, 1.0, *color)]
+                },
+                BorderStyle::Dotted | BorderStyle::Dashed => {
+                    // stroke along the center of the border
+                    let half = |v: f32| v / 2.0;
+                    let center = {
+                        let mut pb = PathBuilder::new();
+                        push_rounded_rect(
+                            &mut pb,
+                            half(left), half(top),
+                            w - half(left) - half(right), h - half(top) - half(bottom),
+                            &radii.shrink(half(top), half(right), half(bottom), half(left)),
+                        );
+                        pb.finish()
+                    };
+                    let (intervals, line_cap) = match style {
+                        BorderStyle::Dotted => (vec![0.001, *width * 2.0], LineCap::Round),
+                        _ => (vec![*width * 3.0, *width * 3.0], LineCap::Butt),
+                    };
+                    let stroke = Stroke {
+                        width: *width,
+                        miter_limit: 4.0,
+                        line_cap,
+                        line_join: LineJoin::Miter,
+                        dash: StrokeDash::new(intervals, 0.0),
+                    };
+                    let mut paint = Paint::default();
+                    paint.set_color(translate_color(*color));
+                    paint.anti_alias = true;
+                    if let Some(center) = center {
+                        let _ = target.stroke_path(&center, &paint, &stroke, ctx.transform, Some(side_mask.as_ref()));
+                    }
+                    continue;
+                },
+                _ => vec![(0.0, 1.0, *color)],
+            };
+
+            for (from, to, ring_color) in rings {
+                if let Some(path) = ring_path(from, to) {
+                    let mut paint = Paint::default();
+                    paint.set_color(translate_color(ring_color));
+                    paint.anti_alias = true;
+                    let _ = target.fill_path(&path, &paint, FillRule::EvenOdd, ctx.transform, Some(side_mask.as_ref()));
+                }
+            }
+        }
+    }
+
+    fn render_linear_gradient_tile(gradient: &LinearGradient, size: LogicalSize, device_scale: f32) -> Option<Pixmap> {
+
+        use azul_css::{LayoutRect as CssLayoutRect, LayoutPoint as CssLayoutPoint, LayoutSize as CssLayoutSize};
+
+        let mut pixmap = new_tile_pixmap(size, device_scale)?;
+        let stops = gradient.stops.iter().map(|s| GradientStop::new(s.offset.get() / 100.0, translate_color(s.color))).collect::<Vec<_>>();
+        if stops.len() < 2 {
+            return None;
+        }
+
+        let rect = CssLayoutRect {
+            origin: CssLayoutPoint { x: 0, y: 0 },
+            size: CssLayoutSize { width: size.width.round() as isize, height: size.height.round() as isize },
+        };
+        let (start, end) = gradient.direction.to_points(&rect);
+
+        let mut paint = Paint::default();
+        paint.anti_alias = false;
+        paint.shader = tiny_skia::LinearGradient::new(
+            Point::from_xy(start.x as f32, start.y as f32),
+            Point::from_xy(end.x as f32, end.y as f32),
+            stops,
+            translate_extend_mode(gradient.extend_mode),
+            identity(),
+        )?;
+
+        fill_tile(&mut pixmap, &paint, size);
+        Some(pixmap)
+    }
+
+    fn render_radial_gradient_tile(gradient: &RadialGradient, size: LogicalSize, device_scale: f32) -> Option<Pixmap> {
+
+        let mut pixmap = new_tile_pixmap(size, device_scale)?;
+        let stops = gradient.stops.iter().map(|s| GradientStop::new(s.offset.normalized(), translate_color(s.color))).collect::<Vec<_>>();
+        if stops.len() < 2 {
+            return None;
+        }
+
+        let (w, h) = (size.width, size.height);
+        let center = calculate_background_position(w, h, gradient.position, LogicalSize::zero());
+        let (cx, cy) = (center.x, center.y);
+
+        // distances to the closest / farthest sides
+        let (side_x_min, side_x_max) = (libm::fabsf(cx).min(libm::fabsf(w - cx)), libm::fabsf(cx).max(libm::fabsf(w - cx)));
+        let (side_y_min, side_y_max) = (libm::fabsf(cy).min(libm::fabsf(h - cy)), libm::fabsf(cy).max(libm::fabsf(h - cy)));
+
+        let (rx, ry) = match (&gradient.shape, &gradient.size) {
+            (Shape::Circle, RadialGradientSize::ClosestSide) => { let r = side_x_min.min(side_y_min); (r, r) },
+            (Shape::Circle, RadialGradientSize::FarthestSide) => { let r = side_x_max.max(side_y_max); (r, r) },
+            (Shape::Circle, RadialGradientSize::ClosestCorner) => { let r = libm::hypotf(side_x_min, side_y_min); (r, r) },
+            (Shape::Circle, RadialGradientSize::FarthestCorner) => { let r = libm::hypotf(side_x_max, side_y_max); (r, r) },
+            (Shape::Ellipse, RadialGradientSize::ClosestSide) => (side_x_min, side_y_min),
+            (Shape::Ellipse, RadialGradientSize::FarthestSide) => (side_x_max, side_y_max),
+            // corner sizes keep the aspect ratio of the side sizes
+            (Shape::Ellipse, RadialGradientSize::ClosestCorner) => (side_x_min * core::f32::consts::SQRT_2, side_y_min * core::f32::consts::SQRT_2),
+            (Shape::Ellipse, RadialGradientSize::FarthestCorner) => (side_x_max * core::f32::consts::SQRT_2, side_y_max * core::f32::consts::SQRT_2),
+        };
+
+        if rx <= 0.0 || ry <= 0.0 {
+            return None;
+        }
+
+        let mut paint = Paint::default();
+        paint.anti_alias = false;
+        // unit circle, scaled to the ellipse
+        paint.shader = tiny_skia::RadialGradient::new(
+            Point::from_xy(0.0, 0.0),
+            Point::from_xy(0.0, 0.0),
+            1.0,
+            stops,
+            translate_extend_mode(gradient.extend_mode),
+            concat(translate(cx, cy), scale(rx, ry)),
+        )?;
+
+        fill_tile(&mut pixmap, &paint, size);
+        Some(pixmap)
+    }
+
+    // tiny-skia has no sweep gradient, so the conic gradient is computed per pixel
+    fn render_conic_gradient_tile(gradient: &ConicGradient, size: LogicalSize, device_scale: f32) -> Option<Pixmap> {
+
+        let mut pixmap = new_tile_pixmap(size, device_scale)?;
+
+        let stop_count = gradient.stops.len();
+        let stops = gradient.stops.iter().enumerate().map(|(i, s)| {
+            let mut deg = s.angle.to_degrees();
+            // to_degrees() wraps 360deg to 0deg, the last stop should still be at the end
+            if i != 0 && i == stop_count - 1 && deg == 0.0 {
+                deg = 360.0;
+            }
+            (deg / 360.0, s.color)
+        }).collect::<Vec<_>>();
+
+        if stops.len() < 2 {
+            return None;
+        }
+
+        let center = calculate_background_position(size.width, size.height, gradient.center, LogicalSize::zero());
+        let start_angle = gradient.angle.to_degrees();
+        let repeat = gradient.extend_mode == ExtendMode::Repeat;
+        let (first, last) = (stops[0].0, stops[stops.len() - 1].0);
+
+        let pw = pixmap.width() as usize;
+        let sx = size.width / pixmap.width() as f32;
+        let sy = size.height / pixmap.height() as f32;
+
+        for (i, px) in pixmap.data_mut().chunks_exact_mut(4).enumerate() {
+
+            let x = ((i % pw) as f32 + 0.5) * sx - center.x;
+            let y = ((i / pw) as f32 + 0.5) * sy - center.y;
+
+            // 0deg = up, clockwise
+            let mut deg = libm::atan2f(x, -y).to_degrees() - start_angle;
+            deg %= 360.0;
+            if deg < 0.0 { deg += 360.0; }
+            let mut t = deg / 360.0;
+
+            if repeat && last > first {
+                let period = last - first;
+                t = first + (t - first) - libm::floorf((t - first) / period) * period;
+            }
+
+            let c = interpolate_stops(&stops, t);
+            let a = c.a as u32;
+            px[0] = ((c.r as u32 * a + 127) / 255) as u8;
+            px[1] = ((c.g as u32 * a + 127) / 255) as u8;
+            px[2] = ((c.b as u32 * a + 127) / 255) as u8;
+            px[3] = c.a;
+        }
+
+        Some(pixmap)
+    }
+
+    fn interpolate_stops(stops: &[(f32, ColorU)], t: f32) -> ColorU {
+        let (first_t, first_color) = stops[0];
+        if t <= first_t {
+            return first_color;
+        }
+        for window in stops.windows(2) {
+            let (t0, c0) = window[0];
+            let (t1, c1) = window[1];
+            if t <= t1 {
+                if t1 <= t0 {
+                    return c1;
+                }
+                return c0.interpolate(&c1, (t - t0) / (t1 - t0));
+            }
+        }
+        stops[stops.len() - 1].1
+    }
+
+    fn new_tile_pixmap(size: LogicalSize, device_scale: f32) -> Option<Pixmap> {
+        let w = libm::ceilf(size.width * device_scale).min(MAX_TILE_SIZE);
+        let h = libm::ceilf(size.height * device_scale).min(MAX_TILE_SIZE);
+        if w < 1.0 || h < 1.0 {
+            return None;
+        }
+        Pixmap::new(w as u32, h as u32)
+    }
+
+    /// Fills the entire tile pixmap, the paint is specified in logical coordinates
+    fn fill_tile(pixmap: &mut Pixmap, paint: &Paint, size: LogicalSize) {
+        let transform = scale(pixmap.width() as f32 / size.width, pixmap.height() as f32 / size.height);
+        if let Some(path) = rect_path(0.0, 0.0, size.width, size.height) {
+            let _ = pixmap.fill_path(&path, paint, FillRule::Winding, transform, None);
+        }
+    }
+
+    /// Transform a background size such as "cover" or "contain" into actual pixels
+    fn calculate_background_size(
+        frame_size: LogicalSize,
+        bg_size: Option<StyleBackgroundSize>,
+        content_size: Option<(f32, f32)>,
+    ) -> LogicalSize {
+
+        let content_size = content_size.unwrap_or((frame_size.width, frame_size.height));
+
+        let bg_size = match bg_size {
+            None => return LogicalSize::new(content_size.0, content_size.1),
+            Some(s) => s,
+        };
+
+        match bg_size {
+            StyleBackgroundSize::ExactSize([w, h]) => {
+                LogicalSize::new(w.to_pixels(frame_size.width), h.to_pixels(frame_size.height))
+            },
+            StyleBackgroundSize::Contain | StyleBackgroundSize::Cover => {
+                if content_size.0 <= 0.0 || content_size.1 <= 0.0 {
+                    return LogicalSize::zero();
+                }
+                let ratio_w = frame_size.width / content_size.0;
+                let ratio_h = frame_size.height / content_size.1;
+                let ratio = match bg_size {
+                    StyleBackgroundSize::Contain => ratio_w.min(ratio_h),
+                    _ => ratio_w.max(ratio_h),
+                };
+                LogicalSize::new(content_size.0 * ratio, content_size.1 * ratio)
+            },
+        }
+    }
+
+    /// Transform a background-position attribute into pixel coordinates
+    fn calculate_background_position(
+        width: f32,
+        height: f32,
+        background_position: StyleBackgroundPosition,
+        background_size: LogicalSize,
+    ) -> LogicalPosition {
+
+        let horizontal_offset = match background_position.horizontal {
+            BackgroundPositionHorizontal::Left => 0.0,
+            BackgroundPositionHorizontal::Center => (width - background_size.width) / 2.0,
+            BackgroundPositionHorizontal::Right => width - background_size.width,
+            BackgroundPositionHorizontal::Exact(e) => e.to_pixels(width),
+        };
+
+        let vertical_offset = match background_position.vertical {
+            BackgroundPositionVertical::Top => 0.0,
+            BackgroundPositionVertical::Center => (height - background_size.height) / 2.0,
+            BackgroundPositionVertical::Bottom => height - background_size.height,
+            BackgroundPositionVertical::Exact(e) => e.to_pixels(height),
+        };
+
+        LogicalPosition { x: horizontal_offset, y: vertical_offset }
+    }
+
+    /// Converts the image data into a premultiplied RGBA pixmap
+    fn image_to_pixmap(descriptor: &ImageDescriptor, data: &ImageData) -> Option<Pixmap> {
+
+        let bytes = match data {
+            ImageData::Raw(r) => r.as_ref(),
+            ImageData::External(_) => return None,
+        };
+
+        let bpp = match descriptor.format {
+            RawImageFormat::R8 => 1,
+            RawImageFormat::RGBA8 | RawImageFormat::BGRA8 => 4,
+            _ => return None,
+        };
+
+        let stride = descriptor.stride.into_option().map(|s| s as usize).unwrap_or(descriptor.width * bpp);
+        let mut pixmap = Pixmap::new(descriptor.width as u32, descriptor.height as u32)?;
+        let width = descriptor.width;
+
+        for (y, row) in pixmap.data_mut().chunks_exact_mut(width * 4).enumerate() {
+            let src_row = bytes.get(y * stride..(y * stride + width * bpp))?;
+            for (x, px) in row.chunks_exact_mut(4).enumerate() {
+                let src = &src_row[x * bpp..(x + 1) * bpp];
+                match descriptor.format {
+                    // masks / greyscale: white with alpha
+                    RawImageFormat::R8 => {
+                        px.copy_from_slice(&[src[0], src[0], src[0], src[0]]);
+                    },
+                    // already premultiplied by into_loaded_image_source()
+                    RawImageFormat::BGRA8 => {
+                        let a = src[3];
+                        px.copy_from_slice(&[src[2].min(a), src[1].min(a), src[0].min(a), a]);
+                    },
+                    _ => {
+                        let a = src[3] as u32;
+                        px.copy_from_slice(&[
+                            ((src[0] as u32 * a + 127) / 255) as u8,
+                            ((src[1] as u32 * a + 127) / 255) as u8,
+                            ((src[2] as u32 * a + 127) / 255) as u8,
+                            src[3],
+                        ]);
+                    },
+                }
+            }
+        }
+
+        Some(pixmap)
+    }
+
+    #[cfg(feature = "text_layout")]
+    fn get_units_per_em(font_ref: &azul_css::FontRef) -> f32 {
+        azul_text_layout::get_font_metrics_fontref(font_ref).units_per_em as f32
+    }
+
+    #[cfg(not(feature = "text_layout"))]
+    fn get_units_per_em(_: &azul_css::FontRef) -> f32 {
+        0.0
+    }
+
+    #[cfg(feature = "text_layout")]
+    fn get_glyph_path(font_ref: &azul_css::FontRef, glyph_index: u32) -> Option<Path> {
+
+        use azul_text_layout::text_shaping::GlyphOutlineOperation;
+
+        if glyph_index > u16::MAX as u32 {
+            return None;
+        }
+
+        let outline = azul_text_layout::get_glyph_outline_fontref(font_ref, glyph_index as u16)?;
+        let mut pb = PathBuilder::new();
+        for op in outline.operations.as_ref().iter() {
+            match op {
+                GlyphOutlineOperation::MoveTo(m) => pb.move_to(m.x, m.y),
+                GlyphOutlineOperation::LineTo(l) => pb.line_to(l.x, l.y),
+                GlyphOutlineOperation::QuadraticCurveTo(q) => pb.quad_to(q.ctrl_1_x, q.ctrl_1_y, q.end_x, q.end_y),
+                GlyphOutlineOperation::CubicCurveTo(c) => pb.cubic_to(c.ctrl_1_x, c.ctrl_1_y, c.ctrl_2_x, c.ctrl_2_y, c.end_x, c.end_y),
+                GlyphOutlineOperation::ClosePath => pb.close(),
+            }
+        }
+        pb.finish()
+    }
+
+    #[cfg(not(feature = "text_layout"))]
+    fn get_glyph_path(_: &azul_css::FontRef, _: u32) -> Option<Path> {
+        None
+    }
+
+    /// Approximates a gaussian blur with three box blurs (in premultiplied space),
+    /// only the pixels inside of the given device-space bounds are blurred
+    fn gaussian_blur(pixmap: &mut Pixmap, x0: f32, y0: f32, x1: f32, y1: f32, sigma: f32) {
+
+        let pw = pixmap.width() as usize;
+        let ph = pixmap.height() as usize;
+        let x0 = (libm::floorf(x0).max(0.0) as usize).min(pw);
+        let y0 = (libm::floorf(y0).max(0.0) as usize).min(ph);
+        let x1 = (libm::ceilf(x1).max(0.0) as usize).min(pw);
+        let y1 = (libm::ceilf(y1).max(0.0) as usize).min(ph);
+
+        if x1 <= x0 || y1 <= y0 {
+            return;
+        }
+
+        // box size from the SVG spec (feGaussianBlur)
+        let d = libm::floorf(sigma * 3.0 * libm::sqrtf(2.0 * core::f32::consts::PI) / 4.0 + 0.5) as usize;
+        let radius = d / 2;
+        if radius == 0 {
+            return;
+        }
+
+        let data = pixmap.data_mut();
+        let mut scratch = vec![0u8; (x1 - x0).max(y1 - y0) * 4];
+
+        for _ in 0..3 {
+            for y in y0..y1 {
+                box_blur_line(data, (y * pw + x0) * 4, 4, x1 - x0, radius, &mut scratch);
+            }
+            for x in x0..x1 {
+                box_blur_line(data, (y0 * pw + x) * 4, pw * 4, y1 - y0, radius, &mut scratch);
+            }
+        }
+    }
+
+    fn box_blur_line(data: &mut [u8], start: usize, step: usize, len: usize, radius: usize, scratch: &mut [u8]) {
+
+        let window = (radius * 2 + 1) as u32;
+
+        for c in 0..4 {
+            let get = |data: &[u8], i: isize| -> u32 {
+                if i < 0 || i >= len as isize { 0 } else { data[start + i as usize * step + c] as u32 }
+            };
+
+            let mut sum = 0;
+            for i in -(radius as isize)..=(radius as isize) {
+                sum += get(data, i);
+            }
+
+            for i in 0..len {
+                scratch[i * 4 + c] = ((sum + window / 2) / window) as u8;
+                sum += get(data, i as isize + radius as isize + 1);
+                sum -= get(data, i as isize - radius as isize);
+            }
+        }
+
+        for i in 0..len {
+            data[start + i * step..start + i * step + 4].copy_from_slice(&scratch[i * 4..i * 4 + 4]);
+        }
+    }
+
+    fn intersect_clip_even_odd(clip: &Clip, device_path: &Path, width: u32, height: u32) -> Clip {
+        let mut mask = match clip {
+            Clip::Empty => return Clip::Empty,
+            Clip::None => {
+                let mut mask = ClipMask::new();
+                match mask.set_path(width, height, device_path, FillRule::EvenOdd, true) {
+                    Some(_) => return Clip::Mask { mask: Rc::new(mask), polygon: None },
+                    None => return Clip::Empty,
+                }
+            },
+            Clip::Mask { mask, .. } => (**mask).clone(),
+        };
+        match mask.intersect_path(device_path, FillRule::EvenOdd, true) {
+            Some(_) => Clip::Mask { mask: Rc::new(mask), polygon: None },
+            None => Clip::Empty,
+        }
+    }
+
+    /// Intersects the clip with a convex path in device space
+    fn intersect_convex_clip(clip: &Clip, device_path: &Path, width: u32, height: u32) -> Clip {
+
+        let polygon = match clip {
+            Clip::Empty => return Clip::Empty,
+            Clip::None => flatten_path(device_path),
+            Clip::Mask { polygon: Some(p), .. } => intersect_convex_polygons(p, &flatten_path(device_path)),
+            Clip::Mask { mask, polygon: None } => {
+                let mut mask = (**mask).clone();
+                return match mask.intersect_path(device_path, FillRule::Winding, true) {
+                    Some(_) => Clip::Mask { mask: Rc::new(mask), polygon: None },
+                    None => Clip::Empty,
+                };
+            },
+        };
+
+        if polygon.len() < 3 {
+            return Clip::Empty;
+        }
+
+        let mut pb = PathBuilder::new();
+        pb.move_to(polygon[0].x, polygon[0].y);
+        for p in polygon[1..].iter() {
+            pb.line_to(p.x, p.y);
+        }
+        pb.close();
+
+        let mut mask = ClipMask::new();
+        match pb.finish().and_then(|path| mask.set_path(width, height, &path, FillRule::Winding, true)) {
+            Some(_) => Clip::Mask { mask: Rc::new(mask), polygon: Some(Rc::new(polygon)) },
+            None => Clip::Empty,
+        }
+    }
+
+    /// Approximates the (single, closed) path with a polygon, subdividing
+    /// the curves into segments of roughly one device pixel
+    fn flatten_path(path: &Path) -> Vec<Point> {
+
+        use tiny_skia::PathSegment;
+
+        let lerp = |a: Point, b: Point, t: f32| Point::from_xy(a.x + (b.x - a.x) * t, a.y + (b.y - a.y) * t);
+        let distance = |a: Point, b: Point| libm::hypotf(b.x - a.x, b.y - a.y);
+        let subdivisions = |length: f32| (libm::ceilf(length) as usize).max(1).min(256);
+
+        let mut points = Vec::new();
+        let mut last = Point::zero();
+
+        for segment in path.segments() {
+            match segment {
+                PathSegment::MoveTo(p) | PathSegment::LineTo(p) => {
+                    points.push(p);
+                    last = p;
+                },
+                PathSegment::QuadTo(c, p) => {
+                    let n = subdivisions(distance(last, c) + distance(c, p));
+                    for i in 1..=n {
+                        let t = i as f32 / n as f32;
+                        points.push(lerp(lerp(last, c, t), lerp(c, p, t), t));
+                    }
+                    last = p;
+                },
+                PathSegment::CubicTo(c1, c2, p) => {
+                    let n = subdivisions(distance(last, c1) + distance(c1, c2) + distance(c2, p));
+                    for i in 1..=n {
+                        let t = i as f32 / n as f32;
+                        let (a, b, c) = (lerp(last, c1, t), lerp(c1, c2, t), lerp(c2, p, t));
+                        points.push(lerp(lerp(a, b, t), lerp(b, c, t), t));
+                    }
+                    last = p;
+                },
+                PathSegment::Close => { },
+            }
+        }
+
+        points.dedup_by(|a, b| distance(*a, *b) < 0.001);
+        if points.len() > 1 && distance(points[0], points[points.len() - 1]) < 0.001 {
+            points.pop();
+        }
+        points
+    }
+
+    /// Sutherland-Hodgman: clips the subject polygon against the convex clip polygon
+    fn intersect_convex_polygons(subject: &[Point], clip: &[Point]) -> Vec<Point> {
+
+        let cross = |a: Point, b: Point, p: Point| (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+
+        if clip.len() < 3 {
+            return Vec::new();
+        }
+
+        // winding direction of the clip polygon (shoelace formula)
+        let area = clip.iter().zip(clip.iter().cycle().skip(1)).map(|(a, b)| a.x * b.y - b.x * a.y).sum::<f32>();
+        let orientation = if area < 0.0 { -1.0 } else { 1.0 };
+
+        let mut output = subject.to_vec();
+
+        for (i, a) in clip.iter().enumerate() {
+            let b = clip[(i + 1) % clip.len()];
+            let inside = |p: Point| cross(*a, b, p) * orientation >= 0.0;
+            let input = core::mem::replace(&mut output, Vec::new());
+            let mut previous = match input.last() {
+                Some(s) => *s,
+                None => break,
+            };
+            for current in input.iter().copied() {
+                let (current_inside, previous_inside) = (inside(current), inside(previous));
+                if current_inside != previous_inside {
+                    // intersection of the line previous -> current with the clip edge
+                    let (d0, d1) = (cross(*a, b, previous), cross(*a, b, current));
+                    let t = d0 / (d0 - d1);
+                    output.push(Point::from_xy(previous.x + (current.x - previous.x) * t, previous.y + (current.y - previous.y) * t));
+                }
+                if current_inside {
+                    output.push(current);
+                }
+                previous = current;
+            }
+        }
+
+        output
+    }
+
+    fn rect_path(x: f32, y: f32, w: f32, h: f32) -> Option<Path> {
+        Some(PathBuilder::from_rect(Rect::from_xywh(x, y, w, h)?))
+    }
+
+    fn rounded_rect_path(x: f32, y: f32, w: f32, h: f32, radii: &Radii) -> Option<Path> {
+        let mut pb = PathBuilder::new();
+        push_rounded_rect(&mut pb, x, y, w, h, radii);
+        pb.finish()
+    }
+
+    fn push_rect(pb: &mut PathBuilder, x: f32, y: f32, w: f32, h: f32) {
+        pb.move_to(x, y);
+        pb.line_to(x + w, y);
+        pb.line_to(x + w, y + h);
+        pb.line_to(x, y + h);
+        pb.close();
+    }
+
+    fn push_rounded_rect(pb: &mut PathBuilder, x: f32, y: f32, w: f32, h: f32, radii: &Radii) {
+
+        if w <= 0.0 || h <= 0.0 {
+            return;
+        }
+
+        // CSS: if the radii of two adjacent corners are larger
+        // than the side, all radii are scaled down proportionally
+        let f = [
+            w / (radii.top_left.0 + radii.top_right.0),
+            w / (radii.bottom_left.0 + radii.bottom_right.0),
+            h / (radii.top_left.1 + radii.bottom_left.1),
+            h / (radii.top_right.1 + radii.bottom_right.1),
+        ].iter().copied().filter(|f| f.is_finite()).fold(1.0_f32, f32::min);
+
+        let r = |(h, v): (f32, f32)| (h.max(0.0) * f, v.max(0.0) * f);
+        let (tl, tr, br, bl) = (r(radii.top_left), r(radii.top_right), r(radii.bottom_right), r(radii.bottom_left));
+
+        pb.move_to(x + tl.0, y);
+        pb.line_to(x + w - tr.0, y);
+        if tr.0 > 0.0 && tr.1 > 0.0 {
+            pb.cubic_to(x + w - tr.0 * (1.0 - KAPPA), y, x + w, y + tr.1 * (1.0 - KAPPA), x + w, y + tr.1);
+        }
+        pb.line_to(x + w, y + h - br.1);
+        if br.0 > 0.0 && br.1 > 0.0 {
+            pb.cubic_to(x + w, y + h - br.1 * (1.0 - KAPPA), x + w - br.0 * (1.0 - KAPPA), y + h, x + w - br.0, y + h);
+        }
+        pb.line_to(x + bl.0, y + h);
+        if bl.0 > 0.0 && bl.1 > 0.0 {
+            pb.cubic_to(x + bl.0 * (1.0 - KAPPA), y + h, x, y + h - bl.1 * (1.0 - KAPPA), x, y + h - bl.1);
+        }
+        pb.line_to(x, y + tl.1);
+        if tl.0 > 0.0 && tl.1 > 0.0 {
+            pb.cubic_to(x, y + tl.1 * (1.0 - KAPPA), x + tl.0 * (1.0 - KAPPA), y, x + tl.0, y);
+        }
+        pb.close();
+    }
+
+    // Apply a gamma of 2.2 to the original value (same as in webrender)
+    fn apply_gamma(color: ColorU) -> ColorU {
+        const GAMMA_F: f32 = 1.0 / 2.2;
+        let g = |c: u8| libm::roundf(libm::powf(c as f32 / 255.0, GAMMA_F) * 255.0) as u8;
+        ColorU { r: g(color.r), g: g(color.g), b: g(color.b), a: color.a }
+    }
+
+    // Color of the "shaded" sides of inset / outset / groove / ridge borders
+    fn darken(color: ColorU) -> ColorU {
+        let d = |c: u8| ((c as u32 * 2) / 3) as u8;
+        ColorU { r: d(color.r), g: d(color.g), b: d(color.b), a: color.a }
+    }
+
+    fn translate_color(i: ColorU) -> Color {
+        Color::from_rgba8(i.r, i.g, i.b, i.a)
+    }
+
+    fn translate_extend_mode(e: ExtendMode) -> SpreadMode {
+        match e {
+            ExtendMode::Clamp => SpreadMode::Pad,
+            ExtendMode::Repeat => SpreadMode::Repeat,
+        }
+    }
+
+    // ComputedTransform3D uses row vectors, only the 2D affine part is used
+    fn translate_computed_transform(t: &ComputedTransform3D) -> Transform {
+        Transform {
+            sx: t.m[0][0],
+            ky: t.m[0][1],
+            kx: t.m[1][0],
+            sy: t.m[1][1],
+            tx: t.m[3][0],
+            ty: t.m[3][1],
+        }
+    }
+
+    fn identity() -> Transform {
+        scale(1.0, 1.0)
+    }
+
+    fn translate(x: f32, y: f32) -> Transform {
+        Transform { sx: 1.0, kx: 0.0, ky: 0.0, sy: 1.0, tx: x, ty: y }
+    }
+
+    fn scale(x: f32, y: f32) -> Transform {
+        Transform { sx: x, kx: 0.0, ky: 0.0, sy: y, tx: 0.0, ty: 0.0 }
+    }
+
+    /// Returns `a * b`, i.e. `b` is applied first
+    fn concat(a: Transform, b: Transform) -> Transform {
+        Transform {
+            sx: a.sx * b.sx + a.kx * b.ky,
+            kx: a.sx * b.kx + a.kx * b.sy,
+            ky: a.ky * b.sx + a.sy * b.ky,
+            sy: a.ky * b.kx + a.sy * b.sy,
+            tx: a.sx * b.tx + a.kx * b.ty + a.tx,
+            ty: a.ky * b.tx + a.sy * b.ty + a.ty,
+        }
+    }
+
+    fn invert(t: Transform) -> Option<Transform> {
+        let det = t.sx * t.sy - t.kx * t.ky;
+        if libm::fabsf(det) < core::f32::EPSILON {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+        Some(Transform {
+            sx: t.sy * inv_det,
+            kx: -t.kx * inv_det,
+            ky: -t.ky * inv_det,
+            sy: t.sx * inv_det,
+            tx: (t.kx * t.ty - t.sy * t.tx) * inv_det,
+            ty: (t.ky * t.tx - t.sx * t.ty) * inv_det,
+        })
+    }
+
+    /// Number of device pixels per logical pixel (for rendering tiles and blurs)
+    fn get_device_scale(t: Transform) -> f32 {
+        libm::hypotf(t.sx, t.ky).max(libm::hypotf(t.kx, t.sy))
+    }
+}
+
+#[cfg(all(test, feature = "svg"))]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_render_nested_backgrounds() {
+
+        use azul_core::{
+            display_list::{DisplayListFrame, DisplayListMsg, LayoutRectContent, RectBackground},
+            app_resources::{RawImageData, RendererResources},
+            ui_solver::{PositionInfo, PositionInfoInner},
+            window::{LogicalSize, ScrollStates},
+        };
+        use azul_css::{LayoutSize, LayoutPoint};
+
+        let background = |color: ColorU| LayoutRectContent::Background {
+            content: RectBackground::Color(color),
+            size: None,
+            offset: None,
+            repeat: None,
+        };
+
+        let mut child = DisplayListFrame::root(LayoutSize::new(20, 20), LayoutPoint::zero());
+        child.position = PositionInfo::Static(PositionInfoInner {
+            x_offset: 10.0,
+            y_offset: 10.0,
+            static_x_offset: 10.0,
+            static_y_offset: 10.0,
+        });
+        child.content.push(background(ColorU::RED));
+
+        let mut root = DisplayListFrame::root(LayoutSize::new(40, 40), LayoutPoint::zero());
+        root.content.push(background(ColorU::WHITE));
+        root.children.push(DisplayListMsg::Frame(child));
+
+        let display_list = CachedDisplayList {
+            root: DisplayListMsg::Frame(root),
+            root_size: LogicalSize::new(40.0, 40.0),
+        };
+
+        let renderer_resources = RendererResources::default();
+        let images = FastHashMap::default();
+        let scroll_states = ScrollStates::default();
+
+        // hidpi factor of 2: 40x40 logical pixels = 80x80 physical pixels
+        let image = render_display_list(&display_list, &CpuRenderOptions {
+            width: 80,
+            height: 80,
+            hidpi_factor: 2.0,
+            background_color: ColorU::TRANSPARENT,
+            renderer_resources: &renderer_resources,
+            images: &images,
+            scroll_states: &scroll_states,
+        }).unwrap();
+
+        let pixels = match &image.pixels {
+            RawImageData::U8(u) => u.as_ref().to_vec(),
+            _ => panic!("expected RGBA8 pixels"),
+        };
+
+        let get_pixel = |x: usize, y: usize| -> [u8;4] {
+            let i = (y * image.width + x) * 4;
+            [pixels[i], pixels[i + 1], pixels[i + 2], pixels[i + 3]]
+        };
+
+        assert_eq!(get_pixel(5, 5), [255, 255, 255, 255]);
+        assert_eq!(get_pixel(40, 40), [255, 0, 0, 255]);
+        assert_eq!(get_pixel(70, 70), [255, 255, 255, 255]);
+    }
+
+    // Renders a single root frame at a hidpi factor of 1, returns a closure
+    // that returns the (non-premultiplied) RGBA value of the pixel at (x, y)
+    fn render_test_frame(
+        root: azul_core::display_list::DisplayListFrame,
+        images: &FastHashMap<ImageKey, (ImageDescriptor, ImageData)>,
+    ) -> impl Fn(usize, usize) -> [u8;4] {
+
+        use azul_core::{
+            display_list::DisplayListMsg,
+            app_resources::RawImageData,
+            window::LogicalSize,
+        };
+
+        let (width, height) = (root.size.width as usize, root.size.height as usize);
+        let display_list = CachedDisplayList {
+            root: DisplayListMsg::Frame(root),
+            root_size: LogicalSize::new(width as f32, height as f32),
+        };
+
+        let renderer_resources = RendererResources::default();
+        let scroll_states = ScrollStates::default();
+
+        let image = render_display_list(&display_list, &CpuRenderOptions {
+            width,
+            height,
+            hidpi_factor: 1.0,
+            background_color: ColorU::TRANSPARENT,
+            renderer_resources: &renderer_resources,
+            images,
+            scroll_states: &scroll_states,
+        }).unwrap();
+
+        let pixels = match &image.pixels {
+            RawImageData::U8(u) => u.as_ref().to_vec(),
+            _ => panic!("expected RGBA8 pixels"),
+        };
+
+        move |x: usize, y: usize| -> [u8;4] {
+            let i = (y * width + x) * 4;
+            [pixels[i], pixels[i + 1], pixels[i + 2], pixels[i + 3]]
+        }
+    }
+
+    fn test_frame(width: isize, height: isize, x: f32, y: f32, color: Option<ColorU>) -> azul_core::display_list::DisplayListFrame {
+
+        use azul_core::{
+            display_list::{DisplayListFrame, LayoutRectContent, RectBackground},
+            ui_solver::{PositionInfo, PositionInfoInner},
+        };
+        use azul_css::{LayoutSize, LayoutPoint};
+
+        let mut frame = DisplayListFrame::root(LayoutSize::new(width, height), LayoutPoint::zero());
+        frame.position = PositionInfo::Static(PositionInfoInner {
+            x_offset: x,
+            y_offset: y,
+            static_x_offset: x,
+            static_y_offset: y,
+        });
+        if let Some(color) = color {
+            frame.content.push(LayoutRectContent::Background {
+                content: RectBackground::Color(color),
+                size: None,
+                offset: None,
+                repeat: None,
+            });
+        }
+        frame
+    }
+
+    #[test]
+    fn test_render_border_radius() {
+
+        use azul_core::display_list::{DisplayListMsg, StyleBorderRadius};
+        use azul_css::{CssPropertyValue, StyleBorderTopLeftRadius, StyleBorderBottomRightRadius};
+
+        let mut root = test_frame(40, 40, 0.0, 0.0, Some(ColorU::RED));
+        root.border_radius = StyleBorderRadius {
+            top_left: Some(CssPropertyValue::Exact(StyleBorderTopLeftRadius::const_px(20))),
+            bottom_right: Some(CssPropertyValue::Exact(StyleBorderBottomRightRadius::const_px(20))),
+            .. Default::default()
+        };
+
+        // the overflowing child is clipped to the rounded corners of the parent
+        let mut parent = test_frame(40, 40, 40.0, 0.0, None);
+        parent.border_radius = root.border_radius;
+        parent.clip_children = Some(parent.size);
+        parent.children.push(DisplayListMsg::Frame(test_frame(40, 40, 0.0, 0.0, Some(ColorU::BLUE))));
+
+        let mut container = test_frame(80, 40, 0.0, 0.0, None);
+        container.children.push(DisplayListMsg::Frame(root));
+        container.children.push(DisplayListMsg::Frame(parent));
+
+        let get_pixel = render_test_frame(container, &FastHashMap::default());
+
+        // rounded corners
+        assert_eq!(get_pixel(1, 1), [0, 0, 0, 0]);
+        assert_eq!(get_pixel(38, 38), [0, 0, 0, 0]);
+        assert_eq!(get_pixel(41, 1), [0, 0, 0, 0]);
+        assert_eq!(get_pixel(78, 38), [0, 0, 0, 0]);
+
+        // square corners and the center
+        assert_eq!(get_pixel(38, 1), [255, 0, 0, 255]);
+        assert_eq!(get_pixel(1, 38), [255, 0, 0, 255]);
+        assert_eq!(get_pixel(20, 20), [255, 0, 0, 255]);
+        assert_eq!(get_pixel(78, 1), [0, 0, 255, 255]);
+        assert_eq!(get_pixel(60, 20), [0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn test_render_box_shadow() {
+
+        use azul_core::display_list::{BoxShadow, DisplayListMsg};
+        use azul_css::{
+            CssPropertyValue, StyleBoxShadow, BoxShadowClipMode,
+            PixelValue, PixelValueNoPercent,
+        };
+
+        let shadow = |clip_mode: BoxShadowClipMode| {
+            let shadow = Some(CssPropertyValue::Exact(StyleBoxShadow {
+                offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }; 2],
+                color: ColorU::BLACK,
+                blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(0) },
+                spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(5) },
+                clip_mode,
+            }));
+            BoxShadow { clip_mode, top: shadow, right: shadow, bottom: shadow, left: shadow }
+        };
+
+        let mut outset = test_frame(20, 20, 10.0, 10.0, None);
+        outset.box_shadow = Some(shadow(BoxShadowClipMode::Outset));
+
+        let mut inset = test_frame(20, 20, 50.0, 10.0, None);
+        inset.box_shadow = Some(shadow(BoxShadowClipMode::Inset));
+
+        let mut root = test_frame(80, 40, 0.0, 0.0, Some(ColorU::WHITE));
+        root.children.push(DisplayListMsg::Frame(outset));
+        root.children.push(DisplayListMsg::Frame(inset));
+
+        let get_pixel = render_test_frame(root, &FastHashMap::default());
+
+        // outset: the shadow is drawn around the frame, but not below it
+        assert_eq!(get_pixel(2, 20), [255, 255, 255, 255]);
+        assert_eq!(get_pixel(7, 20), [0, 0, 0, 255]);
+        assert_eq!(get_pixel(20, 7), [0, 0, 0, 255]);
+        assert_eq!(get_pixel(20, 20), [255, 255, 255, 255]);
+
+        // inset: the shadow is only drawn inside of the frame
+        assert_eq!(get_pixel(47, 20), [255, 255, 255, 255]);
+        assert_eq!(get_pixel(52, 20), [0, 0, 0, 255]);
+        assert_eq!(get_pixel(60, 12), [0, 0, 0, 255]);
+        assert_eq!(get_pixel(60, 20), [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_render_opacity() {
+
+        use azul_core::{app_resources::OpacityKey, display_list::DisplayListMsg};
+
+        let mut child = test_frame(20, 20, 10.0, 10.0, Some(ColorU::RED));
+        child.opacity = Some((OpacityKey::unique(), 0.5));
+
+        // opacity also applies to the children of the frame
+        let mut nested = test_frame(10, 10, 5.0, 5.0, Some(ColorU::BLUE));
+        nested.opacity = Some((OpacityKey::unique(), 0.0));
+        child.children.push(DisplayListMsg::Frame(nested));
+
+        let mut root = test_frame(40, 40, 0.0, 0.0, Some(ColorU::WHITE));
+        root.children.push(DisplayListMsg::Frame(child));
+
+        let get_pixel = render_test_frame(root, &FastHashMap::default());
+
+        let is_pink = |[r, g, b, a]: [u8;4]| r == 255 && a == 255 && g == b && g > 120 && g < 135;
+
+        assert_eq!(get_pixel(5, 5), [255, 255, 255, 255]);
+        assert!(is_pink(get_pixel(12, 12)), "{:?}", get_pixel(12, 12));
+        assert!(is_pink(get_pixel(20, 20)), "{:?}", get_pixel(20, 20));
+    }
+
+    #[test]
+    fn test_render_transform() {
+
+        use azul_core::{
+            app_resources::TransformKey,
+            display_list::DisplayListMsg,
+            ui_solver::ComputedTransform3D,
+        };
+
+        let mut translated = test_frame(10, 10, 0.0, 0.0, Some(ColorU::RED));
+        translated.transform = Some((TransformKey::unique(), ComputedTransform3D::new_translation(20.0, 20.0, 0.0)));
+
+        // the transform also applies to the children of the frame
+        let mut scaled = test_frame(5, 5, 0.0, 0.0, None);
+        scaled.transform = Some((TransformKey::unique(), ComputedTransform3D::new_scale(2.0, 2.0, 1.0)));
+        scaled.children.push(DisplayListMsg::Frame(test_frame(5, 5, 0.0, 0.0, Some(ColorU::BLUE))));
+
+        let mut root = test_frame(40, 40, 0.0, 0.0, Some(ColorU::WHITE));
+        root.children.push(DisplayListMsg::Frame(translated));
+        root.children.push(DisplayListMsg::Frame(scaled));
+
+        let get_pixel = render_test_frame(root, &FastHashMap::default());
+
+        assert_eq!(get_pixel(8, 8), [0, 0, 255, 255]);
+        assert_eq!(get_pixel(12, 12), [255, 255, 255, 255]);
+        assert_eq!(get_pixel(18, 18), [255, 255, 255, 255]);
+        assert_eq!(get_pixel(25, 25), [255, 0, 0, 255]);
+        assert_eq!(get_pixel(35, 35), [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_render_clip_mask() {
+
+        use azul_core::{
+            app_resources::{IdNamespace, ImageDescriptorFlags, RawImageFormat},
+            display_list::{DisplayListImageMask, DisplayListMsg},
+            window::{LogicalPosition, LogicalRect, LogicalSize},
+        };
+        use azul_css::{OptionI32, U8Vec};
+
+        // 4x1 R8 mask: opaque on the left half, transparent on the right half
+        let mask_key = ImageKey { namespace: IdNamespace(0), key: 1 };
+        let mask_descriptor = ImageDescriptor {
+            format: RawImageFormat::R8,
+            width: 4,
+            height: 1,
+            stride: OptionI32::None,
+            offset: 0,
+            flags: ImageDescriptorFlags { is_opaque: false, allow_mipmaps: false },
+        };
+        let mask_data = ImageData::Raw(U8Vec::from_vec(vec![255, 255, 0, 0]));
+
+        let mut images = FastHashMap::default();
+        images.insert(mask_key, (mask_descriptor, mask_data));
+
+        let mut child = test_frame(40, 40, 0.0, 0.0, Some(ColorU::RED));
+        child.clip_mask = Some(DisplayListImageMask {
+            image: mask_key,
+            rect: LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(40.0, 40.0)),
+            repeat: false,
+        });
+
+        let mut root = test_frame(40, 40, 0.0, 0.0, Some(ColorU::WHITE));
+        root.children.push(DisplayListMsg::Frame(child));
+
+        let get_pixel = render_test_frame(root, &images);
+
+        assert_eq!(get_pixel(2, 20), [255, 0, 0, 255]);
+        assert_eq!(get_pixel(10, 20), [255, 0, 0, 255]);
+        assert_eq!(get_pixel(30, 20), [255, 255, 255, 255]);
+        assert_eq!(get_pixel(38, 20), [255, 255, 255, 255]);
+    }
+}
@@ -27,6 +27,14 @@ pub fn css_to_rust_code(css: &Css) -> String {
                 output.push_str(&format!("\t\t\t\t\t\t{},\r\n", print_declaraction(declaration, 6)));
             }
 
+            output.push_str("\t\t\t\t\t],\r\n");
+
+            output.push_str("\t\t\t\t\tmedia_queries: [\r\n");
+
+            for media_query in block.media_queries.iter() {
+                output.push_str(&format!("\t\t\t\t\t\t{},\r\n", format_media_query(media_query)));
+            }
+
             output.push_str("\t\t\t\t\t]\r\n");
 
             output.push_str("\t\t\t\t},\r\n");
@@ -36,6 +44,36 @@ pub fn css_to_rust_code(css: &Css) -> String {
         output.push_str("\t\t},\r\n");
     }
 
+    output.push_str("\t],\r\n");
+    output.push_str("\tfont_faces: [\r\n");
+
+    for font_face in css.font_faces.iter() {
+        output.push_str(&format!(
+            "\t\tCssFontFace {{ font_family: AzString::from_const_str({:?}), src: AzString::from_const_str({:?}), font_weight: {}, font_style: CssFontFaceStyle::{:?} }},\r\n",
+            font_face.font_family.as_str(), font_face.src.as_str(), font_face.font_weight, font_face.font_style,
+        ));
+    }
+
+    output.push_str("\t],\r\n");
+    output.push_str("\tkeyframes: [\r\n");
+
+    for keyframes in css.keyframes.iter() {
+        output.push_str("\t\tCssKeyframes {\r\n");
+        output.push_str(&format!("\t\t\tname: AzString::from_const_str({:?}),\r\n", keyframes.name.as_str()));
+        output.push_str("\t\t\tkeyframes: [\r\n");
+
+        for keyframe in keyframes.keyframes.iter() {
+            output.push_str(&format!("\t\t\t\tCssKeyframe {{ offset: {}, properties: [\r\n", format_float_value(&keyframe.offset)));
+            for property in keyframe.properties.iter() {
+                output.push_str(&format!("\t\t\t\t\t{},\r\n", format_static_css_prop(property, 5)));
+            }
+            output.push_str("\t\t\t\t] },\r\n");
+        }
+
+        output.push_str("\t\t\t]\r\n");
+        output.push_str("\t\t},\r\n");
+    }
+
     output.push_str("\t]\r\n");
     output.push_str("};");
 
@@ -63,15 +101,22 @@ fn format_selectors(selectors: &[CssPathSelector], tabs: usize) -> String {
     format!("vec![\r\n{}\r\n{}].into()", selectors_formatted, t)
 }
 
-fn format_single_selector(p: &CssPathSelector, _tabs: usize) -> String {
+fn format_single_selector(p: &CssPathSelector, tabs: usize) -> String {
     match p {
         CssPathSelector::Global => format!("CssPathSelector::Global"),
         CssPathSelector::Type(ntp) => format!("CssPathSelector::Type({})", format_node_type(ntp)),
         CssPathSelector::Class(class) => format!("CssPathSelector::Class(String::from({:?}))", class),
         CssPathSelector::Id(id) => format!("CssPathSelector::Id(String::from({:?}))", id),
         CssPathSelector::PseudoSelector(cps) => format!("CssPathSelector::PseudoSelector({})", format_pseudo_selector_type(cps)),
+        CssPathSelector::Attribute(a) => format!(
+            "CssPathSelector::Attribute(CssAttributeSelector {{ name: String::from({:?}), op: CssAttributeSelectorOp::{:?}, value: String::from({:?}) }})",
+            a.name, a.op, a.value
+        ),
+        CssPathSelector::Not(n) => format!("CssPathSelector::Not({})", format_selectors(n.as_ref(), tabs)),
         CssPathSelector::DirectChildren => format!("CssPathSelector::DirectChildren"),
         CssPathSelector::Children => format!("CssPathSelector::Children"),
+        CssPathSelector::AdjacentSibling => format!("CssPathSelector::AdjacentSibling"),
+        CssPathSelector::GeneralSibling => format!("CssPathSelector::GeneralSibling"),
     }
 }
 
@@ -94,6 +139,12 @@ fn format_pseudo_selector_type(p: &CssPathPseudoSelector) -> String {
         CssPathPseudoSelector::Hover => format!("CssPathPseudoSelector::Hover"),
         CssPathPseudoSelector::Active => format!("CssPathPseudoSelector::Active"),
         CssPathPseudoSelector::Focus => format!("CssPathPseudoSelector::Focus"),
+        CssPathPseudoSelector::FirstChild => format!("CssPathPseudoSelector::FirstChild"),
+        CssPathPseudoSelector::LastChild => format!("CssPathPseudoSelector::LastChild"),
+        CssPathPseudoSelector::OnlyChild => format!("CssPathPseudoSelector::OnlyChild"),
+        CssPathPseudoSelector::NthLastChild(n) => format!("CssPathPseudoSelector::NthLastChild({})", format_nth_child_selector(n)),
+        CssPathPseudoSelector::Empty => format!("CssPathPseudoSelector::Empty"),
+        CssPathPseudoSelector::FocusWithin => format!("CssPathPseudoSelector::FocusWithin"),
     }
 }
 
@@ -111,6 +162,7 @@ fn print_declaraction(decl: &CssDeclaration, tabs: usize) -> String {
     match decl {
         CssDeclaration::Static(s) => format!("CssDeclaration::Static({})", format_static_css_prop(s, tabs)),
         CssDeclaration::Dynamic(d) => format!("CssDeclaration::Dynamic({})", format_dynamic_css_prop(d, tabs)),
+        CssDeclaration::Custom(c) => format!("CssDeclaration::Custom(CssCustomProperty {{ name: {:?}.into(), value: {:?}.into() }})", c.name.as_str(), c.value.as_str()),
     }
 }
 
@@ -141,6 +193,7 @@ fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String {
         CssProperty::Cursor(p) => format!("CssProperty::Cursor({})", print_css_property_value(p, tabs)),
         CssProperty::Display(p) => format!("CssProperty::Display({})", print_css_property_value(p, tabs)),
         CssProperty::Float(p) => format!("CssProperty::Float({})", print_css_property_value(p, tabs)),
+        CssProperty::Clear(p) => format!("CssProperty::Clear({})", print_css_property_value(p, tabs)),
         CssProperty::BoxSizing(p) => format!("CssProperty::BoxSizing({})", print_css_property_value(p, tabs)),
         CssProperty::Width(p) => format!("CssProperty::Width({})", print_css_property_value(p, tabs)),
         CssProperty::Height(p) => format!("CssProperty::Height({})", print_css_property_value(p, tabs)),
@@ -160,6 +213,12 @@ fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String {
         CssProperty::JustifyContent(p) => format!("CssProperty::JustifyContent({})", print_css_property_value(p, tabs)),
         CssProperty::AlignItems(p) => format!("CssProperty::AlignItems({})", print_css_property_value(p, tabs)),
         CssProperty::AlignContent(p) => format!("CssProperty::AlignContent({})", print_css_property_value(p, tabs)),
+        CssProperty::GridTemplateColumns(p) => format!("CssProperty::GridTemplateColumns({})", print_css_property_value(p, tabs)),
+        CssProperty::GridTemplateRows(p) => format!("CssProperty::GridTemplateRows({})", print_css_property_value(p, tabs)),
+        CssProperty::GridColumn(p) => format!("CssProperty::GridColumn({})", print_css_property_value(p, tabs)),
+        CssProperty::GridRow(p) => format!("CssProperty::GridRow({})", print_css_property_value(p, tabs)),
+        CssProperty::ColumnGap(p) => format!("CssProperty::ColumnGap({})", print_css_property_value(p, tabs)),
+        CssProperty::RowGap(p) => format!("CssProperty::RowGap({})", print_css_property_value(p, tabs)),
         CssProperty::BackgroundContent(p) => format!("CssProperty::BackgroundContent({})", print_css_property_value(p, tabs)),
         CssProperty::BackgroundPosition(p) => format!("CssProperty::BackgroundPosition({})", print_css_property_value(p, tabs)),
         CssProperty::BackgroundSize(p) => format!("CssProperty::BackgroundSize({})", print_css_property_value(p, tabs)),
@@ -200,6 +259,17 @@ fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String {
         CssProperty::TransformOrigin(p) => format!("CssProperty::TransformOrigin({})", print_css_property_value(p, tabs)),
         CssProperty::PerspectiveOrigin(p) => format!("CssProperty::PerspectiveOrigin({})", print_css_property_value(p, tabs)),
         CssProperty::BackfaceVisibility(p) => format!("CssProperty::BackfaceVisibility({})", print_css_property_value(p, tabs)),
+        CssProperty::TransitionProperty(p) => format!("CssProperty::TransitionProperty({})", print_css_property_value(p, tabs)),
+        CssProperty::TransitionDuration(p) => format!("CssProperty::TransitionDuration({})", print_css_property_value(p, tabs)),
+        CssProperty::TransitionTimingFunction(p) => format!("CssProperty::TransitionTimingFunction({})", print_css_property_value(p, tabs)),
+        CssProperty::TransitionDelay(p) => format!("CssProperty::TransitionDelay({})", print_css_property_value(p, tabs)),
+        CssProperty::AnimationName(p) => format!("CssProperty::AnimationName({})", print_css_property_value(p, tabs)),
+        CssProperty::AnimationDuration(p) => format!("CssProperty::AnimationDuration({})", print_css_property_value(p, tabs)),
+        CssProperty::AnimationTimingFunction(p) => format!("CssProperty::AnimationTimingFunction({})", print_css_property_value(p, tabs)),
+        CssProperty::AnimationIterationCount(p) => format!("CssProperty::AnimationIterationCount({})", print_css_property_value(p, tabs)),
+        CssProperty::AnimationDirection(p) => format!("CssProperty::AnimationDirection({})", print_css_property_value(p, tabs)),
+        CssProperty::AnimationFillMode(p) => format!("CssProperty::AnimationFillMode({})", print_css_property_value(p, tabs)),
+        CssProperty::AnimationDelay(p) => format!("CssProperty::AnimationDelay({})", print_css_property_value(p, tabs)),
     }
 }
 
@@ -218,6 +288,21 @@ fn format_pixel_value(p: &PixelValue) -> String {
     }
 }
 
+fn format_media_query(q: &CssMediaQuery) -> String {
+    use azul_css::CssMediaCondition::*;
+    let conditions = q.conditions.iter().map(|c| match c {
+        MinWidth(p) => format!("CssMediaCondition::MinWidth({})", format_pixel_value(p)),
+        MaxWidth(p) => format!("CssMediaCondition::MaxWidth({})", format_pixel_value(p)),
+        MinHeight(p) => format!("CssMediaCondition::MinHeight({})", format_pixel_value(p)),
+        MaxHeight(p) => format!("CssMediaCondition::MaxHeight({})", format_pixel_value(p)),
+        MinResolution(f) => format!("CssMediaCondition::MinResolution({})", format_float_value(f)),
+        MaxResolution(f) => format!("CssMediaCondition::MaxResolution({})", format_float_value(f)),
+        Orientation(o) => format!("CssMediaCondition::Orientation(CssMediaOrientation::{:?})", o),
+        PrefersColorScheme(c) => format!("CssMediaCondition::PrefersColorScheme(CssMediaColorScheme::{:?})", c),
+    }).collect::<Vec<_>>().join(", ");
+    format!("CssMediaQuery {{ conditions: [{}] }}", conditions)
+}
+
 fn format_pixel_value_no_percent(p: &PixelValueNoPercent) -> String {
     format!("PixelValueNoPercent {{ inner: {} }}", format_pixel_value(&p.inner))
 }
@@ -303,6 +388,9 @@ impl_pixel_value_fmt!(LayoutBottom);
 impl_pixel_value_fmt!(LayoutRight);
 impl_pixel_value_fmt!(LayoutLeft);
 
+impl_pixel_value_fmt!(LayoutColumnGap);
+impl_pixel_value_fmt!(LayoutRowGap);
+
 macro_rules! impl_color_value_fmt {($struct_name:ty) => (
     impl FormatAsRustCode for $struct_name {
         fn format_as_rust_code(&self, _tabs: usize) -> String {
@@ -444,12 +532,21 @@ impl_enum_fmt!(LayoutDisplay,
     None,
     Flex,
     Block,
-    InlineBlock
+    InlineBlock,
+    Grid
 );
 
 impl_enum_fmt!(LayoutFloat,
     Left,
-    Right
+    Right,
+    None
+);
+
+impl_enum_fmt!(LayoutClear,
+    None,
+    Left,
+    Right,
+    Both
 );
 
 impl_enum_fmt!(LayoutBoxSizing,
@@ -494,6 +591,68 @@ impl_enum_fmt!(LayoutAlignContent,
     SpaceAround
 );
 
+fn format_grid_track_breadth(b: &GridTrackBreadth) -> String {
+    match b {
+        GridTrackBreadth::Fixed(p) => format!("GridTrackBreadth::Fixed({})", format_pixel_value(p)),
+        GridTrackBreadth::Fraction(f) => format!("GridTrackBreadth::Fraction({})", format_float_value(f)),
+        GridTrackBreadth::Auto => String::from("GridTrackBreadth::Auto"),
+    }
+}
+
+fn format_grid_track_sizing(t: &GridTrackSizing) -> String {
+    match t {
+        GridTrackSizing::Fixed(p) => format!("GridTrackSizing::Fixed({})", format_pixel_value(p)),
+        GridTrackSizing::Fraction(f) => format!("GridTrackSizing::Fraction({})", format_float_value(f)),
+        GridTrackSizing::Auto => String::from("GridTrackSizing::Auto"),
+        GridTrackSizing::MinMax(m) => format!("GridTrackSizing::MinMax(GridMinMax {{ min: {}, max: {} }})",
+            format_grid_track_breadth(&m.min), format_grid_track_breadth(&m.max)),
+    }
+}
+
+impl FormatAsRustCode for GridTrackSizingVec {
+    fn format_as_rust_code(&self, tabs: usize) -> String {
+        let t = String::from("    ").repeat(tabs);
+        let t1 = String::from("    ").repeat(tabs + 1);
+        let tracks = self.iter()
+            .map(|track| format!("{}{},", t1, format_grid_track_sizing(track)))
+            .collect::<Vec<_>>()
+            .join("\r\n");
+        format!("vec![\r\n{}\r\n{}].into()", tracks, t)
+    }
+}
+
+impl FormatAsRustCode for LayoutGridTemplateColumns {
+    fn format_as_rust_code(&self, tabs: usize) -> String {
+        format!("LayoutGridTemplateColumns {{ tracks: {} }}", self.tracks.format_as_rust_code(tabs))
+    }
+}
+
+impl FormatAsRustCode for LayoutGridTemplateRows {
+    fn format_as_rust_code(&self, tabs: usize) -> String {
+        format!("LayoutGridTemplateRows {{ tracks: {} }}", self.tracks.format_as_rust_code(tabs))
+    }
+}
+
+fn format_grid_line(l: &GridLine) -> String {
+    match l {
+        GridLine::Auto => String::from("GridLine::Auto"),
+        GridLine::Line(n) => format!("GridLine::Line({})", n),
+        GridLine::Span(n) => format!("GridLine::Span({})", n),
+    }
+}
+
+impl FormatAsRustCode for LayoutGridColumn {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!("LayoutGridColumn {{ start: {}, end: {} }}", format_grid_line(&self.start), format_grid_line(&self.end))
+    }
+}
+
+impl FormatAsRustCode for LayoutGridRow {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!("LayoutGridRow {{ start: {}, end: {} }}", format_grid_line(&self.start), format_grid_line(&self.end))
+    }
+}
+
 impl_enum_fmt!(Shape,
     Circle,
     Ellipse
@@ -540,6 +699,123 @@ impl_enum_fmt!(StyleBackfaceVisibility,
     Hidden
 );
 
+impl FormatAsRustCode for StyleTransitionPropertyVec {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        let properties = self.iter()
+            .map(|p| match p {
+                StyleTransitionProperty::All => String::from("StyleTransitionProperty::All"),
+                StyleTransitionProperty::Property(t) => format!("StyleTransitionProperty::Property(CssPropertyType::{:?})", t),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("vec![{}].into()", properties)
+    }
+}
+
+impl FormatAsRustCode for StyleTransitionDurationVec {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        let durations = self.iter()
+            .map(|d| format!("StyleTransitionDuration::ms({})", d.ms))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("vec![{}].into()", durations)
+    }
+}
+
+impl FormatAsRustCode for StyleTransitionDelayVec {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        let delays = self.iter()
+            .map(|d| format!("StyleTransitionDelay::ms({})", d.ms))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("vec![{}].into()", delays)
+    }
+}
+
+fn format_transition_timing_function(f: &StyleTransitionTimingFunction) -> String {
+    match f {
+        StyleTransitionTimingFunction::Ease => String::from("StyleTransitionTimingFunction::Ease"),
+        StyleTransitionTimingFunction::Linear => String::from("StyleTransitionTimingFunction::Linear"),
+        StyleTransitionTimingFunction::EaseIn => String::from("StyleTransitionTimingFunction::EaseIn"),
+        StyleTransitionTimingFunction::EaseOut => String::from("StyleTransitionTimingFunction::EaseOut"),
+        StyleTransitionTimingFunction::EaseInOut => String::from("StyleTransitionTimingFunction::EaseInOut"),
+        StyleTransitionTimingFunction::CubicBezier(c) => format!(
+            "StyleTransitionTimingFunction::CubicBezier(StyleCubicBezier {{ x1: {}, y1: {}, x2: {}, y2: {} }})",
+            format_float_value(&c.x1), format_float_value(&c.y1), format_float_value(&c.x2), format_float_value(&c.y2)
+        ),
+    }
+}
+
+impl FormatAsRustCode for StyleTransitionTimingFunctionVec {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        let functions = self.iter()
+            .map(format_transition_timing_function)
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("vec![{}].into()", functions)
+    }
+}
+
+impl FormatAsRustCode for StyleAnimationNameVec {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        let names = self.iter()
+            .map(|n| format!("StyleAnimationName {{ name: AzString::from_const_str({:?}) }}", n.name.as_str()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("vec![{}].into()", names)
+    }
+}
+
+impl FormatAsRustCode for StyleAnimationDurationVec {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        let durations = self.iter()
+            .map(|d| format!("StyleAnimationDuration::ms({})", d.ms))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("vec![{}].into()", durations)
+    }
+}
+
+impl FormatAsRustCode for StyleAnimationDelayVec {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        let delays = self.iter()
+            .map(|d| format!("StyleAnimationDelay::ms({})", d.ms))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("vec![{}].into()", delays)
+    }
+}
+
+impl FormatAsRustCode for StyleAnimationIterationCountVec {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        let counts = self.iter()
+            .map(|c| format!("StyleAnimationIterationCount::{:?}", c))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("vec![{}].into()", counts)
+    }
+}
+
+impl FormatAsRustCode for StyleAnimationDirectionVec {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        let directions = self.iter()
+            .map(|d| format!("StyleAnimationDirection::{:?}", d))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("vec![{}].into()", directions)
+    }
+}
+
+impl FormatAsRustCode for StyleAnimationFillModeVec {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        let fill_modes = self.iter()
+            .map(|f| format!("StyleAnimationFillMode::{:?}", f))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("vec![{}].into()", fill_modes)
+    }
+}
+
 impl FormatAsRustCode for StyleBackgroundContentVec {
     fn format_as_rust_code(&self, tabs: usize) -> String {
         let t = String::from("    ").repeat(tabs);
@@ -0,0 +1,902 @@
+//! Golden-file layout tests
+//!
+//! Runs the `<test>` documents in the `/tests` directory: Each test contains
+//! an `<html>` node (the DOM + CSS to lay out) and one or more
+//! `<output size="WIDTHxHEIGHT">` nodes, which contain the expected
+//! frame tree of the display list at the given window size:
+//!
+//! ```xml
+//! <test name="simple-width">
+//!     <html>
+//!         <body><div id="foo" /></body>
+//!         <style>#foo { width: 100px; height: 50px; }</style>
+//!     </html>
+//!     <output size="600x100">
+//!         Frame(
+//!             rect: 600x100 @ (0, 0),
+//!             children: [
+//!                 Frame(rect: 100x50 @ (0, 0)),
+//!             ],
+//!         )
+//!     </output>
+//! </test>
+//! ```
+//!
+//! The position of each frame is relative to its parent frame
+//! (or the positioned ancestor for `position: absolute` items),
+//! same as in the output of `azulc --display-list`.
+//!
+//! Tests whose expected output is known to be wrong are listed in
+//! [`KNOWN_FAILURES`]: they are still run and reported, but don't fail
+//! the test run.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use azul_core::{
+    app_resources::{Epoch, IdNamespace, ImageCache, RendererResources},
+    callbacks::DocumentId,
+    display_list::{DisplayListFrame, DisplayListMsg, GlTextureCache, RenderCallbacks, SolvedLayout},
+    styled_dom::DomId,
+    ui_solver::LayoutResult,
+    window::{FullWindowState, LogicalPosition, LogicalRect, LogicalSize},
+};
+use rust_fontconfig::FcFontCache;
+
+use crate::xml::{
+    XmlComponentMap, XmlNode, find_attribute, normalize_casing,
+    parse_xml_string, str_to_dom,
+};
+
+/// Maximum difference (in logical pixels) between the expected
+/// and the actual rectangles before a frame is considered different
+pub const DEFAULT_TOLERANCE: f32 = 0.5;
+
+/// Names of the tests that are expected to fail, with the reason
+///
+/// A listed test that passes fails the test run, so that the entry
+/// gets removed once the test or the layout solver is fixed.
+pub const KNOWN_FAILURES: &[(&str, &str)] = &[
+    (
+        "absolute-does-not-affect-static-item-position",
+        "the expected output renders the absolute item first and places static-3 at y=170, \
+        but position:absolute items are rendered after the regular children (see \
+        sort_children_by_position) and don't move the static items (static-3 is at y=100)",
+    ),
+];
+
+/// Single `<test>` node of a test file
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutTest {
+    /// Value of the `name` attribute
+    pub name: String,
+    /// The `<html>` node, with the `<style>` moved into a `<head>`
+    pub html: XmlNode,
+    /// Expected outputs for each window size
+    pub outputs: Vec<ExpectedOutput>,
+}
+
+/// `<output size="WIDTHxHEIGHT">` node of a test
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpectedOutput {
+    pub size: LogicalSize,
+    pub root: LayoutFrame,
+}
+
+/// Frame of the display list, reduced to the information that the tests compare
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutFrame {
+    pub kind: LayoutFrameKind,
+    pub rect: LogicalRect,
+    pub children: Vec<LayoutFrame>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LayoutFrameKind {
+    Frame,
+    ScrollFrame { content_rect: LogicalRect },
+    IFrame,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutTestError {
+    /// Test file could not be read
+    Io(PathBuf, String),
+    /// Test file is not valid XML
+    Xml(String),
+    /// `<test>` node has no `name="..."` attribute
+    MissingTestName,
+    /// `<test>` node has no `<html>` node
+    MissingHtml(String),
+    /// `<output>` node has no (or an invalid) `size="WIDTHxHEIGHT"` attribute
+    InvalidOutputSize(String),
+    /// Content of the `<output>` node could not be parsed
+    InvalidOutput(String, FrameParseError),
+    /// `<html>` node could not be converted into a `StyledDom`
+    Dom(String, String),
+}
+
+impl fmt::Display for LayoutTestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::LayoutTestError::*;
+        match self {
+            Io(path, e) => write!(f, "could not read \"{}\": {}", path.display(), e),
+            Xml(e) => write!(f, "invalid XML: {}", e),
+            MissingTestName => write!(f, "<test> node has no name=\"...\" attribute"),
+            MissingHtml(test) => write!(f, "test \"{}\": no <html> node", test),
+            InvalidOutputSize(test) => write!(f, "test \"{}\": <output> node has no valid size=\"WIDTHxHEIGHT\" attribute", test),
+            InvalidOutput(test, e) => write!(f, "test \"{}\": could not parse <output>: {}", test, e),
+            Dom(test, e) => write!(f, "test \"{}\": could not build DOM: {}", test, e),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrameParseError {
+    /// Character that can't start a token, at byte offset
+    UnexpectedCharacter(char, usize),
+    /// Expected something else, found the given token
+    UnexpectedToken(String, String),
+    UnexpectedEndOfInput,
+    /// Frame type other than `Frame`, `ScrollFrame` or `IFrame`
+    UnknownFrameType(String),
+    /// Frame has no `rect: ...` field
+    MissingRect(String),
+}
+
+impl fmt::Display for FrameParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::FrameParseError::*;
+        match self {
+            UnexpectedCharacter(c, pos) => write!(f, "unexpected character '{}' at position {}", c, pos),
+            UnexpectedToken(expected, found) => write!(f, "expected {}, found \"{}\"", expected, found),
+            UnexpectedEndOfInput => write!(f, "unexpected end of input"),
+            UnknownFrameType(t) => write!(f, "unknown frame type \"{}\" (expected Frame, ScrollFrame or IFrame)", t),
+            MissingRect(t) => write!(f, "{} has no \"rect: WIDTHxHEIGHT @ (X, Y)\" field", t),
+        }
+    }
+}
+
+/// Result of running one `<output>` of a test
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutTestResult {
+    pub file: PathBuf,
+    pub test_name: String,
+    /// `None` if the test could not be parsed
+    pub size: Option<LogicalSize>,
+    /// Reason from [`KNOWN_FAILURES`] if the test is expected to fail
+    pub known_failure: Option<&'static str>,
+    pub outcome: LayoutTestOutcome,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutTestOutcome {
+    /// Test was laid out, `differences` is empty if the test passed
+    Compared {
+        expected: LayoutFrame,
+        actual: LayoutFrame,
+        differences: Vec<FrameDifference>,
+    },
+    /// Test could not be parsed or laid out
+    Error(LayoutTestError),
+}
+
+impl LayoutTestResult {
+
+    /// Whether the actual layout matches the expected output
+    pub fn is_success(&self) -> bool {
+        match &self.outcome {
+            LayoutTestOutcome::Compared { differences, .. } => differences.is_empty(),
+            LayoutTestOutcome::Error(_) => false,
+        }
+    }
+
+    /// Whether the result should fail the test run: the test failed
+    /// and is not a known failure, or a known failure passed
+    pub fn is_failure(&self) -> bool {
+        self.is_success() == self.known_failure.is_some()
+    }
+}
+
+impl fmt::Display for LayoutTestResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+        let size = match self.size {
+            Some(s) => format!("{}x{}", s.width, s.height),
+            None => "?".to_string(),
+        };
+
+        if self.is_success() {
+            return match self.known_failure {
+                Some(_) => write!(f, "test {} @ {} ... ok (listed in KNOWN_FAILURES, remove the entry)", self.test_name, size),
+                None => write!(f, "test {} @ {} ... ok", self.test_name, size),
+            };
+        }
+
+        match self.known_failure {
+            Some(reason) => write!(f, "test {} @ {} ({}) ... FAILED (known failure: {})\r\n", self.test_name, size, self.file.display(), reason)?,
+            None => write!(f, "test {} @ {} ({}) ... FAILED\r\n", self.test_name, size, self.file.display())?,
+        }
+
+        let (expected, actual, differences) = match &self.outcome {
+            LayoutTestOutcome::Compared { expected, actual, differences } => (expected, actual, differences),
+            LayoutTestOutcome::Error(e) => return write!(f, "    {}\r\n", e),
+        };
+
+        for d in differences.iter() {
+            write!(f, "    {}\r\n", d)?;
+        }
+
+        // line-based diff of the two trees, so that the actual
+        // output can be copied into the test file if it is correct
+        let expected = expected.to_string();
+        let actual = actual.to_string();
+        let expected_lines = expected.lines().collect::<Vec<_>>();
+        let actual_lines = actual.lines().collect::<Vec<_>>();
+        write!(f, "\r\n    --- expected\r\n    +++ actual\r\n")?;
+        for line in diff_lines(&expected_lines, &actual_lines) {
+            match line {
+                DiffLine::Same(l) => write!(f, "     {}\r\n", l)?,
+                DiffLine::Removed(l) => write!(f, "    -{}\r\n", l)?,
+                DiffLine::Added(l) => write!(f, "    +{}\r\n", l)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Line diff based on the longest common subsequence of the two texts
+fn diff_lines<'a>(expected: &[&'a str], actual: &[&'a str]) -> Vec<DiffLine<'a>> {
+
+    // lcs[i][j] = length of the LCS of expected[i..] and actual[j..]
+    let mut lcs = vec![vec![0_usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() && j < actual.len() {
+        if expected[i] == actual[j] {
+            lines.push(DiffLine::Same(expected[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(DiffLine::Removed(expected[i]));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(actual[j]));
+            j += 1;
+        }
+    }
+    lines.extend(expected[i..].iter().map(|l| DiffLine::Removed(l)));
+    lines.extend(actual[j..].iter().map(|l| DiffLine::Added(l)));
+    lines
+}
+
+/// Single mismatch between the expected and the actual frame tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameDifference {
+    /// Path to the frame, i.e. `root.children[1].children[0]`
+    pub path: String,
+    pub kind: FrameDifferenceKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrameDifferenceKind {
+    Kind { expected: LayoutFrameKind, actual: LayoutFrameKind },
+    Rect { expected: LogicalRect, actual: LogicalRect },
+    ContentRect { expected: LogicalRect, actual: LogicalRect },
+    ChildCount { expected: usize, actual: usize },
+}
+
+impl fmt::Display for FrameDifference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::FrameDifferenceKind::*;
+        match &self.kind {
+            Kind { expected, actual } => write!(f, "{}: expected {}, got {}", self.path, expected.get_name(), actual.get_name()),
+            Rect { expected, actual } => write!(f, "{}: expected rect {}, got {}", self.path, FmtRect(expected), FmtRect(actual)),
+            ContentRect { expected, actual } => write!(f, "{}: expected content_rect {}, got {}", self.path, FmtRect(expected), FmtRect(actual)),
+            ChildCount { expected, actual } => write!(f, "{}: expected {} children, got {}", self.path, expected, actual),
+        }
+    }
+}
+
+impl LayoutFrameKind {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            LayoutFrameKind::Frame => "Frame",
+            LayoutFrameKind::ScrollFrame { .. } => "ScrollFrame",
+            LayoutFrameKind::IFrame => "IFrame",
+        }
+    }
+}
+
+/// Formats the rect in the same `WIDTHxHEIGHT @ (X, Y)` format as the test files
+struct FmtRect<'a>(&'a LogicalRect);
+
+impl<'a> fmt::Display for FmtRect<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{} @ ({}, {})", self.0.size.width, self.0.size.height, self.0.origin.x, self.0.origin.y)
+    }
+}
+
+impl fmt::Display for LayoutFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+impl LayoutFrame {
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter, indent: usize) -> fmt::Result {
+        let tabs = "    ".repeat(indent);
+        write!(f, "{}{}(\r\n", tabs, self.kind.get_name())?;
+        if let LayoutFrameKind::ScrollFrame { content_rect } = &self.kind {
+            write!(f, "{}    content_rect: {},\r\n", tabs, FmtRect(content_rect))?;
+        }
+        write!(f, "{}    rect: {},\r\n", tabs, FmtRect(&self.rect))?;
+        if !self.children.is_empty() {
+            write!(f, "{}    children: [\r\n", tabs)?;
+            for c in self.children.iter() {
+                c.fmt_indented(f, indent + 2)?;
+                write!(f, ",\r\n")?;
+            }
+            write!(f, "{}    ],\r\n", tabs)?;
+        }
+        write!(f, "{})", tabs)
+    }
+
+    /// Converts the (root) display list message into a frame tree
+    pub fn from_display_list(msg: &DisplayListMsg) -> Self {
+        match msg {
+            DisplayListMsg::Frame(f) => Self::from_frame(LayoutFrameKind::Frame, f),
+            DisplayListMsg::ScrollFrame(sf) => Self::from_frame(
+                LayoutFrameKind::ScrollFrame { content_rect: sf.content_rect },
+                &sf.frame
+            ),
+            DisplayListMsg::IFrame(_, size, _, cached_display_list) => LayoutFrame {
+                kind: LayoutFrameKind::IFrame,
+                rect: LogicalRect::new(LogicalPosition::zero(), *size),
+                children: vec![Self::from_display_list(&cached_display_list.root)],
+            },
+        }
+    }
+
+    fn from_frame(kind: LayoutFrameKind, frame: &DisplayListFrame) -> Self {
+        let (x, y) = frame.position.get_relative_offset();
+        LayoutFrame {
+            kind,
+            rect: LogicalRect::new(LogicalPosition::new(x, y), frame.size),
+            children: frame.children.iter().map(Self::from_display_list).collect(),
+        }
+    }
+}
+
+/// Parses all `<test>` nodes of a test file
+pub fn parse_test_file(xml: &str) -> Result<Vec<LayoutTest>, LayoutTestError> {
+
+    let root_nodes = parse_xml_string(xml).map_err(|e| LayoutTestError::Xml(format!("{}", e)))?;
+
+    root_nodes.as_ref().iter()
+    .filter(|n| normalize_casing(&n.node_type).as_str() == "test")
+    .map(parse_test_node)
+    .collect()
+}
+
+fn parse_test_node(test_node: &XmlNode) -> Result<LayoutTest, LayoutTestError> {
+
+    let name = find_attribute(test_node, "name")
+        .ok_or(LayoutTestError::MissingTestName)?
+        .as_str().to_string();
+
+    let html = test_node.children.as_ref().iter()
+        .find(|n| normalize_casing(&n.node_type).as_str() == "html")
+        .ok_or(LayoutTestError::MissingHtml(name.clone()))?;
+
+    let outputs = test_node.children.as_ref().iter()
+    .filter(|n| normalize_casing(&n.node_type).as_str() == "output")
+    .map(|output_node| {
+        let size = find_attribute(output_node, "size")
+            .and_then(|s| crate::parse_display_list_size(s.as_str()))
+            .ok_or(LayoutTestError::InvalidOutputSize(name.clone()))?;
+        let text = output_node.text.as_ref().map(|s| s.as_str()).unwrap_or("");
+        let root = parse_frame_tree(text).map_err(|e| LayoutTestError::InvalidOutput(name.clone(), e))?;
+        Ok(ExpectedOutput { size: LogicalSize::new(size.0, size.1), root })
+    })
+    .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(LayoutTest {
+        html: move_style_into_head(html),
+        name,
+        outputs,
+    })
+}
+
+/// The test files put the `<style>` directly into the `<html>` node,
+/// but `str_to_dom` only looks for it in the `<head>`
+fn move_style_into_head(html: &XmlNode) -> XmlNode {
+
+    let mut html = html.clone();
+
+    let has_head = html.children.as_ref().iter().any(|n| normalize_casing(&n.node_type).as_str() == "head");
+    if has_head {
+        return html;
+    }
+
+    let (styles, mut children): (Vec<XmlNode>, Vec<XmlNode>) = html.children.as_ref().iter().cloned()
+        .partition(|n| normalize_casing(&n.node_type).as_str() == "style");
+
+    let mut head = XmlNode::new("head");
+    head.children = styles.into();
+    children.push(head);
+    html.children = children.into();
+    html
+}
+
+// --- parser for the expected frame tree
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(f32),
+    /// The "x" in "WIDTHxHEIGHT"
+    By,
+    At,
+    Colon,
+    Comma,
+    OpenParen,
+    CloseParen,
+    OpenBracket,
+    CloseBracket,
+    OpenBrace,
+    CloseBrace,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::Token::*;
+        match self {
+            Ident(s) => write!(f, "{}", s),
+            Number(n) => write!(f, "{}", n),
+            By => write!(f, "x"),
+            At => write!(f, "@"),
+            Colon => write!(f, ":"),
+            Comma => write!(f, ","),
+            OpenParen => write!(f, "("),
+            CloseParen => write!(f, ")"),
+            OpenBracket => write!(f, "["),
+            CloseBracket => write!(f, "]"),
+            OpenBrace => write!(f, "{{"),
+            CloseBrace => write!(f, "}}"),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, FrameParseError> {
+
+    let chars = input.char_indices().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (pos, c) = chars[i];
+        let single = match c {
+            '@' => Some(Token::At),
+            ':' => Some(Token::Colon),
+            ',' => Some(Token::Comma),
+            '(' => Some(Token::OpenParen),
+            ')' => Some(Token::CloseParen),
+            '[' => Some(Token::OpenBracket),
+            ']' => Some(Token::CloseBracket),
+            '{' => Some(Token::OpenBrace),
+            '}' => Some(Token::CloseBrace),
+            _ => None,
+        };
+
+        if let Some(t) = single {
+            tokens.push(t);
+            i += 1;
+        } else if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || c == '-' || c == '.' {
+            let start = i;
+            i += 1;
+            while i < chars.len() && (chars[i].1.is_ascii_digit() || chars[i].1 == '.') {
+                i += 1;
+            }
+            let end = chars.get(i).map(|c| c.0).unwrap_or(input.len());
+            let number = input[pos..end].parse::<f32>()
+                .map_err(|_| FrameParseError::UnexpectedCharacter(chars[start].1, pos))?;
+            tokens.push(Token::Number(number));
+            // "600x100": the "x" directly after a number is the separator
+            if i < chars.len() && chars[i].1 == 'x' {
+                tokens.push(Token::By);
+                i += 1;
+            }
+        } else if c.is_alphabetic() || c == '_' {
+            let start = pos;
+            while i < chars.len() && (chars[i].1.is_alphanumeric() || chars[i].1 == '_') {
+                i += 1;
+            }
+            let end = chars.get(i).map(|c| c.0).unwrap_or(input.len());
+            match &input[start..end] {
+                "x" => tokens.push(Token::By),
+                s => tokens.push(Token::Ident(s.to_string())),
+            }
+        } else {
+            return Err(FrameParseError::UnexpectedCharacter(c, pos));
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Generic value of the frame tree syntax, i.e. `Name(fields)`,
+/// `Name { fields }`, `[values]`, `WxH @ (X, Y)` or plain numbers
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Struct(String, Vec<(Option<String>, Value)>),
+    List(Vec<Value>),
+    Rect(LogicalRect),
+    Number(f32),
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset)
+    }
+
+    fn next(&mut self) -> Result<Token, FrameParseError> {
+        let t = self.tokens.get(self.pos).cloned().ok_or(FrameParseError::UnexpectedEndOfInput)?;
+        self.pos += 1;
+        Ok(t)
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), FrameParseError> {
+        let t = self.next()?;
+        if t == expected {
+            Ok(())
+        } else {
+            Err(FrameParseError::UnexpectedToken(format!("\"{}\"", expected), t.to_string()))
+        }
+    }
+
+    fn expect_number(&mut self) -> Result<f32, FrameParseError> {
+        match self.next()? {
+            Token::Number(n) => Ok(n),
+            t => Err(FrameParseError::UnexpectedToken("number".to_string(), t.to_string())),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value, FrameParseError> {
+        match self.next()? {
+            Token::Number(width) => {
+                if self.peek() != Some(&Token::By) {
+                    return Ok(Value::Number(width));
+                }
+                // WIDTHxHEIGHT @ (X, Y) - the position may be prefixed with
+                // the position type, i.e. "@ absolute(10, 10)"
+                self.expect(Token::By)?;
+                let height = self.expect_number()?;
+                self.expect(Token::At)?;
+                if let Some(Token::Ident(_)) = self.peek() {
+                    self.pos += 1;
+                }
+                self.expect(Token::OpenParen)?;
+                let x = self.expect_number()?;
+                self.expect(Token::Comma)?;
+                let y = self.expect_number()?;
+                self.expect(Token::CloseParen)?;
+                Ok(Value::Rect(LogicalRect::new(LogicalPosition::new(x, y), LogicalSize::new(width, height))))
+            },
+            Token::OpenBracket => {
+                let mut values = Vec::new();
+                loop {
+                    match self.peek() {
+                        Some(Token::CloseBracket) => { self.pos += 1; break; },
+                        Some(Token::Comma) => { self.pos += 1; },
+                        _ => values.push(self.parse_value()?),
+                    }
+                }
+                Ok(Value::List(values))
+            },
+            Token::Ident(name) => {
+                let close = match self.peek() {
+                    Some(Token::OpenParen) => Token::CloseParen,
+                    Some(Token::OpenBrace) => Token::CloseBrace,
+                    _ => return Ok(Value::Struct(name, Vec::new())),
+                };
+                self.pos += 1;
+                let mut fields = Vec::new();
+                loop {
+                    match (self.peek(), self.peek_at(1)) {
+                        (Some(t), _) if *t == close => { self.pos += 1; break; },
+                        (Some(Token::Comma), _) => { self.pos += 1; },
+                        (Some(Token::Ident(field)), Some(Token::Colon)) => {
+                            let field = field.clone();
+                            self.pos += 2;
+                            fields.push((Some(field), self.parse_value()?));
+                        },
+                        _ => fields.push((None, self.parse_value()?)),
+                    }
+                }
+                Ok(Value::Struct(name, fields))
+            },
+            t => Err(FrameParseError::UnexpectedToken("value".to_string(), t.to_string())),
+        }
+    }
+}
+
+fn get_field<'a>(fields: &'a [(Option<String>, Value)], name: &str) -> Option<&'a Value> {
+    fields.iter().find(|(k, _)| k.as_ref().map(|s| s.as_str()) == Some(name)).map(|(_, v)| v)
+}
+
+fn get_rect_field(fields: &[(Option<String>, Value)], frame_type: &str) -> Result<LogicalRect, FrameParseError> {
+    match get_field(fields, "rect") {
+        Some(Value::Rect(r)) => Ok(*r),
+        _ => Err(FrameParseError::MissingRect(frame_type.to_string())),
+    }
+}
+
+fn get_children_field(fields: &[(Option<String>, Value)]) -> Result<Vec<LayoutFrame>, FrameParseError> {
+    match get_field(fields, "children") {
+        Some(Value::List(children)) => children.iter().map(value_to_frame).collect(),
+        _ => Ok(Vec::new()),
+    }
+}
+
+fn value_to_frame(value: &Value) -> Result<LayoutFrame, FrameParseError> {
+
+    let (name, fields) = match value {
+        Value::Struct(name, fields) => (name.as_str(), fields),
+        other => return Err(FrameParseError::UnexpectedToken("frame".to_string(), format!("{:?}", other))),
+    };
+
+    match name {
+        "Frame" | "IFrame" => Ok(LayoutFrame {
+            kind: if name == "Frame" { LayoutFrameKind::Frame } else { LayoutFrameKind::IFrame },
+            rect: get_rect_field(fields, name)?,
+            children: get_children_field(fields)?,
+        }),
+        "ScrollFrame" => {
+            // either ScrollFrame(content_rect: ..., rect: ..., children: [...])
+            // or the debug output: ScrollFrame(DisplayListScrollFrame { content_rect, frame: DisplayListFrame { .. } })
+            let (content_rect_fields, frame_fields) = match fields.first() {
+                Some((None, Value::Struct(_, inner))) => match get_field(inner, "frame") {
+                    Some(Value::Struct(_, frame)) => (inner, frame),
+                    _ => (inner, inner),
+                },
+                _ => (fields, fields),
+            };
+            let content_rect = match get_field(content_rect_fields, "content_rect") {
+                Some(Value::Rect(r)) => *r,
+                _ => return Err(FrameParseError::MissingRect("ScrollFrame content_rect".to_string())),
+            };
+            Ok(LayoutFrame {
+                kind: LayoutFrameKind::ScrollFrame { content_rect },
+                rect: get_rect_field(frame_fields, name)?,
+                children: get_children_field(frame_fields)?,
+            })
+        },
+        other => Err(FrameParseError::UnknownFrameType(other.to_string())),
+    }
+}
+
+/// Parses the content of an `<output>` node
+pub fn parse_frame_tree(input: &str) -> Result<LayoutFrame, FrameParseError> {
+    let mut parser = Parser { tokens: tokenize(input)?, pos: 0 };
+    let value = parser.parse_value()?;
+    while parser.peek() == Some(&Token::Comma) {
+        parser.pos += 1;
+    }
+    if let Some(t) = parser.peek() {
+        return Err(FrameParseError::UnexpectedToken("end of input".to_string(), t.to_string()));
+    }
+    value_to_frame(&value)
+}
+
+// --- comparison
+
+fn rect_eq(a: &LogicalRect, b: &LogicalRect, tolerance: f32) -> bool {
+    (a.origin.x - b.origin.x).abs() <= tolerance &&
+    (a.origin.y - b.origin.y).abs() <= tolerance &&
+    (a.size.width - b.size.width).abs() <= tolerance &&
+    (a.size.height - b.size.height).abs() <= tolerance
+}
+
+/// Compares the two frame trees, returns all differences (empty if the trees match)
+pub fn compare_frames(expected: &LayoutFrame, actual: &LayoutFrame, tolerance: f32) -> Vec<FrameDifference> {
+    let mut differences = Vec::new();
+    compare_frames_recursive(expected, actual, tolerance, "root".to_string(), &mut differences);
+    differences
+}
+
+fn compare_frames_recursive(
+    expected: &LayoutFrame,
+    actual: &LayoutFrame,
+    tolerance: f32,
+    path: String,
+    differences: &mut Vec<FrameDifference>,
+) {
+    use self::LayoutFrameKind::*;
+
+    match (&expected.kind, &actual.kind) {
+        (ScrollFrame { content_rect: e }, ScrollFrame { content_rect: a }) => {
+            if !rect_eq(e, a, tolerance) {
+                differences.push(FrameDifference { path: path.clone(), kind: FrameDifferenceKind::ContentRect { expected: *e, actual: *a } });
+            }
+        },
+        (e, a) if e.get_name() != a.get_name() => {
+            differences.push(FrameDifference { path: path.clone(), kind: FrameDifferenceKind::Kind { expected: *e, actual: *a } });
+        },
+        _ => { },
+    }
+
+    if !rect_eq(&expected.rect, &actual.rect, tolerance) {
+        differences.push(FrameDifference { path: path.clone(), kind: FrameDifferenceKind::Rect { expected: expected.rect, actual: actual.rect } });
+    }
+
+    if expected.children.len() != actual.children.len() {
+        differences.push(FrameDifference {
+            path: path.clone(),
+            kind: FrameDifferenceKind::ChildCount { expected: expected.children.len(), actual: actual.children.len() },
+        });
+    }
+
+    for (i, (e, a)) in expected.children.iter().zip(actual.children.iter()).enumerate() {
+        compare_frames_recursive(e, a, tolerance, format!("{}.children[{}]", path, i), differences);
+    }
+}
+
+// --- running the tests
+
+/// Lays out the `<html>` of the test at the given size
+/// and returns the frame tree of the display list
+pub fn layout_test_html(html: &XmlNode, size: LogicalSize, fc_cache: &FcFontCache) -> Result<LayoutFrame, String> {
+
+    let styled_dom = str_to_dom(std::slice::from_ref(html), &mut XmlComponentMap::default())
+        .map_err(|e| format!("{}", e))?;
+
+    let id_namespace = IdNamespace(0);
+    let document_id = DocumentId { namespace_id: id_namespace, id: 0 };
+    let epoch = Epoch(0);
+    let mut window_state = FullWindowState::default();
+    window_state.size.dimensions = size;
+
+    let image_cache = ImageCache::new();
+    let mut renderer_resources = RendererResources::default();
+    let mut resource_updates = Vec::new();
+
+    let callbacks = RenderCallbacks {
+        insert_into_active_gl_textures_fn: azul_core::gl::insert_into_active_gl_textures,
+        layout_fn: azul_layout::do_the_layout,
+        layout_iframe_fn: azul_layout::do_the_iframe_layout,
+        load_font_fn: crate::font_loading::font_source_get_bytes,
+        parse_font_fn: azul_text_layout::parse_font_fn,
+    };
+
+    let solved_layout = SolvedLayout::new(
+        styled_dom,
+        epoch,
+        &document_id,
+        &window_state,
+        &mut resource_updates,
+        id_namespace,
+        &image_cache,
+        fc_cache,
+        &callbacks,
+        &mut renderer_resources,
+    );
+
+    let display_list = LayoutResult::get_cached_display_list(
+        &document_id,
+        DomId::ROOT_ID,
+        epoch,
+        &solved_layout.layout_results,
+        &window_state,
+        &GlTextureCache::empty(),
+        &renderer_resources,
+        &image_cache,
+    );
+
+    Ok(LayoutFrame::from_display_list(&display_list.root))
+}
+
+/// Runs all outputs of all tests in the given file
+///
+/// Only errors that affect the whole file (unreadable file, invalid XML) are
+/// returned as errors, tests that can't be parsed or laid out are failed results
+pub fn run_test_file(path: &Path, fc_cache: &FcFontCache, tolerance: f32) -> Result<Vec<LayoutTestResult>, LayoutTestError> {
+
+    let xml = fs::read_to_string(path).map_err(|e| LayoutTestError::Io(path.to_path_buf(), format!("{}", e)))?;
+    let root_nodes = parse_xml_string(&xml).map_err(|e| LayoutTestError::Xml(format!("{}", e)))?;
+    let mut results = Vec::new();
+
+    for test_node in root_nodes.as_ref().iter().filter(|n| normalize_casing(&n.node_type).as_str() == "test") {
+
+        let test = match parse_test_node(test_node) {
+            Ok(o) => o,
+            Err(e) => {
+                let test_name = find_attribute(test_node, "name").map(|s| s.as_str().to_string()).unwrap_or_default();
+                results.push(LayoutTestResult {
+                    file: path.to_path_buf(),
+                    known_failure: get_known_failure(&test_name),
+                    test_name,
+                    size: None,
+                    outcome: LayoutTestOutcome::Error(e),
+                });
+                continue;
+            }
+        };
+
+        for output in test.outputs.iter() {
+            let outcome = match layout_test_html(&test.html, output.size, fc_cache) {
+                Ok(actual) => LayoutTestOutcome::Compared {
+                    differences: compare_frames(&output.root, &actual, tolerance),
+                    expected: output.root.clone(),
+                    actual,
+                },
+                Err(e) => LayoutTestOutcome::Error(LayoutTestError::Dom(test.name.clone(), e)),
+            };
+            results.push(LayoutTestResult {
+                file: path.to_path_buf(),
+                test_name: test.name.clone(),
+                size: Some(output.size),
+                known_failure: get_known_failure(&test.name),
+                outcome,
+            });
+        }
+    }
+
+    Ok(results)
+}
+
+fn get_known_failure(test_name: &str) -> Option<&'static str> {
+    KNOWN_FAILURES.iter().find(|(name, _)| *name == test_name).map(|(_, reason)| *reason)
+}
+
+/// Runs all `*.xml` files in the directory (sorted by file name)
+pub fn run_test_directory(dir: &Path, tolerance: f32) -> Result<Vec<LayoutTestResult>, LayoutTestError> {
+
+    let mut files = fs::read_dir(dir)
+        .map_err(|e| LayoutTestError::Io(dir.to_path_buf(), format!("{}", e)))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("xml"))
+        .collect::<Vec<_>>();
+
+    files.sort();
+
+    let fc_cache = crate::font_loading::build_font_cache();
+    let mut results = Vec::new();
+    for file in files.iter() {
+        results.append(&mut run_test_file(file, &fc_cache, tolerance)?);
+    }
+
+    Ok(results)
+}
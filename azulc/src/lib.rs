@@ -17,6 +17,9 @@ extern crate azul_core;
 #[macro_use]
 extern crate azul_css;
 extern crate azul_layout;
+#[cfg(feature = "text_layout")]
+extern crate azul_text_layout;
+extern crate libm;
 #[cfg(feature = "font_loading")]
 extern crate rust_fontconfig;
 #[cfg(feature = "image_loading")]
@@ -29,6 +32,11 @@ pub mod svg;
 pub mod font;
 #[cfg(feature = "image_loading")]
 pub mod image;
+/// CPU rendering of display lists (screenshots, headless rendering, reftests)
+pub mod cpurender;
+/// Golden-file layout tests (runs the `/tests/*.xml` files)
+#[cfg(all(feature = "std", feature = "xml", feature = "font_loading", feature = "text_layout"))]
+pub mod layout_tests;
 /// Module for compiling CSS to Rust code
 pub mod css;
 /// Re-export of the `azul-layout` crate
@@ -6,22 +6,20 @@ use std::fs;
 use std::path::Path;
 use std::process::exit;
 
-use azul_core::window::LogicalSize;
-use azul_core::styled_dom::StyledDom;
-use azul_core::app_resources::{Epoch, AppResources};
-use azul_core::callbacks::PipelineId;
-use azul_core::ui_solver::LayoutResult;
-use azul_core::display_list::GlTextureCache;
-use azul_core::{
-    app_resources::{IdNamespace, LoadImageFn, LoadFontFn},
-    display_list::SolvedLayout,
-    gl::OptionGlContextPtr,
-    window::FullWindowState,
-    display_list::{CachedDisplayList, RenderCallbacks},
+use azul_core::FastHashMap;
+use azul_core::window::{LogicalSize, ScrollStates};
+use azul_core::styled_dom::{StyledDom, DomId};
+use azul_core::app_resources::{
+    Epoch, IdNamespace, ImageCache, ImageRef, ImageKey, ImageDescriptor,
+    ImageData, RendererResources, ResourceUpdate,
 };
+use azul_core::callbacks::DocumentId;
+use azul_core::ui_solver::LayoutResult;
+use azul_core::display_list::{GlTextureCache, CachedDisplayList, SolvedLayout, RenderCallbacks};
+use azul_core::window::FullWindowState;
+use azul_css::ColorU;
 
-use azulc_lib::xml_parser::XmlComponentMap;
-use azulc_lib::xml_parser::XmlNode;
+use azulc_lib::xml::{XmlComponentMap, XmlNode};
 
 #[derive(PartialEq)]
 enum Action {
@@ -35,6 +33,7 @@ enum Action {
     PrintDebugLayout(LogicalSize),
     PrintScrollClips(LogicalSize),
     PrintDisplayList(LogicalSize),
+    Render(LogicalSize, String),
 }
 
 fn print_help() {
@@ -45,7 +44,9 @@ fn print_help() {
     eprintln!("    --debug-layout WIDTHxHEIGHT: print a debug output of the layout solver");
     eprintln!("    --display-list WIDTHxHEIGHT: print the display list given WIDTH and HEIGHT");
     eprintln!("    --scroll-clips WIDTHxHEIGHT: print the overflowing scroll clips given WIDTH and HEIGHT");
+    eprintln!("    --render WIDTHxHEIGHT output.[png | bmp]: render the file to an image of the given WIDTH and HEIGHT");
     eprintln!("    --cascade: print the cascaded styled DOM");
+    eprintln!("    --run-tests [DIRECTORY]: run the layout tests in DIRECTORY (default: \"tests\")");
     eprintln!("");
     eprintln!("If OPTIONS is empty, the file will be printed to Rust code");
 }
@@ -62,6 +63,12 @@ fn main() {
         return;
     }
 
+    if args.get(1).map(|s| s.as_str()) == Some("--run-tests") {
+        let directory = args.get(2).cloned().unwrap_or(String::from("tests"));
+        run_layout_tests(&directory);
+        return;
+    }
+
     let input_file = args.last();
 
     // select action
@@ -110,6 +117,26 @@ fn main() {
             };
             Action::PrintDisplayList(LogicalSize::new(size_parsed.0, size_parsed.1))
         },
+        Some("--render")                => {
+            let size = env::args().nth(2).expect("no output size specified for rendering");
+            let size_parsed = match azulc_lib::parse_display_list_size(&size) {
+                Some(s) => s,
+                None => {
+                    eprintln!("error: render size \"{}\" could not be parsed", size);
+                    print_help();
+                    exit(-1);
+                }
+            };
+            let output_path = match env::args().nth(3) {
+                Some(s) if args.len() > 4 => s,
+                _ => {
+                    eprintln!("error: no output file specified for rendering");
+                    print_help();
+                    exit(-1);
+                }
+            };
+            Action::Render(LogicalSize::new(size_parsed.0, size_parsed.1), output_path)
+        },
         _ => Action::PrintRustCode,
     };
 
@@ -118,7 +145,7 @@ fn main() {
 
 fn process(action: Action, file: Option<&String>) {
 
-    use azulc_lib::xml_parser::*;
+    use azulc_lib::xml::*;
 
     if action == Action::PrintHelp {
         print_help();
@@ -170,7 +197,7 @@ fn process(action: Action, file: Option<&String>) {
             println!("{:#?}", styled_dom);
         },
         Action::PrintHtmlCode => {
-            println!("{}", styled_dom.get_html_string("", ""));
+            println!("{}", styled_dom.get_html_string("", "", false));
         },
         Action::PrintRustCode => {
             match get_rust_code(root_nodes.as_ref()) {
@@ -197,87 +224,247 @@ fn process(action: Action, file: Option<&String>) {
             }
         },
         Action::PrintDebugLayout(size) => {
-            let pipeline_id = PipelineId::new();
-            let epoch = Epoch(0);
-            let mut fake_window_state = FullWindowState::default();
-            fake_window_state.size.dimensions = size;
-            let mut app_resources = AppResources::new();
-            let layout = solve_layout(styled_dom, size, pipeline_id, epoch, &fake_window_state, &mut app_resources);
-            let layout_debug = layout_result_print_layout(&layout);
+            let image_cache = ImageCache::new();
+            let layout = solve_layout(styled_dom, size, &image_cache);
+            let layout_debug = layout_result_print_layout(&layout.layout_results[0]);
             println!("{}", layout_debug);
         },
         Action::PrintScrollClips(size) => {
-            let pipeline_id = PipelineId::new();
-            let epoch = Epoch(0);
-            let mut fake_window_state = FullWindowState::default();
-            fake_window_state.size.dimensions = size;
-            let mut app_resources = AppResources::new();
-            let layout = solve_layout(styled_dom, size, pipeline_id, epoch, &fake_window_state, &mut app_resources);
-            println!("{:#?}", layout.scrollable_nodes);
+            let image_cache = ImageCache::new();
+            let layout = solve_layout(styled_dom, size, &image_cache);
+            println!("{:#?}", layout.layout_results[0].scrollable_nodes);
         },
         Action::PrintDisplayList(size) => {
-            let pipeline_id = PipelineId::new();
-            let epoch = Epoch(0);
-            let mut fake_window_state = FullWindowState::default();
-            fake_window_state.size.dimensions = size;
-            let mut app_resources = AppResources::new();
-            let layout = solve_layout(styled_dom, size, pipeline_id, epoch, &fake_window_state, &mut app_resources);
-            let display_list = CachedDisplayList::new(
-                epoch,
-                pipeline_id,
-                &fake_window_state,
-                &[layout],
-                &GlTextureCache::default(),
-                &app_resources,
-            );
-
+            let image_cache = ImageCache::new();
+            let layout = solve_layout(styled_dom, size, &image_cache);
+            let display_list = layout.get_display_list(&image_cache);
             println!("{:#?}", display_list.root);
         },
+        Action::Render(size, output_path) => {
+            let base_path = Path::new(input_file).parent().unwrap_or(Path::new("."));
+            let image_cache = load_css_images(&styled_dom, base_path);
+            let layout = solve_layout(styled_dom, size, &image_cache);
+            let display_list = layout.get_display_list(&image_cache);
+
+            let scroll_states = ScrollStates::default();
+            let options = azulc_lib::cpurender::CpuRenderOptions {
+                width: size.width.round().max(0.0) as usize,
+                height: size.height.round().max(0.0) as usize,
+                hidpi_factor: 1.0,
+                background_color: ColorU::WHITE,
+                renderer_resources: &layout.renderer_resources,
+                images: &layout.images,
+                scroll_states: &scroll_states,
+            };
+
+            let image = match azulc_lib::cpurender::render_display_list(&display_list, &options) {
+                Some(s) => s,
+                None => {
+                    eprintln!("error: could not render display list (size {}x{})", size.width, size.height);
+                    exit(-1);
+                }
+            };
+
+            if let Err(e) = write_image(&image, &output_path) {
+                eprintln!("error: could not write \"{}\":\r\n{}", output_path, e);
+                exit(-1);
+            }
+        },
         // Action::DisplayFile => // TODO: open window and show the file,
-        // Action::RenderToPng(output_path) -- TODO!
     }
 }
 
-fn solve_layout(
-    styled_dom: StyledDom,
-    size: LogicalSize,
-    pipeline_id: PipelineId,
+/// Runs the golden-file layout tests, exits with -1 if any test failed
+/// (known failures only fail the run if they pass)
+fn run_layout_tests(directory: &str) {
+
+    use azulc_lib::layout_tests::{run_test_directory, DEFAULT_TOLERANCE};
+
+    let results = match run_test_directory(Path::new(directory), DEFAULT_TOLERANCE) {
+        Ok(o) => o,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            exit(-1);
+        }
+    };
+
+    for result in results.iter() {
+        println!("{}", result);
+    }
+
+    let failed = results.iter().filter(|r| r.is_failure()).count();
+    let known_failures = results.iter().filter(|r| !r.is_success() && r.known_failure.is_some()).count();
+    println!("");
+    println!(
+        "test result: {}. {} passed; {} failed; {} known failures",
+        if failed == 0 { "ok" } else { "FAILED" },
+        results.len() - failed - known_failures, failed, known_failures,
+    );
+
+    if failed != 0 {
+        exit(-1);
+    }
+}
+
+/// Layout results + the resources that the layout registered,
+/// necessary to build and render the display list
+struct SolvedFile {
+    document_id: DocumentId,
     epoch: Epoch,
-    fake_window_state: &FullWindowState,
-    app_resources: &mut AppResources
-) -> LayoutResult {
+    window_state: FullWindowState,
+    layout_results: Vec<LayoutResult>,
+    renderer_resources: RendererResources,
+    images: FastHashMap<ImageKey, (ImageDescriptor, ImageData)>,
+}
 
-    let gl_context = OptionGlContextPtr::None;
+impl SolvedFile {
+    fn get_display_list(&self, image_cache: &ImageCache) -> CachedDisplayList {
+        LayoutResult::get_cached_display_list(
+            &self.document_id,
+            DomId::ROOT_ID,
+            self.epoch,
+            &self.layout_results,
+            &self.window_state,
+            &GlTextureCache::empty(),
+            &self.renderer_resources,
+            image_cache,
+        )
+    }
+}
 
-    let fc_cache = azulc_lib::font_loading::build_font_cache();
+fn solve_layout(styled_dom: StyledDom, size: LogicalSize, image_cache: &ImageCache) -> SolvedFile {
 
-    // Important!
-    app_resources.add_pipeline(pipeline_id);
+    let id_namespace = IdNamespace(0);
+    let document_id = DocumentId { namespace_id: id_namespace, id: 0 };
+    let epoch = Epoch(0);
+    let mut fake_window_state = FullWindowState::default();
+    fake_window_state.size.dimensions = size;
 
+    let fc_cache = azulc_lib::font_loading::build_font_cache();
+    let mut renderer_resources = RendererResources::default();
     let mut resource_updates = Vec::new();
+
     let callbacks = RenderCallbacks {
-        insert_into_active_gl_textures: azul_core::gl::insert_into_active_gl_textures,
+        insert_into_active_gl_textures_fn: azul_core::gl::insert_into_active_gl_textures,
         layout_fn: azul_layout::do_the_layout,
-        load_font_fn: LoadFontFn { cb: azulc_lib::font_loading::font_source_get_bytes }, // needs feature="font_loading"
-        load_image_fn: LoadImageFn { cb: azulc_lib::image_loading::image_source_get_bytes }, // needs feature="image_loading"
-        parse_font_fn: azul_layout::text_layout::parse_font_fn, // needs feature="text_layout"
+        layout_iframe_fn: azul_layout::do_the_iframe_layout,
+        load_font_fn: azulc_lib::font_loading::font_source_get_bytes, // needs feature="font_loading"
+        parse_font_fn: azul_text_layout::parse_font_fn, // needs feature="text_layout"
     };
 
     // Solve the layout (the extra parameters are necessary because of IFrame recursion)
-    let mut solved_layout = SolvedLayout::new(
+    let solved_layout = SolvedLayout::new(
         styled_dom,
         epoch,
-        pipeline_id,
+        &document_id,
         &fake_window_state,
-        &gl_context,
         &mut resource_updates,
-        IdNamespace(0),
-        app_resources,
-        callbacks,
+        id_namespace,
+        image_cache,
         &fc_cache,
+        &callbacks,
+        &mut renderer_resources,
     );
 
-    solved_layout.layout_results.remove(0)
+    // There is no renderer that could hold on to the image data,
+    // so store it here for the CPU rasterizer
+    let mut images = FastHashMap::default();
+    for update in resource_updates {
+        match update {
+            ResourceUpdate::AddImage(add) => { images.insert(add.key, (add.descriptor, add.data)); },
+            ResourceUpdate::UpdateImage(update) => { images.insert(update.key, (update.descriptor, update.data)); },
+            ResourceUpdate::DeleteImage(key) => { images.remove(&key); },
+            _ => { },
+        }
+    }
+
+    SolvedFile {
+        document_id,
+        epoch,
+        window_state: fake_window_state,
+        layout_results: solved_layout.layout_results,
+        renderer_resources,
+        images,
+    }
+}
+
+/// Loads the images referenced via `background: image("...")` in the CSS,
+/// the image IDs are interpreted as file paths relative to the XML file
+fn load_css_images(styled_dom: &StyledDom, base_path: &Path) -> ImageCache {
+
+    use azul_core::id_tree::NodeId;
+    use azul_css::StyleBackgroundContent;
+    use azulc_lib::image::decode::{decode_raw_image_from_any_bytes, ResultRawImageDecodeImageError};
+
+    let mut image_cache = ImageCache::new();
+    let css_property_cache = styled_dom.get_css_property_cache();
+    let styled_nodes = styled_dom.styled_nodes.as_container();
+
+    for (node_id, node_data) in styled_dom.node_data.as_ref().iter().enumerate() {
+        let node_id = NodeId::new(node_id);
+        let state = &styled_nodes[node_id].state;
+        let backgrounds = match css_property_cache.get_background_content(node_data, &node_id, state).and_then(|b| b.get_property()) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        for background in backgrounds.as_ref().iter() {
+            let image_id = match background {
+                StyleBackgroundContent::Image(id) => id,
+                _ => continue,
+            };
+
+            if image_cache.get_css_image_id(image_id).is_some() {
+                continue;
+            }
+
+            let image_path = base_path.join(image_id.as_str());
+            let image_bytes = match fs::read(&image_path) {
+                Ok(o) => o,
+                Err(e) => {
+                    eprintln!("warning: could not load image \"{}\": {}", image_path.display(), e);
+                    continue;
+                }
+            };
+
+            let raw_image = match decode_raw_image_from_any_bytes(&image_bytes) {
+                ResultRawImageDecodeImageError::Ok(o) => o,
+                ResultRawImageDecodeImageError::Err(e) => {
+                    eprintln!("warning: could not decode image \"{}\": {}", image_path.display(), e);
+                    continue;
+                }
+            };
+
+            if let Some(image_ref) = ImageRef::new_rawimage(raw_image) {
+                image_cache.add_css_image_id(image_id.clone(), image_ref);
+            }
+        }
+    }
+
+    image_cache
+}
+
+/// Encodes the image depending on the file extension (PNG by default)
+fn write_image(image: &azul_core::app_resources::RawImage, output_path: &str) -> Result<(), String> {
+
+    use azulc_lib::image::encode::{encode_bmp, encode_png, ResultU8VecEncodeImageError};
+
+    let extension = Path::new(output_path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase());
+
+    let encoded = match extension.as_ref().map(|s| s.as_str()) {
+        Some("bmp") => encode_bmp(image),
+        _ => encode_png(image),
+    };
+
+    let bytes = match encoded {
+        ResultU8VecEncodeImageError::Ok(o) => o,
+        ResultU8VecEncodeImageError::Err(e) => return Err(format!("{}", e)),
+    };
+
+    fs::write(output_path, bytes.as_ref()).map_err(|e| format!("{}", e))
 }
 
 fn layout_result_print_layout(result: &LayoutResult) -> String {
@@ -319,7 +506,7 @@ fn layout_result_print_layout(result: &LayoutResult) -> String {
 }
 
 fn get_rust_code(root_nodes: &[XmlNode]) -> Result<String, String> {
-    azulc_lib::xml_parser::str_to_rust_code(root_nodes, "", &mut XmlComponentMap::default()).map_err(|e| format!("{}", e))
+    azulc_lib::xml::str_to_rust_code(root_nodes, "", &mut XmlComponentMap::default()).map_err(|e| format!("{}", e))
 }
 
 fn get_c_code(root_nodes: &[XmlNode]) -> Result<String, String> {
@@ -4,7 +4,7 @@ use core::fmt;
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 use alloc::string::String;
-use alloc::prelude::v1::Box;
+use alloc::boxed::Box;
 use azul_core::{
     impl_from,
     dom::Dom,
@@ -77,16 +77,15 @@ pub fn domxml_from_file<I: AsRef<Path>>(file_path: I, component_map: &mut XmlCom
 /// # Example
 ///
 /// ```rust
-/// # use azulc::xml::{XmlNode, parse_xml_string};
-/// assert_eq!(
-///     parse_xml_string("<app><p /><div id='thing' /></app>").unwrap(),
-///     vec![
-///          XmlNode::new("app").with_children(vec![
-///             XmlNode::new("p"),
-///             XmlNode::new("div").with_attribute("id", "thing"),
-///         ])
-///     ]
-/// )
+/// # use azulc_lib::xml::parse_xml_string;
+/// let nodes = parse_xml_string("<app><p /><div id='thing' /></app>").unwrap();
+/// let app = &nodes.as_ref()[0];
+/// assert_eq!(app.node_type.as_str(), "app");
+///
+/// let children = app.children.as_ref();
+/// assert_eq!(children[0].node_type.as_str(), "p");
+/// assert_eq!(children[1].node_type.as_str(), "div");
+/// assert_eq!(children[1].attributes.as_ref()[0].value.as_str(), "thing");
 /// ```
 #[cfg(feature = "xml")]
 pub fn parse_xml_string(xml: &str) -> Result<XmlNodeVec, XmlError> {
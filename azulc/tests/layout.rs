@@ -0,0 +1,116 @@
+//! Runs the golden-file layout tests in the `/tests` directory
+
+// the test runner needs the XML parser and the system fonts
+#![cfg(all(feature = "xml", feature = "font_loading", feature = "text_layout"))]
+
+use std::path::{Path, PathBuf};
+
+use azul_core::window::{LogicalPosition, LogicalRect, LogicalSize};
+use azulc_lib::layout_tests::{
+    DEFAULT_TOLERANCE, KNOWN_FAILURES, LayoutFrame, LayoutFrameKind,
+    LayoutTestOutcome, LayoutTestResult, compare_frames,
+    parse_frame_tree, run_test_directory,
+};
+
+fn scroll_frame() -> LayoutFrame {
+    LayoutFrame {
+        kind: LayoutFrameKind::ScrollFrame {
+            content_rect: LogicalRect::new(LogicalPosition::new(0.0, 0.0), LogicalSize::new(600.0, 600.0)),
+        },
+        rect: LogicalRect::new(LogicalPosition::new(0.0, 0.0), LogicalSize::new(500.0, 500.0)),
+        children: vec![LayoutFrame {
+            kind: LayoutFrameKind::Frame,
+            rect: LogicalRect::new(LogicalPosition::new(50.0, 50.0), LogicalSize::new(500.0, 500.0)),
+            children: Vec::new(),
+        }],
+    }
+}
+
+#[test]
+fn test_parse_frame_tree() {
+
+    let expected = scroll_frame();
+
+    let debug_output = "
+        ScrollFrame(
+            DisplayListScrollFrame {
+                content_rect: 600x600 @ (0, 0)
+                scroll_tag: ScrollTagId(1)
+                frame: DisplayListFrame {
+                    rect: 500x500 @ (0, 0),
+                    tag: 1
+                    children: [
+                        Frame(
+                            rect: 500x500 @ static(50, 50),
+                        ),
+                    ]
+                }
+            },
+        )
+    ";
+
+    assert_eq!(parse_frame_tree(debug_output), Ok(expected.clone()));
+
+    // the Display output has to be parseable again
+    assert_eq!(parse_frame_tree(&expected.to_string()), Ok(expected.clone()));
+
+    let mut moved = expected.clone();
+    moved.children[0].rect.origin.y = 170.0;
+    assert!(compare_frames(&expected, &expected, DEFAULT_TOLERANCE).is_empty());
+    assert_eq!(compare_frames(&expected, &moved, DEFAULT_TOLERANCE).len(), 1);
+}
+
+#[test]
+fn test_failed_result_diff() {
+
+    let expected = scroll_frame();
+    let mut actual = expected.clone();
+    actual.children[0].rect.origin.y = 170.0;
+
+    let mut result = LayoutTestResult {
+        file: PathBuf::from("test.xml"),
+        test_name: "moved".to_string(),
+        size: Some(LogicalSize::new(500.0, 500.0)),
+        known_failure: None,
+        outcome: LayoutTestOutcome::Compared {
+            differences: compare_frames(&expected, &actual, DEFAULT_TOLERANCE),
+            expected,
+            actual,
+        },
+    };
+
+    assert!(!result.is_success());
+    assert!(result.is_failure());
+
+    // only the changed line is removed / added, the rest of the tree is unchanged
+    let output = result.to_string();
+    let diff = output.lines().skip_while(|l| !l.starts_with("    +++")).skip(1).collect::<Vec<_>>();
+    assert_eq!(diff.iter().filter(|l| l.starts_with("    -")).collect::<Vec<_>>(), vec![&"    -            rect: 500x500 @ (50, 50),"]);
+    assert_eq!(diff.iter().filter(|l| l.starts_with("    +")).collect::<Vec<_>>(), vec![&"    +            rect: 500x500 @ (50, 170),"]);
+    assert!(diff.contains(&"     ScrollFrame("));
+
+    // a known failure doesn't fail the run
+    result.known_failure = Some("reason");
+    assert!(!result.is_failure());
+    assert!(result.to_string().contains("FAILED (known failure: reason)"));
+}
+
+// Runs the tests in the /tests directory (cargo test -p azulc --features="xml font_loading" --test layout)
+#[test]
+fn test_layout_test_files() {
+
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("tests");
+    let results = run_test_directory(&dir, DEFAULT_TOLERANCE).unwrap();
+    let failed = results.iter().filter(|r| r.is_failure()).collect::<Vec<_>>();
+
+    for r in failed.iter() {
+        println!("{}", r);
+    }
+
+    assert!(failed.is_empty(), "{} of {} layout tests failed", failed.len(), results.len());
+
+    // every entry of KNOWN_FAILURES has to refer to an existing test
+    for (name, _) in KNOWN_FAILURES.iter() {
+        assert!(results.iter().any(|r| r.test_name == *name), "unknown test \"{}\" in KNOWN_FAILURES", name);
+    }
+}
@@ -682,11 +682,15 @@ def sort_structs_map(api_data, structs_map):
         "AzDomVec": {"type": "struct", "name": "AzDom"},
         "AzMenuItemVec": {"type": "struct", "name": "AzMenuItem"},
         "AzXmlNodeVec": {"type": "struct", "name": "AzXmlNode"},
+        "AzCssPathSelectorVec": {"type": "union", "name": "AzCssPathSelector"},
+        "AzAccessibilityNodeVec": {"type": "struct", "name": "AzAccessibilityNode"},
     }
     forward_delcarations = OrderedDict([
         ("AzDomVec", "Dom"),
         ("AzXmlNodeVec", "XmlNode"),
-        ("AzMenuItemVec", "MenuItem")
+        ("AzMenuItemVec", "MenuItem"),
+        ("AzCssPathSelectorVec", "CssPathSelector"),
+        ("AzAccessibilityNodeVec", "AccessibilityNode"),
     ])
 
     classes_not_found = OrderedDict([])
@@ -1122,6 +1126,20 @@ def generate_python_api(api_data, structs_map, functions_map):
         ("widgets", "TextInput", "with_on_virtual_key_down"),
         ("widgets", "TextInput", "set_on_focus_lost"),
         ("widgets", "TextInput", "with_on_focus_lost"),
+        ("widgets", "TextEdit", "set_on_text_change"),
+        ("widgets", "TextEdit", "with_on_text_change"),
+        ("widgets", "TextEdit", "set_on_focus_lost"),
+        ("widgets", "TextEdit", "with_on_focus_lost"),
+        ("widgets", "Slider", "set_on_value_change"),
+        ("widgets", "Slider", "with_on_value_change"),
+        ("widgets", "DropDown", "set_on_choice_change"),
+        ("widgets", "DropDown", "with_on_choice_change"),
+        ("widgets", "Spreadsheet", "set_on_cells_change"),
+        ("widgets", "Spreadsheet", "with_on_cells_change"),
+        ("widgets", "Spreadsheet", "set_on_selection_change"),
+        ("widgets", "Spreadsheet", "with_on_selection_change"),
+        ("widgets", "Spreadsheet", "set_on_column_resize"),
+        ("widgets", "Spreadsheet", "with_on_column_resize"),
 
         # unnecessary due to Python string wrappers
         ("str", "String", "as_refstr"),
@@ -1231,6 +1249,13 @@ def generate_python_api(api_data, structs_map, functions_map):
         "TextInputOnVirtualKeyDownCallback": {},
         "TextInputOnFocusLostCallback": {},
         "NumberInputOnValueChangeCallback": {},
+        "SpreadsheetOnCellsChangeCallback": {},
+        "SpreadsheetOnSelectionChangeCallback": {},
+        "SpreadsheetOnColumnResizeCallback": {},
+        "DropDownOnChoiceChangeCallback": {},
+        "SliderOnValueChangeCallback": {},
+        "TextEditOnTextChangeCallback": {},
+        "TextEditOnFocusLostCallback": {},
         "CheckBoxOnToggleCallback": {},
     }
 